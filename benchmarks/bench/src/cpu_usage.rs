@@ -0,0 +1,73 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Measures the CPU time consumed by the current process. The measurement is
+//! currently only supported on Linux, on all other platforms no value is reported.
+
+use core::time::Duration;
+
+/// Snapshot of the CPU time that was consumed by the current process.
+#[derive(Debug, Clone, Copy)]
+pub struct CpuUsage {
+    cpu_time: Option<Duration>,
+}
+
+impl CpuUsage {
+    /// Acquires the CPU time that the process consumed so far.
+    pub fn now() -> Self {
+        Self {
+            cpu_time: process_cpu_time(),
+        }
+    }
+
+    /// Returns the consumed CPU time since the snapshot was taken in percent of a single
+    /// core in relation to the provided wall clock `runtime`. A process that fully
+    /// utilizes two cores reports 200%.
+    pub fn percent_since(&self, runtime: Duration) -> Option<f64> {
+        let start = self.cpu_time?;
+        let now = process_cpu_time()?;
+
+        if runtime.is_zero() {
+            return None;
+        }
+
+        Some(now.saturating_sub(start).as_secs_f64() / runtime.as_secs_f64() * 100.0)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn process_cpu_time() -> Option<Duration> {
+    use iceoryx2_bb_posix::system_configuration::SystemInfo;
+
+    let stat = std::fs::read_to_string("/proc/self/stat").ok()?;
+    // the process name can contain spaces, all fields after the closing parenthesis
+    // are separated by a single space and utime and stime are the 14th and 15th field
+    let fields: Vec<&str> = stat.rsplit_once(')')?.1.split_whitespace().collect();
+    let user_ticks: u64 = fields.get(11)?.parse().ok()?;
+    let system_ticks: u64 = fields.get(12)?.parse().ok()?;
+
+    let ticks_per_second = SystemInfo::NumberOfClockTicksPerSecond.value() as u64;
+    if ticks_per_second == 0 {
+        return None;
+    }
+
+    let ticks = user_ticks + system_ticks;
+    Some(
+        Duration::from_secs(ticks / ticks_per_second)
+            + Duration::from_nanos((ticks % ticks_per_second) * 1_000_000_000 / ticks_per_second),
+    )
+}
+
+#[cfg(not(target_os = "linux"))]
+fn process_cpu_time() -> Option<Duration> {
+    None
+}
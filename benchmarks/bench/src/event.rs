@@ -0,0 +1,244 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Quantifies the latency between a [`Notifier`](iceoryx2::port::notifier::Notifier)
+//! sending a notification and a [`Listener`](iceoryx2::port::listener::Listener) waking up
+//! and responding to it. Two participants are connected bidirectionally and use a blocking
+//! wait to respond to every notification.
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_bench::event::EventBenchmark;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let result = EventBenchmark::new()
+//!     .iterations(10000)
+//!     .run::<ipc::Service>()?;
+//!
+//! println!("{:?}", result);
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2::config::Config;
+use iceoryx2::prelude::*;
+use iceoryx2_bb_posix::barrier::*;
+use iceoryx2_bb_posix::clock::Time;
+use iceoryx2_bb_posix::thread::ThreadBuilder;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+
+use crate::cpu_usage::CpuUsage;
+use crate::report::{BenchmarkResult, Throughput};
+use crate::statistics::LatencyRecorder;
+
+/// The name under which the results of the [`EventBenchmark`] are reported.
+pub const BENCHMARK_NAME: &str = "event";
+
+/// Configures and runs the event benchmark.
+#[derive(Debug, Clone)]
+pub struct EventBenchmark {
+    config: Option<Config>,
+    iterations: u64,
+    max_event_id: usize,
+    cpu_core_participant_1: usize,
+    cpu_core_participant_2: usize,
+    number_of_additional_notifiers: usize,
+    number_of_additional_listeners: usize,
+}
+
+impl Default for EventBenchmark {
+    fn default() -> Self {
+        Self {
+            config: None,
+            iterations: 1000000,
+            max_event_id: 128,
+            cpu_core_participant_1: 0,
+            cpu_core_participant_2: 1,
+            number_of_additional_notifiers: 0,
+            number_of_additional_listeners: 0,
+        }
+    }
+}
+
+impl EventBenchmark {
+    /// Creates a new benchmark with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defines the [`Config`] of the [`Node`]s that are used in the benchmark. If it is not
+    /// set, the global [`Config`] is used.
+    pub fn config(mut self, value: &Config) -> Self {
+        self.config = Some(value.clone());
+        self
+    }
+
+    /// Defines how often the A --> B --> A communication is repeated.
+    pub fn iterations(mut self, value: u64) -> Self {
+        self.iterations = value;
+        self
+    }
+
+    /// Defines the greatest supported [`EventId`].
+    pub fn max_event_id(mut self, value: usize) -> Self {
+        self.max_event_id = value;
+        self
+    }
+
+    /// Defines the cpu core that shall be used by participant 1.
+    pub fn cpu_core_participant_1(mut self, value: usize) -> Self {
+        self.cpu_core_participant_1 = value;
+        self
+    }
+
+    /// Defines the cpu core that shall be used by participant 2.
+    pub fn cpu_core_participant_2(mut self, value: usize) -> Self {
+        self.cpu_core_participant_2 = value;
+        self
+    }
+
+    /// Defines the number of additional notifiers per service in the setup.
+    pub fn number_of_additional_notifiers(mut self, value: usize) -> Self {
+        self.number_of_additional_notifiers = value;
+        self
+    }
+
+    /// Defines the number of additional listeners per service in the setup.
+    pub fn number_of_additional_listeners(mut self, value: usize) -> Self {
+        self.number_of_additional_listeners = value;
+        self
+    }
+
+    /// Runs the benchmark and returns the result.
+    pub fn run<S: Service>(&self) -> Result<BenchmarkResult, Box<dyn core::error::Error>> {
+        let service_name_prefix = format!(
+            "iox2_bench_{}",
+            UniqueSystemId::new()
+                .expect("failed to create unique id")
+                .value()
+        );
+        let service_name_a2b = ServiceName::new(&format!("{}_a2b", service_name_prefix))?;
+        let service_name_b2a = ServiceName::new(&format!("{}_b2a", service_name_prefix))?;
+        let node = match &self.config {
+            Some(config) => NodeBuilder::new().config(config).create::<S>()?,
+            None => NodeBuilder::new().create::<S>()?,
+        };
+
+        let service_a2b = node
+            .service_builder(&service_name_a2b)
+            .event()
+            .max_notifiers(1 + self.number_of_additional_notifiers)
+            .max_listeners(1 + self.number_of_additional_listeners)
+            .event_id_max_value(self.max_event_id)
+            .create()?;
+
+        let service_b2a = node
+            .service_builder(&service_name_b2a)
+            .event()
+            .max_notifiers(1 + self.number_of_additional_notifiers)
+            .max_listeners(1 + self.number_of_additional_listeners)
+            .event_id_max_value(self.max_event_id)
+            .create()?;
+
+        let mut additional_notifiers = Vec::new();
+        let mut additional_listeners = Vec::new();
+
+        for _ in 0..self.number_of_additional_notifiers {
+            additional_notifiers.push(service_a2b.notifier_builder().create()?);
+            additional_notifiers.push(service_b2a.notifier_builder().create()?);
+        }
+
+        for _ in 0..self.number_of_additional_listeners {
+            additional_listeners.push(service_a2b.listener_builder().create()?);
+            additional_listeners.push(service_b2a.listener_builder().create()?);
+        }
+
+        let start_benchmark_barrier_handle = BarrierHandle::new();
+        let startup_barrier_handle = BarrierHandle::new();
+        let startup_barrier = BarrierBuilder::new(3)
+            .create(&startup_barrier_handle)
+            .unwrap();
+        let start_benchmark_barrier = BarrierBuilder::new(3)
+            .create(&start_benchmark_barrier_handle)
+            .unwrap();
+
+        let mut latency_recorder = LatencyRecorder::new(self.iterations);
+        let recorder = &mut latency_recorder;
+
+        let t1 = ThreadBuilder::new()
+            .affinity(self.cpu_core_participant_1)
+            .priority(255)
+            .spawn(|| {
+                let notifier_a2b = service_a2b.notifier_builder().create().unwrap();
+                let listener_b2a = service_b2a.listener_builder().create().unwrap();
+
+                startup_barrier.wait();
+                start_benchmark_barrier.wait();
+
+                for i in 0..self.iterations {
+                    let round_trip_start = if recorder.is_recorded(i) {
+                        Some(Time::now().expect("failed to acquire time"))
+                    } else {
+                        None
+                    };
+
+                    notifier_a2b.notify().expect("failed to notify");
+                    while listener_b2a.blocking_wait_one().unwrap().is_none() {}
+
+                    if let Some(round_trip_start) = round_trip_start {
+                        let round_trip =
+                            round_trip_start.elapsed().expect("failed to measure time");
+                        recorder.record(round_trip.as_nanos() as u64);
+                    }
+                }
+            })
+            .expect("failed to spawn thread");
+
+        let t2 = ThreadBuilder::new()
+            .affinity(self.cpu_core_participant_2)
+            .priority(255)
+            .spawn(|| {
+                let notifier_b2a = service_b2a.notifier_builder().create().unwrap();
+                let listener_a2b = service_a2b.listener_builder().create().unwrap();
+
+                startup_barrier.wait();
+                start_benchmark_barrier.wait();
+
+                for _ in 0..self.iterations {
+                    while listener_a2b.blocking_wait_one().unwrap().is_none() {}
+                    notifier_b2a.notify().expect("failed to notify");
+                }
+            })
+            .expect("failed to spawn thread");
+
+        startup_barrier.wait();
+        let cpu_usage = CpuUsage::now();
+        let start = Time::now().expect("failed to acquire time");
+        start_benchmark_barrier.wait();
+
+        drop(t1);
+        drop(t2);
+
+        let runtime = start.elapsed().expect("failed to measure time");
+
+        Ok(BenchmarkResult {
+            benchmark: BENCHMARK_NAME.to_string(),
+            service_type: core::any::type_name::<S>().to_string(),
+            payload_size: 0,
+            iterations: self.iterations,
+            runtime_ns: runtime.as_nanos(),
+            latency: latency_recorder.statistics(),
+            throughput: Throughput::new(self.iterations * 2, 0, runtime),
+            cpu_usage_percent: cpu_usage.percent_since(runtime),
+        })
+    }
+}
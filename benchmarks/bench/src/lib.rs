@@ -0,0 +1,47 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Reusable iceoryx2 benchmarks that produce machine-readable results. Every benchmark
+//! measures the round-trip between two participants, `a2b` and `b2a`, and reports
+//! the latency percentiles, the throughput and the CPU usage of the process as
+//! [`BenchmarkResult`]. Multiple results can be collected in a [`BenchmarkReport`] and
+//! serialized to JSON to track regressions of custom topologies.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_bench::publish_subscribe::PublishSubscribeBenchmark;
+//! use iceoryx2_bench::BenchmarkReport;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let results = PublishSubscribeBenchmark::new()
+//!     .iterations(100000)
+//!     .payload_sizes(&[64, 1024, 65536])
+//!     .run::<ipc::Service>()?;
+//!
+//! let mut report = BenchmarkReport::new();
+//! report.add_results(results);
+//! println!("{}", report.to_json()?);
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod cpu_usage;
+pub mod event;
+pub mod publish_subscribe;
+
+mod report;
+mod statistics;
+
+pub use report::{BenchmarkReport, BenchmarkResult, Throughput};
+pub use statistics::LatencyStatistics;
@@ -0,0 +1,315 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Quantifies the latency between a [`Publisher`](iceoryx2::port::publisher::Publisher)
+//! sending a message and a [`Subscriber`](iceoryx2::port::subscriber::Subscriber) receiving
+//! it. Two participants are connected bidirectionally and use busy waiting to respond
+//! promptly to every received message.
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_bench::publish_subscribe::PublishSubscribeBenchmark;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! for result in PublishSubscribeBenchmark::new()
+//!     .iterations(10000)
+//!     .payload_sizes(&[8, 8192])
+//!     .run::<ipc::Service>()?
+//! {
+//!     println!("{:?}", result);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::mem::MaybeUninit;
+
+use iceoryx2::config::Config;
+use iceoryx2::prelude::*;
+use iceoryx2_bb_posix::barrier::*;
+use iceoryx2_bb_posix::clock::Time;
+use iceoryx2_bb_posix::thread::ThreadBuilder;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+
+use crate::cpu_usage::CpuUsage;
+use crate::report::{BenchmarkResult, Throughput};
+use crate::statistics::LatencyRecorder;
+
+/// The name under which the results of the [`PublishSubscribeBenchmark`] are reported.
+pub const BENCHMARK_NAME: &str = "publish-subscribe";
+
+/// Configures and runs the publish-subscribe benchmark.
+#[derive(Debug, Clone)]
+pub struct PublishSubscribeBenchmark {
+    config: Option<Config>,
+    iterations: u64,
+    payload_sizes: Vec<usize>,
+    cpu_core_participant_1: usize,
+    cpu_core_participant_2: usize,
+    send_copy: bool,
+    number_of_additional_publishers: usize,
+    number_of_additional_subscribers: usize,
+}
+
+impl Default for PublishSubscribeBenchmark {
+    fn default() -> Self {
+        Self {
+            config: None,
+            iterations: 10000000,
+            payload_sizes: vec![8192],
+            cpu_core_participant_1: 0,
+            cpu_core_participant_2: 1,
+            send_copy: false,
+            number_of_additional_publishers: 0,
+            number_of_additional_subscribers: 0,
+        }
+    }
+}
+
+impl PublishSubscribeBenchmark {
+    /// Creates a new benchmark with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defines the [`Config`] of the [`Node`]s that are used in the benchmark. If it is not
+    /// set, the global [`Config`] is used.
+    pub fn config(mut self, value: &Config) -> Self {
+        self.config = Some(value.clone());
+        self
+    }
+
+    /// Defines how often the A --> B --> A communication is repeated.
+    pub fn iterations(mut self, value: u64) -> Self {
+        self.iterations = value;
+        self
+    }
+
+    /// Defines the payload sizes in bytes. The benchmark is performed once for every payload
+    /// size.
+    pub fn payload_sizes(mut self, value: &[usize]) -> Self {
+        self.payload_sizes = value.to_vec();
+        self
+    }
+
+    /// Defines the cpu core that shall be used by participant 1.
+    pub fn cpu_core_participant_1(mut self, value: usize) -> Self {
+        self.cpu_core_participant_1 = value;
+        self
+    }
+
+    /// Defines the cpu core that shall be used by participant 2.
+    pub fn cpu_core_participant_2(mut self, value: usize) -> Self {
+        self.cpu_core_participant_2 = value;
+        self
+    }
+
+    /// Sends a copy of the payload instead of performing true zero-copy. Can provide an hint
+    /// on how expensive serialization can be.
+    pub fn send_copy(mut self, value: bool) -> Self {
+        self.send_copy = value;
+        self
+    }
+
+    /// Defines the number of additional publishers per service in the setup.
+    pub fn number_of_additional_publishers(mut self, value: usize) -> Self {
+        self.number_of_additional_publishers = value;
+        self
+    }
+
+    /// Defines the number of additional subscribers per service in the setup.
+    pub fn number_of_additional_subscribers(mut self, value: usize) -> Self {
+        self.number_of_additional_subscribers = value;
+        self
+    }
+
+    /// Runs the benchmark for every configured payload size and returns the results.
+    pub fn run<S: Service>(&self) -> Result<Vec<BenchmarkResult>, Box<dyn core::error::Error>> {
+        let mut results = Vec::with_capacity(self.payload_sizes.len());
+        for payload_size in &self.payload_sizes {
+            results.push(self.run_with_payload_size::<S>(*payload_size)?);
+        }
+
+        Ok(results)
+    }
+
+    fn run_with_payload_size<S: Service>(
+        &self,
+        payload_size: usize,
+    ) -> Result<BenchmarkResult, Box<dyn core::error::Error>> {
+        let service_name_prefix = format!(
+            "iox2_bench_{}",
+            UniqueSystemId::new()
+                .expect("failed to create unique id")
+                .value()
+        );
+        let service_name_a2b = ServiceName::new(&format!("{}_a2b", service_name_prefix))?;
+        let service_name_b2a = ServiceName::new(&format!("{}_b2a", service_name_prefix))?;
+        let node = match &self.config {
+            Some(config) => NodeBuilder::new().config(config).create::<S>()?,
+            None => NodeBuilder::new().create::<S>()?,
+        };
+
+        let service_a2b = node
+            .service_builder(&service_name_a2b)
+            .publish_subscribe::<[u8]>()
+            .max_publishers(1 + self.number_of_additional_publishers)
+            .max_subscribers(1 + self.number_of_additional_subscribers)
+            .history_size(0)
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(true)
+            .create()?;
+
+        let service_b2a = node
+            .service_builder(&service_name_b2a)
+            .publish_subscribe::<[u8]>()
+            .max_publishers(1 + self.number_of_additional_publishers)
+            .max_subscribers(1 + self.number_of_additional_subscribers)
+            .history_size(0)
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(true)
+            .create()?;
+
+        let mut additional_publishers = Vec::new();
+        let mut additional_subscribers = Vec::new();
+
+        for _ in 0..self.number_of_additional_publishers {
+            additional_publishers.push(service_a2b.publisher_builder().create()?);
+            additional_publishers.push(service_b2a.publisher_builder().create()?);
+        }
+
+        for _ in 0..self.number_of_additional_subscribers {
+            additional_subscribers.push(service_a2b.subscriber_builder().create()?);
+            additional_subscribers.push(service_b2a.subscriber_builder().create()?);
+        }
+
+        let start_benchmark_barrier_handle = BarrierHandle::new();
+        let startup_barrier_handle = BarrierHandle::new();
+        let startup_barrier = BarrierBuilder::new(3)
+            .create(&startup_barrier_handle)
+            .unwrap();
+        let start_benchmark_barrier = BarrierBuilder::new(3)
+            .create(&start_benchmark_barrier_handle)
+            .unwrap();
+
+        let mut latency_recorder = LatencyRecorder::new(self.iterations);
+        let recorder = &mut latency_recorder;
+
+        let t1 = ThreadBuilder::new()
+            .affinity(self.cpu_core_participant_1)
+            .priority(255)
+            .spawn(|| {
+                let sender_a2b = service_a2b
+                    .publisher_builder()
+                    .initial_max_slice_len(payload_size)
+                    .create()
+                    .unwrap();
+                let receiver_b2a = service_b2a.subscriber_builder().create().unwrap();
+
+                startup_barrier.wait();
+                start_benchmark_barrier.wait();
+
+                let mut sample = if self.send_copy {
+                    let mut sample = sender_a2b.loan_slice_uninit(payload_size).unwrap();
+                    sample.payload_mut().fill(MaybeUninit::new(0));
+                    unsafe { sample.assume_init() }
+                } else {
+                    unsafe {
+                        sender_a2b
+                            .loan_slice_uninit(payload_size)
+                            .unwrap()
+                            .assume_init()
+                    }
+                };
+
+                for i in 0..self.iterations {
+                    let round_trip_start = if recorder.is_recorded(i) {
+                        Some(Time::now().expect("failed to acquire time"))
+                    } else {
+                        None
+                    };
+
+                    sample.send().unwrap();
+                    sample = unsafe {
+                        sender_a2b
+                            .loan_slice_uninit(payload_size)
+                            .unwrap()
+                            .assume_init()
+                    };
+                    while receiver_b2a.receive().unwrap().is_none() {}
+
+                    if let Some(round_trip_start) = round_trip_start {
+                        let round_trip =
+                            round_trip_start.elapsed().expect("failed to measure time");
+                        recorder.record(round_trip.as_nanos() as u64);
+                    }
+                }
+            })
+            .expect("failed to spawn thread");
+
+        let t2 = ThreadBuilder::new()
+            .affinity(self.cpu_core_participant_2)
+            .priority(255)
+            .spawn(|| {
+                let sender_b2a = service_b2a
+                    .publisher_builder()
+                    .initial_max_slice_len(payload_size)
+                    .create()
+                    .unwrap();
+                let receiver_a2b = service_a2b.subscriber_builder().create().unwrap();
+
+                startup_barrier.wait();
+                start_benchmark_barrier.wait();
+
+                for _ in 0..self.iterations {
+                    let sample = if self.send_copy {
+                        let mut sample = sender_b2a.loan_slice_uninit(payload_size).unwrap();
+                        sample.payload_mut().fill(MaybeUninit::new(0));
+                        unsafe { sample.assume_init() }
+                    } else {
+                        unsafe {
+                            sender_b2a
+                                .loan_slice_uninit(payload_size)
+                                .unwrap()
+                                .assume_init()
+                        }
+                    };
+
+                    while receiver_a2b.receive().unwrap().is_none() {}
+
+                    sample.send().unwrap();
+                }
+            })
+            .expect("failed to spawn thread");
+
+        startup_barrier.wait();
+        let cpu_usage = CpuUsage::now();
+        let start = Time::now().expect("failed to acquire time");
+        start_benchmark_barrier.wait();
+
+        drop(t1);
+        drop(t2);
+
+        let runtime = start.elapsed().expect("failed to measure time");
+
+        Ok(BenchmarkResult {
+            benchmark: BENCHMARK_NAME.to_string(),
+            service_type: core::any::type_name::<S>().to_string(),
+            payload_size,
+            iterations: self.iterations,
+            runtime_ns: runtime.as_nanos(),
+            latency: latency_recorder.statistics(),
+            throughput: Throughput::new(self.iterations * 2, payload_size, runtime),
+            cpu_usage_percent: cpu_usage.percent_since(runtime),
+        })
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::statistics::LatencyStatistics;
+
+/// The throughput that was achieved in a benchmark.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Throughput {
+    /// One-way transmissions per second.
+    pub messages_per_second: f64,
+    /// Transmitted payload bytes per second.
+    pub bytes_per_second: f64,
+}
+
+impl Throughput {
+    pub(crate) fn new(number_of_messages: u64, payload_size: usize, runtime: Duration) -> Self {
+        let seconds = runtime.as_secs_f64();
+        if seconds == 0.0 {
+            return Self {
+                messages_per_second: 0.0,
+                bytes_per_second: 0.0,
+            };
+        }
+
+        let messages_per_second = number_of_messages as f64 / seconds;
+        Self {
+            messages_per_second,
+            bytes_per_second: messages_per_second * payload_size as f64,
+        }
+    }
+}
+
+/// The result of a single benchmark run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkResult {
+    /// The name of the benchmark, e.g. `publish-subscribe`.
+    pub benchmark: String,
+    /// The [`Service`](iceoryx2::service::Service) type that was used.
+    pub service_type: String,
+    /// The payload size in bytes, `0` when the benchmark does not transfer a payload.
+    pub payload_size: usize,
+    /// The number of round-trips.
+    pub iterations: u64,
+    /// The runtime of the whole benchmark in nanoseconds.
+    pub runtime_ns: u128,
+    /// The one-way latency statistics.
+    pub latency: Option<LatencyStatistics>,
+    /// The achieved throughput.
+    pub throughput: Throughput,
+    /// The CPU usage of the process in percent of a single core. Is [`None`] when the
+    /// platform does not support the measurement.
+    pub cpu_usage_percent: Option<f64>,
+}
+
+/// A collection of [`BenchmarkResult`]s that can be serialized to JSON.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkReport {
+    results: Vec<BenchmarkResult>,
+}
+
+impl BenchmarkReport {
+    /// Creates an empty [`BenchmarkReport`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a single [`BenchmarkResult`] to the report.
+    pub fn add_result(&mut self, result: BenchmarkResult) {
+        self.results.push(result);
+    }
+
+    /// Adds multiple [`BenchmarkResult`]s to the report.
+    pub fn add_results<I: IntoIterator<Item = BenchmarkResult>>(&mut self, results: I) {
+        self.results.extend(results);
+    }
+
+    /// Returns all contained [`BenchmarkResult`]s.
+    pub fn results(&self) -> &[BenchmarkResult] {
+        &self.results
+    }
+
+    /// Serializes the report into a human readable JSON string.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Deserializes a report from a JSON string, for instance to compare it with
+    /// a baseline.
+    pub fn from_json(value: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(value)
+    }
+}
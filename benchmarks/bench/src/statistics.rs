@@ -0,0 +1,103 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use serde::{Deserialize, Serialize};
+
+/// The maximum number of latency samples that are recorded by a benchmark. When a benchmark
+/// runs more iterations, only every n-th iteration is recorded.
+pub(crate) const MAX_LATENCY_SAMPLES: usize = 1_000_000;
+
+/// Latency statistics of a benchmark. All values are one-way latencies in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LatencyStatistics {
+    /// The number of latency samples the statistics are based on.
+    pub samples: usize,
+    /// The smallest measured latency.
+    pub min_ns: u64,
+    /// The average latency.
+    pub mean_ns: f64,
+    /// The median latency.
+    pub p50_ns: u64,
+    /// The 90th percentile.
+    pub p90_ns: u64,
+    /// The 99th percentile.
+    pub p99_ns: u64,
+    /// The 99.9th percentile.
+    pub p99_9_ns: u64,
+    /// The largest measured latency.
+    pub max_ns: u64,
+}
+
+impl LatencyStatistics {
+    /// Computes the statistics from the provided latencies in nanoseconds. Returns [`None`]
+    /// when no latencies are provided.
+    pub fn from_latencies(latencies: &mut [u64]) -> Option<Self> {
+        if latencies.is_empty() {
+            return None;
+        }
+
+        latencies.sort_unstable();
+        let sum: u128 = latencies.iter().map(|v| *v as u128).sum();
+
+        Some(Self {
+            samples: latencies.len(),
+            min_ns: latencies[0],
+            mean_ns: sum as f64 / latencies.len() as f64,
+            p50_ns: percentile(latencies, 500),
+            p90_ns: percentile(latencies, 900),
+            p99_ns: percentile(latencies, 990),
+            p99_9_ns: percentile(latencies, 999),
+            max_ns: latencies[latencies.len() - 1],
+        })
+    }
+}
+
+// nearest-rank percentile of an ascending sorted slice, the percentile is provided in
+// per mille to avoid rounding issues
+fn percentile(sorted: &[u64], per_mille: usize) -> u64 {
+    let rank = (per_mille * sorted.len()).div_ceil(1000);
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Records the round-trip times of a benchmark. Records at most [`MAX_LATENCY_SAMPLES`]
+/// by recording only every n-th iteration.
+pub(crate) struct LatencyRecorder {
+    round_trips: Vec<u64>,
+    stride: u64,
+}
+
+impl LatencyRecorder {
+    pub(crate) fn new(iterations: u64) -> Self {
+        let stride = iterations.div_ceil(MAX_LATENCY_SAMPLES as u64).max(1);
+        Self {
+            round_trips: Vec::with_capacity((iterations / stride) as usize + 1),
+            stride,
+        }
+    }
+
+    pub(crate) fn is_recorded(&self, iteration: u64) -> bool {
+        iteration % self.stride == 0
+    }
+
+    pub(crate) fn record(&mut self, round_trip_ns: u64) {
+        self.round_trips.push(round_trip_ns);
+    }
+
+    pub(crate) fn statistics(mut self) -> Option<LatencyStatistics> {
+        // a round-trip consists of two one-way transmissions
+        for value in self.round_trips.iter_mut() {
+            *value /= 2;
+        }
+
+        LatencyStatistics::from_latencies(&mut self.round_trips)
+    }
+}
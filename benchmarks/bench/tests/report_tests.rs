@@ -0,0 +1,90 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod report {
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bench::{BenchmarkReport, BenchmarkResult, LatencyStatistics, Throughput};
+
+    fn generate_result(payload_size: usize) -> BenchmarkResult {
+        let mut latencies: Vec<u64> = (1..=1000).rev().collect();
+        BenchmarkResult {
+            benchmark: "publish-subscribe".to_string(),
+            service_type: "ipc".to_string(),
+            payload_size,
+            iterations: 1000,
+            runtime_ns: 2000000,
+            latency: LatencyStatistics::from_latencies(&mut latencies),
+            throughput: Throughput {
+                messages_per_second: 1000000.0,
+                bytes_per_second: 1000000.0 * payload_size as f64,
+            },
+            cpu_usage_percent: Some(100.0),
+        }
+    }
+
+    #[test]
+    fn latency_statistics_of_empty_latencies_is_none() {
+        assert_that!(LatencyStatistics::from_latencies(&mut []), is_none);
+    }
+
+    #[test]
+    fn latency_statistics_are_calculated_correctly() {
+        let mut latencies: Vec<u64> = (1..=1000).rev().collect();
+        let sut = LatencyStatistics::from_latencies(&mut latencies).unwrap();
+
+        assert_that!(sut.samples, eq 1000);
+        assert_that!(sut.min_ns, eq 1);
+        assert_that!(sut.max_ns, eq 1000);
+        assert_that!(sut.mean_ns, eq 500.5);
+        assert_that!(sut.p50_ns, eq 500);
+        assert_that!(sut.p90_ns, eq 900);
+        assert_that!(sut.p99_ns, eq 990);
+        assert_that!(sut.p99_9_ns, eq 999);
+    }
+
+    #[test]
+    fn latency_statistics_of_single_value_works() {
+        let sut = LatencyStatistics::from_latencies(&mut [42]).unwrap();
+
+        assert_that!(sut.samples, eq 1);
+        assert_that!(sut.min_ns, eq 42);
+        assert_that!(sut.p50_ns, eq 42);
+        assert_that!(sut.p99_9_ns, eq 42);
+        assert_that!(sut.max_ns, eq 42);
+    }
+
+    #[test]
+    fn report_contains_added_results() {
+        let mut sut = BenchmarkReport::new();
+        assert_that!(sut.results(), is_empty);
+
+        sut.add_result(generate_result(8));
+        sut.add_results(vec![generate_result(64), generate_result(1024)]);
+
+        assert_that!(sut.results(), len 3);
+        assert_that!(sut.results()[0].payload_size, eq 8);
+        assert_that!(sut.results()[2].payload_size, eq 1024);
+    }
+
+    #[test]
+    fn report_can_be_serialized_to_and_from_json() {
+        let mut sut = BenchmarkReport::new();
+        sut.add_results(vec![generate_result(8), generate_result(4096)]);
+
+        let json = sut.to_json().unwrap();
+        assert_that!(json.contains("\"p99_ns\": 990"), eq true);
+        assert_that!(json.contains("\"payload_size\": 4096"), eq true);
+
+        let deserialized = BenchmarkReport::from_json(&json).unwrap();
+        assert_that!(deserialized, eq sut);
+    }
+}
@@ -10,155 +10,41 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use core::mem::MaybeUninit;
-
 use clap::Parser;
 use iceoryx2::prelude::*;
 use iceoryx2_bb_log::set_log_level;
-use iceoryx2_bb_posix::barrier::*;
-use iceoryx2_bb_posix::clock::Time;
-use iceoryx2_bb_posix::thread::ThreadBuilder;
+use iceoryx2_bench::publish_subscribe::PublishSubscribeBenchmark;
+use iceoryx2_bench::{BenchmarkReport, BenchmarkResult};
 
 const ITERATIONS: u64 = 10000000;
 
-fn perform_benchmark<T: Service>(args: &Args) -> Result<(), Box<dyn core::error::Error>> {
-    let service_name_a2b = ServiceName::new("a2b")?;
-    let service_name_b2a = ServiceName::new("b2a")?;
-    let node = NodeBuilder::new().create::<T>()?;
-
-    let service_a2b = node
-        .service_builder(&service_name_a2b)
-        .publish_subscribe::<[u8]>()
-        .max_publishers(1 + args.number_of_additional_publishers)
-        .max_subscribers(1 + args.number_of_additional_subscribers)
-        .history_size(0)
-        .subscriber_max_buffer_size(1)
-        .enable_safe_overflow(true)
-        .create()?;
-
-    let service_b2a = node
-        .service_builder(&service_name_b2a)
-        .publish_subscribe::<[u8]>()
-        .max_publishers(1 + args.number_of_additional_publishers)
-        .max_subscribers(1 + args.number_of_additional_subscribers)
-        .history_size(0)
-        .subscriber_max_buffer_size(1)
-        .enable_safe_overflow(true)
-        .create()?;
-
-    let mut additional_publishers = Vec::new();
-    let mut additional_subscribers = Vec::new();
-
-    for _ in 0..args.number_of_additional_publishers {
-        additional_publishers.push(service_a2b.publisher_builder().create()?);
-        additional_publishers.push(service_b2a.publisher_builder().create()?);
+fn perform_benchmark<T: Service>(
+    args: &Args,
+) -> Result<Vec<BenchmarkResult>, Box<dyn core::error::Error>> {
+    let results = PublishSubscribeBenchmark::new()
+        .iterations(args.iterations)
+        .payload_sizes(&args.payload_size)
+        .cpu_core_participant_1(args.cpu_core_participant_1)
+        .cpu_core_participant_2(args.cpu_core_participant_2)
+        .send_copy(args.send_copy)
+        .number_of_additional_publishers(args.number_of_additional_publishers)
+        .number_of_additional_subscribers(args.number_of_additional_subscribers)
+        .run::<T>()?;
+
+    if !args.json {
+        for result in &results {
+            println!(
+                "{} ::: Iterations: {}, Time: {} s, Latency: {} ns, Sample Size: {}",
+                result.service_type,
+                result.iterations,
+                result.runtime_ns as f64 / 1_000_000_000.0,
+                result.runtime_ns / (result.iterations as u128 * 2),
+                result.payload_size
+            );
+        }
     }
 
-    for _ in 0..args.number_of_additional_subscribers {
-        additional_subscribers.push(service_a2b.subscriber_builder().create()?);
-        additional_subscribers.push(service_b2a.subscriber_builder().create()?);
-    }
-
-    let start_benchmark_barrier_handle = BarrierHandle::new();
-    let startup_barrier_handle = BarrierHandle::new();
-    let startup_barrier = BarrierBuilder::new(3)
-        .create(&startup_barrier_handle)
-        .unwrap();
-    let start_benchmark_barrier = BarrierBuilder::new(3)
-        .create(&start_benchmark_barrier_handle)
-        .unwrap();
-
-    let t1 = ThreadBuilder::new()
-        .affinity(args.cpu_core_participant_1)
-        .priority(255)
-        .spawn(|| {
-            let sender_a2b = service_a2b
-                .publisher_builder()
-                .initial_max_slice_len(args.payload_size)
-                .create()
-                .unwrap();
-            let receiver_b2a = service_b2a.subscriber_builder().create().unwrap();
-
-            startup_barrier.wait();
-            start_benchmark_barrier.wait();
-
-            let mut sample = if args.send_copy {
-                let mut sample = sender_a2b.loan_slice_uninit(args.payload_size).unwrap();
-                sample.payload_mut().fill(MaybeUninit::new(0));
-                unsafe { sample.assume_init() }
-            } else {
-                unsafe {
-                    sender_a2b
-                        .loan_slice_uninit(args.payload_size)
-                        .unwrap()
-                        .assume_init()
-                }
-            };
-
-            for _ in 0..args.iterations {
-                sample.send().unwrap();
-                sample = unsafe {
-                    sender_a2b
-                        .loan_slice_uninit(args.payload_size)
-                        .unwrap()
-                        .assume_init()
-                };
-                while receiver_b2a.receive().unwrap().is_none() {}
-            }
-        });
-
-    let t2 = ThreadBuilder::new()
-        .affinity(args.cpu_core_participant_2)
-        .priority(255)
-        .spawn(|| {
-            let sender_b2a = service_b2a
-                .publisher_builder()
-                .initial_max_slice_len(args.payload_size)
-                .create()
-                .unwrap();
-            let receiver_a2b = service_a2b.subscriber_builder().create().unwrap();
-
-            startup_barrier.wait();
-            start_benchmark_barrier.wait();
-
-            for _ in 0..args.iterations {
-                let sample = if args.send_copy {
-                    let mut sample = sender_b2a.loan_slice_uninit(args.payload_size).unwrap();
-                    sample.payload_mut().fill(MaybeUninit::new(0));
-                    unsafe { sample.assume_init() }
-                } else {
-                    unsafe {
-                        sender_b2a
-                            .loan_slice_uninit(args.payload_size)
-                            .unwrap()
-                            .assume_init()
-                    }
-                };
-
-                while receiver_a2b.receive().unwrap().is_none() {}
-
-                sample.send().unwrap();
-            }
-        });
-
-    startup_barrier.wait();
-    let start = Time::now().expect("failed to acquire time");
-    start_benchmark_barrier.wait();
-
-    drop(t1);
-    drop(t2);
-
-    let stop = start.elapsed().expect("failed to measure time");
-    println!(
-        "{} ::: Iterations: {}, Time: {} s, Latency: {} ns, Sample Size: {}",
-        core::any::type_name::<T>(),
-        args.iterations,
-        stop.as_secs_f64(),
-        stop.as_nanos() / (args.iterations as u128 * 2),
-        args.payload_size
-    );
-
-    Ok(())
+    Ok(results)
 }
 
 #[derive(Parser, Debug)]
@@ -185,9 +71,10 @@ struct Args {
     /// The cpu core that shall be used by participant 2
     #[clap(long, default_value_t = 1)]
     cpu_core_participant_2: usize,
-    /// The size in bytes of the payload that shall be used
-    #[clap(short, long, default_value_t = 8192)]
-    payload_size: usize,
+    /// The size in bytes of the payload that shall be used. Multiple comma separated sizes
+    /// run the benchmark once for every size.
+    #[clap(short, long, value_delimiter = ',', default_value = "8192")]
+    payload_size: Vec<usize>,
     /// Send a copy of the payload instead of performing true zero-copy. Can provide an hint on
     /// how expensive serialization can be.
     #[clap(long)]
@@ -198,6 +85,9 @@ struct Args {
     /// The number of additional subscribers per service in the setup.
     #[clap(long, default_value_t = 0)]
     number_of_additional_subscribers: usize,
+    /// Print the results with latency percentiles, throughput and CPU usage as JSON
+    #[clap(long)]
+    json: bool,
 }
 
 fn main() -> Result<(), Box<dyn core::error::Error>> {
@@ -210,14 +100,15 @@ fn main() -> Result<(), Box<dyn core::error::Error>> {
     }
 
     let mut at_least_one_benchmark_did_run = false;
+    let mut report = BenchmarkReport::new();
 
     if args.bench_ipc || args.bench_all {
-        perform_benchmark::<ipc::Service>(&args)?;
+        report.add_results(perform_benchmark::<ipc::Service>(&args)?);
         at_least_one_benchmark_did_run = true;
     }
 
     if args.bench_local || args.bench_all {
-        perform_benchmark::<local::Service>(&args)?;
+        report.add_results(perform_benchmark::<local::Service>(&args)?);
         at_least_one_benchmark_did_run = true;
     }
 
@@ -225,6 +116,8 @@ fn main() -> Result<(), Box<dyn core::error::Error>> {
         println!(
             "Please use either '--bench-all' or select a specific benchmark. See `--help` for details."
         );
+    } else if args.json {
+        println!("{}", report.to_json()?);
     }
 
     Ok(())
@@ -29,7 +29,7 @@ int main(int argc, char** argv) {
     iox2_config_ptr config_ptr = iox2_config_global_config();
     iox2_config_h config = NULL;
     iox2_config_from_ptr(config_ptr, NULL, &config);
-    config_ptr = iox2_cast_config_ptr(config);
+    config_ptr = iox2_cast_config_ptr(&config);
 
     // The domain name becomes the prefix for all resources.
     // Therefore, different domain names never share the same resources.
@@ -60,7 +60,7 @@ int main(int argc, char** argv) {
     }
 
     // create service builder
-    iox2_service_name_ptr service_name_ptr = iox2_cast_service_name_ptr(service_name);
+    iox2_service_name_ptr service_name_ptr = iox2_cast_service_name_ptr(&service_name);
     iox2_service_builder_h service_builder = iox2_node_service_builder(&node_handle, NULL, service_name_ptr);
     iox2_service_builder_pub_sub_h service_builder_pub_sub = iox2_service_builder_pub_sub(service_builder);
 
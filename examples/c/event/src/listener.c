@@ -34,7 +34,7 @@ int main(void) {
     }
 
     // create service
-    iox2_service_name_ptr service_name_ptr = iox2_cast_service_name_ptr(service_name);
+    iox2_service_name_ptr service_name_ptr = iox2_cast_service_name_ptr(&service_name);
     iox2_service_builder_h service_builder = iox2_node_service_builder(&node_handle, NULL, service_name_ptr);
     iox2_service_builder_event_h service_builder_event = iox2_service_builder_event(service_builder);
     iox2_port_factory_event_h service = NULL;
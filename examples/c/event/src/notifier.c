@@ -16,13 +16,6 @@
 #include <stdio.h>
 #include <string.h>
 
-#ifdef _WIN64
-#include <windows.h>
-#define sleep Sleep
-#else
-#include <unistd.h>
-#endif
-
 int main(void) {
     // create new node
     iox2_node_builder_h node_builder_handle = iox2_node_builder_new(NULL);
@@ -41,7 +34,7 @@ int main(void) {
     }
 
     // create service
-    iox2_service_name_ptr service_name_ptr = iox2_cast_service_name_ptr(service_name);
+    iox2_service_name_ptr service_name_ptr = iox2_cast_service_name_ptr(&service_name);
     iox2_service_builder_h service_builder = iox2_node_service_builder(&node_handle, NULL, service_name_ptr);
     iox2_service_builder_event_h service_builder_event = iox2_service_builder_event(service_builder);
     iox2_port_factory_event_h service = NULL;
@@ -59,7 +52,7 @@ int main(void) {
     }
 
     uint64_t counter = 0;
-    while (iox2_node_wait(&node_handle, 0, 0) == IOX2_OK) {
+    while (iox2_node_wait(&node_handle, 1, 0) == IOX2_OK) {
         counter += 1;
         iox2_event_id_t event_id = { .value = counter % 12 }; // NOLINT
         if (iox2_notifier_notify_with_custom_event_id(&notifier, &event_id, NULL) != IOX2_OK) {
@@ -68,8 +61,6 @@ int main(void) {
         }
 
         printf("Trigger event with id %lu ...\n", (long unsigned) event_id.value);
-
-        sleep(1);
     }
 
 drop_notifier:
@@ -18,13 +18,6 @@
 
 const int BASE_10 = 10;
 
-#ifdef _WIN64
-#include <windows.h>
-#define sleep Sleep
-#else
-#include <unistd.h>
-#endif
-
 int main(int argc, char** argv) {
     if (argc != 3) {
         printf("Usage: %s EVENT_ID SERVICE_NAME\n", argv[0]);
@@ -50,7 +43,7 @@ int main(int argc, char** argv) {
     }
 
     // create service
-    iox2_service_name_ptr service_name_ptr = iox2_cast_service_name_ptr(service_name);
+    iox2_service_name_ptr service_name_ptr = iox2_cast_service_name_ptr(&service_name);
     iox2_service_builder_h service_builder = iox2_node_service_builder(&node_handle, NULL, service_name_ptr);
     iox2_service_builder_event_h service_builder_event = iox2_service_builder_event(service_builder);
     iox2_port_factory_event_h service = NULL;
@@ -68,7 +61,7 @@ int main(int argc, char** argv) {
     }
 
     // notifier with a period of 1 second
-    while (iox2_node_wait(&node_handle, 0, 0) == IOX2_OK) {
+    while (iox2_node_wait(&node_handle, 1, 0) == IOX2_OK) {
         iox2_event_id_t event_id = { .value = event_id_value }; // NOLINT
         if (iox2_notifier_notify_with_custom_event_id(&notifier, &event_id, NULL) != IOX2_OK) {
             printf("Failed to notify listener!\n");
@@ -76,8 +69,6 @@ int main(int argc, char** argv) {
         }
 
         printf("[service: \"%s\"] Trigger event with id %lu ...\n", argv[2], (long unsigned) event_id.value);
-
-        sleep(1);
     }
 
 drop_notifier:
@@ -97,7 +97,7 @@ int main(int argc, char** argv) {
     }
 
     // create services
-    iox2_service_name_ptr service_name_ptr_1 = iox2_cast_service_name_ptr(service_name_1);
+    iox2_service_name_ptr service_name_ptr_1 = iox2_cast_service_name_ptr(&service_name_1);
     iox2_service_builder_h service_builder_1 = iox2_node_service_builder(&node_handle, NULL, service_name_ptr_1);
     iox2_service_builder_event_h service_builder_event_1 = iox2_service_builder_event(service_builder_1);
     iox2_port_factory_event_h service_1 = NULL;
@@ -106,7 +106,7 @@ int main(int argc, char** argv) {
         goto drop_service_name_2;
     }
 
-    iox2_service_name_ptr service_name_ptr_2 = iox2_cast_service_name_ptr(service_name_2);
+    iox2_service_name_ptr service_name_ptr_2 = iox2_cast_service_name_ptr(&service_name_2);
     iox2_service_builder_h service_builder_2 = iox2_node_service_builder(&node_handle, NULL, service_name_ptr_2);
     iox2_service_builder_event_h service_builder_event_2 = iox2_service_builder_event(service_builder_2);
     iox2_port_factory_event_h service_2 = NULL;
@@ -138,7 +138,7 @@ int main(int argc, char** argv) {
     iox2_waitset_h waitset = NULL;
     if (iox2_waitset_builder_create(waitset_builder, iox2_service_type_e_IPC, NULL, &waitset) != IOX2_OK) {
         printf("Unable to create waitset\n");
-        goto drop_waitset_builder;
+        goto drop_listener_2;
     }
 
     // attach listeners to waitset
@@ -181,10 +181,7 @@ drop_guard_1:
 drop_waitset:
     iox2_waitset_drop(waitset);
 
-drop_waitset_builder:
-    iox2_waitset_builder_drop(waitset_builder);
-
-    //[unused-label] drop_listener_2:
+drop_listener_2:
     iox2_listener_drop(listener_2);
 
 drop_listener_1:
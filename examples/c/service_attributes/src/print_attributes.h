@@ -0,0 +1,36 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#ifndef IOX2_EXAMPLES_PRINT_ATTRIBUTES_H
+#define IOX2_EXAMPLES_PRINT_ATTRIBUTES_H
+
+#include "iox2/iceoryx2.h"
+
+#include <stdio.h>
+
+#define ATTRIBUTE_BUFFER_SIZE 256
+
+static void print_attributes(iox2_attribute_set_h_ref attributes) {
+    char key[ATTRIBUTE_BUFFER_SIZE];
+    char value[ATTRIBUTE_BUFFER_SIZE];
+
+    printf("defined service attributes:\n");
+    size_t number_of_attributes = iox2_attribute_set_len(attributes);
+    for (size_t i = 0; i < number_of_attributes; ++i) {
+        iox2_attribute_h_ref attribute = iox2_attribute_set_at(attributes, i);
+        iox2_attribute_key(attribute, key, ATTRIBUTE_BUFFER_SIZE);
+        iox2_attribute_value(attribute, value, ATTRIBUTE_BUFFER_SIZE);
+        printf("  %s = %s\n", key, value);
+    }
+}
+
+#endif
@@ -0,0 +1,193 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`FixedSizeBitSet`] is a compile-time fixed-size bitset that is self-contained and
+//! shared-memory compatible. It contains no pointers, therefore it can be placed in shared
+//! memory, moved and used as part of a payload type.
+//!
+//! It is not threadsafe. When multiple threads or processes shall modify the bits
+//! concurrently, the lock-free `FixedSizeBitSet` of `iceoryx2_bb_lock_free::mpmc::bit_set`
+//! shall be used instead.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_container::bit_set::*;
+//!
+//! let mut bitset = FixedSizeBitSet::<64>::new();
+//!
+//! assert!(bitset.set(3));
+//! assert!(bitset.set(5));
+//! assert_eq!(bitset.find_first_unset(), Some(0));
+//!
+//! for id in bitset.iter() {
+//!     println!("bit {} is set", id);
+//! }
+//! ```
+
+use core::fmt::Debug;
+
+use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+use iceoryx2_bb_log::fatal_panic;
+
+const BITS_PER_ELEMENT: usize = u8::BITS as usize;
+
+/// A compile-time fixed-size bitset with `CAPACITY` bits.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct FixedSizeBitSet<const CAPACITY: usize> {
+    // only the first CAPACITY.div_ceil(8) bytes are used, since the array size cannot be
+    // computed from a generic parameter
+    data: [u8; CAPACITY],
+}
+
+impl<const CAPACITY: usize> PlacementDefault for FixedSizeBitSet<CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        core::ptr::addr_of_mut!((*ptr).data).write_bytes(0, 1);
+    }
+}
+
+impl<const CAPACITY: usize> Default for FixedSizeBitSet<CAPACITY> {
+    fn default() -> Self {
+        Self {
+            data: [0; CAPACITY],
+        }
+    }
+}
+
+impl<const CAPACITY: usize> Debug for FixedSizeBitSet<CAPACITY> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<const CAPACITY: usize> FixedSizeBitSet<CAPACITY> {
+    const NUMBER_OF_ELEMENTS: usize = CAPACITY.div_ceil(BITS_PER_ELEMENT);
+
+    /// Creates a new [`FixedSizeBitSet`] where no bit is set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of bits.
+    pub const fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Returns the number of set bits.
+    pub fn len(&self) -> usize {
+        self.data[..Self::NUMBER_OF_ELEMENTS]
+            .iter()
+            .map(|v| v.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns true when no bit is set, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.data[..Self::NUMBER_OF_ELEMENTS]
+            .iter()
+            .all(|v| *v == 0)
+    }
+
+    /// Returns true when all bits are set, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.len() == CAPACITY
+    }
+
+    fn verify_id(&self, id: usize, source: &str) {
+        if CAPACITY <= id {
+            fatal_panic!(from "FixedSizeBitSet", "{}: The bit {} is out of bounds since the capacity is {}.",
+                source, id, CAPACITY);
+        }
+    }
+
+    /// Sets the bit. If the bit was not set before it returns true, otherwise false.
+    pub fn set(&mut self, id: usize) -> bool {
+        self.verify_id(id, "set()");
+        let mask = 1 << (id % BITS_PER_ELEMENT);
+        let element = &mut self.data[id / BITS_PER_ELEMENT];
+        let was_set = *element & mask != 0;
+        *element |= mask;
+        !was_set
+    }
+
+    /// Resets the bit. If the bit was set before it returns true, otherwise false.
+    pub fn reset(&mut self, id: usize) -> bool {
+        self.verify_id(id, "reset()");
+        let mask = 1 << (id % BITS_PER_ELEMENT);
+        let element = &mut self.data[id / BITS_PER_ELEMENT];
+        let was_set = *element & mask != 0;
+        *element &= !mask;
+        was_set
+    }
+
+    /// Returns true if the bit is set, otherwise false.
+    pub fn is_set(&self, id: usize) -> bool {
+        self.verify_id(id, "is_set()");
+        self.data[id / BITS_PER_ELEMENT] & (1 << (id % BITS_PER_ELEMENT)) != 0
+    }
+
+    /// Resets all bits.
+    pub fn clear(&mut self) {
+        self.data[..Self::NUMBER_OF_ELEMENTS].fill(0);
+    }
+
+    /// Returns the index of the first bit that is not set. If all bits are set it returns
+    /// [`None`].
+    pub fn find_first_unset(&self) -> Option<usize> {
+        for (i, element) in self.data[..Self::NUMBER_OF_ELEMENTS].iter().enumerate() {
+            if *element != u8::MAX {
+                let id = i * BITS_PER_ELEMENT + element.trailing_ones() as usize;
+                return (id < CAPACITY).then_some(id);
+            }
+        }
+
+        None
+    }
+
+    /// Returns an iterator over the indices of all set bits in ascending order.
+    pub fn iter(&self) -> Iter<'_, CAPACITY> {
+        Iter {
+            bitset: self,
+            position: 0,
+        }
+    }
+}
+
+/// Iterator over the indices of all set bits of a [`FixedSizeBitSet`], see
+/// [`FixedSizeBitSet::iter()`].
+pub struct Iter<'a, const CAPACITY: usize> {
+    bitset: &'a FixedSizeBitSet<CAPACITY>,
+    position: usize,
+}
+
+impl<const CAPACITY: usize> Iterator for Iter<'_, CAPACITY> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.position < CAPACITY {
+            let index = self.position / BITS_PER_ELEMENT;
+            // skips the remaining bits of the current element when none of them is set
+            let remaining = self.bitset.data[index] >> (self.position % BITS_PER_ELEMENT);
+            if remaining == 0 {
+                self.position = (index + 1) * BITS_PER_ELEMENT;
+                continue;
+            }
+
+            let id = self.position + remaining.trailing_zeros() as usize;
+            self.position = id + 1;
+            return (id < CAPACITY).then_some(id);
+        }
+
+        None
+    }
+}
@@ -13,6 +13,9 @@
 //! Relocatable (inter-process shared memory compatible) string implementations.
 //!
 //! The [`FixedSizeByteString`](crate::byte_string::FixedSizeByteString) has a fixed capacity defined at compile time.
+//! The [`RelocatableByteString`](crate::byte_string::RelocatableByteString) has a capacity
+//! defined at run time and acquires its memory from an allocator, e.g. from the payload of a
+//! loaned sample.
 //!
 //! # Example
 //!
@@ -28,6 +31,40 @@
 //!
 //! println!("removed byte {}", some_string.remove(0));
 //! ```
+//!
+//! ## Compose a [`FixedSizeByteString`](crate::byte_string::FixedSizeByteString) without heap allocation
+//!
+//! ```
+//! use iceoryx2_bb_container::byte_string::*;
+//! use core::fmt::Write;
+//!
+//! let mut some_string =
+//!     FixedSizeByteString::<64>::from_fmt(format_args!("{}/{}", "some", 42)).unwrap();
+//! write!(some_string, "/{:x}", 255).unwrap();
+//! assert!(some_string == b"some/42/ff");
+//!
+//! let mut parts = some_string.split_bytes(b"/");
+//! assert_eq!(parts.next(), Some(&b"some"[..]));
+//! assert_eq!(parts.next_back(), Some(&b"ff"[..]));
+//! ```
+//!
+//! ## Create [`RelocatableByteString`](crate::byte_string::RelocatableByteString) with allocator
+//!
+//! ```
+//! use iceoryx2_bb_container::byte_string::*;
+//! use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+//! use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+//!
+//! const STRING_CAPACITY: usize = 12;
+//! const MEM_SIZE: usize = RelocatableByteString::const_memory_size(STRING_CAPACITY);
+//! let mut memory = [0u8; MEM_SIZE];
+//!
+//! let bump_allocator = BumpAllocator::new(memory.as_mut_ptr() as usize);
+//!
+//! let mut some_string = unsafe { RelocatableByteString::new_uninit(STRING_CAPACITY) };
+//! unsafe { some_string.init(&bump_allocator).expect("string init failed") };
+//! unsafe { some_string.push_bytes(b"hello").unwrap() };
+//! ```
 
 use core::{
     cmp::Ordering,
@@ -38,10 +75,15 @@ use core::{
 };
 
 use iceoryx2_bb_derive_macros::PlacementDefault;
+use iceoryx2_bb_elementary::allocator::{AllocationError, BaseAllocator};
 use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_log::{fail, fatal_panic};
+#[cfg(feature = "serde")]
 use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
 
+use crate::vec::RelocatableVec;
+
 /// Returns the length of a string
 ///
 /// # Safety
@@ -69,21 +111,23 @@ pub enum FixedSizeByteStringModificationError {
 
 impl core::fmt::Display for FixedSizeByteStringModificationError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        std::write!(f, "FixedSizeByteStringModificationError::{:?}", self)
+        core::write!(f, "FixedSizeByteStringModificationError::{:?}", self)
     }
 }
 
 impl core::error::Error for FixedSizeByteStringModificationError {}
 
-/// Relocatable string with compile time fixed size capacity.
+/// Relocatable string with compile time fixed size capacity. The length is stored with 64-bit
+/// so that the string has the same layout in 32-bit and 64-bit processes.
 #[derive(Clone, Copy, PlacementDefault)]
-#[repr(C)]
+#[repr(C, align(8))]
 pub struct FixedSizeByteString<const CAPACITY: usize> {
-    len: usize,
+    len: u64,
     data: [MaybeUninit<u8>; CAPACITY],
     terminator: u8,
 }
 
+#[cfg(feature = "serde")]
 impl<const CAPACITY: usize> Serialize for FixedSizeByteString<CAPACITY> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -93,8 +137,10 @@ impl<const CAPACITY: usize> Serialize for FixedSizeByteString<CAPACITY> {
     }
 }
 
+#[cfg(feature = "serde")]
 struct FixedSizeByteStringVisitor<const CAPACITY: usize>;
 
+#[cfg(feature = "serde")]
 impl<const CAPACITY: usize> Visitor<'_> for FixedSizeByteStringVisitor<CAPACITY> {
     type Value = FixedSizeByteString<CAPACITY>;
 
@@ -116,6 +162,7 @@ impl<const CAPACITY: usize> Visitor<'_> for FixedSizeByteStringVisitor<CAPACITY>
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de, const CAPACITY: usize> Deserialize<'de> for FixedSizeByteString<CAPACITY> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -131,9 +178,9 @@ impl<const CAPACITY: usize, const CAPACITY_OTHER: usize>
     PartialOrd<FixedSizeByteString<CAPACITY_OTHER>> for FixedSizeByteString<CAPACITY>
 {
     fn partial_cmp(&self, other: &FixedSizeByteString<CAPACITY_OTHER>) -> Option<Ordering> {
-        self.data[..self.len]
+        self.data[..self.len()]
             .iter()
-            .zip(other.data[..other.len].iter())
+            .zip(other.data[..other.len()].iter())
             .map(|(lhs, rhs)| unsafe { lhs.assume_init_read().cmp(rhs.assume_init_ref()) })
             .find(|&ord| ord != Ordering::Equal)
             .or(Some(self.len.cmp(&other.len)))
@@ -204,15 +251,15 @@ impl<const CAPACITY: usize> Debug for FixedSizeByteString<CAPACITY> {
             f,
             "FixedSizeByteString<{}> {{ len: {}, data: \"{}\" }}",
             CAPACITY,
-            self.len,
-            as_escaped_string(self.as_bytes())
+            self.len(),
+            EscapedBytes(self.as_bytes())
         )
     }
 }
 
 impl<const CAPACITY: usize> Display for FixedSizeByteString<CAPACITY> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}", as_escaped_string(self.as_bytes()))
+        write!(f, "{}", EscapedBytes(self.as_bytes()))
     }
 }
 
@@ -236,30 +283,96 @@ impl<const CAPACITY: usize> Default for FixedSizeByteString<CAPACITY> {
     }
 }
 
+impl<const CAPACITY: usize> core::fmt::Write for FixedSizeByteString<CAPACITY> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.push_bytes(s.as_bytes()).map_err(|_| core::fmt::Error)
+    }
+}
+
+/// Iterator over the parts of a byte string that are separated by a byte sequence. It is
+/// created by [`FixedSizeByteString::split_bytes()`] and can be iterated from both ends.
+#[derive(Debug, Clone)]
+pub struct ByteStringSplit<'a> {
+    remainder: Option<&'a [u8]>,
+    separator: &'a [u8],
+}
+
+impl<'a> Iterator for ByteStringSplit<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remainder = self.remainder?;
+        if self.separator.is_empty() {
+            self.remainder = None;
+            return Some(remainder);
+        }
+
+        match remainder
+            .windows(self.separator.len())
+            .position(|w| w == self.separator)
+        {
+            Some(pos) => {
+                self.remainder = Some(&remainder[pos + self.separator.len()..]);
+                Some(&remainder[..pos])
+            }
+            None => {
+                self.remainder = None;
+                Some(remainder)
+            }
+        }
+    }
+}
+
+impl DoubleEndedIterator for ByteStringSplit<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let remainder = self.remainder?;
+        if self.separator.is_empty() {
+            self.remainder = None;
+            return Some(remainder);
+        }
+
+        match remainder
+            .windows(self.separator.len())
+            .rposition(|w| w == self.separator)
+        {
+            Some(pos) => {
+                self.remainder = Some(&remainder[..pos]);
+                Some(&remainder[pos + self.separator.len()..])
+            }
+            None => {
+                self.remainder = None;
+                Some(remainder)
+            }
+        }
+    }
+}
+
+/// Displays a byte slice with escape characters so that it can be used for console output.
+/// Unlike [`as_escaped_string()`] it does not allocate and is available without the `std`
+/// feature.
+#[derive(Debug, Clone, Copy)]
+pub struct EscapedBytes<'a>(pub &'a [u8]);
+
+impl Display for EscapedBytes<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for c in self.0 {
+            match *c {
+                b'\t' => f.write_str("\\t")?,
+                b'\r' => f.write_str("\\r")?,
+                b'\n' => f.write_str("\\n")?,
+                b'\x20'..=b'\x7e' => write!(f, "{}", *c as char)?,
+                _ => write!(f, "\\x{:02x}", c)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Adds escape characters to the string so that it can be used for console output.
+#[cfg(feature = "std")]
 pub fn as_escaped_string(bytes: &[u8]) -> String {
-    String::from_utf8(
-        bytes
-            .iter()
-            .flat_map(|c| match *c {
-                b'\t' => vec![b'\\', b't'].into_iter(),
-                b'\r' => vec![b'\\', b'r'].into_iter(),
-                b'\n' => vec![b'\\', b'n'].into_iter(),
-                b'\x20'..=b'\x7e' => vec![*c].into_iter(),
-                _ => {
-                    let hex_digits: &[u8; 16] = b"0123456789abcdef";
-                    vec![
-                        b'\\',
-                        b'x',
-                        hex_digits[(c >> 4) as usize],
-                        hex_digits[(c & 0xf) as usize],
-                    ]
-                    .into_iter()
-                }
-            })
-            .collect::<Vec<u8>>(),
-    )
-    .unwrap()
+    EscapedBytes(bytes).to_string()
 }
 
 impl<const CAPACITY: usize> FixedSizeByteString<CAPACITY> {
@@ -281,7 +394,7 @@ impl<const CAPACITY: usize> FixedSizeByteString<CAPACITY> {
     ///
     ///  * `bytes` len must be smaller or equal than [`FixedSizeByteString::capacity()`]
     ///
-    pub unsafe fn new_unchecked(bytes: &[u8]) -> Self {
+    pub const unsafe fn new_unchecked(bytes: &[u8]) -> Self {
         if CAPACITY < bytes.len() {
             panic!("Insufficient capacity to store bytes.");
         }
@@ -295,22 +408,55 @@ impl<const CAPACITY: usize> FixedSizeByteString<CAPACITY> {
         fail!(from "FixedSizeByteString", when new_self.push_bytes(bytes),
                 with FixedSizeByteStringModificationError::InsertWouldExceedCapacity,
                 "Unbale to create from \"{}\" since it would exceed the capacity of {}.",
-                as_escaped_string(bytes), CAPACITY);
+                EscapedBytes(bytes), CAPACITY);
+
+        Ok(new_self)
+    }
+
+    /// Creates a new [`FixedSizeByteString`] from formatted arguments, see
+    /// [`core::format_args!()`]. If the formatted output exceeds the capacity it fails.
+    ///
+    /// ```
+    /// use iceoryx2_bb_container::byte_string::*;
+    ///
+    /// let some_string = FixedSizeByteString::<16>::from_fmt(format_args!("node_{}", 12)).unwrap();
+    /// assert!(some_string == b"node_12");
+    ///
+    /// let result = FixedSizeByteString::<4>::from_fmt(format_args!("node_{}", 12));
+    /// assert!(result.is_err());
+    /// ```
+    pub fn from_fmt(
+        args: core::fmt::Arguments<'_>,
+    ) -> Result<Self, FixedSizeByteStringModificationError> {
+        let mut new_self = Self::new();
+        if core::fmt::write(&mut new_self, args).is_err() {
+            fail!(from "FixedSizeByteString::from_fmt()",
+                with FixedSizeByteStringModificationError::InsertWouldExceedCapacity,
+                "Unable to create from formatted arguments since they would exceed the capacity of {}.",
+                CAPACITY);
+        }
 
         Ok(new_self)
     }
 
     /// Creates a new [`FixedSizeByteString`] from a byte slice. If the byte slice does not fit
     /// into the [`FixedSizeByteString`] it will be truncated.
-    pub fn from_bytes_truncated(bytes: &[u8]) -> Self {
+    pub const fn from_bytes_truncated(bytes: &[u8]) -> Self {
         let mut new_self = Self::new();
-        new_self.len = core::cmp::min(bytes.len(), CAPACITY);
-        for (i, byte) in bytes.iter().enumerate().take(new_self.len) {
-            new_self.data[i].write(*byte);
+        new_self.len = if bytes.len() < CAPACITY {
+            bytes.len() as u64
+        } else {
+            CAPACITY as u64
+        };
+
+        let mut i = 0;
+        while i < new_self.len() {
+            new_self.data[i] = MaybeUninit::new(bytes[i]);
+            i += 1;
         }
 
-        if new_self.len < CAPACITY {
-            new_self.data[new_self.len].write(0);
+        if new_self.len() < CAPACITY {
+            new_self.data[new_self.len()] = MaybeUninit::new(0);
         }
 
         new_self
@@ -337,19 +483,19 @@ impl<const CAPACITY: usize> FixedSizeByteString<CAPACITY> {
             new_self.as_mut_bytes().as_mut_ptr() as *mut core::ffi::c_char,
             string_length,
         );
-        new_self.len = string_length;
+        new_self.len = string_length as u64;
 
         Ok(new_self)
     }
 
     /// Returns a slice to the underlying bytes
     pub const fn as_bytes(&self) -> &[u8] {
-        unsafe { core::slice::from_raw_parts(self.data[0].as_ptr(), self.len) }
+        unsafe { core::slice::from_raw_parts(self.data[0].as_ptr(), self.len()) }
     }
 
     /// Returns a null-terminated slice to the underlying bytes
     pub const fn as_bytes_with_nul(&self) -> &[u8] {
-        unsafe { core::slice::from_raw_parts(self.data[0].as_ptr(), self.len + 1) }
+        unsafe { core::slice::from_raw_parts(self.data[0].as_ptr(), self.len() + 1) }
     }
 
     /// Returns a zero terminated slice of the underlying bytes
@@ -359,7 +505,7 @@ impl<const CAPACITY: usize> FixedSizeByteString<CAPACITY> {
 
     /// Returns a mutable slice to the underlying bytes
     pub fn as_mut_bytes(&mut self) -> &mut [u8] {
-        unsafe { core::slice::from_raw_parts_mut(self.data[0].as_mut_ptr(), self.len) }
+        unsafe { core::slice::from_raw_parts_mut(self.data[0].as_mut_ptr(), self.len()) }
     }
 
     /// Returns the capacity of the string
@@ -369,7 +515,7 @@ impl<const CAPACITY: usize> FixedSizeByteString<CAPACITY> {
 
     /// Returns the length of the string
     pub const fn len(&self) -> usize {
-        self.len
+        self.len as usize
     }
 
     /// Removes all bytes from the string and set the len to zero
@@ -380,12 +526,12 @@ impl<const CAPACITY: usize> FixedSizeByteString<CAPACITY> {
 
     /// True if the string is empty, otherwise false
     pub const fn is_empty(&self) -> bool {
-        self.len == 0
+        self.len() == 0
     }
 
     /// True if the string is full, otherwise false.
     pub const fn is_full(&self) -> bool {
-        self.len == CAPACITY
+        self.len() == CAPACITY
     }
 
     /// Inserts a byte at a provided index. If the index is out of bounds it panics.
@@ -426,15 +572,15 @@ impl<const CAPACITY: usize> FixedSizeByteString<CAPACITY> {
         bytes: &[u8],
     ) -> Result<(), FixedSizeByteStringModificationError> {
         let msg = "Unable to insert byte string";
-        if self.len < idx {
+        if self.len() < idx {
             fatal_panic!(from self, "{} \"{}\" since the index {} is out of bounds.",
-                msg, as_escaped_string(bytes) , idx);
+                msg, EscapedBytes(bytes) , idx);
         }
 
-        if CAPACITY < self.len + bytes.len() {
+        if CAPACITY < self.len() + bytes.len() {
             fail!(from self, with FixedSizeByteStringModificationError::InsertWouldExceedCapacity,
                 "{} \"{}\" since it would exceed the maximum capacity of {}.",
-                msg, as_escaped_string(bytes), CAPACITY);
+                msg, EscapedBytes(bytes), CAPACITY);
         }
 
         unsafe { self.insert_bytes_unchecked(idx, bytes) };
@@ -455,7 +601,7 @@ impl<const CAPACITY: usize> FixedSizeByteString<CAPACITY> {
             core::ptr::copy(
                 self.data[idx].as_ptr(),
                 self.data[idx].as_mut_ptr().add(bytes.len()),
-                self.len - idx,
+                self.len() - idx,
             );
         }
 
@@ -463,9 +609,9 @@ impl<const CAPACITY: usize> FixedSizeByteString<CAPACITY> {
             self.data[idx + i].write(*byte);
         }
 
-        self.len += bytes.len();
-        if self.len < CAPACITY {
-            self.data[self.len].write(0);
+        self.len += bytes.len() as u64;
+        if self.len() < CAPACITY {
+            self.data[self.len()].write(0);
         }
     }
 
@@ -487,24 +633,24 @@ impl<const CAPACITY: usize> FixedSizeByteString<CAPACITY> {
             return None;
         }
 
-        Some(self.remove(self.len - 1))
+        Some(self.remove(self.len() - 1))
     }
 
     /// Adds a byte at the end of the string. If there is no more space left it fails, otherwise
     /// it succeeds.
     pub fn push(&mut self, byte: u8) -> Result<(), FixedSizeByteStringModificationError> {
-        self.insert(self.len, byte)
+        self.insert(self.len(), byte)
     }
 
     /// Adds a byte array at the end of the string. If there is no more space left it fails, otherwise
     /// it succeeds.
     pub fn push_bytes(&mut self, bytes: &[u8]) -> Result<(), FixedSizeByteStringModificationError> {
-        self.insert_bytes(self.len, bytes)
+        self.insert_bytes(self.len(), bytes)
     }
 
     /// Removes a character at the provided index and returns it.
     pub fn remove(&mut self, idx: usize) -> u8 {
-        if self.len < idx {
+        if self.len() < idx {
             fatal_panic!(from self, "Unable to remove byte at position {} since it is out of bounds.",
                 idx);
         }
@@ -518,7 +664,7 @@ impl<const CAPACITY: usize> FixedSizeByteString<CAPACITY> {
 
     /// Removes a range beginning from idx.
     pub fn remove_range(&mut self, idx: usize, len: usize) {
-        if self.len < idx + len {
+        if self.len() < idx + len {
             fatal_panic!(from self, "Unable to remove range from position {} with length {} since it is out of bounds.",
                 idx, len);
         }
@@ -531,12 +677,12 @@ impl<const CAPACITY: usize> FixedSizeByteString<CAPACITY> {
             core::ptr::copy(
                 self.data[idx + len].as_ptr(),
                 self.data[idx].as_mut_ptr(),
-                self.len - (idx + len),
+                self.len() - (idx + len),
             );
         }
 
-        self.len -= len;
-        self.data[self.len].write(0);
+        self.len -= len as u64;
+        self.data[self.len()].write(0);
     }
 
     /// Removes all characters where f(c) returns false.
@@ -545,7 +691,7 @@ impl<const CAPACITY: usize> FixedSizeByteString<CAPACITY> {
     }
 
     pub(crate) fn retain_impl<F: FnMut(u8) -> bool>(&mut self, mut f: F) -> F {
-        let len = self.len;
+        let len = self.len();
         for i in 0..len {
             let idx = len - i - 1;
             if f(unsafe { *self.data[idx].as_ptr() }) {
@@ -603,6 +749,30 @@ impl<const CAPACITY: usize> FixedSizeByteString<CAPACITY> {
         None
     }
 
+    /// Returns an iterator over the parts of the string that are separated by `separator`.
+    /// Like [`str::split()`], adjacent separators result in empty parts. An empty separator
+    /// yields the whole string as a single part.
+    pub fn split_bytes<'a>(&'a self, separator: &'a [u8]) -> ByteStringSplit<'a> {
+        ByteStringSplit {
+            remainder: Some(self.as_bytes()),
+            separator,
+        }
+    }
+
+    /// Returns a copy of the string where every ASCII character is converted into its
+    /// upper case equivalent. Non-ASCII bytes are unchanged.
+    pub fn to_ascii_uppercase(mut self) -> Self {
+        self.as_mut_bytes().make_ascii_uppercase();
+        self
+    }
+
+    /// Returns a copy of the string where every ASCII character is converted into its
+    /// lower case equivalent. Non-ASCII bytes are unchanged.
+    pub fn to_ascii_lowercase(mut self) -> Self {
+        self.as_mut_bytes().make_ascii_lowercase();
+        self
+    }
+
     /// Removes a given prefix from the string. If the prefix was not found it returns false,
     /// otherwise the prefix is removed and the function returns true.
     pub fn strip_prefix(&mut self, bytes: &[u8]) -> bool {
@@ -641,9 +811,125 @@ impl<const CAPACITY: usize> FixedSizeByteString<CAPACITY> {
             return;
         }
 
-        self.len = new_len;
-        if self.len < CAPACITY {
-            self.data[self.len].write(0u8);
+        self.len = new_len as u64;
+        if self.len() < CAPACITY {
+            self.data[self.len()].write(0u8);
+        }
+    }
+}
+
+/// **Non-movable** relocatable byte string with run-time fixed size capacity. The content is
+/// stored in memory that is acquired from the allocator provided in
+/// [`RelocatableContainer::init()`], therefore the string can be constructed in place inside a
+/// shared memory segment, for instance in the payload of a loaned sample.
+#[repr(C)]
+#[derive(Debug)]
+pub struct RelocatableByteString {
+    data: RelocatableVec<u8>,
+}
+
+impl RelocatableContainer for RelocatableByteString {
+    unsafe fn new_uninit(capacity: usize) -> Self {
+        Self {
+            data: RelocatableVec::new_uninit(capacity),
         }
     }
+
+    unsafe fn init<Allocator: BaseAllocator>(
+        &mut self,
+        allocator: &Allocator,
+    ) -> Result<(), AllocationError> {
+        self.data.init(allocator)
+    }
+
+    fn memory_size(capacity: usize) -> usize {
+        Self::const_memory_size(capacity)
+    }
+}
+
+impl PartialEq for RelocatableByteString {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl Eq for RelocatableByteString {}
+
+impl RelocatableByteString {
+    /// Returns the required memory size for a string with a specified capacity
+    pub const fn const_memory_size(capacity: usize) -> usize {
+        RelocatableVec::<u8>::const_memory_size(capacity)
+    }
+
+    /// Returns the capacity of the string
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    /// Returns the length of the string
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns true if the string is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns true if the string is full, otherwise false
+    pub fn is_full(&self) -> bool {
+        self.data.is_full()
+    }
+
+    /// Adds a byte at the end of the string. If there is no more space left it fails, otherwise
+    /// it succeeds.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableByteString::init()`] must be called once before
+    ///
+    pub unsafe fn push(&mut self, byte: u8) -> Result<(), FixedSizeByteStringModificationError> {
+        self.push_bytes(&[byte])
+    }
+
+    /// Adds a byte array at the end of the string. If there is no more space left it fails, otherwise
+    /// it succeeds.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableByteString::init()`] must be called once before
+    ///
+    pub unsafe fn push_bytes(
+        &mut self,
+        bytes: &[u8],
+    ) -> Result<(), FixedSizeByteStringModificationError> {
+        if self.capacity() < self.len() + bytes.len() {
+            fail!(from self, with FixedSizeByteStringModificationError::InsertWouldExceedCapacity,
+                "Unable to push byte string \"{}\" since it would exceed the maximum capacity of {}.",
+                EscapedBytes(bytes), self.capacity());
+        }
+
+        self.data.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Removes all bytes from the string
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableByteString::init()`] must be called once before
+    ///
+    pub unsafe fn clear(&mut self) {
+        self.data.clear()
+    }
+
+    /// Returns a slice to the underlying bytes
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableByteString::init()`] must be called once before
+    ///
+    pub unsafe fn as_bytes(&self) -> &[u8] {
+        self.data.as_slice()
+    }
 }
@@ -0,0 +1,315 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`FixedSizeGenerationalSlotMap`] is a compile-time fixed-size slot map that is
+//! self-contained and shared-memory compatible. Every inserted value is identified by a
+//! [`GenerationalKey`] that consists of the slot index and the generation of the slot.
+//!
+//! The generation of a slot is increased whenever a value is inserted or removed. A key
+//! therefore refers only to the value it was created for. When the value was removed and the
+//! slot was reused, the stale key is rejected instead of accessing the new value, which makes
+//! the keys ABA-safe handles, for instance for registries in shared memory.
+//!
+//! All operations, except the iteration, have a runtime of `O(1)`.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_container::generational_slotmap::*;
+//!
+//! let mut slotmap = FixedSizeGenerationalSlotMap::<u64, 8>::new();
+//!
+//! let key = slotmap.insert(123).unwrap();
+//! assert_eq!(slotmap.get(key), Some(&123));
+//!
+//! assert_eq!(slotmap.remove(key), Some(123));
+//! let new_key = slotmap.insert(456).unwrap();
+//!
+//! // the slot is reused but the old key is stale
+//! assert_eq!(new_key.index(), key.index());
+//! assert_eq!(slotmap.get(key), None);
+//! assert_eq!(slotmap.get(new_key), Some(&456));
+//! ```
+
+use core::fmt::Debug;
+use core::mem::MaybeUninit;
+
+use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+
+const INVALID: u32 = u32::MAX;
+
+/// The key of a value stored in a [`FixedSizeGenerationalSlotMap`]. It is only valid as long
+/// as the value it was created for is stored in the slot map.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(C)]
+pub struct GenerationalKey {
+    index: u32,
+    generation: u32,
+}
+
+impl GenerationalKey {
+    /// Creates a new [`GenerationalKey`] from its index and generation.
+    pub fn new(index: u32, generation: u32) -> Self {
+        Self { index, generation }
+    }
+
+    /// Returns the index of the slot.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the generation of the slot at the time the value was inserted.
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+
+    /// Returns the key as a single value so that it can be stored in an atomic.
+    pub fn as_u64(&self) -> u64 {
+        ((self.generation as u64) << 32) | self.index as u64
+    }
+
+    /// Creates a [`GenerationalKey`] from a value acquired with [`GenerationalKey::as_u64()`].
+    pub fn from_u64(value: u64) -> Self {
+        Self {
+            index: value as u32,
+            generation: (value >> 32) as u32,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+struct SlotState {
+    // an odd generation marks an occupied slot
+    generation: u32,
+    next_free: u32,
+}
+
+impl SlotState {
+    fn is_occupied(&self) -> bool {
+        self.generation % 2 == 1
+    }
+}
+
+/// A compile-time fixed-size slot map whose keys detect the reuse of a slot.
+#[repr(C)]
+pub struct FixedSizeGenerationalSlotMap<T, const CAPACITY: usize> {
+    len: usize,
+    free_list_head: u32,
+    states: [SlotState; CAPACITY],
+    values: [MaybeUninit<T>; CAPACITY],
+}
+
+impl<T, const CAPACITY: usize> PlacementDefault for FixedSizeGenerationalSlotMap<T, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        Self::verify_capacity();
+        core::ptr::addr_of_mut!((*ptr).len).write(0);
+        core::ptr::addr_of_mut!((*ptr).free_list_head).write(Self::first_free_slot());
+        let states = core::ptr::addr_of_mut!((*ptr).states).cast::<SlotState>();
+        for i in 0..CAPACITY {
+            states.add(i).write(Self::initial_state(i));
+        }
+    }
+}
+
+impl<T, const CAPACITY: usize> Default for FixedSizeGenerationalSlotMap<T, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const CAPACITY: usize> Drop for FixedSizeGenerationalSlotMap<T, CAPACITY> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Debug, const CAPACITY: usize> Debug for FixedSizeGenerationalSlotMap<T, CAPACITY> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+unsafe impl<T: Send, const CAPACITY: usize> Send for FixedSizeGenerationalSlotMap<T, CAPACITY> {}
+
+impl<T, const CAPACITY: usize> FixedSizeGenerationalSlotMap<T, CAPACITY> {
+    fn verify_capacity() {
+        const {
+            assert!(
+                CAPACITY < INVALID as usize,
+                "The capacity must be smaller than u32::MAX."
+            )
+        }
+    }
+
+    fn first_free_slot() -> u32 {
+        if CAPACITY == 0 {
+            INVALID
+        } else {
+            0
+        }
+    }
+
+    fn initial_state(index: usize) -> SlotState {
+        SlotState {
+            generation: 0,
+            next_free: if index + 1 < CAPACITY {
+                (index + 1) as u32
+            } else {
+                INVALID
+            },
+        }
+    }
+
+    /// Creates a new and empty [`FixedSizeGenerationalSlotMap`].
+    pub fn new() -> Self {
+        Self::verify_capacity();
+        Self {
+            len: 0,
+            free_list_head: Self::first_free_slot(),
+            states: core::array::from_fn(Self::initial_state),
+            values: core::array::from_fn(|_| MaybeUninit::uninit()),
+        }
+    }
+
+    /// Returns the capacity.
+    pub const fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Returns the number of stored values.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true when no value is stored, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true when the capacity is exhausted, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.len == CAPACITY
+    }
+
+    fn slot_of(&self, key: GenerationalKey) -> Option<usize> {
+        let index = key.index as usize;
+        match self.states.get(index) {
+            Some(state) if state.is_occupied() && state.generation == key.generation => Some(index),
+            _ => None,
+        }
+    }
+
+    /// Returns true when the value of the key is stored, otherwise false.
+    pub fn contains(&self, key: GenerationalKey) -> bool {
+        self.slot_of(key).is_some()
+    }
+
+    /// Returns a reference to the value of the key. If the key is stale it returns [`None`].
+    pub fn get(&self, key: GenerationalKey) -> Option<&T> {
+        self.slot_of(key)
+            .map(|index| unsafe { self.values[index].assume_init_ref() })
+    }
+
+    /// Returns a mutable reference to the value of the key. If the key is stale it returns
+    /// [`None`].
+    pub fn get_mut(&mut self, key: GenerationalKey) -> Option<&mut T> {
+        self.slot_of(key)
+            .map(|index| unsafe { self.values[index].assume_init_mut() })
+    }
+
+    /// Stores the value in a free slot and returns its key. If the slot map is full it returns
+    /// [`None`].
+    pub fn insert(&mut self, value: T) -> Option<GenerationalKey> {
+        if self.free_list_head == INVALID {
+            return None;
+        }
+
+        let index = self.free_list_head as usize;
+        let state = &mut self.states[index];
+        self.free_list_head = state.next_free;
+        state.next_free = INVALID;
+        state.generation = state.generation.wrapping_add(1);
+
+        self.values[index].write(value);
+        self.len += 1;
+
+        Some(GenerationalKey {
+            index: index as u32,
+            generation: state.generation,
+        })
+    }
+
+    /// Removes the value of the key and returns it. If the key is stale it returns [`None`].
+    pub fn remove(&mut self, key: GenerationalKey) -> Option<T> {
+        let index = self.slot_of(key)?;
+        Some(unsafe { self.remove_unchecked(index) })
+    }
+
+    unsafe fn remove_unchecked(&mut self, index: usize) -> T {
+        let state = &mut self.states[index];
+        state.generation = state.generation.wrapping_add(1);
+        state.next_free = self.free_list_head;
+        self.free_list_head = index as u32;
+        self.len -= 1;
+
+        self.values[index].assume_init_read()
+    }
+
+    /// Removes all values. All keys that were handed out become stale.
+    pub fn clear(&mut self) {
+        for index in 0..CAPACITY {
+            if self.states[index].is_occupied() {
+                drop(unsafe { self.remove_unchecked(index) });
+            }
+        }
+    }
+
+    /// Returns an iterator over all keys and values in the order of the slots.
+    pub fn iter(&self) -> Iter<'_, T, CAPACITY> {
+        Iter {
+            slotmap: self,
+            index: 0,
+        }
+    }
+}
+
+/// Iterator over all keys and values of a [`FixedSizeGenerationalSlotMap`], see
+/// [`FixedSizeGenerationalSlotMap::iter()`].
+pub struct Iter<'a, T, const CAPACITY: usize> {
+    slotmap: &'a FixedSizeGenerationalSlotMap<T, CAPACITY>,
+    index: usize,
+}
+
+impl<'a, T, const CAPACITY: usize> Iterator for Iter<'a, T, CAPACITY> {
+    type Item = (GenerationalKey, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < CAPACITY {
+            let index = self.index;
+            self.index += 1;
+
+            let state = &self.slotmap.states[index];
+            if state.is_occupied() {
+                return Some((
+                    GenerationalKey {
+                        index: index as u32,
+                        generation: state.generation,
+                    },
+                    unsafe { self.slotmap.values[index].assume_init_ref() },
+                ));
+            }
+        }
+
+        None
+    }
+}
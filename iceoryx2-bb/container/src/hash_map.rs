@@ -0,0 +1,366 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`FixedSizeHashMap`] is a compile-time fixed-size hash map that is self-contained and
+//! shared-memory compatible. It stores all entries in an array that is part of the map and
+//! uses open addressing, therefore it does not allocate and contains no pointers. It can be
+//! placed in shared memory, moved and used as part of a payload type.
+//!
+//! The slot of a key is determined by the [`ProbingStrategy`], which defaults to
+//! [`LinearProbing`]. The keys are hashed with [FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function)
+//! without any random state, so that every process that accesses the map computes the same
+//! slots for the same keys.
+//!
+//! Removed entries leave a tombstone behind to keep the probing sequences of the remaining
+//! keys intact. Tombstones are reused by the next insert.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_container::hash_map::*;
+//!
+//! const CAPACITY: usize = 32;
+//! let mut map = FixedSizeHashMap::<u64, u32, CAPACITY>::new();
+//!
+//! assert_eq!(map.insert(42, 1), Ok(None));
+//! assert_eq!(map.insert(42, 2), Ok(Some(1)));
+//! assert_eq!(map.get(&42), Some(&2));
+//! assert_eq!(map.remove(&42), Some(2));
+//! assert!(map.is_empty());
+//! ```
+//!
+//! A map with [`QuadraticProbing`] spreads colliding keys further apart.
+//!
+//! ```
+//! use iceoryx2_bb_container::hash_map::*;
+//!
+//! // the capacity must be a power of two to reach every slot with quadratic probing
+//! let mut map = FixedSizeHashMap::<u16, u16, 64, QuadraticProbing>::new();
+//! assert_eq!(map.insert(1, 2), Ok(None));
+//! ```
+
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+
+use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+
+/// Defines the sequence of slots that is visited to find the slot of a key.
+pub trait ProbingStrategy {
+    /// Returns the slot that is visited in the `attempt`-th step for a key with the provided
+    /// `hash`. The result must be smaller than `capacity`.
+    fn slot(hash: u64, attempt: usize, capacity: usize) -> usize;
+}
+
+/// Visits the slots one after another, starting at the hash of the key. It reaches every
+/// slot for every capacity.
+#[derive(Debug, Clone, Copy)]
+pub struct LinearProbing;
+
+impl ProbingStrategy for LinearProbing {
+    fn slot(hash: u64, attempt: usize, capacity: usize) -> usize {
+        ((hash as usize) % capacity + attempt) % capacity
+    }
+}
+
+/// Visits the slots with an increasing distance of triangular numbers, starting at the hash
+/// of the key. It reaches every slot only when the capacity is a power of two, otherwise an
+/// insert can fail before the map is full.
+#[derive(Debug, Clone, Copy)]
+pub struct QuadraticProbing;
+
+impl ProbingStrategy for QuadraticProbing {
+    fn slot(hash: u64, attempt: usize, capacity: usize) -> usize {
+        let offset = attempt.wrapping_mul(attempt.wrapping_add(1)) / 2;
+        ((hash as usize) % capacity).wrapping_add(offset) % capacity
+    }
+}
+
+/// Describes why a key-value pair could not be inserted into a [`FixedSizeHashMap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FixedSizeHashMapInsertError {
+    /// No free slot was found on the probing sequence of the key.
+    ExceedsCapacity,
+}
+
+impl core::fmt::Display for FixedSizeHashMapInsertError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "FixedSizeHashMapInsertError::{:?}", self)
+    }
+}
+
+impl core::error::Error for FixedSizeHashMapInsertError {}
+
+struct Fnv1aHasher(u64);
+
+impl Default for Fnv1aHasher {
+    fn default() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+}
+
+impl Hasher for Fnv1aHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SlotState {
+    Empty,
+    Occupied,
+    Tombstone,
+}
+
+#[repr(C)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+}
+
+/// A compile-time fixed-size hash map that is self-contained and shared-memory compatible,
+/// see the [module documentation](crate::hash_map) for details.
+#[repr(C)]
+pub struct FixedSizeHashMap<K, V, const CAPACITY: usize, P = LinearProbing> {
+    len: usize,
+    states: [SlotState; CAPACITY],
+    entries: [MaybeUninit<Entry<K, V>>; CAPACITY],
+    _probing: PhantomData<P>,
+}
+
+impl<K: Hash + Eq, V, const CAPACITY: usize, P: ProbingStrategy> Default
+    for FixedSizeHashMap<K, V, CAPACITY, P>
+{
+    fn default() -> Self {
+        Self {
+            len: 0,
+            states: [SlotState::Empty; CAPACITY],
+            entries: core::array::from_fn(|_| MaybeUninit::uninit()),
+            _probing: PhantomData,
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, const CAPACITY: usize, P: ProbingStrategy> PlacementDefault
+    for FixedSizeHashMap<K, V, CAPACITY, P>
+{
+    unsafe fn placement_default(ptr: *mut Self) {
+        core::ptr::addr_of_mut!((*ptr).len).write(0);
+        core::ptr::addr_of_mut!((*ptr).states).write([SlotState::Empty; CAPACITY]);
+    }
+}
+
+impl<K, V, const CAPACITY: usize, P> Drop for FixedSizeHashMap<K, V, CAPACITY, P> {
+    fn drop(&mut self) {
+        for (state, entry) in self.states.iter().zip(self.entries.iter_mut()) {
+            if *state == SlotState::Occupied {
+                unsafe { entry.assume_init_drop() };
+            }
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone, const CAPACITY: usize, P: ProbingStrategy> Clone
+    for FixedSizeHashMap<K, V, CAPACITY, P>
+{
+    fn clone(&self) -> Self {
+        let mut new_self = Self::default();
+        // the slots are copied one to one so that the probing sequences stay intact
+        for n in 0..CAPACITY {
+            if self.states[n] == SlotState::Occupied {
+                let entry = unsafe { self.entries[n].assume_init_ref() };
+                new_self.entries[n].write(Entry {
+                    key: entry.key.clone(),
+                    value: entry.value.clone(),
+                });
+            }
+            new_self.states[n] = self.states[n];
+        }
+        new_self.len = self.len;
+        new_self
+    }
+}
+
+impl<
+        K: Hash + Eq + core::fmt::Debug,
+        V: core::fmt::Debug,
+        const CAPACITY: usize,
+        P: ProbingStrategy,
+    > core::fmt::Debug for FixedSizeHashMap<K, V, CAPACITY, P>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Hash + Eq, V: PartialEq, const CAPACITY: usize, P: ProbingStrategy> PartialEq
+    for FixedSizeHashMap<K, V, CAPACITY, P>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<K: Hash + Eq, V: Eq, const CAPACITY: usize, P: ProbingStrategy> Eq
+    for FixedSizeHashMap<K, V, CAPACITY, P>
+{
+}
+
+impl<K: Hash + Eq, V, const CAPACITY: usize, P: ProbingStrategy>
+    FixedSizeHashMap<K, V, CAPACITY, P>
+{
+    /// Creates a new empty [`FixedSizeHashMap`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the capacity of the map
+    pub fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Returns the number of key-value pairs stored inside the map
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the map is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the map is full, otherwise false
+    pub fn is_full(&self) -> bool {
+        self.len == CAPACITY
+    }
+
+    fn hash(key: &K) -> u64 {
+        let mut hasher = Fnv1aHasher::default();
+        key.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn entry(&self, slot: usize) -> &Entry<K, V> {
+        debug_assert!(self.states[slot] == SlotState::Occupied);
+        unsafe { self.entries[slot].assume_init_ref() }
+    }
+
+    fn find(&self, key: &K) -> Option<usize> {
+        let hash = Self::hash(key);
+        for attempt in 0..CAPACITY {
+            let slot = P::slot(hash, attempt, CAPACITY);
+            match self.states[slot] {
+                SlotState::Empty => return None,
+                SlotState::Occupied if self.entry(slot).key == *key => return Some(slot),
+                _ => (),
+            }
+        }
+
+        None
+    }
+
+    /// Inserts a key-value pair into the map. If the key was already present, its value is
+    /// replaced and the old value is returned. If no free slot is available for the key
+    /// [`FixedSizeHashMapInsertError::ExceedsCapacity`] is returned.
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, FixedSizeHashMapInsertError> {
+        let hash = Self::hash(&key);
+        let mut free_slot = None;
+        for attempt in 0..CAPACITY {
+            let slot = P::slot(hash, attempt, CAPACITY);
+            match self.states[slot] {
+                SlotState::Empty => {
+                    free_slot.get_or_insert(slot);
+                    break;
+                }
+                SlotState::Tombstone => {
+                    free_slot.get_or_insert(slot);
+                }
+                SlotState::Occupied => {
+                    let entry = unsafe { self.entries[slot].assume_init_mut() };
+                    if entry.key == key {
+                        return Ok(Some(core::mem::replace(&mut entry.value, value)));
+                    }
+                }
+            }
+        }
+
+        match free_slot {
+            Some(slot) => {
+                self.entries[slot].write(Entry { key, value });
+                self.states[slot] = SlotState::Occupied;
+                self.len += 1;
+                Ok(None)
+            }
+            None => Err(FixedSizeHashMapInsertError::ExceedsCapacity),
+        }
+    }
+
+    /// Returns a reference to the value of the key. If the key is not present [`None`] is
+    /// returned.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.find(key).map(|slot| &self.entry(slot).value)
+    }
+
+    /// Returns a mutable reference to the value of the key. If the key is not present [`None`]
+    /// is returned.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.find(key)
+            .map(|slot| &mut unsafe { self.entries[slot].assume_init_mut() }.value)
+    }
+
+    /// Returns true if the key is present, otherwise false.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.find(key).is_some()
+    }
+
+    /// Removes the key from the map and returns its value. If the key is not present [`None`]
+    /// is returned.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let slot = self.find(key)?;
+        self.states[slot] = SlotState::Tombstone;
+        self.len -= 1;
+        let entry = unsafe { self.entries[slot].assume_init_read() };
+        Some(entry.value)
+    }
+
+    /// Removes all key-value pairs from the map.
+    pub fn clear(&mut self) {
+        for (state, entry) in self.states.iter_mut().zip(self.entries.iter_mut()) {
+            if *state == SlotState::Occupied {
+                unsafe { entry.assume_init_drop() };
+            }
+            *state = SlotState::Empty;
+        }
+        self.len = 0;
+    }
+
+    /// Returns an iterator over all key-value pairs in an unspecified order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        (0..CAPACITY)
+            .filter(|slot| self.states[*slot] == SlotState::Occupied)
+            .map(|slot| {
+                let entry = self.entry(slot);
+                (&entry.key, &entry.value)
+            })
+    }
+}
@@ -10,6 +10,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(clippy::alloc_instead_of_core)]
 #![warn(clippy::std_instead_of_alloc)]
 #![warn(clippy::std_instead_of_core)]
@@ -28,7 +29,8 @@
 //!  2. `Relocatable*Container*`, run-time fixed size version that is shared memory compatible. The
 //!     capacity must be known when the object is created. **This object is not movable!**
 //!  3. `*Container*`, run-time fixed size version that is **not** shared memory compatible but can be
-//!     moved. The memory is by default stored on the heap.
+//!     moved. The memory is by default stored on the heap and it is only available with the
+//!     `std` feature.
 //!
 //! # Example
 //!
@@ -88,8 +90,16 @@
 //! # }
 //! ```
 
+/// A compile-time fixed-size bitset
+pub mod bit_set;
 /// A byte string similar to [`std::string::String`] but it does not support UTF-8
 pub mod byte_string;
+/// A container with keys that detect the reuse of a slot.
+pub mod generational_slotmap;
+/// A hash map similar to [`std::collections::HashMap`]
+pub mod hash_map;
+/// A map with sorted keys similar to [`std::collections::BTreeMap`]
+pub mod ordered_map;
 /// A queue similar to [`std::collections::VecDeque`]
 pub mod queue;
 /// A container with persistent unique keys to access values.
@@ -100,3 +110,8 @@ pub mod slotmap;
 pub mod semantic_string;
 /// A vector similar to [`std::vec::Vec`]
 pub mod vec;
+
+// used by the semantic_string macro to implement serde in the crate that defines the string
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde;
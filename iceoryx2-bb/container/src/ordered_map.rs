@@ -0,0 +1,525 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`FixedSizeOrderedMap`] is a compile-time fixed-size map that keeps its keys sorted. It is
+//! self-contained and shared-memory compatible, all nodes are stored in an array that is part
+//! of the map and reference each other by index instead of by pointer. Therefore, it can be
+//! placed in shared memory, moved and used as part of a payload type.
+//!
+//! The nodes form a balanced [AA tree](https://en.wikipedia.org/wiki/AA_tree), so that insert,
+//! lookup and remove have a runtime of `O(log n)`. Iterating over the map visits the entries
+//! in ascending order of their keys, independent of the order of insertion.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_container::ordered_map::*;
+//!
+//! const CAPACITY: usize = 16;
+//! let mut routing_table = FixedSizeOrderedMap::<u32, u64, CAPACITY>::new();
+//!
+//! assert_eq!(routing_table.insert(30, 3), Ok(None));
+//! assert_eq!(routing_table.insert(10, 1), Ok(None));
+//! assert_eq!(routing_table.insert(20, 2), Ok(None));
+//!
+//! let keys: Vec<u32> = routing_table.iter().map(|(key, _)| *key).collect();
+//! assert_eq!(keys, [10, 20, 30]);
+//!
+//! assert_eq!(routing_table.remove(&20), Some(2));
+//! assert_eq!(routing_table.first_key_value(), Some((&10, &1)));
+//! ```
+
+use core::mem::MaybeUninit;
+
+use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+
+const NIL: usize = usize::MAX;
+
+/// Describes why a key-value pair could not be inserted into a [`FixedSizeOrderedMap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FixedSizeOrderedMapInsertError {
+    /// The map is full and does not contain the key.
+    ExceedsCapacity,
+}
+
+impl core::fmt::Display for FixedSizeOrderedMapInsertError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "FixedSizeOrderedMapInsertError::{:?}", self)
+    }
+}
+
+impl core::error::Error for FixedSizeOrderedMapInsertError {}
+
+// A level of 0 marks an unused node, unused nodes are chained via `right` in the free list.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct Link {
+    left: usize,
+    right: usize,
+    level: usize,
+}
+
+#[repr(C)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+}
+
+/// The iterator of a [`FixedSizeOrderedMap`] that returns the entries in ascending order of
+/// their keys.
+pub struct Iter<'map, K: Ord, V, const CAPACITY: usize> {
+    map: &'map FixedSizeOrderedMap<K, V, CAPACITY>,
+    next: usize,
+}
+
+impl<'map, K: Ord, V, const CAPACITY: usize> Iterator for Iter<'map, K, V, CAPACITY> {
+    type Item = (&'map K, &'map V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == NIL {
+            return None;
+        }
+
+        let entry = self.map.entry(self.next);
+        self.next = self.map.successor(&entry.key);
+        Some((&entry.key, &entry.value))
+    }
+}
+
+/// A compile-time fixed-size ordered map that is self-contained and shared-memory compatible,
+/// see the [module documentation](crate::ordered_map) for details.
+#[repr(C)]
+pub struct FixedSizeOrderedMap<K, V, const CAPACITY: usize> {
+    root: usize,
+    free_list_head: usize,
+    len: usize,
+    links: [Link; CAPACITY],
+    entries: [MaybeUninit<Entry<K, V>>; CAPACITY],
+}
+
+impl<K, V, const CAPACITY: usize> FixedSizeOrderedMap<K, V, CAPACITY> {
+    fn initial_links() -> [Link; CAPACITY] {
+        core::array::from_fn(|n| Link {
+            left: NIL,
+            right: if n + 1 < CAPACITY { n + 1 } else { NIL },
+            level: 0,
+        })
+    }
+
+    fn initial_free_list_head() -> usize {
+        if CAPACITY == 0 {
+            NIL
+        } else {
+            0
+        }
+    }
+
+    fn drop_entries(&mut self) {
+        for (link, entry) in self.links.iter().zip(self.entries.iter_mut()) {
+            if link.level != 0 {
+                unsafe { entry.assume_init_drop() };
+            }
+        }
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> Default for FixedSizeOrderedMap<K, V, CAPACITY> {
+    fn default() -> Self {
+        Self {
+            root: NIL,
+            free_list_head: Self::initial_free_list_head(),
+            len: 0,
+            links: Self::initial_links(),
+            entries: core::array::from_fn(|_| MaybeUninit::uninit()),
+        }
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> PlacementDefault for FixedSizeOrderedMap<K, V, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        core::ptr::addr_of_mut!((*ptr).root).write(NIL);
+        core::ptr::addr_of_mut!((*ptr).free_list_head).write(Self::initial_free_list_head());
+        core::ptr::addr_of_mut!((*ptr).len).write(0);
+        core::ptr::addr_of_mut!((*ptr).links).write(Self::initial_links());
+    }
+}
+
+impl<K, V, const CAPACITY: usize> Drop for FixedSizeOrderedMap<K, V, CAPACITY> {
+    fn drop(&mut self) {
+        self.drop_entries();
+    }
+}
+
+impl<K: Ord + Clone, V: Clone, const CAPACITY: usize> Clone
+    for FixedSizeOrderedMap<K, V, CAPACITY>
+{
+    fn clone(&self) -> Self {
+        let mut new_self = Self::default();
+        // the nodes are copied one to one so that all links stay valid
+        for n in 0..CAPACITY {
+            if self.links[n].level != 0 {
+                let entry = self.entry(n);
+                new_self.entries[n].write(Entry {
+                    key: entry.key.clone(),
+                    value: entry.value.clone(),
+                });
+            }
+        }
+        new_self.links = self.links;
+        new_self.root = self.root;
+        new_self.free_list_head = self.free_list_head;
+        new_self.len = self.len;
+        new_self
+    }
+}
+
+impl<K: Ord + core::fmt::Debug, V: core::fmt::Debug, const CAPACITY: usize> core::fmt::Debug
+    for FixedSizeOrderedMap<K, V, CAPACITY>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Ord, V: PartialEq, const CAPACITY: usize> PartialEq
+    for FixedSizeOrderedMap<K, V, CAPACITY>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Ord, V: Eq, const CAPACITY: usize> Eq for FixedSizeOrderedMap<K, V, CAPACITY> {}
+
+impl<K: Ord, V, const CAPACITY: usize> FixedSizeOrderedMap<K, V, CAPACITY> {
+    /// Creates a new empty [`FixedSizeOrderedMap`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the capacity of the map
+    pub fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Returns the number of key-value pairs stored inside the map
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the map is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the map is full, otherwise false
+    pub fn is_full(&self) -> bool {
+        self.len == CAPACITY
+    }
+
+    fn entry(&self, node: usize) -> &Entry<K, V> {
+        debug_assert!(self.links[node].level != 0);
+        unsafe { self.entries[node].assume_init_ref() }
+    }
+
+    fn left(&self, node: usize) -> usize {
+        self.links[node].left
+    }
+
+    fn right(&self, node: usize) -> usize {
+        self.links[node].right
+    }
+
+    fn level(&self, node: usize) -> usize {
+        if node == NIL {
+            0
+        } else {
+            self.links[node].level
+        }
+    }
+
+    fn find(&self, key: &K) -> usize {
+        let mut node = self.root;
+        while node != NIL {
+            match key.cmp(&self.entry(node).key) {
+                core::cmp::Ordering::Less => node = self.left(node),
+                core::cmp::Ordering::Greater => node = self.right(node),
+                core::cmp::Ordering::Equal => return node,
+            }
+        }
+
+        NIL
+    }
+
+    // returns the node with the smallest key that is greater than the provided key
+    fn successor(&self, key: &K) -> usize {
+        let mut node = self.root;
+        let mut candidate = NIL;
+        while node != NIL {
+            if self.entry(node).key > *key {
+                candidate = node;
+                node = self.left(node);
+            } else {
+                node = self.right(node);
+            }
+        }
+
+        candidate
+    }
+
+    fn outermost(&self, mut node: usize, go_left: bool) -> usize {
+        loop {
+            let next = if go_left {
+                self.left(node)
+            } else {
+                self.right(node)
+            };
+            if next == NIL {
+                return node;
+            }
+            node = next;
+        }
+    }
+
+    fn skew(&mut self, node: usize) -> usize {
+        if node == NIL {
+            return NIL;
+        }
+
+        let left = self.left(node);
+        if left != NIL && self.level(left) == self.level(node) {
+            self.links[node].left = self.right(left);
+            self.links[left].right = node;
+            return left;
+        }
+
+        node
+    }
+
+    fn split(&mut self, node: usize) -> usize {
+        if node == NIL {
+            return NIL;
+        }
+
+        let right = self.right(node);
+        if right != NIL && self.level(self.right(right)) == self.level(node) {
+            self.links[node].right = self.left(right);
+            self.links[right].left = node;
+            self.links[right].level += 1;
+            return right;
+        }
+
+        node
+    }
+
+    fn insert_node(&mut self, node: usize, new_node: usize) -> usize {
+        if node == NIL {
+            return new_node;
+        }
+
+        if self.entry(new_node).key < self.entry(node).key {
+            let left = self.insert_node(self.left(node), new_node);
+            self.links[node].left = left;
+        } else {
+            let right = self.insert_node(self.right(node), new_node);
+            self.links[node].right = right;
+        }
+
+        let node = self.skew(node);
+        self.split(node)
+    }
+
+    fn remove_node(&mut self, node: usize, key: &K, removed: &mut usize) -> usize {
+        if node == NIL {
+            return NIL;
+        }
+
+        match key.cmp(&self.entry(node).key) {
+            core::cmp::Ordering::Less => {
+                let left = self.remove_node(self.left(node), key, removed);
+                self.links[node].left = left;
+            }
+            core::cmp::Ordering::Greater => {
+                let right = self.remove_node(self.right(node), key, removed);
+                self.links[node].right = right;
+            }
+            core::cmp::Ordering::Equal => {
+                if self.left(node) == NIL && self.right(node) == NIL {
+                    *removed = node;
+                    return NIL;
+                }
+
+                // the entry is swapped with its direct neighbor in a subtree, where it stays
+                // the outermost key and is removed from there
+                if self.left(node) == NIL {
+                    let successor = self.outermost(self.right(node), true);
+                    self.entries.swap(node, successor);
+                    let right = self.remove_node(self.right(node), key, removed);
+                    self.links[node].right = right;
+                } else {
+                    let predecessor = self.outermost(self.left(node), false);
+                    self.entries.swap(node, predecessor);
+                    let left = self.remove_node(self.left(node), key, removed);
+                    self.links[node].left = left;
+                }
+            }
+        }
+
+        self.rebalance_after_remove(node)
+    }
+
+    fn rebalance_after_remove(&mut self, node: usize) -> usize {
+        let should_be = self
+            .level(self.left(node))
+            .min(self.level(self.right(node)))
+            + 1;
+        if should_be < self.level(node) {
+            self.links[node].level = should_be;
+            let right = self.right(node);
+            if should_be < self.level(right) {
+                self.links[right].level = should_be;
+            }
+        }
+
+        let node = self.skew(node);
+        let right = self.skew(self.right(node));
+        self.links[node].right = right;
+        if right != NIL {
+            let right_right = self.skew(self.right(right));
+            self.links[right].right = right_right;
+        }
+        let node = self.split(node);
+        let right = self.split(self.right(node));
+        self.links[node].right = right;
+
+        node
+    }
+
+    /// Inserts a key-value pair into the map. If the key was already present, its value is
+    /// replaced and the old value is returned. If the map is full and does not contain the key
+    /// [`FixedSizeOrderedMapInsertError::ExceedsCapacity`] is returned.
+    pub fn insert(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<Option<V>, FixedSizeOrderedMapInsertError> {
+        if let Some(old_value) = self.get_mut(&key) {
+            return Ok(Some(core::mem::replace(old_value, value)));
+        }
+
+        let new_node = self.free_list_head;
+        if new_node == NIL {
+            return Err(FixedSizeOrderedMapInsertError::ExceedsCapacity);
+        }
+
+        self.free_list_head = self.right(new_node);
+        self.links[new_node] = Link {
+            left: NIL,
+            right: NIL,
+            level: 1,
+        };
+        self.entries[new_node].write(Entry { key, value });
+        self.root = self.insert_node(self.root, new_node);
+        self.len += 1;
+
+        Ok(None)
+    }
+
+    /// Returns a reference to the value of the key. If the key is not present [`None`] is
+    /// returned.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        match self.find(key) {
+            NIL => None,
+            node => Some(&self.entry(node).value),
+        }
+    }
+
+    /// Returns a mutable reference to the value of the key. If the key is not present [`None`]
+    /// is returned.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        match self.find(key) {
+            NIL => None,
+            node => Some(&mut unsafe { self.entries[node].assume_init_mut() }.value),
+        }
+    }
+
+    /// Returns true if the key is present, otherwise false.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.find(key) != NIL
+    }
+
+    /// Removes the key from the map and returns its value. If the key is not present [`None`]
+    /// is returned.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        if !self.contains_key(key) {
+            return None;
+        }
+
+        let mut removed = NIL;
+        self.root = self.remove_node(self.root, key, &mut removed);
+        debug_assert!(removed != NIL);
+
+        self.links[removed] = Link {
+            left: NIL,
+            right: self.free_list_head,
+            level: 0,
+        };
+        self.free_list_head = removed;
+        self.len -= 1;
+
+        let entry = unsafe { self.entries[removed].assume_init_read() };
+        Some(entry.value)
+    }
+
+    /// Removes all key-value pairs from the map.
+    pub fn clear(&mut self) {
+        self.drop_entries();
+        self.links = Self::initial_links();
+        self.free_list_head = Self::initial_free_list_head();
+        self.root = NIL;
+        self.len = 0;
+    }
+
+    /// Returns the key-value pair with the smallest key. If the map is empty [`None`] is
+    /// returned.
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        if self.root == NIL {
+            return None;
+        }
+
+        let entry = self.entry(self.outermost(self.root, true));
+        Some((&entry.key, &entry.value))
+    }
+
+    /// Returns the key-value pair with the greatest key. If the map is empty [`None`] is
+    /// returned.
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        if self.root == NIL {
+            return None;
+        }
+
+        let entry = self.entry(self.outermost(self.root, false));
+        Some((&entry.key, &entry.value))
+    }
+
+    /// Returns an iterator over all key-value pairs in ascending order of their keys.
+    pub fn iter(&self) -> Iter<'_, K, V, CAPACITY> {
+        Iter {
+            map: self,
+            next: if self.root == NIL {
+                NIL
+            } else {
+                self.outermost(self.root, true)
+            },
+        }
+    }
+}
@@ -106,6 +106,7 @@ use core::{alloc::Layout, fmt::Debug, mem::MaybeUninit};
 use iceoryx2_bb_elementary::allocator::{AllocationError, BaseAllocator};
 use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
 use iceoryx2_bb_elementary::math::unaligned_mem_size;
+#[cfg(feature = "std")]
 use iceoryx2_bb_elementary::owning_pointer::{GenericOwningPointer, OwningPointer};
 use iceoryx2_bb_elementary::placement_default::PlacementDefault;
 use iceoryx2_bb_elementary::pointer_trait::PointerTrait;
@@ -113,9 +114,12 @@ pub use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_elementary::relocatable_ptr::{GenericRelocatablePointer, RelocatablePointer};
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+#[cfg(feature = "serde")]
+use serde::{de::Visitor, Deserialize, Serialize};
 
 /// Queue with run-time fixed size capacity. In contrast to its counterpart the
 /// [`RelocatableQueue`] it is movable but is not shared memory compatible.
+#[cfg(feature = "std")]
 pub type Queue<T> = details::MetaQueue<T, GenericOwningPointer>;
 /// **Non-movable** relocatable queue with runtime fixed size capacity.
 pub type RelocatableQueue<T> = details::MetaQueue<T, GenericRelocatablePointer>;
@@ -139,6 +143,7 @@ pub mod details {
 
     unsafe impl<T: Send, Ptr: GenericPointer> Send for MetaQueue<T, Ptr> {}
 
+    #[cfg(feature = "std")]
     impl<T> MetaQueue<T, GenericOwningPointer> {
         /// Creates a new [`Queue`] with the provided capacity
         pub fn new(capacity: usize) -> Self {
@@ -362,6 +367,15 @@ pub mod details {
             while self.pop_impl().is_some() {}
         }
 
+        #[cfg(feature = "serde")]
+        pub(crate) unsafe fn get_ref_unchecked(&self, index: usize) -> &T {
+            self.verify_init("get_ref_unchecked()");
+
+            let index = (self.start - self.len + index) % self.capacity;
+
+            (*self.data_ptr.as_ptr().add(index)).assume_init_ref()
+        }
+
         pub(crate) unsafe fn peek_mut_impl(&mut self) -> Option<&mut T> {
             self.verify_init("peek_mut()");
 
@@ -570,3 +584,72 @@ impl<T: Copy + Debug, const CAPACITY: usize> FixedSizeQueue<T, CAPACITY> {
         self.state.get(index)
     }
 }
+
+#[cfg(feature = "serde")]
+impl<T: Serialize, const CAPACITY: usize> Serialize for FixedSizeQueue<T, CAPACITY> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for i in 0..self.len() {
+            seq.serialize_element(unsafe { self.state.get_ref_unchecked(i) })?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+struct FixedSizeQueueVisitor<T, const CAPACITY: usize> {
+    _value: PhantomData<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>, const CAPACITY: usize> Visitor<'de>
+    for FixedSizeQueueVisitor<T, CAPACITY>
+{
+    type Value = FixedSizeQueue<T, CAPACITY>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let str = format!(
+            "an array of at most {} elements of type {}",
+            CAPACITY,
+            core::any::type_name::<T>()
+        );
+        formatter.write_str(&str)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut new_queue = Self::Value::new();
+
+        while let Some(element) = seq.next_element()? {
+            if !new_queue.push(element) {
+                return Err(<A::Error as serde::de::Error>::custom(format!(
+                    "the queue can hold at most {} elements",
+                    CAPACITY
+                )));
+            }
+        }
+
+        Ok(new_queue)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>, const CAPACITY: usize> Deserialize<'de>
+    for FixedSizeQueue<T, CAPACITY>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(FixedSizeQueueVisitor::<T, CAPACITY> {
+            _value: PhantomData,
+        })
+    }
+}
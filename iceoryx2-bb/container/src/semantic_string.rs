@@ -59,11 +59,57 @@
 //!   }
 //! }
 //! ```
+//!
+//! # Example, create a string that can be defined as constant
+//!
+//! When `invalid_content` and `invalid_characters` are `const fn`s, the macro can additionally
+//! generate the `const fn from_static()` constructor. Constants are then validated at compile
+//! time.
+//!
+//! ```
+//! pub use iceoryx2_bb_container::semantic_string::SemanticString;
+//!
+//! use core::hash::{Hash, Hasher};
+//! use iceoryx2_bb_container::semantic_string;
+//!
+//! const fn invalid_content(string: &[u8]) -> bool {
+//!     string.is_empty()
+//! }
+//!
+//! const fn invalid_characters(string: &[u8]) -> bool {
+//!     let mut i = 0;
+//!     while i < string.len() {
+//!         if !string[i].is_ascii_lowercase() {
+//!             return true;
+//!         }
+//!         i += 1;
+//!     }
+//!
+//!     false
+//! }
+//!
+//! semantic_string! {
+//!   name: LowerCaseName,
+//!   capacity: 16,
+//!   invalid_content: invalid_content,
+//!   invalid_characters: invalid_characters,
+//!   normalize: |this: &LowerCaseName| {
+//!       *this
+//!   },
+//!   const_validation: true
+//! }
+//!
+//! const NAME: LowerCaseName = LowerCaseName::from_static("fuu");
+//! assert_eq!(NAME.as_bytes(), b"fuu");
+//!
+//! // fails to compile
+//! // const INVALID_NAME: LowerCaseName = LowerCaseName::from_static("Fuu");
+//! ```
 
 use crate::byte_string::FixedSizeByteStringModificationError;
-use crate::byte_string::{as_escaped_string, strnlen, FixedSizeByteString};
+use crate::byte_string::{strnlen, EscapedBytes, FixedSizeByteString};
 use core::fmt::{Debug, Display};
-use core::hash::Hash;
+use core::hash::{Hash, Hasher};
 use core::ops::Deref;
 use iceoryx2_bb_log::fail;
 
@@ -84,12 +130,23 @@ impl From<FixedSizeByteStringModificationError> for SemanticStringError {
 
 impl core::fmt::Display for SemanticStringError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        std::write!(f, "SemanticStringError::{:?}", self)
+        core::write!(f, "SemanticStringError::{:?}", self)
     }
 }
 
 impl core::error::Error for SemanticStringError {}
 
+/// Defines how the [`SemanticString::normalize()`]d contents of two [`SemanticString`]s are
+/// compared. It is used by the [`PartialEq`] and [`Hash`] implementations.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ComparisonPolicy {
+    /// The contents are equal when all bytes are equal.
+    CaseSensitive,
+    /// The contents are equal when all bytes are equal when the case of ASCII letters is
+    /// ignored, like paths on windows.
+    AsciiCaseInsensitive,
+}
+
 #[doc(hidden)]
 pub mod internal {
     use super::*;
@@ -115,6 +172,9 @@ pub trait SemanticString<const CAPACITY: usize>:
     + Eq
     + Hash
 {
+    /// Defines how the normalized contents are compared, see [`SemanticString::semantic_eq()`].
+    const COMPARISON_POLICY: ComparisonPolicy = ComparisonPolicy::CaseSensitive;
+
     /// Returns a reference to the underlying [`FixedSizeByteString`]
     fn as_string(&self) -> &FixedSizeByteString<CAPACITY>;
 
@@ -127,7 +187,7 @@ pub trait SemanticString<const CAPACITY: usize>:
         let mut new_self =
             unsafe { <Self as internal::SemanticStringAccessor<CAPACITY>>::new_empty() };
         fail!(from origin, when new_self.push_bytes(value),
-            "{} due to an invalid value \"{}\".", msg, as_escaped_string(value));
+            "{} due to an invalid value \"{}\".", msg, EscapedBytes(value));
 
         Ok(new_self)
     }
@@ -213,13 +273,13 @@ pub trait SemanticString<const CAPACITY: usize>:
         fail!(from self, when unsafe { self.get_mut_string().insert_bytes(idx, bytes) },
                 with SemanticStringError::ExceedsMaximumLength,
                     "{} \"{}\" since it would exceed the maximum allowed length of {}.",
-                        msg, as_escaped_string(bytes), CAPACITY);
+                        msg, EscapedBytes(bytes), CAPACITY);
 
         if Self::is_invalid_content(self.as_bytes()) {
             unsafe { self.get_mut_string().remove_range(idx, bytes.len()) };
             fail!(from self, with SemanticStringError::InvalidContent,
                 "{} \"{}\" since it would result in an illegal content.",
-                msg, as_escaped_string(bytes));
+                msg, EscapedBytes(bytes));
         }
 
         Ok(())
@@ -243,6 +303,42 @@ pub trait SemanticString<const CAPACITY: usize>:
     /// same semantic content but different representation compare as equal.
     fn normalize(&self) -> Self;
 
+    /// Returns true when both strings have the same semantic content. Both are
+    /// [`SemanticString::normalize()`]d and compared according to the
+    /// [`SemanticString::COMPARISON_POLICY`].
+    fn semantic_eq(&self, other: &Self) -> bool {
+        match Self::COMPARISON_POLICY {
+            ComparisonPolicy::CaseSensitive => {
+                self.normalize().as_bytes() == other.normalize().as_bytes()
+            }
+            ComparisonPolicy::AsciiCaseInsensitive => self.eq_ignore_ascii_case(other),
+        }
+    }
+
+    /// Feeds the semantic content into the [`Hasher`] so that two strings that are
+    /// [`SemanticString::semantic_eq()`] have the same hash.
+    fn semantic_hash<H: Hasher>(&self, state: &mut H) {
+        let normalized = self.normalize();
+        match Self::COMPARISON_POLICY {
+            ComparisonPolicy::CaseSensitive => normalized.as_bytes().hash(state),
+            ComparisonPolicy::AsciiCaseInsensitive => {
+                state.write_usize(normalized.len());
+                for byte in normalized.as_bytes() {
+                    state.write_u8(byte.to_ascii_lowercase());
+                }
+            }
+        }
+    }
+
+    /// Returns true when both [`SemanticString::normalize()`]d strings are equal when the
+    /// case of ASCII letters is ignored, independent of the
+    /// [`SemanticString::COMPARISON_POLICY`].
+    fn eq_ignore_ascii_case(&self, other: &Self) -> bool {
+        self.normalize()
+            .as_bytes()
+            .eq_ignore_ascii_case(other.normalize().as_bytes())
+    }
+
     /// Removes the last character. If the string is empty it returns [`None`].
     /// If the removal would create an illegal content it fails.
     fn pop(&mut self) -> Result<Option<u8>, SemanticStringError> {
@@ -369,38 +465,89 @@ pub trait SemanticString<const CAPACITY: usize>:
         unsafe { self.get_mut_string().truncate(new_len) };
         Ok(())
     }
-}
 
-/// Helper macro to create a new [`SemanticString`]. Usage example can be found here:
-/// [`mod@crate::semantic_string`].
-#[macro_export(local_inner_macros)]
-macro_rules! semantic_string {
-    {$(#[$documentation:meta])*
-     /// Name of the struct
-     name: $string_name:ident,
-     /// Capacity of the underlying FixedSizeByteString
-     capacity: $capacity:expr,
-     /// Callable that gets a [`&[u8]`] as input and shall return true when the slice contains
-     /// invalid content.
-     invalid_content: $invalid_content:expr,
-     /// Callable that gets a [`&[u8]`] as input and shall return true when the slice contains
-     /// invalid characters.
-     invalid_characters: $invalid_characters:expr,
-     /// Normalizes the content. Required when the same semantical content has multiple
-     /// representations like paths for instance (`/tmp` == `/tmp/`)
-     normalize: $normalize:expr} => {
-        $(#[$documentation])*
-        #[derive(Debug, Clone, Copy, Eq)]
-        pub struct $string_name {
-            value: iceoryx2_bb_container::byte_string::FixedSizeByteString<$capacity>
+    /// Returns the contents as a string slice. The contents of a [`SemanticString`] are
+    /// always valid UTF-8.
+    fn as_str(&self) -> &str {
+        // every constructor and modification verifies that the content is valid UTF-8
+        unsafe { core::str::from_utf8_unchecked(self.as_bytes()) }
+    }
+
+    /// Returns an iterator over the UTF-8 characters of the string.
+    fn chars(&self) -> core::str::Chars<'_> {
+        self.as_str().chars()
+    }
+
+    /// Returns the number of UTF-8 characters. It is less or equal than [`SemanticString::len()`]
+    /// which returns the number of bytes.
+    fn char_len(&self) -> usize {
+        self.chars().count()
+    }
+
+    /// Returns true when the byte position idx is the start of a UTF-8 character or the end of
+    /// the string, otherwise false.
+    fn is_char_boundary(&self, idx: usize) -> bool {
+        self.as_str().is_char_boundary(idx)
+    }
+
+    /// Inserts a UTF-8 character at the byte position idx. When idx is not a character boundary,
+    /// the capacity is exceeded, the character is illegal or the content would result in an
+    /// illegal content it fails.
+    fn insert_char(&mut self, idx: usize, ch: char) -> Result<(), SemanticStringError> {
+        if !self.is_char_boundary(idx) {
+            fail!(from self, with SemanticStringError::InvalidContent,
+                "Unable to insert character '{}' at position {} since it is not a character boundary.",
+                ch.escape_debug(), idx);
         }
 
-        // BEGIN: serde
+        self.insert_bytes(idx, ch.encode_utf8(&mut [0u8; 4]).as_bytes())
+    }
+
+    /// Adds a UTF-8 character at the end. When the capacity is exceeded, the character is
+    /// illegal or the content would result in an illegal content it fails.
+    fn push_char(&mut self, ch: char) -> Result<(), SemanticStringError> {
+        self.insert_char(self.len(), ch)
+    }
+
+    /// Removes the last UTF-8 character. If the string is empty it returns [`None`].
+    /// If the removal would create an illegal content it fails.
+    fn pop_char(&mut self) -> Result<Option<char>, SemanticStringError> {
+        let ch = match self.chars().next_back() {
+            Some(ch) => ch,
+            None => return Ok(None),
+        };
+
+        self.remove_range(self.len() - ch.len_utf8(), ch.len_utf8())?;
+        Ok(Some(ch))
+    }
+
+    /// Truncates the string to at most max_len bytes. When max_len is not a character boundary
+    /// the string is truncated to the start of the character that contains max_len so that no
+    /// character is split. If the truncation would create an illegal content it fails.
+    fn truncate_to_char_boundary(&mut self, max_len: usize) -> Result<(), SemanticStringError> {
+        if max_len >= self.len() {
+            return Ok(());
+        }
+
+        let mut new_len = max_len;
+        while !self.is_char_boundary(new_len) {
+            new_len -= 1;
+        }
+
+        self.truncate(new_len)
+    }
+}
+
+#[doc(hidden)]
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! semantic_string_serde {
+    ($string_name:ident) => {
         pub(crate) mod VisitorType {
             pub(crate) struct $string_name;
         }
 
-        impl<'de> serde::de::Visitor<'de> for VisitorType::$string_name {
+        impl<'de> iceoryx2_bb_container::serde::de::Visitor<'de> for VisitorType::$string_name {
             type Value = $string_name;
 
             fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
@@ -409,35 +556,129 @@ macro_rules! semantic_string {
 
             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
             where
-                E: serde::de::Error,
+                E: iceoryx2_bb_container::serde::de::Error,
             {
                 match $string_name::new(v.as_bytes()) {
                     Ok(v) => Ok(v),
-                    Err(v) => Err(E::custom(std::format!("invalid {} provided {:?}.", std::stringify!($string_name), v))),
+                    Err(v) => Err(E::custom(std::format!(
+                        "invalid {} provided {:?}.",
+                        std::stringify!($string_name),
+                        v
+                    ))),
                 }
             }
         }
 
-        impl<'de> serde::Deserialize<'de> for $string_name {
+        impl<'de> iceoryx2_bb_container::serde::Deserialize<'de> for $string_name {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
-                D: serde::Deserializer<'de>,
+                D: iceoryx2_bb_container::serde::Deserializer<'de>,
             {
                 deserializer.deserialize_str(VisitorType::$string_name)
             }
         }
 
-        impl serde::Serialize for $string_name {
+        impl iceoryx2_bb_container::serde::Serialize for $string_name {
             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
             where
-                S: serde::Serializer,
+                S: iceoryx2_bb_container::serde::Serializer,
             {
                 serializer.serialize_str(core::str::from_utf8(self.as_bytes()).unwrap())
             }
         }
-        // END: serde
+    };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+macro_rules! semantic_string_serde {
+    ($string_name:ident) => {};
+}
+
+/// Helper macro to create a new [`SemanticString`]. Usage example can be found here:
+/// [`mod@crate::semantic_string`].
+#[macro_export(local_inner_macros)]
+macro_rules! semantic_string {
+    {$(#[$documentation:meta])*
+     name: $string_name:ident,
+     capacity: $capacity:expr,
+     invalid_content: $invalid_content:expr,
+     invalid_characters: $invalid_characters:expr,
+     normalize: $normalize:expr
+     $(, comparison_policy: $comparison_policy:expr)?,
+     /// When set, `invalid_content` and `invalid_characters` must be `const fn`s and the
+     /// `const fn from_static()` constructor is generated.
+     const_validation: true} => {
+        semantic_string! {
+            $(#[$documentation])*
+            name: $string_name,
+            capacity: $capacity,
+            invalid_content: $invalid_content,
+            invalid_characters: $invalid_characters,
+            normalize: $normalize
+            $(, comparison_policy: $comparison_policy)?
+        }
+
+        impl $string_name {
+            /// Creates a new instance from a string literal. The content is validated when
+            /// the function is evaluated, therefore it shall be used to define constants.
+            /// An invalid content results in a compilation error instead of a runtime failure.
+            ///
+            /// # Panics
+            ///
+            ///  * When the value exceeds the capacity or contains invalid characters or
+            ///    content and the function is not called in a const context.
+            ///
+            pub const fn from_static(value: &'static str) -> Self {
+                let bytes = value.as_bytes();
+                if bytes.len() > $capacity {
+                    core::panic!("The value exceeds the capacity of the semantic string.");
+                }
+
+                if $invalid_characters(bytes) {
+                    core::panic!("The value contains invalid characters.");
+                }
+
+                if $invalid_content(bytes) {
+                    core::panic!("The value is an invalid content.");
+                }
+
+                Self {
+                    value: iceoryx2_bb_container::byte_string::FixedSizeByteString::from_bytes_truncated(bytes),
+                }
+            }
+        }
+    };
+    {$(#[$documentation:meta])*
+     /// Name of the struct
+     name: $string_name:ident,
+     /// Capacity of the underlying FixedSizeByteString
+     capacity: $capacity:expr,
+     /// Callable that gets a [`&[u8]`] as input and shall return true when the slice contains
+     /// invalid content.
+     invalid_content: $invalid_content:expr,
+     /// Callable that gets a [`&[u8]`] as input and shall return true when the slice contains
+     /// invalid characters.
+     invalid_characters: $invalid_characters:expr,
+     /// Normalizes the content. Required when the same semantical content has multiple
+     /// representations like paths for instance (`/tmp` == `/tmp/`)
+     normalize: $normalize:expr
+     // optional [`ComparisonPolicy`] of the normalized content, the default is
+     // [`ComparisonPolicy::CaseSensitive`]
+     $(, comparison_policy: $comparison_policy:expr)?} => {
+        $(#[$documentation])*
+        #[derive(Debug, Clone, Copy, Eq)]
+        pub struct $string_name {
+            value: iceoryx2_bb_container::byte_string::FixedSizeByteString<$capacity>
+        }
+
+        semantic_string_serde!($string_name);
 
         impl iceoryx2_bb_container::semantic_string::SemanticString<$capacity> for $string_name {
+            $(const COMPARISON_POLICY: iceoryx2_bb_container::semantic_string::ComparisonPolicy =
+                $comparison_policy;)?
+
             fn as_string(&self) -> &iceoryx2_bb_container::byte_string::FixedSizeByteString<$capacity> {
                 &self.value
             }
@@ -466,13 +707,13 @@ macro_rules! semantic_string {
 
         impl core::fmt::Display for $string_name {
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                std::write!(f, "{}", self.value)
+                core::write!(f, "{}", self.value)
             }
         }
 
         impl Hash for $string_name {
             fn hash<H: Hasher>(&self, state: &mut H) {
-                self.normalize().as_bytes().hash(state)
+                self.semantic_hash(state)
             }
         }
 
@@ -500,7 +741,7 @@ macro_rules! semantic_string {
 
         impl PartialEq<$string_name> for $string_name {
             fn eq(&self, other: &$string_name) -> bool {
-                *self.normalize().as_bytes() == *other.normalize().as_bytes()
+                self.semantic_eq(other)
             }
         }
 
@@ -51,6 +51,7 @@ use crate::vec::details::MetaVec;
 use crate::{queue::RelocatableQueue, vec::RelocatableVec};
 use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
 use iceoryx2_bb_elementary::generic_pointer::GenericPointer;
+#[cfg(feature = "std")]
 use iceoryx2_bb_elementary::owning_pointer::GenericOwningPointer;
 use iceoryx2_bb_elementary::placement_default::PlacementDefault;
 use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
@@ -82,6 +83,7 @@ struct FreeListEntry {
 
 /// A runtime fixed-size, non-shared memory compatible [`SlotMap`]. The [`SlotMap`]s memory resides
 /// in the heap.
+#[cfg(feature = "std")]
 pub type SlotMap<T> = details::MetaSlotMap<T, GenericOwningPointer>;
 
 /// A runtime fixed-size, shared-memory compatible [`RelocatableSlotMap`].
@@ -101,6 +103,7 @@ pub mod details {
         key: SlotMapKey,
     }
 
+    #[cfg(feature = "std")]
     pub type OwningIter<'slotmap, T> = Iter<'slotmap, T, GenericOwningPointer>;
     pub type RelocatableIter<'slotmap, T> = Iter<'slotmap, T, GenericRelocatablePointer>;
 
@@ -172,7 +175,7 @@ pub mod details {
             }
         }
 
-        pub(crate) unsafe fn iter_impl(&self) -> Iter<T, Ptr> {
+        pub(crate) unsafe fn iter_impl(&self) -> Iter<'_, T, Ptr> {
             self.verify_init("iter()");
             Iter {
                 slotmap: self,
@@ -371,6 +374,7 @@ pub mod details {
         }
     }
 
+    #[cfg(feature = "std")]
     impl<T> MetaSlotMap<T, GenericOwningPointer> {
         /// Creates a new runtime-fixed size [`SlotMap`] on the heap with the given capacity.
         pub fn new(capacity: usize) -> Self {
@@ -388,7 +392,7 @@ pub mod details {
         }
 
         /// Returns the [`Iter`]ator to iterate over all entries.
-        pub fn iter(&self) -> OwningIter<T> {
+        pub fn iter(&self) -> OwningIter<'_, T> {
             unsafe { self.iter_impl() }
         }
 
@@ -471,7 +475,7 @@ pub mod details {
         ///
         ///  * [`RelocatableSlotMap::init()`] must be called once before
         ///
-        pub unsafe fn iter(&self) -> RelocatableIter<T> {
+        pub unsafe fn iter(&self) -> RelocatableIter<'_, T> {
             self.iter_impl()
         }
 
@@ -626,7 +630,7 @@ impl<T, const CAPACITY: usize> FixedSizeSlotMap<T, CAPACITY> {
     }
 
     /// Returns the [`details::RelocatableIter`]ator to iterate over all entries.
-    pub fn iter(&self) -> details::RelocatableIter<T> {
+    pub fn iter(&self) -> details::RelocatableIter<'_, T> {
         unsafe { self.state.iter_impl() }
     }
 
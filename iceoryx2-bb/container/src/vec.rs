@@ -87,32 +87,37 @@ use core::{
     alloc::Layout,
     marker::PhantomData,
     mem::MaybeUninit,
-    ops::{Deref, DerefMut},
+    ops::{Bound, Deref, DerefMut, RangeBounds},
     sync::atomic::Ordering,
 };
 
 use iceoryx2_bb_elementary::generic_pointer::GenericPointer;
+#[cfg(feature = "std")]
+use iceoryx2_bb_elementary::owning_pointer::{GenericOwningPointer, OwningPointer};
 use iceoryx2_bb_elementary::{
-    bump_allocator::BumpAllocator, owning_pointer::GenericOwningPointer,
-    relocatable_ptr::GenericRelocatablePointer,
+    bump_allocator::BumpAllocator, relocatable_ptr::GenericRelocatablePointer,
 };
 use iceoryx2_bb_elementary::{
-    math::unaligned_mem_size, owning_pointer::OwningPointer, placement_default::PlacementDefault,
-    pointer_trait::PointerTrait, relocatable_container::RelocatableContainer,
-    relocatable_ptr::RelocatablePointer,
+    math::unaligned_mem_size, placement_default::PlacementDefault, pointer_trait::PointerTrait,
+    relocatable_container::RelocatableContainer, relocatable_ptr::RelocatablePointer,
 };
 
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+#[cfg(feature = "serde")]
 use serde::{de::Visitor, Deserialize, Serialize};
 
 /// Vector with run-time fixed size capacity. In contrast to its counterpart the
 /// [`RelocatableVec`] it is movable but is not shared memory compatible.
+#[cfg(feature = "std")]
 pub type Vec<T> = details::MetaVec<T, GenericOwningPointer>;
 
 /// **Non-movable** relocatable vector with runtime fixed size capacity.
 pub type RelocatableVec<T> = details::MetaVec<T, GenericRelocatablePointer>;
 
+/// Draining iterator of a [`FixedSizeVec`], see [`FixedSizeVec::drain()`].
+pub type Drain<'a, T> = details::Drain<'a, T, GenericRelocatablePointer>;
+
 #[doc(hidden)]
 pub mod details {
     use super::*;
@@ -122,8 +127,8 @@ pub mod details {
     #[derive(Debug)]
     pub struct MetaVec<T, Ptr: GenericPointer> {
         data_ptr: Ptr::Type<MaybeUninit<T>>,
-        capacity: usize,
-        len: usize,
+        capacity: u64,
+        len: u64,
         is_initialized: IoxAtomicBool,
         _phantom_data: PhantomData<T>,
     }
@@ -145,7 +150,7 @@ pub mod details {
         unsafe fn new_uninit(capacity: usize) -> Self {
             Self {
                 data_ptr: RelocatablePointer::new_uninit(),
-                capacity,
+                capacity: capacity as u64,
                 len: 0,
                 is_initialized: IoxAtomicBool::new(false),
                 _phantom_data: PhantomData,
@@ -162,7 +167,7 @@ pub mod details {
 
             self.data_ptr.init(fail!(from "Queue::init", when allocator
                  .allocate(Layout::from_size_align_unchecked(
-                     core::mem::size_of::<T>() * self.capacity,
+                     core::mem::size_of::<T>() * self.capacity(),
                      core::mem::align_of::<T>(),
                  )), "Failed to initialize queue since the allocation of the data memory failed."
             ));
@@ -182,7 +187,7 @@ pub mod details {
 
         fn deref(&self) -> &Self::Target {
             self.verify_init("deref()");
-            unsafe { core::slice::from_raw_parts((*self.data_ptr.as_ptr()).as_ptr(), self.len) }
+            unsafe { core::slice::from_raw_parts((*self.data_ptr.as_ptr()).as_ptr(), self.len()) }
         }
     }
 
@@ -192,7 +197,7 @@ pub mod details {
             unsafe {
                 core::slice::from_raw_parts_mut(
                     (*self.data_ptr.as_mut_ptr()).as_mut_ptr(),
-                    self.len,
+                    self.len(),
                 )
             }
         }
@@ -229,12 +234,12 @@ pub mod details {
 
         /// Returns the capacity of the vector
         pub fn capacity(&self) -> usize {
-            self.capacity
+            self.capacity as usize
         }
 
         /// Returns the number of elements stored inside the vector
         pub fn len(&self) -> usize {
-            self.len
+            self.len as usize
         }
 
         /// Returns true if the vector is empty, otherwise false
@@ -261,13 +266,13 @@ pub mod details {
         where
             T: Clone,
         {
-            for _ in self.len..self.capacity {
+            for _ in self.len()..self.capacity() {
                 self.push_unchecked(value.clone());
             }
         }
 
         unsafe fn fill_with_impl<F: FnMut() -> T>(&mut self, mut f: F) {
-            for _ in self.len..self.capacity {
+            for _ in self.len()..self.capacity() {
                 self.push_unchecked(f());
             }
         }
@@ -276,7 +281,7 @@ pub mod details {
             unsafe {
                 self.data_ptr
                     .as_mut_ptr()
-                    .add(self.len)
+                    .add(self.len())
                     .write(MaybeUninit::new(value))
             };
 
@@ -287,7 +292,7 @@ pub mod details {
         where
             T: Clone,
         {
-            if self.capacity < self.len + other.len() {
+            if self.capacity() < self.len() + other.len() {
                 return false;
             }
 
@@ -308,14 +313,14 @@ pub mod details {
         }
 
         unsafe fn clear_impl(&mut self) {
-            for _ in 0..self.len {
+            for _ in 0..self.len() {
                 self.pop_unchecked();
             }
         }
 
         fn pop_unchecked(&mut self) -> T {
             let value = core::mem::replace(
-                unsafe { &mut *self.data_ptr.as_mut_ptr().offset(self.len as isize - 1) },
+                unsafe { &mut *self.data_ptr.as_mut_ptr().offset(self.len() as isize - 1) },
                 MaybeUninit::uninit(),
             );
             self.len -= 1;
@@ -324,20 +329,175 @@ pub mod details {
         }
 
         unsafe fn as_slice_impl(&self) -> &[T] {
-            unsafe { core::slice::from_raw_parts(self.data_ptr.as_ptr().cast(), self.len) }
+            unsafe { core::slice::from_raw_parts(self.data_ptr.as_ptr().cast(), self.len()) }
         }
 
         unsafe fn as_mut_slice_impl(&mut self) -> &mut [T] {
-            unsafe { core::slice::from_raw_parts_mut(self.data_ptr.as_mut_ptr().cast(), self.len) }
+            unsafe {
+                core::slice::from_raw_parts_mut(self.data_ptr.as_mut_ptr().cast(), self.len())
+            }
+        }
+
+        pub(crate) unsafe fn insert_impl(&mut self, idx: usize, value: T) -> bool {
+            if self.len() < idx {
+                fatal_panic!(from "Vec::insert()",
+                    "Unable to insert element at position {} since the vector has only {} elements.",
+                    idx, self.len());
+            }
+
+            if self.is_full() {
+                return false;
+            }
+
+            self.verify_init("insert()");
+            let base = self.data_ptr.as_mut_ptr().cast::<T>();
+            core::ptr::copy(base.add(idx), base.add(idx + 1), self.len() - idx);
+            base.add(idx).write(value);
+            self.len += 1;
+            true
+        }
+
+        pub(crate) unsafe fn remove_impl(&mut self, idx: usize) -> T {
+            if self.len() <= idx {
+                fatal_panic!(from "Vec::remove()",
+                    "Unable to remove element at position {} since the vector has only {} elements.",
+                    idx, self.len());
+            }
+
+            self.verify_init("remove()");
+            let base = self.data_ptr.as_mut_ptr().cast::<T>();
+            let value = base.add(idx).read();
+            core::ptr::copy(base.add(idx + 1), base.add(idx), self.len() - idx - 1);
+            self.len -= 1;
+            value
+        }
+
+        pub(crate) unsafe fn retain_impl<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+            self.verify_init("retain()");
+            let len = self.len();
+            // when the predicate panics the remaining elements are leaked instead of dropped twice
+            self.len = 0;
+
+            let base = self.data_ptr.as_mut_ptr().cast::<T>();
+            let mut number_of_retained_elements = 0;
+            for i in 0..len {
+                let element = base.add(i);
+                if f(&*element) {
+                    if i != number_of_retained_elements {
+                        core::ptr::copy_nonoverlapping(
+                            element,
+                            base.add(number_of_retained_elements),
+                            1,
+                        );
+                    }
+                    number_of_retained_elements += 1;
+                } else {
+                    core::ptr::drop_in_place(element);
+                }
+            }
+
+            self.len = number_of_retained_elements as u64;
+        }
+
+        pub(crate) unsafe fn drain_impl<R: RangeBounds<usize>>(
+            &mut self,
+            range: R,
+        ) -> Drain<'_, T, Ptr> {
+            let start = match range.start_bound() {
+                Bound::Included(v) => *v,
+                Bound::Excluded(v) => *v + 1,
+                Bound::Unbounded => 0,
+            };
+            let end = match range.end_bound() {
+                Bound::Included(v) => *v + 1,
+                Bound::Excluded(v) => *v,
+                Bound::Unbounded => self.len(),
+            };
+
+            if end < start || self.len() < end {
+                fatal_panic!(from "Vec::drain()",
+                    "Unable to drain the range {}..{} since the vector has only {} elements.",
+                    start, end, self.len());
+            }
+
+            self.verify_init("drain()");
+            let tail_len = self.len() - end;
+            // the drained elements and the tail are owned by the drain until it is dropped
+            self.len = start as u64;
+
+            Drain {
+                vec: self,
+                idx: start,
+                end,
+                tail_start: end,
+                tail_len,
+            }
         }
     }
 
+    /// Removes the elements of a range from the vector and returns them by value. Acquired via
+    /// [`FixedSizeVec::drain()`](crate::vec::FixedSizeVec::drain()). When it goes out of
+    /// scope, the elements that were not consumed are dropped and the remaining elements are
+    /// moved to close the gap.
+    pub struct Drain<'a, T, Ptr: GenericPointer> {
+        vec: &'a mut MetaVec<T, Ptr>,
+        idx: usize,
+        end: usize,
+        tail_start: usize,
+        tail_len: usize,
+    }
+
+    impl<T, Ptr: GenericPointer> Iterator for Drain<'_, T, Ptr> {
+        type Item = T;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.idx == self.end {
+                return None;
+            }
+
+            let value = unsafe { self.vec.data_ptr.as_ptr().cast::<T>().add(self.idx).read() };
+            self.idx += 1;
+            Some(value)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.end - self.idx, Some(self.end - self.idx))
+        }
+    }
+
+    impl<T, Ptr: GenericPointer> DoubleEndedIterator for Drain<'_, T, Ptr> {
+        fn next_back(&mut self) -> Option<Self::Item> {
+            if self.idx == self.end {
+                return None;
+            }
+
+            self.end -= 1;
+            Some(unsafe { self.vec.data_ptr.as_ptr().cast::<T>().add(self.end).read() })
+        }
+    }
+
+    impl<T, Ptr: GenericPointer> ExactSizeIterator for Drain<'_, T, Ptr> {}
+
+    impl<T, Ptr: GenericPointer> Drop for Drain<'_, T, Ptr> {
+        fn drop(&mut self) {
+            for _ in self.by_ref() {}
+
+            let len = self.vec.len();
+            unsafe {
+                let base = self.vec.data_ptr.as_mut_ptr().cast::<T>();
+                core::ptr::copy(base.add(self.tail_start), base.add(len), self.tail_len);
+            }
+            self.vec.len = (len + self.tail_len) as u64;
+        }
+    }
+
+    #[cfg(feature = "std")]
     impl<T> MetaVec<T, GenericOwningPointer> {
         /// Creates a new [`Queue`] with the provided capacity
         pub fn new(capacity: usize) -> Self {
             Self {
                 data_ptr: OwningPointer::<MaybeUninit<T>>::new_with_alloc(capacity),
-                capacity,
+                capacity: capacity as u64,
                 len: 0,
                 is_initialized: IoxAtomicBool::new(true),
                 _phantom_data: PhantomData,
@@ -498,6 +658,7 @@ pub struct FixedSizeVec<T, const CAPACITY: usize> {
     _data: [MaybeUninit<T>; CAPACITY],
 }
 
+#[cfg(feature = "serde")]
 impl<'de, T: Serialize + Deserialize<'de>, const CAPACITY: usize> Serialize
     for FixedSizeVec<T, CAPACITY>
 {
@@ -509,10 +670,12 @@ impl<'de, T: Serialize + Deserialize<'de>, const CAPACITY: usize> Serialize
     }
 }
 
+#[cfg(feature = "serde")]
 struct FixedSizeVecVisitor<T, const CAPACITY: usize> {
     _value: PhantomData<T>,
 }
 
+#[cfg(feature = "serde")]
 impl<'de, T: Deserialize<'de>, const CAPACITY: usize> Visitor<'de>
     for FixedSizeVecVisitor<T, CAPACITY>
 {
@@ -546,6 +709,7 @@ impl<'de, T: Deserialize<'de>, const CAPACITY: usize> Visitor<'de>
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de, T: Deserialize<'de>, const CAPACITY: usize> Deserialize<'de>
     for FixedSizeVec<T, CAPACITY>
 {
@@ -695,4 +859,90 @@ impl<T, const CAPACITY: usize> FixedSizeVec<T, CAPACITY> {
     pub fn as_mut_slice(&mut self) -> &mut [T] {
         unsafe { self.state.as_mut_slice() }
     }
+
+    /// Inserts an element at position `idx` and shifts all elements after it to the right.
+    /// If the vector is full it returns false, otherwise true. Panics when `idx` is greater
+    /// than [`FixedSizeVec::len()`].
+    pub fn insert(&mut self, idx: usize, value: T) -> bool {
+        unsafe { self.state.insert_impl(idx, value) }
+    }
+
+    /// Removes and returns the element at position `idx` and shifts all elements after it to
+    /// the left. Panics when `idx` is out of bounds.
+    pub fn remove(&mut self, idx: usize) -> T {
+        unsafe { self.state.remove_impl(idx) }
+    }
+
+    /// Retains only the elements for which the predicate returns true. The order of the
+    /// retained elements is preserved.
+    ///
+    /// ```
+    /// use iceoryx2_bb_container::vec::FixedSizeVec;
+    ///
+    /// let mut vec = FixedSizeVec::<u64, 8>::new();
+    /// vec.extend_from_slice(&[1, 2, 3, 4, 5]);
+    /// vec.retain(|v| v % 2 == 1);
+    /// assert_eq!(vec.as_slice(), &[1, 3, 5]);
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        unsafe { self.state.retain_impl(f) }
+    }
+
+    /// Removes the elements of the range from the vector and returns them in an iterator.
+    /// Elements that are not consumed are dropped together with the iterator. Panics when the
+    /// range is out of bounds.
+    ///
+    /// ```
+    /// use iceoryx2_bb_container::vec::FixedSizeVec;
+    ///
+    /// let mut vec = FixedSizeVec::<u64, 8>::new();
+    /// vec.extend_from_slice(&[1, 2, 3, 4, 5]);
+    /// let drained: Vec<u64> = vec.drain(1..3).collect();
+    /// assert_eq!(drained, [2, 3]);
+    /// assert_eq!(vec.as_slice(), &[1, 4, 5]);
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        unsafe { self.state.drain_impl(range) }
+    }
+
+    /// Inserts the element into a vector that is sorted in ascending order so that it stays
+    /// sorted. Equal elements are inserted after the existing ones. Returns the position of the
+    /// inserted element or [`None`] when the vector is full. Use the slice methods, like
+    /// `binary_search_by()`, to look up elements in the sorted vector.
+    ///
+    /// ```
+    /// use iceoryx2_bb_container::vec::FixedSizeVec;
+    ///
+    /// let mut vec = FixedSizeVec::<u64, 8>::new();
+    /// vec.insert_sorted(5);
+    /// vec.insert_sorted(1);
+    /// assert_eq!(vec.insert_sorted(3), Some(1));
+    /// assert_eq!(vec.as_slice(), &[1, 3, 5]);
+    /// assert_eq!(vec.binary_search_by(|v| v.cmp(&3)), Ok(1));
+    /// ```
+    pub fn insert_sorted(&mut self, value: T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        self.insert_sorted_by(value, |a, b| a.cmp(b))
+    }
+
+    /// Inserts the element into a vector that is sorted with respect to the comparator so that
+    /// it stays sorted. Equal elements are inserted after the existing ones. Returns the
+    /// position of the inserted element or [`None`] when the vector is full.
+    pub fn insert_sorted_by<F: FnMut(&T, &T) -> core::cmp::Ordering>(
+        &mut self,
+        value: T,
+        mut compare: F,
+    ) -> Option<usize> {
+        if self.is_full() {
+            return None;
+        }
+
+        let idx = self
+            .as_slice()
+            .partition_point(|element| compare(element, &value) != core::cmp::Ordering::Greater);
+        self.insert(idx, value);
+        Some(idx)
+    }
 }
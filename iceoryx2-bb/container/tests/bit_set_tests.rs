@@ -0,0 +1,103 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod fixed_size_bit_set {
+    use iceoryx2_bb_container::bit_set::*;
+    use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+    use iceoryx2_bb_testing::{assert_that, memory::RawMemory};
+
+    const SUT_CAPACITY: usize = 77;
+    type Sut = FixedSizeBitSet<SUT_CAPACITY>;
+
+    #[test]
+    fn new_bit_set_is_empty() {
+        let sut = Sut::new();
+
+        assert_that!(sut, is_empty);
+        assert_that!(sut, len 0);
+        assert_that!(sut.is_full(), eq false);
+        assert_that!(sut.capacity(), eq SUT_CAPACITY);
+        assert_that!(sut.iter().next(), is_none);
+        assert_that!(sut.find_first_unset(), eq Some(0));
+    }
+
+    #[test]
+    fn set_and_reset_works() {
+        let mut sut = Sut::new();
+
+        assert_that!(sut.set(12), eq true);
+        assert_that!(sut.set(12), eq false);
+        assert_that!(sut.is_set(12), eq true);
+        assert_that!(sut.is_set(11), eq false);
+        assert_that!(sut, len 1);
+
+        assert_that!(sut.reset(12), eq true);
+        assert_that!(sut.reset(12), eq false);
+        assert_that!(sut.is_set(12), eq false);
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_bit_outside_of_capacity_panics() {
+        let mut sut = Sut::new();
+        sut.set(SUT_CAPACITY);
+    }
+
+    #[test]
+    fn iter_returns_set_bits_in_ascending_order() {
+        let mut sut = Sut::new();
+        let ids = [0, 7, 8, 9, 31, 64, SUT_CAPACITY - 1];
+        for id in ids.iter().rev() {
+            assert_that!(sut.set(*id), eq true);
+        }
+
+        assert_that!(sut.iter().collect::<Vec<_>>(), eq ids.to_vec());
+        assert_that!(sut, len ids.len());
+    }
+
+    #[test]
+    fn find_first_unset_works() {
+        let mut sut = Sut::new();
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.find_first_unset(), eq Some(i));
+            sut.set(i);
+        }
+
+        assert_that!(sut.is_full(), eq true);
+        assert_that!(sut.find_first_unset(), eq None);
+
+        sut.reset(42);
+        assert_that!(sut.find_first_unset(), eq Some(42));
+    }
+
+    #[test]
+    fn clear_resets_all_bits() {
+        let mut sut = Sut::new();
+        for i in (0..SUT_CAPACITY).step_by(3) {
+            sut.set(i);
+        }
+
+        sut.clear();
+
+        assert_that!(sut, is_empty);
+        assert_that!(sut.iter().next(), is_none);
+    }
+
+    #[test]
+    fn placement_default_works() {
+        let sut = RawMemory::<Sut>::new_filled(0xff);
+        unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+        assert_that!(unsafe { sut.assume_init() }, is_empty);
+    }
+}
@@ -17,6 +17,7 @@ mod fixed_size_byte_string {
     use iceoryx2_bb_container::byte_string::*;
     use iceoryx2_bb_elementary::placement_default::PlacementDefault;
     use iceoryx2_bb_testing::{assert_that, memory::RawMemory};
+    #[cfg(feature = "serde")]
     use serde_test::{assert_tokens, Token};
     use std::collections::hash_map::DefaultHasher;
 
@@ -511,6 +512,7 @@ mod fixed_size_byte_string {
         assert_that!(unsafe {sut.assume_init()}.as_bytes(), eq b"hello");
     }
 
+    #[cfg(feature = "serde")]
     #[test]
     fn serialization_works() {
         let content = "Brother Hypnotoad is starring at you.";
@@ -518,4 +520,136 @@ mod fixed_size_byte_string {
 
         assert_tokens(&sut, &[Token::Str(content)]);
     }
+
+    #[test]
+    fn from_fmt_works() {
+        let sut = Sut::from_fmt(format_args!("{}-{:03}", "hello", 7)).unwrap();
+        assert_that!(sut, eq b"hello-007");
+    }
+
+    #[test]
+    fn from_fmt_fails_when_capacity_is_exceeded() {
+        let sut = FixedSizeByteString::<4>::from_fmt(format_args!("{}", 123456));
+        assert_that!(sut, eq Err(FixedSizeByteStringModificationError::InsertWouldExceedCapacity));
+    }
+
+    #[test]
+    fn write_macro_appends_to_string() {
+        use core::fmt::Write;
+
+        let mut sut = Sut::from(b"value: ");
+        assert_that!(write!(sut, "{}", 42), is_ok);
+        assert_that!(sut, eq b"value: 42");
+    }
+
+    #[test]
+    fn write_macro_does_not_modify_string_when_capacity_is_exceeded() {
+        use core::fmt::Write;
+
+        let mut sut = FixedSizeByteString::<4>::from(b"ab");
+        assert_that!(write!(sut, "{}", "cde"), is_err);
+        assert_that!(sut, eq b"ab");
+    }
+
+    #[test]
+    fn ascii_case_conversion_works() {
+        let sut = Sut::from(b"Hello World 123 \xff");
+
+        assert_that!(sut.to_ascii_uppercase(), eq b"HELLO WORLD 123 \xff");
+        assert_that!(sut.to_ascii_lowercase(), eq b"hello world 123 \xff");
+        assert_that!(sut, eq b"Hello World 123 \xff");
+    }
+
+    #[test]
+    fn split_bytes_works() {
+        let sut = Sut::from(b"a::bc::::d");
+        let parts: Vec<&[u8]> = sut.split_bytes(b"::").collect();
+
+        assert_that!(parts, eq vec![&b"a"[..], b"bc", b"", b"d"]);
+    }
+
+    #[test]
+    fn split_bytes_can_be_reversed() {
+        let sut = Sut::from(b"a::bc::::d");
+        let parts: Vec<&[u8]> = sut.split_bytes(b"::").rev().collect();
+
+        assert_that!(parts, eq vec![&b"d"[..], b"", b"bc", b"a"]);
+    }
+
+    #[test]
+    fn split_bytes_without_separator_returns_whole_string() {
+        let sut = Sut::from(b"abc");
+
+        let parts: Vec<&[u8]> = sut.split_bytes(b"/").collect();
+        assert_that!(parts, eq vec![&b"abc"[..]]);
+
+        let parts: Vec<&[u8]> = sut.split_bytes(b"").collect();
+        assert_that!(parts, eq vec![&b"abc"[..]]);
+    }
+
+    #[test]
+    fn split_bytes_of_empty_string_returns_one_empty_part() {
+        let sut = Sut::new();
+        let parts: Vec<&[u8]> = sut.split_bytes(b"/").collect();
+
+        assert_that!(parts, eq vec![&b""[..]]);
+    }
+}
+
+mod relocatable_byte_string {
+    use iceoryx2_bb_container::byte_string::*;
+    use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+    use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+    use iceoryx2_bb_testing::assert_that;
+
+    const SUT_CAPACITY: usize = 17;
+
+    #[test]
+    fn memory_size_is_at_least_capacity() {
+        assert_that!(RelocatableByteString::memory_size(SUT_CAPACITY), ge SUT_CAPACITY);
+        assert_that!(RelocatableByteString::const_memory_size(SUT_CAPACITY), ge SUT_CAPACITY);
+    }
+
+    #[test]
+    fn push_bytes_works_with_uninitialized_memory() {
+        let mut memory = [0u8; 1024];
+        let allocator = BumpAllocator::new(memory.as_mut_ptr() as usize);
+        let mut sut = unsafe { RelocatableByteString::new_uninit(SUT_CAPACITY) };
+        unsafe { assert_that!(sut.init(&allocator), is_ok) };
+
+        assert_that!(sut, is_empty);
+        assert_that!(sut.capacity(), eq SUT_CAPACITY);
+        assert_that!(unsafe { sut.push_bytes(b"hello") }, is_ok);
+        assert_that!(unsafe { sut.push(b'!') }, is_ok);
+
+        assert_that!(sut, len 6);
+        assert_that!(unsafe { sut.as_bytes() }, eq b"hello!");
+    }
+
+    #[test]
+    fn push_bytes_fails_when_capacity_is_exceeded() {
+        let mut memory = [0u8; 1024];
+        let allocator = BumpAllocator::new(memory.as_mut_ptr() as usize);
+        let mut sut = unsafe { RelocatableByteString::new_uninit(SUT_CAPACITY) };
+        unsafe { assert_that!(sut.init(&allocator), is_ok) };
+
+        assert_that!(unsafe { sut.push_bytes(&[b'x'; SUT_CAPACITY]) }, is_ok);
+        assert_that!(sut.is_full(), eq true);
+        assert_that!(unsafe { sut.push(b'y') }, eq Err(FixedSizeByteStringModificationError::InsertWouldExceedCapacity));
+        assert_that!(sut, len SUT_CAPACITY);
+    }
+
+    #[test]
+    fn clear_works() {
+        let mut memory = [0u8; 1024];
+        let allocator = BumpAllocator::new(memory.as_mut_ptr() as usize);
+        let mut sut = unsafe { RelocatableByteString::new_uninit(SUT_CAPACITY) };
+        unsafe { assert_that!(sut.init(&allocator), is_ok) };
+
+        assert_that!(unsafe { sut.push_bytes(b"fuu") }, is_ok);
+        unsafe { sut.clear() };
+
+        assert_that!(sut, is_empty);
+        assert_that!(unsafe { sut.as_bytes() }, eq b"");
+    }
 }
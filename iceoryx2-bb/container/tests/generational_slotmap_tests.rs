@@ -0,0 +1,195 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod fixed_size_generational_slotmap {
+    use iceoryx2_bb_container::generational_slotmap::*;
+    use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+    use iceoryx2_bb_testing::{assert_that, lifetime_tracker::LifetimeTracker, memory::RawMemory};
+
+    const SUT_CAPACITY: usize = 64;
+    type Sut = FixedSizeGenerationalSlotMap<usize, SUT_CAPACITY>;
+
+    #[test]
+    fn new_slotmap_is_empty() {
+        let sut = Sut::new();
+
+        assert_that!(sut, is_empty);
+        assert_that!(sut, len 0);
+        assert_that!(sut.is_full(), eq false);
+        assert_that!(sut.capacity(), eq SUT_CAPACITY);
+        assert_that!(sut.iter().next(), is_none);
+    }
+
+    #[test]
+    fn insert_until_full_works() {
+        let mut sut = Sut::new();
+        let mut keys = vec![];
+
+        for i in 0..SUT_CAPACITY {
+            let key = sut.insert(i * 3);
+            assert_that!(key, is_some);
+            keys.push(key.unwrap());
+            assert_that!(sut, len i + 1);
+        }
+
+        assert_that!(sut.is_full(), eq true);
+        assert_that!(sut.insert(0), is_none);
+
+        for (i, key) in keys.iter().enumerate() {
+            assert_that!(sut.contains(*key), eq true);
+            assert_that!(sut.get(*key), eq Some(&(i * 3)));
+        }
+    }
+
+    #[test]
+    fn get_mut_modifies_value() {
+        let mut sut = Sut::new();
+        let key = sut.insert(5).unwrap();
+
+        *sut.get_mut(key).unwrap() = 19;
+
+        assert_that!(sut.get(key), eq Some(&19));
+    }
+
+    #[test]
+    fn removed_key_is_stale() {
+        let mut sut = Sut::new();
+        let key = sut.insert(7).unwrap();
+
+        assert_that!(sut.remove(key), eq Some(7));
+        assert_that!(sut, is_empty);
+        assert_that!(sut.contains(key), eq false);
+        assert_that!(sut.get(key), is_none);
+        assert_that!(sut.get_mut(key), is_none);
+        assert_that!(sut.remove(key), is_none);
+    }
+
+    #[test]
+    fn stale_key_does_not_access_value_of_reused_slot() {
+        let mut sut = Sut::new();
+        let old_key = sut.insert(1).unwrap();
+        sut.remove(old_key);
+
+        let new_key = sut.insert(2).unwrap();
+
+        assert_that!(new_key.index(), eq old_key.index());
+        assert_that!(new_key.generation(), ne old_key.generation());
+        assert_that!(sut.get(old_key), is_none);
+        assert_that!(sut.remove(old_key), is_none);
+        assert_that!(sut.get(new_key), eq Some(&2));
+    }
+
+    #[test]
+    fn key_with_out_of_bounds_index_is_rejected() {
+        let sut = Sut::new();
+
+        assert_that!(
+            sut.get(GenerationalKey::new(SUT_CAPACITY as u32, 1)),
+            is_none
+        );
+        assert_that!(sut.get(GenerationalKey::new(u32::MAX, 1)), is_none);
+    }
+
+    #[test]
+    fn key_of_free_slot_is_rejected() {
+        let sut = Sut::new();
+
+        assert_that!(sut.get(GenerationalKey::new(0, 0)), is_none);
+    }
+
+    #[test]
+    fn key_can_be_converted_into_u64_and_back() {
+        let mut sut = Sut::new();
+        sut.insert(1);
+        let key = sut.insert(2).unwrap();
+
+        assert_that!(GenerationalKey::from_u64(key.as_u64()), eq key);
+    }
+
+    #[test]
+    fn iter_returns_all_stored_values_with_keys() {
+        let mut sut = Sut::new();
+        let mut keys = vec![];
+        for i in 0..SUT_CAPACITY {
+            keys.push(sut.insert(i).unwrap());
+        }
+
+        for key in keys.iter().step_by(2) {
+            sut.remove(*key);
+        }
+
+        let mut counter = 0;
+        for (key, value) in sut.iter() {
+            assert_that!(keys[*value], eq key);
+            assert_that!(*value % 2, eq 1);
+            counter += 1;
+        }
+        assert_that!(counter, eq SUT_CAPACITY / 2);
+    }
+
+    #[test]
+    fn clear_invalidates_all_keys() {
+        let mut sut = Sut::new();
+        let keys: Vec<_> = (0..SUT_CAPACITY).map(|i| sut.insert(i).unwrap()).collect();
+
+        sut.clear();
+
+        assert_that!(sut, is_empty);
+        for key in keys {
+            assert_that!(sut.contains(key), eq false);
+        }
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(i), is_some);
+        }
+        assert_that!(sut.is_full(), eq true);
+    }
+
+    #[test]
+    fn drops_all_objects_when_out_of_scope() {
+        let state = LifetimeTracker::start_tracking();
+        let mut sut = FixedSizeGenerationalSlotMap::<LifetimeTracker, SUT_CAPACITY>::new();
+
+        for _ in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(LifetimeTracker::new()), is_some);
+        }
+
+        assert_that!(state.number_of_living_instances(), eq SUT_CAPACITY);
+        drop(sut);
+        assert_that!(state.number_of_living_instances(), eq 0);
+    }
+
+    #[test]
+    fn remove_returns_ownership_of_object() {
+        let state = LifetimeTracker::start_tracking();
+        let mut sut = FixedSizeGenerationalSlotMap::<LifetimeTracker, SUT_CAPACITY>::new();
+        let key = sut.insert(LifetimeTracker::new()).unwrap();
+
+        let value = sut.remove(key);
+        assert_that!(state.number_of_living_instances(), eq 1);
+        drop(value);
+        assert_that!(state.number_of_living_instances(), eq 0);
+    }
+
+    #[test]
+    fn placement_default_works() {
+        let mut sut = RawMemory::<Sut>::new_filled(0xff);
+        unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+        let sut = unsafe { sut.assume_init_mut() };
+        assert_that!(*sut, len 0);
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(i), is_some);
+        }
+        assert_that!(sut.insert(0), is_none);
+    }
+}
@@ -0,0 +1,275 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod fixed_size_hash_map {
+    use std::collections::HashMap;
+
+    use iceoryx2_bb_container::hash_map::*;
+    use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
+    use iceoryx2_bb_testing::memory::RawMemory;
+
+    // a power of two so that quadratic probing reaches every slot
+    const SUT_CAPACITY: usize = 128;
+    type Sut<P> = FixedSizeHashMap<u64, u64, SUT_CAPACITY, P>;
+
+    // deterministic xorshift so that a failing sequence can be reproduced
+    struct Random(u64);
+
+    impl Random {
+        fn next(&mut self, upper_bound: u64) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0 % upper_bound
+        }
+    }
+
+    #[test]
+    fn new_map_is_empty<P: ProbingStrategy>() {
+        let sut = Sut::<P>::new();
+
+        assert_that!(sut, is_empty);
+        assert_that!(sut.is_full(), eq false);
+        assert_that!(sut, len 0);
+        assert_that!(sut.capacity(), eq SUT_CAPACITY);
+        assert_that!(sut.get(&0), is_none);
+        assert_that!(sut.iter().count(), eq 0);
+    }
+
+    #[test]
+    fn insert_until_full_works<P: ProbingStrategy>() {
+        let mut sut = Sut::<P>::new();
+
+        for i in 0..SUT_CAPACITY as u64 {
+            assert_that!(sut.is_full(), eq false);
+            assert_that!(sut.insert(i * 7, i), eq Ok(None));
+            assert_that!(sut, len i as usize + 1);
+        }
+
+        assert_that!(sut.is_full(), eq true);
+        assert_that!(sut.insert(1, 1), eq Err(FixedSizeHashMapInsertError::ExceedsCapacity));
+
+        for i in 0..SUT_CAPACITY as u64 {
+            assert_that!(sut.get(&(i * 7)), eq Some(&i));
+            assert_that!(sut.contains_key(&(i * 7)), eq true);
+        }
+        assert_that!(sut.contains_key(&1), eq false);
+    }
+
+    #[test]
+    fn insert_of_existing_key_replaces_value<P: ProbingStrategy>() {
+        let mut sut = Sut::<P>::new();
+
+        assert_that!(sut.insert(42, 1), eq Ok(None));
+        assert_that!(sut.insert(42, 2), eq Ok(Some(1)));
+
+        assert_that!(sut, len 1);
+        assert_that!(sut.get(&42), eq Some(&2));
+    }
+
+    #[test]
+    fn insert_of_existing_key_in_full_map_works<P: ProbingStrategy>() {
+        let mut sut = Sut::<P>::new();
+
+        for i in 0..SUT_CAPACITY as u64 {
+            assert_that!(sut.insert(i, i), eq Ok(None));
+        }
+
+        for i in 0..SUT_CAPACITY as u64 {
+            assert_that!(sut.insert(i, i + 1), eq Ok(Some(i)));
+        }
+        assert_that!(sut, len SUT_CAPACITY);
+    }
+
+    #[test]
+    fn get_mut_modifies_value<P: ProbingStrategy>() {
+        let mut sut = Sut::<P>::new();
+
+        assert_that!(sut.get_mut(&5), is_none);
+        assert_that!(sut.insert(5, 6), eq Ok(None));
+        *sut.get_mut(&5).unwrap() = 7;
+
+        assert_that!(sut.get(&5), eq Some(&7));
+    }
+
+    #[test]
+    fn remove_works<P: ProbingStrategy>() {
+        let mut sut = Sut::<P>::new();
+
+        assert_that!(sut.remove(&3), is_none);
+        assert_that!(sut.insert(3, 4), eq Ok(None));
+        assert_that!(sut.insert(5, 6), eq Ok(None));
+
+        assert_that!(sut.remove(&3), eq Some(4));
+        assert_that!(sut.remove(&3), is_none);
+        assert_that!(sut, len 1);
+        assert_that!(sut.get(&3), is_none);
+        assert_that!(sut.get(&5), eq Some(&6));
+    }
+
+    #[test]
+    fn removed_slots_are_reused<P: ProbingStrategy>() {
+        let mut sut = Sut::<P>::new();
+
+        for round in 0..4u64 {
+            for i in 0..SUT_CAPACITY as u64 {
+                let key = round * SUT_CAPACITY as u64 + i;
+                assert_that!(sut.insert(key, i), eq Ok(None));
+            }
+            assert_that!(sut.is_full(), eq true);
+
+            for i in 0..SUT_CAPACITY as u64 {
+                let key = round * SUT_CAPACITY as u64 + i;
+                assert_that!(sut.remove(&key), eq Some(i));
+            }
+            assert_that!(sut, is_empty);
+        }
+    }
+
+    #[test]
+    fn clear_removes_all_entries<P: ProbingStrategy>() {
+        let mut sut = Sut::<P>::new();
+
+        for i in 0..SUT_CAPACITY as u64 / 2 {
+            assert_that!(sut.insert(i, i), eq Ok(None));
+        }
+        sut.clear();
+
+        assert_that!(sut, is_empty);
+        for i in 0..SUT_CAPACITY as u64 / 2 {
+            assert_that!(sut.get(&i), is_none);
+        }
+    }
+
+    #[test]
+    fn iter_returns_all_entries<P: ProbingStrategy>() {
+        let mut sut = Sut::<P>::new();
+
+        for i in 0..SUT_CAPACITY as u64 / 2 {
+            assert_that!(sut.insert(i, i * 2), eq Ok(None));
+        }
+
+        let mut entries: Vec<(u64, u64)> = sut.iter().map(|(k, v)| (*k, *v)).collect();
+        entries.sort();
+        let expected: Vec<(u64, u64)> = (0..SUT_CAPACITY as u64 / 2).map(|i| (i, i * 2)).collect();
+        assert_that!(entries, eq expected);
+    }
+
+    #[test]
+    fn behaves_like_std_hash_map_for_random_operations<P: ProbingStrategy>() {
+        const NUMBER_OF_OPERATIONS: usize = 20000;
+        let mut random = Random(0x9e3779b97f4a7c15);
+        let mut sut = Sut::<P>::new();
+        let mut reference = HashMap::new();
+
+        for _ in 0..NUMBER_OF_OPERATIONS {
+            let key = random.next(SUT_CAPACITY as u64 * 2);
+            match random.next(3) {
+                0 | 1 => {
+                    let value = random.next(u64::MAX);
+                    let result = sut.insert(key, value);
+                    if reference.len() == SUT_CAPACITY && !reference.contains_key(&key) {
+                        assert_that!(result, eq Err(FixedSizeHashMapInsertError::ExceedsCapacity));
+                    } else {
+                        assert_that!(result, eq Ok(reference.insert(key, value)));
+                    }
+                }
+                _ => assert_that!(sut.remove(&key), eq reference.remove(&key)),
+            }
+
+            assert_that!(sut, len reference.len());
+            assert_that!(sut.get(&key), eq reference.get(&key));
+        }
+
+        for (key, value) in reference.iter() {
+            assert_that!(sut.get(key), eq Some(value));
+        }
+    }
+
+    #[test]
+    fn all_values_are_dropped<P: ProbingStrategy>() {
+        let state = LifetimeTracker::start_tracking();
+        let mut sut = FixedSizeHashMap::<u64, LifetimeTracker, SUT_CAPACITY, P>::new();
+
+        for i in 0..SUT_CAPACITY as u64 {
+            assert_that!(sut.insert(i, LifetimeTracker::new()), is_ok);
+        }
+        assert_that!(state.number_of_living_instances(), eq SUT_CAPACITY);
+
+        // replaced value is returned and dropped by the caller
+        assert_that!(sut.insert(0, LifetimeTracker::new()), is_ok);
+        assert_that!(state.number_of_living_instances(), eq SUT_CAPACITY);
+
+        assert_that!(sut.remove(&1), is_some);
+        assert_that!(state.number_of_living_instances(), eq SUT_CAPACITY - 1);
+
+        sut.clear();
+        assert_that!(state.number_of_living_instances(), eq 0);
+
+        for i in 0..SUT_CAPACITY as u64 {
+            assert_that!(sut.insert(i, LifetimeTracker::new()), is_ok);
+        }
+        drop(sut);
+        assert_that!(state.number_of_living_instances(), eq 0);
+    }
+
+    #[test]
+    fn clone_is_equal_and_independent<P: ProbingStrategy>() {
+        let mut sut = Sut::<P>::new();
+
+        for i in 0..SUT_CAPACITY as u64 {
+            assert_that!(sut.insert(i, i), eq Ok(None));
+        }
+        assert_that!(sut.remove(&7), eq Some(7));
+
+        let mut sut_clone = sut.clone();
+        assert_that!(sut_clone, eq sut);
+
+        assert_that!(sut_clone.insert(7, 8), eq Ok(None));
+        assert_that!(sut_clone, ne sut);
+        assert_that!(sut.get(&7), is_none);
+    }
+
+    #[test]
+    fn map_can_be_moved<P: ProbingStrategy>() {
+        let mut sut = Sut::<P>::new();
+        for i in 0..SUT_CAPACITY as u64 {
+            assert_that!(sut.insert(i, i + 1), eq Ok(None));
+        }
+
+        let moved_sut = Box::new(sut);
+
+        for i in 0..SUT_CAPACITY as u64 {
+            assert_that!(moved_sut.get(&i), eq Some(&(i + 1)));
+        }
+    }
+
+    #[test]
+    fn placement_default_works<P: ProbingStrategy>() {
+        let mut sut = RawMemory::<Sut<P>>::new_filled(0xff);
+        unsafe { Sut::<P>::placement_default(sut.as_mut_ptr()) };
+
+        assert_that!(unsafe { sut.assume_init() }, len 0);
+        assert_that!(unsafe { sut.assume_init_mut() }.insert(12, 34), eq Ok(None));
+        assert_that!(unsafe { sut.assume_init() }.get(&12), eq Some(&34));
+        assert_that!(unsafe { sut.assume_init_mut() }.remove(&12), eq Some(34));
+    }
+
+    #[instantiate_tests(<LinearProbing>)]
+    mod linear_probing {}
+
+    #[instantiate_tests(<QuadraticProbing>)]
+    mod quadratic_probing {}
+}
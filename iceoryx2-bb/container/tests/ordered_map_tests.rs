@@ -0,0 +1,259 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod fixed_size_ordered_map {
+    use std::collections::BTreeMap;
+
+    use iceoryx2_bb_container::ordered_map::*;
+    use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
+    use iceoryx2_bb_testing::memory::RawMemory;
+
+    const SUT_CAPACITY: usize = 100;
+    type Sut = FixedSizeOrderedMap<u64, u64, SUT_CAPACITY>;
+
+    // deterministic xorshift so that a failing sequence can be reproduced
+    struct Random(u64);
+
+    impl Random {
+        fn next(&mut self, upper_bound: u64) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0 % upper_bound
+        }
+    }
+
+    fn keys(sut: &Sut) -> Vec<u64> {
+        sut.iter().map(|(key, _)| *key).collect()
+    }
+
+    #[test]
+    fn new_map_is_empty() {
+        let sut = Sut::new();
+
+        assert_that!(sut, is_empty);
+        assert_that!(sut.is_full(), eq false);
+        assert_that!(sut, len 0);
+        assert_that!(sut.capacity(), eq SUT_CAPACITY);
+        assert_that!(sut.get(&0), is_none);
+        assert_that!(sut.first_key_value(), is_none);
+        assert_that!(sut.last_key_value(), is_none);
+        assert_that!(sut.iter().count(), eq 0);
+    }
+
+    #[test]
+    fn insert_until_full_works() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY as u64 {
+            assert_that!(sut.is_full(), eq false);
+            assert_that!(sut.insert(i * 3, i), eq Ok(None));
+            assert_that!(sut, len i as usize + 1);
+        }
+
+        assert_that!(sut.is_full(), eq true);
+        assert_that!(sut.insert(1, 1), eq Err(FixedSizeOrderedMapInsertError::ExceedsCapacity));
+        assert_that!(sut.insert(0, 5), eq Ok(Some(0)));
+
+        for i in 1..SUT_CAPACITY as u64 {
+            assert_that!(sut.get(&(i * 3)), eq Some(&i));
+            assert_that!(sut.contains_key(&(i * 3)), eq true);
+        }
+        assert_that!(sut.contains_key(&1), eq false);
+    }
+
+    #[test]
+    fn iteration_is_ordered_for_any_insertion_order() {
+        let mut sut = Sut::new();
+        let mut random = Random(4711);
+
+        while !sut.is_full() {
+            let key = random.next(1000);
+            assert_that!(sut.insert(key, key + 1), is_ok);
+        }
+
+        let keys = keys(&sut);
+        assert_that!(keys, len SUT_CAPACITY);
+        assert_that!(keys.windows(2).all(|w| w[0] < w[1]), eq true);
+        for (key, value) in sut.iter() {
+            assert_that!(*value, eq * key + 1);
+        }
+        assert_that!(sut.first_key_value().map(|(k, _)| *k), eq keys.first().copied());
+        assert_that!(sut.last_key_value().map(|(k, _)| *k), eq keys.last().copied());
+    }
+
+    #[test]
+    fn get_mut_modifies_value() {
+        let mut sut = Sut::new();
+
+        assert_that!(sut.get_mut(&5), is_none);
+        assert_that!(sut.insert(5, 6), eq Ok(None));
+        *sut.get_mut(&5).unwrap() = 7;
+
+        assert_that!(sut.get(&5), eq Some(&7));
+    }
+
+    #[test]
+    fn remove_works() {
+        let mut sut = Sut::new();
+
+        assert_that!(sut.remove(&3), is_none);
+        for i in 0..10 {
+            assert_that!(sut.insert(i, i * 2), eq Ok(None));
+        }
+
+        assert_that!(sut.remove(&3), eq Some(6));
+        assert_that!(sut.remove(&3), is_none);
+        assert_that!(sut.remove(&0), eq Some(0));
+        assert_that!(sut.remove(&9), eq Some(18));
+
+        assert_that!(sut, len 7);
+        assert_that!(keys(&sut), eq vec![1, 2, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn removed_nodes_are_reused() {
+        let mut sut = Sut::new();
+
+        for round in 0..4u64 {
+            for i in 0..SUT_CAPACITY as u64 {
+                let key = round * SUT_CAPACITY as u64 + i;
+                assert_that!(sut.insert(key, i), eq Ok(None));
+            }
+            assert_that!(sut.is_full(), eq true);
+
+            for i in (0..SUT_CAPACITY as u64).rev() {
+                let key = round * SUT_CAPACITY as u64 + i;
+                assert_that!(sut.remove(&key), eq Some(i));
+            }
+            assert_that!(sut, is_empty);
+        }
+    }
+
+    #[test]
+    fn clear_removes_all_entries() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY as u64 {
+            assert_that!(sut.insert(i, i), eq Ok(None));
+        }
+        sut.clear();
+
+        assert_that!(sut, is_empty);
+        assert_that!(sut.iter().count(), eq 0);
+        for i in 0..SUT_CAPACITY as u64 {
+            assert_that!(sut.insert(i, i), eq Ok(None));
+        }
+        assert_that!(sut.is_full(), eq true);
+    }
+
+    #[test]
+    fn behaves_like_std_btree_map_for_random_operations() {
+        const NUMBER_OF_OPERATIONS: usize = 20000;
+        let mut random = Random(0x9e3779b97f4a7c15);
+        let mut sut = Sut::new();
+        let mut reference = BTreeMap::new();
+
+        for _ in 0..NUMBER_OF_OPERATIONS {
+            let key = random.next(SUT_CAPACITY as u64 * 2);
+            match random.next(3) {
+                0 | 1 => {
+                    let value = random.next(u64::MAX);
+                    let result = sut.insert(key, value);
+                    if reference.len() == SUT_CAPACITY && !reference.contains_key(&key) {
+                        assert_that!(result, eq Err(FixedSizeOrderedMapInsertError::ExceedsCapacity));
+                    } else {
+                        assert_that!(result, eq Ok(reference.insert(key, value)));
+                    }
+                }
+                _ => assert_that!(sut.remove(&key), eq reference.remove(&key)),
+            }
+
+            assert_that!(sut, len reference.len());
+            assert_that!(sut.get(&key), eq reference.get(&key));
+        }
+
+        assert_that!(sut.iter().eq(reference.iter()), eq true);
+    }
+
+    #[test]
+    fn all_values_are_dropped() {
+        let state = LifetimeTracker::start_tracking();
+        let mut sut = FixedSizeOrderedMap::<u64, LifetimeTracker, SUT_CAPACITY>::new();
+
+        for i in 0..SUT_CAPACITY as u64 {
+            assert_that!(sut.insert(i, LifetimeTracker::new()), is_ok);
+        }
+        assert_that!(state.number_of_living_instances(), eq SUT_CAPACITY);
+
+        // replaced value is returned and dropped by the caller
+        assert_that!(sut.insert(0, LifetimeTracker::new()), is_ok);
+        assert_that!(state.number_of_living_instances(), eq SUT_CAPACITY);
+
+        assert_that!(sut.remove(&1), is_some);
+        assert_that!(state.number_of_living_instances(), eq SUT_CAPACITY - 1);
+
+        sut.clear();
+        assert_that!(state.number_of_living_instances(), eq 0);
+
+        for i in 0..SUT_CAPACITY as u64 {
+            assert_that!(sut.insert(i, LifetimeTracker::new()), is_ok);
+        }
+        drop(sut);
+        assert_that!(state.number_of_living_instances(), eq 0);
+    }
+
+    #[test]
+    fn clone_is_equal_and_independent() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY as u64 {
+            assert_that!(sut.insert(i, i), eq Ok(None));
+        }
+        assert_that!(sut.remove(&7), eq Some(7));
+
+        let mut sut_clone = sut.clone();
+        assert_that!(sut_clone, eq sut);
+
+        assert_that!(sut_clone.insert(7, 8), eq Ok(None));
+        assert_that!(sut_clone, ne sut);
+        assert_that!(sut.get(&7), is_none);
+    }
+
+    #[test]
+    fn map_can_be_moved() {
+        let mut sut = Sut::new();
+        for i in 0..SUT_CAPACITY as u64 {
+            assert_that!(sut.insert(i, i + 1), eq Ok(None));
+        }
+
+        let moved_sut = Box::new(sut);
+
+        for i in 0..SUT_CAPACITY as u64 {
+            assert_that!(moved_sut.get(&i), eq Some(&(i + 1)));
+        }
+    }
+
+    #[test]
+    fn placement_default_works() {
+        let mut sut = RawMemory::<Sut>::new_filled(0xff);
+        unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+        assert_that!(unsafe { sut.assume_init() }, len 0);
+        assert_that!(unsafe { sut.assume_init_mut() }.insert(12, 34), eq Ok(None));
+        assert_that!(unsafe { sut.assume_init_mut() }.insert(10, 11), eq Ok(None));
+        assert_that!(unsafe { sut.assume_init() }.first_key_value(), eq Some((&10, &11)));
+        assert_that!(unsafe { sut.assume_init_mut() }.remove(&12), eq Some(34));
+    }
+}
@@ -325,4 +325,27 @@ mod queue {
         assert_that!(*sut.peek().unwrap(), eq 99182);
         assert_that!(*sut.peek_mut().unwrap(), eq 99182);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialization_keeps_order_when_queue_wraps_around() {
+        use serde_test::{assert_ser_tokens, Token};
+
+        let mut sut = FixedSizeQueue::<usize, 3>::new();
+        sut.push(1);
+        sut.push(2);
+        sut.push(3);
+        assert_that!(sut.push_with_overflow(4), eq Some(1));
+
+        assert_ser_tokens(
+            &sut,
+            &[
+                Token::Seq { len: Some(3) },
+                Token::U64(2),
+                Token::U64(3),
+                Token::U64(4),
+                Token::SeqEnd,
+            ],
+        );
+    }
 }
@@ -17,6 +17,7 @@ use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_testing::assert_that;
 use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
 use iceoryx2_bb_testing::memory::RawMemory;
+#[cfg(feature = "serde")]
 use serde_test::{assert_tokens, Token};
 
 mod fixed_size_vec {
@@ -293,6 +294,100 @@ mod fixed_size_vec {
         }
     }
 
+    #[test]
+    fn insert_and_remove_shift_elements() {
+        let mut sut = FixedSizeVec::<usize, 4>::new();
+        assert_that!(sut.insert(0, 3), eq true);
+        assert_that!(sut.insert(0, 1), eq true);
+        assert_that!(sut.insert(1, 2), eq true);
+        assert_that!(sut.insert(3, 4), eq true);
+        assert_that!(sut.insert(0, 0), eq false);
+        assert_that!(sut.as_slice(), eq [1, 2, 3, 4]);
+
+        assert_that!(sut.remove(1), eq 2);
+        assert_that!(sut.remove(2), eq 4);
+        assert_that!(sut.as_slice(), eq [1, 3]);
+    }
+
+    #[test]
+    fn retain_keeps_order_and_drops_removed_elements() {
+        let state = LifetimeTracker::start_tracking();
+        let mut sut = FixedSizeVec::<(usize, LifetimeTracker), SUT_CAPACITY>::new();
+        for i in 0..10 {
+            assert_that!(sut.push((i, LifetimeTracker::new())), eq true);
+        }
+
+        sut.retain(|(i, _)| i % 3 == 0);
+
+        assert_that!(state.number_of_living_instances(), eq 4);
+        let values: std::vec::Vec<usize> = sut.iter().map(|(i, _)| *i).collect();
+        assert_that!(values, eq vec![0, 3, 6, 9]);
+    }
+
+    #[test]
+    fn drain_returns_range_and_closes_gap() {
+        let mut sut = Sut::new();
+        sut.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+
+        let drained: std::vec::Vec<usize> = sut.drain(1..=3).collect();
+
+        assert_that!(drained, eq vec![1, 2, 3]);
+        assert_that!(sut.as_slice(), eq [0, 4, 5]);
+    }
+
+    #[test]
+    fn drain_can_be_consumed_from_both_ends() {
+        let mut sut = Sut::new();
+        sut.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+
+        let mut drain = sut.drain(..4);
+        assert_that!(drain.len(), eq 4);
+        assert_that!(drain.next_back(), eq Some(3));
+        assert_that!(drain.next(), eq Some(0));
+        drop(drain);
+
+        assert_that!(sut.as_slice(), eq [4, 5]);
+    }
+
+    #[test]
+    fn dropped_drain_drops_remaining_elements() {
+        let state = LifetimeTracker::start_tracking();
+        let mut sut = FixedSizeVec::<LifetimeTracker, SUT_CAPACITY>::new();
+        for _ in 0..8 {
+            assert_that!(sut.push(LifetimeTracker::new()), eq true);
+        }
+
+        let mut drain = sut.drain(2..6);
+        drain.next();
+        drop(drain);
+
+        assert_that!(state.number_of_living_instances(), eq 4);
+        assert_that!(sut, len 4);
+    }
+
+    #[test]
+    fn insert_sorted_keeps_vector_sorted() {
+        let mut sut = FixedSizeVec::<usize, 8>::new();
+        for value in [5, 1, 7, 3, 3, 0, 9, 2] {
+            assert_that!(sut.insert_sorted(value), is_some);
+        }
+
+        assert_that!(sut.insert_sorted(4), is_none);
+        assert_that!(sut.as_slice(), eq [0, 1, 2, 3, 3, 5, 7, 9]);
+        assert_that!(sut.binary_search_by(|v| v.cmp(&7)), eq Ok(6));
+    }
+
+    #[test]
+    fn insert_sorted_by_inserts_equal_elements_after_existing_ones() {
+        let mut sut = FixedSizeVec::<(usize, usize), 8>::new();
+        assert_that!(sut.insert_sorted_by((1, 0), |a, b| a.0.cmp(&b.0)), eq Some(0));
+        assert_that!(sut.insert_sorted_by((0, 1), |a, b| a.0.cmp(&b.0)), eq Some(0));
+        assert_that!(sut.insert_sorted_by((1, 2), |a, b| a.0.cmp(&b.0)), eq Some(2));
+
+        assert_that!(sut.as_slice(), eq [(0, 1), (1, 0), (1, 2)]);
+    }
+
+    #[cfg(feature = "serde")]
     #[test]
     fn serialization_works() {
         let mut sut = Sut::new();
@@ -103,3 +103,79 @@ pub fn placement_default_derive(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Implements the [`iceoryx2_bb_elementary::stable_type_id::StableTypeId`] trait. The
+/// identifier is either provided directly as integer literal or derived from a name with
+/// [`iceoryx2_bb_elementary::stable_type_id::stable_type_id_from_name()`].
+///
+/// ```
+/// use iceoryx2_bb_derive_macros::StableTypeId;
+/// use iceoryx2_bb_elementary::stable_type_id::{stable_type_id_from_name, StableTypeId};
+///
+/// #[derive(StableTypeId)]
+/// #[stable_type_id("geometry::Position/v1")]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// #[derive(StableTypeId)]
+/// #[stable_type_id(0x5f2b_39c1_7a44_4e0d_9b21_6c3e_8d0f_1a72)]
+/// struct Velocity {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// assert_eq!(Position::STABLE_TYPE_ID, stable_type_id_from_name("geometry::Position/v1"));
+/// assert_eq!(Velocity::STABLE_TYPE_ID, 0x5f2b_39c1_7a44_4e0d_9b21_6c3e_8d0f_1a72);
+/// ```
+#[proc_macro_derive(StableTypeId, attributes(stable_type_id))]
+pub fn stable_type_id_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let attribute = match input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("stable_type_id"))
+    {
+        Some(attribute) => attribute,
+        None => {
+            return syn::Error::new_spanned(
+                name,
+                "#[derive(StableTypeId)] requires a #[stable_type_id(\"name\")] or #[stable_type_id(0x...)] attribute",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+
+    let id = match attribute.parse_args::<syn::Lit>() {
+        Ok(syn::Lit::Str(value)) => {
+            iceoryx2_bb_elementary::stable_type_id::stable_type_id_from_name(&value.value())
+        }
+        Ok(syn::Lit::Int(value)) => match value.base10_parse::<u128>() {
+            Ok(value) => value,
+            Err(e) => return e.to_compile_error().into(),
+        },
+        Ok(other) => {
+            return syn::Error::new_spanned(
+                other,
+                "the stable type id must be either a string or an integer literal",
+            )
+            .to_compile_error()
+            .into()
+        }
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let id = proc_macro2::Literal::u128_suffixed(id);
+    let expanded = quote! {
+        impl #impl_generics StableTypeId for #name #ty_generics #where_clause {
+            const STABLE_TYPE_ID: u128 = #id;
+        }
+    };
+
+    TokenStream::from(expanded)
+}
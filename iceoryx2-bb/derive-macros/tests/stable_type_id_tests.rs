@@ -0,0 +1,74 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod stable_type_id {
+    use core::marker::PhantomData;
+
+    use iceoryx2_bb_derive_macros::StableTypeId;
+    use iceoryx2_bb_elementary::stable_type_id::{stable_type_id_from_name, StableTypeId};
+    use iceoryx2_bb_testing::assert_that;
+
+    #[derive(StableTypeId)]
+    #[stable_type_id("my_app::Fuu")]
+    #[allow(dead_code)]
+    struct Fuu {
+        value: u64,
+    }
+
+    #[derive(StableTypeId)]
+    #[stable_type_id("my_app::Fuu")]
+    #[allow(dead_code)]
+    struct RenamedFuu {
+        value: u64,
+    }
+
+    #[derive(StableTypeId)]
+    #[stable_type_id(0x1234_5678_9abc_def0_0fed_cba9_8765_4321)]
+    #[allow(dead_code)]
+    struct Bar(u32);
+
+    #[derive(StableTypeId)]
+    #[stable_type_id("my_app::Generic")]
+    #[allow(dead_code)]
+    struct Generic<T> {
+        _data: PhantomData<T>,
+    }
+
+    #[test]
+    fn stable_type_id_derive_with_name_works() {
+        assert_that!(Fuu::STABLE_TYPE_ID, eq stable_type_id_from_name("my_app::Fuu"));
+        assert_that!(Fuu::STABLE_TYPE_ID, eq RenamedFuu::STABLE_TYPE_ID);
+    }
+
+    #[test]
+    fn stable_type_id_derive_with_integer_works() {
+        assert_that!(Bar::STABLE_TYPE_ID, eq 0x1234_5678_9abc_def0_0fed_cba9_8765_4321);
+    }
+
+    #[test]
+    fn stable_type_id_derive_for_generic_structs_works() {
+        assert_that!(Generic::<u8>::STABLE_TYPE_ID, eq Generic::<u64>::STABLE_TYPE_ID);
+        assert_that!(Generic::<u8>::STABLE_TYPE_ID, ne Fuu::STABLE_TYPE_ID);
+    }
+
+    #[test]
+    fn stable_type_id_of_slice_is_the_id_of_the_element() {
+        assert_that!(<[Fuu]>::STABLE_TYPE_ID, eq Fuu::STABLE_TYPE_ID);
+    }
+
+    #[test]
+    fn stable_type_id_from_name_differs_for_different_names() {
+        assert_that!(stable_type_id_from_name("a"), ne stable_type_id_from_name("b"));
+        assert_that!(stable_type_id_from_name(""), ne stable_type_id_from_name("a"));
+    }
+}
@@ -25,6 +25,14 @@ pub enum AllocationError {
     InternalError,
 }
 
+impl core::fmt::Display for AllocationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::write!(f, "AllocationError::{:?}", self)
+    }
+}
+
+impl core::error::Error for AllocationError {}
+
 /// Failures caused by [`Allocator::grow()`] or [`Allocator::grow_zeroed()`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum AllocationGrowError {
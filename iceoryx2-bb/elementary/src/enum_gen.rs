@@ -167,6 +167,209 @@
 //!     }
 //! }
 //! ```
+//!
+//! ## Error codes and source chaining
+//!
+//! Every enum that is generated with `entry`, `mapping` or `generalization` implements
+//! [`ErrorDetails`]. It provides a stable numeric code for every variant that can be used in
+//! the FFI or for log correlation and the underlying error of a mapping variant, so that it can
+//! be forwarded in [`core::error::Error::source()`].
+//!
+//! ```
+//! use iceoryx2_bb_elementary::enum_gen;
+//! use iceoryx2_bb_elementary::enum_gen::ErrorDetails;
+//!
+//! #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+//! struct SomeError;
+//!
+//! impl core::fmt::Display for SomeError {
+//!     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+//!         write!(f, "SomeError")
+//!     }
+//! }
+//!
+//! impl core::error::Error for SomeError {}
+//!
+//! enum_gen! {
+//!     MyErrorEnum
+//!
+//!   entry:
+//!     Failure1,
+//!     Failure2
+//!
+//!   mapping:
+//!     SomeError
+//! }
+//!
+//! impl core::fmt::Display for MyErrorEnum {
+//!     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+//!         write!(f, "MyErrorEnum::{:?}", self)
+//!     }
+//! }
+//!
+//! impl core::error::Error for MyErrorEnum {
+//!     fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+//!         self.error_source()
+//!     }
+//! }
+//!
+//! assert_eq!(MyErrorEnum::Failure1.error_code(), 1);
+//! assert_eq!(MyErrorEnum::SomeError(SomeError).error_code(), 3);
+//!
+//! let error: &dyn core::error::Error = &MyErrorEnum::SomeError(SomeError);
+//! assert!(error.source().is_some());
+//! ```
+//!
+//! A generalization discards the underlying error. With the `error_backtrace` feature the
+//! name of the discarded error type and a [`std::backtrace::Backtrace`] are recorded in the
+//! current thread whenever an error is generalized and can be acquired with
+//! [`take_last_generalization()`]. The backtrace is captured with
+//! [`std::backtrace::Backtrace::capture()`], therefore `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE`
+//! must be set.
+
+/// Details of an error enum that was generated with [`enum_gen!`](crate::enum_gen!).
+pub trait ErrorDetails {
+    /// Returns the numeric code of the variant. The codes start at `1` and follow the order
+    /// of declaration, first the `entry`, then the `mapping` and then the `generalization`
+    /// variants. The codes stay stable as long as new variants are only appended.
+    fn error_code(&self) -> u32;
+
+    /// Returns the underlying error of a mapping variant when it implements
+    /// [`core::error::Error`], otherwise [`None`].
+    fn error_source(&self) -> Option<&(dyn core::error::Error + 'static)>;
+}
+
+/// Describes an error that was discarded by a generalization, see
+/// [`take_last_generalization()`].
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct GeneralizationTrace {
+    error: &'static str,
+    source: &'static str,
+    backtrace: std::backtrace::Backtrace,
+}
+
+#[cfg(feature = "std")]
+impl GeneralizationTrace {
+    /// Returns the variant the error was generalized into, e.g. `MyErrorEnum::InternalFailure`.
+    pub fn error(&self) -> &'static str {
+        self.error
+    }
+
+    /// Returns the name of the type of the discarded error.
+    pub fn source(&self) -> &'static str {
+        self.source
+    }
+
+    /// Returns the backtrace of the generalization.
+    pub fn backtrace(&self) -> &std::backtrace::Backtrace {
+        &self.backtrace
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for GeneralizationTrace {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} <= {}\n{}", self.error, self.source, self.backtrace)
+    }
+}
+
+/// Returns and removes the last error that was generalized in the current thread. Returns
+/// always [`None`] when the `error_backtrace` feature is not enabled.
+#[cfg(feature = "std")]
+pub fn take_last_generalization() -> Option<GeneralizationTrace> {
+    __internal::LAST_GENERALIZATION.with(|last| last.take())
+}
+
+#[doc(hidden)]
+pub mod __internal {
+    #[cfg(feature = "std")]
+    use super::GeneralizationTrace;
+    #[cfg(feature = "std")]
+    use core::cell::Cell;
+
+    #[cfg(feature = "std")]
+    std::thread_local! {
+        pub(super) static LAST_GENERALIZATION: Cell<Option<GeneralizationTrace>> =
+            const { Cell::new(None) };
+    }
+
+    #[cfg(feature = "error_backtrace")]
+    pub fn record_generalization(error: &'static str, source: &'static str) {
+        LAST_GENERALIZATION.with(|last| {
+            last.set(Some(GeneralizationTrace {
+                error,
+                source,
+                backtrace: std::backtrace::Backtrace::capture(),
+            }))
+        });
+    }
+
+    #[cfg(not(feature = "error_backtrace"))]
+    #[inline(always)]
+    pub fn record_generalization(_error: &'static str, _source: &'static str) {}
+
+    // autoref specialization, works only with concrete types as they are used in enum_gen!
+    pub struct ErrorSourceCheck<'a, T>(pub &'a T);
+
+    pub trait IsErrorSource<'a> {
+        fn error_source(&self) -> Option<&'a (dyn core::error::Error + 'static)>;
+    }
+
+    impl<'a, T: core::error::Error + 'static> IsErrorSource<'a> for ErrorSourceCheck<'a, T> {
+        fn error_source(&self) -> Option<&'a (dyn core::error::Error + 'static)> {
+            Some(self.0)
+        }
+    }
+
+    pub trait IsNoErrorSource<'a> {
+        fn error_source(&self) -> Option<&'a (dyn core::error::Error + 'static)> {
+            None
+        }
+    }
+
+    impl<'a, T> IsNoErrorSource<'a> for &ErrorSourceCheck<'a, T> {}
+}
+
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! __enum_gen_error_details {
+    { $enum_name:ident
+      entry: [$($entry:ident),*]
+      mapping: [$($mapping:ident),*]
+      generalization: [$($destination:ident),*] }
+    => {
+        impl $crate::enum_gen::ErrorDetails for $enum_name {
+            fn error_code(&self) -> u32 {
+                #[allow(dead_code)]
+                enum Code {
+                    __Unused,
+                    $($entry,)*
+                    $($mapping,)*
+                    $($destination,)*
+                }
+
+                (match self {
+                    $($enum_name::$entry { .. } => Code::$entry,)*
+                    $($enum_name::$mapping(_) => Code::$mapping,)*
+                    $($enum_name::$destination => Code::$destination,)*
+                }) as u32
+            }
+
+            #[allow(unreachable_patterns)]
+            fn error_source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+                #[allow(unused_imports)]
+                use $crate::enum_gen::__internal::{IsErrorSource, IsNoErrorSource};
+
+                match self {
+                    $($enum_name::$mapping(v) =>
+                        (&$crate::enum_gen::__internal::ErrorSourceCheck(v)).error_source(),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
 
 #[macro_export(local_inner_macros)]
 macro_rules! enum_gen {
@@ -180,6 +383,13 @@ macro_rules! enum_gen {
         pub enum $enum_name {
             $($entry$(($bla))?),*
         }
+
+        __enum_gen_error_details! {
+            $enum_name
+            entry: [$($entry),*]
+            mapping: []
+            generalization: []
+        }
     };
 
     { $(#[$documentation:meta])*
@@ -198,6 +408,13 @@ macro_rules! enum_gen {
                 $enum_name::$equivalent(v)
             }
         })*
+
+        __enum_gen_error_details! {
+            $enum_name
+            entry: []
+            mapping: [$($equivalent),*]
+            generalization: []
+        }
     };
 
     { $(#[$documentation:meta])*
@@ -216,6 +433,13 @@ macro_rules! enum_gen {
                 $enum_name::$value_name(v)
             }
         })*
+
+        __enum_gen_error_details! {
+            $enum_name
+            entry: []
+            mapping: [$($value_name),*]
+            generalization: []
+        }
     };
 
     { $(#[$documentation:meta])*
@@ -237,6 +461,13 @@ macro_rules! enum_gen {
                 $enum_name::$equivalent(v)
             }
         })*
+
+        __enum_gen_error_details! {
+            $enum_name
+            entry: [$($entry),*]
+            mapping: [$($equivalent),*]
+            generalization: []
+        }
     };
 
     { $(#[$documentation:meta])*
@@ -258,6 +489,13 @@ macro_rules! enum_gen {
                 $enum_name::$value_name(v)
             }
         })*
+
+        __enum_gen_error_details! {
+            $enum_name
+            entry: [$($entry),*]
+            mapping: [$($value_name),*]
+            generalization: []
+        }
     };
 
     { $(#[$documentation:meta])*
@@ -273,9 +511,20 @@ macro_rules! enum_gen {
 
         $($(impl From<$source> for $enum_name {
             fn from(_: $source) -> Self {
+                $crate::enum_gen::__internal::record_generalization(
+                    core::concat!(core::stringify!($enum_name), "::", core::stringify!($destination)),
+                    core::stringify!($source),
+                );
                 $enum_name::$destination
             }
         })*)*
+
+        __enum_gen_error_details! {
+            $enum_name
+            entry: []
+            mapping: []
+            generalization: [$($destination),*]
+        }
     };
 
     { $(#[$documentation:meta])*
@@ -294,9 +543,20 @@ macro_rules! enum_gen {
 
         $($(impl From<$source> for $enum_name {
             fn from(_: $source) -> Self {
+                $crate::enum_gen::__internal::record_generalization(
+                    core::concat!(core::stringify!($enum_name), "::", core::stringify!($destination)),
+                    core::stringify!($source),
+                );
                 $enum_name::$destination
             }
         })*)*
+
+        __enum_gen_error_details! {
+            $enum_name
+            entry: [$($entry),*]
+            mapping: []
+            generalization: [$($destination),*]
+        }
     };
 
     { $(#[$documentation:meta])*
@@ -324,9 +584,20 @@ macro_rules! enum_gen {
 
         $($(impl From<$source> for $enum_name {
             fn from(_: $source) -> Self {
+                $crate::enum_gen::__internal::record_generalization(
+                    core::concat!(core::stringify!($enum_name), "::", core::stringify!($destination)),
+                    core::stringify!($source),
+                );
                 $enum_name::$destination
             }
         })*)*
+
+        __enum_gen_error_details! {
+            $enum_name
+            entry: [$($entry),*]
+            mapping: [$($equivalent),*]
+            generalization: [$($destination),*]
+        }
     };
 
     { $(#[$documentation:meta])*
@@ -355,7 +626,7 @@ macro_rules! enum_gen {
 
       impl Display for $name {
           fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-              std::write!(f, "{}::{:?}", std::stringify!($name), self )
+              core::write!(f, "{}::{:?}", core::stringify!($name), self )
           }
       }
     };
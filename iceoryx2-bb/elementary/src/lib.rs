@@ -10,6 +10,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(clippy::alloc_instead_of_core)]
 #![warn(clippy::std_instead_of_alloc)]
 #![warn(clippy::std_instead_of_core)]
@@ -32,6 +33,7 @@ pub mod bump_allocator;
 pub mod generic_pointer;
 pub mod lazy_singleton;
 pub mod math;
+#[cfg(feature = "std")]
 pub mod owning_pointer;
 pub mod package_version;
 pub mod placement_default;
@@ -39,6 +41,7 @@ pub mod pointer_trait;
 pub mod relocatable_container;
 pub mod relocatable_ptr;
 pub mod scope_guard;
+pub mod stable_type_id;
 pub mod static_assert;
 pub mod unique_id;
 pub mod visitor;
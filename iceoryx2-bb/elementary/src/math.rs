@@ -31,10 +31,12 @@ pub const fn align_to<T>(value: usize) -> usize {
     align(value, core::mem::align_of::<T>())
 }
 
+#[cfg(feature = "std")]
 pub trait ToB64 {
     fn to_b64(&self) -> String;
 }
 
+#[cfg(feature = "std")]
 impl ToB64 for u128 {
     fn to_b64(&self) -> String {
         let mut quotient = *self;
@@ -70,30 +72,35 @@ impl ToB64 for u128 {
     }
 }
 
+#[cfg(feature = "std")]
 impl ToB64 for u64 {
     fn to_b64(&self) -> String {
         (*self as u128).to_b64()
     }
 }
 
+#[cfg(feature = "std")]
 impl ToB64 for u32 {
     fn to_b64(&self) -> String {
         (*self as u128).to_b64()
     }
 }
 
+#[cfg(feature = "std")]
 impl ToB64 for u16 {
     fn to_b64(&self) -> String {
         (*self as u128).to_b64()
     }
 }
 
+#[cfg(feature = "std")]
 impl ToB64 for u8 {
     fn to_b64(&self) -> String {
         (*self as u128).to_b64()
     }
 }
 
+#[cfg(feature = "std")]
 impl<const N: usize> ToB64 for [u8; N] {
     fn to_b64(&self) -> String {
         let mut result = String::new();
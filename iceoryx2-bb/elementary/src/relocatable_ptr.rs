@@ -67,7 +67,7 @@
 use crate::generic_pointer::GenericPointer;
 pub use crate::pointer_trait::PointerTrait;
 use core::{fmt::Debug, marker::PhantomData, ptr::NonNull};
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicIsize;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicI64;
 
 #[derive(Debug)]
 pub struct GenericRelocatablePointer;
@@ -85,10 +85,13 @@ pub struct GenericRelocatablePointer;
 ///      the same shared memory object. Pointing to a different shared memory segment most likely
 ///      leads to crashes since it can be mapped in a different order, at a different position and
 ///      the distance to the memory destination is off.
+///
+/// The distance is always stored with 64-bit, so that a [`RelocatablePointer`] has the same
+/// layout in 32-bit and 64-bit processes.
 #[repr(C)]
 #[derive(Debug)]
 pub struct RelocatablePointer<T> {
-    distance: IoxAtomicIsize,
+    distance: IoxAtomicI64,
     _phantom: PhantomData<T>,
 }
 
@@ -97,7 +100,7 @@ impl<T> RelocatablePointer<T> {
     /// destination starting from the memory location of this [`RelocatablePointer`].
     pub fn new(distance: isize) -> Self {
         Self {
-            distance: IoxAtomicIsize::new(distance),
+            distance: IoxAtomicI64::new(distance as i64),
             _phantom: PhantomData,
         }
     }
@@ -126,7 +129,7 @@ impl<T> RelocatablePointer<T> {
     ///
     pub unsafe fn init(&self, ptr: NonNull<[u8]>) {
         self.distance.store(
-            (ptr.as_ptr() as *const u8) as isize - (self as *const Self) as isize,
+            ((ptr.as_ptr() as *const u8) as isize - (self as *const Self) as isize) as i64,
             core::sync::atomic::Ordering::Relaxed,
         );
     }
@@ -134,7 +137,8 @@ impl<T> RelocatablePointer<T> {
 
 impl<T> PointerTrait<T> for RelocatablePointer<T> {
     unsafe fn as_ptr(&self) -> *const T {
-        ((self as *const Self) as isize + self.distance.load(core::sync::atomic::Ordering::Relaxed))
+        ((self as *const Self) as isize
+            + self.distance.load(core::sync::atomic::Ordering::Relaxed) as isize)
             as *const T
     }
 
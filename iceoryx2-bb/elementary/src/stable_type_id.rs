@@ -0,0 +1,61 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Trait to assign a user defined, stable 128-bit identifier to a type. In contrast to
+//! [`core::any::type_name()`] the identifier does not depend on the crate, module or
+//! compiler version and can therefore be shared across languages and software versions.
+//! See [`StableTypeId`] for example.
+
+const FNV_128_OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+const FNV_128_PRIME: u128 = 0x0000000001000000000000000000013b;
+
+/// Assigns a stable 128-bit identifier to a type. Two types with the same identifier are
+/// considered to be the same type, independent of their name.
+///
+/// ```
+/// use iceoryx2_bb_elementary::stable_type_id::{stable_type_id_from_name, StableTypeId};
+///
+/// #[repr(C)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// impl StableTypeId for Position {
+///     const STABLE_TYPE_ID: u128 = stable_type_id_from_name("geometry::Position/v1");
+/// }
+///
+/// assert_eq!(Position::STABLE_TYPE_ID, stable_type_id_from_name("geometry::Position/v1"));
+/// ```
+pub trait StableTypeId {
+    /// The stable identifier of the type.
+    const STABLE_TYPE_ID: u128;
+}
+
+impl<T: StableTypeId> StableTypeId for [T] {
+    const STABLE_TYPE_ID: u128 = T::STABLE_TYPE_ID;
+}
+
+/// Generates a stable 128-bit identifier from a human readable name using the
+/// FNV-1a hash algorithm.
+pub const fn stable_type_id_from_name(name: &str) -> u128 {
+    let bytes = name.as_bytes();
+    let mut hash = FNV_128_OFFSET_BASIS;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u128;
+        hash = hash.wrapping_mul(FNV_128_PRIME);
+        i += 1;
+    }
+
+    hash
+}
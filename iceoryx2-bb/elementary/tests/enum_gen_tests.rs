@@ -0,0 +1,124 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_elementary::enum_gen::*;
+use iceoryx2_bb_testing::assert_that;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+struct SomeError;
+
+impl core::fmt::Display for SomeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SomeError")
+    }
+}
+
+impl core::error::Error for SomeError {}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+struct NoError;
+
+struct Widget;
+struct AnotherWidget;
+
+enum_gen! {
+    FullError
+  entry:
+    Failure1,
+    Failure2(u8)
+  mapping:
+    SomeError,
+    NoError
+  generalization:
+    InternalFailure <= Widget; AnotherWidget
+}
+
+enum_gen! {
+    RenamedMappingError
+  entry:
+    Failure
+  mapping:
+    SomeError to Renamed
+}
+
+enum_gen! {
+    GeneralizationError
+  generalization:
+    First <= Widget,
+    Second <= AnotherWidget
+}
+
+#[test]
+fn enum_gen_error_codes_follow_declaration_order() {
+    assert_that!(FullError::Failure1.error_code(), eq 1);
+    assert_that!(FullError::Failure2(123).error_code(), eq 2);
+    assert_that!(FullError::SomeError(SomeError).error_code(), eq 3);
+    assert_that!(FullError::NoError(NoError).error_code(), eq 4);
+    assert_that!(FullError::InternalFailure.error_code(), eq 5);
+
+    assert_that!(RenamedMappingError::Failure.error_code(), eq 1);
+    assert_that!(RenamedMappingError::Renamed(SomeError).error_code(), eq 2);
+
+    assert_that!(GeneralizationError::First.error_code(), eq 1);
+    assert_that!(GeneralizationError::Second.error_code(), eq 2);
+}
+
+#[test]
+fn enum_gen_error_source_is_mapped_error() {
+    let sut = FullError::SomeError(SomeError);
+    let source = sut.error_source();
+    assert_that!(source, is_some);
+    assert_that!(source.unwrap().to_string(), eq "SomeError");
+
+    let sut = RenamedMappingError::from(SomeError);
+    assert_that!(sut.error_source(), is_some);
+}
+
+#[test]
+fn enum_gen_error_source_is_none_when_mapped_type_is_no_error() {
+    assert_that!(FullError::NoError(NoError).error_source(), is_none);
+}
+
+#[test]
+fn enum_gen_error_source_is_none_for_entries_and_generalizations() {
+    assert_that!(FullError::Failure1.error_source(), is_none);
+    assert_that!(FullError::Failure2(12).error_source(), is_none);
+    assert_that!(FullError::from(Widget).error_source(), is_none);
+    assert_that!(
+        GeneralizationError::from(AnotherWidget).error_source(),
+        is_none
+    );
+}
+
+#[cfg(feature = "error_backtrace")]
+#[test]
+fn enum_gen_generalization_records_discarded_error() {
+    let _ = take_last_generalization();
+
+    let sut = FullError::from(AnotherWidget);
+    assert_that!(sut, eq FullError::InternalFailure);
+
+    let trace = take_last_generalization();
+    assert_that!(trace, is_some);
+    let trace = trace.unwrap();
+    assert_that!(trace.error(), eq "FullError::InternalFailure");
+    assert_that!(trace.source(), eq "AnotherWidget");
+    assert_that!(take_last_generalization(), is_none);
+}
+
+#[cfg(not(feature = "error_backtrace"))]
+#[test]
+fn enum_gen_generalization_records_nothing_without_feature() {
+    let _ = FullError::from(Widget);
+    assert_that!(take_last_generalization(), is_none);
+}
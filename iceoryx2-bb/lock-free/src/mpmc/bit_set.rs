@@ -37,18 +37,20 @@
 //!  ```
 
 use core::{alloc::Layout, fmt::Debug, sync::atomic::Ordering};
+#[cfg(feature = "std")]
+use iceoryx2_bb_elementary::owning_pointer::OwningPointer;
 use iceoryx2_bb_elementary::{
     bump_allocator::BumpAllocator,
     math::unaligned_mem_size,
-    owning_pointer::OwningPointer,
     relocatable_container::RelocatableContainer,
     relocatable_ptr::{PointerTrait, RelocatablePointer},
 };
-use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU8, IoxAtomicUsize};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, IoxAtomicU8};
 
 use iceoryx2_bb_log::{fail, fatal_panic};
 
 /// This BitSet variant's data is stored in the heap.
+#[cfg(feature = "std")]
 pub type BitSet = details::BitSet<OwningPointer<details::BitsetElement>>;
 /// This BitSet variant can be stored inside shared memory.
 pub type RelocatableBitSet = details::BitSet<RelocatablePointer<details::BitsetElement>>;
@@ -59,6 +61,7 @@ pub mod details {
     use super::*;
 
     pub type BitsetElement = IoxAtomicU8;
+    type BitsetElementType = u8;
     const BITSET_ELEMENT_BITSIZE: usize = core::mem::size_of::<BitsetElement>() * 8;
 
     struct Id {
@@ -79,15 +82,16 @@ pub mod details {
     #[repr(C)]
     pub struct BitSet<PointerType: PointerTrait<BitsetElement>> {
         data_ptr: PointerType,
-        capacity: usize,
-        array_capacity: usize,
-        reset_position: IoxAtomicUsize,
+        capacity: u64,
+        array_capacity: u64,
+        reset_position: IoxAtomicU64,
         is_memory_initialized: IoxAtomicBool,
     }
 
     unsafe impl<PointerType: PointerTrait<BitsetElement>> Send for BitSet<PointerType> {}
     unsafe impl<PointerType: PointerTrait<BitsetElement>> Sync for BitSet<PointerType> {}
 
+    #[cfg(feature = "std")]
     impl BitSet<OwningPointer<BitsetElement>> {
         /// Create a new [`BitSet`] with data located in the heap.
         ///
@@ -105,10 +109,10 @@ pub mod details {
 
             Self {
                 data_ptr,
-                capacity,
-                array_capacity,
+                capacity: capacity as u64,
+                array_capacity: array_capacity as u64,
                 is_memory_initialized: IoxAtomicBool::new(true),
-                reset_position: IoxAtomicUsize::new(0),
+                reset_position: IoxAtomicU64::new(0),
             }
         }
     }
@@ -117,10 +121,10 @@ pub mod details {
         unsafe fn new_uninit(capacity: usize) -> Self {
             Self {
                 data_ptr: RelocatablePointer::new_uninit(),
-                capacity,
-                array_capacity: Self::array_capacity(capacity),
+                capacity: capacity as u64,
+                array_capacity: Self::array_capacity(capacity) as u64,
                 is_memory_initialized: IoxAtomicBool::new(false),
-                reset_position: IoxAtomicUsize::new(0),
+                reset_position: IoxAtomicU64::new(0),
             }
         }
 
@@ -135,13 +139,13 @@ pub mod details {
 
             let memory = fail!(from self, when allocator
             .allocate(Layout::from_size_align_unchecked(
-                    core::mem::size_of::<BitsetElement>() * self.array_capacity,
+                    core::mem::size_of::<BitsetElement>() * self.array_capacity as usize,
                     core::mem::align_of::<BitsetElement>())),
             "Failed to initialize since the allocation of the data memory failed.");
 
             self.data_ptr.init(memory);
 
-            for i in 0..self.array_capacity {
+            for i in 0..self.array_capacity as usize {
                 unsafe {
                     (self.data_ptr.as_ptr() as *mut BitsetElement)
                         .add(i)
@@ -174,7 +178,7 @@ pub mod details {
 
         /// Returns the capacity of the BitSet
         pub fn capacity(&self) -> usize {
-            self.capacity
+            self.capacity as usize
         }
 
         #[inline(always)]
@@ -244,7 +248,7 @@ pub mod details {
         pub fn set(&self, id: usize) -> bool {
             self.verify_init("set()");
             debug_assert!(
-                id < self.capacity,
+                id < self.capacity(),
                 "This should never happen. Out of bounds access with index {}.",
                 id
             );
@@ -252,15 +256,73 @@ pub mod details {
             self.set_bit(Id::new(id))
         }
 
+        /// Resets a bit in the BitSet
+        /// If the bit was successfully reset it returns true, if the bit was not set it
+        /// returns false.
+        pub fn reset(&self, id: usize) -> bool {
+            self.verify_init("reset()");
+            debug_assert!(
+                id < self.capacity(),
+                "This should never happen. Out of bounds access with index {}.",
+                id
+            );
+
+            self.clear_bit(Id::new(id))
+        }
+
+        /// Returns true if the bit is set, otherwise false.
+        pub fn is_set(&self, id: usize) -> bool {
+            self.verify_init("is_set()");
+            debug_assert!(
+                id < self.capacity(),
+                "This should never happen. Out of bounds access with index {}.",
+                id
+            );
+
+            let id = Id::new(id);
+            let value = unsafe { (*self.data_ptr.as_ptr().add(id.index)).load(Ordering::Relaxed) };
+            value & (1 << id.bit) != 0
+        }
+
+        /// Returns the index of the first bit that is not set. If all bits are set it returns
+        /// [`None`]. Since other threads may modify the BitSet concurrently, the bit could
+        /// already be set when the result is used, therefore it shall be claimed with
+        /// [`BitSet::set()`].
+        pub fn find_first_unset(&self) -> Option<usize> {
+            self.verify_init("find_first_unset()");
+
+            for i in 0..self.array_capacity as usize {
+                let value = unsafe { (*self.data_ptr.as_ptr().add(i)).load(Ordering::Relaxed) };
+                if value != BitsetElementType::MAX {
+                    let id = i * BITSET_ELEMENT_BITSIZE + (!value).trailing_zeros() as usize;
+                    return (id < self.capacity()).then_some(id);
+                }
+            }
+
+            None
+        }
+
+        /// Returns an iterator over the indices of all set bits in ascending order. Every bit
+        /// is read when the iterator reaches it, so concurrent modifications may or may not be
+        /// observed.
+        pub fn iter(&self) -> BitSetIter<'_, PointerType> {
+            self.verify_init("iter()");
+
+            BitSetIter {
+                bitset: self,
+                position: 0,
+            }
+        }
+
         /// Resets the next set bit and returns the bit index. If no bit was set it returns
         /// [`None`].
         pub fn reset_next(&self) -> Option<usize> {
             self.verify_init("reset_next()");
 
-            let current_position = self.reset_position.load(Ordering::Relaxed);
-            for pos in (current_position..self.capacity).chain(0..current_position) {
+            let current_position = self.reset_position.load(Ordering::Relaxed) as usize;
+            for pos in (current_position..self.capacity()).chain(0..current_position) {
                 if self.clear_bit(Id::new(pos)) {
-                    self.reset_position.store(pos + 1, Ordering::Relaxed);
+                    self.reset_position.store(pos as u64 + 1, Ordering::Relaxed);
                     return Some(pos);
                 }
             }
@@ -273,7 +335,7 @@ pub mod details {
         pub fn reset_all<F: FnMut(usize)>(&self, mut callback: F) {
             self.verify_init("reset_all()");
 
-            for i in 0..self.array_capacity {
+            for i in 0..self.array_capacity as usize {
                 let value = unsafe { (*self.data_ptr.as_ptr().add(i)).swap(0, Ordering::Relaxed) };
                 let main_index = i * BITSET_ELEMENT_BITSIZE;
                 for b in 0..BITSET_ELEMENT_BITSIZE {
@@ -284,6 +346,28 @@ pub mod details {
             }
         }
     }
+
+    /// Iterator over the indices of all set bits of a [`BitSet`], see [`BitSet::iter()`].
+    pub struct BitSetIter<'a, PointerType: PointerTrait<BitsetElement> + Debug> {
+        bitset: &'a BitSet<PointerType>,
+        position: usize,
+    }
+
+    impl<PointerType: PointerTrait<BitsetElement> + Debug> Iterator for BitSetIter<'_, PointerType> {
+        type Item = usize;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            while self.position < self.bitset.capacity() {
+                let id = self.position;
+                self.position += 1;
+                if self.bitset.is_set(id) {
+                    return Some(id);
+                }
+            }
+
+            None
+        }
+    }
 }
 
 /// This BitSet variant owns all data it requires.
@@ -336,6 +420,27 @@ impl<const CAPACITY: usize> FixedSizeBitSet<CAPACITY> {
         self.bitset.set(id)
     }
 
+    /// Resets a bit in the BitSet
+    pub fn reset(&self, id: usize) -> bool {
+        self.bitset.reset(id)
+    }
+
+    /// Returns true if the bit is set, otherwise false.
+    pub fn is_set(&self, id: usize) -> bool {
+        self.bitset.is_set(id)
+    }
+
+    /// Returns the index of the first bit that is not set. If all bits are set it returns
+    /// [`None`].
+    pub fn find_first_unset(&self) -> Option<usize> {
+        self.bitset.find_first_unset()
+    }
+
+    /// Returns an iterator over the indices of all set bits in ascending order.
+    pub fn iter(&self) -> details::BitSetIter<'_, RelocatablePointer<details::BitsetElement>> {
+        self.bitset.iter()
+    }
+
     /// Reset every set bit in the BitSet and call the provided callback for every bit that
     /// was set.
     pub fn reset_next(&self) -> Option<usize> {
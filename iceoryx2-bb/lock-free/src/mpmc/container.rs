@@ -20,7 +20,8 @@
 //!
 //! To iterate/acquire all container elements
 //! a [`ContainerState`] has to be created with [`Container::get_state()`] and can be updated with
-//! [`Container::update_state()`].
+//! [`Container::update_state()`]. The [`ContainerState`] stores a copy of all elements on the
+//! heap and is therefore only available with the `std` feature.
 //!
 //! # Example
 //!
@@ -109,6 +110,7 @@ impl ContainerHandle {
 
 /// Contains a state of the [`Container`]. Can be created with [`Container::get_state()`] and
 /// updated when the [`Container`] has changed with [`Container::update_state()`].
+#[cfg(feature = "std")]
 #[derive(Debug)]
 pub struct ContainerState<T: Copy + Debug> {
     container_id: u64,
@@ -117,6 +119,7 @@ pub struct ContainerState<T: Copy + Debug> {
     active_index: Vec<u64>,
 }
 
+#[cfg(feature = "std")]
 impl<T: Copy + Debug> ContainerState<T> {
     fn new(container_id: u64, capacity: usize) -> Self {
         Self {
@@ -169,10 +172,10 @@ pub struct Container<T: Copy + Debug> {
     // must be first member, otherwise the offset calculations fail
     active_index_ptr: RelocatablePointer<IoxAtomicU64>,
     data_ptr: RelocatablePointer<UnsafeCell<MaybeUninit<T>>>,
-    capacity: usize,
+    capacity: u64,
     change_counter: IoxAtomicU64,
-    is_initialized: IoxAtomicBool,
     container_id: UniqueId,
+    is_initialized: IoxAtomicBool,
     // must be the last member, since it is a relocatable container as well and then the offset
     // calculations would again fail
     index_set: UniqueIndexSet,
@@ -192,7 +195,7 @@ impl<T: Copy + Debug> RelocatableContainer for Container<T> {
                 distance_to_active_index as usize
                     + capacity * core::mem::size_of::<IoxAtomicBool>(),
             ) as isize),
-            capacity,
+            capacity: capacity as u64,
             change_counter: IoxAtomicU64::new(0),
             index_set: UniqueIndexSet::new_uninit(capacity),
             is_initialized: IoxAtomicBool::new(false),
@@ -212,18 +215,18 @@ impl<T: Copy + Debug> RelocatableContainer for Container<T> {
             "{} since the underlying UniqueIndexSet could not be initialized", msg);
 
         self.active_index_ptr.init(fail!(from self, when allocator.allocate(Layout::from_size_align_unchecked(
-                        core::mem::size_of::<IoxAtomicU64>() * self.capacity,
+                        core::mem::size_of::<IoxAtomicU64>() * self.capacity as usize,
                         core::mem::align_of::<IoxAtomicU64>())), "{} since the allocation of the active index memory failed.",
                 msg));
         self.data_ptr.init(
             fail!(from self, when allocator.allocate(Layout::from_size_align_unchecked(
-                    core::mem::size_of::<T>() * self.capacity,
+                    core::mem::size_of::<T>() * self.capacity as usize,
                     core::mem::align_of::<T>())),
                 "{} since the allocation of the data memory failed.", msg
             ),
         );
 
-        for i in 0..self.capacity {
+        for i in 0..self.capacity as usize {
             (self.active_index_ptr.as_ptr() as *mut IoxAtomicU64)
                 .add(i)
                 .write(IoxAtomicU64::new(0));
@@ -260,7 +263,7 @@ impl<T: Copy + Debug> Container<T> {
 
     /// Returns the capacity of the container.
     pub fn capacity(&self) -> usize {
-        self.capacity
+        self.capacity as usize
     }
 
     /// Returns true if the container is locked, otherwise false.
@@ -347,10 +350,11 @@ impl<T: Copy + Debug> Container<T> {
     ///
     ///  * Ensure that [`Container::init()`] was called before calling this method
     ///
+    #[cfg(feature = "std")]
     pub unsafe fn get_state(&self) -> ContainerState<T> {
         self.verify_init("get_state()");
 
-        let mut state = ContainerState::new(self.container_id.value(), self.capacity);
+        let mut state = ContainerState::new(self.container_id.value(), self.capacity as usize);
         self.update_state(&mut state);
         state
     }
@@ -364,6 +368,7 @@ impl<T: Copy + Debug> Container<T> {
     ///  * Ensure that the input argument `previous_state` was acquired by the same [`Container`]
     ///     with [`Container::get_state()`], otherwise the method will panic.
     ///
+    #[cfg(feature = "std")]
     pub unsafe fn update_state(&self, previous_state: &mut ContainerState<T>) -> bool {
         debug_assert!(
             previous_state.container_id == self.container_id.value(),
@@ -381,7 +386,7 @@ impl<T: Copy + Debug> Container<T> {
         // the previous_state is updated again with the next clone_state iteration
         previous_state.current_change_counter = current_change_counter;
 
-        for i in 0..self.capacity {
+        for i in 0..self.capacity as usize {
             // go through here element by element and do not start the operation from the
             // beginning when the content has changed.
             // only copy single entries otherwise we encounter starvation since this is a
@@ -526,6 +531,7 @@ impl<T: Copy + Debug, const CAPACITY: usize> FixedSizeContainer<T, CAPACITY> {
 
     /// Returns [`ContainerState`] which contains all elements of this container. Be aware that
     /// this state can be out of date as soon as it is returned from this function.
+    #[cfg(feature = "std")]
     pub fn get_state(&self) -> ContainerState<T> {
         unsafe { self.container.get_state() }
     }
@@ -552,6 +558,7 @@ impl<T: Copy + Debug, const CAPACITY: usize> FixedSizeContainer<T, CAPACITY> {
     ///  * Ensure that the input argument `previous_state` was acquired by the same [`Container`]
     ///     with [`Container::get_state()`].
     ///
+    #[cfg(feature = "std")]
     pub unsafe fn update_state(&self, previous_state: &mut ContainerState<T>) -> bool {
         unsafe { self.container.update_state(previous_state) }
     }
@@ -111,6 +111,17 @@ impl<T: Copy> UnrestrictedAtomic<T> {
         }
     }
 
+    /// Releases a [`Producer`] that was acquired by a process that terminated before it could
+    /// release it. Afterwards, [`UnrestrictedAtomic::acquire_producer()`] succeeds again.
+    ///
+    /// # Safety
+    ///
+    ///  * the owner of the acquired [`Producer`] must no longer exist, otherwise there may be
+    ///    two producers at the same time
+    pub unsafe fn release_abandoned_producer(&self) {
+        self.has_producer.store(true, Ordering::Relaxed);
+    }
+
     fn store(&self, new_value: T) {
         let write_cell = self.write_cell.load(Ordering::Relaxed);
         unsafe {
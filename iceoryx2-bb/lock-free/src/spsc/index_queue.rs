@@ -42,22 +42,24 @@
 //! }
 //! ```
 
-use core::{alloc::Layout, cell::UnsafeCell, fmt::Debug, sync::atomic::Ordering};
+use core::{alloc::Layout, fmt::Debug, sync::atomic::Ordering};
 
+#[cfg(feature = "std")]
+use iceoryx2_bb_elementary::owning_pointer::OwningPointer;
 use iceoryx2_bb_elementary::{
-    bump_allocator::BumpAllocator, owning_pointer::OwningPointer, pointer_trait::PointerTrait,
+    bump_allocator::BumpAllocator, pointer_trait::PointerTrait,
     relocatable_container::RelocatableContainer, relocatable_ptr::RelocatablePointer,
 };
 use iceoryx2_bb_log::{fail, fatal_panic};
-use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64};
 
 /// The [`Producer`] of the [`IndexQueue`]/[`FixedSizeIndexQueue`] which can add values to it
 /// via [`Producer::push()`].
-pub struct Producer<'a, PointerType: PointerTrait<UnsafeCell<u64>> + Debug> {
+pub struct Producer<'a, PointerType: PointerTrait<IoxAtomicU64> + Debug> {
     queue: &'a details::IndexQueue<PointerType>,
 }
 
-impl<PointerType: PointerTrait<UnsafeCell<u64>> + Debug> Producer<'_, PointerType> {
+impl<PointerType: PointerTrait<IoxAtomicU64> + Debug> Producer<'_, PointerType> {
     /// Adds a new value to the [`IndexQueue`]/[`FixedSizeIndexQueue`]. If the queue is full
     /// it returns false, otherwise true.
     pub fn push(&mut self, t: u64) -> bool {
@@ -65,7 +67,7 @@ impl<PointerType: PointerTrait<UnsafeCell<u64>> + Debug> Producer<'_, PointerTyp
     }
 }
 
-impl<PointerType: PointerTrait<UnsafeCell<u64>> + Debug> Drop for Producer<'_, PointerType> {
+impl<PointerType: PointerTrait<IoxAtomicU64> + Debug> Drop for Producer<'_, PointerType> {
     fn drop(&mut self) {
         self.queue.has_producer.store(true, Ordering::Relaxed);
     }
@@ -73,11 +75,11 @@ impl<PointerType: PointerTrait<UnsafeCell<u64>> + Debug> Drop for Producer<'_, P
 
 /// The [`Consumer`] of the [`IndexQueue`]/[`FixedSizeIndexQueue`] which can acquire values from it
 /// via [`Consumer::pop()`].
-pub struct Consumer<'a, PointerType: PointerTrait<UnsafeCell<u64>> + Debug> {
+pub struct Consumer<'a, PointerType: PointerTrait<IoxAtomicU64> + Debug> {
     queue: &'a details::IndexQueue<PointerType>,
 }
 
-impl<PointerType: PointerTrait<UnsafeCell<u64>> + Debug> Consumer<'_, PointerType> {
+impl<PointerType: PointerTrait<IoxAtomicU64> + Debug> Consumer<'_, PointerType> {
     /// Acquires a value from the [`IndexQueue`]/[`FixedSizeIndexQueue`]. If the queue is empty
     /// it returns [`None`] otherwise the value.
     pub fn pop(&mut self) -> Option<u64> {
@@ -85,14 +87,15 @@ impl<PointerType: PointerTrait<UnsafeCell<u64>> + Debug> Consumer<'_, PointerTyp
     }
 }
 
-impl<PointerType: PointerTrait<UnsafeCell<u64>> + Debug> Drop for Consumer<'_, PointerType> {
+impl<PointerType: PointerTrait<IoxAtomicU64> + Debug> Drop for Consumer<'_, PointerType> {
     fn drop(&mut self) {
         self.queue.has_consumer.store(true, Ordering::Relaxed);
     }
 }
 
-pub type IndexQueue = details::IndexQueue<OwningPointer<UnsafeCell<u64>>>;
-pub type RelocatableIndexQueue = details::IndexQueue<RelocatablePointer<UnsafeCell<u64>>>;
+#[cfg(feature = "std")]
+pub type IndexQueue = details::IndexQueue<OwningPointer<IoxAtomicU64>>;
+pub type RelocatableIndexQueue = details::IndexQueue<RelocatablePointer<IoxAtomicU64>>;
 
 pub mod details {
     use core::fmt::Debug;
@@ -105,32 +108,33 @@ pub mod details {
     /// queue is created.
     #[repr(C)]
     #[derive(Debug)]
-    pub struct IndexQueue<PointerType: PointerTrait<UnsafeCell<u64>>> {
+    pub struct IndexQueue<PointerType: PointerTrait<IoxAtomicU64>> {
         data_ptr: PointerType,
-        capacity: usize,
-        write_position: IoxAtomicUsize,
-        read_position: IoxAtomicUsize,
+        capacity: u64,
+        write_position: IoxAtomicU64,
+        read_position: IoxAtomicU64,
         pub(super) has_producer: IoxAtomicBool,
         pub(super) has_consumer: IoxAtomicBool,
         is_memory_initialized: IoxAtomicBool,
     }
 
-    unsafe impl<PointerType: PointerTrait<UnsafeCell<u64>>> Sync for IndexQueue<PointerType> {}
-    unsafe impl<PointerType: PointerTrait<UnsafeCell<u64>>> Send for IndexQueue<PointerType> {}
+    unsafe impl<PointerType: PointerTrait<IoxAtomicU64>> Sync for IndexQueue<PointerType> {}
+    unsafe impl<PointerType: PointerTrait<IoxAtomicU64>> Send for IndexQueue<PointerType> {}
 
-    impl IndexQueue<OwningPointer<UnsafeCell<u64>>> {
+    #[cfg(feature = "std")]
+    impl IndexQueue<OwningPointer<IoxAtomicU64>> {
         pub fn new(capacity: usize) -> Self {
-            let mut data_ptr = OwningPointer::<UnsafeCell<u64>>::new_with_alloc(capacity);
+            let mut data_ptr = OwningPointer::<IoxAtomicU64>::new_with_alloc(capacity);
 
             for i in 0..capacity {
-                unsafe { data_ptr.as_mut_ptr().add(i).write(UnsafeCell::new(0)) };
+                unsafe { data_ptr.as_mut_ptr().add(i).write(IoxAtomicU64::new(0)) };
             }
 
             Self {
                 data_ptr,
-                capacity,
-                write_position: IoxAtomicUsize::new(0),
-                read_position: IoxAtomicUsize::new(0),
+                capacity: capacity as u64,
+                write_position: IoxAtomicU64::new(0),
+                read_position: IoxAtomicU64::new(0),
                 has_producer: IoxAtomicBool::new(true),
                 has_consumer: IoxAtomicBool::new(true),
                 is_memory_initialized: IoxAtomicBool::new(true),
@@ -138,13 +142,13 @@ pub mod details {
         }
     }
 
-    impl RelocatableContainer for IndexQueue<RelocatablePointer<UnsafeCell<u64>>> {
+    impl RelocatableContainer for IndexQueue<RelocatablePointer<IoxAtomicU64>> {
         unsafe fn new_uninit(capacity: usize) -> Self {
             Self {
                 data_ptr: RelocatablePointer::new_uninit(),
-                capacity,
-                write_position: IoxAtomicUsize::new(0),
-                read_position: IoxAtomicUsize::new(0),
+                capacity: capacity as u64,
+                write_position: IoxAtomicU64::new(0),
+                read_position: IoxAtomicU64::new(0),
                 has_producer: IoxAtomicBool::new(true),
                 has_consumer: IoxAtomicBool::new(true),
                 is_memory_initialized: IoxAtomicBool::new(false),
@@ -161,14 +165,14 @@ pub mod details {
 
             self.data_ptr.init(fail!(from self, when allocator
             .allocate(Layout::from_size_align_unchecked(
-                    core::mem::size_of::<u64>() * self.capacity,
-                    core::mem::align_of::<u64>())),
+                    core::mem::size_of::<IoxAtomicU64>() * self.capacity as usize,
+                    core::mem::align_of::<IoxAtomicU64>())),
             "Failed to initialize since the allocation of the data memory failed."));
 
-            for i in 0..self.capacity {
-                (self.data_ptr.as_ptr() as *mut UnsafeCell<u64>)
+            for i in 0..self.capacity as usize {
+                (self.data_ptr.as_ptr() as *mut IoxAtomicU64)
                     .add(i)
-                    .write(UnsafeCell::new(0));
+                    .write(IoxAtomicU64::new(0));
             }
 
             self.is_memory_initialized.store(true, Ordering::Relaxed);
@@ -180,7 +184,7 @@ pub mod details {
         }
     }
 
-    impl<PointerType: PointerTrait<UnsafeCell<u64>> + Debug> IndexQueue<PointerType> {
+    impl<PointerType: PointerTrait<IoxAtomicU64> + Debug> IndexQueue<PointerType> {
         #[inline(always)]
         fn verify_init(&self, source: &str) {
             debug_assert!(
@@ -193,11 +197,16 @@ pub mod details {
         /// Returns the amount of memory required to create a [`IndexQueue`] with the provided
         /// capacity.
         pub const fn const_memory_size(capacity: usize) -> usize {
-            unaligned_mem_size::<UnsafeCell<u64>>(capacity)
+            unaligned_mem_size::<IoxAtomicU64>(capacity)
         }
 
-        unsafe fn at(&self, position: usize) -> *mut u64 {
-            (*self.data_ptr.as_ptr().add(position % self.capacity)).get()
+        fn at(&self, position: u64) -> &IoxAtomicU64 {
+            unsafe {
+                &*self
+                    .data_ptr
+                    .as_ptr()
+                    .add((position % self.capacity) as usize)
+            }
         }
 
         /// Acquires the [`Producer`] of the [`IndexQueue`]. This is threadsafe and lock-free without
@@ -278,7 +287,7 @@ pub mod details {
                 return false;
             }
 
-            unsafe { self.at(write_position).write(value) };
+            self.at(write_position).store(value, Ordering::Relaxed);
             ////////////////
             // SYNC POINT
             ////////////////
@@ -304,7 +313,7 @@ pub mod details {
                 return None;
             }
 
-            let value = unsafe { *self.at(read_position) };
+            let value = self.at(read_position).load(Ordering::Relaxed);
             // prevent that `out` and `read_position` statements are reordered according to
             // the AS-IF rule.
             core::sync::atomic::fence(Ordering::AcqRel);
@@ -314,7 +323,7 @@ pub mod details {
             Some(value)
         }
 
-        fn acquire_read_and_write_position(&self) -> (usize, usize) {
+        fn acquire_read_and_write_position(&self) -> (u64, u64) {
             loop {
                 let write_position = self.write_position.load(Ordering::Relaxed);
                 let read_position = self.read_position.load(Ordering::Relaxed);
@@ -340,12 +349,12 @@ pub mod details {
         ///       could be out-of-date as soon as it is acquired.
         pub fn len(&self) -> usize {
             let (write_position, read_position) = self.acquire_read_and_write_position();
-            write_position - read_position
+            (write_position - read_position) as usize
         }
 
         /// Returns the capacity of the [`IndexQueue`].
         pub const fn capacity(&self) -> usize {
-            self.capacity
+            self.capacity as usize
         }
 
         /// Returns true when the [`IndexQueue`] is full, otherwise false.
@@ -363,7 +372,7 @@ pub mod details {
 #[repr(C)]
 pub struct FixedSizeIndexQueue<const CAPACITY: usize> {
     state: RelocatableIndexQueue,
-    data: [UnsafeCell<u64>; CAPACITY],
+    data: [IoxAtomicU64; CAPACITY],
 }
 
 unsafe impl<const CAPACITY: usize> Sync for FixedSizeIndexQueue<CAPACITY> {}
@@ -380,7 +389,7 @@ impl<const CAPACITY: usize> FixedSizeIndexQueue<CAPACITY> {
     pub fn new() -> Self {
         let mut new_self = Self {
             state: unsafe { RelocatableIndexQueue::new_uninit(CAPACITY) },
-            data: core::array::from_fn(|_| UnsafeCell::new(0)),
+            data: core::array::from_fn(|_| IoxAtomicU64::new(0)),
         };
 
         let allocator = BumpAllocator::new(core::ptr::addr_of!(new_self.data) as usize);
@@ -395,12 +404,12 @@ impl<const CAPACITY: usize> FixedSizeIndexQueue<CAPACITY> {
     }
 
     /// See [`IndexQueue::acquire_producer()`]
-    pub fn acquire_producer(&self) -> Option<Producer<'_, RelocatablePointer<UnsafeCell<u64>>>> {
+    pub fn acquire_producer(&self) -> Option<Producer<'_, RelocatablePointer<IoxAtomicU64>>> {
         self.state.acquire_producer()
     }
 
     /// See [`IndexQueue::acquire_consumer()`]
-    pub fn acquire_consumer(&self) -> Option<Consumer<'_, RelocatablePointer<UnsafeCell<u64>>>> {
+    pub fn acquire_consumer(&self) -> Option<Consumer<'_, RelocatablePointer<IoxAtomicU64>>> {
         self.state.acquire_consumer()
     }
 
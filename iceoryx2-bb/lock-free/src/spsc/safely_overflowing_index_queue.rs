@@ -43,11 +43,13 @@
 //! }
 //! ```
 
-use core::{alloc::Layout, cell::UnsafeCell, fmt::Debug, sync::atomic::Ordering};
-use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
+use core::{alloc::Layout, fmt::Debug, sync::atomic::Ordering};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64};
 
+#[cfg(feature = "std")]
+use iceoryx2_bb_elementary::owning_pointer::OwningPointer;
 use iceoryx2_bb_elementary::{
-    bump_allocator::BumpAllocator, owning_pointer::OwningPointer, pointer_trait::PointerTrait,
+    bump_allocator::BumpAllocator, pointer_trait::PointerTrait,
     relocatable_container::RelocatableContainer, relocatable_ptr::RelocatablePointer,
 };
 use iceoryx2_bb_log::{fail, fatal_panic};
@@ -55,11 +57,11 @@ use iceoryx2_bb_log::{fail, fatal_panic};
 /// The [`Producer`] of the [`SafelyOverflowingIndexQueue`]/[`FixedSizeSafelyOverflowingIndexQueue`]
 /// which can add values to it via [`Producer::push()`].
 #[derive(Debug)]
-pub struct Producer<'a, PointerType: PointerTrait<UnsafeCell<u64>>> {
+pub struct Producer<'a, PointerType: PointerTrait<IoxAtomicU64>> {
     queue: &'a details::SafelyOverflowingIndexQueue<PointerType>,
 }
 
-impl<PointerType: PointerTrait<UnsafeCell<u64>> + Debug> Producer<'_, PointerType> {
+impl<PointerType: PointerTrait<IoxAtomicU64> + Debug> Producer<'_, PointerType> {
     /// Adds a new value to the [`SafelyOverflowingIndexQueue`]/[`FixedSizeSafelyOverflowingIndexQueue`].
     /// If the queue is full it returns false, otherwise true.
     pub fn push(&mut self, t: u64) -> Option<u64> {
@@ -67,7 +69,7 @@ impl<PointerType: PointerTrait<UnsafeCell<u64>> + Debug> Producer<'_, PointerTyp
     }
 }
 
-impl<PointerType: PointerTrait<UnsafeCell<u64>>> Drop for Producer<'_, PointerType> {
+impl<PointerType: PointerTrait<IoxAtomicU64>> Drop for Producer<'_, PointerType> {
     fn drop(&mut self) {
         self.queue.has_producer.store(true, Ordering::Relaxed);
     }
@@ -76,11 +78,11 @@ impl<PointerType: PointerTrait<UnsafeCell<u64>>> Drop for Producer<'_, PointerTy
 /// The [`Consumer`] of the [`SafelyOverflowingIndexQueue`]/[`FixedSizeSafelyOverflowingIndexQueue`]
 /// which can acquire values from it via [`Consumer::pop()`].
 #[derive(Debug)]
-pub struct Consumer<'a, PointerType: PointerTrait<UnsafeCell<u64>>> {
+pub struct Consumer<'a, PointerType: PointerTrait<IoxAtomicU64>> {
     queue: &'a details::SafelyOverflowingIndexQueue<PointerType>,
 }
 
-impl<PointerType: PointerTrait<UnsafeCell<u64>> + Debug> Consumer<'_, PointerType> {
+impl<PointerType: PointerTrait<IoxAtomicU64> + Debug> Consumer<'_, PointerType> {
     /// Acquires a value from the [`SafelyOverflowingIndexQueue`]/[`FixedSizeSafelyOverflowingIndexQueue`].
     /// If the queue is empty it returns [`None`] otherwise the value.
     pub fn pop(&mut self) -> Option<u64> {
@@ -88,19 +90,20 @@ impl<PointerType: PointerTrait<UnsafeCell<u64>> + Debug> Consumer<'_, PointerTyp
     }
 }
 
-impl<PointerType: PointerTrait<UnsafeCell<u64>>> Drop for Consumer<'_, PointerType> {
+impl<PointerType: PointerTrait<IoxAtomicU64>> Drop for Consumer<'_, PointerType> {
     fn drop(&mut self) {
         self.queue.has_consumer.store(true, Ordering::Relaxed);
     }
 }
 
 /// Non-relocatable version of the safely overflowing index queue
+#[cfg(feature = "std")]
 pub type SafelyOverflowingIndexQueue =
-    details::SafelyOverflowingIndexQueue<OwningPointer<UnsafeCell<u64>>>;
+    details::SafelyOverflowingIndexQueue<OwningPointer<IoxAtomicU64>>;
 
 /// Relocatable version of the safely overflowing index queue
 pub type RelocatableSafelyOverflowingIndexQueue =
-    details::SafelyOverflowingIndexQueue<RelocatablePointer<UnsafeCell<u64>>>;
+    details::SafelyOverflowingIndexQueue<RelocatablePointer<IoxAtomicU64>>;
 
 pub mod details {
     use iceoryx2_bb_elementary::math::unaligned_mem_size;
@@ -112,38 +115,39 @@ pub mod details {
     /// and overridden with the newest element.
     #[derive(Debug)]
     #[repr(C)]
-    pub struct SafelyOverflowingIndexQueue<PointerType: PointerTrait<UnsafeCell<u64>>> {
+    pub struct SafelyOverflowingIndexQueue<PointerType: PointerTrait<IoxAtomicU64>> {
         data_ptr: PointerType,
-        capacity: usize,
-        write_position: IoxAtomicUsize,
-        read_position: IoxAtomicUsize,
+        capacity: u64,
+        write_position: IoxAtomicU64,
+        read_position: IoxAtomicU64,
         pub(super) has_producer: IoxAtomicBool,
         pub(super) has_consumer: IoxAtomicBool,
         is_memory_initialized: IoxAtomicBool,
     }
 
-    unsafe impl<PointerType: PointerTrait<UnsafeCell<u64>>> Sync
+    unsafe impl<PointerType: PointerTrait<IoxAtomicU64>> Sync
         for SafelyOverflowingIndexQueue<PointerType>
     {
     }
-    unsafe impl<PointerType: PointerTrait<UnsafeCell<u64>>> Send
+    unsafe impl<PointerType: PointerTrait<IoxAtomicU64>> Send
         for SafelyOverflowingIndexQueue<PointerType>
     {
     }
 
-    impl SafelyOverflowingIndexQueue<OwningPointer<UnsafeCell<u64>>> {
+    #[cfg(feature = "std")]
+    impl SafelyOverflowingIndexQueue<OwningPointer<IoxAtomicU64>> {
         pub fn new(capacity: usize) -> Self {
-            let mut data_ptr = OwningPointer::<UnsafeCell<u64>>::new_with_alloc(capacity + 1);
+            let mut data_ptr = OwningPointer::<IoxAtomicU64>::new_with_alloc(capacity + 1);
 
             for i in 0..capacity + 1 {
-                unsafe { data_ptr.as_mut_ptr().add(i).write(UnsafeCell::new(0)) };
+                unsafe { data_ptr.as_mut_ptr().add(i).write(IoxAtomicU64::new(0)) };
             }
 
             Self {
                 data_ptr,
-                capacity,
-                write_position: IoxAtomicUsize::new(0),
-                read_position: IoxAtomicUsize::new(0),
+                capacity: capacity as u64,
+                write_position: IoxAtomicU64::new(0),
+                read_position: IoxAtomicU64::new(0),
                 has_producer: IoxAtomicBool::new(true),
                 has_consumer: IoxAtomicBool::new(true),
                 is_memory_initialized: IoxAtomicBool::new(true),
@@ -151,13 +155,13 @@ pub mod details {
         }
     }
 
-    impl RelocatableContainer for SafelyOverflowingIndexQueue<RelocatablePointer<UnsafeCell<u64>>> {
+    impl RelocatableContainer for SafelyOverflowingIndexQueue<RelocatablePointer<IoxAtomicU64>> {
         unsafe fn new_uninit(capacity: usize) -> Self {
             Self {
                 data_ptr: RelocatablePointer::new_uninit(),
-                capacity,
-                write_position: IoxAtomicUsize::new(0),
-                read_position: IoxAtomicUsize::new(0),
+                capacity: capacity as u64,
+                write_position: IoxAtomicU64::new(0),
+                read_position: IoxAtomicU64::new(0),
                 has_producer: IoxAtomicBool::new(true),
                 has_consumer: IoxAtomicBool::new(true),
                 is_memory_initialized: IoxAtomicBool::new(false),
@@ -174,14 +178,14 @@ pub mod details {
 
             self.data_ptr.init(fail!(from self, when allocator
             .allocate( Layout::from_size_align_unchecked(
-                    core::mem::size_of::<u64>() * (self.capacity + 1),
-                    core::mem::align_of::<u64>())),
+                    core::mem::size_of::<IoxAtomicU64>() * (self.capacity as usize + 1),
+                    core::mem::align_of::<IoxAtomicU64>())),
             "Failed to initialize since the allocation of the data memory failed."));
 
-            for i in 0..self.capacity + 1 {
-                (self.data_ptr.as_ptr() as *mut UnsafeCell<usize>)
+            for i in 0..self.capacity as usize + 1 {
+                (self.data_ptr.as_ptr() as *mut IoxAtomicU64)
                     .add(i)
-                    .write(UnsafeCell::new(0));
+                    .write(IoxAtomicU64::new(0));
             }
 
             self.is_memory_initialized.store(true, Ordering::Relaxed);
@@ -193,7 +197,7 @@ pub mod details {
         }
     }
 
-    impl<PointerType: PointerTrait<UnsafeCell<u64>> + Debug> SafelyOverflowingIndexQueue<PointerType> {
+    impl<PointerType: PointerTrait<IoxAtomicU64> + Debug> SafelyOverflowingIndexQueue<PointerType> {
         #[inline(always)]
         fn verify_init(&self, source: &str) {
             debug_assert!(
@@ -206,11 +210,16 @@ pub mod details {
         /// Returns the amount of memory required to create a [`SafelyOverflowingIndexQueue`] with
         /// the provided capacity.
         pub const fn const_memory_size(capacity: usize) -> usize {
-            unaligned_mem_size::<UnsafeCell<u64>>(capacity + 1)
+            unaligned_mem_size::<IoxAtomicU64>(capacity + 1)
         }
 
-        fn at(&self, position: usize) -> *mut u64 {
-            unsafe { (*self.data_ptr.as_ptr().add(position % (self.capacity + 1))).get() }
+        fn at(&self, position: u64) -> &IoxAtomicU64 {
+            unsafe {
+                &*self
+                    .data_ptr
+                    .as_ptr()
+                    .add((position % (self.capacity + 1)) as usize)
+            }
         }
         /// Acquires the [`Producer`] of the [`SafelyOverflowingIndexQueue`]. This is threadsafe and
         /// lock-free without restrictions but when another thread has already acquired the [`Producer`]
@@ -296,7 +305,7 @@ pub mod details {
             let read_position = self.read_position.load(Ordering::Relaxed);
             let is_full = write_position == read_position + self.capacity;
 
-            unsafe { self.at(write_position).write(value) };
+            self.at(write_position).store(value, Ordering::Relaxed);
 
             ////////////////
             // SYNC POINT W
@@ -318,7 +327,7 @@ pub mod details {
                     )
                     .is_ok()
             {
-                let value = unsafe { *self.at(read_position) };
+                let value = self.at(read_position).load(Ordering::Relaxed);
                 Some(value)
             } else {
                 None
@@ -347,7 +356,7 @@ pub mod details {
 
             let mut value;
             loop {
-                value = unsafe { *self.at(read_position) };
+                value = self.at(read_position).load(Ordering::Relaxed);
 
                 match self.read_position.compare_exchange(
                     read_position,
@@ -366,7 +375,7 @@ pub mod details {
             Some(value)
         }
 
-        fn acquire_read_and_write_position(&self) -> (usize, usize) {
+        fn acquire_read_and_write_position(&self) -> (u64, u64) {
             loop {
                 let write_position = self.write_position.load(Ordering::Relaxed);
                 let read_position = self.read_position.load(Ordering::Relaxed);
@@ -392,12 +401,12 @@ pub mod details {
         ///       could be out-of-date as soon as it is acquired.
         pub fn len(&self) -> usize {
             let (write_position, read_position) = self.acquire_read_and_write_position();
-            write_position - read_position
+            (write_position - read_position) as usize
         }
 
         /// Returns the capacity of the [`SafelyOverflowingIndexQueue`].
         pub const fn capacity(&self) -> usize {
-            self.capacity
+            self.capacity as usize
         }
 
         /// Returns true when the [`SafelyOverflowingIndexQueue`] is full, otherwise false.
@@ -415,8 +424,8 @@ pub mod details {
 #[repr(C)]
 pub struct FixedSizeSafelyOverflowingIndexQueue<const CAPACITY: usize> {
     state: RelocatableSafelyOverflowingIndexQueue,
-    data: [UnsafeCell<u64>; CAPACITY],
-    data_plus_one: UnsafeCell<u64>,
+    data: [IoxAtomicU64; CAPACITY],
+    data_plus_one: IoxAtomicU64,
 }
 
 unsafe impl<const CAPACITY: usize> Sync for FixedSizeSafelyOverflowingIndexQueue<CAPACITY> {}
@@ -433,8 +442,8 @@ impl<const CAPACITY: usize> FixedSizeSafelyOverflowingIndexQueue<CAPACITY> {
     pub fn new() -> Self {
         let mut new_self = Self {
             state: unsafe { RelocatableSafelyOverflowingIndexQueue::new_uninit(CAPACITY) },
-            data: core::array::from_fn(|_| UnsafeCell::new(0)),
-            data_plus_one: UnsafeCell::new(0),
+            data: core::array::from_fn(|_| IoxAtomicU64::new(0)),
+            data_plus_one: IoxAtomicU64::new(0),
         };
 
         let allocator = BumpAllocator::new(core::ptr::addr_of!(new_self.data) as usize);
@@ -449,12 +458,12 @@ impl<const CAPACITY: usize> FixedSizeSafelyOverflowingIndexQueue<CAPACITY> {
     }
 
     /// See [`SafelyOverflowingIndexQueue::acquire_producer()`]
-    pub fn acquire_producer(&self) -> Option<Producer<'_, RelocatablePointer<UnsafeCell<u64>>>> {
+    pub fn acquire_producer(&self) -> Option<Producer<'_, RelocatablePointer<IoxAtomicU64>>> {
         self.state.acquire_producer()
     }
 
     /// See [`SafelyOverflowingIndexQueue::acquire_consumer()`]
-    pub fn acquire_consumer(&self) -> Option<Consumer<'_, RelocatablePointer<UnsafeCell<u64>>>> {
+    pub fn acquire_consumer(&self) -> Option<Consumer<'_, RelocatablePointer<IoxAtomicU64>>> {
         self.state.acquire_consumer()
     }
 
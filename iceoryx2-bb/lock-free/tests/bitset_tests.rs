@@ -111,6 +111,65 @@ fn bit_set_set_bit_outside_of_bitset_leads_to_panic() {
     sut.set(CAPACITY);
 }
 
+#[test]
+fn bit_set_reset_and_is_set_works() {
+    const CAPACITY: usize = 124;
+    let sut = BitSet::new(CAPACITY);
+
+    assert_that!(sut.is_set(17), eq false);
+    assert_that!(sut.set(17), eq true);
+    assert_that!(sut.is_set(17), eq true);
+    assert_that!(sut.is_set(16), eq false);
+
+    assert_that!(sut.reset(17), eq true);
+    assert_that!(sut.reset(17), eq false);
+    assert_that!(sut.is_set(17), eq false);
+}
+
+#[test]
+fn bit_set_iter_returns_set_bits_in_ascending_order() {
+    const CAPACITY: usize = 1551;
+    let sut = BitSet::new(CAPACITY);
+    let ids = [0, 7, 8, 63, 64, 999, CAPACITY - 1];
+
+    assert_that!(sut.iter().next(), is_none);
+    for id in ids.iter().rev() {
+        sut.set(*id);
+    }
+
+    assert_that!(sut.iter().collect::<Vec<_>>(), eq ids.to_vec());
+}
+
+#[test]
+fn bit_set_find_first_unset_works() {
+    const CAPACITY: usize = 21;
+    let sut = BitSet::new(CAPACITY);
+
+    for i in 0..CAPACITY {
+        assert_that!(sut.find_first_unset(), eq Some(i));
+        sut.set(i);
+    }
+    assert_that!(sut.find_first_unset(), eq None);
+
+    sut.reset(9);
+    assert_that!(sut.find_first_unset(), eq Some(9));
+}
+
+#[test]
+fn fixed_size_bit_set_find_first_unset_and_iter_works() {
+    const CAPACITY: usize = 13;
+    let sut = FixedSizeBitSet::<CAPACITY>::new();
+
+    sut.set(0);
+    sut.set(1);
+    sut.set(5);
+
+    assert_that!(sut.find_first_unset(), eq Some(2));
+    assert_that!(sut.iter().collect::<Vec<_>>(), eq vec![0, 1, 5]);
+    assert_that!(sut.reset(1), eq true);
+    assert_that!(sut.is_set(1), eq false);
+}
+
 #[test]
 fn bit_set_set_and_reset_next_works() {
     const CAPACITY: usize = 1551;
@@ -59,6 +59,22 @@ fn spmc_unrestricted_atomic_acquire_multiple_producer_fails() {
     assert_that!(p3, is_some);
 }
 
+#[test]
+fn spmc_unrestricted_atomic_abandoned_producer_can_be_released() {
+    let _test_lock = TEST_LOCK.lock().unwrap();
+    let sut = UnrestrictedAtomic::<[u8; DATA_SIZE]>::new([0xff; DATA_SIZE]);
+
+    core::mem::forget(sut.acquire_producer());
+    assert_that!(sut.acquire_producer(), is_none);
+
+    unsafe { sut.release_abandoned_producer() };
+
+    let producer = sut.acquire_producer();
+    assert_that!(producer, is_some);
+    producer.unwrap().store([0x0f; DATA_SIZE]);
+    assert_that!(verify(0x0f, &sut.load()), eq true);
+}
+
 #[test]
 fn spmc_unrestricted_atomic_load_store_works() {
     let _test_lock = TEST_LOCK.lock().unwrap();
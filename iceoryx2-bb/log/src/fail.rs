@@ -11,9 +11,271 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 //! Combines error handling with logging.
+//!
+//! Every failure that is reported with [`fail!`](crate::fail!) or
+//! [`fatal_panic!`](crate::fatal_panic!) is forwarded to the [`FailureHook`] before the macro
+//! returns or panics, independent of the current log level. It can be set globally with
+//! [`set_failure_hook()`] or for the current thread with [`set_thread_failure_hook()`], so
+//! that failures can be routed into a custom diagnostic or health monitoring system.
+//!
+//! ```
+//! use iceoryx2_bb_log::fail;
+//! use iceoryx2_bb_log::fail::{set_failure_hook, Failure, FailureKind};
+//!
+//! fn report(failure: &Failure) {
+//!     if failure.kind == FailureKind::Fatal {
+//!         // inform the health monitor before the process panics
+//!     }
+//!     println!("{} failed with {:?}: {}", failure.origin, failure.error, failure.message);
+//! }
+//!
+//! static HOOK: fn(&Failure) = report;
+//! set_failure_hook(&HOOK);
+//!
+//! fn do_stuff() -> Result<(), u64> {
+//!     fail!(from "do_stuff()", with 1234, "Something went wrong.");
+//! }
+//!
+//! assert_eq!(do_stuff(), Err(1234));
+//! ```
+
+use core::fmt::{Arguments, Debug};
+#[cfg(feature = "std")]
+use core::{cell::Cell, marker::PhantomData};
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+
+#[cfg(feature = "std")]
+static FAILURE_HOOK: RwLock<Option<&'static dyn FailureHook>> = RwLock::new(None);
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static THREAD_FAILURE_HOOK: Cell<Option<&'static dyn FailureHook>> = const { Cell::new(None) };
+}
+
+/// Defines how severe a [`Failure`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FailureKind {
+    /// Reported by [`fail!`](crate::fail!), the error is returned to the caller.
+    Error,
+    /// Reported by [`fatal_panic!`](crate::fatal_panic!), the process panics afterwards.
+    Fatal,
+}
+
+/// A failure that was reported with [`fail!`](crate::fail!) or
+/// [`fatal_panic!`](crate::fatal_panic!).
+#[derive(Debug, Clone, Copy)]
+pub struct Failure<'a> {
+    pub kind: FailureKind,
+    pub origin: &'a str,
+    pub message: &'a str,
+    /// The error value, [`None`] when it does not implement [`Debug`] or when there is none.
+    pub error: Option<&'a dyn Debug>,
+}
+
+/// The hook that is called for every reported [`Failure`].
+pub trait FailureHook: Send + Sync {
+    /// Is called before [`fail!`](crate::fail!) returns or
+    /// [`fatal_panic!`](crate::fatal_panic!) panics.
+    fn on_failure(&self, failure: &Failure);
+}
+
+impl<F: Fn(&Failure) + Send + Sync> FailureHook for F {
+    fn on_failure(&self, failure: &Failure) {
+        self(failure)
+    }
+}
+
+/// Restores the previous [`FailureHook`] of the current thread when it goes out of scope, see
+/// [`set_thread_failure_hook()`].
+#[cfg(feature = "std")]
+pub struct ThreadFailureHookGuard {
+    previous: Option<&'static dyn FailureHook>,
+    _not_send: PhantomData<*const ()>,
+}
+
+#[cfg(feature = "std")]
+impl Debug for ThreadFailureHookGuard {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ThreadFailureHookGuard {{ has_previous: {} }}",
+            self.previous.is_some()
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for ThreadFailureHookGuard {
+    fn drop(&mut self) {
+        THREAD_FAILURE_HOOK.set(self.previous);
+    }
+}
+
+/// Sets the global [`FailureHook`] and replaces the previous one.
+#[cfg(feature = "std")]
+pub fn set_failure_hook<T: FailureHook + 'static>(value: &'static T) {
+    *failure_hook() = Some(value);
+}
+
+/// Removes the global [`FailureHook`].
+#[cfg(feature = "std")]
+pub fn remove_failure_hook() {
+    *failure_hook() = None;
+}
+
+/// Routes all failures of the current thread to the provided [`FailureHook`] until the
+/// returned [`ThreadFailureHookGuard`] goes out of scope. It takes precedence over the
+/// [`FailureHook`] that was set with [`set_failure_hook()`].
+#[cfg(feature = "std")]
+pub fn set_thread_failure_hook(value: &'static dyn FailureHook) -> ThreadFailureHookGuard {
+    ThreadFailureHookGuard {
+        previous: THREAD_FAILURE_HOOK.replace(Some(value)),
+        _not_send: PhantomData,
+    }
+}
+
+#[cfg(feature = "std")]
+fn failure_hook() -> std::sync::RwLockWriteGuard<'static, Option<&'static dyn FailureHook>> {
+    match FAILURE_HOOK.write() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+#[cfg(feature = "std")]
+fn active_failure_hook() -> Option<&'static dyn FailureHook> {
+    match THREAD_FAILURE_HOOK.get() {
+        Some(hook) => Some(hook),
+        None => match FAILURE_HOOK.read() {
+            Ok(guard) => *guard,
+            Err(poisoned) => *poisoned.into_inner(),
+        },
+    }
+}
+
+#[doc(hidden)]
+pub mod __internal {
+    use super::*;
+    use crate::LogLevel;
+
+    // The origin and the message are formatted before the error value is evaluated, since
+    // the error value may move a value the message refers to. It allocates only when a hook
+    // is set.
+    #[cfg(feature = "std")]
+    pub struct PreparedFailure {
+        hook: &'static dyn FailureHook,
+        kind: FailureKind,
+        origin: String,
+        message: String,
+    }
+
+    // Without std, the origin and the message cannot be stored and failure hooks are not
+    // supported.
+    #[cfg(not(feature = "std"))]
+    pub enum PreparedFailure {}
+
+    #[cfg(not(feature = "std"))]
+    pub fn prepare_failure(
+        _kind: FailureKind,
+        _origin: Arguments,
+        _message: Arguments,
+    ) -> Option<PreparedFailure> {
+        None
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub fn report_failure(_failure: Option<PreparedFailure>, _error: Option<&dyn Debug>) {}
+
+    #[cfg(feature = "std")]
+    pub fn prepare_failure(
+        kind: FailureKind,
+        origin: Arguments,
+        message: Arguments,
+    ) -> Option<PreparedFailure> {
+        active_failure_hook().map(|hook| PreparedFailure {
+            hook,
+            kind,
+            origin: origin.to_string(),
+            message: message.to_string(),
+        })
+    }
+
+    #[cfg(feature = "std")]
+    pub fn report_failure(failure: Option<PreparedFailure>, error: Option<&dyn Debug>) {
+        if let Some(failure) = failure {
+            failure.hook.on_failure(&Failure {
+                kind: failure.kind,
+                origin: failure.origin.trim_matches('"'),
+                message: &failure.message,
+                error,
+            });
+        }
+    }
+
+    // The message is evaluated only once for the log output and the failure hook, since it
+    // may move the values it refers to.
+    pub fn log_and_prepare_failure(
+        kind: FailureKind,
+        log_level: LogLevel,
+        origin: Arguments,
+        message: Arguments,
+    ) -> Option<PreparedFailure> {
+        crate::__internal_print_log_msg(log_level, origin, message);
+        prepare_failure(kind, origin, message)
+    }
+
+    // autoref specialization, provides the error as Debug when it implements Debug
+    pub struct DebugCheck<'a, T>(pub &'a T);
+
+    pub trait IsDebug<'a> {
+        fn as_debug(&self) -> Option<&'a dyn Debug>;
+    }
+
+    impl<'a, T: Debug> IsDebug<'a> for DebugCheck<'a, T> {
+        fn as_debug(&self) -> Option<&'a dyn Debug> {
+            Some(self.0)
+        }
+    }
+
+    pub trait IsNotDebug<'a> {
+        fn as_debug(&self) -> Option<&'a dyn Debug> {
+            None
+        }
+    }
+
+    impl<'a, T> IsNotDebug<'a> for &DebugCheck<'a, T> {}
+}
+
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! __prepare_failure {
+    ($kind:ident, $log_level:ident, $origin:expr, $($message:expr),*) => {
+        $crate::fail::__internal::log_and_prepare_failure(
+            $crate::fail::FailureKind::$kind,
+            $crate::LogLevel::$log_level,
+            $origin,
+            core::format_args!($($message),*),
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! __report_failure {
+    ($failure:expr, $error:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::fail::__internal::{IsDebug, IsNotDebug};
+        $crate::fail::__internal::report_failure(
+            $failure,
+            (&$crate::fail::__internal::DebugCheck($error)).as_debug(),
+        );
+    }};
+}
 
 /// Macro to combine error handling with log messages. It automatically fails and converts the
-/// error with [`From`].
+/// error with [`From`]. Every failure is forwarded to the [`FailureHook`], the error value is
+/// part of the [`Failure`] when it implements [`Debug`].
 ///
 /// ```
 /// use iceoryx2_bb_log::fail;
@@ -54,20 +316,26 @@
 #[macro_export(local_inner_macros)]
 macro_rules! fail {
     (with $error_value:expr, $($message:expr),*) => {
-        debug!($($message),*);
-        return Err($error_value);
+        let failure = __prepare_failure!(Error, Debug, core::format_args!(""), $($message),*);
+        let error = $error_value;
+        __report_failure!(failure, &error);
+        return Err(error);
     };
     (from $origin:expr, with $error_value:expr, $($message:expr),*) => {
-        debug!(from $origin, $($message),*);
-        return Err($error_value);
+        let failure = __prepare_failure!(Error, Debug, core::format_args!("{:?}", $origin), $($message),*);
+        let error = $error_value;
+        __report_failure!(failure, &error);
+        return Err(error);
     };
     (from $origin:expr, when $call:expr, with $error_value:expr, $($message:expr),*) => {
         {
             let result = $call;
             match result.is_err() {
                 true => {
-                    debug!(from $origin, $($message),*);
-                    return Err($error_value);
+                    let failure = __prepare_failure!(Error, Debug, core::format_args!("{:?}", $origin), $($message),*);
+                    let error = $error_value;
+                    __report_failure!(failure, &error);
+                    return Err(error);
                 }
                 false => {
                     result.ok().unwrap()
@@ -81,10 +349,18 @@ macro_rules! fail {
         {
             match $call {
                 Err(e) => {
-                    debug!(from $origin, $($message),*);
+                    let failure = __prepare_failure!(Error, Debug, core::format_args!("{:?}", $origin), $($message),*);
                     match e {
-                        $($error_origin => return Err($error_value)),*,
-                        _ => return Err($error_unmatched),
+                        $($error_origin => {
+                            let error = $error_value;
+                            __report_failure!(failure, &error);
+                            return Err(error);
+                        }),*,
+                        _ => {
+                            let error = $error_unmatched;
+                            __report_failure!(failure, &error);
+                            return Err(error);
+                        }
                     }
                 },
                 Ok(v) => v,
@@ -100,7 +376,8 @@ macro_rules! fail {
             let result = $call;
             match result.is_err() {
                 true => {
-                    debug!($($message),*);
+                    let failure = __prepare_failure!(Error, Debug, core::format_args!(""), $($message),*);
+                    __report_failure!(failure, result.as_ref().err().unwrap());
                     result?
                 }
                 false => {
@@ -114,8 +391,9 @@ macro_rules! fail {
             let result = $call;
             match result.is_err() {
                 true => {
-                    debug!(from $origin, $($message),*);
+                    let failure = __prepare_failure!(Error, Debug, core::format_args!("{:?}", $origin), $($message),*);
                     let error = <$error>::from(result.err().unwrap());
+                    __report_failure!(failure, &error);
                     Err(error)?
                 }
                 false => {
@@ -129,7 +407,8 @@ macro_rules! fail {
             let result = $call;
             match result.is_err() {
                 true => {
-                    debug!(from $origin, $($message),*);
+                    let failure = __prepare_failure!(Error, Debug, core::format_args!("{:?}", $origin), $($message),*);
+                    __report_failure!(failure, result.as_ref().err().unwrap());
                     result?
                 }
                 false => {
@@ -10,6 +10,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(clippy::alloc_instead_of_core)]
 #![warn(clippy::std_instead_of_alloc)]
 #![warn(clippy::std_instead_of_core)]
@@ -23,6 +24,9 @@
 //! result.
 //! The [`fatal_panic!`] macro calls [`panic!`].
 //!
+//! All log messages below [`MAX_LOG_LEVEL`] are removed at compile time. It can be raised
+//! with the features `max_level_info`, `max_level_warn` and `max_level_error`.
+//!
 //! # Example
 //!
 //! ## Logging
@@ -122,6 +126,57 @@
 //!     }
 //! }
 //! ```
+//! ## Log Level per Origin
+//!
+//! [`set_log_level_for()`] overrides the log level for all messages whose origin starts with
+//! the provided pattern, so that for instance high-frequency internals can be silenced while
+//! other components still log with a low log level. When multiple patterns match, the
+//! longest one is used. The same can be achieved with a filter like `info,Node=debug` that is
+//! applied with [`set_log_level_filter()`] or via the environment variable `IOX2_LOG_LEVEL`.
+//!
+//! ```
+//! use iceoryx2_bb_log::{set_log_level, set_log_level_for, LogLevel};
+//!
+//! set_log_level(LogLevel::Info);
+//! set_log_level_for("SharedMemory", LogLevel::Error);
+//! set_log_level_for("Node", LogLevel::Debug);
+//! ```
+//!
+//! ## Structured Logging
+//!
+//! Every log macro accepts additional key/value pairs with `fields [...]`. They are forwarded
+//! as [`Record`] to [`Log::log_record()`] so that a [`Log`]ger can process them separately.
+//! With the features `logger_log` and `logger_tracing` the messages are forwarded to the
+//! [`log`](https://crates.io/crates/log) and [`tracing`](https://crates.io/crates/tracing)
+//! crate so that they show up in the observability stack of the application. Every field
+//! becomes a separate key/value pair of the `log` record or the `tracing` event.
+//!
+//! ```
+//! use iceoryx2_bb_log::{info, warn};
+//!
+//! let service_name = "my_service";
+//! info!(fields [service = service_name, publishers = 2], "service created");
+//! warn!(from "Custom::Origin", fields [port = 42], "port {} is slow", 42);
+//! ```
+//!
+//! ## Setting a logger for the current thread
+//!
+//! [`set_thread_logger()`] routes all messages of the current thread to another [`Log`]ger
+//! until the returned [`ThreadLoggerGuard`] goes out of scope.
+//!
+//! ```
+//! use iceoryx2_bb_log::{set_thread_logger, info};
+//!
+//! static LOGGER: iceoryx2_bb_log::logger::buffer::Logger =
+//!     iceoryx2_bb_log::logger::buffer::Logger::new();
+//!
+//! {
+//!     let _guard = set_thread_logger(&LOGGER);
+//!     info!("hello thread logger");
+//! }
+//! info!("hello default logger");
+//! ```
+//!
 //! ## Setting custom logger on application startup
 //!
 //! In this example we use the [`crate::logger::buffer::Logger`], that stores every log
@@ -150,9 +205,13 @@ pub mod logger;
 
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU8;
 
+#[cfg(feature = "std")]
+use core::{cell::Cell, marker::PhantomData};
 use core::{fmt::Arguments, sync::atomic::Ordering};
-use std::sync::Once;
+#[cfg(feature = "std")]
+use std::sync::RwLock;
 
+#[cfg(feature = "std")]
 use std::env;
 
 #[cfg(feature = "logger_tracing")]
@@ -161,18 +220,153 @@ static DEFAULT_LOGGER: logger::tracing::Logger = logger::tracing::Logger::new();
 #[cfg(feature = "logger_log")]
 static DEFAULT_LOGGER: logger::log::Logger = logger::log::Logger::new();
 
-#[cfg(not(any(feature = "logger_log", feature = "logger_tracing")))]
+#[cfg(all(
+    feature = "std",
+    not(any(feature = "logger_log", feature = "logger_tracing"))
+))]
 static DEFAULT_LOGGER: logger::console::Logger = logger::console::Logger::new();
 
+#[cfg(not(feature = "std"))]
+static DEFAULT_LOGGER: logger::null::Logger = logger::null::Logger::new();
+
 const DEFAULT_LOG_LEVEL: LogLevel = LogLevel::Info;
 
+/// The lowest [`LogLevel`] that is compiled into the binary. Every log message below it is
+/// removed at compile time and cannot be activated with [`set_log_level()`].
+#[cfg(feature = "max_level_error")]
+pub const MAX_LOG_LEVEL: LogLevel = LogLevel::Error;
+
+/// The lowest [`LogLevel`] that is compiled into the binary. Every log message below it is
+/// removed at compile time and cannot be activated with [`set_log_level()`].
+#[cfg(all(feature = "max_level_warn", not(feature = "max_level_error")))]
+pub const MAX_LOG_LEVEL: LogLevel = LogLevel::Warn;
+
+/// The lowest [`LogLevel`] that is compiled into the binary. Every log message below it is
+/// removed at compile time and cannot be activated with [`set_log_level()`].
+#[cfg(all(
+    feature = "max_level_info",
+    not(any(feature = "max_level_warn", feature = "max_level_error"))
+))]
+pub const MAX_LOG_LEVEL: LogLevel = LogLevel::Info;
+
+/// The lowest [`LogLevel`] that is compiled into the binary. Every log message below it is
+/// removed at compile time and cannot be activated with [`set_log_level()`].
+#[cfg(not(any(
+    feature = "max_level_info",
+    feature = "max_level_warn",
+    feature = "max_level_error"
+)))]
+pub const MAX_LOG_LEVEL: LogLevel = LogLevel::Trace;
+
+const LOGGER_UNINITIALIZED: u8 = 0;
+const LOGGER_INITIALIZING: u8 = 1;
+const LOGGER_INITIALIZED: u8 = 2;
+
 static mut LOGGER: Option<&'static dyn Log> = None;
 static LOG_LEVEL: IoxAtomicU8 = IoxAtomicU8::new(DEFAULT_LOG_LEVEL as u8);
-static INIT: Once = Once::new();
+static LOGGER_STATE: IoxAtomicU8 = IoxAtomicU8::new(LOGGER_UNINITIALIZED);
+// sorted by the length of the pattern, so that the longest matching pattern is found first
+#[cfg(feature = "std")]
+static ORIGIN_LOG_LEVELS: RwLock<Vec<(String, LogLevel)>> = RwLock::new(Vec::new());
+// the lowest log level of all origin patterns, u8::MAX when no pattern is set
+#[cfg(feature = "std")]
+static LOWEST_ORIGIN_LOG_LEVEL: IoxAtomicU8 = IoxAtomicU8::new(u8::MAX);
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static THREAD_LOGGER: Cell<Option<&'static dyn Log>> = const { Cell::new(None) };
+}
 
 pub trait Log: Send + Sync {
     /// logs a message
     fn log(&self, log_level: LogLevel, origin: Arguments, formatted_message: Arguments);
+
+    /// logs a structured message, by default the fields are appended to the message as
+    /// `key=value` pairs and forwarded to [`Log::log()`]
+    fn log_record(&self, record: &Record) {
+        if record.fields.is_empty() {
+            self.log(record.log_level, record.origin, record.message)
+        } else {
+            self.log(
+                record.log_level,
+                record.origin,
+                format_args!("{} {}", record.message, DisplayFields(record.fields)),
+            )
+        }
+    }
+}
+
+/// A key/value pair that is attached to a structured log message.
+#[derive(Clone, Copy)]
+pub struct Field<'a> {
+    pub key: &'static str,
+    pub value: &'a dyn core::fmt::Display,
+}
+
+impl core::fmt::Display for Field<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}={}", self.key, self.value)
+    }
+}
+
+impl core::fmt::Debug for Field<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Field {{ key: {:?}, value: \"{}\" }}",
+            self.key, self.value
+        )
+    }
+}
+
+/// A structured log message that consists of the [`LogLevel`], the origin, the message and
+/// additional key/value [`Field`]s.
+#[derive(Debug, Clone, Copy)]
+pub struct Record<'a> {
+    pub log_level: LogLevel,
+    pub origin: Arguments<'a>,
+    pub message: Arguments<'a>,
+    pub fields: &'a [Field<'a>],
+}
+
+pub(crate) struct DisplayFields<'a>(pub(crate) &'a [Field<'a>]);
+
+impl core::fmt::Display for DisplayFields<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (n, field) in self.0.iter().enumerate() {
+            if n != 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", field)?;
+        }
+        Ok(())
+    }
+}
+
+/// Restores the previous [`Log`]ger of the current thread when it goes out of scope, see
+/// [`set_thread_logger()`].
+#[cfg(feature = "std")]
+pub struct ThreadLoggerGuard {
+    previous: Option<&'static dyn Log>,
+    _not_send: PhantomData<*const ()>,
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Debug for ThreadLoggerGuard {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ThreadLoggerGuard {{ has_previous: {} }}",
+            self.previous.is_some()
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for ThreadLoggerGuard {
+    fn drop(&mut self) {
+        THREAD_LOGGER.set(self.previous);
+    }
 }
 
 /// Describes the log level.
@@ -188,6 +382,19 @@ pub enum LogLevel {
 }
 
 impl LogLevel {
+    #[cfg(feature = "std")]
+    pub(crate) fn from_u8(value: u8) -> LogLevel {
+        match value {
+            0 => LogLevel::Trace,
+            1 => LogLevel::Debug,
+            2 => LogLevel::Info,
+            3 => LogLevel::Warn,
+            4 => LogLevel::Error,
+            _ => LogLevel::Fatal,
+        }
+    }
+
+    #[cfg(feature = "std")]
     fn from_str_fuzzy(log_level_string: &str, log_level_fallback: LogLevel) -> LogLevel {
         match log_level_string.to_lowercase().as_str() {
             "trace" => LogLevel::Trace,
@@ -211,21 +418,141 @@ impl LogLevel {
 }
 
 /// Sets the log level by reading environment variable "IOX2_LOG_LEVEL" or default it wiht LogLevel::INFO
+#[cfg(feature = "std")]
 pub fn set_log_level_from_env_or_default() {
     set_log_level_from_env_or(DEFAULT_LOG_LEVEL);
 }
 
 /// Sets the log level by reading environment variable "IOX2_LOG_LEVEL", and if the environment variable
-/// doesn't exits it sets it with a user-defined logging level
+/// doesn't exits it sets it with a user-defined logging level. The environment variable can
+/// contain a filter like `info,Node=debug`, see [`set_log_level_filter()`].
+#[cfg(feature = "std")]
 pub fn set_log_level_from_env_or(v: LogLevel) {
-    let log_level = env::var("IOX2_LOG_LEVEL")
-        .ok()
-        .map(|s| LogLevel::from_str_fuzzy(&s, v))
-        .unwrap_or(v);
-    set_log_level(log_level);
+    set_log_level(v);
+    if let Ok(filter) = env::var("IOX2_LOG_LEVEL") {
+        apply_log_level_filter(&filter, v);
+    }
+}
+
+/// Applies a comma separated log level filter. An entry without `=`, like `info`, sets the
+/// log level via [`set_log_level()`] and an entry like `Node=debug` sets the log level for
+/// all origins that start with `Node` via [`set_log_level_for()`].
+#[cfg(feature = "std")]
+pub fn set_log_level_filter(filter: &str) {
+    apply_log_level_filter(filter, LogLevel::from_u8(get_log_level()));
 }
 
-/// Sets the current log level. This is ignored for external frameworks like `log` or `tracing`.
+#[cfg(feature = "std")]
+fn apply_log_level_filter(filter: &str, log_level_fallback: LogLevel) {
+    for entry in filter
+        .split(',')
+        .map(|e| e.trim())
+        .filter(|e| !e.is_empty())
+    {
+        match entry.split_once('=') {
+            Some((origin_pattern, log_level)) => set_log_level_for(
+                origin_pattern.trim(),
+                LogLevel::from_str_fuzzy(log_level.trim(), log_level_fallback),
+            ),
+            None => set_log_level(LogLevel::from_str_fuzzy(entry, log_level_fallback)),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+fn origin_log_levels() -> std::sync::RwLockWriteGuard<'static, Vec<(String, LogLevel)>> {
+    match ORIGIN_LOG_LEVELS.write() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+#[cfg(feature = "std")]
+fn update_lowest_origin_log_level(origin_log_levels: &[(String, LogLevel)]) {
+    let lowest = origin_log_levels
+        .iter()
+        .map(|(_, log_level)| *log_level as u8)
+        .min()
+        .unwrap_or(u8::MAX);
+    LOWEST_ORIGIN_LOG_LEVEL.store(lowest, Ordering::Relaxed);
+}
+
+/// Sets the log level for all messages whose origin starts with the provided pattern. It
+/// overrides the log level that was set with [`set_log_level()`]. The origin of a message
+/// is its `from` argument. Log messages below [`MAX_LOG_LEVEL`] are never printed.
+#[cfg(feature = "std")]
+pub fn set_log_level_for(origin_pattern: &str, v: LogLevel) {
+    let mut origin_log_levels = origin_log_levels();
+    match origin_log_levels
+        .iter_mut()
+        .find(|(pattern, _)| pattern == origin_pattern)
+    {
+        Some(entry) => entry.1 = v,
+        None => {
+            origin_log_levels.push((origin_pattern.to_string(), v));
+            origin_log_levels.sort_by_key(|(pattern, _)| core::cmp::Reverse(pattern.len()));
+        }
+    }
+    update_lowest_origin_log_level(&origin_log_levels);
+}
+
+/// Removes the log level that was set for the origin pattern with [`set_log_level_for()`].
+#[cfg(feature = "std")]
+pub fn remove_log_level_for(origin_pattern: &str) {
+    let mut origin_log_levels = origin_log_levels();
+    origin_log_levels.retain(|(pattern, _)| pattern != origin_pattern);
+    update_lowest_origin_log_level(&origin_log_levels);
+}
+
+#[cfg(not(feature = "std"))]
+fn is_log_level_active(log_level: LogLevel, _origin: Arguments) -> bool {
+    log_level as u8 >= get_log_level()
+}
+
+#[cfg(feature = "std")]
+fn is_log_level_active(log_level: LogLevel, origin: Arguments) -> bool {
+    let log_level = log_level as u8;
+    let global_log_level = get_log_level();
+    let lowest_origin_log_level = LOWEST_ORIGIN_LOG_LEVEL.load(Ordering::Relaxed);
+
+    if log_level < global_log_level.min(lowest_origin_log_level) {
+        return false;
+    }
+
+    if lowest_origin_log_level == u8::MAX {
+        return true;
+    }
+
+    get_log_level_for(&origin.to_string()) <= log_level
+}
+
+/// Returns the log level for messages with the provided origin. It is the log level of the
+/// longest pattern that was set with [`set_log_level_for()`] and matches the origin or the
+/// current log level, see [`get_log_level()`].
+#[cfg(feature = "std")]
+pub fn get_log_level_for(origin: &str) -> u8 {
+    if LOWEST_ORIGIN_LOG_LEVEL.load(Ordering::Relaxed) == u8::MAX {
+        return get_log_level();
+    }
+
+    // origins that are string literals are printed with quotes
+    let origin = origin.trim_start_matches('"');
+    let origin_log_levels = match ORIGIN_LOG_LEVELS.read() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+
+    match origin_log_levels
+        .iter()
+        .find(|(pattern, _)| origin.starts_with(pattern.as_str()))
+    {
+        Some((_, origin_log_level)) => *origin_log_level as u8,
+        None => get_log_level(),
+    }
+}
+
+/// Sets the current log level. Log messages below [`MAX_LOG_LEVEL`] are never printed.
+/// This is ignored for external frameworks like `log` or `tracing`.
 /// Here you have to use the log-level settings of that framework.
 pub fn set_log_level(v: LogLevel) {
     LOG_LEVEL.store(v as u8, Ordering::Relaxed);
@@ -239,19 +566,54 @@ pub fn get_log_level() -> u8 {
 /// Sets the [`Log`]ger. Can be only called once at the beginning of the program. If the
 /// [`Log`]ger is already set it returns false and does not update it.
 pub fn set_logger<T: Log + 'static>(value: &'static T) -> bool {
-    let mut set_logger_success = false;
-    INIT.call_once(|| {
-        unsafe { LOGGER = Some(value) };
-        set_logger_success = true;
-    });
-    set_logger_success
+    init_logger(value)
+}
+
+// std::sync::Once is not available in no_std, therefore the initialization is guarded by an
+// atomic state. Returns false when the logger was already initialized.
+fn init_logger(value: &'static dyn Log) -> bool {
+    match LOGGER_STATE.compare_exchange(
+        LOGGER_UNINITIALIZED,
+        LOGGER_INITIALIZING,
+        Ordering::Acquire,
+        Ordering::Relaxed,
+    ) {
+        Ok(_) => {
+            unsafe { LOGGER = Some(value) };
+            LOGGER_STATE.store(LOGGER_INITIALIZED, Ordering::Release);
+            true
+        }
+        Err(_) => {
+            while LOGGER_STATE.load(Ordering::Acquire) != LOGGER_INITIALIZED {
+                core::hint::spin_loop();
+            }
+            false
+        }
+    }
 }
 
-/// Returns a reference to the [`Log`]ger.
+/// Routes all log messages of the current thread to the provided [`Log`]ger until the
+/// returned [`ThreadLoggerGuard`] goes out of scope. It takes precedence over the [`Log`]ger
+/// that was set with [`set_logger()`].
+#[cfg(feature = "std")]
+pub fn set_thread_logger(value: &'static dyn Log) -> ThreadLoggerGuard {
+    ThreadLoggerGuard {
+        previous: THREAD_LOGGER.replace(Some(value)),
+        _not_send: PhantomData,
+    }
+}
+
+/// Returns a reference to the [`Log`]ger. When a [`Log`]ger was set for the current thread
+/// with [`set_thread_logger()`] it is returned instead of the global one.
 pub fn get_logger() -> &'static dyn Log {
-    INIT.call_once(|| {
-        unsafe { LOGGER = Some(&DEFAULT_LOGGER) };
-    });
+    #[cfg(feature = "std")]
+    if let Some(logger) = THREAD_LOGGER.get() {
+        return logger;
+    }
+
+    if LOGGER_STATE.load(Ordering::Acquire) != LOGGER_INITIALIZED {
+        init_logger(&DEFAULT_LOGGER);
+    }
 
     // # From The Compiler
     //
@@ -262,8 +624,8 @@ pub fn get_logger() -> &'static dyn Log {
     // # Safety
     //
     // 1. The logger is always an immutable threadsafe object with only interior mutability.
-    // 2. [`std::sync::Once`] is used to ensure it can only mutated on initialization and the
-    //    lifetime is `'static`.
+    // 2. [`LOGGER_STATE`] ensures that it is only mutated on initialization and the lifetime
+    //    is `'static`.
     #[allow(static_mut_refs)]
     unsafe {
         *LOGGER.as_ref().unwrap()
@@ -271,8 +633,37 @@ pub fn get_logger() -> &'static dyn Log {
 }
 
 #[doc(hidden)]
+#[inline(always)]
 pub fn __internal_print_log_msg(log_level: LogLevel, origin: Arguments, args: Arguments) {
-    if get_log_level() <= log_level as u8 {
+    // compile time constant, the optimizer removes the message when it is below the max level
+    if (log_level as u8) < MAX_LOG_LEVEL as u8 {
+        return;
+    }
+
+    if is_log_level_active(log_level, origin) {
         get_logger().log(log_level, origin, args)
     }
 }
+
+#[doc(hidden)]
+#[inline(always)]
+pub fn __internal_print_log_record(
+    log_level: LogLevel,
+    origin: Arguments,
+    args: Arguments,
+    fields: &[Field],
+) {
+    // compile time constant, the optimizer removes the message when it is below the max level
+    if (log_level as u8) < MAX_LOG_LEVEL as u8 {
+        return;
+    }
+
+    if is_log_level_active(log_level, origin) {
+        get_logger().log_record(&Record {
+            log_level,
+            origin,
+            message: args,
+            fields,
+        })
+    }
+}
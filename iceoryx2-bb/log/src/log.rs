@@ -29,6 +29,7 @@
 ///         trace!("Only a message");
 ///         trace!(from self, "Message which adds the object as its origin");
 ///         trace!(from "Somewhere over the Rainbow", "Message with custom origin");
+///         trace!(from self, fields [answer = 42, name = "Bob"], "Message with key/value pairs");
 ///
 ///         trace!(from self, when self.something_that_fails(),
 ///             "Print only when result.is_err()")
@@ -37,17 +38,25 @@
 /// ```
 #[macro_export(local_inner_macros)]
 macro_rules! trace {
+    (fields [$($key:ident = $value:expr),*], $($e:expr),*) => {
+        $crate::__internal_print_log_record($crate::LogLevel::Trace, core::format_args!(""), core::format_args!($($e),*),
+            &[$($crate::Field { key: core::stringify!($key), value: &$value }),*])
+    };
+    (from $o:expr, fields [$($key:ident = $value:expr),*], $($e:expr),*) => {
+        $crate::__internal_print_log_record($crate::LogLevel::Trace, core::format_args!("{:?}", $o), core::format_args!($($e),*),
+            &[$($crate::Field { key: core::stringify!($key), value: &$value }),*])
+    };
     ($($e:expr),*) => {
-        $crate::__internal_print_log_msg($crate::LogLevel::Trace, std::format_args!(""), std::format_args!($($e),*))
+        $crate::__internal_print_log_msg($crate::LogLevel::Trace, core::format_args!(""), core::format_args!($($e),*))
     };
     (from $o:expr, $($e:expr),*) => {
-        $crate::__internal_print_log_msg($crate::LogLevel::Trace, std::format_args!("{:?}", $o), std::format_args!($($e),*))
+        $crate::__internal_print_log_msg($crate::LogLevel::Trace, core::format_args!("{:?}", $o), core::format_args!($($e),*))
     };
     (from $o:expr, when $call:expr, $($e:expr),*) => {
         {
             let result = $call;
             if result.is_err() {
-                $crate::__internal_print_log_msg($crate::LogLevel::Trace, std::format_args!("{:?}", $o), std::format_args!($($e),*))
+                $crate::__internal_print_log_msg($crate::LogLevel::Trace, core::format_args!("{:?}", $o), core::format_args!($($e),*))
             }
         }
     }
@@ -70,6 +79,7 @@ macro_rules! trace {
 ///         debug!("Only a message");
 ///         debug!(from self, "Message which adds the object as its origin");
 ///         debug!(from "Somewhere over the Rainbow", "Message with custom origin");
+///         debug!(from self, fields [answer = 42, name = "Bob"], "Message with key/value pairs");
 ///
 ///         debug!(from self, when self.something_that_fails(),
 ///             "Print only when result.is_err()")
@@ -78,17 +88,25 @@ macro_rules! trace {
 /// ```
 #[macro_export(local_inner_macros)]
 macro_rules! debug {
+    (fields [$($key:ident = $value:expr),*], $($e:expr),*) => {
+        $crate::__internal_print_log_record($crate::LogLevel::Debug, core::format_args!(""), core::format_args!($($e),*),
+            &[$($crate::Field { key: core::stringify!($key), value: &$value }),*])
+    };
+    (from $o:expr, fields [$($key:ident = $value:expr),*], $($e:expr),*) => {
+        $crate::__internal_print_log_record($crate::LogLevel::Debug, core::format_args!("{:?}", $o), core::format_args!($($e),*),
+            &[$($crate::Field { key: core::stringify!($key), value: &$value }),*])
+    };
     ($($e:expr),*) => {
-        $crate::__internal_print_log_msg($crate::LogLevel::Debug, std::format_args!(""), std::format_args!($($e),*))
+        $crate::__internal_print_log_msg($crate::LogLevel::Debug, core::format_args!(""), core::format_args!($($e),*))
     };
     (from $o:expr, $($e:expr),*) => {
-        $crate::__internal_print_log_msg($crate::LogLevel::Debug, std::format_args!("{:?}", $o), std::format_args!($($e),*))
+        $crate::__internal_print_log_msg($crate::LogLevel::Debug, core::format_args!("{:?}", $o), core::format_args!($($e),*))
     };
     (from $o:expr, when $call:expr, $($e:expr),*) => {
         {
             let result = $call;
             if result.is_err() {
-                $crate::__internal_print_log_msg($crate::LogLevel::Debug, std::format_args!("{:?}", $o), std::format_args!($($e),*))
+                $crate::__internal_print_log_msg($crate::LogLevel::Debug, core::format_args!("{:?}", $o), core::format_args!($($e),*))
             }
         }
     }
@@ -111,6 +129,7 @@ macro_rules! debug {
 ///         info!("Only a message");
 ///         info!(from self, "Message which adds the object as its origin");
 ///         info!(from "Somewhere over the Rainbow", "Message with custom origin");
+///         info!(from self, fields [answer = 42, name = "Bob"], "Message with key/value pairs");
 ///
 ///         info!(from self, when self.something_that_fails(),
 ///             "Print only when result.is_err()")
@@ -119,17 +138,25 @@ macro_rules! debug {
 /// ```
 #[macro_export(local_inner_macros)]
 macro_rules! info {
+    (fields [$($key:ident = $value:expr),*], $($e:expr),*) => {
+        $crate::__internal_print_log_record($crate::LogLevel::Info, core::format_args!(""), core::format_args!($($e),*),
+            &[$($crate::Field { key: core::stringify!($key), value: &$value }),*])
+    };
+    (from $o:expr, fields [$($key:ident = $value:expr),*], $($e:expr),*) => {
+        $crate::__internal_print_log_record($crate::LogLevel::Info, core::format_args!("{:?}", $o), core::format_args!($($e),*),
+            &[$($crate::Field { key: core::stringify!($key), value: &$value }),*])
+    };
     ($($e:expr),*) => {
-        $crate::__internal_print_log_msg($crate::LogLevel::Info, std::format_args!(""), std::format_args!($($e),*))
+        $crate::__internal_print_log_msg($crate::LogLevel::Info, core::format_args!(""), core::format_args!($($e),*))
     };
     (from $o:expr, $($e:expr),*) => {
-        $crate::__internal_print_log_msg($crate::LogLevel::Info, std::format_args!("{:?}", $o), std::format_args!($($e),*))
+        $crate::__internal_print_log_msg($crate::LogLevel::Info, core::format_args!("{:?}", $o), core::format_args!($($e),*))
     };
     (from $o:expr, when $call:expr, $($e:expr),*) => {
         {
             let result = $call;
             if result.is_err() {
-                $crate::__internal_print_log_msg($crate::LogLevel::Info, std::format_args!("{:?}", $o), std::format_args!($($e),*))
+                $crate::__internal_print_log_msg($crate::LogLevel::Info, core::format_args!("{:?}", $o), core::format_args!($($e),*))
             }
         }
     }
@@ -152,6 +179,7 @@ macro_rules! info {
 ///         warn!("Only a message");
 ///         warn!(from self, "Message which adds the object as its origin");
 ///         warn!(from "Somewhere over the Rainbow", "Message with custom origin");
+///         warn!(from self, fields [answer = 42, name = "Bob"], "Message with key/value pairs");
 ///
 ///         warn!(from self, when self.something_that_fails(),
 ///             "Print only when result.is_err()")
@@ -160,17 +188,25 @@ macro_rules! info {
 /// ```
 #[macro_export(local_inner_macros)]
 macro_rules! warn {
+    (fields [$($key:ident = $value:expr),*], $($e:expr),*) => {
+        $crate::__internal_print_log_record($crate::LogLevel::Warn, core::format_args!(""), core::format_args!($($e),*),
+            &[$($crate::Field { key: core::stringify!($key), value: &$value }),*])
+    };
+    (from $o:expr, fields [$($key:ident = $value:expr),*], $($e:expr),*) => {
+        $crate::__internal_print_log_record($crate::LogLevel::Warn, core::format_args!("{:?}", $o), core::format_args!($($e),*),
+            &[$($crate::Field { key: core::stringify!($key), value: &$value }),*])
+    };
     ($($e:expr),*) => {
-        $crate::__internal_print_log_msg($crate::LogLevel::Warn, std::format_args!(""), std::format_args!($($e),*))
+        $crate::__internal_print_log_msg($crate::LogLevel::Warn, core::format_args!(""), core::format_args!($($e),*))
     };
     (from $o:expr, $($e:expr),*) => {
-        $crate::__internal_print_log_msg($crate::LogLevel::Warn, std::format_args!("{:?}", $o), std::format_args!($($e),*))
+        $crate::__internal_print_log_msg($crate::LogLevel::Warn, core::format_args!("{:?}", $o), core::format_args!($($e),*))
     };
     (from $o:expr, when $call:expr, $($e:expr),*) => {
         {
             let result = $call;
             if result.is_err() {
-                $crate::__internal_print_log_msg($crate::LogLevel::Warn, std::format_args!("{:?}", $o), std::format_args!($($e),*))
+                $crate::__internal_print_log_msg($crate::LogLevel::Warn, core::format_args!("{:?}", $o), core::format_args!($($e),*))
             }
         }
     }
@@ -193,6 +229,7 @@ macro_rules! warn {
 ///         error!("Only a message");
 ///         error!(from self, "Message which adds the object as its origin");
 ///         error!(from "Somewhere over the Rainbow", "Message with custom origin");
+///         error!(from self, fields [answer = 42, name = "Bob"], "Message with key/value pairs");
 ///
 ///         error!(from self, when self.something_that_fails(),
 ///             "Print only when result.is_err()")
@@ -201,17 +238,25 @@ macro_rules! warn {
 /// ```
 #[macro_export(local_inner_macros)]
 macro_rules! error {
+    (fields [$($key:ident = $value:expr),*], $($e:expr),*) => {
+        $crate::__internal_print_log_record($crate::LogLevel::Error, core::format_args!(""), core::format_args!($($e),*),
+            &[$($crate::Field { key: core::stringify!($key), value: &$value }),*])
+    };
+    (from $o:expr, fields [$($key:ident = $value:expr),*], $($e:expr),*) => {
+        $crate::__internal_print_log_record($crate::LogLevel::Error, core::format_args!("{:?}", $o), core::format_args!($($e),*),
+            &[$($crate::Field { key: core::stringify!($key), value: &$value }),*])
+    };
     ($($e:expr),*) => {
-        $crate::__internal_print_log_msg($crate::LogLevel::Error, std::format_args!(""), std::format_args!($($e),*))
+        $crate::__internal_print_log_msg($crate::LogLevel::Error, core::format_args!(""), core::format_args!($($e),*))
     };
     (from $o:expr, $($e:expr),*) => {
-        $crate::__internal_print_log_msg($crate::LogLevel::Error, std::format_args!("{:?}", $o), std::format_args!($($e),*))
+        $crate::__internal_print_log_msg($crate::LogLevel::Error, core::format_args!("{:?}", $o), core::format_args!($($e),*))
     };
     (from $o:expr, when $call:expr, $($e:expr),*) => {
         {
             let result = $call;
             if result.is_err() {
-                $crate::__internal_print_log_msg($crate::LogLevel::Error, std::format_args!("{:?}", $o), std::format_args!($($e),*))
+                $crate::__internal_print_log_msg($crate::LogLevel::Error, core::format_args!("{:?}", $o), core::format_args!($($e),*))
             }
         }
     }
@@ -244,22 +289,37 @@ macro_rules! error {
 macro_rules! fatal_panic {
     ($($e:expr),*) => {
         {
-            $crate::__internal_print_log_msg($crate::LogLevel::Fatal, std::format_args!(""), std::format_args!($($e),*));
-            std::panic!($($e),*);
+            match core::format_args!($($e),*) {
+                message => {
+                    let failure = $crate::__prepare_failure!(Fatal, Fatal, core::format_args!(""), "{}", message);
+                    $crate::fail::__internal::report_failure(failure, None);
+                    core::panic!("{}", message);
+                }
+            }
         }
     };
     (from $o:expr, $($e:expr),*) => {
         {
-            $crate::__internal_print_log_msg($crate::LogLevel::Fatal, std::format_args!("{:?}", $o), std::format_args!($($e),*));
-            std::panic!("From: {:?} ::: {}", $o, std::format_args!($($e),*));
+            match core::format_args!($($e),*) {
+                message => {
+                    let failure = $crate::__prepare_failure!(Fatal, Fatal, core::format_args!("{:?}", $o), "{}", message);
+                    $crate::fail::__internal::report_failure(failure, None);
+                    core::panic!("From: {:?} ::: {}", $o, message);
+                }
+            }
         }
     };
     (from $o:expr, when $call:expr, $($e:expr),*) => {
         {
             let result = $call;
             if result.is_err() {
-                $crate::__internal_print_log_msg($crate::LogLevel::Fatal, std::format_args!("{:?}", $o), std::format_args!($($e),*));
-                std::panic!("From: {:?} ::: {}", $o, std::format_args!($($e),*));
+                match core::format_args!($($e),*) {
+                    message => {
+                        let failure = $crate::__prepare_failure!(Fatal, Fatal, core::format_args!("{:?}", $o), "{}", message);
+                        $crate::__report_failure!(failure, result.as_ref().err().unwrap());
+                        core::panic!("From: {:?} ::: {}", $o, message);
+                    }
+                }
             }
             result.ok().unwrap()
         }
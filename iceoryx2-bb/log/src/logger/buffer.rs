@@ -12,13 +12,14 @@
 
 use std::sync::Mutex;
 
-use crate::LogLevel;
+use crate::{LogLevel, Record};
 
 #[derive(Debug, Clone)]
 pub struct Entry {
     pub log_level: LogLevel,
     pub origin: String,
     pub message: String,
+    pub fields: Vec<(String, String)>,
 }
 
 pub struct Logger {
@@ -98,6 +99,23 @@ impl crate::Log for Logger {
                 log_level,
                 origin: origin.to_string(),
                 message: formatted_message.to_string(),
+                fields: vec![],
+            });
+    }
+
+    fn log_record(&self, record: &Record) {
+        self.buffer
+            .lock()
+            .expect("Unable to log record since the lock of the log buffer failed.")
+            .push(Entry {
+                log_level: record.log_level,
+                origin: record.origin.to_string(),
+                message: record.message.to_string(),
+                fields: record
+                    .fields
+                    .iter()
+                    .map(|field| (field.key.to_string(), field.value.to_string()))
+                    .collect(),
             });
     }
 }
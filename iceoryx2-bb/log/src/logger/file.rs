@@ -46,7 +46,7 @@ use std::{
 
 use std::sync::mpsc::channel;
 
-use crate::{get_log_level, LogLevel};
+use crate::LogLevel;
 
 enum Message {
     Entry(Entry),
@@ -129,10 +129,6 @@ impl crate::Log for Logger {
         origin: core::fmt::Arguments,
         formatted_message: core::fmt::Arguments,
     ) {
-        if get_log_level() > log_level as u8 {
-            return;
-        }
-
         self.sender
             .send({
                 Message::Entry(Entry {
@@ -10,7 +10,40 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::LogLevel;
+use crate::{Field, LogLevel, Record};
+
+// forwards the fields of a [`Record`] as key/value pairs to the `log` crate
+struct KeyValues<'a>(&'a [Field<'a>]);
+
+impl log::kv::Source for KeyValues<'_> {
+    fn visit<'kvs>(
+        &'kvs self,
+        visitor: &mut dyn log::kv::VisitSource<'kvs>,
+    ) -> Result<(), log::kv::Error> {
+        for field in self.0 {
+            visitor.visit_pair(
+                log::kv::Key::from_str(field.key),
+                log::kv::Value::from_dyn_display(field.value),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn count(&self) -> usize {
+        self.0.len()
+    }
+}
+
+fn to_log_level(log_level: LogLevel) -> log::Level {
+    match log_level {
+        LogLevel::Trace => log::Level::Trace,
+        LogLevel::Debug => log::Level::Debug,
+        LogLevel::Info => log::Level::Info,
+        LogLevel::Warn => log::Level::Warn,
+        LogLevel::Error => log::Level::Error,
+        LogLevel::Fatal => log::Level::Error,
+    }
+}
 
 pub struct Logger {
     _priv: (),
@@ -39,4 +72,22 @@ impl crate::Log for Logger {
             LogLevel::Fatal => log::error!(target: &origin, "{}", formatted_message),
         }
     }
+
+    fn log_record(&self, record: &Record) {
+        let level = to_log_level(record.log_level);
+        if level > log::max_level() {
+            return;
+        }
+
+        let origin = format!("{}", record.origin);
+        let key_values = KeyValues(record.fields);
+        log::logger().log(
+            &log::Record::builder()
+                .args(record.message)
+                .level(level)
+                .target(&origin)
+                .key_values(&key_values)
+                .build(),
+        );
+    }
 }
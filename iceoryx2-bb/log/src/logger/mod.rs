@@ -13,15 +13,22 @@
 //! Trait which can be implemented by logger, see [`crate::logger::console::Logger`]
 //! for instance.
 
+#[cfg(feature = "std")]
 pub mod buffer;
+#[cfg(feature = "std")]
 pub mod console;
+#[cfg(feature = "std")]
 pub mod file;
 #[cfg(feature = "logger_log")]
 pub mod log;
+pub mod null;
+#[cfg(feature = "std")]
+pub mod ring;
 #[cfg(feature = "logger_tracing")]
 pub mod tracing;
 
 /// Sets the [`console::Logger`] as default logger
+#[cfg(feature = "std")]
 pub fn use_console_logger() -> bool {
     // LazyLock is only available in 'std' but since static values are never dropped in Rust,
     // we can also use Box::leak
@@ -30,6 +37,7 @@ pub fn use_console_logger() -> bool {
 }
 
 /// Sets the [`file::Logger`] as default logger
+#[cfg(feature = "std")]
 pub fn use_file_logger(log_file_name: &str) -> bool {
     // LazyLock is only available in 'std' but since static values are never dropped in Rust,
     // we can also use Box::leak
@@ -37,3 +45,18 @@ pub fn use_file_logger(log_file_name: &str) -> bool {
 
     crate::set_logger(logger)
 }
+
+/// Sets the [`ring::Logger`] as default logger and returns a reference to it so that its
+/// content can be acquired. Returns [`None`] when the logger was already set.
+#[cfg(feature = "std")]
+pub fn use_ring_logger<const CAPACITY: usize>() -> Option<&'static ring::Logger<CAPACITY>> {
+    // LazyLock is only available in 'std' but since static values are never dropped in Rust,
+    // we can also use Box::leak
+    let logger = Box::leak(Box::new(ring::Logger::<CAPACITY>::new()));
+
+    if crate::set_logger(&*logger) {
+        Some(logger)
+    } else {
+        None
+    }
+}
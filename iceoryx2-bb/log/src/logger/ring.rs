@@ -0,0 +1,319 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A lock-free [`Logger`] that stores the last `CAPACITY` log messages in a fixed size ring
+//! buffer in memory. Logging neither allocates nor performs a system call, therefore it can
+//! stay enabled in low-latency deployments. The content can be acquired with
+//! [`Logger::content()`], written with [`Logger::dump()`] or written automatically into a
+//! file when the process panics, see [`Logger::enable_crash_dump()`].
+//!
+//! The origin of an entry is truncated to [`MAX_ORIGIN_LEN`] bytes and origin and message
+//! together to [`MAX_ENTRY_LEN`] bytes. When more than `CAPACITY` threads log concurrently
+//! an entry may be lost when a newer entry claims its slot before it is written.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_log::{info, set_logger, logger::ring};
+//!
+//! static LOGGER: ring::Logger<128> = ring::Logger::new();
+//!
+//! set_logger(&LOGGER);
+//! LOGGER.enable_crash_dump("crash.log");
+//!
+//! info!("hello world");
+//!
+//! for entry in LOGGER.content() {
+//!     println!("{}", entry);
+//! }
+//! ```
+
+use core::fmt::Write as _;
+use core::sync::atomic::{fence, Ordering};
+
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+
+use crate::LogLevel;
+
+/// The maximum number of bytes of the origin and the message of an entry.
+pub const MAX_ENTRY_LEN: usize = (ENTRY_WORDS - 1) * WORD_SIZE;
+/// The maximum number of bytes of the origin of an entry.
+pub const MAX_ORIGIN_LEN: usize = 64;
+
+const WORD_SIZE: usize = core::mem::size_of::<u64>();
+const ENTRY_WORDS: usize = 32;
+// how often a writer waits for the writer of an older entry in the same slot
+const MAX_CLAIM_ATTEMPTS: usize = 1024;
+
+/// A log message that was acquired with [`Logger::content()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub sequence_number: u64,
+    pub log_level: LogLevel,
+    pub origin: String,
+    pub message: String,
+}
+
+impl core::fmt::Display for Entry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} [{:?}] {} | {}",
+            self.sequence_number, self.log_level, self.origin, self.message
+        )
+    }
+}
+
+struct TruncatingWriter<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+}
+
+impl core::fmt::Write for TruncatingWriter<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let mut n = s.len().min(self.buffer.len() - self.len);
+        while !s.is_char_boundary(n) {
+            n -= 1;
+        }
+
+        self.buffer[self.len..self.len + n].copy_from_slice(&s.as_bytes()[..n]);
+        self.len += n;
+        Ok(())
+    }
+}
+
+// A slot is protected by a sequence lock. The sequence of the entry with the position `p` is
+// `2p + 1` while it is written and `2p + 2` when it is complete, so that a reader can detect
+// torn and overwritten entries. A writer claims the slot before it writes the data, therefore
+// only one writer at a time modifies a slot.
+struct Slot {
+    sequence: IoxAtomicU64,
+    data: [IoxAtomicU64; ENTRY_WORDS],
+}
+
+impl Slot {
+    const fn new() -> Self {
+        Self {
+            sequence: IoxAtomicU64::new(0),
+            data: [const { IoxAtomicU64::new(0) }; ENTRY_WORDS],
+        }
+    }
+
+    // The claim fails when the slot was already claimed by a newer entry. While the writer of
+    // an older entry still writes the slot, the claim is retried a limited number of times.
+    fn claim(&self, position: u64) -> bool {
+        let sequence = 2 * position + 1;
+        let mut current = self.sequence.load(Ordering::Relaxed);
+        for _ in 0..MAX_CLAIM_ATTEMPTS {
+            if sequence <= current {
+                return false;
+            }
+
+            if current % 2 == 1 {
+                core::hint::spin_loop();
+                current = self.sequence.load(Ordering::Relaxed);
+                continue;
+            }
+
+            match self.sequence.compare_exchange_weak(
+                current,
+                sequence,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return true,
+                Err(v) => current = v,
+            }
+        }
+
+        false
+    }
+
+    fn write(&self, position: u64, log_level: LogLevel, bytes: &[u8], origin_len: usize) {
+        if !self.claim(position) {
+            return;
+        }
+        fence(Ordering::Release);
+
+        let header = (log_level as u64) | ((origin_len as u64) << 8) | ((bytes.len() as u64) << 24);
+        self.data[0].store(header, Ordering::Relaxed);
+        for (word, chunk) in self.data[1..].iter().zip(bytes.chunks(WORD_SIZE)) {
+            let mut value = [0u8; WORD_SIZE];
+            value[..chunk.len()].copy_from_slice(chunk);
+            word.store(u64::from_le_bytes(value), Ordering::Relaxed);
+        }
+
+        self.sequence.store(2 * position + 2, Ordering::Release);
+    }
+
+    fn read(&self, position: u64) -> Option<Entry> {
+        let sequence = 2 * position + 2;
+        if self.sequence.load(Ordering::Acquire) != sequence {
+            return None;
+        }
+
+        let mut words = [0u64; ENTRY_WORDS];
+        for (value, word) in words.iter_mut().zip(self.data.iter()) {
+            *value = word.load(Ordering::Relaxed);
+        }
+
+        fence(Ordering::Acquire);
+        if self.sequence.load(Ordering::Relaxed) != sequence {
+            return None;
+        }
+
+        let header = words[0];
+        let log_level = LogLevel::from_u8((header & 0xff) as u8);
+        let origin_len = ((header >> 8) & 0xffff) as usize;
+        let len = ((header >> 24) & 0xffff) as usize;
+
+        let mut bytes = [0u8; MAX_ENTRY_LEN];
+        for (chunk, word) in bytes.chunks_mut(WORD_SIZE).zip(words[1..].iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+
+        Some(Entry {
+            sequence_number: position,
+            log_level,
+            origin: String::from_utf8_lossy(&bytes[..origin_len]).into_owned(),
+            message: String::from_utf8_lossy(&bytes[origin_len..len]).into_owned(),
+        })
+    }
+}
+
+/// The ring buffer [`Logger`] that stores the last `CAPACITY` log messages.
+pub struct Logger<const CAPACITY: usize> {
+    write_position: IoxAtomicU64,
+    slots: [Slot; CAPACITY],
+}
+
+impl<const CAPACITY: usize> Default for Logger<CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const CAPACITY: usize> core::fmt::Debug for Logger<CAPACITY> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Logger<{}> {{ write_position: {} }}",
+            CAPACITY,
+            self.write_position.load(Ordering::Relaxed)
+        )
+    }
+}
+
+impl<const CAPACITY: usize> Logger<CAPACITY> {
+    /// Creates a new empty [`Logger`].
+    pub const fn new() -> Self {
+        const {
+            assert!(
+                CAPACITY > 0,
+                "The capacity of the ring logger must be greater zero."
+            );
+        }
+
+        Self {
+            write_position: IoxAtomicU64::new(0),
+            slots: [const { Slot::new() }; CAPACITY],
+        }
+    }
+
+    /// Returns the maximum number of entries the [`Logger`] can store.
+    pub const fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Returns the number of stored entries.
+    pub fn len(&self) -> usize {
+        (self.write_position.load(Ordering::Relaxed) as usize).min(CAPACITY)
+    }
+
+    /// Returns true when nothing was logged so far.
+    pub fn is_empty(&self) -> bool {
+        self.write_position.load(Ordering::Relaxed) == 0
+    }
+
+    /// Returns the number of entries that were overwritten since the buffer was full.
+    pub fn number_of_overwritten_entries(&self) -> u64 {
+        self.write_position
+            .load(Ordering::Relaxed)
+            .saturating_sub(CAPACITY as u64)
+    }
+
+    /// Returns a copy of all stored entries, ordered from the oldest to the newest. Entries
+    /// that are written concurrently are skipped.
+    pub fn content(&self) -> Vec<Entry> {
+        let end = self.write_position.load(Ordering::Acquire);
+        let start = end.saturating_sub(CAPACITY as u64);
+
+        (start..end)
+            .filter_map(|position| self.slots[(position % CAPACITY as u64) as usize].read(position))
+            .collect()
+    }
+
+    /// Writes all stored entries, one per line, into the provided writer.
+    pub fn dump<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for entry in self.content() {
+            writeln!(writer, "{}", entry)?;
+        }
+        writer.flush()
+    }
+
+    /// Writes all stored entries into the provided file when the process panics, e.g. due to
+    /// [`fatal_panic!`](crate::fatal_panic). The file is created or truncated and the
+    /// previously installed panic hook is called afterwards.
+    pub fn enable_crash_dump(&'static self, file_name: &str) {
+        let file_name = file_name.to_string();
+        let previous_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if let Ok(mut file) = std::fs::File::create(&file_name) {
+                let _ = self.dump(&mut file);
+            }
+            previous_hook(info);
+        }));
+    }
+}
+
+impl<const CAPACITY: usize> crate::Log for Logger<CAPACITY> {
+    fn log(
+        &self,
+        log_level: LogLevel,
+        origin: core::fmt::Arguments,
+        formatted_message: core::fmt::Arguments,
+    ) {
+        let mut bytes = [0u8; MAX_ENTRY_LEN];
+
+        let mut writer = TruncatingWriter {
+            buffer: &mut bytes[..MAX_ORIGIN_LEN],
+            len: 0,
+        };
+        let _ = writer.write_fmt(origin);
+        let origin_len = writer.len;
+
+        let mut writer = TruncatingWriter {
+            buffer: &mut bytes[origin_len..],
+            len: 0,
+        };
+        let _ = writer.write_fmt(formatted_message);
+        let len = origin_len + writer.len;
+
+        let position = self.write_position.fetch_add(1, Ordering::Relaxed);
+        self.slots[(position % CAPACITY as u64) as usize].write(
+            position,
+            log_level,
+            &bytes[..len],
+            origin_len,
+        );
+    }
+}
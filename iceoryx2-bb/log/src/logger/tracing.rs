@@ -10,7 +10,100 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::LogLevel;
+use std::sync::{Mutex, OnceLock};
+
+use tracing_core::{
+    callsite::{self, Callsite},
+    field::{self, FieldSet, Value},
+    metadata::Kind,
+    subscriber::Interest,
+    Event, Level, Metadata,
+};
+
+use crate::{DisplayFields, LogLevel, Record};
+
+// the maximum number of fields of a structured message that are forwarded as separate
+// tracing fields, records with more fields append them to the message
+const MAX_NUMBER_OF_FIELDS: usize = 16;
+// every event of a record carries the message and the origin as first two fields
+const NUMBER_OF_DEFAULT_FIELDS: usize = 2;
+
+// tracing requires the field names of an event at compile time, since the keys of a
+// record are only known at runtime a callsite is created once for every combination of
+// log level and keys
+struct RecordCallsite {
+    metadata: OnceLock<Metadata<'static>>,
+}
+
+impl Callsite for RecordCallsite {
+    fn set_interest(&self, _interest: Interest) {}
+
+    fn metadata(&self) -> &Metadata<'_> {
+        self.metadata
+            .get()
+            .expect("the metadata is initialized before the callsite is registered")
+    }
+}
+
+struct RegisteredCallsite {
+    level: Level,
+    keys: Vec<&'static str>,
+    callsite: &'static RecordCallsite,
+}
+
+static CALLSITES: Mutex<Vec<RegisteredCallsite>> = Mutex::new(Vec::new());
+
+fn to_tracing_level(log_level: LogLevel) -> Level {
+    match log_level {
+        LogLevel::Trace => Level::TRACE,
+        LogLevel::Debug => Level::DEBUG,
+        LogLevel::Info => Level::INFO,
+        LogLevel::Warn => Level::WARN,
+        LogLevel::Error => Level::ERROR,
+        LogLevel::Fatal => Level::ERROR,
+    }
+}
+
+fn callsite(level: Level, record: &Record) -> &'static RecordCallsite {
+    let mut callsites = CALLSITES.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(registered) = callsites.iter().find(|c| {
+        c.level == level
+            && c.keys.len() == record.fields.len()
+            && c.keys.iter().zip(record.fields).all(|(k, f)| *k == f.key)
+    }) {
+        return registered.callsite;
+    }
+
+    let keys: Vec<&'static str> = record.fields.iter().map(|f| f.key).collect();
+    let names: &'static [&'static str] = Box::leak(
+        ["message", "origin"]
+            .into_iter()
+            .chain(keys.iter().copied())
+            .collect::<Vec<_>>()
+            .into_boxed_slice(),
+    );
+    let callsite: &'static RecordCallsite = Box::leak(Box::new(RecordCallsite {
+        metadata: OnceLock::new(),
+    }));
+    let _ = callsite.metadata.set(Metadata::new(
+        "event iceoryx2-bb-log",
+        module_path!(),
+        level,
+        Some(file!()),
+        Some(line!()),
+        Some(module_path!()),
+        FieldSet::new(names, callsite::Identifier(callsite)),
+        Kind::EVENT,
+    ));
+    callsite::register(callsite);
+
+    callsites.push(RegisteredCallsite {
+        level,
+        keys,
+        callsite,
+    });
+    callsite
+}
 
 pub struct Logger {
     _priv: (),
@@ -38,4 +131,55 @@ impl crate::Log for Logger {
             LogLevel::Fatal => tracing::error!(origin, "{}", formatted_message),
         }
     }
+
+    fn log_record(&self, record: &Record) {
+        if record.fields.is_empty() {
+            return self.log(record.log_level, record.origin, record.message);
+        }
+
+        if record.fields.len() > MAX_NUMBER_OF_FIELDS {
+            return self.log(
+                record.log_level,
+                record.origin,
+                format_args!("{} {}", record.message, DisplayFields(record.fields)),
+            );
+        }
+
+        let level = to_tracing_level(record.log_level);
+        if level > tracing::level_filters::LevelFilter::current() {
+            return;
+        }
+
+        let metadata = callsite(level, record).metadata();
+        let field_set = metadata.fields();
+        let mut field_iter = field_set.iter();
+        let message_field = field_iter.next().expect("the message field exists");
+        let origin_field = field_iter.next().expect("the origin field exists");
+        let field_names: [Option<field::Field>; MAX_NUMBER_OF_FIELDS] =
+            core::array::from_fn(|_| field_iter.next());
+
+        let message = record.message;
+        let origin = record.origin;
+        let values: [field::DisplayValue<&dyn core::fmt::Display>; MAX_NUMBER_OF_FIELDS] =
+            core::array::from_fn(|n| {
+                field::display(record.fields.get(n).map_or(&"" as _, |f| f.value))
+            });
+
+        let mut value_set: [(&field::Field, Option<&dyn Value>);
+            MAX_NUMBER_OF_FIELDS + NUMBER_OF_DEFAULT_FIELDS] =
+            [(&message_field, None); MAX_NUMBER_OF_FIELDS + NUMBER_OF_DEFAULT_FIELDS];
+        value_set[0] = (&message_field, Some(&message as &dyn Value));
+        value_set[1] = (&origin_field, Some(&origin as &dyn Value));
+        for (n, name) in field_names.iter().enumerate() {
+            if let Some(name) = name {
+                value_set[n + NUMBER_OF_DEFAULT_FIELDS] = (name, Some(&values[n] as &dyn Value));
+            }
+        }
+
+        tracing_core::dispatcher::get_default(|dispatch| {
+            if dispatch.enabled(metadata) {
+                dispatch.event(&Event::new(metadata, &field_set.value_set(&value_set)));
+            }
+        });
+    }
 }
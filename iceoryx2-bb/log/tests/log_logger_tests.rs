@@ -0,0 +1,116 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "logger_log")]
+
+use std::sync::Mutex;
+
+use iceoryx2_bb_log::logger::log::Logger;
+use iceoryx2_bb_log::{Field, Log, LogLevel, Record};
+use iceoryx2_bb_testing::assert_that;
+
+type Fields = Vec<(String, String)>;
+
+struct Entry {
+    level: log::Level,
+    target: String,
+    message: String,
+    fields: Fields,
+}
+
+struct FieldCollector<'a>(&'a mut Fields);
+
+impl<'kvs> log::kv::VisitSource<'kvs> for FieldCollector<'_> {
+    fn visit_pair(
+        &mut self,
+        key: log::kv::Key<'kvs>,
+        value: log::kv::Value<'kvs>,
+    ) -> Result<(), log::kv::Error> {
+        self.0.push((key.to_string(), value.to_string()));
+        Ok(())
+    }
+}
+
+struct TestLogger {
+    entries: Mutex<Vec<Entry>>,
+}
+
+impl log::Log for TestLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        let mut fields = Fields::new();
+        record
+            .key_values()
+            .visit(&mut FieldCollector(&mut fields))
+            .unwrap();
+        self.entries.lock().unwrap().push(Entry {
+            level: record.level(),
+            target: record.target().to_string(),
+            message: record.args().to_string(),
+            fields,
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+static TEST_LOGGER: TestLogger = TestLogger {
+    entries: Mutex::new(Vec::new()),
+};
+
+#[test]
+fn log_logger_forwards_fields_as_separate_key_value_pairs() {
+    log::set_logger(&TEST_LOGGER).unwrap();
+    log::set_max_level(log::LevelFilter::Info);
+    let sut = Logger::new();
+
+    sut.log_record(&Record {
+        log_level: LogLevel::Debug,
+        origin: format_args!("filtered"),
+        message: format_args!("below max level"),
+        fields: &[Field {
+            key: "answer",
+            value: &42,
+        }],
+    });
+    sut.log_record(&Record {
+        log_level: LogLevel::Error,
+        origin: format_args!("some origin"),
+        message: format_args!("hello"),
+        fields: &[
+            Field {
+                key: "answer",
+                value: &42,
+            },
+            Field {
+                key: "name",
+                value: &"Bob",
+            },
+        ],
+    });
+
+    let entries = TEST_LOGGER.entries.lock().unwrap();
+    assert_that!(*entries, len 1);
+    assert_that!(entries[0].level, eq log::Level::Error);
+    assert_that!(entries[0].target, eq "some origin");
+    assert_that!(entries[0].message, eq "hello");
+    assert_that!(
+        entries[0].fields,
+        eq vec![
+            ("answer".to_string(), "42".to_string()),
+            ("name".to_string(), "Bob".to_string())
+        ]
+    );
+}
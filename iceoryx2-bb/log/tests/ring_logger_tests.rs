@@ -0,0 +1,121 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::sync::atomic::Ordering;
+use std::sync::Barrier;
+
+use iceoryx2_bb_log::logger::ring::{Entry, Logger};
+use iceoryx2_bb_log::{Log, LogLevel};
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+
+const CAPACITY: usize = 2;
+const PAYLOAD_LEN: usize = 200;
+
+fn log_entry(sut: &Logger<CAPACITY>, thread: usize, counter: usize) {
+    let filler = char::from(b'a' + thread as u8);
+    let payload = filler.to_string().repeat(PAYLOAD_LEN);
+    sut.log(
+        LogLevel::Info,
+        format_args!("thread_{thread}"),
+        format_args!("{thread}:{counter}:{payload}"),
+    );
+}
+
+// an entry is consistent when origin, thread id and payload were written by the same call
+fn verify_entry(entry: &Entry) {
+    let mut parts = entry.message.split(':');
+    let thread: usize = parts.next().unwrap().parse().unwrap();
+    let _counter: usize = parts.next().unwrap().parse().unwrap();
+    let payload = parts.next().unwrap();
+    let filler = char::from(b'a' + thread as u8);
+
+    assert_that!(entry.log_level, eq LogLevel::Info);
+    assert_that!(entry.origin, eq format!("thread_{thread}"));
+    assert_that!(payload.len(), eq PAYLOAD_LEN);
+    assert_that!(payload.chars().all(|c| c == filler), eq true);
+    assert_that!(parts.next(), is_none);
+}
+
+#[test]
+fn ring_logger_stores_the_last_entries_in_order() {
+    let sut = Logger::<CAPACITY>::new();
+
+    for n in 0..3 * CAPACITY {
+        log_entry(&sut, 0, n);
+    }
+
+    let content = sut.content();
+    assert_that!(content, len CAPACITY);
+    assert_that!(sut.number_of_overwritten_entries(), eq 2 * CAPACITY as u64);
+    for (n, entry) in content.iter().enumerate() {
+        verify_entry(entry);
+        assert_that!(entry.sequence_number, eq(2 * CAPACITY + n) as u64);
+        assert_that!(entry.message.starts_with(&format!("0:{}:", 2 * CAPACITY + n)), eq true);
+    }
+}
+
+#[test]
+fn ring_logger_entries_are_never_torn_when_the_ring_wraps_concurrently() {
+    const NUMBER_OF_WRITERS: usize = 2 * CAPACITY;
+    const NUMBER_OF_ENTRIES: usize = 20000;
+
+    let sut = Logger::<CAPACITY>::new();
+    let barrier = Barrier::new(NUMBER_OF_WRITERS + 1);
+    let keep_reading = IoxAtomicBool::new(true);
+
+    std::thread::scope(|s| {
+        let reader = s.spawn(|| {
+            let mut number_of_read_entries = 0;
+            barrier.wait();
+            while keep_reading.load(Ordering::Relaxed) {
+                let content = sut.content();
+                for entry in &content {
+                    verify_entry(entry);
+                }
+                for pair in content.windows(2) {
+                    assert_that!(pair[0].sequence_number, lt pair[1].sequence_number);
+                }
+                number_of_read_entries += content.len();
+            }
+            number_of_read_entries
+        });
+
+        let writers: Vec<_> = (0..NUMBER_OF_WRITERS)
+            .map(|thread| {
+                let sut = &sut;
+                let barrier = &barrier;
+                s.spawn(move || {
+                    barrier.wait();
+                    for counter in 0..NUMBER_OF_ENTRIES {
+                        log_entry(sut, thread, counter);
+                    }
+                })
+            })
+            .collect();
+
+        for writer in writers {
+            writer.join().unwrap();
+        }
+        keep_reading.store(false, Ordering::Relaxed);
+        assert_that!(reader.join().unwrap(), gt 0);
+    });
+
+    assert_that!(
+        sut.number_of_overwritten_entries(),
+        eq(NUMBER_OF_WRITERS * NUMBER_OF_ENTRIES - CAPACITY) as u64
+    );
+    let content = sut.content();
+    for entry in &content {
+        verify_entry(entry);
+    }
+}
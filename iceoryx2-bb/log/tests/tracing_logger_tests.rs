@@ -0,0 +1,155 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "logger_tracing")]
+
+use std::sync::{Arc, Mutex};
+
+use iceoryx2_bb_log::logger::tracing::Logger;
+use iceoryx2_bb_log::{Field, Log, LogLevel, Record};
+use iceoryx2_bb_testing::assert_that;
+use tracing_core::field::{Field as TracingField, Visit};
+use tracing_core::span::{Attributes, Id, Record as SpanRecord};
+use tracing_core::{Event, Level, Metadata, Subscriber};
+
+type Fields = Vec<(String, String)>;
+
+struct FieldCollector<'a>(&'a mut Fields);
+
+impl Visit for FieldCollector<'_> {
+    fn record_debug(&mut self, field: &TracingField, value: &dyn core::fmt::Debug) {
+        self.0
+            .push((field.name().to_string(), format!("{:?}", value)));
+    }
+}
+
+#[derive(Default, Clone)]
+struct TestSubscriber {
+    events: Arc<Mutex<Vec<(Level, Fields)>>>,
+}
+
+impl Subscriber for TestSubscriber {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _span: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &Id, _values: &SpanRecord<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields::new();
+        event.record(&mut FieldCollector(&mut fields));
+        self.events
+            .lock()
+            .unwrap()
+            .push((*event.metadata().level(), fields));
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+fn pair(key: &str, value: &str) -> (String, String) {
+    (key.to_string(), value.to_string())
+}
+
+#[test]
+fn tracing_logger_forwards_fields_as_separate_key_value_pairs() {
+    let subscriber = TestSubscriber::default();
+    let sut = Logger::new();
+
+    tracing::subscriber::with_default(subscriber.clone(), || {
+        sut.log_record(&Record {
+            log_level: LogLevel::Warn,
+            origin: format_args!("some origin"),
+            message: format_args!("hello"),
+            fields: &[
+                Field {
+                    key: "answer",
+                    value: &42,
+                },
+                Field {
+                    key: "name",
+                    value: &"Bob",
+                },
+            ],
+        });
+    });
+
+    let events = subscriber.events.lock().unwrap();
+    assert_that!(*events, len 1);
+    assert_that!(events[0].0, eq Level::WARN);
+    assert_that!(
+        events[0].1,
+        eq vec![
+            pair("message", "hello"),
+            pair("origin", "some origin"),
+            pair("answer", "42"),
+            pair("name", "Bob")
+        ]
+    );
+}
+
+#[test]
+fn tracing_logger_distinguishes_records_with_different_keys() {
+    let subscriber = TestSubscriber::default();
+    let sut = Logger::new();
+
+    tracing::subscriber::with_default(subscriber.clone(), || {
+        for key in ["first", "second", "first"] {
+            sut.log_record(&Record {
+                log_level: LogLevel::Info,
+                origin: format_args!(""),
+                message: format_args!("msg"),
+                fields: &[Field { key, value: &key }],
+            });
+        }
+    });
+
+    let events = subscriber.events.lock().unwrap();
+    assert_that!(*events, len 3);
+    assert_that!(events[0].1[2], eq pair("first", "first"));
+    assert_that!(events[1].1[2], eq pair("second", "second"));
+    assert_that!(events[2].1[2], eq pair("first", "first"));
+}
+
+#[test]
+fn tracing_logger_appends_fields_to_message_when_there_are_too_many() {
+    const NUMBER_OF_FIELDS: usize = 17;
+    const KEYS: [&str; NUMBER_OF_FIELDS] = [
+        "k0", "k1", "k2", "k3", "k4", "k5", "k6", "k7", "k8", "k9", "k10", "k11", "k12", "k13",
+        "k14", "k15", "k16",
+    ];
+    let subscriber = TestSubscriber::default();
+    let sut = Logger::new();
+    let fields: Vec<Field> = KEYS.iter().map(|key| Field { key, value: &1 }).collect();
+
+    tracing::subscriber::with_default(subscriber.clone(), || {
+        sut.log_record(&Record {
+            log_level: LogLevel::Info,
+            origin: format_args!(""),
+            message: format_args!("msg"),
+            fields: &fields,
+        });
+    });
+
+    let events = subscriber.events.lock().unwrap();
+    assert_that!(*events, len 1);
+    assert_that!(events[0].1, len 2);
+    assert_that!(events[0].1[0].1.starts_with("msg k0=1 k1=1"), eq true);
+}
@@ -63,10 +63,10 @@ use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
 #[derive(Debug)]
 pub struct PoolAllocator {
     buckets: UniqueIndexSet,
-    bucket_size: usize,
-    bucket_alignment: usize,
-    start: usize,
-    size: usize,
+    bucket_size: u64,
+    bucket_alignment: u64,
+    start: u64,
+    size: u64,
     is_memory_initialized: IoxAtomicBool,
 }
 
@@ -85,19 +85,19 @@ impl PoolAllocator {
     }
 
     pub fn bucket_size(&self) -> usize {
-        self.bucket_size
+        self.bucket_size as usize
     }
 
     pub fn size(&self) -> usize {
-        self.size
+        self.size as usize
     }
 
     pub fn start_address(&self) -> usize {
-        self.start
+        self.start as usize
     }
 
     pub fn max_alignment(&self) -> usize {
-        self.bucket_alignment
+        self.bucket_alignment as usize
     }
 
     /// Releases an previously allocated bucket of memory.
@@ -126,10 +126,10 @@ impl PoolAllocator {
             buckets: unsafe {
                 UniqueIndexSet::new_uninit(Self::calc_number_of_buckets(bucket_layout, ptr, size))
             },
-            bucket_size: bucket_layout.size(),
-            bucket_alignment: bucket_layout.align(),
-            start: adjusted_start,
-            size,
+            bucket_size: bucket_layout.size() as u64,
+            bucket_alignment: bucket_layout.align() as u64,
+            start: adjusted_start as u64,
+            size: size as u64,
             is_memory_initialized: IoxAtomicBool::new(false),
         }
     }
@@ -172,9 +172,9 @@ impl PoolAllocator {
     fn verify_ptr_is_managaed_by_allocator(&self, ptr: NonNull<u8>) {
         let position = ptr.as_ptr() as usize;
         debug_assert!(
-            !(position < self.start
-                || position > self.start + self.size
-                || (position - self.start) % self.bucket_size != 0),
+            !(position < self.start_address()
+                || position > self.start_address() + self.size()
+                || (position - self.start_address()) % self.bucket_size() != 0),
             "The pointer {:?} is not managed by this allocator.",
             ptr
         );
@@ -184,7 +184,7 @@ impl PoolAllocator {
         self.verify_ptr_is_managaed_by_allocator(ptr);
         let position = ptr.as_ptr() as usize;
 
-        ((position - self.start) / self.bucket_size) as u32
+        ((position - self.start_address()) / self.bucket_size()) as u32
     }
 }
 
@@ -192,20 +192,20 @@ impl BaseAllocator for PoolAllocator {
     fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocationError> {
         self.verify_init("allocate");
 
-        if layout.size() > self.bucket_size {
+        if layout.size() > self.bucket_size() {
             fail!(from self, with AllocationError::SizeTooLarge,
-                "The requested allocation size {} is greater than the maximum supported size of {}.", layout.size(), self.bucket_size);
+                "The requested allocation size {} is greater than the maximum supported size of {}.", layout.size(), self.bucket_size());
         }
 
-        if layout.align() > self.bucket_alignment {
+        if layout.align() > self.max_alignment() {
             fail!(from self, with AllocationError::AlignmentFailure,
-                "The requested allocation alignment {} is greater than the maximum supported alignment of {}.", layout.align(), self.bucket_alignment);
+                "The requested allocation alignment {} is greater than the maximum supported alignment of {}.", layout.align(), self.max_alignment());
         }
 
         match unsafe { self.buckets.acquire_raw_index() } {
             Ok(v) => Ok(unsafe {
                 NonNull::new_unchecked(core::slice::from_raw_parts_mut(
-                    (self.start + v as usize * self.bucket_size) as *mut u8,
+                    (self.start_address() + v as usize * self.bucket_size()) as *mut u8,
                     layout.size(),
                 ))
             }),
@@ -240,12 +240,12 @@ impl Allocator for PoolAllocator {
                 "{} since the new size of {} would be smaller than the old size of {}. Use Allocator::shrink instead.", msg, new_layout.size(), old_layout.size());
         }
 
-        if self.bucket_alignment < new_layout.align() {
+        if self.max_alignment() < new_layout.align() {
             fail!(from self, with AllocationGrowError::AlignmentFailure,
                 "{} since the new alignment {} exceeds the maximum supported alignment.", msg, new_layout.align() );
         }
 
-        if self.bucket_size < new_layout.size() {
+        if self.bucket_size() < new_layout.size() {
             fail!(from self, with AllocationGrowError::OutOfMemory,
                 "{} since the new size {} exceeds the maximum supported size.", msg, new_layout.size());
         }
@@ -273,7 +273,7 @@ impl Allocator for PoolAllocator {
                 "{} since the new size of {} would be greater than the old size of {}. Use Allocator::grow instead.", msg, new_layout.size(), old_layout.size());
         }
 
-        if self.bucket_alignment < new_layout.align() {
+        if self.max_alignment() < new_layout.align() {
             fail!(from self, with AllocationShrinkError::AlignmentFailure,
                 "{} since the new alignment {} exceeds the maximum supported alignment.", msg, new_layout.align() );
         }
@@ -334,10 +334,10 @@ impl<const MAX_NUMBER_OF_BUCKETS: usize> FixedSizePoolAllocator<MAX_NUMBER_OF_BU
                         MAX_NUMBER_OF_BUCKETS,
                     ))
                 },
-                bucket_size: bucket_layout.size(),
-                bucket_alignment: bucket_layout.align(),
-                start: adjusted_start,
-                size,
+                bucket_size: bucket_layout.size() as u64,
+                bucket_alignment: bucket_layout.align() as u64,
+                start: adjusted_start as u64,
+                size: size as u64,
                 is_memory_initialized: IoxAtomicBool::new(true),
             },
             next_free_index: core::array::from_fn(|i| UnsafeCell::new(i as u32 + 1)),
@@ -0,0 +1,202 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`AccessControlList`] defines which users and groups are allowed to access a
+//! resource, like a [`crate::file::File`] or a [`crate::shared_memory::SharedMemory`]. It
+//! consists of an optional owning user, an optional owning group and the [`Permission`]s that
+//! are granted to the group and all others.
+//!
+//! The owner permissions are not part of the [`AccessControlList`] since they are defined by
+//! the construct that owns the resource, see [`AccessControlList::apply()`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2_bb_posix::access_control_list::*;
+//! use iceoryx2_bb_posix::file::*;
+//! use iceoryx2_bb_posix::group::GroupExt;
+//! use iceoryx2_bb_posix::permission::Permission;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//!
+//! // only the members of the group "iox" are allowed to read and write besides the owner
+//! let acl = AccessControlListBuilder::new()
+//!     .group("iox".as_group().expect("no such group").gid())
+//!     .permission(Permission::GROUP_READ | Permission::GROUP_WRITE)
+//!     .create();
+//!
+//! let mut file = FileBuilder::new(&FilePath::new(b"/tmp/some_file").unwrap())
+//!     .creation_mode(CreationMode::PurgeAndCreate)
+//!     .create()
+//!     .unwrap();
+//!
+//! acl.apply(Permission::OWNER_ALL, &mut file).unwrap();
+//! ```
+
+use iceoryx2_bb_log::fail;
+use serde::{Deserialize, Serialize};
+
+use crate::file::{FileSetOwnerError, FileSetPermissionError};
+use crate::file_descriptor::FileDescriptorManagement;
+use crate::ownership::OwnershipBuilder;
+use crate::permission::Permission;
+
+/// Defines the failures that can occur when an [`AccessControlList`] is applied with
+/// [`AccessControlList::apply()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum AccessControlListApplyError {
+    /// The process is not allowed to change the owner or the permissions of the resource.
+    InsufficientPermissions,
+    /// The user or group id does not exist.
+    InvalidId,
+    /// The resource is part of a read-only file system.
+    ReadOnlyFilesystem,
+    InternalError,
+}
+
+impl core::fmt::Display for AccessControlListApplyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "AccessControlListApplyError::{:?}", self)
+    }
+}
+
+impl core::error::Error for AccessControlListApplyError {}
+
+/// Defines the owning user, the owning group and the [`Permission`]s of the group and all
+/// others of a resource. Can be created with the [`AccessControlListBuilder`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct AccessControlList {
+    #[serde(default)]
+    owner: Option<u32>,
+    #[serde(default)]
+    group: Option<u32>,
+    #[serde(default)]
+    permission: Permission,
+}
+
+impl AccessControlList {
+    /// Returns the user id of the owner. When it is [`None`], the owner is not changed.
+    pub fn owner(&self) -> Option<u32> {
+        self.owner
+    }
+
+    /// Returns the group id of the owning group. When it is [`None`], the group is not
+    /// changed.
+    pub fn group(&self) -> Option<u32> {
+        self.group
+    }
+
+    /// Returns the [`Permission`]s of the group and all others.
+    pub fn permission(&self) -> Permission {
+        self.permission
+    }
+
+    /// Returns the [`Permission`]s of the resource when the [`AccessControlList`] is applied
+    /// to a resource that requires the provided owner permissions.
+    pub fn permission_for(&self, owner_permission: Permission) -> Permission {
+        (owner_permission & Permission::OWNER_ALL)
+            | (self.permission & (Permission::GROUP_ALL | Permission::OTHERS_ALL))
+    }
+
+    /// Applies the [`AccessControlList`] to the provided resource. The owner permissions of
+    /// the resource are replaced with `owner_permission`, see
+    /// [`AccessControlList::permission_for()`].
+    pub fn apply<T: FileDescriptorManagement>(
+        &self,
+        owner_permission: Permission,
+        resource: &mut T,
+    ) -> Result<(), AccessControlListApplyError> {
+        let msg = "Unable to apply access control list";
+
+        if self.owner.is_some() || self.group.is_some() {
+            // an id of u32::MAX keeps the current owner or group
+            let ownership = OwnershipBuilder::new()
+                .uid(self.owner.unwrap_or(u32::MAX))
+                .gid(self.group.unwrap_or(u32::MAX))
+                .create();
+
+            match resource.set_ownership(ownership) {
+                Ok(()) => (),
+                Err(FileSetOwnerError::InsufficientPermissions) => {
+                    fail!(from self, with AccessControlListApplyError::InsufficientPermissions,
+                        "{} since the process is not allowed to change the ownership to {:?}.", msg, ownership);
+                }
+                Err(FileSetOwnerError::InvalidId) => {
+                    fail!(from self, with AccessControlListApplyError::InvalidId,
+                        "{} since the ownership {:?} contains an invalid id.", msg, ownership);
+                }
+                Err(FileSetOwnerError::ReadOnlyFilesystem) => {
+                    fail!(from self, with AccessControlListApplyError::ReadOnlyFilesystem,
+                        "{} since the resource is part of a read-only file system.", msg);
+                }
+                Err(e) => {
+                    fail!(from self, with AccessControlListApplyError::InternalError,
+                        "{} due to an internal failure while changing the ownership ({:?}).", msg, e);
+                }
+            }
+        }
+
+        let permission = self.permission_for(owner_permission);
+        match resource.set_permission(permission) {
+            Ok(()) => Ok(()),
+            Err(FileSetPermissionError::InsufficientPermissions) => {
+                fail!(from self, with AccessControlListApplyError::InsufficientPermissions,
+                    "{} since the process is not allowed to change the permissions to {}.", msg, permission);
+            }
+            Err(FileSetPermissionError::ReadOnlyFilesystem) => {
+                fail!(from self, with AccessControlListApplyError::ReadOnlyFilesystem,
+                    "{} since the resource is part of a read-only file system.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with AccessControlListApplyError::InternalError,
+                    "{} due to an internal failure while changing the permissions ({:?}).", msg, e);
+            }
+        }
+    }
+}
+
+/// The builder of the [`AccessControlList`]. By default, neither the owner nor the group is
+/// changed and the group and all others have no access.
+#[derive(Debug, Default)]
+pub struct AccessControlListBuilder {
+    acl: AccessControlList,
+}
+
+impl AccessControlListBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the user id of the owner
+    pub fn owner(mut self, uid: u32) -> Self {
+        self.acl.owner = Some(uid);
+        self
+    }
+
+    /// Sets the group id of the owning group
+    pub fn group(mut self, gid: u32) -> Self {
+        self.acl.group = Some(gid);
+        self
+    }
+
+    /// Sets the [`Permission`]s of the group and all others. The owner permissions are
+    /// ignored.
+    pub fn permission(mut self, value: Permission) -> Self {
+        self.acl.permission = value & (Permission::GROUP_ALL | Permission::OTHERS_ALL);
+        self
+    }
+
+    pub fn create(self) -> AccessControlList {
+        self.acl
+    }
+}
@@ -39,6 +39,7 @@
 pub use iceoryx2_bb_elementary::CallbackProgression;
 
 use core::{cell::RefCell, fmt::Debug, sync::atomic::Ordering, time::Duration};
+use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_log::fail;
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
@@ -47,6 +48,18 @@ use crate::{
     clock::{Time, TimeError},
 };
 
+enum_gen! {
+    /// Defines the failures that can occur when a deadline is added to the [`DeadlineQueue`]
+    /// with [`DeadlineQueue::add_deadline_interval()`] or
+    /// [`DeadlineQueue::add_deadline_timeout()`]. `ZeroPeriod` is returned when the deadline
+    /// is zero since it would be hit in every iteration.
+    DeadlineQueueAddError
+  entry:
+    ZeroPeriod
+  mapping:
+    TimeError
+}
+
 /// Represents an index to identify an added deadline_queue with [`DeadlineQueue::add_deadline_interval()`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct DeadlineQueueIndex(u64);
@@ -127,10 +140,18 @@ struct Attachment {
     index: u64,
     period: u128,
     start_time: u128,
+    is_one_shot: bool,
+    has_fired: bool,
+    number_of_missed_deadlines: u64,
 }
 
 impl Attachment {
-    fn new(index: u64, period: u128, clock_type: ClockType) -> Result<Self, TimeError> {
+    fn new(
+        index: u64,
+        period: u128,
+        is_one_shot: bool,
+        clock_type: ClockType,
+    ) -> Result<Self, TimeError> {
         let start_time = fail!(from "Attachment::new()", when Time::now_with_clock(clock_type),
                                 "Failed to create DeadlineQueue attachment since the current time could not be acquired.");
         let start_time = start_time.as_duration().as_nanos();
@@ -139,6 +160,9 @@ impl Attachment {
             index,
             period,
             start_time,
+            is_one_shot,
+            has_fired: false,
+            number_of_missed_deadlines: 0,
         })
     }
 
@@ -146,8 +170,32 @@ impl Attachment {
         let start_time = fail!(from "Attachment::new()", when Time::now_with_clock(clock_type),
                                 "Failed to reset DeadlineQueue attachment since the current time could not be acquired.");
         self.start_time = start_time.as_duration().as_nanos();
+        self.has_fired = false;
         Ok(())
     }
+
+    // returns the number of deadlines that were hit between the two time points
+    fn number_of_hit_deadlines(&self, last: u128, now: u128) -> u128 {
+        let duration_until_last = last.max(self.start_time) - self.start_time;
+        let duration_until_now = now - self.start_time;
+
+        if self.is_one_shot {
+            (!self.has_fired && self.period <= duration_until_now) as u128
+        } else {
+            duration_until_now / self.period - duration_until_last / self.period
+        }
+    }
+
+    fn duration_until_next_deadline(&self, now: u128) -> u128 {
+        if self.is_one_shot {
+            match self.has_fired {
+                true => u128::MAX,
+                false => self.period.saturating_sub(now - self.start_time),
+            }
+        } else {
+            self.period - (now - self.start_time) % self.period
+        }
+    }
 }
 
 /// The [`DeadlineQueue`] allows the user to attach multiple periodic deadline_queues with
@@ -181,11 +229,37 @@ impl DeadlineQueue {
     pub fn add_deadline_interval(
         &self,
         deadline: Duration,
-    ) -> Result<DeadlineQueueGuard, TimeError> {
+    ) -> Result<DeadlineQueueGuard<'_>, DeadlineQueueAddError> {
+        self.add_deadline(deadline, false)
+    }
+
+    /// Adds a one-shot deadline to the [`DeadlineQueue`] and returns an [`DeadlineQueueGuard`]
+    /// to identify the attachment uniquely. In contrast to
+    /// [`DeadlineQueue::add_deadline_interval()`] the deadline is reported only once by
+    /// [`DeadlineQueue::missed_deadlines()`] until it is rearmed with
+    /// [`DeadlineQueue::reset()`].
+    pub fn add_deadline_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<DeadlineQueueGuard<'_>, DeadlineQueueAddError> {
+        self.add_deadline(timeout, true)
+    }
+
+    fn add_deadline(
+        &self,
+        deadline: Duration,
+        is_one_shot: bool,
+    ) -> Result<DeadlineQueueGuard<'_>, DeadlineQueueAddError> {
+        if deadline.is_zero() {
+            fail!(from self, with DeadlineQueueAddError::ZeroPeriod,
+                "Unable to add deadline since a deadline of zero would be hit in every iteration.");
+        }
+
         let current_idx = self.id_count.load(Ordering::Relaxed);
         self.attachments.borrow_mut().push(Attachment::new(
             current_idx,
             deadline.as_nanos(),
+            is_one_shot,
             self.clock_type,
         )?);
         self.id_count.fetch_add(1, Ordering::Relaxed);
@@ -210,7 +284,20 @@ impl DeadlineQueue {
         }
     }
 
-    /// Resets the attached deadline_queue and wait again the full time.
+    /// Returns the number of deadlines of the attachment that were hit but not reported
+    /// separately by [`DeadlineQueue::missed_deadlines()`] since more than one period passed
+    /// between two calls. It is always zero for one-shot deadlines.
+    pub fn number_of_missed_deadlines(&self, index: DeadlineQueueIndex) -> u64 {
+        self.attachments
+            .borrow()
+            .iter()
+            .find(|attachment| attachment.index == index.0)
+            .map(|attachment| attachment.number_of_missed_deadlines)
+            .unwrap_or(0)
+    }
+
+    /// Resets the attached deadline_queue and wait again the full time. A one-shot deadline
+    /// is rearmed.
     pub fn reset(&self, index: DeadlineQueueIndex) -> Result<(), TimeError> {
         for attachment in &mut *self.attachments.borrow_mut() {
             if attachment.index == index.0 {
@@ -232,11 +319,12 @@ impl DeadlineQueue {
         let now = fail!(from self, when Time::now_with_clock(self.clock_type),
                         "Unable to return next duration since the current time could not be acquired.");
         let now = now.as_duration().as_nanos();
-        let mut has_missed_deadline = false;
-        self.handle_missed_deadlines(now, |_| {
-            has_missed_deadline = true;
-            CallbackProgression::Stop
-        });
+        let last = *self.previous_iteration.borrow();
+        let has_missed_deadline = self
+            .attachments
+            .borrow()
+            .iter()
+            .any(|attachment| attachment.number_of_hit_deadlines(last, now) > 0);
 
         if has_missed_deadline {
             return Ok(Duration::ZERO);
@@ -248,8 +336,12 @@ impl DeadlineQueue {
 
         let mut min_time = u128::MAX;
         for attachment in &*self.attachments.borrow() {
-            min_time =
-                min_time.min(attachment.period - (now - attachment.start_time) % attachment.period);
+            min_time = min_time.min(attachment.duration_until_next_deadline(now));
+        }
+
+        // only one-shot deadlines that have already fired are attached
+        if min_time == u128::MAX {
+            return Ok(Duration::MAX);
         }
 
         Ok(Duration::from_nanos(min_time as _))
@@ -262,31 +354,27 @@ impl DeadlineQueue {
     ) {
         let last = *self.previous_iteration.borrow();
 
-        for attachment in &*self.attachments.borrow() {
-            let duration_until_last = last.max(attachment.start_time) - attachment.start_time;
-            let duration_until_now = now - attachment.start_time;
-            match attachment.period {
-                0 => {
-                    if matches!(
-                        call(DeadlineQueueIndex(attachment.index)),
-                        CallbackProgression::Stop
-                    ) {
-                        return;
-                    }
-                }
-                _ => {
-                    let last = duration_until_last / attachment.period;
-                    let current = duration_until_now / attachment.period;
-
-                    if last < current
-                        && matches!(
-                            call(DeadlineQueueIndex(attachment.index)),
-                            CallbackProgression::Stop
-                        )
-                    {
-                        return;
-                    }
-                }
+        // the attachments must not be borrowed while the callback is called since the user
+        // may remove attachments or query them from within the callback
+        let mut hit_deadlines = vec![];
+        for attachment in &mut *self.attachments.borrow_mut() {
+            let number_of_hit_deadlines = attachment.number_of_hit_deadlines(last, now);
+            if number_of_hit_deadlines == 0 {
+                continue;
+            }
+
+            if attachment.is_one_shot {
+                attachment.has_fired = true;
+            } else {
+                attachment.number_of_missed_deadlines += (number_of_hit_deadlines - 1) as u64;
+            }
+
+            hit_deadlines.push(DeadlineQueueIndex(attachment.index));
+        }
+
+        for index in hit_deadlines {
+            if let CallbackProgression::Stop = call(index) {
+                return;
             }
         }
     }
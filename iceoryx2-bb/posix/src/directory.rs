@@ -39,7 +39,6 @@ use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_elementary::scope_guard::ScopeGuardBuilder;
 use iceoryx2_bb_log::{error, fail, fatal_panic, trace};
 use iceoryx2_bb_system_types::{file_name::FileName, file_path::FilePath, path::Path};
-use iceoryx2_pal_configuration::PATH_SEPARATOR;
 use iceoryx2_pal_posix::posix::Struct;
 use iceoryx2_pal_posix::*;
 use iceoryx2_pal_posix::{posix::errno::Errno, posix::S_IFDIR};
@@ -474,9 +473,7 @@ impl Directory {
 
     fn acquire_metadata(&self, file: &FileName, msg: &str) -> Result<Metadata, DirectoryStatError> {
         let mut buffer = posix::stat_t::new();
-        let mut path = *self.path();
-        path.push(PATH_SEPARATOR).unwrap();
-        path.push_bytes(file.as_bytes()).unwrap();
+        let path = self.path().join_file_name(file).unwrap();
 
         if unsafe { posix::stat(path.as_c_str(), &mut buffer) } == -1 {
             handle_errno!(DirectoryStatError, from self,
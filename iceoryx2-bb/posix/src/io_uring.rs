@@ -0,0 +1,580 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Abstraction of the Linux io_uring interface. Operations are pushed into the submission
+//! queue of an [`IoUring`], are submitted to the kernel with a single system call and their
+//! [`Completion`]s are harvested in batches from the completion queue without any further
+//! system call.
+//!
+//! When the [`IoUring`] is created with [`IoUringBuilder::completion_event()`], every
+//! completion signals an eventfd which can be attached to a
+//! [`FileDescriptorSet`](crate::file_descriptor_set::FileDescriptorSet).
+//!
+//! io_uring is only available on Linux 5.11 or newer and can be disabled by the system, for
+//! instance with the `kernel.io_uring_disabled` sysctl or a seccomp profile. On all other
+//! platforms the creation fails with [`IoUringCreationError::NotSupported`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2_bb_posix::io_uring::*;
+//! use iceoryx2_bb_posix::socket_pair::*;
+//! use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+//!
+//! let (socket_1, socket_2) = StreamingSocket::create_pair().unwrap();
+//! let mut ring = IoUringBuilder::new().number_of_entries(8).create().unwrap();
+//!
+//! // the buffer must stay valid until the completion of the operation is harvested
+//! let mut buffer = [0u8; 16];
+//! unsafe {
+//!     ring.push_receive(socket_2.file_descriptor(), buffer.as_mut_ptr(), buffer.len(), 1)
+//!         .unwrap();
+//! }
+//!
+//! socket_1.try_send(b"hello").unwrap();
+//! ring.submit_and_wait(1, None).unwrap();
+//! ring.harvest(|completion| {
+//!     assert_eq!(completion.user_data, 1);
+//!     assert_eq!(completion.result, 5);
+//! });
+//! ```
+
+use core::sync::atomic::Ordering;
+use core::time::Duration;
+
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU32;
+use iceoryx2_pal_posix::posix::{self, Errno};
+
+use crate::file_descriptor::FileDescriptor;
+
+const DEFAULT_NUMBER_OF_ENTRIES: u32 = 64;
+const CANCEL_ALL_USER_DATA: u64 = u64::MAX;
+const DROP_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Defines the errors that can occur when an [`IoUring`] is created with
+/// [`IoUringBuilder::create()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum IoUringCreationError {
+    NotSupported,
+    InsufficientPermissions,
+    InsufficientMemory,
+    InvalidNumberOfEntries,
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    UnknownError(i32),
+}
+
+impl core::fmt::Display for IoUringCreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "IoUringCreationError::{:?}", self)
+    }
+}
+
+impl core::error::Error for IoUringCreationError {}
+
+/// Defines the errors that can occur when an operation is pushed into the submission queue
+/// of an [`IoUring`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum IoUringPushError {
+    SubmissionQueueFull,
+}
+
+impl core::fmt::Display for IoUringPushError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "IoUringPushError::{:?}", self)
+    }
+}
+
+impl core::error::Error for IoUringPushError {}
+
+/// Defines the errors that can occur in [`IoUring::submit_and_wait()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum IoUringWaitError {
+    Interrupt,
+    InsufficientResources,
+    UnknownError(i32),
+}
+
+impl core::fmt::Display for IoUringWaitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "IoUringWaitError::{:?}", self)
+    }
+}
+
+impl core::error::Error for IoUringWaitError {}
+
+/// The completion of an operation that was pushed into the [`IoUring`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct Completion {
+    /// The user data that was provided when the operation was pushed.
+    pub user_data: u64,
+    /// The result of the operation, for instance the number of received bytes. A negative
+    /// value is the negated errno of the failure.
+    pub result: i32,
+}
+
+/// Creates an [`IoUring`].
+#[derive(Debug)]
+pub struct IoUringBuilder {
+    number_of_entries: u32,
+    has_completion_event: bool,
+}
+
+impl Default for IoUringBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IoUringBuilder {
+    pub fn new() -> Self {
+        Self {
+            number_of_entries: DEFAULT_NUMBER_OF_ENTRIES,
+            has_completion_event: false,
+        }
+    }
+
+    /// Defines the capacity of the submission queue. The kernel rounds it up to the next
+    /// power of two.
+    pub fn number_of_entries(mut self, value: u32) -> Self {
+        self.number_of_entries = value;
+        self
+    }
+
+    /// Registers an eventfd that is signalled on every completion, see
+    /// [`IoUring::completion_event()`].
+    pub fn completion_event(mut self, value: bool) -> Self {
+        self.has_completion_event = value;
+        self
+    }
+
+    /// Creates the [`IoUring`].
+    pub fn create(self) -> Result<IoUring, IoUringCreationError> {
+        let msg = "Unable to create io_uring";
+        let mut params = posix::io_uring_params::default();
+        let fd = unsafe { posix::io_uring_setup(self.number_of_entries, &mut params) };
+        let file_descriptor = match FileDescriptor::new(fd) {
+            Some(fd) => fd,
+            None => {
+                handle_errno!(IoUringCreationError, from self,
+                    Errno::ENOSYS => (NotSupported, "{} since it is not supported by the platform.", msg),
+                    Errno::EPERM => (InsufficientPermissions, "{} since it is disabled for the process.", msg),
+                    Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+                    Errno::EINVAL => (InvalidNumberOfEntries, "{} since the number of entries {} is not supported.", msg, self.number_of_entries),
+                    Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process file handle limit was reached.", msg),
+                    Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since the system-wide file handle limit was reached.", msg),
+                    v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+                );
+            }
+        };
+
+        if params.features & posix::IORING_FEAT_EXT_ARG == 0 {
+            fail!(from self, with IoUringCreationError::NotSupported,
+                "{} since the kernel does not support waiting with a timeout (requires Linux 5.11).", msg);
+        }
+
+        let sq_ring_size =
+            params.sq_off.array as usize + params.sq_entries as usize * core::mem::size_of::<u32>();
+        let cq_ring_size = params.cq_off.cqes as usize
+            + params.cq_entries as usize * core::mem::size_of::<posix::io_uring_cqe>();
+        let has_single_mapping = params.features & posix::IORING_FEAT_SINGLE_MMAP != 0;
+
+        let sq_ring = Mapping::new(
+            &file_descriptor,
+            if has_single_mapping {
+                sq_ring_size.max(cq_ring_size)
+            } else {
+                sq_ring_size
+            },
+            posix::IORING_OFF_SQ_RING,
+        );
+        let sq_ring = fail!(from self, when sq_ring, "{} since the submission queue could not be mapped.", msg);
+
+        let cq_ring = if has_single_mapping {
+            None
+        } else {
+            Some(
+                fail!(from self, when Mapping::new(&file_descriptor, cq_ring_size, posix::IORING_OFF_CQ_RING),
+                "{} since the completion queue could not be mapped.", msg),
+            )
+        };
+
+        let sqes = Mapping::new(
+            &file_descriptor,
+            params.sq_entries as usize * core::mem::size_of::<posix::io_uring_sqe>(),
+            posix::IORING_OFF_SQES,
+        );
+        let sqes = fail!(from self, when sqes, "{} since the submission queue entries could not be mapped.", msg);
+
+        let completion_event = if self.has_completion_event {
+            let event_fd = FileDescriptor::new(unsafe {
+                posix::eventfd(0, posix::EFD_CLOEXEC | posix::EFD_NONBLOCK)
+            });
+            let event_fd = match event_fd {
+                Some(fd) => fd,
+                None => {
+                    handle_errno!(IoUringCreationError, from self,
+                        Errno::ENOMEM => (InsufficientMemory, "{} since the completion event could not be created due to insufficient memory.", msg),
+                        Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process file handle limit was reached.", msg),
+                        Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since the system-wide file handle limit was reached.", msg),
+                        v => (UnknownError(v as i32), "{} since an unknown error occurred while creating the completion event ({}).", msg, v)
+                    );
+                }
+            };
+
+            let raw_event_fd = unsafe { event_fd.native_handle() };
+            if unsafe {
+                posix::io_uring_register(
+                    file_descriptor.native_handle(),
+                    posix::IORING_REGISTER_EVENTFD,
+                    (&raw_event_fd as *const posix::int).cast(),
+                    1,
+                )
+            } != 0
+            {
+                handle_errno!(IoUringCreationError, from self,
+                    Errno::ENOMEM => (InsufficientMemory, "{} since the completion event could not be registered due to insufficient memory.", msg),
+                    v => (UnknownError(v as i32), "{} since an unknown error occurred while registering the completion event ({}).", msg, v)
+                );
+            }
+
+            Some(event_fd)
+        } else {
+            None
+        };
+
+        let cq_base = match &cq_ring {
+            Some(cq_ring) => cq_ring.address,
+            None => sq_ring.address,
+        };
+
+        let sq_base = sq_ring.address;
+        Ok(IoUring {
+            sq_head: unsafe { &*(sq_base.add(params.sq_off.head as usize) as *const IoxAtomicU32) },
+            sq_tail: unsafe { &*(sq_base.add(params.sq_off.tail as usize) as *const IoxAtomicU32) },
+            sq_mask: unsafe { *(sq_base.add(params.sq_off.ring_mask as usize) as *const u32) },
+            sq_entries: params.sq_entries,
+            sq_array: unsafe { sq_base.add(params.sq_off.array as usize) as *mut u32 },
+            cq_head: unsafe { &*(cq_base.add(params.cq_off.head as usize) as *const IoxAtomicU32) },
+            cq_tail: unsafe { &*(cq_base.add(params.cq_off.tail as usize) as *const IoxAtomicU32) },
+            cq_mask: unsafe { *(cq_base.add(params.cq_off.ring_mask as usize) as *const u32) },
+            cqes: unsafe { cq_base.add(params.cq_off.cqes as usize) as *const posix::io_uring_cqe },
+            number_of_operations_in_flight: 0,
+            completion_event,
+            sqes_mapping: sqes,
+            _cq_ring_mapping: cq_ring,
+            _sq_ring_mapping: sq_ring,
+            file_descriptor,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Mapping {
+    address: *mut u8,
+    size: usize,
+}
+
+impl Mapping {
+    fn new(
+        fd: &FileDescriptor,
+        size: usize,
+        offset: posix::off_t,
+    ) -> Result<Self, IoUringCreationError> {
+        let address = unsafe {
+            posix::mmap(
+                core::ptr::null_mut(),
+                size,
+                posix::PROT_READ | posix::PROT_WRITE,
+                posix::MAP_SHARED,
+                fd.native_handle(),
+                offset,
+            )
+        };
+
+        if address == posix::MAP_FAILED {
+            let msg = "Unable to map io_uring memory";
+            handle_errno!(IoUringCreationError, from "IoUring::Mapping::new()",
+                Errno::ENOMEM => (InsufficientMemory, "{} of {} bytes due to insufficient memory.", msg, size),
+                Errno::EPERM => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+
+        Ok(Self {
+            address: address as *mut u8,
+            size,
+        })
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        if unsafe { posix::munmap(self.address as *mut posix::void, self.size) } != 0 {
+            warn!(from self, "Unable to unmap io_uring memory ({:?}).", Errno::get());
+        }
+    }
+}
+
+/// An io_uring instance, see the [module documentation](crate::io_uring) for details. When it
+/// goes out of scope, all operations that are still in flight are cancelled.
+#[derive(Debug)]
+pub struct IoUring {
+    sq_head: &'static IoxAtomicU32,
+    sq_tail: &'static IoxAtomicU32,
+    sq_mask: u32,
+    sq_entries: u32,
+    sq_array: *mut u32,
+    cq_head: &'static IoxAtomicU32,
+    cq_tail: &'static IoxAtomicU32,
+    cq_mask: u32,
+    cqes: *const posix::io_uring_cqe,
+    number_of_operations_in_flight: usize,
+    completion_event: Option<FileDescriptor>,
+    // the mappings must be unmapped before the io_uring file descriptor is closed
+    sqes_mapping: Mapping,
+    _cq_ring_mapping: Option<Mapping>,
+    _sq_ring_mapping: Mapping,
+    file_descriptor: FileDescriptor,
+}
+
+// SAFETY: the ring memory is owned by the IoUring and only accessed via &mut self, besides
+// the completion queue head and tail which are atomics
+unsafe impl Send for IoUring {}
+
+impl Drop for IoUring {
+    fn drop(&mut self) {
+        if self.number_of_operations_in_flight == 0 {
+            return;
+        }
+
+        // the kernel may still write into the buffers of the operations in flight, they are
+        // cancelled and awaited so that the buffers can be released afterwards
+        let sqe = posix::io_uring_sqe {
+            opcode: posix::IORING_OP_ASYNC_CANCEL,
+            fd: -1,
+            op_flags: posix::IORING_ASYNC_CANCEL_ANY,
+            user_data: CANCEL_ALL_USER_DATA,
+            ..Default::default()
+        };
+        if self.push(sqe).is_err() {
+            warn!(from self, "Unable to cancel the operations in flight since the submission queue is full.");
+        }
+
+        while self.number_of_operations_in_flight != 0 {
+            if let Err(e) = self.submit_and_wait(1, Some(DROP_TIMEOUT)) {
+                warn!(from self, "Unable to await the cancellation of the operations in flight ({:?}).", e);
+                return;
+            }
+
+            if self.harvest(|_| {}) == 0 {
+                warn!(from self, "The cancellation of the {} operations in flight was not confirmed within {:?}.",
+                    self.number_of_operations_in_flight, DROP_TIMEOUT);
+                return;
+            }
+        }
+    }
+}
+
+impl IoUring {
+    /// Returns the eventfd that is signalled on every completion when the [`IoUring`] was
+    /// created with [`IoUringBuilder::completion_event()`].
+    pub fn completion_event(&self) -> Option<&FileDescriptor> {
+        self.completion_event.as_ref()
+    }
+
+    /// Resets the completion event. It shall be called before the completions are harvested,
+    /// otherwise a completion that arrives in between could be missed.
+    pub fn reset_completion_event(&self) {
+        if let Some(event_fd) = &self.completion_event {
+            let mut value = 0u64;
+            unsafe {
+                posix::read(
+                    event_fd.native_handle(),
+                    (&mut value as *mut u64).cast(),
+                    core::mem::size_of::<u64>(),
+                )
+            };
+        }
+    }
+
+    /// Signals the completion event, for instance when harvested completions were not yet
+    /// consumed by the user.
+    pub fn trigger_completion_event(&self) {
+        if let Some(event_fd) = &self.completion_event {
+            let value = 1u64;
+            unsafe {
+                posix::write(
+                    event_fd.native_handle(),
+                    (&value as *const u64).cast(),
+                    core::mem::size_of::<u64>(),
+                )
+            };
+        }
+    }
+
+    /// Returns the number of pushed operations whose [`Completion`] was not yet harvested.
+    pub fn number_of_operations_in_flight(&self) -> usize {
+        self.number_of_operations_in_flight
+    }
+
+    /// Pushes a receive operation on the provided socket into the submission queue. It is
+    /// submitted with the next [`IoUring::submit_and_wait()`] call.
+    ///
+    /// # Safety
+    ///
+    ///  * `buffer` must be valid for writes of `len` bytes until the [`Completion`] with
+    ///    `user_data` was harvested or the [`IoUring`] went out of scope
+    ///  * `socket` must stay valid until the [`Completion`] was harvested
+    pub unsafe fn push_receive(
+        &mut self,
+        socket: &FileDescriptor,
+        buffer: *mut u8,
+        len: usize,
+        user_data: u64,
+    ) -> Result<(), IoUringPushError> {
+        self.push(posix::io_uring_sqe {
+            opcode: posix::IORING_OP_RECV,
+            fd: socket.native_handle(),
+            addr: buffer as u64,
+            len: len as u32,
+            user_data,
+            ..Default::default()
+        })
+    }
+
+    /// Pushes the cancellation of the operation with the provided `user_data` into the
+    /// submission queue. The cancellation itself completes with `cancel_user_data`.
+    pub fn push_cancel(
+        &mut self,
+        user_data: u64,
+        cancel_user_data: u64,
+    ) -> Result<(), IoUringPushError> {
+        self.push(posix::io_uring_sqe {
+            opcode: posix::IORING_OP_ASYNC_CANCEL,
+            fd: -1,
+            addr: user_data,
+            user_data: cancel_user_data,
+            ..Default::default()
+        })
+    }
+
+    fn push(&mut self, sqe: posix::io_uring_sqe) -> Result<(), IoUringPushError> {
+        let tail = self.sq_tail.load(Ordering::Relaxed);
+        let head = self.sq_head.load(Ordering::Acquire);
+        if tail.wrapping_sub(head) == self.sq_entries {
+            fail!(from self, with IoUringPushError::SubmissionQueueFull,
+                "Unable to push operation since the submission queue is full.");
+        }
+
+        let index = tail & self.sq_mask;
+        unsafe {
+            (self.sqes_mapping.address as *mut posix::io_uring_sqe)
+                .add(index as usize)
+                .write(sqe);
+            self.sq_array.add(index as usize).write(index);
+        }
+        self.sq_tail.store(tail.wrapping_add(1), Ordering::Release);
+        self.number_of_operations_in_flight += 1;
+
+        Ok(())
+    }
+
+    /// Submits all pushed operations and waits until at least `min_complete` completions are
+    /// available or the `timeout` has passed. When `min_complete` is zero, it returns
+    /// immediately after the submission.
+    pub fn submit_and_wait(
+        &mut self,
+        min_complete: u32,
+        timeout: Option<Duration>,
+    ) -> Result<(), IoUringWaitError> {
+        let to_submit = self
+            .sq_tail
+            .load(Ordering::Relaxed)
+            .wrapping_sub(self.sq_head.load(Ordering::Acquire));
+
+        let mut flags = 0;
+        if min_complete != 0 {
+            flags |= posix::IORING_ENTER_GETEVENTS;
+        }
+
+        let timespec = timeout.map(|t| posix::__kernel_timespec {
+            tv_sec: t.as_secs() as _,
+            tv_nsec: t.subsec_nanos() as _,
+        });
+        let arg = posix::io_uring_getevents_arg {
+            ts: match &timespec {
+                Some(ts) => ts as *const posix::__kernel_timespec as u64,
+                None => 0,
+            },
+            ..Default::default()
+        };
+        if timespec.is_some() {
+            flags |= posix::IORING_ENTER_EXT_ARG;
+        }
+
+        let result = unsafe {
+            posix::io_uring_enter(
+                self.file_descriptor.native_handle(),
+                to_submit,
+                min_complete,
+                flags,
+                if timespec.is_some() {
+                    (&arg as *const posix::io_uring_getevents_arg).cast()
+                } else {
+                    core::ptr::null()
+                },
+                if timespec.is_some() {
+                    core::mem::size_of::<posix::io_uring_getevents_arg>()
+                } else {
+                    0
+                },
+            )
+        };
+
+        if result >= 0 {
+            return Ok(());
+        }
+
+        let msg = "Unable to submit and wait for completions";
+        handle_errno!(IoUringWaitError, from self,
+            success Errno::ETIMEDOUT => (),
+            Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+            Errno::EAGAIN => (InsufficientResources, "{} due to insufficient resources.", msg),
+            Errno::EBUSY => (InsufficientResources, "{} since the completion queue overflowed.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
+    /// Calls the provided callback for every available [`Completion`] and returns the number
+    /// of harvested completions. It does not perform a system call.
+    pub fn harvest<F: FnMut(Completion)>(&mut self, mut callback: F) -> usize {
+        let mut head = self.cq_head.load(Ordering::Relaxed);
+        let tail = self.cq_tail.load(Ordering::Acquire);
+        let number_of_completions = tail.wrapping_sub(head) as usize;
+
+        while head != tail {
+            let cqe = unsafe { self.cqes.add((head & self.cq_mask) as usize).read() };
+            head = head.wrapping_add(1);
+            self.number_of_operations_in_flight =
+                self.number_of_operations_in_flight.saturating_sub(1);
+            callback(Completion {
+                user_data: cqe.user_data,
+                result: cqe.res,
+            });
+        }
+
+        self.cq_head.store(head, Ordering::Release);
+        number_of_completions
+    }
+}
@@ -34,6 +34,7 @@ use thread::ThreadError;
 use unix_datagram_socket::UnixDatagramError;
 use user::UserError;
 
+pub mod access_control_list;
 pub mod access_mode;
 pub mod adaptive_wait;
 pub mod barrier;
@@ -52,6 +53,7 @@ pub mod file_descriptor_set;
 pub mod file_lock;
 pub mod file_type;
 pub mod group;
+pub mod io_uring;
 pub mod ipc_capable;
 pub mod memory;
 pub mod memory_lock;
@@ -60,9 +62,11 @@ pub mod mutex;
 pub mod ownership;
 pub mod permission;
 pub mod process;
+pub mod process_file_descriptor;
 pub mod process_state;
 pub mod read_write_mutex;
 pub mod scheduler;
+pub mod security_descriptor;
 pub mod semaphore;
 pub mod shared_memory;
 pub mod signal;
@@ -16,13 +16,14 @@
 //! credentials of [`crate::file::File`], [`crate::shared_memory::SharedMemory`] and others.
 
 use core::fmt::Display;
-use core::ops::{BitOr, BitOrAssign, Not};
+use core::ops::{BitAnd, BitOr, BitOrAssign, Not};
 use iceoryx2_pal_posix::*;
+use serde::{Deserialize, Serialize};
 
 type ModeType = posix::mode_t;
 
 /// Defines the permission of a file or directory in a POSIX system.
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Permission(ModeType);
 
 impl Permission {
@@ -73,6 +74,14 @@ impl BitOr for Permission {
     }
 }
 
+impl BitAnd for Permission {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(self.0 & rhs.0)
+    }
+}
+
 impl Not for Permission {
     type Output = Self;
 
@@ -160,9 +160,10 @@ impl Process {
         }
     }
 
-    /// Checks if the process is still alive
+    /// Checks if the process is still alive. A process that exists but cannot be signaled
+    /// due to insufficient permissions is considered alive.
     pub fn is_alive(&self) -> bool {
-        unsafe { posix::kill(self.pid.0, 0_i32) == 0 }
+        unsafe { posix::kill(self.pid.0, 0_i32) == 0 || Errno::get() == Errno::EPERM }
     }
 
     /// Returns the id of the process.
@@ -0,0 +1,164 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`ProcessFileDescriptor`] refers to a process with a Linux `pidfd`. In contrast to a
+//! [`ProcessId`] it always refers to the same process, even when the id is reused after the
+//! process terminated. It becomes readable as soon as the process terminates, therefore the
+//! termination can be awaited with a
+//! [`FileDescriptorSet`](crate::file_descriptor_set::FileDescriptorSet) instead of polling.
+//!
+//! It requires Linux 5.3 or newer and fails with
+//! [`ProcessFileDescriptorCreateError::NotSupported`] on every other platform.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use iceoryx2_bb_posix::file_descriptor_set::*;
+//! use iceoryx2_bb_posix::process::ProcessId;
+//! use iceoryx2_bb_posix::process_file_descriptor::*;
+//!
+//! let process = ProcessFileDescriptor::new(ProcessId::new(1234)).unwrap();
+//!
+//! let fd_set = FileDescriptorSet::new();
+//! let _guard = fd_set.add(&process).unwrap();
+//! fd_set.timed_wait(Duration::from_secs(1), FileEvent::Read, |_| {
+//!     println!("process {} terminated", process.pid());
+//! }).unwrap();
+//! ```
+
+use core::time::Duration;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_pal_posix::posix::{self, Errno, Struct};
+
+use crate::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use crate::file_descriptor_set::{
+    FileDescriptorSet, FileDescriptorSetWaitError, FileEvent, SynchronousMultiplexing,
+};
+use crate::process::ProcessId;
+
+/// Defines the errors that can occur when a [`ProcessFileDescriptor`] is created with
+/// [`ProcessFileDescriptor::new()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ProcessFileDescriptorCreateError {
+    NotSupported,
+    DoesNotExist,
+    InsufficientMemory,
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    UnknownError(i32),
+}
+
+impl core::fmt::Display for ProcessFileDescriptorCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ProcessFileDescriptorCreateError::{:?}", self)
+    }
+}
+
+impl core::error::Error for ProcessFileDescriptorCreateError {}
+
+/// Defines the errors that can occur in [`ProcessFileDescriptor::has_terminated()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ProcessFileDescriptorStateError {
+    Interrupt,
+    InternalError,
+}
+
+impl core::fmt::Display for ProcessFileDescriptorStateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ProcessFileDescriptorStateError::{:?}", self)
+    }
+}
+
+impl core::error::Error for ProcessFileDescriptorStateError {}
+
+/// Returns the id of the pid namespace of the calling process or [`None`] when it is not
+/// available. A [`ProcessId`] identifies the same process only in processes that share
+/// the same pid namespace.
+pub fn pid_namespace_id() -> Option<u64> {
+    let mut buffer = posix::stat_t::new();
+    if unsafe { posix::stat(c"/proc/self/ns/pid".as_ptr(), &mut buffer) } == -1 {
+        return None;
+    }
+
+    Some(buffer.st_ino)
+}
+
+/// Refers to a process with a Linux `pidfd`, see the [module documentation](self).
+#[derive(Debug)]
+pub struct ProcessFileDescriptor {
+    file_descriptor: FileDescriptor,
+    pid: ProcessId,
+}
+
+impl ProcessFileDescriptor {
+    /// Creates a [`ProcessFileDescriptor`] for the process with the provided [`ProcessId`].
+    /// When the process terminated already and the id was reused, it refers to the new
+    /// process.
+    pub fn new(pid: ProcessId) -> Result<Self, ProcessFileDescriptorCreateError> {
+        let msg = "Unable to create process file descriptor";
+        let origin = "ProcessFileDescriptor::new()";
+        let fd = unsafe { posix::pidfd_open(pid.value(), posix::PIDFD_NONBLOCK) };
+
+        match FileDescriptor::new(fd) {
+            Some(file_descriptor) => Ok(Self {
+                file_descriptor,
+                pid,
+            }),
+            None => {
+                handle_errno!(ProcessFileDescriptorCreateError, from origin,
+                    Errno::ENOSYS => (NotSupported, "{} for the process {} since it is not supported by the platform.", msg, pid),
+                    Errno::ESRCH => (DoesNotExist, "{} since the process {} does not exist.", msg, pid),
+                    Errno::ENOMEM => (InsufficientMemory, "{} for the process {} due to insufficient memory.", msg, pid),
+                    Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} for the process {} since the per-process file handle limit was reached.", msg, pid),
+                    Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} for the process {} since the system-wide file handle limit was reached.", msg, pid),
+                    v => (UnknownError(v as i32), "{} for the process {} since an unknown error occurred ({}).", msg, pid, v)
+                );
+            }
+        }
+    }
+
+    /// Returns the [`ProcessId`] of the process.
+    pub fn pid(&self) -> ProcessId {
+        self.pid
+    }
+
+    /// Returns true when the process has terminated. It does not block.
+    pub fn has_terminated(&self) -> Result<bool, ProcessFileDescriptorStateError> {
+        let msg = "Unable to acquire the state of the process";
+        let fd_set = FileDescriptorSet::new();
+        let _guard = fail!(from self, when fd_set.add(self),
+            with ProcessFileDescriptorStateError::InternalError,
+            "{} {} since the file descriptor could not be attached to a file descriptor set.", msg, self.pid);
+
+        match fd_set.timed_wait(Duration::ZERO, FileEvent::Read, |_| {}) {
+            Ok(number_of_notifications) => Ok(number_of_notifications > 0),
+            Err(FileDescriptorSetWaitError::Interrupt) => {
+                fail!(from self, with ProcessFileDescriptorStateError::Interrupt,
+                    "{} {} since an interrupt signal was received.", msg, self.pid);
+            }
+            Err(e) => {
+                fail!(from self, with ProcessFileDescriptorStateError::InternalError,
+                    "{} {} due to an internal failure ({:?}).", msg, self.pid, e);
+            }
+        }
+    }
+}
+
+impl FileDescriptorBased for ProcessFileDescriptor {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.file_descriptor
+    }
+}
+
+impl SynchronousMultiplexing for ProcessFileDescriptor {}
@@ -0,0 +1,96 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Restricts on Windows which accounts are allowed to open the named objects, like shared
+//! memory, that are created by the current process. The restriction is defined with a security
+//! descriptor in the
+//! [Security Descriptor Definition Language (SDDL)](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-definition-language).
+//!
+//! On all other platforms the access is controlled with [`Permission`](crate::permission::Permission)s
+//! and the security descriptor is ignored.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_posix::security_descriptor::*;
+//!
+//! // only local system and the builtin administrators are allowed to access named objects
+//! NamedObjectSecurityDescriptor::set("D:P(A;;GA;;;SY)(A;;GA;;;BA)").unwrap();
+//!
+//! // restore the default security descriptor
+//! NamedObjectSecurityDescriptor::reset();
+//! ```
+
+use iceoryx2_bb_log::fail;
+
+/// Defines the failures that can occur when a security descriptor is set with
+/// [`NamedObjectSecurityDescriptor::set()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum SecurityDescriptorError {
+    /// The provided string is not a valid SDDL security descriptor.
+    InvalidSecurityDescriptor,
+}
+
+/// Manages the process-wide security descriptor that is applied to all named objects that
+/// are created after it was set.
+#[derive(Debug)]
+pub struct NamedObjectSecurityDescriptor {}
+
+impl NamedObjectSecurityDescriptor {
+    /// Sets the security descriptor in the SDDL format that is applied to all named objects
+    /// that are created afterwards by the current process. On all platforms except Windows the
+    /// security descriptor is ignored.
+    pub fn set(sddl: &str) -> Result<(), SecurityDescriptorError> {
+        let msg = "Unable to set named object security descriptor";
+        let origin = "NamedObjectSecurityDescriptor::set()";
+
+        if sddl.is_empty() || sddl.contains('\0') {
+            fail!(from origin, with SecurityDescriptorError::InvalidSecurityDescriptor,
+                "{} \"{}\" since it is empty or contains a null character.", msg, sddl);
+        }
+
+        Self::set_impl(sddl, msg, origin)
+    }
+
+    /// Restores the default security descriptor for all named objects that are created
+    /// afterwards by the current process.
+    pub fn reset() {
+        #[cfg(target_os = "windows")]
+        unsafe {
+            iceoryx2_pal_posix::posix::win32_security_attributes::set_named_object_security_descriptor(
+                core::ptr::null(),
+            );
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    fn set_impl(sddl: &str, msg: &str, origin: &str) -> Result<(), SecurityDescriptorError> {
+        let mut nul_terminated_sddl = sddl.as_bytes().to_vec();
+        nul_terminated_sddl.push(0);
+
+        if !unsafe {
+            iceoryx2_pal_posix::posix::win32_security_attributes::set_named_object_security_descriptor(
+                nul_terminated_sddl.as_ptr() as *const iceoryx2_pal_posix::posix::c_char,
+            )
+        } {
+            fail!(from origin, with SecurityDescriptorError::InvalidSecurityDescriptor,
+                "{} \"{}\" since it is not a valid SDDL string.", msg, sddl);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    fn set_impl(_sddl: &str, _msg: &str, _origin: &str) -> Result<(), SecurityDescriptorError> {
+        Ok(())
+    }
+}
@@ -62,7 +62,33 @@
 //! // print the first byte of the shared memory
 //! println!("first byte: {}", shm.as_slice()[0]);
 //! ```
+//!
+//! ## Create shared memory backed by explicit huge pages.
+//!
+//! ```no_run
+//! use iceoryx2_bb_posix::shared_memory::*;
+//! use iceoryx2_bb_system_types::file_name::FileName;
+//! use iceoryx2_bb_system_types::path::Path;
+//! use iceoryx2_bb_container::semantic_string::*;
+//!
+//! let name = FileName::new(b"someHugeShmName").unwrap();
+//! let huge_pages = HugePages {
+//!     page_size: HugePageSize::Size2MiB,
+//!     // a hugetlbfs mount point with a page size of 2 MiB
+//!     directory: Path::new(b"/dev/hugepages").unwrap(),
+//! };
+//! let shm = SharedMemoryBuilder::new(&name)
+//!                     .huge_pages(&huge_pages)
+//!                     .creation_mode(CreationMode::PurgeAndCreate)
+//!                     .size(1024)
+//!                     .create()
+//!                     .expect("failed to create shared memory");
+//!
+//! // the size is rounded up to a multiple of the huge page size
+//! assert_eq!(shm.size(), HugePageSize::Size2MiB.value());
+//! ```
 
+use crate::directory::Directory;
 use crate::file::{FileStatError, FileTruncateError};
 use crate::file_descriptor::*;
 use crate::handle_errno;
@@ -84,6 +110,7 @@ use iceoryx2_pal_posix::*;
 use core::ptr::NonNull;
 use core::sync::atomic::Ordering;
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+use serde::{Deserialize, Serialize};
 
 pub use crate::access_mode::AccessMode;
 pub use crate::creation_mode::CreationMode;
@@ -104,6 +131,7 @@ enum_gen! { SharedMemoryCreationError
     AlreadyExist,
     DoesNotExist,
     UnableToMapAtEnforcedBaseAddress,
+    HugePagesNotAvailable,
     UnknownError(i32)
   mapping:
     FileTruncateError,
@@ -118,6 +146,50 @@ enum_gen! { SharedMemoryRemoveError
     UnknownError(i32)
 }
 
+enum_gen! { SharedMemoryNumaBindError
+  entry:
+    NumaNodeDoesNotExist,
+    NotSupported,
+    InsufficientPermissions,
+    InsufficientMemory,
+    UnknownError(i32)
+}
+
+const MAX_NUMBER_OF_NUMA_NODES: usize = 1024;
+const NUMA_NODE_MASK_ENTRY_BITS: usize = core::mem::size_of::<posix::ulong>() * 8;
+
+/// The size of an explicit huge page.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum HugePageSize {
+    /// Huge pages with a size of 2 MiB.
+    #[serde(rename = "2MiB")]
+    Size2MiB,
+    /// Huge pages with a size of 1 GiB.
+    #[serde(rename = "1GiB")]
+    Size1GiB,
+}
+
+impl HugePageSize {
+    /// Returns the size of a huge page in bytes.
+    pub const fn value(&self) -> usize {
+        match self {
+            HugePageSize::Size2MiB => 2 * 1024 * 1024,
+            HugePageSize::Size1GiB => 1024 * 1024 * 1024,
+        }
+    }
+}
+
+/// Explicit huge pages that are provided by a hugetlbfs mount point, like `/dev/hugepages`.
+/// A [`SharedMemory`] that is backed by huge pages is stored as file in
+/// [`HugePages::directory`] instead of the POSIX shared memory namespace.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct HugePages {
+    /// The page size of the hugetlbfs mount point.
+    pub page_size: HugePageSize,
+    /// The hugetlbfs mount point.
+    pub directory: Path,
+}
+
 /// The builder for the [`SharedMemory`].
 #[derive(Debug)]
 pub struct SharedMemoryBuilder {
@@ -130,6 +202,7 @@ pub struct SharedMemoryBuilder {
     zero_memory: bool,
     access_mode: AccessMode,
     enforce_base_address: Option<u64>,
+    huge_pages: Option<HugePages>,
 }
 
 impl SharedMemoryBuilder {
@@ -144,6 +217,7 @@ impl SharedMemoryBuilder {
             creation_mode: None,
             zero_memory: true,
             enforce_base_address: None,
+            huge_pages: None,
         }
     }
 
@@ -161,6 +235,16 @@ impl SharedMemoryBuilder {
         self
     }
 
+    /// Backs the shared memory with explicit [`HugePages`] to reduce the TLB misses when large
+    /// amounts of memory are accessed. The size of a newly created shared memory is rounded up
+    /// to a multiple of the huge page size. The creation fails with
+    /// [`SharedMemoryCreationError::HugePagesNotAvailable`] when [`HugePages::directory`] is
+    /// not a hugetlbfs mount point with the configured page size.
+    pub fn huge_pages(mut self, value: &HugePages) -> Self {
+        self.huge_pages = Some(*value);
+        self
+    }
+
     /// Opens an already existing shared memory.
     pub fn open_existing(
         mut self,
@@ -196,6 +280,7 @@ impl SharedMemoryBuilder {
             has_ownership: IoxAtomicBool::new(false),
             memory_lock: None,
             file_descriptor: fd,
+            huge_pages: self.huge_pages,
         };
 
         trace!(from shm, "open");
@@ -250,6 +335,13 @@ impl SharedMemoryCreationBuilder {
     pub fn create(mut self) -> Result<SharedMemory, SharedMemoryCreationError> {
         let msg = "Unable to create shared memory";
 
+        if let Some(huge_pages) = &self.config.huge_pages {
+            self.config.size = self
+                .config
+                .size
+                .next_multiple_of(huge_pages.page_size.value());
+        }
+
         let shm_created;
         let fd = match self
             .config
@@ -262,7 +354,7 @@ impl SharedMemoryCreationBuilder {
             }
             CreationMode::PurgeAndCreate => {
                 shm_created = true;
-                fail!(from self.config, when SharedMemory::shm_unlink(&self.config.name),
+                fail!(from self.config, when SharedMemory::shm_unlink(&self.config.name, self.config.huge_pages.as_ref()),
                     "Failed to remove already existing shared memory.");
                 SharedMemory::shm_create(&self.config.name, &self.config)?
             }
@@ -295,6 +387,7 @@ impl SharedMemoryCreationBuilder {
             has_ownership: IoxAtomicBool::new(self.config.has_ownership),
             memory_lock: None,
             file_descriptor: fd,
+            huge_pages: self.config.huge_pages,
         };
 
         if !shm_created {
@@ -314,6 +407,18 @@ impl SharedMemoryCreationBuilder {
             return Ok(shm);
         }
 
+        if let Some(huge_pages) = &self.config.huge_pages {
+            // hugetlbfs reports the huge page size as block size
+            let block_size = fail!(from self.config, when shm.metadata(),
+                    "{} since a failure occurred while acquiring the file attributes.", msg)
+            .block_size();
+            if block_size != huge_pages.page_size.value() as u64 {
+                fail!(from self.config, with SharedMemoryCreationError::HugePagesNotAvailable,
+                    "{} since \"{}\" provides a page size of {} bytes instead of huge pages with {:?}.",
+                    msg, huge_pages.directory, block_size, huge_pages.page_size);
+            }
+        }
+
         fail!(from self.config, when shm.truncate(self.config.size), "{} since the shared memory truncation failed.", msg);
 
         shm.base_address = fail!(from self.config, when SharedMemory::mmap(&shm.file_descriptor, &self.config),
@@ -374,6 +479,7 @@ pub struct SharedMemory {
     has_ownership: IoxAtomicBool,
     file_descriptor: FileDescriptor,
     memory_lock: Option<MemoryLock>,
+    huge_pages: Option<HugePages>,
 }
 
 impl Drop for SharedMemory {
@@ -387,7 +493,7 @@ impl Drop for SharedMemory {
 
         if self.has_ownership() {
             match self.set_permission(Permission::OWNER_ALL) {
-                Ok(()) => match Self::shm_unlink(&self.name) {
+                Ok(()) => match Self::shm_unlink(&self.name, self.huge_pages.as_ref()) {
                     Ok(_) => {
                         trace!(from self, "delete");
                     }
@@ -418,6 +524,18 @@ impl SharedMemory {
         .is_some()
     }
 
+    /// Returns true if the shared memory that is backed by the provided [`HugePages`] exists
+    /// and is accessible, otherwise false.
+    pub fn does_exist_with_huge_pages(name: &FileName, huge_pages: &HugePages) -> bool {
+        match FilePath::from_path_and_file(&huge_pages.directory, name) {
+            Ok(file_path) => FileDescriptor::new(unsafe {
+                posix::open(file_path.as_c_str(), AccessMode::Read.as_oflag())
+            })
+            .is_some(),
+            Err(_) => false,
+        }
+    }
+
     /// Returns if the posix implementation supports persistent shared memory, meaning that when every
     /// shared memory handle got out of scope the underlying OS resource remains.
     pub fn does_support_persistency() -> bool {
@@ -445,7 +563,7 @@ impl SharedMemory {
 
     /// Removes a shared memory file.
     pub fn remove(name: &FileName) -> Result<bool, SharedMemoryRemoveError> {
-        match Self::shm_unlink(name) {
+        match Self::shm_unlink(name, None) {
             Ok(true) => {
                 trace!(from "SharedMemory::remove", "\"{}\"", name);
                 Ok(true)
@@ -455,6 +573,21 @@ impl SharedMemory {
         }
     }
 
+    /// Removes a shared memory file that is backed by the provided [`HugePages`].
+    pub fn remove_with_huge_pages(
+        name: &FileName,
+        huge_pages: &HugePages,
+    ) -> Result<bool, SharedMemoryRemoveError> {
+        match Self::shm_unlink(name, Some(huge_pages)) {
+            Ok(true) => {
+                trace!(from "SharedMemory::remove_with_huge_pages", "\"{}\" in \"{}\"", name, huge_pages.directory);
+                Ok(true)
+            }
+            Ok(false) => Ok(false),
+            Err(v) => Err(v),
+        }
+    }
+
     /// Returns a list of all shared memory objects
     pub fn list() -> Vec<FileName> {
         let mut result = vec![];
@@ -469,6 +602,20 @@ impl SharedMemory {
         result
     }
 
+    /// Returns a list of all shared memory objects that are backed by the provided
+    /// [`HugePages`]
+    pub fn list_with_huge_pages(huge_pages: &HugePages) -> Vec<FileName> {
+        let contents = match Directory::new(&huge_pages.directory) {
+            Ok(directory) => directory.contents(),
+            Err(_) => return vec![],
+        };
+
+        match contents {
+            Ok(entries) => entries.iter().map(|entry| *entry.name()).collect(),
+            Err(_) => vec![],
+        }
+    }
+
     /// returns the name of the shared memory
     pub fn name(&self) -> &FileName {
         &self.name
@@ -492,6 +639,62 @@ impl SharedMemory {
         self.size
     }
 
+    /// Returns the [`HugePages`] that back the shared memory. If the shared memory uses the
+    /// regular pages it returns [`None`].
+    pub fn huge_pages(&self) -> Option<&HugePages> {
+        self.huge_pages.as_ref()
+    }
+
+    /// Binds the memory of the shared memory to the provided NUMA node. The policy is
+    /// attached to the underlying shared memory object, therefore every page that is touched
+    /// afterwards, by any process, is allocated on that node. Pages that are already in use
+    /// are migrated when possible. To avoid the migration, it shall be called before the
+    /// memory is touched, for instance by creating it with
+    /// [`SharedMemoryCreationBuilder::zero_memory()`] set to false.
+    ///
+    /// Is only supported on Linux, on all other platforms it fails with
+    /// [`SharedMemoryNumaBindError::NotSupported`].
+    pub fn bind_to_numa_node(&self, numa_node: u32) -> Result<(), SharedMemoryNumaBindError> {
+        let msg = "Unable to bind shared memory to NUMA node";
+        let numa_node = numa_node as usize;
+        if MAX_NUMBER_OF_NUMA_NODES <= numa_node {
+            fail!(from self, with SharedMemoryNumaBindError::NumaNodeDoesNotExist,
+                "{} {} since it exceeds the maximum supported NUMA node {}.",
+                msg, numa_node, MAX_NUMBER_OF_NUMA_NODES - 1);
+        }
+
+        let mut node_mask: [posix::ulong; MAX_NUMBER_OF_NUMA_NODES / NUMA_NODE_MASK_ENTRY_BITS] =
+            [0; MAX_NUMBER_OF_NUMA_NODES / NUMA_NODE_MASK_ENTRY_BITS];
+        node_mask[numa_node / NUMA_NODE_MASK_ENTRY_BITS] =
+            1 << (numa_node % NUMA_NODE_MASK_ENTRY_BITS);
+
+        if unsafe {
+            posix::mbind(
+                self.base_address as *mut posix::void,
+                self.size,
+                posix::MPOL_BIND,
+                node_mask.as_ptr(),
+                // the kernel ignores the last bit of the node mask, like libnuma does, one is
+                // added so that all nodes can be addressed
+                (MAX_NUMBER_OF_NUMA_NODES + 1) as _,
+                posix::MPOL_MF_MOVE,
+            )
+        } == 0
+        {
+            trace!(from self, "bind to NUMA node {}", numa_node);
+            return Ok(());
+        }
+
+        handle_errno!(SharedMemoryNumaBindError, from self,
+            Errno::EINVAL => (NumaNodeDoesNotExist, "{} {} since the node does not exist or is not available to the process.", msg, numa_node),
+            Errno::ENOSYS => (NotSupported, "{} {} since NUMA is not supported by the platform.", msg, numa_node),
+            // seccomp profiles of container runtimes deny the call without CAP_SYS_NICE
+            Errno::EPERM => (InsufficientPermissions, "{} {} due to insufficient permissions.", msg, numa_node),
+            Errno::ENOMEM => (InsufficientMemory, "{} {} due to insufficient memory.", msg, numa_node),
+            v => (UnknownError(v as i32), "{} {} since an unknown error occurred ({}).", msg, numa_node, v)
+        );
+    }
+
     /// returns a slice to the memory
     pub fn as_slice(&self) -> &[u8] {
         unsafe { core::slice::from_raw_parts(self.base_address, self.size) }
@@ -502,18 +705,38 @@ impl SharedMemory {
         unsafe { core::slice::from_raw_parts_mut(self.base_address, self.size) }
     }
 
+    fn file_path(
+        name: &FileName,
+        config: &SharedMemoryBuilder,
+    ) -> Result<FilePath, SharedMemoryCreationError> {
+        match &config.huge_pages {
+            None => Ok(FilePath::from_path_and_file(
+                &Path::new(&[PATH_SEPARATOR; 1]).unwrap(),
+                name,
+            )
+            .unwrap()),
+            Some(huge_pages) => Ok(
+                fail!(from config, when FilePath::from_path_and_file(&huge_pages.directory, name),
+                    with SharedMemoryCreationError::NameTooLong,
+                    "Unable to access shared memory since the huge page directory \"{}\" in combination with the name \"{}\" exceeds the maximum supported path length.",
+                    huge_pages.directory, name),
+            ),
+        }
+    }
+
     fn shm_create(
         name: &FileName,
         config: &SharedMemoryBuilder,
     ) -> Result<FileDescriptor, SharedMemoryCreationError> {
-        let file_path =
-            FilePath::from_path_and_file(&Path::new(&[PATH_SEPARATOR; 1]).unwrap(), name).unwrap();
+        let file_path = Self::file_path(name, config)?;
+        let flags = CreationMode::CreateExclusive.as_oflag() | config.access_mode.as_oflag();
         let fd = FileDescriptor::new(unsafe {
-            posix::shm_open(
-                file_path.as_c_str(),
-                CreationMode::CreateExclusive.as_oflag() | config.access_mode.as_oflag(),
-                config.permission.as_mode(),
-            )
+            match config.huge_pages {
+                None => posix::shm_open(file_path.as_c_str(), flags, config.permission.as_mode()),
+                Some(_) => {
+                    posix::open_with_mode(file_path.as_c_str(), flags, config.permission.as_mode())
+                }
+            }
         });
 
         if let Some(v) = fd {
@@ -521,6 +744,11 @@ impl SharedMemory {
         }
 
         let msg = "Unable to create shared memory";
+        if let (Some(huge_pages), Errno::ENOENT) = (&config.huge_pages, Errno::get()) {
+            fail!(from config, with SharedMemoryCreationError::HugePagesNotAvailable,
+                "{} since the huge page directory \"{}\" does not exist.", msg, huge_pages.directory);
+        }
+
         handle_errno!(SharedMemoryCreationError, from config,
             Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
             Errno::EINVAL => (InvalidName, "{} since the provided name \"{}\" is invalid.", msg, name),
@@ -536,14 +764,16 @@ impl SharedMemory {
         name: &FileName,
         config: &SharedMemoryBuilder,
     ) -> Result<FileDescriptor, SharedMemoryCreationError> {
-        let file_path =
-            FilePath::from_path_and_file(&Path::new(&[PATH_SEPARATOR; 1]).unwrap(), name).unwrap();
+        let file_path = Self::file_path(name, config)?;
         let fd = FileDescriptor::new(unsafe {
-            posix::shm_open(
-                file_path.as_c_str(),
-                config.access_mode.as_oflag(),
-                Permission::none().as_mode(),
-            )
+            match config.huge_pages {
+                None => posix::shm_open(
+                    file_path.as_c_str(),
+                    config.access_mode.as_oflag(),
+                    Permission::none().as_mode(),
+                ),
+                Some(_) => posix::open(file_path.as_c_str(), config.access_mode.as_oflag()),
+            }
         });
 
         if let Some(v) = fd {
@@ -591,10 +821,25 @@ impl SharedMemory {
         );
     }
 
-    fn shm_unlink(name: &FileName) -> Result<bool, SharedMemoryRemoveError> {
-        let file_path =
-            FilePath::from_path_and_file(&Path::new(&[PATH_SEPARATOR; 1]).unwrap(), name).unwrap();
-        if unsafe { posix::shm_unlink(file_path.as_c_str()) } == 0 {
+    fn shm_unlink(
+        name: &FileName,
+        huge_pages: Option<&HugePages>,
+    ) -> Result<bool, SharedMemoryRemoveError> {
+        let result = match huge_pages {
+            None => {
+                let file_path =
+                    FilePath::from_path_and_file(&Path::new(&[PATH_SEPARATOR; 1]).unwrap(), name)
+                        .unwrap();
+                unsafe { posix::shm_unlink(file_path.as_c_str()) }
+            }
+            Some(huge_pages) => match FilePath::from_path_and_file(&huge_pages.directory, name) {
+                Ok(file_path) => unsafe { posix::unlink(file_path.as_c_str()) },
+                // a file with that path cannot exist
+                Err(_) => return Ok(false),
+            },
+        };
+
+        if result == 0 {
             return Ok(true);
         }
 
@@ -0,0 +1,107 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_elementary::math::ToB64;
+use iceoryx2_bb_posix::access_control_list::*;
+use iceoryx2_bb_posix::config::*;
+use iceoryx2_bb_posix::file::*;
+use iceoryx2_bb_posix::file_descriptor::*;
+use iceoryx2_bb_posix::group::Group;
+use iceoryx2_bb_posix::permission::Permission;
+use iceoryx2_bb_posix::testing::create_test_directory;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_posix::user::User;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::test_requires;
+use iceoryx2_pal_posix::posix::{POSIX_SUPPORT_PERMISSIONS, POSIX_SUPPORT_USERS_AND_GROUPS};
+
+fn create_file() -> (FilePath, File) {
+    let mut name = FileName::new(b"acl_test_").unwrap();
+    name.push_bytes(UniqueSystemId::new().unwrap().value().to_b64().as_bytes())
+        .unwrap();
+    let path = FilePath::from_path_and_file(&test_directory(), &name).unwrap();
+
+    create_test_directory();
+    let file = FileBuilder::new(&path)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .permission(Permission::OWNER_ALL)
+        .create()
+        .unwrap();
+
+    (path, file)
+}
+
+#[test]
+fn access_control_list_builder_defaults_are_correct() {
+    let sut = AccessControlListBuilder::new().create();
+
+    assert_that!(sut.owner(), eq None);
+    assert_that!(sut.group(), eq None);
+    assert_that!(sut.permission(), eq Permission::none());
+}
+
+#[test]
+fn access_control_list_ignores_owner_permissions() {
+    let sut = AccessControlListBuilder::new()
+        .owner(123)
+        .group(456)
+        .permission(Permission::OWNER_WRITE | Permission::GROUP_READ | Permission::OTHERS_EXEC)
+        .create();
+
+    assert_that!(sut.owner(), eq Some(123));
+    assert_that!(sut.group(), eq Some(456));
+    assert_that!(sut.permission(), eq Permission::GROUP_READ | Permission::OTHERS_EXEC);
+    assert_that!(sut.permission_for(Permission::OWNER_READ | Permission::GROUP_WRITE),
+        eq Permission::OWNER_READ | Permission::GROUP_READ | Permission::OTHERS_EXEC);
+}
+
+#[test]
+fn access_control_list_apply_sets_permissions() {
+    test_requires!(POSIX_SUPPORT_PERMISSIONS);
+
+    let (path, mut file) = create_file();
+    let sut = AccessControlListBuilder::new()
+        .permission(Permission::GROUP_READ | Permission::GROUP_WRITE)
+        .create();
+
+    assert_that!(sut.apply(Permission::OWNER_READ, &mut file), is_ok);
+    assert_that!(file.permission().unwrap(),
+        eq Permission::OWNER_READ | Permission::GROUP_READ | Permission::GROUP_WRITE);
+
+    File::remove(&path).unwrap();
+}
+
+#[test]
+fn access_control_list_apply_sets_ownership() {
+    test_requires!(POSIX_SUPPORT_PERMISSIONS);
+    test_requires!(POSIX_SUPPORT_USERS_AND_GROUPS);
+
+    let (path, mut file) = create_file();
+    let user = User::from_self().unwrap();
+    let group = Group::from_self().unwrap();
+    let sut = AccessControlListBuilder::new()
+        .owner(user.uid())
+        .group(group.gid())
+        .permission(Permission::GROUP_READ)
+        .create();
+
+    assert_that!(sut.apply(Permission::OWNER_ALL, &mut file), is_ok);
+    let ownership = file.ownership().unwrap();
+    assert_that!(ownership.uid(), eq user.uid());
+    assert_that!(ownership.gid(), eq group.gid());
+    assert_that!(file.permission().unwrap(), eq Permission::OWNER_ALL | Permission::GROUP_READ);
+
+    File::remove(&path).unwrap();
+}
@@ -43,12 +43,17 @@ mod deadline_queue {
     }
 
     #[test]
-    fn next_iteration_works_zero_deadline() {
+    fn adding_zero_deadline_fails() {
         let sut = DeadlineQueueBuilder::new().create().unwrap();
 
-        let _guard = sut.add_deadline_interval(Duration::from_secs(0)).unwrap();
+        let result = sut.add_deadline_interval(Duration::from_secs(0));
+        assert_that!(result.err(), eq Some(DeadlineQueueAddError::ZeroPeriod));
 
-        assert_that!(sut.duration_until_next_deadline().unwrap(), eq Duration::from_secs(0));
+        let result = sut.add_deadline_timeout(Duration::from_secs(0));
+        assert_that!(result.err(), eq Some(DeadlineQueueAddError::ZeroPeriod));
+
+        assert_that!(sut.is_empty(), eq true);
+        assert_that!(sut.duration_until_next_deadline().unwrap(), eq Duration::MAX);
     }
 
     #[test]
@@ -224,4 +229,81 @@ mod deadline_queue {
         let next_deadline = sut.duration_until_next_deadline().unwrap();
         assert_that!(next_deadline, ne Duration::ZERO);
     }
+
+    #[test]
+    fn one_shot_deadline_is_reported_only_once() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let guard = sut.add_deadline_timeout(Duration::from_millis(10)).unwrap();
+
+        std::thread::sleep(Duration::from_millis(20));
+
+        let mut missed_deadlines = vec![];
+        sut.missed_deadlines(|idx| {
+            missed_deadlines.push(idx);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(missed_deadlines, len 1);
+        assert_that!(missed_deadlines, contains guard.index());
+
+        let mut missed_deadline_counter = 0;
+        sut.missed_deadlines(|_| {
+            missed_deadline_counter += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(missed_deadline_counter, eq 0);
+        assert_that!(sut.duration_until_next_deadline().unwrap(), eq Duration::MAX);
+    }
+
+    #[test]
+    fn one_shot_deadline_can_be_rearmed_with_reset() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let guard = sut.add_deadline_timeout(Duration::from_millis(10)).unwrap();
+
+        std::thread::sleep(Duration::from_millis(20));
+        sut.missed_deadlines(|_| CallbackProgression::Continue)
+            .unwrap();
+
+        assert_that!(guard.reset(), is_ok);
+        assert_that!(sut.duration_until_next_deadline().unwrap(), ne Duration::MAX);
+
+        std::thread::sleep(Duration::from_millis(20));
+
+        let mut missed_deadlines = vec![];
+        sut.missed_deadlines(|idx| {
+            missed_deadlines.push(idx);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(missed_deadlines, len 1);
+        assert_that!(sut.number_of_missed_deadlines(guard.index()), eq 0);
+    }
+
+    #[test]
+    fn deadlines_that_are_hit_multiple_times_are_counted_as_missed() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let guard = sut
+            .add_deadline_interval(Duration::from_millis(10))
+            .unwrap();
+        assert_that!(sut.number_of_missed_deadlines(guard.index()), eq 0);
+
+        std::thread::sleep(Duration::from_millis(55));
+
+        let mut missed_deadline_counter = 0;
+        sut.missed_deadlines(|_| {
+            missed_deadline_counter += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(missed_deadline_counter, eq 1);
+        assert_that!(sut.number_of_missed_deadlines(guard.index()), ge 4);
+    }
 }
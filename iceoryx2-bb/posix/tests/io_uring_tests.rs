@@ -0,0 +1,162 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+use iceoryx2_bb_posix::io_uring::*;
+use iceoryx2_bb_posix::socket_pair::*;
+use iceoryx2_bb_testing::{assert_that, watchdog::Watchdog};
+use std::time::Instant;
+
+const TIMEOUT: Duration = Duration::from_millis(50);
+
+// io_uring is not available on every platform and can be disabled by the system, the tests
+// are skipped in that case
+fn create_sut(builder: IoUringBuilder) -> Option<IoUring> {
+    match builder.create() {
+        Ok(sut) => Some(sut),
+        Err(IoUringCreationError::NotSupported)
+        | Err(IoUringCreationError::InsufficientPermissions) => None,
+        Err(e) => panic!("unable to create io_uring: {:?}", e),
+    }
+}
+
+#[test]
+fn io_uring_completion_event_is_only_available_when_requested() {
+    let Some(sut) = create_sut(IoUringBuilder::new()) else {
+        return;
+    };
+    assert_that!(sut.completion_event(), is_none);
+
+    let Some(sut) = create_sut(IoUringBuilder::new().completion_event(true)) else {
+        return;
+    };
+    assert_that!(sut.completion_event(), is_some);
+}
+
+#[test]
+fn io_uring_submit_and_wait_blocks_at_least_timeout() {
+    let _watchdog = Watchdog::new();
+    let Some(mut sut) = create_sut(IoUringBuilder::new()) else {
+        return;
+    };
+
+    let (_socket_1, socket_2) = StreamingSocket::create_pair().unwrap();
+    let mut buffer = [0u8; 8];
+    unsafe {
+        sut.push_receive(
+            socket_2.file_descriptor(),
+            buffer.as_mut_ptr(),
+            buffer.len(),
+            0,
+        )
+        .unwrap()
+    };
+
+    let start = Instant::now();
+    assert_that!(sut.submit_and_wait(1, Some(TIMEOUT)), is_ok);
+    assert_that!(start.elapsed(), time_at_least TIMEOUT);
+    assert_that!(sut.harvest(|_| {}), eq 0);
+    assert_that!(sut.number_of_operations_in_flight(), eq 1);
+}
+
+#[test]
+fn io_uring_harvests_completions_of_received_data() {
+    let _watchdog = Watchdog::new();
+    const NUMBER_OF_RECEIVES: usize = 4;
+    let Some(mut sut) = create_sut(IoUringBuilder::new().number_of_entries(8)) else {
+        return;
+    };
+
+    let (socket_1, socket_2) = StreamingSocket::create_pair().unwrap();
+    let mut buffer = [0u8; NUMBER_OF_RECEIVES];
+    for i in 0..NUMBER_OF_RECEIVES {
+        unsafe {
+            sut.push_receive(
+                socket_2.file_descriptor(),
+                buffer.as_mut_ptr().add(i),
+                1,
+                i as u64,
+            )
+            .unwrap()
+        };
+    }
+    assert_that!(sut.submit_and_wait(0, None), is_ok);
+
+    for i in 0..NUMBER_OF_RECEIVES {
+        socket_1.blocking_send(&[i as u8 + 1]).unwrap();
+        assert_that!(sut.submit_and_wait(1, None), is_ok);
+
+        let mut completions = vec![];
+        sut.harvest(|c| completions.push(c));
+        assert_that!(completions, len 1);
+        assert_that!(completions[0].result, eq 1);
+        let index = completions[0].user_data as usize;
+        assert_that!(buffer[index], eq i as u8 + 1);
+    }
+
+    assert_that!(sut.number_of_operations_in_flight(), eq 0);
+}
+
+#[test]
+fn io_uring_push_fails_when_submission_queue_is_full() {
+    let Some(mut sut) = create_sut(IoUringBuilder::new().number_of_entries(2)) else {
+        return;
+    };
+
+    let (_socket_1, socket_2) = StreamingSocket::create_pair().unwrap();
+    let mut buffer = [0u8; 3];
+    for i in 0..2 {
+        assert_that!(
+            unsafe {
+                sut.push_receive(
+                    socket_2.file_descriptor(),
+                    buffer.as_mut_ptr().add(i),
+                    1,
+                    i as u64,
+                )
+            },
+            is_ok
+        );
+    }
+    assert_that!(unsafe { sut.push_receive(socket_2.file_descriptor(), buffer.as_mut_ptr().add(2), 1, 2) },
+        eq Err(IoUringPushError::SubmissionQueueFull));
+
+    assert_that!(sut.submit_and_wait(0, None), is_ok);
+    assert_that!(
+        unsafe { sut.push_receive(socket_2.file_descriptor(), buffer.as_mut_ptr().add(2), 1, 2) },
+        is_ok
+    );
+}
+
+#[test]
+fn io_uring_with_operations_in_flight_can_be_dropped() {
+    let _watchdog = Watchdog::new();
+    let Some(mut sut) = create_sut(IoUringBuilder::new()) else {
+        return;
+    };
+
+    let (_socket_1, socket_2) = StreamingSocket::create_pair().unwrap();
+    let mut buffer = [0u8; 8];
+    unsafe {
+        sut.push_receive(
+            socket_2.file_descriptor(),
+            buffer.as_mut_ptr(),
+            buffer.len(),
+            0,
+        )
+        .unwrap()
+    };
+    assert_that!(sut.submit_and_wait(0, None), is_ok);
+
+    drop(sut);
+}
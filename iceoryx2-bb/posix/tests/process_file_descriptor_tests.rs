@@ -0,0 +1,42 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_posix::process::*;
+use iceoryx2_bb_posix::process_file_descriptor::*;
+use iceoryx2_bb_testing::assert_that;
+
+#[test]
+pub fn process_file_descriptor_of_self_has_not_terminated() {
+    let sut = match ProcessFileDescriptor::new(Process::from_self().id()) {
+        Err(ProcessFileDescriptorCreateError::NotSupported) => return,
+        v => v.unwrap(),
+    };
+
+    assert_that!(sut.pid(), eq Process::from_self().id());
+    assert_that!(sut.has_terminated(), eq Ok(false));
+}
+
+#[test]
+pub fn process_file_descriptor_detects_terminated_process() {
+    let mut child = std::process::Command::new("true").spawn().unwrap();
+    let sut = match ProcessFileDescriptor::new(ProcessId::new(child.id() as _)) {
+        Err(ProcessFileDescriptorCreateError::NotSupported) => {
+            child.wait().unwrap();
+            return;
+        }
+        v => v.unwrap(),
+    };
+
+    child.wait().unwrap();
+
+    assert_that!(sut.has_terminated(), eq Ok(true));
+}
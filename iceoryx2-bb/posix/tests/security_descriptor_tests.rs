@@ -0,0 +1,48 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_posix::security_descriptor::*;
+use iceoryx2_bb_testing::assert_that;
+
+#[test]
+fn security_descriptor_set_with_valid_sddl_works() {
+    assert_that!(
+        NamedObjectSecurityDescriptor::set("D:P(A;;GA;;;SY)(A;;GA;;;BA)(A;;GA;;;OW)"),
+        is_ok
+    );
+    NamedObjectSecurityDescriptor::reset();
+}
+
+#[test]
+fn security_descriptor_set_with_empty_sddl_fails() {
+    assert_that!(
+        NamedObjectSecurityDescriptor::set(""),
+        eq Err(SecurityDescriptorError::InvalidSecurityDescriptor)
+    );
+}
+
+#[test]
+fn security_descriptor_set_with_null_character_fails() {
+    assert_that!(
+        NamedObjectSecurityDescriptor::set("D:P\0(A;;GA;;;SY)"),
+        eq Err(SecurityDescriptorError::InvalidSecurityDescriptor)
+    );
+}
+
+#[cfg(target_os = "windows")]
+#[test]
+fn security_descriptor_set_with_invalid_sddl_fails() {
+    assert_that!(
+        NamedObjectSecurityDescriptor::set("this is not a security descriptor"),
+        eq Err(SecurityDescriptorError::InvalidSecurityDescriptor)
+    );
+}
@@ -12,8 +12,11 @@
 
 use iceoryx2_bb_container::semantic_string::*;
 use iceoryx2_bb_elementary::math::ToB64;
+use iceoryx2_bb_posix::config::test_directory;
+use iceoryx2_bb_posix::testing::create_test_directory;
 use iceoryx2_bb_posix::{shared_memory::*, unique_system_id::UniqueSystemId};
 use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_bb_testing::{assert_that, test_requires};
 use iceoryx2_pal_posix::posix::POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY;
 
@@ -260,3 +263,68 @@ fn shared_memory_existing_shm_can_be_listed() {
         assert_that!(shm_list, contains * shm.name());
     }
 }
+
+#[test]
+fn shared_memory_with_huge_pages_in_non_existing_directory_fails() {
+    let shm_name = generate_shm_name();
+    let huge_pages = HugePages {
+        page_size: HugePageSize::Size2MiB,
+        directory: Path::new(b"/this/directory/does/not/exist").unwrap(),
+    };
+
+    let sut = SharedMemoryBuilder::new(&shm_name)
+        .huge_pages(&huge_pages)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .create();
+
+    assert_that!(sut.err(), eq Some(SharedMemoryCreationError::HugePagesNotAvailable));
+}
+
+#[test]
+fn shared_memory_with_huge_pages_in_regular_directory_fails_and_is_removed() {
+    create_test_directory();
+    let shm_name = generate_shm_name();
+    let huge_pages = HugePages {
+        page_size: HugePageSize::Size1GiB,
+        directory: test_directory(),
+    };
+
+    let sut = SharedMemoryBuilder::new(&shm_name)
+        .huge_pages(&huge_pages)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .create();
+
+    assert_that!(sut.err(), eq Some(SharedMemoryCreationError::HugePagesNotAvailable));
+    assert_that!(SharedMemory::does_exist_with_huge_pages(&shm_name, &huge_pages), eq false);
+    assert_that!(
+        SharedMemory::list_with_huge_pages(&huge_pages),
+        not_contains_match | name | *name == shm_name
+    );
+}
+
+#[test]
+fn shared_memory_without_huge_pages_reports_no_huge_pages() {
+    let shm_name = generate_shm_name();
+    let sut = SharedMemoryBuilder::new(&shm_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .create()
+        .unwrap();
+
+    assert_that!(sut.huge_pages(), is_none);
+}
+
+#[test]
+fn shared_memory_binding_to_non_existing_numa_node_fails() {
+    let shm_name = generate_shm_name();
+    let sut = SharedMemoryBuilder::new(&shm_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .zero_memory(false)
+        .create()
+        .unwrap();
+
+    assert_that!(sut.bind_to_numa_node(u32::MAX).err(), eq Some(SharedMemoryNumaBindError::NumaNodeDoesNotExist));
+}
@@ -32,11 +32,20 @@ use iceoryx2_bb_elementary::static_assert::{static_assert_ge, static_assert_le};
 
 use core::hash::{Hash, Hasher};
 use iceoryx2_bb_container::semantic_string;
+use iceoryx2_bb_container::semantic_string::ComparisonPolicy;
 use iceoryx2_pal_configuration::FILENAME_LENGTH;
 
-fn invalid_characters(value: &[u8]) -> bool {
-    for c in value {
-        match c {
+/// File names and paths are case-insensitive on windows.
+pub(crate) const PATH_COMPARISON_POLICY: ComparisonPolicy = if cfg!(target_os = "windows") {
+    ComparisonPolicy::AsciiCaseInsensitive
+} else {
+    ComparisonPolicy::CaseSensitive
+};
+
+const fn invalid_characters(value: &[u8]) -> bool {
+    let mut i = 0;
+    while i < value.len() {
+        match value[i] {
             // linux & windows
             0 => return true,
             b'/' => return true,
@@ -52,11 +61,12 @@ fn invalid_characters(value: &[u8]) -> bool {
             b'*' => return true,
             _ => (),
         }
+        i += 1;
     }
     false
 }
 
-fn invalid_content(value: &[u8]) -> bool {
+const fn invalid_content(value: &[u8]) -> bool {
     matches!(value, b"" | b"." | b"..")
 }
 
@@ -71,7 +81,9 @@ semantic_string! {
   capacity: FILENAME_LENGTH,
   invalid_content: invalid_content,
   invalid_characters: invalid_characters,
-  normalize: normalize
+  normalize: normalize,
+  comparison_policy: PATH_COMPARISON_POLICY,
+  const_validation: true
 }
 
 #[derive(Debug, Clone, Copy, Eq)]
@@ -116,10 +128,12 @@ impl<const CAPACITY: usize>
 }
 
 // BEGIN: serde
+#[cfg(feature = "serde")]
 pub(crate) mod visitor_type {
     pub(crate) struct RestrictedFileName<const CAPACITY: usize>;
 }
 
+#[cfg(feature = "serde")]
 impl<const CAPACITY: usize> serde::de::Visitor<'_> for visitor_type::RestrictedFileName<CAPACITY> {
     type Value = RestrictedFileName<CAPACITY>;
 
@@ -142,6 +156,7 @@ impl<const CAPACITY: usize> serde::de::Visitor<'_> for visitor_type::RestrictedF
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de, const CAPACITY: usize> serde::Deserialize<'de> for RestrictedFileName<CAPACITY> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -151,6 +166,7 @@ impl<'de, const CAPACITY: usize> serde::Deserialize<'de> for RestrictedFileName<
     }
 }
 
+#[cfg(feature = "serde")]
 impl<const CAPACITY: usize> serde::Serialize for RestrictedFileName<CAPACITY> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -164,6 +180,8 @@ impl<const CAPACITY: usize> serde::Serialize for RestrictedFileName<CAPACITY> {
 impl<const CAPACITY: usize> iceoryx2_bb_container::semantic_string::SemanticString<CAPACITY>
     for RestrictedFileName<CAPACITY>
 {
+    const COMPARISON_POLICY: ComparisonPolicy = PATH_COMPARISON_POLICY;
+
     fn as_string(&self) -> &iceoryx2_bb_container::byte_string::FixedSizeByteString<CAPACITY> {
         &self.value
     }
@@ -198,7 +216,7 @@ impl<const CAPACITY: usize> core::fmt::Display for RestrictedFileName<CAPACITY>
 
 impl<const CAPACITY: usize> Hash for RestrictedFileName<CAPACITY> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.normalize().as_bytes().hash(state)
+        self.semantic_hash(state)
     }
 }
 
@@ -244,7 +262,7 @@ impl<const CAPACITY: usize> PartialEq<RestrictedFileName<CAPACITY>>
     for RestrictedFileName<CAPACITY>
 {
     fn eq(&self, other: &RestrictedFileName<CAPACITY>) -> bool {
-        *self.normalize().as_bytes() == *other.normalize().as_bytes()
+        self.semantic_eq(other)
     }
 }
 
@@ -29,7 +29,7 @@
 
 pub use iceoryx2_bb_container::semantic_string::SemanticString;
 
-use crate::file_name::FileName;
+use crate::file_name::{FileName, PATH_COMPARISON_POLICY};
 use crate::path::Path;
 use core::hash::{Hash, Hasher};
 use iceoryx2_bb_container::byte_string::FixedSizeByteString;
@@ -38,12 +38,7 @@ use iceoryx2_bb_container::semantic_string::SemanticStringError;
 use iceoryx2_bb_log::fail;
 use iceoryx2_pal_configuration::{PATH_LENGTH, PATH_SEPARATOR};
 
-semantic_string! {
-  /// Represents a file path. The restriction are choosen in a way that it is platform independent.
-  /// This means characters/strings which would be legal on some platforms are forbidden as well.
-  name: FilePath,
-  capacity: PATH_LENGTH,
-  invalid_content: |string: &[u8]| {
+const fn invalid_content(string: &[u8]) -> bool {
     match string {
         b"" => return true,
         b"." => return true,
@@ -51,26 +46,34 @@ semantic_string! {
         _ => (),
     }
 
+    let len = string.len();
+
     // only directories can end with /
-    if string[string.len() - 1] == PATH_SEPARATOR {
+    if string[len - 1] == PATH_SEPARATOR {
         return true;
     }
 
     // everything which ends with /. is invalid
-    if string.len() >= 2 && string.get(string.len() - 2..string.len()).unwrap() == [PATH_SEPARATOR, b'.'] {
+    if len >= 2 && string[len - 2] == PATH_SEPARATOR && string[len - 1] == b'.' {
         return true;
     }
 
     // everything which ends with /.. is invalid
-    if string.len() >= 3 && string.get(string.len() - 3..string.len()).unwrap() == [PATH_SEPARATOR, b'.', b'.'] {
+    if len >= 3
+        && string[len - 3] == PATH_SEPARATOR
+        && string[len - 2] == b'.'
+        && string[len - 1] == b'.'
+    {
         return true;
     }
 
     false
-  },
-  invalid_characters: |value: &[u8]| {
-    for c in value {
-        match c {
+}
+
+const fn invalid_characters(value: &[u8]) -> bool {
+    let mut i = 0;
+    while i < value.len() {
+        match value[i] {
             // linux & windows
             0 => return true,
             // windows only
@@ -83,13 +86,24 @@ semantic_string! {
             b'*' => return true,
             _ => (),
         }
+        i += 1;
     }
 
     false
-  },
+}
+
+semantic_string! {
+  /// Represents a file path. The restriction are choosen in a way that it is platform independent.
+  /// This means characters/strings which would be legal on some platforms are forbidden as well.
+  name: FilePath,
+  capacity: PATH_LENGTH,
+  invalid_content: invalid_content,
+  invalid_characters: invalid_characters,
   normalize: |this: &FilePath| {
       *this
-  }
+  },
+  comparison_policy: PATH_COMPARISON_POLICY,
+  const_validation: true
 }
 
 impl FilePath {
@@ -119,7 +133,7 @@ impl FilePath {
     pub fn file_name(&self) -> FileName {
         let file_name = self
             .as_bytes()
-            .rsplitn(2, |c| *c == PATH_SEPARATOR)
+            .rsplitn(2, |c| Path::is_separator(*c))
             .next()
             .unwrap();
         // SAFETY
@@ -130,7 +144,11 @@ impl FilePath {
 
     /// Returns the [`Path`] part of the [`FilePath`].
     pub fn path(&self) -> Path {
-        let path = match self.as_bytes().rsplitn(2, |c| *c == PATH_SEPARATOR).nth(1) {
+        let path = match self
+            .as_bytes()
+            .rsplitn(2, |c| Path::is_separator(*c))
+            .nth(1)
+        {
             Some(p) if !p.is_empty() => p,
             Some(_) => &[PATH_SEPARATOR],
             None => &[],
@@ -140,6 +158,15 @@ impl FilePath {
         //   must be a valid path
         unsafe { Path::new_unchecked(path) }
     }
+
+    /// Returns the lexically canonicalized file path, see [`Path::canonicalize_lexically()`].
+    pub fn canonicalize_lexically(&self) -> FilePath {
+        let path = Path::from(self).canonicalize_lexically();
+        // SAFETY
+        // * the last entry of a file path is a valid file name and is kept by the
+        //   canonicalization, therefore the result is a valid file path
+        unsafe { FilePath::new_unchecked(path.as_bytes()) }
+    }
 }
 
 impl From<FileName> for FilePath {
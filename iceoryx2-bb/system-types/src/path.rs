@@ -35,20 +35,19 @@ use iceoryx2_bb_container::semantic_string::*;
 use iceoryx2_bb_log::fail;
 use iceoryx2_pal_configuration::{PATH_SEPARATOR, ROOT};
 
-use crate::file_name::FileName;
+use crate::file_name::{FileName, PATH_COMPARISON_POLICY};
 use crate::file_path::FilePath;
 
 const PATH_LENGTH: usize = iceoryx2_pal_configuration::PATH_LENGTH;
 
-semantic_string! {
-  name: Path,
-  capacity: PATH_LENGTH,
-  invalid_content: |_: &[u8]| {
+const fn invalid_content(_: &[u8]) -> bool {
     false
-  },
-  invalid_characters: |string: &[u8]| {
-    for value in string {
-        match value {
+}
+
+const fn invalid_characters(string: &[u8]) -> bool {
+    let mut i = 0;
+    while i < string.len() {
+        match string[i] {
             // linux & windows
             0 => return true,
             // windows only
@@ -61,10 +60,17 @@ semantic_string! {
             b'*' => return true,
             _ => (),
         }
+        i += 1;
     }
 
     false
-  },
+}
+
+semantic_string! {
+  name: Path,
+  capacity: PATH_LENGTH,
+  invalid_content: invalid_content,
+  invalid_characters: invalid_characters,
   normalize: |this: &Path| {
         let mut raw_path = [0u8; PATH_LENGTH];
         let value = this.as_bytes();
@@ -96,7 +102,9 @@ semantic_string! {
         // SAFETY
         // * raw_path contains a valid path since the input `this` is a valid path
         unsafe { Path::new_unchecked(&raw_path[0..n]) }
-  }
+  },
+  comparison_policy: PATH_COMPARISON_POLICY,
+  const_validation: true
 }
 
 impl Path {
@@ -136,7 +144,7 @@ impl Path {
             let has_drive_letter = (b'a' <= self.as_bytes()[0] && self.as_bytes()[0] <= b'z')
                 || (b'A' <= self.as_bytes()[0] && self.as_bytes()[0] <= b'Z');
 
-            has_drive_letter && self.as_bytes()[1] == b':' && (self.as_bytes()[2] == PATH_SEPARATOR)
+            has_drive_letter && self.as_bytes()[1] == b':' && Self::is_separator(self.as_bytes()[2])
         }
     }
 
@@ -152,6 +160,155 @@ impl Path {
         Ok(Path::new(value)?.normalize())
     }
 
+    /// Returns true when the byte is a path separator. On windows, `/` is accepted as
+    /// separator in addition to the platform separator `\`.
+    pub const fn is_separator(value: u8) -> bool {
+        value == PATH_SEPARATOR || (cfg!(target_os = "windows") && value == b'/')
+    }
+
+    /// Returns the length of the root prefix, like `/` or `C:\`, of an absolute path.
+    fn root_len(&self) -> usize {
+        let bytes = self.as_bytes();
+        if cfg!(target_os = "windows") && self.is_absolute() {
+            3
+        } else if !bytes.is_empty() && Self::is_separator(bytes[0]) {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// Returns the length of the path without the trailing path separators.
+    fn trimmed_len(&self) -> usize {
+        let bytes = self.as_bytes();
+        let root_len = self.root_len();
+        let mut len = bytes.len();
+        while len > root_len && Self::is_separator(bytes[len - 1]) {
+            len -= 1;
+        }
+
+        len
+    }
+
+    /// Creates a new [`Path`] by adding the entry to a copy of the current path, see
+    /// [`Path::add_path_entry()`]. When the entry is an absolute path it replaces the current
+    /// path.
+    pub fn join(&self, entry: &Path) -> Result<Path, SemanticStringError> {
+        if entry.is_absolute() {
+            return Ok(*entry);
+        }
+
+        let mut new_self = *self;
+        new_self.add_path_entry(entry)?;
+        Ok(new_self)
+    }
+
+    /// Creates a new [`FilePath`] that points to the file in the current path, see
+    /// [`FilePath::from_path_and_file()`].
+    pub fn join_file_name(&self, file: &FileName) -> Result<FilePath, SemanticStringError> {
+        FilePath::from_path_and_file(self, file)
+    }
+
+    /// Returns the path without its last entry. If the path is empty or consists only of the
+    /// root it returns [`None`]. The parent of a relative path with a single entry is the
+    /// empty path.
+    pub fn parent(&self) -> Option<Path> {
+        let bytes = self.as_bytes();
+        let root_len = self.root_len();
+        let mut len = self.trimmed_len();
+        if len == root_len {
+            return None;
+        }
+
+        while len > root_len && !Self::is_separator(bytes[len - 1]) {
+            len -= 1;
+        }
+
+        while len > root_len && Self::is_separator(bytes[len - 1]) {
+            len -= 1;
+        }
+
+        // SAFETY
+        // * a prefix of a valid path that does not split an entry is a valid path
+        Some(unsafe { Path::new_unchecked(&bytes[..len]) })
+    }
+
+    /// Returns the last entry of the path. If the path is empty, consists only of the root or
+    /// the last entry is `.` or `..` or not a valid [`FileName`], it returns [`None`].
+    pub fn file_name(&self) -> Option<FileName> {
+        let bytes = self.as_bytes();
+        let root_len = self.root_len();
+        let len = self.trimmed_len();
+        let start = bytes[root_len..len]
+            .iter()
+            .rposition(|c| Self::is_separator(*c))
+            .map_or(root_len, |p| root_len + p + 1);
+
+        match &bytes[start..len] {
+            b"" | b"." | b".." => None,
+            entry => FileName::new(entry).ok(),
+        }
+    }
+
+    /// Returns the lexically canonicalized path. Besides the [`Path::normalize()`]ation of
+    /// `.` entries and duplicated separators, every `..` removes the preceding entry. A `..`
+    /// at the root of an absolute path is removed, a leading `..` of a relative path is kept.
+    /// On windows all separators are converted into `\`. Trailing separators are removed.
+    ///
+    /// The file system is not accessed, therefore symbolic links are not resolved.
+    pub fn canonicalize_lexically(&self) -> Path {
+        let bytes = self.as_bytes();
+        let root_len = self.root_len();
+        let mut raw_path = [0u8; PATH_LENGTH];
+        for (i, value) in bytes[..root_len].iter().enumerate() {
+            raw_path[i] = if Self::is_separator(*value) {
+                PATH_SEPARATOR
+            } else {
+                *value
+            };
+        }
+
+        let mut n = root_len;
+        let mut number_of_removable_entries = 0;
+        for entry in bytes[root_len..]
+            .split(|c| Self::is_separator(*c))
+            .filter(|entry| !entry.is_empty() && *entry != b".")
+        {
+            if entry == b".." {
+                if number_of_removable_entries > 0 {
+                    while n > root_len && raw_path[n - 1] != PATH_SEPARATOR {
+                        n -= 1;
+                    }
+
+                    if n > root_len {
+                        n -= 1;
+                    }
+
+                    number_of_removable_entries -= 1;
+                    continue;
+                }
+
+                if root_len > 0 {
+                    continue;
+                }
+            } else {
+                number_of_removable_entries += 1;
+            }
+
+            if n > root_len {
+                raw_path[n] = PATH_SEPARATOR;
+                n += 1;
+            }
+            raw_path[n..n + entry.len()].copy_from_slice(entry);
+            n += entry.len();
+        }
+
+        // SAFETY
+        // * raw_path contains a valid path since the input `self` is a valid path and the
+        //   canonicalized path consists only of its entries
+        unsafe { Path::new_unchecked(&raw_path[0..n]) }
+    }
+
     pub fn entries(&self) -> Vec<FileName> {
         let skip_size = if cfg!(target_os = "windows") && self.is_absolute() {
             // skip drive letter like C:\ since the path is absolute
@@ -161,7 +318,7 @@ impl Path {
         };
 
         self.as_bytes()
-            .split(|c| *c == PATH_SEPARATOR)
+            .split(|c| Self::is_separator(*c))
             .skip(skip_size)
             .filter(|entry| !entry.is_empty())
             .map(|entry| unsafe { FileName::new_unchecked(entry) })
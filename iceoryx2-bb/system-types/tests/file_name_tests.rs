@@ -92,3 +92,25 @@ fn file_name_with_utf_8_content_works() {
     assert_that!(sut.pop(), eq Err(SemanticStringError::InvalidContent));
     assert_that!(sut.insert(6, b'a'), eq Err(SemanticStringError::InvalidContent));
 }
+
+#[test]
+fn file_name_from_static_works_in_const_context() {
+    const SUT: FileName = FileName::from_static("some_file.txt");
+
+    assert_that!(SUT, eq FileName::new(b"some_file.txt").unwrap());
+    assert_that!(SUT, len 13);
+}
+
+#[test]
+#[should_panic]
+fn file_name_from_static_with_illegal_content_panics_at_runtime() {
+    let value = "..";
+    FileName::from_static(value);
+}
+
+#[test]
+#[should_panic]
+fn file_name_from_static_with_illegal_character_panics_at_runtime() {
+    let value = "no/path/allowed.txt";
+    FileName::from_static(value);
+}
@@ -180,6 +180,15 @@ mod unix {
         let sut = FilePath::new(b"/blubbb").unwrap();
         assert_that!(sut.path(), eq b"/");
     }
+
+    #[test]
+    fn file_path_canonicalize_lexically_works() {
+        let sut = FilePath::new(b"/some/./file/../path//file.txt").unwrap();
+        assert_that!(sut.canonicalize_lexically(), eq b"/some/path/file.txt");
+
+        let sut = FilePath::new(b"some/../../file.txt").unwrap();
+        assert_that!(sut.canonicalize_lexically(), eq b"../file.txt");
+    }
 }
 
 #[test]
@@ -231,3 +240,17 @@ fn file_path_with_utf_8_content_works() {
     assert_that!(sut.remove(6), eq Err(SemanticStringError::InvalidContent));
     assert_that!(sut.pop(), eq Err(SemanticStringError::InvalidContent));
 }
+
+#[test]
+fn file_path_from_static_works_in_const_context() {
+    const SUT: FilePath = FilePath::from_static("some_file.txt");
+
+    assert_that!(SUT, eq FilePath::new(b"some_file.txt").unwrap());
+}
+
+#[test]
+#[should_panic]
+fn file_path_from_static_with_illegal_content_panics_at_runtime() {
+    let value = "..";
+    FilePath::from_static(value);
+}
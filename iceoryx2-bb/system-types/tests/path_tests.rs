@@ -11,6 +11,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use iceoryx2_bb_container::semantic_string::*;
+use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::path::*;
 use iceoryx2_bb_testing::assert_that;
 
@@ -66,6 +67,23 @@ mod windows {
         assert_that!(sut, eq b"fuu\\blaaaha\\blub.ma");
     }
 
+    #[test]
+    fn path_operations_accept_slash_as_separator() {
+        let sut = Path::new(b"C:/some/file/path").unwrap();
+
+        assert_that!(sut.parent().unwrap(), eq b"C:/some/file");
+        assert_that!(sut.file_name().unwrap(), eq b"path");
+        assert_that!(Path::new(b"C:/").unwrap().parent(), is_none);
+        assert_that!(
+            Path::new(b"C:/some/../file/./path/").unwrap().canonicalize_lexically(),
+            eq b"C:\\file\\path"
+        );
+        assert_that!(
+            Path::new(b"some\\..\\..\\path").unwrap().canonicalize_lexically(),
+            eq b"..\\path"
+        );
+    }
+
     #[test]
     fn path_is_absolute_works() {
         let sut = Path::new(b"D:\\bla").unwrap();
@@ -222,6 +240,52 @@ mod unix {
         assert_that!(sut.is_absolute(), eq false);
     }
 
+    #[test]
+    fn path_join_works() {
+        let sut = Path::new(b"/some").unwrap();
+        assert_that!(sut.join(&Path::new(b"file/path").unwrap()).unwrap(), eq b"/some/file/path");
+        assert_that!(sut.join(&Path::new(b"/absolute").unwrap()).unwrap(), eq b"/absolute");
+        assert_that!(sut, eq b"/some");
+
+        let file = sut.join_file_name(&FileName::new(b"file.txt").unwrap());
+        assert_that!(file.unwrap(), eq b"/some/file.txt");
+    }
+
+    #[test]
+    fn path_parent_works() {
+        assert_that!(Path::new(b"/some/file/path").unwrap().parent().unwrap(), eq b"/some/file");
+        assert_that!(Path::new(b"/some//file//").unwrap().parent().unwrap(), eq b"/some");
+        assert_that!(Path::new(b"/some").unwrap().parent().unwrap(), eq b"/");
+        assert_that!(Path::new(b"some").unwrap().parent().unwrap(), eq b"");
+        assert_that!(Path::new(b"/").unwrap().parent(), is_none);
+        assert_that!(Path::new(b"").unwrap().parent(), is_none);
+    }
+
+    #[test]
+    fn path_file_name_works() {
+        assert_that!(Path::new(b"/some/file/path").unwrap().file_name().unwrap(), eq b"path");
+        assert_that!(Path::new(b"/some/file/").unwrap().file_name().unwrap(), eq b"file");
+        assert_that!(Path::new(b"some").unwrap().file_name().unwrap(), eq b"some");
+        assert_that!(Path::new(b"/some/..").unwrap().file_name(), is_none);
+        assert_that!(Path::new(b"/some/.").unwrap().file_name(), is_none);
+        assert_that!(Path::new(b"/").unwrap().file_name(), is_none);
+        assert_that!(Path::new(b"").unwrap().file_name(), is_none);
+    }
+
+    #[test]
+    fn path_canonicalize_lexically_works() {
+        let canonicalize = |value: &[u8]| Path::new(value).unwrap().canonicalize_lexically();
+
+        assert_that!(canonicalize(b"/some/file/../path"), eq b"/some/path");
+        assert_that!(canonicalize(b"//some/./file//path/"), eq b"/some/file/path");
+        assert_that!(canonicalize(b"/some/.."), eq b"/");
+        assert_that!(canonicalize(b"/../some"), eq b"/some");
+        assert_that!(canonicalize(b"some/../../path"), eq b"../path");
+        assert_that!(canonicalize(b"../../some"), eq b"../../some");
+        assert_that!(canonicalize(b"some/file/../.."), eq b"");
+        assert_that!(canonicalize(b"a/b/c/../../d"), eq b"a/d");
+    }
+
     #[test]
     fn path_with_utf_8_content_works() {
         let mut sut = Path::new(b"/fuu/").unwrap();
@@ -233,3 +297,17 @@ mod unix {
         assert_that!(sut.pop(), eq Err(SemanticStringError::InvalidContent));
     }
 }
+
+#[test]
+fn path_from_static_works_in_const_context() {
+    const SUT: Path = Path::from_static("some_dir");
+
+    assert_that!(SUT, eq Path::new(b"some_dir").unwrap());
+}
+
+#[test]
+#[should_panic]
+fn path_from_static_with_illegal_character_panics_at_runtime() {
+    let value = "some\0dir";
+    Path::from_static(value);
+}
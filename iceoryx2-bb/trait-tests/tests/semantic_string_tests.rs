@@ -204,6 +204,53 @@ mod semantic_string {
         }
     }
 
+    #[test]
+    fn chars_and_char_len_of_ascii_string_work<
+        const CAPACITY: usize,
+        Sut: SemanticString<CAPACITY>,
+    >() {
+        let sut = Sut::new(b"a01234567").unwrap();
+
+        assert_that!(sut.as_str(), eq "a01234567");
+        assert_that!(sut.char_len(), eq sut.len());
+        assert_that!(sut.chars().collect::<String>(), eq "a01234567");
+        assert_that!(sut.is_char_boundary(9), eq true);
+        assert_that!(sut.is_char_boundary(10), eq false);
+    }
+
+    #[test]
+    fn push_char_and_pop_char_work<const CAPACITY: usize, Sut: SemanticString<CAPACITY>>() {
+        let mut sut = Sut::new(b"a0123").unwrap();
+
+        assert_that!(sut.push_char('b'), is_ok);
+        assert_that!(sut.insert_char(1, 'c'), is_ok);
+        assert_that!(sut.as_bytes(), eq b"ac0123b");
+
+        assert_that!(sut.pop_char(), eq Ok(Some('b')));
+        assert_that!(sut.as_bytes(), eq b"ac0123");
+    }
+
+    #[test]
+    fn insert_char_out_of_bounds_fails<const CAPACITY: usize, Sut: SemanticString<CAPACITY>>() {
+        let mut sut = Sut::new(b"a0123").unwrap();
+
+        assert_that!(sut.insert_char(6, 'b'), eq Err(SemanticStringError::InvalidContent));
+        assert_that!(sut.as_bytes(), eq b"a0123");
+    }
+
+    #[test]
+    fn truncate_to_char_boundary_of_ascii_string_works<
+        const CAPACITY: usize,
+        Sut: SemanticString<CAPACITY>,
+    >() {
+        let mut sut = Sut::new(b"a01234567").unwrap();
+
+        assert_that!(sut.truncate_to_char_boundary(12), is_ok);
+        assert_that!(sut.as_bytes(), eq b"a01234567");
+        assert_that!(sut.truncate_to_char_boundary(4), is_ok);
+        assert_that!(sut.as_bytes(), eq b"a012");
+    }
+
     #[test]
     fn invalid_utf8_characters_fail<const CAPACITY: usize, Sut: SemanticString<CAPACITY>>() {
         let sut = Sut::new(&[b'a', b'b', 0xdf, 0xff]);
@@ -284,3 +331,148 @@ mod semantic_string {
     #[instantiate_tests(<{Base64Url::max_len()}, Base64Url>)]
     mod base64url {}
 }
+
+mod semantic_string_with_multi_byte_characters {
+    use super::*;
+
+    #[test]
+    fn chars_and_char_len_work() {
+        let sut = FileName::new("grüße-🦀".as_bytes()).unwrap();
+
+        assert_that!(sut, len 12);
+        assert_that!(sut.char_len(), eq 7);
+        assert_that!(sut.as_str(), eq "grüße-🦀");
+        assert_that!(sut.chars().collect::<Vec<_>>(), eq vec!['g', 'r', 'ü', 'ß', 'e', '-', '🦀']);
+    }
+
+    #[test]
+    fn is_char_boundary_works() {
+        let sut = FileName::new("aü".as_bytes()).unwrap();
+
+        assert_that!(sut.is_char_boundary(0), eq true);
+        assert_that!(sut.is_char_boundary(1), eq true);
+        assert_that!(sut.is_char_boundary(2), eq false);
+        assert_that!(sut.is_char_boundary(3), eq true);
+    }
+
+    #[test]
+    fn insert_char_works() {
+        let mut sut = FileName::new("aü".as_bytes()).unwrap();
+
+        assert_that!(sut.insert_char(1, '🦀'), is_ok);
+        assert_that!(sut.push_char('ß'), is_ok);
+
+        assert_that!(sut.as_str(), eq "a🦀üß");
+    }
+
+    #[test]
+    fn insert_char_inside_of_character_fails() {
+        let mut sut = FileName::new("aü".as_bytes()).unwrap();
+
+        assert_that!(sut.insert_char(2, 'b'), eq Err(SemanticStringError::InvalidContent));
+        assert_that!(sut.insert(2, b'b'), eq Err(SemanticStringError::InvalidContent));
+        assert_that!(sut.as_str(), eq "aü");
+    }
+
+    #[test]
+    fn pop_char_removes_whole_character() {
+        let mut sut = FileName::new("a🦀".as_bytes()).unwrap();
+
+        assert_that!(sut.pop_char(), eq Ok(Some('🦀')));
+        assert_that!(sut.as_str(), eq "a");
+    }
+
+    #[test]
+    fn truncate_inside_of_character_fails() {
+        let mut sut = FileName::new("aü".as_bytes()).unwrap();
+
+        assert_that!(sut.truncate(2), eq Err(SemanticStringError::InvalidContent));
+        assert_that!(sut.as_str(), eq "aü");
+    }
+
+    #[test]
+    fn truncate_to_char_boundary_does_not_split_character() {
+        let mut sut = FileName::new("ab🦀cd".as_bytes()).unwrap();
+
+        assert_that!(sut.truncate_to_char_boundary(5), is_ok);
+        assert_that!(sut.as_str(), eq "ab");
+
+        let mut sut = FileName::new("ab🦀cd".as_bytes()).unwrap();
+        assert_that!(sut.truncate_to_char_boundary(6), is_ok);
+        assert_that!(sut.as_str(), eq "ab🦀");
+    }
+}
+
+mod semantic_string_comparison_policy {
+    use super::*;
+    use core::hash::{Hash, Hasher};
+    use iceoryx2_bb_container::semantic_string;
+    use std::collections::hash_map::DefaultHasher;
+
+    semantic_string! {
+      name: CaseInsensitiveName,
+      capacity: 16,
+      invalid_content: |string: &[u8]| string.is_empty(),
+      invalid_characters: |string: &[u8]| !string.is_ascii(),
+      normalize: |this: &CaseInsensitiveName| {
+          *this
+      },
+      comparison_policy: ComparisonPolicy::AsciiCaseInsensitive
+    }
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn case_insensitive_strings_with_different_case_are_equal() {
+        let sut = CaseInsensitiveName::new(b"Hypno-Toad").unwrap();
+        let other = CaseInsensitiveName::new(b"hYPNO-tOAD").unwrap();
+
+        assert_that!(CaseInsensitiveName::COMPARISON_POLICY, eq ComparisonPolicy::AsciiCaseInsensitive);
+        assert_that!(sut, eq other);
+        assert_that!(sut, eq b"HYPNO-TOAD");
+        assert_that!(hash_of(&sut), eq hash_of(&other));
+        assert_that!(sut, ne CaseInsensitiveName::new(b"Hypno-Frog").unwrap());
+    }
+
+    #[test]
+    fn case_sensitive_strings_with_different_case_are_not_equal() {
+        let sut = Base64Url::new(b"HypnoToad").unwrap();
+        let other = Base64Url::new(b"hypnotoad").unwrap();
+
+        assert_that!(Base64Url::COMPARISON_POLICY, eq ComparisonPolicy::CaseSensitive);
+        assert_that!(sut, ne other);
+        assert_that!(sut.eq_ignore_ascii_case(&other), eq true);
+        assert_that!(sut.eq_ignore_ascii_case(&Base64Url::new(b"hypnofrog").unwrap()), eq false);
+    }
+
+    #[test]
+    fn file_names_are_compared_case_insensitive_only_on_windows() {
+        let sut = FileName::new(b"Hypno.Toad").unwrap();
+        let other = FileName::new(b"hypno.toad").unwrap();
+
+        assert_that!(sut.eq_ignore_ascii_case(&other), eq true);
+        assert_that!(sut == other, eq cfg!(target_os = "windows"));
+        assert_that!(hash_of(&sut) == hash_of(&other), eq cfg!(target_os = "windows"));
+    }
+
+    #[test]
+    fn paths_with_trailing_separator_are_equal() {
+        let sut = Path::new_root_path()
+            .join(&Path::new(b"hypno").unwrap())
+            .unwrap();
+        let separator = if cfg!(target_os = "windows") {
+            b'\\'
+        } else {
+            b'/'
+        };
+        let mut other = sut;
+        other.push(separator).unwrap();
+
+        assert_that!(sut, eq other);
+        assert_that!(hash_of(&sut), eq hash_of(&other));
+    }
+}
@@ -58,10 +58,14 @@ use core::{fmt::Debug, time::Duration};
 
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+pub use iceoryx2_bb_posix::shared_memory::{HugePageSize, HugePages};
 use iceoryx2_bb_system_types::file_name::*;
 use tiny_fn::tiny_fn;
 
-use crate::static_storage::file::{NamedConcept, NamedConceptBuilder, NamedConceptMgmt};
+use crate::static_storage::file::{
+    AccessControlConfiguration, NamedConcept, NamedConceptBuilder, NamedConceptConfiguration,
+    NamedConceptMgmt,
+};
 
 tiny_fn! {
     pub(crate) struct Initializer<T> = FnMut(value: &mut T, allocator: &mut BumpAllocator) -> bool;
@@ -76,6 +80,7 @@ impl<T> Debug for Initializer<'_, T> {
 pub(crate) mod dynamic_storage_configuration;
 pub mod posix_shared_memory;
 pub mod process_local;
+pub mod static_memory;
 
 /// Describes failures when creating a new [`DynamicStorage`]
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
@@ -102,6 +107,27 @@ enum_gen! {
     DynamicStorageCreateError
 }
 
+/// Configures the explicit [`HugePages`] that back the memory of a [`DynamicStorage`].
+pub trait HugePageConfiguration: NamedConceptConfiguration {
+    /// Backs the memory with [`HugePages`]. When they are not available, the regular pages are
+    /// used as fallback. Implementations that do not support huge pages ignore the setting.
+    fn huge_pages(self, value: Option<HugePages>) -> Self;
+
+    /// Returns the configured [`HugePages`].
+    fn get_huge_pages(&self) -> Option<&HugePages>;
+}
+
+/// Configures the NUMA node to which the memory of a [`DynamicStorage`] is bound.
+pub trait NumaNodeConfiguration: NamedConceptConfiguration {
+    /// Binds the memory to the provided NUMA node. When the binding fails, for instance since
+    /// the node does not exist, the memory is used without binding. Implementations that do
+    /// not support NUMA ignore the setting.
+    fn numa_node(self, value: Option<u32>) -> Self;
+
+    /// Returns the configured NUMA node.
+    fn get_numa_node(&self) -> Option<u32>;
+}
+
 /// Builder for the [`DynamicStorage`]. T is not allowed to implement the [`Drop`] trait.
 pub trait DynamicStorageBuilder<'builder, T: Send + Sync, D: DynamicStorage<T>>:
     Debug + Sized + NamedConceptBuilder<D>
@@ -144,7 +170,13 @@ pub trait DynamicStorageBuilder<'builder, T: Send + Sync, D: DynamicStorage<T>>:
 /// Is being built by the [`DynamicStorageBuilder`]. The [`DynamicStorage`] trait shall provide
 /// inter-process access to a modifyable piece of memory identified by some name.
 pub trait DynamicStorage<T: Send + Sync>:
-    Sized + Debug + NamedConceptMgmt + NamedConcept + Send + Sync
+    Sized
+    + Debug
+    + NamedConceptMgmt<
+        Configuration: HugePageConfiguration + NumaNodeConfiguration + AccessControlConfiguration,
+    > + NamedConcept
+    + Send
+    + Sync
 {
     type Builder<'builder>: DynamicStorageBuilder<'builder, T, Self>;
 
@@ -168,6 +200,12 @@ pub trait DynamicStorage<T: Send + Sync>:
     /// thread-safe.
     fn get(&self) -> &T;
 
+    /// Returns the [`HugePages`] that back the [`DynamicStorage`]. If the regular pages are
+    /// used it returns [`None`].
+    fn huge_pages(&self) -> Option<&HugePages> {
+        None
+    }
+
     /// The default suffix of every dynamic storage
     fn default_suffix() -> FileName {
         unsafe { FileName::new_unchecked(b".dyn") }
@@ -39,7 +39,22 @@
 //! storage.get().store(456, Ordering::Relaxed);
 //!
 //! ```
+//!
+//! # Huge Pages
+//!
+//! With [`HugePageConfiguration::huge_pages()`] the storage is backed by explicit
+//! [`HugePages`]. When the huge pages are not available, for instance since the hugetlbfs
+//! mount point does not exist or the huge page pool is exhausted, a warning is logged and the
+//! storage falls back to regular POSIX shared memory. [`DynamicStorage::huge_pages()`]
+//! reports what actually backs the storage.
+//!
+//! # NUMA
+//!
+//! With [`NumaNodeConfiguration::numa_node()`] the memory of the storage is bound to a NUMA
+//! node before it is initialized. When the binding fails, a warning is logged and the storage
+//! uses the default memory policy of the system.
 pub use crate::dynamic_storage::*;
+use crate::named_concept::{AccessControlConfiguration, AccessControlList};
 use crate::static_storage::file::NamedConceptConfiguration;
 use crate::static_storage::file::NamedConceptRemoveError;
 use core::fmt::Debug;
@@ -50,6 +65,7 @@ use core::sync::atomic::Ordering;
 use iceoryx2_bb_elementary::package_version::PackageVersion;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_log::warn;
+use iceoryx2_bb_posix::access_control_list::AccessControlListApplyError;
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_bb_posix::directory::*;
 use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
@@ -84,6 +100,9 @@ pub struct Configuration<T: Send + Sync + Debug> {
     suffix: FileName,
     prefix: FileName,
     path: Path,
+    huge_pages: Option<HugePages>,
+    numa_node: Option<u32>,
+    access_control_list: Option<AccessControlList>,
     _data: PhantomData<T>,
 }
 
@@ -93,6 +112,9 @@ impl<T: Send + Sync + Debug> Clone for Configuration<T> {
             suffix: self.suffix,
             prefix: self.prefix,
             path: self.path,
+            huge_pages: self.huge_pages,
+            numa_node: self.numa_node,
+            access_control_list: self.access_control_list,
             _data: PhantomData,
         }
     }
@@ -110,6 +132,9 @@ impl<T: Send + Sync + Debug> Default for Configuration<T> {
             path: Storage::<()>::default_path_hint(),
             suffix: Storage::<()>::default_suffix(),
             prefix: Storage::<()>::default_prefix(),
+            huge_pages: None,
+            numa_node: None,
+            access_control_list: None,
             _data: PhantomData,
         }
     }
@@ -117,6 +142,39 @@ impl<T: Send + Sync + Debug> Default for Configuration<T> {
 
 impl<T: Send + Sync + Debug> DynamicStorageConfiguration<T> for Configuration<T> {}
 
+impl<T: Send + Sync + Debug> HugePageConfiguration for Configuration<T> {
+    fn huge_pages(mut self, value: Option<HugePages>) -> Self {
+        self.huge_pages = value;
+        self
+    }
+
+    fn get_huge_pages(&self) -> Option<&HugePages> {
+        self.huge_pages.as_ref()
+    }
+}
+
+impl<T: Send + Sync + Debug> NumaNodeConfiguration for Configuration<T> {
+    fn numa_node(mut self, value: Option<u32>) -> Self {
+        self.numa_node = value;
+        self
+    }
+
+    fn get_numa_node(&self) -> Option<u32> {
+        self.numa_node
+    }
+}
+
+impl<T: Send + Sync + Debug> AccessControlConfiguration for Configuration<T> {
+    fn access_control_list(mut self, value: Option<AccessControlList>) -> Self {
+        self.access_control_list = value;
+        self
+    }
+
+    fn get_access_control_list(&self) -> Option<&AccessControlList> {
+        self.access_control_list.as_ref()
+    }
+}
+
 impl<T: Send + Sync + Debug> NamedConceptConfiguration for Configuration<T> {
     fn prefix(mut self, value: &FileName) -> Self {
         self.prefix = *value;
@@ -174,6 +232,71 @@ impl<T: Send + Sync + Debug> NamedConceptBuilder<Storage<T>> for Builder<'_, T>
 }
 
 impl<T: Send + Sync + Debug> Builder<'_, T> {
+    // a storage that falls back to regular pages is stored in the POSIX shared memory
+    // namespace, therefore both locations are considered
+    fn open_shm(&self, full_name: &FileName) -> Result<SharedMemory, SharedMemoryCreationError> {
+        if let Some(huge_pages) = &self.config.huge_pages {
+            match SharedMemoryBuilder::new(full_name)
+                .huge_pages(huge_pages)
+                .open_existing(AccessMode::ReadWrite)
+            {
+                Err(SharedMemoryCreationError::DoesNotExist) => (),
+                result => return result,
+            }
+        }
+
+        SharedMemoryBuilder::new(full_name).open_existing(AccessMode::ReadWrite)
+    }
+
+    fn create_shm(
+        &self,
+        full_name: &FileName,
+        huge_pages: Option<&HugePages>,
+    ) -> Result<SharedMemory, SharedMemoryCreationError> {
+        let builder = match huge_pages {
+            Some(huge_pages) => SharedMemoryBuilder::new(full_name).huge_pages(huge_pages),
+            None => SharedMemoryBuilder::new(full_name),
+        };
+
+        builder
+            .creation_mode(CreationMode::CreateExclusive)
+            // posix shared memory is always aligned to the greatest possible value (PAGE_SIZE)
+            // therefore we do not have to add additional alignment space for T
+            .size(core::mem::size_of::<Data<T>>() + self.supplementary_size)
+            .permission(INIT_PERMISSIONS)
+            .zero_memory(false)
+            .has_ownership(self.has_ownership)
+            .create()
+    }
+
+    fn create_shm_with_fallback(
+        &self,
+        full_name: &FileName,
+    ) -> Result<SharedMemory, SharedMemoryCreationError> {
+        let huge_pages = match &self.config.huge_pages {
+            None => return self.create_shm(full_name, None),
+            Some(huge_pages) => huge_pages,
+        };
+
+        if SharedMemory::does_exist(full_name) {
+            return Err(SharedMemoryCreationError::AlreadyExist);
+        }
+
+        match self.create_shm(full_name, Some(huge_pages)) {
+            Err(
+                e @ (SharedMemoryCreationError::AlreadyExist
+                | SharedMemoryCreationError::InsufficientPermissions),
+            ) => Err(e),
+            Err(e) => {
+                warn!(from self,
+                    "Unable to back the storage with {:?} huge pages of \"{}\" ({:?}), falling back to regular pages.",
+                    huge_pages.page_size, huge_pages.directory, e);
+                self.create_shm(full_name, None)
+            }
+            Ok(shm) => Ok(shm),
+        }
+    }
+
     fn open_impl(&self) -> Result<Storage<T>, DynamicStorageOpenError> {
         let msg = "Failed to open posix_shared_memory::DynamicStorage";
 
@@ -184,7 +307,7 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
 
         let mut elapsed_time = Duration::ZERO;
         let shm = loop {
-            match SharedMemoryBuilder::new(&full_name).open_existing(AccessMode::ReadWrite) {
+            match self.open_shm(&full_name) {
                 Ok(v) => break v,
                 Err(SharedMemoryCreationError::DoesNotExist) => {
                     fail!(from self, with DynamicStorageOpenError::DoesNotExist,
@@ -252,16 +375,7 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
         let msg = "Failed to create dynamic_storage::PosixSharedMemory";
 
         let full_name = self.config.path_for(&self.storage_name).file_name();
-        let shm = match SharedMemoryBuilder::new(&full_name)
-            .creation_mode(CreationMode::CreateExclusive)
-            // posix shared memory is always aligned to the greatest possible value (PAGE_SIZE)
-            // therefore we do not have to add additional alignment space for T
-            .size(core::mem::size_of::<Data<T>>() + self.supplementary_size)
-            .permission(INIT_PERMISSIONS)
-            .zero_memory(false)
-            .has_ownership(self.has_ownership)
-            .create()
-        {
+        let shm = match self.create_shm_with_fallback(&full_name) {
             Ok(v) => v,
             Err(SharedMemoryCreationError::AlreadyExist) => {
                 fail!(from self, with DynamicStorageCreateError::AlreadyExists,
@@ -277,6 +391,15 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
             }
         };
 
+        // the memory is not yet touched, therefore all pages are allocated on the node
+        if let Some(numa_node) = self.config.numa_node {
+            if let Err(e) = shm.bind_to_numa_node(numa_node) {
+                warn!(from self,
+                    "Unable to bind the storage to the NUMA node {} ({:?}), the default memory policy is used.",
+                    numa_node, e);
+            }
+        }
+
         Ok(shm)
     }
 
@@ -319,10 +442,29 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
         //////////////////////////////////////////
         unsafe { (*version_ptr).store(PackageVersion::get().to_u64(), Ordering::SeqCst) };
 
-        if let Err(e) = shm.set_permission(FINAL_PERMISSIONS) {
-            fail!(from origin, with DynamicStorageCreateError::InternalError,
-                "{} since the final permissions could not be applied to the underlying shared memory ({:?}).",
-                msg, e);
+        match self.config.access_control_list {
+            Some(access_control_list) => {
+                match access_control_list.apply(FINAL_PERMISSIONS, &mut shm) {
+                    Ok(()) => (),
+                    Err(AccessControlListApplyError::InsufficientPermissions) => {
+                        fail!(from origin, with DynamicStorageCreateError::InsufficientPermissions,
+                            "{} since the access control list {:?} could not be applied to the underlying shared memory due to insufficient permissions.",
+                            msg, access_control_list);
+                    }
+                    Err(e) => {
+                        fail!(from origin, with DynamicStorageCreateError::InternalError,
+                            "{} since the access control list {:?} could not be applied to the underlying shared memory ({:?}).",
+                            msg, access_control_list, e);
+                    }
+                }
+            }
+            None => {
+                if let Err(e) = shm.set_permission(FINAL_PERMISSIONS) {
+                    fail!(from origin, with DynamicStorageCreateError::InternalError,
+                        "{} since the final permissions could not be applied to the underlying shared memory ({:?}).",
+                        msg, e);
+                }
+            }
         }
 
         Ok(Storage {
@@ -424,20 +566,26 @@ impl<T: Send + Sync + Debug> NamedConceptMgmt for Storage<T> {
     ) -> Result<bool, crate::static_storage::file::NamedConceptDoesExistError> {
         let full_name = cfg.path_for(name).file_name();
 
-        Ok(iceoryx2_bb_posix::shared_memory::SharedMemory::does_exist(
-            &full_name,
-        ))
+        Ok(SharedMemory::does_exist(&full_name)
+            || cfg
+                .huge_pages
+                .is_some_and(|h| SharedMemory::does_exist_with_huge_pages(&full_name, &h)))
     }
 
     fn list_cfg(
         config: &Self::Configuration,
     ) -> Result<Vec<FileName>, crate::static_storage::file::NamedConceptListError> {
-        let entries = SharedMemory::list();
+        let mut entries = SharedMemory::list();
+        if let Some(huge_pages) = &config.huge_pages {
+            entries.extend(SharedMemory::list_with_huge_pages(huge_pages));
+        }
 
         let mut result = vec![];
         for entry in &entries {
             if let Some(entry_name) = config.extract_name_from_file(entry) {
-                result.push(entry_name);
+                if !result.contains(&entry_name) {
+                    result.push(entry_name);
+                }
             }
         }
 
@@ -463,7 +611,15 @@ impl<T: Send + Sync + Debug> NamedConceptMgmt for Storage<T> {
                     "Removing DynamicStorage in broken state ({:?}) will not call drop of the underlying data type {:?}.",
                     e, core::any::type_name::<T>());
 
-                match iceoryx2_bb_posix::shared_memory::SharedMemory::remove(&full_name) {
+                let result = match &cfg.huge_pages {
+                    Some(huge_pages) => {
+                        SharedMemory::remove_with_huge_pages(&full_name, huge_pages)
+                    }
+                    None => Ok(false),
+                }
+                .and_then(|has_removed| Ok(SharedMemory::remove(&full_name)? || has_removed));
+
+                match result {
                     Ok(v) => Ok(v),
                     Err(
                         iceoryx2_bb_posix::shared_memory::SharedMemoryRemoveError::InsufficientPermissions,
@@ -509,4 +665,8 @@ impl<T: Send + Sync + Debug> DynamicStorage<T> for Storage<T> {
     fn release_ownership(&self) {
         self.shm.release_ownership()
     }
+
+    fn huge_pages(&self) -> Option<&HugePages> {
+        self.shm.huge_pages()
+    }
 }
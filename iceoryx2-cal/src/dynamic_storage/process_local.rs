@@ -59,7 +59,8 @@ use alloc::sync::Arc;
 
 pub use crate::dynamic_storage::*;
 use crate::named_concept::{
-    NamedConceptDoesExistError, NamedConceptListError, NamedConceptRemoveError,
+    AccessControlConfiguration, AccessControlList, NamedConceptDoesExistError,
+    NamedConceptListError, NamedConceptRemoveError,
 };
 use crate::static_storage::file::NamedConceptConfiguration;
 
@@ -108,6 +109,39 @@ impl<T: Send + Sync + Debug> Default for Configuration<T> {
 
 impl<T: Send + Sync + Debug> DynamicStorageConfiguration<T> for Configuration<T> {}
 
+// the process local storage uses heap memory, therefore huge pages are not supported
+impl<T: Send + Sync + Debug> HugePageConfiguration for Configuration<T> {
+    fn huge_pages(self, _value: Option<HugePages>) -> Self {
+        self
+    }
+
+    fn get_huge_pages(&self) -> Option<&HugePages> {
+        None
+    }
+}
+
+// the process local storage uses heap memory, therefore it is not bound to a NUMA node
+impl<T: Send + Sync + Debug> NumaNodeConfiguration for Configuration<T> {
+    fn numa_node(self, _value: Option<u32>) -> Self {
+        self
+    }
+
+    fn get_numa_node(&self) -> Option<u32> {
+        None
+    }
+}
+
+// the process local storage cannot be accessed by other processes
+impl<T: Send + Sync + Debug> AccessControlConfiguration for Configuration<T> {
+    fn access_control_list(self, _value: Option<AccessControlList>) -> Self {
+        self
+    }
+
+    fn get_access_control_list(&self) -> Option<&AccessControlList> {
+        None
+    }
+}
+
 impl<T: Send + Sync + Debug> NamedConceptConfiguration for Configuration<T> {
     fn prefix(mut self, value: &FileName) -> Self {
         self.prefix = *value;
@@ -0,0 +1,423 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Implementation of [`DynamicStorage`] that places the value into the statically allocated
+//! memory region of [`static_memory`](crate::static_memory). **Cannot be used in an
+//! inter-process context.**
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_system_types::file_name::FileName;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//! use iceoryx2_cal::dynamic_storage::static_memory::*;
+//! use iceoryx2_cal::named_concept::*;
+//! use core::sync::atomic::{AtomicI64, Ordering};
+//!
+//! let additional_size: usize = 1024;
+//! let storage_name = FileName::new(b"myDynStorage").unwrap();
+//! let storage = Builder::new(&storage_name)
+//!                 .supplementary_size(additional_size)
+//!                 .create(AtomicI64::new(444)).unwrap();
+//!
+//! // at some other place in the local process, can be another thread
+//! let reader = Builder::<AtomicI64>::new(&storage_name)
+//!                                 .open().unwrap();
+//!
+//! println!("Old value: {}", reader.get().load(Ordering::Relaxed));
+//! reader.get().store(456, Ordering::Relaxed);
+//! println!("New value: {}", reader.get().load(Ordering::Relaxed));
+//! ```
+
+use core::alloc::Layout;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+use core::sync::atomic::Ordering;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::path::Path;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+
+pub use crate::dynamic_storage::*;
+use crate::named_concept::{
+    AccessControlConfiguration, AccessControlList, NamedConceptDoesExistError,
+    NamedConceptListError, NamedConceptRemoveError,
+};
+use crate::static_memory::{EntryKind, RegistryError, RegistryGuard, REGISTRY};
+use crate::static_storage::file::NamedConceptConfiguration;
+
+use self::dynamic_storage_configuration::DynamicStorageConfiguration;
+
+#[derive(PartialEq, Eq, Copy, Debug)]
+pub struct Configuration<T: Send + Sync + Debug> {
+    suffix: FileName,
+    prefix: FileName,
+    path_hint: Path,
+    _data: PhantomData<T>,
+}
+
+impl<T: Send + Sync + Debug> Clone for Configuration<T> {
+    fn clone(&self) -> Self {
+        Self {
+            suffix: self.suffix,
+            prefix: self.prefix,
+            path_hint: self.path_hint,
+            _data: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + Debug> Default for Configuration<T> {
+    fn default() -> Self {
+        Self {
+            suffix: Storage::<()>::default_suffix(),
+            prefix: Storage::<()>::default_prefix(),
+            path_hint: Storage::<()>::default_path_hint(),
+            _data: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + Debug> DynamicStorageConfiguration<T> for Configuration<T> {}
+
+// the static memory is part of the binary, therefore huge pages are not supported
+impl<T: Send + Sync + Debug> HugePageConfiguration for Configuration<T> {
+    fn huge_pages(self, _value: Option<HugePages>) -> Self {
+        self
+    }
+
+    fn get_huge_pages(&self) -> Option<&HugePages> {
+        None
+    }
+}
+
+// the static memory is part of the binary, therefore it is not bound to a NUMA node
+impl<T: Send + Sync + Debug> NumaNodeConfiguration for Configuration<T> {
+    fn numa_node(self, _value: Option<u32>) -> Self {
+        self
+    }
+
+    fn get_numa_node(&self) -> Option<u32> {
+        None
+    }
+}
+
+// the static memory cannot be accessed by other processes
+impl<T: Send + Sync + Debug> AccessControlConfiguration for Configuration<T> {
+    fn access_control_list(self, _value: Option<AccessControlList>) -> Self {
+        self
+    }
+
+    fn get_access_control_list(&self) -> Option<&AccessControlList> {
+        None
+    }
+}
+
+impl<T: Send + Sync + Debug> NamedConceptConfiguration for Configuration<T> {
+    fn prefix(mut self, value: &FileName) -> Self {
+        self.prefix = *value;
+        self
+    }
+
+    fn get_prefix(&self) -> &FileName {
+        &self.prefix
+    }
+
+    fn suffix(mut self, value: &FileName) -> Self {
+        self.suffix = *value;
+        self
+    }
+
+    fn path_hint(mut self, value: &Path) -> Self {
+        self.path_hint = *value;
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path_hint
+    }
+
+    fn path_for(&self, value: &FileName) -> iceoryx2_bb_system_types::file_path::FilePath {
+        self.path_for_with_type(value)
+    }
+
+    fn extract_name_from_file(&self, value: &FileName) -> Option<FileName> {
+        self.extract_name_from_file_with_type(value)
+    }
+}
+
+#[derive(Debug)]
+pub struct Storage<T: Send + Sync + Debug + 'static> {
+    name: FileName,
+    index: usize,
+    data_ptr: *mut T,
+    has_ownership: IoxAtomicBool,
+    config: Configuration<T>,
+}
+
+unsafe impl<T: Send + Sync + Debug + 'static> Send for Storage<T> {}
+unsafe impl<T: Send + Sync + Debug + 'static> Sync for Storage<T> {}
+
+impl<T: Send + Sync + Debug + 'static> NamedConcept for Storage<T> {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl<T: Send + Sync + Debug + 'static> NamedConceptMgmt for Storage<T> {
+    type Configuration = Configuration<T>;
+
+    fn does_exist_cfg(
+        name: &FileName,
+        config: &Self::Configuration,
+    ) -> Result<bool, NamedConceptDoesExistError> {
+        let guard = REGISTRY.lock();
+        Ok(guard
+            .find(EntryKind::DynamicStorage, &config.path_for(name))
+            .is_some())
+    }
+
+    fn list_cfg(config: &Self::Configuration) -> Result<Vec<FileName>, NamedConceptListError> {
+        let guard = REGISTRY.lock();
+        Ok(guard
+            .list(EntryKind::DynamicStorage)
+            .filter_map(|path| config.extract_name_from_path(path))
+            .collect())
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptRemoveError> {
+        let mut guard = REGISTRY.lock();
+        let index = match guard.find(EntryKind::DynamicStorage, &cfg.path_for(name)) {
+            Some(index) => index,
+            None => return Ok(false),
+        };
+
+        core::ptr::drop_in_place(guard.entry(index).memory() as *mut T);
+        guard.remove(index);
+
+        Ok(true)
+    }
+
+    fn remove_path_hint(
+        _value: &Path,
+    ) -> Result<(), crate::named_concept::NamedConceptPathHintRemoveError> {
+        Ok(())
+    }
+}
+
+impl<T: Send + Sync + Debug + 'static> DynamicStorage<T> for Storage<T> {
+    type Builder<'builder> = Builder<'builder, T>;
+
+    fn does_support_persistency() -> bool {
+        true
+    }
+
+    fn acquire_ownership(&self) {
+        self.has_ownership.store(true, Ordering::Relaxed);
+    }
+
+    fn get(&self) -> &T {
+        unsafe { &*self.data_ptr }
+    }
+
+    fn has_ownership(&self) -> bool {
+        self.has_ownership.load(Ordering::Relaxed)
+    }
+
+    fn release_ownership(&self) {
+        self.has_ownership.store(false, Ordering::Relaxed)
+    }
+}
+
+impl<T: Send + Sync + Debug + 'static> Drop for Storage<T> {
+    fn drop(&mut self) {
+        if self.has_ownership() {
+            match unsafe { Self::remove_cfg(&self.name, &self.config) } {
+                Ok(false) | Err(_) => {
+                    fatal_panic!(from self, "This should never happen! Unable to remove dynamic storage");
+                }
+                Ok(_) => (),
+            }
+        }
+
+        REGISTRY.lock().release(self.index);
+    }
+}
+
+#[derive(Debug)]
+pub struct Builder<'builder, T: Send + Sync + Debug> {
+    name: FileName,
+    supplementary_size: usize,
+    has_ownership: bool,
+    config: Configuration<T>,
+    initializer: Initializer<'builder, T>,
+    _phantom_data: PhantomData<T>,
+}
+
+impl<T: Send + Sync + Debug + 'static> NamedConceptBuilder<Storage<T>> for Builder<'_, T> {
+    fn new(storage_name: &FileName) -> Self {
+        Self {
+            name: *storage_name,
+            has_ownership: true,
+            supplementary_size: 0,
+            config: Configuration::default(),
+            initializer: Initializer::new(|_, _| true),
+            _phantom_data: PhantomData,
+        }
+    }
+
+    fn config(mut self, config: &Configuration<T>) -> Self {
+        self.config = config.clone();
+        self
+    }
+}
+
+impl<T: Send + Sync + Debug + 'static> Builder<'_, T> {
+    fn open_impl(&self, guard: &mut RegistryGuard) -> Result<Storage<T>, DynamicStorageOpenError> {
+        let msg = "Failed to open dynamic storage";
+
+        let index = match guard.find(EntryKind::DynamicStorage, &self.config.path_for(&self.name)) {
+            Some(index) => index,
+            None => {
+                fail!(from self, with DynamicStorageOpenError::DoesNotExist,
+                    "{} since the storage does not exist.", msg);
+            }
+        };
+
+        guard.acquire(index);
+
+        Ok(Storage::<T> {
+            name: self.name,
+            index,
+            data_ptr: guard.entry(index).memory() as *mut T,
+            has_ownership: IoxAtomicBool::new(false),
+            config: self.config.clone(),
+        })
+    }
+
+    fn create_impl(
+        &mut self,
+        guard: &mut RegistryGuard,
+        initial_value: T,
+    ) -> Result<Storage<T>, DynamicStorageCreateError> {
+        let msg = "Failed to create dynamic storage";
+
+        let full_path = self.config.path_for(&self.name);
+        if guard.find(EntryKind::DynamicStorage, &full_path).is_some() {
+            fail!(from self, with DynamicStorageCreateError::AlreadyExists,
+                "{} since the storage does already exist.", msg);
+        }
+
+        let size = core::mem::size_of::<T>() + self.supplementary_size;
+        let layout = unsafe { Layout::from_size_align_unchecked(size, core::mem::align_of::<T>()) };
+        let index = match guard.insert(EntryKind::DynamicStorage, &full_path, false, layout) {
+            Ok(index) => index,
+            Err(RegistryError::OutOfEntries) => {
+                fail!(from self, with DynamicStorageCreateError::InternalError,
+                    "{} since the maximum number of {} static memory entries is exceeded.",
+                    msg, crate::static_memory::STATIC_MEMORY_MAX_ENTRIES);
+            }
+            Err(RegistryError::OutOfMemory) => {
+                fail!(from self, with DynamicStorageCreateError::InternalError,
+                    "{} since the static memory of {} bytes has not enough space left for {} bytes.",
+                    msg, crate::static_memory::STATIC_MEMORY_SIZE, size);
+            }
+        };
+
+        let value = guard.entry(index).memory() as *mut T;
+        unsafe { value.write(initial_value) };
+        let supplementary_start = (value as usize + core::mem::size_of::<T>()) as *mut u8;
+
+        let mut allocator = BumpAllocator::new(
+            unsafe { NonNull::new_unchecked(supplementary_start) },
+            self.supplementary_size,
+        );
+
+        if !self
+            .initializer
+            .call(unsafe { &mut *value }, &mut allocator)
+        {
+            guard.entry_mut(index).is_removed = true;
+            guard.release(index);
+            fail!(from self, with DynamicStorageCreateError::InitializationFailed,
+                "{} since the initialization of the underlying construct failed.", msg);
+        }
+
+        Ok(Storage::<T> {
+            name: self.name,
+            index,
+            data_ptr: value,
+            has_ownership: IoxAtomicBool::new(self.has_ownership),
+            config: self.config.clone(),
+        })
+    }
+}
+
+impl<'builder, T: Send + Sync + Debug + 'static> DynamicStorageBuilder<'builder, T, Storage<T>>
+    for Builder<'builder, T>
+{
+    fn has_ownership(mut self, value: bool) -> Self {
+        self.has_ownership = value;
+        self
+    }
+
+    fn initializer<F: FnMut(&mut T, &mut BumpAllocator) -> bool + 'builder>(
+        mut self,
+        value: F,
+    ) -> Self {
+        self.initializer = Initializer::new(value);
+        self
+    }
+
+    fn timeout(self, _value: Duration) -> Self {
+        self
+    }
+
+    fn supplementary_size(mut self, value: usize) -> Self {
+        self.supplementary_size = value;
+        self
+    }
+
+    fn open(self) -> Result<Storage<T>, DynamicStorageOpenError> {
+        self.open_impl(&mut REGISTRY.lock())
+    }
+
+    fn create(mut self, initial_value: T) -> Result<Storage<T>, DynamicStorageCreateError> {
+        self.create_impl(&mut REGISTRY.lock(), initial_value)
+    }
+
+    fn open_or_create(
+        mut self,
+        initial_value: T,
+    ) -> Result<Storage<T>, DynamicStorageOpenOrCreateError> {
+        let mut guard = REGISTRY.lock();
+
+        match self.open_impl(&mut guard) {
+            Ok(storage) => Ok(storage),
+            Err(DynamicStorageOpenError::DoesNotExist) => {
+                match self.create_impl(&mut guard, initial_value) {
+                    Ok(storage) => Ok(storage),
+                    Err(e) => Err(e.into()),
+                }
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -16,7 +16,7 @@ pub mod details {
     use iceoryx2_bb_log::{debug, fail};
     use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
     use iceoryx2_bb_system_types::{file_name::FileName, path::Path};
-    use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
+    use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64};
 
     use crate::{
         dynamic_storage::{
@@ -39,7 +39,7 @@ pub mod details {
     pub struct Management<Tracker: IdTracker, WaitMechanism: SignalMechanism> {
         id_tracker: Tracker,
         signal_mechanism: WaitMechanism,
-        reference_counter: IoxAtomicUsize,
+        reference_counter: IoxAtomicU64,
         has_listener: IoxAtomicBool,
     }
 
@@ -228,6 +228,10 @@ pub mod details {
         fn has_trigger_id_limit() -> bool {
             true
         }
+
+        fn default_trigger_id_max() -> TriggerId {
+            TRIGGER_ID_DEFAULT_MAX
+        }
     }
 
     #[derive(Debug)]
@@ -623,7 +627,7 @@ pub mod details {
                 .create(Management {
                     id_tracker: unsafe { Tracker::new_uninit(id_tracker_capacity) },
                     signal_mechanism: WaitMechanism::new(),
-                    reference_counter: IoxAtomicUsize::new(1),
+                    reference_counter: IoxAtomicU64::new(1),
                     has_listener: IoxAtomicBool::new(true),
                 }) {
                 Ok(storage) => Ok(Listener {
@@ -0,0 +1,449 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! An [`Event`] that transports the [`TriggerId`]s like the
+//! [`unix_datagram_socket`](crate::event::unix_datagram_socket) event, but the
+//! [`Listener`](crate::event::Listener) receives them with an [`IoUring`]. A batch of receive
+//! operations is always in flight, so that all [`TriggerId`]s that arrived in between are
+//! harvested from the completion queue without a system call per [`TriggerId`]. The
+//! [`Listener`] is signalled via an eventfd which is attached to the io_uring.
+//!
+//! The [`Notifier`] is identical to the one of the
+//! [`unix_datagram_socket`](crate::event::unix_datagram_socket) event, therefore both events
+//! can be mixed between processes.
+//!
+//! When io_uring is not available, for instance on older kernels, other platforms or when it
+//! is disabled by the system, the [`Listener`] falls back to the plain socket operations.
+
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+use core::time::Duration;
+use std::sync::{Mutex, MutexGuard};
+
+use crate::event::unix_datagram_socket as socket;
+pub use crate::event::*;
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::{
+    file_descriptor::{FileDescriptor, FileDescriptorBased},
+    file_descriptor_set::SynchronousMultiplexing,
+    io_uring::*,
+};
+pub use iceoryx2_bb_system_types::file_name::FileName;
+
+pub type Configuration = socket::Configuration;
+
+const MAX_BATCH_SIZE: usize = 512;
+const NUMBER_OF_RECEIVE_SLOTS: usize = 64;
+
+#[derive(Debug)]
+pub struct EventImpl {}
+
+impl NamedConceptMgmt for EventImpl {
+    type Configuration = Configuration;
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::static_storage::file::NamedConceptDoesExistError> {
+        socket::EventImpl::does_exist_cfg(name, cfg)
+    }
+
+    fn list_cfg(
+        cfg: &Self::Configuration,
+    ) -> Result<Vec<FileName>, crate::static_storage::file::NamedConceptListError> {
+        socket::EventImpl::list_cfg(cfg)
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::static_storage::file::NamedConceptRemoveError> {
+        socket::EventImpl::remove_cfg(name, cfg)
+    }
+
+    fn remove_path_hint(
+        value: &Path,
+    ) -> Result<(), crate::named_concept::NamedConceptPathHintRemoveError> {
+        socket::EventImpl::remove_path_hint(value)
+    }
+}
+
+impl crate::event::Event for EventImpl {
+    type Notifier = Notifier;
+    type Listener = Listener;
+    type NotifierBuilder = NotifierBuilder;
+    type ListenerBuilder = ListenerBuilder;
+}
+
+#[derive(Debug)]
+pub struct Notifier {
+    notifier: socket::Notifier,
+}
+
+impl NamedConcept for Notifier {
+    fn name(&self) -> &FileName {
+        self.notifier.name()
+    }
+}
+
+impl crate::event::Notifier for Notifier {
+    fn notify(&self, id: TriggerId) -> Result<(), NotifierNotifyError> {
+        self.notifier.notify(id)
+    }
+}
+
+#[derive(Debug)]
+pub struct NotifierBuilder {
+    builder: socket::NotifierBuilder,
+}
+
+impl NamedConceptBuilder<EventImpl> for NotifierBuilder {
+    fn new(name: &FileName) -> Self {
+        Self {
+            builder: <socket::NotifierBuilder as NamedConceptBuilder<socket::EventImpl>>::new(name),
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.builder = self.builder.config(config);
+        self
+    }
+}
+
+impl crate::event::NotifierBuilder<EventImpl> for NotifierBuilder {
+    fn timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+
+    fn open(self) -> Result<Notifier, NotifierCreateError> {
+        Ok(Notifier {
+            notifier: self.builder.open()?,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Ring {
+    // must be dropped first since the receive operations in flight write into the slots
+    io_uring: IoUring,
+    slots: Box<[TriggerId]>,
+    completed_slots: Vec<usize>,
+    pending: VecDeque<TriggerId>,
+}
+
+impl Ring {
+    fn create(origin: &str, socket: &FileDescriptor) -> Option<Ring> {
+        let io_uring = match IoUringBuilder::new()
+            .number_of_entries(NUMBER_OF_RECEIVE_SLOTS as u32)
+            .completion_event(true)
+            .create()
+        {
+            Ok(io_uring) => io_uring,
+            Err(e) => {
+                warn!(from origin,
+                    "Unable to create io_uring ({:?}), falling back to plain socket operations.", e);
+                return None;
+            }
+        };
+
+        let mut ring = Ring {
+            io_uring,
+            slots: vec![TriggerId::default(); NUMBER_OF_RECEIVE_SLOTS].into_boxed_slice(),
+            completed_slots: Vec::with_capacity(NUMBER_OF_RECEIVE_SLOTS),
+            pending: VecDeque::with_capacity(NUMBER_OF_RECEIVE_SLOTS),
+        };
+
+        for slot in 0..NUMBER_OF_RECEIVE_SLOTS {
+            if !ring.push_receive(socket, slot) {
+                warn!(from origin,
+                    "Unable to post all receive operations, falling back to plain socket operations.");
+                return None;
+            }
+        }
+
+        if let Err(e) = ring.io_uring.submit_and_wait(0, None) {
+            warn!(from origin,
+                "Unable to submit the receive operations ({:?}), falling back to plain socket operations.", e);
+            return None;
+        }
+
+        Some(ring)
+    }
+
+    fn push_receive(&mut self, socket: &FileDescriptor, slot: usize) -> bool {
+        unsafe {
+            self.io_uring
+                .push_receive(
+                    socket,
+                    (&mut self.slots[slot] as *mut TriggerId).cast(),
+                    core::mem::size_of::<TriggerId>(),
+                    slot as u64,
+                )
+                .is_ok()
+        }
+    }
+
+    fn harvest(&mut self, socket: &FileDescriptor) -> Result<(), IoUringWaitError> {
+        // the eventfd is reset before the completions are harvested, otherwise a completion
+        // that arrives in between would not signal the listener
+        self.io_uring.reset_completion_event();
+
+        let slots = &self.slots;
+        let completed_slots = &mut self.completed_slots;
+        let pending = &mut self.pending;
+        self.io_uring.harvest(|completion| {
+            let slot = completion.user_data as usize;
+            if completion.result == core::mem::size_of::<TriggerId>() as i32 {
+                pending.push_back(slots[slot]);
+            }
+            completed_slots.push(slot);
+        });
+
+        if !self.completed_slots.is_empty() {
+            let mut completed_slots = core::mem::take(&mut self.completed_slots);
+            for slot in completed_slots.drain(..) {
+                self.push_receive(socket, slot);
+            }
+            self.completed_slots = completed_slots;
+            self.io_uring.submit_and_wait(0, None)?;
+        }
+
+        // the eventfd is level-triggered from the perspective of the user, as long as
+        // harvested trigger ids are pending it stays signalled
+        if !self.pending.is_empty() {
+            self.io_uring.trigger_completion_event();
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct Listener {
+    // must be dropped before the socket it receives from
+    ring: Option<Mutex<Ring>>,
+    // non-owning handle of the eventfd of the io_uring, it is accessible without acquiring
+    // the ring
+    completion_event: Option<FileDescriptor>,
+    listener: socket::Listener,
+}
+
+impl FileDescriptorBased for Listener {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        match &self.completion_event {
+            Some(fd) => fd,
+            None => self.listener.file_descriptor(),
+        }
+    }
+}
+
+impl SynchronousMultiplexing for Listener {}
+
+impl NamedConcept for Listener {
+    fn name(&self) -> &FileName {
+        self.listener.name()
+    }
+}
+
+impl Listener {
+    fn lock(ring: &Mutex<Ring>) -> MutexGuard<'_, Ring> {
+        match ring.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    fn handle_wait_result(
+        &self,
+        result: Result<(), IoUringWaitError>,
+        msg: &str,
+    ) -> Result<(), ListenerWaitError> {
+        match result {
+            Ok(()) => Ok(()),
+            Err(IoUringWaitError::Interrupt) => {
+                fail!(from self, with ListenerWaitError::InterruptSignal,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with ListenerWaitError::InternalFailure,
+                    "{} due to an unknown failure ({:?}).", msg, v);
+            }
+        }
+    }
+
+    fn wait(
+        &self,
+        ring: &Mutex<Ring>,
+        msg: &str,
+        timeout: Option<Duration>,
+    ) -> Result<Option<TriggerId>, ListenerWaitError> {
+        let mut ring = Self::lock(ring);
+        if let Some(id) = ring.pending.pop_front() {
+            return Ok(Some(id));
+        }
+
+        loop {
+            self.handle_wait_result(ring.io_uring.submit_and_wait(1, timeout), msg)?;
+            self.handle_wait_result(ring.harvest(self.listener.file_descriptor()), msg)?;
+            if let Some(id) = ring.pending.pop_front() {
+                return Ok(Some(id));
+            }
+
+            if timeout.is_some() {
+                return Ok(None);
+            }
+        }
+    }
+}
+
+impl crate::event::Listener for Listener {
+    fn try_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+        let ring = match &self.ring {
+            Some(ring) => ring,
+            None => return self.listener.try_wait_one(),
+        };
+
+        let msg = "Unable to try wait for signal on event::io_uring::Listener";
+        let mut ring = Self::lock(ring);
+        if ring.pending.is_empty() {
+            self.handle_wait_result(ring.harvest(self.listener.file_descriptor()), msg)?;
+        }
+
+        if let Some(id) = ring.pending.pop_front() {
+            return Ok(Some(id));
+        }
+
+        // the kernel completes the receive operations asynchronously, a trigger id that
+        // already arrived is either still in the socket or was completed in between
+        if let Some(id) = self.listener.try_wait_one()? {
+            return Ok(Some(id));
+        }
+
+        self.handle_wait_result(ring.harvest(self.listener.file_descriptor()), msg)?;
+        Ok(ring.pending.pop_front())
+    }
+
+    fn timed_wait_one(&self, timeout: Duration) -> Result<Option<TriggerId>, ListenerWaitError> {
+        match &self.ring {
+            Some(ring) => self.wait(
+                ring,
+                &format!(
+                    "Unable to wait for signal with timeout {:?} on event::io_uring::Listener",
+                    timeout
+                ),
+                Some(timeout),
+            ),
+            None => self.listener.timed_wait_one(timeout),
+        }
+    }
+
+    fn blocking_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+        match &self.ring {
+            Some(ring) => self.wait(
+                ring,
+                "Unable to blocking wait for signal on event::io_uring::Listener",
+                None,
+            ),
+            None => self.listener.blocking_wait_one(),
+        }
+    }
+
+    fn try_wait_all<F: FnMut(TriggerId)>(&self, mut callback: F) -> Result<(), ListenerWaitError> {
+        let mut counter = 0;
+        while let Some(id) = self.try_wait_one()? {
+            callback(id);
+
+            counter += 1;
+            if counter == MAX_BATCH_SIZE {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn timed_wait_all<F: FnMut(TriggerId)>(
+        &self,
+        mut callback: F,
+        timeout: Duration,
+    ) -> Result<(), ListenerWaitError> {
+        if let Some(id) = self.timed_wait_one(timeout)? {
+            callback(id);
+        }
+        self.try_wait_all(callback)
+    }
+
+    fn blocking_wait_all<F: FnMut(TriggerId)>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        if let Some(id) = self.blocking_wait_one()? {
+            callback(id);
+        }
+        self.try_wait_all(callback)
+    }
+}
+
+#[derive(Debug)]
+pub struct ListenerBuilder {
+    builder: socket::ListenerBuilder,
+}
+
+impl NamedConceptBuilder<EventImpl> for ListenerBuilder {
+    fn new(name: &FileName) -> Self {
+        Self {
+            builder: <socket::ListenerBuilder as NamedConceptBuilder<socket::EventImpl>>::new(name),
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.builder = self.builder.config(config);
+        self
+    }
+}
+
+impl crate::event::ListenerBuilder<EventImpl> for ListenerBuilder {
+    fn trigger_id_max(mut self, id: TriggerId) -> Self {
+        self.builder = self.builder.trigger_id_max(id);
+        self
+    }
+
+    fn create(self) -> Result<Listener, ListenerCreateError> {
+        let origin = format!("{:?}", self);
+        let listener = fail!(from origin, when self.builder.create(),
+                "Failed to create event::io_uring::Listener since the underlying socket could not be created.");
+
+        let ring = match Ring::create(&origin, listener.file_descriptor()) {
+            Some(ring) => ring,
+            None => {
+                return Ok(Listener {
+                    ring: None,
+                    completion_event: None,
+                    listener,
+                })
+            }
+        };
+
+        let completion_event = ring
+            .io_uring
+            .completion_event()
+            .and_then(|fd| FileDescriptor::non_owning_new(unsafe { fd.native_handle() }));
+
+        Ok(Listener {
+            ring: Some(Mutex::new(ring)),
+            completion_event,
+            listener,
+        })
+    }
+}
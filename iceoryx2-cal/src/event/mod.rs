@@ -12,9 +12,11 @@
 
 pub mod common;
 pub mod id_tracker;
+pub mod io_uring;
 pub mod process_local_socketpair;
 pub mod sem_bitset_posix_shared_memory;
 pub mod sem_bitset_process_local;
+pub mod sem_bitset_static_memory;
 pub mod signal_mechanism;
 pub mod unix_datagram_socket;
 
@@ -89,16 +91,19 @@ impl core::fmt::Display for ListenerCreateError {
 
 impl core::error::Error for ListenerCreateError {}
 
+/// The id that is transported from a [`Notifier`] to a [`Listener`]. It is always stored
+/// with 64-bit so that 32-bit and 64-bit processes can exchange it.
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct TriggerId(usize);
+#[repr(transparent)]
+pub struct TriggerId(u64);
 
 impl TriggerId {
     pub const fn new(value: usize) -> Self {
-        Self(value)
+        Self(value as u64)
     }
 
     pub const fn as_value(&self) -> usize {
-        self.0
+        self.0 as usize
     }
 }
 
@@ -147,4 +152,10 @@ pub trait Event: Sized + NamedConceptMgmt + Debug {
     fn has_trigger_id_limit() -> bool {
         false
     }
+
+    /// The greatest [`TriggerId`] that shall be used when no explicit maximum is required.
+    /// Implementations whose resources grow with the maximum provide a smaller value.
+    fn default_trigger_id_max() -> TriggerId {
+        TriggerId::new(usize::MAX)
+    }
 }
@@ -29,6 +29,7 @@ use iceoryx2_bb_container::semantic_string::{SemanticString, SemanticStringError
 use iceoryx2_bb_system_types::base64url::Base64Url;
 
 pub mod sha1;
+pub mod sip_hash;
 
 /// Represents the value of the hash.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -0,0 +1,125 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Creates a keyed SipHash-2-4 [`Hash`] with a 128 bit output. When created with
+//! [`Hash::new()`] the all-zero key is used. A secret key, see [`SipHash::new_with_key()`],
+//! prevents that a third party can construct inputs that collide on purpose.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_cal::hash::*;
+//! use iceoryx2_cal::hash::sip_hash::SipHash;
+//!
+//! let key = [0x0706050403020100, 0x0f0e0d0c0b0a0908];
+//! let hash = SipHash::new_with_key(&key, b"");
+//!
+//! let value: String = hash.value().into();
+//! assert_eq!(value, "a3817f04ba25a8e66df67214c7550293");
+//! ```
+
+use crate::hash::*;
+
+const NUMBER_OF_COMPRESSION_ROUNDS: usize = 2;
+const NUMBER_OF_FINALIZATION_ROUNDS: usize = 4;
+
+pub struct SipHash {
+    hash: [u8; 16],
+}
+
+struct State {
+    v: [u64; 4],
+}
+
+impl State {
+    fn new(key: &[u64; 2]) -> Self {
+        Self {
+            v: [
+                key[0] ^ 0x736f6d6570736575,
+                key[1] ^ 0x646f72616e646f6d ^ 0xee,
+                key[0] ^ 0x6c7967656e657261,
+                key[1] ^ 0x7465646279746573,
+            ],
+        }
+    }
+
+    fn round(&mut self) {
+        let v = &mut self.v;
+        v[0] = v[0].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(13) ^ v[0];
+        v[0] = v[0].rotate_left(32);
+        v[2] = v[2].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(16) ^ v[2];
+        v[0] = v[0].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(21) ^ v[0];
+        v[2] = v[2].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(17) ^ v[2];
+        v[2] = v[2].rotate_left(32);
+    }
+
+    fn compress(&mut self, block: u64) {
+        self.v[3] ^= block;
+        for _ in 0..NUMBER_OF_COMPRESSION_ROUNDS {
+            self.round();
+        }
+        self.v[0] ^= block;
+    }
+
+    fn finalize(&mut self) -> u64 {
+        for _ in 0..NUMBER_OF_FINALIZATION_ROUNDS {
+            self.round();
+        }
+        self.v[0] ^ self.v[1] ^ self.v[2] ^ self.v[3]
+    }
+}
+
+impl SipHash {
+    /// Creates a new [`SipHash`] from `bytes` with the provided secret key.
+    pub fn new_with_key(key: &[u64; 2], bytes: &[u8]) -> Self {
+        let mut state = State::new(key);
+
+        let mut blocks = bytes.chunks_exact(8);
+        for block in &mut blocks {
+            let mut buffer = [0u8; 8];
+            buffer.copy_from_slice(block);
+            state.compress(u64::from_le_bytes(buffer));
+        }
+
+        let mut buffer = [0u8; 8];
+        let remainder = blocks.remainder();
+        buffer[..remainder.len()].copy_from_slice(remainder);
+        state.compress(u64::from_le_bytes(buffer) | ((bytes.len() as u64 & 0xff) << 56));
+
+        state.v[2] ^= 0xee;
+        let lower = state.finalize();
+        state.v[1] ^= 0xdd;
+        let upper = state.finalize();
+
+        let mut hash = [0u8; 16];
+        hash[..8].copy_from_slice(&lower.to_le_bytes());
+        hash[8..].copy_from_slice(&upper.to_le_bytes());
+
+        Self { hash }
+    }
+}
+
+impl Hash for SipHash {
+    fn new(bytes: &[u8]) -> Self {
+        Self::new_with_key(&[0, 0], bytes)
+    }
+
+    fn value(&self) -> HashValue {
+        let hex: String = self.hash.iter().map(|b| format!("{:02x}", b)).collect();
+        // a hex representation is always a valid Base64Url representation
+        HashValue::new(hex.as_bytes()).unwrap()
+    }
+}
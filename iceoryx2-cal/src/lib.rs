@@ -26,6 +26,7 @@ pub mod serialize;
 pub mod shared_memory;
 pub mod shared_memory_directory;
 pub mod shm_allocator;
+pub mod static_memory;
 pub mod static_storage;
 pub mod zero_copy_connection;
 
@@ -71,6 +71,8 @@
 
 use core::fmt::Debug;
 
+use iceoryx2_bb_posix::process_file_descriptor::ProcessFileDescriptor;
+
 pub use iceoryx2_bb_container::semantic_string::SemanticString;
 pub use iceoryx2_bb_system_types::file_name::FileName;
 
@@ -80,7 +82,9 @@ pub use crate::{
 };
 
 pub mod file_lock;
+pub mod pidfd;
 pub mod process_local;
+pub mod robust_mutex;
 #[doc(hidden)]
 pub mod testing;
 
@@ -146,6 +150,13 @@ pub trait MonitoringMonitor: NamedConcept {
     /// Returns the current [`State`] of the monitored process. On failure it returns
     /// [`MonitoringStateError`].
     fn state(&self) -> Result<State, MonitoringStateError>;
+
+    /// Returns the [`ProcessFileDescriptor`] of the monitored process that becomes readable as
+    /// soon as the process terminates. Returns [`None`] when the termination can only be
+    /// detected by acquiring the [`State`] with [`MonitoringMonitor::state()`].
+    fn process_file_descriptor(&self) -> Option<&ProcessFileDescriptor> {
+        None
+    }
 }
 
 /// Creates either a [`MonitoringToken`] or instantiates a [`MonitoringMonitor`] that can monitor
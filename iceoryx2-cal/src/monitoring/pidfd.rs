@@ -0,0 +1,193 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [`Monitoring`] based on a Linux `pidfd` of the process that owns the [`MonitoringToken`].
+//! The [`MonitoringMonitor`] acquires a
+//! [`ProcessFileDescriptor`] of the owner when it is created. It becomes readable as soon as
+//! the owner terminates, so that the death of a process can be awaited with a
+//! [`FileDescriptorSet`](iceoryx2_bb_posix::file_descriptor_set::FileDescriptorSet), see
+//! [`MonitoringMonitor::process_file_descriptor()`], instead of being detected by periodically
+//! acquiring the [`State`].
+//!
+//! The [`MonitoringToken`] and the [`MonitoringCleaner`] are the ones of the
+//! [`RobustMutexMonitoring`]. Whenever no `pidfd` can be acquired, since the platform does not
+//! support it or the owner lives in another pid namespace, the [`Monitor`] falls back to the
+//! robust mutex.
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::process_file_descriptor::{
+    ProcessFileDescriptor, ProcessFileDescriptorStateError,
+};
+use iceoryx2_bb_system_types::{file_name::FileName, path::Path};
+
+use crate::{
+    monitoring::{
+        robust_mutex::{self, RobustMutexMonitoring},
+        MonitoringCreateCleanerError, MonitoringCreateMonitorError, State,
+    },
+    named_concept::{
+        NamedConcept, NamedConceptBuilder, NamedConceptDoesExistError, NamedConceptListError,
+        NamedConceptMgmt, NamedConceptRemoveError,
+    },
+};
+
+use super::{
+    Monitoring, MonitoringBuilder, MonitoringCreateTokenError, MonitoringMonitor,
+    MonitoringStateError,
+};
+
+pub type Configuration = robust_mutex::Configuration;
+pub type Token = robust_mutex::Token;
+pub type Cleaner = robust_mutex::Cleaner;
+
+#[derive(Debug)]
+pub struct PidFdMonitoring {}
+
+impl NamedConceptMgmt for PidFdMonitoring {
+    type Configuration = Configuration;
+
+    fn list_cfg(cfg: &Self::Configuration) -> Result<Vec<FileName>, NamedConceptListError> {
+        RobustMutexMonitoring::list_cfg(cfg)
+    }
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptDoesExistError> {
+        RobustMutexMonitoring::does_exist_cfg(name, cfg)
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptRemoveError> {
+        RobustMutexMonitoring::remove_cfg(name, cfg)
+    }
+
+    fn remove_path_hint(
+        value: &Path,
+    ) -> Result<(), crate::named_concept::NamedConceptPathHintRemoveError> {
+        RobustMutexMonitoring::remove_path_hint(value)
+    }
+}
+
+impl Monitoring for PidFdMonitoring {
+    type Token = Token;
+    type Monitor = Monitor;
+    type Builder = Builder;
+    type Cleaner = Cleaner;
+}
+
+#[derive(Debug)]
+pub struct Monitor {
+    monitor: robust_mutex::Monitor,
+    process: Option<ProcessFileDescriptor>,
+    config: Configuration,
+    name: FileName,
+}
+
+impl NamedConcept for Monitor {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl MonitoringMonitor for Monitor {
+    fn state(&self) -> Result<State, MonitoringStateError> {
+        let msg = "Unable to acquire monitor state";
+
+        let process = match &self.process {
+            Some(process) => process,
+            None => return self.monitor.state(),
+        };
+
+        match process.has_terminated() {
+            // the token may have been released or handed over to a thread that died
+            Ok(false) => self.monitor.state(),
+            Ok(true) => match PidFdMonitoring::does_exist_cfg(&self.name, &self.config) {
+                Ok(true) => Ok(State::Dead),
+                Ok(false) => Ok(State::DoesNotExist),
+                Err(e) => {
+                    fail!(from self, with MonitoringStateError::InternalError,
+                        "{} since the existance of the underlying shared state could not be verified ({:?}).", msg, e);
+                }
+            },
+            Err(ProcessFileDescriptorStateError::Interrupt) => {
+                fail!(from self, with MonitoringStateError::Interrupt,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with MonitoringStateError::InternalError,
+                    "{} since the state of the process could not be acquired ({:?}).", msg, e);
+            }
+        }
+    }
+
+    // The process file descriptor of the owner of the MonitoringToken when the Monitor was
+    // created, None when the Monitor falls back to the robust mutex.
+    fn process_file_descriptor(&self) -> Option<&ProcessFileDescriptor> {
+        self.process.as_ref()
+    }
+}
+
+#[derive(Debug)]
+pub struct Builder {
+    name: FileName,
+    config: Configuration,
+}
+
+impl Builder {
+    fn robust_mutex_builder(&self) -> robust_mutex::Builder {
+        robust_mutex::Builder::new(&self.name).config(&self.config)
+    }
+}
+
+impl NamedConceptBuilder<PidFdMonitoring> for Builder {
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            config: Configuration::default(),
+        }
+    }
+
+    fn config(mut self, config: &<PidFdMonitoring as NamedConceptMgmt>::Configuration) -> Self {
+        self.config = *config;
+        self
+    }
+}
+
+impl MonitoringBuilder<PidFdMonitoring> for Builder {
+    fn token(self) -> Result<Token, MonitoringCreateTokenError> {
+        self.robust_mutex_builder().token()
+    }
+
+    fn monitor(self) -> Result<Monitor, MonitoringCreateMonitorError> {
+        let monitor = self.robust_mutex_builder().monitor()?;
+
+        // the process id may already be reused when the owner is dead, the process file
+        // descriptor refers to the owner only when it is still alive afterwards
+        let process = robust_mutex::owner_process_id(&self.name, &self.config)
+            .and_then(|pid| ProcessFileDescriptor::new(pid).ok())
+            .filter(|_| monitor.state() == Ok(State::Alive));
+
+        Ok(Monitor {
+            monitor,
+            process,
+            config: self.config,
+            name: self.name,
+        })
+    }
+
+    fn cleaner(self) -> Result<Cleaner, MonitoringCreateCleanerError> {
+        self.robust_mutex_builder().cleaner()
+    }
+}
@@ -0,0 +1,529 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [`Monitoring`] based on the ownership of a robust inter-process [`Mutex`] that is stored in
+//! shared memory. The [`MonitoringToken`] owns the [`Mutex`] as long as it is in scope. When the
+//! owning process dies the operating system marks the [`Mutex`] as inconsistent (`EOWNERDEAD`)
+//! and the next [`MonitoringMonitor`] or [`MonitoringCleaner`] detects the process as
+//! [`State::Dead`].
+//!
+//! In contrast to [`FileLockMonitoring`](crate::monitoring::file_lock::FileLockMonitoring) it
+//! does not rely on process ids, process start times or file locks and is therefore suited for
+//! environments like containers where those may be unreliable.
+//!
+//! The [`Mutex`] is owned by a background thread of the [`MonitoringToken`], so that the
+//! [`MonitoringToken`] can be moved and dropped on any thread without affecting the
+//! detection.
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use core::sync::atomic::Ordering;
+use std::sync::mpsc;
+use std::thread::JoinHandle;
+
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::mutex::{
+    Handle, IpcCapable, Mutex, MutexBuilder, MutexHandle, MutexLockError,
+    MutexThreadTerminationBehavior,
+};
+use iceoryx2_bb_posix::process::{Process, ProcessId};
+use iceoryx2_bb_posix::process_file_descriptor::pid_namespace_id;
+use iceoryx2_bb_system_types::{file_name::FileName, path::Path};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+
+use crate::{
+    dynamic_storage::{
+        posix_shared_memory, DynamicStorage, DynamicStorageBuilder, DynamicStorageCreateError,
+        DynamicStorageOpenError,
+    },
+    monitoring::{MonitoringCreateCleanerError, MonitoringCreateMonitorError, State},
+    named_concept::{
+        NamedConcept, NamedConceptBuilder, NamedConceptConfiguration, NamedConceptDoesExistError,
+        NamedConceptListError, NamedConceptMgmt, NamedConceptRemoveError,
+    },
+};
+
+use super::{
+    testing::__InternalMonitoringTokenTestable, Monitoring, MonitoringBuilder, MonitoringCleaner,
+    MonitoringCreateTokenError, MonitoringMonitor, MonitoringStateError, MonitoringToken,
+};
+
+type SharedState = posix_shared_memory::Storage<MonitoringState>;
+
+#[derive(Debug)]
+#[repr(C)]
+struct MonitoringState {
+    owner: MutexHandle<()>,
+    owner_died: IoxAtomicBool,
+    has_cleaner: IoxAtomicBool,
+    owner_pid: i32,
+    owner_pid_namespace: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OwnerState {
+    Alive,
+    Dead,
+    Released,
+}
+
+impl MonitoringState {
+    fn new() -> Self {
+        Self {
+            owner: MutexHandle::new(),
+            owner_died: IoxAtomicBool::new(false),
+            has_cleaner: IoxAtomicBool::new(false),
+            owner_pid: 0,
+            owner_pid_namespace: 0,
+        }
+    }
+
+    fn init(&mut self, _allocator: &mut BumpAllocator) -> bool {
+        self.owner_pid = Process::from_self().id().value();
+        self.owner_pid_namespace = pid_namespace_id().unwrap_or(0);
+
+        MutexBuilder::new()
+            .is_interprocess_capable(true)
+            .thread_termination_behavior(MutexThreadTerminationBehavior::ReleaseWhenLocked)
+            .create((), &self.owner)
+            .is_ok()
+    }
+
+    fn owner(&self) -> Mutex<'_, ()> {
+        unsafe { Mutex::from_ipc_handle(&self.owner) }
+    }
+
+    fn owner_state(&self) -> Result<OwnerState, MonitoringStateError> {
+        if self.owner_died.load(Ordering::Relaxed) {
+            return Ok(OwnerState::Dead);
+        }
+
+        match self.owner().try_lock() {
+            // another monitor may hold the lock right now since it detected the dead owner
+            Ok(None) => {
+                if self.owner_died.load(Ordering::Relaxed) {
+                    Ok(OwnerState::Dead)
+                } else {
+                    Ok(OwnerState::Alive)
+                }
+            }
+            Ok(Some(_)) => Ok(OwnerState::Released),
+            Err(MutexLockError::LockAcquiredButOwnerDied(_guard)) => {
+                // the mutex is never made consistent, every following lock attempt results in
+                // MutexLockError::UnrecoverableState
+                self.owner_died.store(true, Ordering::Relaxed);
+                Ok(OwnerState::Dead)
+            }
+            Err(MutexLockError::UnrecoverableState) => {
+                self.owner_died.store(true, Ordering::Relaxed);
+                Ok(OwnerState::Dead)
+            }
+            Err(e) => {
+                fail!(from self, with MonitoringStateError::InternalError,
+                    "Unable to acquire the state of the owner due to an internal failure ({:?}).", e);
+            }
+        }
+    }
+}
+
+fn open_shared_state(
+    name: &FileName,
+    config: &Configuration,
+) -> Result<SharedState, DynamicStorageOpenError> {
+    <SharedState as DynamicStorage<MonitoringState>>::Builder::new(name)
+        .config(&config.convert())
+        .open()
+}
+
+// Returns the process id of the owner when the owner is part of the same pid namespace.
+pub(crate) fn owner_process_id(name: &FileName, config: &Configuration) -> Option<ProcessId> {
+    let shared_state = open_shared_state(name, config).ok()?;
+    let state = shared_state.get();
+
+    match pid_namespace_id() {
+        Some(pid_namespace) if pid_namespace == state.owner_pid_namespace => {
+            Some(ProcessId::new(state.owner_pid as _))
+        }
+        _ => None,
+    }
+}
+
+#[derive(Debug)]
+pub struct RobustMutexMonitoring {}
+
+impl NamedConceptMgmt for RobustMutexMonitoring {
+    type Configuration = Configuration;
+
+    fn list_cfg(cfg: &Self::Configuration) -> Result<Vec<FileName>, NamedConceptListError> {
+        SharedState::list_cfg(&cfg.convert())
+    }
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptDoesExistError> {
+        SharedState::does_exist_cfg(name, &cfg.convert())
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptRemoveError> {
+        SharedState::remove_cfg(name, &cfg.convert())
+    }
+
+    fn remove_path_hint(
+        value: &Path,
+    ) -> Result<(), crate::named_concept::NamedConceptPathHintRemoveError> {
+        SharedState::remove_path_hint(value)
+    }
+}
+
+impl crate::monitoring::Monitoring for RobustMutexMonitoring {
+    type Token = Token;
+    type Monitor = Monitor;
+    type Builder = Builder;
+    type Cleaner = Cleaner;
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Configuration {
+    suffix: FileName,
+    prefix: FileName,
+    path_hint: Path,
+}
+
+impl Configuration {
+    fn convert(&self) -> <SharedState as NamedConceptMgmt>::Configuration {
+        <SharedState as NamedConceptMgmt>::Configuration::default()
+            .prefix(&self.prefix)
+            .suffix(&self.suffix)
+            .path_hint(&self.path_hint)
+    }
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            suffix: RobustMutexMonitoring::default_suffix(),
+            prefix: RobustMutexMonitoring::default_prefix(),
+            path_hint: RobustMutexMonitoring::default_path_hint(),
+        }
+    }
+}
+
+impl NamedConceptConfiguration for Configuration {
+    fn prefix(mut self, value: &FileName) -> Self {
+        self.prefix = *value;
+        self
+    }
+
+    fn get_prefix(&self) -> &FileName {
+        &self.prefix
+    }
+
+    fn suffix(mut self, value: &FileName) -> Self {
+        self.suffix = *value;
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn path_hint(mut self, value: &Path) -> Self {
+        self.path_hint = *value;
+        self
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path_hint
+    }
+}
+
+#[derive(Debug)]
+pub struct Cleaner {
+    shared_state: SharedState,
+    is_abandoned: bool,
+    name: FileName,
+}
+
+impl NamedConcept for Cleaner {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl MonitoringCleaner for Cleaner {
+    fn abandon(mut self) {
+        self.shared_state
+            .get()
+            .has_cleaner
+            .store(false, Ordering::Relaxed);
+        self.is_abandoned = true;
+    }
+}
+
+impl Drop for Cleaner {
+    fn drop(&mut self) {
+        if !self.is_abandoned {
+            self.shared_state.acquire_ownership();
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OwnerCommand {
+    Release,
+    StagedDeath,
+}
+
+#[derive(Debug)]
+pub struct Token {
+    shared_state: Arc<SharedState>,
+    owner_commands: mpsc::Sender<OwnerCommand>,
+    owner_thread: Option<JoinHandle<()>>,
+    name: FileName,
+}
+
+impl Token {
+    fn own(
+        shared_state: &SharedState,
+        has_acquired_ownership: mpsc::Sender<bool>,
+        owner_commands: mpsc::Receiver<OwnerCommand>,
+    ) {
+        let owner = shared_state.get().owner();
+        let guard = match owner.try_lock() {
+            Ok(Some(guard)) => guard,
+            Ok(None) | Err(_) => {
+                let _ = has_acquired_ownership.send(false);
+                return;
+            }
+        };
+        let _ = has_acquired_ownership.send(true);
+
+        match owner_commands.recv() {
+            // the thread terminates while owning the mutex, exactly like a crashed process
+            Ok(OwnerCommand::StagedDeath) => core::mem::forget(guard),
+            Ok(OwnerCommand::Release) | Err(_) => drop(guard),
+        }
+    }
+
+    fn stop_owner_thread(&mut self, command: OwnerCommand) {
+        let _ = self.owner_commands.send(command);
+        if let Some(owner_thread) = self.owner_thread.take() {
+            if owner_thread.join().is_err() {
+                warn!(from self, "The owner thread of the robust mutex terminated with a panic.");
+            }
+        }
+    }
+}
+
+impl Drop for Token {
+    fn drop(&mut self) {
+        self.stop_owner_thread(OwnerCommand::Release);
+    }
+}
+
+impl NamedConcept for Token {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl MonitoringToken for Token {}
+
+impl __InternalMonitoringTokenTestable for Token {
+    fn staged_death(mut self) {
+        self.shared_state.release_ownership();
+        self.stop_owner_thread(OwnerCommand::StagedDeath);
+    }
+}
+
+#[derive(Debug)]
+pub struct Monitor {
+    config: Configuration,
+    name: FileName,
+}
+
+impl NamedConcept for Monitor {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl MonitoringMonitor for Monitor {
+    fn state(&self) -> Result<State, MonitoringStateError> {
+        let msg = "Unable to acquire monitor state";
+
+        let shared_state = match open_shared_state(&self.name, &self.config) {
+            Ok(shared_state) => shared_state,
+            Err(DynamicStorageOpenError::DoesNotExist)
+            | Err(DynamicStorageOpenError::InitializationNotYetFinalized) => {
+                return Ok(State::DoesNotExist)
+            }
+            Err(e) => {
+                fail!(from self, with MonitoringStateError::InternalError,
+                    "{} since the underlying shared state could not be opened ({:?}).", msg, e);
+            }
+        };
+
+        match fail!(from self, when shared_state.get().owner_state(),
+                "{} since the state of the owner could not be acquired.", msg)
+        {
+            OwnerState::Alive => Ok(State::Alive),
+            OwnerState::Dead => Ok(State::Dead),
+            OwnerState::Released => Ok(State::DoesNotExist),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Builder {
+    name: FileName,
+    config: Configuration,
+}
+
+impl NamedConceptBuilder<RobustMutexMonitoring> for Builder {
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            config: Configuration::default(),
+        }
+    }
+
+    fn config(
+        mut self,
+        config: &<RobustMutexMonitoring as NamedConceptMgmt>::Configuration,
+    ) -> Self {
+        self.config = *config;
+        self
+    }
+}
+
+impl MonitoringBuilder<RobustMutexMonitoring> for Builder {
+    fn token(self) -> Result<Token, MonitoringCreateTokenError> {
+        let msg = "Unable to create RobustMutexMonitoring token";
+        let shared_state =
+            match <SharedState as DynamicStorage<MonitoringState>>::Builder::new(&self.name)
+                .config(&self.config.convert())
+                .initializer(MonitoringState::init)
+                .has_ownership(true)
+                .create(MonitoringState::new())
+            {
+                Ok(shared_state) => Arc::new(shared_state),
+                Err(DynamicStorageCreateError::AlreadyExists) => {
+                    fail!(from self, with MonitoringCreateTokenError::AlreadyExists,
+                    "{} since it already exists.", msg);
+                }
+                Err(DynamicStorageCreateError::InsufficientPermissions) => {
+                    fail!(from self, with MonitoringCreateTokenError::InsufficientPermissions,
+                    "{} due to insufficient permissions.", msg);
+                }
+                Err(e) => {
+                    fail!(from self, with MonitoringCreateTokenError::InternalError,
+                    "{} due to an internal failure ({:?}).", msg, e);
+                }
+            };
+
+        let (ownership_sender, ownership_receiver) = mpsc::channel();
+        let (owner_commands, owner_command_receiver) = mpsc::channel();
+        let owner_shared_state = shared_state.clone();
+        let owner_thread = match std::thread::Builder::new()
+            .name(format!("monitor-{}", self.name))
+            .spawn(move || {
+                Token::own(
+                    &owner_shared_state,
+                    ownership_sender,
+                    owner_command_receiver,
+                )
+            }) {
+            Ok(owner_thread) => owner_thread,
+            Err(e) => {
+                fail!(from self, with MonitoringCreateTokenError::InternalError,
+                    "{} since the owner thread could not be started ({:?}).", msg, e);
+            }
+        };
+
+        let token = Token {
+            shared_state,
+            owner_commands,
+            owner_thread: Some(owner_thread),
+            name: self.name,
+        };
+
+        if ownership_receiver.recv() != Ok(true) {
+            fail!(from self, with MonitoringCreateTokenError::InternalError,
+                "{} since the robust mutex could not be acquired.", msg);
+        }
+
+        Ok(token)
+    }
+
+    fn monitor(self) -> Result<Monitor, MonitoringCreateMonitorError> {
+        Ok(Monitor {
+            config: self.config,
+            name: self.name,
+        })
+    }
+
+    fn cleaner(self) -> Result<Cleaner, MonitoringCreateCleanerError> {
+        let msg = "Unable to create RobustMutexMonitoring cleaner";
+
+        let shared_state = match open_shared_state(&self.name, &self.config) {
+            Ok(shared_state) => shared_state,
+            Err(DynamicStorageOpenError::DoesNotExist)
+            | Err(DynamicStorageOpenError::InitializationNotYetFinalized) => {
+                fail!(from self, with MonitoringCreateCleanerError::DoesNotExist,
+                    "{} since it does not exist.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with MonitoringCreateCleanerError::InternalError,
+                    "{} since the underlying shared state could not be opened ({:?}).", msg, e);
+            }
+        };
+
+        match shared_state.get().owner_state() {
+            Ok(OwnerState::Dead) => (),
+            Ok(OwnerState::Alive) => {
+                fail!(from self, with MonitoringCreateCleanerError::InstanceStillAlive,
+                    "{} since the instance is still alive.", msg);
+            }
+            Ok(OwnerState::Released) => {
+                fail!(from self, with MonitoringCreateCleanerError::DoesNotExist,
+                    "{} since the instance is shutting down.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with MonitoringCreateCleanerError::InternalError,
+                    "{} due to an internal failure ({:?}).", msg, e);
+            }
+        }
+
+        if shared_state
+            .get()
+            .has_cleaner
+            .compare_exchange(false, true, Ordering::Relaxed, Ordering::Relaxed)
+            .is_err()
+        {
+            fail!(from self, with MonitoringCreateCleanerError::AlreadyOwnedByAnotherInstance,
+                "{} since another instance already acquired the cleaner.", msg);
+        }
+
+        Ok(Cleaner {
+            shared_state,
+            is_abandoned: false,
+            name: self.name,
+        })
+    }
+}
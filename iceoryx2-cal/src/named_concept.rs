@@ -14,6 +14,7 @@ use core::fmt::Debug;
 
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_log::{fail, fatal_panic};
+pub use iceoryx2_bb_posix::access_control_list::AccessControlList;
 use iceoryx2_bb_posix::directory::{Directory, DirectoryRemoveError};
 pub use iceoryx2_bb_system_types::file_name::FileName;
 pub use iceoryx2_bb_system_types::file_path::FilePath;
@@ -33,6 +34,12 @@ pub enum NamedConceptRemoveError {
     InternalError,
 }
 
+impl core::fmt::Display for NamedConceptRemoveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "NamedConceptRemoveError::{:?}", self)
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum NamedConceptListError {
     InsufficientPermissions,
@@ -117,6 +124,18 @@ pub trait NamedConceptConfiguration: Default + Clone + Debug + Send {
     }
 }
 
+/// Configures the [`AccessControlList`] that restricts which users and groups are allowed to
+/// access the underlying resources of a [`NamedConcept`].
+pub trait AccessControlConfiguration: NamedConceptConfiguration {
+    /// Applies the [`AccessControlList`] to the underlying resources when they are created.
+    /// Implementations whose resources cannot be accessed by other processes ignore the
+    /// setting.
+    fn access_control_list(self, value: Option<AccessControlList>) -> Self;
+
+    /// Returns the configured [`AccessControlList`].
+    fn get_access_control_list(&self) -> Option<&AccessControlList>;
+}
+
 /// Builder trait to create new [`NamedConcept`]s.
 pub trait NamedConceptBuilder<T: NamedConceptMgmt> {
     /// Defines the name of the newly created [`NamedConcept`].
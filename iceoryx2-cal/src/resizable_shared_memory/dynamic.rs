@@ -598,10 +598,11 @@ where
         Shm::Builder::new(&adjusted_name).config(config)
     }
 
-    fn create_resized_segment(
+    fn create_resized_segment<F: FnMut(usize) -> bool>(
         &self,
         shm: &Shm,
         layout: Layout,
+        approve_resize: &mut F,
     ) -> Result<(), ResizableShmAllocationError> {
         let msg = "Unable to create resized segment for";
         let state = self.state_mut();
@@ -617,6 +618,12 @@ where
                 layout, Self::max_number_of_reallocations());
         };
 
+        if !approve_resize(adjusted_segment_setup.payload_size) {
+            fail!(from self, with ResizableShmAllocationError::ResizeRejected,
+                "{msg} {:?} since the new segment with a payload size of {} bytes was rejected.",
+                layout, adjusted_segment_setup.payload_size);
+        }
+
         state.builder_config.allocator_config_hint = adjusted_segment_setup.config;
         let shm = Self::create_segment(
             &state.builder_config,
@@ -645,12 +652,13 @@ where
         Ok(())
     }
 
-    fn handle_reallocation(
+    fn handle_reallocation<F: FnMut(usize) -> bool>(
         &self,
         e: ShmAllocationError,
         state: &InternalState<Allocator, Shm>,
         layout: Layout,
         shm: &Shm,
+        approve_resize: &mut F,
     ) -> Result<(), ResizableShmAllocationError> {
         let msg = "Unable to allocate memory";
         if e == ShmAllocationError::AllocationError(AllocationError::OutOfMemory)
@@ -662,7 +670,7 @@ where
                                     "{msg} since there is not enough memory left ({:?}) and the allocation strategy {:?} forbids reallocation.",
                                     e, state.shared_state.allocation_strategy);
             } else {
-                self.create_resized_segment(shm, layout)?;
+                self.create_resized_segment(shm, layout, approve_resize)?;
                 Ok(())
             }
         } else {
@@ -729,7 +737,23 @@ where
         self.state().shared_memory_map.len()
     }
 
+    fn size(&self) -> usize {
+        self.state()
+            .shared_memory_map
+            .iter()
+            .map(|(_, entry)| entry.shm.size())
+            .sum()
+    }
+
     fn allocate(&self, layout: Layout) -> Result<ShmPointer, ResizableShmAllocationError> {
+        self.allocate_with_resize_approval(layout, |_| true)
+    }
+
+    fn allocate_with_resize_approval<F: FnMut(usize) -> bool>(
+        &self,
+        layout: Layout,
+        mut approve_resize: F,
+    ) -> Result<ShmPointer, ResizableShmAllocationError> {
         let msg = "Unable to allocate memory";
         let state = self.state_mut();
 
@@ -742,7 +766,9 @@ where
                             .set_segment_id(SegmentId::new(state.current_idx.value() as u8));
                         return Ok(ptr);
                     }
-                    Err(e) => self.handle_reallocation(e, state, layout, &entry.shm)?,
+                    Err(e) => {
+                        self.handle_reallocation(e, state, layout, &entry.shm, &mut approve_resize)?
+                    }
                 },
                 None => fatal_panic!(from self,
                         "This should never happen! {msg} since the current shared memory segment is not available!"),
@@ -98,7 +98,8 @@ use iceoryx2_bb_elementary::enum_gen;
 
 use crate::named_concept::*;
 use crate::shared_memory::{
-    SegmentId, SharedMemory, SharedMemoryCreateError, SharedMemoryOpenError, ShmPointer,
+    HugePageConfiguration, NumaNodeConfiguration, SegmentId, SharedMemory, SharedMemoryCreateError,
+    SharedMemoryOpenError, ShmPointer,
 };
 use crate::shm_allocator::{PointerOffset, ShmAllocationError, ShmAllocator};
 
@@ -109,9 +110,14 @@ enum_gen! {
 /// this error will be returned. It can be mitigated by providing a better
 /// [`ResizableSharedMemoryBuilder::max_number_of_chunks_hint()`] or
 /// [`ResizableSharedMemoryBuilder::max_chunk_layout_hint()`].
+///
+/// When the resize is rejected by the approval of
+/// [`ResizableSharedMemory::allocate_with_resize_approval()`],
+/// [`ResizableShmAllocationError::ResizeRejected`] is returned.
     ResizableShmAllocationError
   entry:
-    MaxReallocationsReached
+    MaxReallocationsReached,
+    ResizeRejected
   mapping:
     ShmAllocationError,
     SharedMemoryCreateError
@@ -201,7 +207,11 @@ pub trait ResizableSharedMemoryView<Allocator: ShmAllocator, Shm: SharedMemory<A
 /// [`ResizableSharedMemory::allocate()`] memory and distribute the memory to all
 /// [`ResizableSharedMemoryView`]s.
 pub trait ResizableSharedMemory<Allocator: ShmAllocator, Shm: SharedMemory<Allocator>>:
-    Sized + NamedConcept + NamedConceptMgmt + Debug
+    Sized
+    + NamedConcept
+    + NamedConceptMgmt<
+        Configuration: HugePageConfiguration + NumaNodeConfiguration + AccessControlConfiguration,
+    > + Debug
 {
     /// Type alias to the [`ResizableSharedMemoryViewBuilder`] to open a
     /// [`ResizableSharedMemoryView`] to an existing [`ResizableSharedMemory`].
@@ -222,6 +232,9 @@ pub trait ResizableSharedMemory<Allocator: ShmAllocator, Shm: SharedMemory<Alloc
     /// Returns the number of active [`SharedMemory`] segments.
     fn number_of_active_segments(&self) -> usize;
 
+    /// Returns the accumulated size of all active [`SharedMemory`] segments.
+    fn size(&self) -> usize;
+
     /// Allocates a new piece of [`SharedMemory`] if the provided [`Layout`] exceeds the current
     /// supported [`Layout`], the memory would be out-of-memory or the number of chunks exceeds the
     /// current supported amount of chunks, a new [`SharedMemory`] segment will be created. If this
@@ -231,6 +244,16 @@ pub trait ResizableSharedMemory<Allocator: ShmAllocator, Shm: SharedMemory<Alloc
         layout: core::alloc::Layout,
     ) -> Result<ShmPointer, ResizableShmAllocationError>;
 
+    /// Like [`ResizableSharedMemory::allocate()`] but a new [`SharedMemory`] segment is only
+    /// created when `approve_resize` accepts its payload size in bytes. Otherwise,
+    /// [`ResizableShmAllocationError::ResizeRejected`] is returned and the
+    /// [`ResizableSharedMemory`] remains unchanged.
+    fn allocate_with_resize_approval<F: FnMut(usize) -> bool>(
+        &self,
+        layout: core::alloc::Layout,
+        approve_resize: F,
+    ) -> Result<ShmPointer, ResizableShmAllocationError>;
+
     /// Release previously allocated memory
     ///
     /// # Safety
@@ -24,7 +24,8 @@ use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_bb_system_types::path::Path;
 
 use crate::static_storage::file::{
-    NamedConcept, NamedConceptBuilder, NamedConceptConfiguration, NamedConceptMgmt,
+    AccessControlConfiguration, AccessControlList, NamedConcept, NamedConceptBuilder,
+    NamedConceptConfiguration, NamedConceptMgmt,
 };
 
 #[doc(hidden)]
@@ -41,7 +42,7 @@ pub mod details {
         storage: &Storage,
     ) -> usize {
         (storage.get() as *const AllocatorDetails<Allocator>) as usize
-            + storage.get().payload_start_offset
+            + storage.get().payload_start_offset as usize
             + unsafe {
                 storage
                     .get()
@@ -91,6 +92,45 @@ pub mod details {
         }
     }
 
+    impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
+        HugePageConfiguration for Configuration<Allocator, Storage>
+    {
+        fn huge_pages(mut self, value: Option<HugePages>) -> Self {
+            self.dynamic_storage_config = self.dynamic_storage_config.huge_pages(value);
+            self
+        }
+
+        fn get_huge_pages(&self) -> Option<&HugePages> {
+            self.dynamic_storage_config.get_huge_pages()
+        }
+    }
+
+    impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
+        NumaNodeConfiguration for Configuration<Allocator, Storage>
+    {
+        fn numa_node(mut self, value: Option<u32>) -> Self {
+            self.dynamic_storage_config = self.dynamic_storage_config.numa_node(value);
+            self
+        }
+
+        fn get_numa_node(&self) -> Option<u32> {
+            self.dynamic_storage_config.get_numa_node()
+        }
+    }
+
+    impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
+        AccessControlConfiguration for Configuration<Allocator, Storage>
+    {
+        fn access_control_list(mut self, value: Option<AccessControlList>) -> Self {
+            self.dynamic_storage_config = self.dynamic_storage_config.access_control_list(value);
+            self
+        }
+
+        fn get_access_control_list(&self) -> Option<&AccessControlList> {
+            self.dynamic_storage_config.get_access_control_list()
+        }
+    }
+
     impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
         NamedConceptConfiguration for Configuration<Allocator, Storage>
     {
@@ -181,8 +221,9 @@ pub mod details {
                 }
             };
 
-            details.payload_start_offset = (memory.as_ptr() as *const u8) as usize
-                - (details as *const AllocatorDetails<Allocator>) as usize;
+            details.payload_start_offset = ((memory.as_ptr() as *const u8) as usize
+                - (details as *const AllocatorDetails<Allocator>) as usize)
+                as u64;
 
             details.allocator.write(unsafe {
                 Allocator::new_uninit(SystemInfo::PageSize.value(), memory, allocator_config)
@@ -239,8 +280,8 @@ pub mod details {
                 .create(AllocatorDetails {
                     allocator_id: Allocator::unique_id(),
                     allocator: MaybeUninit::uninit(),
-                    mgmt_size: allocator_mgmt_size,
-                    payload_size: self.size,
+                    mgmt_size: allocator_mgmt_size as u64,
+                    payload_size: self.size as u64,
                     payload_start_offset: 0,
                 }) {
                 Ok(s) => s,
@@ -304,7 +345,7 @@ pub mod details {
                 msg, storage.get().allocator_id, Allocator::unique_id());
             }
 
-            let payload_size = storage.get().payload_size;
+            let payload_size = storage.get().payload_size as usize;
             if payload_size < self.size {
                 fail!(from self, with SharedMemoryOpenError::SizeDoesNotFit,
                     "{} since a memory size of {} was requested but only {} is available.",
@@ -333,9 +374,9 @@ pub mod details {
     pub struct AllocatorDetails<Allocator: ShmAllocator> {
         allocator_id: u8,
         allocator: MaybeUninit<Allocator>,
-        mgmt_size: usize,
-        payload_size: usize,
-        payload_start_offset: usize,
+        mgmt_size: u64,
+        payload_size: u64,
+        payload_start_offset: u64,
     }
 
     impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
@@ -415,13 +456,17 @@ pub mod details {
         }
 
         fn size(&self) -> usize {
-            self.storage.get().payload_size
+            self.storage.get().payload_size as usize
         }
 
         fn max_alignment(&self) -> usize {
             unsafe { self.storage.get().allocator.assume_init_ref() }.max_alignment()
         }
 
+        fn huge_pages(&self) -> Option<&HugePages> {
+            self.storage.huge_pages()
+        }
+
         fn allocate(&self, layout: core::alloc::Layout) -> Result<ShmPointer, ShmAllocationError> {
             let offset = fail!(from self, when unsafe { self.storage.get().allocator.assume_init_ref().allocate(layout) },
             "Failed to allocate shared memory due to an internal allocator failure.");
@@ -459,5 +504,25 @@ pub mod details {
         fn bucket_size(&self) -> usize {
             unsafe { self.storage.get().allocator.assume_init_ref().bucket_size() }
         }
+
+        fn number_of_buckets(&self) -> usize {
+            unsafe {
+                self.storage
+                    .get()
+                    .allocator
+                    .assume_init_ref()
+                    .number_of_buckets() as usize
+            }
+        }
+
+        fn number_of_used_buckets(&self) -> usize {
+            unsafe {
+                self.storage
+                    .get()
+                    .allocator
+                    .assume_init_ref()
+                    .number_of_used_buckets()
+            }
+        }
     }
 }
@@ -53,15 +53,34 @@
 //!     // send shm_pointer to another process with [`ZeroCopyConnection`]
 //! }
 //! ```
+//!
+//! # Huge Pages
+//!
+//! Large payloads, like camera images or lidar point clouds, can be backed by explicit
+//! [`HugePages`] with [`HugePageConfiguration::huge_pages()`] to reduce the number of TLB
+//! misses. When the huge pages are not available the implementation falls back to regular
+//! pages, [`SharedMemory::huge_pages()`] reports what actually backs the memory.
+//!
+//! # NUMA
+//!
+//! With [`NumaNodeConfiguration::numa_node()`] the memory is bound to a NUMA node, so that
+//! a producer and consumer that are pinned to the same socket avoid cross-node memory
+//! traffic.
 
 pub mod common;
 pub mod posix;
 pub mod process_local;
+pub mod static_memory;
 
 use core::{fmt::Debug, time::Duration};
 
+pub use crate::dynamic_storage::{
+    HugePageConfiguration, HugePageSize, HugePages, NumaNodeConfiguration,
+};
 pub use crate::shm_allocator::*;
-use crate::static_storage::file::{NamedConcept, NamedConceptBuilder, NamedConceptMgmt};
+use crate::static_storage::file::{
+    AccessControlConfiguration, NamedConcept, NamedConceptBuilder, NamedConceptMgmt,
+};
 use iceoryx2_bb_system_types::file_name::*;
 use pool_allocator::PoolAllocator;
 
@@ -138,7 +157,12 @@ pub trait SharedMemoryBuilder<Allocator: ShmAllocator, Shm: SharedMemory<Allocat
 /// Abstract concept of a memory shared between multiple processes. Can be created with the
 /// [`SharedMemoryBuilder`].
 pub trait SharedMemory<Allocator: ShmAllocator>:
-    Sized + Debug + NamedConcept + NamedConceptMgmt + details::SharedMemoryLowLevelAPI<Allocator>
+    Sized
+    + Debug
+    + NamedConcept
+    + NamedConceptMgmt<
+        Configuration: HugePageConfiguration + NumaNodeConfiguration + AccessControlConfiguration,
+    > + details::SharedMemoryLowLevelAPI<Allocator>
 {
     type Builder: SharedMemoryBuilder<Allocator, Self>;
 
@@ -148,6 +172,10 @@ pub trait SharedMemory<Allocator: ShmAllocator>:
     /// Returns the max supported alignment.
     fn max_alignment(&self) -> usize;
 
+    /// Returns the [`HugePages`] that back the [`SharedMemory`]. If the regular pages are used
+    /// it returns [`None`].
+    fn huge_pages(&self) -> Option<&HugePages>;
+
     /// Returns the start address of the shared memory. Used by the [`ShmPointer`] to calculate
     /// the actual memory position.
     fn payload_start_address(&self) -> usize;
@@ -197,4 +225,10 @@ pub trait SharedMemoryForPoolAllocator: SharedMemory<PoolAllocator> {
 
     /// Returns the bucket size of the [`PoolAllocator`]
     fn bucket_size(&self) -> usize;
+
+    /// Returns the number of buckets of the [`PoolAllocator`]
+    fn number_of_buckets(&self) -> usize;
+
+    /// Returns the number of buckets of the [`PoolAllocator`] that are currently allocated
+    fn number_of_used_buckets(&self) -> usize;
 }
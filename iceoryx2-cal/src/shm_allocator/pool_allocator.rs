@@ -15,7 +15,7 @@ use core::{alloc::Layout, ptr::NonNull, sync::atomic::Ordering};
 use crate::shm_allocator::{ShmAllocator, ShmAllocatorConfig};
 use iceoryx2_bb_elementary::allocator::BaseAllocator;
 use iceoryx2_bb_log::fail;
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
 use super::{
     AllocationStrategy, PointerOffset, SharedMemorySetupHint, ShmAllocationError,
@@ -43,9 +43,9 @@ pub struct PoolAllocator {
     // is even with absolut base address relocatable since every process acquire and return
     // the same relative offset which map then to the same absolut base address
     // the allocator only manages a range of numbers
-    base_address: usize,
-    max_supported_alignment_by_memory: usize,
-    number_of_used_buckets: IoxAtomicUsize,
+    base_address: u64,
+    max_supported_alignment_by_memory: u64,
+    number_of_used_buckets: IoxAtomicU64,
 }
 
 impl PoolAllocator {
@@ -57,6 +57,11 @@ impl PoolAllocator {
         self.allocator.number_of_buckets()
     }
 
+    /// Returns the number of buckets that are currently allocated.
+    pub fn number_of_used_buckets(&self) -> usize {
+        self.number_of_used_buckets.load(Ordering::Relaxed) as usize
+    }
+
     /// # Safety
     ///
     ///  * provided [`PointerOffset`] must be allocated with [`PoolAllocator::allocate()`]
@@ -84,7 +89,7 @@ impl ShmAllocator for PoolAllocator {
         };
 
         let adjusted_number_of_buckets = if self.number_of_used_buckets.load(Ordering::Relaxed)
-            == self.number_of_buckets() as usize
+            == self.number_of_buckets() as u64
         {
             match strategy {
                 AllocationStrategy::BestFit => self.allocator.number_of_buckets() + 1,
@@ -149,7 +154,7 @@ impl ShmAllocator for PoolAllocator {
     }
 
     fn relative_start_address(&self) -> usize {
-        self.allocator.start_address() - self.base_address
+        self.allocator.start_address() - self.base_address as usize
     }
 
     unsafe fn new_uninit(
@@ -163,9 +168,9 @@ impl ShmAllocator for PoolAllocator {
                 unsafe { NonNull::new_unchecked(managed_memory.as_ptr() as *mut u8) },
                 managed_memory.len(),
             ),
-            base_address: (managed_memory.as_ptr() as *mut u8) as usize,
-            max_supported_alignment_by_memory,
-            number_of_used_buckets: IoxAtomicUsize::new(0),
+            base_address: (managed_memory.as_ptr() as *mut u8) as usize as u64,
+            max_supported_alignment_by_memory: max_supported_alignment_by_memory as u64,
+            number_of_used_buckets: IoxAtomicU64::new(0),
         }
     }
 
@@ -178,7 +183,7 @@ impl ShmAllocator for PoolAllocator {
         mgmt_allocator: &Allocator,
     ) -> Result<(), ShmAllocatorInitError> {
         let msg = "Unable to initialize allocator";
-        if self.max_supported_alignment_by_memory < self.max_alignment() {
+        if self.max_supported_alignment_by_memory < self.max_alignment() as u64 {
             fail!(from self, with ShmAllocatorInitError::MaxSupportedMemoryAlignmentInsufficient,
                 "{} since the required alignment {} exceeds the maximum supported alignment {} of the memory.",
                 msg, self.max_alignment(), self.max_supported_alignment_by_memory);
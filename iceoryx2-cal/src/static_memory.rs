@@ -0,0 +1,284 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The statically allocated memory region that backs the `static_memory` implementations of
+//! the concepts, like
+//! [`dynamic_storage::static_memory`](crate::dynamic_storage::static_memory) or
+//! [`static_storage::static_memory`](crate::static_storage::static_memory). They never
+//! acquire memory from the heap or the operating system, every named resource is placed into
+//! one region with a fixed size that is part of the binary. Therefore, they can only be used
+//! in a single address space, like on a microcontroller without memory management unit.
+//!
+//! The size of the region and the maximum number of resources are fixed at compile time and
+//! can be adjusted with the environment variables `IOX2_STATIC_MEMORY_SIZE` (in bytes) and
+//! `IOX2_STATIC_MEMORY_MAX_ENTRIES` when building the crate.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_system_types::file_name::FileName;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//! use iceoryx2_cal::dynamic_storage::static_memory::*;
+//! use iceoryx2_cal::named_concept::*;
+//! use iceoryx2_cal::static_memory;
+//! use core::sync::atomic::AtomicU64;
+//!
+//! let storage = Builder::new(&FileName::new(b"myStaticStorage").unwrap())
+//!                 .create(AtomicU64::new(0)).unwrap();
+//!
+//! assert!(static_memory::contains(storage.get() as *const AtomicU64 as *const u8));
+//! ```
+
+use core::{alloc::Layout, cell::UnsafeCell, hint::spin_loop};
+
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_pal_concurrency_sync::{mutex::Mutex, WaitAction};
+
+/// The size in bytes of the memory region that contains all `static_memory` resources. Can
+/// be set with the environment variable `IOX2_STATIC_MEMORY_SIZE` at compile time.
+pub const STATIC_MEMORY_SIZE: usize =
+    value_from_env(option_env!("IOX2_STATIC_MEMORY_SIZE"), 64 * 1024 * 1024);
+
+/// The maximum number of `static_memory` resources that can exist at the same time. Can be
+/// set with the environment variable `IOX2_STATIC_MEMORY_MAX_ENTRIES` at compile time.
+pub const STATIC_MEMORY_MAX_ENTRIES: usize =
+    value_from_env(option_env!("IOX2_STATIC_MEMORY_MAX_ENTRIES"), 1024);
+
+const fn value_from_env(value: Option<&str>, default: usize) -> usize {
+    let value = match value {
+        Some(value) => value.as_bytes(),
+        None => return default,
+    };
+
+    let mut result: usize = 0;
+    let mut i = 0;
+    while i < value.len() {
+        if !value[i].is_ascii_digit() {
+            panic!("The static memory settings must be unsigned integers.");
+        }
+        result = result * 10 + (value[i] - b'0') as usize;
+        i += 1;
+    }
+
+    result
+}
+
+/// Returns true when the address is part of the static memory region, otherwise false.
+pub fn contains(address: *const u8) -> bool {
+    let start = MEMORY.0.get() as usize;
+    (start..start + STATIC_MEMORY_SIZE).contains(&(address as usize))
+}
+
+#[repr(C, align(128))]
+struct Memory(UnsafeCell<[u8; STATIC_MEMORY_SIZE]>);
+
+unsafe impl Sync for Memory {}
+
+static MEMORY: Memory = Memory(UnsafeCell::new([0; STATIC_MEMORY_SIZE]));
+
+// Separates the resources of the different concepts so that they can use the same names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EntryKind {
+    DynamicStorage,
+    StaticStorage,
+}
+
+#[derive(Debug)]
+pub(crate) struct Entry {
+    pub(crate) kind: EntryKind,
+    pub(crate) path: FilePath,
+    pub(crate) is_removed: bool,
+    pub(crate) is_locked: bool,
+    offset: usize,
+    size: usize,
+    reference_counter: usize,
+}
+
+impl Entry {
+    pub(crate) fn memory(&self) -> *mut u8 {
+        unsafe { (MEMORY.0.get() as *mut u8).add(self.offset) }
+    }
+
+    pub(crate) fn size(&self) -> usize {
+        self.size
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RegistryError {
+    OutOfEntries,
+    OutOfMemory,
+}
+
+pub(crate) struct Registry {
+    mtx: Mutex,
+    entries: UnsafeCell<[Option<Entry>; STATIC_MEMORY_MAX_ENTRIES]>,
+}
+
+unsafe impl Sync for Registry {}
+
+pub(crate) static REGISTRY: Registry = Registry {
+    mtx: Mutex::new(),
+    entries: UnsafeCell::new([const { None }; STATIC_MEMORY_MAX_ENTRIES]),
+};
+
+impl Registry {
+    pub(crate) fn lock(&self) -> RegistryGuard<'_> {
+        self.mtx.lock(|_, _| {
+            spin_loop();
+            WaitAction::Continue
+        });
+
+        RegistryGuard { registry: self }
+    }
+}
+
+pub(crate) struct RegistryGuard<'a> {
+    registry: &'a Registry,
+}
+
+impl Drop for RegistryGuard<'_> {
+    fn drop(&mut self) {
+        self.registry.mtx.unlock(|_| {});
+    }
+}
+
+impl RegistryGuard<'_> {
+    fn entries(&self) -> &[Option<Entry>; STATIC_MEMORY_MAX_ENTRIES] {
+        unsafe { &*self.registry.entries.get() }
+    }
+
+    fn entries_mut(&mut self) -> &mut [Option<Entry>; STATIC_MEMORY_MAX_ENTRIES] {
+        unsafe { &mut *self.registry.entries.get() }
+    }
+
+    /// Returns the index of the entry that was not yet removed.
+    pub(crate) fn find(&self, kind: EntryKind, path: &FilePath) -> Option<usize> {
+        self.entries().iter().position(|e| match e {
+            Some(e) => e.kind == kind && !e.is_removed && e.path == *path,
+            None => false,
+        })
+    }
+
+    pub(crate) fn entry(&self, index: usize) -> &Entry {
+        self.entries()[index].as_ref().unwrap()
+    }
+
+    pub(crate) fn entry_mut(&mut self, index: usize) -> &mut Entry {
+        self.entries_mut()[index].as_mut().unwrap()
+    }
+
+    pub(crate) fn list(&self, kind: EntryKind) -> impl Iterator<Item = &FilePath> {
+        self.entries()
+            .iter()
+            .flatten()
+            .filter(move |e| e.kind == kind && !e.is_removed)
+            .map(|e| &e.path)
+    }
+
+    /// Adds a new entry with the reference counter set to one and the memory for the provided
+    /// [`Layout`].
+    pub(crate) fn insert(
+        &mut self,
+        kind: EntryKind,
+        path: &FilePath,
+        is_locked: bool,
+        layout: Layout,
+    ) -> Result<usize, RegistryError> {
+        let index = match self.entries().iter().position(|e| e.is_none()) {
+            Some(index) => index,
+            None => return Err(RegistryError::OutOfEntries),
+        };
+
+        let offset = match self.find_free_memory(layout) {
+            Some(offset) => offset,
+            None => return Err(RegistryError::OutOfMemory),
+        };
+
+        self.entries_mut()[index] = Some(Entry {
+            kind,
+            path: *path,
+            is_removed: false,
+            is_locked,
+            offset,
+            size: layout.size(),
+            reference_counter: 1,
+        });
+
+        Ok(index)
+    }
+
+    /// Replaces the memory of the entry with a new memory for the provided [`Layout`]. The
+    /// previous content is lost.
+    pub(crate) fn reallocate(&mut self, index: usize, layout: Layout) -> Result<(), RegistryError> {
+        self.entry_mut(index).size = 0;
+        match self.find_free_memory(layout) {
+            Some(offset) => {
+                let entry = self.entry_mut(index);
+                entry.offset = offset;
+                entry.size = layout.size();
+                Ok(())
+            }
+            None => Err(RegistryError::OutOfMemory),
+        }
+    }
+
+    pub(crate) fn acquire(&mut self, index: usize) {
+        self.entry_mut(index).reference_counter += 1;
+    }
+
+    /// Decrements the reference counter. The entry and its memory are released when it
+    /// was removed and the last reference is gone.
+    pub(crate) fn release(&mut self, index: usize) {
+        let entry = self.entry_mut(index);
+        entry.reference_counter -= 1;
+        if entry.reference_counter == 0 && entry.is_removed {
+            self.entries_mut()[index] = None;
+        }
+    }
+
+    /// Marks the entry as removed so that it can no longer be found. The entry and its
+    /// memory are released when no reference is left.
+    pub(crate) fn remove(&mut self, index: usize) {
+        let entry = self.entry_mut(index);
+        entry.is_removed = true;
+        if entry.reference_counter == 0 {
+            self.entries_mut()[index] = None;
+        }
+    }
+
+    // first fit, every candidate that overlaps with an entry is moved behind that entry
+    fn find_free_memory(&self, layout: Layout) -> Option<usize> {
+        let start = MEMORY.0.get() as usize;
+        let size = layout.size().max(1);
+        let mut offset = start.next_multiple_of(layout.align()) - start;
+
+        loop {
+            if offset + size > STATIC_MEMORY_SIZE {
+                return None;
+            }
+
+            let end_of_overlap = self
+                .entries()
+                .iter()
+                .flatten()
+                .filter(|e| e.size != 0 && e.offset < offset + size && offset < e.offset + e.size)
+                .map(|e| e.offset + e.size)
+                .max();
+
+            match end_of_overlap {
+                Some(end) => offset = (start + end).next_multiple_of(layout.align()) - start,
+                None => return Some(offset),
+            }
+        }
+    }
+}
@@ -43,11 +43,23 @@
 //!
 //! println!("Storage {} content: {}", reader.name(), content);
 //! ```
+//!
+//! # Encryption
+//!
+//! With [`StaticStorageConfiguration::encryption_key_provider()`] the contents are stored
+//! encrypted with XChaCha20-Poly1305 of the
+//! [`chacha20poly1305`](https://crates.io/crates/chacha20poly1305) crate, it requires the
+//! `static_storage_encryption` feature. The file consists of a random nonce, the encrypted
+//! contents and the authentication tag, the name of the storage is used as associated data.
+//! When the file was modified or renamed, [`StaticStorage::read()`] fails with
+//! [`StaticStorageReadError::StaticStorageWasModified`].
 
 pub use crate::named_concept::*;
 pub use crate::static_storage::*;
 
+use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_log::{fail, trace, warn};
+use iceoryx2_bb_posix::access_control_list::AccessControlListApplyError;
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_bb_posix::{
     directory::*, file::*, file_descriptor::FileDescriptorManagement, file_type::FileType,
@@ -55,12 +67,111 @@ use iceoryx2_bb_posix::{
 
 const FINAL_PERMISSIONS: Permission = Permission::OWNER_READ;
 
+// the group and others permissions are defined by the access control list, only the owner
+// permissions mark a completely written storage
+fn has_final_permissions(permission: Permission) -> bool {
+    (permission & Permission::OWNER_ALL) == FINAL_PERMISSIONS
+}
+
+mod encryption {
+    use crate::static_storage::EncryptionKey;
+
+    pub(super) const NONCE_LENGTH: usize = 24;
+    pub(super) const TAG_LENGTH: usize = 16;
+    pub(super) const OVERHEAD: u64 = (NONCE_LENGTH + TAG_LENGTH) as u64;
+
+    #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+    pub(super) enum EncryptionError {
+        #[cfg(not(feature = "static_storage_encryption"))]
+        FeatureDisabled,
+        #[cfg(feature = "static_storage_encryption")]
+        NonceUnavailable,
+        #[cfg(feature = "static_storage_encryption")]
+        ContentsTooLarge,
+        #[cfg(feature = "static_storage_encryption")]
+        AuthenticationFailed,
+    }
+
+    // returns the nonce, the encrypted contents and the authentication tag
+    #[cfg(feature = "static_storage_encryption")]
+    pub(super) fn encrypt(
+        key: &EncryptionKey,
+        associated_data: &[u8],
+        contents: &[u8],
+    ) -> Result<Vec<u8>, EncryptionError> {
+        use chacha20poly1305::aead::{rand_core::RngCore, AeadInPlace, KeyInit, OsRng};
+        use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+
+        // a random nonce never repeats in practice, in contrast to a nonce derived from
+        // process ids or timestamps
+        let mut nonce = XNonce::default();
+        OsRng
+            .try_fill_bytes(&mut nonce)
+            .map_err(|_| EncryptionError::NonceUnavailable)?;
+
+        let mut buffer = Vec::with_capacity(contents.len() + OVERHEAD as usize);
+        buffer.extend_from_slice(&nonce);
+        buffer.extend_from_slice(contents);
+        let tag = XChaCha20Poly1305::new(Key::from_slice(key))
+            .encrypt_in_place_detached(&nonce, associated_data, &mut buffer[NONCE_LENGTH..])
+            .map_err(|_| EncryptionError::ContentsTooLarge)?;
+        buffer.extend_from_slice(&tag);
+
+        Ok(buffer)
+    }
+
+    // decrypts the file contents in place and returns the decrypted contents, the file
+    // contents must be at least OVERHEAD bytes long
+    #[cfg(feature = "static_storage_encryption")]
+    pub(super) fn decrypt<'a>(
+        key: &EncryptionKey,
+        associated_data: &[u8],
+        file_contents: &'a mut [u8],
+    ) -> Result<&'a [u8], EncryptionError> {
+        use chacha20poly1305::aead::{AeadInPlace, KeyInit};
+        use chacha20poly1305::{Key, Tag, XChaCha20Poly1305, XNonce};
+
+        let (nonce, buffer) = file_contents.split_at_mut(NONCE_LENGTH);
+        let (data, tag) = buffer.split_at_mut(buffer.len() - TAG_LENGTH);
+        XChaCha20Poly1305::new(Key::from_slice(key))
+            .decrypt_in_place_detached(
+                XNonce::from_slice(nonce),
+                associated_data,
+                data,
+                Tag::from_slice(tag),
+            )
+            .map_err(|_| EncryptionError::AuthenticationFailed)?;
+
+        Ok(data)
+    }
+
+    #[cfg(not(feature = "static_storage_encryption"))]
+    pub(super) fn encrypt(
+        _key: &EncryptionKey,
+        _associated_data: &[u8],
+        _contents: &[u8],
+    ) -> Result<Vec<u8>, EncryptionError> {
+        Err(EncryptionError::FeatureDisabled)
+    }
+
+    #[cfg(not(feature = "static_storage_encryption"))]
+    pub(super) fn decrypt<'a>(
+        _key: &EncryptionKey,
+        _associated_data: &[u8],
+        _file_contents: &'a mut [u8],
+    ) -> Result<&'a [u8], EncryptionError> {
+        Err(EncryptionError::FeatureDisabled)
+    }
+}
+
 /// The custom configuration of the [`Storage`].
 #[derive(Clone, Debug)]
 pub struct Configuration {
     path: Path,
     suffix: FileName,
     prefix: FileName,
+    encryption_key_provider: Option<EncryptionKeyProvider>,
+    access_control_list: Option<AccessControlList>,
 }
 
 impl Default for Configuration {
@@ -69,6 +180,8 @@ impl Default for Configuration {
             path: Storage::default_path_hint(),
             suffix: Storage::default_suffix(),
             prefix: Storage::default_prefix(),
+            encryption_key_provider: None,
+            access_control_list: None,
         }
     }
 }
@@ -102,7 +215,27 @@ impl crate::named_concept::NamedConceptConfiguration for Configuration {
     }
 }
 
-impl crate::static_storage::StaticStorageConfiguration for Configuration {}
+impl crate::static_storage::StaticStorageConfiguration for Configuration {
+    fn encryption_key_provider(mut self, value: Option<EncryptionKeyProvider>) -> Self {
+        self.encryption_key_provider = value;
+        self
+    }
+
+    fn get_encryption_key_provider(&self) -> Option<EncryptionKeyProvider> {
+        self.encryption_key_provider
+    }
+}
+
+impl crate::named_concept::AccessControlConfiguration for Configuration {
+    fn access_control_list(mut self, value: Option<AccessControlList>) -> Self {
+        self.access_control_list = value;
+        self
+    }
+
+    fn get_access_control_list(&self) -> Option<&AccessControlList> {
+        self.access_control_list.as_ref()
+    }
+}
 
 #[derive(Debug)]
 pub struct Locked {
@@ -118,22 +251,51 @@ impl NamedConcept for Locked {
 impl StaticStorageLocked<Storage> for Locked {
     fn unlock(mut self, contents: &[u8]) -> Result<Storage, StaticStorageUnlockError> {
         let msg = "Failed to unlock storage";
-        let bytes_written = fail!(from self, when self.static_storage.file.write(contents),
+        let encrypted_contents;
+        let file_contents = match self.static_storage.config.encryption_key_provider {
+            Some(encryption_key_provider) => {
+                encrypted_contents = match encryption::encrypt(
+                    &encryption_key_provider(),
+                    self.static_storage.name.as_bytes(),
+                    contents,
+                ) {
+                    Ok(encrypted_contents) => encrypted_contents,
+                    Err(e) => {
+                        fail!(from self, with StaticStorageUnlockError::InternalError,
+                            "{} since the contents could not be encrypted ({:?}).", msg, e);
+                    }
+                };
+                encrypted_contents.as_slice()
+            }
+            None => contents,
+        };
+
+        let bytes_written = fail!(from self, when self.static_storage.file.write(file_contents),
             map FileWriteError::InsufficientPermissions => StaticStorageUnlockError::InsufficientPermissions;
                 FileWriteError::NoSpaceLeft => StaticStorageUnlockError::NoSpaceLeft,
             unmatched StaticStorageUnlockError::InternalError,
             "{} due to a failure while writing the contents.", msg);
 
-        if bytes_written != contents.len() as u64 {
+        if bytes_written != file_contents.len() as u64 {
             fail!(from self, with StaticStorageUnlockError::NoSpaceLeft,
                 "{} since the contents length is {} bytes but only {} bytes could be written to the file.",
-                msg, contents.len(), bytes_written);
+                msg, file_contents.len(), bytes_written);
         }
 
-        fail!(from self, when self.static_storage.file.set_permission(FINAL_PERMISSIONS),
-                map FileSetPermissionError::InsufficientPermissions => StaticStorageUnlockError::InsufficientPermissions,
-                unmatched StaticStorageUnlockError::InternalError,
-                "{} due to a failure while updating the permissions to {}.", msg, FINAL_PERMISSIONS);
+        match self.static_storage.config.access_control_list {
+            Some(access_control_list) => {
+                fail!(from self, when access_control_list.apply(FINAL_PERMISSIONS, &mut self.static_storage.file),
+                    map AccessControlListApplyError::InsufficientPermissions => StaticStorageUnlockError::InsufficientPermissions,
+                    unmatched StaticStorageUnlockError::InternalError,
+                    "{} due to a failure while applying the access control list {:?}.", msg, access_control_list);
+            }
+            None => {
+                fail!(from self, when self.static_storage.file.set_permission(FINAL_PERMISSIONS),
+                    map FileSetPermissionError::InsufficientPermissions => StaticStorageUnlockError::InsufficientPermissions,
+                    unmatched StaticStorageUnlockError::InternalError,
+                    "{} due to a failure while updating the permissions to {}.", msg, FINAL_PERMISSIONS);
+            }
+        }
 
         self.static_storage.len = contents.len() as u64;
 
@@ -240,7 +402,8 @@ impl crate::named_concept::NamedConceptMgmt for Storage {
             .iter()
             .filter(|entry| {
                 let metadata = entry.metadata();
-                metadata.file_type() == FileType::File && metadata.permission() == FINAL_PERMISSIONS
+                metadata.file_type() == FileType::File
+                    && has_final_permissions(metadata.permission())
             })
             .filter_map(|entry| config.extract_name_from_file(entry.name()))
             .collect())
@@ -289,7 +452,7 @@ impl crate::named_concept::NamedConceptMgmt for Storage {
         }
         let metadata = metadata.unwrap();
 
-        if metadata.file_type() == FileType::File && metadata.permission() == FINAL_PERMISSIONS {
+        if metadata.file_type() == FileType::File && has_final_permissions(metadata.permission()) {
             return Ok(true);
         }
 
@@ -332,16 +495,57 @@ impl crate::static_storage::StaticStorage for Storage {
                 msg, len, content.len());
         }
 
-        let bytes_read = fail!(from self, when self.file.read(content),
+        let encryption_key_provider = match self.config.encryption_key_provider {
+            Some(encryption_key_provider) => encryption_key_provider,
+            None => {
+                let bytes_read = fail!(from self, when self.file.read(content),
                                 with StaticStorageReadError::ReadError,
                                 "{} due to a failure while reading the underlying file.", msg);
 
-        if bytes_read != len {
-            fail!(from self, with StaticStorageReadError::StaticStorageWasModified,
+                if bytes_read != len {
+                    fail!(from self, with StaticStorageReadError::StaticStorageWasModified,
                         "{} since the expected read size is {} bytes but {} bytes were read instead. Was the static storage file modified?",
                         msg, len, bytes_read);
+                }
+
+                return Ok(());
+            }
+        };
+
+        // read one byte more to detect files that were extended
+        let file_len = len + encryption::OVERHEAD;
+        let mut buffer = vec![0u8; file_len as usize + 1];
+        let bytes_read = fail!(from self, when self.file.read(&mut buffer),
+                                with StaticStorageReadError::ReadError,
+                                "{} due to a failure while reading the underlying file.", msg);
+
+        if bytes_read != file_len {
+            fail!(from self, with StaticStorageReadError::StaticStorageWasModified,
+                "{} since the expected read size is {} bytes but {} bytes were read instead. Was the static storage file modified?",
+                msg, file_len, bytes_read);
         }
 
+        let data = match encryption::decrypt(
+            &encryption_key_provider(),
+            self.name.as_bytes(),
+            &mut buffer[..file_len as usize],
+        ) {
+            Ok(data) => data,
+            #[cfg(not(feature = "static_storage_encryption"))]
+            Err(encryption::EncryptionError::FeatureDisabled) => {
+                fail!(from self, with StaticStorageReadError::ReadError,
+                    "{} since the contents are encrypted but the \"static_storage_encryption\" feature is disabled.", msg);
+            }
+            #[cfg(feature = "static_storage_encryption")]
+            Err(_) => {
+                fail!(from self, with StaticStorageReadError::StaticStorageWasModified,
+                    "{} since the contents could not be authenticated. Was the static storage file modified or is the encryption key wrong?",
+                    msg);
+            }
+        };
+
+        content[..len as usize].copy_from_slice(data);
+
         Ok(())
     }
 }
@@ -420,7 +624,7 @@ impl crate::static_storage::StaticStorageBuilder<Storage> for Builder {
         let msg = "Unable to open static storage";
         let origin = "static_storage::File::Builder::open()";
 
-        let file = fail!(from origin,
+        let mut file = fail!(from origin,
             when FileBuilder::new(&self.config.path_for(&self.storage_name)).open_existing(AccessMode::Read),
             with StaticStorageOpenError::DoesNotExist,
             "{} due to a failure while opening the file.", msg);
@@ -437,7 +641,7 @@ impl crate::static_storage::StaticStorageBuilder<Storage> for Builder {
             when file.metadata(), with StaticStorageOpenError::Read,
             "{} due to a failure while reading the files metadata.", msg);
 
-            if metadata.permission() != FINAL_PERMISSIONS {
+            if !has_final_permissions(metadata.permission()) {
                 if elapsed_time > timeout {
                     fail!(from origin,
                         with StaticStorageOpenError::InitializationNotYetFinalized,
@@ -449,13 +653,31 @@ impl crate::static_storage::StaticStorageBuilder<Storage> for Builder {
                     when wait_for_read_access.wait(),
                     with StaticStorageOpenError::InternalError,
                     "{} since the adaptive wait call failed.", msg);
+
+                // the creator may have removed the unfinished storage, or removed and created
+                // it again, in the meantime
+                file = fail!(from origin,
+                    when FileBuilder::new(&self.config.path_for(&self.storage_name)).open_existing(AccessMode::Read),
+                    with StaticStorageOpenError::DoesNotExist,
+                    "{} since the static storage was removed while it was being created.", msg);
             } else {
+                let len = match self.config.encryption_key_provider {
+                    Some(_) => {
+                        if metadata.size() < encryption::OVERHEAD {
+                            fail!(from origin, with StaticStorageOpenError::Read,
+                                "{} since the file is too small to contain encrypted contents.", msg);
+                        }
+                        metadata.size() - encryption::OVERHEAD
+                    }
+                    None => metadata.size(),
+                };
+
                 return Ok(Storage {
                     name: self.storage_name,
                     config: self.config,
                     has_ownership: self.has_ownership,
                     file,
-                    len: metadata.size(),
+                    len,
                 });
             }
         }
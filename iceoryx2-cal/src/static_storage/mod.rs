@@ -15,6 +15,7 @@
 
 pub mod file;
 pub mod process_local;
+pub mod static_memory;
 
 use core::{fmt::Debug, time::Duration};
 
@@ -22,7 +23,8 @@ use iceoryx2_bb_log::fail;
 use iceoryx2_bb_system_types::file_name::*;
 
 use crate::named_concept::{
-    NamedConcept, NamedConceptBuilder, NamedConceptConfiguration, NamedConceptMgmt,
+    AccessControlConfiguration, NamedConcept, NamedConceptBuilder, NamedConceptConfiguration,
+    NamedConceptMgmt,
 };
 
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
@@ -57,9 +59,24 @@ pub enum StaticStorageUnlockError {
     InternalError,
 }
 
+/// The secret key that encrypts the contents of a [`StaticStorage`].
+pub type EncryptionKey = [u8; 32];
+
+/// Provides the [`EncryptionKey`] that encrypts the contents of a [`StaticStorage`]. Every
+/// process that opens the [`StaticStorage`] must provide the same [`EncryptionKey`].
+pub type EncryptionKeyProvider = fn() -> EncryptionKey;
+
 /// A custom configuration which can be used by the [`StaticStorageBuilder`] to create a
 /// [`StaticStorage`] with implementation specific settings.
-pub trait StaticStorageConfiguration: Clone + Default + NamedConceptConfiguration {}
+pub trait StaticStorageConfiguration: Clone + Default + NamedConceptConfiguration {
+    /// Encrypts the contents with the [`EncryptionKey`] of the [`EncryptionKeyProvider`] and
+    /// authenticates them, so that any modification is detected in [`StaticStorage::read()`].
+    /// Implementations whose contents never leave the process ignore the setting.
+    fn encryption_key_provider(self, value: Option<EncryptionKeyProvider>) -> Self;
+
+    /// Returns the configured [`EncryptionKeyProvider`].
+    fn get_encryption_key_provider(&self) -> Option<EncryptionKeyProvider>;
+}
 
 /// Creates either a [`StaticStorage`], that can own the [`StaticStorage`] if it was created with
 /// [`StaticStorageBuilder::has_ownership()`] (default = true) or a [`StaticStorageLocked`] that is
@@ -101,7 +118,12 @@ pub trait StaticStorageLocked<T: StaticStorage>: Sized + NamedConcept {
 
 /// A static storage which owns its underlying resources. When it goes out of scope those resources
 /// shall be removed.
-pub trait StaticStorage: Debug + Sized + NamedConceptMgmt + NamedConcept {
+pub trait StaticStorage:
+    Debug
+    + Sized
+    + NamedConceptMgmt<Configuration: StaticStorageConfiguration + AccessControlConfiguration>
+    + NamedConcept
+{
     type Builder: StaticStorageBuilder<Self> + NamedConceptBuilder<Self>;
     type Locked: StaticStorageLocked<Self>;
 
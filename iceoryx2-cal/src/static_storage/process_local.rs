@@ -78,6 +78,7 @@ pub struct Configuration {
     path: Path,
     suffix: FileName,
     prefix: FileName,
+    encryption_key_provider: Option<EncryptionKeyProvider>,
 }
 
 impl Default for Configuration {
@@ -86,6 +87,7 @@ impl Default for Configuration {
             path: Storage::default_path_hint(),
             suffix: Storage::default_suffix(),
             prefix: Storage::default_prefix(),
+            encryption_key_provider: None,
         }
     }
 }
@@ -119,7 +121,27 @@ impl NamedConceptConfiguration for Configuration {
     }
 }
 
-impl StaticStorageConfiguration for Configuration {}
+impl StaticStorageConfiguration for Configuration {
+    fn encryption_key_provider(mut self, value: Option<EncryptionKeyProvider>) -> Self {
+        self.encryption_key_provider = value;
+        self
+    }
+
+    fn get_encryption_key_provider(&self) -> Option<EncryptionKeyProvider> {
+        self.encryption_key_provider
+    }
+}
+
+// the process local storage cannot be accessed by other processes
+impl AccessControlConfiguration for Configuration {
+    fn access_control_list(self, _value: Option<AccessControlList>) -> Self {
+        self
+    }
+
+    fn get_access_control_list(&self) -> Option<&AccessControlList> {
+        None
+    }
+}
 
 #[derive(Debug)]
 pub struct Locked {
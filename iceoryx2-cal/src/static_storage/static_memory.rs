@@ -0,0 +1,383 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Implementation of [`StaticStorage`] that places the content into the statically allocated
+//! memory region of [`static_memory`](crate::static_memory). Cannot be used in an
+//! inter-process context.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_cal::static_storage::static_memory::*;
+//! use iceoryx2_bb_system_types::file_name::FileName;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//!
+//! let mut content = "look over there!".to_string();
+//!
+//! let storage_name = FileName::new(b"someInternalStorage").unwrap();
+//! let owner = Builder::new(&storage_name)
+//!                 .create(content.as_bytes()).unwrap();
+//!
+//! // at some other place in the local process, can be another thread
+//! let initialization_timeout = core::time::Duration::from_millis(100);
+//! let reader = Builder::new(&storage_name)
+//!                 .open(initialization_timeout).unwrap();
+//!
+//! let content_length = reader.len();
+//! let mut content = String::from_utf8(vec![b' '; content_length as usize]).unwrap();
+//! reader.read(unsafe { content.as_mut_vec() }.as_mut_slice()).unwrap();
+//!
+//! println!("Storage {} content: {}", reader.name(), content);
+//! ```
+pub use crate::named_concept::*;
+pub use crate::static_storage::*;
+use core::alloc::Layout;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+
+use crate::static_memory::{EntryKind, RegistryError, REGISTRY};
+
+#[derive(Clone, Debug)]
+pub struct Configuration {
+    path: Path,
+    suffix: FileName,
+    prefix: FileName,
+    encryption_key_provider: Option<EncryptionKeyProvider>,
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            path: Storage::default_path_hint(),
+            suffix: Storage::default_suffix(),
+            prefix: Storage::default_prefix(),
+            encryption_key_provider: None,
+        }
+    }
+}
+
+impl NamedConceptConfiguration for Configuration {
+    fn prefix(mut self, value: &FileName) -> Self {
+        self.prefix = *value;
+        self
+    }
+
+    fn get_prefix(&self) -> &FileName {
+        &self.prefix
+    }
+
+    fn suffix(mut self, value: &FileName) -> Self {
+        self.suffix = *value;
+        self
+    }
+
+    fn path_hint(mut self, value: &Path) -> Self {
+        self.path = *value;
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl StaticStorageConfiguration for Configuration {
+    fn encryption_key_provider(mut self, value: Option<EncryptionKeyProvider>) -> Self {
+        self.encryption_key_provider = value;
+        self
+    }
+
+    fn get_encryption_key_provider(&self) -> Option<EncryptionKeyProvider> {
+        self.encryption_key_provider
+    }
+}
+
+// the static memory cannot be accessed by other processes
+impl AccessControlConfiguration for Configuration {
+    fn access_control_list(self, _value: Option<AccessControlList>) -> Self {
+        self
+    }
+
+    fn get_access_control_list(&self) -> Option<&AccessControlList> {
+        None
+    }
+}
+
+#[derive(Debug)]
+pub struct Locked {
+    storage: Storage,
+}
+
+impl NamedConcept for Locked {
+    fn name(&self) -> &FileName {
+        self.storage.name()
+    }
+}
+
+impl StaticStorageLocked<Storage> for Locked {
+    fn unlock(mut self, contents: &[u8]) -> Result<Storage, StaticStorageUnlockError> {
+        let msg = "Failed to unlock storage";
+        let mut guard = REGISTRY.lock();
+        let index = self.storage.index;
+
+        match guard.reallocate(index, Layout::for_value(contents)) {
+            Ok(()) => (),
+            Err(RegistryError::OutOfMemory) | Err(RegistryError::OutOfEntries) => {
+                fail!(from self, with StaticStorageUnlockError::NoSpaceLeft,
+                    "{} since the static memory of {} bytes has not enough space left for {} bytes.",
+                    msg, crate::static_memory::STATIC_MEMORY_SIZE, contents.len());
+            }
+        }
+
+        let entry = guard.entry_mut(index);
+        unsafe {
+            entry
+                .memory()
+                .copy_from_nonoverlapping(contents.as_ptr(), contents.len())
+        };
+        entry.is_locked = false;
+
+        self.storage.content = entry.memory();
+        self.storage.len = contents.len();
+
+        Ok(self.storage)
+    }
+}
+
+#[derive(Debug)]
+pub struct Storage {
+    name: FileName,
+    index: usize,
+    content: *const u8,
+    len: usize,
+    has_ownership: bool,
+    config: Configuration,
+}
+
+unsafe impl Send for Storage {}
+unsafe impl Sync for Storage {}
+
+impl Drop for Storage {
+    fn drop(&mut self) {
+        if self.has_ownership {
+            if let Err(v) = unsafe { Self::remove_cfg(&self.name, &self.config) } {
+                fatal_panic!(from self, "This should never happen! Failed to remove underlying storage ({:?})", v);
+            }
+        }
+
+        REGISTRY.lock().release(self.index);
+    }
+}
+
+impl NamedConceptMgmt for Storage {
+    type Configuration = Configuration;
+
+    unsafe fn remove_cfg(
+        storage_name: &FileName,
+        config: &Self::Configuration,
+    ) -> Result<bool, NamedConceptRemoveError> {
+        let mut guard = REGISTRY.lock();
+        match guard.find(EntryKind::StaticStorage, &config.path_for(storage_name)) {
+            Some(index) => {
+                guard.remove(index);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn list_cfg(config: &Self::Configuration) -> Result<Vec<FileName>, NamedConceptListError> {
+        let guard = REGISTRY.lock();
+        Ok(guard
+            .list(EntryKind::StaticStorage)
+            .filter_map(|path| config.extract_name_from_path(path))
+            .collect())
+    }
+
+    fn does_exist_cfg(
+        storage_name: &FileName,
+        config: &Self::Configuration,
+    ) -> Result<bool, NamedConceptDoesExistError> {
+        let guard = REGISTRY.lock();
+        match guard.find(EntryKind::StaticStorage, &config.path_for(storage_name)) {
+            Some(index) => match guard.entry(index).is_locked {
+                true => Err(NamedConceptDoesExistError::UnderlyingResourcesBeingSetUp),
+                false => Ok(true),
+            },
+            None => Ok(false),
+        }
+    }
+
+    fn remove_path_hint(_value: &Path) -> Result<(), NamedConceptPathHintRemoveError> {
+        Ok(())
+    }
+}
+
+impl NamedConcept for Storage {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl StaticStorage for Storage {
+    type Builder = Builder;
+    type Locked = Locked;
+
+    fn len(&self) -> u64 {
+        self.len as u64
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn read(&self, content: &mut [u8]) -> Result<(), StaticStorageReadError> {
+        let msg = "Failed to read from storage";
+        if self.len > content.len() {
+            fail!(from self, with StaticStorageReadError::BufferTooSmall,
+                    "{} since the provided buffer with a size of {} bytes is too small. Require at least a size of {} bytes.",
+                    msg, content.len(), self.len);
+        }
+
+        if self.len != 0 {
+            content[..self.len]
+                .copy_from_slice(unsafe { core::slice::from_raw_parts(self.content, self.len) });
+        }
+
+        Ok(())
+    }
+
+    fn release_ownership(&mut self) {
+        self.has_ownership = false;
+    }
+
+    fn acquire_ownership(&mut self) {
+        self.has_ownership = true
+    }
+}
+
+#[derive(Debug)]
+pub struct Builder {
+    name: FileName,
+    has_ownership: bool,
+    config: Configuration,
+}
+
+impl NamedConceptBuilder<Storage> for Builder {
+    fn new(storage_name: &FileName) -> Self {
+        Self {
+            has_ownership: true,
+            name: *storage_name,
+            config: Configuration::default(),
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.config = config.clone();
+        self
+    }
+}
+
+impl StaticStorageBuilder<Storage> for Builder {
+    fn has_ownership(mut self, value: bool) -> Self {
+        self.has_ownership = value;
+        self
+    }
+
+    fn open(self, timeout: Duration) -> Result<Storage, StaticStorageOpenError> {
+        let msg = "Failed to open static storage";
+        let mut wait_for_read_access = fail!(from self,
+            when AdaptiveWaitBuilder::new().create(),
+            with StaticStorageOpenError::InternalError,
+            "{} since the AdaptiveWait could not be initialized.", msg);
+
+        let mut elapsed_time = Duration::ZERO;
+        let full_path = self.config.path_for(&self.name);
+
+        loop {
+            let mut guard = REGISTRY.lock();
+
+            let index = match guard.find(EntryKind::StaticStorage, &full_path) {
+                Some(index) => index,
+                None => {
+                    fail!(from self, with StaticStorageOpenError::DoesNotExist,
+                        "{} since the storage does not exist.", msg);
+                }
+            };
+
+            if guard.entry(index).is_locked {
+                drop(guard);
+                if elapsed_time > timeout {
+                    fail!(from self, with StaticStorageOpenError::InitializationNotYetFinalized,
+                        "{} since the static storage is still being created (in locked state), try later.", msg);
+                }
+
+                elapsed_time = fail!(from self,
+                    when wait_for_read_access.wait(),
+                    with StaticStorageOpenError::InternalError,
+                    "{} since the adaptive wait call failed.", msg);
+            } else {
+                guard.acquire(index);
+                let entry = guard.entry(index);
+                return Ok(Storage {
+                    name: self.name,
+                    index,
+                    content: entry.memory(),
+                    len: entry.size(),
+                    has_ownership: self.has_ownership,
+                    config: self.config,
+                });
+            }
+        }
+    }
+
+    fn create_locked(self) -> Result<<Storage as StaticStorage>::Locked, StaticStorageCreateError> {
+        let msg = "Failed to create storage";
+
+        let mut guard = REGISTRY.lock();
+
+        let full_path = self.config.path_for(&self.name);
+        if guard.find(EntryKind::StaticStorage, &full_path).is_some() {
+            fail!(from self, with StaticStorageCreateError::AlreadyExists,
+                "{} since a storage with the name \"{}\" does already exist.", msg, self.name);
+        }
+
+        let index = match guard.insert(
+            EntryKind::StaticStorage,
+            &full_path,
+            true,
+            Layout::new::<()>(),
+        ) {
+            Ok(index) => index,
+            Err(RegistryError::OutOfEntries) | Err(RegistryError::OutOfMemory) => {
+                fail!(from self, with StaticStorageCreateError::InternalError,
+                    "{} since the maximum number of {} static memory entries is exceeded.",
+                    msg, crate::static_memory::STATIC_MEMORY_MAX_ENTRIES);
+            }
+        };
+
+        Ok(Locked {
+            storage: Storage {
+                name: self.name,
+                index,
+                content: core::ptr::null(),
+                len: 0,
+                has_ownership: self.has_ownership,
+                config: self.config,
+            },
+        })
+    }
+}
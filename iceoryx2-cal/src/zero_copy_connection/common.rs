@@ -17,7 +17,7 @@ pub mod details {
     use core::marker::PhantomData;
     use core::sync::atomic::Ordering;
     use iceoryx2_bb_elementary::allocator::{AllocationError, BaseAllocator};
-    use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicU8, IoxAtomicUsize};
+    use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicU8};
 
     use crate::dynamic_storage::{
         DynamicStorage, DynamicStorageBuilder, DynamicStorageCreateError, DynamicStorageOpenError,
@@ -32,9 +32,10 @@ pub mod details {
         index_queue::RelocatableIndexQueue,
         safely_overflowing_index_queue::RelocatableSafelyOverflowingIndexQueue,
     };
-    use iceoryx2_bb_log::{fail, fatal_panic, warn};
+    use iceoryx2_bb_log::{debug, fail, fatal_panic, warn};
     use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 
+    use self::state_machine::{ConnectionEvent, ConnectionState, ConnectionTransitionError};
     use self::used_chunk_list::RelocatableUsedChunkList;
 
     #[derive(Debug, PartialEq, Eq, Copy)]
@@ -103,71 +104,63 @@ pub mod details {
         }
     }
 
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-    #[repr(u8)]
-    enum State {
-        None = 0b00000000,
-        Sender = 0b00000001,
-        Receiver = 0b00000010,
-        MarkedForDestruction = 0b10000000,
-    }
-
-    impl State {
-        fn value(&self) -> u8 {
-            *self as u8
-        }
-    }
-
     fn cleanup_shared_memory<Storage: DynamicStorage<SharedManagementData>>(
         storage: &Storage,
-        state_to_remove: State,
+        event: ConnectionEvent,
     ) {
+        let origin = "common::ZeroCopyConnection::cleanup_shared_memory()";
         let mut current_state = storage.get().state.load(Ordering::Relaxed);
-        if current_state == State::MarkedForDestruction.value() {
-            warn!(from "common::ZeroCopyConnection::cleanup_shared_memory()",
-                    "Trying to remove state {:?} on the connection {:?} which is already marked for destruction.", state_to_remove, storage.name());
-            return;
-        }
 
-        loop {
-            let new_state = if current_state == state_to_remove.value() {
-                State::MarkedForDestruction.value()
-            } else {
-                current_state & !state_to_remove.value()
+        let new_state = loop {
+            let new_state = match ConnectionState::from_value(current_state)
+                .map(|state| state.transition(event))
+            {
+                Some(Ok(new_state)) => new_state,
+                Some(Err(ConnectionTransitionError::MarkedForDestruction)) => {
+                    warn!(from origin,
+                        "Trying to apply {:?} on the connection {:?} which is already marked for destruction.", event, storage.name());
+                    return;
+                }
+                Some(Err(e)) => {
+                    debug!(from origin,
+                        "Ignoring {:?} on the connection {:?} since the transition is not possible ({:?}).", event, storage.name(), e);
+                    return;
+                }
+                None => {
+                    warn!(from origin,
+                        "Unable to apply {:?} on the connection {:?} since it has the corrupted state {:#b}.", event, storage.name(), current_state);
+                    return;
+                }
             };
 
             match storage.get().state.compare_exchange(
                 current_state,
-                new_state,
+                new_state.value(),
                 Ordering::Relaxed,
                 Ordering::Relaxed,
             ) {
-                Ok(_) => {
-                    current_state = new_state;
-                    break;
-                }
-                Err(s) => {
-                    current_state = s;
-                }
+                Ok(_) => break new_state,
+                Err(s) => current_state = s,
             }
-        }
+        };
 
-        if current_state == State::MarkedForDestruction.value() {
+        if new_state == ConnectionState::MarkedForDestruction {
             storage.acquire_ownership()
         }
     }
 
     #[derive(Debug)]
+    #[repr(C)]
     struct SegmentDetails {
         used_chunk_list: RelocatableUsedChunkList,
-        sample_size: IoxAtomicUsize,
+        sample_size: IoxAtomicU64,
     }
 
     impl SegmentDetails {
         fn new_uninit(number_of_samples: usize) -> Self {
             Self {
                 used_chunk_list: unsafe { RelocatableUsedChunkList::new_uninit(number_of_samples) },
-                sample_size: IoxAtomicUsize::new(0),
+                sample_size: IoxAtomicU64::new(0),
             }
         }
 
@@ -186,11 +179,12 @@ pub mod details {
         submission_channel: RelocatableSafelyOverflowingIndexQueue,
         completion_channel: RelocatableIndexQueue,
         segment_details: RelocatableVec<SegmentDetails>,
-        max_borrowed_samples: usize,
-        number_of_samples_per_segment: usize,
+        max_borrowed_samples: u64,
+        number_of_samples_per_segment: u64,
         number_of_segments: u8,
         state: IoxAtomicU8,
         init_state: IoxAtomicU64,
+        number_of_discarded_samples: IoxAtomicU64,
         enable_safe_overflow: bool,
     }
 
@@ -213,11 +207,12 @@ pub mod details {
                     RelocatableIndexQueue::new_uninit(completion_channel_buffer_capacity)
                 },
                 segment_details: unsafe { RelocatableVec::new_uninit(number_of_segments as usize) },
-                state: IoxAtomicU8::new(State::None.value()),
+                state: IoxAtomicU8::new(ConnectionState::Unused.value()),
                 init_state: IoxAtomicU64::new(0),
+                number_of_discarded_samples: IoxAtomicU64::new(0),
                 enable_safe_overflow,
-                max_borrowed_samples,
-                number_of_samples_per_segment,
+                max_borrowed_samples: max_borrowed_samples as u64,
+                number_of_samples_per_segment: number_of_samples_per_segment as u64,
                 number_of_segments,
             }
         }
@@ -359,7 +354,8 @@ pub mod details {
                         msg, storage.get().enable_safe_overflow, self.enable_safe_overflow);
                 }
 
-                if storage.get().number_of_samples_per_segment != self.number_of_samples_per_segment
+                if storage.get().number_of_samples_per_segment
+                    != self.number_of_samples_per_segment as u64
                 {
                     fail!(from self, with ZeroCopyCreationError::IncompatibleNumberOfSamples,
                         "{} since the requested number of samples is set to {} but should be set to {}.",
@@ -385,33 +381,40 @@ pub mod details {
         fn reserve_port(
             &self,
             mgmt_ref: &SharedManagementData,
-            new_state: u8,
+            event: ConnectionEvent,
             msg: &str,
         ) -> Result<(), ZeroCopyCreationError> {
-            let mut current_state = State::None.value();
+            let mut current_state = ConnectionState::Unused.value();
 
             loop {
+                let new_state = match ConnectionState::from_value(current_state)
+                    .map(|state| state.transition(event))
+                {
+                    Some(Ok(new_state)) => new_state,
+                    Some(Err(ConnectionTransitionError::PortAlreadyAttached)) => {
+                        fail!(from self, with ZeroCopyCreationError::AnotherInstanceIsAlreadyConnected,
+                            "{} since an instance is already connected.", msg);
+                    }
+                    Some(Err(ConnectionTransitionError::MarkedForDestruction)) => {
+                        fail!(from self, with ZeroCopyCreationError::InternalError,
+                            "{} since the connection is currently being cleaned up.", msg);
+                    }
+                    Some(Err(ConnectionTransitionError::PortNotAttached)) | None => {
+                        fail!(from self, with ZeroCopyCreationError::ConnectionMaybeCorrupted,
+                            "{} since the connection has the corrupted state {:#b}.", msg, current_state);
+                    }
+                };
+
                 match mgmt_ref.state.compare_exchange(
                     current_state,
-                    current_state | new_state,
+                    new_state.value(),
                     Ordering::Relaxed,
                     Ordering::Relaxed,
                 ) {
-                    Ok(_) => break,
-                    Err(v) => {
-                        current_state = v;
-                        if current_state & new_state != 0 {
-                            fail!(from self, with ZeroCopyCreationError::AnotherInstanceIsAlreadyConnected,
-                            "{} since an instance is already connected.", msg);
-                        } else if current_state & State::MarkedForDestruction.value() != 0 {
-                            fail!(from self, with ZeroCopyCreationError::InternalError,
-                            "{} since the connection is currently being cleaned up.", msg);
-                        }
-                    }
+                    Ok(_) => return Ok(()),
+                    Err(v) => current_state = v,
                 }
             }
-
-            Ok(())
         }
     }
 
@@ -478,7 +481,7 @@ pub mod details {
             let storage = fail!(from self, when self.create_or_open_shm(),
             "{} since the corresponding connection could not be created or opened", msg);
 
-            self.reserve_port(storage.get(), State::Sender.value(), msg)?;
+            self.reserve_port(storage.get(), ConnectionEvent::AttachSender, msg)?;
 
             Ok(Sender {
                 storage,
@@ -494,7 +497,7 @@ pub mod details {
             let storage = fail!(from self, when self.create_or_open_shm(),
             "{} since the corresponding connection could not be created or opened", msg);
 
-            self.reserve_port(storage.get(), State::Receiver.value(), msg)?;
+            self.reserve_port(storage.get(), ConnectionEvent::AttachReceiver, msg)?;
 
             Ok(Receiver {
                 storage,
@@ -512,7 +515,7 @@ pub mod details {
 
     impl<Storage: DynamicStorage<SharedManagementData>> Drop for Sender<Storage> {
         fn drop(&mut self) {
-            cleanup_shared_memory(&self.storage, State::Sender);
+            cleanup_shared_memory(&self.storage, ConnectionEvent::DetachSender);
         }
     }
 
@@ -532,7 +535,7 @@ pub mod details {
         }
 
         fn max_borrowed_samples(&self) -> usize {
-            self.storage.get().max_borrowed_samples
+            self.storage.get().max_borrowed_samples as usize
         }
 
         fn has_enabled_safe_overflow(&self) -> bool {
@@ -540,8 +543,14 @@ pub mod details {
         }
 
         fn is_connected(&self) -> bool {
-            self.storage.get().state.load(Ordering::Relaxed)
-                == State::Sender.value() | State::Receiver.value()
+            self.storage.get().state.load(Ordering::Relaxed) == ConnectionState::Connected.value()
+        }
+
+        fn number_of_discarded_samples(&self) -> u64 {
+            self.storage
+                .get()
+                .number_of_discarded_samples
+                .load(Ordering::Relaxed)
         }
     }
 
@@ -555,6 +564,9 @@ pub mod details {
             let storage = self.storage.get();
 
             if !storage.enable_safe_overflow && storage.submission_channel.is_full() {
+                storage
+                    .number_of_discarded_samples
+                    .fetch_add(1, Ordering::Relaxed);
                 fail!(from self, with ZeroCopySendError::ReceiveBufferFull,
                              "{} since the receive buffer is full.", msg);
             }
@@ -563,7 +575,7 @@ pub mod details {
             let segment_details = &storage.segment_details[segment_id];
             segment_details
                 .sample_size
-                .store(sample_size, Ordering::Relaxed);
+                .store(sample_size as u64, Ordering::Relaxed);
             debug_assert!(ptr.offset() % sample_size == 0);
             let index = ptr.offset() / sample_size;
 
@@ -580,17 +592,21 @@ pub mod details {
                     let segment_details = &storage.segment_details[segment_id];
                     debug_assert!(
                         pointer_offset.offset()
-                            % segment_details.sample_size.load(Ordering::Relaxed)
+                            % segment_details.sample_size.load(Ordering::Relaxed) as usize
                             == 0
                     );
                     let index = pointer_offset.offset()
-                        / segment_details.sample_size.load(Ordering::Relaxed);
+                        / segment_details.sample_size.load(Ordering::Relaxed) as usize;
 
                     if !segment_details.used_chunk_list.remove(index) {
                         fail!(from self, with ZeroCopySendError::ConnectionCorrupted,
                         "{} since the invalid offset {:?} was returned on overflow.", msg, pointer_offset);
                     }
 
+                    storage
+                        .number_of_discarded_samples
+                        .fetch_add(1, Ordering::Relaxed);
+
                     Ok(Some(pointer_offset))
                 }
                 None => Ok(None),
@@ -634,11 +650,11 @@ pub mod details {
                     let segment_details = &storage.segment_details[segment_id];
                     debug_assert!(
                         pointer_offset.offset()
-                            % segment_details.sample_size.load(Ordering::Relaxed)
+                            % segment_details.sample_size.load(Ordering::Relaxed) as usize
                             == 0
                     );
                     let index = pointer_offset.offset()
-                        / segment_details.sample_size.load(Ordering::Relaxed);
+                        / segment_details.sample_size.load(Ordering::Relaxed) as usize;
 
                     if !segment_details.used_chunk_list.remove(index) {
                         fail!(from self, with ZeroCopyReclaimError::ReceiverReturnedCorruptedPointerOffset,
@@ -654,7 +670,7 @@ pub mod details {
             for (n, segment_details) in self.storage.get().segment_details.iter().enumerate() {
                 segment_details.used_chunk_list.remove_all(|index| {
                     callback(PointerOffset::from_offset_and_segment_id(
-                        index * segment_details.sample_size.load(Ordering::Relaxed),
+                        index * segment_details.sample_size.load(Ordering::Relaxed) as usize,
                         SegmentId::new(n as u8),
                     ))
                 });
@@ -671,7 +687,7 @@ pub mod details {
 
     impl<Storage: DynamicStorage<SharedManagementData>> Drop for Receiver<Storage> {
         fn drop(&mut self) {
-            cleanup_shared_memory(&self.storage, State::Receiver);
+            cleanup_shared_memory(&self.storage, ConnectionEvent::DetachReceiver);
         }
     }
 
@@ -702,7 +718,7 @@ pub mod details {
         }
 
         fn max_borrowed_samples(&self) -> usize {
-            self.storage.get().max_borrowed_samples
+            self.storage.get().max_borrowed_samples as usize
         }
 
         fn has_enabled_safe_overflow(&self) -> bool {
@@ -710,8 +726,14 @@ pub mod details {
         }
 
         fn is_connected(&self) -> bool {
-            self.storage.get().state.load(Ordering::Relaxed)
-                == State::Sender.value() | State::Receiver.value()
+            self.storage.get().state.load(Ordering::Relaxed) == ConnectionState::Connected.value()
+        }
+
+        fn number_of_discarded_samples(&self) -> u64 {
+            self.storage
+                .get()
+                .number_of_discarded_samples
+                .load(Ordering::Relaxed)
         }
     }
 
@@ -721,7 +743,7 @@ pub mod details {
         }
 
         fn receive(&self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError> {
-            if *self.borrow_counter() >= self.storage.get().max_borrowed_samples {
+            if *self.borrow_counter() >= self.storage.get().max_borrowed_samples as usize {
                 fail!(from self, with ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue,
                 "Unable to receive another sample since already {} samples were borrowed and this would exceed the max borrow value of {}.",
                     self.borrow_counter(), self.max_borrowed_samples());
@@ -834,7 +856,7 @@ pub mod details {
                 config,
                 "Unable to remove forcefully the sender of the Zero Copy Connection",
             )?;
-            cleanup_shared_memory(&storage, State::Sender);
+            cleanup_shared_memory(&storage, ConnectionEvent::DetachSender);
             Ok(())
         }
 
@@ -847,7 +869,7 @@ pub mod details {
                 config,
                 "Unable to remove forcefully the receiver of the Zero Copy Connection",
             )?;
-            cleanup_shared_memory(&storage, State::Receiver);
+            cleanup_shared_memory(&storage, ConnectionEvent::DetachReceiver);
             Ok(())
         }
 
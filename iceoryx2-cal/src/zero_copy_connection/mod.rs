@@ -13,6 +13,8 @@
 pub mod common;
 pub mod posix_shared_memory;
 pub mod process_local;
+pub mod state_machine;
+pub mod static_memory;
 pub mod used_chunk_list;
 
 use core::fmt::Debug;
@@ -137,6 +139,9 @@ pub trait ZeroCopyPortDetails {
     fn max_borrowed_samples(&self) -> usize;
     fn max_supported_shared_memory_segments(&self) -> u8;
     fn is_connected(&self) -> bool;
+    /// Returns the number of samples that were discarded since the receive buffer was full,
+    /// either because they could not be sent or because they were overridden on overflow.
+    fn number_of_discarded_samples(&self) -> u64;
 }
 
 pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept {
@@ -0,0 +1,257 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The explicit state machines of the zero copy connection protocol.
+//!
+//! [`ConnectionState`] describes the lifetime of a connection between one
+//! [`ZeroCopySender`](crate::zero_copy_connection::ZeroCopySender) and one
+//! [`ZeroCopyReceiver`](crate::zero_copy_connection::ZeroCopyReceiver). It is stored in the
+//! shared management data of the connection and every attach and detach of a port is a
+//! [`ConnectionState::transition()`].
+//!
+//! [`ChunkState`] describes the ownership of a single chunk from the perspective of the
+//! connection. A chunk is owned by the sender until it is sent, it is then owned by the
+//! connection until the sender reclaims it. Every chunk that is not
+//! [`ChunkState::OwnedBySender`] is tracked in the used chunk list of the connection, so that
+//! the sender can recover it when the receiver is gone.
+//!
+//! Both transition tables are exhaustive. Every combination of state and event is either a
+//! valid transition or an explicit error, therefore the tables can be used as a reference
+//! for the safety argumentation of the protocol.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_cal::zero_copy_connection::state_machine::*;
+//!
+//! let state = ConnectionState::Unused
+//!     .transition(ConnectionEvent::AttachSender)
+//!     .and_then(|s| s.transition(ConnectionEvent::AttachReceiver));
+//! assert_eq!(state, Ok(ConnectionState::Connected));
+//!
+//! let chunk = ChunkState::OwnedBySender
+//!     .transition(ChunkEvent::Send)
+//!     .and_then(|s| s.transition(ChunkEvent::Receive));
+//! assert_eq!(chunk, Ok(ChunkState::BorrowedByReceiver));
+//! ```
+
+/// The states of a zero copy connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum ConnectionState {
+    /// Neither a sender nor a receiver is attached.
+    Unused = 0b00000000,
+    /// Only the sender is attached.
+    SenderAttached = 0b00000001,
+    /// Only the receiver is attached.
+    ReceiverAttached = 0b00000010,
+    /// Sender and receiver are attached.
+    Connected = 0b00000011,
+    /// The last port was detached and the connection is removed. This is a final state.
+    MarkedForDestruction = 0b10000000,
+}
+
+/// The events that change the [`ConnectionState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConnectionEvent {
+    /// A sender is created for the connection.
+    AttachSender,
+    /// A receiver is created for the connection.
+    AttachReceiver,
+    /// The sender goes out of scope or is removed.
+    DetachSender,
+    /// The receiver goes out of scope or is removed.
+    DetachReceiver,
+}
+
+/// Describes why a [`ConnectionEvent`] is not allowed in a [`ConnectionState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConnectionTransitionError {
+    /// The port that shall be attached is already attached.
+    PortAlreadyAttached,
+    /// The port that shall be detached is not attached.
+    PortNotAttached,
+    /// The connection is already marked for destruction.
+    MarkedForDestruction,
+}
+
+impl core::fmt::Display for ConnectionTransitionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl core::error::Error for ConnectionTransitionError {}
+
+impl ConnectionState {
+    /// All states of the connection.
+    pub const ALL: [ConnectionState; 5] = [
+        ConnectionState::Unused,
+        ConnectionState::SenderAttached,
+        ConnectionState::ReceiverAttached,
+        ConnectionState::Connected,
+        ConnectionState::MarkedForDestruction,
+    ];
+
+    /// Returns the state that follows when the [`ConnectionEvent`] occurs in the current
+    /// state.
+    pub fn transition(self, event: ConnectionEvent) -> Result<Self, ConnectionTransitionError> {
+        use ConnectionEvent::*;
+        use ConnectionState::*;
+
+        match (self, event) {
+            (Unused, AttachSender) => Ok(SenderAttached),
+            (Unused, AttachReceiver) => Ok(ReceiverAttached),
+            (Unused, DetachSender) => Err(ConnectionTransitionError::PortNotAttached),
+            (Unused, DetachReceiver) => Err(ConnectionTransitionError::PortNotAttached),
+
+            (SenderAttached, AttachSender) => Err(ConnectionTransitionError::PortAlreadyAttached),
+            (SenderAttached, AttachReceiver) => Ok(Connected),
+            (SenderAttached, DetachSender) => Ok(MarkedForDestruction),
+            (SenderAttached, DetachReceiver) => Err(ConnectionTransitionError::PortNotAttached),
+
+            (ReceiverAttached, AttachSender) => Ok(Connected),
+            (ReceiverAttached, AttachReceiver) => {
+                Err(ConnectionTransitionError::PortAlreadyAttached)
+            }
+            (ReceiverAttached, DetachSender) => Err(ConnectionTransitionError::PortNotAttached),
+            (ReceiverAttached, DetachReceiver) => Ok(MarkedForDestruction),
+
+            (Connected, AttachSender) => Err(ConnectionTransitionError::PortAlreadyAttached),
+            (Connected, AttachReceiver) => Err(ConnectionTransitionError::PortAlreadyAttached),
+            (Connected, DetachSender) => Ok(ReceiverAttached),
+            (Connected, DetachReceiver) => Ok(SenderAttached),
+
+            (MarkedForDestruction, _) => Err(ConnectionTransitionError::MarkedForDestruction),
+        }
+    }
+
+    /// Returns true when a sender is attached.
+    pub fn has_sender(&self) -> bool {
+        matches!(
+            self,
+            ConnectionState::SenderAttached | ConnectionState::Connected
+        )
+    }
+
+    /// Returns true when a receiver is attached.
+    pub fn has_receiver(&self) -> bool {
+        matches!(
+            self,
+            ConnectionState::ReceiverAttached | ConnectionState::Connected
+        )
+    }
+
+    pub(crate) fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub(crate) fn from_value(value: u8) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.value() == value)
+    }
+}
+
+/// The ownership states of a single chunk in a zero copy connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChunkState {
+    /// The chunk is owned by the sender and not part of the connection.
+    OwnedBySender,
+    /// The chunk was sent and is stored in the submission channel.
+    Submitted,
+    /// The receiver received the chunk and borrows it.
+    BorrowedByReceiver,
+    /// The receiver released the chunk into the completion channel.
+    Completed,
+}
+
+/// The events that change the [`ChunkState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChunkEvent {
+    /// The sender sends the chunk.
+    Send,
+    /// The chunk is returned to the sender on a safe overflow of the submission channel.
+    Overflow,
+    /// The receiver receives the chunk.
+    Receive,
+    /// The receiver releases the chunk.
+    Release,
+    /// The sender reclaims the chunk from the completion channel.
+    Reclaim,
+    /// The receiver is gone and the sender recovers all chunks of the connection.
+    AcquireUsed,
+}
+
+/// Describes why a [`ChunkEvent`] is not allowed in a [`ChunkState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChunkTransitionError {
+    /// The event requires a chunk that is owned by the sender.
+    NotOwnedBySender,
+    /// The event requires a chunk that is stored in the submission channel.
+    NotSubmitted,
+    /// The event requires a chunk that is borrowed by the receiver.
+    NotBorrowedByReceiver,
+    /// The event requires a chunk that is stored in the completion channel.
+    NotCompleted,
+    /// The event requires a chunk that is part of the connection.
+    NotPartOfConnection,
+}
+
+impl core::fmt::Display for ChunkTransitionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl core::error::Error for ChunkTransitionError {}
+
+impl ChunkState {
+    /// All states of a chunk.
+    pub const ALL: [ChunkState; 4] = [
+        ChunkState::OwnedBySender,
+        ChunkState::Submitted,
+        ChunkState::BorrowedByReceiver,
+        ChunkState::Completed,
+    ];
+
+    /// Returns the state that follows when the [`ChunkEvent`] occurs in the current state.
+    pub fn transition(self, event: ChunkEvent) -> Result<Self, ChunkTransitionError> {
+        use ChunkEvent::*;
+        use ChunkState::*;
+        use ChunkTransitionError::*;
+
+        match (self, event) {
+            (OwnedBySender, Send) => Ok(Submitted),
+            (_, Send) => Err(NotOwnedBySender),
+
+            (Submitted, Overflow) => Ok(OwnedBySender),
+            (_, Overflow) => Err(NotSubmitted),
+
+            (Submitted, Receive) => Ok(BorrowedByReceiver),
+            (_, Receive) => Err(NotSubmitted),
+
+            (BorrowedByReceiver, Release) => Ok(Completed),
+            (_, Release) => Err(NotBorrowedByReceiver),
+
+            (Completed, Reclaim) => Ok(OwnedBySender),
+            (_, Reclaim) => Err(NotCompleted),
+
+            (OwnedBySender, AcquireUsed) => Err(NotPartOfConnection),
+            (Submitted | BorrowedByReceiver | Completed, AcquireUsed) => Ok(OwnedBySender),
+        }
+    }
+
+    /// Returns true when the chunk is tracked in the used chunk list of the connection. These
+    /// are exactly the chunks the sender recovers with [`ChunkEvent::AcquireUsed`].
+    pub fn is_part_of_connection(&self) -> bool {
+        *self != ChunkState::OwnedBySender
+    }
+}
@@ -34,7 +34,7 @@ pub mod details {
     #[repr(C)]
     pub struct UsedChunkList<PointerType: PointerTrait<IoxAtomicBool>> {
         data_ptr: PointerType,
-        capacity: usize,
+        capacity: u64,
         is_memory_initialized: IoxAtomicBool,
     }
 
@@ -56,7 +56,7 @@ pub mod details {
 
             Self {
                 data_ptr,
-                capacity,
+                capacity: capacity as u64,
                 is_memory_initialized: IoxAtomicBool::new(true),
             }
         }
@@ -66,7 +66,7 @@ pub mod details {
         unsafe fn new_uninit(capacity: usize) -> Self {
             Self {
                 data_ptr: RelocatablePointer::new_uninit(),
-                capacity,
+                capacity: capacity as u64,
                 is_memory_initialized: IoxAtomicBool::new(false),
             }
         }
@@ -82,13 +82,13 @@ pub mod details {
 
             let memory = fail!(from self, when allocator
             .allocate(Layout::from_size_align_unchecked(
-                    core::mem::size_of::<IoxAtomicBool>() * self.capacity,
+                    core::mem::size_of::<IoxAtomicBool>() * self.capacity(),
                     core::mem::align_of::<IoxAtomicBool>())),
             "Failed to initialize since the allocation of the data memory failed.");
 
             self.data_ptr.init(memory);
 
-            for i in 0..self.capacity {
+            for i in 0..self.capacity() {
                 unsafe {
                     (self.data_ptr.as_ptr() as *mut IoxAtomicBool)
                         .add(i)
@@ -115,7 +115,7 @@ pub mod details {
         }
 
         pub fn capacity(&self) -> usize {
-            self.capacity
+            self.capacity as usize
         }
 
         #[inline(always)]
@@ -130,7 +130,7 @@ pub mod details {
         fn set(&self, idx: usize, value: bool) -> bool {
             self.verify_init("set");
             debug_assert!(
-                idx < self.capacity,
+                idx < self.capacity(),
                 "This should never happen. Out of bounds access with index {}.",
                 idx
             );
@@ -149,7 +149,7 @@ pub mod details {
         pub fn remove_all<F: FnMut(usize)>(&self, mut callback: F) {
             self.verify_init("pop");
 
-            for i in 0..self.capacity {
+            for i in 0..self.capacity() {
                 if unsafe { (*self.data_ptr.as_ptr().add(i)).swap(false, Ordering::Relaxed) } {
                     callback(i);
                 }
@@ -12,13 +12,19 @@
 
 mod dynamic_storage_posix_shared_memory {
     use core::time::Duration;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_posix::access_control_list::AccessControlListBuilder;
+    use iceoryx2_bb_posix::access_mode::AccessMode;
     use iceoryx2_bb_posix::creation_mode::CreationMode;
+    use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
     use iceoryx2_bb_posix::permission::Permission;
     use iceoryx2_bb_posix::shared_memory::SharedMemoryBuilder;
-    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_system_types::path::Path;
+    use iceoryx2_bb_testing::{assert_that, test_requires};
     use iceoryx2_cal::dynamic_storage::*;
     use iceoryx2_cal::named_concept::*;
     use iceoryx2_cal::testing::*;
+    use iceoryx2_pal_posix::posix::POSIX_SUPPORT_PERMISSIONS;
 
     const TIMEOUT: Duration = Duration::from_millis(100);
 
@@ -102,4 +108,62 @@ mod dynamic_storage_posix_shared_memory {
         assert_that!(sut.err().unwrap(), eq DynamicStorageOpenError::InitializationNotYetFinalized);
         assert_that!(start.elapsed().unwrap(), ge TIMEOUT);
     }
+
+    #[test]
+    fn falls_back_to_regular_pages_when_huge_pages_are_not_available() {
+        type Sut = iceoryx2_cal::dynamic_storage::posix_shared_memory::Storage<TestData>;
+        let storage_name = generate_name();
+        let config = generate_isolated_config::<Sut>().huge_pages(Some(HugePages {
+            page_size: HugePageSize::Size2MiB,
+            directory: Path::new(b"/this/directory/does/not/exist").unwrap(),
+        }));
+
+        let sut = <Sut as DynamicStorage<TestData>>::Builder::new(&storage_name)
+            .config(&config)
+            .create(TestData {})
+            .unwrap();
+        assert_that!(sut.huge_pages(), is_none);
+
+        let opened = <Sut as DynamicStorage<TestData>>::Builder::new(&storage_name)
+            .config(&config)
+            .open();
+        assert_that!(opened, is_ok);
+        assert_that!(Sut::does_exist_cfg(&storage_name, &config), eq Ok(true));
+        assert_that!(Sut::list_cfg(&config).unwrap(), contains storage_name);
+
+        let sut2 = <Sut as DynamicStorage<TestData>>::Builder::new(&storage_name)
+            .config(&config)
+            .create(TestData {});
+        assert_that!(sut2.err(), eq Some(DynamicStorageCreateError::AlreadyExists));
+    }
+
+    #[test]
+    fn access_control_list_is_applied_to_underlying_shared_memory() {
+        test_requires!(POSIX_SUPPORT_PERMISSIONS);
+
+        type Sut = iceoryx2_cal::dynamic_storage::posix_shared_memory::Storage<TestData>;
+        let storage_name = generate_name();
+        let config = generate_isolated_config::<Sut>().access_control_list(Some(
+            AccessControlListBuilder::new()
+                .permission(Permission::GROUP_READ | Permission::GROUP_WRITE)
+                .create(),
+        ));
+        let file_name = config.path_for(&storage_name).file_name();
+
+        let _sut = <Sut as DynamicStorage<TestData>>::Builder::new(&storage_name)
+            .config(&config)
+            .create(TestData {})
+            .unwrap();
+
+        let raw_shm = SharedMemoryBuilder::new(&file_name)
+            .open_existing(AccessMode::Read)
+            .unwrap();
+        assert_that!(raw_shm.permission().unwrap(),
+            eq Permission::OWNER_ALL | Permission::GROUP_READ | Permission::GROUP_WRITE);
+
+        let opened = <Sut as DynamicStorage<TestData>>::Builder::new(&storage_name)
+            .config(&config)
+            .open();
+        assert_that!(opened, is_ok);
+    }
 }
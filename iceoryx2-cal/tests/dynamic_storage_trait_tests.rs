@@ -632,11 +632,16 @@ mod dynamic_storage {
         let state = LifetimeTracker::start_tracking();
         let config = generate_isolated_config::<Sut>();
 
+        // skip process local and static memory test since the process locality ensures that an
+        // initializer never dies
         if core::any::TypeId::of::<Sut>()
-            // skip process local test since the process locality ensures that an initializer
-            // never dies
-            != core::any::TypeId::of::<iceoryx2_cal::dynamic_storage::process_local::Storage<TestData>>(
-            )
+            != core::any::TypeId::of::<
+                iceoryx2_cal::dynamic_storage::process_local::Storage<TestData>,
+            >()
+            && core::any::TypeId::of::<Sut>()
+                != core::any::TypeId::of::<
+                    iceoryx2_cal::dynamic_storage::static_memory::Storage<TestData>,
+                >()
         {
             let storage_name = generate_name();
 
@@ -782,4 +787,8 @@ mod dynamic_storage {
     #[instantiate_tests(<iceoryx2_cal::dynamic_storage::process_local::Storage<TestData>,
                          iceoryx2_cal::dynamic_storage::process_local::Storage<u64>>)]
     mod process_local {}
+
+    #[instantiate_tests(<iceoryx2_cal::dynamic_storage::static_memory::Storage<TestData>,
+                         iceoryx2_cal::dynamic_storage::static_memory::Storage<u64>>)]
+    mod static_memory {}
 }
@@ -741,9 +741,15 @@ mod event {
     #[instantiate_tests(<iceoryx2_cal::event::unix_datagram_socket::EventImpl>)]
     mod unix_datagram {}
 
+    #[instantiate_tests(<iceoryx2_cal::event::io_uring::EventImpl>)]
+    mod io_uring {}
+
     #[instantiate_tests(<iceoryx2_cal::event::sem_bitset_process_local::Event>)]
     mod sem_bitset_process_local {}
 
+    #[instantiate_tests(<iceoryx2_cal::event::sem_bitset_static_memory::Event>)]
+    mod sem_bitset_static_memory {}
+
     #[cfg(not(any(target_os = "macos", target_os = "windows")))]
     #[instantiate_tests(<iceoryx2_cal::event::sem_bitset_posix_shared_memory::Event>)]
     mod sem_bitset_posix_shared_memory {}
@@ -0,0 +1,69 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod hash {
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::hash::*;
+
+    #[test]
+    fn same_input_creates_same_hash<Sut: Hash>() {
+        let sut_1 = Sut::new(b"all glory to the hypnotoad");
+        let sut_2 = Sut::new(b"all glory to the hypnotoad");
+
+        assert_that!(sut_1.value(), eq sut_2.value());
+    }
+
+    #[test]
+    fn different_input_creates_different_hash<Sut: Hash>() {
+        let sut_1 = Sut::new(b"all glory to the hypnotoad");
+        let sut_2 = Sut::new(b"all glory to the hypnotoat");
+
+        assert_that!(sut_1.value(), ne sut_2.value());
+    }
+
+    #[instantiate_tests(<iceoryx2_cal::hash::sha1::Sha1>)]
+    mod sha1 {}
+
+    #[instantiate_tests(<iceoryx2_cal::hash::sip_hash::SipHash>)]
+    mod sip_hash {}
+}
+
+mod sip_hash {
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::hash::sip_hash::SipHash;
+    use iceoryx2_cal::hash::*;
+
+    const KEY: [u64; 2] = [0x0706050403020100, 0x0f0e0d0c0b0a0908];
+
+    #[test]
+    fn matches_reference_test_vectors() {
+        let message: Vec<u8> = (0..15).collect();
+
+        let value: String = SipHash::new_with_key(&KEY, &message[..0]).value().into();
+        assert_that!(value, eq "a3817f04ba25a8e66df67214c7550293");
+
+        let value: String = SipHash::new_with_key(&KEY, &message[..8]).value().into();
+        assert_that!(value, eq "3b62a9ba6258f5610f83e264f31497b4");
+
+        let value: String = SipHash::new_with_key(&KEY, &message).value().into();
+        assert_that!(value, eq "5493e99933b0a8117e08ec0f97cfc3d9");
+    }
+
+    #[test]
+    fn different_keys_create_different_hashes() {
+        let sut_1 = SipHash::new_with_key(&KEY, b"hypnotoad");
+        let sut_2 = SipHash::new_with_key(&[KEY[0], KEY[1] + 1], b"hypnotoad");
+
+        assert_that!(sut_1.value(), ne sut_2.value());
+    }
+}
@@ -68,7 +68,12 @@ mod monitoring {
     }
 
     #[test]
-    #[cfg(not(any(target_os = "linux", target_os = "freebsd", target_os = "macos")))]
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "macos",
+        target_os = "nto"
+    )))]
     fn monitor_works<Sut: Monitoring>() {
         let name = generate_name();
         let config = generate_isolated_config::<Sut>();
@@ -182,6 +187,12 @@ mod monitoring {
     #[instantiate_tests(<iceoryx2_cal::monitoring::file_lock::FileLockMonitoring>)]
     mod file_lock {}
 
+    #[instantiate_tests(<iceoryx2_cal::monitoring::pidfd::PidFdMonitoring>)]
+    mod pidfd {}
+
     #[instantiate_tests(<iceoryx2_cal::monitoring::process_local::ProcessLocalMonitoring>)]
     mod process_local {}
+
+    #[instantiate_tests(<iceoryx2_cal::monitoring::robust_mutex::RobustMutexMonitoring>)]
+    mod robust_mutex {}
 }
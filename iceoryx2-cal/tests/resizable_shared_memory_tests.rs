@@ -109,6 +109,31 @@ mod resizable_shared_memory {
         assert_that!(sut.number_of_active_segments(), eq 4);
     }
 
+    #[test]
+    fn size_grows_with_additional_segments<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        let storage_name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u8>())
+            .max_number_of_chunks_hint(128)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+
+        sut.allocate(Layout::new::<u8>()).unwrap();
+        let initial_size = sut.size();
+        assert_that!(initial_size, ge 128);
+
+        sut.allocate(Layout::new::<u64>()).unwrap();
+        assert_that!(sut.number_of_active_segments(), eq 2);
+        assert_that!(sut.size(), gt initial_size);
+    }
+
     #[test]
     fn allocate_more_than_hinted_works<
         Shm: SharedMemory<DefaultAllocator>,
@@ -794,6 +819,42 @@ mod resizable_shared_memory {
         );
     }
 
+    #[test]
+    fn rejected_resize_fails_allocation_and_keeps_segments<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        let config = generate_isolated_config::<Sut>();
+        let storage_name = generate_name();
+
+        let sut_creator = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u8>())
+            .max_number_of_chunks_hint(1)
+            .allocation_strategy(AllocationStrategy::BestFit)
+            .create()
+            .unwrap();
+        let size = sut_creator.size();
+
+        let mut requested_size = 0;
+        let result = sut_creator.allocate_with_resize_approval(
+            Layout::from_size_align(1024, 1).unwrap(),
+            |payload_size| {
+                requested_size = payload_size;
+                false
+            },
+        );
+        assert_that!(result.err().unwrap(), eq ResizableShmAllocationError::ResizeRejected);
+        assert_that!(requested_size, ge 1024);
+        assert_that!(sut_creator.number_of_active_segments(), eq 1);
+        assert_that!(sut_creator.size(), eq size);
+
+        let result = sut_creator
+            .allocate_with_resize_approval(Layout::from_size_align(1024, 1).unwrap(), |_| true);
+        assert_that!(result, is_ok);
+        assert_that!(sut_creator.size(), ge 1024);
+    }
+
     #[test]
     fn register_offset_in_view_maps_required_segments<
         Shm: SharedMemory<DefaultAllocator>,
@@ -989,4 +1050,7 @@ mod resizable_shared_memory {
 
     #[instantiate_tests(<iceoryx2_cal::shared_memory::process_local::Memory<DefaultAllocator>, resizable_shared_memory::dynamic::DynamicMemory<DefaultAllocator, iceoryx2_cal::shared_memory::process_local::Memory<DefaultAllocator>>>)]
     mod process_local {}
+
+    #[instantiate_tests(<iceoryx2_cal::shared_memory::static_memory::Memory<DefaultAllocator>, resizable_shared_memory::dynamic::DynamicMemory<DefaultAllocator, iceoryx2_cal::shared_memory::static_memory::Memory<DefaultAllocator>>>)]
+    mod static_memory {}
 }
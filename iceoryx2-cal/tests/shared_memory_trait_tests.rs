@@ -401,4 +401,7 @@ mod shared_memory {
 
     #[instantiate_tests(<iceoryx2_cal::shared_memory::process_local::Memory<DefaultAllocator>>)]
     mod process_local {}
+
+    #[instantiate_tests(<iceoryx2_cal::shared_memory::static_memory::Memory<DefaultAllocator>>)]
+    mod static_memory {}
 }
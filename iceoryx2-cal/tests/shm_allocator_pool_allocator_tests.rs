@@ -205,6 +205,22 @@ mod shm_allocator_pool_allocator {
         }
     }
 
+    #[test]
+    fn number_of_used_buckets_is_tracked() {
+        let test_context = TestContext::new(BUCKET_CONFIG);
+        assert_that!(test_context.sut.number_of_used_buckets(), eq 0);
+
+        let memory_1 = unsafe { test_context.sut.allocate(BUCKET_CONFIG).unwrap() };
+        let memory_2 = unsafe { test_context.sut.allocate(BUCKET_CONFIG).unwrap() };
+        assert_that!(test_context.sut.number_of_used_buckets(), eq 2);
+
+        unsafe { test_context.sut.deallocate(memory_1, BUCKET_CONFIG) };
+        assert_that!(test_context.sut.number_of_used_buckets(), eq 1);
+
+        unsafe { test_context.sut.deallocate_bucket(memory_2) };
+        assert_that!(test_context.sut.number_of_used_buckets(), eq 0);
+    }
+
     #[test]
     fn allocate_twice_release_once_until_memory_is_exhausted_works() {
         const REPETITIONS: usize = 10;
@@ -141,3 +141,141 @@ fn static_storage_file_custom_path_and_suffix_list_storage_works() {
         File::remove(file).unwrap();
     }
 }
+
+#[cfg(feature = "static_storage_encryption")]
+mod static_storage_file_encryption {
+    use super::*;
+    use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
+
+    fn encryption_key() -> [u8; 32] {
+        [42; 32]
+    }
+
+    fn other_encryption_key() -> [u8; 32] {
+        [43; 32]
+    }
+
+    #[test]
+    fn static_storage_file_with_encryption_stores_encrypted_contents() {
+        let storage_name = generate_name();
+        let config =
+            generate_isolated_config::<Storage>().encryption_key_provider(Some(encryption_key));
+        let content = "some secret storage content".to_string();
+
+        let _storage_guard = Builder::new(&storage_name)
+            .config(&config)
+            .create(content.as_bytes())
+            .unwrap();
+
+        let mut raw_content = vec![];
+        FileBuilder::new(&config.path_for(&storage_name))
+            .open_existing(AccessMode::Read)
+            .unwrap()
+            .read_to_vector(&mut raw_content)
+            .unwrap();
+        assert_that!(raw_content.len(), gt content.len());
+        assert_that!(raw_content.windows(content.len()).any(|w| w == content.as_bytes()), eq false);
+
+        let storage_reader = Builder::new(&storage_name)
+            .config(&config)
+            .open(Duration::ZERO)
+            .unwrap();
+
+        let content_len = content.len() as u64;
+        assert_that!(storage_reader, len content_len);
+
+        let mut read_content = String::from_utf8(vec![b' '; content.len()]).unwrap();
+        storage_reader
+            .read(unsafe { read_content.as_mut_vec() }.as_mut_slice())
+            .unwrap();
+        assert_that!(read_content, eq content);
+    }
+
+    #[test]
+    fn static_storage_file_with_encryption_detects_modified_contents() {
+        let storage_name = generate_name();
+        let config =
+            generate_isolated_config::<Storage>().encryption_key_provider(Some(encryption_key));
+        let content = "some secret storage content".to_string();
+
+        let _storage_guard = Builder::new(&storage_name)
+            .config(&config)
+            .create(content.as_bytes())
+            .unwrap();
+
+        let file_path = config.path_for(&storage_name);
+        let mut file = FileBuilder::new(&file_path)
+            .open_existing(AccessMode::Read)
+            .unwrap();
+        file.set_permission(Permission::OWNER_ALL).unwrap();
+        let mut file = FileBuilder::new(&file_path)
+            .open_existing(AccessMode::ReadWrite)
+            .unwrap();
+        let mut byte = [0u8; 1];
+        file.read_range(30, &mut byte).unwrap();
+        byte[0] ^= 1;
+        file.write_at(30, &byte).unwrap();
+        file.set_permission(Permission::OWNER_READ).unwrap();
+
+        let storage_reader = Builder::new(&storage_name)
+            .config(&config)
+            .open(Duration::ZERO)
+            .unwrap();
+
+        let mut read_content = vec![0u8; content.len()];
+        assert_that!(storage_reader.read(&mut read_content), eq Err(StaticStorageReadError::StaticStorageWasModified));
+    }
+
+    #[test]
+    fn static_storage_file_with_encryption_and_different_key_cannot_be_read() {
+        let storage_name = generate_name();
+        let config =
+            generate_isolated_config::<Storage>().encryption_key_provider(Some(encryption_key));
+        let content = "some secret storage content".to_string();
+
+        let _storage_guard = Builder::new(&storage_name)
+            .config(&config)
+            .create(content.as_bytes())
+            .unwrap();
+
+        let storage_reader = Builder::new(&storage_name)
+            .config(
+                &config
+                    .clone()
+                    .encryption_key_provider(Some(other_encryption_key)),
+            )
+            .open(Duration::ZERO)
+            .unwrap();
+
+        let mut read_content = vec![0u8; content.len()];
+        assert_that!(storage_reader.read(&mut read_content), eq Err(StaticStorageReadError::StaticStorageWasModified));
+    }
+
+    #[test]
+    fn static_storage_file_with_encryption_uses_random_nonce() {
+        const NONCE_LENGTH: usize = 24;
+        let config =
+            generate_isolated_config::<Storage>().encryption_key_provider(Some(encryption_key));
+        let content = "some secret storage content".to_string();
+
+        let mut nonces = vec![];
+        for _ in 0..2 {
+            let storage_name = generate_name();
+            let _storage_guard = Builder::new(&storage_name)
+                .config(&config)
+                .create(content.as_bytes())
+                .unwrap();
+
+            let mut raw_content = vec![];
+            FileBuilder::new(&config.path_for(&storage_name))
+                .open_existing(AccessMode::Read)
+                .unwrap()
+                .read_to_vector(&mut raw_content)
+                .unwrap();
+            nonces.push(raw_content[..NONCE_LENGTH].to_vec());
+        }
+
+        assert_that!(nonces[0], ne nonces[1]);
+        assert_that!(nonces[0].iter().any(|b| *b != 0), eq true);
+    }
+}
@@ -546,4 +546,7 @@ mod static_storage {
 
     #[instantiate_tests(<iceoryx2_cal::static_storage::process_local::Storage>)]
     mod process_local {}
+
+    #[instantiate_tests(<iceoryx2_cal::static_storage::static_memory::Storage>)]
+    mod static_memory {}
 }
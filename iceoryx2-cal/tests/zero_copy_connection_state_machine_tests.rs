@@ -0,0 +1,265 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod zero_copy_connection_state_machine {
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::zero_copy_connection::state_machine::*;
+
+    const NUMBER_OF_ITERATIONS: usize = 10000;
+    const SEEDS: [u64; 4] = [1, 42, 4711, 0x9e3779b97f4a7c15];
+
+    const CONNECTION_EVENTS: [ConnectionEvent; 4] = [
+        ConnectionEvent::AttachSender,
+        ConnectionEvent::AttachReceiver,
+        ConnectionEvent::DetachSender,
+        ConnectionEvent::DetachReceiver,
+    ];
+
+    const CHUNK_EVENTS: [ChunkEvent; 6] = [
+        ChunkEvent::Send,
+        ChunkEvent::Overflow,
+        ChunkEvent::Receive,
+        ChunkEvent::Release,
+        ChunkEvent::Reclaim,
+        ChunkEvent::AcquireUsed,
+    ];
+
+    // deterministic xorshift so that a failing sequence can be reproduced with its seed
+    struct Random(u64);
+
+    impl Random {
+        fn next(&mut self, upper_bound: usize) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % upper_bound as u64) as usize
+        }
+    }
+
+    #[test]
+    fn connection_transition_table_is_complete() {
+        use ConnectionState::*;
+        use ConnectionTransitionError::PortAlreadyAttached as Attached;
+        use ConnectionTransitionError::PortNotAttached as NotAttached;
+
+        let destroyed = Err(ConnectionTransitionError::MarkedForDestruction);
+        let table = [
+            (
+                Unused,
+                [
+                    Ok(SenderAttached),
+                    Ok(ReceiverAttached),
+                    Err(NotAttached),
+                    Err(NotAttached),
+                ],
+            ),
+            (
+                SenderAttached,
+                [
+                    Err(Attached),
+                    Ok(Connected),
+                    Ok(MarkedForDestruction),
+                    Err(NotAttached),
+                ],
+            ),
+            (
+                ReceiverAttached,
+                [
+                    Ok(Connected),
+                    Err(Attached),
+                    Err(NotAttached),
+                    Ok(MarkedForDestruction),
+                ],
+            ),
+            (
+                Connected,
+                [
+                    Err(Attached),
+                    Err(Attached),
+                    Ok(ReceiverAttached),
+                    Ok(SenderAttached),
+                ],
+            ),
+            (
+                MarkedForDestruction,
+                [destroyed, destroyed, destroyed, destroyed],
+            ),
+        ];
+
+        assert_that!(table, len ConnectionState::ALL.len());
+        for (state, expected_transitions) in table {
+            for (event, expected) in CONNECTION_EVENTS.iter().zip(expected_transitions) {
+                assert_that!(state.transition(*event), eq expected);
+            }
+        }
+    }
+
+    #[test]
+    fn marked_for_destruction_is_final_connection_state() {
+        for event in CONNECTION_EVENTS {
+            assert_that!(
+                ConnectionState::MarkedForDestruction.transition(event),
+                eq Err(ConnectionTransitionError::MarkedForDestruction)
+            );
+        }
+    }
+
+    #[test]
+    fn connection_state_tracks_attached_ports_for_random_event_sequences() {
+        for seed in SEEDS {
+            let mut random = Random(seed);
+            let mut sut = ConnectionState::Unused;
+            let mut has_sender = false;
+            let mut has_receiver = false;
+
+            for _ in 0..NUMBER_OF_ITERATIONS {
+                let event = CONNECTION_EVENTS[random.next(CONNECTION_EVENTS.len())];
+                let is_allowed = match event {
+                    ConnectionEvent::AttachSender => !has_sender,
+                    ConnectionEvent::AttachReceiver => !has_receiver,
+                    ConnectionEvent::DetachSender => has_sender,
+                    ConnectionEvent::DetachReceiver => has_receiver,
+                };
+
+                match sut.transition(event) {
+                    Ok(new_state) => {
+                        assert_that!(is_allowed, eq true);
+                        match event {
+                            ConnectionEvent::AttachSender => has_sender = true,
+                            ConnectionEvent::AttachReceiver => has_receiver = true,
+                            ConnectionEvent::DetachSender => has_sender = false,
+                            ConnectionEvent::DetachReceiver => has_receiver = false,
+                        }
+                        sut = new_state;
+                    }
+                    Err(_) => assert_that!(is_allowed, eq false),
+                }
+
+                if sut == ConnectionState::MarkedForDestruction {
+                    assert_that!(has_sender || has_receiver, eq false);
+                    // a new connection is created as soon as the next port attaches
+                    sut = ConnectionState::Unused;
+                }
+
+                assert_that!(sut.has_sender(), eq has_sender);
+                assert_that!(sut.has_receiver(), eq has_receiver);
+            }
+        }
+    }
+
+    #[test]
+    fn chunk_transition_table_is_complete() {
+        use ChunkState::*;
+        use ChunkTransitionError::*;
+
+        let table = [
+            (
+                OwnedBySender,
+                [
+                    Ok(Submitted),
+                    Err(NotSubmitted),
+                    Err(NotSubmitted),
+                    Err(NotBorrowedByReceiver),
+                    Err(NotCompleted),
+                    Err(NotPartOfConnection),
+                ],
+            ),
+            (
+                Submitted,
+                [
+                    Err(NotOwnedBySender),
+                    Ok(OwnedBySender),
+                    Ok(BorrowedByReceiver),
+                    Err(NotBorrowedByReceiver),
+                    Err(NotCompleted),
+                    Ok(OwnedBySender),
+                ],
+            ),
+            (
+                BorrowedByReceiver,
+                [
+                    Err(NotOwnedBySender),
+                    Err(NotSubmitted),
+                    Err(NotSubmitted),
+                    Ok(Completed),
+                    Err(NotCompleted),
+                    Ok(OwnedBySender),
+                ],
+            ),
+            (
+                Completed,
+                [
+                    Err(NotOwnedBySender),
+                    Err(NotSubmitted),
+                    Err(NotSubmitted),
+                    Err(NotBorrowedByReceiver),
+                    Ok(OwnedBySender),
+                    Ok(OwnedBySender),
+                ],
+            ),
+        ];
+
+        assert_that!(table, len ChunkState::ALL.len());
+        for (state, expected_transitions) in table {
+            for (event, expected) in CHUNK_EVENTS.iter().zip(expected_transitions) {
+                assert_that!(state.transition(*event), eq expected);
+            }
+        }
+    }
+
+    #[test]
+    fn only_chunks_owned_by_sender_are_not_part_of_connection() {
+        for state in ChunkState::ALL {
+            assert_that!(state.is_part_of_connection(), eq state != ChunkState::OwnedBySender);
+            assert_that!(
+                state.transition(ChunkEvent::AcquireUsed).is_ok(),
+                eq state.is_part_of_connection()
+            );
+        }
+    }
+
+    #[test]
+    fn every_chunk_returns_to_sender_for_random_event_sequences() {
+        for seed in SEEDS {
+            let mut random = Random(seed);
+            let mut sut = ChunkState::OwnedBySender;
+            let mut number_of_sends = 0u64;
+            let mut number_of_returns = 0u64;
+
+            for _ in 0..NUMBER_OF_ITERATIONS {
+                let event = CHUNK_EVENTS[random.next(CHUNK_EVENTS.len())];
+                if let Ok(new_state) = sut.transition(event) {
+                    match (
+                        sut.is_part_of_connection(),
+                        new_state.is_part_of_connection(),
+                    ) {
+                        (false, true) => number_of_sends += 1,
+                        (true, false) => number_of_returns += 1,
+                        _ => (),
+                    }
+                    sut = new_state;
+                }
+
+                // a chunk is part of the connection exactly between a send and its return
+                assert_that!(
+                    number_of_sends - number_of_returns,
+                    eq sut.is_part_of_connection() as u64
+                );
+            }
+
+            if sut.is_part_of_connection() {
+                sut = sut.transition(ChunkEvent::AcquireUsed).unwrap();
+            }
+            assert_that!(sut, eq ChunkState::OwnedBySender);
+        }
+    }
+}
@@ -27,6 +27,7 @@ mod zero_copy_connection {
     use iceoryx2_cal::shm_allocator::{PointerOffset, SegmentId};
     use iceoryx2_cal::testing::{generate_isolated_config, generate_name};
     use iceoryx2_cal::zero_copy_connection;
+    use iceoryx2_cal::zero_copy_connection::state_machine::*;
     use iceoryx2_cal::zero_copy_connection::*;
 
     const TIMEOUT: Duration = Duration::from_millis(25);
@@ -392,6 +393,46 @@ mod zero_copy_connection {
         assert_that!(result.err().unwrap(), eq ZeroCopySendError::ReceiveBufferFull);
     }
 
+    #[test]
+    fn discarded_samples_are_counted_when_buffer_is_full<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        const BUFFER_SIZE: usize = 12;
+        const NUMBER_OF_DISCARDED_SAMPLES: usize = 5;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE * i), SAMPLE_SIZE),
+                is_ok
+            );
+        }
+        assert_that!(sut_sender.number_of_discarded_samples(), eq 0);
+
+        for i in 0..NUMBER_OF_DISCARDED_SAMPLES {
+            let sample_offset = SAMPLE_SIZE * (BUFFER_SIZE + i);
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
+                is_err
+            );
+        }
+
+        assert_that!(sut_sender.number_of_discarded_samples(), eq NUMBER_OF_DISCARDED_SAMPLES as u64);
+        assert_that!(sut_receiver.number_of_discarded_samples(), eq NUMBER_OF_DISCARDED_SAMPLES as u64);
+    }
+
     #[test]
     fn send_until_overflow_works<Sut: ZeroCopyConnection>() {
         let name = generate_name();
@@ -421,6 +462,8 @@ mod zero_copy_connection {
             assert_that!(result, is_ok);
             assert_that!(result.ok().unwrap().unwrap().offset(), eq overflow_sample_offset);
         }
+
+        assert_that!(sut_sender.number_of_discarded_samples(), eq BUFFER_SIZE as u64);
     }
 
     #[test]
@@ -1391,9 +1434,189 @@ mod zero_copy_connection {
         assert_that!(unsafe { Sut::remove_sender(&name, &config) }, eq Err(ZeroCopyPortRemoveError::DoesNotExist));
     }
 
+    #[test]
+    fn chunk_ownership_follows_state_machine_for_random_operations<Sut: ZeroCopyConnection>() {
+        const BUFFER_SIZE: usize = 4;
+        const MAX_BORROWED_SAMPLES: usize = 3;
+        const NUMBER_OF_CHUNKS: usize = 16;
+        const NUMBER_OF_ITERATIONS: usize = 5000;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_CHUNKS)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples(MAX_BORROWED_SAMPLES)
+            .enable_safe_overflow(true)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_CHUNKS)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples(MAX_BORROWED_SAMPLES)
+            .enable_safe_overflow(true)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        // deterministic xorshift so that a failing sequence is reproducible
+        let mut seed = 0x9e3779b97f4a7c15u64;
+        let mut random = |upper_bound: usize| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed % upper_bound as u64) as usize
+        };
+
+        let mut chunks = [ChunkState::OwnedBySender; NUMBER_OF_CHUNKS];
+        let apply = |chunks: &mut [ChunkState], offset: PointerOffset, event: ChunkEvent| {
+            let chunk = &mut chunks[offset.offset() / SAMPLE_SIZE];
+            let new_state = chunk.transition(event);
+            assert_that!(new_state, is_ok);
+            *chunk = new_state.unwrap();
+        };
+        let indices_in = |chunks: &[ChunkState], state: ChunkState| {
+            (0..NUMBER_OF_CHUNKS)
+                .filter(|i| chunks[*i] == state)
+                .collect::<Vec<_>>()
+        };
+
+        for _ in 0..NUMBER_OF_ITERATIONS {
+            match random(4) {
+                0 => {
+                    let candidates = indices_in(&chunks, ChunkState::OwnedBySender);
+                    if candidates.is_empty() {
+                        continue;
+                    }
+                    let offset =
+                        PointerOffset::new(candidates[random(candidates.len())] * SAMPLE_SIZE);
+                    let returned_offset = sut_sender.try_send(offset, SAMPLE_SIZE).unwrap();
+                    apply(&mut chunks, offset, ChunkEvent::Send);
+                    if let Some(returned_offset) = returned_offset {
+                        apply(&mut chunks, returned_offset, ChunkEvent::Overflow);
+                    }
+                }
+                1 => match sut_receiver.receive() {
+                    Ok(Some(offset)) => apply(&mut chunks, offset, ChunkEvent::Receive),
+                    Ok(None) => {
+                        assert_that!(indices_in(&chunks, ChunkState::Submitted), len 0)
+                    }
+                    Err(_) => {
+                        assert_that!(indices_in(&chunks, ChunkState::BorrowedByReceiver), len MAX_BORROWED_SAMPLES)
+                    }
+                },
+                2 => {
+                    let candidates = indices_in(&chunks, ChunkState::BorrowedByReceiver);
+                    if candidates.is_empty() {
+                        continue;
+                    }
+                    let offset =
+                        PointerOffset::new(candidates[random(candidates.len())] * SAMPLE_SIZE);
+                    match sut_receiver.release(offset) {
+                        Ok(()) => apply(&mut chunks, offset, ChunkEvent::Release),
+                        Err(_) => {
+                            assert_that!(indices_in(&chunks, ChunkState::Completed), len BUFFER_SIZE + MAX_BORROWED_SAMPLES + 1)
+                        }
+                    }
+                }
+                _ => match sut_sender.reclaim().unwrap() {
+                    Some(offset) => apply(&mut chunks, offset, ChunkEvent::Reclaim),
+                    None => assert_that!(indices_in(&chunks, ChunkState::Completed), len 0),
+                },
+            }
+
+            assert_that!(indices_in(&chunks, ChunkState::Submitted).len(), le BUFFER_SIZE);
+            assert_that!(indices_in(&chunks, ChunkState::BorrowedByReceiver).len(), le MAX_BORROWED_SAMPLES);
+        }
+
+        drop(sut_receiver);
+        unsafe {
+            sut_sender
+                .acquire_used_offsets(|offset| apply(&mut chunks, offset, ChunkEvent::AcquireUsed))
+        };
+
+        assert_that!(indices_in(&chunks, ChunkState::OwnedBySender), len NUMBER_OF_CHUNKS);
+    }
+
+    #[test]
+    fn connection_follows_state_machine_for_random_port_lifecycles<Sut: ZeroCopyConnection>() {
+        const NUMBER_OF_ITERATIONS: usize = 500;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        let builder = || {
+            Sut::Builder::new(&name)
+                .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+                .config(&config)
+        };
+
+        let mut seed = 4711u64;
+        let mut random = |upper_bound: usize| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed % upper_bound as u64) as usize
+        };
+
+        let mut model = ConnectionState::Unused;
+        let mut sender = None;
+        let mut receiver = None;
+
+        for _ in 0..NUMBER_OF_ITERATIONS {
+            let event = match random(4) {
+                0 => ConnectionEvent::AttachSender,
+                1 => ConnectionEvent::AttachReceiver,
+                2 => ConnectionEvent::DetachSender,
+                _ => ConnectionEvent::DetachReceiver,
+            };
+
+            let expected = model.transition(event);
+            match event {
+                ConnectionEvent::AttachSender => {
+                    let new_sender = builder().create_sender();
+                    assert_that!(new_sender.is_ok(), eq expected.is_ok());
+                    if let Ok(new_sender) = new_sender {
+                        sender = Some(new_sender);
+                    }
+                }
+                ConnectionEvent::AttachReceiver => {
+                    let new_receiver = builder().create_receiver();
+                    assert_that!(new_receiver.is_ok(), eq expected.is_ok());
+                    if let Ok(new_receiver) = new_receiver {
+                        receiver = Some(new_receiver);
+                    }
+                }
+                ConnectionEvent::DetachSender => {
+                    assert_that!(sender.take().is_some(), eq expected.is_ok())
+                }
+                ConnectionEvent::DetachReceiver => {
+                    assert_that!(receiver.take().is_some(), eq expected.is_ok())
+                }
+            }
+
+            if let Ok(new_state) = expected {
+                model = match new_state {
+                    ConnectionState::MarkedForDestruction => ConnectionState::Unused,
+                    v => v,
+                };
+            }
+
+            let is_connected = model == ConnectionState::Connected;
+            if let Some(sender) = &sender {
+                assert_that!(sender.is_connected(), eq is_connected);
+            }
+            if let Some(receiver) = &receiver {
+                assert_that!(receiver.is_connected(), eq is_connected);
+            }
+        }
+    }
+
     #[instantiate_tests(<zero_copy_connection::posix_shared_memory::Connection>)]
     mod posix_shared_memory {}
 
     #[instantiate_tests(<zero_copy_connection::process_local::Connection>)]
     mod process_local {}
+
+    #[instantiate_tests(<zero_copy_connection::static_memory::Connection>)]
+    mod static_memory {}
 }
@@ -0,0 +1,90 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::str::FromStr;
+
+use clap::Parser;
+
+use iceoryx2_cli::help_template;
+
+#[derive(Parser)]
+#[command(
+    name = "iox2-bridge",
+    about = "Forward publish-subscribe services between two domains on the same host",
+    long_about = None,
+    version = env!("CARGO_PKG_VERSION"),
+    disable_help_subcommand = true,
+    arg_required_else_help = true,
+    help_template = help_template("iox2 bridge", false),
+)]
+pub struct Cli {
+    #[clap(
+        long,
+        help = "Domain the services are forwarded from e.g. \"production\""
+    )]
+    pub from: String,
+
+    #[clap(long, help = "Domain the services are forwarded to e.g. \"test\"")]
+    pub to: String,
+
+    #[clap(
+        long = "service",
+        short = 's',
+        required = true,
+        help = "Name of a service that shall be forwarded, can be repeated"
+    )]
+    pub services: Vec<String>,
+
+    #[clap(
+        long,
+        short = 'r',
+        help = "Renames every forwarded service that starts with OLD_PREFIX, the first matching rule is applied, can be repeated [format: OLD_PREFIX=NEW_PREFIX]"
+    )]
+    pub rename: Vec<RenameRule>,
+
+    #[clap(
+        long,
+        default_value_t = 10,
+        help = "Interval in milliseconds in which received samples are forwarded"
+    )]
+    pub cycle_time_ms: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct RenameRule {
+    old_prefix: String,
+    new_prefix: String,
+}
+
+impl RenameRule {
+    pub fn apply(&self, service_name: &str) -> Option<String> {
+        service_name
+            .strip_prefix(&self.old_prefix)
+            .map(|rest| format!("{}{}", self.new_prefix, rest))
+    }
+}
+
+impl FromStr for RenameRule {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.split_once('=') {
+            Some((old_prefix, new_prefix)) if !old_prefix.is_empty() => Ok(Self {
+                old_prefix: old_prefix.to_string(),
+                new_prefix: new_prefix.to_string(),
+            }),
+            _ => Err(format!(
+                "invalid rename rule \"{value}\", expected OLD_PREFIX=NEW_PREFIX"
+            )),
+        }
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use anyhow::{anyhow, Result};
+use core::time::Duration;
+use iceoryx2::port::publisher::Publisher;
+use iceoryx2::port::subscriber::Subscriber;
+use iceoryx2::prelude::*;
+use iceoryx2::service::builder::publish_subscribe::{CustomHeaderMarker, CustomPayloadMarker};
+use iceoryx2::service::static_config::messaging_pattern;
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_system_types::file_name::FileName;
+
+use crate::cli::{Cli, RenameRule};
+
+const BRIDGE_NODE_NAME: &str = "iox2-bridge";
+
+/// Forwards every sample of one publish-subscribe service of the source domain to the
+/// corresponding service of the target domain without knowing the payload type.
+struct Route {
+    subscriber: Subscriber<ipc::Service, [CustomPayloadMarker], CustomHeaderMarker>,
+    publisher: Publisher<ipc::Service, [CustomPayloadMarker], CustomHeaderMarker>,
+    user_header_size: usize,
+}
+
+impl Route {
+    fn new(
+        source_node: &Node<ipc::Service>,
+        target_node: &Node<ipc::Service>,
+        source_name: &ServiceName,
+        target_name: &ServiceName,
+    ) -> Result<Self> {
+        let details = ipc::Service::details(
+            source_name,
+            source_node.config(),
+            MessagingPattern::PublishSubscribe,
+        )?
+        .ok_or_else(|| anyhow!("the publish-subscribe service \"{source_name}\" does not exist"))?;
+
+        let static_config = match details.static_details.messaging_pattern() {
+            messaging_pattern::MessagingPattern::PublishSubscribe(static_config) => static_config,
+            _ => {
+                return Err(anyhow!(
+                    "\"{source_name}\" is not a publish-subscribe service"
+                ))
+            }
+        };
+        let types = static_config.message_type_details();
+
+        // the type details are taken from the existing service, therefore they are always
+        // compatible with the payload and user header of the forwarded samples
+        let source_service = unsafe {
+            source_node
+                .service_builder(source_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .user_header::<CustomHeaderMarker>()
+                .__internal_set_payload_type_details(&types.payload)
+                .__internal_set_user_header_type_details(&types.user_header)
+        }
+        .open()?;
+
+        let target_service = unsafe {
+            target_node
+                .service_builder(target_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .user_header::<CustomHeaderMarker>()
+                .__internal_set_payload_type_details(&types.payload)
+                .__internal_set_user_header_type_details(&types.user_header)
+        }
+        .open_or_create()?;
+
+        Ok(Self {
+            subscriber: source_service.subscriber_builder().create()?,
+            publisher: target_service
+                .publisher_builder()
+                .allocation_strategy(AllocationStrategy::PowerOfTwo)
+                .create()?,
+            user_header_size: types.user_header.size,
+        })
+    }
+
+    fn forward(&self) -> Result<()> {
+        while let Some(sample) = unsafe { self.subscriber.receive_custom_payload()? } {
+            let number_of_elements = sample.header().number_of_elements() as usize;
+            let mut forwarded_sample =
+                unsafe { self.publisher.loan_custom_payload(number_of_elements)? };
+
+            let payload = sample.payload();
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    payload.as_ptr().cast::<u8>(),
+                    forwarded_sample.payload_mut().as_mut_ptr().cast::<u8>(),
+                    payload.len(),
+                );
+                core::ptr::copy_nonoverlapping(
+                    (sample.user_header() as *const CustomHeaderMarker).cast::<u8>(),
+                    (forwarded_sample.user_header_mut() as *mut CustomHeaderMarker).cast::<u8>(),
+                    self.user_header_size,
+                );
+            }
+
+            // when the provenance chain is full, the sample is forwarded without extending it
+            let _ = forwarded_sample.append_provenance(sample.header());
+            unsafe { forwarded_sample.assume_init() }.send()?;
+        }
+
+        Ok(())
+    }
+}
+
+fn domain_config(domain: &str) -> Result<Config> {
+    let mut config = Config::global_config().clone();
+    config.global.prefix = FileName::new(domain.as_bytes())?;
+    Ok(config)
+}
+
+fn target_name(service_name: &str, rules: &[RenameRule]) -> String {
+    rules
+        .iter()
+        .find_map(|rule| rule.apply(service_name))
+        .unwrap_or_else(|| service_name.to_string())
+}
+
+pub fn bridge(cli: Cli) -> Result<()> {
+    let node_name = NodeName::new(BRIDGE_NODE_NAME)?;
+    let source_node = NodeBuilder::new()
+        .name(&node_name)
+        .config(&domain_config(&cli.from)?)
+        .create::<ipc::Service>()?;
+    let target_node = NodeBuilder::new()
+        .name(&node_name)
+        .config(&domain_config(&cli.to)?)
+        .create::<ipc::Service>()?;
+
+    let mut routes = vec![];
+    for service in &cli.services {
+        let target = target_name(service, &cli.rename);
+        if cli.from == cli.to && *service == target {
+            return Err(anyhow!(
+                "forwarding \"{service}\" into the same domain requires a rename rule, otherwise the bridge would receive its own samples"
+            ));
+        }
+
+        routes.push(Route::new(
+            &source_node,
+            &target_node,
+            &ServiceName::new(service)?,
+            &ServiceName::new(&target)?,
+        )?);
+        println!(
+            "forwarding \"{}\" [{}] -> \"{}\" [{}]",
+            service, cli.from, target, cli.to
+        );
+    }
+
+    let cycle_time = Duration::from_millis(cli.cycle_time_ms);
+    while source_node.wait(cycle_time).is_ok() {
+        for route in &routes {
+            route.forward()?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,461 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::fs::OpenOptions;
+use std::io::ErrorKind;
+
+use iceoryx2::config::Config;
+use iceoryx2::node::NodeView;
+use iceoryx2::prelude::*;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+use iceoryx2_bb_posix::system_configuration::{Feature, Limit, ProcessResourceLimit};
+
+/// Container runtimes like docker restrict `/dev/shm` to 64MiB by default which is usually
+/// not sufficient for zero-copy communication with larger payloads.
+#[cfg(target_os = "linux")]
+const RECOMMENDED_MIN_SHM_SIZE: u64 = 128 * 1024 * 1024;
+
+/// Every port requires a couple of file descriptors, the common default of 1024 is the lowest
+/// value that is not considered an issue.
+const RECOMMENDED_MIN_OPEN_FILES: u64 = 1024;
+
+/// Upper bound of the file name that is appended to the root path for a unix domain socket,
+/// consisting of the prefix, the hashed service name and the suffix.
+const MAX_SOCKET_FILE_NAME_SUFFIX_LENGTH: usize = 64;
+
+#[derive(serde::Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Warning,
+    Error,
+    #[cfg_attr(target_os = "linux", allow(dead_code))]
+    Skipped,
+}
+
+#[derive(serde::Serialize)]
+pub struct CheckResult {
+    name: &'static str,
+    status: Status,
+    details: String,
+    remediation: Option<String>,
+}
+
+impl CheckResult {
+    fn ok(name: &'static str, details: String) -> Self {
+        Self {
+            name,
+            status: Status::Ok,
+            details,
+            remediation: None,
+        }
+    }
+
+    fn warning(name: &'static str, details: String, remediation: String) -> Self {
+        Self {
+            name,
+            status: Status::Warning,
+            details,
+            remediation: Some(remediation),
+        }
+    }
+
+    fn error(name: &'static str, details: String, remediation: String) -> Self {
+        Self {
+            name,
+            status: Status::Error,
+            details,
+            remediation: Some(remediation),
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn skipped(name: &'static str, details: String) -> Self {
+        Self {
+            name,
+            status: Status::Skipped,
+            details,
+            remediation: None,
+        }
+    }
+
+    fn with_remediation(mut self, remediation: String) -> Self {
+        self.remediation = Some(remediation);
+        self
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct Diagnosis {
+    num_warnings: usize,
+    num_errors: usize,
+    checks: Vec<CheckResult>,
+}
+
+impl Diagnosis {
+    pub fn new(checks: Vec<CheckResult>) -> Self {
+        let count = |status| checks.iter().filter(|c| c.status == status).count();
+
+        Self {
+            num_warnings: count(Status::Warning),
+            num_errors: count(Status::Error),
+            checks,
+        }
+    }
+}
+
+fn is_writable(directory: &str) -> std::io::Result<()> {
+    let probe = format!(
+        "{}/.iox2-doctor-{}",
+        directory.trim_end_matches('/'),
+        std::process::id()
+    );
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)?;
+    std::fs::remove_file(&probe)
+}
+
+pub fn root_path(config: &Config) -> CheckResult {
+    let name = "root path";
+    let root_path = config.global.root_path().to_string();
+
+    match std::fs::metadata(&root_path) {
+        Err(e) if e.kind() == ErrorKind::NotFound => CheckResult::warning(
+            name,
+            format!("The root path \"{root_path}\" does not exist yet."),
+            format!(
+                "It is created by the first node. Ensure that its parent directory is writable or create it upfront with `mkdir -p {root_path}`."
+            ),
+        ),
+        Err(e) => CheckResult::error(
+            name,
+            format!("The root path \"{root_path}\" is not accessible ({e})."),
+            format!("Grant the current user access to \"{root_path}\"."),
+        ),
+        Ok(metadata) if !metadata.is_dir() => CheckResult::error(
+            name,
+            format!("The root path \"{root_path}\" is not a directory."),
+            "Remove the file or configure another root path in the iceoryx2 config file."
+                .to_string(),
+        ),
+        Ok(_) => match is_writable(&root_path) {
+            Ok(()) => CheckResult::ok(
+                name,
+                format!("The root path \"{root_path}\" is writable."),
+            ),
+            Err(e) => CheckResult::error(
+                name,
+                format!("The root path \"{root_path}\" is not writable ({e})."),
+                format!(
+                    "Grant write permissions to the current user, e.g. with `chmod 1777 {root_path}` when the directory is shared between multiple users."
+                ),
+            ),
+        },
+    }
+}
+
+pub fn socket_path_length(config: &Config) -> CheckResult {
+    let name = "socket path length";
+    let root_path = config.global.root_path().to_string();
+    let max_length = Limit::MaxUnixDomainSocketNameLength.value() as usize;
+    let required_length = root_path.len() + MAX_SOCKET_FILE_NAME_SUFFIX_LENGTH;
+
+    if max_length < required_length {
+        CheckResult::warning(
+            name,
+            format!(
+                "The root path \"{root_path}\" is too long, unix domain socket paths may require up to {required_length} characters but only {max_length} are supported."
+            ),
+            "Configure a shorter root path in the iceoryx2 config file.".to_string(),
+        )
+    } else {
+        CheckResult::ok(
+            name,
+            format!(
+                "Unix domain socket paths require up to {required_length} of {max_length} supported characters."
+            ),
+        )
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn parse_size(value: &str) -> Option<u64> {
+    let (number, factor) = match value.chars().last()? {
+        'k' | 'K' => (&value[..value.len() - 1], 1024),
+        'm' | 'M' => (&value[..value.len() - 1], 1024 * 1024),
+        'g' | 'G' => (&value[..value.len() - 1], 1024 * 1024 * 1024),
+        _ => (value, 1),
+    };
+
+    number.parse::<u64>().ok().map(|n| n * factor)
+}
+
+#[cfg(target_os = "linux")]
+fn shm_mount_size() -> Option<u64> {
+    let mounts = std::fs::read_to_string("/proc/mounts").ok()?;
+    let options = mounts
+        .lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>())
+        .find(|entry| entry.len() > 3 && entry[1] == "/dev/shm")
+        .map(|entry| entry[3].to_string())?;
+
+    options
+        .split(',')
+        .find_map(|option| option.strip_prefix("size="))
+        .and_then(parse_size)
+}
+
+#[cfg(target_os = "linux")]
+pub fn shared_memory() -> CheckResult {
+    let name = "shared memory";
+    let shm_dir = "/dev/shm";
+
+    match std::fs::metadata(shm_dir) {
+        Ok(metadata) if metadata.is_dir() => (),
+        _ => {
+            return CheckResult::error(
+                name,
+                format!("\"{shm_dir}\" does not exist or is not a directory."),
+                format!("Mount a tmpfs with `mount -t tmpfs -o size=1G tmpfs {shm_dir}`."),
+            )
+        }
+    }
+
+    if let Err(e) = is_writable(shm_dir) {
+        return CheckResult::error(
+            name,
+            format!("\"{shm_dir}\" is not writable ({e})."),
+            format!("Grant write permissions, e.g. with `chmod 1777 {shm_dir}`."),
+        );
+    }
+
+    let shmmax = std::fs::read_to_string("/proc/sys/kernel/shmmax")
+        .map(|v| v.trim().to_string())
+        .unwrap_or_else(|_| "unknown".to_string());
+
+    match shm_mount_size() {
+        Some(size) if size < RECOMMENDED_MIN_SHM_SIZE => CheckResult::warning(
+            name,
+            format!(
+                "\"{shm_dir}\" is limited to {size} bytes which may not be sufficient (shmmax: {shmmax})."
+            ),
+            format!(
+                "Increase the size with `mount -o remount,size=1G {shm_dir}` or, inside a docker container, with `--shm-size=1g`."
+            ),
+        ),
+        Some(size) => CheckResult::ok(
+            name,
+            format!("\"{shm_dir}\" is writable and has a size of {size} bytes (shmmax: {shmmax})."),
+        ),
+        None => CheckResult::ok(
+            name,
+            format!("\"{shm_dir}\" is writable and uses the default size (shmmax: {shmmax})."),
+        ),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn shared_memory() -> CheckResult {
+    CheckResult::skipped(
+        "shared memory",
+        "The shared memory check is not supported on this platform.".to_string(),
+    )
+}
+
+pub fn file_descriptors() -> CheckResult {
+    let name = "file descriptors";
+    let limit = ProcessResourceLimit::MaxNumberOfOpenFileDescriptors;
+    let soft_limit = limit.soft_limit();
+    let hard_limit = limit.hard_limit();
+    let details = format!(
+        "The process can open up to {soft_limit} file descriptors (hard limit: {hard_limit})."
+    );
+
+    if RECOMMENDED_MIN_OPEN_FILES <= soft_limit {
+        CheckResult::ok(name, details)
+    } else if RECOMMENDED_MIN_OPEN_FILES <= hard_limit {
+        CheckResult::warning(
+            name,
+            details,
+            format!("Raise the soft limit with `ulimit -n {hard_limit}`."),
+        )
+    } else {
+        CheckResult::warning(
+            name,
+            details,
+            format!(
+                "Raise the hard limit to at least {RECOMMENDED_MIN_OPEN_FILES}, e.g. in /etc/security/limits.conf."
+            ),
+        )
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn slow_clocksource() -> Option<String> {
+    const SLOW_CLOCKSOURCES: [&str; 3] = ["hpet", "acpi_pm", "jiffies"];
+
+    let clocksource =
+        std::fs::read_to_string("/sys/devices/system/clocksource/clocksource0/current_clocksource")
+            .ok()?;
+    let clocksource = clocksource.trim();
+
+    SLOW_CLOCKSOURCES
+        .contains(&clocksource)
+        .then(|| clocksource.to_string())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn slow_clocksource() -> Option<String> {
+    None
+}
+
+pub fn clock() -> CheckResult {
+    let name = "clock";
+
+    if !Feature::MonotonicClock.is_available() {
+        return CheckResult::warning(
+            name,
+            "The monotonic clock is not available, the realtime clock is used instead.".to_string(),
+            "Timeouts and deadlines are affected by adjustments of the system time, avoid changing the system time while iceoryx2 applications are running.".to_string(),
+        );
+    }
+
+    if let Err(e) = Time::now_with_clock(ClockType::Monotonic) {
+        return CheckResult::error(
+            name,
+            format!("The monotonic clock cannot be read ({e:?})."),
+            "Verify the clock configuration of the system.".to_string(),
+        );
+    }
+
+    match slow_clocksource() {
+        Some(clocksource) => CheckResult::warning(
+            name,
+            format!("The system uses the slow clocksource \"{clocksource}\"."),
+            "Select a faster clocksource like \"tsc\" if it is listed in /sys/devices/system/clocksource/clocksource0/available_clocksource.".to_string(),
+        ),
+        None => CheckResult::ok(name, "The monotonic clock is available.".to_string()),
+    }
+}
+
+pub fn platform() -> CheckResult {
+    let name = "platform";
+    let mut details = format!(
+        "Running on {} ({})",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    );
+
+    #[cfg(target_os = "linux")]
+    {
+        let release = std::fs::read_to_string("/proc/sys/kernel/osrelease")
+            .map(|v| v.trim().to_string())
+            .unwrap_or_default();
+        details.push_str(&format!(" with kernel {release}"));
+
+        let lowercase_release = release.to_lowercase();
+        if lowercase_release.contains("microsoft") && !lowercase_release.contains("wsl2") {
+            return CheckResult::warning(
+                name,
+                format!("{details} inside WSL1."),
+                "WSL1 does not fully support POSIX shared memory and unix domain sockets, use WSL2 instead.".to_string(),
+            );
+        }
+
+        if std::fs::metadata("/.dockerenv").is_ok()
+            || std::fs::metadata("/run/.containerenv").is_ok()
+        {
+            return CheckResult::ok(name, format!("{details} inside a container."))
+                .with_remediation(
+                    "Only processes that share the root path and /dev/shm can communicate, start the containers with `--ipc=host` and a shared root path volume to communicate across containers.".to_string(),
+                );
+        }
+    }
+
+    details.push('.');
+    CheckResult::ok(name, details)
+}
+
+pub fn stale_nodes(config: &Config) -> CheckResult {
+    let name = "stale nodes";
+    let mut dead_nodes = vec![];
+    let mut inaccessible_nodes = 0;
+
+    let result = Node::<ipc::Service>::list(config, |node| {
+        match node {
+            NodeState::Dead(view) => dead_nodes.push(view.id().pid().value()),
+            NodeState::Inaccessible(_) => inaccessible_nodes += 1,
+            NodeState::Alive(_) | NodeState::Undefined(_) => (),
+        }
+        CallbackProgression::Continue
+    });
+
+    if let Err(e) = result {
+        return CheckResult::error(
+            name,
+            format!("The nodes cannot be listed ({e:?})."),
+            "Grant the current user access to the root path and rerun the doctor.".to_string(),
+        );
+    }
+
+    if !dead_nodes.is_empty() {
+        return CheckResult::warning(
+            name,
+            format!(
+                "{} dead node(s) left stale resources behind, they were owned by the process(es) {:?}.",
+                dead_nodes.len(),
+                dead_nodes
+            ),
+            "The stale resources are removed when the next node is created with `cleanup-dead-nodes-on-creation = true` in the iceoryx2 config file, see also `iox2 node list --state dead`.".to_string(),
+        );
+    }
+
+    if inaccessible_nodes != 0 {
+        return CheckResult::warning(
+            name,
+            format!(
+                "{inaccessible_nodes} node(s) cannot be identified as dead or alive due to insufficient permissions."
+            ),
+            "Run the doctor as the user that owns the nodes to verify them.".to_string(),
+        );
+    }
+
+    CheckResult::ok(
+        name,
+        "No dead nodes with stale resources were found.".to_string(),
+    )
+}
+
+pub fn self_test(config: &Config) -> CheckResult {
+    let name = "self test";
+    let report = ipc::Service::self_test(config);
+
+    match report.failed_stage() {
+        Some(stage) => CheckResult::error(
+            name,
+            format!(
+                "The self test failed in stage {:?} ({}).",
+                stage.stage(),
+                stage.failure().unwrap_or_default()
+            ),
+            "Resolve the errors of the other checks and rerun the doctor with `IOX2_LOG_LEVEL=debug` for details.".to_string(),
+        ),
+        None => CheckResult::ok(
+            name,
+            format!(
+                "A sample and an event were exchanged successfully in {:?}.",
+                report.duration()
+            ),
+        ),
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use anyhow::Result;
+use iceoryx2::prelude::*;
+use iceoryx2_cli::Format;
+
+use crate::checks;
+use crate::checks::Diagnosis;
+
+pub fn diagnose(format: Format) -> Result<()> {
+    let config = Config::global_config();
+
+    let checks = vec![
+        checks::root_path(config),
+        checks::socket_path_length(config),
+        checks::shared_memory(),
+        checks::file_descriptors(),
+        checks::clock(),
+        checks::platform(),
+        // before the self test, its node removes the stale resources of dead nodes
+        checks::stale_nodes(config),
+        checks::self_test(config),
+    ];
+
+    print!("{}", format.as_string(&Diagnosis::new(checks))?);
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use clap::Parser;
+
+use iceoryx2_cli::help_template;
+use iceoryx2_cli::Format;
+
+use crate::decode::TypeDescription;
+
+#[derive(Parser)]
+#[command(
+    name = "iox2-echo",
+    about = "Print the samples of a running publish-subscribe service",
+    long_about = None,
+    version = env!("CARGO_PKG_VERSION"),
+    disable_help_subcommand = true,
+    arg_required_else_help = true,
+    help_template = help_template("iox2 echo", false),
+)]
+pub struct Cli {
+    #[clap(help = "Name of the publish-subscribe service e.g. \"My/Funk/ServiceName\"")]
+    pub service: String,
+
+    #[clap(
+        long = "type",
+        short = 't',
+        help = "Decodes the payload as repr(C) struct with the given fields instead of printing a hexdump e.g. \"x:f32,y:f32,id:u64,raw:u8[4]\""
+    )]
+    pub payload_type: Option<TypeDescription>,
+
+    #[clap(
+        long,
+        short = 'n',
+        help = "Stops after the given number of samples was printed"
+    )]
+    pub count: Option<u64>,
+
+    #[clap(
+        long,
+        default_value_t = 10,
+        help = "Interval in milliseconds in which received samples are printed"
+    )]
+    pub cycle_time_ms: u64,
+
+    #[clap(long, short = 'f', value_enum, default_value_t = Format::Ron)]
+    pub format: Format,
+}
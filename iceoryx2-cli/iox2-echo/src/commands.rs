@@ -0,0 +1,144 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use anyhow::{anyhow, Result};
+use core::time::Duration;
+use iceoryx2::port::subscriber::Subscriber;
+use iceoryx2::prelude::*;
+use iceoryx2::service::builder::publish_subscribe::{CustomHeaderMarker, CustomPayloadMarker};
+use iceoryx2::service::static_config::messaging_pattern;
+use iceoryx2_cli::output::{PayloadDescription, SampleDescription};
+
+use crate::cli::Cli;
+use crate::decode::{hexdump, TypeDescription};
+
+const ECHO_NODE_NAME: &str = "iox2-echo";
+
+/// Receives the samples of a publish-subscribe service without knowing the payload type.
+struct Echo {
+    subscriber: Subscriber<ipc::Service, [CustomPayloadMarker], CustomHeaderMarker>,
+    user_header_size: usize,
+    payload_type: Option<TypeDescription>,
+}
+
+impl Echo {
+    fn new(
+        node: &Node<ipc::Service>,
+        service_name: &ServiceName,
+        payload_type: Option<TypeDescription>,
+    ) -> Result<Self> {
+        let details = ipc::Service::details(
+            service_name,
+            node.config(),
+            MessagingPattern::PublishSubscribe,
+        )?
+        .ok_or_else(|| {
+            anyhow!("the publish-subscribe service \"{service_name}\" does not exist")
+        })?;
+
+        let static_config = match details.static_details.messaging_pattern() {
+            messaging_pattern::MessagingPattern::PublishSubscribe(static_config) => static_config,
+            _ => {
+                return Err(anyhow!(
+                    "\"{service_name}\" is not a publish-subscribe service"
+                ))
+            }
+        };
+        let types = static_config.message_type_details();
+
+        if let Some(payload_type) = &payload_type {
+            payload_type
+                .verify_size(&types.payload.type_name, types.payload.size)
+                .map_err(|e| anyhow!(e))?;
+        }
+
+        // the type details are taken from the existing service, therefore they are always
+        // compatible with the payload and user header of the received samples
+        let service = unsafe {
+            node.service_builder(service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .user_header::<CustomHeaderMarker>()
+                .__internal_set_payload_type_details(&types.payload)
+                .__internal_set_user_header_type_details(&types.user_header)
+        }
+        .open()?;
+
+        let max_subscribers = service.static_config().max_subscribers();
+        if service.dynamic_config().number_of_subscribers() >= max_subscribers {
+            return Err(anyhow!(
+                "the service \"{service_name}\" already has the maximum of {max_subscribers} subscribers, echo requires a free subscriber slot"
+            ));
+        }
+
+        Ok(Self {
+            subscriber: service.subscriber_builder().create()?,
+            user_header_size: types.user_header.size,
+            payload_type,
+        })
+    }
+
+    fn print<F: FnMut(&SampleDescription) -> Result<bool>>(&self, mut callback: F) -> Result<bool> {
+        while let Some(sample) = unsafe { self.subscriber.receive_custom_payload()? } {
+            let payload = sample.payload();
+            let payload = unsafe {
+                core::slice::from_raw_parts(payload.as_ptr().cast::<u8>(), payload.len())
+            };
+            let user_header = unsafe {
+                core::slice::from_raw_parts(
+                    (sample.user_header() as *const CustomHeaderMarker).cast::<u8>(),
+                    self.user_header_size,
+                )
+            };
+
+            let payload = match &self.payload_type {
+                Some(payload_type) => PayloadDescription::Decoded(payload_type.decode(payload)),
+                None => PayloadDescription::Hexdump(hexdump(payload)),
+            };
+
+            if !callback(&SampleDescription::new(
+                sample.header(),
+                hexdump(user_header),
+                payload,
+            ))? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+pub fn echo(cli: Cli) -> Result<()> {
+    let node = NodeBuilder::new()
+        .name(&NodeName::new(ECHO_NODE_NAME)?)
+        .create::<ipc::Service>()?;
+    let echo = Echo::new(&node, &ServiceName::new(&cli.service)?, cli.payload_type)?;
+
+    let mut number_of_samples = 0;
+    let cycle_time = Duration::from_millis(cli.cycle_time_ms);
+    while node.wait(cycle_time).is_ok() {
+        let keep_running = echo.print(|sample| {
+            println!("{}", cli.format.as_string(sample)?);
+            number_of_samples += 1;
+            Ok(match cli.count {
+                Some(count) => number_of_samples < count,
+                None => true,
+            })
+        })?;
+
+        if !keep_running {
+            break;
+        }
+    }
+
+    Ok(())
+}
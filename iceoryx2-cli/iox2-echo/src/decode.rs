@@ -0,0 +1,396 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::str::FromStr;
+
+use iceoryx2_cli::output::{DecodedField, DecodedValue};
+
+#[derive(Debug, Clone, Copy)]
+enum Primitive {
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    F32,
+    F64,
+    Bool,
+}
+
+impl Primitive {
+    fn size(&self) -> usize {
+        match self {
+            Primitive::U8 | Primitive::I8 | Primitive::Bool => 1,
+            Primitive::U16 | Primitive::I16 => 2,
+            Primitive::U32 | Primitive::I32 | Primitive::F32 => 4,
+            Primitive::U64 | Primitive::I64 | Primitive::F64 => 8,
+        }
+    }
+
+    fn decode(&self, bytes: &[u8]) -> DecodedValue {
+        macro_rules! from_bytes {
+            ($t:ty) => {
+                <$t>::from_ne_bytes(bytes.try_into().expect("the size matches the primitive"))
+            };
+        }
+
+        match self {
+            Primitive::U8 => DecodedValue::Unsigned(bytes[0] as u64),
+            Primitive::U16 => DecodedValue::Unsigned(from_bytes!(u16) as u64),
+            Primitive::U32 => DecodedValue::Unsigned(from_bytes!(u32) as u64),
+            Primitive::U64 => DecodedValue::Unsigned(from_bytes!(u64)),
+            Primitive::I8 => DecodedValue::Signed(bytes[0] as i8 as i64),
+            Primitive::I16 => DecodedValue::Signed(from_bytes!(i16) as i64),
+            Primitive::I32 => DecodedValue::Signed(from_bytes!(i32) as i64),
+            Primitive::I64 => DecodedValue::Signed(from_bytes!(i64)),
+            Primitive::F32 => DecodedValue::Float(from_bytes!(f32) as f64),
+            Primitive::F64 => DecodedValue::Float(from_bytes!(f64)),
+            Primitive::Bool => DecodedValue::Bool(bytes[0] != 0),
+        }
+    }
+}
+
+impl FromStr for Primitive {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "u8" => Ok(Primitive::U8),
+            "u16" => Ok(Primitive::U16),
+            "u32" => Ok(Primitive::U32),
+            "u64" => Ok(Primitive::U64),
+            "i8" => Ok(Primitive::I8),
+            "i16" => Ok(Primitive::I16),
+            "i32" => Ok(Primitive::I32),
+            "i64" => Ok(Primitive::I64),
+            "f32" => Ok(Primitive::F32),
+            "f64" => Ok(Primitive::F64),
+            "bool" => Ok(Primitive::Bool),
+            _ => Err(format!(
+                "unsupported type \"{value}\", expected u8, u16, u32, u64, i8, i16, i32, i64, f32, f64 or bool"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Field {
+    name: String,
+    primitive: Primitive,
+    array_len: Option<usize>,
+    offset: usize,
+}
+
+/// Describes the payload as `repr(C)` struct consisting of primitive fields and arrays of
+/// primitives, e.g. `x:f32,y:f32,id:u64,raw:u8[4]`.
+#[derive(Debug, Clone)]
+pub struct TypeDescription {
+    fields: Vec<Field>,
+    size: usize,
+}
+
+impl TypeDescription {
+    /// Fails when the described struct does not have the size of the payload type of the
+    /// service.
+    pub fn verify_size(&self, type_name: &str, size: usize) -> Result<(), String> {
+        match self.size == size {
+            true => Ok(()),
+            false => Err(format!(
+                "the type description has a size of {} bytes but the payload \"{type_name}\" of the service has a size of {size} bytes",
+                self.size
+            )),
+        }
+    }
+
+    /// Decodes every element of the payload.
+    pub fn decode(&self, payload: &[u8]) -> Vec<Vec<DecodedField>> {
+        payload
+            .chunks_exact(self.size)
+            .map(|element| {
+                self.fields
+                    .iter()
+                    .map(|field| {
+                        let size = field.primitive.size();
+                        let value = match field.array_len {
+                            None => field
+                                .primitive
+                                .decode(&element[field.offset..field.offset + size]),
+                            Some(len) => DecodedValue::Array(
+                                element[field.offset..field.offset + size * len]
+                                    .chunks_exact(size)
+                                    .map(|bytes| field.primitive.decode(bytes))
+                                    .collect(),
+                            ),
+                        };
+
+                        DecodedField {
+                            name: field.name.clone(),
+                            value,
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl FromStr for TypeDescription {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut fields = vec![];
+        let mut offset: usize = 0;
+        let mut alignment = 1;
+
+        for field in value.split(',').map(str::trim) {
+            let (name, field_type) = field
+                .split_once(':')
+                .ok_or_else(|| format!("invalid field \"{field}\", expected NAME:TYPE"))?;
+
+            let (primitive, array_len) = match field_type.strip_suffix(']') {
+                Some(array) => {
+                    let (primitive, len) = array.split_once('[').ok_or_else(|| {
+                        format!("invalid array \"{field_type}\", expected TYPE[LEN]")
+                    })?;
+                    let len = len
+                        .parse::<usize>()
+                        .map_err(|e| format!("invalid array length in \"{field_type}\" ({e})"))?;
+                    (primitive.parse::<Primitive>()?, Some(len))
+                }
+                None => (field_type.parse::<Primitive>()?, None),
+            };
+
+            let size = primitive.size();
+            offset = offset.next_multiple_of(size);
+            alignment = alignment.max(size);
+            fields.push(Field {
+                name: name.to_string(),
+                primitive,
+                array_len,
+                offset,
+            });
+            offset += size * array_len.unwrap_or(1);
+        }
+
+        Ok(Self {
+            fields,
+            size: offset.next_multiple_of(alignment),
+        })
+    }
+}
+
+/// Formats the bytes as lines of 16 bytes with the offset, the hex values and the printable
+/// ASCII characters.
+pub fn hexdump(bytes: &[u8]) -> Vec<String> {
+    bytes
+        .chunks(16)
+        .enumerate()
+        .map(|(n, line)| {
+            let hex = line
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let ascii = line
+                .iter()
+                .map(|byte| match byte.is_ascii_graphic() || *byte == b' ' {
+                    true => *byte as char,
+                    false => '.',
+                })
+                .collect::<String>();
+            format!("{:08x}  {:<47}  |{}|", n * 16, hex, ascii)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn offsets(sut: &TypeDescription) -> Vec<usize> {
+        sut.fields.iter().map(|field| field.offset).collect()
+    }
+
+    fn field(name: &str, value: DecodedValue) -> DecodedField {
+        DecodedField {
+            name: name.to_string(),
+            value,
+        }
+    }
+
+    #[test]
+    fn parse_type_description_with_all_primitives_works() {
+        let sut: TypeDescription =
+            "a:u8,b:u16,c:u32,d:u64,e:i8,f:i16,g:i32,h:i64,i:f32,j:f64,k:bool"
+                .parse()
+                .unwrap();
+
+        assert_that!(sut.fields, len 11);
+        assert_that!(offsets(&sut), eq vec![0, 2, 4, 8, 16, 18, 20, 24, 32, 40, 48]);
+        assert_that!(sut.size, eq 56);
+    }
+
+    #[test]
+    fn parse_type_description_ignores_whitespace_around_fields() {
+        let sut: TypeDescription = " x:f32 , y:f32 ".parse().unwrap();
+
+        assert_that!(sut.fields[0].name, eq "x");
+        assert_that!(sut.fields[1].name, eq "y");
+        assert_that!(sut.size, eq 8);
+    }
+
+    #[test]
+    fn fields_are_aligned_to_their_size() {
+        let sut: TypeDescription = "a:u8,b:u32,c:u8,d:u16".parse().unwrap();
+
+        assert_that!(offsets(&sut), eq vec![0, 4, 8, 10]);
+        assert_that!(sut.size, eq 12);
+    }
+
+    #[test]
+    fn size_contains_trailing_padding_up_to_the_largest_alignment() {
+        let sut: TypeDescription = "id:u64,flag:bool".parse().unwrap();
+        assert_that!(sut.size, eq 16);
+
+        let sut: TypeDescription = "a:u16,b:u8".parse().unwrap();
+        assert_that!(sut.size, eq 4);
+
+        let sut: TypeDescription = "a:u8,b:bool,c:i8".parse().unwrap();
+        assert_that!(sut.size, eq 3);
+    }
+
+    #[test]
+    fn arrays_are_aligned_to_their_element_size() {
+        let sut: TypeDescription = "flag:bool,raw:u16[3],id:u64,tail:u8[3]".parse().unwrap();
+
+        assert_that!(sut.fields[1].array_len, eq Some(3));
+        assert_that!(offsets(&sut), eq vec![0, 2, 8, 16]);
+        assert_that!(sut.size, eq 24);
+    }
+
+    #[test]
+    fn parse_field_without_type_fails() {
+        let sut = "x:f32,y".parse::<TypeDescription>();
+        assert_that!(sut.err(), eq Some("invalid field \"y\", expected NAME:TYPE".to_string()));
+
+        let sut = "".parse::<TypeDescription>();
+        assert_that!(sut.err(), eq Some("invalid field \"\", expected NAME:TYPE".to_string()));
+    }
+
+    #[test]
+    fn parse_unsupported_type_fails() {
+        let sut = "x:u128".parse::<TypeDescription>();
+        assert_that!(sut.err().unwrap().starts_with("unsupported type \"u128\""), eq true);
+
+        let sut = "x:u128[2]".parse::<TypeDescription>();
+        assert_that!(sut.err().unwrap().starts_with("unsupported type \"u128\""), eq true);
+
+        let sut = "x:u8[2".parse::<TypeDescription>();
+        assert_that!(sut.err().unwrap().starts_with("unsupported type \"u8[2\""), eq true);
+    }
+
+    #[test]
+    fn parse_invalid_array_fails() {
+        let sut = "x:u82]".parse::<TypeDescription>();
+        assert_that!(sut.err(), eq Some("invalid array \"u82]\", expected TYPE[LEN]".to_string()));
+
+        let sut = "x:u8[-1]".parse::<TypeDescription>();
+        assert_that!(sut.err().unwrap().starts_with("invalid array length in \"u8[-1]\""), eq true);
+
+        let sut = "x:u8[]".parse::<TypeDescription>();
+        assert_that!(sut.err().unwrap().starts_with("invalid array length in \"u8[]\""), eq true);
+    }
+
+    #[test]
+    fn decode_fields_works() {
+        let sut: TypeDescription = "flag:bool,x:f32,id:u64,delta:i16".parse().unwrap();
+
+        let mut payload = vec![0u8; sut.size];
+        payload[0] = 1;
+        payload[4..8].copy_from_slice(&1.5f32.to_ne_bytes());
+        payload[8..16].copy_from_slice(&u64::MAX.to_ne_bytes());
+        payload[16..18].copy_from_slice(&(-42i16).to_ne_bytes());
+
+        assert_that!(sut.decode(&payload), eq vec![vec![
+            field("flag", DecodedValue::Bool(true)),
+            field("x", DecodedValue::Float(1.5)),
+            field("id", DecodedValue::Unsigned(u64::MAX)),
+            field("delta", DecodedValue::Signed(-42)),
+        ]]);
+    }
+
+    #[test]
+    fn decode_arrays_works() {
+        let sut: TypeDescription = "raw:u8[3],values:i32[2]".parse().unwrap();
+
+        let mut payload = vec![0u8; sut.size];
+        payload[0..3].copy_from_slice(&[7, 8, 9]);
+        payload[4..8].copy_from_slice(&(-1i32).to_ne_bytes());
+        payload[8..12].copy_from_slice(&123i32.to_ne_bytes());
+
+        assert_that!(sut.decode(&payload), eq vec![vec![
+            field("raw", DecodedValue::Array(vec![
+                DecodedValue::Unsigned(7),
+                DecodedValue::Unsigned(8),
+                DecodedValue::Unsigned(9),
+            ])),
+            field("values", DecodedValue::Array(vec![
+                DecodedValue::Signed(-1),
+                DecodedValue::Signed(123),
+            ])),
+        ]]);
+    }
+
+    #[test]
+    fn decode_slice_payload_decodes_every_element() {
+        let sut: TypeDescription = "id:u16,flag:bool".parse().unwrap();
+
+        let mut payload = vec![];
+        for id in 0..3u16 {
+            payload.extend_from_slice(&id.to_ne_bytes());
+            payload.extend_from_slice(&[(id % 2) as u8, 0]);
+        }
+
+        let decoded = sut.decode(&payload);
+        assert_that!(decoded, len 3);
+        for (id, element) in decoded.iter().enumerate() {
+            assert_that!(*element, eq vec![
+                field("id", DecodedValue::Unsigned(id as u64)),
+                field("flag", DecodedValue::Bool(id % 2 == 1)),
+            ]);
+        }
+    }
+
+    #[test]
+    fn verify_size_fails_when_payload_size_differs() {
+        let sut: TypeDescription = "id:u64,flag:bool".parse().unwrap();
+
+        assert_that!(sut.verify_size("MyType", 16), eq Ok(()));
+        assert_that!(sut.verify_size("MyType", 9), eq Err(
+            "the type description has a size of 16 bytes but the payload \"MyType\" of the service has a size of 9 bytes".to_string()
+        ));
+    }
+
+    #[test]
+    fn hexdump_prints_offset_hex_values_and_ascii() {
+        let sut = hexdump(b"iceoryx2 is fast\x00\x01");
+
+        assert_that!(sut, eq vec![
+            format!("00000000  {}  |iceoryx2 is fast|", "69 63 65 6f 72 79 78 32 20 69 73 20 66 61 73 74"),
+            format!("00000010  {:<47}  |..|", "00 01"),
+        ]);
+    }
+}
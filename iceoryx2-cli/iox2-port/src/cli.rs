@@ -0,0 +1,63 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use clap::Args;
+use clap::Parser;
+use clap::Subcommand;
+
+use iceoryx2::service::service_name::ServiceNamePattern;
+use iceoryx2_cli::filter::PortTypeFilter;
+use iceoryx2_cli::help_template;
+use iceoryx2_cli::Format;
+
+#[derive(Parser)]
+#[command(
+    name = "iox2-port",
+    about = "Query information about iceoryx2 ports",
+    long_about = None,
+    version = env!("CARGO_PKG_VERSION"),
+    disable_help_subcommand = true,
+    arg_required_else_help = false,
+    help_template = help_template("iox2 port", false),
+)]
+pub struct Cli {
+    #[clap(subcommand)]
+    pub action: Option<Action>,
+
+    #[clap(long, short = 'f', value_enum, global = true, value_enum, default_value_t = Format::Ron)]
+    pub format: Format,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct OutputFilter {
+    #[clap(
+        short,
+        long,
+        help = "Glob of the service names e.g. \"My/*/Service\" or \"My/**\""
+    )]
+    pub service: Option<ServiceNamePattern>,
+
+    #[clap(short = 't', long, value_enum, default_value_t = PortTypeFilter::All)]
+    pub port_type: PortTypeFilter,
+}
+
+#[derive(Args)]
+pub struct ListOptions {
+    #[command(flatten)]
+    pub filter: OutputFilter,
+}
+
+#[derive(Subcommand)]
+pub enum Action {
+    #[clap(about = "List all ports")]
+    List(ListOptions),
+}
@@ -0,0 +1,52 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use anyhow::{Context, Result};
+use iceoryx2::prelude::*;
+use iceoryx2::service::{ServiceDetails, ServicePortDetails};
+use iceoryx2_cli::filter::Filter;
+use iceoryx2_cli::output::PortDescriptor;
+use iceoryx2_cli::output::PortList;
+use iceoryx2_cli::Format;
+
+use crate::cli::OutputFilter;
+
+pub fn list(filter: OutputFilter, format: Format) -> Result<()> {
+    let mut ports = Vec::<PortDescriptor>::new();
+
+    ipc::Service::list(Config::global_config(), |service| {
+        if !Filter::<ServiceDetails<ipc::Service>>::matches(&filter, &service) {
+            return CallbackProgression::Continue;
+        }
+
+        if let Some(dynamic_details) = &service.dynamic_details {
+            let service_name = service.static_details.name().as_str();
+            for port in &dynamic_details.ports {
+                if Filter::<ServicePortDetails>::matches(&filter, port) {
+                    ports.push(PortDescriptor::new(service_name, port));
+                }
+            }
+        }
+        CallbackProgression::Continue
+    })
+    .context("failed to retrieve services")?;
+
+    print!(
+        "{}",
+        format.as_string(&PortList {
+            num: ports.len(),
+            details: ports
+        })?
+    );
+
+    Ok(())
+}
@@ -14,6 +14,7 @@ use clap::Args;
 use clap::Parser;
 use clap::Subcommand;
 
+use iceoryx2::service::service_name::ServiceNamePattern;
 use iceoryx2_cli::filter::MessagingPatternFilter;
 use iceoryx2_cli::help_template;
 use iceoryx2_cli::Format;
@@ -40,6 +41,13 @@ pub struct Cli {
 pub struct OutputFilter {
     #[clap(short, long, value_enum, default_value_t = MessagingPatternFilter::All)]
     pub pattern: MessagingPatternFilter,
+
+    #[clap(
+        short,
+        long,
+        help = "Glob of the service names e.g. \"My/*/Service\" or \"My/**\""
+    )]
+    pub name: Option<ServiceNamePattern>,
 }
 
 #[derive(Args)]
@@ -61,6 +69,6 @@ pub struct DetailsOptions {
 pub enum Action {
     #[clap(about = "List all services")]
     List(ListOptions),
-    #[clap(about = "Show service details")]
+    #[clap(about = "Show service details", visible_alias = "describe")]
     Details(DetailsOptions),
 }
@@ -17,6 +17,11 @@ use iceoryx2_cli::filter::Filter;
 
 impl Filter<ServiceDetails<Service>> for OutputFilter {
     fn matches(&self, service: &ServiceDetails<Service>) -> bool {
-        self.pattern.matches(service)
+        let name_matches = match &self.name {
+            Some(name) => name.matches(service.static_details.name()),
+            None => true,
+        };
+
+        name_matches && self.pattern.matches(service)
     }
 }
@@ -16,9 +16,11 @@ use core::fmt::Debug;
 use core::str::FromStr;
 use iceoryx2::node::NodeState;
 use iceoryx2::node::NodeView;
+use iceoryx2::port::port_identifiers::UniquePortId;
 use iceoryx2::service::ipc::Service;
 use iceoryx2::service::static_config::messaging_pattern::MessagingPattern;
 use iceoryx2::service::ServiceDetails;
+use iceoryx2::service::ServicePortDetails;
 use iceoryx2_pal_posix::posix::pid_t;
 
 pub trait Filter<T>: Debug {
@@ -131,3 +133,32 @@ impl Filter<ServiceDetails<Service>> for MessagingPatternFilter {
         )
     }
 }
+
+#[derive(Debug, Clone, ValueEnum)]
+#[clap(rename_all = "PascalCase")]
+#[derive(Default)]
+pub enum PortTypeFilter {
+    Publisher,
+    Subscriber,
+    Notifier,
+    Listener,
+    Client,
+    Server,
+    #[default]
+    All,
+}
+
+impl Filter<ServicePortDetails> for PortTypeFilter {
+    fn matches(&self, port: &ServicePortDetails) -> bool {
+        matches!(
+            (self, &port.port_id),
+            (PortTypeFilter::Publisher, UniquePortId::Publisher(_))
+                | (PortTypeFilter::Subscriber, UniquePortId::Subscriber(_))
+                | (PortTypeFilter::Notifier, UniquePortId::Notifier(_))
+                | (PortTypeFilter::Listener, UniquePortId::Listener(_))
+                | (PortTypeFilter::Client, UniquePortId::Client(_))
+                | (PortTypeFilter::Server, UniquePortId::Server(_))
+                | (PortTypeFilter::All, _)
+        )
+    }
+}
@@ -16,11 +16,14 @@ use iceoryx2::node::NodeDetails as IceoryxNodeDetails;
 use iceoryx2::node::NodeId as IceoryxNodeId;
 use iceoryx2::node::NodeState as IceoryxNodeState;
 use iceoryx2::node::NodeView as IceoryxNodeView;
+use iceoryx2::port::port_identifiers::UniquePortId as IceoryxUniquePortId;
 use iceoryx2::service::attribute::AttributeSet as IceoryxAttributeSet;
+use iceoryx2::service::header::publish_subscribe::Header as IceoryxPublishSubscribeHeader;
 use iceoryx2::service::static_config::messaging_pattern::MessagingPattern as IceoryxMessagingPattern;
 use iceoryx2::service::Service as IceoryxService;
 use iceoryx2::service::ServiceDetails as IceoryxServiceDetails;
 use iceoryx2::service::ServiceDynamicDetails as IceoryxServiceDynamicDetails;
+use iceoryx2::service::ServicePortDetails as IceoryxServicePortDetails;
 use iceoryx2_pal_posix::posix::pid_t;
 
 #[derive(serde::Serialize, Eq, PartialEq, Ord, PartialOrd)]
@@ -54,6 +57,7 @@ pub struct ServiceDescription {
     pub attributes: IceoryxAttributeSet,
     pub pattern: IceoryxMessagingPattern,
     pub nodes: Option<NodeList>,
+    pub discarded_samples: Option<u64>,
 }
 
 impl<T> From<&IceoryxServiceDetails<T>> for ServiceDescription
@@ -69,6 +73,13 @@ where
             attributes: config.attributes().clone(),
             pattern: config.messaging_pattern().clone(),
             nodes: service.dynamic_details.as_ref().map(NodeList::from),
+            discarded_samples: match config.messaging_pattern() {
+                IceoryxMessagingPattern::PublishSubscribe(_) => service
+                    .dynamic_details
+                    .as_ref()
+                    .map(|details| details.number_of_discarded_samples),
+                _ => None,
+            },
         }
     }
 }
@@ -235,3 +246,100 @@ where
         }
     }
 }
+
+#[derive(serde::Serialize)]
+pub enum PortType {
+    Publisher,
+    Subscriber,
+    Notifier,
+    Listener,
+    Client,
+    Server,
+}
+
+#[derive(serde::Serialize)]
+pub struct PortDescriptor {
+    port_type: PortType,
+    id: String,
+    node_id: NodeIdString,
+    service_name: String,
+}
+
+impl PortDescriptor {
+    pub fn new(service_name: &str, port: &IceoryxServicePortDetails) -> Self {
+        let (port_type, id) = match port.port_id {
+            IceoryxUniquePortId::Publisher(id) => (PortType::Publisher, id.value()),
+            IceoryxUniquePortId::Subscriber(id) => (PortType::Subscriber, id.value()),
+            IceoryxUniquePortId::Notifier(id) => (PortType::Notifier, id.value()),
+            IceoryxUniquePortId::Listener(id) => (PortType::Listener, id.value()),
+            IceoryxUniquePortId::Client(id) => (PortType::Client, id.value()),
+            IceoryxUniquePortId::Server(id) => (PortType::Server, id.value()),
+        };
+
+        PortDescriptor {
+            port_type,
+            id: format!("{:032x}", id),
+            node_id: NodeIdString::from(&port.node_id),
+            service_name: service_name.to_string(),
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct PortList {
+    pub num: usize,
+    pub details: Vec<PortDescriptor>,
+}
+
+#[derive(Debug, PartialEq, serde::Serialize)]
+#[serde(untagged)]
+pub enum DecodedValue {
+    Unsigned(u64),
+    Signed(i64),
+    Float(f64),
+    Bool(bool),
+    Array(Vec<DecodedValue>),
+}
+
+#[derive(Debug, PartialEq, serde::Serialize)]
+pub struct DecodedField {
+    pub name: String,
+    pub value: DecodedValue,
+}
+
+#[derive(serde::Serialize)]
+pub enum PayloadDescription {
+    Hexdump(Vec<String>),
+    Decoded(Vec<Vec<DecodedField>>),
+}
+
+#[derive(serde::Serialize)]
+pub struct SampleDescription {
+    pub sequence_number: u64,
+    pub publisher_id: String,
+    pub node_id: NodeIdString,
+    pub timestamp: core::time::Duration,
+    pub priority: u8,
+    pub number_of_elements: u64,
+    pub user_header: Vec<String>,
+    pub payload: PayloadDescription,
+}
+
+impl SampleDescription {
+    pub fn new(
+        header: &IceoryxPublishSubscribeHeader,
+        user_header: Vec<String>,
+        payload: PayloadDescription,
+    ) -> Self {
+        SampleDescription {
+            sequence_number: header.sequence_number(),
+            publisher_id: format!("{:032x}", header.publisher_id().value()),
+            node_id: NodeIdString::from(&header.node_id()),
+            timestamp: header.timestamp().as_duration(),
+            priority: header.priority(),
+            number_of_elements: header.number_of_elements(),
+            user_header,
+            payload,
+        }
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod doctor {
+    use std::process::Command;
+
+    use iceoryx2::node::testing::__internal_node_staged_death;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::generate_isolated_config;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::monitoring::testing::__InternalMonitoringTokenTestable;
+    use tempfile::TempDir;
+
+    fn diagnose(config: &Config) -> serde_json::Value {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let config_file = temp_dir.path().join("iceoryx2.toml");
+        std::fs::write(&config_file, config.to_toml_string().unwrap())
+            .expect("Failed to write config file");
+
+        let output = Command::new(env!("CARGO_BIN_EXE_iox2-doctor"))
+            .args(["--format", "JSON"])
+            .env("IOX2_CONFIG", &config_file)
+            .output()
+            .expect("Failed to run iox2-doctor");
+        assert_that!(output.status.success(), eq true);
+
+        serde_json::from_slice(&output.stdout).expect("Failed to parse the diagnosis")
+    }
+
+    fn check<'a>(diagnosis: &'a serde_json::Value, name: &str) -> &'a serde_json::Value {
+        diagnosis["checks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|check| check["name"] == name)
+            .unwrap_or_else(|| panic!("The diagnosis contains no \"{name}\" check."))
+    }
+
+    #[test]
+    fn healthy_system_passes_stale_node_check_and_self_test() {
+        let config = generate_isolated_config();
+
+        let diagnosis = diagnose(&config);
+
+        let stale_nodes = check(&diagnosis, "stale nodes");
+        assert_that!(stale_nodes["status"], eq "Ok");
+        assert_that!(stale_nodes["remediation"].is_null(), eq true);
+        assert_that!(check(&diagnosis, "self test")["status"], eq "Ok");
+    }
+
+    #[test]
+    fn system_with_dead_node_reports_stale_nodes() {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let mut node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let monitor = unsafe { __internal_node_staged_death(&mut node) };
+        monitor.staged_death();
+        core::mem::forget(node);
+
+        let diagnosis = diagnose(&config);
+
+        let stale_nodes = check(&diagnosis, "stale nodes");
+        assert_that!(stale_nodes["status"], eq "Warning");
+        assert_that!(stale_nodes["remediation"].is_string(), eq true);
+        assert_that!(diagnosis["num_warnings"].as_u64().unwrap(), ge 1);
+
+        Node::<ipc::Service>::list(&config, |node_state| {
+            if let NodeState::Dead(view) = node_state {
+                view.remove_stale_resources().unwrap();
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod echo {
+    use core::time::Duration;
+    use std::process::{Command, Output, Stdio};
+
+    use iceoryx2::port::publisher::Publisher;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::generate_isolated_config;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+    use tempfile::TempDir;
+
+    const SERVICE_NAME: &str = "Echo/Test/Service";
+
+    #[derive(Debug, Clone, Copy)]
+    #[repr(C)]
+    struct Payload {
+        x: f32,
+        id: u64,
+        raw: [u8; 4],
+    }
+
+    fn echo(
+        config: &Config,
+        args: &[&str],
+        publisher: &Publisher<ipc::Service, Payload, ()>,
+    ) -> Output {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let config_file = temp_dir.path().join("iceoryx2.toml");
+        std::fs::write(&config_file, config.to_toml_string().unwrap())
+            .expect("Failed to write config file");
+
+        let mut child = Command::new(env!("CARGO_BIN_EXE_iox2-echo"))
+            .arg(SERVICE_NAME)
+            .args(args)
+            .env("IOX2_CONFIG", &config_file)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("Failed to run iox2-echo");
+
+        // the sample is only received after the subscriber of iox2-echo is connected,
+        // therefore it is sent until iox2-echo terminates
+        while child.try_wait().unwrap().is_none() {
+            publisher
+                .send_copy(Payload {
+                    x: 1.5,
+                    id: 42,
+                    raw: [1, 2, 3, 4],
+                })
+                .unwrap();
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        child.wait_with_output().unwrap()
+    }
+
+    #[test]
+    fn echo_decodes_received_sample_with_type_description() {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let service = node
+            .service_builder(&SERVICE_NAME.try_into().unwrap())
+            .publish_subscribe::<Payload>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let output = echo(
+            &config,
+            &[
+                "--type",
+                "x:f32,id:u64,raw:u8[4]",
+                "--count",
+                "1",
+                "--format",
+                "JSON",
+            ],
+            &publisher,
+        );
+        assert_that!(output.status.success(), eq true);
+
+        let sample: serde_json::Value =
+            serde_json::from_slice(&output.stdout).expect("Failed to parse the sample");
+        assert_that!(
+            sample["payload"]["Decoded"],
+            eq serde_json::json!([[
+                {"name": "x", "value": 1.5},
+                {"name": "id", "value": 42},
+                {"name": "raw", "value": [1, 2, 3, 4]}
+            ]])
+        );
+    }
+
+    #[test]
+    fn echo_fails_when_type_description_does_not_match_payload_size() {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let service = node
+            .service_builder(&SERVICE_NAME.try_into().unwrap())
+            .publish_subscribe::<Payload>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let output = echo(&config, &["--type", "x:f32,id:u64"], &publisher);
+        assert_that!(output.stdout, is_empty);
+        assert_that!(
+            String::from_utf8_lossy(&output.stderr).contains(
+                "the type description has a size of 16 bytes but the payload \"echo_tests::echo::Payload\" of the service has a size of 24 bytes"
+            ),
+            eq true
+        );
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#ifndef IOX2_C_CONFORMANCE_TESTS_CONFORMANCE_H
+#define IOX2_C_CONFORMANCE_TESTS_CONFORMANCE_H
+
+#include "iox2/iceoryx2.h"
+
+#include <stdbool.h>
+#include <stdio.h>
+
+// The conformance tests are written in plain C and link only against the C
+// header and the static library. This way they detect ABI and behavioral
+// regressions that the Rust side FFI tests cannot see since they call the
+// functions from Rust.
+
+/// Fails the current test when the condition does not hold.
+#define IOX2_CHECK(condition)                                                                                          \
+    do {                                                                                                               \
+        if (!(condition)) {                                                                                            \
+            printf("    %s:%d: check failed: %s\n", __FILE__, __LINE__, #condition);                                   \
+            return false;                                                                                              \
+        }                                                                                                              \
+    } while (false)
+
+/// Fails the current test when the call does not return IOX2_OK.
+#define IOX2_CHECK_OK(call) IOX2_CHECK((call) == IOX2_OK)
+
+/// Fails the current test when the error string function returns NULL or an
+/// empty string for the provided error value.
+#define IOX2_CHECK_ERROR_STRING(error_string_fn, error)                                                                \
+    do {                                                                                                               \
+        const char* error_string = error_string_fn(error);                                                             \
+        IOX2_CHECK(error_string != NULL);                                                                              \
+        IOX2_CHECK(error_string[0] != '\0');                                                                           \
+    } while (false)
+
+/// A single conformance test. It is executed once for every service type.
+typedef bool (*iox2_conformance_test_fn)(iox2_service_type_e service_type, iox2_config_h_ref config);
+
+typedef struct {
+    const char* name;
+    iox2_conformance_test_fn test;
+} iox2_conformance_test_t;
+
+/// Generates a service name that is unique for the current process.
+void iox2_conformance_unique_name(char* buffer, size_t buffer_len);
+
+/// Installs the logger of the conformance tests. Must be called before anything
+/// is logged. Returns false when another logger was already set.
+bool iox2_conformance_install_logger(void);
+
+// test suites, every suite is terminated with an entry where the test is NULL
+extern const iox2_conformance_test_t IOX2_CONFIG_TESTS[];
+extern const iox2_conformance_test_t IOX2_NODE_TESTS[];
+extern const iox2_conformance_test_t IOX2_SERVICE_TESTS[];
+extern const iox2_conformance_test_t IOX2_PUBLISH_SUBSCRIBE_TESTS[];
+extern const iox2_conformance_test_t IOX2_EVENT_TESTS[];
+extern const iox2_conformance_test_t IOX2_WAITSET_TESTS[];
+extern const iox2_conformance_test_t IOX2_ATTRIBUTE_TESTS[];
+extern const iox2_conformance_test_t IOX2_LOG_TESTS[];
+
+#endif // IOX2_C_CONFORMANCE_TESTS_CONFORMANCE_H
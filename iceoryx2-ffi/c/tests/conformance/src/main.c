@@ -0,0 +1,97 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#include "conformance.h"
+
+#include <stdint.h>
+#include <stdlib.h>
+#include <time.h>
+
+static uint64_t TEST_RUN_ID = 0;
+static uint64_t NAME_COUNTER = 0;
+
+void iox2_conformance_unique_name(char* buffer, size_t buffer_len) {
+    NAME_COUNTER += 1;
+    snprintf(buffer,
+             buffer_len,
+             "conformance_%llu_%llu",
+             (unsigned long long) TEST_RUN_ID,
+             (unsigned long long) NAME_COUNTER);
+}
+
+static const char* service_type_name(iox2_service_type_e service_type) {
+    return service_type == iox2_service_type_e_IPC ? "ipc" : "local";
+}
+
+static void run_suite(const char* suite_name,
+                      const iox2_conformance_test_t* suite,
+                      iox2_config_h_ref config,
+                      int* number_of_tests,
+                      int* number_of_failures) {
+    const iox2_service_type_e service_types[] = { iox2_service_type_e_IPC, iox2_service_type_e_LOCAL };
+
+    for (const iox2_conformance_test_t* entry = suite; entry->test != NULL; ++entry) {
+        for (size_t i = 0; i < sizeof(service_types) / sizeof(service_types[0]); ++i) {
+            *number_of_tests += 1;
+            printf("[ RUN      ] %s.%s<%s>\n", suite_name, entry->name, service_type_name(service_types[i]));
+            if (entry->test(service_types[i], config)) {
+                printf("[       OK ] %s.%s<%s>\n", suite_name, entry->name, service_type_name(service_types[i]));
+            } else {
+                *number_of_failures += 1;
+                printf("[  FAILED  ] %s.%s<%s>\n", suite_name, entry->name, service_type_name(service_types[i]));
+            }
+        }
+    }
+}
+
+int main(void) {
+    TEST_RUN_ID = ((uint64_t) time(NULL) << 16U) ^ (uint64_t) clock();
+
+    if (!iox2_conformance_install_logger()) {
+        printf("Unable to install the conformance logger!\n");
+        return EXIT_FAILURE;
+    }
+
+    // all tests use an isolated config so that they do not interfere with
+    // other iceoryx2 applications running on the same system
+    iox2_config_h config = NULL;
+    if (iox2_config_default(NULL, &config) != IOX2_OK) {
+        printf("Unable to create the default config!\n");
+        return EXIT_FAILURE;
+    }
+
+    char prefix[64];
+    snprintf(prefix, sizeof(prefix), "iox2_c_conformance_%llu_", (unsigned long long) TEST_RUN_ID);
+    if (iox2_config_global_set_prefix(&config, prefix) != IOX2_OK) {
+        printf("Unable to set the config prefix!\n");
+        iox2_config_drop(config);
+        return EXIT_FAILURE;
+    }
+
+    int number_of_tests = 0;
+    int number_of_failures = 0;
+
+    run_suite("Config", IOX2_CONFIG_TESTS, &config, &number_of_tests, &number_of_failures);
+    run_suite("Node", IOX2_NODE_TESTS, &config, &number_of_tests, &number_of_failures);
+    run_suite("Service", IOX2_SERVICE_TESTS, &config, &number_of_tests, &number_of_failures);
+    run_suite("PublishSubscribe", IOX2_PUBLISH_SUBSCRIBE_TESTS, &config, &number_of_tests, &number_of_failures);
+    run_suite("Event", IOX2_EVENT_TESTS, &config, &number_of_tests, &number_of_failures);
+    run_suite("WaitSet", IOX2_WAITSET_TESTS, &config, &number_of_tests, &number_of_failures);
+    run_suite("Attribute", IOX2_ATTRIBUTE_TESTS, &config, &number_of_tests, &number_of_failures);
+    run_suite("Log", IOX2_LOG_TESTS, &config, &number_of_tests, &number_of_failures);
+
+    iox2_config_drop(config);
+
+    printf("\n%d tests, %d failures\n", number_of_tests, number_of_failures);
+
+    return number_of_failures == 0 ? EXIT_SUCCESS : EXIT_FAILURE;
+}
@@ -0,0 +1,248 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#include "conformance.h"
+
+#include <string.h>
+
+static bool attribute_specifier_stores_attributes(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    iox2_attribute_specifier_h sut = NULL;
+    IOX2_CHECK_OK(iox2_attribute_specifier_new(NULL, &sut));
+    iox2_attribute_specifier_define(&sut, "the_key", "the_value");
+
+    iox2_attribute_set_h_ref attributes = iox2_attribute_specifier_attributes(&sut);
+    IOX2_CHECK(iox2_attribute_set_len(attributes) == 1);
+
+    iox2_attribute_h_ref attribute = iox2_attribute_set_at(attributes, 0);
+    char buffer[64];
+    IOX2_CHECK(iox2_attribute_key_len(attribute) == strlen("the_key"));
+    IOX2_CHECK(iox2_attribute_key(attribute, buffer, sizeof(buffer)) == strlen("the_key") + 1);
+    IOX2_CHECK(strcmp(buffer, "the_key") == 0);
+    IOX2_CHECK(iox2_attribute_value_len(attribute) == strlen("the_value"));
+    IOX2_CHECK(iox2_attribute_value(attribute, buffer, sizeof(buffer)) == strlen("the_value") + 1);
+    IOX2_CHECK(strcmp(buffer, "the_value") == 0);
+
+    iox2_attribute_specifier_drop(sut);
+    return true;
+}
+
+static bool attribute_is_truncated_and_null_terminated_when_buffer_is_too_small(iox2_service_type_e service_type,
+                                                                                iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    iox2_attribute_specifier_h sut = NULL;
+    IOX2_CHECK_OK(iox2_attribute_specifier_new(NULL, &sut));
+    iox2_attribute_specifier_define(&sut, "the_key", "the_value");
+
+    iox2_attribute_h_ref attribute = iox2_attribute_set_at(iox2_attribute_specifier_attributes(&sut), 0);
+    char buffer[5];
+    IOX2_CHECK(iox2_attribute_key(attribute, buffer, sizeof(buffer)) == sizeof(buffer));
+    IOX2_CHECK(strcmp(buffer, "the_") == 0);
+    IOX2_CHECK(iox2_attribute_value(attribute, buffer, sizeof(buffer)) == sizeof(buffer));
+    IOX2_CHECK(strcmp(buffer, "the_") == 0);
+
+    iox2_attribute_specifier_drop(sut);
+    return true;
+}
+
+static iox2_callback_progression_e count_values(const char* value, iox2_callback_context context) {
+    (void) value;
+    *(size_t*) context += 1;
+    return iox2_callback_progression_e_CONTINUE;
+}
+
+static bool attribute_set_provides_all_values_of_a_key(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    iox2_attribute_specifier_t storage;
+    iox2_attribute_specifier_h sut = NULL;
+    IOX2_CHECK_OK(iox2_attribute_specifier_new(&storage, &sut));
+    iox2_attribute_specifier_define(&sut, "key", "value_1");
+    iox2_attribute_specifier_define(&sut, "key", "value_2");
+    iox2_attribute_specifier_define(&sut, "another_key", "value_3");
+
+    size_t number_of_values = 0;
+    iox2_attribute_set_get_key_values(
+        iox2_attribute_specifier_attributes(&sut), "key", count_values, &number_of_values);
+    IOX2_CHECK(number_of_values == 2);
+
+    iox2_attribute_specifier_drop(sut);
+    return true;
+}
+
+static bool attribute_verifier_verifies_requirements(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    iox2_attribute_verifier_h sut = NULL;
+    IOX2_CHECK_OK(iox2_attribute_verifier_new(NULL, &sut));
+    iox2_attribute_verifier_require(&sut, "key", "value");
+    iox2_attribute_verifier_require_key(&sut, "required_key");
+
+    IOX2_CHECK(iox2_attribute_set_len(iox2_attribute_verifier_attributes(&sut)) == 1);
+    IOX2_CHECK(iox2_attribute_verifier_number_of_keys(&sut) == 1);
+    char buffer[64];
+    IOX2_CHECK(iox2_attribute_verifier_key_len(&sut, 0) == strlen("required_key"));
+    IOX2_CHECK(iox2_attribute_verifier_key(&sut, 0, buffer, sizeof(buffer)) == strlen("required_key") + 1);
+    IOX2_CHECK(strcmp(buffer, "required_key") == 0);
+
+    iox2_attribute_specifier_h matching = NULL;
+    IOX2_CHECK_OK(iox2_attribute_specifier_new(NULL, &matching));
+    iox2_attribute_specifier_define(&matching, "key", "value");
+    iox2_attribute_specifier_define(&matching, "required_key", "whatever");
+    IOX2_CHECK(iox2_attribute_verifier_verify_requirements(
+        &sut, iox2_attribute_specifier_attributes(&matching), buffer, sizeof(buffer)));
+
+    iox2_attribute_specifier_h not_matching = NULL;
+    IOX2_CHECK_OK(iox2_attribute_specifier_new(NULL, &not_matching));
+    iox2_attribute_specifier_define(&not_matching, "key", "value");
+    IOX2_CHECK(!iox2_attribute_verifier_verify_requirements(
+        &sut, iox2_attribute_specifier_attributes(&not_matching), buffer, sizeof(buffer)));
+    IOX2_CHECK(strcmp(buffer, "required_key") == 0);
+
+    iox2_attribute_specifier_drop(not_matching);
+    iox2_attribute_specifier_drop(matching);
+    iox2_attribute_verifier_drop(sut);
+    return true;
+}
+
+static iox2_service_builder_pub_sub_h pub_sub_builder(iox2_node_h_ref node, iox2_service_name_ptr service_name) {
+    const char* type_name = "u64";
+    iox2_service_builder_pub_sub_h service_builder =
+        iox2_service_builder_pub_sub(iox2_node_service_builder(node, NULL, service_name));
+    iox2_service_builder_pub_sub_set_payload_type_details(
+        &service_builder, iox2_type_variant_e_FIXED_SIZE, type_name, strlen(type_name), 8, 8);
+    return service_builder;
+}
+
+static bool publish_subscribe_service_with_attributes_can_be_opened(iox2_service_type_e service_type,
+                                                                    iox2_config_h_ref config) {
+    iox2_node_builder_h node_builder = iox2_node_builder_new(NULL);
+    iox2_node_builder_set_config(&node_builder, config);
+    iox2_node_h node = NULL;
+    IOX2_CHECK_OK(iox2_node_builder_create(node_builder, NULL, service_type, &node));
+
+    char name[128];
+    iox2_conformance_unique_name(name, sizeof(name));
+    iox2_service_name_h service_name = NULL;
+    IOX2_CHECK_OK(iox2_service_name_new(NULL, name, strlen(name), &service_name));
+    iox2_service_name_ptr service_name_ptr = iox2_cast_service_name_ptr(&service_name);
+
+    iox2_attribute_specifier_h specifier = NULL;
+    IOX2_CHECK_OK(iox2_attribute_specifier_new(NULL, &specifier));
+    iox2_attribute_specifier_define(&specifier, "camera", "front");
+
+    iox2_attribute_verifier_h verifier = NULL;
+    IOX2_CHECK_OK(iox2_attribute_verifier_new(NULL, &verifier));
+    iox2_attribute_verifier_require(&verifier, "camera", "front");
+
+    iox2_attribute_verifier_h failing_verifier = NULL;
+    IOX2_CHECK_OK(iox2_attribute_verifier_new(NULL, &failing_verifier));
+    iox2_attribute_verifier_require(&failing_verifier, "camera", "rear");
+
+    iox2_port_factory_pub_sub_h service = NULL;
+    IOX2_CHECK_OK(iox2_service_builder_pub_sub_create_with_attributes(
+        pub_sub_builder(&node, service_name_ptr), &specifier, NULL, &service));
+    IOX2_CHECK(iox2_attribute_set_len(iox2_port_factory_pub_sub_attributes(&service)) == 1);
+
+    iox2_port_factory_pub_sub_h sut = NULL;
+    IOX2_CHECK_OK(iox2_service_builder_pub_sub_open_with_attributes(
+        pub_sub_builder(&node, service_name_ptr), &verifier, NULL, &sut));
+    iox2_port_factory_pub_sub_drop(sut);
+
+    IOX2_CHECK_OK(iox2_service_builder_pub_sub_open_or_create_with_attributes(
+        pub_sub_builder(&node, service_name_ptr), &verifier, NULL, &sut));
+    iox2_port_factory_pub_sub_drop(sut);
+
+    sut = NULL;
+    IOX2_CHECK(iox2_service_builder_pub_sub_open_with_attributes(
+                   pub_sub_builder(&node, service_name_ptr), &failing_verifier, NULL, &sut)
+               != IOX2_OK);
+    IOX2_CHECK(sut == NULL);
+
+    iox2_port_factory_pub_sub_drop(service);
+    iox2_attribute_verifier_drop(failing_verifier);
+    iox2_attribute_verifier_drop(verifier);
+    iox2_attribute_specifier_drop(specifier);
+    iox2_service_name_drop(service_name);
+    iox2_node_drop(node);
+    return true;
+}
+
+static bool event_service_with_attributes_can_be_opened(iox2_service_type_e service_type,
+                                                        iox2_config_h_ref config) {
+    iox2_node_builder_h node_builder = iox2_node_builder_new(NULL);
+    iox2_node_builder_set_config(&node_builder, config);
+    iox2_node_h node = NULL;
+    IOX2_CHECK_OK(iox2_node_builder_create(node_builder, NULL, service_type, &node));
+
+    char name[128];
+    iox2_conformance_unique_name(name, sizeof(name));
+    iox2_service_name_h service_name = NULL;
+    IOX2_CHECK_OK(iox2_service_name_new(NULL, name, strlen(name), &service_name));
+    iox2_service_name_ptr service_name_ptr = iox2_cast_service_name_ptr(&service_name);
+
+    iox2_attribute_specifier_h specifier = NULL;
+    IOX2_CHECK_OK(iox2_attribute_specifier_new(NULL, &specifier));
+    iox2_attribute_specifier_define(&specifier, "button", "red");
+
+    iox2_attribute_verifier_h verifier = NULL;
+    IOX2_CHECK_OK(iox2_attribute_verifier_new(NULL, &verifier));
+    iox2_attribute_verifier_require_key(&verifier, "button");
+
+    iox2_port_factory_event_h service = NULL;
+    iox2_service_builder_h service_builder = iox2_node_service_builder(&node, NULL, service_name_ptr);
+    iox2_service_builder_event_h service_builder_event = iox2_service_builder_event(service_builder);
+    iox2_service_builder_event_set_notifier_dead_event(&service_builder_event, 3);
+    iox2_service_builder_event_disable_notifier_dead_event(&service_builder_event);
+    IOX2_CHECK_OK(iox2_service_builder_event_create_with_attributes(service_builder_event, &specifier, NULL, &service));
+    IOX2_CHECK(iox2_attribute_set_len(iox2_port_factory_event_attributes(&service)) == 1);
+
+    iox2_static_config_event_t static_config;
+    iox2_port_factory_event_static_config(&service, &static_config);
+    IOX2_CHECK(!static_config.has_notifier_dead_event);
+
+    iox2_port_factory_event_h sut = NULL;
+    service_builder = iox2_node_service_builder(&node, NULL, service_name_ptr);
+    IOX2_CHECK_OK(iox2_service_builder_event_open_with_attributes(
+        iox2_service_builder_event(service_builder), &verifier, NULL, &sut));
+    iox2_port_factory_event_drop(sut);
+
+    service_builder = iox2_node_service_builder(&node, NULL, service_name_ptr);
+    IOX2_CHECK_OK(iox2_service_builder_event_open_or_create_with_attributes(
+        iox2_service_builder_event(service_builder), &verifier, NULL, &sut));
+    iox2_port_factory_event_drop(sut);
+
+    iox2_port_factory_event_drop(service);
+    iox2_attribute_verifier_drop(verifier);
+    iox2_attribute_specifier_drop(specifier);
+    iox2_service_name_drop(service_name);
+    iox2_node_drop(node);
+    return true;
+}
+
+const iox2_conformance_test_t IOX2_ATTRIBUTE_TESTS[] = {
+    { "attribute_specifier_stores_attributes", attribute_specifier_stores_attributes },
+    { "attribute_is_truncated_and_null_terminated_when_buffer_is_too_small",
+      attribute_is_truncated_and_null_terminated_when_buffer_is_too_small },
+    { "attribute_set_provides_all_values_of_a_key", attribute_set_provides_all_values_of_a_key },
+    { "attribute_verifier_verifies_requirements", attribute_verifier_verifies_requirements },
+    { "publish_subscribe_service_with_attributes_can_be_opened",
+      publish_subscribe_service_with_attributes_can_be_opened },
+    { "event_service_with_attributes_can_be_opened", event_service_with_attributes_can_be_opened },
+    { NULL, NULL },
+};
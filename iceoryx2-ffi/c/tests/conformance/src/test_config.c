@@ -0,0 +1,310 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#include "conformance.h"
+
+#include <string.h>
+
+static bool global_config_is_accessible(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    iox2_config_ptr global_config = iox2_config_global_config();
+    IOX2_CHECK(global_config != NULL);
+
+    iox2_config_h sut = NULL;
+    iox2_config_from_ptr(global_config, NULL, &sut);
+    IOX2_CHECK(sut != NULL);
+    IOX2_CHECK(strlen(iox2_config_global_prefix(&sut)) > 0);
+
+    iox2_config_drop(sut);
+    return true;
+}
+
+static bool config_can_be_created_on_the_stack(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    iox2_config_t storage;
+    iox2_config_h sut = NULL;
+    IOX2_CHECK_OK(iox2_config_default(&storage, &sut));
+    IOX2_CHECK(sut != NULL);
+
+    iox2_config_drop(sut);
+    return true;
+}
+
+static bool config_handle_can_be_cast_to_pointer(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    iox2_config_h sut = NULL;
+    IOX2_CHECK_OK(iox2_config_default(NULL, &sut));
+    IOX2_CHECK_OK(iox2_config_global_set_prefix(&sut, "casted_"));
+
+    iox2_config_h copy = NULL;
+    iox2_config_from_ptr(iox2_cast_config_ptr(&sut), NULL, &copy);
+    IOX2_CHECK(strcmp(iox2_config_global_prefix(&copy), "casted_") == 0);
+
+    iox2_config_drop(copy);
+    iox2_config_drop(sut);
+    return true;
+}
+
+static bool cloned_config_is_independent(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+
+    iox2_config_h sut = NULL;
+    iox2_config_clone(config, NULL, &sut);
+    IOX2_CHECK(sut != NULL);
+    IOX2_CHECK(strcmp(iox2_config_global_prefix(config), iox2_config_global_prefix(&sut)) == 0);
+
+    IOX2_CHECK_OK(iox2_config_global_set_prefix(&sut, "fuu_"));
+    IOX2_CHECK(strcmp(iox2_config_global_prefix(&sut), "fuu_") == 0);
+    IOX2_CHECK(strcmp(iox2_config_global_prefix(config), "fuu_") != 0);
+
+    iox2_config_drop(sut);
+    return true;
+}
+
+static bool global_string_settings_can_be_modified(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    iox2_config_h sut = NULL;
+    IOX2_CHECK_OK(iox2_config_default(NULL, &sut));
+
+    IOX2_CHECK_OK(iox2_config_global_set_root_path(&sut, "/some/root/path"));
+    IOX2_CHECK(strcmp(iox2_config_global_root_path(&sut), "/some/root/path") == 0);
+
+    IOX2_CHECK_OK(iox2_config_global_node_set_directory(&sut, "nodes"));
+    IOX2_CHECK(strcmp(iox2_config_global_node_directory(&sut), "nodes") == 0);
+    IOX2_CHECK_OK(iox2_config_global_node_set_monitor_suffix(&sut, ".mon"));
+    IOX2_CHECK(strcmp(iox2_config_global_node_monitor_suffix(&sut), ".mon") == 0);
+    IOX2_CHECK_OK(iox2_config_global_node_set_static_config_suffix(&sut, ".sc"));
+    IOX2_CHECK(strcmp(iox2_config_global_node_static_config_suffix(&sut), ".sc") == 0);
+    IOX2_CHECK_OK(iox2_config_global_node_set_service_tag_suffix(&sut, ".tag"));
+    IOX2_CHECK(strcmp(iox2_config_global_node_service_tag_suffix(&sut), ".tag") == 0);
+    IOX2_CHECK_OK(iox2_config_global_node_set_crash_journal_suffix(&sut, ".crash"));
+    IOX2_CHECK(strcmp(iox2_config_global_node_crash_journal_suffix(&sut), ".crash") == 0);
+
+    IOX2_CHECK_OK(iox2_config_global_service_set_directory(&sut, "services"));
+    IOX2_CHECK(strcmp(iox2_config_global_service_directory(&sut), "services") == 0);
+    IOX2_CHECK_OK(iox2_config_global_service_set_publisher_data_segment_suffix(&sut, ".data"));
+    IOX2_CHECK(strcmp(iox2_config_global_service_publisher_data_segment_suffix(&sut), ".data") == 0);
+    IOX2_CHECK_OK(iox2_config_global_service_set_static_config_storage_suffix(&sut, ".static"));
+    IOX2_CHECK(strcmp(iox2_config_global_service_static_config_storage_suffix(&sut), ".static") == 0);
+    IOX2_CHECK_OK(iox2_config_global_service_set_dynamic_config_storage_suffix(&sut, ".dynamic"));
+    IOX2_CHECK(strcmp(iox2_config_global_service_dynamic_config_storage_suffix(&sut), ".dynamic") == 0);
+    IOX2_CHECK_OK(iox2_config_global_service_set_connection_suffix(&sut, ".con"));
+    IOX2_CHECK(strcmp(iox2_config_global_service_connection_suffix(&sut), ".con") == 0);
+    IOX2_CHECK_OK(iox2_config_global_service_set_event_connection_suffix(&sut, ".evt"));
+    IOX2_CHECK(strcmp(iox2_config_global_service_event_connection_suffix(&sut), ".evt") == 0);
+
+    iox2_config_drop(sut);
+    return true;
+}
+
+static bool invalid_global_string_settings_are_rejected(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    iox2_config_h sut = NULL;
+    IOX2_CHECK_OK(iox2_config_default(NULL, &sut));
+
+    IOX2_CHECK(iox2_config_global_set_prefix(&sut, "a/b") != IOX2_OK);
+    IOX2_CHECK(iox2_config_global_service_set_connection_suffix(&sut, "a/b") != IOX2_OK);
+
+    iox2_config_drop(sut);
+    return true;
+}
+
+static bool global_node_and_service_settings_can_be_modified(iox2_service_type_e service_type,
+                                                             iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    iox2_config_h sut = NULL;
+    IOX2_CHECK_OK(iox2_config_default(NULL, &sut));
+
+    iox2_config_global_node_set_cleanup_dead_nodes_on_creation(&sut, false);
+    IOX2_CHECK(!iox2_config_global_node_cleanup_dead_nodes_on_creation(&sut));
+    iox2_config_global_node_set_cleanup_dead_nodes_on_destruction(&sut, false);
+    IOX2_CHECK(!iox2_config_global_node_cleanup_dead_nodes_on_destruction(&sut));
+
+    uint64_t seconds = 0;
+    uint32_t nanoseconds = 0;
+    iox2_config_global_service_set_creation_timeout(&sut, 12, 34);
+    iox2_config_global_service_creation_timeout(&sut, &seconds, &nanoseconds);
+    IOX2_CHECK(seconds == 12);
+    IOX2_CHECK(nanoseconds == 34);
+
+    iox2_config_drop(sut);
+    return true;
+}
+
+static bool publish_subscribe_defaults_can_be_modified(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    iox2_config_h sut = NULL;
+    IOX2_CHECK_OK(iox2_config_default(NULL, &sut));
+
+    iox2_config_defaults_publish_subscribe_set_max_subscribers(&sut, 11);
+    IOX2_CHECK(iox2_config_defaults_publish_subscribe_max_subscribers(&sut) == 11);
+    iox2_config_defaults_publish_subscribe_set_max_publishers(&sut, 12);
+    IOX2_CHECK(iox2_config_defaults_publish_subscribe_max_publishers(&sut) == 12);
+    iox2_config_defaults_publish_subscribe_set_max_nodes(&sut, 13);
+    IOX2_CHECK(iox2_config_defaults_publish_subscribe_max_nodes(&sut) == 13);
+    iox2_config_defaults_publish_subscribe_set_subscriber_max_buffer_size(&sut, 14);
+    IOX2_CHECK(iox2_config_defaults_publish_subscribe_subscriber_max_buffer_size(&sut) == 14);
+    iox2_config_defaults_publish_subscribe_set_subscriber_max_borrowed_samples(&sut, 15);
+    IOX2_CHECK(iox2_config_defaults_publish_subscribe_subscriber_max_borrowed_samples(&sut) == 15);
+    iox2_config_defaults_publish_subscribe_set_publisher_max_loaned_samples(&sut, 16);
+    IOX2_CHECK(iox2_config_defaults_publish_subscribe_publisher_max_loaned_samples(&sut) == 16);
+    iox2_config_defaults_publish_subscribe_set_publisher_history_size(&sut, 17);
+    IOX2_CHECK(iox2_config_defaults_publish_subscribe_publisher_history_size(&sut) == 17);
+    iox2_config_defaults_publish_subscribe_set_subscriber_expired_connection_buffer(&sut, 18);
+    IOX2_CHECK(iox2_config_defaults_publish_subscribe_subscriber_expired_connection_buffer(&sut) == 18);
+    iox2_config_defaults_publish_subscribe_set_enable_safe_overflow(&sut, false);
+    IOX2_CHECK(!iox2_config_defaults_publish_subscribe_enable_safe_overflow(&sut));
+    iox2_config_defaults_publish_subscribe_set_unable_to_deliver_strategy(&sut,
+                                                                          iox2_unable_to_deliver_strategy_e_BLOCK);
+    IOX2_CHECK(iox2_config_defaults_publish_subscribe_unable_to_deliver_strategy(&sut)
+               == iox2_unable_to_deliver_strategy_e_BLOCK);
+    iox2_config_defaults_publish_subscribe_set_zero_payload_on_loan(&sut, true);
+    IOX2_CHECK(iox2_config_defaults_publish_subscribe_zero_payload_on_loan(&sut));
+    iox2_config_defaults_publish_subscribe_set_zero_payload_on_loan(&sut, false);
+    IOX2_CHECK(!iox2_config_defaults_publish_subscribe_zero_payload_on_loan(&sut));
+
+    iox2_config_drop(sut);
+    return true;
+}
+
+static bool event_defaults_can_be_modified(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    iox2_config_h sut = NULL;
+    IOX2_CHECK_OK(iox2_config_default(NULL, &sut));
+
+    iox2_config_defaults_event_set_max_listeners(&sut, 21);
+    IOX2_CHECK(iox2_config_defaults_event_max_listeners(&sut) == 21);
+    iox2_config_defaults_event_set_max_notifiers(&sut, 22);
+    IOX2_CHECK(iox2_config_defaults_event_max_notifiers(&sut) == 22);
+    iox2_config_defaults_event_set_max_nodes(&sut, 23);
+    IOX2_CHECK(iox2_config_defaults_event_max_nodes(&sut) == 23);
+    iox2_config_defaults_event_set_event_id_max_value(&sut, 24);
+    IOX2_CHECK(iox2_config_defaults_event_event_id_max_value(&sut) == 24);
+
+    uint64_t seconds = 5;
+    uint32_t nanoseconds = 6;
+    iox2_config_defaults_event_set_deadline(&sut, &seconds, &nanoseconds);
+    seconds = 0;
+    nanoseconds = 0;
+    IOX2_CHECK(iox2_config_defaults_event_deadline(&sut, &seconds, &nanoseconds));
+    IOX2_CHECK(seconds == 5);
+    IOX2_CHECK(nanoseconds == 6);
+    iox2_config_defaults_event_set_deadline(&sut, NULL, NULL);
+    IOX2_CHECK(!iox2_config_defaults_event_deadline(&sut, &seconds, &nanoseconds));
+
+    size_t event_id = 7;
+    size_t value = 0;
+    iox2_config_defaults_event_set_notifier_created_event(&sut, &event_id);
+    IOX2_CHECK(iox2_config_defaults_event_notifier_created_event(&sut, &value));
+    IOX2_CHECK(value == 7);
+    iox2_config_defaults_event_set_notifier_created_event(&sut, NULL);
+    IOX2_CHECK(!iox2_config_defaults_event_notifier_created_event(&sut, &value));
+
+    event_id = 8;
+    iox2_config_defaults_event_set_notifier_dropped_event(&sut, &event_id);
+    IOX2_CHECK(iox2_config_defaults_event_notifier_dropped_event(&sut, &value));
+    IOX2_CHECK(value == 8);
+    iox2_config_defaults_event_set_notifier_dropped_event(&sut, NULL);
+    IOX2_CHECK(!iox2_config_defaults_event_notifier_dropped_event(&sut, &value));
+
+    event_id = 9;
+    iox2_config_defaults_event_set_notifier_dead_event(&sut, &event_id);
+    IOX2_CHECK(iox2_config_defaults_event_notifier_dead_event(&sut, &value));
+    IOX2_CHECK(value == 9);
+    iox2_config_defaults_event_set_notifier_dead_event(&sut, NULL);
+    IOX2_CHECK(!iox2_config_defaults_event_notifier_dead_event(&sut, &value));
+
+    iox2_config_drop(sut);
+    return true;
+}
+
+static bool request_response_defaults_can_be_modified(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    iox2_config_h sut = NULL;
+    IOX2_CHECK_OK(iox2_config_default(NULL, &sut));
+
+    iox2_config_defaults_request_response_set_enable_safe_overflow_for_requests(&sut, false);
+    IOX2_CHECK(!iox2_config_defaults_request_response_enable_safe_overflow_for_requests(&sut));
+    iox2_config_defaults_request_response_set_enable_safe_overflow_for_responses(&sut, false);
+    IOX2_CHECK(!iox2_config_defaults_request_response_enable_safe_overflow_for_responses(&sut));
+    iox2_config_defaults_request_response_set_max_active_responses(&sut, 31);
+    IOX2_CHECK(iox2_config_defaults_request_response_max_active_responses(&sut) == 31);
+    iox2_config_defaults_request_response_set_max_active_requests(&sut, 32);
+    IOX2_CHECK(iox2_config_defaults_request_response_max_active_requests(&sut) == 32);
+    iox2_config_defaults_request_response_set_max_borrowed_responses(&sut, 33);
+    IOX2_CHECK(iox2_config_defaults_request_response_max_borrowed_responses(&sut) == 33);
+    iox2_config_defaults_request_response_set_max_borrowed_requests(&sut, 34);
+    IOX2_CHECK(iox2_config_defaults_request_response_max_borrowed_requests(&sut) == 34);
+    iox2_config_defaults_request_response_set_max_response_buffer_size(&sut, 35);
+    IOX2_CHECK(iox2_config_defaults_request_response_max_response_buffer_size(&sut) == 35);
+    iox2_config_defaults_request_response_set_max_request_buffer_size(&sut, 36);
+    IOX2_CHECK(iox2_config_defaults_request_response_max_request_buffer_size(&sut) == 36);
+    iox2_config_defaults_request_response_set_max_servers(&sut, 37);
+    IOX2_CHECK(iox2_config_defaults_request_response_max_servers(&sut) == 37);
+    iox2_config_defaults_request_response_set_max_clients(&sut, 38);
+    IOX2_CHECK(iox2_config_defaults_request_response_max_clients(&sut) == 38);
+    iox2_config_defaults_request_response_set_max_nodes(&sut, 39);
+    IOX2_CHECK(iox2_config_defaults_request_response_max_nodes(&sut) == 39);
+    iox2_config_defaults_request_response_set_client_max_loaned_requests(&sut, 40);
+    IOX2_CHECK(iox2_config_defaults_request_response_client_max_loaned_requests(&sut) == 40);
+    iox2_config_defaults_request_response_set_server_max_loaned_responses_per_request(&sut, 41);
+    IOX2_CHECK(iox2_config_defaults_request_response_server_max_loaned_responses_per_request(&sut) == 41);
+
+    iox2_config_drop(sut);
+    return true;
+}
+
+static bool loading_non_existing_config_file_fails(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    iox2_config_h sut = NULL;
+    int result = iox2_config_from_file(NULL, &sut, "/there/is/no/config/file/named/like/this.toml");
+    IOX2_CHECK(result != IOX2_OK);
+    IOX2_CHECK(sut == NULL);
+    IOX2_CHECK_ERROR_STRING(iox2_config_creation_error_string, (iox2_config_creation_error_e) result);
+
+    return true;
+}
+
+const iox2_conformance_test_t IOX2_CONFIG_TESTS[] = {
+    { "global_config_is_accessible", global_config_is_accessible },
+    { "config_can_be_created_on_the_stack", config_can_be_created_on_the_stack },
+    { "config_handle_can_be_cast_to_pointer", config_handle_can_be_cast_to_pointer },
+    { "cloned_config_is_independent", cloned_config_is_independent },
+    { "global_string_settings_can_be_modified", global_string_settings_can_be_modified },
+    { "invalid_global_string_settings_are_rejected", invalid_global_string_settings_are_rejected },
+    { "global_node_and_service_settings_can_be_modified", global_node_and_service_settings_can_be_modified },
+    { "publish_subscribe_defaults_can_be_modified", publish_subscribe_defaults_can_be_modified },
+    { "event_defaults_can_be_modified", event_defaults_can_be_modified },
+    { "request_response_defaults_can_be_modified", request_response_defaults_can_be_modified },
+    { "loading_non_existing_config_file_fails", loading_non_existing_config_file_fails },
+    { NULL, NULL },
+};
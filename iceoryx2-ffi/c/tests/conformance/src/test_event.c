@@ -0,0 +1,268 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#include "conformance.h"
+
+#include <string.h>
+
+static const size_t MAX_NOTIFIERS = 3;
+static const size_t MAX_LISTENERS = 4;
+static const size_t DEFAULT_EVENT_ID = 12;
+static const size_t NOTIFIER_DEAD_EVENT = 99;
+
+typedef struct {
+    iox2_node_h node;
+    iox2_service_name_h service_name;
+    iox2_port_factory_event_h service;
+    iox2_notifier_h notifier;
+    iox2_listener_h listener;
+} fixture_t;
+
+static bool fixture_create(fixture_t* fixture, iox2_service_type_e service_type, iox2_config_h_ref config) {
+    iox2_node_builder_h node_builder = iox2_node_builder_new(NULL);
+    iox2_node_builder_set_config(&node_builder, config);
+    IOX2_CHECK_OK(iox2_node_builder_create(node_builder, NULL, service_type, &fixture->node));
+
+    char name[128];
+    iox2_conformance_unique_name(name, sizeof(name));
+    IOX2_CHECK_OK(iox2_service_name_new(NULL, name, strlen(name), &fixture->service_name));
+
+    iox2_service_builder_h service_builder =
+        iox2_node_service_builder(&fixture->node, NULL, iox2_cast_service_name_ptr(&fixture->service_name));
+    iox2_service_builder_event_h service_builder_event = iox2_service_builder_event(service_builder);
+    iox2_service_builder_event_set_max_notifiers(&service_builder_event, MAX_NOTIFIERS);
+    iox2_service_builder_event_set_max_listeners(&service_builder_event, MAX_LISTENERS);
+    iox2_service_builder_event_set_notifier_created_event(&service_builder_event, 1);
+    iox2_service_builder_event_disable_notifier_created_event(&service_builder_event);
+    iox2_service_builder_event_set_notifier_dropped_event(&service_builder_event, 2);
+    iox2_service_builder_event_disable_notifier_dropped_event(&service_builder_event);
+    iox2_service_builder_event_set_notifier_dead_event(&service_builder_event, NOTIFIER_DEAD_EVENT);
+    iox2_service_builder_event_set_deadline(&service_builder_event, 1, 2);
+    iox2_service_builder_event_disable_deadline(&service_builder_event);
+    IOX2_CHECK_OK(iox2_service_builder_event_create(service_builder_event, NULL, &fixture->service));
+
+    iox2_port_factory_notifier_builder_h notifier_builder =
+        iox2_port_factory_event_notifier_builder(&fixture->service, NULL);
+    iox2_event_id_t default_event_id = { DEFAULT_EVENT_ID };
+    iox2_port_factory_notifier_builder_set_default_event_id(&notifier_builder, &default_event_id);
+    IOX2_CHECK_OK(iox2_port_factory_notifier_builder_create(notifier_builder, NULL, &fixture->notifier));
+
+    iox2_port_factory_listener_builder_h listener_builder =
+        iox2_port_factory_event_listener_builder(&fixture->service, NULL);
+    IOX2_CHECK_OK(iox2_port_factory_listener_builder_create(listener_builder, NULL, &fixture->listener));
+
+    return true;
+}
+
+static void fixture_drop(fixture_t* fixture) {
+    iox2_listener_drop(fixture->listener);
+    iox2_notifier_drop(fixture->notifier);
+    iox2_port_factory_event_drop(fixture->service);
+    iox2_service_name_drop(fixture->service_name);
+    iox2_node_drop(fixture->node);
+}
+
+static bool service_settings_are_applied(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    fixture_t fixture;
+    IOX2_CHECK(fixture_create(&fixture, service_type, config));
+
+    iox2_static_config_event_t static_config;
+    iox2_port_factory_event_static_config(&fixture.service, &static_config);
+    IOX2_CHECK(static_config.max_notifiers == MAX_NOTIFIERS);
+    IOX2_CHECK(static_config.max_listeners == MAX_LISTENERS);
+    IOX2_CHECK(!static_config.has_notifier_created_event);
+    IOX2_CHECK(!static_config.has_notifier_dropped_event);
+    IOX2_CHECK(static_config.has_notifier_dead_event);
+    IOX2_CHECK(static_config.notifier_dead_event == NOTIFIER_DEAD_EVENT);
+
+    size_t len = 0;
+    const char* name = iox2_service_name_as_chars(iox2_port_factory_event_service_name(&fixture.service), &len);
+    size_t expected_len = 0;
+    const char* expected_name =
+        iox2_service_name_as_chars(iox2_cast_service_name_ptr(&fixture.service_name), &expected_len);
+    IOX2_CHECK(len == expected_len);
+    IOX2_CHECK(strncmp(name, expected_name, len) == 0);
+
+    IOX2_CHECK(iox2_port_factory_event_attributes(&fixture.service) != NULL);
+
+    uint64_t seconds = 0;
+    uint32_t nanoseconds = 0;
+    IOX2_CHECK(!iox2_notifier_deadline(&fixture.notifier, &seconds, &nanoseconds));
+    IOX2_CHECK(!iox2_listener_deadline(&fixture.listener, &seconds, &nanoseconds));
+
+    fixture_drop(&fixture);
+    return true;
+}
+
+static bool existing_service_can_be_opened(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    fixture_t fixture;
+    IOX2_CHECK(fixture_create(&fixture, service_type, config));
+
+    iox2_service_builder_h service_builder =
+        iox2_node_service_builder(&fixture.node, NULL, iox2_cast_service_name_ptr(&fixture.service_name));
+    iox2_port_factory_event_h sut = NULL;
+    IOX2_CHECK_OK(iox2_service_builder_event_open(iox2_service_builder_event(service_builder), NULL, &sut));
+    iox2_port_factory_event_drop(sut);
+
+    service_builder = iox2_node_service_builder(&fixture.node, NULL, iox2_cast_service_name_ptr(&fixture.service_name));
+    IOX2_CHECK_OK(iox2_service_builder_event_open_or_create(iox2_service_builder_event(service_builder), NULL, &sut));
+    iox2_port_factory_event_drop(sut);
+
+    service_builder = iox2_node_service_builder(&fixture.node, NULL, iox2_cast_service_name_ptr(&fixture.service_name));
+    IOX2_CHECK(iox2_service_builder_event_create(iox2_service_builder_event(service_builder), NULL, &sut)
+               == iox2_event_open_or_create_error_e_C_ALREADY_EXISTS);
+
+    fixture_drop(&fixture);
+    return true;
+}
+
+static bool port_ids_are_unique(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    fixture_t fixture;
+    IOX2_CHECK(fixture_create(&fixture, service_type, config));
+
+    iox2_port_factory_notifier_builder_h notifier_builder =
+        iox2_port_factory_event_notifier_builder(&fixture.service, NULL);
+    iox2_notifier_h notifier = NULL;
+    IOX2_CHECK_OK(iox2_port_factory_notifier_builder_create(notifier_builder, NULL, &notifier));
+
+    iox2_port_factory_listener_builder_h listener_builder =
+        iox2_port_factory_event_listener_builder(&fixture.service, NULL);
+    iox2_listener_h listener = NULL;
+    IOX2_CHECK_OK(iox2_port_factory_listener_builder_create(listener_builder, NULL, &listener));
+
+    iox2_unique_notifier_id_h notifier_id_1 = NULL;
+    iox2_notifier_id(&fixture.notifier, NULL, &notifier_id_1);
+    iox2_unique_notifier_id_h notifier_id_2 = NULL;
+    iox2_notifier_id(&notifier, NULL, &notifier_id_2);
+    IOX2_CHECK(iox2_unique_notifier_id_eq(&notifier_id_1, &notifier_id_1));
+    IOX2_CHECK(!iox2_unique_notifier_id_eq(&notifier_id_1, &notifier_id_2));
+    IOX2_CHECK(iox2_unique_notifier_id_less(&notifier_id_1, &notifier_id_2)
+               != iox2_unique_notifier_id_less(&notifier_id_2, &notifier_id_1));
+
+    uint8_t notifier_id_value_1[IOX2_UNIQUE_PORT_ID_LENGTH] = { 0 };
+    iox2_unique_notifier_id_value(notifier_id_1, notifier_id_value_1, sizeof(notifier_id_value_1));
+    uint8_t notifier_id_value_2[IOX2_UNIQUE_PORT_ID_LENGTH] = { 0 };
+    iox2_unique_notifier_id_value(notifier_id_2, notifier_id_value_2, sizeof(notifier_id_value_2));
+    IOX2_CHECK(memcmp(notifier_id_value_1, notifier_id_value_2, IOX2_UNIQUE_PORT_ID_LENGTH) != 0);
+
+    iox2_unique_listener_id_h listener_id_1 = NULL;
+    iox2_listener_id(&fixture.listener, NULL, &listener_id_1);
+    iox2_unique_listener_id_h listener_id_2 = NULL;
+    iox2_listener_id(&listener, NULL, &listener_id_2);
+    IOX2_CHECK(iox2_unique_listener_id_eq(&listener_id_1, &listener_id_1));
+    IOX2_CHECK(!iox2_unique_listener_id_eq(&listener_id_1, &listener_id_2));
+    IOX2_CHECK(iox2_unique_listener_id_less(&listener_id_1, &listener_id_2)
+               != iox2_unique_listener_id_less(&listener_id_2, &listener_id_1));
+
+    uint8_t listener_id_value_1[IOX2_UNIQUE_PORT_ID_LENGTH] = { 0 };
+    iox2_unique_listener_id_value(listener_id_1, listener_id_value_1, sizeof(listener_id_value_1));
+    uint8_t listener_id_value_2[IOX2_UNIQUE_PORT_ID_LENGTH] = { 0 };
+    iox2_unique_listener_id_value(listener_id_2, listener_id_value_2, sizeof(listener_id_value_2));
+    IOX2_CHECK(memcmp(listener_id_value_1, listener_id_value_2, IOX2_UNIQUE_PORT_ID_LENGTH) != 0);
+
+    iox2_unique_listener_id_drop(listener_id_2);
+    iox2_unique_listener_id_drop(listener_id_1);
+    iox2_unique_notifier_id_drop(notifier_id_2);
+    iox2_unique_notifier_id_drop(notifier_id_1);
+    iox2_listener_drop(listener);
+    iox2_notifier_drop(notifier);
+    fixture_drop(&fixture);
+    return true;
+}
+
+static bool notification_with_default_event_id_is_received(iox2_service_type_e service_type,
+                                                           iox2_config_h_ref config) {
+    fixture_t fixture;
+    IOX2_CHECK(fixture_create(&fixture, service_type, config));
+
+    iox2_event_id_t event_id = { 0 };
+    bool has_received_one = true;
+    IOX2_CHECK_OK(iox2_listener_try_wait_one(&fixture.listener, &event_id, &has_received_one));
+    IOX2_CHECK(!has_received_one);
+
+    size_t number_of_notified_listeners = 0;
+    IOX2_CHECK_OK(iox2_notifier_notify(&fixture.notifier, &number_of_notified_listeners));
+    IOX2_CHECK(number_of_notified_listeners == 1);
+
+    IOX2_CHECK_OK(iox2_listener_timed_wait_one(&fixture.listener, &event_id, &has_received_one, 1, 0));
+    IOX2_CHECK(has_received_one);
+    IOX2_CHECK(event_id.value == DEFAULT_EVENT_ID);
+
+    IOX2_CHECK_OK(iox2_notifier_notify(&fixture.notifier, NULL));
+    IOX2_CHECK_OK(iox2_listener_blocking_wait_one(&fixture.listener, &event_id, &has_received_one));
+    IOX2_CHECK(has_received_one);
+    IOX2_CHECK(event_id.value == DEFAULT_EVENT_ID);
+
+    fixture_drop(&fixture);
+    return true;
+}
+
+typedef struct {
+    size_t number_of_events;
+    size_t last_event_id;
+} wait_all_context_t;
+
+static void wait_all_callback(const iox2_event_id_t* event_id, iox2_callback_context context) {
+    wait_all_context_t* ctx = (wait_all_context_t*) context;
+    ctx->number_of_events += 1;
+    ctx->last_event_id = event_id->value;
+}
+
+static bool notification_with_custom_event_id_is_received(iox2_service_type_e service_type,
+                                                          iox2_config_h_ref config) {
+    fixture_t fixture;
+    IOX2_CHECK(fixture_create(&fixture, service_type, config));
+
+    iox2_event_id_t custom_event_id = { 5 };
+    IOX2_CHECK_OK(iox2_notifier_notify_with_custom_event_id(&fixture.notifier, &custom_event_id, NULL));
+
+    wait_all_context_t ctx = { 0, 0 };
+    IOX2_CHECK_OK(iox2_listener_timed_wait_all(&fixture.listener, wait_all_callback, &ctx, 1, 0));
+    IOX2_CHECK(ctx.number_of_events == 1);
+    IOX2_CHECK(ctx.last_event_id == 5);
+
+    custom_event_id.value = 6;
+    IOX2_CHECK_OK(iox2_notifier_notify_with_custom_event_id(&fixture.notifier, &custom_event_id, NULL));
+    IOX2_CHECK_OK(iox2_listener_blocking_wait_all(&fixture.listener, wait_all_callback, &ctx));
+    IOX2_CHECK(ctx.number_of_events == 2);
+    IOX2_CHECK(ctx.last_event_id == 6);
+
+    IOX2_CHECK_OK(iox2_listener_try_wait_all(&fixture.listener, wait_all_callback, &ctx));
+    IOX2_CHECK(ctx.number_of_events == 2);
+
+    fixture_drop(&fixture);
+    return true;
+}
+
+static bool event_error_strings_are_available(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    IOX2_CHECK_ERROR_STRING(iox2_notifier_create_error_string,
+                            iox2_notifier_create_error_e_EXCEEDS_MAX_SUPPORTED_NOTIFIERS);
+    IOX2_CHECK_ERROR_STRING(iox2_listener_create_error_string,
+                            iox2_listener_create_error_e_EXCEEDS_MAX_SUPPORTED_LISTENERS);
+    IOX2_CHECK_ERROR_STRING(iox2_notifier_notify_error_string, iox2_notifier_notify_error_e_EVENT_ID_OUT_OF_BOUNDS);
+    IOX2_CHECK_ERROR_STRING(iox2_listener_wait_error_string, iox2_listener_wait_error_e_INTERNAL_FAILURE);
+
+    return true;
+}
+
+const iox2_conformance_test_t IOX2_EVENT_TESTS[] = {
+    { "service_settings_are_applied", service_settings_are_applied },
+    { "existing_service_can_be_opened", existing_service_can_be_opened },
+    { "port_ids_are_unique", port_ids_are_unique },
+    { "notification_with_default_event_id_is_received", notification_with_default_event_id_is_received },
+    { "notification_with_custom_event_id_is_received", notification_with_custom_event_id_is_received },
+    { "event_error_strings_are_available", event_error_strings_are_available },
+    { NULL, NULL },
+};
@@ -0,0 +1,102 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#include "conformance.h"
+
+#include <stdint.h>
+#include <string.h>
+
+// The logger is a process wide singleton which can be set only once, therefore
+// the conformance logger is installed at startup before anything is logged and
+// the tests verify that it cannot be replaced afterwards.
+
+static uint64_t NUMBER_OF_LOG_MESSAGES = 0;
+
+static void conformance_logger(iox2_log_level_e log_level, const char* origin, const char* message) {
+    (void) log_level;
+    NUMBER_OF_LOG_MESSAGES += 1;
+    printf("    [log] %s: %s\n", origin, message);
+}
+
+bool iox2_conformance_install_logger(void) {
+    return iox2_set_logger(conformance_logger);
+}
+
+static bool log_level_can_be_set_and_read(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    const iox2_log_level_e original_level = iox2_get_log_level();
+
+    iox2_set_log_level(iox2_log_level_e_TRACE);
+    IOX2_CHECK(iox2_get_log_level() == iox2_log_level_e_TRACE);
+    iox2_set_log_level(iox2_log_level_e_FATAL);
+    IOX2_CHECK(iox2_get_log_level() == iox2_log_level_e_FATAL);
+
+    iox2_set_log_level(original_level);
+    IOX2_CHECK(iox2_get_log_level() == original_level);
+    return true;
+}
+
+static bool messages_below_the_log_level_can_be_logged(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    const iox2_log_level_e original_level = iox2_get_log_level();
+
+    iox2_set_log_level(iox2_log_level_e_FATAL);
+    iox2_log(iox2_log_level_e_TRACE, "conformance", "this message shall be suppressed");
+
+    iox2_set_log_level(original_level);
+    return true;
+}
+
+static bool installed_logger_receives_messages(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    const iox2_log_level_e original_level = iox2_get_log_level();
+    iox2_set_log_level(iox2_log_level_e_INFO);
+
+    const uint64_t number_of_log_messages = NUMBER_OF_LOG_MESSAGES;
+    iox2_log(iox2_log_level_e_INFO, "conformance", "this message shall be received by the installed logger");
+    IOX2_CHECK(NUMBER_OF_LOG_MESSAGES == number_of_log_messages + 1);
+
+    iox2_set_log_level(original_level);
+    return true;
+}
+
+static bool installed_logger_cannot_be_replaced(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    IOX2_CHECK(!iox2_set_logger(conformance_logger));
+    IOX2_CHECK(!iox2_use_console_logger());
+
+    // the file logger creates its log file even when it is not installed
+    char log_file[128];
+    iox2_conformance_unique_name(log_file, sizeof(log_file));
+    strncat(log_file, ".log", sizeof(log_file) - strlen(log_file) - 1);
+    const bool is_file_logger_installed = iox2_use_file_logger(log_file);
+    remove(log_file);
+    IOX2_CHECK(!is_file_logger_installed);
+
+    return true;
+}
+
+const iox2_conformance_test_t IOX2_LOG_TESTS[] = {
+    { "log_level_can_be_set_and_read", log_level_can_be_set_and_read },
+    { "messages_below_the_log_level_can_be_logged", messages_below_the_log_level_can_be_logged },
+    { "installed_logger_receives_messages", installed_logger_receives_messages },
+    { "installed_logger_cannot_be_replaced", installed_logger_cannot_be_replaced },
+    { NULL, NULL },
+};
@@ -0,0 +1,250 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#include "conformance.h"
+
+#include <string.h>
+
+static bool node_can_be_created_with_name_and_config(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    const char* name_value = "conformance node";
+    iox2_node_name_h node_name = NULL;
+    IOX2_CHECK_OK(iox2_node_name_new(NULL, name_value, strlen(name_value), &node_name));
+
+    iox2_node_builder_h node_builder = iox2_node_builder_new(NULL);
+    iox2_node_builder_set_name(&node_builder, iox2_cast_node_name_ptr(&node_name));
+    iox2_node_builder_set_config(&node_builder, config);
+    iox2_node_builder_set_signal_handling_mode(&node_builder, iox2_signal_handling_mode_e_DISABLED);
+
+    iox2_node_h sut = NULL;
+    IOX2_CHECK_OK(iox2_node_builder_create(node_builder, NULL, service_type, &sut));
+    iox2_node_name_drop(node_name);
+
+    size_t name_len = 0;
+    const char* name = iox2_node_name_as_chars(iox2_node_name(&sut), &name_len);
+    IOX2_CHECK(name_len == strlen(name_value));
+    IOX2_CHECK(strncmp(name, name_value, name_len) == 0);
+
+    iox2_config_h node_config = NULL;
+    iox2_config_from_ptr(iox2_node_config(&sut), NULL, &node_config);
+    IOX2_CHECK(strcmp(iox2_config_global_prefix(&node_config), iox2_config_global_prefix(config)) == 0);
+    iox2_config_drop(node_config);
+
+    IOX2_CHECK(iox2_node_signal_handling_mode(&sut) == iox2_signal_handling_mode_e_DISABLED);
+
+    iox2_node_drop(sut);
+    return true;
+}
+
+static bool node_can_be_created_on_the_stack(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    iox2_node_builder_t node_builder_storage;
+    iox2_node_builder_h node_builder = iox2_node_builder_new(&node_builder_storage);
+    iox2_node_builder_set_config(&node_builder, config);
+
+    iox2_node_t node_storage;
+    iox2_node_h sut = NULL;
+    IOX2_CHECK_OK(iox2_node_builder_create(node_builder, &node_storage, service_type, &sut));
+    IOX2_CHECK(sut != NULL);
+
+    iox2_node_drop(sut);
+    return true;
+}
+
+static bool invalid_node_name_is_rejected(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    // node names must be valid UTF-8
+    const char name_value[] = { 'a', (char) 0xff, (char) 0xfe, 'b' };
+
+    iox2_node_name_h sut = NULL;
+    IOX2_CHECK(iox2_node_name_new(NULL, name_value, sizeof(name_value), &sut) != IOX2_OK);
+    IOX2_CHECK(sut == NULL);
+
+    return true;
+}
+
+static bool node_with_quota_can_be_created(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    iox2_node_builder_h node_builder = iox2_node_builder_new(NULL);
+    iox2_node_builder_set_config(&node_builder, config);
+    iox2_node_builder_set_max_shared_memory_bytes(&node_builder, 1024UL * 1024UL * 1024UL);
+    iox2_node_builder_set_max_ports(&node_builder, 8);
+    iox2_node_builder_set_max_services(&node_builder, 4);
+
+    iox2_node_h sut = NULL;
+    IOX2_CHECK_OK(iox2_node_builder_create(node_builder, NULL, service_type, &sut));
+
+    iox2_node_drop(sut);
+    return true;
+}
+
+static bool node_with_crash_journal_can_be_created(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    iox2_node_builder_h node_builder = iox2_node_builder_new(NULL);
+    iox2_node_builder_set_config(&node_builder, config);
+    iox2_node_builder_set_crash_journal(&node_builder, true);
+
+    iox2_node_h sut = NULL;
+    IOX2_CHECK_OK(iox2_node_builder_create(node_builder, NULL, service_type, &sut));
+
+    iox2_node_drop(sut);
+    return true;
+}
+
+static bool node_id_can_be_cloned_and_compared(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    iox2_node_builder_h node_builder = iox2_node_builder_new(NULL);
+    iox2_node_builder_set_config(&node_builder, config);
+    iox2_node_h node = NULL;
+    IOX2_CHECK_OK(iox2_node_builder_create(node_builder, NULL, service_type, &node));
+
+    iox2_node_id_h sut = NULL;
+    iox2_node_id_clone_from_ptr(NULL, iox2_node_id(&node, service_type), &sut);
+    IOX2_CHECK(sut != NULL);
+
+    iox2_node_id_t clone_storage;
+    iox2_node_id_h clone = NULL;
+    iox2_node_id_clone_from_handle(&clone_storage, &sut, &clone);
+    IOX2_CHECK(clone != NULL);
+
+    IOX2_CHECK(iox2_node_id_value_high(&sut) == iox2_node_id_value_high(&clone));
+    IOX2_CHECK(iox2_node_id_value_low(&sut) == iox2_node_id_value_low(&clone));
+    IOX2_CHECK(iox2_node_id_pid(&sut) == iox2_node_id_pid(&clone));
+    IOX2_CHECK(iox2_node_id_pid(&sut) > 0);
+
+    uint64_t seconds = 0;
+    uint32_t nanoseconds = 0;
+    iox2_node_id_creation_time(&sut, &seconds, &nanoseconds);
+    IOX2_CHECK(seconds != 0 || nanoseconds != 0);
+
+    iox2_node_id_drop(clone);
+    iox2_node_id_drop(sut);
+    iox2_node_drop(node);
+    return true;
+}
+
+static bool stale_resources_of_removed_node_cannot_be_removed(iox2_service_type_e service_type,
+                                                              iox2_config_h_ref config) {
+    iox2_node_builder_h node_builder = iox2_node_builder_new(NULL);
+    iox2_node_builder_set_config(&node_builder, config);
+    iox2_node_h node = NULL;
+    IOX2_CHECK_OK(iox2_node_builder_create(node_builder, NULL, service_type, &node));
+
+    iox2_node_id_h node_id = NULL;
+    iox2_node_id_clone_from_ptr(NULL, iox2_node_id(&node, service_type), &node_id);
+
+    // a dropped node removes all of its resources, nothing is left to clean up
+    iox2_node_drop(node);
+
+    bool has_success = true;
+    IOX2_CHECK_OK(iox2_dead_node_remove_stale_resources(service_type, &node_id, config, &has_success));
+    IOX2_CHECK(!has_success);
+
+    iox2_node_id_drop(node_id);
+    return true;
+}
+
+static bool node_wait_returns_after_cycle_time(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    iox2_node_builder_h node_builder = iox2_node_builder_new(NULL);
+    iox2_node_builder_set_config(&node_builder, config);
+    iox2_node_h sut = NULL;
+    IOX2_CHECK_OK(iox2_node_builder_create(node_builder, NULL, service_type, &sut));
+
+    IOX2_CHECK_OK(iox2_node_wait(&sut, 0, 1000));
+
+    iox2_node_drop(sut);
+    return true;
+}
+
+typedef struct {
+    uint64_t value_high;
+    uint64_t value_low;
+    bool found;
+    int32_t pid;
+    uint64_t seconds;
+    uint32_t nanoseconds;
+} node_list_context_t;
+
+static iox2_callback_progression_e node_list_callback(iox2_node_state_e node_state,
+                                                      iox2_node_id_ptr node_id_ptr,
+                                                      const char* executable,
+                                                      iox2_node_name_ptr node_name,
+                                                      iox2_config_ptr config,
+                                                      iox2_callback_context context) {
+    (void) executable;
+    (void) node_name;
+    (void) config;
+
+    node_list_context_t* ctx = (node_list_context_t*) context;
+
+    if (node_state != iox2_node_state_e_ALIVE) {
+        return iox2_callback_progression_e_CONTINUE;
+    }
+
+    if (iox2_node_id_ptr_value_high(node_id_ptr) == ctx->value_high
+        && iox2_node_id_ptr_value_low(node_id_ptr) == ctx->value_low) {
+        ctx->found = true;
+        ctx->pid = iox2_node_id_ptr_pid(node_id_ptr);
+        iox2_node_id_ptr_creation_time(node_id_ptr, &ctx->seconds, &ctx->nanoseconds);
+    }
+
+    return ctx->found ? iox2_callback_progression_e_STOP : iox2_callback_progression_e_CONTINUE;
+}
+
+static bool node_list_contains_created_node(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    iox2_node_builder_h node_builder = iox2_node_builder_new(NULL);
+    iox2_node_builder_set_config(&node_builder, config);
+    iox2_node_h node = NULL;
+    IOX2_CHECK_OK(iox2_node_builder_create(node_builder, NULL, service_type, &node));
+
+    iox2_node_id_h node_id = NULL;
+    iox2_node_id_clone_from_ptr(NULL, iox2_node_id(&node, service_type), &node_id);
+
+    node_list_context_t ctx = { iox2_node_id_value_high(&node_id), iox2_node_id_value_low(&node_id), false, 0, 0, 0 };
+
+    IOX2_CHECK_OK(iox2_node_list(service_type, iox2_node_config(&node), node_list_callback, &ctx));
+    IOX2_CHECK(ctx.found);
+
+    uint64_t seconds = 0;
+    uint32_t nanoseconds = 0;
+    iox2_node_id_creation_time(&node_id, &seconds, &nanoseconds);
+    IOX2_CHECK(ctx.pid == iox2_node_id_pid(&node_id));
+    IOX2_CHECK(ctx.seconds == seconds);
+    IOX2_CHECK(ctx.nanoseconds == nanoseconds);
+    iox2_node_id_drop(node_id);
+
+    iox2_node_drop(node);
+    return true;
+}
+
+static bool node_error_strings_are_available(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    IOX2_CHECK_ERROR_STRING(iox2_node_creation_failure_string, iox2_node_creation_failure_e_INTERNAL_ERROR);
+    IOX2_CHECK_ERROR_STRING(iox2_node_list_failure_string, iox2_node_list_failure_e_INTERRUPT);
+    IOX2_CHECK_ERROR_STRING(iox2_node_wait_failure_string, iox2_node_wait_failure_e_TERMINATION_REQUEST);
+    IOX2_CHECK_ERROR_STRING(iox2_node_cleanup_failure_string, iox2_node_cleanup_failure_e_VERSION_MISMATCH);
+
+    return true;
+}
+
+const iox2_conformance_test_t IOX2_NODE_TESTS[] = {
+    { "node_can_be_created_with_name_and_config", node_can_be_created_with_name_and_config },
+    { "node_can_be_created_on_the_stack", node_can_be_created_on_the_stack },
+    { "invalid_node_name_is_rejected", invalid_node_name_is_rejected },
+    { "node_with_quota_can_be_created", node_with_quota_can_be_created },
+    { "node_with_crash_journal_can_be_created", node_with_crash_journal_can_be_created },
+    { "node_id_can_be_cloned_and_compared", node_id_can_be_cloned_and_compared },
+    { "stale_resources_of_removed_node_cannot_be_removed", stale_resources_of_removed_node_cannot_be_removed },
+    { "node_wait_returns_after_cycle_time", node_wait_returns_after_cycle_time },
+    { "node_list_contains_created_node", node_list_contains_created_node },
+    { "node_error_strings_are_available", node_error_strings_are_available },
+    { NULL, NULL },
+};
@@ -0,0 +1,538 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#include "conformance.h"
+
+#include <string.h>
+
+static const char* PAYLOAD_TYPE_NAME = "u64";
+static const char* USER_HEADER_TYPE_NAME = "u32";
+static const size_t MAX_PUBLISHERS = 3;
+static const size_t MAX_SUBSCRIBERS = 4;
+static const size_t MAX_NODES = 5;
+static const size_t HISTORY_SIZE = 2;
+static const size_t BUFFER_SIZE = 6;
+static const size_t MAX_BORROWED_SAMPLES = 3;
+
+typedef struct {
+    iox2_node_h node;
+    iox2_service_name_h service_name;
+    iox2_port_factory_pub_sub_h service;
+} fixture_t;
+
+static bool fixture_create(fixture_t* fixture, iox2_service_type_e service_type, iox2_config_h_ref config) {
+    iox2_node_builder_h node_builder = iox2_node_builder_new(NULL);
+    iox2_node_builder_set_config(&node_builder, config);
+    IOX2_CHECK_OK(iox2_node_builder_create(node_builder, NULL, service_type, &fixture->node));
+
+    char name[128];
+    iox2_conformance_unique_name(name, sizeof(name));
+    IOX2_CHECK_OK(iox2_service_name_new(NULL, name, strlen(name), &fixture->service_name));
+
+    iox2_service_builder_h service_builder =
+        iox2_node_service_builder(&fixture->node, NULL, iox2_cast_service_name_ptr(&fixture->service_name));
+    iox2_service_builder_pub_sub_h service_builder_pub_sub = iox2_service_builder_pub_sub(service_builder);
+    IOX2_CHECK_OK(iox2_service_builder_pub_sub_set_payload_type_details(&service_builder_pub_sub,
+                                                                        iox2_type_variant_e_FIXED_SIZE,
+                                                                        PAYLOAD_TYPE_NAME,
+                                                                        strlen(PAYLOAD_TYPE_NAME),
+                                                                        sizeof(uint64_t),
+                                                                        sizeof(uint64_t)));
+    IOX2_CHECK_OK(iox2_service_builder_pub_sub_set_user_header_type_details(&service_builder_pub_sub,
+                                                                            iox2_type_variant_e_FIXED_SIZE,
+                                                                            USER_HEADER_TYPE_NAME,
+                                                                            strlen(USER_HEADER_TYPE_NAME),
+                                                                            sizeof(uint32_t),
+                                                                            sizeof(uint32_t)));
+    iox2_service_builder_pub_sub_set_max_publishers(&service_builder_pub_sub, MAX_PUBLISHERS);
+    iox2_service_builder_pub_sub_set_max_subscribers(&service_builder_pub_sub, MAX_SUBSCRIBERS);
+    iox2_service_builder_pub_sub_set_max_nodes(&service_builder_pub_sub, MAX_NODES);
+    iox2_service_builder_pub_sub_set_history_size(&service_builder_pub_sub, HISTORY_SIZE);
+    iox2_service_builder_pub_sub_set_subscriber_max_buffer_size(&service_builder_pub_sub, BUFFER_SIZE);
+    iox2_service_builder_pub_sub_set_subscriber_max_borrowed_samples(&service_builder_pub_sub, MAX_BORROWED_SAMPLES);
+    iox2_service_builder_pub_sub_set_enable_safe_overflow(&service_builder_pub_sub, true);
+    iox2_service_builder_pub_sub_set_payload_alignment(&service_builder_pub_sub, sizeof(uint64_t));
+
+    IOX2_CHECK_OK(iox2_service_builder_pub_sub_create(service_builder_pub_sub, NULL, &fixture->service));
+    return true;
+}
+
+static void fixture_drop(fixture_t* fixture) {
+    iox2_port_factory_pub_sub_drop(fixture->service);
+    iox2_service_name_drop(fixture->service_name);
+    iox2_node_drop(fixture->node);
+}
+
+static bool create_publisher(fixture_t* fixture, iox2_publisher_h* publisher) {
+    iox2_port_factory_publisher_builder_h publisher_builder =
+        iox2_port_factory_pub_sub_publisher_builder(&fixture->service, NULL);
+    iox2_port_factory_publisher_builder_set_max_loaned_samples(&publisher_builder, 2);
+    iox2_port_factory_publisher_builder_set_initial_max_slice_len(&publisher_builder, 1);
+    iox2_port_factory_publisher_builder_set_allocation_strategy(&publisher_builder,
+                                                                iox2_allocation_strategy_e_STATIC);
+    iox2_port_factory_publisher_builder_unable_to_deliver_strategy(&publisher_builder,
+                                                                   iox2_unable_to_deliver_strategy_e_DISCARD_SAMPLE);
+    IOX2_CHECK_OK(iox2_port_factory_publisher_builder_create(publisher_builder, NULL, publisher));
+    return true;
+}
+
+static bool create_subscriber(fixture_t* fixture, iox2_subscriber_h* subscriber) {
+    iox2_port_factory_subscriber_builder_h subscriber_builder =
+        iox2_port_factory_pub_sub_subscriber_builder(&fixture->service, NULL);
+    iox2_port_factory_subscriber_builder_set_buffer_size(&subscriber_builder, BUFFER_SIZE);
+    IOX2_CHECK_OK(iox2_port_factory_subscriber_builder_create(subscriber_builder, NULL, subscriber));
+    return true;
+}
+
+static bool service_settings_are_applied(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    fixture_t fixture;
+    IOX2_CHECK(fixture_create(&fixture, service_type, config));
+
+    iox2_static_config_publish_subscribe_t static_config;
+    iox2_port_factory_pub_sub_static_config(&fixture.service, &static_config);
+    IOX2_CHECK(static_config.max_publishers == MAX_PUBLISHERS);
+    IOX2_CHECK(static_config.max_subscribers == MAX_SUBSCRIBERS);
+    IOX2_CHECK(static_config.max_nodes == MAX_NODES);
+    IOX2_CHECK(static_config.history_size == HISTORY_SIZE);
+    IOX2_CHECK(static_config.subscriber_max_buffer_size == BUFFER_SIZE);
+    IOX2_CHECK(static_config.subscriber_max_borrowed_samples == MAX_BORROWED_SAMPLES);
+    IOX2_CHECK(static_config.enable_safe_overflow);
+
+    IOX2_CHECK(iox2_port_factory_pub_sub_attributes(&fixture.service) != NULL);
+
+    fixture_drop(&fixture);
+    return true;
+}
+
+static bool existing_service_can_be_opened(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    fixture_t fixture;
+    IOX2_CHECK(fixture_create(&fixture, service_type, config));
+
+    iox2_service_builder_h service_builder =
+        iox2_node_service_builder(&fixture.node, NULL, iox2_cast_service_name_ptr(&fixture.service_name));
+    iox2_service_builder_pub_sub_h service_builder_pub_sub = iox2_service_builder_pub_sub(service_builder);
+    IOX2_CHECK_OK(iox2_service_builder_pub_sub_set_payload_type_details(&service_builder_pub_sub,
+                                                                        iox2_type_variant_e_FIXED_SIZE,
+                                                                        PAYLOAD_TYPE_NAME,
+                                                                        strlen(PAYLOAD_TYPE_NAME),
+                                                                        sizeof(uint64_t),
+                                                                        sizeof(uint64_t)));
+    IOX2_CHECK_OK(iox2_service_builder_pub_sub_set_user_header_type_details(&service_builder_pub_sub,
+                                                                            iox2_type_variant_e_FIXED_SIZE,
+                                                                            USER_HEADER_TYPE_NAME,
+                                                                            strlen(USER_HEADER_TYPE_NAME),
+                                                                            sizeof(uint32_t),
+                                                                            sizeof(uint32_t)));
+    iox2_port_factory_pub_sub_h sut = NULL;
+    IOX2_CHECK_OK(iox2_service_builder_pub_sub_open(service_builder_pub_sub, NULL, &sut));
+    iox2_port_factory_pub_sub_drop(sut);
+
+    service_builder = iox2_node_service_builder(&fixture.node, NULL, iox2_cast_service_name_ptr(&fixture.service_name));
+    service_builder_pub_sub = iox2_service_builder_pub_sub(service_builder);
+    IOX2_CHECK_OK(iox2_service_builder_pub_sub_set_payload_type_details(&service_builder_pub_sub,
+                                                                        iox2_type_variant_e_FIXED_SIZE,
+                                                                        PAYLOAD_TYPE_NAME,
+                                                                        strlen(PAYLOAD_TYPE_NAME),
+                                                                        sizeof(uint64_t),
+                                                                        sizeof(uint64_t)));
+    IOX2_CHECK_OK(iox2_service_builder_pub_sub_set_user_header_type_details(&service_builder_pub_sub,
+                                                                            iox2_type_variant_e_FIXED_SIZE,
+                                                                            USER_HEADER_TYPE_NAME,
+                                                                            strlen(USER_HEADER_TYPE_NAME),
+                                                                            sizeof(uint32_t),
+                                                                            sizeof(uint32_t)));
+    IOX2_CHECK_OK(iox2_service_builder_pub_sub_open_or_create(service_builder_pub_sub, NULL, &sut));
+    iox2_port_factory_pub_sub_drop(sut);
+
+    fixture_drop(&fixture);
+    return true;
+}
+
+static bool service_with_incompatible_type_cannot_be_opened(iox2_service_type_e service_type,
+                                                            iox2_config_h_ref config) {
+    fixture_t fixture;
+    IOX2_CHECK(fixture_create(&fixture, service_type, config));
+
+    iox2_service_builder_h service_builder =
+        iox2_node_service_builder(&fixture.node, NULL, iox2_cast_service_name_ptr(&fixture.service_name));
+    iox2_service_builder_pub_sub_h service_builder_pub_sub = iox2_service_builder_pub_sub(service_builder);
+    const char* type_name = "u8";
+    IOX2_CHECK_OK(iox2_service_builder_pub_sub_set_payload_type_details(
+        &service_builder_pub_sub, iox2_type_variant_e_FIXED_SIZE, type_name, strlen(type_name), 1, 1));
+    iox2_port_factory_pub_sub_h sut = NULL;
+    int result = iox2_service_builder_pub_sub_open(service_builder_pub_sub, NULL, &sut);
+    IOX2_CHECK(result == iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_TYPES);
+    IOX2_CHECK(sut == NULL);
+
+    service_builder = iox2_node_service_builder(&fixture.node, NULL, iox2_cast_service_name_ptr(&fixture.service_name));
+    service_builder_pub_sub = iox2_service_builder_pub_sub(service_builder);
+    IOX2_CHECK_OK(iox2_service_builder_pub_sub_set_payload_type_details(&service_builder_pub_sub,
+                                                                        iox2_type_variant_e_FIXED_SIZE,
+                                                                        PAYLOAD_TYPE_NAME,
+                                                                        strlen(PAYLOAD_TYPE_NAME),
+                                                                        sizeof(uint64_t),
+                                                                        sizeof(uint64_t)));
+    IOX2_CHECK_OK(iox2_service_builder_pub_sub_set_user_header_type_details(&service_builder_pub_sub,
+                                                                            iox2_type_variant_e_FIXED_SIZE,
+                                                                            USER_HEADER_TYPE_NAME,
+                                                                            strlen(USER_HEADER_TYPE_NAME),
+                                                                            sizeof(uint32_t),
+                                                                            sizeof(uint32_t)));
+    result = iox2_service_builder_pub_sub_create(service_builder_pub_sub, NULL, &sut);
+    IOX2_CHECK(result == iox2_pub_sub_open_or_create_error_e_C_ALREADY_EXISTS);
+
+    fixture_drop(&fixture);
+    return true;
+}
+
+static bool port_settings_are_applied(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    fixture_t fixture;
+    IOX2_CHECK(fixture_create(&fixture, service_type, config));
+
+    iox2_publisher_h publisher = NULL;
+    IOX2_CHECK(create_publisher(&fixture, &publisher));
+    iox2_subscriber_h subscriber = NULL;
+    IOX2_CHECK(create_subscriber(&fixture, &subscriber));
+
+    IOX2_CHECK(iox2_publisher_unable_to_deliver_strategy(&publisher)
+               == iox2_unable_to_deliver_strategy_e_DISCARD_SAMPLE);
+    IOX2_CHECK(iox2_publisher_initial_max_slice_len(&publisher) == 1);
+    IOX2_CHECK(iox2_subscriber_buffer_size(&subscriber) == BUFFER_SIZE);
+
+    IOX2_CHECK_OK(iox2_publisher_update_connections(&publisher));
+    IOX2_CHECK_OK(iox2_subscriber_update_connections(&subscriber));
+
+    iox2_subscriber_drop(subscriber);
+    iox2_publisher_drop(publisher);
+    fixture_drop(&fixture);
+    return true;
+}
+
+static bool port_ids_are_unique(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    fixture_t fixture;
+    IOX2_CHECK(fixture_create(&fixture, service_type, config));
+
+    iox2_publisher_h publisher_1 = NULL;
+    IOX2_CHECK(create_publisher(&fixture, &publisher_1));
+    iox2_publisher_h publisher_2 = NULL;
+    IOX2_CHECK(create_publisher(&fixture, &publisher_2));
+    iox2_subscriber_h subscriber_1 = NULL;
+    IOX2_CHECK(create_subscriber(&fixture, &subscriber_1));
+    iox2_subscriber_h subscriber_2 = NULL;
+    IOX2_CHECK(create_subscriber(&fixture, &subscriber_2));
+
+    iox2_unique_publisher_id_h publisher_id_1 = NULL;
+    iox2_publisher_id(&publisher_1, NULL, &publisher_id_1);
+    iox2_unique_publisher_id_h publisher_id_2 = NULL;
+    iox2_publisher_id(&publisher_2, NULL, &publisher_id_2);
+    IOX2_CHECK(iox2_unique_publisher_id_eq(&publisher_id_1, &publisher_id_1));
+    IOX2_CHECK(!iox2_unique_publisher_id_eq(&publisher_id_1, &publisher_id_2));
+    IOX2_CHECK(iox2_unique_publisher_id_less(&publisher_id_1, &publisher_id_2)
+               != iox2_unique_publisher_id_less(&publisher_id_2, &publisher_id_1));
+
+    uint8_t publisher_id_value_1[IOX2_UNIQUE_PORT_ID_LENGTH] = { 0 };
+    iox2_unique_publisher_id_value(publisher_id_1, publisher_id_value_1, sizeof(publisher_id_value_1));
+    uint8_t publisher_id_value_2[IOX2_UNIQUE_PORT_ID_LENGTH] = { 0 };
+    iox2_unique_publisher_id_value(publisher_id_2, publisher_id_value_2, sizeof(publisher_id_value_2));
+    IOX2_CHECK(memcmp(publisher_id_value_1, publisher_id_value_2, IOX2_UNIQUE_PORT_ID_LENGTH) != 0);
+
+    iox2_unique_subscriber_id_h subscriber_id_1 = NULL;
+    iox2_subscriber_id(&subscriber_1, NULL, &subscriber_id_1);
+    iox2_unique_subscriber_id_h subscriber_id_2 = NULL;
+    iox2_subscriber_id(&subscriber_2, NULL, &subscriber_id_2);
+    IOX2_CHECK(iox2_unique_subscriber_id_eq(&subscriber_id_1, &subscriber_id_1));
+    IOX2_CHECK(!iox2_unique_subscriber_id_eq(&subscriber_id_1, &subscriber_id_2));
+    IOX2_CHECK(iox2_unique_subscriber_id_less(&subscriber_id_1, &subscriber_id_2)
+               != iox2_unique_subscriber_id_less(&subscriber_id_2, &subscriber_id_1));
+
+    uint8_t subscriber_id_value_1[IOX2_UNIQUE_PORT_ID_LENGTH] = { 0 };
+    iox2_unique_subscriber_id_value(subscriber_id_1, subscriber_id_value_1, sizeof(subscriber_id_value_1));
+    uint8_t subscriber_id_value_2[IOX2_UNIQUE_PORT_ID_LENGTH] = { 0 };
+    iox2_unique_subscriber_id_value(subscriber_id_2, subscriber_id_value_2, sizeof(subscriber_id_value_2));
+    IOX2_CHECK(memcmp(subscriber_id_value_1, subscriber_id_value_2, IOX2_UNIQUE_PORT_ID_LENGTH) != 0);
+
+    iox2_unique_subscriber_id_drop(subscriber_id_2);
+    iox2_unique_subscriber_id_drop(subscriber_id_1);
+    iox2_unique_publisher_id_drop(publisher_id_2);
+    iox2_unique_publisher_id_drop(publisher_id_1);
+    iox2_subscriber_drop(subscriber_2);
+    iox2_subscriber_drop(subscriber_1);
+    iox2_publisher_drop(publisher_2);
+    iox2_publisher_drop(publisher_1);
+    fixture_drop(&fixture);
+    return true;
+}
+
+static bool loaned_sample_can_be_sent_and_received(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    fixture_t fixture;
+    IOX2_CHECK(fixture_create(&fixture, service_type, config));
+
+    iox2_publisher_h publisher = NULL;
+    IOX2_CHECK(create_publisher(&fixture, &publisher));
+    iox2_subscriber_h subscriber = NULL;
+    IOX2_CHECK(create_subscriber(&fixture, &subscriber));
+
+    bool has_samples = true;
+    IOX2_CHECK_OK(iox2_subscriber_has_samples(&subscriber, &has_samples));
+    IOX2_CHECK(!has_samples);
+
+    iox2_sample_mut_t sample_mut_storage;
+    iox2_sample_mut_h sample_mut = NULL;
+    IOX2_CHECK_OK(iox2_publisher_loan_slice_uninit(&publisher, &sample_mut_storage, &sample_mut, 1));
+
+    uint64_t* payload_mut = NULL;
+    size_t number_of_elements = 0;
+    iox2_sample_mut_payload_mut(&sample_mut, (void**) &payload_mut, &number_of_elements);
+    IOX2_CHECK(payload_mut != NULL);
+    IOX2_CHECK(number_of_elements == 1);
+    *payload_mut = 8912; // NOLINT
+
+    const uint64_t* payload_const = NULL;
+    iox2_sample_mut_payload(&sample_mut, (const void**) &payload_const, NULL);
+    IOX2_CHECK(payload_const == payload_mut);
+
+    uint32_t* user_header_mut = NULL;
+    iox2_sample_mut_user_header_mut(&sample_mut, (void**) &user_header_mut);
+    IOX2_CHECK(user_header_mut != NULL);
+    *user_header_mut = 1234; // NOLINT
+
+    const uint32_t* user_header_const = NULL;
+    iox2_sample_mut_user_header(&sample_mut, (const void**) &user_header_const);
+    IOX2_CHECK(user_header_const == user_header_mut);
+
+    iox2_publish_subscribe_header_h sample_mut_header = NULL;
+    iox2_sample_mut_header(&sample_mut, NULL, &sample_mut_header);
+    IOX2_CHECK(iox2_publish_subscribe_header_number_of_elements(&sample_mut_header) == 1);
+    iox2_publish_subscribe_header_drop(sample_mut_header);
+
+    size_t number_of_recipients = 0;
+    IOX2_CHECK_OK(iox2_sample_mut_send(sample_mut, &number_of_recipients));
+    IOX2_CHECK(number_of_recipients == 1);
+
+    IOX2_CHECK_OK(iox2_subscriber_has_samples(&subscriber, &has_samples));
+    IOX2_CHECK(has_samples);
+
+    iox2_sample_t sample_storage;
+    iox2_sample_h sample = NULL;
+    IOX2_CHECK_OK(iox2_subscriber_receive(&subscriber, &sample_storage, &sample));
+    IOX2_CHECK(sample != NULL);
+
+    const uint64_t* payload = NULL;
+    iox2_sample_payload(&sample, (const void**) &payload, &number_of_elements);
+    IOX2_CHECK(number_of_elements == 1);
+    IOX2_CHECK(*payload == 8912);
+
+    const uint32_t* user_header = NULL;
+    iox2_sample_user_header(&sample, (const void**) &user_header);
+    IOX2_CHECK(*user_header == 1234);
+
+    iox2_sample_drop(sample);
+    iox2_subscriber_drop(subscriber);
+    iox2_publisher_drop(publisher);
+    fixture_drop(&fixture);
+    return true;
+}
+
+static bool samples_lost_on_overflow_are_counted_as_missed(iox2_service_type_e service_type,
+                                                           iox2_config_h_ref config) {
+    fixture_t fixture;
+    IOX2_CHECK(fixture_create(&fixture, service_type, config));
+
+    iox2_publisher_h publisher = NULL;
+    IOX2_CHECK(create_publisher(&fixture, &publisher));
+    iox2_subscriber_h subscriber = NULL;
+    IOX2_CHECK(create_subscriber(&fixture, &subscriber));
+    IOX2_CHECK_OK(iox2_publisher_update_connections(&publisher));
+
+    IOX2_CHECK(iox2_subscriber_number_of_missed_samples(&subscriber) == 0);
+
+    // the service has safe overflow enabled, the oldest samples are replaced
+    const uint64_t number_of_lost_samples = 2;
+    for (uint64_t i = 0; i < BUFFER_SIZE + number_of_lost_samples; ++i) {
+        IOX2_CHECK_OK(iox2_publisher_send_copy(&publisher, &i, sizeof(i), NULL));
+    }
+
+    IOX2_CHECK(iox2_subscriber_number_of_missed_samples(&subscriber) == number_of_lost_samples);
+
+    iox2_subscriber_drop(subscriber);
+    iox2_publisher_drop(publisher);
+    fixture_drop(&fixture);
+    return true;
+}
+
+static bool sample_header_identifies_origin(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    fixture_t fixture;
+    IOX2_CHECK(fixture_create(&fixture, service_type, config));
+
+    iox2_publisher_h publisher = NULL;
+    IOX2_CHECK(create_publisher(&fixture, &publisher));
+    iox2_subscriber_h subscriber = NULL;
+    IOX2_CHECK(create_subscriber(&fixture, &subscriber));
+
+    iox2_node_id_h node_id = NULL;
+    iox2_node_id_clone_from_ptr(NULL, iox2_node_id(&fixture.node, service_type), &node_id);
+    iox2_unique_publisher_id_h publisher_id = NULL;
+    iox2_publisher_id(&publisher, NULL, &publisher_id);
+
+    const uint64_t number_of_samples = 3;
+    for (uint64_t i = 0; i < number_of_samples; ++i) {
+        IOX2_CHECK_OK(iox2_publisher_send_copy(&publisher, &i, sizeof(i), NULL));
+    }
+
+    for (uint64_t i = 0; i < number_of_samples; ++i) {
+        iox2_sample_h sample = NULL;
+        IOX2_CHECK_OK(iox2_subscriber_receive(&subscriber, NULL, &sample));
+        IOX2_CHECK(sample != NULL);
+
+        iox2_publish_subscribe_header_t header_storage;
+        iox2_publish_subscribe_header_h header = NULL;
+        iox2_sample_header(&sample, &header_storage, &header);
+
+        iox2_unique_publisher_id_h header_publisher_id = NULL;
+        iox2_publish_subscribe_header_publisher_id(&header, NULL, &header_publisher_id);
+        IOX2_CHECK(iox2_unique_publisher_id_eq(&header_publisher_id, &publisher_id));
+        iox2_unique_publisher_id_drop(header_publisher_id);
+
+        iox2_node_id_h header_node_id = NULL;
+        iox2_publish_subscribe_header_node_id(&header, NULL, &header_node_id);
+        IOX2_CHECK(iox2_node_id_value_high(&header_node_id) == iox2_node_id_value_high(&node_id));
+        IOX2_CHECK(iox2_node_id_value_low(&header_node_id) == iox2_node_id_value_low(&node_id));
+        iox2_node_id_drop(header_node_id);
+
+        IOX2_CHECK(iox2_publish_subscribe_header_sequence_number(&header) == i);
+        IOX2_CHECK(iox2_publish_subscribe_header_number_of_elements(&header) == 1);
+        IOX2_CHECK(iox2_publish_subscribe_header_priority(&header) == 0);
+
+        uint64_t seconds = 0;
+        uint32_t nanoseconds = 0;
+        iox2_publish_subscribe_header_timestamp(&header, &seconds, &nanoseconds);
+        IOX2_CHECK(seconds != 0 || nanoseconds != 0);
+
+        iox2_publish_subscribe_header_drop(header);
+        iox2_sample_drop(sample);
+    }
+
+    iox2_unique_publisher_id_drop(publisher_id);
+    iox2_node_id_drop(node_id);
+    iox2_subscriber_drop(subscriber);
+    iox2_publisher_drop(publisher);
+    fixture_drop(&fixture);
+    return true;
+}
+
+static bool slice_copy_can_be_sent(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    fixture_t fixture;
+    IOX2_CHECK(fixture_create(&fixture, service_type, config));
+
+    iox2_publisher_h publisher = NULL;
+    IOX2_CHECK(create_publisher(&fixture, &publisher));
+    iox2_subscriber_h subscriber = NULL;
+    IOX2_CHECK(create_subscriber(&fixture, &subscriber));
+
+    const uint64_t value = 771;
+    size_t number_of_recipients = 0;
+    IOX2_CHECK_OK(iox2_publisher_send_slice_copy(&publisher, &value, sizeof(value), 1, &number_of_recipients));
+    IOX2_CHECK(number_of_recipients == 1);
+
+    iox2_sample_h sample = NULL;
+    IOX2_CHECK_OK(iox2_subscriber_receive(&subscriber, NULL, &sample));
+    IOX2_CHECK(sample != NULL);
+
+    const uint64_t* payload = NULL;
+    iox2_sample_payload(&sample, (const void**) &payload, NULL);
+    IOX2_CHECK(*payload == value);
+
+    iox2_sample_drop(sample);
+    iox2_subscriber_drop(subscriber);
+    iox2_publisher_drop(publisher);
+    fixture_drop(&fixture);
+    return true;
+}
+
+static bool dropped_sample_mut_is_returned_to_the_publisher(iox2_service_type_e service_type,
+                                                            iox2_config_h_ref config) {
+    fixture_t fixture;
+    IOX2_CHECK(fixture_create(&fixture, service_type, config));
+
+    iox2_publisher_h publisher = NULL;
+    IOX2_CHECK(create_publisher(&fixture, &publisher));
+
+    iox2_sample_mut_h sample_1 = NULL;
+    IOX2_CHECK_OK(iox2_publisher_loan_slice_uninit(&publisher, NULL, &sample_1, 1));
+    iox2_sample_mut_h sample_2 = NULL;
+    IOX2_CHECK_OK(iox2_publisher_loan_slice_uninit(&publisher, NULL, &sample_2, 1));
+
+    iox2_sample_mut_h sample_3 = NULL;
+    IOX2_CHECK(iox2_publisher_loan_slice_uninit(&publisher, NULL, &sample_3, 1)
+               == iox2_loan_error_e_EXCEEDS_MAX_LOANED_SAMPLES);
+    IOX2_CHECK(sample_3 == NULL);
+
+    iox2_sample_mut_drop(sample_1);
+    IOX2_CHECK_OK(iox2_publisher_loan_slice_uninit(&publisher, NULL, &sample_3, 1));
+
+    iox2_sample_mut_drop(sample_3);
+    iox2_sample_mut_drop(sample_2);
+    iox2_publisher_drop(publisher);
+    fixture_drop(&fixture);
+    return true;
+}
+
+static bool receive_without_samples_returns_no_sample(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    fixture_t fixture;
+    IOX2_CHECK(fixture_create(&fixture, service_type, config));
+
+    iox2_subscriber_h subscriber = NULL;
+    IOX2_CHECK(create_subscriber(&fixture, &subscriber));
+
+    iox2_sample_h sample = NULL;
+    IOX2_CHECK_OK(iox2_subscriber_receive(&subscriber, NULL, &sample));
+    IOX2_CHECK(sample == NULL);
+
+    iox2_subscriber_drop(subscriber);
+    fixture_drop(&fixture);
+    return true;
+}
+
+static bool publish_subscribe_error_strings_are_available(iox2_service_type_e service_type,
+                                                          iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    IOX2_CHECK_ERROR_STRING(iox2_send_error_string, iox2_send_error_e_CONNECTION_CORRUPTED);
+    IOX2_CHECK_ERROR_STRING(iox2_loan_error_string, iox2_loan_error_e_OUT_OF_MEMORY);
+    IOX2_CHECK_ERROR_STRING(iox2_receive_error_string, iox2_receive_error_e_EXCEEDS_MAX_BORROWED_SAMPLES);
+    IOX2_CHECK_ERROR_STRING(iox2_connection_failure_string,
+                            iox2_connection_failure_e_UNABLE_TO_MAP_SENDERS_DATA_SEGMENT);
+    IOX2_CHECK_ERROR_STRING(iox2_publisher_create_error_string,
+                            iox2_publisher_create_error_e_EXCEEDS_MAX_SUPPORTED_PUBLISHERS);
+    IOX2_CHECK_ERROR_STRING(iox2_subscriber_create_error_string,
+                            iox2_subscriber_create_error_e_EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS);
+    IOX2_CHECK_ERROR_STRING(iox2_type_detail_error_string, iox2_type_detail_error_e_INVALID_TYPE_NAME);
+
+    return true;
+}
+
+const iox2_conformance_test_t IOX2_PUBLISH_SUBSCRIBE_TESTS[] = {
+    { "service_settings_are_applied", service_settings_are_applied },
+    { "existing_service_can_be_opened", existing_service_can_be_opened },
+    { "service_with_incompatible_type_cannot_be_opened", service_with_incompatible_type_cannot_be_opened },
+    { "port_settings_are_applied", port_settings_are_applied },
+    { "port_ids_are_unique", port_ids_are_unique },
+    { "loaned_sample_can_be_sent_and_received", loaned_sample_can_be_sent_and_received },
+    { "samples_lost_on_overflow_are_counted_as_missed", samples_lost_on_overflow_are_counted_as_missed },
+    { "sample_header_identifies_origin", sample_header_identifies_origin },
+    { "slice_copy_can_be_sent", slice_copy_can_be_sent },
+    { "dropped_sample_mut_is_returned_to_the_publisher", dropped_sample_mut_is_returned_to_the_publisher },
+    { "receive_without_samples_returns_no_sample", receive_without_samples_returns_no_sample },
+    { "publish_subscribe_error_strings_are_available", publish_subscribe_error_strings_are_available },
+    { NULL, NULL },
+};
@@ -0,0 +1,255 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#include "conformance.h"
+
+#include <string.h>
+
+static bool service_name_can_be_created(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    const char* value = "All/Glory/To/Hypnotoad";
+    iox2_service_name_t storage;
+    iox2_service_name_h sut = NULL;
+    IOX2_CHECK_OK(iox2_service_name_new(&storage, value, strlen(value), &sut));
+
+    size_t len = 0;
+    const char* chars = iox2_service_name_as_chars(iox2_cast_service_name_ptr(&sut), &len);
+    IOX2_CHECK(len == strlen(value));
+    IOX2_CHECK(strncmp(chars, value, len) == 0);
+
+    iox2_service_name_drop(sut);
+    return true;
+}
+
+static bool service_name_can_be_copied_into_buffer(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    const char* value = "All/Glory/To/Hypnotoad";
+    iox2_service_name_h sut = NULL;
+    IOX2_CHECK_OK(iox2_service_name_new(NULL, value, strlen(value), &sut));
+    iox2_service_name_ptr sut_ptr = iox2_cast_service_name_ptr(&sut);
+
+    IOX2_CHECK(iox2_service_name_len(sut_ptr) == strlen(value));
+
+    char buffer[64];
+    IOX2_CHECK(iox2_service_name_as_c_str(sut_ptr, buffer, sizeof(buffer)) == strlen(value));
+    IOX2_CHECK(strcmp(buffer, value) == 0);
+
+    // a too small buffer truncates the name but it is still zero-terminated
+    char small_buffer[4];
+    IOX2_CHECK(iox2_service_name_as_c_str(sut_ptr, small_buffer, sizeof(small_buffer)) == sizeof(small_buffer) - 1);
+    IOX2_CHECK(strcmp(small_buffer, "All") == 0);
+
+    iox2_service_name_drop(sut);
+    return true;
+}
+
+static bool service_name_can_be_validated(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    const char* valid_value = "All/Glory/To/Hypnotoad";
+    IOX2_CHECK_OK(iox2_service_name_validate(valid_value, strlen(valid_value)));
+    IOX2_CHECK(iox2_service_name_validate("", 0) != IOX2_OK);
+
+    // the prefix is reserved for the builtin services
+    const char* reserved_value = "iox2://system_event";
+    IOX2_CHECK(iox2_service_name_validate(reserved_value, strlen(reserved_value)) != IOX2_OK);
+
+    return true;
+}
+
+static bool invalid_service_name_is_rejected(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    iox2_service_name_h sut = NULL;
+    IOX2_CHECK(iox2_service_name_new(NULL, "", 0, &sut) != IOX2_OK);
+    IOX2_CHECK(sut == NULL);
+
+    return true;
+}
+
+typedef struct {
+    const char* name;
+    bool found;
+} service_list_context_t;
+
+static iox2_callback_progression_e service_list_callback(const iox2_static_config_t* static_config,
+                                                         iox2_callback_context context) {
+    service_list_context_t* ctx = (service_list_context_t*) context;
+
+    if (strcmp(static_config->name, ctx->name) == 0
+        && static_config->messaging_pattern == iox2_messaging_pattern_e_PUBLISH_SUBSCRIBE) {
+        ctx->found = true;
+        return iox2_callback_progression_e_STOP;
+    }
+
+    return iox2_callback_progression_e_CONTINUE;
+}
+
+static bool created_service_exists_and_is_listed(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    iox2_node_builder_h node_builder = iox2_node_builder_new(NULL);
+    iox2_node_builder_set_config(&node_builder, config);
+    iox2_node_h node = NULL;
+    IOX2_CHECK_OK(iox2_node_builder_create(node_builder, NULL, service_type, &node));
+
+    char name[128];
+    iox2_conformance_unique_name(name, sizeof(name));
+    iox2_service_name_h service_name = NULL;
+    IOX2_CHECK_OK(iox2_service_name_new(NULL, name, strlen(name), &service_name));
+    iox2_service_name_ptr service_name_ptr = iox2_cast_service_name_ptr(&service_name);
+
+    bool does_exist = true;
+    IOX2_CHECK_OK(iox2_service_does_exist(service_type,
+                                          service_name_ptr,
+                                          iox2_node_config(&node),
+                                          iox2_messaging_pattern_e_PUBLISH_SUBSCRIBE,
+                                          &does_exist));
+    IOX2_CHECK(!does_exist);
+
+    iox2_service_builder_h service_builder = iox2_node_service_builder(&node, NULL, service_name_ptr);
+    iox2_service_builder_pub_sub_h service_builder_pub_sub = iox2_service_builder_pub_sub(service_builder);
+    const char* type_name = "u64";
+    IOX2_CHECK_OK(iox2_service_builder_pub_sub_set_payload_type_details(
+        &service_builder_pub_sub, iox2_type_variant_e_FIXED_SIZE, type_name, strlen(type_name), 8, 8));
+    iox2_port_factory_pub_sub_h service = NULL;
+    IOX2_CHECK_OK(iox2_service_builder_pub_sub_create(service_builder_pub_sub, NULL, &service));
+
+    IOX2_CHECK_OK(iox2_service_does_exist(service_type,
+                                          service_name_ptr,
+                                          iox2_node_config(&node),
+                                          iox2_messaging_pattern_e_PUBLISH_SUBSCRIBE,
+                                          &does_exist));
+    IOX2_CHECK(does_exist);
+    IOX2_CHECK_OK(iox2_service_does_exist(
+        service_type, service_name_ptr, iox2_node_config(&node), iox2_messaging_pattern_e_EVENT, &does_exist));
+    IOX2_CHECK(!does_exist);
+
+    service_list_context_t ctx = { name, false };
+    IOX2_CHECK_OK(iox2_service_list(service_type, iox2_node_config(&node), service_list_callback, &ctx));
+    IOX2_CHECK(ctx.found);
+
+    iox2_port_factory_pub_sub_drop(service);
+
+    IOX2_CHECK_OK(iox2_service_does_exist(service_type,
+                                          service_name_ptr,
+                                          iox2_node_config(&node),
+                                          iox2_messaging_pattern_e_PUBLISH_SUBSCRIBE,
+                                          &does_exist));
+    IOX2_CHECK(!does_exist);
+
+    iox2_service_name_drop(service_name);
+    iox2_node_drop(node);
+    return true;
+}
+
+typedef struct {
+    const char* name;
+    iox2_node_id_ptr node_id;
+    int number_of_calls;
+    bool is_valid;
+} service_details_context_t;
+
+static void service_details_callback(const iox2_service_details_t* details, iox2_callback_context context) {
+    service_details_context_t* ctx = (service_details_context_t*) context;
+    ctx->number_of_calls += 1;
+    ctx->is_valid = strcmp(details->static_config.name, ctx->name) == 0
+                    && details->static_config.messaging_pattern == iox2_messaging_pattern_e_PUBLISH_SUBSCRIBE
+                    && iox2_attribute_set_len(details->static_attributes) == 0 && details->is_accessible
+                    && iox2_attribute_set_len(details->dynamic_attributes) == 0 && details->number_of_ports == 1
+                    && details->ports[0].port_type == iox2_port_type_e_PUBLISHER;
+
+    if (ctx->is_valid) {
+        iox2_node_id_ptr port_node_id = details->ports[0].node_id;
+        ctx->is_valid = iox2_node_id_ptr_value_high(port_node_id) == iox2_node_id_ptr_value_high(ctx->node_id)
+                        && iox2_node_id_ptr_value_low(port_node_id) == iox2_node_id_ptr_value_low(ctx->node_id);
+    }
+}
+
+static bool service_details_contain_connected_ports(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    iox2_node_builder_h node_builder = iox2_node_builder_new(NULL);
+    iox2_node_builder_set_config(&node_builder, config);
+    iox2_node_h node = NULL;
+    IOX2_CHECK_OK(iox2_node_builder_create(node_builder, NULL, service_type, &node));
+
+    char name[128];
+    iox2_conformance_unique_name(name, sizeof(name));
+    iox2_service_name_h service_name = NULL;
+    IOX2_CHECK_OK(iox2_service_name_new(NULL, name, strlen(name), &service_name));
+    iox2_service_name_ptr service_name_ptr = iox2_cast_service_name_ptr(&service_name);
+
+    service_details_context_t ctx = { name, iox2_node_id(&node, service_type), 0, false };
+    IOX2_CHECK_OK(iox2_service_details(service_type,
+                                       service_name_ptr,
+                                       iox2_node_config(&node),
+                                       iox2_messaging_pattern_e_PUBLISH_SUBSCRIBE,
+                                       service_details_callback,
+                                       &ctx));
+    IOX2_CHECK(ctx.number_of_calls == 0);
+
+    iox2_service_builder_h service_builder = iox2_node_service_builder(&node, NULL, service_name_ptr);
+    iox2_service_builder_pub_sub_h service_builder_pub_sub = iox2_service_builder_pub_sub(service_builder);
+    const char* type_name = "u64";
+    IOX2_CHECK_OK(iox2_service_builder_pub_sub_set_payload_type_details(
+        &service_builder_pub_sub, iox2_type_variant_e_FIXED_SIZE, type_name, strlen(type_name), 8, 8));
+    iox2_port_factory_pub_sub_h service = NULL;
+    IOX2_CHECK_OK(iox2_service_builder_pub_sub_create(service_builder_pub_sub, NULL, &service));
+
+    iox2_port_factory_publisher_builder_h publisher_builder =
+        iox2_port_factory_pub_sub_publisher_builder(&service, NULL);
+    iox2_publisher_h publisher = NULL;
+    IOX2_CHECK_OK(iox2_port_factory_publisher_builder_create(publisher_builder, NULL, &publisher));
+
+    IOX2_CHECK_OK(iox2_service_details(service_type,
+                                       service_name_ptr,
+                                       iox2_node_config(&node),
+                                       iox2_messaging_pattern_e_PUBLISH_SUBSCRIBE,
+                                       service_details_callback,
+                                       &ctx));
+    IOX2_CHECK(ctx.number_of_calls == 1);
+    IOX2_CHECK(ctx.is_valid);
+
+    iox2_publisher_drop(publisher);
+    iox2_port_factory_pub_sub_drop(service);
+    iox2_service_name_drop(service_name);
+    iox2_node_drop(node);
+    return true;
+}
+
+static bool service_error_strings_are_available(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    IOX2_CHECK_ERROR_STRING(iox2_semantic_string_error_string, iox2_semantic_string_error_e_INVALID_CONTENT);
+    IOX2_CHECK_ERROR_STRING(iox2_service_details_error_string, iox2_service_details_error_e_INTERNAL_ERROR);
+    IOX2_CHECK_ERROR_STRING(iox2_service_list_error_string, iox2_service_list_error_e_INTERNAL_ERROR);
+    IOX2_CHECK_ERROR_STRING(iox2_pub_sub_open_or_create_error_string,
+                            iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_EXIST);
+    IOX2_CHECK_ERROR_STRING(iox2_event_open_or_create_error_string, iox2_event_open_or_create_error_e_O_DOES_NOT_EXIST);
+
+    return true;
+}
+
+const iox2_conformance_test_t IOX2_SERVICE_TESTS[] = {
+    { "service_name_can_be_created", service_name_can_be_created },
+    { "service_name_can_be_copied_into_buffer", service_name_can_be_copied_into_buffer },
+    { "service_name_can_be_validated", service_name_can_be_validated },
+    { "invalid_service_name_is_rejected", invalid_service_name_is_rejected },
+    { "created_service_exists_and_is_listed", created_service_exists_and_is_listed },
+    { "service_details_contain_connected_ports", service_details_contain_connected_ports },
+    { "service_error_strings_are_available", service_error_strings_are_available },
+    { NULL, NULL },
+};
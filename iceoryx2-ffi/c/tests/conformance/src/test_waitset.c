@@ -0,0 +1,290 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#include "conformance.h"
+
+#include <stdlib.h>
+#include <string.h>
+
+static bool create_waitset(iox2_service_type_e service_type, iox2_waitset_h* waitset) {
+    iox2_waitset_builder_h waitset_builder = NULL;
+    iox2_waitset_builder_new(NULL, &waitset_builder);
+    iox2_waitset_builder_set_signal_handling_mode(&waitset_builder, iox2_signal_handling_mode_e_DISABLED);
+    IOX2_CHECK_OK(iox2_waitset_builder_create(waitset_builder, service_type, NULL, waitset));
+    return true;
+}
+
+static bool waitset_builder_can_be_dropped_without_creating(iox2_service_type_e service_type,
+                                                            iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    iox2_waitset_builder_t storage;
+    iox2_waitset_builder_h sut = NULL;
+    iox2_waitset_builder_new(&storage, &sut);
+    IOX2_CHECK(sut != NULL);
+    iox2_waitset_builder_drop(sut);
+
+    return true;
+}
+
+static bool created_waitset_is_empty(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) config;
+
+    iox2_waitset_h sut = NULL;
+    IOX2_CHECK(create_waitset(service_type, &sut));
+
+    IOX2_CHECK(iox2_waitset_is_empty(&sut));
+    IOX2_CHECK(iox2_waitset_len(&sut) == 0);
+    IOX2_CHECK(iox2_waitset_capacity(&sut) > 0);
+    IOX2_CHECK(iox2_waitset_signal_handling_mode(&sut) == iox2_signal_handling_mode_e_DISABLED);
+
+    iox2_waitset_drop(sut);
+    return true;
+}
+
+typedef struct {
+    iox2_waitset_guard_h_ref guard;
+    size_t number_of_events;
+    bool has_missed_deadline;
+    bool debug_output_available;
+} run_context_t;
+
+static iox2_callback_progression_e run_callback(iox2_waitset_attachment_id_h attachment_id,
+                                                iox2_callback_context context) {
+    run_context_t* ctx = (run_context_t*) context;
+
+    if (iox2_waitset_attachment_id_has_event_from(&attachment_id, ctx->guard)) {
+        ctx->number_of_events += 1;
+    }
+    if (iox2_waitset_attachment_id_has_missed_deadline(&attachment_id, ctx->guard)) {
+        ctx->has_missed_deadline = true;
+    }
+
+    size_t debug_len = iox2_waitset_attachment_id_debug_len(&attachment_id);
+    char* debug_output = (char*) malloc(debug_len + 1);
+    if (debug_output != NULL) {
+        ctx->debug_output_available = iox2_waitset_attachment_id_debug(&attachment_id, debug_output, debug_len + 1);
+        free(debug_output);
+    }
+
+    iox2_waitset_attachment_id_drop(attachment_id);
+    return iox2_callback_progression_e_STOP;
+}
+
+static bool interval_wakes_up_waitset(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) config;
+
+    iox2_waitset_h sut = NULL;
+    IOX2_CHECK(create_waitset(service_type, &sut));
+
+    iox2_waitset_guard_h guard = NULL;
+    IOX2_CHECK_OK(iox2_waitset_attach_interval(&sut, 0, 1000000, NULL, &guard)); // NOLINT
+    IOX2_CHECK(iox2_waitset_len(&sut) == 1);
+    IOX2_CHECK(!iox2_waitset_is_empty(&sut));
+
+    run_context_t ctx = { &guard, 0, false, false };
+    iox2_waitset_run_result_e result = iox2_waitset_run_result_e_ALL_EVENTS_HANDLED;
+    IOX2_CHECK_OK(iox2_waitset_wait_and_process(&sut, run_callback, &ctx, &result));
+    IOX2_CHECK(result == iox2_waitset_run_result_e_STOP_REQUEST);
+    IOX2_CHECK(ctx.number_of_events == 1);
+    IOX2_CHECK(!ctx.has_missed_deadline);
+    IOX2_CHECK(ctx.debug_output_available);
+
+    iox2_waitset_guard_drop(guard);
+    IOX2_CHECK(iox2_waitset_is_empty(&sut));
+
+    iox2_waitset_drop(sut);
+    return true;
+}
+
+static bool notification_wakes_up_waitset(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    iox2_node_builder_h node_builder = iox2_node_builder_new(NULL);
+    iox2_node_builder_set_config(&node_builder, config);
+    iox2_node_h node = NULL;
+    IOX2_CHECK_OK(iox2_node_builder_create(node_builder, NULL, service_type, &node));
+
+    char name[128];
+    iox2_conformance_unique_name(name, sizeof(name));
+    iox2_service_name_h service_name = NULL;
+    IOX2_CHECK_OK(iox2_service_name_new(NULL, name, strlen(name), &service_name));
+
+    iox2_service_builder_h service_builder =
+        iox2_node_service_builder(&node, NULL, iox2_cast_service_name_ptr(&service_name));
+    iox2_port_factory_event_h service = NULL;
+    IOX2_CHECK_OK(iox2_service_builder_event_create(iox2_service_builder_event(service_builder), NULL, &service));
+
+    iox2_notifier_h notifier = NULL;
+    IOX2_CHECK_OK(iox2_port_factory_notifier_builder_create(
+        iox2_port_factory_event_notifier_builder(&service, NULL), NULL, &notifier));
+    iox2_listener_h listener = NULL;
+    IOX2_CHECK_OK(iox2_port_factory_listener_builder_create(
+        iox2_port_factory_event_listener_builder(&service, NULL), NULL, &listener));
+
+    iox2_waitset_h sut = NULL;
+    IOX2_CHECK(create_waitset(service_type, &sut));
+
+    iox2_waitset_guard_t guard_storage;
+    iox2_waitset_guard_h guard = NULL;
+    IOX2_CHECK_OK(
+        iox2_waitset_attach_notification(&sut, iox2_listener_get_file_descriptor(&listener), &guard_storage, &guard));
+
+    iox2_waitset_guard_h second_guard = NULL;
+    IOX2_CHECK(iox2_waitset_attach_notification(&sut, iox2_listener_get_file_descriptor(&listener), NULL, &second_guard)
+               == iox2_waitset_attachment_error_e_ALREADY_ATTACHED);
+
+    IOX2_CHECK_OK(iox2_notifier_notify(&notifier, NULL));
+
+    run_context_t ctx = { &guard, 0, false, false };
+    iox2_waitset_run_result_e result = iox2_waitset_run_result_e_STOP_REQUEST;
+    IOX2_CHECK_OK(iox2_waitset_wait_and_process_once_with_timeout(&sut, run_callback, &ctx, 1, 0, &result));
+    IOX2_CHECK(ctx.number_of_events == 1);
+
+    iox2_waitset_attachment_id_h attachment_id_1 = NULL;
+    iox2_waitset_attachment_id_from_guard(&guard, NULL, &attachment_id_1);
+    iox2_waitset_attachment_id_h attachment_id_2 = NULL;
+    iox2_waitset_attachment_id_from_guard(&guard, NULL, &attachment_id_2);
+    IOX2_CHECK(iox2_waitset_attachment_id_equal(&attachment_id_1, &attachment_id_2));
+    IOX2_CHECK(!iox2_waitset_attachment_id_less(&attachment_id_1, &attachment_id_2));
+    iox2_waitset_attachment_id_drop(attachment_id_2);
+    iox2_waitset_attachment_id_drop(attachment_id_1);
+
+    iox2_waitset_guard_drop(guard);
+    iox2_waitset_drop(sut);
+    iox2_listener_drop(listener);
+    iox2_notifier_drop(notifier);
+    iox2_port_factory_event_drop(service);
+    iox2_service_name_drop(service_name);
+    iox2_node_drop(node);
+    return true;
+}
+
+static bool user_file_descriptor_wakes_up_waitset(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    iox2_file_descriptor_h invalid_file_descriptor = NULL;
+    IOX2_CHECK(!iox2_file_descriptor_new(-1, false, NULL, &invalid_file_descriptor));
+
+    iox2_node_builder_h node_builder = iox2_node_builder_new(NULL);
+    iox2_node_builder_set_config(&node_builder, config);
+    iox2_node_h node = NULL;
+    IOX2_CHECK_OK(iox2_node_builder_create(node_builder, NULL, service_type, &node));
+
+    char name[128];
+    iox2_conformance_unique_name(name, sizeof(name));
+    iox2_service_name_h service_name = NULL;
+    IOX2_CHECK_OK(iox2_service_name_new(NULL, name, strlen(name), &service_name));
+
+    iox2_service_builder_h service_builder =
+        iox2_node_service_builder(&node, NULL, iox2_cast_service_name_ptr(&service_name));
+    iox2_port_factory_event_h service = NULL;
+    IOX2_CHECK_OK(iox2_service_builder_event_create(iox2_service_builder_event(service_builder), NULL, &service));
+
+    iox2_notifier_h notifier = NULL;
+    IOX2_CHECK_OK(iox2_port_factory_notifier_builder_create(
+        iox2_port_factory_event_notifier_builder(&service, NULL), NULL, &notifier));
+    iox2_listener_h listener = NULL;
+    IOX2_CHECK_OK(iox2_port_factory_listener_builder_create(
+        iox2_port_factory_event_listener_builder(&service, NULL), NULL, &listener));
+
+    // the user file descriptor does not own the native handle of the listener
+    int32_t native_handle = iox2_file_descriptor_native_handle(iox2_listener_get_file_descriptor(&listener));
+    iox2_file_descriptor_t file_descriptor_storage;
+    iox2_file_descriptor_h file_descriptor = NULL;
+    IOX2_CHECK(iox2_file_descriptor_new(native_handle, false, &file_descriptor_storage, &file_descriptor));
+    iox2_file_descriptor_ptr file_descriptor_ptr = iox2_cast_file_descriptor_ptr(&file_descriptor);
+    IOX2_CHECK(iox2_file_descriptor_native_handle(file_descriptor_ptr) == native_handle);
+
+    iox2_waitset_h sut = NULL;
+    IOX2_CHECK(create_waitset(service_type, &sut));
+
+    iox2_waitset_guard_h guard = NULL;
+    IOX2_CHECK_OK(iox2_waitset_attach_notification(&sut, file_descriptor_ptr, NULL, &guard));
+
+    IOX2_CHECK_OK(iox2_notifier_notify(&notifier, NULL));
+
+    run_context_t ctx = { &guard, 0, false, false };
+    iox2_waitset_run_result_e result = iox2_waitset_run_result_e_STOP_REQUEST;
+    IOX2_CHECK_OK(iox2_waitset_wait_and_process_once_with_timeout(&sut, run_callback, &ctx, 1, 0, &result));
+    IOX2_CHECK(ctx.number_of_events == 1);
+
+    iox2_waitset_guard_drop(guard);
+    iox2_waitset_drop(sut);
+    iox2_file_descriptor_drop(file_descriptor);
+    iox2_listener_drop(listener);
+    iox2_notifier_drop(notifier);
+    iox2_port_factory_event_drop(service);
+    iox2_service_name_drop(service_name);
+    iox2_node_drop(node);
+    return true;
+}
+
+static bool missed_deadline_wakes_up_waitset(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    iox2_node_builder_h node_builder = iox2_node_builder_new(NULL);
+    iox2_node_builder_set_config(&node_builder, config);
+    iox2_node_h node = NULL;
+    IOX2_CHECK_OK(iox2_node_builder_create(node_builder, NULL, service_type, &node));
+
+    char name[128];
+    iox2_conformance_unique_name(name, sizeof(name));
+    iox2_service_name_h service_name = NULL;
+    IOX2_CHECK_OK(iox2_service_name_new(NULL, name, strlen(name), &service_name));
+
+    iox2_service_builder_h service_builder =
+        iox2_node_service_builder(&node, NULL, iox2_cast_service_name_ptr(&service_name));
+    iox2_port_factory_event_h service = NULL;
+    IOX2_CHECK_OK(iox2_service_builder_event_create(iox2_service_builder_event(service_builder), NULL, &service));
+
+    iox2_listener_h listener = NULL;
+    IOX2_CHECK_OK(iox2_port_factory_listener_builder_create(
+        iox2_port_factory_event_listener_builder(&service, NULL), NULL, &listener));
+
+    iox2_waitset_h sut = NULL;
+    IOX2_CHECK(create_waitset(service_type, &sut));
+
+    iox2_waitset_guard_h guard = NULL;
+    IOX2_CHECK_OK(iox2_waitset_attach_deadline(
+        &sut, iox2_listener_get_file_descriptor(&listener), 0, 1000000, NULL, &guard)); // NOLINT
+
+    run_context_t ctx = { &guard, 0, false, false };
+    iox2_waitset_run_result_e result = iox2_waitset_run_result_e_STOP_REQUEST;
+    IOX2_CHECK_OK(iox2_waitset_wait_and_process_once(&sut, run_callback, &ctx, &result));
+    IOX2_CHECK(ctx.has_missed_deadline);
+
+    iox2_waitset_guard_drop(guard);
+    iox2_waitset_drop(sut);
+    iox2_listener_drop(listener);
+    iox2_port_factory_event_drop(service);
+    iox2_service_name_drop(service_name);
+    iox2_node_drop(node);
+    return true;
+}
+
+static bool waitset_error_strings_are_available(iox2_service_type_e service_type, iox2_config_h_ref config) {
+    (void) service_type;
+    (void) config;
+
+    IOX2_CHECK_ERROR_STRING(iox2_waitset_create_error_string, iox2_waitset_create_error_e_INTERNAL_ERROR);
+    IOX2_CHECK_ERROR_STRING(iox2_waitset_attachment_error_string, iox2_waitset_attachment_error_e_ALREADY_ATTACHED);
+    IOX2_CHECK_ERROR_STRING(iox2_waitset_run_error_string, iox2_waitset_run_error_e_INTERNAL_ERROR);
+
+    return true;
+}
+
+const iox2_conformance_test_t IOX2_WAITSET_TESTS[] = {
+    { "waitset_builder_can_be_dropped_without_creating", waitset_builder_can_be_dropped_without_creating },
+    { "created_waitset_is_empty", created_waitset_is_empty },
+    { "interval_wakes_up_waitset", interval_wakes_up_waitset },
+    { "notification_wakes_up_waitset", notification_wakes_up_waitset },
+    { "user_file_descriptor_wakes_up_waitset", user_file_descriptor_wakes_up_waitset },
+    { "missed_deadline_wakes_up_waitset", missed_deadline_wakes_up_waitset },
+    { "waitset_error_strings_are_available", waitset_error_strings_are_available },
+    { NULL, NULL },
+};
@@ -46,6 +46,10 @@ class Node {
     auto service_tag_suffix() && -> const char*;
     /// Set the suffix of the service tags.
     void set_service_tag_suffix(const iox::FileName& value) &&;
+    /// The suffix of the crash journals.
+    auto crash_journal_suffix() && -> const char*;
+    /// Set the suffix of the crash journals.
+    void set_crash_journal_suffix(const iox::FileName& value) &&;
     /// When true, the [`NodeBuilder`](NodeBuilder) checks for dead nodes and
     /// cleans up all their stale resources whenever a new [`Node`](Node) is
     /// created.
@@ -183,6 +187,10 @@ class PublishSubscribe {
     auto subscriber_expired_connection_buffer() && -> size_t;
     /// Set the expired connection buffer size
     void set_subscriber_expired_connection_buffer(size_t value) &&;
+    /// Defines if the payload of every [`SampleMut`] is zeroed when it is loaned.
+    auto zero_payload_on_loan() && -> bool;
+    /// Enables/disables the zeroing of the payload on loan
+    void set_zero_payload_on_loan(bool value) &&;
 
   private:
     friend class Defaults;
@@ -238,6 +246,71 @@ class Event {
     iox2_config_h* m_config = nullptr;
 };
 
+/// Default settings for the request-response messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+class RequestResponse {
+  public:
+    /// Defines if the request buffer of the service safely overflows.
+    auto enable_safe_overflow_for_requests() && -> bool;
+    /// Enables/disables safe overflow for the request buffer
+    void set_enable_safe_overflow_for_requests(bool value) &&;
+    /// Defines if the response buffer of the service safely overflows.
+    auto enable_safe_overflow_for_responses() && -> bool;
+    /// Enables/disables safe overflow for the response buffer
+    void set_enable_safe_overflow_for_responses(bool value) &&;
+    /// The maximum of active responses a server can hold in parallel.
+    auto max_active_responses() && -> size_t;
+    /// Set the maximum of active responses a server can hold in parallel.
+    void set_max_active_responses(size_t value) &&;
+    /// The maximum of active requests a client can hold in parallel.
+    auto max_active_requests() && -> size_t;
+    /// Set the maximum of active requests a client can hold in parallel.
+    void set_max_active_requests(size_t value) &&;
+    /// The maximum number of responses a client can borrow from an active request.
+    auto max_borrowed_responses() && -> size_t;
+    /// Set the maximum number of responses a client can borrow from an active request.
+    void set_max_borrowed_responses(size_t value) &&;
+    /// The maximum number of requests a server can borrow.
+    auto max_borrowed_requests() && -> size_t;
+    /// Set the maximum number of requests a server can borrow.
+    void set_max_borrowed_requests(size_t value) &&;
+    /// The maximum buffer size for responses for an active request.
+    auto max_response_buffer_size() && -> size_t;
+    /// Set the maximum buffer size for responses for an active request.
+    void set_max_response_buffer_size(size_t value) &&;
+    /// The maximum buffer size for requests for a server.
+    auto max_request_buffer_size() && -> size_t;
+    /// Set the maximum buffer size for requests for a server.
+    void set_max_request_buffer_size(size_t value) &&;
+    /// The maximum amount of supported servers.
+    auto max_servers() && -> size_t;
+    /// Set the maximum amount of supported servers.
+    void set_max_servers(size_t value) &&;
+    /// The maximum amount of supported clients.
+    auto max_clients() && -> size_t;
+    /// Set the maximum amount of supported clients.
+    void set_max_clients(size_t value) &&;
+    /// The maximum amount of supported [`Node`]s. Defines indirectly how many
+    /// processes can open the service at the same time.
+    auto max_nodes() && -> size_t;
+    /// Set the maximum amount of supported [`Node`]s.
+    void set_max_nodes(size_t value) &&;
+    /// Defines how many requests a client can loan in parallel.
+    auto client_max_loaned_requests() && -> size_t;
+    /// Set how many requests a client can loan in parallel.
+    void set_client_max_loaned_requests(size_t value) &&;
+    /// Defines how many responses a server can loan in parallel for a request.
+    auto server_max_loaned_responses_per_request() && -> size_t;
+    /// Set how many responses a server can loan in parallel for a request.
+    void set_server_max_loaned_responses_per_request(size_t value) &&;
+
+  private:
+    friend class Defaults;
+    explicit RequestResponse(iox2_config_h* config);
+
+    iox2_config_h* m_config = nullptr;
+};
+
 /// Default settings. These values are used when the user in the code does not specify anything
 /// else.
 class Defaults {
@@ -246,6 +319,8 @@ class Defaults {
     auto publish_subscribe() && -> PublishSubscribe;
     /// Returns the event part of the default settings
     auto event() && -> Event;
+    /// Returns the request_response part of the default settings
+    auto request_response() && -> RequestResponse;
 
   private:
     friend class ::iox2::Config;
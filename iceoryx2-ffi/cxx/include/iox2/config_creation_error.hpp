@@ -28,6 +28,10 @@ enum class ConfigCreationError : uint8_t {
     ConfigFileDoesNotExist,
     /// The config file could not be opened due to an internal error
     UnableToOpenConfigFile,
+    /// The config contains an entry that is not known.
+    UnknownConfigEntry,
+    /// The config files include each other recursively or the includes are nested too deep.
+    MaxIncludeDepthExceeded,
 };
 
 } // namespace iox2
@@ -107,6 +107,8 @@ constexpr auto from<int, iox2::NodeCreationFailure>(const int value) noexcept ->
         return iox2::NodeCreationFailure::InsufficientPermissions;
     case iox2_node_creation_failure_e_INTERNAL_ERROR:
         return iox2::NodeCreationFailure::InternalError;
+    case iox2_node_creation_failure_e_INVALID_SECURITY_DESCRIPTOR:
+        return iox2::NodeCreationFailure::InvalidSecurityDescriptor;
     }
 
     IOX_UNREACHABLE();
@@ -121,6 +123,8 @@ from<iox2::NodeCreationFailure, iox2_node_creation_failure_e>(const iox2::NodeCr
         return iox2_node_creation_failure_e_INSUFFICIENT_PERMISSIONS;
     case iox2::NodeCreationFailure::InternalError:
         return iox2_node_creation_failure_e_INTERNAL_ERROR;
+    case iox2::NodeCreationFailure::InvalidSecurityDescriptor:
+        return iox2_node_creation_failure_e_INVALID_SECURITY_DESCRIPTOR;
     }
 
     IOX_UNREACHABLE();
@@ -233,6 +237,8 @@ constexpr auto from<iox2::MessagingPattern, iox2_messaging_pattern_e>(const iox2
         return iox2_messaging_pattern_e_PUBLISH_SUBSCRIBE;
     case iox2::MessagingPattern::Event:
         return iox2_messaging_pattern_e_EVENT;
+    case iox2::MessagingPattern::RequestResponse:
+        return iox2_messaging_pattern_e_REQUEST_RESPONSE;
     }
 
     IOX_UNREACHABLE();
@@ -323,12 +329,24 @@ constexpr auto from<int, iox2::EventOpenOrCreateError>(const int value) noexcept
         return iox2::EventOpenOrCreateError::OpenExceedsMaxNumberOfNodes;
     case iox2_event_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::EventOpenOrCreateError::OpenIsMarkedForDestruction;
+    case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_VERSION:
+        return iox2::EventOpenOrCreateError::OpenIncompatibleVersion;
+    case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_DOMAIN:
+        return iox2::EventOpenOrCreateError::OpenIncompatibleDomain;
+    case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_ICEORYX2_VERSION:
+        return iox2::EventOpenOrCreateError::OpenIncompatibleIceoryx2Version;
+    case iox2_event_open_or_create_error_e_O_SERVICE_NAME_HASH_COLLISION:
+        return iox2::EventOpenOrCreateError::OpenServiceNameHashCollision;
+    case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_ABI_VERSION:
+        return iox2::EventOpenOrCreateError::OpenIncompatibleAbiVersion;
     case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_NOTIFIER_CREATED_EVENT:
         return iox2::EventOpenOrCreateError::OpenIncompatibleNotifierCreatedEvent;
     case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_NOTIFIER_DROPPED_EVENT:
         return iox2::EventOpenOrCreateError::OpenIncompatibleNotifierDroppedEvent;
     case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_NOTIFIER_DEAD_EVENT:
         return iox2::EventOpenOrCreateError::OpenIncompatibleNotifierDeadEvent;
+    case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_DYNAMIC_ATTRIBUTE_CHANGED_EVENT:
+        return iox2::EventOpenOrCreateError::OpenIncompatibleDynamicAttributeChangedEvent;
     case iox2_event_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE:
         return iox2::EventOpenOrCreateError::CreateServiceInCorruptedState;
     case iox2_event_open_or_create_error_e_C_INTERNAL_FAILURE:
@@ -345,6 +363,8 @@ constexpr auto from<int, iox2::EventOpenOrCreateError>(const int value) noexcept
         return iox2::EventOpenOrCreateError::CreateOldConnectionsStillActive;
     case iox2_event_open_or_create_error_e_SYSTEM_IN_FLUX:
         return iox2::EventOpenOrCreateError::SystemInFlux;
+    case iox2_event_open_or_create_error_e_C_EXCEEDS_NODE_QUOTA:
+        return iox2::EventOpenOrCreateError::CreateExceedsNodeQuota;
     }
 
     IOX_UNREACHABLE();
@@ -381,6 +401,16 @@ from<iox2::EventOpenOrCreateError, iox2_event_open_or_create_error_e>(const iox2
         return iox2_event_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES;
     case iox2::EventOpenOrCreateError::OpenIsMarkedForDestruction:
         return iox2_event_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION;
+    case iox2::EventOpenOrCreateError::OpenIncompatibleVersion:
+        return iox2_event_open_or_create_error_e_O_INCOMPATIBLE_VERSION;
+    case iox2::EventOpenOrCreateError::OpenIncompatibleDomain:
+        return iox2_event_open_or_create_error_e_O_INCOMPATIBLE_DOMAIN;
+    case iox2::EventOpenOrCreateError::OpenIncompatibleIceoryx2Version:
+        return iox2_event_open_or_create_error_e_O_INCOMPATIBLE_ICEORYX2_VERSION;
+    case iox2::EventOpenOrCreateError::OpenServiceNameHashCollision:
+        return iox2_event_open_or_create_error_e_O_SERVICE_NAME_HASH_COLLISION;
+    case iox2::EventOpenOrCreateError::OpenIncompatibleAbiVersion:
+        return iox2_event_open_or_create_error_e_O_INCOMPATIBLE_ABI_VERSION;
 
     case iox2::EventOpenOrCreateError::CreateServiceInCorruptedState:
         return iox2_event_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE;
@@ -396,6 +426,8 @@ from<iox2::EventOpenOrCreateError, iox2_event_open_or_create_error_e>(const iox2
         return iox2_event_open_or_create_error_e_C_INSUFFICIENT_PERMISSIONS;
     case iox2::EventOpenOrCreateError::CreateOldConnectionsStillActive:
         return iox2_event_open_or_create_error_e_C_OLD_CONNECTION_STILL_ACTIVE;
+    case iox2::EventOpenOrCreateError::CreateExceedsNodeQuota:
+        return iox2_event_open_or_create_error_e_C_EXCEEDS_NODE_QUOTA;
     default:
         IOX_UNREACHABLE();
     }
@@ -437,6 +469,16 @@ constexpr auto from<int, iox2::EventOpenError>(const int value) noexcept -> iox2
         return iox2::EventOpenError::ExceedsMaxNumberOfNodes;
     case iox2_event_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::EventOpenError::IsMarkedForDestruction;
+    case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_VERSION:
+        return iox2::EventOpenError::IncompatibleVersion;
+    case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_DOMAIN:
+        return iox2::EventOpenError::IncompatibleDomain;
+    case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_ICEORYX2_VERSION:
+        return iox2::EventOpenError::IncompatibleIceoryx2Version;
+    case iox2_event_open_or_create_error_e_O_SERVICE_NAME_HASH_COLLISION:
+        return iox2::EventOpenError::ServiceNameHashCollision;
+    case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_ABI_VERSION:
+        return iox2::EventOpenError::IncompatibleAbiVersion;
     default:
         IOX_UNREACHABLE();
     }
@@ -472,6 +514,16 @@ constexpr auto from<iox2::EventOpenError, iox2_event_open_or_create_error_e>(con
         return iox2_event_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES;
     case iox2::EventOpenError::IsMarkedForDestruction:
         return iox2_event_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION;
+    case iox2::EventOpenError::IncompatibleVersion:
+        return iox2_event_open_or_create_error_e_O_INCOMPATIBLE_VERSION;
+    case iox2::EventOpenError::IncompatibleDomain:
+        return iox2_event_open_or_create_error_e_O_INCOMPATIBLE_DOMAIN;
+    case iox2::EventOpenError::IncompatibleIceoryx2Version:
+        return iox2_event_open_or_create_error_e_O_INCOMPATIBLE_ICEORYX2_VERSION;
+    case iox2::EventOpenError::ServiceNameHashCollision:
+        return iox2_event_open_or_create_error_e_O_SERVICE_NAME_HASH_COLLISION;
+    case iox2::EventOpenError::IncompatibleAbiVersion:
+        return iox2_event_open_or_create_error_e_O_INCOMPATIBLE_ABI_VERSION;
     default:
         IOX_UNREACHABLE();
     }
@@ -498,6 +550,8 @@ constexpr auto from<int, iox2::EventCreateError>(const int value) noexcept -> io
         return iox2::EventCreateError::HangsInCreation;
     case iox2_event_open_or_create_error_e_C_INSUFFICIENT_PERMISSIONS:
         return iox2::EventCreateError::InsufficientPermissions;
+    case iox2_event_open_or_create_error_e_C_EXCEEDS_NODE_QUOTA:
+        return iox2::EventCreateError::ExceedsNodeQuota;
     default:
         IOX_UNREACHABLE();
     }
@@ -522,6 +576,8 @@ from<iox2::EventCreateError, iox2_event_open_or_create_error_e>(const iox2::Even
         return iox2_event_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE;
     case iox2::EventCreateError::OldConnectionsStillActive:
         return iox2_event_open_or_create_error_e_C_OLD_CONNECTION_STILL_ACTIVE;
+    case iox2::EventCreateError::ExceedsNodeQuota:
+        return iox2_event_open_or_create_error_e_C_EXCEEDS_NODE_QUOTA;
     default:
         IOX_UNREACHABLE();
     }
@@ -571,6 +627,18 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::OpenExceedsMaxNumberOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::PublishSubscribeOpenOrCreateError::OpenIsMarkedForDestruction;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_VERSION:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleVersion;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_DOMAIN:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleDomain;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_ICEORYX2_VERSION:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleIceoryx2Version;
+    case iox2_pub_sub_open_or_create_error_e_O_SERVICE_NAME_HASH_COLLISION:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenServiceNameHashCollision;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_ABI_VERSION:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleAbiVersion;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_BITNESS:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleBitness;
 
     case iox2_pub_sub_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE:
         return iox2::PublishSubscribeOpenOrCreateError::CreateServiceInCorruptedState;
@@ -590,6 +658,8 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::CreateOldConnectionsStillActive;
     case iox2_pub_sub_open_or_create_error_e_SYSTEM_IN_FLUX:
         return iox2::PublishSubscribeOpenOrCreateError::SystemInFlux;
+    case iox2_pub_sub_open_or_create_error_e_C_EXCEEDS_NODE_QUOTA:
+        return iox2::PublishSubscribeOpenOrCreateError::CreateExceedsNodeQuota;
     }
 
     IOX_UNREACHABLE();
@@ -633,6 +703,18 @@ constexpr auto from<int, iox2::PublishSubscribeOpenError>(const int value) noexc
         return iox2::PublishSubscribeOpenError::ExceedsMaxNumberOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::PublishSubscribeOpenError::IsMarkedForDestruction;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_VERSION:
+        return iox2::PublishSubscribeOpenError::IncompatibleVersion;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_DOMAIN:
+        return iox2::PublishSubscribeOpenError::IncompatibleDomain;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_ICEORYX2_VERSION:
+        return iox2::PublishSubscribeOpenError::IncompatibleIceoryx2Version;
+    case iox2_pub_sub_open_or_create_error_e_O_SERVICE_NAME_HASH_COLLISION:
+        return iox2::PublishSubscribeOpenError::ServiceNameHashCollision;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_ABI_VERSION:
+        return iox2::PublishSubscribeOpenError::IncompatibleAbiVersion;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_BITNESS:
+        return iox2::PublishSubscribeOpenError::IncompatibleBitness;
     default:
         IOX_UNREACHABLE();
     }
@@ -676,6 +758,18 @@ constexpr auto from<iox2::PublishSubscribeOpenError, iox2_pub_sub_open_or_create
         return iox2_pub_sub_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES;
     case iox2::PublishSubscribeOpenError::IsMarkedForDestruction:
         return iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION;
+    case iox2::PublishSubscribeOpenError::IncompatibleVersion:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_VERSION;
+    case iox2::PublishSubscribeOpenError::IncompatibleDomain:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_DOMAIN;
+    case iox2::PublishSubscribeOpenError::IncompatibleIceoryx2Version:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_ICEORYX2_VERSION;
+    case iox2::PublishSubscribeOpenError::ServiceNameHashCollision:
+        return iox2_pub_sub_open_or_create_error_e_O_SERVICE_NAME_HASH_COLLISION;
+    case iox2::PublishSubscribeOpenError::IncompatibleAbiVersion:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_ABI_VERSION;
+    case iox2::PublishSubscribeOpenError::IncompatibleBitness:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_BITNESS;
     default:
         IOX_UNREACHABLE();
     }
@@ -706,6 +800,8 @@ constexpr auto from<int, iox2::PublishSubscribeCreateError>(const int value) noe
         return iox2::PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance;
     case iox2_pub_sub_open_or_create_error_e_C_HANGS_IN_CREATION:
         return iox2::PublishSubscribeCreateError::HangsInCreation;
+    case iox2_pub_sub_open_or_create_error_e_C_EXCEEDS_NODE_QUOTA:
+        return iox2::PublishSubscribeCreateError::ExceedsNodeQuota;
     default:
         IOX_UNREACHABLE();
     }
@@ -729,6 +825,8 @@ constexpr auto from<iox2::PublishSubscribeCreateError, iox2_pub_sub_open_or_crea
         return iox2_pub_sub_open_or_create_error_e_C_IS_BEING_CREATED_BY_ANOTHER_INSTANCE;
     case iox2::PublishSubscribeCreateError::HangsInCreation:
         return iox2_pub_sub_open_or_create_error_e_C_HANGS_IN_CREATION;
+    case iox2::PublishSubscribeCreateError::ExceedsNodeQuota:
+        return iox2_pub_sub_open_or_create_error_e_C_EXCEEDS_NODE_QUOTA;
     default:
         IOX_UNREACHABLE();
     }
@@ -778,6 +876,18 @@ constexpr auto from<iox2::PublishSubscribeOpenOrCreateError, iox2_pub_sub_open_o
         return iox2_pub_sub_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES;
     case iox2::PublishSubscribeOpenOrCreateError::OpenIsMarkedForDestruction:
         return iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleVersion:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_VERSION;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleDomain:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_DOMAIN;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleIceoryx2Version:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_ICEORYX2_VERSION;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenServiceNameHashCollision:
+        return iox2_pub_sub_open_or_create_error_e_O_SERVICE_NAME_HASH_COLLISION;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleAbiVersion:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_ABI_VERSION;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleBitness:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_BITNESS;
     case iox2::PublishSubscribeOpenOrCreateError::CreateServiceInCorruptedState:
         return iox2_pub_sub_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE;
     case iox2::PublishSubscribeOpenOrCreateError::CreateSubscriberBufferMustBeLargerThanHistorySize:
@@ -794,6 +904,8 @@ constexpr auto from<iox2::PublishSubscribeOpenOrCreateError, iox2_pub_sub_open_o
         return iox2_pub_sub_open_or_create_error_e_C_HANGS_IN_CREATION;
     case iox2::PublishSubscribeOpenOrCreateError::CreateOldConnectionsStillActive:
         return iox2_pub_sub_open_or_create_error_e_C_OLD_CONNECTION_STILL_ACTIVE;
+    case iox2::PublishSubscribeOpenOrCreateError::CreateExceedsNodeQuota:
+        return iox2_pub_sub_open_or_create_error_e_C_EXCEEDS_NODE_QUOTA;
     default:
         IOX_UNREACHABLE();
     }
@@ -812,6 +924,8 @@ constexpr auto from<int, iox2::NotifierCreateError>(const int value) noexcept ->
     switch (error) {
     case iox2_notifier_create_error_e_EXCEEDS_MAX_SUPPORTED_NOTIFIERS:
         return iox2::NotifierCreateError::ExceedsMaxSupportedNotifiers;
+    case iox2_notifier_create_error_e_EXCEEDS_NODE_QUOTA:
+        return iox2::NotifierCreateError::ExceedsNodeQuota;
     }
 
     IOX_UNREACHABLE();
@@ -824,6 +938,8 @@ from<iox2::NotifierCreateError, iox2_notifier_create_error_e>(const iox2::Notifi
     switch (value) {
     case iox2::NotifierCreateError::ExceedsMaxSupportedNotifiers:
         return iox2_notifier_create_error_e_EXCEEDS_MAX_SUPPORTED_NOTIFIERS;
+    case iox2::NotifierCreateError::ExceedsNodeQuota:
+        return iox2_notifier_create_error_e_EXCEEDS_NODE_QUOTA;
     }
 
     IOX_UNREACHABLE();
@@ -843,6 +959,8 @@ constexpr auto from<int, iox2::ListenerCreateError>(const int value) noexcept ->
         return iox2::ListenerCreateError::ExceedsMaxSupportedListeners;
     case iox2_listener_create_error_e_RESOURCE_CREATION_FAILED:
         return iox2::ListenerCreateError::ResourceCreationFailed;
+    case iox2_listener_create_error_e_EXCEEDS_NODE_QUOTA:
+        return iox2::ListenerCreateError::ExceedsNodeQuota;
     }
 
     IOX_UNREACHABLE();
@@ -857,6 +975,8 @@ from<iox2::ListenerCreateError, iox2_listener_create_error_e>(const iox2::Listen
         return iox2_listener_create_error_e_EXCEEDS_MAX_SUPPORTED_LISTENERS;
     case iox2::ListenerCreateError::ResourceCreationFailed:
         return iox2_listener_create_error_e_RESOURCE_CREATION_FAILED;
+    case iox2::ListenerCreateError::ExceedsNodeQuota:
+        return iox2_listener_create_error_e_EXCEEDS_NODE_QUOTA;
     }
 
     IOX_UNREACHABLE();
@@ -948,6 +1068,10 @@ constexpr auto from<int, iox2::PublisherCreateError>(const int value) noexcept -
         return iox2::PublisherCreateError::ExceedsMaxSupportedPublishers;
     case iox2_publisher_create_error_e_UNABLE_TO_CREATE_DATA_SEGMENT:
         return iox2::PublisherCreateError::UnableToCreateDataSegment;
+    case iox2_publisher_create_error_e_EXCEEDS_NODE_QUOTA:
+        return iox2::PublisherCreateError::ExceedsNodeQuota;
+    case iox2_publisher_create_error_e_INCOMPATIBLE_PAYLOAD_SIZE:
+        return iox2::PublisherCreateError::IncompatiblePayloadSize;
     }
 
     IOX_UNREACHABLE();
@@ -962,6 +1086,10 @@ from<iox2::PublisherCreateError, iox2_publisher_create_error_e>(const iox2::Publ
         return iox2_publisher_create_error_e_EXCEEDS_MAX_SUPPORTED_PUBLISHERS;
     case iox2::PublisherCreateError::UnableToCreateDataSegment:
         return iox2_publisher_create_error_e_UNABLE_TO_CREATE_DATA_SEGMENT;
+    case iox2::PublisherCreateError::ExceedsNodeQuota:
+        return iox2_publisher_create_error_e_EXCEEDS_NODE_QUOTA;
+    case iox2::PublisherCreateError::IncompatiblePayloadSize:
+        return iox2_publisher_create_error_e_INCOMPATIBLE_PAYLOAD_SIZE;
     }
 
     IOX_UNREACHABLE();
@@ -981,6 +1109,8 @@ constexpr auto from<int, iox2::SubscriberCreateError>(const int value) noexcept
         return iox2::SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService;
     case iox2_subscriber_create_error_e_EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS:
         return iox2::SubscriberCreateError::ExceedsMaxSupportedSubscribers;
+    case iox2_subscriber_create_error_e_EXCEEDS_NODE_QUOTA:
+        return iox2::SubscriberCreateError::ExceedsNodeQuota;
     }
 
     IOX_UNREACHABLE();
@@ -995,6 +1125,8 @@ from<iox2::SubscriberCreateError, iox2_subscriber_create_error_e>(const iox2::Su
         return iox2_subscriber_create_error_e_BUFFER_SIZE_EXCEEDS_MAX_SUPPORTED_BUFFER_SIZE_OF_SERVICE;
     case iox2::SubscriberCreateError::ExceedsMaxSupportedSubscribers:
         return iox2_subscriber_create_error_e_EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS;
+    case iox2::SubscriberCreateError::ExceedsNodeQuota:
+        return iox2_subscriber_create_error_e_EXCEEDS_NODE_QUOTA;
     }
 
     IOX_UNREACHABLE();
@@ -1066,6 +1198,8 @@ constexpr auto from<int, iox2::ReceiveError>(const int value) noexcept -> iox2::
         return iox2::ReceiveError::UnableToMapSendersDataSegment;
     case iox2_receive_error_e_EXCEEDS_MAX_BORROWED_SAMPLES:
         return iox2::ReceiveError::ExceedsMaxBorrowedSamples;
+    case iox2_receive_error_e_INTERNAL_FAILURE:
+        return iox2::ReceiveError::InternalFailure;
     }
 
     IOX_UNREACHABLE();
@@ -1081,6 +1215,8 @@ constexpr auto from<iox2::ReceiveError, iox2_receive_error_e>(const iox2::Receiv
         return iox2_receive_error_e_UNABLE_TO_MAP_SENDERS_DATA_SEGMENT;
     case iox2::ReceiveError::ExceedsMaxBorrowedSamples:
         return iox2_receive_error_e_EXCEEDS_MAX_BORROWED_SAMPLES;
+    case iox2::ReceiveError::InternalFailure:
+        return iox2_receive_error_e_INTERNAL_FAILURE;
     }
 
     IOX_UNREACHABLE();
@@ -1181,6 +1317,8 @@ constexpr auto from<int, iox2::MessagingPattern>(const int value) noexcept -> io
         return iox2::MessagingPattern::Event;
     case iox2_messaging_pattern_e_PUBLISH_SUBSCRIBE:
         return iox2::MessagingPattern::PublishSubscribe;
+    case iox2_messaging_pattern_e_REQUEST_RESPONSE:
+        return iox2::MessagingPattern::RequestResponse;
     }
 
     IOX_UNREACHABLE();
@@ -1256,6 +1394,10 @@ constexpr auto from<int, iox2::ConfigCreationError>(const int value) noexcept ->
         return iox2::ConfigCreationError::ConfigFileDoesNotExist;
     case iox2_config_creation_error_e_UNABLE_TO_OPEN_CONFIG_FILE:
         return iox2::ConfigCreationError::UnableToOpenConfigFile;
+    case iox2_config_creation_error_e_UNKNOWN_CONFIG_ENTRY:
+        return iox2::ConfigCreationError::UnknownConfigEntry;
+    case iox2_config_creation_error_e_MAX_INCLUDE_DEPTH_EXCEEDED:
+        return iox2::ConfigCreationError::MaxIncludeDepthExceeded;
     case iox2_config_creation_error_e_INVALID_FILE_PATH:
         // unreachable since this error case is excluded by using the strong type iox::FilePath
         IOX_UNREACHABLE();
@@ -1279,6 +1421,10 @@ from<iox2::ConfigCreationError, iox2_config_creation_error_e>(const iox2::Config
         return iox2_config_creation_error_e_CONFIG_FILE_DOES_NOT_EXIST;
     case iox2::ConfigCreationError::UnableToOpenConfigFile:
         return iox2_config_creation_error_e_UNABLE_TO_OPEN_CONFIG_FILE;
+    case iox2::ConfigCreationError::UnknownConfigEntry:
+        return iox2_config_creation_error_e_UNKNOWN_CONFIG_ENTRY;
+    case iox2::ConfigCreationError::MaxIncludeDepthExceeded:
+        return iox2_config_creation_error_e_MAX_INCLUDE_DEPTH_EXCEEDED;
     }
 
     IOX_UNREACHABLE();
@@ -1532,6 +1678,29 @@ constexpr auto from<int, iox2::NodeCleanupFailure>(const int value) noexcept ->
 
     IOX_UNREACHABLE();
 }
+
+template <>
+constexpr auto from<iox2::NodeCleanupFailure, iox2_node_cleanup_failure_e>(const iox2::NodeCleanupFailure value) noexcept
+    -> iox2_node_cleanup_failure_e {
+    switch (value) {
+    case iox2::NodeCleanupFailure::Interrupt:
+        return iox2_node_cleanup_failure_e_INTERRUPT;
+    case iox2::NodeCleanupFailure::InternalError:
+        return iox2_node_cleanup_failure_e_INTERNAL_ERROR;
+    case iox2::NodeCleanupFailure::InsufficientPermissions:
+        return iox2_node_cleanup_failure_e_INSUFFICIENT_PERMISSIONS;
+    case iox2::NodeCleanupFailure::VersionMismatch:
+        return iox2_node_cleanup_failure_e_VERSION_MISMATCH;
+    }
+
+    IOX_UNREACHABLE();
+}
+
+template <>
+inline auto from<iox2::NodeCleanupFailure, const char*>(const iox2::NodeCleanupFailure value) noexcept
+    -> const char* {
+    return iox2_node_cleanup_failure_string(iox::into<iox2_node_cleanup_failure_e>(value));
+}
 } // namespace iox
 
 #endif
@@ -15,6 +15,7 @@
 
 #include "iox/layout.hpp"
 #include "iox2/internal/iceoryx2.hpp"
+#include "node_id.hpp"
 #include "unique_port_id.hpp"
 
 namespace iox2 {
@@ -30,6 +31,19 @@ class HeaderPublishSubscribe {
     /// Returns the [`UniquePublisherId`] of the source [`Publisher`].
     auto publisher_id() const -> UniquePublisherId;
 
+    /// Returns the [`NodeId`] of the [`Node`] that owns the source [`Publisher`].
+    auto node_id() const -> NodeId;
+
+    /// Returns the sequence number of the [`Sample`]. Every [`Publisher`] starts with zero
+    /// and increments it with every sent [`Sample`], so that a gap indicates a lost [`Sample`].
+    auto sequence_number() const -> uint64_t;
+
+    /// Returns the priority of the [`Sample`]. A higher value means a higher priority.
+    auto priority() const -> uint8_t;
+
+    /// Returns the time of the monotonic clock when the [`Sample`] was sent.
+    auto timestamp() const -> timespec;
+
     /// Returns the number of [`Payload`] elements in the received [`Sample`].
     auto number_of_elements() const -> uint64_t;
 
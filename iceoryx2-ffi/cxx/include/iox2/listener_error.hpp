@@ -28,6 +28,9 @@ enum class ListenerCreateError : uint8_t {
     /// An underlying resource of the [`Service`] could
     /// not be created
     ResourceCreationFailed,
+    /// The [`Listener`] cannot be created since it would exceed the
+    /// [`NodeQuota`] of the [`Node`].
+    ExceedsNodeQuota,
 };
 
 /// Defines failures that can occur while waiting for a notification from a
@@ -31,6 +31,10 @@ enum class MessagingPattern : uint8_t {
     /// ability to sleep until a signal/event arrives.
     /// Building block to realize push-notifications.
     Event,
+
+    /// Bidirectional communication pattern where the client sends requests to
+    /// the server and receives a stream of responses.
+    RequestResponse,
 };
 } // namespace iox2
 
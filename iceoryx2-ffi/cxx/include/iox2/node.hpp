@@ -94,6 +94,23 @@ class NodeBuilder {
     /// that returns any received signal via its [`NodeWaitFailure`]
     IOX_BUILDER_OPTIONAL(SignalHandlingMode, signal_handling_mode);
 
+    /// Enables the crash journal of the [`Node`]. When the process crashes,
+    /// the journal allows the next cleanup pass to reclaim exactly the
+    /// resources of the [`Node`]. By default, it is disabled.
+    IOX_BUILDER_OPTIONAL(bool, crash_journal);
+
+    /// Defines the maximum amount of bytes all payload data segments of the
+    /// ports of the [`Node`] are allowed to occupy. If not set, it is unlimited.
+    IOX_BUILDER_OPTIONAL(uint64_t, max_shared_memory_bytes);
+
+    /// Defines the maximum amount of ports the [`Node`] can own at the same
+    /// time. If not set, it is unlimited.
+    IOX_BUILDER_OPTIONAL(uint64_t, max_ports);
+
+    /// Defines the maximum amount of services the [`Node`] can create. If not
+    /// set, it is unlimited.
+    IOX_BUILDER_OPTIONAL(uint64_t, max_services);
+
   public:
     NodeBuilder();
     NodeBuilder(NodeBuilder&&) = default;
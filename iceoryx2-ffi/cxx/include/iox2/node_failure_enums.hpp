@@ -33,7 +33,10 @@ enum class NodeCreationFailure : uint8_t {
     InsufficientPermissions,
     /// Maybe the configuration/system is broken since someone has
     /// removed/modified internal resources
-    InternalError
+    InternalError,
+    /// The Windows security descriptor defined in the [`Config`] is not a
+    /// valid SDDL string.
+    InvalidSecurityDescriptor
 };
 
 /// Failures of [`DeadNodeView::remove_stale_resources()`] that occur when the stale resources of
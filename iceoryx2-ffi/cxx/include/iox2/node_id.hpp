@@ -47,6 +47,7 @@ class NodeId {
     friend class Node;
     template <ServiceType>
     friend class DeadNodeView;
+    friend class HeaderPublishSubscribe;
     template <ServiceType>
     friend auto list_callback(
         iox2_node_state_e, iox2_node_id_ptr, const char*, iox2_node_name_ptr, iox2_config_ptr, iox2_callback_context)
@@ -25,6 +25,9 @@ enum class NotifierCreateError : uint8_t {
     /// [`Notifier`]s
     /// can be created for a specific [`Service`].
     ExceedsMaxSupportedNotifiers,
+    /// The [`Notifier`] cannot be created since it would exceed the
+    /// [`NodeQuota`] of the [`Node`].
+    ExceedsNodeQuota,
 };
 
 /// Defines the failures that can occur while a [`Notifier::notify()`] call.
@@ -69,7 +69,10 @@ enum class ReceiveError : uint8_t {
     FailedToEstablishConnection,
 
     /// Failures when mapping the corresponding data segment
-    UnableToMapSendersDataSegment
+    UnableToMapSendersDataSegment,
+
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure
 };
 } // namespace iox2
 
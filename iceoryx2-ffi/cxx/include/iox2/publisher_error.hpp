@@ -29,6 +29,13 @@ enum class PublisherCreateError : uint8_t {
     /// The datasegment in which the payload of the [`Publisher`] is stored,
     /// could not be created.
     UnableToCreateDataSegment,
+    /// The [`Publisher`] cannot be created since it would exceed the
+    /// [`NodeQuota`] of the [`Node`].
+    ExceedsNodeQuota,
+    /// The [`Service`] was opened with a payload type that is smaller than
+    /// the payload type of the [`Service`]. The [`Publisher`] would be unable
+    /// to provide the full payload.
+    IncompatiblePayloadSize,
 };
 } // namespace iox2
 
@@ -59,6 +59,18 @@ enum class EventOpenError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`Service`] has a lower version than required.
+    IncompatibleVersion,
+    /// The [`Service`] was created in another domain.
+    IncompatibleDomain,
+    /// The [`Service`] was created by an incompatible iceoryx2 version.
+    IncompatibleIceoryx2Version,
+    /// A different [`Service`] is mapped onto the same underlying resources since the
+    /// hashes of their service names collide.
+    ServiceNameHashCollision,
+    /// The [`Service`] was created by a process with a different memory layout of the
+    /// shared data.
+    IncompatibleAbiVersion,
 };
 
 /// Failures that can occur when a new [`MessagingPattern::Event`] [`Service`] shall be created.
@@ -86,6 +98,9 @@ enum class EventCreateError : uint8_t {
     /// [`Sample`] or
     /// [`SampleMut`] in use.
     OldConnectionsStillActive,
+    /// The [`Service`] cannot be created since it would exceed the
+    /// [`NodeQuota`] of the [`Node`].
+    ExceedsNodeQuota,
 };
 
 /// Failures that can occur when a [`MessagingPattern::Event`] [`Service`] shall be opened or
@@ -115,6 +130,9 @@ enum class EventOpenOrCreateError : uint8_t {
     /// The event id that is emitted if a [`Notifier`](crate::port::notifier::Notifier) is
     /// identified as dead does not fit the required event id.
     OpenIncompatibleNotifierDeadEvent,
+    /// The event id that is emitted if a dynamic attribute of the [`Service`] is
+    /// updated does not fit the required event id.
+    OpenIncompatibleDynamicAttributeChangedEvent,
     /// Errors that indicate either an implementation issue or a wrongly
     /// configured system.
     OpenInternalFailure,
@@ -143,6 +161,18 @@ enum class EventOpenOrCreateError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     OpenIsMarkedForDestruction,
+    /// The [`Service`] has a lower version than required.
+    OpenIncompatibleVersion,
+    /// The [`Service`] was created in another domain.
+    OpenIncompatibleDomain,
+    /// The [`Service`] was created by an incompatible iceoryx2 version.
+    OpenIncompatibleIceoryx2Version,
+    /// A different [`Service`] is mapped onto the same underlying resources since the
+    /// hashes of their service names collide.
+    OpenServiceNameHashCollision,
+    /// The [`Service`] was created by a process with a different memory layout of the
+    /// shared data.
+    OpenIncompatibleAbiVersion,
 
     /// Some underlying resources of the [`Service`] are either missing,
     /// corrupted or unaccessible.
@@ -167,6 +197,9 @@ enum class EventOpenOrCreateError : uint8_t {
     /// [`Sample`] or
     /// [`SampleMut`] in use.
     CreateOldConnectionsStillActive,
+    /// The [`Service`] cannot be created since it would exceed the
+    /// [`NodeQuota`] of the [`Node`].
+    CreateExceedsNodeQuota,
     /// Can occur when another process creates and removes the same [`Service`] repeatedly with a
     /// high frequency.
     SystemInFlux,
@@ -67,6 +67,21 @@ enum class PublishSubscribeOpenError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`Service`] has a lower version than required.
+    IncompatibleVersion,
+    /// The [`Service`] was created in another domain.
+    IncompatibleDomain,
+    /// The [`Service`] was created by an incompatible iceoryx2 version.
+    IncompatibleIceoryx2Version,
+    /// A different [`Service`] is mapped onto the same underlying resources since the
+    /// hashes of their service names collide.
+    ServiceNameHashCollision,
+    /// The [`Service`] was created by a process with a different memory layout of the
+    /// shared data.
+    IncompatibleAbiVersion,
+    /// The [`Service`] was created by a process with a different pointer width and its
+    /// types have a different layout in this process.
+    IncompatibleBitness,
 };
 
 /// Errors that can occur when a new [`MessagingPattern::PublishSubscribe`] [`Service`] shall be created.
@@ -92,6 +107,9 @@ enum class PublishSubscribeCreateError : uint8_t {
     /// initialized. Can be caused
     /// by a process that crashed during [`Service`] creation.
     HangsInCreation,
+    /// The [`Service`] cannot be created since it would exceed the
+    /// [`NodeQuota`] of the [`Node`].
+    ExceedsNodeQuota,
 };
 
 /// Errors that can occur when a [`MessagingPattern::PublishSubscribe`] [`Service`] shall be
@@ -145,6 +163,21 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     OpenIsMarkedForDestruction,
+    /// The [`Service`] has a lower version than required.
+    OpenIncompatibleVersion,
+    /// The [`Service`] was created in another domain.
+    OpenIncompatibleDomain,
+    /// The [`Service`] was created by an incompatible iceoryx2 version.
+    OpenIncompatibleIceoryx2Version,
+    /// A different [`Service`] is mapped onto the same underlying resources since the
+    /// hashes of their service names collide.
+    OpenServiceNameHashCollision,
+    /// The [`Service`] was created by a process with a different memory layout of the
+    /// shared data.
+    OpenIncompatibleAbiVersion,
+    /// The [`Service`] was created by a process with a different pointer width and its
+    /// types have a different layout in this process.
+    OpenIncompatibleBitness,
 
     /// Some underlying resources of the [`Service`] are either missing,
     /// corrupted or unaccessible.
@@ -174,6 +207,9 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     /// initialized. Can be caused
     /// by a process that crashed during [`Service`] creation.
     CreateHangsInCreation,
+    /// The [`Service`] cannot be created since it would exceed the
+    /// [`NodeQuota`] of the [`Node`].
+    CreateExceedsNodeQuota,
     /// Can occur when another process creates and removes the same [`Service`] repeatedly with a
     /// high frequency.
     SystemInFlux,
@@ -42,6 +42,10 @@ class Subscriber {
     /// Returns the internal buffer size of the [`Subscriber`].
     auto buffer_size() const -> uint64_t;
 
+    /// Returns the number of [`Sample`]s that were lost since the buffer of
+    /// the [`Subscriber`] was full when a [`Publisher`] delivered them.
+    auto number_of_missed_samples() const -> uint64_t;
+
     /// Receives a [`Sample`] from [`Publisher`]. If no sample could be
     /// received [`None`] is returned. If a failure occurs [`ReceiveError`] is returned.
     auto receive() const -> iox::expected<iox::optional<Sample<S, Payload, UserHeader>>, ReceiveError>;
@@ -123,7 +127,12 @@ inline auto Subscriber<S, Payload, UserHeader>::id() const -> UniqueSubscriberId
 
 template <ServiceType S, typename Payload, typename UserHeader>
 inline auto Subscriber<S, Payload, UserHeader>::buffer_size() const -> uint64_t {
-    IOX_TODO();
+    return iox2_subscriber_buffer_size(&m_handle);
+}
+
+template <ServiceType S, typename Payload, typename UserHeader>
+inline auto Subscriber<S, Payload, UserHeader>::number_of_missed_samples() const -> uint64_t {
+    return iox2_subscriber_number_of_missed_samples(&m_handle);
 }
 
 template <ServiceType S, typename Payload, typename UserHeader>
@@ -28,6 +28,9 @@ enum class SubscriberCreateError : uint8_t {
     /// When the [`Subscriber`] requires a larger buffer size than the
     /// [`Service`] offers the creation will fail.
     BufferSizeExceedsMaxSupportedBufferSizeOfService,
+    /// The [`Subscriber`] cannot be created since it would exceed the
+    /// [`NodeQuota`] of the [`Node`].
+    ExceedsNodeQuota,
 };
 
 } // namespace iox2
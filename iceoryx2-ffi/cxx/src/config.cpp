@@ -104,7 +104,7 @@ auto Config::global_config() -> ConfigView {
 }
 
 auto Config::view() -> ConfigView {
-    return ConfigView { iox2_cast_config_ptr(m_handle) };
+    return ConfigView { iox2_cast_config_ptr(&m_handle) };
 }
 /////////////////////////
 // END: Config
@@ -159,6 +159,10 @@ auto Defaults::publish_subscribe() && -> PublishSubscribe {
 auto Defaults::event() && -> Event {
     return Event(m_config);
 }
+
+auto Defaults::request_response() && -> RequestResponse {
+    return RequestResponse(m_config);
+}
 /////////////////////////
 // END: Defaults
 /////////////////////////
@@ -277,6 +281,120 @@ void Event::set_deadline(iox::optional<iox::units::Duration> value) && {
 // END: Event
 /////////////////////////
 
+/////////////////////////
+// BEGIN: RequestResponse
+/////////////////////////
+RequestResponse::RequestResponse(iox2_config_h* config)
+    : m_config { config } {
+}
+
+auto RequestResponse::enable_safe_overflow_for_requests() && -> bool {
+    return iox2_config_defaults_request_response_enable_safe_overflow_for_requests(m_config);
+}
+
+void RequestResponse::set_enable_safe_overflow_for_requests(bool value) && {
+    iox2_config_defaults_request_response_set_enable_safe_overflow_for_requests(m_config, value);
+}
+
+auto RequestResponse::enable_safe_overflow_for_responses() && -> bool {
+    return iox2_config_defaults_request_response_enable_safe_overflow_for_responses(m_config);
+}
+
+void RequestResponse::set_enable_safe_overflow_for_responses(bool value) && {
+    iox2_config_defaults_request_response_set_enable_safe_overflow_for_responses(m_config, value);
+}
+
+auto RequestResponse::max_active_responses() && -> size_t {
+    return iox2_config_defaults_request_response_max_active_responses(m_config);
+}
+
+void RequestResponse::set_max_active_responses(size_t value) && {
+    iox2_config_defaults_request_response_set_max_active_responses(m_config, value);
+}
+
+auto RequestResponse::max_active_requests() && -> size_t {
+    return iox2_config_defaults_request_response_max_active_requests(m_config);
+}
+
+void RequestResponse::set_max_active_requests(size_t value) && {
+    iox2_config_defaults_request_response_set_max_active_requests(m_config, value);
+}
+
+auto RequestResponse::max_borrowed_responses() && -> size_t {
+    return iox2_config_defaults_request_response_max_borrowed_responses(m_config);
+}
+
+void RequestResponse::set_max_borrowed_responses(size_t value) && {
+    iox2_config_defaults_request_response_set_max_borrowed_responses(m_config, value);
+}
+
+auto RequestResponse::max_borrowed_requests() && -> size_t {
+    return iox2_config_defaults_request_response_max_borrowed_requests(m_config);
+}
+
+void RequestResponse::set_max_borrowed_requests(size_t value) && {
+    iox2_config_defaults_request_response_set_max_borrowed_requests(m_config, value);
+}
+
+auto RequestResponse::max_response_buffer_size() && -> size_t {
+    return iox2_config_defaults_request_response_max_response_buffer_size(m_config);
+}
+
+void RequestResponse::set_max_response_buffer_size(size_t value) && {
+    iox2_config_defaults_request_response_set_max_response_buffer_size(m_config, value);
+}
+
+auto RequestResponse::max_request_buffer_size() && -> size_t {
+    return iox2_config_defaults_request_response_max_request_buffer_size(m_config);
+}
+
+void RequestResponse::set_max_request_buffer_size(size_t value) && {
+    iox2_config_defaults_request_response_set_max_request_buffer_size(m_config, value);
+}
+
+auto RequestResponse::max_servers() && -> size_t {
+    return iox2_config_defaults_request_response_max_servers(m_config);
+}
+
+void RequestResponse::set_max_servers(size_t value) && {
+    iox2_config_defaults_request_response_set_max_servers(m_config, value);
+}
+
+auto RequestResponse::max_clients() && -> size_t {
+    return iox2_config_defaults_request_response_max_clients(m_config);
+}
+
+void RequestResponse::set_max_clients(size_t value) && {
+    iox2_config_defaults_request_response_set_max_clients(m_config, value);
+}
+
+auto RequestResponse::max_nodes() && -> size_t {
+    return iox2_config_defaults_request_response_max_nodes(m_config);
+}
+
+void RequestResponse::set_max_nodes(size_t value) && {
+    iox2_config_defaults_request_response_set_max_nodes(m_config, value);
+}
+
+auto RequestResponse::client_max_loaned_requests() && -> size_t {
+    return iox2_config_defaults_request_response_client_max_loaned_requests(m_config);
+}
+
+void RequestResponse::set_client_max_loaned_requests(size_t value) && {
+    iox2_config_defaults_request_response_set_client_max_loaned_requests(m_config, value);
+}
+
+auto RequestResponse::server_max_loaned_responses_per_request() && -> size_t {
+    return iox2_config_defaults_request_response_server_max_loaned_responses_per_request(m_config);
+}
+
+void RequestResponse::set_server_max_loaned_responses_per_request(size_t value) && {
+    iox2_config_defaults_request_response_set_server_max_loaned_responses_per_request(m_config, value);
+}
+/////////////////////////
+// END: RequestResponse
+/////////////////////////
+
 /////////////////////////
 // BEGIN: PublishSubscribe
 /////////////////////////
@@ -365,6 +483,14 @@ auto PublishSubscribe::subscriber_expired_connection_buffer() && -> size_t {
 void PublishSubscribe::set_subscriber_expired_connection_buffer(size_t value) && {
     iox2_config_defaults_publish_subscribe_set_subscriber_expired_connection_buffer(m_config, value);
 }
+
+auto PublishSubscribe::zero_payload_on_loan() && -> bool {
+    return iox2_config_defaults_publish_subscribe_zero_payload_on_loan(m_config);
+}
+
+void PublishSubscribe::set_zero_payload_on_loan(bool value) && {
+    iox2_config_defaults_publish_subscribe_set_zero_payload_on_loan(m_config, value);
+}
 /////////////////////////
 // END: PublishSubscribe
 /////////////////////////
@@ -479,6 +605,14 @@ void Node::set_service_tag_suffix(const iox::FileName& value) && {
     iox2_config_global_node_set_service_tag_suffix(m_config, value.as_string().c_str());
 }
 
+auto Node::crash_journal_suffix() && -> const char* {
+    return iox2_config_global_node_crash_journal_suffix(m_config);
+}
+
+void Node::set_crash_journal_suffix(const iox::FileName& value) && {
+    iox2_config_global_node_set_crash_journal_suffix(m_config, value.as_string().c_str());
+}
+
 auto Node::cleanup_dead_nodes_on_creation() && -> bool {
     return iox2_config_global_node_cleanup_dead_nodes_on_creation(m_config);
 }
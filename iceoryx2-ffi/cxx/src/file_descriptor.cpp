@@ -73,11 +73,11 @@ void FileDescriptor::drop() {
 }
 
 auto FileDescriptor::unsafe_native_handle() const -> int32_t {
-    return iox2_file_descriptor_native_handle(iox2_cast_file_descriptor_ptr(m_handle));
+    return iox2_file_descriptor_native_handle(iox2_cast_file_descriptor_ptr(&m_handle));
 }
 
 auto FileDescriptor::as_view() const -> FileDescriptorView {
-    return FileDescriptorView(iox2_cast_file_descriptor_ptr(m_handle));
+    return FileDescriptorView(iox2_cast_file_descriptor_ptr(&m_handle));
 }
 
 
@@ -50,6 +50,30 @@ auto HeaderPublishSubscribe::publisher_id() const -> UniquePublisherId {
     return UniquePublisherId { id_handle };
 }
 
+auto HeaderPublishSubscribe::node_id() const -> NodeId {
+    iox2_node_id_h id_handle = nullptr;
+
+    iox2_publish_subscribe_header_node_id(&m_handle, nullptr, &id_handle);
+    return NodeId { id_handle };
+}
+
+auto HeaderPublishSubscribe::sequence_number() const -> uint64_t {
+    return iox2_publish_subscribe_header_sequence_number(&m_handle);
+}
+
+auto HeaderPublishSubscribe::priority() const -> uint8_t {
+    return iox2_publish_subscribe_header_priority(&m_handle);
+}
+
+auto HeaderPublishSubscribe::timestamp() const -> timespec {
+    uint64_t seconds = 0;
+    uint32_t nanoseconds = 0;
+
+    iox2_publish_subscribe_header_timestamp(&m_handle, &seconds, &nanoseconds);
+
+    return { static_cast<decltype(timespec::tv_sec)>(seconds), static_cast<decltype(timespec::tv_nsec)>(nanoseconds) };
+}
+
 auto HeaderPublishSubscribe::number_of_elements() const -> uint64_t {
     return iox2_publish_subscribe_header_number_of_elements(&m_handle);
 }
@@ -157,7 +157,7 @@ NodeBuilder::NodeBuilder()
 template <ServiceType T>
 auto NodeBuilder::create() const&& -> iox::expected<Node<T>, NodeCreationFailure> {
     if (m_name.has_value()) {
-        const auto* name_ptr = iox2_cast_node_name_ptr(m_name->m_handle);
+        const auto* name_ptr = iox2_cast_node_name_ptr(&m_name->m_handle);
         iox2_node_builder_set_name(&m_handle, name_ptr);
     }
 
@@ -170,6 +170,22 @@ auto NodeBuilder::create() const&& -> iox::expected<Node<T>, NodeCreationFailure
             &m_handle, iox::into<iox2_signal_handling_mode_e>(m_signal_handling_mode.value()));
     }
 
+    if (m_crash_journal.has_value()) {
+        iox2_node_builder_set_crash_journal(&m_handle, m_crash_journal.value());
+    }
+
+    if (m_max_shared_memory_bytes.has_value()) {
+        iox2_node_builder_set_max_shared_memory_bytes(&m_handle, m_max_shared_memory_bytes.value());
+    }
+
+    if (m_max_ports.has_value()) {
+        iox2_node_builder_set_max_ports(&m_handle, m_max_ports.value());
+    }
+
+    if (m_max_services.has_value()) {
+        iox2_node_builder_set_max_services(&m_handle, m_max_services.value());
+    }
+
     iox2_node_h node_handle {};
     const auto ret_val = iox2_node_builder_create(m_handle, nullptr, iox::into<iox2_service_type_e>(T), &node_handle);
 
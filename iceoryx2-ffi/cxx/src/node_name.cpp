@@ -62,7 +62,7 @@ auto NodeName::operator=(const NodeName& rhs) -> NodeName& {
     if (this != &rhs) {
         drop();
 
-        const auto* ptr = iox2_cast_node_name_ptr(rhs.m_handle);
+        const auto* ptr = iox2_cast_node_name_ptr(&rhs.m_handle);
         size_t len = 0;
         const auto* chars = iox2_node_name_as_chars(ptr, &len);
         IOX_ASSERT(iox2_node_name_new(nullptr, chars, len, &m_handle) == IOX2_OK,
@@ -102,7 +102,7 @@ auto NodeName::to_string() const -> iox::string<IOX2_NODE_NAME_LENGTH> {
 }
 
 auto NodeName::as_view() const -> NodeNameView {
-    return NodeNameView(iox2_cast_node_name_ptr(m_handle));
+    return NodeNameView(iox2_cast_node_name_ptr(&m_handle));
 }
 
 } // namespace iox2
@@ -11,7 +11,6 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 #include "iox2/service.hpp"
-#include "iox/assertions_addendum.hpp"
 #include "iox2/iceoryx2.h"
 #include "iox2/internal/iceoryx2.hpp"
 #include "iox2/service_details.hpp"
@@ -38,11 +37,29 @@ auto Service<S>::does_exist(const ServiceName& service_name,
 }
 
 template <ServiceType S>
-auto Service<S>::details([[maybe_unused]] const ServiceName& service_name,
-                         [[maybe_unused]] const ConfigView config,
-                         [[maybe_unused]] const MessagingPattern messaging_pattern)
+auto details_callback(const iox2_service_details_t* const details, void* ctx) -> void {
+    auto service_details = static_cast<iox::optional<ServiceDetails<S>>*>(ctx);
+    *service_details = ServiceDetails<S> { StaticConfig(details->static_config) };
+}
+
+template <ServiceType S>
+auto Service<S>::details(const ServiceName& service_name,
+                         const ConfigView config,
+                         const MessagingPattern messaging_pattern)
     -> iox::expected<iox::optional<ServiceDetails<S>>, ServiceDetailsError> {
-    IOX_TODO();
+    iox::optional<ServiceDetails<S>> service_details;
+    auto result = iox2_service_details(iox::into<iox2_service_type_e>(S),
+                                       service_name.as_view().m_ptr,
+                                       config.m_ptr,
+                                       iox::into<iox2_messaging_pattern_e>(messaging_pattern),
+                                       details_callback<S>,
+                                       &service_details);
+
+    if (result == IOX2_OK) {
+        return iox::ok(service_details);
+    }
+
+    return iox::err(iox::into<ServiceDetailsError>(result));
 }
 
 template <ServiceType S>
@@ -62,7 +62,7 @@ auto ServiceName::operator=(const ServiceName& rhs) -> ServiceName& {
     if (this != &rhs) {
         drop();
 
-        const auto* ptr = iox2_cast_service_name_ptr(rhs.m_handle);
+        const auto* ptr = iox2_cast_service_name_ptr(&rhs.m_handle);
         size_t len = 0;
         const auto* chars = iox2_service_name_as_chars(ptr, &len);
         IOX_ASSERT(iox2_service_name_new(nullptr, chars, len, &m_handle) == IOX2_OK,
@@ -104,6 +104,6 @@ auto ServiceName::to_string() const -> iox::string<IOX2_SERVICE_NAME_LENGTH> {
 }
 
 auto ServiceName::as_view() const -> ServiceNameView {
-    return ServiceNameView(iox2_cast_service_name_ptr(m_handle));
+    return ServiceNameView(iox2_cast_service_name_ptr(&m_handle));
 }
 } // namespace iox2
@@ -269,6 +269,48 @@ TEST(Config, global_node_service_tag_suffix) {
     ASSERT_THAT(config.global().node().service_tag_suffix(), StrEq(test_value.as_string().c_str()));
 }
 
+TEST(Config, global_node_crash_journal_suffix) {
+    const auto test_value = iox::FileName::create("what_happened_to_fluffy").expect("");
+    auto config = Config();
+
+    config.global().node().set_crash_journal_suffix(test_value);
+    ASSERT_THAT(config.global().node().crash_journal_suffix(), StrEq(test_value.as_string().c_str()));
+}
+
+TEST(Config, defaults_publish_subscribe_zero_payload_on_loan) {
+    auto config = Config();
+
+    config.defaults().publish_subscribe().set_zero_payload_on_loan(true);
+    ASSERT_THAT(config.defaults().publish_subscribe().zero_payload_on_loan(), Eq(true));
+    config.defaults().publish_subscribe().set_zero_payload_on_loan(false);
+    ASSERT_THAT(config.defaults().publish_subscribe().zero_payload_on_loan(), Eq(false));
+}
+
+TEST(Config, defaults_request_response_max_clients) {
+    const auto test_value = 123;
+    auto config = Config();
+
+    config.defaults().request_response().set_max_clients(test_value);
+    ASSERT_THAT(config.defaults().request_response().max_clients(), Eq(test_value));
+}
+
+TEST(Config, defaults_request_response_max_response_buffer_size) {
+    const auto test_value = 45;
+    auto config = Config();
+
+    config.defaults().request_response().set_max_response_buffer_size(test_value);
+    ASSERT_THAT(config.defaults().request_response().max_response_buffer_size(), Eq(test_value));
+}
+
+TEST(Config, defaults_request_response_enable_safe_overflow_for_requests) {
+    auto config = Config();
+
+    config.defaults().request_response().set_enable_safe_overflow_for_requests(true);
+    ASSERT_THAT(config.defaults().request_response().enable_safe_overflow_for_requests(), Eq(true));
+    config.defaults().request_response().set_enable_safe_overflow_for_requests(false);
+    ASSERT_THAT(config.defaults().request_response().enable_safe_overflow_for_requests(), Eq(false));
+}
+
 TEST(Config, global_node_cleanup_dead_nodes_on_creation) {
     auto config = Config();
 
@@ -34,6 +34,8 @@ TEST(EnumConversionTest, config_creation_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ConfigFileDoesNotExist)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::UnableToOpenConfigFile)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::UnknownConfigEntry)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::MaxIncludeDepthExceeded)), 1U);
 }
 
 TEST(EnumConversionTest, connection_failure_into_c_str) {
@@ -46,6 +48,7 @@ TEST(EnumConversionTest, listener_create_into_c_str) {
     using Sut = iox2::ListenerCreateError;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxSupportedListeners)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ResourceCreationFailed)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsNodeQuota)), 1U);
 }
 
 TEST(EnumConversionTest, listener_wait_into_c_str) {
@@ -66,6 +69,7 @@ TEST(EnumConversionTest, node_creation_failure_into_c_str) {
     using Sut = iox2::NodeCreationFailure;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InternalError)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::InvalidSecurityDescriptor)), 1U);
 }
 
 TEST(EnumConversionTest, node_wait_failure_into_c_str) {
@@ -74,9 +78,18 @@ TEST(EnumConversionTest, node_wait_failure_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::Interrupt)), 1U);
 }
 
+TEST(EnumConversionTest, node_cleanup_failure_into_c_str) {
+    using Sut = iox2::NodeCleanupFailure;
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::Interrupt)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::InternalError)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::InsufficientPermissions)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::VersionMismatch)), 1U);
+}
+
 TEST(EnumConversionTest, notifier_create_into_c_str) {
     using Sut = iox2::NotifierCreateError;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxSupportedNotifiers)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsNodeQuota)), 1U);
 }
 
 TEST(EnumConversionTest, notifier_notify_into_c_str) {
@@ -88,6 +101,8 @@ TEST(EnumConversionTest, publisher_create_into_c_str) {
     using Sut = iox2::PublisherCreateError;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxSupportedPublishers)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::UnableToCreateDataSegment)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsNodeQuota)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatiblePayloadSize)), 1U);
 }
 
 TEST(EnumConversionTest, publisher_loan_into_c_str) {
@@ -124,6 +139,11 @@ TEST(EnumConversionTest, event_open_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::DoesNotSupportRequestedAmountOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxNumberOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::IsMarkedForDestruction)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleVersion)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleDomain)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleIceoryx2Version)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ServiceNameHashCollision)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleAbiVersion)), 1U);
 }
 
 TEST(EnumConversionTest, event_create_into_c_str) {
@@ -135,6 +155,7 @@ TEST(EnumConversionTest, event_create_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::HangsInCreation)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OldConnectionsStillActive)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsNodeQuota)), 1U);
 }
 
 TEST(EnumConversionTest, event_open_or_create_into_c_str) {
@@ -152,6 +173,11 @@ TEST(EnumConversionTest, event_open_or_create_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenDoesNotSupportRequestedAmountOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenExceedsMaxNumberOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIsMarkedForDestruction)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleVersion)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleDomain)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleIceoryx2Version)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenServiceNameHashCollision)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleAbiVersion)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateServiceInCorruptedState)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateInternalFailure)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateIsBeingCreatedByAnotherInstance)), 1U);
@@ -159,6 +185,7 @@ TEST(EnumConversionTest, event_open_or_create_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateHangsInCreation)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateInsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateOldConnectionsStillActive)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateExceedsNodeQuota)), 1U);
 }
 
 TEST(EnumConversionTest, publish_subscribe_open_into_c_str) {
@@ -180,6 +207,12 @@ TEST(EnumConversionTest, publish_subscribe_open_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::HangsInCreation)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxNumberOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::IsMarkedForDestruction)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleVersion)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleDomain)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleIceoryx2Version)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ServiceNameHashCollision)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleAbiVersion)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleBitness)), 1U);
 }
 
 TEST(EnumConversionTest, publish_subscribe_create_into_c_str) {
@@ -191,6 +224,7 @@ TEST(EnumConversionTest, publish_subscribe_create_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InternalFailure)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::IsBeingCreatedByAnotherInstance)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::HangsInCreation)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsNodeQuota)), 1U);
 }
 
 TEST(EnumConversionTest, publish_subscribe_open_or_create_into_c_str) {
@@ -212,6 +246,12 @@ TEST(EnumConversionTest, publish_subscribe_open_or_create_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenHangsInCreation)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenExceedsMaxNumberOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIsMarkedForDestruction)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleVersion)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleDomain)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleIceoryx2Version)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenServiceNameHashCollision)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleAbiVersion)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleBitness)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateServiceInCorruptedState)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateSubscriberBufferMustBeLargerThanHistorySize)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateAlreadyExists)), 1U);
@@ -220,6 +260,7 @@ TEST(EnumConversionTest, publish_subscribe_open_or_create_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateIsBeingCreatedByAnotherInstance)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateOldConnectionsStillActive)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateHangsInCreation)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateExceedsNodeQuota)), 1U);
 }
 
 TEST(EnumConversionTest, service_details_into_c_str) {
@@ -244,12 +285,14 @@ TEST(EnumConversionTest, subscriber_receive_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxBorrowedSamples)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::FailedToEstablishConnection)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::UnableToMapSendersDataSegment)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::InternalFailure)), 1U);
 }
 
 TEST(EnumConversionTest, subscriber_create_into_c_str) {
     using Sut = iox2::SubscriberCreateError;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxSupportedSubscribers)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::BufferSizeExceedsMaxSupportedBufferSizeOfService)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsNodeQuota)), 1U);
 }
 
 TEST(EnumConversionTest, waitset_create_into_c_str) {
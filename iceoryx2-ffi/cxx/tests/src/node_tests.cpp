@@ -91,6 +91,19 @@ TYPED_TEST(NodeTest, signal_handling_mode_can_be_set) {
     ASSERT_THAT(sut_2.signal_handling_mode(), Eq(SignalHandlingMode::HandleTerminationRequests));
 }
 
+TYPED_TEST(NodeTest, max_services_quota_is_applied) {
+    constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
+
+    auto node = NodeBuilder().max_services(1).create<SERVICE_TYPE>().expect("");
+
+    auto sut_1 = node.service_builder(iox2_testing::generate_service_name()).event().create();
+    auto sut_2 = node.service_builder(iox2_testing::generate_service_name()).event().create();
+
+    ASSERT_THAT(sut_1.has_value(), Eq(true));
+    ASSERT_THAT(sut_2.has_error(), Eq(true));
+    ASSERT_THAT(sut_2.error(), Eq(EventCreateError::ExceedsNodeQuota));
+}
+
 TYPED_TEST(NodeTest, node_id_is_unique) {
     constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
 
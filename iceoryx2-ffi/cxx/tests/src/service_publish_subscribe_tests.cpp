@@ -56,6 +56,27 @@ TYPED_TEST(ServicePublishSubscribeTest, created_service_does_exist) {
         Service<SERVICE_TYPE>::does_exist(service_name, Config::global_config(), MessagingPattern::Event).expect(""));
 }
 
+TYPED_TEST(ServicePublishSubscribeTest, details_of_created_service_can_be_acquired) {
+    constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
+
+    const auto service_name = iox2_testing::generate_service_name();
+
+    ASSERT_FALSE(
+        Service<SERVICE_TYPE>::details(service_name, Config::global_config(), MessagingPattern::PublishSubscribe)
+            .expect("")
+            .has_value());
+
+    auto node = NodeBuilder().create<SERVICE_TYPE>().expect("");
+    auto sut = node.service_builder(service_name).template publish_subscribe<uint64_t>().create().expect("");
+
+    auto details =
+        Service<SERVICE_TYPE>::details(service_name, Config::global_config(), MessagingPattern::PublishSubscribe)
+            .expect("");
+    ASSERT_TRUE(details.has_value());
+    ASSERT_THAT(details->static_details.name(), StrEq(service_name.to_string().c_str()));
+    ASSERT_THAT(details->static_details.messaging_pattern(), Eq(MessagingPattern::PublishSubscribe));
+}
+
 TYPED_TEST(ServicePublishSubscribeTest, creating_existing_service_fails) {
     constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
 
@@ -178,6 +199,25 @@ TYPED_TEST(ServicePublishSubscribeTest, send_copy_receive_works) {
     ASSERT_THAT(**sample, Eq(payload));
 }
 
+TYPED_TEST(ServicePublishSubscribeTest, subscriber_buffer_size_is_set) {
+    constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
+    constexpr uint64_t BUFFER_SIZE = 7;
+
+    const auto service_name = iox2_testing::generate_service_name();
+
+    auto node = NodeBuilder().create<SERVICE_TYPE>().expect("");
+    auto service = node.service_builder(service_name)
+                       .template publish_subscribe<uint64_t>()
+                       .subscriber_max_buffer_size(BUFFER_SIZE)
+                       .create()
+                       .expect("");
+
+    auto sut = service.subscriber_builder().buffer_size(BUFFER_SIZE).create().expect("");
+
+    ASSERT_THAT(sut.buffer_size(), Eq(BUFFER_SIZE));
+    ASSERT_THAT(sut.number_of_missed_samples(), Eq(0));
+}
+
 TYPED_TEST(ServicePublishSubscribeTest, loan_send_receive_works) {
     constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
 
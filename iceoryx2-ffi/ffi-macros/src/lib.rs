@@ -22,6 +22,14 @@ use syn::{
     LitStr, Meta, Token,
 };
 
+/// Generates the boilerplate for an FFI type `iox2_foo_t` which wraps the Rust type provided as
+/// argument, e.g. `#[iceoryx2_ffi(Foo)]`.
+///
+/// Besides the storage accessors and the `alloc`/`dealloc` functions, it implements
+/// `AssertNonNullHandle` and `HandleToType` for the owning handle `iox2_foo_h` and the
+/// non-owning handle `iox2_foo_h_ref`. The handle type definitions themselves must still be
+/// written out in the source file since cbindgen does not expand macros. Both traits need to
+/// be in scope where the macro is used.
 #[proc_macro_attribute]
 pub fn iceoryx2_ffi(args: TokenStream, input: TokenStream) -> TokenStream {
     let Args { rust_type: my_type } = parse_attribute_args(args);
@@ -68,7 +76,8 @@ pub fn iceoryx2_ffi(args: TokenStream, input: TokenStream) -> TokenStream {
     let struct_storage_name = format_ident!("iox2_{}_storage_t", stripped_struct_name);
     let _struct_h_t_name = format_ident!("iox2_{}_h_t", stripped_struct_name);
     let struct_h_name = format_ident!("iox2_{}_h", stripped_struct_name);
-    let _struct_h_ref_name = format_ident!("iox2_{}_h_ref", stripped_struct_name);
+    let struct_h_ref_name = format_ident!("iox2_{}_h_ref", stripped_struct_name);
+    let struct_name_literal = struct_name.to_string();
 
     // NOTE: cbindgen does not play well with adding new structs or fields to existing structs;
     // this code is kept for reference
@@ -135,9 +144,20 @@ pub fn iceoryx2_ffi(args: TokenStream, input: TokenStream) -> TokenStream {
 
         impl #struct_name {
             pub(super) fn as_handle(&mut self) -> #struct_h_name {
+                let is_send = {
+                    use crate::api::thread_safety::{IsNotSend, IsSend, SendCheck};
+                    (&SendCheck::<#my_type>::new()).is_send()
+                };
+                crate::api::handle_validation::register(self as *mut Self as usize, #struct_name_literal, is_send);
                 self as *mut _ as _
             }
 
+            // invalidates all handles to the struct and releases it with its deleter
+            pub(super) fn delete(&mut self) {
+                crate::api::handle_validation::unregister(self as *mut Self as usize, #struct_name_literal);
+                (self.deleter)(self)
+            }
+
             pub(super) fn take(&mut self) -> Option<#my_type> {
                 unsafe { self.value.as_option_mut().take() }
             }
@@ -157,6 +177,45 @@ pub fn iceoryx2_ffi(args: TokenStream, input: TokenStream) -> TokenStream {
             }
         }
 
+        // The owning handle '_h' is passed by value to all functions which consume the object and
+        // the non-owning handle '_h_ref' to all functions which only borrow it. Both handle types
+        // get their conversions from this macro so that every type follows the same ownership rules.
+
+        impl AssertNonNullHandle for #struct_h_name {
+            fn assert_non_null(self) {
+                debug_assert!(!self.is_null());
+            }
+        }
+
+        impl AssertNonNullHandle for #struct_h_ref_name {
+            fn assert_non_null(self) {
+                debug_assert!(!self.is_null());
+                unsafe {
+                    debug_assert!(!(*self).is_null());
+                }
+            }
+        }
+
+        impl HandleToType for #struct_h_name {
+            type Target = *mut #struct_name;
+
+            fn as_type(self) -> Self::Target {
+                crate::api::handle_validation::verify(self as usize, #struct_name_literal);
+                self as *mut _ as _
+            }
+        }
+
+        impl HandleToType for #struct_h_ref_name {
+            type Target = *mut #struct_name;
+
+            fn as_type(self) -> Self::Target {
+                unsafe {
+                    crate::api::handle_validation::verify(*self as usize, #struct_name_literal);
+                    *self as *mut _ as _
+                }
+            }
+        }
+
         #[cfg(test)]
         mod test_generated {
             use super::*;
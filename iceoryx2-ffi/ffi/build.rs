@@ -28,7 +28,36 @@ fn main() {
 
     let crate_dir = env::var("CARGO_MANIFEST_DIR").expect("Cargo manifest dir");
 
-    cbindgen::generate(crate_dir)
+    // the opaque type renaming and forward declarations are maintained in the cbindgen.toml,
+    // the version macros are added here to keep them in sync with the crate version
+    let mut config = cbindgen::Config::from_root_or_default(&crate_dir);
+    config.after_includes = Some(format!(
+        "{}\n{}",
+        config.after_includes.unwrap_or_default(),
+        version_macros()
+    ));
+
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    cbindgen::Builder::new()
+        .with_crate(crate_dir)
+        .with_config(config)
+        .generate()
         .expect("Unable to generate c bindings")
         .write_to_file(header_path);
 }
+
+fn version_macros() -> String {
+    let major = env::var("CARGO_PKG_VERSION_MAJOR").expect("Major version of the crate");
+    let minor = env::var("CARGO_PKG_VERSION_MINOR").expect("Minor version of the crate");
+    let patch = env::var("CARGO_PKG_VERSION_PATCH").expect("Patch version of the crate");
+
+    format!(
+        "// version of the iceoryx2 library the header was generated for
+#define IOX2_VERSION_MAJOR {major}
+#define IOX2_VERSION_MINOR {minor}
+#define IOX2_VERSION_PATCH {patch}
+#define IOX2_VERSION_STRING \"{major}.{minor}.{patch}\"
+"
+    )
+}
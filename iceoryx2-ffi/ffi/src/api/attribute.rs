@@ -14,7 +14,7 @@
 
 use iceoryx2::service::attribute::Attribute;
 
-use core::ffi::c_char;
+use core::ffi::{c_char, CStr};
 
 extern crate alloc;
 use alloc::ffi::CString;
@@ -32,6 +32,20 @@ pub type iox2_attribute_h_ref = *const iox2_attribute_h_t;
 
 // END type definition
 
+/// Copies the string into the buffer and truncates it when the buffer is too small. The buffer
+/// always contains a null-terminated string afterwards. Returns the number of copied bytes
+/// including the null-terminator.
+unsafe fn copy_null_terminated(value: &CStr, buffer: *mut c_char, buffer_len: usize) -> usize {
+    if buffer_len == 0 {
+        return 0;
+    }
+
+    let copied_length = (buffer_len - 1).min(value.to_bytes().len());
+    core::ptr::copy_nonoverlapping(value.as_ptr(), buffer, copied_length);
+    *buffer.add(copied_length) = 0;
+    copied_length + 1
+}
+
 // BEGIN C API
 /// Returns the length of the attributes key.
 ///
@@ -46,7 +60,9 @@ pub unsafe extern "C" fn iox2_attribute_key_len(handle: iox2_attribute_h_ref) ->
     attribute.key().len()
 }
 
-/// Copies the keys value into the provided buffer.
+/// Copies the keys value into the provided buffer. The copied string is always null-terminated
+/// and truncated when the buffer is too small. Returns the number of copied bytes including the
+/// null-terminator.
 ///
 /// # Safety
 ///
@@ -63,13 +79,7 @@ pub unsafe extern "C" fn iox2_attribute_key(
 
     let attribute = (*handle).underlying_type();
     if let Ok(key) = CString::new(attribute.key()) {
-        let copied_key_length = buffer_len.min(key.as_bytes_with_nul().len());
-        core::ptr::copy_nonoverlapping(
-            key.as_bytes_with_nul().as_ptr(),
-            buffer.cast(),
-            copied_key_length,
-        );
-        copied_key_length
+        copy_null_terminated(&key, buffer, buffer_len)
     } else {
         0
     }
@@ -88,7 +98,9 @@ pub unsafe extern "C" fn iox2_attribute_value_len(handle: iox2_attribute_h_ref)
     attribute.value().len()
 }
 
-/// Copies the values value into the provided buffer.
+/// Copies the values value into the provided buffer. The copied string is always null-terminated
+/// and truncated when the buffer is too small. Returns the number of copied bytes including the
+/// null-terminator.
 ///
 /// # Safety
 ///
@@ -105,13 +117,7 @@ pub unsafe extern "C" fn iox2_attribute_value(
 
     let attribute = (*handle).underlying_type();
     if let Ok(value) = CString::new(attribute.value()) {
-        let copied_key_length = buffer_len.min(value.as_bytes_with_nul().len());
-        core::ptr::copy_nonoverlapping(
-            value.as_bytes_with_nul().as_ptr(),
-            buffer.cast(),
-            copied_key_length,
-        );
-        copied_key_length
+        copy_null_terminated(&value, buffer, buffer_len)
     } else {
         0
     }
@@ -44,7 +44,7 @@ impl AttributeSpecifierType {
 #[repr(C)]
 #[repr(align(8))] // alignment of Option<AttributeSpecifier>
 pub struct iox2_attribute_specifier_storage_t {
-    internal: [u8; 24], // magic number obtained with size_of::<Option<AttributeSpecifier>>()
+    internal: [u8; 72], // magic number obtained with size_of::<Option<AttributeSpecifier>>()
 }
 
 #[repr(C)]
@@ -67,37 +67,6 @@ pub type iox2_attribute_specifier_ptr = *const AttributeSpecifier;
 /// The mutable pointer to the underlying `AttributeSpecifier`
 pub type iox2_attribute_specifier_ptr_mut = *mut AttributeSpecifier;
 
-impl AssertNonNullHandle for iox2_attribute_specifier_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_attribute_specifier_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_attribute_specifier_h {
-    type Target = *mut iox2_attribute_specifier_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_attribute_specifier_h_ref {
-    type Target = *mut iox2_attribute_specifier_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END type definition
 
 // BEGIN C API
@@ -148,7 +117,7 @@ pub unsafe extern "C" fn iox2_attribute_specifier_drop(handle: iox2_attribute_sp
     let attribute_specifier = &mut *handle.as_type();
 
     ManuallyDrop::drop(&mut attribute_specifier.value.as_mut().0);
-    (attribute_specifier.deleter)(attribute_specifier);
+    attribute_specifier.delete();
 }
 
 /// Defines a attribute (key / value pair).
@@ -70,37 +70,6 @@ pub type iox2_attribute_verifier_ptr = *const AttributeVerifier;
 /// The mutable pointer to the underlying `AttributeVerifier`
 pub type iox2_attribute_verifier_ptr_mut = *mut AttributeVerifier;
 
-impl AssertNonNullHandle for iox2_attribute_verifier_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_attribute_verifier_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_attribute_verifier_h {
-    type Target = *mut iox2_attribute_verifier_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_attribute_verifier_h_ref {
-    type Target = *mut iox2_attribute_verifier_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END type definition
 
 // BEGIN C API
@@ -151,7 +120,7 @@ pub unsafe extern "C" fn iox2_attribute_verifier_drop(handle: iox2_attribute_ver
     let attribute_verifier = &mut *handle.as_type();
 
     ManuallyDrop::drop(&mut attribute_verifier.value.as_mut().0);
-    (attribute_verifier.deleter)(attribute_verifier);
+    attribute_verifier.delete();
 }
 
 /// Defines a attribute (key / value pair) that is required.
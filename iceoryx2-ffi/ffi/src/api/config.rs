@@ -48,6 +48,10 @@ pub enum iox2_config_creation_error_e {
     UNABLE_TO_OPEN_CONFIG_FILE,
     /// The provided string is not a valid file path
     INVALID_FILE_PATH,
+    /// The config contains an entry that is not known.
+    UNKNOWN_CONFIG_ENTRY,
+    /// The config files include each other recursively or the includes are nested too deep.
+    MAX_INCLUDE_DEPTH_EXCEEDED,
 }
 
 impl IntoCInt for ConfigCreationError {
@@ -68,6 +72,12 @@ impl IntoCInt for ConfigCreationError {
             ConfigCreationError::UnableToOpenConfigFile => {
                 iox2_config_creation_error_e::UNABLE_TO_OPEN_CONFIG_FILE
             }
+            ConfigCreationError::UnknownConfigEntry => {
+                iox2_config_creation_error_e::UNKNOWN_CONFIG_ENTRY
+            }
+            ConfigCreationError::MaxIncludeDepthExceeded => {
+                iox2_config_creation_error_e::MAX_INCLUDE_DEPTH_EXCEEDED
+            }
         }) as c_int
     }
 }
@@ -86,7 +96,7 @@ pub(super) struct ConfigOwner {
 #[repr(C)]
 #[repr(align(8))] // align_of<ConfigOwner>()
 pub struct iox2_config_storage_t {
-    internal: [u8; 3720], // size_of<ConfigOwner>()
+    internal: [u8; 5456], // size_of<ConfigOwner>()
 }
 
 /// Contains the iceoryx2 config
@@ -111,37 +121,6 @@ pub type iox2_config_h = *mut iox2_config_h_t;
 /// The non-owning handle for `iox2_config_t`. Passing the handle to an function does not transfers the ownership.
 pub type iox2_config_h_ref = *const iox2_config_h;
 
-impl AssertNonNullHandle for iox2_config_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_config_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_config_h {
-    type Target = *mut iox2_config_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_config_h_ref {
-    type Target = *mut iox2_config_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END type definition
 
 // BEGIN C API
@@ -167,12 +146,12 @@ pub unsafe extern "C" fn iox2_config_creation_error_string(
     error.as_const_cstr().as_ptr() as *const c_char
 }
 
-/// This function casts a [`iox2_config_h`] into a [`iox2_config_ptr`]
+/// This function casts a [`iox2_config_h_ref`] into a [`iox2_config_ptr`]
 ///
 /// # Arguments
 ///
-/// * `handle` obtained by [`iox2_config_from_file()`], [`iox2_config_default()`],
-///     [`iox2_config_clone()`] or [`iox2_config_from_ptr()`]
+/// * `config_handle` - Must be a valid [`iox2_config_h_ref`] obtained by [`iox2_config_from_file()`],
+///   [`iox2_config_default()`], [`iox2_config_clone()`] or [`iox2_config_from_ptr()`]
 ///
 /// Returns a [`iox2_config_ptr`]
 ///
@@ -181,8 +160,8 @@ pub unsafe extern "C" fn iox2_config_creation_error_string(
 /// * The `config_handle` must be a valid handle.
 /// * The `config_handle` is still valid after the call to this function.
 #[no_mangle]
-pub unsafe extern "C" fn iox2_cast_config_ptr(config_handle: iox2_config_h) -> iox2_config_ptr {
-    debug_assert!(!config_handle.is_null());
+pub unsafe extern "C" fn iox2_cast_config_ptr(config_handle: iox2_config_h_ref) -> iox2_config_ptr {
+    config_handle.assert_non_null();
 
     &*(*config_handle.as_type()).value.as_ref().value
 }
@@ -344,7 +323,7 @@ pub unsafe extern "C" fn iox2_config_drop(handle: iox2_config_h) {
 
     let config = &mut *handle.as_type();
     ManuallyDrop::drop(&mut config.value.as_mut().value);
-    (config.deleter)(config)
+    config.delete()
 }
 
 /////////////////
@@ -386,7 +365,7 @@ pub unsafe extern "C" fn iox2_config_global_set_prefix(
             config.value.as_mut().value.global.prefix = n;
             IOX2_OK as _
         }
-        Err(e) => e as c_int,
+        Err(e) => e.into_c_int(),
     }
 }
 
@@ -425,7 +404,7 @@ pub unsafe extern "C" fn iox2_config_global_set_root_path(
             config.value.as_mut().value.global.set_root_path(&n);
             IOX2_OK as _
         }
-        Err(e) => e as c_int,
+        Err(e) => e.into_c_int(),
     }
 }
 /////////////////
@@ -472,7 +451,7 @@ pub unsafe extern "C" fn iox2_config_global_node_set_directory(
             config.value.as_mut().value.global.node.directory = n;
             IOX2_OK as _
         }
-        Err(e) => e as c_int,
+        Err(e) => e.into_c_int(),
     }
 }
 
@@ -520,7 +499,7 @@ pub unsafe extern "C" fn iox2_config_global_node_set_monitor_suffix(
             config.value.as_mut().value.global.node.monitor_suffix = n;
             IOX2_OK as _
         }
-        Err(e) => e as c_int,
+        Err(e) => e.into_c_int(),
     }
 }
 
@@ -568,7 +547,7 @@ pub unsafe extern "C" fn iox2_config_global_node_set_static_config_suffix(
             config.value.as_mut().value.global.node.static_config_suffix = n;
             IOX2_OK as _
         }
-        Err(e) => e as c_int,
+        Err(e) => e.into_c_int(),
     }
 }
 
@@ -616,7 +595,55 @@ pub unsafe extern "C" fn iox2_config_global_node_set_service_tag_suffix(
             config.value.as_mut().value.global.node.service_tag_suffix = n;
             IOX2_OK as _
         }
-        Err(e) => e as c_int,
+        Err(e) => e.into_c_int(),
+    }
+}
+
+/// Returns the suffix of the crash journals.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_node_crash_journal_suffix(
+    handle: iox2_config_h_ref,
+) -> *const c_char {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .global
+        .node
+        .crash_journal_suffix
+        .as_c_str()
+}
+
+/// Sets the suffix of the crash journals.
+///
+/// Returns: [`iox2_semantic_string_error_e`](crate::api::iox2_semantic_string_error_e) when an
+/// invalid file name was provided
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+/// * `value` - A valid file name containing the suffix
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_node_set_crash_journal_suffix(
+    handle: iox2_config_h_ref,
+    value: *const c_char,
+) -> c_int {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    match FileName::from_c_str(value) {
+        Ok(n) => {
+            config.value.as_mut().value.global.node.crash_journal_suffix = n;
+            IOX2_OK as _
+        }
+        Err(e) => e.into_c_int(),
     }
 }
 
@@ -763,7 +790,7 @@ pub unsafe extern "C" fn iox2_config_global_service_set_directory(
             config.value.as_mut().value.global.service.directory = n;
             IOX2_OK as _
         }
-        Err(e) => e as c_int,
+        Err(e) => e.into_c_int(),
     }
 }
 
@@ -817,7 +844,7 @@ pub unsafe extern "C" fn iox2_config_global_service_set_publisher_data_segment_s
                 .publisher_data_segment_suffix = n;
             IOX2_OK as _
         }
-        Err(e) => e as c_int,
+        Err(e) => e.into_c_int(),
     }
 }
 
@@ -871,7 +898,7 @@ pub unsafe extern "C" fn iox2_config_global_service_set_static_config_storage_su
                 .static_config_storage_suffix = n;
             IOX2_OK as _
         }
-        Err(e) => e as c_int,
+        Err(e) => e.into_c_int(),
     }
 }
 
@@ -925,7 +952,7 @@ pub unsafe extern "C" fn iox2_config_global_service_set_dynamic_config_storage_s
                 .dynamic_config_storage_suffix = n;
             IOX2_OK as _
         }
-        Err(e) => e as c_int,
+        Err(e) => e.into_c_int(),
     }
 }
 
@@ -1019,7 +1046,7 @@ pub unsafe extern "C" fn iox2_config_global_service_set_connection_suffix(
             config.value.as_mut().value.global.service.connection_suffix = n;
             IOX2_OK as _
         }
-        Err(e) => e as c_int,
+        Err(e) => e.into_c_int(),
     }
 }
 
@@ -1073,7 +1100,7 @@ pub unsafe extern "C" fn iox2_config_global_service_set_event_connection_suffix(
                 .event_connection_suffix = n;
             IOX2_OK as _
         }
-        Err(e) => e as c_int,
+        Err(e) => e.into_c_int(),
     }
 }
 /////////////////
@@ -1530,6 +1557,50 @@ pub unsafe extern "C" fn iox2_config_defaults_publish_subscribe_set_subscriber_e
         .publish_subscribe
         .subscriber_expired_connection_buffer = value;
 }
+
+/// Returns true when the payload of every loaned [`iox2_sample_mut_h`](crate::api::iox2_sample_mut_h)
+/// is zeroed before it is handed out.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_publish_subscribe_zero_payload_on_loan(
+    handle: iox2_config_h_ref,
+) -> bool {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .defaults
+        .publish_subscribe
+        .zero_payload_on_loan
+}
+
+/// Enables/disables the zeroing of the payload on loan
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_publish_subscribe_set_zero_payload_on_loan(
+    handle: iox2_config_h_ref,
+    value: bool,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .defaults
+        .publish_subscribe
+        .zero_payload_on_loan = value;
+}
 //////////////////////////
 // END: publish subscribe
 //////////////////////////
@@ -1903,4 +1974,569 @@ pub unsafe extern "C" fn iox2_config_defaults_event_set_event_id_max_value(
 //////////////////////////
 // END: event
 //////////////////////////
+
+//////////////////////////
+// BEGIN: request response
+///////////////////////////// Defines if the request buffer of the service safely overflows.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_enable_safe_overflow_for_requests(
+    handle: iox2_config_h_ref,
+) -> bool {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .defaults
+        .request_response
+        .enable_safe_overflow_for_requests
+}
+
+/// Enables/disables safe overflow for the request buffer
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_set_enable_safe_overflow_for_requests(
+    handle: iox2_config_h_ref,
+    value: bool,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .defaults
+        .request_response
+        .enable_safe_overflow_for_requests = value;
+}
+
+/// Defines if the response buffer of the service safely overflows.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_enable_safe_overflow_for_responses(
+    handle: iox2_config_h_ref,
+) -> bool {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .defaults
+        .request_response
+        .enable_safe_overflow_for_responses
+}
+
+/// Enables/disables safe overflow for the response buffer
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_set_enable_safe_overflow_for_responses(
+    handle: iox2_config_h_ref,
+    value: bool,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .defaults
+        .request_response
+        .enable_safe_overflow_for_responses = value;
+}
+
+/// Returns the maximum of active responses a server can hold in parallel.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_max_active_responses(
+    handle: iox2_config_h_ref,
+) -> c_size_t {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .defaults
+        .request_response
+        .max_active_responses
+}
+
+/// Sets the maximum of active responses a server can hold in parallel.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_set_max_active_responses(
+    handle: iox2_config_h_ref,
+    value: c_size_t,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .defaults
+        .request_response
+        .max_active_responses = value;
+}
+
+/// Returns the maximum of active requests a client can hold in parallel.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_max_active_requests(
+    handle: iox2_config_h_ref,
+) -> c_size_t {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .defaults
+        .request_response
+        .max_active_requests
+}
+
+/// Sets the maximum of active requests a client can hold in parallel.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_set_max_active_requests(
+    handle: iox2_config_h_ref,
+    value: c_size_t,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .defaults
+        .request_response
+        .max_active_requests = value;
+}
+
+/// Returns the maximum number of responses a client can borrow from an active request.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_max_borrowed_responses(
+    handle: iox2_config_h_ref,
+) -> c_size_t {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .defaults
+        .request_response
+        .max_borrowed_responses
+}
+
+/// Sets the maximum number of responses a client can borrow from an active request.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_set_max_borrowed_responses(
+    handle: iox2_config_h_ref,
+    value: c_size_t,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .defaults
+        .request_response
+        .max_borrowed_responses = value;
+}
+
+/// Returns the maximum number of requests a server can borrow.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_max_borrowed_requests(
+    handle: iox2_config_h_ref,
+) -> c_size_t {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .defaults
+        .request_response
+        .max_borrowed_requests
+}
+
+/// Sets the maximum number of requests a server can borrow.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_set_max_borrowed_requests(
+    handle: iox2_config_h_ref,
+    value: c_size_t,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .defaults
+        .request_response
+        .max_borrowed_requests = value;
+}
+
+/// Returns the maximum buffer size for responses for an active request.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_max_response_buffer_size(
+    handle: iox2_config_h_ref,
+) -> c_size_t {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .defaults
+        .request_response
+        .max_response_buffer_size
+}
+
+/// Sets the maximum buffer size for responses for an active request.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_set_max_response_buffer_size(
+    handle: iox2_config_h_ref,
+    value: c_size_t,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .defaults
+        .request_response
+        .max_response_buffer_size = value;
+}
+
+/// Returns the maximum buffer size for requests for a server.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_max_request_buffer_size(
+    handle: iox2_config_h_ref,
+) -> c_size_t {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .defaults
+        .request_response
+        .max_request_buffer_size
+}
+
+/// Sets the maximum buffer size for requests for a server.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_set_max_request_buffer_size(
+    handle: iox2_config_h_ref,
+    value: c_size_t,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .defaults
+        .request_response
+        .max_request_buffer_size = value;
+}
+
+/// Returns the maximum amount of supported servers.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_max_servers(
+    handle: iox2_config_h_ref,
+) -> c_size_t {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .defaults
+        .request_response
+        .max_servers
+}
+
+/// Sets the maximum amount of supported servers.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_set_max_servers(
+    handle: iox2_config_h_ref,
+    value: c_size_t,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .defaults
+        .request_response
+        .max_servers = value;
+}
+
+/// Returns the maximum amount of supported clients.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_max_clients(
+    handle: iox2_config_h_ref,
+) -> c_size_t {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .defaults
+        .request_response
+        .max_clients
+}
+
+/// Sets the maximum amount of supported clients.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_set_max_clients(
+    handle: iox2_config_h_ref,
+    value: c_size_t,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .defaults
+        .request_response
+        .max_clients = value;
+}
+
+/// Returns the maximum amount of supported [`iox2_node_h`](crate::api::iox2_node_h)s. Defines
+/// indirectly how many processes can open the service at the same time.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_max_nodes(
+    handle: iox2_config_h_ref,
+) -> c_size_t {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .defaults
+        .request_response
+        .max_nodes
+}
+
+/// Sets the maximum amount of supported [`iox2_node_h`](crate::api::iox2_node_h)s.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_set_max_nodes(
+    handle: iox2_config_h_ref,
+    value: c_size_t,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .defaults
+        .request_response
+        .max_nodes = value;
+}
+
+/// Returns how many requests a client can loan in parallel.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_client_max_loaned_requests(
+    handle: iox2_config_h_ref,
+) -> c_size_t {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .defaults
+        .request_response
+        .client_max_loaned_requests
+}
+
+/// Sets how many requests a client can loan in parallel.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_set_client_max_loaned_requests(
+    handle: iox2_config_h_ref,
+    value: c_size_t,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .defaults
+        .request_response
+        .client_max_loaned_requests = value;
+}
+
+/// Returns how many responses a server can loan in parallel for a request.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_server_max_loaned_responses_per_request(
+    handle: iox2_config_h_ref,
+) -> c_size_t {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .defaults
+        .request_response
+        .server_max_loaned_responses_per_request
+}
+
+/// Sets how many responses a server can loan in parallel for a request.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_set_server_max_loaned_responses_per_request(
+    handle: iox2_config_h_ref,
+    value: c_size_t,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .defaults
+        .request_response
+        .server_max_loaned_responses_per_request = value;
+}
+//////////////////////////
+// END: request response
+//////////////////////////
 // END C API
@@ -75,51 +75,22 @@ pub type iox2_file_descriptor_ptr = *const CFileDescriptor;
 /// The mutable pointer to the underlying `FileDescriptor`
 pub type iox2_file_descriptor_ptr_mut = *mut CFileDescriptor;
 
-impl AssertNonNullHandle for iox2_file_descriptor_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_file_descriptor_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_file_descriptor_h {
-    type Target = *mut iox2_file_descriptor_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_file_descriptor_h_ref {
-    type Target = *mut iox2_file_descriptor_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
 // END type definition
 
 // BEGIN C API
 
-/// Casts a [`iox2_file_descriptor_h`] into an [`iox2_file_descriptor_ptr`]. The result
+/// Casts a [`iox2_file_descriptor_h_ref`] into an [`iox2_file_descriptor_ptr`]. The result
 /// is valid as long as the source is valid.
 ///
 /// # Safety
 ///
 /// * `handle` must be valid and acquired with [`iox2_file_descriptor_new()`].
+/// * `handle` is still valid after the call to this function.
 #[no_mangle]
 pub unsafe extern "C" fn iox2_cast_file_descriptor_ptr(
-    handle: iox2_file_descriptor_h,
+    handle: iox2_file_descriptor_h_ref,
 ) -> iox2_file_descriptor_ptr {
-    debug_assert!(!handle.is_null());
+    handle.assert_non_null();
 
     (*handle.as_type()).value.as_ref()
 }
@@ -135,7 +106,7 @@ pub unsafe extern "C" fn iox2_file_descriptor_drop(handle: iox2_file_descriptor_
 
     let file_descriptor = &mut *handle.as_type();
     core::ptr::drop_in_place(file_descriptor.value.as_option_mut());
-    (file_descriptor.deleter)(file_descriptor);
+    file_descriptor.delete();
 }
 
 /// Returns the underlying native file descriptor value. When the
@@ -0,0 +1,141 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tracks the validity of every handle that is handed out to the C API when the
+//! `handle_validation` feature is enabled. Every handle is tagged with the type it belongs to
+//! and a generation counter. Every C API entry point verifies the tag before the handle is
+//! cast into its underlying type, so that a use-after-drop or a handle of the wrong type
+//! terminates the process with a diagnostic message instead of causing undefined behavior.
+//!
+//! Handles of types which are not `Send` are additionally bound to the thread that created
+//! them, unless the call is serialized with the `thread_safe_handles` feature, see
+//! [`thread_safety`](crate::api::thread_safety).
+//!
+//! The bookkeeping requires a global lock, therefore it is meant for debug builds only.
+//! Without the feature all functions are no-ops.
+
+#[cfg(feature = "handle_validation")]
+mod registry {
+    use crate::api::thread_safety;
+    use iceoryx2_bb_log::fatal_panic;
+    use std::collections::HashMap;
+    use std::sync::{Mutex, MutexGuard, OnceLock};
+    use std::thread::ThreadId;
+
+    #[derive(Debug, Clone, Copy)]
+    struct HandleTag {
+        type_name: &'static str,
+        generation: u64,
+        is_alive: bool,
+        // set for types which are not `Send`
+        owner: Option<ThreadId>,
+    }
+
+    #[derive(Debug, Default)]
+    struct Registry {
+        tags: HashMap<usize, HandleTag>,
+        generation: u64,
+    }
+
+    static REGISTRY: OnceLock<Mutex<Registry>> = OnceLock::new();
+
+    fn registry() -> MutexGuard<'static, Registry> {
+        match REGISTRY
+            .get_or_init(|| Mutex::new(Registry::default()))
+            .lock()
+        {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    pub(crate) fn register(address: usize, type_name: &'static str, is_send: bool) {
+        let mut registry = registry();
+        if let Some(tag) = registry.tags.get(&address) {
+            if tag.is_alive && tag.type_name == type_name {
+                return;
+            }
+        }
+
+        registry.generation += 1;
+        let generation = registry.generation;
+        registry.tags.insert(
+            address,
+            HandleTag {
+                type_name,
+                generation,
+                is_alive: true,
+                owner: (!is_send).then(|| std::thread::current().id()),
+            },
+        );
+    }
+
+    pub(crate) fn verify(address: usize, type_name: &'static str) {
+        let registry = registry();
+        match registry.tags.get(&address) {
+            None => {
+                drop(registry);
+                fatal_panic!(from "handle_validation::verify()",
+                    "The handle {:#x} was never handed out by the C API but is used as {}.",
+                    address, type_name);
+            }
+            Some(tag) if !tag.is_alive => {
+                let tag = *tag;
+                drop(registry);
+                fatal_panic!(from "handle_validation::verify()",
+                    "The handle {:#x} of {} (generation {}) is used after it was dropped.",
+                    address, tag.type_name, tag.generation);
+            }
+            Some(tag) if tag.type_name != type_name => {
+                let tag = *tag;
+                drop(registry);
+                fatal_panic!(from "handle_validation::verify()",
+                    "The handle {:#x} of {} (generation {}) is used as {}.",
+                    address, tag.type_name, tag.generation, type_name);
+            }
+            Some(tag) => {
+                if let Some(owner) = tag.owner {
+                    let current = std::thread::current().id();
+                    if owner != current && !thread_safety::is_serialized() {
+                        let tag = *tag;
+                        drop(registry);
+                        fatal_panic!(from "handle_validation::verify()",
+                            "The handle {:#x} of {} (generation {}) is not thread-safe but is used in {:?} instead of {:?}, the thread that created it.",
+                            address, tag.type_name, tag.generation, current, owner);
+                    }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn unregister(address: usize, type_name: &'static str) {
+        verify(address, type_name);
+        if let Some(tag) = registry().tags.get_mut(&address) {
+            tag.is_alive = false;
+        }
+    }
+}
+
+#[cfg(feature = "handle_validation")]
+pub(crate) use registry::*;
+
+#[cfg(not(feature = "handle_validation"))]
+#[inline(always)]
+pub(crate) fn register(_address: usize, _type_name: &'static str, _is_send: bool) {}
+
+#[cfg(not(feature = "handle_validation"))]
+#[inline(always)]
+pub(crate) fn verify(_address: usize, _type_name: &'static str) {}
+
+#[cfg(not(feature = "handle_validation"))]
+#[inline(always)]
+pub(crate) fn unregister(_address: usize, _type_name: &'static str) {}
@@ -12,6 +12,7 @@
 
 #![allow(non_camel_case_types)]
 
+use crate::api::thread_safety::serialize;
 use crate::api::{
     iox2_callback_context, iox2_event_id_t, iox2_service_type_e, iox2_unique_listener_id_h,
     iox2_unique_listener_id_t, AssertNonNullHandle, HandleToType, IntoCInt, IOX2_OK,
@@ -102,37 +103,6 @@ pub type iox2_listener_h = *mut iox2_listener_h_t;
 /// The non-owning handle for `iox2_listener_t`. Passing the handle to an function does not transfers the ownership.
 pub type iox2_listener_h_ref = *const iox2_listener_h;
 
-impl AssertNonNullHandle for iox2_listener_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_listener_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_listener_h {
-    type Target = *mut iox2_listener_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_listener_h_ref {
-    type Target = *mut iox2_listener_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 pub type iox2_listener_wait_all_callback =
     extern "C" fn(*const iox2_event_id_t, iox2_callback_context);
 
@@ -174,6 +144,8 @@ pub unsafe extern "C" fn iox2_listener_wait_error_string(
 ///   [`iox2_port_factory_listener_builder_create`](crate::iox2_port_factory_listener_builder_create)!
 #[no_mangle]
 pub unsafe extern "C" fn iox2_listener_drop(listener_handle: iox2_listener_h) {
+    let _guard = serialize();
+
     listener_handle.assert_non_null();
 
     let listener = &mut *listener_handle.as_type();
@@ -186,7 +158,7 @@ pub unsafe extern "C" fn iox2_listener_drop(listener_handle: iox2_listener_h) {
             ManuallyDrop::drop(&mut listener.value.as_mut().local);
         }
     }
-    (listener.deleter)(listener);
+    listener.delete();
 }
 
 /// Returns the underlying non-owning file descriptor of the [`iox2_listener_h`].
@@ -202,6 +174,8 @@ pub unsafe extern "C" fn iox2_listener_drop(listener_handle: iox2_listener_h) {
 pub unsafe extern "C" fn iox2_listener_get_file_descriptor(
     listener_handle: iox2_listener_h_ref,
 ) -> iox2_file_descriptor_ptr {
+    let _guard = serialize();
+
     listener_handle.assert_non_null();
 
     let listener = &mut *listener_handle.as_type();
@@ -234,6 +208,8 @@ pub unsafe extern "C" fn iox2_listener_try_wait_all(
     callback: iox2_listener_wait_all_callback,
     callback_ctx: iox2_callback_context,
 ) -> c_int {
+    let _guard = serialize();
+
     listener_handle.assert_non_null();
 
     let listener = &mut *listener_handle.as_type();
@@ -321,6 +297,8 @@ pub unsafe extern "C" fn iox2_listener_id(
     id_struct_ptr: *mut iox2_unique_listener_id_t,
     id_handle_ptr: *mut iox2_unique_listener_id_h,
 ) {
+    let _guard = serialize();
+
     listener_handle.assert_non_null();
     debug_assert!(!id_handle_ptr.is_null());
 
@@ -359,6 +337,8 @@ pub unsafe extern "C" fn iox2_listener_deadline(
     seconds: *mut u64,
     nanoseconds: *mut u32,
 ) -> bool {
+    let _guard = serialize();
+
     listener_handle.assert_non_null();
     debug_assert!(!seconds.is_null());
     debug_assert!(!nanoseconds.is_null());
@@ -437,6 +417,8 @@ pub unsafe extern "C" fn iox2_listener_try_wait_one(
     event_id: *mut iox2_event_id_t,
     has_received_one: *mut bool,
 ) -> c_int {
+    let _guard = serialize();
+
     listener_handle.assert_non_null();
     debug_assert!(!event_id.is_null());
     debug_assert!(!has_received_one.is_null());
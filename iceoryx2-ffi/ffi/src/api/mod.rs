@@ -26,6 +26,7 @@ mod attribute_verifier;
 mod config;
 mod event_id;
 mod file_descriptor;
+mod handle_validation;
 mod iceoryx2_settings;
 mod listener;
 mod log;
@@ -55,7 +56,9 @@ mod signal_handling_mode;
 mod static_config;
 mod static_config_event;
 mod static_config_publish_subscribe;
+mod static_config_request_response;
 mod subscriber;
+mod thread_safety;
 mod unique_listener_id;
 mod unique_notifier_id;
 mod unique_publisher_id;
@@ -100,6 +103,7 @@ pub use signal_handling_mode::*;
 pub use static_config::*;
 pub use static_config_event::*;
 pub use static_config_publish_subscribe::*;
+pub use static_config_request_response::*;
 pub use subscriber::*;
 pub use unique_listener_id::*;
 pub use unique_notifier_id::*;
@@ -128,7 +128,7 @@ impl NodeUnion {
 #[repr(C)]
 #[repr(align(8))] // alignment of Option<NodeUnion>
 pub struct iox2_node_storage_t {
-    internal: [u8; 16], // magic number obtained with size_of::<Option<NodeUnion>>()
+    internal: [u8; 24], // magic number obtained with size_of::<Option<NodeUnion>>()
 }
 
 #[repr(C)]
@@ -158,37 +158,6 @@ pub type iox2_node_h = *mut iox2_name_h_t;
 /// The non-owning handle for `iox2_node_t`. Passing the handle to an function does not transfers the ownership.
 pub type iox2_node_h_ref = *const iox2_node_h;
 
-impl AssertNonNullHandle for iox2_node_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_node_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_node_h {
-    type Target = *mut iox2_node_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_node_h_ref {
-    type Target = *mut iox2_node_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub enum iox2_node_state_e {
@@ -261,6 +230,27 @@ pub unsafe extern "C" fn iox2_node_wait_failure_string(
     error.as_const_cstr().as_ptr() as *const c_char
 }
 
+/// Returns a string representation of the [`iox2_node_cleanup_failure_e`] error code.
+///
+/// # Arguments
+///
+/// * `error` - The error value for which a description should be returned
+///
+/// # Returns
+///
+/// A pointer to a null-terminated string containing the error message.
+/// The string is stored in the .rodata section of the binary.
+///
+/// # Safety
+///
+/// * The returned pointer is valid as long as the program runs and must not be modified or freed
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_cleanup_failure_string(
+    error: iox2_node_cleanup_failure_e,
+) -> *const c_char {
+    error.as_const_cstr().as_ptr() as *const c_char
+}
+
 /// Returns the [`iox2_node_name_ptr`](crate::iox2_node_name_ptr), an immutable pointer to the node name.
 ///
 /// # Safety
@@ -279,7 +269,25 @@ pub unsafe extern "C" fn iox2_node_name(node_handle: iox2_node_h_ref) -> iox2_no
 }
 
 /// Wait until the provided cycle time has passed and returns a [`iox2_node_wait_failure_e`] enum containing the event that
-/// has occurred.
+/// has occurred. It is the C counterpart of `Node::wait()` and drives the cyclic main loop of an application.
+///
+/// # Arguments
+///
+/// * `node_handle` - Must be a valid [`iox2_node_h_ref`] obtained by [`iox2_node_builder_create`](crate::iox2_node_builder_create)
+/// * `cycle_time_sec` - The seconds part of the cycle time
+/// * `cycle_time_nsec` - The nanoseconds part of the cycle time
+///
+/// # Returns
+///
+/// * [`IOX2_OK`] when the cycle time has passed
+/// * [`iox2_node_wait_failure_e::TERMINATION_REQUEST`] when a `SIGTERM` was received and the node handles termination requests
+/// * [`iox2_node_wait_failure_e::INTERRUPT`] when a `SIGINT` interrupted the wait
+///
+/// ```c
+/// while (iox2_node_wait(&node_handle, 1, 0) == IOX2_OK) {
+///     // do the work of one cycle
+/// }
+/// ```
 ///
 /// # Safety
 ///
@@ -607,7 +615,7 @@ pub unsafe extern "C" fn iox2_node_drop(node_handle: iox2_node_h) {
             ManuallyDrop::drop(&mut node.value.as_mut().local);
         }
     }
-    (node.deleter)(node);
+    node.delete();
 }
 
 // END C API
@@ -16,6 +16,7 @@ use crate::api::{
     iox2_config_h_ref, iox2_node_h, iox2_node_name_ptr, iox2_node_t, iox2_service_type_e,
     AssertNonNullHandle, HandleToType, IntoCInt, NodeUnion, IOX2_OK,
 };
+use crate::c_size_t;
 
 use iceoryx2::node::NodeCreationFailure;
 use iceoryx2::prelude::*;
@@ -36,6 +37,7 @@ use super::iox2_signal_handling_mode_e;
 pub enum iox2_node_creation_failure_e {
     INSUFFICIENT_PERMISSIONS = IOX2_OK as isize + 1,
     INTERNAL_ERROR,
+    INVALID_SECURITY_DESCRIPTOR,
 }
 
 impl IntoCInt for NodeCreationFailure {
@@ -45,6 +47,9 @@ impl IntoCInt for NodeCreationFailure {
                 iox2_node_creation_failure_e::INSUFFICIENT_PERMISSIONS
             }
             NodeCreationFailure::InternalError => iox2_node_creation_failure_e::INTERNAL_ERROR,
+            NodeCreationFailure::InvalidSecurityDescriptor => {
+                iox2_node_creation_failure_e::INVALID_SECURITY_DESCRIPTOR
+            }
         }) as c_int
     }
 }
@@ -68,37 +73,6 @@ pub type iox2_node_builder_h = *mut iox2_node_builder_h_t;
 /// The non-owning handle for `iox2_node_builder_t`. Passing the handle to an function does not transfers the ownership.
 pub type iox2_node_builder_h_ref = *const iox2_node_builder_h;
 
-impl AssertNonNullHandle for iox2_node_builder_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_node_builder_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_node_builder_h {
-    type Target = *mut iox2_node_builder_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_node_builder_h_ref {
-    type Target = *mut iox2_node_builder_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END type definition
 
 // BEGIN C API
@@ -205,6 +179,105 @@ pub unsafe extern "C" fn iox2_node_builder_set_signal_handling_mode(
     node_builder_struct.set(node_builder);
 }
 
+/// Enables the crash journal of the [`iox2_node_h`]. When the process crashes, the journal
+/// allows the next cleanup pass to reclaim exactly the resources of the node.
+///
+/// # Arguments
+///
+/// * `node_builder_handle` - Must be a valid [`iox2_node_builder_h_ref`] obtained by [`iox2_node_builder_new`].
+///
+/// # Safety
+///
+/// * `node_builder_handle` must be a valid handle
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_builder_set_crash_journal(
+    node_builder_handle: iox2_node_builder_h_ref,
+    value: bool,
+) {
+    node_builder_handle.assert_non_null();
+
+    let node_builder_struct = &mut *node_builder_handle.as_type();
+
+    let node_builder = node_builder_struct.take().unwrap();
+    let node_builder = node_builder.crash_journal(value);
+    node_builder_struct.set(node_builder);
+}
+
+/// Sets the maximum amount of bytes all payload data segments of the ports of the
+/// [`iox2_node_h`] are allowed to occupy.
+///
+/// # Arguments
+///
+/// * `node_builder_handle` - Must be a valid [`iox2_node_builder_h_ref`] obtained by [`iox2_node_builder_new`].
+///
+/// # Safety
+///
+/// * `node_builder_handle` must be a valid handle
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_builder_set_max_shared_memory_bytes(
+    node_builder_handle: iox2_node_builder_h_ref,
+    value: c_size_t,
+) {
+    node_builder_handle.assert_non_null();
+
+    let node_builder_struct = &mut *node_builder_handle.as_type();
+
+    let node_builder = node_builder_struct.take().unwrap();
+    let quota = node_builder
+        .__internal_quota()
+        .max_shared_memory_bytes(value);
+    let node_builder = node_builder.quota(quota);
+    node_builder_struct.set(node_builder);
+}
+
+/// Sets the maximum amount of ports the [`iox2_node_h`] can own at the same time.
+///
+/// # Arguments
+///
+/// * `node_builder_handle` - Must be a valid [`iox2_node_builder_h_ref`] obtained by [`iox2_node_builder_new`].
+///
+/// # Safety
+///
+/// * `node_builder_handle` must be a valid handle
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_builder_set_max_ports(
+    node_builder_handle: iox2_node_builder_h_ref,
+    value: c_size_t,
+) {
+    node_builder_handle.assert_non_null();
+
+    let node_builder_struct = &mut *node_builder_handle.as_type();
+
+    let node_builder = node_builder_struct.take().unwrap();
+    let quota = node_builder.__internal_quota().max_ports(value);
+    let node_builder = node_builder.quota(quota);
+    node_builder_struct.set(node_builder);
+}
+
+/// Sets the maximum amount of services the [`iox2_node_h`] can create.
+///
+/// # Arguments
+///
+/// * `node_builder_handle` - Must be a valid [`iox2_node_builder_h_ref`] obtained by [`iox2_node_builder_new`].
+///
+/// # Safety
+///
+/// * `node_builder_handle` must be a valid handle
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_builder_set_max_services(
+    node_builder_handle: iox2_node_builder_h_ref,
+    value: c_size_t,
+) {
+    node_builder_handle.assert_non_null();
+
+    let node_builder_struct = &mut *node_builder_handle.as_type();
+
+    let node_builder = node_builder_struct.take().unwrap();
+    let quota = node_builder.__internal_quota().max_services(value);
+    let node_builder = node_builder.quota(quota);
+    node_builder_struct.set(node_builder);
+}
+
 /// Sets the node config for the builder
 ///
 /// Returns IOX2_OK
@@ -236,7 +309,7 @@ unsafe fn iox2_node_builder_drop(node_builder_handle: iox2_node_builder_h) {
 
     let node_builder = &mut *node_builder_handle.as_type();
     core::ptr::drop_in_place(node_builder.value.as_option_mut());
-    (node_builder.deleter)(node_builder);
+    node_builder.delete();
 }
 
 /// Creates a node and consumes the builder
@@ -33,6 +33,13 @@ pub struct iox2_node_id_t {
     deleter: fn(*mut iox2_node_id_t),
 }
 
+impl iox2_node_id_t {
+    pub(super) fn init(&mut self, value: NodeId, deleter: fn(*mut iox2_node_id_t)) {
+        self.value.init(value);
+        self.deleter = deleter;
+    }
+}
+
 pub struct iox2_node_id_h_t;
 /// The owning handle for `iox2_node_id_t`. Passing the handle to an function transfers the ownership.
 pub type iox2_node_id_h = *mut iox2_node_id_h_t;
@@ -46,37 +53,6 @@ pub type iox2_node_id_ptr = *const NodeId;
 /// The mutable pointer to the underlying `NodeId`
 pub type iox2_node_id_ptr_mut = *mut NodeId;
 
-impl AssertNonNullHandle for iox2_node_id_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_node_id_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_node_id_h {
-    type Target = *mut iox2_node_id_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_node_id_h_ref {
-    type Target = *mut iox2_node_id_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END type definition
 
 // BEGIN C API
@@ -140,6 +116,67 @@ pub unsafe extern "C" fn iox2_node_id_clone_from_handle(
     iox2_node_id_clone_from_ptr(node_id_struct_ptr, node_id_ptr, node_id_handle_ptr);
 }
 
+/// Returns the high bits of the underlying value of the [`iox2_node_id_ptr`]. In contrast to
+/// [`iox2_node_id_value_high()`] it can be used directly on the pointer that is provided to
+/// the [`iox2_node_list_callback`](crate::iox2_node_list_callback) without cloning it into
+/// an [`iox2_node_id_h`] first.
+///
+/// # Safety
+///
+/// * `node_id_ptr` - Must be a valid [`iox2_node_id_ptr`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_id_ptr_value_high(node_id_ptr: iox2_node_id_ptr) -> u64 {
+    debug_assert!(!node_id_ptr.is_null());
+
+    ((*node_id_ptr).value() >> 64) as u64
+}
+
+/// Returns the low bits of the underlying value of the [`iox2_node_id_ptr`].
+///
+/// # Safety
+///
+/// * `node_id_ptr` - Must be a valid [`iox2_node_id_ptr`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_id_ptr_value_low(node_id_ptr: iox2_node_id_ptr) -> u64 {
+    debug_assert!(!node_id_ptr.is_null());
+
+    (*node_id_ptr).value() as u64
+}
+
+/// Returns the process id of the [`iox2_node_id_ptr`].
+///
+/// # Safety
+///
+/// * `node_id_ptr` - Must be a valid [`iox2_node_id_ptr`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_id_ptr_pid(node_id_ptr: iox2_node_id_ptr) -> i32 {
+    debug_assert!(!node_id_ptr.is_null());
+
+    (*node_id_ptr).pid().value() as _
+}
+
+/// Returns the creation time of the [`iox2_node_id_ptr`].
+///
+/// # Safety
+///
+/// * `node_id_ptr` - Must be a valid [`iox2_node_id_ptr`]
+/// * `seconds` - Must point to a valid memory location
+/// * `nanoseconds` - Must point to a valid memory location
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_id_ptr_creation_time(
+    node_id_ptr: iox2_node_id_ptr,
+    seconds: *mut u64,
+    nanoseconds: *mut u32,
+) {
+    debug_assert!(!node_id_ptr.is_null());
+    debug_assert!(!seconds.is_null());
+    debug_assert!(!nanoseconds.is_null());
+
+    let creation_time = (*node_id_ptr).creation_time();
+    *seconds = creation_time.seconds();
+    *nanoseconds = creation_time.nanoseconds();
+}
+
 /// Returns the high bits of the underlying value of the [`iox2_node_id_h`].
 ///
 /// # Safety
@@ -150,7 +187,7 @@ pub unsafe extern "C" fn iox2_node_id_value_high(node_id_handle: iox2_node_id_h_
     node_id_handle.assert_non_null();
 
     let node_id = &mut *node_id_handle.as_type();
-    (node_id.value.as_ref().value() >> 64) as u64
+    iox2_node_id_ptr_value_high(node_id.value.as_ref())
 }
 
 /// Returns the low bits of the underlying value of the [`iox2_node_id_h`].
@@ -163,7 +200,7 @@ pub unsafe extern "C" fn iox2_node_id_value_low(node_id_handle: iox2_node_id_h_r
     node_id_handle.assert_non_null();
 
     let node_id = &mut *node_id_handle.as_type();
-    node_id.value.as_ref().value() as u64
+    iox2_node_id_ptr_value_low(node_id.value.as_ref())
 }
 
 /// Returns the process id of the [`iox2_node_id_h`].
@@ -176,7 +213,7 @@ pub unsafe extern "C" fn iox2_node_id_pid(node_id_handle: iox2_node_id_h_ref) ->
     node_id_handle.assert_non_null();
 
     let node_id = &mut *node_id_handle.as_type();
-    node_id.value.as_ref().pid().value() as _
+    iox2_node_id_ptr_pid(node_id.value.as_ref())
 }
 
 /// Returns the creation time of the [`iox2_node_id_h`].
@@ -193,12 +230,9 @@ pub unsafe extern "C" fn iox2_node_id_creation_time(
     nanoseconds: *mut u32,
 ) {
     node_id_handle.assert_non_null();
-    debug_assert!(!seconds.is_null());
-    debug_assert!(!nanoseconds.is_null());
 
     let node_id = &mut *node_id_handle.as_type();
-    *seconds = node_id.value.as_ref().creation_time().seconds();
-    *nanoseconds = node_id.value.as_ref().creation_time().nanoseconds();
+    iox2_node_id_ptr_creation_time(node_id.value.as_ref(), seconds, nanoseconds);
 }
 
 /// Takes ownership of the handle to delete and remove the underlying resources of a
@@ -214,7 +248,7 @@ pub unsafe extern "C" fn iox2_node_id_drop(node_id_handle: iox2_node_id_h) {
     let node_id = &mut *node_id_handle.as_type();
 
     core::ptr::drop_in_place(node_id.value.as_option_mut());
-    (node_id.deleter)(node_id);
+    node_id.delete();
 }
 
 // END C API
@@ -51,37 +51,6 @@ pub type iox2_node_name_ptr = *const NodeName;
 /// The mutable pointer to the underlying `NodeName`
 pub type iox2_node_name_ptr_mut = *mut NodeName;
 
-impl AssertNonNullHandle for iox2_node_name_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_node_name_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_node_name_h {
-    type Target = *mut iox2_node_name_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_node_name_h_ref {
-    type Target = *mut iox2_node_name_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END type definition
 
 // BEGIN C API
@@ -152,11 +121,11 @@ pub unsafe extern "C" fn iox2_node_name_new(
     IOX2_OK
 }
 
-/// This function casts a [`iox2_node_name_h`] into a [`iox2_node_name_ptr`]
+/// This function casts a [`iox2_node_name_h_ref`] into a [`iox2_node_name_ptr`]
 ///
 /// # Arguments
 ///
-/// * `node_name_handle` obtained by [`iox2_node_name_new`]
+/// * `node_name_handle` - Must be a valid [`iox2_node_name_h_ref`] obtained by [`iox2_node_name_new`]
 ///
 /// Returns a [`iox2_node_name_ptr`]
 ///
@@ -166,9 +135,9 @@ pub unsafe extern "C" fn iox2_node_name_new(
 /// * The `node_name_handle` is still valid after the call to this function.
 #[no_mangle]
 pub unsafe extern "C" fn iox2_cast_node_name_ptr(
-    node_name_handle: iox2_node_name_h,
+    node_name_handle: iox2_node_name_h_ref,
 ) -> iox2_node_name_ptr {
-    debug_assert!(!node_name_handle.is_null());
+    node_name_handle.assert_non_null();
 
     (*node_name_handle.as_type()).value.as_ref()
 }
@@ -224,7 +193,7 @@ pub unsafe extern "C" fn iox2_node_name_drop(node_name_handle: iox2_node_name_h)
     let node_name = &mut *node_name_handle.as_type();
 
     core::ptr::drop_in_place(node_name.value.as_option_mut());
-    (node_name.deleter)(node_name);
+    node_name.delete();
 }
 
 // END C API
@@ -12,6 +12,7 @@
 
 #![allow(non_camel_case_types)]
 
+use crate::api::thread_safety::serialize;
 use crate::api::{
     c_size_t, iox2_event_id_t, iox2_service_type_e, iox2_unique_notifier_id_h,
     iox2_unique_notifier_id_t, AssertNonNullHandle, HandleToType, IntoCInt, IOX2_OK,
@@ -102,37 +103,6 @@ pub type iox2_notifier_h = *mut iox2_notifier_h_t;
 /// The non-owning handle for `iox2_notifier_t`. Passing the handle to an function does not transfers the ownership.
 pub type iox2_notifier_h_ref = *const iox2_notifier_h;
 
-impl AssertNonNullHandle for iox2_notifier_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_notifier_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_notifier_h {
-    type Target = *mut iox2_notifier_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_notifier_h_ref {
-    type Target = *mut iox2_notifier_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END type definition
 
 // BEGIN C API
@@ -172,6 +142,8 @@ pub unsafe extern "C" fn iox2_notifier_id(
     id_struct_ptr: *mut iox2_unique_notifier_id_t,
     id_handle_ptr: *mut iox2_unique_notifier_id_h,
 ) {
+    let _guard = serialize();
+
     notifier_handle.assert_non_null();
     debug_assert!(!id_handle_ptr.is_null());
 
@@ -210,6 +182,8 @@ pub unsafe extern "C" fn iox2_notifier_deadline(
     seconds: *mut u64,
     nanoseconds: *mut u32,
 ) -> bool {
+    let _guard = serialize();
+
     notifier_handle.assert_non_null();
     debug_assert!(!seconds.is_null());
     debug_assert!(!nanoseconds.is_null());
@@ -248,6 +222,8 @@ pub unsafe extern "C" fn iox2_notifier_notify(
     notifier_handle: iox2_notifier_h_ref,
     number_of_notified_listener_ptr: *mut c_size_t,
 ) -> c_int {
+    let _guard = serialize();
+
     notifier_handle.assert_non_null();
 
     let notifier = &mut *notifier_handle.as_type();
@@ -293,6 +269,8 @@ pub unsafe extern "C" fn iox2_notifier_notify_with_custom_event_id(
     custom_event_id_ptr: *const iox2_event_id_t,
     number_of_notified_listener_ptr: *mut c_size_t,
 ) -> c_int {
+    let _guard = serialize();
+
     notifier_handle.assert_non_null();
     debug_assert!(!custom_event_id_ptr.is_null());
 
@@ -339,6 +317,8 @@ pub unsafe extern "C" fn iox2_notifier_notify_with_custom_event_id(
 ///   [`iox2_port_factory_notifier_builder_create`](crate::iox2_port_factory_notifier_builder_create)!
 #[no_mangle]
 pub unsafe extern "C" fn iox2_notifier_drop(notifier_handle: iox2_notifier_h) {
+    let _guard = serialize();
+
     debug_assert!(!notifier_handle.is_null());
 
     let notifier = &mut *notifier_handle.as_type();
@@ -351,7 +331,7 @@ pub unsafe extern "C" fn iox2_notifier_drop(notifier_handle: iox2_notifier_h) {
             ManuallyDrop::drop(&mut notifier.value.as_mut().local);
         }
     }
-    (notifier.deleter)(notifier);
+    notifier.delete();
 }
 
 // END C API
@@ -81,37 +81,6 @@ pub type iox2_port_factory_event_h = *mut iox2_port_factory_event_h_t;
 /// The non-owning handle for `iox2_port_factory_event_t`. Passing the handle to an function does not transfers the ownership.
 pub type iox2_port_factory_event_h_ref = *const iox2_port_factory_event_h;
 
-impl AssertNonNullHandle for iox2_port_factory_event_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_port_factory_event_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_port_factory_event_h {
-    type Target = *mut iox2_port_factory_event_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_port_factory_event_h_ref {
-    type Target = *mut iox2_port_factory_event_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END type definition
 
 // BEGIN C API
@@ -322,7 +291,7 @@ pub unsafe extern "C" fn iox2_port_factory_event_drop(
             ManuallyDrop::drop(&mut port_factory.value.as_mut().local);
         }
     }
-    (port_factory.deleter)(port_factory);
+    port_factory.delete();
 }
 
 // END C API
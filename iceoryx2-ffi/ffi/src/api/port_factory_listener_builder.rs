@@ -35,6 +35,7 @@ use core::mem::ManuallyDrop;
 pub enum iox2_listener_create_error_e {
     EXCEEDS_MAX_SUPPORTED_LISTENERS = IOX2_OK as isize + 1,
     RESOURCE_CREATION_FAILED,
+    EXCEEDS_NODE_QUOTA,
 }
 
 impl IntoCInt for ListenerCreateError {
@@ -46,6 +47,9 @@ impl IntoCInt for ListenerCreateError {
             ListenerCreateError::ResourceCreationFailed => {
                 iox2_listener_create_error_e::RESOURCE_CREATION_FAILED
             }
+            ListenerCreateError::ExceedsNodeQuota => {
+                iox2_listener_create_error_e::EXCEEDS_NODE_QUOTA
+            }
         }) as c_int
     }
 }
@@ -71,7 +75,7 @@ impl PortFactoryListenerBuilderUnion {
 #[repr(C)]
 #[repr(align(8))] // alignment of Option<PortFactoryListenerBuilderUnion>
 pub struct iox2_port_factory_listener_builder_storage_t {
-    internal: [u8; 24], // magic number obtained with size_of::<Option<PortFactoryListenerBuilderUnion>>()
+    internal: [u8; 32], // magic number obtained with size_of::<Option<PortFactoryListenerBuilderUnion>>()
 }
 
 #[repr(C)]
@@ -101,37 +105,6 @@ pub type iox2_port_factory_listener_builder_h = *mut iox2_port_factory_listener_
 /// The non-owning handle for `iox2_port_factory_listener_builder_t`. Passing the handle to an function does not transfers the ownership.
 pub type iox2_port_factory_listener_builder_h_ref = *const iox2_port_factory_listener_builder_h;
 
-impl AssertNonNullHandle for iox2_port_factory_listener_builder_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_port_factory_listener_builder_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_port_factory_listener_builder_h {
-    type Target = *mut iox2_port_factory_listener_builder_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_port_factory_listener_builder_h_ref {
-    type Target = *mut iox2_port_factory_listener_builder_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END type definition
 
 // BEGIN C API
@@ -201,7 +174,7 @@ pub unsafe extern "C" fn iox2_port_factory_listener_builder_create(
         .unwrap_or_else(|| {
             panic!("Trying to use an invalid 'iox2_port_factory_listener_builder_h'!")
         });
-    (listener_builder_struct.deleter)(listener_builder_struct);
+    listener_builder_struct.delete();
 
     match service_type {
         iox2_service_type_e::IPC => {
@@ -216,6 +189,7 @@ pub unsafe extern "C" fn iox2_port_factory_listener_builder_create(
                     );
                 }
                 Err(error) => {
+                    deleter(listener_struct_ptr);
                     return error.into_c_int();
                 }
             }
@@ -232,6 +206,7 @@ pub unsafe extern "C" fn iox2_port_factory_listener_builder_create(
                     );
                 }
                 Err(error) => {
+                    deleter(listener_struct_ptr);
                     return error.into_c_int();
                 }
             }
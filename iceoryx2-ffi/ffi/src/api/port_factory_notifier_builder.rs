@@ -34,6 +34,7 @@ use core::mem::ManuallyDrop;
 #[derive(Copy, Clone, CStrRepr)]
 pub enum iox2_notifier_create_error_e {
     EXCEEDS_MAX_SUPPORTED_NOTIFIERS = IOX2_OK as isize + 1,
+    EXCEEDS_NODE_QUOTA,
 }
 
 impl IntoCInt for NotifierCreateError {
@@ -42,6 +43,9 @@ impl IntoCInt for NotifierCreateError {
             NotifierCreateError::ExceedsMaxSupportedNotifiers => {
                 iox2_notifier_create_error_e::EXCEEDS_MAX_SUPPORTED_NOTIFIERS
             }
+            NotifierCreateError::ExceedsNodeQuota => {
+                iox2_notifier_create_error_e::EXCEEDS_NODE_QUOTA
+            }
         }) as c_int
     }
 }
@@ -97,37 +101,6 @@ pub type iox2_port_factory_notifier_builder_h = *mut iox2_port_factory_notifier_
 /// The non-owning handle for `iox2_port_factory_notifier_builder_t`. Passing the handle to an function does not transfers the ownership.
 pub type iox2_port_factory_notifier_builder_h_ref = *const iox2_port_factory_notifier_builder_h;
 
-impl AssertNonNullHandle for iox2_port_factory_notifier_builder_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_port_factory_notifier_builder_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_port_factory_notifier_builder_h {
-    type Target = *mut iox2_port_factory_notifier_builder_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_port_factory_notifier_builder_h_ref {
-    type Target = *mut iox2_port_factory_notifier_builder_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END type definition
 
 // BEGIN C API
@@ -235,7 +208,7 @@ pub unsafe extern "C" fn iox2_port_factory_notifier_builder_create(
         .unwrap_or_else(|| {
             panic!("Trying to use an invalid 'iox2_port_factory_notifier_builder_h'!")
         });
-    (notifier_builder_struct.deleter)(notifier_builder_struct);
+    notifier_builder_struct.delete();
 
     match service_type {
         iox2_service_type_e::IPC => {
@@ -250,6 +223,7 @@ pub unsafe extern "C" fn iox2_port_factory_notifier_builder_create(
                     );
                 }
                 Err(error) => {
+                    deleter(notifier_struct_ptr);
                     return error.into_c_int();
                 }
             }
@@ -266,6 +240,7 @@ pub unsafe extern "C" fn iox2_port_factory_notifier_builder_create(
                     );
                 }
                 Err(error) => {
+                    deleter(notifier_struct_ptr);
                     return error.into_c_int();
                 }
             }
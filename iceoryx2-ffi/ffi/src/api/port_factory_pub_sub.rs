@@ -85,37 +85,6 @@ pub type iox2_port_factory_pub_sub_h = *mut iox2_port_factory_pub_sub_h_t;
 /// The non-owning handle for `iox2_port_factory_pub_sub_t`. Passing the handle to an function does not transfers the ownership.
 pub type iox2_port_factory_pub_sub_h_ref = *const iox2_port_factory_pub_sub_h;
 
-impl AssertNonNullHandle for iox2_port_factory_pub_sub_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_port_factory_pub_sub_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_port_factory_pub_sub_h {
-    type Target = *mut iox2_port_factory_pub_sub_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_port_factory_pub_sub_h_ref {
-    type Target = *mut iox2_port_factory_pub_sub_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END type definition
 
 // BEGIN C API
@@ -305,7 +274,7 @@ pub unsafe extern "C" fn iox2_port_factory_pub_sub_drop(
             ManuallyDrop::drop(&mut port_factory.value.as_mut().local);
         }
     }
-    (port_factory.deleter)(port_factory);
+    port_factory.delete();
 }
 
 // END C API
@@ -60,6 +60,8 @@ impl From<iox2_allocation_strategy_e> for AllocationStrategy {
 pub enum iox2_publisher_create_error_e {
     EXCEEDS_MAX_SUPPORTED_PUBLISHERS = IOX2_OK as isize + 1,
     UNABLE_TO_CREATE_DATA_SEGMENT,
+    EXCEEDS_NODE_QUOTA,
+    INCOMPATIBLE_PAYLOAD_SIZE,
 }
 
 impl IntoCInt for PublisherCreateError {
@@ -71,6 +73,12 @@ impl IntoCInt for PublisherCreateError {
             PublisherCreateError::UnableToCreateDataSegment => {
                 iox2_publisher_create_error_e::UNABLE_TO_CREATE_DATA_SEGMENT
             }
+            PublisherCreateError::ExceedsNodeQuota => {
+                iox2_publisher_create_error_e::EXCEEDS_NODE_QUOTA
+            }
+            PublisherCreateError::IncompatiblePayloadSize => {
+                iox2_publisher_create_error_e::INCOMPATIBLE_PAYLOAD_SIZE
+            }
         }) as c_int
     }
 }
@@ -135,7 +143,7 @@ impl IntoCInt for UnableToDeliverStrategy {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<PortFactoryPublisherBuilderUnion>
 pub struct iox2_port_factory_publisher_builder_storage_t {
-    internal: [u8; 128], // magic number obtained with size_of::<Option<PortFactoryPublisherBuilderUnion>>()
+    internal: [u8; 176], // magic number obtained with size_of::<Option<PortFactoryPublisherBuilderUnion>>()
 }
 
 #[repr(C)]
@@ -165,37 +173,6 @@ pub type iox2_port_factory_publisher_builder_h = *mut iox2_port_factory_publishe
 /// The non-owning handle for `iox2_port_factory_publisher_builder_t`. Passing the handle to an function does not transfers the ownership.
 pub type iox2_port_factory_publisher_builder_h_ref = *const iox2_port_factory_publisher_builder_h;
 
-impl AssertNonNullHandle for iox2_port_factory_publisher_builder_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_port_factory_publisher_builder_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_port_factory_publisher_builder_h {
-    type Target = *mut iox2_port_factory_publisher_builder_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_port_factory_publisher_builder_h_ref {
-    type Target = *mut iox2_port_factory_publisher_builder_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END type definition
 
 // BEGIN C API
@@ -413,7 +390,7 @@ pub unsafe extern "C" fn iox2_port_factory_publisher_builder_create(
         .unwrap_or_else(|| {
             panic!("Trying to use an invalid 'iox2_port_factory_publisher_builder_h'!")
         });
-    (publisher_builder_struct.deleter)(publisher_builder_struct);
+    publisher_builder_struct.delete();
 
     match service_type {
         iox2_service_type_e::IPC => {
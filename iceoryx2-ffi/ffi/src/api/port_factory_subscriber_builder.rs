@@ -35,6 +35,7 @@ use core::mem::ManuallyDrop;
 pub enum iox2_subscriber_create_error_e {
     EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS = IOX2_OK as isize + 1,
     BUFFER_SIZE_EXCEEDS_MAX_SUPPORTED_BUFFER_SIZE_OF_SERVICE,
+    EXCEEDS_NODE_QUOTA,
 }
 
 impl IntoCInt for SubscriberCreateError {
@@ -46,6 +47,9 @@ impl IntoCInt for SubscriberCreateError {
             SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService => {
                 iox2_subscriber_create_error_e::BUFFER_SIZE_EXCEEDS_MAX_SUPPORTED_BUFFER_SIZE_OF_SERVICE
             }
+            SubscriberCreateError::ExceedsNodeQuota => {
+                iox2_subscriber_create_error_e::EXCEEDS_NODE_QUOTA
+            }
         }) as c_int
     }
 }
@@ -75,7 +79,7 @@ impl PortFactorySubscriberBuilderUnion {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<PortFactorySubscriberBuilderUnion>
 pub struct iox2_port_factory_subscriber_builder_storage_t {
-    internal: [u8; 112], // magic number obtained with size_of::<Option<PortFactorySubscriberBuilderUnion>>()
+    internal: [u8; 144], // magic number obtained with size_of::<Option<PortFactorySubscriberBuilderUnion>>()
 }
 
 #[repr(C)]
@@ -105,37 +109,6 @@ pub type iox2_port_factory_subscriber_builder_h = *mut iox2_port_factory_subscri
 /// The non-owning handle for `iox2_port_factory_subscriber_builder_t`. Passing the handle to an function does not transfers the ownership.
 pub type iox2_port_factory_subscriber_builder_h_ref = *const iox2_port_factory_subscriber_builder_h;
 
-impl AssertNonNullHandle for iox2_port_factory_subscriber_builder_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_port_factory_subscriber_builder_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_port_factory_subscriber_builder_h {
-    type Target = *mut iox2_port_factory_subscriber_builder_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_port_factory_subscriber_builder_h_ref {
-    type Target = *mut iox2_port_factory_subscriber_builder_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END type definition
 
 // BEGIN C API
@@ -242,7 +215,7 @@ pub unsafe extern "C" fn iox2_port_factory_subscriber_builder_create(
         .unwrap_or_else(|| {
             panic!("Trying to use an invalid 'iox2_port_factory_subscriber_builder_h'!")
         });
-    (subscriber_builder_struct.deleter)(subscriber_builder_struct);
+    subscriber_builder_struct.delete();
 
     match service_type {
         iox2_service_type_e::IPC => {
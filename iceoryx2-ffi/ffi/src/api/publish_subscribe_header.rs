@@ -17,8 +17,8 @@ use iceoryx2_bb_elementary::static_assert::static_assert_ge;
 use iceoryx2_ffi_macros::iceoryx2_ffi;
 
 use crate::{
-    api::AssertNonNullHandle, api::HandleToType, iox2_unique_publisher_id_h,
-    iox2_unique_publisher_id_t,
+    api::AssertNonNullHandle, api::HandleToType, iox2_node_id_h, iox2_node_id_t,
+    iox2_unique_publisher_id_h, iox2_unique_publisher_id_t,
 };
 
 // BEGIN types definition
@@ -27,7 +27,7 @@ use crate::{
 #[repr(C)]
 #[repr(align(8))] // core::mem::align_of::<Option<Header>>()
 pub struct iox2_publish_subscribe_header_storage_t {
-    internal: [u8; 32], // core::mem::size_of::<Option<Header>>()
+    internal: [u8; 200], // core::mem::size_of::<Option<Header>>()
 }
 
 #[repr(C)]
@@ -60,37 +60,6 @@ pub type iox2_publish_subscribe_header_ptr = *const Header;
 /// The mutable pointer to the underlying `publish_subscribe::Header`
 pub type iox2_publish_subscribe_header_ptr_mut = *mut Header;
 
-impl AssertNonNullHandle for iox2_publish_subscribe_header_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_publish_subscribe_header_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_publish_subscribe_header_h {
-    type Target = *mut iox2_publish_subscribe_header_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_publish_subscribe_header_h_ref {
-    type Target = *mut iox2_publish_subscribe_header_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END types definition
 
 // BEGIN C API
@@ -110,7 +79,7 @@ pub unsafe extern "C" fn iox2_publish_subscribe_header_drop(
     let header = &mut *handle.as_type();
     core::ptr::drop_in_place(header.value.as_option_mut());
 
-    (header.deleter)(header);
+    header.delete();
 }
 
 /// Returns the unique publisher id of the source of the sample.
@@ -154,6 +123,119 @@ pub unsafe extern "C" fn iox2_publish_subscribe_header_publisher_id(
     *id_handle_ptr = (*storage_ptr).as_handle();
 }
 
+/// Returns the id of the node that owns the publisher that sent the sample.
+///
+/// # Arguments
+///
+/// * `handle` is valid, non-null and was initialized with
+///   [`iox2_sample_header()`](crate::iox2_sample_header)
+/// * `id_struct_ptr` - Must be either a NULL pointer or a pointer to a valid [`iox2_node_id_t`].
+///   If it is a NULL pointer, the storage will be allocated on the heap.
+/// * `id_handle_ptr` valid pointer to a [`iox2_node_id_h`].
+///
+/// # Safety
+///
+/// * `header_handle` is valid and non-null
+/// * `id_struct_ptr` is either null or valid and non-null
+/// * `id_handle_ptr` is valid and non-null
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publish_subscribe_header_node_id(
+    header_handle: iox2_publish_subscribe_header_h_ref,
+    id_struct_ptr: *mut iox2_node_id_t,
+    id_handle_ptr: *mut iox2_node_id_h,
+) {
+    header_handle.assert_non_null();
+    debug_assert!(!id_handle_ptr.is_null());
+
+    fn no_op(_: *mut iox2_node_id_t) {}
+    let mut deleter: fn(*mut iox2_node_id_t) = no_op;
+    let mut storage_ptr = id_struct_ptr;
+    if id_struct_ptr.is_null() {
+        deleter = iox2_node_id_t::dealloc;
+        storage_ptr = iox2_node_id_t::alloc();
+    }
+    debug_assert!(!storage_ptr.is_null());
+
+    let header = &mut *header_handle.as_type();
+
+    let id = header.value.as_ref().node_id();
+
+    (*storage_ptr).init(id, deleter);
+    *id_handle_ptr = (*storage_ptr).as_handle();
+}
+
+/// Returns the sequence number of the sample. Every publisher starts with zero and increments
+/// it with every sent sample, so that a gap indicates a lost sample.
+///
+/// # Arguments
+///
+/// * `handle` is valid, non-null and was initialized with
+///   [`iox2_sample_header()`](crate::iox2_sample_header)
+///
+/// # Safety
+///
+/// * `header_handle` is valid and non-null
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publish_subscribe_header_sequence_number(
+    header_handle: iox2_publish_subscribe_header_h_ref,
+) -> u64 {
+    header_handle.assert_non_null();
+
+    let header = &mut *header_handle.as_type();
+
+    header.value.as_ref().sequence_number()
+}
+
+/// Returns the priority of the sample. A higher value means a higher priority, the default
+/// is `0`.
+///
+/// # Arguments
+///
+/// * `handle` is valid, non-null and was initialized with
+///   [`iox2_sample_header()`](crate::iox2_sample_header)
+///
+/// # Safety
+///
+/// * `header_handle` is valid and non-null
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publish_subscribe_header_priority(
+    header_handle: iox2_publish_subscribe_header_h_ref,
+) -> u8 {
+    header_handle.assert_non_null();
+
+    let header = &mut *header_handle.as_type();
+
+    header.value.as_ref().priority()
+}
+
+/// Returns the time of the monotonic clock when the sample was sent.
+///
+/// # Arguments
+///
+/// * `handle` is valid, non-null and was initialized with
+///   [`iox2_sample_header()`](crate::iox2_sample_header)
+///
+/// # Safety
+///
+/// * `header_handle` is valid and non-null
+/// * `seconds` - Must point to a valid memory location
+/// * `nanoseconds` - Must point to a valid memory location
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publish_subscribe_header_timestamp(
+    header_handle: iox2_publish_subscribe_header_h_ref,
+    seconds: *mut u64,
+    nanoseconds: *mut u32,
+) {
+    header_handle.assert_non_null();
+    debug_assert!(!seconds.is_null());
+    debug_assert!(!nanoseconds.is_null());
+
+    let header = &mut *header_handle.as_type();
+    let timestamp = header.value.as_ref().timestamp();
+    *seconds = timestamp.seconds();
+    *nanoseconds = timestamp.nanoseconds();
+}
+
 /// Returns the number of elements of the payload.
 /// The element size is defined via this call when creating a new service
 /// [`crate::iox2_service_builder_pub_sub_set_payload_type_details()`].
@@ -12,6 +12,7 @@
 
 #![allow(non_camel_case_types)]
 
+use crate::api::thread_safety::serialize;
 use crate::api::{
     iox2_service_type_e, iox2_unable_to_deliver_strategy_e, iox2_unique_publisher_id_h,
     iox2_unique_publisher_id_t, AssertNonNullHandle, HandleToType, PayloadFfi,
@@ -144,37 +145,6 @@ pub type iox2_publisher_h = *mut iox2_publisher_h_t;
 /// The non-owning handle for `iox2_publisher_t`. Passing the handle to an function does not transfers the ownership.
 pub type iox2_publisher_h_ref = *const iox2_publisher_h;
 
-impl AssertNonNullHandle for iox2_publisher_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_publisher_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_publisher_h {
-    type Target = *mut iox2_publisher_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_publisher_h_ref {
-    type Target = *mut iox2_publisher_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END type definition
 
 unsafe fn send_copy<S: Service>(
@@ -296,6 +266,8 @@ pub unsafe extern "C" fn iox2_loan_error_string(error: iox2_loan_error_e) -> *co
 pub unsafe extern "C" fn iox2_publisher_unable_to_deliver_strategy(
     publisher_handle: iox2_publisher_h_ref,
 ) -> iox2_unable_to_deliver_strategy_e {
+    let _guard = serialize();
+
     publisher_handle.assert_non_null();
 
     let publisher = &mut *publisher_handle.as_type();
@@ -332,6 +304,8 @@ pub unsafe extern "C" fn iox2_publisher_unable_to_deliver_strategy(
 pub unsafe extern "C" fn iox2_publisher_initial_max_slice_len(
     publisher_handle: iox2_publisher_h_ref,
 ) -> c_int {
+    let _guard = serialize();
+
     publisher_handle.assert_non_null();
 
     let publisher = &mut *publisher_handle.as_type();
@@ -362,6 +336,8 @@ pub unsafe extern "C" fn iox2_publisher_id(
     id_struct_ptr: *mut iox2_unique_publisher_id_t,
     id_handle_ptr: *mut iox2_unique_publisher_id_h,
 ) {
+    let _guard = serialize();
+
     publisher_handle.assert_non_null();
     debug_assert!(!id_handle_ptr.is_null());
 
@@ -414,6 +390,8 @@ pub unsafe extern "C" fn iox2_publisher_send_slice_copy(
     number_of_elements: usize,
     number_of_recipients: *mut usize,
 ) -> c_int {
+    let _guard = serialize();
+
     publisher_handle.assert_non_null();
     debug_assert!(!data_ptr.is_null());
     debug_assert!(size_of_element != 0);
@@ -462,6 +440,8 @@ pub unsafe extern "C" fn iox2_publisher_send_copy(
     data_len: usize,
     number_of_recipients: *mut usize,
 ) -> c_int {
+    let _guard = serialize();
+
     publisher_handle.assert_non_null();
     debug_assert!(!data_ptr.is_null());
     debug_assert!(data_len != 0);
@@ -492,7 +472,9 @@ pub unsafe extern "C" fn iox2_publisher_send_copy(
 /// * `sample_struct_ptr` - Must be either a NULL pointer or a pointer to a valid [`iox2_sample_mut_t`].
 ///    If it is a NULL pointer, the storage will be allocated on the heap.
 /// * `sample_handle_ptr` - An uninitialized or dangling [`iox2_sample_mut_h`] handle which will be initialized by this function call if a sample is obtained, otherwise it will be set to NULL.
-/// * `number_of_bytes` - The number of bytes to loan from the publisher's payload segment
+/// * `number_of_elements` - The number of elements of the payload type to loan from the
+///   publisher's payload segment. The loaned payload has a size of `number_of_elements` *
+///   the size of the payload type.
 ///
 /// Return [`IOX2_OK`] on success, otherwise [`iox2_loan_error_e`].
 ///
@@ -507,6 +489,8 @@ pub unsafe extern "C" fn iox2_publisher_loan_slice_uninit(
     sample_handle_ptr: *mut iox2_sample_mut_h,
     number_of_elements: usize,
 ) -> c_int {
+    let _guard = serialize();
+
     publisher_handle.assert_non_null();
     debug_assert!(!sample_handle_ptr.is_null());
 
@@ -582,6 +566,8 @@ pub unsafe extern "C" fn iox2_publisher_loan_slice_uninit(
 pub unsafe extern "C" fn iox2_publisher_update_connections(
     publisher_handle: iox2_publisher_h_ref,
 ) -> c_int {
+    let _guard = serialize();
+
     publisher_handle.assert_non_null();
 
     let publisher = &mut *publisher_handle.as_type();
@@ -611,6 +597,8 @@ pub unsafe extern "C" fn iox2_publisher_update_connections(
 ///   [`iox2_port_factory_publisher_builder_create`](crate::iox2_port_factory_publisher_builder_create)!
 #[no_mangle]
 pub unsafe extern "C" fn iox2_publisher_drop(publisher_handle: iox2_publisher_h) {
+    let _guard = serialize();
+
     publisher_handle.assert_non_null();
 
     let publisher = &mut *publisher_handle.as_type();
@@ -623,7 +611,7 @@ pub unsafe extern "C" fn iox2_publisher_drop(publisher_handle: iox2_publisher_h)
             ManuallyDrop::drop(&mut publisher.value.as_mut().local);
         }
     }
-    (publisher.deleter)(publisher);
+    publisher.delete();
 }
 
 // END C API
@@ -12,6 +12,7 @@
 
 #![allow(non_camel_case_types)]
 
+use crate::api::thread_safety::serialize;
 use crate::api::{
     c_size_t, iox2_publish_subscribe_header_h, iox2_publish_subscribe_header_t,
     iox2_service_type_e, AssertNonNullHandle, HandleToType, PayloadFfi, UserHeaderFfi,
@@ -78,37 +79,6 @@ pub type iox2_sample_h = *mut iox2_sample_h_t;
 /// The non-owning handle for `iox2_sample_t`. Passing the handle to an function does not transfers the ownership.
 pub type iox2_sample_h_ref = *const iox2_sample_h;
 
-impl AssertNonNullHandle for iox2_sample_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_sample_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_sample_h {
-    type Target = *mut iox2_sample_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_sample_h_ref {
-    type Target = *mut iox2_sample_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END type definition
 
 // BEGIN C API
@@ -127,6 +97,8 @@ pub unsafe extern "C" fn iox2_sample_move(
     dest_struct_ptr: *mut iox2_sample_t,
     dest_handle_ptr: *mut iox2_sample_h,
 ) {
+    let _guard = serialize();
+
     debug_assert!(!source_struct_ptr.is_null());
     debug_assert!(!dest_struct_ptr.is_null());
     debug_assert!(!dest_handle_ptr.is_null());
@@ -161,6 +133,8 @@ pub unsafe extern "C" fn iox2_sample_header(
     header_struct_ptr: *mut iox2_publish_subscribe_header_t,
     header_handle_ptr: *mut iox2_publish_subscribe_header_h,
 ) {
+    let _guard = serialize();
+
     handle.assert_non_null();
     debug_assert!(!header_handle_ptr.is_null());
 
@@ -195,6 +169,8 @@ pub unsafe extern "C" fn iox2_sample_user_header(
     handle: iox2_sample_h_ref,
     header_ptr: *mut *const c_void,
 ) {
+    let _guard = serialize();
+
     handle.assert_non_null();
     debug_assert!(!header_ptr.is_null());
 
@@ -214,7 +190,7 @@ pub unsafe extern "C" fn iox2_sample_user_header(
 ///
 /// * `handle` obtained by [`iox2_subscriber_receive()`](crate::iox2_subscriber_receive())
 /// * `payload_ptr` a valid, non-null pointer pointing to a [`*const c_void`] pointer.
-/// * `number_of_elements` (optional) either a null poitner or a valid pointer pointing to a [`c_size_t`] with
+/// * `number_of_elements` (optional) either a null pointer or a valid pointer pointing to a [`c_size_t`] with
 ///                        the number of elements of the underlying type
 #[no_mangle]
 pub unsafe extern "C" fn iox2_sample_payload(
@@ -222,23 +198,33 @@ pub unsafe extern "C" fn iox2_sample_payload(
     payload_ptr: *mut *const c_void,
     number_of_elements: *mut c_size_t,
 ) {
+    let _guard = serialize();
+
     handle.assert_non_null();
     debug_assert!(!payload_ptr.is_null());
 
-    let sample = &mut *handle.as_type();
-    let payload = sample.value.as_mut().local.payload();
+    let sample = &*handle.as_type();
 
-    match sample.service_type {
+    let (payload, len) = match sample.service_type {
         iox2_service_type_e::IPC => {
-            *payload_ptr = payload.as_ptr().cast();
+            let sample = &sample.value.as_ref().ipc;
+            (
+                sample.payload().as_ptr(),
+                sample.header().number_of_elements(),
+            )
         }
         iox2_service_type_e::LOCAL => {
-            *payload_ptr = payload.as_ptr().cast();
+            let sample = &sample.value.as_ref().local;
+            (
+                sample.payload().as_ptr(),
+                sample.header().number_of_elements(),
+            )
         }
     };
 
+    *payload_ptr = payload.cast();
     if !number_of_elements.is_null() {
-        *number_of_elements = sample.value.as_mut().local.header().number_of_elements() as c_size_t;
+        *number_of_elements = len as c_size_t;
     }
 }
 
@@ -255,6 +241,8 @@ pub unsafe extern "C" fn iox2_sample_payload(
 ///   [`iox2_subscriber_receive`](crate::iox2_subscriber_receive)!
 #[no_mangle]
 pub unsafe extern "C" fn iox2_sample_drop(sample_handle: iox2_sample_h) {
+    let _guard = serialize();
+
     debug_assert!(!sample_handle.is_null());
 
     let sample = &mut *sample_handle.as_type();
@@ -267,7 +255,7 @@ pub unsafe extern "C" fn iox2_sample_drop(sample_handle: iox2_sample_h) {
             ManuallyDrop::drop(&mut sample.value.as_mut().local);
         }
     }
-    (sample.deleter)(sample);
+    sample.delete();
 }
 
 // END C API
@@ -12,6 +12,7 @@
 
 #![allow(non_camel_case_types)]
 
+use crate::api::thread_safety::serialize;
 use crate::api::{
     c_size_t, iox2_publish_subscribe_header_h, iox2_publish_subscribe_header_t,
     iox2_service_type_e, AssertNonNullHandle, HandleToType, IntoCInt, UserHeaderFfi, IOX2_OK,
@@ -84,37 +85,6 @@ pub type iox2_sample_mut_h = *mut iox2_sample_mut_h_t;
 /// The non-owning handle for `iox2_sample_mut_t`. Passing the handle to an function does not transfers the ownership.
 pub type iox2_sample_mut_h_ref = *const iox2_sample_mut_h;
 
-impl AssertNonNullHandle for iox2_sample_mut_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_sample_mut_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_sample_mut_h {
-    type Target = *mut iox2_sample_mut_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_sample_mut_h_ref {
-    type Target = *mut iox2_sample_mut_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END type definition
 
 // BEGIN C API
@@ -133,6 +103,8 @@ pub unsafe extern "C" fn iox2_sample_mut_move(
     dest_struct_ptr: *mut iox2_sample_mut_t,
     dest_handle_ptr: *mut iox2_sample_mut_h,
 ) {
+    let _guard = serialize();
+
     debug_assert!(!source_struct_ptr.is_null());
     debug_assert!(!dest_struct_ptr.is_null());
     debug_assert!(!dest_handle_ptr.is_null());
@@ -164,6 +136,8 @@ pub unsafe extern "C" fn iox2_sample_mut_user_header(
     handle: iox2_sample_mut_h_ref,
     header_ptr: *mut *const c_void,
 ) {
+    let _guard = serialize();
+
     handle.assert_non_null();
     debug_assert!(!header_ptr.is_null());
 
@@ -191,6 +165,8 @@ pub unsafe extern "C" fn iox2_sample_mut_header(
     header_struct_ptr: *mut iox2_publish_subscribe_header_t,
     header_handle_ptr: *mut iox2_publish_subscribe_header_h,
 ) {
+    let _guard = serialize();
+
     handle.assert_non_null();
     debug_assert!(!header_handle_ptr.is_null());
 
@@ -225,6 +201,8 @@ pub unsafe extern "C" fn iox2_sample_mut_user_header_mut(
     handle: iox2_sample_mut_h_ref,
     header_ptr: *mut *mut c_void,
 ) {
+    let _guard = serialize();
+
     handle.assert_non_null();
     debug_assert!(!header_ptr.is_null());
 
@@ -244,30 +222,38 @@ pub unsafe extern "C" fn iox2_sample_mut_user_header_mut(
 ///
 /// * `handle` obtained by [`iox2_publisher_loan_slice_uninit()`](crate::iox2_publisher_loan_slice_uninit())
 /// * `payload_ptr` a valid, non-null pointer pointing to a [`*const c_void`] pointer.
-/// * `payload_len` (optional) either a null poitner or a valid pointer pointing to a [`c_size_t`].
+/// * `number_of_elements` (optional) either a null pointer or a valid pointer pointing to a [`c_size_t`].
 #[no_mangle]
 pub unsafe extern "C" fn iox2_sample_mut_payload_mut(
     handle: iox2_sample_mut_h_ref,
     payload_ptr: *mut *mut c_void,
     number_of_elements: *mut c_size_t,
 ) {
+    let _guard = serialize();
+
     handle.assert_non_null();
     debug_assert!(!payload_ptr.is_null());
 
     let sample = &mut *handle.as_type();
-    let payload = sample.value.as_mut().ipc.payload_mut();
 
-    match sample.service_type {
+    // the payload consists of `number_of_elements` * `payload size` bytes of
+    // `CustomPayloadMarker`, therefore the number of elements is taken from the header
+    let (payload, len) = match sample.service_type {
         iox2_service_type_e::IPC => {
-            *payload_ptr = payload.as_mut_ptr().cast();
+            let sample = &mut sample.value.as_mut().ipc;
+            let len = sample.header().number_of_elements();
+            (sample.payload_mut().as_mut_ptr(), len)
         }
         iox2_service_type_e::LOCAL => {
-            *payload_ptr = payload.as_mut_ptr().cast();
+            let sample = &mut sample.value.as_mut().local;
+            let len = sample.header().number_of_elements();
+            (sample.payload_mut().as_mut_ptr(), len)
         }
     };
 
+    *payload_ptr = payload.cast();
     if !number_of_elements.is_null() {
-        *number_of_elements = sample.value.as_mut().local.header().number_of_elements() as c_size_t;
+        *number_of_elements = len as c_size_t;
     }
 }
 
@@ -277,30 +263,36 @@ pub unsafe extern "C" fn iox2_sample_mut_payload_mut(
 ///
 /// * `handle` obtained by [`iox2_publisher_loan_slice_uninit()`](crate::iox2_publisher_loan_slice_uninit())
 /// * `payload_ptr` a valid, non-null pointer pointing to a [`*const c_void`] pointer.
-/// * `payload_len` (optional) either a null poitner or a valid pointer pointing to a [`c_size_t`].
+/// * `number_of_elements` (optional) either a null pointer or a valid pointer pointing to a [`c_size_t`].
 #[no_mangle]
 pub unsafe extern "C" fn iox2_sample_mut_payload(
     handle: iox2_sample_mut_h_ref,
     payload_ptr: *mut *const c_void,
     number_of_elements: *mut c_size_t,
 ) {
+    let _guard = serialize();
+
     handle.assert_non_null();
     debug_assert!(!payload_ptr.is_null());
 
     let sample = &mut *handle.as_type();
-    let payload = sample.value.as_mut().ipc.payload_mut();
 
-    match sample.service_type {
+    let (payload, len) = match sample.service_type {
         iox2_service_type_e::IPC => {
-            *payload_ptr = payload.as_mut_ptr().cast();
+            let sample = &sample.value.as_ref().ipc;
+            let len = sample.header().number_of_elements();
+            (sample.payload().as_ptr(), len)
         }
         iox2_service_type_e::LOCAL => {
-            *payload_ptr = payload.as_mut_ptr().cast();
+            let sample = &sample.value.as_ref().local;
+            let len = sample.header().number_of_elements();
+            (sample.payload().as_ptr(), len)
         }
     };
 
+    *payload_ptr = payload.cast();
     if !number_of_elements.is_null() {
-        *number_of_elements = sample.value.as_mut().local.header().number_of_elements() as c_size_t;
+        *number_of_elements = len as c_size_t;
     }
 }
 
@@ -316,6 +308,8 @@ pub unsafe extern "C" fn iox2_sample_mut_send(
     sample_handle: iox2_sample_mut_h,
     number_of_recipients: *mut c_size_t,
 ) -> c_int {
+    let _guard = serialize();
+
     debug_assert!(!sample_handle.is_null());
 
     let sample_struct = &mut *sample_handle.as_type();
@@ -326,7 +320,7 @@ pub unsafe extern "C" fn iox2_sample_mut_send(
         .as_option_mut()
         .take()
         .unwrap_or_else(|| panic!("Trying to send an already sent sample!"));
-    (sample_struct.deleter)(sample_struct);
+    sample_struct.delete();
 
     match service_type {
         iox2_service_type_e::IPC => {
@@ -370,9 +364,11 @@ pub unsafe extern "C" fn iox2_sample_mut_send(
 ///
 /// * The `sample_handle` is invalid after the return of this function and leads to undefined behavior if used in another function call!
 /// * The corresponding [`iox2_sample_mut_t`] can be re-used with a call to
-///   [`iox2_subscriber_receive`](crate::iox2_subscriber_receive)!
+///   [`iox2_publisher_loan_slice_uninit`](crate::iox2_publisher_loan_slice_uninit)!
 #[no_mangle]
 pub unsafe extern "C" fn iox2_sample_mut_drop(sample_handle: iox2_sample_mut_h) {
+    let _guard = serialize();
+
     debug_assert!(!sample_handle.is_null());
 
     let sample = &mut *sample_handle.as_type();
@@ -385,7 +381,7 @@ pub unsafe extern "C" fn iox2_sample_mut_drop(sample_handle: iox2_sample_mut_h)
             ManuallyDrop::drop(&mut sample.value.as_mut().local);
         }
     }
-    (sample.deleter)(sample);
+    sample.delete();
 }
 
 // END C API
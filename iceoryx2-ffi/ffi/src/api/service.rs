@@ -17,17 +17,21 @@
 
 use core::ffi::{c_char, c_int};
 
+use iceoryx2::port::port_identifiers::UniquePortId;
+use iceoryx2::service::attribute::AttributeSet;
 use iceoryx2::service::{
     ipc, local, messaging_pattern::MessagingPattern, Service, ServiceDetails, ServiceDetailsError,
-    ServiceListError,
+    ServiceListError, ServicePortDetails,
 };
 use iceoryx2_bb_elementary::AsCStr;
 use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::warn;
 use iceoryx2_ffi_macros::CStrRepr;
 
 use crate::{
-    iox2_callback_context, iox2_callback_progression_e, iox2_config_ptr, iox2_service_name_ptr,
-    iox2_static_config_t, IOX2_OK,
+    iox2_attribute_set_h_ref, iox2_callback_context, iox2_callback_progression_e, iox2_config_ptr,
+    iox2_node_id_ptr, iox2_service_name_ptr, iox2_static_config_t, IOX2_OK,
+    IOX2_UNIQUE_PORT_ID_LENGTH,
 };
 
 use super::IntoCInt;
@@ -44,6 +48,7 @@ pub enum iox2_service_type_e {
 pub enum iox2_messaging_pattern_e {
     PUBLISH_SUBSCRIBE = 0,
     EVENT,
+    REQUEST_RESPONSE,
 }
 
 impl From<iox2_messaging_pattern_e> for MessagingPattern {
@@ -51,22 +56,34 @@ impl From<iox2_messaging_pattern_e> for MessagingPattern {
         match value {
             iox2_messaging_pattern_e::EVENT => MessagingPattern::Event,
             iox2_messaging_pattern_e::PUBLISH_SUBSCRIBE => MessagingPattern::PublishSubscribe,
+            iox2_messaging_pattern_e::REQUEST_RESPONSE => MessagingPattern::RequestResponse,
         }
     }
 }
 
-impl From<&iceoryx2::service::static_config::messaging_pattern::MessagingPattern>
+/// Returned when a messaging pattern of a service has no representation in the C API.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct UnsupportedMessagingPattern;
+
+impl TryFrom<&iceoryx2::service::static_config::messaging_pattern::MessagingPattern>
     for iox2_messaging_pattern_e
 {
-    fn from(value: &iceoryx2::service::static_config::messaging_pattern::MessagingPattern) -> Self {
+    type Error = UnsupportedMessagingPattern;
+
+    fn try_from(
+        value: &iceoryx2::service::static_config::messaging_pattern::MessagingPattern,
+    ) -> Result<Self, Self::Error> {
         match value {
             iceoryx2::service::static_config::messaging_pattern::MessagingPattern::Event(_) => {
-                iox2_messaging_pattern_e::EVENT
+                Ok(iox2_messaging_pattern_e::EVENT)
             }
             iceoryx2::service::static_config::messaging_pattern::MessagingPattern::PublishSubscribe(_) => {
-                iox2_messaging_pattern_e::PUBLISH_SUBSCRIBE
+                Ok(iox2_messaging_pattern_e::PUBLISH_SUBSCRIBE)
             }
-            _ => unreachable!()
+            iceoryx2::service::static_config::messaging_pattern::MessagingPattern::RequestResponse(_) => {
+                Ok(iox2_messaging_pattern_e::REQUEST_RESPONSE)
+            }
+            _ => Err(UnsupportedMessagingPattern),
         }
     }
 }
@@ -125,6 +142,65 @@ impl IntoCInt for ServiceListError {
     }
 }
 
+/// The type of a port that is connected to a service.
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum iox2_port_type_e {
+    PUBLISHER,
+    SUBSCRIBER,
+    NOTIFIER,
+    LISTENER,
+    CLIENT,
+    SERVER,
+}
+
+/// Describes a port that is connected to a service and the node that owns it.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct iox2_service_port_details_t {
+    pub port_type: iox2_port_type_e,
+    /// The unique id of the port in native byte order.
+    pub port_id: [u8; IOX2_UNIQUE_PORT_ID_LENGTH],
+    pub node_id: iox2_node_id_ptr,
+}
+
+impl From<&ServicePortDetails> for iox2_service_port_details_t {
+    fn from(value: &ServicePortDetails) -> Self {
+        let (port_type, port_id) = match value.port_id {
+            UniquePortId::Publisher(id) => (iox2_port_type_e::PUBLISHER, id.value()),
+            UniquePortId::Subscriber(id) => (iox2_port_type_e::SUBSCRIBER, id.value()),
+            UniquePortId::Notifier(id) => (iox2_port_type_e::NOTIFIER, id.value()),
+            UniquePortId::Listener(id) => (iox2_port_type_e::LISTENER, id.value()),
+            UniquePortId::Client(id) => (iox2_port_type_e::CLIENT, id.value()),
+            UniquePortId::Server(id) => (iox2_port_type_e::SERVER, id.value()),
+        };
+
+        Self {
+            port_type,
+            port_id: port_id.to_ne_bytes(),
+            node_id: &value.node_id,
+        }
+    }
+}
+
+/// The details of a service. All pointers and handles are only valid inside the
+/// [`iox2_service_details_callback`].
+#[repr(C)]
+pub struct iox2_service_details_t {
+    pub static_config: iox2_static_config_t,
+    /// The attributes the service was created with.
+    pub static_attributes: iox2_attribute_set_h_ref,
+    /// True when the dynamic details of the service are accessible by the current process.
+    /// Otherwise `ports` and `dynamic_attributes` are NULL and `number_of_ports` is 0.
+    pub is_accessible: bool,
+    pub ports: *const iox2_service_port_details_t,
+    pub number_of_ports: usize,
+    pub dynamic_attributes: iox2_attribute_set_h_ref,
+}
+
+pub type iox2_service_details_callback =
+    extern "C" fn(*const iox2_service_details_t, iox2_callback_context);
+
 pub type iox2_service_list_callback = extern "C" fn(
     *const iox2_static_config_t,
     iox2_callback_context,
@@ -219,28 +295,92 @@ pub unsafe extern "C" fn iox2_service_does_exist(
     }
 }
 
-/// Acquires the service details of a specified service. If the service exists `service_details` will contain
-/// the requested information, otherwise it is NULL. On error it returns `iox2_service_details_error_e`, on
-/// success `IOX2_OK`.
+/// Acquires the service details of a specified service. If the service exists the `callback`
+/// is called with its [`iox2_service_details_t`], otherwise the `callback` is not called.
+/// On error it returns `iox2_service_details_error_e`, on success `IOX2_OK`.
 ///
 /// # Safety
 ///
 /// * The `service_name` must be valid and non-null
 /// * The `config` must be valid and non-null
-/// * The `service_details` must be valid and non-null
+/// * The `callback` must be valid and non-null
 #[no_mangle]
 pub unsafe extern "C" fn iox2_service_details(
-    _service_type: iox2_service_type_e,
+    service_type: iox2_service_type_e,
     service_name: iox2_service_name_ptr,
     config: iox2_config_ptr,
-    _messaging_pattern: iox2_messaging_pattern_e,
-    service_details: *mut iox2_static_config_t,
+    messaging_pattern: iox2_messaging_pattern_e,
+    callback: iox2_service_details_callback,
+    callback_ctx: iox2_callback_context,
 ) -> c_int {
     debug_assert!(!service_name.is_null());
     debug_assert!(!config.is_null());
-    debug_assert!(!service_details.is_null());
 
-    todo!()
+    let config = &*config;
+    let service_name = &*service_name;
+    let messaging_pattern = messaging_pattern.into();
+
+    let result = match service_type {
+        iox2_service_type_e::IPC => ipc::Service::details(service_name, config, messaging_pattern)
+            .map(|details| {
+                details.map(|details| details_callback(callback, callback_ctx, &details))
+            }),
+        iox2_service_type_e::LOCAL => {
+            local::Service::details(service_name, config, messaging_pattern).map(|details| {
+                details.map(|details| details_callback(callback, callback_ctx, &details))
+            })
+        }
+    };
+
+    match result {
+        Ok(None) | Ok(Some(Ok(()))) => IOX2_OK,
+        Ok(Some(Err(e))) | Err(e) => e.into_c_int(),
+    }
+}
+
+fn details_callback<S: Service>(
+    callback: iox2_service_details_callback,
+    callback_ctx: iox2_callback_context,
+    service_details: &ServiceDetails<S>,
+) -> Result<(), ServiceDetailsError> {
+    let static_config = match iox2_static_config_t::try_from(&service_details.static_details) {
+        Ok(static_config) => static_config,
+        Err(_) => {
+            warn!(from "iox2_service_details",
+                "Unable to provide the details of the service \"{}\" since its messaging pattern {} is not supported by the C API.",
+                service_details.static_details.name(),
+                service_details.static_details.messaging_pattern());
+            return Err(ServiceDetailsError::InternalError);
+        }
+    };
+
+    let ports: Vec<iox2_service_port_details_t> = service_details
+        .dynamic_details
+        .iter()
+        .flat_map(|dynamic_details| dynamic_details.ports.iter().map(Into::into))
+        .collect();
+
+    let details = iox2_service_details_t {
+        static_config,
+        static_attributes: (service_details.static_details.attributes() as *const AttributeSet)
+            .cast(),
+        is_accessible: service_details.dynamic_details.is_some(),
+        ports: if ports.is_empty() {
+            core::ptr::null()
+        } else {
+            ports.as_ptr()
+        },
+        number_of_ports: ports.len(),
+        dynamic_attributes: match service_details.dynamic_details {
+            Some(ref dynamic_details) => {
+                (&dynamic_details.dynamic_attributes as *const AttributeSet).cast()
+            }
+            None => core::ptr::null(),
+        },
+    };
+
+    callback(&details, callback_ctx);
+    Ok(())
 }
 
 fn list_callback<S: Service>(
@@ -248,11 +388,22 @@ fn list_callback<S: Service>(
     callback_ctx: iox2_callback_context,
     service_details: &ServiceDetails<S>,
 ) -> CallbackProgression {
-    callback(&(&service_details.static_details).into(), callback_ctx).into()
+    match iox2_static_config_t::try_from(&service_details.static_details) {
+        Ok(static_config) => callback(&static_config, callback_ctx).into(),
+        Err(_) => {
+            warn!(from "iox2_service_list",
+                "Skipping the service \"{}\" since its messaging pattern {} is not supported by the C API.",
+                service_details.static_details.name(),
+                service_details.static_details.messaging_pattern());
+            CallbackProgression::Continue
+        }
+    }
 }
 
 /// Iterates over the all accessible services and calls the provided callback for
-/// every service with iox2_service_details as input argument.
+/// every service with its [`iox2_static_config_t`] as input argument. The
+/// `messaging_pattern` of the [`iox2_static_config_t`] defines which field of the
+/// `details` union is valid.
 /// On error it returns `iox2_service_list_error_e`, otherwise IOX2_OK.
 ///
 /// # Safety
@@ -12,7 +12,7 @@
 
 #![allow(non_camel_case_types)]
 
-use crate::api::{iox2_service_type_e, AssertNonNullHandle, HandleToType};
+use crate::api::{handle_validation, iox2_service_type_e, AssertNonNullHandle, HandleToType};
 
 use iceoryx2::prelude::*;
 use iceoryx2::service::builder::publish_subscribe::{CustomHeaderMarker, CustomPayloadMarker};
@@ -98,9 +98,9 @@ impl ServiceBuilderUnion {
 }
 
 #[repr(C)]
-#[repr(align(8))] // alignment of Option<ServiceBuilderUnion>
+#[repr(align(16))] // alignment of Option<ServiceBuilderUnion>
 pub struct iox2_service_builder_storage_t {
-    internal: [u8; 632], // magic number obtained with size_of::<Option<ServiceBuilderUnion>>()
+    internal: [u8; 1584], // magic number obtained with size_of::<Option<ServiceBuilderUnion>>()
 }
 
 #[repr(C)]
@@ -172,26 +172,11 @@ impl AssertNonNullHandle for iox2_service_builder_pub_sub_h_ref {
     }
 }
 
-impl HandleToType for iox2_service_builder_h {
-    type Target = *mut iox2_service_builder_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_service_builder_h_ref {
-    type Target = *mut iox2_service_builder_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 impl HandleToType for iox2_service_builder_event_h {
     type Target = *mut iox2_service_builder_t;
 
     fn as_type(self) -> Self::Target {
+        handle_validation::verify(self as usize, "iox2_service_builder_t");
         self as *mut _ as _
     }
 }
@@ -200,7 +185,10 @@ impl HandleToType for iox2_service_builder_event_h_ref {
     type Target = *mut iox2_service_builder_t;
 
     fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
+        unsafe {
+            handle_validation::verify(*self as usize, "iox2_service_builder_t");
+            *self as *mut _ as _
+        }
     }
 }
 
@@ -208,6 +196,7 @@ impl HandleToType for iox2_service_builder_pub_sub_h {
     type Target = *mut iox2_service_builder_t;
 
     fn as_type(self) -> Self::Target {
+        handle_validation::verify(self as usize, "iox2_service_builder_t");
         self as *mut _ as _
     }
 }
@@ -216,7 +205,10 @@ impl HandleToType for iox2_service_builder_pub_sub_h_ref {
     type Target = *mut iox2_service_builder_t;
 
     fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
+        unsafe {
+            handle_validation::verify(*self as usize, "iox2_service_builder_t");
+            *self as *mut _ as _
+        }
     }
 }
 
@@ -55,6 +55,8 @@ pub enum iox2_event_open_or_create_error_e {
     O_INCOMPATIBLE_NOTIFIER_DROPPED_EVENT,
     #[CStr = "incompatible notifier_dead event"]
     O_INCOMPATIBLE_NOTIFIER_DEAD_EVENT,
+    #[CStr = "incompatible dynamic_attribute_changed event"]
+    O_INCOMPATIBLE_DYNAMIC_ATTRIBUTE_CHANGED_EVENT,
     #[CStr = "internal failure"]
     O_INTERNAL_FAILURE,
     #[CStr = "hangs in creation"]
@@ -71,6 +73,16 @@ pub enum iox2_event_open_or_create_error_e {
     O_EXCEEDS_MAX_NUMBER_OF_NODES,
     #[CStr = "is marked for destruction"]
     O_IS_MARKED_FOR_DESTRUCTION,
+    #[CStr = "incompatible version"]
+    O_INCOMPATIBLE_VERSION,
+    #[CStr = "incompatible domain"]
+    O_INCOMPATIBLE_DOMAIN,
+    #[CStr = "incompatible iceoryx2 version"]
+    O_INCOMPATIBLE_ICEORYX2_VERSION,
+    #[CStr = "service name hash collision"]
+    O_SERVICE_NAME_HASH_COLLISION,
+    #[CStr = "incompatible abi version"]
+    O_INCOMPATIBLE_ABI_VERSION,
     #[CStr = "service in corrupted state"]
     C_SERVICE_IN_CORRUPTED_STATE,
     #[CStr = "internal failure"]
@@ -85,6 +97,8 @@ pub enum iox2_event_open_or_create_error_e {
     C_INSUFFICIENT_PERMISSIONS,
     #[CStr = "old connection still active"]
     C_OLD_CONNECTION_STILL_ACTIVE,
+    #[CStr = "exceeds node quota"]
+    C_EXCEEDS_NODE_QUOTA,
     #[CStr = "same service is created and removed repeatedly"]
     SYSTEM_IN_FLUX,
 }
@@ -129,6 +143,21 @@ impl IntoCInt for EventOpenError {
             EventOpenError::IsMarkedForDestruction => {
                 iox2_event_open_or_create_error_e::O_IS_MARKED_FOR_DESTRUCTION
             }
+            EventOpenError::IncompatibleVersion => {
+                iox2_event_open_or_create_error_e::O_INCOMPATIBLE_VERSION
+            }
+            EventOpenError::IncompatibleDomain => {
+                iox2_event_open_or_create_error_e::O_INCOMPATIBLE_DOMAIN
+            }
+            EventOpenError::IncompatibleIceoryx2Version => {
+                iox2_event_open_or_create_error_e::O_INCOMPATIBLE_ICEORYX2_VERSION
+            }
+            EventOpenError::ServiceNameHashCollision => {
+                iox2_event_open_or_create_error_e::O_SERVICE_NAME_HASH_COLLISION
+            }
+            EventOpenError::IncompatibleAbiVersion => {
+                iox2_event_open_or_create_error_e::O_INCOMPATIBLE_ABI_VERSION
+            }
             EventOpenError::IncompatibleNotifierCreatedEvent => {
                 iox2_event_open_or_create_error_e::O_INCOMPATIBLE_NOTIFIER_CREATED_EVENT
             }
@@ -138,6 +167,9 @@ impl IntoCInt for EventOpenError {
             EventOpenError::IncompatibleNotifierDeadEvent => {
                 iox2_event_open_or_create_error_e::O_INCOMPATIBLE_NOTIFIER_DEAD_EVENT
             }
+            EventOpenError::IncompatibleDynamicAttributeChangedEvent => {
+                iox2_event_open_or_create_error_e::O_INCOMPATIBLE_DYNAMIC_ATTRIBUTE_CHANGED_EVENT
+            }
             EventOpenError::IncompatibleDeadline => {
                 iox2_event_open_or_create_error_e::O_INCOMPATIBLE_DEADLINE
             }
@@ -165,6 +197,9 @@ impl IntoCInt for EventCreateError {
             EventCreateError::InsufficientPermissions => {
                 iox2_event_open_or_create_error_e::C_INSUFFICIENT_PERMISSIONS
             }
+            EventCreateError::ExceedsNodeQuota => {
+                iox2_event_open_or_create_error_e::C_EXCEEDS_NODE_QUOTA
+            }
         }) as c_int
     }
 }
@@ -819,7 +854,7 @@ unsafe fn iox2_service_builder_event_open_create_impl<E: IntoCInt>(
         .unwrap_or_else(|| {
             panic!("Trying to use an invalid 'iox2_service_builder_event_h'!");
         });
-    (service_builder_struct.deleter)(service_builder_struct);
+    service_builder_struct.delete();
 
     match service_type {
         iox2_service_type_e::IPC => {
@@ -76,6 +76,18 @@ pub enum iox2_pub_sub_open_or_create_error_e {
     O_EXCEEDS_MAX_NUMBER_OF_NODES,
     #[CStr = "is marked for destruction"]
     O_IS_MARKED_FOR_DESTRUCTION,
+    #[CStr = "incompatible version"]
+    O_INCOMPATIBLE_VERSION,
+    #[CStr = "incompatible domain"]
+    O_INCOMPATIBLE_DOMAIN,
+    #[CStr = "incompatible iceoryx2 version"]
+    O_INCOMPATIBLE_ICEORYX2_VERSION,
+    #[CStr = "service name hash collision"]
+    O_SERVICE_NAME_HASH_COLLISION,
+    #[CStr = "incompatible abi version"]
+    O_INCOMPATIBLE_ABI_VERSION,
+    #[CStr = "incompatible bitness"]
+    O_INCOMPATIBLE_BITNESS,
     #[CStr = "service in corrupted state"]
     C_SERVICE_IN_CORRUPTED_STATE,
     #[CStr = "subscriber buffer must be larger than history size"]
@@ -92,6 +104,8 @@ pub enum iox2_pub_sub_open_or_create_error_e {
     C_OLD_CONNECTION_STILL_ACTIVE,
     #[CStr = "hangs in creation"]
     C_HANGS_IN_CREATION,
+    #[CStr = "exceeds node quota"]
+    C_EXCEEDS_NODE_QUOTA,
     #[CStr = "same service is created and removed repeatedly"]
     SYSTEM_IN_FLUX,
 }
@@ -148,6 +162,24 @@ impl IntoCInt for PublishSubscribeOpenError {
          PublishSubscribeOpenError::IsMarkedForDestruction => {
              iox2_pub_sub_open_or_create_error_e::O_IS_MARKED_FOR_DESTRUCTION
          }
+         PublishSubscribeOpenError::IncompatibleVersion => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_VERSION
+         }
+         PublishSubscribeOpenError::IncompatibleDomain => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_DOMAIN
+         }
+         PublishSubscribeOpenError::IncompatibleIceoryx2Version => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_ICEORYX2_VERSION
+         }
+         PublishSubscribeOpenError::ServiceNameHashCollision => {
+             iox2_pub_sub_open_or_create_error_e::O_SERVICE_NAME_HASH_COLLISION
+         }
+         PublishSubscribeOpenError::IncompatibleAbiVersion => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_ABI_VERSION
+         }
+         PublishSubscribeOpenError::IncompatibleBitness => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_BITNESS
+         }
         }) as c_int
     }
 }
@@ -174,6 +206,9 @@ impl IntoCInt for PublishSubscribeCreateError {
          PublishSubscribeCreateError::HangsInCreation => {
              iox2_pub_sub_open_or_create_error_e::C_HANGS_IN_CREATION
          }
+            PublishSubscribeCreateError::ExceedsNodeQuota => {
+                iox2_pub_sub_open_or_create_error_e::C_EXCEEDS_NODE_QUOTA
+            }
         }) as c_int
     }
 }
@@ -222,7 +257,7 @@ impl From<iox2_type_variant_e> for TypeVariant {
 }
 
 #[repr(C)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, CStrRepr)]
 pub enum iox2_type_detail_error_e {
     INVALID_TYPE_NAME = IOX2_OK as isize + 1,
     INVALID_SIZE_OR_ALIGNMENT_VALUE,
@@ -253,6 +288,27 @@ pub unsafe extern "C" fn iox2_pub_sub_open_or_create_error_string(
     error.as_const_cstr().as_ptr() as *const c_char
 }
 
+/// Returns a string literal describing the provided [`iox2_type_detail_error_e`].
+///
+/// # Arguments
+///
+/// * `error` - The error value for which a description should be returned
+///
+/// # Returns
+///
+/// A pointer to a null-terminated string containing the error message.
+/// The string is stored in the .rodata section of the binary.
+///
+/// # Safety
+///
+/// The returned pointer must not be modified or freed and is valid as long as the program runs.
+#[no_mangle]
+pub unsafe extern "C" fn iox2_type_detail_error_string(
+    error: iox2_type_detail_error_e,
+) -> *const c_char {
+    error.as_const_cstr().as_ptr() as *const c_char
+}
+
 /// Sets the user header type details for the builder
 ///
 /// # Arguments
@@ -302,6 +358,8 @@ pub unsafe extern "C" fn iox2_service_builder_pub_sub_set_user_header_type_detai
         type_name,
         size,
         alignment,
+        stable_type_id: None,
+        variants: vec![],
     };
 
     let service_builder_struct = unsafe { &mut *service_builder_handle.as_type() };
@@ -379,6 +437,8 @@ pub unsafe extern "C" fn iox2_service_builder_pub_sub_set_payload_type_details(
         type_name,
         size,
         alignment,
+        stable_type_id: None,
+        variants: vec![],
     };
 
     let service_builder_struct = unsafe { &mut *service_builder_handle.as_type() };
@@ -995,7 +1055,7 @@ unsafe fn iox2_service_builder_pub_sub_open_create_impl<E: IntoCInt>(
         .unwrap_or_else(|| {
             panic!("Trying to use an invalid 'iox2_service_builder_pub_sub_h'!");
         });
-    (service_builder_struct.deleter)(service_builder_struct);
+    service_builder_struct.delete();
 
     match service_type {
         iox2_service_type_e::IPC => {
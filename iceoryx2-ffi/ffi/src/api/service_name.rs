@@ -14,6 +14,7 @@
 
 use crate::api::{
     iox2_semantic_string_error_e, AssertNonNullHandle, HandleToType, IntoCInt, IOX2_OK,
+    IOX2_SERVICE_NAME_LENGTH,
 };
 use crate::c_size_t;
 
@@ -52,48 +53,36 @@ pub type iox2_service_name_ptr = *const ServiceName;
 /// The mutable pointer to the underlying `ServiceName`
 pub type iox2_service_name_ptr_mut = *mut ServiceName;
 
-impl AssertNonNullHandle for iox2_service_name_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
+// END type definition
 
-impl AssertNonNullHandle for iox2_service_name_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
+unsafe fn to_service_name(
+    service_name_str: *const c_char,
+    service_name_len: c_size_t,
+) -> Result<ServiceName, c_int> {
+    if service_name_len > IOX2_SERVICE_NAME_LENGTH {
+        return Err(iox2_semantic_string_error_e::EXCEEDS_MAXIMUM_LENGTH as c_int);
     }
-}
-
-impl HandleToType for iox2_service_name_h {
-    type Target = *mut iox2_service_name_t;
 
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
+    let service_name = slice::from_raw_parts(service_name_str as _, service_name_len as _);
 
-impl HandleToType for iox2_service_name_h_ref {
-    type Target = *mut iox2_service_name_t;
+    let service_name = if let Ok(service_name) = str::from_utf8(service_name) {
+        service_name
+    } else {
+        return Err(iox2_semantic_string_error_e::INVALID_CONTENT as c_int);
+    };
 
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
+    ServiceName::new(service_name).map_err(|e| e.into_c_int())
 }
 
-// END type definition
-
 // BEGIN C API
 
-/// This function create a new node name!
+/// This function create a new service name!
 ///
 /// # Arguments
 ///
 /// * `service_name_struct_ptr` - Must be either a NULL pointer or a pointer to a valid [`iox2_service_name_t`]. If it is a NULL pointer, the storage will be allocated on the heap.
-/// * `service_name_str` - Must be valid node name string.
-/// * `service_name_len` - The length of the node name string, not including a null termination.
+/// * `service_name_str` - Must be valid service name string.
+/// * `service_name_len` - The length of the service name string, not including a null termination.
 /// * `service_name_handle_ptr` - An uninitialized or dangling [`iox2_service_name_h`] handle which will be initialized by this function call.
 ///
 /// Returns IOX2_OK on success, an [`iox2_semantic_string_error_e`](crate::iox2_semantic_string_error_e) otherwise.
@@ -127,20 +116,11 @@ pub unsafe extern "C" fn iox2_service_name_new(
         (*service_name_struct_ptr).deleter = deleter;
     }
 
-    let service_name = slice::from_raw_parts(service_name_str as _, service_name_len as _);
-
-    let service_name = if let Ok(service_name) = str::from_utf8(service_name) {
-        service_name
-    } else {
-        deleter(service_name_struct_ptr);
-        return iox2_semantic_string_error_e::INVALID_CONTENT as c_int;
-    };
-
-    let service_name = match ServiceName::new(service_name) {
+    let service_name = match to_service_name(service_name_str, service_name_len) {
         Ok(service_name) => service_name,
         Err(e) => {
             deleter(service_name_struct_ptr);
-            return e.into_c_int();
+            return e;
         }
     };
 
@@ -153,11 +133,37 @@ pub unsafe extern "C" fn iox2_service_name_new(
     IOX2_OK
 }
 
-/// This function casts a [`iox2_service_name_h`] into a [`iox2_service_name_ptr`]
+/// Verifies whether the provided string is a valid service name without creating it.
+///
+/// # Arguments
+///
+/// * `service_name_str` - Must be a valid pointer to a string.
+/// * `service_name_len` - The length of the service name string, not including a null termination.
+///
+/// Returns IOX2_OK when the service name is valid, an [`iox2_semantic_string_error_e`](crate::iox2_semantic_string_error_e) otherwise.
+///
+/// # Safety
+///
+/// * Terminates if `service_name_str` is a NULL pointer!
+/// * It is undefined behavior to pass a `service_name_len` which is larger than the actual length of `service_name_str`!
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_name_validate(
+    service_name_str: *const c_char,
+    service_name_len: c_size_t,
+) -> c_int {
+    debug_assert!(!service_name_str.is_null());
+
+    match to_service_name(service_name_str, service_name_len) {
+        Ok(_) => IOX2_OK,
+        Err(e) => e,
+    }
+}
+
+/// This function casts a [`iox2_service_name_h_ref`] into a [`iox2_service_name_ptr`]
 ///
 /// # Arguments
 ///
-/// * `service_name_handle` obtained by [`iox2_service_name_new`]
+/// * `service_name_handle` - Must be a valid [`iox2_service_name_h_ref`] obtained by [`iox2_service_name_new`]
 ///
 /// Returns a [`iox2_service_name_ptr`]
 ///
@@ -167,14 +173,65 @@ pub unsafe extern "C" fn iox2_service_name_new(
 /// * The `service_name_handle` is still valid after the call to this function.
 #[no_mangle]
 pub unsafe extern "C" fn iox2_cast_service_name_ptr(
-    service_name_handle: iox2_service_name_h,
+    service_name_handle: iox2_service_name_h_ref,
 ) -> iox2_service_name_ptr {
-    debug_assert!(!service_name_handle.is_null());
+    service_name_handle.assert_non_null();
 
     (*service_name_handle.as_type()).value.as_ref()
 }
 
-/// This function gives access to the node name as a non-zero-terminated char array
+/// Returns the length of the service name, not including a null termination.
+///
+/// # Safety
+///
+/// * The `service_name_ptr` must be a valid pointer to a service name.
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_name_len(
+    service_name_ptr: iox2_service_name_ptr,
+) -> c_size_t {
+    debug_assert!(!service_name_ptr.is_null());
+
+    let service_name = &*service_name_ptr;
+    service_name.len() as _
+}
+
+/// Copies the service name as zero-terminated string into the provided buffer. When the
+/// buffer is too small, the service name is truncated but always zero-terminated.
+///
+/// # Arguments
+///
+/// * `service_name_ptr` obtained by e.g. [`iox2_cast_service_name_ptr`] or a function returning a [`iox2_service_name_ptr`]
+/// * `buffer` - Must be non-null and pointing to a valid memory location
+/// * `buffer_len` - Must be the length of the provided `buffer`
+///
+/// Returns the number of copied characters, not including the zero termination.
+///
+/// # Safety
+///
+/// * The `service_name_ptr` must be a valid pointer to a service name.
+/// * The `buffer` must be valid for `buffer_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_name_as_c_str(
+    service_name_ptr: iox2_service_name_ptr,
+    buffer: *mut c_char,
+    buffer_len: c_size_t,
+) -> c_size_t {
+    debug_assert!(!service_name_ptr.is_null());
+    debug_assert!(!buffer.is_null());
+
+    if buffer_len == 0 {
+        return 0;
+    }
+
+    let service_name = (*service_name_ptr).as_bytes();
+    let copied_length = service_name.len().min(buffer_len - 1);
+    core::ptr::copy_nonoverlapping(service_name.as_ptr(), buffer.cast(), copied_length);
+    *buffer.add(copied_length) = 0;
+
+    copied_length as _
+}
+
+/// This function gives access to the service name as a non-zero-terminated char array
 ///
 /// # Arguments
 ///
@@ -185,7 +242,7 @@ pub unsafe extern "C" fn iox2_cast_service_name_ptr(
 ///
 /// # Safety
 ///
-/// * The `service_name_ptr` must be a valid pointer to a node name.
+/// * The `service_name_ptr` must be a valid pointer to a service name.
 /// * The `service_name_len` must be a valid pointer to a size_t.
 #[no_mangle]
 pub unsafe extern "C" fn iox2_service_name_as_chars(
@@ -206,9 +263,7 @@ pub unsafe extern "C" fn iox2_service_name_as_chars(
     service_name.as_str().as_ptr() as _
 }
 
-/// This function needs to be called to destroy the node name!
-///
-/// In general, this function is not required to call, since [`iox2_node_builder_set_name`](crate::iox2_node_builder_set_name) will consume the [`iox2_service_name_h`] handle.
+/// This function needs to be called to destroy the service name!
 ///
 /// # Arguments
 ///
@@ -225,7 +280,7 @@ pub unsafe extern "C" fn iox2_service_name_drop(service_name_handle: iox2_servic
     let service_name = &mut *service_name_handle.as_type();
 
     core::ptr::drop_in_place(service_name.value.as_option_mut());
-    (service_name.deleter)(service_name);
+    service_name.delete();
 }
 
 // END C API
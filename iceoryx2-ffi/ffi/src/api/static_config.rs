@@ -16,11 +16,11 @@ use core::ffi::c_char;
 
 use iceoryx2::service::static_config::messaging_pattern::MessagingPattern;
 use iceoryx2::service::static_config::StaticConfig;
-use iceoryx2_bb_log::fatal_panic;
 
+use super::service::UnsupportedMessagingPattern;
 use crate::{
     iox2_messaging_pattern_e, iox2_static_config_event_t, iox2_static_config_publish_subscribe_t,
-    IOX2_SERVICE_ID_LENGTH, IOX2_SERVICE_NAME_LENGTH,
+    iox2_static_config_request_response_t, IOX2_SERVICE_ID_LENGTH, IOX2_SERVICE_NAME_LENGTH,
 };
 
 #[derive(Clone, Copy)]
@@ -28,6 +28,7 @@ use crate::{
 pub union iox2_static_config_details_t {
     pub event: iox2_static_config_event_t,
     pub publish_subscribe: iox2_static_config_publish_subscribe_t,
+    pub request_response: iox2_static_config_request_response_t,
 }
 
 #[derive(Clone, Copy)]
@@ -39,9 +40,11 @@ pub struct iox2_static_config_t {
     pub details: iox2_static_config_details_t,
 }
 
-impl From<&StaticConfig> for iox2_static_config_t {
-    fn from(value: &StaticConfig) -> Self {
-        Self {
+impl TryFrom<&StaticConfig> for iox2_static_config_t {
+    type Error = UnsupportedMessagingPattern;
+
+    fn try_from(value: &StaticConfig) -> Result<Self, Self::Error> {
+        Ok(Self {
             id: core::array::from_fn(|n| {
                 let raw_service_id = value.service_id().as_str().as_bytes();
                 if n < raw_service_id.len() {
@@ -59,7 +62,7 @@ impl From<&StaticConfig> for iox2_static_config_t {
                     0
                 }
             }),
-            messaging_pattern: value.messaging_pattern().into(),
+            messaging_pattern: value.messaging_pattern().try_into()?,
             details: {
                 match value.messaging_pattern() {
                     MessagingPattern::Event(event) => iox2_static_config_details_t {
@@ -68,11 +71,14 @@ impl From<&StaticConfig> for iox2_static_config_t {
                     MessagingPattern::PublishSubscribe(pubsub) => iox2_static_config_details_t {
                         publish_subscribe: pubsub.into(),
                     },
-                    _ => {
-                        fatal_panic!(from "StaticConfig", "missing implementation for messaging pattern.")
+                    MessagingPattern::RequestResponse(request_response) => {
+                        iox2_static_config_details_t {
+                            request_response: request_response.into(),
+                        }
                     }
+                    _ => return Err(UnsupportedMessagingPattern),
                 }
             },
-        }
+        })
     }
 }
@@ -12,6 +12,7 @@
 
 #![allow(non_camel_case_types)]
 
+use crate::api::thread_safety::serialize;
 use crate::api::{
     c_size_t, iox2_sample_h, iox2_sample_t, iox2_service_type_e, iox2_unique_subscriber_id_h,
     iox2_unique_subscriber_id_t, AssertNonNullHandle, HandleToType, IntoCInt, PayloadFfi,
@@ -38,6 +39,7 @@ pub enum iox2_receive_error_e {
     EXCEEDS_MAX_BORROWED_SAMPLES = IOX2_OK as isize + 1,
     FAILED_TO_ESTABLISH_CONNECTION,
     UNABLE_TO_MAP_SENDERS_DATA_SEGMENT,
+    INTERNAL_FAILURE,
 }
 
 impl IntoCInt for ReceiveError {
@@ -52,6 +54,7 @@ impl IntoCInt for ReceiveError {
             ReceiveError::ConnectionFailure(ConnectionFailure::UnableToMapSendersDataSegment(
                 _,
             )) => iox2_receive_error_e::UNABLE_TO_MAP_SENDERS_DATA_SEGMENT,
+            ReceiveError::InternalFailure => iox2_receive_error_e::INTERNAL_FAILURE,
         }) as c_int
     }
 }
@@ -99,7 +102,7 @@ impl SubscriberUnion {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<SubscriberUnion>
 pub struct iox2_subscriber_storage_t {
-    internal: [u8; 976], // magic number obtained with size_of::<Option<SubscriberUnion>>()
+    internal: [u8; 1792], // magic number obtained with size_of::<Option<SubscriberUnion>>()
 }
 
 #[repr(C)]
@@ -129,37 +132,6 @@ pub type iox2_subscriber_h = *mut iox2_subscriber_h_t;
 /// The non-owning handle for `iox2_subscriber_t`. Passing the handle to an function does not transfers the ownership.
 pub type iox2_subscriber_h_ref = *const iox2_subscriber_h;
 
-impl AssertNonNullHandle for iox2_subscriber_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_subscriber_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_subscriber_h {
-    type Target = *mut iox2_subscriber_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_subscriber_h_ref {
-    type Target = *mut iox2_subscriber_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END type definition
 
 // BEGIN C API
@@ -218,6 +190,8 @@ pub unsafe extern "C" fn iox2_connection_failure_string(
 pub unsafe extern "C" fn iox2_subscriber_buffer_size(
     subscriber_handle: iox2_subscriber_h_ref,
 ) -> c_size_t {
+    let _guard = serialize();
+
     subscriber_handle.assert_non_null();
 
     let subscriber = &mut *subscriber_handle.as_type();
@@ -228,6 +202,33 @@ pub unsafe extern "C" fn iox2_subscriber_buffer_size(
     }
 }
 
+/// Returns the number of samples that were lost since the subscriber's buffer was full
+/// when a publisher tried to deliver them.
+///
+/// # Arguments
+///
+/// * `subscriber_handle` - Must be a valid [`iox2_subscriber_h_ref`]
+///   obtained by [`iox2_port_factory_subscriber_builder_create`](crate::iox2_port_factory_subscriber_builder_create).
+///
+/// # Safety
+///
+/// * `subscriber_handle` must be valid handles
+#[no_mangle]
+pub unsafe extern "C" fn iox2_subscriber_number_of_missed_samples(
+    subscriber_handle: iox2_subscriber_h_ref,
+) -> u64 {
+    let _guard = serialize();
+
+    subscriber_handle.assert_non_null();
+
+    let subscriber = &mut *subscriber_handle.as_type();
+
+    match subscriber.service_type {
+        iox2_service_type_e::IPC => subscriber.value.as_ref().ipc.number_of_missed_samples(),
+        iox2_service_type_e::LOCAL => subscriber.value.as_ref().local.number_of_missed_samples(),
+    }
+}
+
 /// Returns the unique port id of the subscriber.
 ///
 /// # Arguments
@@ -247,6 +248,8 @@ pub unsafe extern "C" fn iox2_subscriber_id(
     id_struct_ptr: *mut iox2_unique_subscriber_id_t,
     id_handle_ptr: *mut iox2_unique_subscriber_id_h,
 ) {
+    let _guard = serialize();
+
     subscriber_handle.assert_non_null();
     debug_assert!(!id_handle_ptr.is_null());
 
@@ -295,6 +298,8 @@ pub unsafe extern "C" fn iox2_subscriber_receive(
     sample_struct_ptr: *mut iox2_sample_t,
     sample_handle_ptr: *mut iox2_sample_h,
 ) -> c_int {
+    let _guard = serialize();
+
     subscriber_handle.assert_non_null();
     debug_assert!(!sample_handle_ptr.is_null());
 
@@ -369,6 +374,8 @@ pub unsafe extern "C" fn iox2_subscriber_has_samples(
     subscriber_handle: iox2_subscriber_h_ref,
     result_ptr: *mut bool,
 ) -> c_int {
+    let _guard = serialize();
+
     subscriber_handle.assert_non_null();
     debug_assert!(!result_ptr.is_null());
 
@@ -406,6 +413,8 @@ pub unsafe extern "C" fn iox2_subscriber_has_samples(
 pub unsafe extern "C" fn iox2_subscriber_update_connections(
     subscriber_handle: iox2_subscriber_h_ref,
 ) -> c_int {
+    let _guard = serialize();
+
     subscriber_handle.assert_non_null();
 
     let subscriber = &mut *subscriber_handle.as_type();
@@ -435,6 +444,8 @@ pub unsafe extern "C" fn iox2_subscriber_update_connections(
 ///   [`iox2_port_factory_subscriber_builder_create`](crate::iox2_port_factory_subscriber_builder_create)!
 #[no_mangle]
 pub unsafe extern "C" fn iox2_subscriber_drop(subscriber_handle: iox2_subscriber_h) {
+    let _guard = serialize();
+
     subscriber_handle.assert_non_null();
 
     let subscriber = &mut *subscriber_handle.as_type();
@@ -447,7 +458,7 @@ pub unsafe extern "C" fn iox2_subscriber_drop(subscriber_handle: iox2_subscriber
             ManuallyDrop::drop(&mut subscriber.value.as_mut().local);
         }
     }
-    (subscriber.deleter)(subscriber);
+    subscriber.delete();
 }
 
 // END C API
@@ -0,0 +1,133 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The ports and samples of iceoryx2 are neither `Send` nor `Sync`, but a C caller cannot see
+//! these bounds. This module provides the two building blocks to handle this in the C API.
+//!
+//! * [`SendCheck`] determines at compile time if the Rust type behind a handle is `Send`. With
+//!   the `handle_validation` feature, a handle of a type which is not `Send` is bound to the
+//!   thread that created it and every use from another thread terminates the process.
+//! * With the `thread_safe_handles` feature, every C API call on a port or sample acquires
+//!   a process wide lock via [`serialize()`]. Those handles can then be shared between threads,
+//!   since all accesses to their internal state are serialized. The blocking and timed wait
+//!   calls of the listener are excluded since they would stall all other ports while waiting.
+//!
+//! Without the `thread_safe_handles` feature, [`serialize()`] is a no-op.
+
+use core::marker::PhantomData;
+
+/// Determines if `T` is `Send` with autoref specialization. It works only when `T` is a
+/// concrete type, therefore it is used by the `#[iceoryx2_ffi(Type)]` macro as
+/// `(&SendCheck::<Type>::new()).is_send()`.
+pub(crate) struct SendCheck<T>(PhantomData<T>);
+
+impl<T> SendCheck<T> {
+    pub(crate) const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+pub(crate) trait IsSend {
+    fn is_send(&self) -> bool {
+        true
+    }
+}
+
+impl<T: Send> IsSend for SendCheck<T> {}
+
+pub(crate) trait IsNotSend {
+    fn is_send(&self) -> bool {
+        false
+    }
+}
+
+impl<T> IsNotSend for &SendCheck<T> {}
+
+#[cfg(feature = "thread_safe_handles")]
+mod serialization {
+    use core::cell::Cell;
+    use core::marker::PhantomData;
+    use std::sync::{Condvar, Mutex, MutexGuard};
+    use std::thread::ThreadId;
+
+    static OWNER: Mutex<Option<ThreadId>> = Mutex::new(None);
+    static RELEASED: Condvar = Condvar::new();
+
+    thread_local! {
+        // the lock is reentrant since C API calls may be nested, e.g. in callbacks
+        static DEPTH: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn owner() -> MutexGuard<'static, Option<ThreadId>> {
+        match OWNER.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    /// Releases the process wide lock when the outermost guard goes out of scope.
+    pub(crate) struct SerializationGuard {
+        _not_send: PhantomData<*const ()>,
+    }
+
+    impl Drop for SerializationGuard {
+        fn drop(&mut self) {
+            let depth = DEPTH.get() - 1;
+            DEPTH.set(depth);
+            if depth == 0 {
+                *owner() = None;
+                RELEASED.notify_one();
+            }
+        }
+    }
+
+    pub(crate) fn serialize() -> SerializationGuard {
+        if DEPTH.get() == 0 {
+            let mut owner = owner();
+            while owner.is_some() {
+                owner = match RELEASED.wait(owner) {
+                    Ok(guard) => guard,
+                    Err(poisoned) => poisoned.into_inner(),
+                };
+            }
+            *owner = Some(std::thread::current().id());
+        }
+        DEPTH.set(DEPTH.get() + 1);
+
+        SerializationGuard {
+            _not_send: PhantomData,
+        }
+    }
+
+    pub(crate) fn is_serialized() -> bool {
+        DEPTH.get() > 0
+    }
+}
+
+#[cfg(feature = "thread_safe_handles")]
+pub(crate) use serialization::*;
+
+#[cfg(not(feature = "thread_safe_handles"))]
+pub(crate) struct SerializationGuard;
+
+#[cfg(not(feature = "thread_safe_handles"))]
+#[inline(always)]
+pub(crate) fn serialize() -> SerializationGuard {
+    SerializationGuard
+}
+
+#[cfg(not(feature = "thread_safe_handles"))]
+#[inline(always)]
+#[allow(dead_code)] // only used with the handle_validation feature
+pub(crate) fn is_serialized() -> bool {
+    false
+}
@@ -51,37 +51,6 @@ pub type iox2_unique_notifier_id_h = *mut iox2_unique_notifier_id_h_t;
 /// The non-owning handle for [`iox2_unique_notifier_id_t`]. Passing the handle to an function does not transfers the ownership.
 pub type iox2_unique_notifier_id_h_ref = *const iox2_unique_notifier_id_h;
 
-impl AssertNonNullHandle for iox2_unique_notifier_id_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_unique_notifier_id_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_unique_notifier_id_h {
-    type Target = *mut iox2_unique_notifier_id_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_unique_notifier_id_h_ref {
-    type Target = *mut iox2_unique_notifier_id_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END types definition
 
 // BEGIN C API
@@ -140,7 +109,7 @@ pub unsafe extern "C" fn iox2_unique_notifier_id_drop(handle: iox2_unique_notifi
 
     let h = &mut *handle.as_type();
     core::ptr::drop_in_place(h.value.as_option_mut());
-    (h.deleter)(h);
+    h.delete();
 }
 
 /// Checks two [`iox2_unique_notifier_id_t`] for equality.
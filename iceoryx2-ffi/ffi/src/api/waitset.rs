@@ -172,37 +172,6 @@ pub type iox2_waitset_h = *mut iox2_waitset_h_t;
 /// The non-owning handle for `iox2_waitset_t`. Passing the handle to an function does not transfers the ownership.
 pub type iox2_waitset_h_ref = *const iox2_waitset_h;
 
-impl AssertNonNullHandle for iox2_waitset_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_waitset_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_waitset_h {
-    type Target = *mut iox2_waitset_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_waitset_h_ref {
-    type Target = *mut iox2_waitset_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 pub type iox2_waitset_run_callback = extern "C" fn(
     iox2_waitset_attachment_id_h,
     iox2_callback_context,
@@ -294,7 +263,7 @@ pub unsafe extern "C" fn iox2_waitset_drop(handle: iox2_waitset_h) {
             ManuallyDrop::drop(&mut waitset.value.as_mut().local);
         }
     }
-    (waitset.deleter)(waitset);
+    waitset.delete();
 }
 
 /// Returns `true` if the [`iox2_waitset_h`] is empty, otherwise false.
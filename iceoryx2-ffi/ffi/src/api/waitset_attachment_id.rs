@@ -79,36 +79,6 @@ pub type iox2_waitset_attachment_id_h = *mut iox2_waitset_attachment_id_h_t;
 /// The non-owning handle for `iox2_waitset_attachment_id_t`. Passing the handle to an function does not transfers the ownership.
 pub type iox2_waitset_attachment_id_h_ref = *const iox2_waitset_attachment_id_h;
 
-impl AssertNonNullHandle for iox2_waitset_attachment_id_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_waitset_attachment_id_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_waitset_attachment_id_h {
-    type Target = *mut iox2_waitset_attachment_id_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_waitset_attachment_id_h_ref {
-    type Target = *mut iox2_waitset_attachment_id_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
 // END type definition
 
 // BEGIN C API
@@ -132,7 +102,7 @@ pub unsafe extern "C" fn iox2_waitset_attachment_id_drop(handle: iox2_waitset_at
             ManuallyDrop::drop(&mut attachment_id.value.as_mut().local);
         }
     }
-    (attachment_id.deleter)(attachment_id);
+    attachment_id.delete();
 }
 
 /// Checks if two provided [`iox2_waitset_attachment_id_h_ref`] are semantically equal.
@@ -52,37 +52,6 @@ pub type iox2_waitset_builder_ptr = *const WaitSetBuilder;
 /// The mutable pointer to the underlying `NodeName`
 pub type iox2_waitset_builder_ptr_mut = *mut WaitSetBuilder;
 
-impl AssertNonNullHandle for iox2_waitset_builder_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_waitset_builder_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_waitset_builder_h {
-    type Target = *mut iox2_waitset_builder_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_waitset_builder_h_ref {
-    type Target = *mut iox2_waitset_builder_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
-
 // END type definition
 
 // BEGIN C API
@@ -129,7 +98,7 @@ pub unsafe extern "C" fn iox2_waitset_builder_drop(handle: iox2_waitset_builder_
 
     let waitset_builder = &mut *handle.as_type();
     core::ptr::drop_in_place(waitset_builder.value.as_option_mut());
-    (waitset_builder.deleter)(waitset_builder);
+    waitset_builder.delete();
 }
 
 /// Creates a new [`iox2_waitset_t`].
@@ -142,8 +111,8 @@ pub unsafe extern "C" fn iox2_waitset_builder_drop(handle: iox2_waitset_builder_
 /// # Safety
 ///
 ///  * `handle` must be acquired with [`iox2_waitset_builder_new()`] and valid
-///  * `handle` is invalidated after a successful operation and cannot used again
-///     with this function.
+///  * `handle` is consumed by this function, independent of the result, and must not be
+///    used afterwards.
 ///  * `struct_ptr` must be either a valid pointer to uninitialized memory or `null`
 ///  * `handle_ptr` must point to a valid uninitialized memory location
 #[no_mangle]
@@ -75,36 +75,6 @@ pub type iox2_waitset_guard_h = *mut iox2_waitset_guard_h_t;
 /// The non-owning handle for `iox2_attachment_id_t`. Passing the handle to an function does not transfers the ownership.
 pub type iox2_waitset_guard_h_ref = *const iox2_waitset_guard_h;
 
-impl AssertNonNullHandle for iox2_waitset_guard_h {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-    }
-}
-
-impl AssertNonNullHandle for iox2_waitset_guard_h_ref {
-    fn assert_non_null(self) {
-        debug_assert!(!self.is_null());
-        unsafe {
-            debug_assert!(!(*self).is_null());
-        }
-    }
-}
-
-impl HandleToType for iox2_waitset_guard_h {
-    type Target = *mut iox2_waitset_guard_t;
-
-    fn as_type(self) -> Self::Target {
-        self as *mut _ as _
-    }
-}
-
-impl HandleToType for iox2_waitset_guard_h_ref {
-    type Target = *mut iox2_waitset_guard_t;
-
-    fn as_type(self) -> Self::Target {
-        unsafe { *self as *mut _ as _ }
-    }
-}
 // END type definition
 
 // BEGIN C API
@@ -130,6 +100,6 @@ pub unsafe extern "C" fn iox2_waitset_guard_drop(handle: iox2_waitset_guard_h) {
             ManuallyDrop::drop(&mut guard.value.as_mut().local);
         }
     }
-    (guard.deleter)(guard);
+    guard.delete();
 }
 // END C API
@@ -0,0 +1,148 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod config {
+    use crate::tests::*;
+    use core::ffi::{c_int, CStr};
+    use iceoryx2::config::Config;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+
+    struct TestConfig {
+        handle: iox2_config_h,
+    }
+
+    impl Drop for TestConfig {
+        fn drop(&mut self) {
+            unsafe { iox2_config_drop(self.handle) };
+        }
+    }
+
+    fn create_default_config() -> TestConfig {
+        let mut handle: iox2_config_h = core::ptr::null_mut();
+        let ret_val = unsafe { iox2_config_default(core::ptr::null_mut(), &mut handle) };
+        assert_that!(ret_val, eq(IOX2_OK));
+        assert_that!(handle.is_null(), eq false);
+
+        TestConfig { handle }
+    }
+
+    #[test]
+    fn default_config_contains_default_values() {
+        let sut = create_default_config();
+        let expected = Config::default();
+
+        unsafe {
+            assert_that!(
+                iox2_config_defaults_publish_subscribe_max_subscribers(&sut.handle),
+                eq expected.defaults.publish_subscribe.max_subscribers
+            );
+            assert_that!(
+                iox2_config_defaults_publish_subscribe_subscriber_max_buffer_size(&sut.handle),
+                eq expected.defaults.publish_subscribe.subscriber_max_buffer_size
+            );
+            assert_that!(
+                iox2_config_defaults_publish_subscribe_zero_payload_on_loan(&sut.handle),
+                eq expected.defaults.publish_subscribe.zero_payload_on_loan
+            );
+            assert_that!(
+                iox2_config_defaults_request_response_max_clients(&sut.handle),
+                eq expected.defaults.request_response.max_clients
+            );
+            assert_that!(
+                iox2_config_defaults_request_response_max_request_buffer_size(&sut.handle),
+                eq expected.defaults.request_response.max_request_buffer_size
+            );
+            assert_that!(
+                CStr::from_ptr(iox2_config_global_prefix(&sut.handle)).to_bytes(),
+                eq expected.global.prefix.as_bytes()
+            );
+        }
+    }
+
+    #[test]
+    fn setters_modify_config_without_touching_the_global_config() {
+        let sut = create_default_config();
+
+        unsafe {
+            iox2_config_defaults_publish_subscribe_set_max_subscribers(&sut.handle, 123);
+            iox2_config_defaults_publish_subscribe_set_subscriber_max_buffer_size(&sut.handle, 45);
+            iox2_config_defaults_publish_subscribe_set_zero_payload_on_loan(&sut.handle, true);
+            iox2_config_defaults_request_response_set_max_clients(&sut.handle, 67);
+            iox2_config_defaults_request_response_set_max_response_buffer_size(&sut.handle, 89);
+            iox2_config_defaults_request_response_set_enable_safe_overflow_for_requests(
+                &sut.handle,
+                false,
+            );
+            assert_that!(
+                iox2_config_global_set_prefix(&sut.handle, c"custom_prefix_".as_ptr()),
+                eq(IOX2_OK)
+            );
+            assert_that!(
+                iox2_config_global_set_root_path(&sut.handle, c"/tmp/custom_root".as_ptr()),
+                eq(IOX2_OK)
+            );
+
+            assert_that!(iox2_config_defaults_publish_subscribe_max_subscribers(&sut.handle), eq 123);
+            assert_that!(iox2_config_defaults_publish_subscribe_subscriber_max_buffer_size(&sut.handle), eq 45);
+            assert_that!(iox2_config_defaults_publish_subscribe_zero_payload_on_loan(&sut.handle), eq true);
+            assert_that!(iox2_config_defaults_request_response_max_clients(&sut.handle), eq 67);
+            assert_that!(iox2_config_defaults_request_response_max_response_buffer_size(&sut.handle), eq 89);
+            assert_that!(
+                iox2_config_defaults_request_response_enable_safe_overflow_for_requests(&sut.handle),
+                eq false
+            );
+            assert_that!(
+                CStr::from_ptr(iox2_config_global_prefix(&sut.handle)),
+                eq c"custom_prefix_"
+            );
+            assert_that!(
+                CStr::from_ptr(iox2_config_global_root_path(&sut.handle)),
+                eq c"/tmp/custom_root"
+            );
+
+            let global_config = &*iox2_config_global_config();
+            assert_that!(
+                global_config.defaults.publish_subscribe.max_subscribers,
+                ne 123
+            );
+        }
+    }
+
+    #[test]
+    fn setting_invalid_prefix_fails() {
+        let sut = create_default_config();
+
+        unsafe {
+            assert_that!(
+                iox2_config_global_set_prefix(&sut.handle, c"in/valid".as_ptr()),
+                eq(iox2_semantic_string_error_e::INVALID_CONTENT as c_int)
+            );
+        }
+    }
+
+    #[test]
+    fn config_from_non_existing_file_fails() {
+        let mut handle: iox2_config_h = core::ptr::null_mut();
+        let ret_val = unsafe {
+            iox2_config_from_file(
+                core::ptr::null_mut(),
+                &mut handle,
+                c"/this/file/does/not/exist.toml".as_ptr(),
+            )
+        };
+
+        assert_that!(
+            ret_val,
+            eq(iox2_config_creation_error_e::CONFIG_FILE_DOES_NOT_EXIST as c_int)
+        );
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// The values of the enums are part of the C ABI and are compiled into the applications which
+// use the generated header. Changing a value is an API breaking change and requires that these
+// tests as well as the release notes are adjusted.
+mod enum_stability {
+    use crate::tests::*;
+    use core::ffi::c_int;
+
+    #[test]
+    fn enums_passed_from_c_have_stable_values() {
+        assert_that!(iox2_service_type_e::LOCAL as c_int, eq 0);
+        assert_that!(iox2_service_type_e::IPC as c_int, eq 1);
+
+        assert_that!(iox2_messaging_pattern_e::PUBLISH_SUBSCRIBE as c_int, eq 0);
+        assert_that!(iox2_messaging_pattern_e::EVENT as c_int, eq 1);
+        assert_that!(iox2_messaging_pattern_e::REQUEST_RESPONSE as c_int, eq 2);
+
+        assert_that!(iox2_callback_progression_e::STOP as c_int, eq 0);
+        assert_that!(iox2_callback_progression_e::CONTINUE as c_int, eq 1);
+
+        assert_that!(iox2_type_variant_e::FIXED_SIZE as c_int, eq 0);
+        assert_that!(iox2_type_variant_e::DYNAMIC as c_int, eq 1);
+
+        assert_that!(iox2_allocation_strategy_e::BEST_FIT as c_int, eq 0);
+        assert_that!(iox2_allocation_strategy_e::POWER_OF_TWO as c_int, eq 1);
+        assert_that!(iox2_allocation_strategy_e::STATIC as c_int, eq 2);
+
+        assert_that!(iox2_unable_to_deliver_strategy_e::BLOCK as c_int, eq 0);
+        assert_that!(iox2_unable_to_deliver_strategy_e::DISCARD_SAMPLE as c_int, eq 1);
+
+        assert_that!(iox2_signal_handling_mode_e::HANDLE_TERMINATION_REQUESTS as c_int, eq 1);
+        assert_that!(iox2_signal_handling_mode_e::DISABLED as c_int, eq 2);
+    }
+
+    #[test]
+    fn enums_returned_to_c_have_stable_values() {
+        assert_that!(iox2_node_state_e::ALIVE as c_int, eq 0);
+        assert_that!(iox2_node_state_e::DEAD as c_int, eq 1);
+        assert_that!(iox2_node_state_e::INACCESSIBLE as c_int, eq 2);
+        assert_that!(iox2_node_state_e::UNDEFINED as c_int, eq 3);
+
+        assert_that!(iox2_waitset_run_result_e::TERMINATION_REQUEST as c_int, eq 1);
+        assert_that!(iox2_waitset_run_result_e::INTERRUPT as c_int, eq 2);
+        assert_that!(iox2_waitset_run_result_e::STOP_REQUEST as c_int, eq 3);
+        assert_that!(iox2_waitset_run_result_e::ALL_EVENTS_HANDLED as c_int, eq 4);
+    }
+
+    #[test]
+    fn error_enums_start_after_ok() {
+        assert_that!(IOX2_OK, eq 0);
+
+        assert_that!(iox2_semantic_string_error_e::INVALID_CONTENT as c_int, eq 1);
+        assert_that!(iox2_config_creation_error_e::FAILED_TO_READ_CONFIG_FILE_CONTENTS as c_int, eq 1);
+        assert_that!(iox2_node_creation_failure_e::INSUFFICIENT_PERMISSIONS as c_int, eq 1);
+        assert_that!(iox2_node_list_failure_e::INSUFFICIENT_PERMISSIONS as c_int, eq 1);
+        assert_that!(iox2_pub_sub_open_or_create_error_e::O_DOES_NOT_EXIST as c_int, eq 1);
+        assert_that!(iox2_event_open_or_create_error_e::O_DOES_NOT_EXIST as c_int, eq 1);
+        assert_that!(iox2_publisher_create_error_e::EXCEEDS_MAX_SUPPORTED_PUBLISHERS as c_int, eq 1);
+        assert_that!(iox2_subscriber_create_error_e::EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS as c_int, eq 1);
+        assert_that!(iox2_loan_error_e::OUT_OF_MEMORY as c_int, eq 1);
+        assert_that!(iox2_receive_error_e::EXCEEDS_MAX_BORROWED_SAMPLES as c_int, eq 1);
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod event {
+    use crate::tests::*;
+    use core::ffi::c_int;
+
+    const MAX_NOTIFIERS: usize = 10;
+
+    struct TestSetup {
+        node: iox2_node_h,
+        service: iox2_port_factory_event_h,
+        notifier: iox2_notifier_h,
+        listener: iox2_listener_h,
+    }
+
+    impl Drop for TestSetup {
+        fn drop(&mut self) {
+            unsafe {
+                iox2_listener_drop(self.listener);
+                iox2_notifier_drop(self.notifier);
+                iox2_port_factory_event_drop(self.service);
+                iox2_node_drop(self.node);
+            }
+        }
+    }
+
+    fn create_notifier(service: iox2_port_factory_event_h_ref) -> (c_int, iox2_notifier_h) {
+        unsafe {
+            let notifier_builder =
+                iox2_port_factory_event_notifier_builder(service, core::ptr::null_mut());
+            let mut notifier: iox2_notifier_h = core::ptr::null_mut();
+            let ret_val = iox2_port_factory_notifier_builder_create(
+                notifier_builder,
+                core::ptr::null_mut(),
+                &mut notifier,
+            );
+            (ret_val, notifier)
+        }
+    }
+
+    fn create_test_setup<S: Service + ServiceTypeMapping>(service_name: &str) -> TestSetup {
+        unsafe {
+            let node = create_node::<S>("");
+            let service = create_event_service(&node, service_name);
+
+            let (ret_val, notifier) = create_notifier(&service);
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            let listener_builder =
+                iox2_port_factory_event_listener_builder(&service, core::ptr::null_mut());
+            let mut listener: iox2_listener_h = core::ptr::null_mut();
+            let ret_val = iox2_port_factory_listener_builder_create(
+                listener_builder,
+                core::ptr::null_mut(),
+                &mut listener,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            TestSetup {
+                node,
+                service,
+                notifier,
+                listener,
+            }
+        }
+    }
+
+    #[test]
+    fn try_wait_one_without_notification_receives_nothing<S: Service + ServiceTypeMapping>() {
+        let sut = create_test_setup::<S>("try/wait/one/without/notification");
+
+        unsafe {
+            let mut event_id = iox2_event_id_t { value: 0 };
+            let mut has_received_one = true;
+            let ret_val =
+                iox2_listener_try_wait_one(&sut.listener, &mut event_id, &mut has_received_one);
+            assert_that!(ret_val, eq(IOX2_OK));
+            assert_that!(has_received_one, eq false);
+        }
+    }
+
+    #[test]
+    fn try_wait_one_receives_custom_event_id<S: Service + ServiceTypeMapping>() {
+        let sut = create_test_setup::<S>("try/wait/one/receives/custom/event/id");
+
+        unsafe {
+            let custom_event_id = iox2_event_id_t { value: 13 };
+            let mut number_of_notified_listeners = 0;
+            let ret_val = iox2_notifier_notify_with_custom_event_id(
+                &sut.notifier,
+                &custom_event_id,
+                &mut number_of_notified_listeners,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+            assert_that!(number_of_notified_listeners, eq 1);
+
+            let mut event_id = iox2_event_id_t { value: 0 };
+            let mut has_received_one = false;
+            let ret_val =
+                iox2_listener_try_wait_one(&sut.listener, &mut event_id, &mut has_received_one);
+            assert_that!(ret_val, eq(IOX2_OK));
+            assert_that!(has_received_one, eq true);
+            assert_that!(event_id.value, eq 13);
+        }
+    }
+
+    #[test]
+    fn timed_wait_one_without_notification_times_out<S: Service + ServiceTypeMapping>() {
+        let sut = create_test_setup::<S>("timed/wait/one/times/out");
+
+        unsafe {
+            let mut event_id = iox2_event_id_t { value: 0 };
+            let mut has_received_one = true;
+            let ret_val = iox2_listener_timed_wait_one(
+                &sut.listener,
+                &mut event_id,
+                &mut has_received_one,
+                0,
+                1_000_000,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+            assert_that!(has_received_one, eq false);
+        }
+    }
+
+    #[test]
+    fn blocking_wait_one_receives_default_event_id<S: Service + ServiceTypeMapping>() {
+        let sut = create_test_setup::<S>("blocking/wait/one/receives/default/event/id");
+
+        unsafe {
+            let ret_val = iox2_notifier_notify(&sut.notifier, core::ptr::null_mut());
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            let mut event_id = iox2_event_id_t { value: 99 };
+            let mut has_received_one = false;
+            let ret_val = iox2_listener_blocking_wait_one(
+                &sut.listener,
+                &mut event_id,
+                &mut has_received_one,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+            assert_that!(has_received_one, eq true);
+            assert_that!(event_id.value, eq 0);
+        }
+    }
+
+    #[test]
+    fn notifier_creation_fails_when_max_notifiers_are_exceeded<S: Service + ServiceTypeMapping>() {
+        let sut = create_test_setup::<S>("notifier/creation/exceeds/max/notifiers");
+
+        let mut notifiers = vec![];
+        for _ in 1..MAX_NOTIFIERS {
+            let (ret_val, notifier) = create_notifier(&sut.service);
+            assert_that!(ret_val, eq(IOX2_OK));
+            notifiers.push(notifier);
+        }
+
+        let (ret_val, _) = create_notifier(&sut.service);
+        assert_that!(
+            ret_val,
+            eq(iox2_notifier_create_error_e::EXCEEDS_MAX_SUPPORTED_NOTIFIERS as c_int)
+        );
+
+        for notifier in notifiers {
+            unsafe { iox2_notifier_drop(notifier) };
+        }
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
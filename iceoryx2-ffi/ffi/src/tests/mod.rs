@@ -10,15 +10,24 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+mod config_tests;
+mod enum_stability_tests;
+mod event_tests;
 mod iceoryx2_settings_tests;
 mod listener_tests;
 mod node_builder_tests;
 mod node_name_tests;
 mod node_tests;
 mod notifier_tests;
+mod publisher_tests;
 mod service_builder_event_tests;
 mod service_builder_pub_sub_tests;
 mod service_name_tests;
+mod service_tests;
+mod subscriber_tests;
+#[cfg(feature = "thread_safe_handles")]
+mod thread_safety_tests;
+mod user_header_tests;
 
 use crate::*;
 use iceoryx2::prelude::*;
@@ -54,7 +63,7 @@ fn create_node<S: Service + ServiceTypeMapping>(node_name: &str) -> iox2_node_h
         assert_that!(ret_val, eq(IOX2_OK));
         iox2_node_builder_set_name(
             &node_builder_handle,
-            iox2_cast_node_name_ptr(node_name_handle),
+            iox2_cast_node_name_ptr(&node_name_handle),
         );
         iox2_node_name_drop(node_name_handle);
 
@@ -89,7 +98,7 @@ fn create_event_service(
         let service_builder_handle = iox2_node_service_builder(
             node_handle,
             core::ptr::null_mut(),
-            iox2_cast_service_name_ptr(service_name_handle),
+            iox2_cast_service_name_ptr(&service_name_handle),
         );
         iox2_service_name_drop(service_name_handle);
 
@@ -108,3 +117,48 @@ fn create_event_service(
         event_factory
     }
 }
+
+fn create_u64_slice_pub_sub_service(
+    node_handle: iox2_node_h_ref,
+    service_name: &str,
+) -> iox2_port_factory_pub_sub_h {
+    unsafe {
+        let mut service_name_handle: iox2_service_name_h = core::ptr::null_mut();
+        let ret_val = iox2_service_name_new(
+            core::ptr::null_mut(),
+            service_name.as_ptr() as *const _,
+            service_name.len(),
+            &mut service_name_handle,
+        );
+        assert_that!(ret_val, eq(IOX2_OK));
+
+        let service_builder_handle = iox2_node_service_builder(
+            node_handle,
+            core::ptr::null_mut(),
+            iox2_cast_service_name_ptr(&service_name_handle),
+        );
+        iox2_service_name_drop(service_name_handle);
+
+        let service_builder_handle = iox2_service_builder_pub_sub(service_builder_handle);
+        let type_name = "u64";
+        let ret_val = iox2_service_builder_pub_sub_set_payload_type_details(
+            &service_builder_handle,
+            iox2_type_variant_e::DYNAMIC,
+            type_name.as_ptr() as *const _,
+            type_name.len(),
+            core::mem::size_of::<u64>(),
+            core::mem::align_of::<u64>(),
+        );
+        assert_that!(ret_val, eq(IOX2_OK));
+
+        let mut pub_sub_factory: iox2_port_factory_pub_sub_h = core::ptr::null_mut();
+        let ret_val = iox2_service_builder_pub_sub_create(
+            service_builder_handle,
+            core::ptr::null_mut(),
+            &mut pub_sub_factory as *mut _,
+        );
+        assert_that!(ret_val, eq(IOX2_OK));
+
+        pub_sub_factory
+    }
+}
@@ -30,7 +30,7 @@ fn basic_node_name_test() -> Result<(), Box<dyn std::error::Error>> {
 
         let mut node_name_len = 0;
         let node_name_chars = iox2_node_name_as_chars(
-            iox2_cast_node_name_ptr(node_name_handle),
+            iox2_cast_node_name_ptr(&node_name_handle),
             &mut node_name_len,
         );
 
@@ -65,6 +65,36 @@ mod node {
         }
     }
 
+    #[test]
+    fn node_id_ptr_accessors_work<S: Service + ServiceTypeMapping>() {
+        unsafe {
+            let node_handle = create_node::<S>("");
+            let node_id_ptr = iox2_node_id(&node_handle, S::service_type());
+            let node_id = *node_id_ptr;
+
+            assert_that!(
+                iox2_node_id_ptr_value_high(node_id_ptr),
+                eq((node_id.value() >> 64) as u64)
+            );
+            assert_that!(
+                iox2_node_id_ptr_value_low(node_id_ptr),
+                eq(node_id.value() as u64)
+            );
+            assert_that!(
+                iox2_node_id_ptr_pid(node_id_ptr),
+                eq(node_id.pid().value() as i32)
+            );
+
+            let mut seconds = 0;
+            let mut nanoseconds = 0;
+            iox2_node_id_ptr_creation_time(node_id_ptr, &mut seconds, &mut nanoseconds);
+            assert_that!(seconds, eq(node_id.creation_time().seconds()));
+            assert_that!(nanoseconds, eq(node_id.creation_time().nanoseconds()));
+
+            iox2_node_drop(node_handle);
+        }
+    }
+
     #[derive(Default)]
     struct NodeListCtx {
         alive: u64,
@@ -0,0 +1,216 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod publisher {
+    use crate::tests::*;
+    use core::ffi::{c_int, c_void};
+
+    const MAX_SLICE_LEN: usize = 8;
+    const MAX_LOANED_SAMPLES: usize = 2;
+
+    struct TestSetup {
+        node: iox2_node_h,
+        service: iox2_port_factory_pub_sub_h,
+        publisher: iox2_publisher_h,
+        subscriber: iox2_subscriber_h,
+    }
+
+    impl Drop for TestSetup {
+        fn drop(&mut self) {
+            unsafe {
+                iox2_subscriber_drop(self.subscriber);
+                iox2_publisher_drop(self.publisher);
+                iox2_port_factory_pub_sub_drop(self.service);
+                iox2_node_drop(self.node);
+            }
+        }
+    }
+
+    fn create_test_setup<S: Service + ServiceTypeMapping>(service_name: &str) -> TestSetup {
+        unsafe {
+            let node = create_node::<S>("");
+            let service = create_u64_slice_pub_sub_service(&node, service_name);
+
+            let publisher_builder =
+                iox2_port_factory_pub_sub_publisher_builder(&service, core::ptr::null_mut());
+            iox2_port_factory_publisher_builder_set_initial_max_slice_len(
+                &publisher_builder,
+                MAX_SLICE_LEN,
+            );
+            iox2_port_factory_publisher_builder_set_max_loaned_samples(
+                &publisher_builder,
+                MAX_LOANED_SAMPLES,
+            );
+            let mut publisher: iox2_publisher_h = core::ptr::null_mut();
+            let ret_val = iox2_port_factory_publisher_builder_create(
+                publisher_builder,
+                core::ptr::null_mut(),
+                &mut publisher,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            let subscriber_builder =
+                iox2_port_factory_pub_sub_subscriber_builder(&service, core::ptr::null_mut());
+            let mut subscriber: iox2_subscriber_h = core::ptr::null_mut();
+            let ret_val = iox2_port_factory_subscriber_builder_create(
+                subscriber_builder,
+                core::ptr::null_mut(),
+                &mut subscriber,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            TestSetup {
+                node,
+                service,
+                publisher,
+                subscriber,
+            }
+        }
+    }
+
+    #[test]
+    fn loaned_slice_can_be_written_and_sent<S: Service + ServiceTypeMapping>() {
+        const NUMBER_OF_ELEMENTS: usize = 3;
+
+        unsafe {
+            let sut = create_test_setup::<S>("loaned/slice/is/sent");
+
+            let mut sample: iox2_sample_mut_h = core::ptr::null_mut();
+            let ret_val = iox2_publisher_loan_slice_uninit(
+                &sut.publisher,
+                core::ptr::null_mut(),
+                &mut sample,
+                NUMBER_OF_ELEMENTS,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+            assert_that!(sample.is_null(), eq false);
+
+            let mut payload: *mut c_void = core::ptr::null_mut();
+            let mut number_of_elements = 0;
+            iox2_sample_mut_payload_mut(&sample, &mut payload, &mut number_of_elements);
+            assert_that!(number_of_elements, eq NUMBER_OF_ELEMENTS);
+
+            let payload = core::slice::from_raw_parts_mut(payload as *mut u64, number_of_elements);
+            for (n, element) in payload.iter_mut().enumerate() {
+                *element = 4711 + n as u64;
+            }
+
+            let mut number_of_recipients = 0;
+            let ret_val = iox2_sample_mut_send(sample, &mut number_of_recipients);
+            assert_that!(ret_val, eq(IOX2_OK));
+            assert_that!(number_of_recipients, eq 1);
+
+            let mut received_sample: iox2_sample_h = core::ptr::null_mut();
+            let ret_val = iox2_subscriber_receive(
+                &sut.subscriber,
+                core::ptr::null_mut(),
+                &mut received_sample,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+            assert_that!(received_sample.is_null(), eq false);
+
+            let mut received_payload: *const c_void = core::ptr::null();
+            let mut number_of_elements = 0;
+            iox2_sample_payload(
+                &received_sample,
+                &mut received_payload,
+                &mut number_of_elements,
+            );
+            assert_that!(number_of_elements, eq NUMBER_OF_ELEMENTS);
+
+            let received_payload =
+                core::slice::from_raw_parts(received_payload as *const u64, number_of_elements);
+            assert_that!(received_payload, eq [4711, 4712, 4713]);
+
+            iox2_sample_drop(received_sample);
+        }
+    }
+
+    #[test]
+    fn loaned_slice_fits_into_caller_provided_storage<S: Service + ServiceTypeMapping>() {
+        unsafe {
+            let sut = create_test_setup::<S>("loaned/slice/into/storage");
+
+            let mut sample_storage = core::mem::MaybeUninit::<iox2_sample_mut_t>::uninit();
+            let mut sample: iox2_sample_mut_h = core::ptr::null_mut();
+            let ret_val = iox2_publisher_loan_slice_uninit(
+                &sut.publisher,
+                sample_storage.as_mut_ptr(),
+                &mut sample,
+                MAX_SLICE_LEN,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            let mut payload: *const c_void = core::ptr::null();
+            let mut number_of_elements = 0;
+            iox2_sample_mut_payload(&sample, &mut payload, &mut number_of_elements);
+            assert_that!(number_of_elements, eq MAX_SLICE_LEN);
+            assert_that!(payload.is_null(), eq false);
+
+            iox2_sample_mut_drop(sample);
+        }
+    }
+
+    #[test]
+    fn loan_of_slice_larger_than_max_slice_len_fails<S: Service + ServiceTypeMapping>() {
+        unsafe {
+            let sut = create_test_setup::<S>("loaned/slice/is/too/large");
+
+            let mut sample: iox2_sample_mut_h = core::ptr::null_mut();
+            let ret_val = iox2_publisher_loan_slice_uninit(
+                &sut.publisher,
+                core::ptr::null_mut(),
+                &mut sample,
+                MAX_SLICE_LEN + 1,
+            );
+            assert_that!(
+                ret_val,
+                eq(iox2_loan_error_e::EXCEEDS_MAX_LOAN_SIZE as c_int)
+            );
+            assert_that!(sample.is_null(), eq true);
+        }
+    }
+
+    #[test]
+    fn dropped_sample_releases_the_loan<S: Service + ServiceTypeMapping>() {
+        unsafe {
+            let sut = create_test_setup::<S>("dropped/sample/releases/loan");
+            let loan = |sample: &mut iox2_sample_mut_h| {
+                iox2_publisher_loan_slice_uninit(&sut.publisher, core::ptr::null_mut(), sample, 1)
+            };
+
+            let mut samples = [core::ptr::null_mut(); MAX_LOANED_SAMPLES];
+            for sample in &mut samples {
+                assert_that!(loan(sample), eq(IOX2_OK));
+            }
+
+            let mut sample: iox2_sample_mut_h = core::ptr::null_mut();
+            assert_that!(
+                loan(&mut sample),
+                eq(iox2_loan_error_e::EXCEEDS_MAX_LOANED_SAMPLES as c_int)
+            );
+
+            iox2_sample_mut_drop(samples[0]);
+            assert_that!(loan(&mut sample), eq(IOX2_OK));
+
+            iox2_sample_mut_drop(sample);
+            iox2_sample_mut_drop(samples[1]);
+        }
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
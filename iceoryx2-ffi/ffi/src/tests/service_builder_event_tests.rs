@@ -33,7 +33,7 @@ mod service_builder {
             let service_builder_handle = iox2_node_service_builder(
                 &node_handle,
                 core::ptr::null_mut(),
-                iox2_cast_service_name_ptr(service_name_handle),
+                iox2_cast_service_name_ptr(&service_name_handle),
             );
             iox2_service_name_drop(service_name_handle);
 
@@ -54,6 +54,50 @@ mod service_builder {
         }
     }
 
+    #[test]
+    fn service_builder_with_caller_provided_storage_test<S: Service + ServiceTypeMapping>() {
+        unsafe {
+            let node_handle = create_node::<S>("bar");
+
+            let service_name = "all/glory/to/the/caller/provided/storage";
+
+            let mut service_name_handle: iox2_service_name_h = core::ptr::null_mut();
+            let ret_val = iox2_service_name_new(
+                core::ptr::null_mut(),
+                service_name.as_ptr() as *const _,
+                service_name.len(),
+                &mut service_name_handle,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            let mut service_builder_storage =
+                core::mem::MaybeUninit::<iox2_service_builder_t>::uninit();
+            let service_builder_handle = iox2_node_service_builder(
+                &node_handle,
+                service_builder_storage.as_mut_ptr(),
+                iox2_cast_service_name_ptr(&service_name_handle),
+            );
+            iox2_service_name_drop(service_name_handle);
+            assert_that!(
+                service_builder_handle as *mut iox2_service_builder_t,
+                eq(service_builder_storage.as_mut_ptr())
+            );
+
+            let service_builder_handle = iox2_service_builder_event(service_builder_handle);
+
+            let mut event_factory: iox2_port_factory_event_h = core::ptr::null_mut();
+            let ret_val = iox2_service_builder_event_open_or_create(
+                service_builder_handle,
+                core::ptr::null_mut(),
+                &mut event_factory as *mut _,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            iox2_port_factory_event_drop(event_factory);
+            iox2_node_drop(node_handle);
+        }
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
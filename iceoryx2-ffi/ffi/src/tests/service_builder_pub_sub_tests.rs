@@ -33,7 +33,7 @@ mod service_builder {
             let service_builder_handle = iox2_node_service_builder(
                 &node_handle,
                 core::ptr::null_mut(),
-                iox2_cast_service_name_ptr(service_name_handle),
+                iox2_cast_service_name_ptr(&service_name_handle),
             );
             iox2_service_name_drop(service_name_handle);
 
@@ -30,7 +30,7 @@ fn basic_service_name_test() -> Result<(), Box<dyn std::error::Error>> {
 
         let mut service_name_len = 0;
         let service_name_chars = iox2_service_name_as_chars(
-            iox2_cast_service_name_ptr(service_name_handle),
+            iox2_cast_service_name_ptr(&service_name_handle),
             &mut service_name_len,
         );
 
@@ -46,3 +46,88 @@ fn basic_service_name_test() -> Result<(), Box<dyn std::error::Error>> {
         Ok(())
     }
 }
+
+#[test]
+fn service_name_len_and_c_str_work() -> Result<(), Box<dyn core::error::Error>> {
+    unsafe {
+        let expected_service_name = ServiceName::new("the/toad/is/watching")?;
+
+        let mut service_name_handle: iox2_service_name_h = core::ptr::null_mut();
+        let ret_val = iox2_service_name_new(
+            core::ptr::null_mut(),
+            expected_service_name.as_str().as_ptr() as *const _,
+            expected_service_name.len() as _,
+            &mut service_name_handle,
+        );
+        assert_that!(ret_val, eq(IOX2_OK));
+        let service_name_ptr = iox2_cast_service_name_ptr(&service_name_handle);
+
+        assert_that!(
+            iox2_service_name_len(service_name_ptr) as usize,
+            eq(expected_service_name.len())
+        );
+
+        let mut buffer = [1 as core::ffi::c_char; 64];
+        let copied =
+            iox2_service_name_as_c_str(service_name_ptr, buffer.as_mut_ptr(), buffer.len() as _);
+        assert_that!(copied as usize, eq(expected_service_name.len()));
+        let c_str = core::ffi::CStr::from_ptr(buffer.as_ptr());
+        assert_that!(c_str.to_str()?, eq(expected_service_name.as_str()));
+
+        let mut small_buffer = [1 as core::ffi::c_char; 4];
+        let copied = iox2_service_name_as_c_str(
+            service_name_ptr,
+            small_buffer.as_mut_ptr(),
+            small_buffer.len() as _,
+        );
+        assert_that!(copied, eq(3));
+        let c_str = core::ffi::CStr::from_ptr(small_buffer.as_ptr());
+        assert_that!(c_str.to_str()?, eq("the"));
+
+        iox2_service_name_drop(service_name_handle);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn invalid_service_names_are_rejected() {
+    unsafe {
+        let mut service_name_handle: iox2_service_name_h = core::ptr::null_mut();
+        let empty_name = "";
+        let ret_val = iox2_service_name_new(
+            core::ptr::null_mut(),
+            empty_name.as_ptr() as *const _,
+            0,
+            &mut service_name_handle,
+        );
+        assert_that!(
+            ret_val,
+            eq(iox2_semantic_string_error_e::INVALID_CONTENT as core::ffi::c_int)
+        );
+        assert_that!(service_name_handle.is_null(), eq(true));
+
+        let too_long_name = "x".repeat(IOX2_SERVICE_NAME_LENGTH + 1);
+        let ret_val = iox2_service_name_validate(
+            too_long_name.as_ptr() as *const _,
+            too_long_name.len() as _,
+        );
+        assert_that!(
+            ret_val,
+            eq(iox2_semantic_string_error_e::EXCEEDS_MAXIMUM_LENGTH as core::ffi::c_int)
+        );
+
+        let invalid_utf8 = [0xffu8, 0xfe];
+        let ret_val =
+            iox2_service_name_validate(invalid_utf8.as_ptr() as *const _, invalid_utf8.len() as _);
+        assert_that!(
+            ret_val,
+            eq(iox2_semantic_string_error_e::INVALID_CONTENT as core::ffi::c_int)
+        );
+
+        let valid_name = "hypnotoad";
+        let ret_val =
+            iox2_service_name_validate(valid_name.as_ptr() as *const _, valid_name.len() as _);
+        assert_that!(ret_val, eq(IOX2_OK));
+    }
+}
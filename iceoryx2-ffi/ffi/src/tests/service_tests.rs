@@ -0,0 +1,148 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service {
+    use crate::tests::*;
+    use core::ffi::CStr;
+
+    struct ListContext<'a> {
+        service_name: &'a str,
+        static_config: Option<iox2_static_config_t>,
+    }
+
+    extern "C" fn list_callback(
+        static_config: *const iox2_static_config_t,
+        callback_ctx: iox2_callback_context,
+    ) -> iox2_callback_progression_e {
+        let ctx = unsafe { &mut *(callback_ctx as *mut ListContext) };
+        let static_config = unsafe { &*static_config };
+        let name = unsafe { CStr::from_ptr(static_config.name.as_ptr()) };
+
+        if name.to_str() == Ok(ctx.service_name) {
+            ctx.static_config = Some(*static_config);
+            return iox2_callback_progression_e::STOP;
+        }
+
+        iox2_callback_progression_e::CONTINUE
+    }
+
+    fn list_service<S: Service + ServiceTypeMapping>(
+        node: iox2_node_h_ref,
+        service_name: &str,
+    ) -> Option<iox2_static_config_t> {
+        let mut ctx = ListContext {
+            service_name,
+            static_config: None,
+        };
+
+        unsafe {
+            let ret_val = iox2_service_list(
+                S::service_type(),
+                iox2_node_config(node),
+                list_callback,
+                (&mut ctx as *mut ListContext).cast(),
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+        }
+
+        ctx.static_config
+    }
+
+    #[test]
+    fn list_provides_publish_subscribe_service<S: Service + ServiceTypeMapping>() {
+        const SERVICE_NAME: &str = "list/provides/publish/subscribe/service";
+
+        unsafe {
+            let node = create_node::<S>("");
+            let service = create_u64_slice_pub_sub_service(&node, SERVICE_NAME);
+
+            let static_config = list_service::<S>(&node, SERVICE_NAME);
+            assert_that!(static_config.is_some(), eq true);
+            let static_config = static_config.unwrap();
+            assert_that!(matches!(static_config.messaging_pattern, iox2_messaging_pattern_e::PUBLISH_SUBSCRIBE), eq true);
+            assert_that!(
+                static_config.details.publish_subscribe.message_type_details.payload.size,
+                eq core::mem::size_of::<u64>()
+            );
+
+            iox2_port_factory_pub_sub_drop(service);
+            iox2_node_drop(node);
+        }
+    }
+
+    #[test]
+    fn list_provides_event_service<S: Service + ServiceTypeMapping>() {
+        const SERVICE_NAME: &str = "list/provides/event/service";
+
+        unsafe {
+            let node = create_node::<S>("");
+            let service = create_event_service(&node, SERVICE_NAME);
+
+            let static_config = list_service::<S>(&node, SERVICE_NAME);
+            assert_that!(static_config.is_some(), eq true);
+            let static_config = static_config.unwrap();
+            assert_that!(matches!(static_config.messaging_pattern, iox2_messaging_pattern_e::EVENT), eq true);
+            assert_that!(static_config.details.event.max_notifiers, eq 10);
+
+            iox2_port_factory_event_drop(service);
+            iox2_node_drop(node);
+        }
+    }
+
+    #[test]
+    fn list_provides_request_response_service<S: Service + ServiceTypeMapping>() {
+        const SERVICE_NAME: &str = "list/provides/request/response/service";
+        const MAX_CLIENTS: usize = 3;
+
+        let rust_node = NodeBuilder::new().create::<S>().unwrap();
+        let _service = rust_node
+            .service_builder(&SERVICE_NAME.try_into().unwrap())
+            .request_response::<u64, u64>()
+            .max_clients(MAX_CLIENTS)
+            .create()
+            .unwrap();
+
+        unsafe {
+            let node = create_node::<S>("");
+
+            let static_config = list_service::<S>(&node, SERVICE_NAME);
+            assert_that!(static_config.is_some(), eq true);
+            let static_config = static_config.unwrap();
+            assert_that!(matches!(static_config.messaging_pattern, iox2_messaging_pattern_e::REQUEST_RESPONSE), eq true);
+            assert_that!(static_config.details.request_response.max_clients, eq MAX_CLIENTS);
+
+            iox2_node_drop(node);
+        }
+    }
+
+    #[test]
+    fn list_does_not_provide_removed_service<S: Service + ServiceTypeMapping>() {
+        const SERVICE_NAME: &str = "list/does/not/provide/removed/service";
+
+        unsafe {
+            let node = create_node::<S>("");
+            let service = create_event_service(&node, SERVICE_NAME);
+            iox2_port_factory_event_drop(service);
+
+            assert_that!(list_service::<S>(&node, SERVICE_NAME).is_none(), eq true);
+
+            iox2_node_drop(node);
+        }
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -0,0 +1,184 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod subscriber {
+    use crate::tests::*;
+    use core::ffi::{c_int, c_void};
+
+    const BUFFER_SIZE: usize = 2;
+    const MAX_BORROWED_SAMPLES: usize = 2;
+
+    struct TestSetup {
+        node: iox2_node_h,
+        service: iox2_port_factory_pub_sub_h,
+        publisher: iox2_publisher_h,
+        subscriber: iox2_subscriber_h,
+    }
+
+    impl Drop for TestSetup {
+        fn drop(&mut self) {
+            unsafe {
+                iox2_subscriber_drop(self.subscriber);
+                iox2_publisher_drop(self.publisher);
+                iox2_port_factory_pub_sub_drop(self.service);
+                iox2_node_drop(self.node);
+            }
+        }
+    }
+
+    impl TestSetup {
+        fn send(&self, payload: &[u64]) {
+            unsafe {
+                let ret_val = iox2_publisher_send_slice_copy(
+                    &self.publisher,
+                    payload.as_ptr().cast(),
+                    core::mem::size_of::<u64>(),
+                    payload.len(),
+                    core::ptr::null_mut(),
+                );
+                assert_that!(ret_val, eq(IOX2_OK));
+            }
+        }
+
+        fn receive(&self, sample: &mut iox2_sample_h) -> c_int {
+            unsafe { iox2_subscriber_receive(&self.subscriber, core::ptr::null_mut(), sample) }
+        }
+
+        fn has_samples(&self) -> bool {
+            let mut has_samples = false;
+            let ret_val =
+                unsafe { iox2_subscriber_has_samples(&self.subscriber, &mut has_samples) };
+            assert_that!(ret_val, eq(IOX2_OK));
+            has_samples
+        }
+    }
+
+    fn create_test_setup<S: Service + ServiceTypeMapping>(service_name: &str) -> TestSetup {
+        unsafe {
+            let node = create_node::<S>("");
+            let service = create_u64_slice_pub_sub_service(&node, service_name);
+
+            let publisher_builder =
+                iox2_port_factory_pub_sub_publisher_builder(&service, core::ptr::null_mut());
+            iox2_port_factory_publisher_builder_set_initial_max_slice_len(&publisher_builder, 8);
+            let mut publisher: iox2_publisher_h = core::ptr::null_mut();
+            let ret_val = iox2_port_factory_publisher_builder_create(
+                publisher_builder,
+                core::ptr::null_mut(),
+                &mut publisher,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            let subscriber_builder =
+                iox2_port_factory_pub_sub_subscriber_builder(&service, core::ptr::null_mut());
+            iox2_port_factory_subscriber_builder_set_buffer_size(&subscriber_builder, BUFFER_SIZE);
+            let mut subscriber: iox2_subscriber_h = core::ptr::null_mut();
+            let ret_val = iox2_port_factory_subscriber_builder_create(
+                subscriber_builder,
+                core::ptr::null_mut(),
+                &mut subscriber,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            TestSetup {
+                node,
+                service,
+                publisher,
+                subscriber,
+            }
+        }
+    }
+
+    #[test]
+    fn receive_without_sample_returns_null<S: Service + ServiceTypeMapping>() {
+        let sut = create_test_setup::<S>("receive/without/sample");
+
+        assert_that!(sut.has_samples(), eq false);
+
+        let mut sample: iox2_sample_h = core::ptr::null_mut();
+        assert_that!(sut.receive(&mut sample), eq(IOX2_OK));
+        assert_that!(sample.is_null(), eq true);
+    }
+
+    #[test]
+    fn received_sample_provides_header_and_payload<S: Service + ServiceTypeMapping>() {
+        let sut = create_test_setup::<S>("received/sample/header/and/payload");
+        sut.send(&[19, 87]);
+
+        assert_that!(sut.has_samples(), eq true);
+
+        let mut sample: iox2_sample_h = core::ptr::null_mut();
+        assert_that!(sut.receive(&mut sample), eq(IOX2_OK));
+        assert_that!(sample.is_null(), eq false);
+        assert_that!(sut.has_samples(), eq false);
+
+        unsafe {
+            let mut header: iox2_publish_subscribe_header_h = core::ptr::null_mut();
+            iox2_sample_header(&sample, core::ptr::null_mut(), &mut header);
+            assert_that!(iox2_publish_subscribe_header_number_of_elements(&header), eq 2);
+            iox2_publish_subscribe_header_drop(header);
+
+            let mut payload: *const c_void = core::ptr::null();
+            let mut number_of_elements = 0;
+            iox2_sample_payload(&sample, &mut payload, &mut number_of_elements);
+            assert_that!(number_of_elements, eq 2);
+            assert_that!(core::slice::from_raw_parts(payload as *const u64, number_of_elements), eq [19, 87]);
+
+            iox2_sample_drop(sample);
+        }
+    }
+
+    #[test]
+    fn buffer_size_and_missed_samples_can_be_acquired<S: Service + ServiceTypeMapping>() {
+        let sut = create_test_setup::<S>("buffer/size/and/missed/samples");
+
+        unsafe {
+            assert_that!(iox2_subscriber_buffer_size(&sut.subscriber), eq BUFFER_SIZE);
+            assert_that!(iox2_subscriber_number_of_missed_samples(&sut.subscriber), eq 0);
+        }
+    }
+
+    #[test]
+    fn dropped_sample_releases_the_borrow<S: Service + ServiceTypeMapping>() {
+        let sut = create_test_setup::<S>("dropped/sample/releases/borrow");
+
+        let mut samples = [core::ptr::null_mut(); MAX_BORROWED_SAMPLES];
+        for sample in &mut samples {
+            sut.send(&[1]);
+            assert_that!(sut.receive(sample), eq(IOX2_OK));
+            assert_that!(sample.is_null(), eq false);
+        }
+
+        sut.send(&[2]);
+        let mut sample: iox2_sample_h = core::ptr::null_mut();
+        assert_that!(
+            sut.receive(&mut sample),
+            eq(iox2_receive_error_e::EXCEEDS_MAX_BORROWED_SAMPLES as c_int)
+        );
+
+        unsafe {
+            iox2_sample_drop(samples[0]);
+            assert_that!(sut.receive(&mut sample), eq(IOX2_OK));
+            assert_that!(sample.is_null(), eq false);
+
+            iox2_sample_drop(sample);
+            iox2_sample_drop(samples[1]);
+        }
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
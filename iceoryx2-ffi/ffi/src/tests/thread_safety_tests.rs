@@ -0,0 +1,87 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod thread_safety {
+    use crate::tests::*;
+    use std::sync::Barrier;
+
+    const NUMBER_OF_THREADS: usize = 4;
+    const NUMBER_OF_SAMPLES_PER_THREAD: usize = 64;
+
+    #[test]
+    fn publisher_handle_can_be_shared_between_threads<S: Service + ServiceTypeMapping>() {
+        unsafe {
+            let node = create_node::<S>("");
+            let service = create_u64_slice_pub_sub_service(&node, "shared/publisher/handle");
+
+            let subscriber_builder =
+                iox2_port_factory_pub_sub_subscriber_builder(&service, core::ptr::null_mut());
+            let mut subscriber: iox2_subscriber_h = core::ptr::null_mut();
+            let ret_val = iox2_port_factory_subscriber_builder_create(
+                subscriber_builder,
+                core::ptr::null_mut(),
+                &mut subscriber,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            let publisher_builder =
+                iox2_port_factory_pub_sub_publisher_builder(&service, core::ptr::null_mut());
+            let mut publisher: iox2_publisher_h = core::ptr::null_mut();
+            let ret_val = iox2_port_factory_publisher_builder_create(
+                publisher_builder,
+                core::ptr::null_mut(),
+                &mut publisher,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            // handles are raw pointers, the C caller shares them as plain addresses
+            let publisher_address = publisher as usize;
+            let barrier = Barrier::new(NUMBER_OF_THREADS);
+            std::thread::scope(|s| {
+                for _ in 0..NUMBER_OF_THREADS {
+                    s.spawn(|| {
+                        let publisher = publisher_address as iox2_publisher_h;
+                        barrier.wait();
+                        for i in 0..NUMBER_OF_SAMPLES_PER_THREAD as u64 {
+                            let ret_val = iox2_publisher_send_slice_copy(
+                                &publisher,
+                                (&i as *const u64).cast(),
+                                core::mem::size_of::<u64>(),
+                                1,
+                                core::ptr::null_mut(),
+                            );
+                            assert_that!(ret_val, eq(IOX2_OK));
+                        }
+                    });
+                }
+            });
+
+            let mut sample: iox2_sample_h = core::ptr::null_mut();
+            let ret_val = iox2_subscriber_receive(&subscriber, core::ptr::null_mut(), &mut sample);
+            assert_that!(ret_val, eq(IOX2_OK));
+            assert_that!(sample.is_null(), eq false);
+            iox2_sample_drop(sample);
+
+            iox2_publisher_drop(publisher);
+            iox2_subscriber_drop(subscriber);
+            iox2_port_factory_pub_sub_drop(service);
+            iox2_node_drop(node);
+        }
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -0,0 +1,215 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod user_header {
+    use crate::tests::*;
+    use core::ffi::{c_int, c_void};
+
+    const USER_HEADER_TYPE_NAME: &str = "CustomHeader";
+
+    #[repr(C, align(16))]
+    struct CustomHeader {
+        id: u64,
+        timestamp: u32,
+    }
+
+    struct TestSetup {
+        node: iox2_node_h,
+        service_builder: iox2_service_builder_pub_sub_h,
+    }
+
+    impl Drop for TestSetup {
+        fn drop(&mut self) {
+            unsafe { iox2_node_drop(self.node) };
+        }
+    }
+
+    fn create_test_setup<S: Service + ServiceTypeMapping>(service_name: &str) -> TestSetup {
+        unsafe {
+            let node = create_node::<S>("");
+
+            let mut service_name_handle: iox2_service_name_h = core::ptr::null_mut();
+            let ret_val = iox2_service_name_new(
+                core::ptr::null_mut(),
+                service_name.as_ptr() as *const _,
+                service_name.len(),
+                &mut service_name_handle,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            let service_builder = iox2_node_service_builder(
+                &node,
+                core::ptr::null_mut(),
+                iox2_cast_service_name_ptr(&service_name_handle),
+            );
+            iox2_service_name_drop(service_name_handle);
+
+            let service_builder = iox2_service_builder_pub_sub(service_builder);
+            let type_name = "u64";
+            let ret_val = iox2_service_builder_pub_sub_set_payload_type_details(
+                &service_builder,
+                iox2_type_variant_e::FIXED_SIZE,
+                type_name.as_ptr() as *const _,
+                type_name.len(),
+                core::mem::size_of::<u64>(),
+                core::mem::align_of::<u64>(),
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            TestSetup {
+                node,
+                service_builder,
+            }
+        }
+    }
+
+    unsafe fn set_user_header_type_details(
+        service_builder: &iox2_service_builder_pub_sub_h,
+        size: usize,
+        alignment: usize,
+    ) -> c_int {
+        iox2_service_builder_pub_sub_set_user_header_type_details(
+            service_builder,
+            iox2_type_variant_e::FIXED_SIZE,
+            USER_HEADER_TYPE_NAME.as_ptr() as *const _,
+            USER_HEADER_TYPE_NAME.len(),
+            size,
+            alignment,
+        )
+    }
+
+    unsafe fn create_service(sut: &TestSetup) -> iox2_port_factory_pub_sub_h {
+        let mut service: iox2_port_factory_pub_sub_h = core::ptr::null_mut();
+        let ret_val = iox2_service_builder_pub_sub_create(
+            sut.service_builder,
+            core::ptr::null_mut(),
+            &mut service,
+        );
+        assert_that!(ret_val, eq(IOX2_OK));
+        service
+    }
+
+    #[test]
+    fn user_header_type_details_are_stored_in_static_config<S: Service + ServiceTypeMapping>() {
+        unsafe {
+            let sut = create_test_setup::<S>("user/header/static/config");
+            let ret_val = set_user_header_type_details(
+                &sut.service_builder,
+                core::mem::size_of::<CustomHeader>(),
+                core::mem::align_of::<CustomHeader>(),
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+            let service = create_service(&sut);
+
+            let mut static_config = core::mem::MaybeUninit::uninit();
+            iox2_port_factory_pub_sub_static_config(&service, static_config.as_mut_ptr());
+            let user_header = static_config.assume_init().message_type_details.user_header;
+
+            assert_that!(user_header.size, eq core::mem::size_of::<CustomHeader>());
+            assert_that!(user_header.alignment, eq core::mem::align_of::<CustomHeader>());
+            let type_name = core::ffi::CStr::from_ptr(user_header.type_name.as_ptr());
+            assert_that!(type_name.to_str(), eq Ok(USER_HEADER_TYPE_NAME));
+
+            iox2_port_factory_pub_sub_drop(service);
+        }
+    }
+
+    #[test]
+    fn user_header_is_transmitted_with_the_sample<S: Service + ServiceTypeMapping>() {
+        unsafe {
+            let sut = create_test_setup::<S>("user/header/is/transmitted");
+            let ret_val = set_user_header_type_details(
+                &sut.service_builder,
+                core::mem::size_of::<CustomHeader>(),
+                core::mem::align_of::<CustomHeader>(),
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+            let service = create_service(&sut);
+
+            let publisher_builder =
+                iox2_port_factory_pub_sub_publisher_builder(&service, core::ptr::null_mut());
+            let mut publisher: iox2_publisher_h = core::ptr::null_mut();
+            let ret_val = iox2_port_factory_publisher_builder_create(
+                publisher_builder,
+                core::ptr::null_mut(),
+                &mut publisher,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            let subscriber_builder =
+                iox2_port_factory_pub_sub_subscriber_builder(&service, core::ptr::null_mut());
+            let mut subscriber: iox2_subscriber_h = core::ptr::null_mut();
+            let ret_val = iox2_port_factory_subscriber_builder_create(
+                subscriber_builder,
+                core::ptr::null_mut(),
+                &mut subscriber,
+            );
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            let mut sample: iox2_sample_mut_h = core::ptr::null_mut();
+            let ret_val =
+                iox2_publisher_loan_slice_uninit(&publisher, core::ptr::null_mut(), &mut sample, 1);
+            assert_that!(ret_val, eq(IOX2_OK));
+
+            let mut user_header: *mut c_void = core::ptr::null_mut();
+            iox2_sample_mut_user_header_mut(&sample, &mut user_header);
+            assert_that!(user_header as usize % core::mem::align_of::<CustomHeader>(), eq 0);
+            user_header.cast::<CustomHeader>().write(CustomHeader {
+                id: 1234,
+                timestamp: 5678,
+            });
+            assert_that!(
+                iox2_sample_mut_send(sample, core::ptr::null_mut()),
+                eq(IOX2_OK)
+            );
+
+            let mut received_sample: iox2_sample_h = core::ptr::null_mut();
+            let ret_val =
+                iox2_subscriber_receive(&subscriber, core::ptr::null_mut(), &mut received_sample);
+            assert_that!(ret_val, eq(IOX2_OK));
+            assert_that!(received_sample.is_null(), eq false);
+
+            let mut user_header: *const c_void = core::ptr::null();
+            iox2_sample_user_header(&received_sample, &mut user_header);
+            let user_header = &*user_header.cast::<CustomHeader>();
+            assert_that!(user_header.id, eq 1234);
+            assert_that!(user_header.timestamp, eq 5678);
+
+            iox2_sample_drop(received_sample);
+            iox2_subscriber_drop(subscriber);
+            iox2_publisher_drop(publisher);
+            iox2_port_factory_pub_sub_drop(service);
+        }
+    }
+
+    #[test]
+    fn user_header_with_invalid_alignment_is_rejected<S: Service + ServiceTypeMapping>() {
+        unsafe {
+            let sut = create_test_setup::<S>("user/header/invalid/alignment");
+            let ret_val = set_user_header_type_details(&sut.service_builder, 8, 3);
+            assert_that!(
+                ret_val,
+                eq(iox2_type_detail_error_e::INVALID_SIZE_OR_ALIGNMENT_VALUE as c_int)
+            );
+
+            let service = create_service(&sut);
+            iox2_port_factory_pub_sub_drop(service);
+        }
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
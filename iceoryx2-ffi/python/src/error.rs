@@ -0,0 +1,32 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::PyErr;
+
+create_exception!(
+    iceoryx2,
+    Iceoryx2Error,
+    PyException,
+    "Raised when an iceoryx2 operation fails. The message contains the Rust error value."
+);
+
+/// Converts any iceoryx2 error into an [`Iceoryx2Error`] that carries the error value, e.g.
+/// `PublishSubscribeOpenError::DoesNotExist`.
+pub(crate) fn into_py_err<E: core::fmt::Display>(error: E) -> PyErr {
+    Iceoryx2Error::new_err(error.to_string())
+}
+
+pub(crate) fn already_consumed(what: &str) -> PyErr {
+    Iceoryx2Error::new_err(format!("The {} was already consumed.", what))
+}
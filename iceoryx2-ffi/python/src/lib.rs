@@ -0,0 +1,67 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Python bindings of iceoryx2 for test automation and prototyping.
+//!
+//! The bindings cover nodes, publish-subscribe with a dynamically sized `bytes` payload and
+//! events, all based on the [`ipc::Service`](iceoryx2::service::ipc::Service). Samples
+//! implement the Python buffer protocol, therefore `memoryview(sample)` or
+//! `numpy.frombuffer(sample, dtype)` provide a zero-copy view of the payload.
+//!
+//! ```python
+//! import iceoryx2
+//!
+//! node = iceoryx2.Node()
+//! service = node.service_builder("My/Funk/ServiceName").publish_subscribe().open_or_create()
+//! publisher = service.publisher()
+//! subscriber = service.subscriber()
+//!
+//! sample = publisher.loan(4)
+//! memoryview(sample)[:] = b"1234"
+//! sample.send()
+//!
+//! received = subscriber.receive()
+//! assert bytes(memoryview(received)) == b"1234"
+//! ```
+//!
+//! The ports are not thread-safe and can only be used in the Python thread that created them.
+
+mod error;
+mod listener;
+mod node;
+mod notifier;
+mod port_factory;
+mod publisher;
+mod sample;
+mod service_builder;
+mod subscriber;
+
+use pyo3::prelude::*;
+
+#[pymodule]
+fn _iceoryx2(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    m.add("Iceoryx2Error", m.py().get_type::<error::Iceoryx2Error>())?;
+    m.add_class::<node::Node>()?;
+    m.add_class::<service_builder::ServiceBuilder>()?;
+    m.add_class::<service_builder::PublishSubscribeServiceBuilder>()?;
+    m.add_class::<service_builder::EventServiceBuilder>()?;
+    m.add_class::<port_factory::PortFactoryPublishSubscribe>()?;
+    m.add_class::<port_factory::PortFactoryEvent>()?;
+    m.add_class::<publisher::Publisher>()?;
+    m.add_class::<subscriber::Subscriber>()?;
+    m.add_class::<sample::Sample>()?;
+    m.add_class::<sample::SampleMut>()?;
+    m.add_class::<notifier::Notifier>()?;
+    m.add_class::<listener::Listener>()?;
+    Ok(())
+}
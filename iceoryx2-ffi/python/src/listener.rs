@@ -0,0 +1,85 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+
+use iceoryx2::prelude::*;
+use pyo3::prelude::*;
+
+use crate::error::into_py_err;
+
+/// Waits for the notifications of the event service and returns the received event ids.
+///
+/// The blocking calls do not react to `KeyboardInterrupt` until a notification arrives, prefer
+/// the timed variants in interactive sessions.
+#[pyclass(unsendable)]
+pub struct Listener(iceoryx2::port::listener::Listener<ipc::Service>);
+
+impl Listener {
+    pub(crate) fn new(listener: iceoryx2::port::listener::Listener<ipc::Service>) -> Self {
+        Self(listener)
+    }
+}
+
+#[pymethods]
+impl Listener {
+    /// Returns the next event id without blocking or `None` when there is none.
+    fn try_wait_one(&self) -> PyResult<Option<usize>> {
+        let event_id = self.0.try_wait_one().map_err(into_py_err)?;
+        Ok(event_id.map(|id| id.as_value()))
+    }
+
+    /// Waits at most `timeout` seconds for the next event id.
+    fn timed_wait_one(&self, timeout: f64) -> PyResult<Option<usize>> {
+        let event_id = self
+            .0
+            .timed_wait_one(Duration::from_secs_f64(timeout))
+            .map_err(into_py_err)?;
+        Ok(event_id.map(|id| id.as_value()))
+    }
+
+    /// Blocks until the next event id arrives.
+    fn blocking_wait_one(&self) -> PyResult<Option<usize>> {
+        let event_id = self.0.blocking_wait_one().map_err(into_py_err)?;
+        Ok(event_id.map(|id| id.as_value()))
+    }
+
+    /// Returns all available event ids without blocking.
+    fn try_wait_all(&self) -> PyResult<Vec<usize>> {
+        let mut event_ids = vec![];
+        self.0
+            .try_wait_all(|id| event_ids.push(id.as_value()))
+            .map_err(into_py_err)?;
+        Ok(event_ids)
+    }
+
+    /// Waits at most `timeout` seconds and returns all event ids that arrived.
+    fn timed_wait_all(&self, timeout: f64) -> PyResult<Vec<usize>> {
+        let mut event_ids = vec![];
+        self.0
+            .timed_wait_all(
+                |id| event_ids.push(id.as_value()),
+                Duration::from_secs_f64(timeout),
+            )
+            .map_err(into_py_err)?;
+        Ok(event_ids)
+    }
+
+    /// Blocks until at least one event id arrives and returns all event ids that arrived.
+    fn blocking_wait_all(&self) -> PyResult<Vec<usize>> {
+        let mut event_ids = vec![];
+        self.0
+            .blocking_wait_all(|id| event_ids.push(id.as_value()))
+            .map_err(into_py_err)?;
+        Ok(event_ids)
+    }
+}
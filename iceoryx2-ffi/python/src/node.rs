@@ -0,0 +1,56 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+
+use iceoryx2::prelude::*;
+use pyo3::prelude::*;
+
+use crate::error::into_py_err;
+use crate::service_builder::ServiceBuilder;
+
+/// The central entry point of iceoryx2. Every service and every port belongs to a node.
+#[pyclass(unsendable)]
+pub struct Node(iceoryx2::node::Node<ipc::Service>);
+
+#[pymethods]
+impl Node {
+    #[new]
+    #[pyo3(signature = (name = None))]
+    fn new(name: Option<&str>) -> PyResult<Self> {
+        let mut builder = NodeBuilder::new();
+        if let Some(name) = name {
+            builder = builder.name(&NodeName::new(name).map_err(into_py_err)?);
+        }
+
+        Ok(Self(builder.create::<ipc::Service>().map_err(into_py_err)?))
+    }
+
+    /// The name of the node.
+    #[getter]
+    fn name(&self) -> String {
+        self.0.name().as_str().to_string()
+    }
+
+    /// Waits for `timeout` seconds and raises an error when a termination signal was received.
+    fn wait(&self, timeout: f64) -> PyResult<()> {
+        self.0
+            .wait(Duration::from_secs_f64(timeout))
+            .map_err(into_py_err)
+    }
+
+    /// Returns the builder to open or create the service with the provided name.
+    fn service_builder(&self, name: &str) -> PyResult<ServiceBuilder> {
+        let name = ServiceName::new(name).map_err(into_py_err)?;
+        Ok(ServiceBuilder::new(self.0.service_builder(&name)))
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::*;
+use pyo3::prelude::*;
+
+use crate::error::into_py_err;
+
+/// Wakes up the listeners of the event service.
+#[pyclass(unsendable)]
+pub struct Notifier(iceoryx2::port::notifier::Notifier<ipc::Service>);
+
+impl Notifier {
+    pub(crate) fn new(notifier: iceoryx2::port::notifier::Notifier<ipc::Service>) -> Self {
+        Self(notifier)
+    }
+}
+
+#[pymethods]
+impl Notifier {
+    /// Notifies all listeners with the provided event id or with the default event id of the
+    /// notifier. Returns the number of listeners that were notified.
+    #[pyo3(signature = (event_id = None))]
+    fn notify(&self, event_id: Option<usize>) -> PyResult<usize> {
+        match event_id {
+            Some(event_id) => self
+                .0
+                .notify_with_custom_event_id(EventId::new(event_id))
+                .map_err(into_py_err),
+            None => self.0.notify().map_err(into_py_err),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::*;
+use iceoryx2::service::port_factory::{event, publish_subscribe};
+use pyo3::prelude::*;
+
+use crate::error::into_py_err;
+use crate::listener::Listener;
+use crate::notifier::Notifier;
+use crate::publisher::Publisher;
+use crate::subscriber::Subscriber;
+
+/// Creates the publishers and subscribers of a publish-subscribe service.
+#[pyclass(unsendable)]
+pub struct PortFactoryPublishSubscribe(publish_subscribe::PortFactory<ipc::Service, [u8], ()>);
+
+impl PortFactoryPublishSubscribe {
+    pub(crate) fn new(factory: publish_subscribe::PortFactory<ipc::Service, [u8], ()>) -> Self {
+        Self(factory)
+    }
+}
+
+#[pymethods]
+impl PortFactoryPublishSubscribe {
+    /// The name of the service.
+    #[getter]
+    fn name(&self) -> String {
+        self.0.name().as_str().to_string()
+    }
+
+    /// Creates a publisher. The samples are reallocated with a power of two strategy when a
+    /// loan exceeds `initial_max_slice_len`.
+    #[pyo3(signature = (initial_max_slice_len = 128))]
+    fn publisher(&self, initial_max_slice_len: usize) -> PyResult<Publisher> {
+        let publisher = self
+            .0
+            .publisher_builder()
+            .initial_max_slice_len(initial_max_slice_len)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .map_err(into_py_err)?;
+        Ok(Publisher::new(publisher))
+    }
+
+    /// Creates a subscriber, the default buffer size is defined by the service.
+    #[pyo3(signature = (buffer_size = None))]
+    fn subscriber(&self, buffer_size: Option<usize>) -> PyResult<Subscriber> {
+        let mut builder = self.0.subscriber_builder();
+        if let Some(buffer_size) = buffer_size {
+            builder = builder.buffer_size(buffer_size);
+        }
+        Ok(Subscriber::new(builder.create().map_err(into_py_err)?))
+    }
+}
+
+/// Creates the notifiers and listeners of an event service.
+#[pyclass(unsendable)]
+pub struct PortFactoryEvent(event::PortFactory<ipc::Service>);
+
+impl PortFactoryEvent {
+    pub(crate) fn new(factory: event::PortFactory<ipc::Service>) -> Self {
+        Self(factory)
+    }
+}
+
+#[pymethods]
+impl PortFactoryEvent {
+    /// The name of the service.
+    #[getter]
+    fn name(&self) -> String {
+        self.0.name().as_str().to_string()
+    }
+
+    fn notifier(&self) -> PyResult<Notifier> {
+        let notifier = self.0.notifier_builder().create().map_err(into_py_err)?;
+        Ok(Notifier::new(notifier))
+    }
+
+    fn listener(&self) -> PyResult<Listener> {
+        let listener = self.0.listener_builder().create().map_err(into_py_err)?;
+        Ok(Listener::new(listener))
+    }
+}
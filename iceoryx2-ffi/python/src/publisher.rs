@@ -0,0 +1,56 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::port::update_connections::UpdateConnections;
+use iceoryx2::prelude::*;
+use pyo3::prelude::*;
+
+use crate::error::into_py_err;
+use crate::sample::SampleMut;
+
+/// Sends `bytes` payloads to all subscribers of the service.
+#[pyclass(unsendable)]
+pub struct Publisher(iceoryx2::port::publisher::Publisher<ipc::Service, [u8], ()>);
+
+impl Publisher {
+    pub(crate) fn new(
+        publisher: iceoryx2::port::publisher::Publisher<ipc::Service, [u8], ()>,
+    ) -> Self {
+        Self(publisher)
+    }
+}
+
+#[pymethods]
+impl Publisher {
+    /// Loans a zero initialized sample with a payload of `size` bytes.
+    fn loan(&self, size: usize) -> PyResult<SampleMut> {
+        Ok(SampleMut::new(
+            self.0.loan_slice(size).map_err(into_py_err)?,
+        ))
+    }
+
+    /// Copies `data` into a new sample, sends it and returns the number of subscribers that
+    /// received it.
+    fn send_copy(&self, data: &[u8]) -> PyResult<usize> {
+        let sample = self
+            .0
+            .loan_slice_uninit(data.len())
+            .map_err(into_py_err)?
+            .write_from_slice(data);
+        sample.send().map_err(into_py_err)
+    }
+
+    /// Explicitly connects to all new subscribers.
+    fn update_connections(&self) -> PyResult<()> {
+        self.0.update_connections().map_err(into_py_err)
+    }
+}
@@ -0,0 +1,200 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::ffi::{c_int, c_void};
+
+use iceoryx2::prelude::*;
+use pyo3::exceptions::PyBufferError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use pyo3::{ffi, PyErr};
+
+use crate::error::{already_consumed, into_py_err};
+
+type RustSample = iceoryx2::sample::Sample<ipc::Service, [u8], ()>;
+type RustSampleMut = iceoryx2::sample_mut::SampleMut<ipc::Service, [u8], ()>;
+
+// The payload must stay valid as long as a buffer view exists, therefore a sample cannot be
+// released or sent while it is exported.
+fn ensure_not_exported(exports: usize) -> PyResult<()> {
+    if exports != 0 {
+        return Err(PyBufferError::new_err(format!(
+            "The payload is still used by {} buffer view(s), release them first.",
+            exports
+        )));
+    }
+    Ok(())
+}
+
+unsafe fn fill_buffer(
+    py: Python<'_>,
+    owner: *mut ffi::PyObject,
+    view: *mut ffi::Py_buffer,
+    payload: *mut u8,
+    len: usize,
+    is_readonly: bool,
+    flags: c_int,
+) -> PyResult<()> {
+    if view.is_null() {
+        return Err(PyBufferError::new_err("The buffer view must not be null."));
+    }
+
+    // increments the reference count of the owner, so that the sample outlives the view
+    if ffi::PyBuffer_FillInfo(
+        view,
+        owner,
+        payload as *mut c_void,
+        len as ffi::Py_ssize_t,
+        is_readonly as c_int,
+        flags,
+    ) == -1
+    {
+        return Err(PyErr::fetch(py));
+    }
+
+    Ok(())
+}
+
+/// A received sample. It implements the buffer protocol to provide a read-only zero-copy
+/// view of the payload, e.g. with `memoryview(sample)` or `numpy.frombuffer(sample)`.
+#[pyclass(unsendable)]
+pub struct Sample {
+    sample: Option<RustSample>,
+    exports: usize,
+}
+
+impl Sample {
+    pub(crate) fn new(sample: RustSample) -> Self {
+        Self {
+            sample: Some(sample),
+            exports: 0,
+        }
+    }
+
+    fn payload_ref(&self) -> PyResult<&[u8]> {
+        Ok(self
+            .sample
+            .as_ref()
+            .ok_or_else(|| already_consumed("sample"))?
+            .payload())
+    }
+}
+
+#[pymethods]
+impl Sample {
+    /// Returns a copy of the payload.
+    fn payload<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        Ok(PyBytes::new(py, self.payload_ref()?))
+    }
+
+    /// Returns the sample to the publisher. Afterwards, the sample cannot be accessed anymore.
+    fn release(&mut self) -> PyResult<()> {
+        ensure_not_exported(self.exports)?;
+        self.sample
+            .take()
+            .ok_or_else(|| already_consumed("sample"))?;
+        Ok(())
+    }
+
+    fn __len__(&self) -> PyResult<usize> {
+        Ok(self.payload_ref()?.len())
+    }
+
+    unsafe fn __getbuffer__(
+        mut slf: PyRefMut<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        let payload = slf.payload_ref()?;
+        let (ptr, len) = (payload.as_ptr() as *mut u8, payload.len());
+        fill_buffer(slf.py(), slf.as_ptr(), view, ptr, len, true, flags)?;
+        slf.exports += 1;
+        Ok(())
+    }
+
+    unsafe fn __releasebuffer__(&mut self, _view: *mut ffi::Py_buffer) {
+        self.exports -= 1;
+    }
+}
+
+/// A loaned sample whose payload can be written before it is sent. It implements the buffer
+/// protocol to provide a writable zero-copy view of the payload, e.g.
+/// `memoryview(sample)[:] = data`.
+#[pyclass(unsendable)]
+pub struct SampleMut {
+    sample: Option<RustSampleMut>,
+    exports: usize,
+}
+
+impl SampleMut {
+    pub(crate) fn new(sample: RustSampleMut) -> Self {
+        Self {
+            sample: Some(sample),
+            exports: 0,
+        }
+    }
+
+    fn payload_mut(&mut self) -> PyResult<&mut [u8]> {
+        Ok(self
+            .sample
+            .as_mut()
+            .ok_or_else(|| already_consumed("sample"))?
+            .payload_mut())
+    }
+}
+
+#[pymethods]
+impl SampleMut {
+    /// Copies `data` into the beginning of the payload.
+    fn write(&mut self, data: &[u8]) -> PyResult<()> {
+        let payload = self.payload_mut()?;
+        if payload.len() < data.len() {
+            return Err(PyBufferError::new_err(format!(
+                "The data ({} bytes) exceeds the payload size ({} bytes).",
+                data.len(),
+                payload.len()
+            )));
+        }
+        payload[..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    /// Sends the sample and returns the number of subscribers that received it.
+    fn send(&mut self) -> PyResult<usize> {
+        ensure_not_exported(self.exports)?;
+        let sample = self
+            .sample
+            .take()
+            .ok_or_else(|| already_consumed("sample"))?;
+        sample.send().map_err(into_py_err)
+    }
+
+    fn __len__(&mut self) -> PyResult<usize> {
+        Ok(self.payload_mut()?.len())
+    }
+
+    unsafe fn __getbuffer__(
+        mut slf: PyRefMut<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        let payload = slf.payload_mut()?;
+        let (ptr, len) = (payload.as_mut_ptr(), payload.len());
+        fill_buffer(slf.py(), slf.as_ptr(), view, ptr, len, false, flags)?;
+        slf.exports += 1;
+        Ok(())
+    }
+
+    unsafe fn __releasebuffer__(&mut self, _view: *mut ffi::Py_buffer) {
+        self.exports -= 1;
+    }
+}
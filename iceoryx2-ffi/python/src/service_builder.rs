@@ -0,0 +1,193 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+
+use iceoryx2::prelude::*;
+use iceoryx2::service::builder;
+use pyo3::prelude::*;
+
+use crate::error::{already_consumed, into_py_err};
+use crate::port_factory::{PortFactoryEvent, PortFactoryPublishSubscribe};
+
+type PublishSubscribeBuilder = builder::publish_subscribe::Builder<[u8], (), ipc::Service>;
+type EventBuilder = builder::event::Builder<ipc::Service>;
+
+/// Selects the messaging pattern of the service.
+#[pyclass(unsendable)]
+pub struct ServiceBuilder(Option<builder::Builder<ipc::Service>>);
+
+impl ServiceBuilder {
+    pub(crate) fn new(builder: builder::Builder<ipc::Service>) -> Self {
+        Self(Some(builder))
+    }
+
+    fn take(&mut self) -> PyResult<builder::Builder<ipc::Service>> {
+        self.0
+            .take()
+            .ok_or_else(|| already_consumed("service builder"))
+    }
+}
+
+#[pymethods]
+impl ServiceBuilder {
+    /// Returns the builder of a publish-subscribe service with a `bytes` payload.
+    fn publish_subscribe(&mut self) -> PyResult<PublishSubscribeServiceBuilder> {
+        Ok(PublishSubscribeServiceBuilder(Some(
+            self.take()?.publish_subscribe::<[u8]>(),
+        )))
+    }
+
+    /// Returns the builder of an event service.
+    fn event(&mut self) -> PyResult<EventServiceBuilder> {
+        Ok(EventServiceBuilder(Some(self.take()?.event())))
+    }
+}
+
+/// Defines the properties of a publish-subscribe service. All setters return the builder so
+/// that they can be chained.
+#[pyclass(unsendable)]
+pub struct PublishSubscribeServiceBuilder(Option<PublishSubscribeBuilder>);
+
+impl PublishSubscribeServiceBuilder {
+    fn update<F: FnOnce(PublishSubscribeBuilder) -> PublishSubscribeBuilder>(
+        mut slf: PyRefMut<'_, Self>,
+        f: F,
+    ) -> PyResult<PyRefMut<'_, Self>> {
+        let builder = slf.take()?;
+        slf.0 = Some(f(builder));
+        Ok(slf)
+    }
+
+    fn take(&mut self) -> PyResult<PublishSubscribeBuilder> {
+        self.0
+            .take()
+            .ok_or_else(|| already_consumed("publish subscribe service builder"))
+    }
+}
+
+#[pymethods]
+impl PublishSubscribeServiceBuilder {
+    fn max_publishers(slf: PyRefMut<'_, Self>, value: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::update(slf, |b| b.max_publishers(value))
+    }
+
+    fn max_subscribers(slf: PyRefMut<'_, Self>, value: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::update(slf, |b| b.max_subscribers(value))
+    }
+
+    fn max_nodes(slf: PyRefMut<'_, Self>, value: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::update(slf, |b| b.max_nodes(value))
+    }
+
+    fn history_size(slf: PyRefMut<'_, Self>, value: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::update(slf, |b| b.history_size(value))
+    }
+
+    fn subscriber_max_buffer_size(
+        slf: PyRefMut<'_, Self>,
+        value: usize,
+    ) -> PyResult<PyRefMut<'_, Self>> {
+        Self::update(slf, |b| b.subscriber_max_buffer_size(value))
+    }
+
+    fn subscriber_max_borrowed_samples(
+        slf: PyRefMut<'_, Self>,
+        value: usize,
+    ) -> PyResult<PyRefMut<'_, Self>> {
+        Self::update(slf, |b| b.subscriber_max_borrowed_samples(value))
+    }
+
+    fn enable_safe_overflow(slf: PyRefMut<'_, Self>, value: bool) -> PyResult<PyRefMut<'_, Self>> {
+        Self::update(slf, |b| b.enable_safe_overflow(value))
+    }
+
+    fn zero_payload_on_loan(slf: PyRefMut<'_, Self>, value: bool) -> PyResult<PyRefMut<'_, Self>> {
+        Self::update(slf, |b| b.zero_payload_on_loan(value))
+    }
+
+    fn open_or_create(&mut self) -> PyResult<PortFactoryPublishSubscribe> {
+        let factory = self.take()?.open_or_create().map_err(into_py_err)?;
+        Ok(PortFactoryPublishSubscribe::new(factory))
+    }
+
+    fn open(&mut self) -> PyResult<PortFactoryPublishSubscribe> {
+        let factory = self.take()?.open().map_err(into_py_err)?;
+        Ok(PortFactoryPublishSubscribe::new(factory))
+    }
+
+    fn create(&mut self) -> PyResult<PortFactoryPublishSubscribe> {
+        let factory = self.take()?.create().map_err(into_py_err)?;
+        Ok(PortFactoryPublishSubscribe::new(factory))
+    }
+}
+
+/// Defines the properties of an event service. All setters return the builder so that they
+/// can be chained.
+#[pyclass(unsendable)]
+pub struct EventServiceBuilder(Option<EventBuilder>);
+
+impl EventServiceBuilder {
+    fn update<F: FnOnce(EventBuilder) -> EventBuilder>(
+        mut slf: PyRefMut<'_, Self>,
+        f: F,
+    ) -> PyResult<PyRefMut<'_, Self>> {
+        let builder = slf.take()?;
+        slf.0 = Some(f(builder));
+        Ok(slf)
+    }
+
+    fn take(&mut self) -> PyResult<EventBuilder> {
+        self.0
+            .take()
+            .ok_or_else(|| already_consumed("event service builder"))
+    }
+}
+
+#[pymethods]
+impl EventServiceBuilder {
+    fn max_notifiers(slf: PyRefMut<'_, Self>, value: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::update(slf, |b| b.max_notifiers(value))
+    }
+
+    fn max_listeners(slf: PyRefMut<'_, Self>, value: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::update(slf, |b| b.max_listeners(value))
+    }
+
+    fn max_nodes(slf: PyRefMut<'_, Self>, value: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::update(slf, |b| b.max_nodes(value))
+    }
+
+    fn event_id_max_value(slf: PyRefMut<'_, Self>, value: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::update(slf, |b| b.event_id_max_value(value))
+    }
+
+    /// Sets the deadline in seconds.
+    fn deadline(slf: PyRefMut<'_, Self>, value: f64) -> PyResult<PyRefMut<'_, Self>> {
+        Self::update(slf, |b| b.deadline(Duration::from_secs_f64(value)))
+    }
+
+    fn open_or_create(&mut self) -> PyResult<PortFactoryEvent> {
+        let factory = self.take()?.open_or_create().map_err(into_py_err)?;
+        Ok(PortFactoryEvent::new(factory))
+    }
+
+    fn open(&mut self) -> PyResult<PortFactoryEvent> {
+        let factory = self.take()?.open().map_err(into_py_err)?;
+        Ok(PortFactoryEvent::new(factory))
+    }
+
+    fn create(&mut self) -> PyResult<PortFactoryEvent> {
+        let factory = self.take()?.create().map_err(into_py_err)?;
+        Ok(PortFactoryEvent::new(factory))
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::port::update_connections::UpdateConnections;
+use iceoryx2::prelude::*;
+use pyo3::prelude::*;
+
+use crate::error::into_py_err;
+use crate::sample::Sample;
+
+/// Receives the `bytes` payloads of all publishers of the service.
+#[pyclass(unsendable)]
+pub struct Subscriber(iceoryx2::port::subscriber::Subscriber<ipc::Service, [u8], ()>);
+
+impl Subscriber {
+    pub(crate) fn new(
+        subscriber: iceoryx2::port::subscriber::Subscriber<ipc::Service, [u8], ()>,
+    ) -> Self {
+        Self(subscriber)
+    }
+}
+
+#[pymethods]
+impl Subscriber {
+    /// Returns the next sample or `None` when no sample is available.
+    fn receive(&self) -> PyResult<Option<Sample>> {
+        Ok(self.0.receive().map_err(into_py_err)?.map(Sample::new))
+    }
+
+    /// Returns true when samples are available.
+    fn has_samples(&self) -> PyResult<bool> {
+        self.0.has_samples().map_err(into_py_err)
+    }
+
+    /// The size of the receive buffer.
+    #[getter]
+    fn buffer_size(&self) -> usize {
+        self.0.buffer_size()
+    }
+
+    /// Explicitly connects to all new publishers.
+    fn update_connections(&self) -> PyResult<()> {
+        self.0.update_connections().map_err(into_py_err)
+    }
+}
@@ -0,0 +1,111 @@
+# Copyright (c) 2025 Contributors to the Eclipse Foundation
+#
+# See the NOTICE file(s) distributed with this work for additional
+# information regarding copyright ownership.
+#
+# This program and the accompanying materials are made available under the
+# terms of the Apache Software License 2.0 which is available at
+# https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+# which is available at https://opensource.org/licenses/MIT.
+#
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+import uuid
+
+import numpy
+import pytest
+
+import iceoryx2
+
+
+def unique_service_name():
+    return "python/tests/" + uuid.uuid4().hex
+
+
+@pytest.fixture
+def node():
+    return iceoryx2.Node()
+
+
+@pytest.fixture
+def pub_sub_service(node):
+    return node.service_builder(unique_service_name()).publish_subscribe().create()
+
+
+def test_node_has_provided_name():
+    assert iceoryx2.Node("my_node").name == "my_node"
+
+
+def test_opening_non_existing_service_fails(node):
+    with pytest.raises(iceoryx2.Iceoryx2Error, match="DoesNotExist"):
+        node.service_builder(unique_service_name()).publish_subscribe().open()
+
+
+def test_service_builder_can_be_used_only_once(node):
+    builder = node.service_builder(unique_service_name())
+    builder.event()
+    with pytest.raises(iceoryx2.Iceoryx2Error):
+        builder.publish_subscribe()
+
+
+def test_send_copy_is_received(pub_sub_service):
+    publisher = pub_sub_service.publisher()
+    subscriber = pub_sub_service.subscriber()
+
+    assert publisher.send_copy(b"hello world") == 1
+
+    sample = subscriber.receive()
+    assert sample is not None
+    assert len(sample) == 11
+    assert sample.payload() == b"hello world"
+    assert subscriber.receive() is None
+
+
+def test_loaned_sample_is_written_and_read_without_copy(pub_sub_service):
+    publisher = pub_sub_service.publisher()
+    subscriber = pub_sub_service.subscriber()
+
+    sample = publisher.loan(8)
+    view = numpy.frombuffer(sample, dtype=numpy.uint8)
+    view[:] = range(8)
+    del view
+    sample.send()
+
+    received = subscriber.receive()
+    values = numpy.frombuffer(received, dtype=numpy.uint8)
+    assert list(values) == list(range(8))
+    assert not values.flags.writeable
+
+
+def test_sample_cannot_be_sent_while_it_is_exported(pub_sub_service):
+    publisher = pub_sub_service.publisher()
+
+    sample = publisher.loan(4)
+    view = memoryview(sample)
+    with pytest.raises(BufferError):
+        sample.send()
+
+    view.release()
+    sample.send()
+    with pytest.raises(iceoryx2.Iceoryx2Error):
+        sample.send()
+
+
+def test_write_exceeding_the_payload_fails(pub_sub_service):
+    sample = pub_sub_service.publisher().loan(2)
+    with pytest.raises(BufferError):
+        sample.write(b"abc")
+
+
+def test_notification_is_received(node):
+    service = node.service_builder(unique_service_name()).event().create()
+    notifier = service.notifier()
+    listener = service.listener()
+
+    assert listener.try_wait_one() is None
+    assert notifier.notify(5) == 1
+    assert listener.timed_wait_one(1.0) == 5
+
+    notifier.notify(1)
+    notifier.notify(2)
+    assert sorted(listener.try_wait_all()) == [1, 2]
@@ -16,7 +16,10 @@
 pub mod settings {
     pub const TEMP_DIRECTORY: &[u8] = b"/tmp/";
     pub const TEST_DIRECTORY: &[u8] = b"/tmp/iceoryx2/tests/";
+    #[cfg(not(target_os = "nto"))]
     pub const SHARED_MEMORY_DIRECTORY: &[u8] = b"/dev/shm/";
+    #[cfg(target_os = "nto")]
+    pub const SHARED_MEMORY_DIRECTORY: &[u8] = b"/dev/shmem/";
     pub const PATH_SEPARATOR: u8 = b'/';
     pub const ROOT: &[u8] = b"/";
     pub const FILENAME_LENGTH: usize = 255;
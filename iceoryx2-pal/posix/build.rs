@@ -25,6 +25,11 @@ fn main() {
     #[cfg(any(target_os = "linux", target_os = "freebsd"))]
     println!("cargo:rustc-link-lib=pthread");
 
+    // QNX is always cross-compiled, therefore the target has to be acquired from cargo
+    if env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("nto") {
+        println!("cargo:rustc-link-lib=socket");
+    }
+
     println!("cargo:rerun-if-changed=src/c/posix.h");
 
     let bindings = if std::env::var("DOCS_RS").is_ok() {
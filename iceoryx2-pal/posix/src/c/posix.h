@@ -21,6 +21,11 @@
 #include <sys/user.h>
 #endif
 
+#ifdef __QNXNTO__
+#include <sys/neutrino.h>
+#include <sys/procfs.h>
+#endif
+
 #ifdef __APPLE__
 #include <libproc.h>
 #include <mach-o/dyld.h>
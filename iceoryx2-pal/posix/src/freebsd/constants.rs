@@ -51,6 +51,12 @@ pub const MCL_CURRENT: int = crate::internal::MCL_CURRENT as _;
 pub const MCL_FUTURE: int = crate::internal::MCL_FUTURE as _;
 pub const MAP_SHARED: int = crate::internal::MAP_SHARED as _;
 pub const MAP_FAILED: *mut void = u64::MAX as *mut void;
+pub const POSIX_TYPED_MEM_ALLOCATE: int = 0x01;
+pub const POSIX_TYPED_MEM_ALLOCATE_CONTIG: int = 0x02;
+pub const POSIX_TYPED_MEM_MAP_ALLOCATABLE: int = 0x04;
+pub const MPOL_BIND: int = 2;
+pub const MPOL_MF_STRICT: uint = 1;
+pub const MPOL_MF_MOVE: uint = 2;
 
 pub const PTHREAD_BARRIER_SERIAL_THREAD: int = crate::internal::PTHREAD_BARRIER_SERIAL_THREAD as _;
 pub const PTHREAD_EXPLICIT_SCHED: int = crate::internal::PTHREAD_EXPLICIT_SCHED as _;
@@ -0,0 +1,202 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The Linux io_uring interface. It is not part of POSIX, therefore all functions fail with
+//! [`Errno::ENOSYS`](crate::posix::Errno::ENOSYS) on every other platform.
+
+#![allow(non_camel_case_types)]
+#![allow(clippy::missing_safety_doc)]
+
+use crate::posix::types::*;
+
+pub const IORING_OFF_SQ_RING: off_t = 0;
+pub const IORING_OFF_CQ_RING: off_t = 0x8000000;
+pub const IORING_OFF_SQES: off_t = 0x10000000;
+
+pub const IORING_ENTER_GETEVENTS: uint = 1 << 0;
+pub const IORING_ENTER_EXT_ARG: uint = 1 << 3;
+
+pub const IORING_FEAT_SINGLE_MMAP: u32 = 1 << 0;
+pub const IORING_FEAT_EXT_ARG: u32 = 1 << 8;
+
+pub const IORING_OP_ASYNC_CANCEL: u8 = 14;
+pub const IORING_OP_RECV: u8 = 27;
+
+pub const IORING_ASYNC_CANCEL_ANY: u32 = 1 << 2;
+
+pub const IORING_REGISTER_EVENTFD: uint = 4;
+
+pub const EFD_CLOEXEC: int = 0o2000000;
+pub const EFD_NONBLOCK: int = 0o4000;
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct io_sqring_offsets {
+    pub head: u32,
+    pub tail: u32,
+    pub ring_mask: u32,
+    pub ring_entries: u32,
+    pub flags: u32,
+    pub dropped: u32,
+    pub array: u32,
+    pub resv1: u32,
+    pub user_addr: u64,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct io_cqring_offsets {
+    pub head: u32,
+    pub tail: u32,
+    pub ring_mask: u32,
+    pub ring_entries: u32,
+    pub overflow: u32,
+    pub cqes: u32,
+    pub flags: u32,
+    pub resv1: u32,
+    pub user_addr: u64,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct io_uring_params {
+    pub sq_entries: u32,
+    pub cq_entries: u32,
+    pub flags: u32,
+    pub sq_thread_cpu: u32,
+    pub sq_thread_idle: u32,
+    pub features: u32,
+    pub wq_fd: u32,
+    pub resv: [u32; 3],
+    pub sq_off: io_sqring_offsets,
+    pub cq_off: io_cqring_offsets,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct io_uring_sqe {
+    pub opcode: u8,
+    pub flags: u8,
+    pub ioprio: u16,
+    pub fd: i32,
+    pub off: u64,
+    pub addr: u64,
+    pub len: u32,
+    pub op_flags: u32,
+    pub user_data: u64,
+    pub buf_index: u16,
+    pub personality: u16,
+    pub splice_fd_in: i32,
+    pub addr3: u64,
+    pub __pad2: [u64; 1],
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct io_uring_cqe {
+    pub user_data: u64,
+    pub res: i32,
+    pub flags: u32,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct __kernel_timespec {
+    pub tv_sec: i64,
+    pub tv_nsec: i64,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct io_uring_getevents_arg {
+    pub sigmask: u64,
+    pub sigmask_sz: u32,
+    pub pad: u32,
+    pub ts: u64,
+}
+
+#[cfg(target_os = "linux")]
+pub unsafe fn io_uring_setup(entries: u32, params: *mut io_uring_params) -> int {
+    libc::syscall(libc::SYS_io_uring_setup, entries, params) as _
+}
+
+/// A wait that times out fails with `ETIME`, it is reported as [`Errno::ETIMEDOUT`] since
+/// `ETIME` is not part of [`Errno`].
+///
+/// [`Errno`]: crate::posix::Errno
+/// [`Errno::ETIMEDOUT`]: crate::posix::Errno::ETIMEDOUT
+#[cfg(target_os = "linux")]
+pub unsafe fn io_uring_enter(
+    fd: int,
+    to_submit: u32,
+    min_complete: u32,
+    flags: uint,
+    arg: *const void,
+    argsz: size_t,
+) -> int {
+    let result = libc::syscall(
+        libc::SYS_io_uring_enter,
+        fd,
+        to_submit,
+        min_complete,
+        flags,
+        arg,
+        argsz,
+    );
+
+    if result < 0 && *libc::__errno_location() == libc::ETIME {
+        crate::posix::Errno::set(crate::posix::Errno::ETIMEDOUT);
+    }
+
+    result as _
+}
+
+#[cfg(target_os = "linux")]
+pub unsafe fn io_uring_register(fd: int, opcode: uint, arg: *const void, nr_args: uint) -> int {
+    libc::syscall(libc::SYS_io_uring_register, fd, opcode, arg, nr_args) as _
+}
+
+#[cfg(target_os = "linux")]
+pub unsafe fn eventfd(initval: uint, flags: int) -> int {
+    libc::eventfd(initval, flags)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub unsafe fn io_uring_setup(_entries: u32, _params: *mut io_uring_params) -> int {
+    crate::posix::Errno::set(crate::posix::Errno::ENOSYS);
+    -1
+}
+
+#[cfg(not(target_os = "linux"))]
+pub unsafe fn io_uring_enter(
+    _fd: int,
+    _to_submit: u32,
+    _min_complete: u32,
+    _flags: uint,
+    _arg: *const void,
+    _argsz: size_t,
+) -> int {
+    crate::posix::Errno::set(crate::posix::Errno::ENOSYS);
+    -1
+}
+
+#[cfg(not(target_os = "linux"))]
+pub unsafe fn io_uring_register(_fd: int, _opcode: uint, _arg: *const void, _nr_args: uint) -> int {
+    crate::posix::Errno::set(crate::posix::Errno::ENOSYS);
+    -1
+}
+
+#[cfg(not(target_os = "linux"))]
+pub unsafe fn eventfd(_initval: uint, _flags: int) -> int {
+    crate::posix::Errno::set(crate::posix::Errno::ENOSYS);
+    -1
+}
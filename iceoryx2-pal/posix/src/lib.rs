@@ -15,6 +15,13 @@
 #![warn(clippy::std_instead_of_alloc)]
 #![warn(clippy::std_instead_of_core)]
 
+#[cfg(all(target_os = "windows", feature = "libc_platform"))]
+compile_error!(
+    "The feature \"libc_platform\" is not supported on Windows since the libc crate does not \
+     provide the POSIX functionality iceoryx2 requires there. Use the default platform \
+     abstraction instead, it is based on the Win32 API."
+);
+
 #[cfg(not(feature = "libc_platform"))]
 pub(crate) mod internal {
     #![allow(non_upper_case_globals)]
@@ -42,17 +49,32 @@ mod freebsd;
 mod linux;
 #[cfg(all(target_os = "macos", not(feature = "libc_platform")))]
 mod macos;
+#[cfg(all(target_os = "nto", not(feature = "libc_platform")))]
+mod qnx;
 #[cfg(all(target_os = "windows", not(feature = "libc_platform")))]
 mod windows;
 
-#[cfg(not(target_os = "windows"))]
+mod io_uring;
+mod pidfd;
+
+// QNX uses the scandir of the C library since its dirent has a flexible array member
+#[cfg(not(any(
+    target_os = "windows",
+    all(target_os = "nto", not(feature = "libc_platform"))
+)))]
 mod scandir;
-#[cfg(not(target_os = "windows"))]
+#[cfg(not(any(
+    target_os = "windows",
+    all(target_os = "nto", not(feature = "libc_platform"))
+)))]
 use scandir::*;
 
 pub mod posix {
     #![allow(dead_code)]
 
+    pub use crate::io_uring::*;
+    pub use crate::pidfd::*;
+
     #[cfg(feature = "libc_platform")]
     pub use crate::libc::*;
 
@@ -62,6 +84,8 @@ pub mod posix {
     pub use crate::linux::*;
     #[cfg(all(target_os = "macos", not(feature = "libc_platform")))]
     pub use crate::macos::*;
+    #[cfg(all(target_os = "nto", not(feature = "libc_platform")))]
+    pub use crate::qnx::*;
     #[cfg(all(target_os = "windows", not(feature = "libc_platform")))]
     pub use crate::windows::*;
 
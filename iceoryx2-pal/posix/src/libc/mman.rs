@@ -94,3 +94,41 @@ pub unsafe fn munmap(addr: *mut void, len: size_t) -> int {
 pub unsafe fn mprotect(addr: *mut void, len: size_t, prot: int) -> int {
     libc::mprotect(addr, len, prot)
 }
+
+pub unsafe fn posix_typed_mem_open(_name: *const c_char, _oflag: int, _tflag: int) -> int {
+    crate::posix::Errno::set(crate::posix::Errno::ENOSYS);
+    -1
+}
+
+#[cfg(target_os = "linux")]
+pub unsafe fn mbind(
+    addr: *mut void,
+    len: size_t,
+    mode: int,
+    nodemask: *const ulong,
+    maxnode: ulong,
+    flags: uint,
+) -> int {
+    libc::syscall(
+        libc::SYS_mbind,
+        addr,
+        len,
+        mode as ulong,
+        nodemask,
+        maxnode,
+        flags,
+    ) as _
+}
+
+#[cfg(not(target_os = "linux"))]
+pub unsafe fn mbind(
+    _addr: *mut void,
+    _len: size_t,
+    _mode: int,
+    _nodemask: *const ulong,
+    _maxnode: ulong,
+    _flags: uint,
+) -> int {
+    crate::posix::Errno::set(crate::posix::Errno::ENOSYS);
+    -1
+}
@@ -94,3 +94,28 @@ pub unsafe fn munmap(addr: *mut void, len: size_t) -> int {
 pub unsafe fn mprotect(addr: *mut void, len: size_t, prot: int) -> int {
     crate::internal::mprotect(addr, len, prot)
 }
+
+pub unsafe fn posix_typed_mem_open(_name: *const c_char, _oflag: int, _tflag: int) -> int {
+    crate::posix::Errno::set(crate::posix::Errno::ENOSYS);
+    -1
+}
+
+pub unsafe fn mbind(
+    addr: *mut void,
+    len: size_t,
+    mode: int,
+    nodemask: *const ulong,
+    maxnode: ulong,
+    flags: uint,
+) -> int {
+    // glibc does not provide mbind, it is part of libnuma
+    libc::syscall(
+        libc::SYS_mbind,
+        addr,
+        len,
+        mode as ulong,
+        nodemask,
+        maxnode,
+        flags,
+    ) as _
+}
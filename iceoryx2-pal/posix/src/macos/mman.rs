@@ -208,6 +208,23 @@ pub unsafe fn mprotect(addr: *mut void, len: size_t, prot: int) -> int {
     crate::internal::mprotect(addr, len, prot)
 }
 
+pub unsafe fn posix_typed_mem_open(_name: *const c_char, _oflag: int, _tflag: int) -> int {
+    Errno::set(Errno::ENOSYS);
+    -1
+}
+
+pub unsafe fn mbind(
+    _addr: *mut void,
+    _len: size_t,
+    _mode: int,
+    _nodemask: *const ulong,
+    _maxnode: ulong,
+    _flags: uint,
+) -> int {
+    Errno::set(Errno::ENOSYS);
+    -1
+}
+
 unsafe fn trim_ascii(value: &[i8]) -> &[u8] {
     for i in 0..value.len() {
         if value[i] == 0 {
@@ -0,0 +1,31 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The Linux process file descriptor interface. It is not part of POSIX, therefore all
+//! functions fail with [`Errno::ENOSYS`](crate::posix::Errno::ENOSYS) on every other platform.
+
+#![allow(clippy::missing_safety_doc)]
+
+use crate::posix::types::*;
+
+pub const PIDFD_NONBLOCK: uint = 0o4000;
+
+#[cfg(target_os = "linux")]
+pub unsafe fn pidfd_open(pid: pid_t, flags: uint) -> int {
+    libc::syscall(libc::SYS_pidfd_open, pid, flags) as _
+}
+
+#[cfg(not(target_os = "linux"))]
+pub unsafe fn pidfd_open(_pid: pid_t, _flags: uint) -> int {
+    crate::posix::Errno::set(crate::posix::Errno::ENOSYS);
+    -1
+}
@@ -0,0 +1,57 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+pub mod constants;
+pub mod dirent;
+pub mod errno;
+pub mod fcntl;
+pub mod inet;
+pub mod mman;
+pub mod pthread;
+pub mod pwd;
+pub mod resource;
+pub mod sched;
+pub mod select;
+pub mod semaphore;
+pub mod signal;
+pub mod socket;
+pub mod stat;
+pub mod stdio;
+pub mod stdlib;
+pub mod string;
+pub mod support;
+pub mod time;
+pub mod types;
+pub mod unistd;
+
+pub use crate::qnx::constants::*;
+pub use crate::qnx::dirent::*;
+pub use crate::qnx::errno::*;
+pub use crate::qnx::fcntl::*;
+pub use crate::qnx::inet::*;
+pub use crate::qnx::mman::*;
+pub use crate::qnx::pthread::*;
+pub use crate::qnx::pwd::*;
+pub use crate::qnx::resource::*;
+pub use crate::qnx::sched::*;
+pub use crate::qnx::select::*;
+pub use crate::qnx::semaphore::*;
+pub use crate::qnx::signal::*;
+pub use crate::qnx::socket::*;
+pub use crate::qnx::stat::*;
+pub use crate::qnx::stdio::*;
+pub use crate::qnx::stdlib::*;
+pub use crate::qnx::string::*;
+pub use crate::qnx::support::*;
+pub use crate::qnx::time::*;
+pub use crate::qnx::types::*;
+pub use crate::qnx::unistd::*;
@@ -0,0 +1,297 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+#![allow(clippy::missing_safety_doc)]
+
+use crate::posix::*;
+
+pub unsafe fn pthread_rwlockattr_setkind_np(_attr: *mut pthread_rwlockattr_t, _pref: int) -> int {
+    0
+}
+
+pub unsafe fn pthread_barrier_wait(barrier: *mut pthread_barrier_t) -> int {
+    crate::internal::pthread_barrier_wait(barrier)
+}
+
+pub unsafe fn pthread_barrier_init(
+    barrier: *mut pthread_barrier_t,
+    attr: *const pthread_barrierattr_t,
+    count: uint,
+) -> int {
+    crate::internal::pthread_barrier_init(barrier, attr, count)
+}
+
+pub unsafe fn pthread_barrier_destroy(barrier: *mut pthread_barrier_t) -> int {
+    crate::internal::pthread_barrier_destroy(barrier)
+}
+
+pub unsafe fn pthread_barrierattr_destroy(attr: *mut pthread_barrierattr_t) -> int {
+    crate::internal::pthread_barrierattr_destroy(attr)
+}
+
+pub unsafe fn pthread_barrierattr_init(attr: *mut pthread_barrierattr_t) -> int {
+    crate::internal::pthread_barrierattr_init(attr)
+}
+
+pub unsafe fn pthread_barrierattr_setpshared(
+    attr: *mut pthread_barrierattr_t,
+    pshared: int,
+) -> int {
+    crate::internal::pthread_barrierattr_setpshared(attr, pshared)
+}
+
+pub unsafe fn pthread_attr_init(attr: *mut pthread_attr_t) -> int {
+    crate::internal::pthread_attr_init(attr)
+}
+
+pub unsafe fn pthread_attr_destroy(attr: *mut pthread_attr_t) -> int {
+    crate::internal::pthread_attr_destroy(attr)
+}
+
+pub unsafe fn pthread_attr_setguardsize(attr: *mut pthread_attr_t, guardsize: size_t) -> int {
+    crate::internal::pthread_attr_setguardsize(attr, guardsize)
+}
+
+pub unsafe fn pthread_attr_setinheritsched(attr: *mut pthread_attr_t, inheritsched: int) -> int {
+    crate::internal::pthread_attr_setinheritsched(attr, inheritsched)
+}
+
+pub unsafe fn pthread_attr_setschedpolicy(attr: *mut pthread_attr_t, policy: int) -> int {
+    crate::internal::pthread_attr_setschedpolicy(attr, policy)
+}
+
+pub unsafe fn pthread_attr_setschedparam(
+    attr: *mut pthread_attr_t,
+    param: *const sched_param,
+) -> int {
+    crate::internal::pthread_attr_setschedparam(attr, param)
+}
+
+pub unsafe fn pthread_attr_setstacksize(attr: *mut pthread_attr_t, stacksize: size_t) -> int {
+    crate::internal::pthread_attr_setstacksize(attr, stacksize)
+}
+
+pub unsafe fn pthread_attr_setaffinity_np(
+    attr: *mut pthread_attr_t,
+    cpusetsize: size_t,
+    cpuset: *const cpu_set_t,
+) -> int {
+    match to_runmask(cpusetsize, cpuset) {
+        Some(runmask) => crate::internal::pthread_attr_setrunmask_np(attr, runmask),
+        None => Errno::EINVAL as _,
+    }
+}
+
+pub unsafe fn pthread_create(
+    thread: *mut pthread_t,
+    attr: *const pthread_attr_t,
+    start_routine: unsafe extern "C" fn(*mut void) -> *mut void,
+    arg: *mut void,
+) -> int {
+    crate::internal::pthread_create(thread, attr, Some(start_routine), arg)
+}
+
+pub unsafe fn pthread_join(thread: pthread_t, retval: *mut *mut void) -> int {
+    crate::internal::pthread_join(thread, retval)
+}
+
+pub unsafe fn pthread_self() -> pthread_t {
+    crate::internal::pthread_self()
+}
+
+pub unsafe fn pthread_setname_np(thread: pthread_t, name: *const c_char) -> int {
+    crate::internal::pthread_setname_np(thread, name)
+}
+
+pub unsafe fn pthread_getname_np(thread: pthread_t, name: *mut c_char, len: size_t) -> int {
+    crate::internal::pthread_getname_np(thread, name, len as _)
+}
+
+pub unsafe fn pthread_kill(thread: pthread_t, sig: int) -> int {
+    crate::internal::pthread_kill(thread, sig)
+}
+
+pub unsafe fn pthread_setaffinity_np(
+    thread: pthread_t,
+    cpusetsize: size_t,
+    cpuset: *const cpu_set_t,
+) -> int {
+    let mut runmask = match to_runmask(cpusetsize, cpuset) {
+        Some(runmask) => runmask as int,
+        None => return Errno::EINVAL as _,
+    };
+
+    // the runmask of a thread can only be changed with the runmask of a set of cpus, an
+    // empty runmask only acquires the current one
+    if runmask == 0 {
+        return Errno::EINVAL as _;
+    }
+
+    match crate::internal::ThreadCtlExt(
+        0,
+        thread as _,
+        crate::internal::_NTO_TCTL_RUNMASK_GET_AND_SET as _,
+        (&mut runmask as *mut int).cast(),
+    ) {
+        -1 => Errno::get() as _,
+        _ => 0,
+    }
+}
+
+pub unsafe fn pthread_getaffinity_np(
+    thread: pthread_t,
+    cpusetsize: size_t,
+    cpuset: *mut cpu_set_t,
+) -> int {
+    if cpusetsize < core::mem::size_of::<uint>() {
+        return Errno::EINVAL as _;
+    }
+
+    let mut runmask: int = 0;
+    if crate::internal::ThreadCtlExt(
+        0,
+        thread as _,
+        crate::internal::_NTO_TCTL_RUNMASK_GET_AND_SET as _,
+        (&mut runmask as *mut int).cast(),
+    ) == -1
+    {
+        return Errno::get() as _;
+    }
+
+    *cpuset = cpu_set_t::new();
+    for cpu in 0..uint::BITS as usize {
+        if runmask as uint & (1 << cpu) != 0 {
+            (*cpuset).set(cpu);
+        }
+    }
+
+    0
+}
+
+// QNX stores the cpu affinity of a thread in a runmask which can represent at most 32 cpus.
+// All cpus beyond are ignored.
+unsafe fn to_runmask(cpusetsize: size_t, cpuset: *const cpu_set_t) -> Option<uint> {
+    if cpusetsize < core::mem::size_of::<uint>() {
+        return None;
+    }
+
+    let mut runmask: uint = 0;
+    for cpu in 0..uint::BITS as usize {
+        if (*cpuset).has(cpu) {
+            runmask |= 1 << cpu;
+        }
+    }
+
+    Some(runmask)
+}
+
+pub unsafe fn pthread_rwlockattr_init(attr: *mut pthread_rwlockattr_t) -> int {
+    crate::internal::pthread_rwlockattr_init(attr)
+}
+
+pub unsafe fn pthread_rwlockattr_destroy(attr: *mut pthread_rwlockattr_t) -> int {
+    crate::internal::pthread_rwlockattr_destroy(attr)
+}
+
+pub unsafe fn pthread_rwlockattr_setpshared(attr: *mut pthread_rwlockattr_t, pshared: int) -> int {
+    crate::internal::pthread_rwlockattr_setpshared(attr, pshared)
+}
+
+pub unsafe fn pthread_rwlock_init(
+    lock: *mut pthread_rwlock_t,
+    attr: *const pthread_rwlockattr_t,
+) -> int {
+    crate::internal::pthread_rwlock_init(lock, attr)
+}
+
+pub unsafe fn pthread_rwlock_destroy(lock: *mut pthread_rwlock_t) -> int {
+    crate::internal::pthread_rwlock_destroy(lock)
+}
+
+pub unsafe fn pthread_rwlock_rdlock(lock: *mut pthread_rwlock_t) -> int {
+    crate::internal::pthread_rwlock_rdlock(lock)
+}
+
+pub unsafe fn pthread_rwlock_tryrdlock(lock: *mut pthread_rwlock_t) -> int {
+    crate::internal::pthread_rwlock_tryrdlock(lock)
+}
+
+pub unsafe fn pthread_rwlock_unlock(lock: *mut pthread_rwlock_t) -> int {
+    crate::internal::pthread_rwlock_unlock(lock)
+}
+
+pub unsafe fn pthread_rwlock_wrlock(lock: *mut pthread_rwlock_t) -> int {
+    crate::internal::pthread_rwlock_wrlock(lock)
+}
+
+pub unsafe fn pthread_rwlock_trywrlock(lock: *mut pthread_rwlock_t) -> int {
+    crate::internal::pthread_rwlock_trywrlock(lock)
+}
+
+pub unsafe fn pthread_mutex_init(
+    mtx: *mut pthread_mutex_t,
+    attr: *const pthread_mutexattr_t,
+) -> int {
+    crate::internal::pthread_mutex_init(mtx, attr)
+}
+
+pub unsafe fn pthread_mutex_destroy(mtx: *mut pthread_mutex_t) -> int {
+    crate::internal::pthread_mutex_destroy(mtx)
+}
+
+pub unsafe fn pthread_mutex_lock(mtx: *mut pthread_mutex_t) -> int {
+    crate::internal::pthread_mutex_lock(mtx)
+}
+
+pub unsafe fn pthread_mutex_timedlock(
+    mtx: *mut pthread_mutex_t,
+    abs_timeout: *const timespec,
+) -> int {
+    crate::internal::pthread_mutex_timedlock(mtx, abs_timeout)
+}
+
+pub unsafe fn pthread_mutex_trylock(mtx: *mut pthread_mutex_t) -> int {
+    crate::internal::pthread_mutex_trylock(mtx)
+}
+
+pub unsafe fn pthread_mutex_unlock(mtx: *mut pthread_mutex_t) -> int {
+    crate::internal::pthread_mutex_unlock(mtx)
+}
+
+pub unsafe fn pthread_mutex_consistent(mtx: *mut pthread_mutex_t) -> int {
+    crate::internal::pthread_mutex_consistent(mtx)
+}
+
+pub unsafe fn pthread_mutexattr_init(attr: *mut pthread_mutexattr_t) -> int {
+    crate::internal::pthread_mutexattr_init(attr)
+}
+
+pub unsafe fn pthread_mutexattr_destroy(attr: *mut pthread_mutexattr_t) -> int {
+    crate::internal::pthread_mutexattr_destroy(attr)
+}
+
+pub unsafe fn pthread_mutexattr_setprotocol(attr: *mut pthread_mutexattr_t, protocol: int) -> int {
+    crate::internal::pthread_mutexattr_setprotocol(attr, protocol)
+}
+
+pub unsafe fn pthread_mutexattr_setpshared(attr: *mut pthread_mutexattr_t, pshared: int) -> int {
+    crate::internal::pthread_mutexattr_setpshared(attr, pshared)
+}
+
+pub unsafe fn pthread_mutexattr_setrobust(attr: *mut pthread_mutexattr_t, robustness: int) -> int {
+    crate::internal::pthread_mutexattr_setrobust(attr, robustness)
+}
+
+pub unsafe fn pthread_mutexattr_settype(attr: *mut pthread_mutexattr_t, mtype: int) -> int {
+    crate::internal::pthread_mutexattr_settype(attr, mtype)
+}
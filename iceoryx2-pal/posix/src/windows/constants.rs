@@ -52,6 +52,12 @@ pub const MCL_CURRENT: int = 16;
 pub const MCL_FUTURE: int = 32;
 pub const MAP_SHARED: int = 64;
 pub const MAP_FAILED: *mut void = 0 as *mut void;
+pub const POSIX_TYPED_MEM_ALLOCATE: int = 0x01;
+pub const POSIX_TYPED_MEM_ALLOCATE_CONTIG: int = 0x02;
+pub const POSIX_TYPED_MEM_MAP_ALLOCATABLE: int = 0x04;
+pub const MPOL_BIND: int = 2;
+pub const MPOL_MF_STRICT: uint = 1;
+pub const MPOL_MF_MOVE: uint = 2;
 
 pub const PTHREAD_MUTEX_NORMAL: int = 1;
 pub const PTHREAD_MUTEX_RECURSIVE: int = 2;
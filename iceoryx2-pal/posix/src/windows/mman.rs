@@ -37,6 +37,7 @@ use windows_sys::Win32::{
 };
 
 use super::win32_handle_translator::{FdHandleEntry, FileHandle, HandleTranslator, ShmHandle};
+use super::win32_security_attributes::with_named_object_security_attributes;
 
 const MAX_SUPPORTED_SHM_SIZE: u64 = 128 * 1024 * 1024 * 1024;
 
@@ -142,14 +143,16 @@ pub unsafe fn shm_open(name: *const c_char, oflag: int, mode: mode_t) -> int {
         const MAX_SIZE_HIGH: u32 = ((MAX_SUPPORTED_SHM_SIZE >> 32) & 0xFFFFFFFF) as u32;
 
         let last_mapping_error;
-        (shm_handle, last_mapping_error) = win32call! {CreateFileMappingA(
-            handle,
-            core::ptr::null::<SECURITY_ATTRIBUTES>(),
-            PAGE_READWRITE | SEC_RESERVE,
-            MAX_SIZE_HIGH,
-            MAX_SIZE_LOW,
-            name as *const u8,
-        ), ignore ERROR_ALREADY_EXISTS};
+        (shm_handle, last_mapping_error) = with_named_object_security_attributes(|attr| {
+            win32call! {CreateFileMappingA(
+                handle,
+                attr,
+                PAGE_READWRITE | SEC_RESERVE,
+                MAX_SIZE_HIGH,
+                MAX_SIZE_LOW,
+                name as *const u8,
+            ), ignore ERROR_ALREADY_EXISTS}
+        });
 
         if shm_handle == 0 {
             Errno::set(Errno::EACCES);
@@ -228,16 +231,17 @@ unsafe fn create_state_handle(name: *const c_char) -> HANDLE {
     let name = remove_leading_path_separator(name);
 
     let create_file = || {
-        let (handle, last_error) = win32call! {CreateFileA(
-            shm_file_path(name, SHM_STATE_SUFFIX).as_ptr(),
-            GENERIC_WRITE | GENERIC_READ,
-            FILE_SHARE_READ | FILE_SHARE_WRITE | FILE_SHARE_DELETE,
-            core::ptr::null::<SECURITY_ATTRIBUTES>(),
-            CREATE_NEW,
-            FILE_ATTRIBUTE_NORMAL,
-            0,
-        ), ignore ERROR_FILE_EXISTS};
-        (handle, last_error)
+        with_named_object_security_attributes(|attr| {
+            win32call! {CreateFileA(
+                shm_file_path(name, SHM_STATE_SUFFIX).as_ptr(),
+                GENERIC_WRITE | GENERIC_READ,
+                FILE_SHARE_READ | FILE_SHARE_WRITE | FILE_SHARE_DELETE,
+                attr,
+                CREATE_NEW,
+                FILE_ATTRIBUTE_NORMAL,
+                0,
+            ), ignore ERROR_FILE_EXISTS}
+        })
     };
 
     let (mut handle, last_error) = create_file();
@@ -380,3 +384,20 @@ pub unsafe fn munmap(addr: *mut void, len: size_t) -> int {
 pub unsafe fn mprotect(addr: *mut void, len: size_t, prot: int) -> int {
     -1
 }
+
+pub unsafe fn posix_typed_mem_open(_name: *const c_char, _oflag: int, _tflag: int) -> int {
+    Errno::set(Errno::ENOSYS);
+    -1
+}
+
+pub unsafe fn mbind(
+    addr: *mut void,
+    len: size_t,
+    mode: int,
+    nodemask: *const ulong,
+    maxnode: ulong,
+    flags: uint,
+) -> int {
+    Errno::set(Errno::ENOSYS);
+    -1
+}
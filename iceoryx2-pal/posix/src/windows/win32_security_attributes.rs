@@ -77,6 +77,80 @@ const IDENT_OTHERS: &[u8] = b"WD";
 const IDENT_GROUP: &[u8] = b"SU";
 const IDENT_OWNER: &[u8] = b"BU";
 
+struct SecurityDescriptor(PSECURITY_DESCRIPTOR);
+
+// the security descriptor is immutable after its creation and only freed on drop
+unsafe impl Send for SecurityDescriptor {}
+
+impl Drop for SecurityDescriptor {
+    fn drop(&mut self) {
+        unsafe {
+            win32call! {LocalFree(self.0 as isize)}
+        };
+    }
+}
+
+static NAMED_OBJECT_SECURITY_DESCRIPTOR: std::sync::Mutex<Option<SecurityDescriptor>> =
+    std::sync::Mutex::new(None);
+
+/// Defines the security descriptor in the SDDL format that is applied to all named objects,
+/// like shared memory, that are created afterwards by the process. When `sddl` is null the
+/// default security descriptor is restored. Returns false when `sddl` is not a valid SDDL
+/// string.
+pub unsafe fn set_named_object_security_descriptor(sddl: *const c_char) -> bool {
+    let security_descriptor = if sddl.is_null() {
+        None
+    } else {
+        let mut security_descriptor: PSECURITY_DESCRIPTOR = core::ptr::null_mut::<void>();
+        let (convert_result, _) = win32call! { ConvertStringSecurityDescriptorToSecurityDescriptorA(
+            sddl as *const u8,
+            SDDL_REVISION_1,
+            &mut security_descriptor,
+            core::ptr::null_mut::<u32>(),
+        ) };
+
+        if convert_result == FALSE {
+            return false;
+        }
+
+        Some(SecurityDescriptor(security_descriptor))
+    };
+
+    match NAMED_OBJECT_SECURITY_DESCRIPTOR.lock() {
+        Ok(mut guard) => *guard = security_descriptor,
+        Err(poisoned) => *poisoned.into_inner() = security_descriptor,
+    }
+
+    true
+}
+
+/// Calls `f` with the [`SECURITY_ATTRIBUTES`] that shall be used to create a named object.
+/// When no security descriptor was defined with [`set_named_object_security_descriptor()`]
+/// a null pointer is provided so that the default security descriptor is used.
+pub(crate) fn with_named_object_security_attributes<
+    R,
+    F: FnOnce(*const SECURITY_ATTRIBUTES) -> R,
+>(
+    f: F,
+) -> R {
+    let guard = match NAMED_OBJECT_SECURITY_DESCRIPTOR.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+
+    match guard.as_ref() {
+        Some(security_descriptor) => {
+            let attr = SECURITY_ATTRIBUTES {
+                nLength: core::mem::size_of::<SECURITY_ATTRIBUTES>() as u32,
+                lpSecurityDescriptor: security_descriptor.0,
+                bInheritHandle: FALSE,
+            };
+            f(&attr)
+        }
+        None => f(core::ptr::null::<SECURITY_ATTRIBUTES>()),
+    }
+}
+
 fn add_to_sd_string(data: &mut [u8], add: &[u8]) {
     let mut start_adding = false;
     let mut start = 0;
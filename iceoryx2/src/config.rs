@@ -55,6 +55,68 @@
 //! # }
 //! ```
 //!
+//! ## Generate Config From Layered Sources
+//!
+//! Every layer only needs to contain the entries it overrides. Later layers override the
+//! entries of earlier layers. A config file can include other config files with a top-level
+//! `include = ["other.toml"]` entry, relative paths are resolved relative to the including file.
+//!
+//! ```no_run
+//! use iceoryx2::config::ConfigBuilder;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let custom_config = ConfigBuilder::new()
+//!     // system, user and project local config file and the file stored in `IOX2_CONFIG`
+//!     .default_layers()
+//!     .file(&FilePath::new(b"my/custom/config/file.toml")?)
+//!     .override_with("defaults.publish-subscribe.max-publishers = 5")
+//!     .create()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Reload Config At Runtime
+//!
+//! Only the entries that are used when new resources are created, like the [`Defaults`], can be
+//! changed at runtime. See [`ConfigWatcher`] for the full list.
+//!
+//! ```no_run
+//! use iceoryx2::config::Config;
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! // the file is monitored in a background thread as long as the watcher exists
+//! let watcher = Config::watch(
+//!     &FilePath::new(b"my/custom/config/file.toml")?,
+//!     |config| println!("new max publishers: {}", config.defaults.publish_subscribe.max_publishers))?;
+//!
+//! // new nodes use the reloaded config
+//! let node = NodeBuilder::new()
+//!     .config(&watcher.config())
+//!     .create::<ipc::Service>()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Set Global Config From Environment Variables
+//!
+//! Every config entry can be overridden with an environment variable, e.g.
+//! `IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_SUBSCRIBERS=4`. See
+//! [`ConfigBuilder::environment_variables()`] for the naming of the variables.
+//!
+//! ```no_run
+//! use iceoryx2::config::Config;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! Config::setup_global_config_from_env()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## Generate Config From Custom File
 //!
 //! ```no_run
@@ -69,26 +131,44 @@
 //! # }
 //! ```
 
+extern crate alloc;
+
+use alloc::collections::{BTreeMap, BTreeSet};
 use core::time::Duration;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::{lazy_singleton::*, CallbackProgression};
 use iceoryx2_bb_posix::{
+    access_control_list::{AccessControlList, AccessControlListBuilder},
+    creation_mode::CreationMode,
+    directory::Directory,
     file::{FileBuilder, FileOpenError},
-    shared_memory::AccessMode,
+    shared_memory::{AccessMode, HugePageSize, HugePages},
     system_configuration::get_global_config_path,
+    unique_system_id::UniqueSystemId,
 };
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_bb_system_types::path::Path;
 use serde::{Deserialize, Serialize};
 
-use iceoryx2_bb_log::{fail, fatal_panic, trace, warn};
+use alloc::sync::Arc;
+use iceoryx2_bb_log::{fail, fatal_panic, set_log_level_filter, trace, warn};
+use std::sync::{mpsc, Mutex, MutexGuard};
+use std::thread::JoinHandle;
 
+use crate::port::out_of_memory_strategy::OutOfMemoryStrategy;
 use crate::port::unable_to_deliver_strategy::UnableToDeliverStrategy;
+use crate::service::service_id::ServiceNameHashing;
+
+pub use iceoryx2_cal::static_storage::EncryptionKeyProvider;
 
 const DEFAULT_CONFIG_FILE_NAME: &[u8] = b"iceoryx2.toml";
 const RELATIVE_LOCAL_CONFIG_PATH: &[u8] = b"config";
 const RELATIVE_CONFIG_FILE_PATH: &[u8] = b"iceoryx2";
+const CONFIG_FILE_ENV_VARIABLE: &str = "IOX2_CONFIG";
+const ENV_VARIABLE_PREFIX: &str = "IOX2_";
+const INCLUDE_KEY: &str = "include";
+const MAX_INCLUDE_DEPTH: usize = 16;
 
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 enum ConfigIterationFailure {
@@ -97,8 +177,8 @@ enum ConfigIterationFailure {
     TooLongUserConfigDirectory,
 }
 
-/// Failures occurring while creating a new [`Config`] object with [`Config::from_file()`] or
-/// [`Config::setup_global_config_from_file()`]
+/// Failures occurring while creating a new [`Config`] object with [`Config::from_file()`],
+/// [`Config::setup_global_config_from_file()`] or [`ConfigBuilder::create()`]
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum ConfigCreationError {
     /// The config file could not be read.
@@ -111,6 +191,10 @@ pub enum ConfigCreationError {
     ConfigFileDoesNotExist,
     /// Since the config file could not be opened
     UnableToOpenConfigFile,
+    /// The config contains an entry that is not known.
+    UnknownConfigEntry,
+    /// The config files include each other recursively or the includes are nested too deep.
+    MaxIncludeDepthExceeded,
 }
 
 impl core::fmt::Display for ConfigCreationError {
@@ -121,6 +205,233 @@ impl core::fmt::Display for ConfigCreationError {
 
 impl core::error::Error for ConfigCreationError {}
 
+/// Failures occurring while a [`ConfigWatcher`] is created with [`Config::watch()`]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ConfigWatchError {
+    /// The config file could not be loaded.
+    ConfigCreationError(ConfigCreationError),
+    /// The background thread that monitors the config file could not be started.
+    UnableToStartMonitoringThread,
+}
+
+impl From<ConfigCreationError> for ConfigWatchError {
+    fn from(value: ConfigCreationError) -> Self {
+        ConfigWatchError::ConfigCreationError(value)
+    }
+}
+
+impl core::fmt::Display for ConfigWatchError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ConfigWatchError::{:?}", self)
+    }
+}
+
+impl core::error::Error for ConfigWatchError {}
+
+/// Failures occurring while a [`ConfigWatcher`] reloads the watched config file
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ConfigReloadError {
+    /// The config file could not be loaded.
+    ConfigCreationError(ConfigCreationError),
+    /// The config file changed an entry that cannot be changed at runtime. None of the changes
+    /// were applied.
+    NonReloadableEntryChanged,
+}
+
+impl From<ConfigCreationError> for ConfigReloadError {
+    fn from(value: ConfigCreationError) -> Self {
+        ConfigReloadError::ConfigCreationError(value)
+    }
+}
+
+impl core::fmt::Display for ConfigReloadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ConfigReloadError::{:?}", self)
+    }
+}
+
+impl core::error::Error for ConfigReloadError {}
+
+/// Failures occurring while a [`Config`] is serialized with [`Config::to_toml_string()`] or
+/// [`Config::to_json_string()`]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ConfigSerializationError {
+    /// The config could not be represented in the requested format.
+    UnableToSerializeContents,
+}
+
+impl core::fmt::Display for ConfigSerializationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ConfigSerializationError::{:?}", self)
+    }
+}
+
+impl core::error::Error for ConfigSerializationError {}
+
+/// Failures occurring while the domain of a [`Config`] is defined with [`Config::set_domain()`]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ConfigDomainError {
+    /// The domain is not a valid file name or the prefix derived from it would be too long.
+    InvalidDomainName,
+}
+
+impl core::fmt::Display for ConfigDomainError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ConfigDomainError::{:?}", self)
+    }
+}
+
+impl core::error::Error for ConfigDomainError {}
+
+/// A single entry that differs between two [`Config`]s, reported by [`Config::diff()`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ConfigDifference {
+    entry: String,
+    value: Option<String>,
+    other_value: Option<String>,
+}
+
+impl ConfigDifference {
+    /// Returns the config entry that differs, e.g. `defaults.event.max-listeners`.
+    pub fn entry(&self) -> &str {
+        &self.entry
+    }
+
+    /// Returns the TOML representation of the value in the [`Config`] on which
+    /// [`Config::diff()`] was called. Returns [`None`] when an optional entry is not set.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// Returns the TOML representation of the value in the [`Config`] that was provided to
+    /// [`Config::diff()`]. Returns [`None`] when an optional entry is not set.
+    pub fn other_value(&self) -> Option<&str> {
+        self.other_value.as_deref()
+    }
+}
+
+impl core::fmt::Display for ConfigDifference {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(
+            f,
+            "{}: {} (other: {})",
+            self.entry,
+            self.value.as_deref().unwrap_or("<not set>"),
+            self.other_value.as_deref().unwrap_or("<not set>")
+        )
+    }
+}
+
+/// Describes how severe a [`ConfigValidationIssue`] is.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ConfigValidationSeverity {
+    /// The config can be used but the entry is most likely not what was intended.
+    Warning,
+    /// The config cannot be used as it is, creating a [`Node`](crate::node::Node) or a
+    /// [`Service`](crate::service::Service) with the default settings fails or is adjusted.
+    Error,
+}
+
+/// A single finding of [`Config::validate()`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ConfigValidationIssue {
+    severity: ConfigValidationSeverity,
+    entry: &'static str,
+    message: String,
+}
+
+impl ConfigValidationIssue {
+    /// Returns the [`ConfigValidationSeverity`] of the issue.
+    pub fn severity(&self) -> ConfigValidationSeverity {
+        self.severity
+    }
+
+    /// Returns the config entry the issue refers to, e.g.
+    /// `defaults.publish-subscribe.max-publishers`.
+    pub fn entry(&self) -> &str {
+        self.entry
+    }
+
+    /// Returns a human readable description of the issue.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl core::fmt::Display for ConfigValidationIssue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let severity = match self.severity {
+            ConfigValidationSeverity::Warning => "warning",
+            ConfigValidationSeverity::Error => "error",
+        };
+        std::write!(f, "{}: {}: {}", severity, self.entry, self.message)
+    }
+}
+
+/// The result of [`Config::validate()`] and [`ConfigBuilder::dry_run()`] containing all
+/// [`ConfigValidationIssue`]s.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ConfigValidationReport {
+    issues: Vec<ConfigValidationIssue>,
+}
+
+impl ConfigValidationReport {
+    /// Returns all issues.
+    pub fn issues(&self) -> &[ConfigValidationIssue] {
+        &self.issues
+    }
+
+    /// Returns an iterator over all issues with [`ConfigValidationSeverity::Error`].
+    pub fn errors(&self) -> impl Iterator<Item = &ConfigValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|i| i.severity == ConfigValidationSeverity::Error)
+    }
+
+    /// Returns an iterator over all issues with [`ConfigValidationSeverity::Warning`].
+    pub fn warnings(&self) -> impl Iterator<Item = &ConfigValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|i| i.severity == ConfigValidationSeverity::Warning)
+    }
+
+    /// Returns `true` when the report contains at least one error.
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+
+    fn add(&mut self, severity: ConfigValidationSeverity, entry: &'static str, message: String) {
+        self.issues.push(ConfigValidationIssue {
+            severity,
+            entry,
+            message,
+        });
+    }
+
+    fn error(&mut self, entry: &'static str, message: String) {
+        self.add(ConfigValidationSeverity::Error, entry, message);
+    }
+
+    fn warning(&mut self, entry: &'static str, message: String) {
+        self.add(ConfigValidationSeverity::Warning, entry, message);
+    }
+
+    fn require_non_zero(&mut self, entry: &'static str, value: usize) {
+        if value == 0 {
+            self.error(entry, "The value must be at least 1.".to_string());
+        }
+    }
+}
+
+impl core::fmt::Display for ConfigValidationReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for issue in &self.issues {
+            std::writeln!(f, "{}", issue)?;
+        }
+        Ok(())
+    }
+}
+
 /// All configurable settings of a [`crate::service::Service`].
 #[non_exhaustive]
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
@@ -141,6 +452,35 @@ pub struct Service {
     pub connection_suffix: FileName,
     /// The suffix of a one-to-one connection
     pub event_connection_suffix: FileName,
+    /// Defines how the [`ServiceName`](crate::service::service_name::ServiceName) is mapped
+    /// onto the names of the underlying resources
+    pub name_hashing: ServiceNameHashing,
+    /// The secret key that is used when the
+    /// [`ServiceNameHashing::KeyedSipHash`] is configured. All processes that shall
+    /// communicate with each other must use the same key.
+    pub name_hashing_key: [u32; 4],
+    /// The size of the explicit huge pages that back the data segments. When the huge pages
+    /// are not available, the data segments fall back to regular pages. By default, no huge
+    /// pages are used.
+    pub data_segment_huge_page_size: Option<HugePageSize>,
+    /// The hugetlbfs mount point that provides the huge pages with
+    /// [`Service::data_segment_huge_page_size`].
+    pub data_segment_huge_page_directory: Path,
+    /// The [`AccessControlList`] that is applied to the shared memory and the static storage
+    /// of every newly created service. When it is [`None`], only the owner can access them.
+    /// It can be overridden per service with
+    /// [`Builder::access_control_list()`](crate::service::builder::Builder::access_control_list()).
+    pub access_control_list: Option<AccessControlList>,
+}
+
+impl Service {
+    /// Returns the [`HugePages`] that back the data segments, if they are configured.
+    pub fn data_segment_huge_pages(&self) -> Option<HugePages> {
+        self.data_segment_huge_page_size.map(|page_size| HugePages {
+            page_size,
+            directory: self.data_segment_huge_page_directory,
+        })
+    }
 }
 
 /// All configurable settings of a [`crate::node::Node`].
@@ -156,6 +496,8 @@ pub struct Node {
     pub static_config_suffix: FileName,
     /// The suffix of the service tags.
     pub service_tag_suffix: FileName,
+    /// The suffix of the crash journals, see [`crate::node::crash_journal`].
+    pub crash_journal_suffix: FileName,
     /// When true, the [`NodeBuilder`](crate::node::NodeBuilder) checks for dead nodes and
     /// cleans up all their stale resources whenever a new [`Node`](crate::node::Node) is
     /// created.
@@ -173,25 +515,71 @@ pub struct Node {
 pub struct Global {
     root_path_unix: Path,
     root_path_windows: Path,
+    static_storage_root_path: Option<Path>,
+    data_segment_root_path: Option<Path>,
+    monitoring_root_path: Option<Path>,
+    domain: Option<FileName>,
     /// Prefix used for all files created during runtime
     pub prefix: FileName,
     /// [`crate::service::Service`] settings
     pub service: Service,
     /// [`crate::node::Node`] settings
     pub node: Node,
+    /// Security descriptor in the SDDL format that restricts on Windows which accounts are
+    /// allowed to open the named objects, like shared memory, that are created by the
+    /// process. It is applied process-wide when a [`crate::node::Node`] is created and
+    /// ignored on all other platforms.
+    pub windows_security_descriptor: Option<String>,
+    /// Log level filter in the format of the `IOX2_LOG_LEVEL` environment variable, like
+    /// `info,SharedMemory=error,Node=debug`. It is applied process-wide when a
+    /// [`crate::node::Node`] is created or when a [`ConfigWatcher`] reloads it, see
+    /// [`iceoryx2_bb_log::set_log_level_filter()`].
+    pub log_level_filter: Option<String>,
+    #[serde(skip)]
+    static_storage_encryption: StaticStorageEncryption,
+}
+
+// The key provider is a callback and can therefore only be set in code, it is compared by its
+// address.
+#[derive(Default, Clone, Copy)]
+struct StaticStorageEncryption(Option<EncryptionKeyProvider>);
+
+impl PartialEq for StaticStorageEncryption {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.map(|v| v as usize) == other.0.map(|v| v as usize)
+    }
+}
+
+impl Eq for StaticStorageEncryption {}
+
+impl core::fmt::Debug for StaticStorageEncryption {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "StaticStorageEncryption {{ is_enabled: {} }}",
+            self.0.is_some()
+        )
+    }
 }
 
 impl Global {
     /// The absolute path to the service directory where all static service infos are stored
     pub fn service_dir(&self) -> Path {
-        let mut path = *self.root_path();
+        let mut path = *self.static_storage_root_path();
         path.add_path_entry(&self.service.directory).unwrap();
         path
     }
 
     /// The absolute path to the node directory where all node details are stored
     pub fn node_dir(&self) -> Path {
-        let mut path = *self.root_path();
+        let mut path = *self.static_storage_root_path();
+        path.add_path_entry(&self.node.directory).unwrap();
+        path
+    }
+
+    /// The absolute path to the directory where the monitoring tokens of all nodes are stored
+    pub fn node_monitoring_dir(&self) -> Path {
+        let mut path = *self.monitoring_root_path();
         path.add_path_entry(&self.node.directory).unwrap();
         path
     }
@@ -219,6 +607,71 @@ impl Global {
             self.root_path_unix = *value;
         }
     }
+
+    /// The path under which the static service configs and the node details are stored.
+    /// Falls back to [`Global::root_path()`] when it is not set.
+    pub fn static_storage_root_path(&self) -> &Path {
+        self.static_storage_root_path
+            .as_ref()
+            .unwrap_or_else(|| self.root_path())
+    }
+
+    /// Defines the path under which the static service configs and the node details are stored.
+    pub fn set_static_storage_root_path(&mut self, value: &Path) {
+        self.static_storage_root_path = Some(*value);
+    }
+
+    /// The path under which the data segments of the ports are stored. Falls back to
+    /// [`Global::root_path()`] when it is not set. It is only relevant when the shared memory
+    /// of the [`crate::service::Service`] is backed by the file system, like a hugetlbfs mount.
+    pub fn data_segment_root_path(&self) -> &Path {
+        self.data_segment_root_path
+            .as_ref()
+            .unwrap_or_else(|| self.root_path())
+    }
+
+    /// Defines the path under which the data segments of the ports are stored.
+    pub fn set_data_segment_root_path(&mut self, value: &Path) {
+        self.data_segment_root_path = Some(*value);
+    }
+
+    /// The path under which the monitoring tokens of the nodes are stored. Falls back to
+    /// [`Global::root_path()`] when it is not set.
+    pub fn monitoring_root_path(&self) -> &Path {
+        self.monitoring_root_path
+            .as_ref()
+            .unwrap_or_else(|| self.root_path())
+    }
+
+    /// Defines the path under which the monitoring tokens of the nodes are stored.
+    pub fn set_monitoring_root_path(&mut self, value: &Path) {
+        self.monitoring_root_path = Some(*value);
+    }
+
+    /// The [`EncryptionKeyProvider`] that encrypts the static service configs and the node
+    /// details that are stored under [`Global::static_storage_root_path()`]. Returns [`None`]
+    /// when they are stored unencrypted.
+    pub fn static_storage_encryption_key_provider(&self) -> Option<EncryptionKeyProvider> {
+        self.static_storage_encryption.0
+    }
+
+    /// Encrypts the static service configs and the node details with the key of the provided
+    /// [`EncryptionKeyProvider`] so that any modification of the stored files is detected.
+    /// Every process that shall open the services must use the same key. The setting cannot
+    /// be defined in a config file and requires the `static_storage_encryption` feature.
+    #[cfg(feature = "static_storage_encryption")]
+    pub fn set_static_storage_encryption_key_provider(
+        &mut self,
+        value: Option<EncryptionKeyProvider>,
+    ) {
+        self.static_storage_encryption = StaticStorageEncryption(value);
+    }
+
+    /// The domain in which all iceoryx2 resources are isolated, see [`Config::set_domain()`].
+    /// Returns [`None`] when no domain is defined.
+    pub fn domain(&self) -> Option<&FileName> {
+        self.domain.as_ref()
+    }
 }
 
 /// Default settings. These values are used when the user in the code does not specify anything
@@ -267,12 +720,23 @@ pub struct PublishSubscribe {
     /// [`crate::port::publisher::Publisher`] when the [`crate::port::subscriber::Subscriber`]s
     /// buffer is full.
     pub unable_to_deliver_strategy: UnableToDeliverStrategy,
+    /// Defines how a [`crate::port::publisher::Publisher`] degrades when the shared memory of
+    /// its data segment is exhausted.
+    pub out_of_memory_strategy: OutOfMemoryStrategy,
+    /// Defines if a [`crate::port::publisher::Publisher`] zeroes the memory of every
+    /// [`crate::sample_mut::SampleMut`] on loan so that no previous payload is leaked.
+    pub zero_payload_on_loan: bool,
     /// Defines the size of the internal [`Subscriber`](crate::port::subscriber::Subscriber)
     /// buffer that contains expired connections. An
     /// connection is expired when the [`Publisher`](crate::port::publisher::Publisher)
     /// disconnected from a service and the connection
     /// still contains unconsumed [`Sample`](crate::sample::Sample)s.
     pub subscriber_expired_connection_buffer: usize,
+    /// If defined, the memory of the data segment of every
+    /// [`crate::port::publisher::Publisher`] is bound to this NUMA node. It can be overridden
+    /// with
+    /// [`PortFactoryPublisher::data_segment_numa_node()`](crate::service::port_factory::publisher::PortFactoryPublisher::data_segment_numa_node).
+    pub publisher_data_segment_numa_node: Option<u32>,
 }
 
 /// Default settings for the event messaging pattern. These settings are used unless
@@ -300,6 +764,9 @@ pub struct Event {
     pub notifier_dropped_event: Option<usize>,
     /// Defines the event id value that is emitted if a notifier was identified as dead.
     pub notifier_dead_event: Option<usize>,
+    /// Defines the event id value that is emitted if a dynamic attribute of the service was
+    /// updated.
+    pub dynamic_attribute_changed_event: Option<usize>,
 }
 
 /// Default settings for the request response messaging pattern. These settings are used unless
@@ -366,6 +833,10 @@ impl Default for Config {
             global: Global {
                 root_path_unix: Path::new(b"/tmp/iceoryx2/").unwrap(),
                 root_path_windows: Path::new(b"c:\\Temp\\iceoryx2\\").unwrap(),
+                static_storage_root_path: None,
+                data_segment_root_path: None,
+                monitoring_root_path: None,
+                domain: None,
                 prefix: FileName::new(b"iox2_").unwrap(),
                 service: Service {
                     directory: Path::new(b"services").unwrap(),
@@ -375,15 +846,24 @@ impl Default for Config {
                     creation_timeout: Duration::from_millis(500),
                     connection_suffix: FileName::new(b".connection").unwrap(),
                     event_connection_suffix: FileName::new(b".event").unwrap(),
+                    name_hashing: ServiceNameHashing::Hashed,
+                    name_hashing_key: [0; 4],
+                    data_segment_huge_page_size: None,
+                    data_segment_huge_page_directory: Path::new(b"/dev/hugepages").unwrap(),
+                    access_control_list: None,
                 },
                 node: Node {
                     directory: Path::new(b"nodes").unwrap(),
                     monitor_suffix: FileName::new(b".node_monitor").unwrap(),
                     static_config_suffix: FileName::new(b".details").unwrap(),
                     service_tag_suffix: FileName::new(b".service_tag").unwrap(),
+                    crash_journal_suffix: FileName::new(b".crash_journal").unwrap(),
                     cleanup_dead_nodes_on_creation: true,
                     cleanup_dead_nodes_on_destruction: true,
                 },
+                windows_security_descriptor: None,
+                log_level_filter: None,
+                static_storage_encryption: StaticStorageEncryption::default(),
             },
             defaults: Defaults {
                 request_response: RequestResonse {
@@ -413,7 +893,10 @@ impl Default for Config {
                     publisher_max_loaned_samples: 2,
                     enable_safe_overflow: true,
                     unable_to_deliver_strategy: UnableToDeliverStrategy::Block,
+                    out_of_memory_strategy: OutOfMemoryStrategy::Fail,
+                    zero_payload_on_loan: false,
                     subscriber_expired_connection_buffer: 128,
+                    publisher_data_segment_numa_node: None,
                 },
                 event: Event {
                     max_listeners: 16,
@@ -424,6 +907,7 @@ impl Default for Config {
                     notifier_created_event: None,
                     notifier_dropped_event: None,
                     notifier_dead_event: None,
+                    dynamic_attribute_changed_event: None,
                 },
             },
         }
@@ -507,28 +991,33 @@ impl Config {
         Ok(())
     }
 
-    /// Loads a configuration from a file. On success it returns a [`Config`] object otherwise a
+    /// Loads a configuration from a file. Entries that are not contained in the file are set
+    /// to their default values. On success it returns a [`Config`] object otherwise a
     /// [`ConfigCreationError`] describing the failure.
     pub fn from_file(config_file: &FilePath) -> Result<Config, ConfigCreationError> {
-        let msg = "Failed to create config";
-        let mut new_config = Self::default();
+        ConfigBuilder::new().file(config_file).create()
+    }
+
+    fn read_file(config_file: &FilePath) -> Result<String, ConfigCreationError> {
+        let msg = "Failed to read config file";
+        let origin = "Config::read_file()";
 
         let file = match FileBuilder::new(config_file).open_existing(AccessMode::Read) {
             Ok(file) => file,
             Err(FileOpenError::InsufficientPermissions) => {
-                fail!(from new_config,
+                fail!(from origin,
                       with ConfigCreationError::InsufficientPermissions,
                       "{} since the config file \"{}\" could not be opened due to insufficient permissions.",
                       msg, config_file);
             }
             Err(FileOpenError::FileDoesNotExist) => {
-                fail!(from new_config,
+                fail!(from origin,
                       with ConfigCreationError::ConfigFileDoesNotExist,
                       "{} since the config file \"{}\" does not exist.",
                       msg, config_file);
             }
             Err(e) => {
-                fail!(from new_config,
+                fail!(from origin,
                       with ConfigCreationError::UnableToOpenConfigFile,
                       "{} since the config file \"{}\" could not be open due to an internal error ({:?}).",
                       msg, config_file, e);
@@ -536,20 +1025,11 @@ impl Config {
         };
 
         let mut contents = String::new();
-        fail!(from new_config, when file.read_to_string(&mut contents),
+        fail!(from origin, when file.read_to_string(&mut contents),
                 with ConfigCreationError::FailedToReadConfigFileContents,
-                "{} since the config file contents could not be read.", msg);
-
-        match toml::from_str(&contents) {
-            Ok(v) => new_config = v,
-            Err(e) => {
-                fail!(from new_config, with ConfigCreationError::UnableToDeserializeContents,
-                                "{} since the contents could not be deserialized ({}).", msg, e);
-            }
-        }
+                "{} since the config file \"{}\" contents could not be read.", msg, config_file);
 
-        trace!(from new_config, "Loaded.");
-        Ok(new_config)
+        Ok(contents)
     }
 
     /// Sets up the global configuration from a file. If the global configuration was already setup
@@ -562,16 +1042,410 @@ impl Config {
             return Ok(ICEORYX2_CONFIG.get());
         }
 
-        if !ICEORYX2_CONFIG.set_value(Config::from_file(config_file)?) {
+        Ok(Self::set_global_config(Config::from_file(config_file)?))
+    }
+
+    /// Sets up the global configuration from the default config files and the environment
+    /// variables, see [`ConfigBuilder::environment_variables()`] for the naming of the variables.
+    /// If the global configuration was already setup it does not load anything. It returns the
+    /// [`Config`] when it could be successfully loaded otherwise a [`ConfigCreationError`]
+    /// describing the error.
+    pub fn setup_global_config_from_env() -> Result<&'static Config, ConfigCreationError> {
+        if ICEORYX2_CONFIG.is_initialized() {
+            return Ok(ICEORYX2_CONFIG.get());
+        }
+
+        let config = ConfigBuilder::new()
+            .default_layers()
+            .environment_variables()
+            .create()?;
+
+        Ok(Self::set_global_config(config))
+    }
+
+    fn set_global_config(config: Config) -> &'static Config {
+        if !ICEORYX2_CONFIG.set_value(config) {
             warn!(
                 from ICEORYX2_CONFIG.get(),
                 "Configuration already loaded and set up, cannot load another one. This may happen when this function is called from multiple threads."
             );
-            return Ok(ICEORYX2_CONFIG.get());
+            return ICEORYX2_CONFIG.get();
         }
 
         trace!(from ICEORYX2_CONFIG.get(), "Set as global config.");
-        Ok(ICEORYX2_CONFIG.get())
+        ICEORYX2_CONFIG.get()
+    }
+
+    /// Loads the config from a file and returns a [`ConfigWatcher`] that monitors the file in
+    /// a background thread and applies its changes at runtime. The file is checked every
+    /// [`DEFAULT_CONFIG_MONITORING_INTERVAL`], see [`ConfigWatcher::set_monitoring_interval()`].
+    /// The callback is called with the new [`Config`] every time a change was applied.
+    pub fn watch<F: FnMut(&Config) + Send + 'static>(
+        config_file: &FilePath,
+        callback: F,
+    ) -> Result<ConfigWatcher, ConfigWatchError> {
+        let config = Config::from_file(config_file)?;
+
+        let watched_config = Arc::new(Mutex::new(WatchedConfig {
+            config_file: *config_file,
+            config,
+            callbacks: vec![],
+        }));
+        let (monitor_sender, monitor_receiver) = mpsc::channel();
+
+        let thread = {
+            let watched_config = watched_config.clone();
+            std::thread::Builder::new()
+                .name("iox2_config".to_string())
+                .spawn(move || ConfigWatcher::monitor(&watched_config, monitor_receiver))
+        };
+
+        let thread = fail!(from "Config::watch()", when thread,
+            with ConfigWatchError::UnableToStartMonitoringThread,
+            "Unable to watch the config file \"{}\" since the monitoring thread could not be started.",
+            config_file);
+
+        let watcher = ConfigWatcher {
+            config_file: *config_file,
+            watched_config,
+            monitor_sender,
+            thread: Some(thread),
+        };
+        watcher.register(callback);
+
+        Ok(watcher)
+    }
+
+    /// Checks the config for inconsistent or unusable entries and whether the root path is
+    /// writable, without creating any iceoryx2 resources. Returns a [`ConfigValidationReport`]
+    /// with all found issues.
+    pub fn validate(&self) -> ConfigValidationReport {
+        let mut report = ConfigValidationReport::default();
+
+        self.validate_root_path("global.root-path", self.global.root_path(), &mut report);
+        for (entry, path) in [
+            (
+                "global.static-storage-root-path",
+                &self.global.static_storage_root_path,
+            ),
+            (
+                "global.data-segment-root-path",
+                &self.global.data_segment_root_path,
+            ),
+            (
+                "global.monitoring-root-path",
+                &self.global.monitoring_root_path,
+            ),
+        ] {
+            if let Some(path) = path {
+                self.validate_root_path(entry, path, &mut report);
+            }
+        }
+
+        if self.global.service.creation_timeout.is_zero() {
+            report.warning(
+                "global.service.creation-timeout",
+                "A timeout of zero lets every process fail that opens a service while it is created.".to_string(),
+            );
+        }
+
+        let pub_sub = &self.defaults.publish_subscribe;
+        report.require_non_zero(
+            "defaults.publish-subscribe.max-subscribers",
+            pub_sub.max_subscribers,
+        );
+        report.require_non_zero(
+            "defaults.publish-subscribe.max-publishers",
+            pub_sub.max_publishers,
+        );
+        report.require_non_zero("defaults.publish-subscribe.max-nodes", pub_sub.max_nodes);
+        report.require_non_zero(
+            "defaults.publish-subscribe.subscriber-max-buffer-size",
+            pub_sub.subscriber_max_buffer_size,
+        );
+        report.require_non_zero(
+            "defaults.publish-subscribe.subscriber-max-borrowed-samples",
+            pub_sub.subscriber_max_borrowed_samples,
+        );
+        report.require_non_zero(
+            "defaults.publish-subscribe.publisher-max-loaned-samples",
+            pub_sub.publisher_max_loaned_samples,
+        );
+        if !pub_sub.enable_safe_overflow
+            && pub_sub.subscriber_max_buffer_size < pub_sub.publisher_history_size
+        {
+            report.error(
+                "defaults.publish-subscribe.publisher-history-size",
+                std::format!(
+                    "The history size of {} exceeds the subscriber buffer size of {}, which is not supported when safe overflow is disabled.",
+                    pub_sub.publisher_history_size, pub_sub.subscriber_max_buffer_size
+                ),
+            );
+        }
+
+        let event = &self.defaults.event;
+        report.require_non_zero("defaults.event.max-listeners", event.max_listeners);
+        report.require_non_zero("defaults.event.max-notifiers", event.max_notifiers);
+        report.require_non_zero("defaults.event.max-nodes", event.max_nodes);
+        if event.deadline == Some(Duration::ZERO) {
+            report.error(
+                "defaults.event.deadline",
+                "A deadline of zero is always missed.".to_string(),
+            );
+        }
+        for (entry, event_id) in [
+            (
+                "defaults.event.notifier-created-event",
+                event.notifier_created_event,
+            ),
+            (
+                "defaults.event.notifier-dropped-event",
+                event.notifier_dropped_event,
+            ),
+            (
+                "defaults.event.notifier-dead-event",
+                event.notifier_dead_event,
+            ),
+            (
+                "defaults.event.dynamic-attribute-changed-event",
+                event.dynamic_attribute_changed_event,
+            ),
+        ] {
+            if let Some(event_id) = event_id {
+                if event.event_id_max_value < event_id {
+                    report.error(
+                        entry,
+                        std::format!(
+                            "The event id {} exceeds the event-id-max-value of {}.",
+                            event_id,
+                            event.event_id_max_value
+                        ),
+                    );
+                }
+            }
+        }
+
+        let request_response = &self.defaults.request_response;
+        report.require_non_zero(
+            "defaults.request-response.max-servers",
+            request_response.max_servers,
+        );
+        report.require_non_zero(
+            "defaults.request-response.max-clients",
+            request_response.max_clients,
+        );
+        report.require_non_zero(
+            "defaults.request-response.max-nodes",
+            request_response.max_nodes,
+        );
+        report.require_non_zero(
+            "defaults.request-response.max-active-requests",
+            request_response.max_active_requests,
+        );
+        report.require_non_zero(
+            "defaults.request-response.max-response-buffer-size",
+            request_response.max_response_buffer_size,
+        );
+        report.require_non_zero(
+            "defaults.request-response.max-request-buffer-size",
+            request_response.max_request_buffer_size,
+        );
+
+        report
+    }
+
+    fn validate_root_path(
+        &self,
+        entry: &'static str,
+        root_path: &Path,
+        report: &mut ConfigValidationReport,
+    ) {
+        match Directory::does_exist(root_path) {
+            Ok(true) => (),
+            Ok(false) => {
+                report.warning(
+                    entry,
+                    std::format!(
+                        "The root path \"{}\" does not exist yet and is created by the first node.",
+                        root_path
+                    ),
+                );
+                return;
+            }
+            Err(e) => {
+                report.error(
+                    entry,
+                    std::format!(
+                        "The root path \"{}\" is not accessible ({:?}).",
+                        root_path,
+                        e
+                    ),
+                );
+                return;
+            }
+        }
+
+        let probe = UniqueSystemId::new().ok().and_then(|id| {
+            FileName::new(
+                std::format!("{}config_validation_{}", self.global.prefix, id.value()).as_bytes(),
+            )
+            .ok()
+        });
+        let probe =
+            match probe.and_then(|probe| FilePath::from_path_and_file(root_path, &probe).ok()) {
+                Some(probe) => probe,
+                None => {
+                    report.warning(
+                        entry,
+                        std::format!(
+                            "Unable to verify that the root path \"{}\" is writable.",
+                            root_path
+                        ),
+                    );
+                    return;
+                }
+            };
+
+        // the probe file is removed when it goes out of scope
+        if let Err(e) = FileBuilder::new(&probe)
+            .has_ownership(true)
+            .creation_mode(CreationMode::CreateExclusive)
+            .create()
+        {
+            report.error(
+                entry,
+                std::format!("The root path \"{}\" is not writable ({:?}).", root_path, e),
+            );
+        }
+    }
+
+    fn has_equal_non_reloadable_entries(&self, other: &Config) -> bool {
+        let mut other = other.global.clone();
+        other.log_level_filter = self.global.log_level_filter.clone();
+        other.service.creation_timeout = self.global.service.creation_timeout;
+        other.node.cleanup_dead_nodes_on_creation = self.global.node.cleanup_dead_nodes_on_creation;
+        other.node.cleanup_dead_nodes_on_destruction =
+            self.global.node.cleanup_dead_nodes_on_destruction;
+
+        self.global == other
+    }
+
+    /// Serializes the [`Config`] into a TOML string that can be used as config file.
+    pub fn to_toml_string(&self) -> Result<String, ConfigSerializationError> {
+        match toml::to_string(self) {
+            Ok(value) => Ok(value),
+            Err(e) => {
+                fail!(from self, with ConfigSerializationError::UnableToSerializeContents,
+                    "Unable to serialize the config into TOML ({}).", e);
+            }
+        }
+    }
+
+    /// Serializes the [`Config`] into a JSON string, e.g. for tooling that dumps the
+    /// effective configuration.
+    pub fn to_json_string(&self) -> Result<String, ConfigSerializationError> {
+        match serde_json::to_string_pretty(self) {
+            Ok(value) => Ok(value),
+            Err(e) => {
+                fail!(from self, with ConfigSerializationError::UnableToSerializeContents,
+                    "Unable to serialize the config into JSON ({}).", e);
+            }
+        }
+    }
+
+    /// Returns all entries whose value differs between this and the other [`Config`], ordered
+    /// by the entry name. Use `config.diff(&Config::default())` to acquire all entries that
+    /// deviate from the default values.
+    pub fn diff(&self, other: &Config) -> Vec<ConfigDifference> {
+        let mut entries = BTreeMap::new();
+        Self::flatten(&ConfigBuilder::to_table(self), "", &mut entries);
+        let mut other_entries = BTreeMap::new();
+        Self::flatten(&ConfigBuilder::to_table(other), "", &mut other_entries);
+
+        let names = entries
+            .keys()
+            .chain(other_entries.keys())
+            .collect::<BTreeSet<_>>();
+
+        names
+            .into_iter()
+            .filter(|entry| entries.get(*entry) != other_entries.get(*entry))
+            .map(|entry| ConfigDifference {
+                entry: entry.clone(),
+                value: entries.get(entry).cloned(),
+                other_value: other_entries.get(entry).cloned(),
+            })
+            .collect()
+    }
+
+    fn flatten(table: &toml::Table, prefix: &str, entries: &mut BTreeMap<String, String>) {
+        for (key, value) in table {
+            let entry = if prefix.is_empty() {
+                key.clone()
+            } else {
+                prefix.to_string() + "." + key
+            };
+
+            match value {
+                toml::Value::Table(table) => Self::flatten(table, &entry, entries),
+                value => {
+                    entries.insert(entry, value.to_string());
+                }
+            }
+        }
+    }
+
+    /// Isolates all iceoryx2 resources in the provided domain. The [`Global::prefix`] and the
+    /// root paths are derived from the domain, therefore previously defined values are
+    /// overridden. This includes the [`Global::static_storage_root_path()`], the
+    /// [`Global::data_segment_root_path()`] and the [`Global::monitoring_root_path()`], which
+    /// fall back to the root path of the domain. [`Node`](crate::node::Node)s can only communicate with each other when they
+    /// share the same domain and a [`Service`](crate::service::Service) that was created in
+    /// another domain cannot be opened.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let mut config = Config::default();
+    /// config.set_domain("robot_arm")?;
+    ///
+    /// let node = NodeBuilder::new()
+    ///     .config(&config)
+    ///     .create::<ipc::Service>()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_domain(&mut self, domain: &str) -> Result<(), ConfigDomainError> {
+        let msg = "Unable to set domain";
+        let domain_name = fail!(from self, when FileName::new(domain.as_bytes()),
+            with ConfigDomainError::InvalidDomainName,
+            "{} since \"{}\" is not a valid file name.", msg, domain);
+        let domain_path = fail!(from self, when Path::new(domain.as_bytes()),
+            with ConfigDomainError::InvalidDomainName,
+            "{} since \"{}\" is not a valid path entry.", msg, domain);
+        let prefix = fail!(from self, when FileName::new(std::format!("iox2_{}_", domain).as_bytes()),
+            with ConfigDomainError::InvalidDomainName,
+            "{} since the prefix derived from \"{}\" would be too long.", msg, domain);
+
+        let defaults = Config::default().global;
+        let mut root_path_unix = defaults.root_path_unix;
+        let mut root_path_windows = defaults.root_path_windows;
+        for root_path in [&mut root_path_unix, &mut root_path_windows] {
+            fail!(from self, when root_path.add_path_entry(&domain_path),
+                with ConfigDomainError::InvalidDomainName,
+                "{} since the root path derived from \"{}\" would be too long.", msg, domain);
+        }
+
+        self.global.domain = Some(domain_name);
+        self.global.prefix = prefix;
+        self.global.root_path_unix = root_path_unix;
+        self.global.root_path_windows = root_path_windows;
+        self.global.static_storage_root_path = None;
+        self.global.data_segment_root_path = None;
+        self.global.monitoring_root_path = None;
+
+        Ok(())
     }
 
     /// Returns the global configuration. If the global configuration was not yet loaded it will
@@ -583,34 +1457,584 @@ impl Config {
     pub fn global_config() -> &'static Config {
         let origin = "Config::global_config()";
         if !ICEORYX2_CONFIG.is_initialized() {
-            let mut is_config_file_set = false;
-            if let Err(e) = Self::iterate_over_config_files(|config_file_path| {
-                match Config::setup_global_config_from_file(&config_file_path) {
-                    Ok(_) => {
-                        is_config_file_set = true;
-                        CallbackProgression::Stop
+            let (config, number_of_loaded_files) = match ConfigBuilder::new()
+                .default_layers()
+                .create_impl()
+            {
+                Ok(v) => v,
+                Err(e) => {
+                    fatal_panic!(from origin,
+                            "A failure occurred ({:?}) while loading the available config files.", e);
+                }
+            };
+
+            if number_of_loaded_files == 0 {
+                warn!(from origin,
+                    "No config file was loaded, a config with default values will be used.");
+            }
+
+            if ICEORYX2_CONFIG.set_value(config) {
+                trace!(from ICEORYX2_CONFIG.get(), "Set as global config.");
+            }
+        }
+
+        ICEORYX2_CONFIG.get()
+    }
+}
+
+/// The interval in which a [`ConfigWatcher`] checks the watched config file for changes
+/// when no other interval is set with [`ConfigWatcher::set_monitoring_interval()`].
+pub const DEFAULT_CONFIG_MONITORING_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Watches a config file and applies its changes at runtime, created with [`Config::watch()`].
+/// A background thread reloads the file in the monitoring interval; when the resulting
+/// [`Config`] differs from the current one, it is applied and all registered callbacks are
+/// notified from the background thread. [`ConfigWatcher::update()`] reloads the file
+/// immediately.
+///
+/// Only entries that are used when new resources are created can be reloaded:
+///  * all [`Defaults`]
+///  * [`Global::log_level_filter`], it is applied to the logger right away
+///  * [`Service::creation_timeout`]
+///  * [`Node::cleanup_dead_nodes_on_creation`] and [`Node::cleanup_dead_nodes_on_destruction`]
+///
+/// All other [`Global`] entries, like the [`Global::prefix`] or the [`Global::root_path()`],
+/// define which iceoryx2 instance is joined and must not change while the application is
+/// running. When such an entry is changed, the whole file is rejected with
+/// [`ConfigReloadError::NonReloadableEntryChanged`].
+///
+/// Resources that are already created keep the settings they were created with. The
+/// [`Config`] returned by [`ConfigWatcher::config()`] must be passed to the
+/// [`NodeBuilder`](crate::node::NodeBuilder) of new [`Node`](crate::node::Node)s
+/// so that they use the reloaded settings.
+pub struct ConfigWatcher {
+    config_file: FilePath,
+    watched_config: Arc<Mutex<WatchedConfig>>,
+    monitor_sender: mpsc::Sender<Option<Duration>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+struct WatchedConfig {
+    config_file: FilePath,
+    config: Config,
+    #[allow(clippy::type_complexity)]
+    callbacks: Vec<Box<dyn FnMut(&Config) + Send>>,
+}
+
+impl core::fmt::Debug for WatchedConfig {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ConfigWatcher")
+            .field("config_file", &self.config_file)
+            .field("config", &self.config)
+            .field("number_of_callbacks", &self.callbacks.len())
+            .finish()
+    }
+}
+
+impl WatchedConfig {
+    fn reload(&mut self) -> Result<bool, ConfigReloadError> {
+        let msg = "Unable to reload the config";
+        let config = match Config::from_file(&self.config_file) {
+            Ok(config) => config,
+            Err(e) => {
+                fail!(from self, with ConfigReloadError::ConfigCreationError(e),
+                    "{} since the config file \"{}\" could not be loaded ({:?}).",
+                    msg, self.config_file, e);
+            }
+        };
+
+        if config == self.config {
+            return Ok(false);
+        }
+
+        if !self.config.has_equal_non_reloadable_entries(&config) {
+            fail!(from self, with ConfigReloadError::NonReloadableEntryChanged,
+                "{} since the config file \"{}\" changed global entries that cannot be changed at runtime.",
+                msg, self.config_file);
+        }
+
+        if config.global.log_level_filter != self.config.global.log_level_filter {
+            if let Some(ref log_level_filter) = config.global.log_level_filter {
+                set_log_level_filter(log_level_filter);
+            }
+        }
+
+        self.config = config;
+        trace!(from self, "Reloaded.");
+
+        for callback in &mut self.callbacks {
+            callback(&self.config);
+        }
+
+        Ok(true)
+    }
+}
+
+impl core::fmt::Debug for ConfigWatcher {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.lock().fmt(f)
+    }
+}
+
+impl Drop for ConfigWatcher {
+    fn drop(&mut self) {
+        // disconnects the channel and stops the monitoring thread
+        let (monitor_sender, _) = mpsc::channel();
+        drop(core::mem::replace(&mut self.monitor_sender, monitor_sender));
+
+        if let Some(thread) = self.thread.take() {
+            if thread.join().is_err() {
+                warn!(from self, "The monitoring thread terminated with a panic.");
+            }
+        }
+    }
+}
+
+impl ConfigWatcher {
+    /// Returns a copy of the currently applied [`Config`].
+    pub fn config(&self) -> Config {
+        self.lock().config.clone()
+    }
+
+    /// Returns the watched config file.
+    pub fn config_file(&self) -> &FilePath {
+        &self.config_file
+    }
+
+    /// Registers an additional callback that is called with the new [`Config`] whenever a
+    /// change was applied.
+    pub fn register<F: FnMut(&Config) + Send + 'static>(&self, callback: F) {
+        self.lock().callbacks.push(Box::new(callback));
+    }
+
+    /// Defines the interval in which the background thread checks the config file for
+    /// changes. [`None`] pauses the monitoring, the file is then only reloaded with
+    /// [`ConfigWatcher::update()`].
+    pub fn set_monitoring_interval(&self, interval: Option<Duration>) {
+        if self.monitor_sender.send(interval).is_err() {
+            warn!(from self,
+                "Unable to set the monitoring interval to {:?} since the monitoring thread is not running.",
+                interval);
+        }
+    }
+
+    /// Reloads the config file immediately and applies the changes. Returns `true` when the
+    /// [`Config`] has changed and the callbacks were notified, otherwise `false`. When the file
+    /// cannot be loaded or changes an entry that cannot be reloaded, the current [`Config`]
+    /// is kept and a [`ConfigReloadError`] is returned.
+    pub fn update(&self) -> Result<bool, ConfigReloadError> {
+        self.lock().reload()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, WatchedConfig> {
+        match self.watched_config.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    fn monitor(
+        watched_config: &Mutex<WatchedConfig>,
+        monitor_receiver: mpsc::Receiver<Option<Duration>>,
+    ) {
+        let mut interval = Some(DEFAULT_CONFIG_MONITORING_INTERVAL);
+        let mut last_error = None;
+        loop {
+            let received = match interval {
+                Some(interval) => monitor_receiver.recv_timeout(interval),
+                None => monitor_receiver
+                    .recv()
+                    .map_err(|_| mpsc::RecvTimeoutError::Disconnected),
+            };
+
+            match received {
+                Ok(new_interval) => interval = new_interval,
+                Err(mpsc::RecvTimeoutError::Disconnected) => return,
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    let mut watched_config = match watched_config.lock() {
+                        Ok(guard) => guard,
+                        Err(poisoned) => poisoned.into_inner(),
+                    };
+
+                    // an invalid file is reported once and not in every interval
+                    match watched_config.reload() {
+                        Ok(_) => last_error = None,
+                        Err(e) if last_error != Some(e) => {
+                            warn!(from watched_config,
+                                "Keeping the current config since the config file could not be reloaded ({:?}).", e);
+                            last_error = Some(e);
+                        }
+                        Err(_) => (),
                     }
-                    Err(ConfigCreationError::ConfigFileDoesNotExist) => {
-                        CallbackProgression::Continue
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+enum ConfigLayer {
+    DefaultFiles,
+    File(FilePath),
+    Override(String),
+    EnvironmentVariables,
+}
+
+/// Creates a [`Config`] from multiple layers. It starts with the [`Config::default()`] values
+/// and every layer overrides the entries it contains in the order the layers were added.
+/// A layer does not have to contain all entries and entries that are not known result in a
+/// [`ConfigCreationError::UnknownConfigEntry`].
+///
+/// ```
+/// use iceoryx2::config::ConfigBuilder;
+///
+/// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+/// let config = ConfigBuilder::new()
+///     .override_with("defaults.publish-subscribe.max-publishers = 5")
+///     .override_with("[global]\nprefix = 'my_app_'")
+///     .create()?;
+///
+/// assert_eq!(config.defaults.publish_subscribe.max_publishers, 5);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct ConfigBuilder {
+    layers: Vec<ConfigLayer>,
+}
+
+impl ConfigBuilder {
+    /// Creates a new [`ConfigBuilder`] without any layers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the default config file layers with increasing priority: the global config file,
+    /// the config file of the user, the project local config file and the config file defined
+    /// in the environment variable `IOX2_CONFIG`. Config files that do not exist are skipped.
+    pub fn default_layers(mut self) -> Self {
+        self.layers.push(ConfigLayer::DefaultFiles);
+        self
+    }
+
+    /// Adds a config file as layer. The file must exist.
+    pub fn file(mut self, config_file: &FilePath) -> Self {
+        self.layers.push(ConfigLayer::File(*config_file));
+        self
+    }
+
+    /// Adds a TOML string as layer, e.g. `"defaults.event.max-listeners = 4"`.
+    pub fn override_with(mut self, toml: &str) -> Self {
+        self.layers.push(ConfigLayer::Override(toml.to_string()));
+        self
+    }
+
+    /// Adds the environment variables as layer. Every config entry can be set with an
+    /// environment variable whose name is the full key of the entry in upper case, prefixed
+    /// with `IOX2_` and with `.` and `-` replaced by `_`, e.g. the entry
+    /// `defaults.publish-subscribe.max-subscribers` is set with
+    /// `IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_SUBSCRIBERS`. Entries consisting of multiple values,
+    /// like `global.service.creation-timeout`, are set by their individual values, e.g.
+    /// `IOX2_GLOBAL_SERVICE_CREATION_TIMEOUT_SECS` and `IOX2_GLOBAL_SERVICE_CREATION_TIMEOUT_NANOS`.
+    ///
+    /// Values of string entries are used as they are, all other values are parsed as TOML
+    /// values, e.g. `true` or `42`.
+    pub fn environment_variables(mut self) -> Self {
+        self.layers.push(ConfigLayer::EnvironmentVariables);
+        self
+    }
+
+    /// Creates the [`Config`] by applying all layers on top of the [`Config::default()`]
+    /// values.
+    pub fn create(self) -> Result<Config, ConfigCreationError> {
+        Ok(self.create_impl()?.0)
+    }
+
+    /// Creates the [`Config`] like [`ConfigBuilder::create()`] and validates it with
+    /// [`Config::validate()`] without setting it up as global config or creating any iceoryx2
+    /// resources. It can be used to verify a deployment config before it is rolled out.
+    pub fn dry_run(self) -> Result<ConfigValidationReport, ConfigCreationError> {
+        Ok(self.create()?.validate())
+    }
+
+    fn create_impl(self) -> Result<(Config, usize), ConfigCreationError> {
+        let msg = "Unable to create config";
+        let schema = Self::entry_schema();
+        let mut config = Self::to_table(&Config::default());
+        let mut number_of_loaded_files = 0;
+
+        for layer in &self.layers {
+            match layer {
+                ConfigLayer::DefaultFiles => {
+                    for config_file in Self::default_config_files()? {
+                        match Self::apply_file(&mut config, &schema, &config_file, 0) {
+                            Ok(()) => number_of_loaded_files += 1,
+                            Err(ConfigCreationError::ConfigFileDoesNotExist) => (),
+                            Err(e) => return Err(e),
+                        }
                     }
-                    Err(e) => {
-                        fatal_panic!(from origin,
-                            "Config file found \"{}\" but a failure occurred ({:?}) while reading the content.",
-                            config_file_path, e);
+
+                    if let Ok(config_file) = std::env::var(CONFIG_FILE_ENV_VARIABLE) {
+                        let config_file = fail!(from self,
+                            when FilePath::new(config_file.as_bytes()),
+                            with ConfigCreationError::UnableToOpenConfigFile,
+                            "{} since the environment variable {} contains the invalid file path \"{}\".",
+                            msg, CONFIG_FILE_ENV_VARIABLE, config_file);
+                        Self::apply_file(&mut config, &schema, &config_file, 0)?;
+                        number_of_loaded_files += 1;
                     }
                 }
-            }) {
-                fatal_panic!(from origin,
-                    "A failure occurred ({:?}) while looking up the available config files.", e);
+                ConfigLayer::File(config_file) => {
+                    Self::apply_file(&mut config, &schema, config_file, 0)?;
+                    number_of_loaded_files += 1;
+                }
+                ConfigLayer::EnvironmentVariables => {
+                    let layer = Self::environment_variable_layer(&schema, "")?;
+                    Self::merge(&mut config, layer);
+                }
+                ConfigLayer::Override(toml) => {
+                    let layer = Self::parse(toml, "override")?;
+                    Self::verify_entries(&layer, &schema, "", "override")?;
+                    Self::merge(&mut config, layer);
+                }
             }
+        }
 
-            if !is_config_file_set {
-                warn!(from origin,
-                    "No config file was loaded, a config with default values will be used.");
-                ICEORYX2_CONFIG.set_value(Config::default());
+        match config.try_into::<Config>() {
+            Ok(mut config) => {
+                if let Some(domain) = config.global.domain {
+                    fail!(from self, when config.set_domain(&domain.to_string()),
+                        with ConfigCreationError::UnableToDeserializeContents,
+                        "{} since the domain \"{}\" is invalid.", msg, domain);
+                }
+
+                trace!(from config, "Loaded.");
+                Ok((config, number_of_loaded_files))
+            }
+            Err(e) => {
+                fail!(from self, with ConfigCreationError::UnableToDeserializeContents,
+                    "{} since the combined layers contain an entry with an invalid value ({}).", msg, e);
             }
         }
+    }
 
-        ICEORYX2_CONFIG.get()
+    fn default_config_files() -> Result<Vec<FilePath>, ConfigCreationError> {
+        let mut config_files = vec![];
+        fail!(from "ConfigBuilder::default_config_files()",
+            when Config::iterate_over_config_files(|config_file| {
+                config_files.push(config_file);
+                CallbackProgression::Continue
+            }),
+            with ConfigCreationError::UnableToOpenConfigFile,
+            "Unable to acquire the default config files since the config file paths could not be determined.");
+
+        // the config files are provided with decreasing priority
+        config_files.reverse();
+        Ok(config_files)
+    }
+
+    fn apply_file(
+        config: &mut toml::Table,
+        schema: &toml::Table,
+        config_file: &FilePath,
+        include_depth: usize,
+    ) -> Result<(), ConfigCreationError> {
+        let msg = "Unable to apply config file";
+        let origin = "ConfigBuilder::apply_file()";
+
+        if MAX_INCLUDE_DEPTH < include_depth {
+            fail!(from origin, with ConfigCreationError::MaxIncludeDepthExceeded,
+                "{} \"{}\" since the maximum include depth of {} is exceeded. Maybe the config files include each other.",
+                msg, config_file, MAX_INCLUDE_DEPTH);
+        }
+
+        let origin_name = config_file.to_string();
+        let mut layer = Self::parse(&Config::read_file(config_file)?, &origin_name)?;
+
+        if let Some(includes) = layer.remove(INCLUDE_KEY) {
+            let includes = match includes {
+                toml::Value::Array(includes) => includes,
+                _ => {
+                    fail!(from origin, with ConfigCreationError::UnableToDeserializeContents,
+                        "{} \"{}\" since the entry \"{}\" is not an array of file paths.",
+                        msg, config_file, INCLUDE_KEY);
+                }
+            };
+
+            for include in includes {
+                let include = match include.as_str().map(|v| FilePath::new(v.as_bytes())) {
+                    Some(Ok(include)) => include,
+                    _ => {
+                        fail!(from origin, with ConfigCreationError::UnableToDeserializeContents,
+                            "{} \"{}\" since the include \"{}\" is not a valid file path.",
+                            msg, config_file, include);
+                    }
+                };
+
+                let include = if include.path().is_absolute() {
+                    include
+                } else {
+                    let mut path = config_file.path();
+                    fail!(from origin,
+                        when path.add_path_entry(&include.path()),
+                        with ConfigCreationError::UnableToOpenConfigFile,
+                        "{} \"{}\" since the path of the include \"{}\" would be too long.",
+                        msg, config_file, include);
+                    fail!(from origin,
+                        when FilePath::from_path_and_file(&path, &include.file_name()),
+                        with ConfigCreationError::UnableToOpenConfigFile,
+                        "{} \"{}\" since the path of the include \"{}\" would be too long.",
+                        msg, config_file, include)
+                };
+
+                Self::apply_file(config, schema, &include, include_depth + 1)?;
+            }
+        }
+
+        Self::verify_entries(&layer, schema, "", &origin_name)?;
+        Self::merge(config, layer);
+
+        Ok(())
+    }
+
+    fn parse(toml: &str, origin_name: &str) -> Result<toml::Table, ConfigCreationError> {
+        match toml::from_str::<toml::Table>(toml) {
+            Ok(layer) => Ok(layer),
+            Err(e) => {
+                fail!(from "ConfigBuilder::parse()", with ConfigCreationError::UnableToDeserializeContents,
+                    "Unable to parse the config layer \"{}\" since it is not valid TOML ({}).", origin_name, e);
+            }
+        }
+    }
+
+    fn verify_entries(
+        layer: &toml::Table,
+        schema: &toml::Table,
+        prefix: &str,
+        origin_name: &str,
+    ) -> Result<(), ConfigCreationError> {
+        for (key, value) in layer {
+            let entry = if prefix.is_empty() {
+                key.clone()
+            } else {
+                prefix.to_string() + "." + key
+            };
+
+            match (schema.get(key), value) {
+                (None, _) => {
+                    fail!(from "ConfigBuilder::verify_entries()", with ConfigCreationError::UnknownConfigEntry,
+                        "The config layer \"{}\" contains the unknown entry \"{}\".", origin_name, entry);
+                }
+                (Some(toml::Value::Table(schema)), toml::Value::Table(value)) => {
+                    Self::verify_entries(value, schema, &entry, origin_name)?;
+                }
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn merge(config: &mut toml::Table, layer: toml::Table) {
+        for (key, value) in layer {
+            match (config.get_mut(&key), value) {
+                (Some(toml::Value::Table(config)), toml::Value::Table(value)) => {
+                    Self::merge(config, value)
+                }
+                (_, value) => {
+                    config.insert(key, value);
+                }
+            }
+        }
+    }
+
+    fn environment_variable_name(entry: &str) -> String {
+        ENV_VARIABLE_PREFIX.to_string() + &entry.to_uppercase().replace(['.', '-'], "_")
+    }
+
+    fn environment_variable_layer(
+        schema: &toml::Table,
+        prefix: &str,
+    ) -> Result<toml::Table, ConfigCreationError> {
+        let origin = "ConfigBuilder::environment_variable_layer()";
+        let mut layer = toml::Table::new();
+
+        for (key, schema_value) in schema {
+            let entry = if prefix.is_empty() {
+                key.clone()
+            } else {
+                prefix.to_string() + "." + key
+            };
+
+            if let toml::Value::Table(schema) = schema_value {
+                let sub_layer = Self::environment_variable_layer(schema, &entry)?;
+                if !sub_layer.is_empty() {
+                    layer.insert(key.clone(), toml::Value::Table(sub_layer));
+                }
+                continue;
+            }
+
+            let variable = Self::environment_variable_name(&entry);
+            let value = match std::env::var(&variable) {
+                Ok(value) => value,
+                Err(std::env::VarError::NotPresent) => continue,
+                Err(std::env::VarError::NotUnicode(_)) => {
+                    fail!(from origin, with ConfigCreationError::UnableToDeserializeContents,
+                        "Unable to apply the environment variable {} since its value is not valid unicode.",
+                        variable);
+                }
+            };
+
+            let value = match schema_value {
+                toml::Value::String(_) => toml::Value::String(value),
+                _ => match toml::from_str::<toml::Table>(&format!("value = {}", value)) {
+                    Ok(mut table) => table.remove("value").unwrap_or(toml::Value::String(value)),
+                    Err(_) => toml::Value::String(value),
+                },
+            };
+
+            trace!(from origin, "Apply environment variable {} to the entry \"{}\".", variable, entry);
+            layer.insert(key.clone(), value);
+        }
+
+        Ok(layer)
+    }
+
+    fn to_table(config: &Config) -> toml::Table {
+        match toml::Table::try_from(config) {
+            Ok(table) => table,
+            Err(e) => {
+                fatal_panic!(from "ConfigBuilder::to_table()",
+                    "This should never happen! Unable to serialize the config ({}).", e);
+            }
+        }
+    }
+
+    // contains every entry of the config, optional entries are not serialized when they are
+    // not set and must therefore be set here
+    fn entry_schema() -> toml::Table {
+        let mut config = Config::default();
+        config.global.windows_security_descriptor = Some(String::new());
+        config.global.log_level_filter = Some(String::new());
+        config.global.service.data_segment_huge_page_size = Some(HugePageSize::Size2MiB);
+        config.global.service.access_control_list =
+            Some(AccessControlListBuilder::new().owner(0).group(0).create());
+        let root_path = *config.global.root_path();
+        config.global.set_static_storage_root_path(&root_path);
+        config.global.set_data_segment_root_path(&root_path);
+        config.global.set_monitoring_root_path(&root_path);
+        config.global.domain = Some(config.global.prefix);
+        config.defaults.event.deadline = Some(Duration::ZERO);
+        config.defaults.event.notifier_created_event = Some(0);
+        config.defaults.event.notifier_dropped_event = Some(0);
+        config.defaults.event.notifier_dead_event = Some(0);
+        config.defaults.event.dynamic_attribute_changed_event = Some(0);
+        config
+            .defaults
+            .publish_subscribe
+            .publisher_data_segment_numa_node = Some(0);
+
+        Self::to_table(&config)
     }
 }
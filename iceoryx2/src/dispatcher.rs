@@ -0,0 +1,395 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`Dispatcher`] is a small executor built on top of the [`WaitSet`]. It takes the
+//! ownership of [`Subscriber`]s and [`Listener`]s, and calls a user provided callback for
+//! every received [`Sample`] and [`EventId`]. It allows small applications to handle all of
+//! their incoming communication from one single [`Dispatcher::run()`] loop.
+//!
+//! Since a [`Subscriber`] cannot be attached to a [`WaitSet`], all [`Subscriber`]s are
+//! polled whenever the [`Dispatcher`] wakes up, either due to an event on one of its
+//! [`Listener`]s or when the
+//! [`DispatcherBuilder::polling_interval()`](crate::dispatcher::DispatcherBuilder::polling_interval())
+//! has passed.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::dispatcher::DispatcherBuilder;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let pubsub = node.service_builder(&"MyServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//! let event = node.service_builder(&"MyEventName".try_into()?)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! let mut dispatcher = DispatcherBuilder::new().create::<ipc::Service>()?;
+//!
+//! dispatcher.add_subscriber(pubsub.subscriber_builder().create()?, |sample| {
+//!     println!("received: {:?}", *sample);
+//! });
+//! dispatcher.add_listener(event.listener_builder().create()?, |event_id| {
+//!     println!("event was triggered with id: {:?}", event_id);
+//! });
+//!
+//! // runs until a termination or interrupt signal was received
+//! dispatcher.run()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::{fmt::Debug, time::Duration};
+use std::collections::HashMap;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+use iceoryx2_cal::event::Event;
+
+use crate::port::event_id::EventId;
+use crate::port::listener::Listener;
+use crate::port::subscriber::Subscriber;
+use crate::port::ReceiveError;
+use crate::sample::Sample;
+use crate::service;
+use crate::signal_handling_mode::SignalHandlingMode;
+use crate::waitset::{
+    WaitSet, WaitSetAttachmentError, WaitSetAttachmentId, WaitSetBuilder, WaitSetRunError,
+    WaitSetRunResult,
+};
+
+/// The default interval in which the [`Subscriber`]s of the [`Dispatcher`] are polled for new
+/// [`Sample`]s.
+pub const DEFAULT_POLLING_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Defines the failures that can occur when calling [`DispatcherBuilder::create()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DispatcherCreateError {
+    /// The polling interval is zero.
+    InvalidPollingInterval,
+    /// An internal error has occurred.
+    InternalError,
+}
+
+impl core::fmt::Display for DispatcherCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "DispatcherCreateError::{:?}", self)
+    }
+}
+
+impl core::error::Error for DispatcherCreateError {}
+
+/// Defines the failures that can occur when calling [`Dispatcher::run()`],
+/// [`Dispatcher::run_once()`] or [`Dispatcher::run_once_with_timeout()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DispatcherRunError {
+    /// The [`Dispatcher`] has neither [`Subscriber`]s nor [`Listener`]s, running it would
+    /// lead to a deadlock.
+    NoAttachments,
+    /// The [`Listener`]s and the polling interval exceed the capacity of the underlying
+    /// [`WaitSet`].
+    InsufficientCapacity,
+    /// The process has not sufficient permissions to wait on the attachments.
+    InsufficientPermissions,
+    /// A [`Subscriber`] was unable to receive a [`Sample`].
+    ReceiveFailed(ReceiveError),
+    /// A [`Listener`] was unable to wait for [`EventId`]s.
+    ListenerWaitFailed,
+    /// An internal error has occurred.
+    InternalError,
+}
+
+impl core::fmt::Display for DispatcherRunError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "DispatcherRunError::{:?}", self)
+    }
+}
+
+impl core::error::Error for DispatcherRunError {}
+
+impl From<WaitSetRunError> for DispatcherRunError {
+    fn from(value: WaitSetRunError) -> Self {
+        match value {
+            WaitSetRunError::NoAttachments => DispatcherRunError::NoAttachments,
+            WaitSetRunError::InsufficientPermissions => DispatcherRunError::InsufficientPermissions,
+            WaitSetRunError::InternalError => DispatcherRunError::InternalError,
+        }
+    }
+}
+
+trait SubscriberDispatch {
+    fn dispatch(&mut self) -> Result<(), ReceiveError>;
+}
+
+struct SubscriberEntry<
+    Service: service::Service,
+    Payload: Debug + 'static,
+    UserHeader: Debug,
+    F: FnMut(Sample<Service, Payload, UserHeader>),
+> {
+    subscriber: Subscriber<Service, Payload, UserHeader>,
+    callback: F,
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + 'static,
+        UserHeader: Debug,
+        F: FnMut(Sample<Service, Payload, UserHeader>),
+    > SubscriberDispatch for SubscriberEntry<Service, Payload, UserHeader, F>
+{
+    fn dispatch(&mut self) -> Result<(), ReceiveError> {
+        while let Some(sample) = self.subscriber.receive()? {
+            (self.callback)(sample);
+        }
+
+        Ok(())
+    }
+}
+
+/// The builder for the [`Dispatcher`].
+#[derive(Debug)]
+pub struct DispatcherBuilder {
+    signal_handling_mode: SignalHandlingMode,
+    polling_interval: Duration,
+}
+
+impl Default for DispatcherBuilder {
+    fn default() -> Self {
+        Self {
+            signal_handling_mode: SignalHandlingMode::default(),
+            polling_interval: DEFAULT_POLLING_INTERVAL,
+        }
+    }
+}
+
+impl DispatcherBuilder {
+    /// Creates a new [`DispatcherBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defines the [`SignalHandlingMode`] of the underlying [`WaitSet`].
+    pub fn signal_handling_mode(mut self, value: SignalHandlingMode) -> Self {
+        self.signal_handling_mode = value;
+        self
+    }
+
+    /// Defines the interval in which the [`Subscriber`]s are polled for new [`Sample`]s.
+    /// Default is [`DEFAULT_POLLING_INTERVAL`].
+    pub fn polling_interval(mut self, value: Duration) -> Self {
+        self.polling_interval = value;
+        self
+    }
+
+    /// Creates the [`Dispatcher`].
+    pub fn create<'callback, Service: service::Service>(
+        self,
+    ) -> Result<Dispatcher<'callback, Service>, DispatcherCreateError> {
+        let msg = "Unable to create Dispatcher";
+        if self.polling_interval.is_zero() {
+            fail!(from self, with DispatcherCreateError::InvalidPollingInterval,
+                "{msg} since the polling interval must not be zero.");
+        }
+
+        let waitset = fail!(from self, when WaitSetBuilder::new()
+                .signal_handling_mode(self.signal_handling_mode)
+                .create(),
+            with DispatcherCreateError::InternalError,
+            "{msg} since the underlying WaitSet could not be created.");
+
+        Ok(Dispatcher {
+            waitset,
+            polling_interval: self.polling_interval,
+            subscribers: vec![],
+            listeners: vec![],
+            listener_callbacks: vec![],
+        })
+    }
+}
+
+/// Owns [`Subscriber`]s and [`Listener`]s and calls the registered callbacks whenever
+/// a [`Sample`] or an [`EventId`] was received. Can be created via the [`DispatcherBuilder`].
+pub struct Dispatcher<'callback, Service: service::Service> {
+    waitset: WaitSet<Service>,
+    polling_interval: Duration,
+    subscribers: Vec<Box<dyn SubscriberDispatch + 'callback>>,
+    listeners: Vec<Listener<Service>>,
+    listener_callbacks: Vec<Box<dyn FnMut(EventId) + 'callback>>,
+}
+
+impl<Service: service::Service> Debug for Dispatcher<'_, Service> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Dispatcher<{}> {{ waitset: {:?}, polling_interval: {:?}, number_of_subscribers: {}, listeners: {:?} }}",
+            core::any::type_name::<Service>(),
+            self.waitset,
+            self.polling_interval,
+            self.subscribers.len(),
+            self.listeners
+        )
+    }
+}
+
+impl<'callback, Service: service::Service + 'callback> Dispatcher<'callback, Service> {
+    /// Transfers the ownership of the [`Subscriber`] to the [`Dispatcher`]. The provided
+    /// callback is called for every [`Sample`] that the [`Subscriber`] receives.
+    pub fn add_subscriber<
+        Payload: Debug + 'static,
+        UserHeader: Debug + 'callback,
+        F: FnMut(Sample<Service, Payload, UserHeader>) + 'callback,
+    >(
+        &mut self,
+        subscriber: Subscriber<Service, Payload, UserHeader>,
+        callback: F,
+    ) {
+        self.subscribers.push(Box::new(SubscriberEntry {
+            subscriber,
+            callback,
+        }));
+    }
+
+    /// Transfers the ownership of the [`Listener`] to the [`Dispatcher`]. The provided
+    /// callback is called for every [`EventId`] that the [`Listener`] receives.
+    pub fn add_listener<F: FnMut(EventId) + 'callback>(
+        &mut self,
+        listener: Listener<Service>,
+        callback: F,
+    ) {
+        self.listeners.push(listener);
+        self.listener_callbacks.push(Box::new(callback));
+    }
+
+    /// Returns the number of [`Subscriber`]s that are owned by the [`Dispatcher`].
+    pub fn number_of_subscribers(&self) -> usize {
+        self.subscribers.len()
+    }
+
+    /// Returns the number of [`Listener`]s that are owned by the [`Dispatcher`].
+    pub fn number_of_listeners(&self) -> usize {
+        self.listeners.len()
+    }
+
+    /// Returns the interval in which the [`Subscriber`]s are polled for new [`Sample`]s.
+    pub fn polling_interval(&self) -> Duration {
+        self.polling_interval
+    }
+}
+
+impl<'callback, Service: service::Service + 'callback> Dispatcher<'callback, Service>
+where
+    <Service::Event as Event>::Listener: SynchronousMultiplexing,
+{
+    /// Dispatches all received [`Sample`]s and [`EventId`]s to the registered callbacks until
+    /// an interrupt- (`SIGINT`) or a termination-signal (`SIGTERM`) was received.
+    /// See [`WaitSet::wait_and_process()`].
+    pub fn run(&mut self) -> Result<WaitSetRunResult, DispatcherRunError> {
+        self.run_impl(|waitset, on_event| waitset.wait_and_process(on_event))
+    }
+
+    /// Waits until either a [`Listener`] has received an event or the polling interval has
+    /// passed, dispatches all received [`Sample`]s and [`EventId`]s and then returns.
+    /// See [`WaitSet::wait_and_process_once()`].
+    pub fn run_once(&mut self) -> Result<WaitSetRunResult, DispatcherRunError> {
+        self.run_impl(|waitset, on_event| waitset.wait_and_process_once(on_event))
+    }
+
+    /// Waits until either a [`Listener`] has received an event, the polling interval or the
+    /// provided timeout has passed, dispatches all received [`Sample`]s and [`EventId`]s and
+    /// then returns. See [`WaitSet::wait_and_process_once_with_timeout()`].
+    pub fn run_once_with_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<WaitSetRunResult, DispatcherRunError> {
+        self.run_impl(|waitset, on_event| {
+            waitset.wait_and_process_once_with_timeout(on_event, timeout)
+        })
+    }
+
+    fn run_impl<
+        W: FnOnce(
+            &WaitSet<Service>,
+            &mut dyn FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression,
+        ) -> Result<WaitSetRunResult, WaitSetRunError>,
+    >(
+        &mut self,
+        wait: W,
+    ) -> Result<WaitSetRunResult, DispatcherRunError> {
+        let msg = "Unable to run Dispatcher";
+        if self.listeners.is_empty() && self.subscribers.is_empty() {
+            fail!(from self, with DispatcherRunError::NoAttachments,
+                "{msg} since it has neither subscribers nor listeners, therefore the call would end up in a deadlock.");
+        }
+
+        let mut listener_guards = Vec::with_capacity(self.listeners.len());
+        let mut listener_ids = HashMap::with_capacity(self.listeners.len());
+        for (n, listener) in self.listeners.iter().enumerate() {
+            let guard = fail!(from self, when self.waitset.attach_notification(listener),
+                map WaitSetAttachmentError::InsufficientCapacity => DispatcherRunError::InsufficientCapacity,
+                unmatched DispatcherRunError::InternalError,
+                "{msg} since the listener {:?} could not be attached to the underlying WaitSet.", listener);
+            listener_ids.insert(WaitSetAttachmentId::from_guard(&guard), n);
+            listener_guards.push(guard);
+        }
+
+        let _polling_guard = if self.subscribers.is_empty() {
+            None
+        } else {
+            Some(
+                fail!(from self, when self.waitset.attach_interval(self.polling_interval),
+                map WaitSetAttachmentError::InsufficientCapacity => DispatcherRunError::InsufficientCapacity,
+                unmatched DispatcherRunError::InternalError,
+                "{msg} since the polling interval could not be attached to the underlying WaitSet."),
+            )
+        };
+
+        let mut dispatch_error = None;
+        let mut on_event = |attachment_id: WaitSetAttachmentId<Service>| {
+            if let Some(n) = listener_ids.get(&attachment_id) {
+                let callback = &mut self.listener_callbacks[*n];
+                if self.listeners[*n].try_wait_all(callback).is_err() {
+                    dispatch_error = Some(DispatcherRunError::ListenerWaitFailed);
+                    return CallbackProgression::Stop;
+                }
+            }
+
+            for subscriber in &mut self.subscribers {
+                if let Err(e) = subscriber.dispatch() {
+                    dispatch_error = Some(DispatcherRunError::ReceiveFailed(e));
+                    return CallbackProgression::Stop;
+                }
+            }
+
+            CallbackProgression::Continue
+        };
+
+        let result = wait(&self.waitset, &mut on_event);
+
+        if let Some(e) = dispatch_error {
+            fail!(from self, with e,
+                "{msg} since the received data could not be dispatched ({:?}).", e);
+        }
+
+        match result {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                fail!(from self, with e.into(),
+                    "{msg} since the underlying WaitSet failed with ({:?}).", e);
+            }
+        }
+    }
+}
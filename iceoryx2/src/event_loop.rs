@@ -0,0 +1,227 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`EventLoop`] binds the attachments of a [`WaitSet`] to callbacks and dispatches
+//! every wakeup of the [`WaitSet`] to them. It takes the ownership of the [`WaitSetGuard`]s
+//! so that the attachments stay attached as long as the [`EventLoop`] exists.
+//!
+//! Whenever the [`WaitSet`] wakes up, the [`EventLoop`] first collects all triggered
+//! attachments and calls their callbacks afterwards in a deterministic order:
+//!
+//!  1. attachments with a higher priority are handled first, see [`EventLoop::bind_with_priority()`]
+//!  2. attachments with the same priority are handled in the order in which they were bound
+//!
+//! Triggered attachments that are not bound to the [`EventLoop`] are ignored.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::event_loop::EventLoop;
+//! # use core::time::Duration;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let event = node.service_builder(&"MyEventName".try_into()?)
+//! #     .event()
+//! #     .open_or_create()?;
+//!
+//! let listener = event.listener_builder().create()?;
+//! let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+//! let mut event_loop = EventLoop::new(&waitset);
+//!
+//! // the listener is always handled before the heartbeat when both trigger in the same wakeup
+//! event_loop.bind_with_priority(waitset.attach_deadline(&listener, Duration::from_secs(1))?, 1,
+//!     |guard, attachment_id| {
+//!         if attachment_id.has_missed_deadline(guard) {
+//!             println!("listener missed its deadline");
+//!         }
+//!         while let Ok(Some(event_id)) = listener.try_wait_one() {
+//!             println!("received notification {:?}", event_id);
+//!         }
+//!         CallbackProgression::Continue
+//!     });
+//! event_loop.bind(waitset.attach_interval(Duration::from_millis(100))?, |guard, _| {
+//!     println!("heartbeat, lost periods: {}", guard.missed_deadlines());
+//!     CallbackProgression::Continue
+//! });
+//!
+//! // runs until a termination or interrupt signal was received
+//! event_loop.run()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::{fmt::Debug, time::Duration};
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::fail;
+
+use crate::service;
+use crate::waitset::{
+    WaitSet, WaitSetAttachmentId, WaitSetGuard, WaitSetRunError, WaitSetRunResult,
+};
+
+/// The priority that is used by [`EventLoop::bind()`].
+pub const DEFAULT_PRIORITY: u32 = 0;
+
+type EventLoopCallback<'waitset, 'attachment, 'callback, Service> = Box<
+    dyn FnMut(
+            &WaitSetGuard<'waitset, 'attachment, Service>,
+            WaitSetAttachmentId<Service>,
+        ) -> CallbackProgression
+        + 'callback,
+>;
+
+struct Binding<'waitset, 'attachment, 'callback, Service: service::Service>
+where
+    Service::Reactor: 'waitset,
+{
+    guard: WaitSetGuard<'waitset, 'attachment, Service>,
+    priority: u32,
+    callback: EventLoopCallback<'waitset, 'attachment, 'callback, Service>,
+}
+
+impl<Service: service::Service> Binding<'_, '_, '_, Service> {
+    fn is_triggered_by(&self, attachment_id: &WaitSetAttachmentId<Service>) -> bool {
+        attachment_id.has_event_from(&self.guard) || attachment_id.has_missed_deadline(&self.guard)
+    }
+}
+
+/// Binds the attachments of a [`WaitSet`] to callbacks and calls them in a deterministic
+/// order whenever the [`WaitSet`] wakes up.
+pub struct EventLoop<'waitset, 'attachment, 'callback, Service: service::Service>
+where
+    Service::Reactor: 'waitset,
+{
+    waitset: &'waitset WaitSet<Service>,
+    bindings: Vec<Binding<'waitset, 'attachment, 'callback, Service>>,
+}
+
+impl<Service: service::Service> Debug for EventLoop<'_, '_, '_, Service> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "EventLoop<{}> {{ waitset: {:?}, priorities: {:?} }}",
+            core::any::type_name::<Service>(),
+            self.waitset,
+            self.bindings.iter().map(|b| b.priority).collect::<Vec<_>>()
+        )
+    }
+}
+
+impl<'waitset, 'attachment, 'callback, Service: service::Service>
+    EventLoop<'waitset, 'attachment, 'callback, Service>
+where
+    Service::Reactor: 'waitset,
+{
+    /// Creates a new [`EventLoop`] that dispatches the wakeups of the provided [`WaitSet`].
+    pub fn new(waitset: &'waitset WaitSet<Service>) -> Self {
+        Self {
+            waitset,
+            bindings: vec![],
+        }
+    }
+
+    /// Binds the attachment of the provided [`WaitSetGuard`] with the [`DEFAULT_PRIORITY`] to
+    /// the callback. See [`EventLoop::bind_with_priority()`].
+    pub fn bind<
+        F: FnMut(
+                &WaitSetGuard<'waitset, 'attachment, Service>,
+                WaitSetAttachmentId<Service>,
+            ) -> CallbackProgression
+            + 'callback,
+    >(
+        &mut self,
+        guard: WaitSetGuard<'waitset, 'attachment, Service>,
+        callback: F,
+    ) {
+        self.bind_with_priority(guard, DEFAULT_PRIORITY, callback)
+    }
+
+    /// Transfers the ownership of the [`WaitSetGuard`] to the [`EventLoop`] and binds its
+    /// attachment to the callback. The callback is called with the [`WaitSetGuard`] and the
+    /// [`WaitSetAttachmentId`] whenever the attachment triggers, so that it can for instance
+    /// distinguish an event from a missed deadline. When multiple attachments trigger in the
+    /// same wakeup, the ones with the higher priority are handled first.
+    pub fn bind_with_priority<
+        F: FnMut(
+                &WaitSetGuard<'waitset, 'attachment, Service>,
+                WaitSetAttachmentId<Service>,
+            ) -> CallbackProgression
+            + 'callback,
+    >(
+        &mut self,
+        guard: WaitSetGuard<'waitset, 'attachment, Service>,
+        priority: u32,
+        callback: F,
+    ) {
+        self.bindings.push(Binding {
+            guard,
+            priority,
+            callback: Box::new(callback),
+        });
+    }
+
+    /// Returns the number of attachments that are bound to the [`EventLoop`].
+    pub fn number_of_bindings(&self) -> usize {
+        self.bindings.len()
+    }
+
+    /// Dispatches all wakeups of the [`WaitSet`] until a callback returns
+    /// [`CallbackProgression::Stop`] or an interrupt- (`SIGINT`) or a termination-signal
+    /// (`SIGTERM`) was received. See [`WaitSet::wait_and_process()`].
+    pub fn run(&mut self) -> Result<WaitSetRunResult, WaitSetRunError> {
+        loop {
+            match self.run_once()? {
+                WaitSetRunResult::AllEventsHandled => (),
+                v => return Ok(v),
+            }
+        }
+    }
+
+    /// Waits until the [`WaitSet`] wakes up, calls the callbacks of all triggered attachments
+    /// and then returns. See [`WaitSet::wait_and_process_once()`].
+    pub fn run_once(&mut self) -> Result<WaitSetRunResult, WaitSetRunError> {
+        self.run_once_with_timeout(Duration::MAX)
+    }
+
+    /// Waits until the [`WaitSet`] wakes up or the provided timeout has passed, calls the
+    /// callbacks of all triggered attachments and then returns.
+    /// See [`WaitSet::wait_and_process_once_with_timeout()`].
+    pub fn run_once_with_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+        let mut triggered = vec![];
+        let result = fail!(from self, when self.waitset.wait_and_process_once_with_timeout(
+                |attachment_id| {
+                    if let Some(n) = self.bindings.iter().position(|b| b.is_triggered_by(&attachment_id)) {
+                        triggered.push((n, attachment_id));
+                    }
+                    CallbackProgression::Continue
+                }, timeout),
+            "Unable to run EventLoop since the underlying WaitSet failed.");
+
+        // attachments with the same priority are handled in the order in which they were bound
+        triggered.sort_by_key(|(n, _)| (core::cmp::Reverse(self.bindings[*n].priority), *n));
+
+        for (n, attachment_id) in triggered {
+            let binding = &mut self.bindings[n];
+            if let CallbackProgression::Stop = (binding.callback)(&binding.guard, attachment_id) {
+                return Ok(WaitSetRunResult::StopRequest);
+            }
+        }
+
+        Ok(result)
+    }
+}
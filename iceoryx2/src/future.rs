@@ -0,0 +1,341 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Executor agnostic [`Future`]s to receive [`Sample`]s with
+//! [`Subscriber::receive_async()`](crate::port::subscriber::Subscriber::receive_async()) and
+//! to wait for [`EventId`]s with
+//! [`Listener::wait_async()`](crate::port::listener::Listener::wait_async()). They can be
+//! awaited in any async runtime, like `tokio` or `async-std`, without dedicating a blocking
+//! thread to every port.
+//!
+//! Whenever a [`Future`] is pending, an [`AsyncReactor`] is responsible to wake it up again.
+//! By default, a process-wide [`WaitSetReactor`] is used, that wakes up all pending
+//! [`Future`]s from one background thread in the interval of [`DEFAULT_POLLING_INTERVAL`].
+//! Custom runtimes can provide their own [`AsyncReactor`] via
+//! [`Subscriber::receive_async_with()`](crate::port::subscriber::Subscriber::receive_async_with())
+//! and [`Listener::wait_async_with()`](crate::port::listener::Listener::wait_async_with()).
+//!
+//! Requires the feature flag `async`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #     .publish_subscribe::<u64>()
+//! #     .open_or_create()?;
+//! let subscriber = service.subscriber_builder().create()?;
+//!
+//! let task = async {
+//!     while let Ok(sample) = subscriber.receive_async().await {
+//!         println!("received: {:?}", *sample);
+//!     }
+//! };
+//! // spawn the task with the executor of your choice
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::future::Future;
+use core::pin::Pin;
+use core::sync::atomic::Ordering;
+use core::task::{Context, Poll, Waker};
+use core::time::Duration;
+use std::sync::{mpsc, Arc, Mutex, OnceLock};
+use std::thread::JoinHandle;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_cal::event::ListenerWaitError;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+
+use crate::port::event_id::EventId;
+use crate::port::listener::Listener;
+use crate::port::subscriber::Subscriber;
+use crate::port::ReceiveError;
+use crate::sample::Sample;
+use crate::service;
+use crate::signal_handling_mode::SignalHandlingMode;
+use crate::waitset::WaitSetBuilder;
+
+/// The interval in which the default [`WaitSetReactor`] wakes up all pending [`Future`]s.
+pub const DEFAULT_POLLING_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Wakes up pending [`Future`]s so that the executor polls them again.
+pub trait AsyncReactor: Debug + Send + Sync {
+    /// Is called whenever a [`Future`] is pending. The implementation must call
+    /// [`Waker::wake()`] at some point in the future.
+    fn schedule_wake_up(&self, waker: &Waker);
+}
+
+/// Wakes up a pending [`Future`] immediately. The executor continuously polls the
+/// [`Future`], which minimizes the latency at the cost of CPU time.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ImmediateWakeUp;
+
+impl AsyncReactor for ImmediateWakeUp {
+    fn schedule_wake_up(&self, waker: &Waker) {
+        waker.wake_by_ref();
+    }
+}
+
+/// Defines the failures that can occur when a [`WaitSetReactor`] is created.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetReactorCreateError {
+    /// The background thread could not be started.
+    UnableToStartThread,
+    /// The underlying [`WaitSet`](crate::waitset::WaitSet) could not be created.
+    InternalError,
+}
+
+impl core::fmt::Display for WaitSetReactorCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "WaitSetReactorCreateError::{:?}", self)
+    }
+}
+
+impl core::error::Error for WaitSetReactorCreateError {}
+
+/// An [`AsyncReactor`] that runs a [`WaitSet`](crate::waitset::WaitSet) in a background
+/// thread and wakes up all pending [`Future`]s whenever the polling interval has passed.
+#[derive(Debug)]
+pub struct WaitSetReactor {
+    pending_wakers: Arc<Mutex<Vec<Waker>>>,
+    keep_running: Arc<IoxAtomicBool>,
+    polling_interval: Duration,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for WaitSetReactor {
+    fn drop(&mut self) {
+        self.keep_running.store(false, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            if thread.join().is_err() {
+                warn!(from self, "The background thread terminated with a panic.");
+            }
+        }
+        Self::wake_up_all(&self.pending_wakers);
+    }
+}
+
+impl AsyncReactor for WaitSetReactor {
+    fn schedule_wake_up(&self, waker: &Waker) {
+        let mut pending_wakers = match self.pending_wakers.lock() {
+            Ok(pending_wakers) => pending_wakers,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        if !pending_wakers.iter().any(|w| w.will_wake(waker)) {
+            pending_wakers.push(waker.clone());
+        }
+    }
+}
+
+impl WaitSetReactor {
+    /// Creates a new [`WaitSetReactor`] and starts its background thread that wakes up all
+    /// pending [`Future`]s in the provided interval.
+    pub fn new(polling_interval: Duration) -> Result<Self, WaitSetReactorCreateError> {
+        let msg = "Unable to create WaitSetReactor";
+        let pending_wakers = Arc::new(Mutex::new(Vec::<Waker>::new()));
+        let keep_running = Arc::new(IoxAtomicBool::new(true));
+        let (startup_sender, startup_receiver) = mpsc::channel();
+
+        let thread = {
+            let pending_wakers = pending_wakers.clone();
+            let keep_running = keep_running.clone();
+            std::thread::Builder::new()
+                .name("iox2_reactor".to_string())
+                .spawn(move || {
+                    Self::run(
+                        polling_interval,
+                        &pending_wakers,
+                        &keep_running,
+                        startup_sender,
+                    )
+                })
+        };
+
+        let thread = fail!(from "WaitSetReactor::new()", when thread,
+            with WaitSetReactorCreateError::UnableToStartThread,
+            "{msg} since the background thread could not be started.");
+
+        let new_self = Self {
+            pending_wakers,
+            keep_running,
+            polling_interval,
+            thread: Some(thread),
+        };
+
+        match startup_receiver.recv() {
+            Ok(Ok(())) => Ok(new_self),
+            _ => {
+                fail!(from new_self, with WaitSetReactorCreateError::InternalError,
+                    "{msg} since the underlying WaitSet could not be created.");
+            }
+        }
+    }
+
+    /// Returns the interval in which the [`WaitSetReactor`] wakes up all pending [`Future`]s.
+    pub fn polling_interval(&self) -> Duration {
+        self.polling_interval
+    }
+
+    fn run(
+        polling_interval: Duration,
+        pending_wakers: &Mutex<Vec<Waker>>,
+        keep_running: &IoxAtomicBool,
+        startup_sender: mpsc::Sender<Result<(), WaitSetReactorCreateError>>,
+    ) {
+        let waitset = match WaitSetBuilder::new()
+            .signal_handling_mode(SignalHandlingMode::Disabled)
+            .create::<crate::service::ipc::Service>()
+        {
+            Ok(waitset) => waitset,
+            Err(_) => {
+                let _ = startup_sender.send(Err(WaitSetReactorCreateError::InternalError));
+                return;
+            }
+        };
+
+        let _guard = match waitset.attach_interval(polling_interval) {
+            Ok(guard) => guard,
+            Err(_) => {
+                let _ = startup_sender.send(Err(WaitSetReactorCreateError::InternalError));
+                return;
+            }
+        };
+
+        let _ = startup_sender.send(Ok(()));
+
+        while keep_running.load(Ordering::Relaxed) {
+            let result = waitset.wait_and_process_once(|_| {
+                Self::wake_up_all(pending_wakers);
+                CallbackProgression::Continue
+            });
+
+            // never leave a future behind, fall back to sleeping when the WaitSet fails
+            if result.is_err() {
+                std::thread::sleep(polling_interval);
+                Self::wake_up_all(pending_wakers);
+            }
+        }
+    }
+
+    fn wake_up_all(pending_wakers: &Mutex<Vec<Waker>>) {
+        let wakers = match pending_wakers.lock() {
+            Ok(mut pending_wakers) => core::mem::take(&mut *pending_wakers),
+            Err(poisoned) => core::mem::take(&mut *poisoned.into_inner()),
+        };
+
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+/// Returns the process-wide [`AsyncReactor`] that is used by
+/// [`Subscriber::receive_async()`](crate::port::subscriber::Subscriber::receive_async()) and
+/// [`Listener::wait_async()`](crate::port::listener::Listener::wait_async()). It is a
+/// [`WaitSetReactor`] with the [`DEFAULT_POLLING_INTERVAL`] that is started on first use.
+/// If it cannot be started, [`ImmediateWakeUp`] is used instead.
+pub fn default_reactor() -> &'static dyn AsyncReactor {
+    static DEFAULT_REACTOR: OnceLock<Option<WaitSetReactor>> = OnceLock::new();
+    static FALLBACK_REACTOR: ImmediateWakeUp = ImmediateWakeUp;
+
+    let reactor = DEFAULT_REACTOR.get_or_init(|| match WaitSetReactor::new(DEFAULT_POLLING_INTERVAL) {
+        Ok(reactor) => Some(reactor),
+        Err(e) => {
+            warn!(from "future::default_reactor()",
+                "Unable to start the default WaitSetReactor ({:?}), pending futures are woken up immediately instead.", e);
+            None
+        }
+    });
+
+    match reactor {
+        Some(reactor) => reactor,
+        None => &FALLBACK_REACTOR,
+    }
+}
+
+/// The [`Future`] that is returned by
+/// [`Subscriber::receive_async()`](crate::port::subscriber::Subscriber::receive_async()). It
+/// is ready as soon as a [`Sample`] was received.
+#[derive(Debug)]
+pub struct ReceiveFuture<'a, Service: service::Service, Payload: Debug + 'static, UserHeader: Debug>
+{
+    subscriber: &'a Subscriber<Service, Payload, UserHeader>,
+    reactor: &'a dyn AsyncReactor,
+}
+
+impl<'a, Service: service::Service, Payload: Debug + 'static, UserHeader: Debug>
+    ReceiveFuture<'a, Service, Payload, UserHeader>
+{
+    pub(crate) fn new(
+        subscriber: &'a Subscriber<Service, Payload, UserHeader>,
+        reactor: &'a dyn AsyncReactor,
+    ) -> Self {
+        Self {
+            subscriber,
+            reactor,
+        }
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + 'static, UserHeader: Debug> Future
+    for ReceiveFuture<'_, Service, Payload, UserHeader>
+{
+    type Output = Result<Sample<Service, Payload, UserHeader>, ReceiveError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.subscriber.receive() {
+            Ok(Some(sample)) => Poll::Ready(Ok(sample)),
+            Ok(None) => {
+                self.reactor.schedule_wake_up(cx.waker());
+                Poll::Pending
+            }
+            Err(e) => Poll::Ready(Err(e)),
+        }
+    }
+}
+
+/// The [`Future`] that is returned by
+/// [`Listener::wait_async()`](crate::port::listener::Listener::wait_async()). It is ready as
+/// soon as an [`EventId`] was received.
+#[derive(Debug)]
+pub struct ListenerWaitFuture<'a, Service: service::Service> {
+    listener: &'a Listener<Service>,
+    reactor: &'a dyn AsyncReactor,
+}
+
+impl<'a, Service: service::Service> ListenerWaitFuture<'a, Service> {
+    pub(crate) fn new(listener: &'a Listener<Service>, reactor: &'a dyn AsyncReactor) -> Self {
+        Self { listener, reactor }
+    }
+}
+
+impl<Service: service::Service> Future for ListenerWaitFuture<'_, Service> {
+    type Output = Result<EventId, ListenerWaitError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.listener.try_wait_one() {
+            Ok(Some(event_id)) => Poll::Ready(Ok(event_id)),
+            Ok(None) => {
+                self.reactor.schedule_wake_up(cx.waker());
+                Poll::Pending
+            }
+            Err(e) => Poll::Ready(Err(e)),
+        }
+    }
+}
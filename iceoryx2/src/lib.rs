@@ -270,11 +270,20 @@
 //! # Feature Flags
 //!
 //!  * `dev_permissions` - The permissions of all resources will be set to read, write, execute
-//!     for everyone. This shall not be used in production and is meant to be enabled in a docker
-//!     environment with inconsistent user configuration.
+//!    for everyone. This shall not be used in production and is meant to be enabled in a docker
+//!    environment with inconsistent user configuration.
 //!  * `logger_log` - Uses the [log crate](https://crates.io/crates/log) as default log backend
 //!  * `logger_tracing` - Uses the [tracing crate](https://crates.io/crates/tracing) as default log
-//!     backend
+//!    backend
+//!  * `small_capacities` - Reduces the internal fixed capacities, like
+//!    [`MAX_NUMBER_OF_DYNAMIC_ATTRIBUTES`](crate::service::attribute::MAX_NUMBER_OF_DYNAMIC_ATTRIBUTES)
+//!    or [`SYSTEM_EVENT_MAX_PORTS`](crate::node::system_event::SYSTEM_EVENT_MAX_PORTS), to
+//!    lower the memory footprint.
+//!  * `large_capacities` - Increases the internal fixed capacities for large-scale deployments.
+//!    Takes precedence over `small_capacities`.
+//!
+//! The capacities define the layout of the shared resources, therefore all processes that
+//! communicate with each other must be built with the same capacity feature.
 //!
 //! # Custom Configuration
 //!
@@ -284,15 +293,64 @@
 //! For in-depth details and examples, please visit the
 //! [GitHub config folder](https://github.com/eclipse-iceoryx/iceoryx2/tree/main/config).
 
+// Selects the value of an internal fixed capacity depending on the `small_capacities` and
+// `large_capacities` features.
+macro_rules! capacity {
+    (small: $small:expr, default: $default:expr, large: $large:expr) => {
+        if cfg!(feature = "large_capacities") {
+            $large
+        } else if cfg!(feature = "small_capacities") {
+            $small
+        } else {
+            $default
+        }
+    };
+}
+
+#[cfg(all(target_os = "windows", feature = "robust_mutex_monitoring"))]
+compile_error!(
+    "The feature \"robust_mutex_monitoring\" is not supported on Windows since the robust \
+     mutex emulation cannot detect owners that died in another process. Use the default file \
+     lock based node monitoring instead."
+);
+
+#[cfg(all(not(target_os = "linux"), feature = "pidfd_monitoring"))]
+compile_error!(
+    "The feature \"pidfd_monitoring\" is only supported on Linux. Use the feature \
+     \"robust_mutex_monitoring\" or the default file lock based node monitoring instead."
+);
+
+#[cfg(all(not(target_os = "linux"), feature = "io_uring_event"))]
+compile_error!(
+    "The feature \"io_uring_event\" is only supported on Linux. Use the default unix datagram \
+     socket based events instead."
+);
+
 #[cfg(doctest)]
 mod compiletests;
 
 /// Handles iceoryx2s global configuration
 pub mod config;
 
+/// Executor-style helper that owns [`Subscriber`](crate::port::subscriber::Subscriber)s and
+/// [`Listener`](crate::port::listener::Listener)s and dispatches everything they receive to
+/// user provided callbacks.
+pub mod dispatcher;
+
+/// Binds the attachments of a [`WaitSet`](crate::waitset::WaitSet) to callbacks with
+/// priorities and dispatches them in a deterministic order.
+pub mod event_loop;
+
+/// Executor agnostic futures to receive samples and events in async runtimes.
+#[cfg(feature = "async")]
+pub mod future;
+
 /// Central instance that owns all service entities and can handle incoming event in an event loop
 pub mod node;
 
+/// Typed views on the serialized `[u8]` payload of a [`Sample`](crate::sample::Sample).
+pub mod payload_view;
+
 /// The ports or communication endpoints of iceoryx2
 pub mod port;
 
@@ -314,6 +372,9 @@ pub mod service;
 /// [`WaitSet`](crate::waitset::WaitSet) shall handle system signals.
 pub mod signal_handling_mode;
 
+/// Services whose payload is one type out of a closed set of types.
+pub mod tagged_union;
+
 /// Loads a meaninful subset to cover 90% of the iceoryx2 communication use cases.
 pub mod prelude;
 
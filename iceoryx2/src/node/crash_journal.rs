@@ -0,0 +1,405 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The crash journal is an opt-in, machine-readable record of a [`Node`](crate::node::Node)
+//! that survives an abnormal termination of the owning process. It is enabled with
+//! [`NodeBuilder::crash_journal()`](crate::node::NodeBuilder::crash_journal()).
+//!
+//! The journal is created together with the [`Node`](crate::node::Node) and contains the
+//! [`NodeId`] and the [`ProcessId`] of the owner. When the [`Node`](crate::node::Node) goes
+//! out of scope the journal is removed. When the process is terminated by a fatal signal
+//! (`SIGSEGV`, `SIGBUS`, `SIGILL`, `SIGFPE` or `SIGABRT`) a crash handler appends the signal
+//! to the journal before the process terminates with the default action of the signal.
+//!
+//! The next cleanup pass, see [`Node::cleanup_crashed_nodes()`](crate::node::Node::cleanup_crashed_nodes()),
+//! uses the remaining journals to reclaim exactly the resources of the crashed
+//! [`Node`](crate::node::Node)s.
+//!
+//! The crash handler is best-effort. It cannot be installed when another signal handler is
+//! already registered for one of the fatal signals via the
+//! [`SignalHandler`](iceoryx2_bb_posix::signal::SignalHandler) and it cannot record a stack
+//! overflow. The journal itself is written on creation, therefore a crashed
+//! [`Node`](crate::node::Node) is reclaimed in those cases as well, just without the signal.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::node::crash_journal::CrashRecord;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new()
+//!     .crash_journal(true)
+//!     .create::<ipc::Service>()?;
+//!
+//! CrashRecord::list(node.config(), |record| {
+//!     println!("node {:?} of process {} crashed with {:?}",
+//!         record.node_id(), record.process_id(), record.signal());
+//!     CallbackProgression::Continue
+//! })?;
+//!
+//! // reclaims the resources of all crashed nodes that left a journal behind
+//! Node::<ipc::Service>::cleanup_crashed_nodes(node.config());
+//! # Ok(())
+//! # }
+//! ```
+
+use core::sync::atomic::Ordering;
+use std::sync::OnceLock;
+
+use iceoryx2_bb_container::semantic_string::{SemanticString, SemanticStringError};
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::{debug, fail, warn};
+use iceoryx2_bb_posix::directory::{Directory, DirectoryCreateError, DirectoryOpenError};
+use iceoryx2_bb_posix::file::{
+    AccessMode, CreationMode, File, FileBuilder, FileCreationError, FileRemoveError,
+};
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+use iceoryx2_bb_posix::permission::Permission;
+use iceoryx2_bb_posix::process::{Process, ProcessId};
+use iceoryx2_bb_posix::signal::{FetchableSignal, SignalGuard, SignalHandler};
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicI32;
+use iceoryx2_pal_posix::posix::{self, Struct};
+
+use crate::config::Config;
+use crate::node::NodeId;
+
+/// The maximum number of [`Node`](crate::node::Node)s with a crash journal in one process.
+pub const MAX_NUMBER_OF_CRASH_JOURNALS: usize = capacity!(small: 8, default: 64, large: 256);
+
+const INVALID_FILE_DESCRIPTOR: i32 = -1;
+const NODE_ID_KEY: &str = "node_id";
+const PROCESS_ID_KEY: &str = "pid";
+const SIGNAL_KEY: &str = "signal";
+const CRASH_SIGNALS: [FetchableSignal; 5] = [
+    FetchableSignal::Abort,
+    FetchableSignal::Bus,
+    FetchableSignal::FloatingPointError,
+    FetchableSignal::IllegalInstruction,
+    FetchableSignal::SegmentationFault,
+];
+
+// The crash handler must not lock or allocate, therefore the open journals are stored as
+// plain file descriptors.
+static JOURNAL_FILE_DESCRIPTORS: [IoxAtomicI32; MAX_NUMBER_OF_CRASH_JOURNALS] =
+    [const { IoxAtomicI32::new(INVALID_FILE_DESCRIPTOR) }; MAX_NUMBER_OF_CRASH_JOURNALS];
+
+struct CrashHandlerGuard(#[allow(dead_code)] SignalGuard);
+
+unsafe impl Send for CrashHandlerGuard {}
+unsafe impl Sync for CrashHandlerGuard {}
+
+static CRASH_HANDLER: OnceLock<Option<CrashHandlerGuard>> = OnceLock::new();
+
+/// Describes the failures of [`CrashRecord::list()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum CrashJournalListError {
+    /// The process has insufficient permissions to read the crash journals.
+    InsufficientPermissions,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalError,
+}
+
+impl core::fmt::Display for CrashJournalListError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "CrashJournalListError::{:?}", self)
+    }
+}
+
+impl core::error::Error for CrashJournalListError {}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub(crate) enum CrashJournalCreateError {
+    InsufficientPermissions,
+    ExceedsMaxNumberOfCrashJournals,
+    InternalError,
+}
+
+/// The content of a crash journal that was left behind by a [`Node`](crate::node::Node).
+#[derive(Debug, Clone)]
+pub struct CrashRecord {
+    node_id: NodeId,
+    process_id: ProcessId,
+    signal: Option<FetchableSignal>,
+    path: FilePath,
+}
+
+impl CrashRecord {
+    /// Calls the provided callback for every crash journal that exists in the
+    /// [`Config`]. Journals that cannot be read or parsed are skipped.
+    ///
+    /// The journal of a [`Node`](crate::node::Node) that is still alive is listed as well,
+    /// since it exists until the [`Node`](crate::node::Node) goes out of scope.
+    pub fn list<F: FnMut(CrashRecord) -> CallbackProgression>(
+        config: &Config,
+        mut callback: F,
+    ) -> Result<(), CrashJournalListError> {
+        let origin = "CrashRecord::list()";
+        let msg = "Unable to list crash journals";
+        let journal_dir = config.global.node_dir();
+
+        let directory = match Directory::new(&journal_dir) {
+            Ok(directory) => directory,
+            Err(DirectoryOpenError::DoesNotExist) => return Ok(()),
+            Err(DirectoryOpenError::InsufficientPermissions) => {
+                fail!(from origin, with CrashJournalListError::InsufficientPermissions,
+                    "{} since the directory {} could not be opened due to insufficient permissions.",
+                    msg, journal_dir);
+            }
+            Err(e) => {
+                fail!(from origin, with CrashJournalListError::InternalError,
+                    "{} since the directory {} could not be opened ({:?}).", msg, journal_dir, e);
+            }
+        };
+
+        let entries = fail!(from origin, when directory.contents(),
+            with CrashJournalListError::InternalError,
+            "{} since the contents of the directory {} could not be read.", msg, journal_dir);
+
+        let prefix = config.global.prefix.as_bytes();
+        let suffix = config.global.node.crash_journal_suffix.as_bytes();
+        for entry in entries {
+            let name = entry.name().as_bytes();
+            if name.len() <= prefix.len() + suffix.len()
+                || !name.starts_with(prefix)
+                || !name.ends_with(suffix)
+            {
+                continue;
+            }
+
+            let path = fail!(from origin, when FilePath::from_path_and_file(&journal_dir, entry.name()),
+                with CrashJournalListError::InternalError,
+                "This should never happen! {} since the path of the crash journal exceeds the maximum path length.", msg);
+
+            if let Some(record) = Self::read(&path) {
+                if callback(record) == CallbackProgression::Stop {
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the [`NodeId`] of the [`Node`](crate::node::Node) that owned the journal.
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the [`ProcessId`] of the process that owned the
+    /// [`Node`](crate::node::Node).
+    pub fn process_id(&self) -> ProcessId {
+        self.process_id
+    }
+
+    /// Returns the fatal signal that terminated the process. If the process terminated
+    /// without recording a signal, for instance when it was killed with `SIGKILL`, it
+    /// returns [`None`].
+    pub fn signal(&self) -> Option<FetchableSignal> {
+        self.signal
+    }
+
+    pub(crate) fn remove(self) -> Result<bool, FileRemoveError> {
+        File::remove(&self.path)
+    }
+
+    fn read(path: &FilePath) -> Option<CrashRecord> {
+        let origin = "CrashRecord::read()";
+        let file = match FileBuilder::new(path).open_existing(AccessMode::Read) {
+            Ok(file) => file,
+            Err(e) => {
+                debug!(from origin, "Unable to open the crash journal {} ({:?}).", path, e);
+                return None;
+            }
+        };
+
+        let mut content = String::new();
+        if let Err(e) = file.read_to_string(&mut content) {
+            debug!(from origin, "Unable to read the crash journal {} ({:?}).", path, e);
+            return None;
+        }
+
+        let mut node_id = None;
+        let mut process_id = None;
+        let mut signal = None;
+        for line in content.lines() {
+            match line.split_once('=') {
+                Some((NODE_ID_KEY, value)) => node_id = value.parse::<u128>().ok(),
+                Some((PROCESS_ID_KEY, value)) => process_id = value.parse::<posix::pid_t>().ok(),
+                Some((SIGNAL_KEY, value)) => signal = value.parse::<i32>().ok().map(Into::into),
+                _ => (),
+            }
+        }
+
+        match (node_id, process_id) {
+            (Some(node_id), Some(process_id)) => Some(CrashRecord {
+                node_id: NodeId(node_id.into()),
+                process_id: ProcessId::new(process_id),
+                signal,
+                path: *path,
+            }),
+            _ => {
+                debug!(from origin, "Skipping the crash journal {} since it is corrupted.", path);
+                None
+            }
+        }
+    }
+}
+
+/// The crash journal of a [`Node`](crate::node::Node). It is removed when it goes out of
+/// scope.
+#[derive(Debug)]
+pub(crate) struct CrashJournal {
+    _file: File,
+    slot: usize,
+}
+
+impl Drop for CrashJournal {
+    fn drop(&mut self) {
+        JOURNAL_FILE_DESCRIPTORS[self.slot].store(INVALID_FILE_DESCRIPTOR, Ordering::Relaxed);
+    }
+}
+
+impl CrashJournal {
+    pub(crate) fn create(
+        config: &Config,
+        node_id: &NodeId,
+    ) -> Result<Self, CrashJournalCreateError> {
+        let origin = format!("CrashJournal::create({:?})", node_id);
+        let msg = "Unable to create crash journal";
+        let journal_dir = config.global.node_dir();
+
+        match Directory::create(&journal_dir, Permission::OWNER_ALL | Permission::GROUP_ALL) {
+            Ok(_) | Err(DirectoryCreateError::DirectoryAlreadyExists) => (),
+            Err(DirectoryCreateError::InsufficientPermissions) => {
+                fail!(from origin, with CrashJournalCreateError::InsufficientPermissions,
+                    "{} since the directory {} could not be created due to insufficient permissions.",
+                    msg, journal_dir);
+            }
+            Err(e) => {
+                fail!(from origin, with CrashJournalCreateError::InternalError,
+                    "{} since the directory {} could not be created ({:?}).", msg, journal_dir, e);
+            }
+        }
+
+        let path = fail!(from origin, when journal_path(config, node_id),
+            with CrashJournalCreateError::InternalError,
+            "{} since the path of the crash journal exceeds the maximum path length.", msg);
+
+        let mut file = fail!(from origin, when FileBuilder::new(&path)
+                .has_ownership(true)
+                .creation_mode(CreationMode::PurgeAndCreate)
+                .permission(Permission::OWNER_ALL | Permission::GROUP_READ)
+                .create(),
+            map FileCreationError::InsufficientPermissions => CrashJournalCreateError::InsufficientPermissions,
+            unmatched CrashJournalCreateError::InternalError,
+            "{} since the journal file {} could not be created.", msg, path);
+
+        let header = format!(
+            "{NODE_ID_KEY}={}\n{PROCESS_ID_KEY}={}\n",
+            node_id.value(),
+            Process::from_self().id()
+        );
+        fail!(from origin, when file.write(header.as_bytes()),
+            with CrashJournalCreateError::InternalError,
+            "{} since the journal header could not be written.", msg);
+
+        let fd = unsafe { file.file_descriptor().native_handle() };
+        let slot = match JOURNAL_FILE_DESCRIPTORS.iter().position(|s| {
+            s.compare_exchange(
+                INVALID_FILE_DESCRIPTOR,
+                fd,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            )
+            .is_ok()
+        }) {
+            Some(slot) => slot,
+            None => {
+                fail!(from origin, with CrashJournalCreateError::ExceedsMaxNumberOfCrashJournals,
+                    "{} since the process already has the maximum number of {} crash journals.",
+                    msg, MAX_NUMBER_OF_CRASH_JOURNALS);
+            }
+        };
+
+        install_crash_handler();
+
+        Ok(Self { _file: file, slot })
+    }
+}
+
+fn journal_path(config: &Config, node_id: &NodeId) -> Result<FilePath, SemanticStringError> {
+    let mut name: FileName = config.global.prefix;
+    name.push_bytes(node_id.value().to_string().as_bytes())?;
+    name.push_bytes(config.global.node.crash_journal_suffix.as_bytes())?;
+    FilePath::from_path_and_file(&config.global.node_dir(), &name)
+}
+
+fn install_crash_handler() {
+    CRASH_HANDLER.get_or_init(|| {
+        match SignalHandler::register_multiple_signals(&CRASH_SIGNALS.to_vec(), &record_crash) {
+            Ok(guard) => Some(CrashHandlerGuard(guard)),
+            Err(e) => {
+                warn!(from "CrashJournal::install_crash_handler()",
+                    "Unable to install the crash handler ({:?}). The crash journals will not contain the signal that terminated the process.", e);
+                None
+            }
+        }
+    });
+}
+
+// Called from within the signal handler, therefore it must neither lock nor allocate.
+fn record_crash(signal: FetchableSignal) {
+    let mut record = [0u8; 32];
+    let mut len = 0;
+    for byte in SIGNAL_KEY.as_bytes().iter().chain(b"=") {
+        record[len] = *byte;
+        len += 1;
+    }
+
+    let mut digits = [0u8; 10];
+    let mut number_of_digits = 0;
+    let mut value = (signal as i32).unsigned_abs();
+    loop {
+        digits[number_of_digits] = b'0' + (value % 10) as u8;
+        number_of_digits += 1;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    for digit in digits[..number_of_digits].iter().rev() {
+        record[len] = *digit;
+        len += 1;
+    }
+    record[len] = b'\n';
+    len += 1;
+
+    for fd in &JOURNAL_FILE_DESCRIPTORS {
+        let fd = fd.load(Ordering::Relaxed);
+        if fd != INVALID_FILE_DESCRIPTOR {
+            unsafe { posix::write(fd, record.as_ptr() as *const posix::void, len) };
+        }
+    }
+
+    // restore the default action and raise the signal again, so that the process
+    // terminates as if the crash handler was never installed
+    let mut default_action = posix::sigaction_t::new();
+    default_action.set_handler(posix::SIG_DFL as posix::sighandler_t);
+    let mut previous_action = posix::sigaction_t::new();
+    unsafe {
+        posix::sigaction(signal as posix::int, &default_action, &mut previous_action);
+        posix::kill(posix::getpid(), signal as posix::int);
+    }
+}
@@ -0,0 +1,260 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Waits for the termination of the processes of all other [`Node`]s and removes the stale
+//! resources of a dead [`Node`] right away, instead of at the next
+//! [`Node::cleanup_dead_nodes()`] call.
+//!
+//! The termination can only be awaited when the [`Monitoring`] of the
+//! [`Service`](crate::service::Service) provides a
+//! [`ProcessFileDescriptor`](iceoryx2_bb_posix::process_file_descriptor::ProcessFileDescriptor),
+//! like the `pidfd` based monitoring of the `ipc::Service` with the `pidfd_monitoring` feature.
+//! All other [`Node`]s are not watched and are still detected with
+//! [`Node::cleanup_dead_nodes()`].
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use core::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let mut detector = node.dead_node_detector();
+//!
+//! // returns as soon as another node died and was cleaned up or when the timeout has passed
+//! let cleanup_state = detector.timed_wait(Duration::from_millis(10))?;
+//! println!("cleaned up {} dead nodes", cleanup_state.cleanups);
+//! # Ok(())
+//! # }
+//! ```
+
+use core::time::Duration;
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_log::{debug, fail};
+use iceoryx2_bb_posix::file_descriptor_set::{
+    FileDescriptorSet, FileDescriptorSetWaitError, FileEvent,
+};
+use iceoryx2_bb_posix::process::Process;
+use iceoryx2_cal::monitoring::{Monitoring, MonitoringBuilder, MonitoringMonitor, State};
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+
+use crate::node::{CleanupState, Node, NodeId, NodeListFailure, NodeState, NodeWaitFailure};
+use crate::service;
+use crate::service::config_scheme::node_monitoring_config;
+
+/// Describes the failures of [`DeadNodeDetector::timed_wait()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DeadNodeDetectorWaitError {
+    /// The process received an interrupt signal while waiting.
+    Interrupt,
+    /// A termination signal `SIGTERM` was received while waiting.
+    TerminationRequest,
+    /// The [`Node`]s could not be listed since the process does not have sufficient
+    /// permissions.
+    InsufficientPermissions,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
+
+impl core::fmt::Display for DeadNodeDetectorWaitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "DeadNodeDetectorWaitError::{:?}", self)
+    }
+}
+
+impl core::error::Error for DeadNodeDetectorWaitError {}
+
+impl From<NodeWaitFailure> for DeadNodeDetectorWaitError {
+    fn from(value: NodeWaitFailure) -> Self {
+        match value {
+            NodeWaitFailure::Interrupt => DeadNodeDetectorWaitError::Interrupt,
+            NodeWaitFailure::TerminationRequest => DeadNodeDetectorWaitError::TerminationRequest,
+        }
+    }
+}
+
+impl From<NodeListFailure> for DeadNodeDetectorWaitError {
+    fn from(value: NodeListFailure) -> Self {
+        match value {
+            NodeListFailure::Interrupt => DeadNodeDetectorWaitError::Interrupt,
+            NodeListFailure::InsufficientPermissions => {
+                DeadNodeDetectorWaitError::InsufficientPermissions
+            }
+            NodeListFailure::InternalError => DeadNodeDetectorWaitError::InternalFailure,
+        }
+    }
+}
+
+const NO_CLEANUPS: CleanupState = CleanupState {
+    cleanups: 0,
+    failed_cleanups: 0,
+};
+
+type Monitor<Service> = <<Service as service::Service>::Monitoring as Monitoring>::Monitor;
+
+/// Waits for the termination of the processes of all other [`Node`]s and cleans up every
+/// [`Node`] that died. Created with [`Node::dead_node_detector()`].
+pub struct DeadNodeDetector<'node, Service: service::Service> {
+    node: &'node Node<Service>,
+    watched_nodes: Vec<(NodeId, Monitor<Service>)>,
+}
+
+impl<Service: service::Service> core::fmt::Debug for DeadNodeDetector<'_, Service> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "DeadNodeDetector<{}> {{ watched_nodes: {:?} }}",
+            core::any::type_name::<Service>(),
+            self.watched_nodes
+                .iter()
+                .map(|(node_id, _)| node_id)
+                .collect::<Vec<_>>()
+        )
+    }
+}
+
+impl<'node, Service: service::Service> DeadNodeDetector<'node, Service> {
+    pub(crate) fn new(node: &'node Node<Service>) -> Self {
+        Self {
+            node,
+            watched_nodes: vec![],
+        }
+    }
+
+    /// Returns the number of [`Node`]s whose termination was awaited with the last
+    /// [`DeadNodeDetector::timed_wait()`] call.
+    pub fn number_of_watched_nodes(&self) -> usize {
+        self.watched_nodes.len()
+    }
+
+    /// Blocks until the process of at least one other [`Node`] terminated or the timeout has
+    /// passed. The stale resources of every dead [`Node`] are removed before it returns, the
+    /// result is reported in the returned [`CleanupState`]. The watched [`Node`]s are updated
+    /// at the beginning of every call, [`Node`]s that are created while waiting are watched
+    /// with the next call.
+    pub fn timed_wait(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<CleanupState, DeadNodeDetectorWaitError> {
+        let msg = "Unable to wait for the termination of nodes";
+        self.node.handle_termination_request(msg)?;
+        self.update_watched_nodes(msg)?;
+
+        if self.watched_nodes.is_empty() {
+            self.node.wait(timeout)?;
+            return Ok(NO_CLEANUPS);
+        }
+
+        let fd_set = FileDescriptorSet::new();
+        let mut guards = vec![];
+        for (node_id, monitor) in &self.watched_nodes {
+            if let Some(process) = monitor.process_file_descriptor() {
+                guards.push(fail!(from self, when fd_set.add(process),
+                    with DeadNodeDetectorWaitError::InternalFailure,
+                    "{} since the process of the node {:?} could not be watched.", msg, node_id));
+            }
+        }
+
+        let wait_result = fd_set.timed_wait(timeout, FileEvent::Read, |_| {});
+        drop(guards);
+        self.node.handle_termination_request(msg)?;
+        match wait_result {
+            Ok(0) => Ok(NO_CLEANUPS),
+            Ok(_) => Ok(self.cleanup_terminated_nodes()),
+            Err(FileDescriptorSetWaitError::Interrupt) => {
+                fail!(from self, with DeadNodeDetectorWaitError::Interrupt,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with DeadNodeDetectorWaitError::InternalFailure,
+                    "{} since the wait on the processes of the nodes failed ({:?}).", msg, e);
+            }
+        }
+    }
+
+    fn update_watched_nodes(&mut self, msg: &str) -> Result<(), DeadNodeDetectorWaitError> {
+        let config = node_monitoring_config::<Service>(self.node.config());
+        let node_list = fail!(from self, when Node::<Service>::list_all_nodes(&config),
+            "{} since the nodes could not be listed.", msg);
+
+        let node_ids: Vec<NodeId> = node_list
+            .iter()
+            .filter_map(|name| core::str::from_utf8(name.as_bytes()).ok())
+            .filter_map(|value| value.parse::<u128>().ok())
+            .map(|value| NodeId(value.into()))
+            .collect();
+
+        self.watched_nodes
+            .retain(|(node_id, _)| node_ids.contains(node_id));
+
+        // the process of a node of this process can never terminate while waiting
+        let own_pid = Process::from_self().id();
+        for node_id in node_ids {
+            if node_id.0.pid() == own_pid || self.watched_nodes.iter().any(|(id, _)| *id == node_id)
+            {
+                continue;
+            }
+
+            let monitor = match <Service::Monitoring as Monitoring>::Builder::new(
+                &node_id.as_file_name(),
+            )
+            .config(&config)
+            .monitor()
+            {
+                Ok(monitor) => monitor,
+                Err(e) => {
+                    debug!(from self, "Unable to watch the node {:?} since its monitor could not be created ({:?}).", node_id, e);
+                    continue;
+                }
+            };
+
+            if monitor.process_file_descriptor().is_some() {
+                self.watched_nodes.push((node_id, monitor));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn cleanup_terminated_nodes(&mut self) -> CleanupState {
+        let mut cleanup_state = NO_CLEANUPS;
+        let config = self.node.config().clone();
+
+        self.watched_nodes.retain(|(node_id, monitor)| {
+            if monitor.state() != Ok(State::Dead) {
+                return true;
+            }
+
+            match NodeState::<Service>::new(node_id, &config) {
+                Ok(Some(NodeState::Dead(dead_node))) => match dead_node.remove_stale_resources() {
+                    Ok(_) => {
+                        debug!(from "DeadNodeDetector::cleanup_terminated_nodes()",
+                            "The dead node ({:?}) was successfully removed.", node_id);
+                        cleanup_state.cleanups += 1;
+                    }
+                    Err(e) => {
+                        debug!(from "DeadNodeDetector::cleanup_terminated_nodes()",
+                            "Unable to remove the dead node {:?} ({:?}).", node_id, e);
+                        cleanup_state.failed_cleanups += 1;
+                    }
+                },
+                // already cleaned up by someone else
+                Ok(None) => (),
+                Ok(Some(_)) | Err(_) => cleanup_state.failed_cleanups += 1,
+            }
+
+            false
+        });
+
+        cleanup_state
+    }
+}
@@ -139,17 +139,38 @@
 //! # }
 //! ```
 
+/// Opt-in record of a node that survives a crash of the owning process.
+pub mod crash_journal;
+
+/// Waits for the termination of other nodes and cleans them up right away.
+pub mod dead_node_detector;
+
 /// The name for a node.
 pub mod node_name;
 
+/// Optional resource quotas of a node.
+pub mod quota;
+
+/// Waits until the services a node requires are available.
+pub mod service_dependencies;
+
+/// The built-in system event channel of a domain.
+pub mod system_event;
+
 #[doc(hidden)]
 pub mod testing;
 
+use crate::node::crash_journal::{CrashJournal, CrashJournalCreateError, CrashRecord};
+use crate::node::dead_node_detector::DeadNodeDetector;
 use crate::node::node_name::NodeName;
+use crate::node::quota::{NodeQuota, NodeResourceUsage, QuotaTracker};
+use crate::node::service_dependencies::ServiceDependencies;
+use crate::node::system_event::{SystemEventCreateError, SystemEventListener, SystemEventNotifier};
 use crate::service::builder::{Builder, OpenDynamicStorageFailure};
 use crate::service::config_scheme::{
     node_details_path, node_monitoring_config, service_tag_config,
 };
+use crate::service::port_factory::event;
 use crate::service::service_id::ServiceId;
 use crate::service::service_name::ServiceName;
 use crate::service::{
@@ -164,9 +185,14 @@ use core::time::Duration;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
-use iceoryx2_bb_log::{debug, fail, fatal_panic, trace, warn};
+use iceoryx2_bb_log::fail::{set_thread_failure_hook, FailureHook, ThreadFailureHookGuard};
+use iceoryx2_bb_log::{
+    debug, fail, fatal_panic, set_log_level_filter, set_thread_logger, trace, warn, Log,
+    ThreadLoggerGuard,
+};
 use iceoryx2_bb_posix::clock::{nanosleep, NanosleepError, Time};
 use iceoryx2_bb_posix::process::{Process, ProcessId};
+use iceoryx2_bb_posix::security_descriptor::NamedObjectSecurityDescriptor;
 use iceoryx2_bb_posix::signal::SignalHandler;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_bb_system_types::file_name::FileName;
@@ -186,6 +212,7 @@ use std::sync::Mutex;
 #[derive(
     Debug, Eq, Hash, PartialEq, Clone, Copy, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
 )]
+#[repr(C)]
 pub struct NodeId(UniqueSystemId);
 
 impl NodeId {
@@ -217,6 +244,8 @@ pub enum NodeCreationFailure {
     InsufficientPermissions,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
     InternalError,
+    /// The Windows security descriptor defined in the [`Config`] is not a valid SDDL string.
+    InvalidSecurityDescriptor,
 }
 
 impl core::fmt::Display for NodeCreationFailure {
@@ -779,6 +808,39 @@ impl RegisteredServices {
     }
 }
 
+// the logger and the failure hook of the node, when they are not set the global ones are used
+#[derive(Clone, Copy, Default)]
+struct NodeLogger {
+    logger: Option<&'static dyn Log>,
+    failure_hook: Option<&'static dyn FailureHook>,
+}
+
+impl core::fmt::Debug for NodeLogger {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "NodeLogger {{ has_logger: {}, has_failure_hook: {} }}",
+            self.logger.is_some(),
+            self.failure_hook.is_some()
+        )
+    }
+}
+
+impl NodeLogger {
+    fn scope(&self) -> LogScope {
+        LogScope {
+            _logger: self.logger.map(set_thread_logger),
+            _failure_hook: self.failure_hook.map(set_thread_failure_hook),
+        }
+    }
+}
+
+// restores the previous logger and failure hook of the thread when it goes out of scope
+pub(crate) struct LogScope {
+    _logger: Option<ThreadLoggerGuard>,
+    _failure_hook: Option<ThreadFailureHookGuard>,
+}
+
 #[derive(Debug)]
 pub(crate) struct SharedNode<Service: service::Service> {
     id: NodeId,
@@ -786,7 +848,10 @@ pub(crate) struct SharedNode<Service: service::Service> {
     monitoring_token: UnsafeCell<Option<<Service::Monitoring as Monitoring>::Token>>,
     registered_services: RegisteredServices,
     signal_handling_mode: SignalHandlingMode,
+    quota: Arc<QuotaTracker>,
+    logger: NodeLogger,
     _details_storage: Service::StaticStorage,
+    _crash_journal: Option<CrashJournal>,
 }
 
 unsafe impl<Service: service::Service> Send for SharedNode<Service> {}
@@ -804,10 +869,21 @@ impl<Service: service::Service> SharedNode<Service> {
     pub(crate) fn registered_services(&self) -> &RegisteredServices {
         &self.registered_services
     }
+
+    pub(crate) fn quota(&self) -> &Arc<QuotaTracker> {
+        &self.quota
+    }
+
+    /// Routes the log messages and failures of the current thread to the logger and the
+    /// failure hook of the node until the returned guard goes out of scope.
+    pub(crate) fn log_scope(&self) -> LogScope {
+        self.logger.scope()
+    }
 }
 
 impl<Service: service::Service> Drop for SharedNode<Service> {
     fn drop(&mut self) {
+        let _log_scope = self.log_scope();
         if self.monitoring_token.get_mut().is_some() {
             if self.config().global.node.cleanup_dead_nodes_on_destruction {
                 Node::<Service>::cleanup_dead_nodes(self.config());
@@ -829,6 +905,7 @@ impl<Service: service::Service> Drop for SharedNode<Service> {
 #[derive(Debug)]
 pub struct Node<Service: service::Service> {
     shared: Arc<SharedNode<Service>>,
+    system_event_service: event::PortFactory<Service>,
 }
 
 unsafe impl<Service: service::Service> Send for Node<Service> {}
@@ -849,11 +926,54 @@ impl<Service: service::Service> Node<Service> {
         &self.shared.id
     }
 
+    /// Returns the [`NodeQuota`] that limits the resources the [`Node`] can acquire.
+    pub fn quota(&self) -> &NodeQuota {
+        self.shared.quota.quota()
+    }
+
+    /// Returns the resources that are currently accounted to the [`Node`] and its
+    /// [`NodeQuota`].
+    pub fn resource_usage(&self) -> NodeResourceUsage {
+        self.shared.quota.usage()
+    }
+
     /// Instantiates a [`ServiceBuilder`](Builder) for a service with the provided name.
     pub fn service_builder(&self, name: &ServiceName) -> Builder<Service> {
         Builder::new(name, self.shared.clone())
     }
 
+    /// Creates [`ServiceDependencies`] to declare the [`Service`](crate::service::Service)s the
+    /// [`Node`] requires and to wait until all of them are available.
+    pub fn service_dependencies(&self) -> ServiceDependencies<'_, Service> {
+        ServiceDependencies::new(self)
+    }
+
+    /// Creates a [`DeadNodeDetector`] that waits for the termination of the processes of all
+    /// other [`Node`]s and removes the stale resources of a dead [`Node`] right away.
+    pub fn dead_node_detector(&self) -> DeadNodeDetector<'_, Service> {
+        DeadNodeDetector::new(self)
+    }
+
+    /// Creates a [`SystemEventNotifier`] that broadcasts
+    /// [`SystemEvent`](crate::node::system_event::SystemEvent)s, like an emergency stop, to
+    /// every [`SystemEventListener`] of the domain. The underlying service is created together
+    /// with the [`Node`].
+    pub fn system_event_notifier(
+        &self,
+    ) -> Result<SystemEventNotifier<Service>, SystemEventCreateError> {
+        SystemEventNotifier::new(self)
+    }
+
+    /// Creates a [`SystemEventListener`] that receives the
+    /// [`SystemEvent`](crate::node::system_event::SystemEvent)s broadcast by any
+    /// [`SystemEventNotifier`] of the domain. The underlying service is created together with
+    /// the [`Node`].
+    pub fn system_event_listener(
+        &self,
+    ) -> Result<SystemEventListener<Service>, SystemEventCreateError> {
+        SystemEventListener::new(self)
+    }
+
     /// Calls the provided callback for all [`Node`]s in the system under a given [`Config`] and
     /// provides [`NodeState<Service>`] as input argument. With every iteration the callback has to
     /// return [`CallbackProgression::Continue`] to perform the next iteration or
@@ -921,6 +1041,7 @@ impl<Service: service::Service> Node<Service> {
     /// when a `SIGTERM` signal was received or [`NodeWaitFailure::Interrupt`] when a `SIGINT`
     /// signal was received.
     pub fn wait(&self, cycle_time: Duration) -> Result<(), NodeWaitFailure> {
+        let _log_scope = self.shared.log_scope();
         let msg = "Unable to wait on node";
         self.handle_termination_request(msg)?;
 
@@ -946,16 +1067,73 @@ impl<Service: service::Service> Node<Service> {
         self.shared.signal_handling_mode
     }
 
-    /// Removes the stale system resources of all dead [`Node`]s. The dead [`Node`]s are also
-    /// removed from all registered [`Service`](crate::service::Service)s.
+    /// Removes the stale system resources of all dead [`Node`]s that left a crash journal
+    /// behind, see [`crash_journal`]. In contrast to [`Node::cleanup_dead_nodes()`] it does
+    /// not scan all existing [`Node`]s. The crash journal is removed as soon as the
+    /// resources of the [`Node`] are reclaimed.
     ///
-    /// If a [`Node`] cannot be cleaned up since the process has insufficient permissions then
-    /// the [`Node`] is skipped.
-    pub fn cleanup_dead_nodes(config: &Config) -> CleanupState {
+    /// The journals of [`Node`]s that are still alive are skipped.
+    pub fn cleanup_crashed_nodes(config: &Config) -> CleanupState {
         let mut cleanup_state = CleanupState {
             cleanups: 0,
             failed_cleanups: 0,
         };
+        let origin = format!(
+            "Node::<{}>::cleanup_crashed_nodes()",
+            core::any::type_name::<Service>()
+        );
+
+        let mut records = vec![];
+        if let Err(e) = CrashRecord::list(config, |record| {
+            records.push(record);
+            CallbackProgression::Continue
+        }) {
+            debug!(from origin, "Unable to acquire all crash journals ({:?}).", e);
+        }
+
+        for record in records {
+            let node_id = *record.node_id();
+            match NodeState::<Service>::new(&node_id, config) {
+                Ok(Some(NodeState::Dead(dead_node))) => {
+                    debug!(from origin, "Crashed node ({:?}) detected, signal: {:?}", node_id, record.signal());
+                    match dead_node.remove_stale_resources() {
+                        Ok(_) => {
+                            cleanup_state.cleanups += 1;
+                            warn!(from origin, when record.remove(),
+                                "Unable to remove the crash journal of the crashed node ({:?}).", node_id);
+                        }
+                        Err(e) => {
+                            cleanup_state.failed_cleanups += 1;
+                            trace!(from origin, "Unable to remove crashed node {:?} ({:?}).", node_id, e)
+                        }
+                    }
+                }
+                // the node was already removed, the journal is stale when its process is gone
+                Ok(None) => {
+                    if !Process::from_pid(record.process_id()).is_alive() {
+                        warn!(from origin, when record.remove(),
+                            "Unable to remove the stale crash journal of the node ({:?}).", node_id);
+                    }
+                }
+                Ok(Some(_)) => (),
+                Err(e) => {
+                    cleanup_state.failed_cleanups += 1;
+                    trace!(from origin, "Unable to acquire the state of the crashed node {:?} ({:?}).", node_id, e)
+                }
+            }
+        }
+
+        cleanup_state
+    }
+
+    /// Removes the stale system resources of all dead [`Node`]s. The dead [`Node`]s are also
+    /// removed from all registered [`Service`](crate::service::Service)s. The crash journals
+    /// of the dead [`Node`]s are handled first, see [`Node::cleanup_crashed_nodes()`].
+    ///
+    /// If a [`Node`] cannot be cleaned up since the process has insufficient permissions then
+    /// the [`Node`] is skipped.
+    pub fn cleanup_dead_nodes(config: &Config) -> CleanupState {
+        let mut cleanup_state = Self::cleanup_crashed_nodes(config);
         let origin = format!(
             "Node::<{}>::cleanup_dead_nodes()",
             core::any::type_name::<Service>()
@@ -1131,11 +1309,22 @@ impl<Service: service::Service> Node<Service> {
                 "{} since the content of the node config storage could not be read.", msg);
         }
 
-        let node_details = fail!(from origin,
+        #[allow(unused_mut)] // only modified with the static_storage_encryption feature
+        let mut node_details = fail!(from origin,
                     when Service::ConfigSerializer::deserialize::<NodeDetails>(unsafe { read_content.as_mut_vec()}),
                     with NodeReadStorageFailure::Corrupted,
                 "{} since the contents of the node config storage is corrupted.", msg);
 
+        // the encryption key provider cannot be serialized, the details could only be read
+        // with the provider of the given config
+        #[cfg(feature = "static_storage_encryption")]
+        node_details
+            .config
+            .global
+            .set_static_storage_encryption_key_provider(
+                config.global.static_storage_encryption_key_provider(),
+            );
+
         Ok(Some(node_details))
     }
 
@@ -1195,6 +1384,9 @@ pub struct NodeBuilder {
     name: Option<NodeName>,
     signal_handling_mode: SignalHandlingMode,
     config: Option<Config>,
+    quota: NodeQuota,
+    crash_journal: bool,
+    logger: NodeLogger,
 }
 
 impl NodeBuilder {
@@ -1217,6 +1409,45 @@ impl NodeBuilder {
         self
     }
 
+    /// Defines the [`NodeQuota`] of the [`Node`]. Whenever the creation of a port or a service
+    /// would exceed the quota, the corresponding builder fails. By default, the [`Node`] has
+    /// no quota.
+    pub fn quota(mut self, value: NodeQuota) -> Self {
+        self.quota = value;
+        self
+    }
+
+    /// Enables the crash journal of the [`Node`], see [`crash_journal`]. When the process
+    /// crashes, the journal allows the next cleanup pass to reclaim exactly the resources
+    /// of the [`Node`]. By default, it is disabled.
+    pub fn crash_journal(mut self, value: bool) -> Self {
+        self.crash_journal = value;
+        self
+    }
+
+    /// Sets the [`Log`]ger of the [`Node`]. All messages that are logged while the [`Node`] is
+    /// created, while a service is created or opened via [`Node::service_builder()`], while
+    /// a port of one of its services is created, used or dropped, in [`Node::wait()`] and
+    /// when the [`Node`] is dropped are routed to it instead of the global logger. By default,
+    /// the global logger is used.
+    pub fn logger<T: Log + 'static>(mut self, value: &'static T) -> Self {
+        self.logger.logger = Some(value);
+        self
+    }
+
+    /// Sets the [`FailureHook`] of the [`Node`]. It is called for every failure that is
+    /// reported in the same scopes in which the [`NodeBuilder::logger()`] is used, instead of
+    /// the global [`FailureHook`]. By default, the global [`FailureHook`] is used.
+    pub fn failure_hook<T: FailureHook + 'static>(mut self, value: &'static T) -> Self {
+        self.logger.failure_hook = Some(value);
+        self
+    }
+
+    #[doc(hidden)]
+    pub fn __internal_quota(&self) -> NodeQuota {
+        self.quota
+    }
+
     /// Sets the config of the [`Node`] that will be used to create all entities owned by the
     /// [`Node`].
     pub fn config(mut self, value: &Config) -> Self {
@@ -1227,6 +1458,7 @@ impl NodeBuilder {
     /// Creates a new [`Node`] for a specific [`service::Service`]. All entities owned by the
     /// [`Node`] will have the same [`service::Service`].
     pub fn create<Service: service::Service>(self) -> Result<Node<Service>, NodeCreationFailure> {
+        let _log_scope = self.logger.scope();
         let msg = "Unable to create node";
         let node_id = fail!(from self, when UniqueSystemId::new(),
                                 with NodeCreationFailure::InternalError,
@@ -1239,12 +1471,24 @@ impl NodeBuilder {
         self,
         node_id: UniqueSystemId,
     ) -> Result<Node<Service>, NodeCreationFailure> {
+        let _log_scope = self.logger.scope();
         let config = if let Some(ref config) = self.config {
             config.clone()
         } else {
             Config::global_config().clone()
         };
 
+        if let Some(ref security_descriptor) = config.global.windows_security_descriptor {
+            fail!(from self, when NamedObjectSecurityDescriptor::set(security_descriptor),
+                with NodeCreationFailure::InvalidSecurityDescriptor,
+                "Unable to create node since the security descriptor \"{}\" is invalid.",
+                security_descriptor);
+        }
+
+        if let Some(ref log_level_filter) = config.global.log_level_filter {
+            set_log_level_filter(log_level_filter);
+        }
+
         if config.global.node.cleanup_dead_nodes_on_creation {
             Node::<Service>::cleanup_dead_nodes(&config);
         }
@@ -1255,18 +1499,30 @@ impl NodeBuilder {
         let (details_storage, details) =
             self.create_node_details_storage::<Service>(&config, &NodeId(node_id))?;
         let monitoring_token = self.create_token::<Service>(&config, &monitor_name)?;
+        let crash_journal = if self.crash_journal {
+            Some(self.create_crash_journal(&config, &NodeId(node_id))?)
+        } else {
+            None
+        };
+
+        let shared = Arc::new(SharedNode {
+            id: NodeId(node_id),
+            monitoring_token: UnsafeCell::new(Some(monitoring_token)),
+            registered_services: RegisteredServices {
+                data: Mutex::new(HashMap::new()),
+            },
+            _details_storage: details_storage,
+            _crash_journal: crash_journal,
+            signal_handling_mode: self.signal_handling_mode,
+            quota: Arc::new(QuotaTracker::new(self.quota)),
+            logger: self.logger,
+            details,
+        });
+        let system_event_service = system_event::open_or_create_service(&shared)?;
 
         Ok(Node {
-            shared: Arc::new(SharedNode {
-                id: NodeId(node_id),
-                monitoring_token: UnsafeCell::new(Some(monitoring_token)),
-                registered_services: RegisteredServices {
-                    data: Mutex::new(HashMap::new()),
-                },
-                _details_storage: details_storage,
-                signal_handling_mode: self.signal_handling_mode,
-                details,
-            }),
+            shared,
+            system_event_service,
         })
     }
 
@@ -1297,6 +1553,29 @@ impl NodeBuilder {
         }
     }
 
+    fn create_crash_journal(
+        &self,
+        config: &Config,
+        node_id: &NodeId,
+    ) -> Result<CrashJournal, NodeCreationFailure> {
+        let msg = "Unable to create crash journal for new node";
+        match CrashJournal::create(config, node_id) {
+            Ok(crash_journal) => Ok(crash_journal),
+            Err(CrashJournalCreateError::InsufficientPermissions) => {
+                fail!(from self, with NodeCreationFailure::InsufficientPermissions,
+                    "{msg} due to insufficient permissions to create the journal file.");
+            }
+            Err(CrashJournalCreateError::ExceedsMaxNumberOfCrashJournals) => {
+                fail!(from self, with NodeCreationFailure::InternalError,
+                    "{msg} since the process exceeds the maximum number of crash journals.");
+            }
+            Err(CrashJournalCreateError::InternalError) => {
+                fail!(from self, with NodeCreationFailure::InternalError,
+                    "{msg} since the journal file could not be created.");
+            }
+        }
+    }
+
     fn create_node_details_storage<Service: service::Service>(
         &self,
         config: &Config,
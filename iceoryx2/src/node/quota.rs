@@ -0,0 +1,292 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Optional per [`Node`](crate::node::Node) resource quotas. They prevent that a single
+//! misbehaving component exhausts the memory budget of the whole system. The quota is
+//! enforced whenever a port or a service is created and the corresponding builder fails
+//! with an `ExceedsNodeQuota` error when the quota would be violated.
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::node::quota::NodeQuota;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new()
+//!                 .quota(NodeQuota::new()
+//!                         .max_ports(16)
+//!                         .max_services(4)
+//!                         .max_shared_memory_bytes(64 * 1024 * 1024))
+//!                 .create::<ipc::Service>()?;
+//!
+//! println!("resource usage: {:?}", node.resource_usage());
+//! # Ok(())
+//! # }
+//! ```
+
+use core::sync::atomic::Ordering;
+
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+/// Defines the upper limits of the resources a [`Node`](crate::node::Node) is allowed to
+/// acquire. Every limit is optional, an unset limit is not enforced.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NodeQuota {
+    max_shared_memory_bytes: Option<usize>,
+    max_ports: Option<usize>,
+    max_services: Option<usize>,
+}
+
+impl NodeQuota {
+    /// Creates a new [`NodeQuota`] without any limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defines the maximum amount of bytes all payload data segments of the ports of the
+    /// [`Node`](crate::node::Node) are allowed to occupy. Dynamically resizing data segments
+    /// account every resize, a loan that would resize the data segment beyond the limit fails
+    /// with [`LoanError::OutOfMemory`](crate::port::LoanError::OutOfMemory).
+    pub fn max_shared_memory_bytes(mut self, value: usize) -> Self {
+        self.max_shared_memory_bytes = Some(value);
+        self
+    }
+
+    /// Defines the maximum amount of ports, like
+    /// [`Publisher`](crate::port::publisher::Publisher)s or
+    /// [`Listener`](crate::port::listener::Listener)s, the [`Node`](crate::node::Node) can
+    /// own at the same time.
+    pub fn max_ports(mut self, value: usize) -> Self {
+        self.max_ports = Some(value);
+        self
+    }
+
+    /// Defines the maximum amount of services the [`Node`](crate::node::Node) can create.
+    /// Opening an existing service is not restricted.
+    pub fn max_services(mut self, value: usize) -> Self {
+        self.max_services = Some(value);
+        self
+    }
+
+    /// Returns the shared memory limit in bytes, if one was defined.
+    pub fn shared_memory_bytes_limit(&self) -> Option<usize> {
+        self.max_shared_memory_bytes
+    }
+
+    /// Returns the port limit, if one was defined.
+    pub fn ports_limit(&self) -> Option<usize> {
+        self.max_ports
+    }
+
+    /// Returns the limit of created services, if one was defined.
+    pub fn services_limit(&self) -> Option<usize> {
+        self.max_services
+    }
+}
+
+/// A snapshot of the resources that are currently accounted to a
+/// [`Node`](crate::node::Node).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NodeResourceUsage {
+    shared_memory_bytes: usize,
+    ports: usize,
+    services: usize,
+}
+
+impl NodeResourceUsage {
+    /// Returns the amount of bytes occupied by the payload data segments of all ports.
+    pub fn shared_memory_bytes(&self) -> usize {
+        self.shared_memory_bytes
+    }
+
+    /// Returns the number of ports that are currently owned.
+    pub fn ports(&self) -> usize {
+        self.ports
+    }
+
+    /// Returns the number of services that were created and are still alive.
+    pub fn services(&self) -> usize {
+        self.services
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum QuotaExceeded {
+    SharedMemoryBytes { limit: usize, requested: usize },
+    Ports { limit: usize },
+    Services { limit: usize },
+}
+
+impl core::fmt::Display for QuotaExceeded {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "QuotaExceeded::{:?}", self)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct QuotaTracker {
+    quota: NodeQuota,
+    shared_memory_bytes: IoxAtomicUsize,
+    ports: IoxAtomicUsize,
+    services: IoxAtomicUsize,
+}
+
+impl QuotaTracker {
+    pub(crate) fn new(quota: NodeQuota) -> Self {
+        Self {
+            quota,
+            shared_memory_bytes: IoxAtomicUsize::new(0),
+            ports: IoxAtomicUsize::new(0),
+            services: IoxAtomicUsize::new(0),
+        }
+    }
+
+    pub(crate) fn quota(&self) -> &NodeQuota {
+        &self.quota
+    }
+
+    pub(crate) fn usage(&self) -> NodeResourceUsage {
+        NodeResourceUsage {
+            shared_memory_bytes: self.shared_memory_bytes.load(Ordering::Relaxed),
+            ports: self.ports.load(Ordering::Relaxed),
+            services: self.services.load(Ordering::Relaxed),
+        }
+    }
+
+    fn reserve(counter: &IoxAtomicUsize, amount: usize, limit: Option<usize>) -> bool {
+        match limit {
+            None => {
+                counter.fetch_add(amount, Ordering::Relaxed);
+                true
+            }
+            Some(limit) => counter
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+                    current
+                        .checked_add(amount)
+                        .filter(|new_value| *new_value <= limit)
+                })
+                .is_ok(),
+        }
+    }
+
+    pub(crate) fn acquire_port(
+        self: &Arc<Self>,
+        shared_memory_bytes: usize,
+    ) -> Result<QuotaReservation, QuotaExceeded> {
+        if !Self::reserve(&self.ports, 1, self.quota.max_ports) {
+            return Err(QuotaExceeded::Ports {
+                limit: self.quota.max_ports.unwrap_or_default(),
+            });
+        }
+
+        if !Self::reserve(
+            &self.shared_memory_bytes,
+            shared_memory_bytes,
+            self.quota.max_shared_memory_bytes,
+        ) {
+            self.ports.fetch_sub(1, Ordering::Relaxed);
+            return Err(QuotaExceeded::SharedMemoryBytes {
+                limit: self.quota.max_shared_memory_bytes.unwrap_or_default(),
+                requested: shared_memory_bytes,
+            });
+        }
+
+        Ok(QuotaReservation {
+            tracker: self.clone(),
+            shared_memory_bytes: IoxAtomicUsize::new(shared_memory_bytes),
+            ports: 1,
+            services: 0,
+        })
+    }
+
+    pub(crate) fn acquire_service(self: &Arc<Self>) -> Result<QuotaReservation, QuotaExceeded> {
+        if !Self::reserve(&self.services, 1, self.quota.max_services) {
+            return Err(QuotaExceeded::Services {
+                limit: self.quota.max_services.unwrap_or_default(),
+            });
+        }
+
+        Ok(QuotaReservation {
+            tracker: self.clone(),
+            shared_memory_bytes: IoxAtomicUsize::new(0),
+            ports: 0,
+            services: 1,
+        })
+    }
+
+    // Builtin services, like the system event channel, are part of every node and are therefore
+    // not accounted to its quota.
+    pub(crate) fn acquire_builtin_service(self: &Arc<Self>) -> QuotaReservation {
+        QuotaReservation {
+            tracker: self.clone(),
+            shared_memory_bytes: IoxAtomicUsize::new(0),
+            ports: 0,
+            services: 0,
+        }
+    }
+}
+
+/// Returns the acquired resources to the [`QuotaTracker`] when it goes out of scope.
+#[derive(Debug)]
+pub(crate) struct QuotaReservation {
+    tracker: Arc<QuotaTracker>,
+    shared_memory_bytes: IoxAtomicUsize,
+    ports: usize,
+    services: usize,
+}
+
+impl QuotaReservation {
+    pub(crate) fn shared_memory_bytes(&self) -> usize {
+        self.shared_memory_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Adjusts the reserved shared memory to `value` bytes. Shrinking always succeeds, growing
+    /// fails when it would exceed the quota.
+    pub(crate) fn resize_shared_memory(&self, value: usize) -> Result<(), QuotaExceeded> {
+        let current = self.shared_memory_bytes.load(Ordering::Relaxed);
+        if value <= current {
+            self.tracker
+                .shared_memory_bytes
+                .fetch_sub(current - value, Ordering::Relaxed);
+        } else if !QuotaTracker::reserve(
+            &self.tracker.shared_memory_bytes,
+            value - current,
+            self.tracker.quota.max_shared_memory_bytes,
+        ) {
+            return Err(QuotaExceeded::SharedMemoryBytes {
+                limit: self
+                    .tracker
+                    .quota
+                    .max_shared_memory_bytes
+                    .unwrap_or_default(),
+                requested: value - current,
+            });
+        }
+
+        self.shared_memory_bytes.store(value, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+impl Drop for QuotaReservation {
+    fn drop(&mut self) {
+        self.tracker
+            .shared_memory_bytes
+            .fetch_sub(self.shared_memory_bytes(), Ordering::Relaxed);
+        self.tracker.ports.fetch_sub(self.ports, Ordering::Relaxed);
+        self.tracker
+            .services
+            .fetch_sub(self.services, Ordering::Relaxed);
+    }
+}
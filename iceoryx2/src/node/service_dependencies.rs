@@ -0,0 +1,313 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Declares the [`Service`](crate::service::Service)s a [`Node`] requires during system
+//! bring-up and waits until all of them are available with compatible settings.
+//!
+//! A dependency is available as soon as its open function succeeds. The open function
+//! receives the [`Builder`] of the [`Service`](crate::service::Service) and configures the
+//! settings the [`Node`] requires, therefore the same compatibility rules apply as if the
+//! [`Service`](crate::service::Service) is opened later on.
+//!
+//! The unavailable dependencies are not polled. Every newly created
+//! [`Service`](crate::service::Service) emits a service discovery event and
+//! [`ServiceDependencies::wait_until_available()`] checks them again only when it is woken up
+//! by it.
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use core::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let _service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #     .publish_subscribe::<u64>()
+//! #     .create()?;
+//! # let _event = node.service_builder(&"My/Funk/Trigger".try_into()?)
+//! #     .event()
+//! #     .create()?;
+//!
+//! node.service_dependencies()
+//!     .require(&"My/Funk/ServiceName".try_into()?, |builder| {
+//!         builder.publish_subscribe::<u64>().open()
+//!     })
+//!     .require_event(&"My/Funk/Trigger".try_into()?)
+//!     .wait_until_available(Duration::from_secs(5))?;
+//! # Ok(())
+//! # }
+//! ```
+
+use core::time::Duration;
+use std::time::Instant;
+
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_cal::event::ListenerWaitError;
+
+use crate::node::{Node, NodeWaitFailure, SharedNode};
+use crate::port::event_id::EventId;
+use crate::port::listener::Listener;
+use crate::port::notifier::notify_all_listeners;
+use crate::service;
+use crate::service::builder::event::EventOpenError;
+use crate::service::builder::Builder;
+use crate::service::port_factory::event::PortFactory;
+use crate::service::service_name::ServiceName;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+const SERVICE_DISCOVERY_SERVICE_NAME: &str = "iox2://service_discovery";
+const SERVICE_CREATED_EVENT: EventId = EventId::new(0);
+
+/// The maximum amount of [`ServiceDependencies::wait_until_available()`] calls that can wait
+/// at the same time.
+pub const SERVICE_DISCOVERY_MAX_LISTENERS: usize = capacity!(small: 8, default: 64, large: 1024);
+
+/// Describes the failures of [`ServiceDependencies::wait_until_available()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ServiceDependenciesWaitError {
+    /// Not all required [`Service`](crate::service::Service)s became available before the
+    /// timeout passed.
+    Timeout,
+    /// The process received an interrupt signal while waiting.
+    Interrupt,
+    /// A termination signal `SIGTERM` was received while waiting.
+    TerminationRequest,
+    /// The service discovery event, that signals newly created
+    /// [`Service`](crate::service::Service)s, could not be created or waited on.
+    InternalFailure,
+}
+
+impl core::fmt::Display for ServiceDependenciesWaitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ServiceDependenciesWaitError::{:?}", self)
+    }
+}
+
+impl core::error::Error for ServiceDependenciesWaitError {}
+
+impl From<NodeWaitFailure> for ServiceDependenciesWaitError {
+    fn from(value: NodeWaitFailure) -> Self {
+        match value {
+            NodeWaitFailure::Interrupt => ServiceDependenciesWaitError::Interrupt,
+            NodeWaitFailure::TerminationRequest => ServiceDependenciesWaitError::TerminationRequest,
+        }
+    }
+}
+
+fn service_discovery_builder<Service: service::Service>(
+    shared_node: &Arc<SharedNode<Service>>,
+) -> Option<crate::service::builder::event::Builder<Service>> {
+    let service_name = ServiceName::new_builtin(SERVICE_DISCOVERY_SERVICE_NAME).ok()?;
+
+    Some(
+        Builder::new(&service_name, shared_node.clone())
+            .event()
+            .max_nodes(SERVICE_DISCOVERY_MAX_LISTENERS)
+            .max_notifiers(1)
+            .max_listeners(SERVICE_DISCOVERY_MAX_LISTENERS)
+            .event_id_max_value(SERVICE_CREATED_EVENT.as_value())
+            .disable_deadline()
+            .disable_notifier_created_event()
+            .disable_notifier_dropped_event()
+            .disable_notifier_dead_event()
+            .disable_dynamic_attribute_changed_event(),
+    )
+}
+
+// Wakes up all waiting ServiceDependencies after a service was created. Like the dynamic
+// attribute change notification it is delivered directly to the listeners so that no notifier
+// port is required.
+pub(crate) fn notify_service_created<Service: service::Service>(
+    shared_node: &Arc<SharedNode<Service>>,
+    service_name: &ServiceName,
+) {
+    // builtin services cannot be declared as dependency
+    if service_name.is_builtin() {
+        return;
+    }
+
+    let builder = match service_discovery_builder(shared_node) {
+        Some(builder) => builder,
+        None => {
+            warn!(from service_name,
+                "This should never happen! Unable to announce the creation of the service since the service discovery event name is invalid.");
+            return;
+        }
+    };
+
+    // nobody waits for a service
+    if !builder.does_exist() {
+        return;
+    }
+
+    let service = match builder.open() {
+        Ok(service) => service,
+        Err(EventOpenError::DoesNotExist) => return,
+        Err(e) => {
+            warn!(from service_name,
+                "Unable to announce the creation of the service since the service discovery event could not be opened ({:?}).",
+                e);
+            return;
+        }
+    };
+
+    notify_all_listeners(service.service.__internal_state(), SERVICE_CREATED_EVENT);
+}
+
+type OpenFunction<'node, Service> = Box<dyn Fn(Builder<Service>) -> bool + 'node>;
+
+struct Dependency<'node, Service: service::Service> {
+    service_name: ServiceName,
+    is_available: OpenFunction<'node, Service>,
+}
+
+/// Collects the [`Service`](crate::service::Service)s a [`Node`] requires and waits until all
+/// of them are available. Created with [`Node::service_dependencies()`].
+pub struct ServiceDependencies<'node, Service: service::Service> {
+    node: &'node Node<Service>,
+    dependencies: Vec<Dependency<'node, Service>>,
+}
+
+impl<Service: service::Service> core::fmt::Debug for ServiceDependencies<'_, Service> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ServiceDependencies<{}> {{ dependencies: {:?} }}",
+            core::any::type_name::<Service>(),
+            self.dependencies
+                .iter()
+                .map(|d| &d.service_name)
+                .collect::<Vec<_>>()
+        )
+    }
+}
+
+impl<'node, Service: service::Service> ServiceDependencies<'node, Service> {
+    pub(crate) fn new(node: &'node Node<Service>) -> Self {
+        Self {
+            node,
+            dependencies: vec![],
+        }
+    }
+
+    /// Requires the [`Service`](crate::service::Service) with the provided [`ServiceName`].
+    /// It is available as soon as the provided open function, which configures the required
+    /// settings on the [`Builder`], succeeds.
+    pub fn require<T, E, F: Fn(Builder<Service>) -> Result<T, E> + 'node>(
+        mut self,
+        service_name: &ServiceName,
+        open: F,
+    ) -> Self {
+        self.dependencies.push(Dependency {
+            service_name: service_name.clone(),
+            is_available: Box::new(move |builder| open(builder).is_ok()),
+        });
+        self
+    }
+
+    /// Requires the [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
+    /// [`Service`](crate::service::Service) with the provided [`ServiceName`].
+    pub fn require_event(self, service_name: &ServiceName) -> Self {
+        self.require(service_name, |builder| builder.event().open())
+    }
+
+    /// Returns the number of required [`Service`](crate::service::Service)s.
+    pub fn number_of_dependencies(&self) -> usize {
+        self.dependencies.len()
+    }
+
+    /// Returns the [`ServiceName`]s of all required [`Service`](crate::service::Service)s that
+    /// are currently not available.
+    pub fn unavailable_services(&self) -> Vec<ServiceName> {
+        self.dependencies
+            .iter()
+            .filter(|d| !self.is_available(d))
+            .map(|d| d.service_name.clone())
+            .collect()
+    }
+
+    /// Blocks until all required [`Service`](crate::service::Service)s are available or the
+    /// timeout has passed. The unavailable [`Service`](crate::service::Service)s are checked
+    /// again whenever a [`Service`](crate::service::Service) is created. Returns
+    /// [`ServiceDependenciesWaitError::Timeout`] when not all
+    /// [`Service`](crate::service::Service)s became available in time. The unavailable ones can
+    /// be acquired with [`ServiceDependencies::unavailable_services()`].
+    pub fn wait_until_available(
+        &self,
+        timeout: Duration,
+    ) -> Result<(), ServiceDependenciesWaitError> {
+        let msg = "Unable to wait until all service dependencies are available";
+        let start = Instant::now();
+        let mut pending: Vec<&Dependency<'node, Service>> = self.dependencies.iter().collect();
+
+        pending.retain(|d| !self.is_available(d));
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        // the listener must exist before the dependencies are checked again, otherwise the
+        // creation of a service in between would be missed
+        let (_service, listener) = self.create_service_discovery_listener(msg)?;
+
+        loop {
+            pending.retain(|d| !self.is_available(d));
+            if pending.is_empty() {
+                return Ok(());
+            }
+
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                fail!(from self, with ServiceDependenciesWaitError::Timeout,
+                    "{} since the services {:?} are still unavailable after {:?}.",
+                    msg, pending.iter().map(|d| &d.service_name).collect::<Vec<_>>(), timeout);
+            }
+
+            let wait_result = listener.timed_wait_all(|_| {}, timeout - elapsed);
+            self.node.handle_termination_request(msg)?;
+            match wait_result {
+                Ok(()) => (),
+                Err(ListenerWaitError::InterruptSignal) => {
+                    fail!(from self, with ServiceDependenciesWaitError::Interrupt,
+                        "{} since an interrupt signal was received.", msg);
+                }
+                Err(e) => {
+                    fail!(from self, with ServiceDependenciesWaitError::InternalFailure,
+                        "{} since the wait on the service discovery event failed ({:?}).", msg, e);
+                }
+            }
+        }
+    }
+
+    fn create_service_discovery_listener(
+        &self,
+        msg: &str,
+    ) -> Result<(PortFactory<Service>, Listener<Service>), ServiceDependenciesWaitError> {
+        let builder = fail!(from self, when service_discovery_builder(&self.node.shared).ok_or(()),
+            with ServiceDependenciesWaitError::InternalFailure,
+            "{} since the name of the service discovery event is invalid.", msg);
+
+        let service = fail!(from self, when builder.open_or_create(),
+            with ServiceDependenciesWaitError::InternalFailure,
+            "{} since the service discovery event could not be opened or created.", msg);
+
+        let listener = fail!(from self, when service.listener_builder().create(),
+            with ServiceDependenciesWaitError::InternalFailure,
+            "{} since the listener of the service discovery event could not be created.", msg);
+
+        Ok((service, listener))
+    }
+
+    fn is_available(&self, dependency: &Dependency<'node, Service>) -> bool {
+        (dependency.is_available)(self.node.service_builder(&dependency.service_name))
+    }
+}
@@ -0,0 +1,301 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Built-in system event channel that is available for every [`Node`] of a domain. It is
+//! created with fixed settings together with the [`Node`] so that safety critical paths, like an
+//! emergency stop, do not depend on user-created and user-configured services. Every
+//! [`SystemEventListener`] is woken up whenever a [`SystemEvent`] is broadcast.
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::node::system_event::SystemEvent;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! let listener = node.system_event_listener()?;
+//! let notifier = node.system_event_notifier()?;
+//!
+//! notifier.broadcast(SystemEvent::EmergencyStop)?;
+//!
+//! listener.try_wait_all(|event| {
+//!     if event == SystemEvent::EmergencyStop {
+//!         println!("emergency stop requested");
+//!     }
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+
+use core::time::Duration;
+
+use iceoryx2_bb_log::{fail, warn};
+
+use crate::node::{Node, NodeCreationFailure, SharedNode};
+use crate::port::event_id::EventId;
+use crate::port::listener::{Listener, ListenerCreateError};
+use crate::port::notifier::{Notifier, NotifierCreateError, NotifierNotifyError};
+use crate::service;
+use crate::service::builder::event::{EventCreateError, EventOpenError, EventOpenOrCreateError};
+use crate::service::builder::Builder;
+use crate::service::port_factory::event::PortFactory;
+use crate::service::service_name::ServiceName;
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_cal::event::ListenerWaitError;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+/// The name of the [`Service`](crate::service::Service) that is used for the system events.
+/// It uses the reserved
+/// [`BUILTIN_SERVICE_NAME_PREFIX`](crate::service::service_name::BUILTIN_SERVICE_NAME_PREFIX).
+pub const SYSTEM_EVENT_SERVICE_NAME: &str = "iox2://system_event";
+
+/// The maximum amount of [`Node`]s, [`SystemEventNotifier`]s and [`SystemEventListener`]s
+/// that can use the system event channel at the same time.
+pub const SYSTEM_EVENT_MAX_PORTS: usize = capacity!(small: 32, default: 256, large: 4096);
+
+const SYSTEM_EVENT_ID_MAX_VALUE: usize = 15;
+
+/// The events that can be broadcast via the system event channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SystemEvent {
+    /// Every participant shall bring its actuators into a safe state immediately.
+    EmergencyStop,
+    /// A previously broadcast [`SystemEvent::EmergencyStop`] was released.
+    EmergencyStopReleased,
+    /// Every participant shall shut down gracefully.
+    Shutdown,
+}
+
+impl SystemEvent {
+    /// Returns the [`EventId`] that represents the [`SystemEvent`].
+    pub fn event_id(&self) -> EventId {
+        match self {
+            SystemEvent::EmergencyStop => EventId::new(1),
+            SystemEvent::EmergencyStopReleased => EventId::new(2),
+            SystemEvent::Shutdown => EventId::new(3),
+        }
+    }
+
+    /// Returns the [`SystemEvent`] that is represented by the provided [`EventId`]. If the
+    /// [`EventId`] does not correspond to any [`SystemEvent`] it returns [`None`].
+    pub fn from_event_id(value: EventId) -> Option<Self> {
+        match value.as_value() {
+            1 => Some(SystemEvent::EmergencyStop),
+            2 => Some(SystemEvent::EmergencyStopReleased),
+            3 => Some(SystemEvent::Shutdown),
+            _ => None,
+        }
+    }
+}
+
+/// Describes the failures when a [`SystemEventNotifier`] or [`SystemEventListener`] is created.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SystemEventCreateError {
+    /// The system event [`Service`](crate::service::Service) already has
+    /// [`SYSTEM_EVENT_MAX_PORTS`] ports of the requested kind.
+    ExceedsMaxSupportedPorts,
+    /// The port cannot be created since it would exceed the
+    /// [`NodeQuota`](crate::node::quota::NodeQuota) of the [`Node`].
+    ExceedsNodeQuota,
+    /// An underlying resource of the system event [`Service`](crate::service::Service) could
+    /// not be created.
+    InternalFailure,
+}
+
+impl core::fmt::Display for SystemEventCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "SystemEventCreateError::{:?}", self)
+    }
+}
+
+impl core::error::Error for SystemEventCreateError {}
+
+impl From<NotifierCreateError> for SystemEventCreateError {
+    fn from(value: NotifierCreateError) -> Self {
+        match value {
+            NotifierCreateError::ExceedsMaxSupportedNotifiers => {
+                SystemEventCreateError::ExceedsMaxSupportedPorts
+            }
+            NotifierCreateError::ExceedsNodeQuota => SystemEventCreateError::ExceedsNodeQuota,
+        }
+    }
+}
+
+impl From<ListenerCreateError> for SystemEventCreateError {
+    fn from(value: ListenerCreateError) -> Self {
+        match value {
+            ListenerCreateError::ExceedsMaxSupportedListeners => {
+                SystemEventCreateError::ExceedsMaxSupportedPorts
+            }
+            ListenerCreateError::ExceedsNodeQuota => SystemEventCreateError::ExceedsNodeQuota,
+            ListenerCreateError::ResourceCreationFailed => SystemEventCreateError::InternalFailure,
+        }
+    }
+}
+
+// Every node opens or creates the system event service on creation and keeps it alive until it
+// is dropped, so that the channel is available whenever a node of the domain exists.
+pub(crate) fn open_or_create_service<Service: service::Service>(
+    shared_node: &Arc<SharedNode<Service>>,
+) -> Result<PortFactory<Service>, NodeCreationFailure> {
+    let origin = "system_event::open_or_create_service()";
+    let service_name = fail!(from origin, when ServiceName::new_builtin(SYSTEM_EVENT_SERVICE_NAME),
+        with NodeCreationFailure::InternalError,
+        "This should never happen! The system event service name is invalid.");
+
+    let creation_timeout = shared_node.config().global.service.creation_timeout;
+    let mut adaptive_wait = fail!(from origin, when AdaptiveWaitBuilder::new().create(),
+        with NodeCreationFailure::InternalError,
+        "Unable to open or create the system event service since the adaptive wait could not be created.");
+    let mut elapsed_time = Duration::ZERO;
+
+    loop {
+        match Builder::new(&service_name, shared_node.clone())
+            .event()
+            .max_nodes(SYSTEM_EVENT_MAX_PORTS)
+            .max_notifiers(SYSTEM_EVENT_MAX_PORTS)
+            .max_listeners(SYSTEM_EVENT_MAX_PORTS)
+            .event_id_max_value(SYSTEM_EVENT_ID_MAX_VALUE)
+            .disable_deadline()
+            .disable_notifier_created_event()
+            .disable_notifier_dropped_event()
+            .disable_notifier_dead_event()
+            .disable_dynamic_attribute_changed_event()
+            .open_or_create()
+        {
+            Ok(service) => return Ok(service),
+            // the last node of the domain removes the service concurrently, it is created again
+            // as soon as the removal is finished
+            Err(EventOpenOrCreateError::EventOpenError(
+                EventOpenError::IsMarkedForDestruction
+                | EventOpenError::DoesNotExist
+                | EventOpenError::ServiceInCorruptedState,
+            ))
+            | Err(EventOpenOrCreateError::SystemInFlux)
+                if elapsed_time < creation_timeout =>
+            {
+                elapsed_time = fail!(from origin, when adaptive_wait.wait(),
+                    with NodeCreationFailure::InternalError,
+                    "Unable to open or create the system event service since the adaptive wait call failed.");
+            }
+            Err(EventOpenOrCreateError::EventOpenError(
+                EventOpenError::InsufficientPermissions,
+            ))
+            | Err(EventOpenOrCreateError::EventCreateError(
+                EventCreateError::InsufficientPermissions,
+            )) => {
+                fail!(from origin, with NodeCreationFailure::InsufficientPermissions,
+                    "Unable to open or create the system event service \"{}\" due to insufficient permissions.",
+                    SYSTEM_EVENT_SERVICE_NAME);
+            }
+            Err(e) => {
+                fail!(from origin, with NodeCreationFailure::InternalError,
+                    "Unable to open or create the system event service \"{}\" ({:?}).",
+                    SYSTEM_EVENT_SERVICE_NAME, e);
+            }
+        }
+    }
+}
+
+/// Broadcasts [`SystemEvent`]s to all [`SystemEventListener`]s of the domain. Can be created
+/// via [`Node::system_event_notifier()`].
+#[derive(Debug)]
+pub struct SystemEventNotifier<Service: service::Service> {
+    notifier: Notifier<Service>,
+}
+
+impl<Service: service::Service> SystemEventNotifier<Service> {
+    pub(crate) fn new(node: &Node<Service>) -> Result<Self, SystemEventCreateError> {
+        let notifier = fail!(from node,
+            when node.system_event_service.notifier_builder().create(),
+            "Unable to create the system event notifier.");
+
+        Ok(Self { notifier })
+    }
+
+    /// Broadcasts the [`SystemEvent`] and wakes up every [`SystemEventListener`]. On success
+    /// it returns the number of [`SystemEventListener`]s that were notified.
+    pub fn broadcast(&self, event: SystemEvent) -> Result<usize, NotifierNotifyError> {
+        self.notifier.notify_with_custom_event_id(event.event_id())
+    }
+
+    /// Broadcasts a [`SystemEvent::EmergencyStop`].
+    pub fn emergency_stop(&self) -> Result<usize, NotifierNotifyError> {
+        self.broadcast(SystemEvent::EmergencyStop)
+    }
+}
+
+/// Receives the [`SystemEvent`]s that are broadcast by any [`SystemEventNotifier`] of the
+/// domain. Can be created via [`Node::system_event_listener()`].
+#[derive(Debug)]
+pub struct SystemEventListener<Service: service::Service> {
+    listener: Listener<Service>,
+}
+
+impl<Service: service::Service> SystemEventListener<Service> {
+    pub(crate) fn new(node: &Node<Service>) -> Result<Self, SystemEventCreateError> {
+        let listener = fail!(from node,
+            when node.system_event_service.listener_builder().create(),
+            "Unable to create the system event listener.");
+
+        Ok(Self { listener })
+    }
+
+    /// Returns the underlying [`Listener`] so that it can be attached to a
+    /// [`WaitSet`](crate::waitset::WaitSet).
+    pub fn listener(&self) -> &Listener<Service> {
+        &self.listener
+    }
+
+    /// Non-blocking wait for new [`SystemEvent`]s. Calls the callback for every received
+    /// [`SystemEvent`].
+    pub fn try_wait_all<F: FnMut(SystemEvent)>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        self.listener
+            .try_wait_all(|id| self.handle_event_id(id, &mut callback))
+    }
+
+    /// Blocking wait for new [`SystemEvent`]s until either at least one [`SystemEvent`] was
+    /// received or the timeout has passed. Calls the callback for every received
+    /// [`SystemEvent`].
+    pub fn timed_wait_all<F: FnMut(SystemEvent)>(
+        &self,
+        mut callback: F,
+        timeout: Duration,
+    ) -> Result<(), ListenerWaitError> {
+        self.listener
+            .timed_wait_all(|id| self.handle_event_id(id, &mut callback), timeout)
+    }
+
+    /// Blocking wait for new [`SystemEvent`]s until at least one [`SystemEvent`] was received.
+    /// Calls the callback for every received [`SystemEvent`].
+    pub fn blocking_wait_all<F: FnMut(SystemEvent)>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        self.listener
+            .blocking_wait_all(|id| self.handle_event_id(id, &mut callback))
+    }
+
+    fn handle_event_id<F: FnMut(SystemEvent)>(&self, id: EventId, callback: &mut F) {
+        match SystemEvent::from_event_id(id) {
+            Some(event) => callback(event),
+            None => {
+                warn!(from self, "Ignoring the unknown system event id {:?}.", id);
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Provides typed views on the payload of [`Service`](crate::service::Service)s that transport
+//! serialized data as `[u8]` slices, like the generic byte services created by gateways.
+//!
+//! The creator of the [`Service`](crate::service::Service) declares the schema of the payload,
+//! e.g. a flatbuffer or CDR type, with the [`PAYLOAD_SCHEMA_ATTRIBUTE_KEY`] attribute. The
+//! receiving side implements [`PayloadView`] for a reader of this schema, verifies with
+//! [`verify_schema()`] that the [`Service`](crate::service::Service) carries the expected
+//! schema and acquires a validated reader with
+//! [`Sample::view()`](crate::sample::Sample::view()) on every received
+//! [`Sample`](crate::sample::Sample).
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::payload_view::{self, PayloadView, PAYLOAD_SCHEMA_ATTRIBUTE_KEY};
+//!
+//! // a reader that interprets the first 8 bytes as little endian timestamp
+//! struct Timestamp<'payload> {
+//!     bytes: &'payload [u8; 8],
+//! }
+//!
+//! impl<'payload> Timestamp<'payload> {
+//!     fn value(&self) -> u64 {
+//!         u64::from_le_bytes(*self.bytes)
+//!     }
+//! }
+//!
+//! impl<'payload> PayloadView<'payload> for Timestamp<'payload> {
+//!     const SCHEMA: &'static str = "le:u64";
+//!
+//!     fn view(payload: &'payload [u8]) -> Option<Self> {
+//!         Some(Self { bytes: payload.try_into().ok()? })
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"Gateway/Timestamps".try_into()?)
+//!     .publish_subscribe::<[u8]>()
+//!     .create_with_attributes(
+//!         &AttributeSpecifier::new().define(PAYLOAD_SCHEMA_ATTRIBUTE_KEY, Timestamp::SCHEMA),
+//!     )?;
+//!
+//! payload_view::verify_schema::<Timestamp>(service.attributes())?;
+//!
+//! let subscriber = service.subscriber_builder().create()?;
+//! while let Some(sample) = subscriber.receive()? {
+//!     let timestamp = sample.view::<Timestamp>()?;
+//!     println!("received timestamp {}", timestamp.value());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::service::attribute::AttributeSet;
+
+/// The key of the [`Attribute`](crate::service::attribute::Attribute) that declares the schema
+/// of the `[u8]` payload of a [`Service`](crate::service::Service).
+pub const PAYLOAD_SCHEMA_ATTRIBUTE_KEY: &str = "iox2.payload_schema";
+
+/// Defines the failures that can occur when a [`PayloadView`] is acquired.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum PayloadViewError {
+    /// The [`Service`](crate::service::Service) does not declare a schema with the
+    /// [`PAYLOAD_SCHEMA_ATTRIBUTE_KEY`] attribute.
+    UndeclaredSchema,
+    /// The [`Service`](crate::service::Service) declares a different schema than
+    /// [`PayloadView::SCHEMA`].
+    SchemaMismatch,
+    /// The payload does not contain valid data of the schema.
+    InvalidPayload,
+}
+
+impl core::fmt::Display for PayloadViewError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "PayloadViewError::{:?}", self)
+    }
+}
+
+impl core::error::Error for PayloadViewError {}
+
+/// A typed reader on the serialized `[u8]` payload of a [`Sample`](crate::sample::Sample).
+pub trait PayloadView<'payload>: Sized {
+    /// The name of the schema the view can read. It is compared with the value of the
+    /// [`PAYLOAD_SCHEMA_ATTRIBUTE_KEY`] attribute of the [`Service`](crate::service::Service).
+    const SCHEMA: &'static str;
+
+    /// Validates the payload and returns the view on it. Returns [`None`] when the payload does
+    /// not contain valid data of the schema.
+    fn view(payload: &'payload [u8]) -> Option<Self>;
+}
+
+/// Verifies that the provided [`AttributeSet`] of a [`Service`](crate::service::Service)
+/// declares the [`PayloadView::SCHEMA`] of the provided [`PayloadView`].
+pub fn verify_schema<'payload, View: PayloadView<'payload>>(
+    attributes: &AttributeSet,
+) -> Result<(), PayloadViewError> {
+    match attributes.get_key_value_at(PAYLOAD_SCHEMA_ATTRIBUTE_KEY, 0) {
+        None => Err(PayloadViewError::UndeclaredSchema),
+        Some(schema) if schema == View::SCHEMA => Ok(()),
+        Some(_) => Err(PayloadViewError::SchemaMismatch),
+    }
+}
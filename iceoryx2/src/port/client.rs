@@ -19,9 +19,10 @@ use iceoryx2_bb_elementary::{visitor::Visitor, CallbackProgression};
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{fail, warn};
 use iceoryx2_cal::{dynamic_storage::DynamicStorage, zero_copy_connection::ZeroCopyCreationError};
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicUsize};
 
 use crate::{
+    node::quota::QuotaReservation,
     port::{details::data_segment::DataSegment, UniqueClientId},
     prelude::PortFactory,
     service::{
@@ -57,6 +58,7 @@ pub struct Client<
     server_connections: OutgoingConnections<Service>,
     service_state: Arc<ServiceState<Service>>,
     client_port_id: UniqueClientId,
+    _quota_reservation: QuotaReservation,
     _request_payload: PhantomData<RequestPayload>,
     _request_header: PhantomData<RequestHeader>,
     _response_payload: PhantomData<ResponsePayload>,
@@ -72,6 +74,7 @@ impl<
     > Drop for Client<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
 {
     fn drop(&mut self) {
+        let _log_scope = self.service_state.shared_node.log_scope();
         if let Some(handle) = self.client_handle {
             self.service_state
                 .dynamic_storage
@@ -102,6 +105,7 @@ impl<
         let msg = "Unable to create Client port";
         let origin = "Client::new()";
         let service = &client_factory.factory.service;
+        let _log_scope = service.__internal_state().shared_node.log_scope();
         let client_port_id = UniqueClientId::new();
         let number_of_requests = unsafe {
             service
@@ -124,11 +128,32 @@ impl<
         let data_segment_type = DataSegmentType::Static;
         let max_number_of_segments =
             DataSegment::<Service>::max_number_of_segments(data_segment_type);
+        let sample_layout = static_config.request_message_type_details.sample_layout(1);
+
+        let quota_reservation = match service
+            .__internal_state()
+            .shared_node
+            .quota()
+            .acquire_port(sample_layout.size() * number_of_requests)
+        {
+            Ok(reservation) => reservation,
+            Err(e) => {
+                fail!(from origin, with ClientCreateError::ExceedsNodeQuota,
+                    "{} since it would exceed the quota of the node ({:?}).", msg, e);
+            }
+        };
+
         let data_segment = DataSegment::<Service>::create_static_segment(
             &segment_name,
-            static_config.request_message_type_details.sample_layout(1),
+            sample_layout,
             global_config,
             number_of_requests,
+            None,
+            service
+                .__internal_state()
+                .static_config
+                .access_control_list()
+                .copied(),
         );
 
         let data_segment = fail!(from origin,
@@ -139,7 +164,7 @@ impl<
         let client_details = ClientDetails {
             client_port_id,
             node_id: *service.__internal_state().shared_node.id(),
-            number_of_requests,
+            number_of_requests: number_of_requests as u64,
         };
 
         let mut new_self = Self {
@@ -162,12 +187,15 @@ impl<
                 service_state: service.__internal_state().clone(),
                 visitor: Visitor::new(),
                 loan_counter: IoxAtomicUsize::new(0),
+                number_of_discarded_samples: IoxAtomicU64::new(0),
+                number_of_reclaimed_abandoned_samples: IoxAtomicU64::new(0),
                 sender_max_borrowed_samples: client_factory.max_loaned_requests,
                 unable_to_deliver_strategy: client_factory.unable_to_deliver_strategy,
                 message_type_details: static_config.request_message_type_details.clone(),
             },
             client_port_id,
             service_state: service.__internal_state().clone(),
+            _quota_reservation: quota_reservation,
             _request_payload: PhantomData,
             _request_header: PhantomData,
             _response_payload: PhantomData,
@@ -175,8 +203,8 @@ impl<
         };
 
         if let Err(e) = new_self.force_update_connections() {
-            warn!(from new_self,
-                "The new Client port is unable to connect to every Server port, caused by {:?}.", e);
+            warn!(from new_self, fields [error = e],
+                "The new Client port is unable to connect to every Server port.");
         }
 
         core::sync::atomic::compiler_fence(Ordering::SeqCst);
@@ -216,7 +244,7 @@ impl<
                     h.index() as usize,
                     ReceiverDetails {
                         port_id: port.server_port_id.value(),
-                        buffer_size: port.buffer_size,
+                        buffer_size: port.buffer_size as usize,
                     },
                     |_| {},
                 );
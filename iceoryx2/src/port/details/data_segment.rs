@@ -11,27 +11,32 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use core::alloc::Layout;
+use core::sync::atomic::Ordering;
 
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::{
     event::NamedConceptBuilder,
+    named_concept::{AccessControlConfiguration, AccessControlList},
     resizable_shared_memory::*,
     shared_memory::{
-        SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError, SharedMemoryForPoolAllocator,
-        SharedMemoryOpenError, ShmPointer,
+        NumaNodeConfiguration, SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError,
+        SharedMemoryForPoolAllocator, SharedMemoryOpenError, ShmPointer,
     },
     shm_allocator::{
         self, pool_allocator::PoolAllocator, AllocationError, AllocationStrategy, PointerOffset,
         SegmentId, ShmAllocationError,
     },
 };
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU8;
 
 use crate::{
     config,
+    port::publisher::MAX_NUMBER_OF_SIZE_CLASSES,
     service::{
         self,
         config_scheme::{data_segment_config, resizable_data_segment_config},
+        naming_scheme::size_class_data_segment_name,
     },
 };
 
@@ -41,6 +46,7 @@ use crate::{
 pub enum DataSegmentType {
     Dynamic,
     Static,
+    Pooled,
 }
 
 impl DataSegmentType {
@@ -56,11 +62,29 @@ impl DataSegmentType {
 enum MemoryType<Service: service::Service> {
     Static(Service::SharedMemory),
     Dynamic(Service::ResizableSharedMemory),
+    // one static segment per size class, the index is the segment id
+    Pooled(Vec<Service::SharedMemory>),
 }
 
+/// The number of buckets of a size class of the data segment and how many of them are
+/// currently allocated.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BucketUsage {
+    pub(crate) number_of_buckets: usize,
+    pub(crate) number_of_used_buckets: usize,
+}
+
+const _: () = assert!(MAX_NUMBER_OF_SIZE_CLASSES <= u8::BITS as usize);
+
+// all size classes are reported as changed after the creation
+const ALL_SIZE_CLASSES_CHANGED: u8 = u8::MAX;
+
 #[derive(Debug)]
 pub(crate) struct DataSegment<Service: service::Service> {
     memory: MemoryType<Service>,
+    // one bit per size class whose bucket usage changed since the last call to
+    // `changed_bucket_usage()`
+    changed_size_classes: IoxAtomicU8,
 }
 
 impl<Service: service::Service> DataSegment<Service> {
@@ -69,6 +93,8 @@ impl<Service: service::Service> DataSegment<Service> {
         chunk_layout: Layout,
         global_config: &config::Config,
         number_of_chunks: usize,
+        numa_node: Option<u32>,
+        access_control_list: Option<AccessControlList>,
     ) -> Result<Self, SharedMemoryCreateError> {
         let allocator_config = shm_allocator::pool_allocator::Config {
             bucket_layout: chunk_layout,
@@ -76,7 +102,9 @@ impl<Service: service::Service> DataSegment<Service> {
         let msg = "Unable to create the static data segment since the underlying shared memory could not be created.";
         let origin = "DataSegment::create_static_segment()";
 
-        let segment_config = data_segment_config::<Service>(global_config);
+        let segment_config = data_segment_config::<Service>(global_config)
+            .numa_node(numa_node)
+            .access_control_list(access_control_list);
         let memory = fail!(from origin,
                                 when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
                                 Service::SharedMemory,
@@ -88,6 +116,45 @@ impl<Service: service::Service> DataSegment<Service> {
 
         Ok(Self {
             memory: MemoryType::Static(memory),
+            changed_size_classes: IoxAtomicU8::new(ALL_SIZE_CLASSES_CHANGED),
+        })
+    }
+
+    /// Creates one static segment per size class. The size classes must be ordered by their
+    /// chunk layout size, an allocation is served by the smallest size class that fits and
+    /// falls back to the larger ones when it is exhausted.
+    pub(crate) fn create_pooled_segment(
+        segment_name: &FileName,
+        size_classes: &[(Layout, usize)],
+        global_config: &config::Config,
+        numa_node: Option<u32>,
+        access_control_list: Option<AccessControlList>,
+    ) -> Result<Self, SharedMemoryCreateError> {
+        let msg = "Unable to create the pooled data segment since the underlying shared memory of a size class could not be created.";
+        let origin = "DataSegment::create_pooled_segment()";
+
+        let segment_config = data_segment_config::<Service>(global_config)
+            .numa_node(numa_node)
+            .access_control_list(access_control_list);
+        let mut memories = Vec::with_capacity(size_classes.len());
+        for (n, (chunk_layout, number_of_chunks)) in size_classes.iter().enumerate() {
+            let allocator_config = shm_allocator::pool_allocator::Config {
+                bucket_layout: *chunk_layout,
+            };
+            let memory = fail!(from origin,
+                                when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
+                                Service::SharedMemory,
+                                    >>::new(&size_class_data_segment_name(segment_name, n as u8))
+                                    .config(&segment_config)
+                                    .size(chunk_layout.size() * number_of_chunks + chunk_layout.align() - 1)
+                                    .create(&allocator_config),
+                                "{msg} [size class: {}, chunk layout: {:?}]", n, chunk_layout);
+            memories.push(memory);
+        }
+
+        Ok(Self {
+            memory: MemoryType::Pooled(memories),
+            changed_size_classes: IoxAtomicU8::new(ALL_SIZE_CLASSES_CHANGED),
         })
     }
 
@@ -97,11 +164,15 @@ impl<Service: service::Service> DataSegment<Service> {
         global_config: &config::Config,
         number_of_chunks: usize,
         allocation_strategy: AllocationStrategy,
+        numa_node: Option<u32>,
+        access_control_list: Option<AccessControlList>,
     ) -> Result<Self, SharedMemoryCreateError> {
         let msg = "Unable to create the dynamic data segment since the underlying shared memory could not be created.";
         let origin = "DataSegment::create_dynamic_segment()";
 
-        let segment_config = resizable_data_segment_config::<Service>(global_config);
+        let segment_config = resizable_data_segment_config::<Service>(global_config)
+            .numa_node(numa_node)
+            .access_control_list(access_control_list);
         let memory = fail!(from origin,
                     when <<Service::ResizableSharedMemory as ResizableSharedMemory<
                         PoolAllocator,
@@ -118,51 +189,149 @@ impl<Service: service::Service> DataSegment<Service> {
 
         Ok(Self {
             memory: MemoryType::Dynamic(memory),
+            changed_size_classes: IoxAtomicU8::new(ALL_SIZE_CLASSES_CHANGED),
         })
     }
 
-    pub(crate) fn allocate(&self, layout: Layout) -> Result<ShmPointer, ShmAllocationError> {
+    /// Allocates a chunk with the provided [`Layout`]. A dynamic data segment creates a new
+    /// segment when it is exhausted, but only when `approve_resize` accepts the payload size
+    /// of the new segment.
+    pub(crate) fn allocate<F: FnMut(usize) -> bool>(
+        &self,
+        layout: Layout,
+        approve_resize: F,
+    ) -> Result<ShmPointer, ShmAllocationError> {
         let msg = "Unable to allocate memory from the data segment";
         match &self.memory {
-            MemoryType::Static(memory) => Ok(fail!(from self, when memory.allocate(layout),
-                                            "{msg}.")),
-            MemoryType::Dynamic(memory) => match memory.allocate(layout) {
-                Ok(ptr) => Ok(ptr),
-                Err(ResizableShmAllocationError::ShmAllocationError(e)) => {
-                    fail!(from self, with e,
+            MemoryType::Static(memory) => {
+                let ptr = fail!(from self, when memory.allocate(layout), "{msg}.");
+                self.mark_size_class_as_changed(0);
+                Ok(ptr)
+            }
+            MemoryType::Dynamic(memory) => {
+                match memory.allocate_with_resize_approval(layout, approve_resize) {
+                    Ok(ptr) => Ok(ptr),
+                    Err(ResizableShmAllocationError::ShmAllocationError(e)) => {
+                        fail!(from self, with e,
                         "{msg} caused by {:?}.", e);
-                }
-                Err(ResizableShmAllocationError::MaxReallocationsReached) => {
-                    fail!(from self,
+                    }
+                    Err(ResizableShmAllocationError::MaxReallocationsReached) => {
+                        fail!(from self,
                         with ShmAllocationError::AllocationError(AllocationError::OutOfMemory),
                         "{msg} since the maxmimum number of reallocations was reached. Try to provide initial_max_slice_len({}) as hint when creating the publisher to have a more fitting initial setup.", layout.size());
-                }
-                Err(ResizableShmAllocationError::SharedMemoryCreateError(e)) => {
-                    fail!(from self,
+                    }
+                    Err(ResizableShmAllocationError::ResizeRejected) => {
+                        fail!(from self,
+                        with ShmAllocationError::AllocationError(AllocationError::OutOfMemory),
+                        "{msg} since the required resize of the memory for {:?} was rejected.", layout);
+                    }
+                    Err(ResizableShmAllocationError::SharedMemoryCreateError(e)) => {
+                        fail!(from self,
                         with ShmAllocationError::AllocationError(AllocationError::InternalError),
                         "{msg} since the shared memory segment creation failed while resizing the memory due to ({:?}).", e);
+                    }
                 }
-            },
+            }
+            MemoryType::Pooled(memories) => {
+                let mut result = ShmAllocationError::AllocationError(AllocationError::SizeTooLarge);
+                for (n, memory) in memories.iter().enumerate() {
+                    if memory.bucket_size() < layout.size() {
+                        continue;
+                    }
+
+                    match memory.allocate(layout) {
+                        Ok(mut ptr) => {
+                            ptr.offset.set_segment_id(SegmentId::new(n as u8));
+                            self.mark_size_class_as_changed(n);
+                            return Ok(ptr);
+                        }
+                        // the next larger size class takes over
+                        Err(e) => result = e,
+                    }
+                }
+
+                fail!(from self, with result,
+                    "{msg} since no size class is able to provide a chunk for {:?} ({:?}).", layout, result);
+            }
         }
     }
 
     pub(crate) unsafe fn deallocate_bucket(&self, offset: PointerOffset) {
         match &self.memory {
-            MemoryType::Static(memory) => memory.deallocate_bucket(offset),
+            MemoryType::Static(memory) => {
+                memory.deallocate_bucket(offset);
+                self.mark_size_class_as_changed(0);
+            }
             MemoryType::Dynamic(memory) => memory.deallocate_bucket(offset),
+            MemoryType::Pooled(memories) => {
+                let size_class = offset.segment_id().value() as usize;
+                memories[size_class].deallocate_bucket(offset);
+                self.mark_size_class_as_changed(size_class);
+            }
         }
     }
 
+    fn mark_size_class_as_changed(&self, size_class: usize) {
+        self.changed_size_classes
+            .fetch_or(1 << size_class, Ordering::Relaxed);
+    }
+
     pub(crate) fn bucket_size(&self, segment_id: SegmentId) -> usize {
         match &self.memory {
             MemoryType::Static(memory) => memory.bucket_size(),
             MemoryType::Dynamic(memory) => memory.bucket_size(segment_id),
+            MemoryType::Pooled(memories) => memories[segment_id.value() as usize].bucket_size(),
+        }
+    }
+
+    pub(crate) fn is_resizable(&self) -> bool {
+        matches!(self.memory, MemoryType::Dynamic(_))
+    }
+
+    pub(crate) fn size(&self) -> usize {
+        match &self.memory {
+            MemoryType::Static(memory) => memory.size(),
+            MemoryType::Dynamic(memory) => memory.size(),
+            MemoryType::Pooled(memories) => memories.iter().map(|m| m.size()).sum(),
+        }
+    }
+
+    /// Calls the callback with the [`BucketUsage`] of every size class whose usage changed
+    /// since the last call. A dynamic data segment reallocates its buckets on demand and does
+    /// not provide it.
+    pub(crate) fn changed_bucket_usage<F: FnMut(usize, BucketUsage)>(&self, mut callback: F) {
+        let usage = |memory: &Service::SharedMemory| BucketUsage {
+            number_of_buckets: memory.number_of_buckets(),
+            number_of_used_buckets: memory.number_of_used_buckets(),
+        };
+
+        if self.changed_size_classes.load(Ordering::Relaxed) == 0 {
+            return;
+        }
+        let changed_size_classes = self.changed_size_classes.swap(0, Ordering::Relaxed);
+        let has_changed = |size_class: usize| changed_size_classes & (1 << size_class) != 0;
+
+        match &self.memory {
+            MemoryType::Static(memory) => {
+                if has_changed(0) {
+                    callback(0, usage(memory))
+                }
+            }
+            MemoryType::Dynamic(_) => (),
+            MemoryType::Pooled(memories) => {
+                for (n, memory) in memories.iter().enumerate() {
+                    if has_changed(n) {
+                        callback(n, usage(memory))
+                    }
+                }
+            }
         }
     }
 
     pub(crate) fn max_number_of_segments(data_segment_type: DataSegmentType) -> u8 {
         match data_segment_type {
             DataSegmentType::Static => 1,
+            DataSegmentType::Pooled => MAX_NUMBER_OF_SIZE_CLASSES as u8,
             DataSegmentType::Dynamic => {
                 (Service::ResizableSharedMemory::max_number_of_reallocations() - 1) as u8
             }
@@ -173,6 +342,7 @@ impl<Service: service::Service> DataSegment<Service> {
 #[derive(Debug)]
 enum MemoryViewType<Service: service::Service> {
     Static(Service::SharedMemory),
+    Pooled(Vec<Service::SharedMemory>),
     Dynamic(
         <Service::ResizableSharedMemory as ResizableSharedMemory<
             PoolAllocator,
@@ -209,6 +379,32 @@ impl<Service: service::Service> DataSegmentView<Service> {
         })
     }
 
+    pub(crate) fn open_pooled_segment(
+        segment_name: &FileName,
+        global_config: &config::Config,
+        number_of_size_classes: u8,
+    ) -> Result<Self, SharedMemoryOpenError> {
+        let origin = "DataSegment::open()";
+        let msg = "Unable to open data segment since the underlying shared memory of a size class could not be opened.";
+
+        let segment_config = data_segment_config::<Service>(global_config);
+        let mut memories = Vec::with_capacity(number_of_size_classes as usize);
+        for n in 0..number_of_size_classes {
+            let memory = fail!(from origin,
+                            when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
+                                Builder::new(&size_class_data_segment_name(segment_name, n))
+                                .config(&segment_config)
+                                .timeout(global_config.global.service.creation_timeout)
+                                .open(),
+                            "{msg} [size class: {}]", n);
+            memories.push(memory);
+        }
+
+        Ok(Self {
+            memory: MemoryViewType::Pooled(memories),
+        })
+    }
+
     pub(crate) fn open_dynamic_segment(
         segment_name: &FileName,
         global_config: &config::Config,
@@ -240,6 +436,16 @@ impl<Service: service::Service> DataSegmentView<Service> {
     ) -> Result<usize, SharedMemoryOpenError> {
         match &self.memory {
             MemoryViewType::Static(memory) => Ok(offset.offset() + memory.payload_start_address()),
+            MemoryViewType::Pooled(memories) => {
+                match memories.get(offset.segment_id().value() as usize) {
+                    Some(memory) => Ok(offset.offset() + memory.payload_start_address()),
+                    None => {
+                        fail!(from self, with SharedMemoryOpenError::DoesNotExist,
+                        "Failed to translate pointer since the size class of the segment {:?} does not exist.",
+                        offset.segment_id());
+                    }
+                }
+            }
             MemoryViewType::Dynamic(memory) => unsafe {
                 match memory.register_and_translate_offset(offset) {
                     Ok(ptr) => Ok(ptr as usize),
@@ -25,6 +25,7 @@ use crate::service::{self, config_scheme::connection_config, naming_scheme::conn
 use alloc::sync::Arc;
 use iceoryx2_bb_container::queue::Queue;
 use iceoryx2_bb_elementary::visitor::{Visitable, Visitor, VisitorMarker};
+use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_log::{fail, warn};
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
 use iceoryx2_cal::zero_copy_connection::*;
@@ -87,6 +88,11 @@ impl<Service: service::Service> Connection<Service> {
             DataSegmentType::Dynamic => {
                 DataSegmentView::open_dynamic_segment(&segment_name, global_config)
             }
+            DataSegmentType::Pooled => DataSegmentView::open_pooled_segment(
+                &segment_name,
+                global_config,
+                max_number_of_segments,
+            ),
         };
 
         let data_segment = fail!(from this,
@@ -171,6 +177,23 @@ impl<Service: service::Service> IncomingConnections<Service> {
         self.connections.len()
     }
 
+    pub(crate) fn list_missed_samples<F: FnMut(u128, u64) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        for i in 0..self.len() {
+            if let Some(ref connection) = self.get(i) {
+                if callback(
+                    connection.sender_port_id,
+                    connection.receiver.number_of_discarded_samples(),
+                ) == CallbackProgression::Stop
+                {
+                    return;
+                }
+            }
+        }
+    }
+
     pub(crate) fn has_samples(&self) -> Result<bool, ConnectionFailure> {
         for id in 0..self.len() {
             if let Some(ref connection) = &self.get(id) {
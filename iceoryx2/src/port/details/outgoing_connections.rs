@@ -18,19 +18,22 @@ extern crate alloc;
 use alloc::sync::Arc;
 
 use iceoryx2_bb_elementary::visitor::{Visitable, Visitor, VisitorMarker};
-use iceoryx2_bb_log::{error, fail, fatal_panic, warn};
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::{debug, error, fail, fatal_panic, warn};
+use iceoryx2_cal::event::{Event, Notifier, NotifierBuilder, TriggerId};
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
 use iceoryx2_cal::shm_allocator::{AllocationError, PointerOffset, ShmAllocationError};
 use iceoryx2_cal::zero_copy_connection::{
-    ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyCreationError, ZeroCopySendError,
-    ZeroCopySender,
+    ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyCreationError, ZeroCopyPortDetails,
+    ZeroCopySendError, ZeroCopySender,
 };
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicUsize};
 
 use crate::node::SharedNode;
 use crate::port::{DegrationAction, DegrationCallback, LoanError, SendError};
 use crate::prelude::UnableToDeliverStrategy;
-use crate::service::config_scheme::connection_config;
+use crate::service::config_scheme::{connection_config, event_config};
+use crate::service::naming_scheme::receiver_event_concept_name;
 use crate::service::static_config::message_type_details::{MessageTypeDetails, TypeVariant};
 use crate::service::ServiceState;
 use crate::{service, service::naming_scheme::connection_name};
@@ -49,6 +52,7 @@ pub(crate) struct ReceiverDetails {
 pub(crate) struct Connection<Service: service::Service> {
     pub(crate) sender: <Service::Connection as ZeroCopyConnection>::Sender,
     pub(crate) receiver_port_id: u128,
+    receiver_notifier: Option<<Service::Event as Event>::Notifier>,
     visitor_marker: VisitorMarker,
 }
 
@@ -91,6 +95,7 @@ impl<Service: service::Service> Connection<Service> {
         Ok(Self {
             sender,
             receiver_port_id,
+            receiver_notifier: None,
             visitor_marker,
         })
     }
@@ -113,6 +118,8 @@ pub(crate) struct OutgoingConnections<Service: service::Service> {
     pub(crate) service_state: Arc<ServiceState<Service>>,
     pub(crate) visitor: Visitor,
     pub(crate) loan_counter: IoxAtomicUsize,
+    pub(crate) number_of_discarded_samples: IoxAtomicU64,
+    pub(crate) number_of_reclaimed_abandoned_samples: IoxAtomicU64,
     pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
     pub(crate) message_type_details: MessageTypeDetails,
 }
@@ -156,6 +163,8 @@ impl<Service: service::Service> OutgoingConnections<Service> {
                          *   blocking_send => can never happen
                          *   try_send => we tried and expect that the buffer is full
                          * */
+                        self.number_of_discarded_samples
+                            .fetch_add(1, Ordering::Relaxed);
                     }
                     Err(ZeroCopySendError::ConnectionCorrupted) => match &self.degration_callback {
                         Some(c) => match c.call(
@@ -186,6 +195,8 @@ impl<Service: service::Service> OutgoingConnections<Service> {
                         number_of_recipients += 1;
 
                         if let Some(old) = overflow {
+                            self.number_of_discarded_samples
+                                .fetch_add(1, Ordering::Relaxed);
                             self.release_sample(old)
                         }
                     }
@@ -195,6 +206,55 @@ impl<Service: service::Service> OutgoingConnections<Service> {
         Ok(number_of_recipients)
     }
 
+    // wakes up a receiver that waits on its event listener, the notifier is opened with the
+    // first notification since only receivers that wait create a listener
+    pub(crate) fn notify_receiver(&self, index: usize) {
+        let connection = match self.get_mut(index) {
+            Some(connection) => connection,
+            None => return,
+        };
+
+        if connection.receiver_notifier.is_none() {
+            match <Service::Event as Event>::NotifierBuilder::new(&receiver_event_concept_name(
+                connection.receiver_port_id,
+            ))
+            .config(&event_config::<Service>(self.shared_node.config()))
+            .open()
+            {
+                Ok(notifier) => connection.receiver_notifier = Some(notifier),
+                Err(e) => {
+                    debug!(from self, "Unable to open the notifier to wake up the receiver {:?} ({:?}).",
+                        connection.receiver_port_id, e);
+                    return;
+                }
+            }
+        }
+
+        if let Some(notifier) = &connection.receiver_notifier {
+            if let Err(e) = notifier.notify(TriggerId::new(0)) {
+                debug!(from self, "Unable to wake up the receiver {:?} ({:?}).",
+                    connection.receiver_port_id, e);
+            }
+        }
+    }
+
+    pub(crate) fn list_discarded_samples<F: FnMut(u128, u64) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        for i in 0..self.len() {
+            if let Some(ref connection) = self.get(i) {
+                if callback(
+                    connection.receiver_port_id,
+                    connection.sender.number_of_discarded_samples(),
+                ) == CallbackProgression::Stop
+                {
+                    return;
+                }
+            }
+        }
+    }
+
     pub(crate) fn return_loaned_sample(&self, distance_to_chunk: PointerOffset) {
         self.release_sample(distance_to_chunk);
         self.loan_counter.fetch_sub(1, Ordering::Relaxed);
@@ -216,11 +276,15 @@ impl<Service: service::Service> OutgoingConnections<Service> {
         Ok(())
     }
 
-    fn len(&self) -> usize {
+    pub(crate) fn len(&self) -> usize {
         self.connections.len()
     }
 
-    pub(crate) fn allocate(&self, layout: Layout) -> Result<ChunkMut, LoanError> {
+    pub(crate) fn allocate<F: FnMut(usize) -> bool>(
+        &self,
+        layout: Layout,
+        approve_resize: F,
+    ) -> Result<ChunkMut, LoanError> {
         self.retrieve_returned_samples();
         let msg = "Unable to allocate data";
 
@@ -230,7 +294,7 @@ impl<Service: service::Service> OutgoingConnections<Service> {
                 msg, layout, self.loan_counter.load(Ordering::Relaxed), self.sender_max_borrowed_samples);
         }
 
-        let shm_pointer = match self.data_segment.allocate(layout) {
+        let shm_pointer = match self.data_segment.allocate(layout, approve_resize) {
             Ok(chunk) => chunk,
             Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
                 fail!(from self, with LoanError::OutOfMemory,
@@ -313,6 +377,27 @@ impl<Service: service::Service> OutgoingConnections<Service> {
         }
     }
 
+    // removes the connection to the receiver with the given port id and reclaims all samples
+    // it holds, must only be called when the node of the receiver died
+    pub(crate) fn reclaim_abandoned_connection(&self, index: usize, receiver_port_id: u128) {
+        if let Some(connection) = self.get(index) {
+            if connection.receiver_port_id != receiver_port_id {
+                return;
+            }
+
+            // # SAFETY: the receiver died, therefore we can reacquire all delivered samples
+            unsafe {
+                connection.sender.acquire_used_offsets(|offset| {
+                    self.number_of_reclaimed_abandoned_samples
+                        .fetch_add(1, Ordering::Relaxed);
+                    self.release_sample(offset)
+                })
+            };
+
+            *self.get_mut(index) = None;
+        }
+    }
+
     pub(crate) fn start_update_connection_cycle(&self) {
         self.visitor.next_cycle();
     }
@@ -335,6 +420,9 @@ impl<Service: service::Service> OutgoingConnections<Service> {
         };
 
         if create_connection {
+            // the slot may still hold the connection of a removed receiver, its samples must
+            // be reclaimed before the connection to the new receiver replaces it
+            self.remove_connection(index);
             match self.create(index, receiver_details) {
                 Ok(()) => match &self.get(index) {
                     Some(connection) => establish_new_connection_call(connection),
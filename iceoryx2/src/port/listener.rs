@@ -57,6 +57,39 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Integrate Into An External Event Loop
+//!
+//! On unix platforms the [`Listener`](crate::port::listener::Listener) implements
+//! [`AsRawFd`](std::os::fd::AsRawFd) and [`AsFd`](std::os::fd::AsFd) when the underlying event
+//! mechanism is file descriptor based, like for the [`ipc::Service`](crate::service::ipc::Service).
+//! The file descriptor can be registered in `epoll`, `mio` or `tokio` next to network sockets.
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # #[cfg(unix)]
+//! # {
+//! use std::os::fd::AsRawFd;
+//!
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let event = node.service_builder(&"MyEventName".try_into()?)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! let listener = event.listener_builder().create()?;
+//!
+//! // register the file descriptor, e.g. with mio::unix::SourceFd(&fd)
+//! let fd = listener.as_raw_fd();
+//!
+//! // when the event loop reports the file descriptor as readable
+//! listener.try_wait_all(|id| {
+//!     println!("event was triggered with id: {:?}", id);
+//! })?;
+//! # }
+//! # Ok(())
+//! # }
+//! ```
 
 use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
 use iceoryx2_bb_log::fail;
@@ -67,6 +100,7 @@ use iceoryx2_cal::event::{ListenerBuilder, ListenerWaitError, NamedConceptMgmt,
 use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptRemoveError};
 
 use crate::config::Config;
+use crate::node::quota::QuotaReservation;
 use crate::service::config_scheme::event_config;
 use crate::service::dynamic_config::event::ListenerDetails;
 use crate::service::naming_scheme::event_concept_name;
@@ -79,6 +113,7 @@ extern crate alloc;
 use alloc::sync::Arc;
 
 use super::event_id::EventId;
+use super::wait_strategy::WaitStrategy;
 
 /// Defines the failures that can occur when a [`Listener`] is created with the
 /// [`crate::service::port_factory::listener::PortFactoryListener`].
@@ -91,6 +126,9 @@ pub enum ListenerCreateError {
     ExceedsMaxSupportedListeners,
     /// An underlying resource of the [`Service`](crate::service::Service) could not be created
     ResourceCreationFailed,
+    /// The [`Listener`] cannot be created since it would exceed the
+    /// [`NodeQuota`](crate::node::quota::NodeQuota) of the [`Node`](crate::node::Node).
+    ExceedsNodeQuota,
 }
 
 impl core::fmt::Display for ListenerCreateError {
@@ -108,6 +146,8 @@ pub struct Listener<Service: service::Service> {
     listener: <Service::Event as iceoryx2_cal::event::Event>::Listener,
     service_state: Arc<ServiceState<Service>>,
     listener_id: UniqueListenerId,
+    wait_strategy: WaitStrategy,
+    _quota_reservation: QuotaReservation,
 }
 
 impl<Service: service::Service> FileDescriptorBased for Listener<Service>
@@ -124,8 +164,34 @@ impl<Service: service::Service> SynchronousMultiplexing for Listener<Service> wh
 {
 }
 
+/// Allows external event loops like `epoll`, `mio` or `tokio` to poll the [`Listener`]. The
+/// file descriptor becomes readable as soon as an [`EventId`] was received and stays readable
+/// until all [`EventId`]s were consumed, for instance with [`Listener::try_wait_all()`].
+#[cfg(unix)]
+impl<Service: service::Service> std::os::fd::AsRawFd for Listener<Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    fn as_raw_fd(&self) -> std::os::fd::RawFd {
+        unsafe { self.file_descriptor().native_handle() }
+    }
+}
+
+#[cfg(unix)]
+impl<Service: service::Service> std::os::fd::AsFd for Listener<Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    fn as_fd(&self) -> std::os::fd::BorrowedFd<'_> {
+        // the file descriptor is owned by the listener and therefore valid as long as the
+        // listener exists
+        unsafe { std::os::fd::BorrowedFd::borrow_raw(self.file_descriptor().native_handle()) }
+    }
+}
+
 impl<Service: service::Service> Drop for Listener<Service> {
     fn drop(&mut self) {
+        let _log_scope = self.service_state.shared_node.log_scope();
         if let Some(handle) = self.dynamic_listener_handle {
             self.service_state
                 .dynamic_storage
@@ -137,11 +203,28 @@ impl<Service: service::Service> Drop for Listener<Service> {
 }
 
 impl<Service: service::Service> Listener<Service> {
-    pub(crate) fn new(service: &Service) -> Result<Self, ListenerCreateError> {
+    pub(crate) fn new(
+        service: &Service,
+        wait_strategy: WaitStrategy,
+    ) -> Result<Self, ListenerCreateError> {
+        let _log_scope = service.__internal_state().shared_node.log_scope();
         let msg = "Failed to create listener";
         let origin = "Listener::new()";
         let listener_id = UniqueListenerId::new();
 
+        let quota_reservation = match service
+            .__internal_state()
+            .shared_node
+            .quota()
+            .acquire_port(0)
+        {
+            Ok(reservation) => reservation,
+            Err(e) => {
+                fail!(from origin, with ListenerCreateError::ExceedsNodeQuota,
+                    "{} since it would exceed the quota of the node ({:?}).", msg, e);
+            }
+        };
+
         let event_name = event_concept_name(&listener_id);
         let event_config = event_config::<Service>(service.__internal_state().shared_node.config());
 
@@ -157,6 +240,8 @@ impl<Service: service::Service> Listener<Service> {
             dynamic_listener_handle: None,
             listener,
             listener_id,
+            wait_strategy,
+            _quota_reservation: quota_reservation,
         };
 
         core::sync::atomic::compiler_fence(Ordering::SeqCst);
@@ -197,6 +282,7 @@ impl<Service: service::Service> Listener<Service> {
     /// Non-blocking wait for new [`EventId`]s. Collects all [`EventId`]s that were received and
     /// calls the provided callback is with the [`EventId`] as input argument.
     pub fn try_wait_all<F: FnMut(EventId)>(&self, callback: F) -> Result<(), ListenerWaitError> {
+        let _log_scope = self.service_state.shared_node.log_scope();
         use iceoryx2_cal::event::Listener;
         fail!(from self, when self.listener.try_wait_all(callback),
             "Failed to while calling try_wait on underlying event::Listener");
@@ -211,6 +297,7 @@ impl<Service: service::Service> Listener<Service> {
         callback: F,
         timeout: Duration,
     ) -> Result<(), ListenerWaitError> {
+        let _log_scope = self.service_state.shared_node.log_scope();
         use iceoryx2_cal::event::Listener;
         fail!(from self, when self.listener.timed_wait_all(callback, timeout),
             "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout);
@@ -220,11 +307,27 @@ impl<Service: service::Service> Listener<Service> {
     /// Blocking wait for new [`EventId`]s. Unblocks as soon
     /// as an [`EventId`] was received and then collects all [`EventId`]s that were received and
     /// calls the provided callback is with the [`EventId`] as input argument.
+    /// See [`PortFactoryListener::wait_strategy()`](crate::service::port_factory::listener::PortFactoryListener::wait_strategy())
+    /// for the waiting strategy.
     pub fn blocking_wait_all<F: FnMut(EventId)>(
         &self,
-        callback: F,
+        mut callback: F,
     ) -> Result<(), ListenerWaitError> {
+        let _log_scope = self.service_state.shared_node.log_scope();
         use iceoryx2_cal::event::Listener;
+        let mut has_received_events = false;
+        let result = self.wait_strategy.poll(None, || {
+            self.try_wait_all(|id| {
+                has_received_events = true;
+                callback(id)
+            })?;
+            Ok(has_received_events.then_some(()))
+        })?;
+
+        if result.is_some() {
+            return Ok(());
+        }
+
         fail!(from self, when self.listener.blocking_wait_all(callback),
             "Failed to while calling blocking_wait on underlying event::Listener");
         Ok(())
@@ -234,16 +337,36 @@ impl<Service: service::Service> Listener<Service> {
     /// On error it returns [`ListenerWaitError`] is returned which describes the error
     /// in detail.
     pub fn try_wait_one(&self) -> Result<Option<EventId>, ListenerWaitError> {
+        let _log_scope = self.service_state.shared_node.log_scope();
         use iceoryx2_cal::event::Listener;
         Ok(fail!(from self, when self.listener.try_wait_one(),
             "Failed to while calling try_wait on underlying event::Listener"))
     }
 
+    /// Returns a [`Future`](core::future::Future) that is ready as soon as an [`EventId`] was
+    /// received. The pending [`Future`](core::future::Future) is woken up by the
+    /// [`default_reactor()`](crate::future::default_reactor()).
+    #[cfg(feature = "async")]
+    pub fn wait_async(&self) -> crate::future::ListenerWaitFuture<'_, Service> {
+        self.wait_async_with(crate::future::default_reactor())
+    }
+
+    /// Like [`Listener::wait_async()`] but the pending [`Future`](core::future::Future) is
+    /// woken up by the provided [`AsyncReactor`](crate::future::AsyncReactor).
+    #[cfg(feature = "async")]
+    pub fn wait_async_with<'a>(
+        &'a self,
+        reactor: &'a dyn crate::future::AsyncReactor,
+    ) -> crate::future::ListenerWaitFuture<'a, Service> {
+        crate::future::ListenerWaitFuture::new(self, reactor)
+    }
+
     /// Blocking wait for a new [`EventId`] until either an [`EventId`] was received or the timeout
     /// has passed. If no [`EventId`] was notified it returns [`None`].
     /// On error it returns [`ListenerWaitError`] is returned which describes the error
     /// in detail.
     pub fn timed_wait_one(&self, timeout: Duration) -> Result<Option<EventId>, ListenerWaitError> {
+        let _log_scope = self.service_state.shared_node.log_scope();
         use iceoryx2_cal::event::Listener;
         Ok(fail!(from self, when self.listener.timed_wait_one(timeout),
             "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout))
@@ -253,8 +376,15 @@ impl<Service: service::Service> Listener<Service> {
     /// Sporadic wakeups can occur and if no [`EventId`] was notified it returns [`None`].
     /// On error it returns [`ListenerWaitError`] is returned which describes the error
     /// in detail.
+    /// See [`PortFactoryListener::wait_strategy()`](crate::service::port_factory::listener::PortFactoryListener::wait_strategy())
+    /// for the waiting strategy.
     pub fn blocking_wait_one(&self) -> Result<Option<EventId>, ListenerWaitError> {
+        let _log_scope = self.service_state.shared_node.log_scope();
         use iceoryx2_cal::event::Listener;
+        if let Some(id) = self.wait_strategy.poll(None, || self.try_wait_one())? {
+            return Ok(Some(id));
+        }
+
         Ok(fail!(from self, when self.listener.blocking_wait_one(),
             "Failed to while calling blocking_wait on underlying event::Listener"))
     }
@@ -41,6 +41,12 @@ pub mod update_connections;
 /// receiver is full and the service does not overflow.
 pub mod unable_to_deliver_strategy;
 
+/// Defines the degradation policy of a publisher when the shared memory is exhausted.
+pub mod out_of_memory_strategy;
+
+/// Defines how a port waits in its blocking calls.
+pub mod wait_strategy;
+
 use crate::port::port_identifiers::*;
 use crate::service;
 
@@ -141,6 +147,10 @@ pub enum ReceiveError {
 
     /// Occurs when a receiver is unable to connect to a corresponding sender.
     ConnectionFailure(ConnectionFailure),
+
+    /// Errors that indicate either an implementation issue or a wrongly configured system,
+    /// for instance when the event listener of a blocking receive could not be created.
+    InternalFailure,
 }
 
 impl core::fmt::Display for ReceiveError {
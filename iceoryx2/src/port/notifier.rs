@@ -37,6 +37,7 @@
 
 use super::{event_id::EventId, port_identifiers::UniqueListenerId};
 use crate::{
+    node::quota::QuotaReservation,
     port::port_identifiers::UniqueNotifierId,
     service::{
         self,
@@ -52,7 +53,7 @@ use iceoryx2_bb_log::{debug, fail, warn};
 use iceoryx2_cal::{dynamic_storage::DynamicStorage, event::NotifierBuilder};
 use iceoryx2_cal::{event::Event, named_concept::NamedConceptBuilder};
 
-use core::{cell::UnsafeCell, sync::atomic::Ordering, time::Duration};
+use core::{cell::UnsafeCell, fmt::Debug, sync::atomic::Ordering, time::Duration};
 
 extern crate alloc;
 use alloc::sync::Arc;
@@ -66,6 +67,9 @@ pub enum NotifierCreateError {
     /// defined in [`crate::config::Config`]. When this is exceeded no more [`Notifier`]s
     /// can be created for a specific [`Service`](crate::service::Service).
     ExceedsMaxSupportedNotifiers,
+    /// The [`Notifier`] cannot be created since it would exceed the
+    /// [`NodeQuota`](crate::node::quota::NodeQuota) of the [`Node`](crate::node::Node).
+    ExceedsNodeQuota,
 }
 
 impl core::fmt::Display for NotifierCreateError {
@@ -129,38 +133,14 @@ impl<Service: service::Service> ListenerConnections<Service> {
     }
 
     fn create(&self, index: usize, listener_id: UniqueListenerId) {
-        let msg = "Unable to establish connection to listener";
-        let event_name = event_concept_name(&listener_id);
-        let event_config = event_config::<Service>(self.service_state.shared_node.config());
         if self.get(index).is_none() {
-            match <Service::Event as iceoryx2_cal::event::Event>::NotifierBuilder::new(&event_name)
-                .config(&event_config)
-                .open()
+            if let Some(notifier) =
+                open_listener_connection(self, &self.service_state, &listener_id)
             {
-                Ok(notifier) => {
-                    *self.get_mut(index) = Some(Connection {
-                        notifier,
-                        listener_id,
-                    });
-                }
-                Err(
-                    iceoryx2_cal::event::NotifierCreateError::DoesNotExist
-                    | iceoryx2_cal::event::NotifierCreateError::InitializationNotYetFinalized,
-                ) => (),
-                Err(iceoryx2_cal::event::NotifierCreateError::VersionMismatch) => {
-                    warn!(from self,
-                        "{} since a version mismatch was detected! All entities must use the same iceoryx2 version!",
-                        msg);
-                }
-                Err(iceoryx2_cal::event::NotifierCreateError::InsufficientPermissions) => {
-                    warn!(from self, "{} since the permissions do not match. The service or the participants are maybe misconfigured.", msg);
-                }
-                Err(iceoryx2_cal::event::NotifierCreateError::Interrupt) => {
-                    debug!(from self, "{} since an interrupt signal was received.", msg);
-                }
-                Err(iceoryx2_cal::event::NotifierCreateError::InternalFailure) => {
-                    debug!(from self, "{} due to an internal failure.", msg);
-                }
+                *self.get_mut(index) = Some(Connection {
+                    notifier,
+                    listener_id,
+                });
             }
         }
     }
@@ -183,6 +163,80 @@ impl<Service: service::Service> ListenerConnections<Service> {
     }
 }
 
+fn open_listener_connection<Service: service::Service, Origin: Debug>(
+    origin: &Origin,
+    service_state: &ServiceState<Service>,
+    listener_id: &UniqueListenerId,
+) -> Option<<Service::Event as Event>::Notifier> {
+    let msg = "Unable to establish connection to listener";
+    let event_name = event_concept_name(listener_id);
+    let event_config = event_config::<Service>(service_state.shared_node.config());
+    match <Service::Event as iceoryx2_cal::event::Event>::NotifierBuilder::new(&event_name)
+        .config(&event_config)
+        .open()
+    {
+        Ok(notifier) => return Some(notifier),
+        Err(
+            iceoryx2_cal::event::NotifierCreateError::DoesNotExist
+            | iceoryx2_cal::event::NotifierCreateError::InitializationNotYetFinalized,
+        ) => (),
+        Err(iceoryx2_cal::event::NotifierCreateError::VersionMismatch) => {
+            warn!(from origin,
+                "{} since a version mismatch was detected! All entities must use the same iceoryx2 version!",
+                msg);
+        }
+        Err(iceoryx2_cal::event::NotifierCreateError::InsufficientPermissions) => {
+            warn!(from origin, "{} since the permissions do not match. The service or the participants are maybe misconfigured.", msg);
+        }
+        Err(iceoryx2_cal::event::NotifierCreateError::Interrupt) => {
+            debug!(from origin, "{} since an interrupt signal was received.", msg);
+        }
+        Err(iceoryx2_cal::event::NotifierCreateError::InternalFailure) => {
+            debug!(from origin, "{} due to an internal failure.", msg);
+        }
+    }
+
+    None
+}
+
+/// Emits the [`EventId`] to all [`Listener`](crate::port::listener::Listener)s of the event
+/// service without a [`Notifier`] port. Used for the notifications the service emits on its
+/// own, they must not occupy a notifier slot of the user. Returns the number of
+/// [`Listener`](crate::port::listener::Listener)s that were notified.
+pub(crate) fn notify_all_listeners<Service: service::Service>(
+    service_state: &ServiceState<Service>,
+    event_id: EventId,
+) -> usize {
+    use iceoryx2_cal::event::Notifier;
+    let origin = "notify_all_listeners()";
+    let mut number_of_triggered_listeners = 0;
+
+    let listeners = unsafe {
+        service_state
+            .dynamic_storage
+            .get()
+            .event()
+            .listeners
+            .get_state()
+    };
+    listeners.for_each(|_, details| {
+        if let Some(notifier) = open_listener_connection(&origin, service_state, &details.listener_id)
+        {
+            match notifier.notify(event_id) {
+                Ok(_) => number_of_triggered_listeners += 1,
+                Err(iceoryx2_cal::event::NotifierNotifyError::Disconnected) => (),
+                Err(e) => {
+                    warn!(from origin, fields [listener_id = details.listener_id.value(), error = e],
+                        "Unable to send notification to the listener.")
+                }
+            }
+        }
+        CallbackProgression::Continue
+    });
+
+    number_of_triggered_listeners
+}
+
 /// Represents the sending endpoint of an event based communication.
 #[derive(Debug)]
 pub struct Notifier<Service: service::Service> {
@@ -193,14 +247,20 @@ pub struct Notifier<Service: service::Service> {
     dynamic_notifier_handle: Option<ContainerHandle>,
     notifier_id: UniqueNotifierId,
     on_drop_notification: Option<EventId>,
+    _quota_reservation: QuotaReservation,
 }
 
 impl<Service: service::Service> Drop for Notifier<Service> {
     fn drop(&mut self) {
+        let _log_scope = self
+            .listener_connections
+            .service_state
+            .shared_node
+            .log_scope();
         if let Some(event_id) = self.on_drop_notification {
             if let Err(e) = self.notify_with_custom_event_id(event_id) {
-                warn!(from self, "Unable to send notifier_dropped_event {:?} due to ({:?}).",
-                    event_id, e);
+                warn!(from self, fields [event_id = event_id.as_value(), error = e],
+                    "Unable to send notifier_dropped_event.");
             }
         }
 
@@ -220,6 +280,7 @@ impl<Service: service::Service> Notifier<Service> {
         service: &Service,
         default_event_id: EventId,
     ) -> Result<Self, NotifierCreateError> {
+        let _log_scope = service.__internal_state().shared_node.log_scope();
         let mut new_self = Self::new_without_auto_event_emission(service, default_event_id)?;
 
         let static_config = service.__internal_state().static_config.event();
@@ -233,9 +294,8 @@ impl<Service: service::Service> Notifier<Service> {
                     | NotifierNotifyError::UnableToAcquireElapsedTime,
                 ) => (),
                 Err(e) => {
-                    warn!(from new_self,
-                        "The new notifier was unable to send out the notifier_created_event: {:?} due to ({:?}).",
-                        event_id, e);
+                    warn!(from new_self, fields [event_id = event_id.as_value(), error = e],
+                        "The new notifier was unable to send out the notifier_created_event.");
                 }
             }
         }
@@ -247,6 +307,7 @@ impl<Service: service::Service> Notifier<Service> {
         service: &Service,
         default_event_id: EventId,
     ) -> Result<Self, NotifierCreateError> {
+        let _log_scope = service.__internal_state().shared_node.log_scope();
         let msg = "Unable to create Notifier port";
         let origin = "Notifier::new()";
         let notifier_id = UniqueNotifierId::new();
@@ -258,6 +319,19 @@ impl<Service: service::Service> Notifier<Service> {
             .event()
             .listeners;
 
+        let quota_reservation = match service
+            .__internal_state()
+            .shared_node
+            .quota()
+            .acquire_port(0)
+        {
+            Ok(reservation) => reservation,
+            Err(e) => {
+                fail!(from origin, with NotifierCreateError::ExceedsNodeQuota,
+                    "{} since it would exceed the quota of the node ({:?}).", msg, e);
+            }
+        };
+
         let static_config = service.__internal_state().static_config.event();
         let mut new_self = Self {
             listener_connections: ListenerConnections::new(
@@ -270,6 +344,7 @@ impl<Service: service::Service> Notifier<Service> {
             dynamic_notifier_handle: None,
             notifier_id,
             on_drop_notification: None,
+            _quota_reservation: quota_reservation,
         };
 
         new_self.populate_listener_channels();
@@ -381,6 +456,11 @@ impl<Service: service::Service> Notifier<Service> {
         &self,
         value: EventId,
     ) -> Result<usize, NotifierNotifyError> {
+        let _log_scope = self
+            .listener_connections
+            .service_state
+            .shared_node
+            .log_scope();
         let msg = "Unable to notify event";
         self.update_connections();
 
@@ -400,8 +480,9 @@ impl<Service: service::Service> Notifier<Service> {
                         self.listener_connections.remove(i);
                     }
                     Err(e) => {
-                        warn!(from self, "Unable to send notification via connection {:?} due to {:?}.",
-                        connection, e)
+                        warn!(from self,
+                            fields [listener_id = connection.listener_id.value(), error = e],
+                            "Unable to send notification via connection.")
                     }
                     Ok(_) => {
                         number_of_triggered_listeners += 1;
@@ -0,0 +1,75 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use serde::{de::Visitor, Deserialize, Serialize};
+
+/// Defines the degradation policy of a [`crate::port::publisher::Publisher`] when the shared
+/// memory of its data segment is exhausted. It is defined per
+/// [`Service`](crate::service::Service) with
+/// [`Builder::out_of_memory_strategy()`](crate::service::builder::publish_subscribe::Builder::out_of_memory_strategy()).
+#[derive(Debug, Default, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum OutOfMemoryStrategy {
+    /// The loan fails with [`LoanError::OutOfMemory`](crate::port::LoanError::OutOfMemory).
+    #[default]
+    Fail,
+    /// The [`crate::port::publisher::Publisher`] releases the oldest
+    /// [`crate::sample::Sample`]s of its history until the loan succeeds. A released
+    /// [`crate::sample::Sample`] is only reused when no
+    /// [`crate::port::subscriber::Subscriber`] references it anymore, therefore no data that
+    /// is still in use is overwritten. Late joining
+    /// [`crate::port::subscriber::Subscriber`]s receive a shorter history in return. When the
+    /// history is empty and the loan still fails,
+    /// [`LoanError::OutOfMemory`](crate::port::LoanError::OutOfMemory) is returned.
+    ReclaimHistory,
+}
+
+impl Serialize for OutOfMemoryStrategy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&std::format!("{:?}", self))
+    }
+}
+
+struct OutOfMemoryStrategyVisitor;
+
+impl Visitor<'_> for OutOfMemoryStrategyVisitor {
+    type Value = OutOfMemoryStrategy;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a string containing either 'Fail' or 'ReclaimHistory'")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "Fail" => Ok(OutOfMemoryStrategy::Fail),
+            "ReclaimHistory" => Ok(OutOfMemoryStrategy::ReclaimHistory),
+            v => Err(E::custom(format!(
+                "Invalid OutOfMemoryStrategy provided: \"{:?}\".",
+                v
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for OutOfMemoryStrategy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(OutOfMemoryStrategyVisitor)
+    }
+}
@@ -101,11 +101,15 @@
 //! # }
 //! ```
 
+use super::details::chunk::ChunkMut;
 use super::details::data_segment::{DataSegment, DataSegmentType};
 use super::details::segment_state::SegmentState;
 use super::port_identifiers::UniquePublisherId;
 use super::{LoanError, SendError, UniqueSubscriberId};
+use crate::node::quota::QuotaReservation;
+use crate::node::NodeState;
 use crate::port::details::outgoing_connections::*;
+use crate::port::out_of_memory_strategy::OutOfMemoryStrategy;
 use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
 use crate::prelude::UnableToDeliverStrategy;
 use crate::raw_sample::RawSampleMut;
@@ -116,22 +120,26 @@ use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, Subscr
 use crate::service::header::publish_subscribe::Header;
 use crate::service::naming_scheme::{
     data_segment_name, extract_publisher_id_from_connection, extract_subscriber_id_from_connection,
+    size_class_data_segment_name,
 };
-use crate::service::port_factory::publisher::LocalPublisherConfig;
+use crate::service::port_factory::publisher::{LocalPublisherConfig, SizeClass};
 use crate::service::static_config::message_type_details::TypeVariant;
 use crate::service::static_config::publish_subscribe::{self};
 use crate::service::{self, ServiceState};
+use crate::tagged_union::{TaggedUnion, TaggedUnionVariant};
 use crate::{config, sample_mut::SampleMut};
+use core::alloc::Layout;
 use core::any::TypeId;
 use core::cell::UnsafeCell;
 use core::fmt::Debug;
 use core::sync::atomic::Ordering;
-use core::{marker::PhantomData, mem::MaybeUninit};
+use core::{marker::PhantomData, mem::MaybeUninit, time::Duration};
 use iceoryx2_bb_container::queue::Queue;
 use iceoryx2_bb_elementary::visitor::Visitor;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{debug, fail, warn};
+use iceoryx2_bb_posix::clock::{ClockType, Time};
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
@@ -142,7 +150,7 @@ use iceoryx2_cal::zero_copy_connection::{
     ZeroCopyConnection, ZeroCopyCreationError, ZeroCopyPortDetails, ZeroCopyPortRemoveError,
     ZeroCopySender,
 };
-use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, IoxAtomicUsize};
 
 extern crate alloc;
 use alloc::sync::Arc;
@@ -158,6 +166,14 @@ pub enum PublisherCreateError {
     ExceedsMaxSupportedPublishers,
     /// The datasegment in which the payload of the [`Publisher`] is stored, could not be created.
     UnableToCreateDataSegment,
+    /// The [`Publisher`] cannot be created since it would exceed the
+    /// [`NodeQuota`](crate::node::quota::NodeQuota) of the [`Node`](crate::node::Node).
+    ExceedsNodeQuota,
+    /// The [`Service`](crate::service::Service) was opened with a payload type that is smaller
+    /// than the payload type of the [`Service`](crate::service::Service), see
+    /// [`Builder::allow_smaller_payload_type()`](crate::service::builder::publish_subscribe::Builder::allow_smaller_payload_type()).
+    /// The [`Publisher`] would be unable to provide the full payload.
+    IncompatiblePayloadSize,
 }
 
 impl core::fmt::Display for PublisherCreateError {
@@ -176,6 +192,39 @@ pub(crate) enum RemovePubSubPortFromAllConnectionsError {
     InternalError,
 }
 
+impl core::fmt::Display for RemovePubSubPortFromAllConnectionsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "RemovePubSubPortFromAllConnectionsError::{:?}", self)
+    }
+}
+
+const INVALID_RESOURCE_USAGE_INDEX: usize = usize::MAX;
+const RESOURCE_USAGE_SAMPLING_INTERVAL: Duration = Duration::from_millis(100);
+const DEAD_NODE_MONITORING_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The maximum number of size classes the data segment of a [`Publisher`] supports, see
+/// [`PortFactoryPublisher::size_class()`](crate::service::port_factory::publisher::PortFactoryPublisher::size_class()).
+pub const MAX_NUMBER_OF_SIZE_CLASSES: usize = 8;
+
+#[derive(Debug)]
+struct PendingBatch {
+    offset: PointerOffset,
+    sample_size: usize,
+    header: *mut Header,
+    payload: *mut u8,
+    len: usize,
+    capacity: usize,
+    start: Option<Time>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ResourceUsage {
+    number_of_loaned_samples: usize,
+    allocated_data_segment_bytes: usize,
+    number_of_discarded_samples: u64,
+    number_of_reclaimed_abandoned_samples: u64,
+}
+
 #[derive(Debug, Clone, Copy)]
 struct OffsetAndSize {
     offset: u64,
@@ -191,9 +240,251 @@ pub(crate) struct PublisherBackend<Service: service::Service> {
     subscriber_list_state: UnsafeCell<ContainerState<SubscriberDetails>>,
     history: Option<UnsafeCell<Queue<OffsetAndSize>>>,
     is_active: IoxAtomicBool,
+    resource_usage_index: IoxAtomicUsize,
+    published_resource_usage: UnsafeCell<Option<ResourceUsage>>,
+    last_resource_usage_update: UnsafeCell<Option<Time>>,
+    last_dead_node_sweep: UnsafeCell<Option<Time>>,
+    sequence_number: IoxAtomicU64,
+    out_of_memory_strategy: OutOfMemoryStrategy,
+    number_of_reclaimed_samples: IoxAtomicU64,
+    zero_payload_on_loan: bool,
+    number_of_zeroed_bytes: IoxAtomicU64,
+    is_degraded: IoxAtomicBool,
+    pending_batch: UnsafeCell<Option<PendingBatch>>,
+    quota_reservation: QuotaReservation,
 }
 
 impl<Service: service::Service> PublisherBackend<Service> {
+    pub(crate) fn allocate(&self, layout: Layout) -> Result<ChunkMut, LoanError> {
+        let _log_scope = self.service_state.shared_node.log_scope();
+        if let Err(e) = self.flush_pending_batch_if_due() {
+            warn!(from self, fields [error = e],
+                "Unable to deliver the due write-combined sample.");
+        }
+
+        let mut has_reclaimed_samples = false;
+        let mut has_swept_abandoned_samples = false;
+        loop {
+            let mut has_resized = false;
+            let approve_resize = |payload_size| {
+                has_resized = self.approve_data_segment_resize(payload_size);
+                has_resized
+            };
+            match self.subscriber_connections.allocate(layout, approve_resize) {
+                Ok(chunk) => {
+                    if has_resized {
+                        self.release_unused_quota();
+                    }
+                    if !has_reclaimed_samples && self.is_degraded.swap(false, Ordering::Relaxed) {
+                        debug!(from self, "Recovered from the exhausted data segment.");
+                    }
+                    self.sample_resource_usage();
+                    if self.zero_payload_on_loan {
+                        self.zero_chunk(&chunk);
+                    }
+                    return Ok(chunk);
+                }
+                Err(LoanError::OutOfMemory) if !has_swept_abandoned_samples => {
+                    has_swept_abandoned_samples = true;
+                    self.reclaim_abandoned_samples();
+                }
+                Err(LoanError::OutOfMemory)
+                    if self.out_of_memory_strategy == OutOfMemoryStrategy::ReclaimHistory
+                        && self.reclaim_oldest_history_sample() =>
+                {
+                    has_reclaimed_samples = true;
+                    self.number_of_reclaimed_samples
+                        .fetch_add(1, Ordering::Relaxed);
+                    if !self.is_degraded.swap(true, Ordering::Relaxed) {
+                        warn!(from self,
+                            fields [size = layout.size(), alignment = layout.align()],
+                            "The data segment is exhausted, the oldest history samples are reclaimed to be able to loan.");
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    // A dead subscriber never returns the samples it holds. The samples are reclaimed when
+    // the connection is removed, which is safe as soon as the subscriber was removed from the
+    // service by any remaining participant. Dead nodes of connected subscribers are cleaned up
+    // here, at most once per monitoring interval, so that an exhausted data segment recovers
+    // without waiting for another participant.
+    fn reclaim_abandoned_samples(&self) {
+        let is_due = match unsafe { &*self.last_dead_node_sweep.get() } {
+            Some(last_sweep) => match last_sweep.elapsed() {
+                Ok(elapsed) => DEAD_NODE_MONITORING_INTERVAL <= elapsed,
+                Err(_) => true,
+            },
+            None => true,
+        };
+
+        if is_due {
+            unsafe {
+                *self.last_dead_node_sweep.get() = Time::now_with_clock(ClockType::Monotonic).ok()
+            };
+
+            let config = self.service_state.shared_node.config();
+            unsafe { &*self.subscriber_list_state.get() }.for_each(|h, details| {
+                if let Ok(Some(NodeState::Dead(node))) =
+                    NodeState::<Service>::new(&details.node_id, config)
+                {
+                    match node.remove_stale_resources() {
+                        Ok(_) => self.subscriber_connections.reclaim_abandoned_connection(
+                            h.index() as usize,
+                            details.subscriber_id.value(),
+                        ),
+                        Err(e) => {
+                            debug!(from self,
+                                fields [node_id = details.node_id.value(),
+                                        subscriber_id = details.subscriber_id.value(),
+                                        error = e],
+                                "Unable to remove the stale resources of the dead node of the subscriber.");
+                        }
+                    }
+                }
+                CallbackProgression::Continue
+            });
+        }
+
+        if let Err(e) = self.update_connections() {
+            warn!(from self, fields [error = e],
+                "Abandoned samples were reclaimed only partially since the connections could not be updated.");
+        }
+    }
+
+    // every resize of a dynamic data segment is accounted in the quota of the node, the
+    // reservation covers at least the current size of the data segment and grows by the
+    // size of the new segment
+    fn approve_data_segment_resize(&self, payload_size: usize) -> bool {
+        let reserved = self.quota_reservation.shared_memory_bytes();
+        match self
+            .quota_reservation
+            .resize_shared_memory(reserved.saturating_add(payload_size))
+        {
+            Ok(()) => true,
+            Err(e) => {
+                warn!(from self, fields [resize_bytes = payload_size, error = e],
+                    "The data segment is not resized since it would exceed the quota of the node.");
+                false
+            }
+        }
+    }
+
+    // segments of a dynamic data segment are removed as soon as they are no longer used,
+    // their memory is returned to the quota of the node
+    fn release_unused_quota(&self) {
+        let data_segment = &self.subscriber_connections.data_segment;
+        if !data_segment.is_resizable() {
+            return;
+        }
+
+        let size = data_segment.size();
+        if size < self.quota_reservation.shared_memory_bytes() {
+            // shrinking the reservation cannot fail
+            let _ = self.quota_reservation.resize_shared_memory(size);
+        }
+    }
+
+    fn zero_chunk(&self, chunk: &ChunkMut) {
+        // the chunk is exclusively owned by the publisher until it is sent
+        unsafe { core::ptr::write_bytes(chunk.header, 0, chunk.size) };
+        self.number_of_zeroed_bytes
+            .fetch_add(chunk.size as u64, Ordering::Relaxed);
+    }
+
+    // releases the oldest sample of the history, the memory is reused as soon as no subscriber
+    // references the sample anymore
+    fn reclaim_oldest_history_sample(&self) -> bool {
+        match &self.history {
+            None => false,
+            Some(history) => match unsafe { &mut *history.get() }.pop() {
+                None => false,
+                Some(old) => {
+                    self.subscriber_connections
+                        .release_sample(PointerOffset::from_value(old.offset));
+                    true
+                }
+            },
+        }
+    }
+
+    pub(crate) fn return_loaned_sample(&self, offset: PointerOffset) {
+        self.subscriber_connections.return_loaned_sample(offset);
+        self.sample_resource_usage();
+    }
+
+    // the counters in the dynamic config are shared memory, loans and sends update them at
+    // most once per sampling interval to keep the writes out of the hot path
+    fn sample_resource_usage(&self) {
+        let is_due = match unsafe { &*self.last_resource_usage_update.get() } {
+            Some(last_update) => match last_update.elapsed() {
+                Ok(elapsed) => RESOURCE_USAGE_SAMPLING_INTERVAL <= elapsed,
+                Err(_) => true,
+            },
+            None => true,
+        };
+
+        if is_due {
+            self.update_resource_usage();
+        }
+    }
+
+    // the counters in the dynamic config are written only when a value has changed since
+    // the last update
+    fn update_resource_usage(&self) {
+        let index = self.resource_usage_index.load(Ordering::Relaxed);
+        if index == INVALID_RESOURCE_USAGE_INDEX {
+            return;
+        }
+
+        unsafe {
+            *self.last_resource_usage_update.get() = Time::now_with_clock(ClockType::Monotonic).ok()
+        };
+        self.release_unused_quota();
+
+        let usage = ResourceUsage {
+            number_of_loaned_samples: self
+                .subscriber_connections
+                .loan_counter
+                .load(Ordering::Relaxed),
+            allocated_data_segment_bytes: self.subscriber_connections.data_segment.size(),
+            number_of_discarded_samples: self
+                .subscriber_connections
+                .number_of_discarded_samples
+                .load(Ordering::Relaxed),
+            number_of_reclaimed_abandoned_samples: self
+                .subscriber_connections
+                .number_of_reclaimed_abandoned_samples
+                .load(Ordering::Relaxed),
+        };
+
+        let dynamic_config = self.service_state.dynamic_storage.get().publish_subscribe();
+        let published_usage = unsafe { &mut *self.published_resource_usage.get() };
+        if *published_usage != Some(usage) {
+            *published_usage = Some(usage);
+            dynamic_config.update_publisher_resource_usage(
+                index,
+                usage.number_of_loaned_samples,
+                usage.allocated_data_segment_bytes,
+                usage.number_of_discarded_samples,
+                usage.number_of_reclaimed_abandoned_samples,
+            );
+        }
+
+        self.subscriber_connections
+            .data_segment
+            .changed_bucket_usage(|size_class, usage| {
+                dynamic_config.update_publisher_size_class_usage(
+                    index,
+                    size_class,
+                    self.config.size_classes[size_class].max_slice_len,
+                    usage,
+                )
+            });
+    }
+
     fn add_sample_to_history(&self, offset: PointerOffset, sample_size: usize) {
         match &self.history {
             None => (),
@@ -222,7 +513,7 @@ impl<Service: service::Service> PublisherBackend<Service> {
                     h.index() as usize,
                     ReceiverDetails {
                         port_id: port.subscriber_id.value(),
-                        buffer_size: port.buffer_size,
+                        buffer_size: port.buffer_size as usize,
                     },
                     |connection| self.deliver_sample_history(connection),
                 );
@@ -236,6 +527,8 @@ impl<Service: service::Service> PublisherBackend<Service> {
         };
 
         self.subscriber_connections.finish_update_connection_cycle();
+        // new subscribers may have received the history
+        self.notify_waiting_subscribers();
 
         result
     }
@@ -278,7 +571,9 @@ impl<Service: service::Service> PublisherBackend<Service> {
                             }
                         }
                         Err(e) => {
-                            warn!(from self, "Failed to deliver history to new subscriber via {:?} due to {:?}", connection, e);
+                            warn!(from self,
+                                fields [subscriber_id = connection.receiver_port_id, error = e],
+                                "Failed to deliver history to new subscriber.");
                         }
                     }
                 }
@@ -286,8 +581,31 @@ impl<Service: service::Service> PublisherBackend<Service> {
         }
     }
 
+    pub(crate) fn new_header(&self, number_of_elements: u64) -> Header {
+        Header::new(
+            UniquePublisherId(UniqueSystemId::from(
+                self.subscriber_connections.sender_port_id,
+            )),
+            *self.service_state.shared_node.id(),
+            number_of_elements,
+        )
+    }
+
     pub(crate) fn send_sample(
         &self,
+        header: &mut Header,
+        offset: PointerOffset,
+        sample_size: usize,
+    ) -> Result<usize, SendError> {
+        let _log_scope = self.service_state.shared_node.log_scope();
+        // the pending write-combined values were sent before, they are delivered first
+        self.flush_pending_batch_if_due()?;
+        self.deliver_sample(header, offset, sample_size)
+    }
+
+    fn deliver_sample(
+        &self,
+        header: &mut Header,
         offset: PointerOffset,
         sample_size: usize,
     ) -> Result<usize, SendError> {
@@ -300,9 +618,82 @@ impl<Service: service::Service> PublisherBackend<Service> {
         fail!(from self, when self.update_connections(),
             "{} since the connections could not be updated.", msg);
 
+        header.set_send_details(
+            self.sequence_number.fetch_add(1, Ordering::Relaxed),
+            Time::now_with_clock(ClockType::Monotonic).unwrap_or_default(),
+        );
+
         self.add_sample_to_history(offset, sample_size);
-        self.subscriber_connections
-            .deliver_offset(offset, sample_size)
+        let number_of_recipients = self
+            .subscriber_connections
+            .deliver_offset(offset, sample_size);
+        self.notify_waiting_subscribers();
+        self.sample_resource_usage();
+        number_of_recipients
+    }
+
+    fn flush_pending_batch(&self) -> Result<usize, SendError> {
+        let _log_scope = self.service_state.shared_node.log_scope();
+        let batch = match unsafe { &mut *self.pending_batch.get() }.take() {
+            Some(batch) => batch,
+            None => return Ok(0),
+        };
+
+        unsafe { batch.header.write(self.new_header(batch.len as _)) };
+        let result = self.deliver_sample(
+            unsafe { &mut *batch.header },
+            batch.offset,
+            batch.sample_size,
+        );
+        self.return_loaned_sample(batch.offset);
+
+        result
+    }
+
+    fn flush_pending_batch_if_due(&self) -> Result<Option<usize>, SendError> {
+        let is_due = match unsafe { &*self.pending_batch.get() } {
+            Some(batch) => self.is_batch_window_elapsed(batch),
+            None => false,
+        };
+
+        if is_due {
+            Ok(Some(self.flush_pending_batch()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn remaining_batch_window(&self, batch: &PendingBatch) -> Duration {
+        match (self.config.write_combining, &batch.start) {
+            (Some(write_combining), Some(start)) => match start.elapsed() {
+                Ok(elapsed) => write_combining.window.saturating_sub(elapsed),
+                Err(_) => Duration::ZERO,
+            },
+            _ => Duration::ZERO,
+        }
+    }
+
+    fn is_batch_window_elapsed(&self, batch: &PendingBatch) -> bool {
+        self.remaining_batch_window(batch) == Duration::ZERO
+    }
+
+    // the subscriber marks itself as waiting before it checks its buffer for the last time.
+    // A sample that is delivered concurrently to the registration may be missed by both,
+    // the subscriber bounds its first wait so that it receives such a sample nevertheless,
+    // see Subscriber::wait_on_listener().
+    fn notify_waiting_subscribers(&self) {
+        // the waiting subscribers must be read after the sample was delivered
+        core::sync::atomic::compiler_fence(Ordering::SeqCst);
+        let dynamic_config = self.service_state.dynamic_storage.get().publish_subscribe();
+        if dynamic_config.number_of_waiting_subscribers() == 0 {
+            return;
+        }
+
+        for index in 0..self.subscriber_connections.len() {
+            if dynamic_config.is_subscriber_waiting(index) {
+                self.subscriber_connections.notify_receiver(index);
+            }
+        }
     }
 }
 
@@ -323,7 +714,18 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Drop
     for Publisher<Service, Payload, UserHeader>
 {
     fn drop(&mut self) {
+        let _log_scope = self.backend.service_state.shared_node.log_scope();
+        if let Err(e) = self.backend.flush_pending_batch() {
+            warn!(from self, fields [error = e],
+                "Unable to deliver the pending write-combined sample.");
+        }
+
         if let Some(handle) = self.dynamic_publisher_handle {
+            // samples can outlive the publisher, they must not update the usage of a
+            // publisher that may reuse the released slot
+            self.backend
+                .resource_usage_index
+                .store(INVALID_RESOURCE_USAGE_INDEX, Ordering::Relaxed);
             self.backend
                 .service_state
                 .dynamic_storage
@@ -340,8 +742,9 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
     pub(crate) fn new(
         service: &Service,
         static_config: &publish_subscribe::StaticConfig,
-        config: LocalPublisherConfig,
+        mut config: LocalPublisherConfig,
     ) -> Result<Self, PublisherCreateError> {
+        let _log_scope = service.__internal_state().shared_node.log_scope();
         let msg = "Unable to create Publisher port";
         let origin = "Publisher::new()";
         let port_id = UniquePublisherId::new();
@@ -361,25 +764,89 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         }
         .required_amount_of_samples_per_data_segment(config.max_loaned_samples);
 
-        let data_segment_type =
-            DataSegmentType::new_from_allocation_strategy(config.allocation_strategy);
+        // a dynamic data segment reallocates the buckets with the required size on demand
+        config.size_classes = match config.allocation_strategy {
+            AllocationStrategy::Static => effective_size_classes(&config, number_of_samples),
+            _ => vec![],
+        };
+
+        if config.size_classes.len() > MAX_NUMBER_OF_SIZE_CLASSES {
+            fail!(from origin, with PublisherCreateError::UnableToCreateDataSegment,
+                "{} since the {} size classes exceed the maximum supported number of size classes of {}.",
+                msg, config.size_classes.len(), MAX_NUMBER_OF_SIZE_CLASSES);
+        }
+
+        let size_class_layouts: Vec<(Layout, usize)> = config
+            .size_classes
+            .iter()
+            .map(|size_class| {
+                (
+                    static_config
+                        .message_type_details
+                        .sample_layout(size_class.max_slice_len),
+                    size_class.number_of_samples,
+                )
+            })
+            .collect();
+
+        let data_segment_type = match size_class_layouts.len() > 1 {
+            true => DataSegmentType::Pooled,
+            false => DataSegmentType::new_from_allocation_strategy(config.allocation_strategy),
+        };
 
         let sample_layout = static_config
             .message_type_details
             .sample_layout(config.initial_max_slice_len);
 
+        // every size class has its own segment, the segment with the most samples defines
+        // the number of samples per segment
+        let (number_of_samples_per_segment, data_segment_size) = match data_segment_type {
+            DataSegmentType::Pooled => (
+                size_class_layouts
+                    .iter()
+                    .map(|(_, n)| *n)
+                    .max()
+                    .unwrap_or(0),
+                size_class_layouts
+                    .iter()
+                    .map(|(layout, n)| layout.size() * n)
+                    .sum::<usize>(),
+            ),
+            _ => (number_of_samples, sample_layout.size() * number_of_samples),
+        };
+
         let max_slice_len = config.initial_max_slice_len;
-        let max_number_of_segments =
-            DataSegment::<Service>::max_number_of_segments(data_segment_type);
+        let max_number_of_segments = match data_segment_type {
+            DataSegmentType::Pooled => size_class_layouts.len() as u8,
+            _ => DataSegment::<Service>::max_number_of_segments(data_segment_type),
+        };
         let publisher_details = PublisherDetails {
             data_segment_type,
             publisher_id: port_id,
-            number_of_samples,
-            max_slice_len,
+            number_of_samples: number_of_samples_per_segment as u64,
+            max_slice_len: max_slice_len as u64,
             node_id: *service.__internal_state().shared_node.id(),
             max_number_of_segments,
         };
+        let quota_reservation = match service
+            .__internal_state()
+            .shared_node
+            .quota()
+            .acquire_port(data_segment_size)
+        {
+            Ok(reservation) => reservation,
+            Err(e) => {
+                fail!(from origin, with PublisherCreateError::ExceedsNodeQuota,
+                    "{} since it would exceed the quota of the node ({:?}).", msg, e);
+            }
+        };
+
         let global_config = service.__internal_state().shared_node.config();
+        let access_control_list = service
+            .__internal_state()
+            .static_config
+            .access_control_list()
+            .copied();
 
         let segment_name = data_segment_name(publisher_details.publisher_id.value());
         let data_segment = match data_segment_type {
@@ -388,6 +855,8 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                 sample_layout,
                 global_config,
                 number_of_samples,
+                config.data_segment_numa_node,
+                access_control_list,
             ),
             DataSegmentType::Dynamic => DataSegment::create_dynamic_segment(
                 &segment_name,
@@ -395,6 +864,15 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                 global_config,
                 number_of_samples,
                 config.allocation_strategy,
+                config.data_segment_numa_node,
+                access_control_list,
+            ),
+            DataSegmentType::Pooled => DataSegment::create_pooled_segment(
+                &segment_name,
+                &size_class_layouts,
+                global_config,
+                config.data_segment_numa_node,
+                access_control_list,
             ),
         };
 
@@ -403,8 +881,28 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                 with PublisherCreateError::UnableToCreateDataSegment,
                 "{} since the data segment could not be acquired.", msg);
 
+        // the reservation of a dynamic data segment must cover its actual size since every
+        // resize is accounted on top of it
+        if data_segment.is_resizable() {
+            if let Err(e) = quota_reservation.resize_shared_memory(data_segment.size()) {
+                fail!(from origin, with PublisherCreateError::ExceedsNodeQuota,
+                    "{} since the data segment would exceed the quota of the node ({:?}).", msg, e);
+            }
+        }
+
         let backend = Arc::new(PublisherBackend {
             is_active: IoxAtomicBool::new(true),
+            resource_usage_index: IoxAtomicUsize::new(INVALID_RESOURCE_USAGE_INDEX),
+            published_resource_usage: UnsafeCell::new(None),
+            last_resource_usage_update: UnsafeCell::new(None),
+            last_dead_node_sweep: UnsafeCell::new(None),
+            sequence_number: IoxAtomicU64::new(0),
+            out_of_memory_strategy: static_config.out_of_memory_strategy,
+            number_of_reclaimed_samples: IoxAtomicU64::new(0),
+            zero_payload_on_loan: static_config.zero_payload_on_loan,
+            number_of_zeroed_bytes: IoxAtomicU64::new(0),
+            is_degraded: IoxAtomicBool::new(false),
+            pending_batch: UnsafeCell::new(None),
             service_state: service.__internal_state().clone(),
             subscriber_connections: OutgoingConnections {
                 data_segment,
@@ -412,7 +910,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                     let mut v: Vec<SegmentState> =
                         Vec::with_capacity(max_number_of_segments as usize);
                     for _ in 0..max_number_of_segments {
-                        v.push(SegmentState::new(number_of_samples))
+                        v.push(SegmentState::new(number_of_samples_per_segment))
                     }
                     v
                 },
@@ -424,12 +922,14 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                 receiver_max_buffer_size: static_config.subscriber_max_buffer_size,
                 receiver_max_borrowed_samples: static_config.subscriber_max_borrowed_samples,
                 enable_safe_overflow: static_config.enable_safe_overflow,
-                number_of_samples,
+                number_of_samples: number_of_samples_per_segment,
                 max_number_of_segments,
                 degration_callback: None,
                 service_state: service.__internal_state().clone(),
                 visitor: Visitor::new(),
                 loan_counter: IoxAtomicUsize::new(0),
+                number_of_discarded_samples: IoxAtomicU64::new(0),
+                number_of_reclaimed_abandoned_samples: IoxAtomicU64::new(0),
                 sender_max_borrowed_samples: config.max_loaned_samples,
                 unable_to_deliver_strategy: config.unable_to_deliver_strategy,
                 message_type_details: static_config.message_type_details.clone(),
@@ -440,6 +940,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                 true => None,
                 false => Some(UnsafeCell::new(Queue::new(static_config.history_size))),
             },
+            quota_reservation,
         });
 
         let mut new_self = Self {
@@ -450,7 +951,8 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         };
 
         if let Err(e) = new_self.backend.force_update_connections() {
-            warn!(from new_self, "The new Publisher port is unable to connect to every Subscriber port, caused by {:?}.", e);
+            warn!(from new_self, fields [error = e],
+                "The new Publisher port is unable to connect to every Subscriber port.");
         }
 
         core::sync::atomic::compiler_fence(Ordering::SeqCst);
@@ -473,6 +975,11 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         };
 
         new_self.dynamic_publisher_handle = Some(dynamic_publisher_handle);
+        new_self
+            .backend
+            .resource_usage_index
+            .store(dynamic_publisher_handle.index() as usize, Ordering::Relaxed);
+        new_self.backend.update_resource_usage();
 
         Ok(new_self)
     }
@@ -492,6 +999,75 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             .unable_to_deliver_strategy
     }
 
+    /// Returns the strategy the [`Publisher`] follows when its data segment is exhausted.
+    pub fn out_of_memory_strategy(&self) -> OutOfMemoryStrategy {
+        self.backend.out_of_memory_strategy
+    }
+
+    /// Returns how many history [`SampleMut`]s the [`Publisher`] reclaimed so far since its
+    /// data segment was exhausted, see [`OutOfMemoryStrategy::ReclaimHistory`].
+    pub fn number_of_reclaimed_samples(&self) -> u64 {
+        self.backend
+            .number_of_reclaimed_samples
+            .load(Ordering::Relaxed)
+    }
+
+    /// Returns how many [`SampleMut`]s the [`Publisher`] reclaimed so far from
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s whose [`Node`](crate::node::Node)
+    /// died. Samples that are returned when a [`Subscriber`](crate::port::subscriber::Subscriber)
+    /// disconnects regularly are not counted.
+    pub fn number_of_reclaimed_abandoned_samples(&self) -> u64 {
+        self.backend
+            .subscriber_connections
+            .number_of_reclaimed_abandoned_samples
+            .load(Ordering::Relaxed)
+    }
+
+    /// Returns true if the [`Publisher`] zeroes the memory of every [`SampleMut`] on loan, see
+    /// [`crate::service::builder::publish_subscribe::Builder::zero_payload_on_loan()`].
+    pub fn zero_payload_on_loan(&self) -> bool {
+        self.backend.zero_payload_on_loan
+    }
+
+    /// Returns how many bytes the [`Publisher`] zeroed so far on loan. It makes the cost of
+    /// [`Publisher::zero_payload_on_loan()`] visible.
+    pub fn number_of_zeroed_bytes(&self) -> u64 {
+        self.backend.number_of_zeroed_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Returns true when the last successful loan of the [`Publisher`] required to reclaim
+    /// history [`SampleMut`]s since the data segment was exhausted.
+    pub fn is_degraded(&self) -> bool {
+        self.backend.is_degraded.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of [`SampleMut`]s that could not be delivered or that were overridden
+    /// since the buffer of a [`Subscriber`](crate::port::subscriber::Subscriber) was full.
+    pub fn number_of_discarded_samples(&self) -> u64 {
+        self.backend
+            .subscriber_connections
+            .number_of_discarded_samples
+            .load(Ordering::Relaxed)
+    }
+
+    /// Iterates over all connected [`Subscriber`](crate::port::subscriber::Subscriber)s and
+    /// provides the number of [`SampleMut`]s that were discarded for the corresponding
+    /// connection to the callback. It can be used to identify slow
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s.
+    pub fn list_discarded_samples<F: FnMut(UniqueSubscriberId, u64) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        self.backend.subscriber_connections.list_discarded_samples(
+            |port_id, number_of_discarded_samples| {
+                callback(
+                    UniqueSubscriberId(UniqueSystemId::from(port_id)),
+                    number_of_discarded_samples,
+                )
+            },
+        );
+    }
+
     /// Returns the maximum initial slice length configured for this [`Publisher`].
     pub fn initial_max_slice_len(&self) -> usize {
         self.backend.config.initial_max_slice_len
@@ -566,10 +1142,9 @@ impl<Service: service::Service, Payload: Debug + Sized, UserHeader: Debug>
     ) -> Result<SampleMutUninit<Service, MaybeUninit<Payload>, UserHeader>, LoanError> {
         let chunk = self
             .backend
-            .subscriber_connections
             .allocate(self.backend.subscriber_connections.sample_layout(1))?;
         let header_ptr = chunk.header as *mut Header;
-        unsafe { header_ptr.write(Header::new(self.id(), 1)) };
+        unsafe { header_ptr.write(self.backend.new_header(1)) };
 
         let sample = unsafe {
             RawSampleMut::new_unchecked(header_ptr, chunk.user_header.cast(), chunk.payload.cast())
@@ -585,6 +1160,20 @@ impl<Service: service::Service, Payload: Debug + Sized, UserHeader: Debug>
     }
 }
 
+impl<Service: service::Service, Payload: Debug + TaggedUnion, UserHeader: Debug>
+    Publisher<Service, Payload, UserHeader>
+{
+    /// Wraps the `value` into its variant of the [`TaggedUnion`] payload and delivers it.
+    /// Only types that are a variant of the payload can be sent.
+    /// See [`Publisher::send_copy()`].
+    pub fn send_variant<T: TaggedUnionVariant<Payload>>(
+        &self,
+        value: T,
+    ) -> Result<usize, SendError> {
+        self.send_copy(value.into_union())
+    }
+}
+
 impl<Service: service::Service, Payload: Default + Debug + Sized, UserHeader: Debug>
     Publisher<Service, Payload, UserHeader>
 {
@@ -709,6 +1298,72 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
         unsafe { self.loan_slice_uninit_impl(slice_len, slice_len) }
     }
 
+    /// Delivers the pending write-combined sample, independent of its size or age. Returns
+    /// the number of [`crate::port::subscriber::Subscriber`]s that received the sample or
+    /// 0 when no values were pending.
+    pub fn flush_combined(&self) -> Result<usize, SendError> {
+        self.backend.flush_pending_batch()
+    }
+
+    /// Returns the number of values that were sent with [`Publisher::send_combined()`] but
+    /// are not yet delivered.
+    pub fn number_of_pending_combined_values(&self) -> usize {
+        match unsafe { &*self.backend.pending_batch.get() } {
+            Some(batch) => batch.len,
+            None => 0,
+        }
+    }
+
+    /// Delivers the pending write-combined sample when its write-combining window has
+    /// elapsed. Returns the number of [`crate::port::subscriber::Subscriber`]s that received
+    /// the sample or [`None`] when no sample was due.
+    ///
+    /// The window is verified on every loan and send of the [`Publisher`] and the pending
+    /// sample is delivered when the [`Publisher`] is dropped. A [`Publisher`] that goes quiet
+    /// shall call this after the [`Publisher::combined_flush_timeout()`] has passed, for
+    /// instance with
+    /// [`WaitSet::wait_and_process_once_with_timeout()`](crate::waitset::WaitSet::wait_and_process_once_with_timeout()),
+    /// so that pending values are delivered within the window.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use core::time::Duration;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u64]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let publisher = service.publisher_builder()
+    ///                        .write_combining(64, Duration::from_millis(1))
+    ///                        .create()?;
+    ///
+    /// publisher.send_combined(1)?;
+    ///
+    /// // no further values are sent, wait until the pending sample is due
+    /// if let Some(timeout) = publisher.combined_flush_timeout() {
+    ///     std::thread::sleep(timeout);
+    /// }
+    /// assert!(publisher.flush_combined_if_due()?.is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn flush_combined_if_due(&self) -> Result<Option<usize>, SendError> {
+        self.backend.flush_pending_batch_if_due()
+    }
+
+    /// Returns the time until the write-combining window of the pending write-combined
+    /// sample has elapsed and it shall be delivered with
+    /// [`Publisher::flush_combined_if_due()`]. Returns [`None`] when no values are pending.
+    pub fn combined_flush_timeout(&self) -> Option<Duration> {
+        unsafe { &*self.backend.pending_batch.get() }
+            .as_ref()
+            .map(|batch| self.backend.remaining_batch_window(batch))
+    }
+
     unsafe fn loan_slice_uninit_impl(
         &self,
         slice_len: usize,
@@ -724,19 +1379,16 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
         }
 
         let sample_layout = self.backend.subscriber_connections.sample_layout(slice_len);
-        let chunk = self
-            .backend
-            .subscriber_connections
-            .allocate(sample_layout)?;
+        let chunk = self.backend.allocate(sample_layout)?;
         let header_ptr = chunk.header as *mut Header;
-        unsafe { header_ptr.write(Header::new(self.id(), slice_len as _)) };
+        unsafe { header_ptr.write(self.backend.new_header(slice_len as _)) };
 
         let sample = unsafe {
             RawSampleMut::new_unchecked(
                 header_ptr,
                 chunk.user_header.cast(),
-                core::slice::from_raw_parts_mut(
-                    chunk.payload.cast(),
+                core::ptr::slice_from_raw_parts_mut(
+                    chunk.payload.cast::<MaybeUninit<Payload>>(),
                     underlying_number_of_slice_elements,
                 ),
             )
@@ -753,6 +1405,97 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
     }
 }
 
+impl<Service: service::Service, Payload: Debug, UserHeader: Default + Debug>
+    Publisher<Service, [Payload], UserHeader>
+{
+    /// Adds the `value` to the pending write-combined sample. The sample is delivered when it
+    /// reaches the `max_batch_len` or the write-combining window, both defined with
+    /// [`PortFactoryPublisher::write_combining()`](crate::service::port_factory::publisher::PortFactoryPublisher::write_combining()),
+    /// has elapsed. The window is verified on every loan and send of the [`Publisher`], use
+    /// [`Publisher::flush_combined_if_due()`] to deliver the sample when the [`Publisher`]
+    /// goes quiet. The delivered sample contains all combined values in the order they were
+    /// sent.
+    ///
+    /// Returns the number of [`crate::port::subscriber::Subscriber`]s that received the
+    /// sample when it was delivered, otherwise [`None`]. When write-combining is not enabled
+    /// every value is delivered immediately as sample with one element. The user header of
+    /// the delivered sample is initialized with [`Default::default()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use core::time::Duration;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u64]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let publisher = service.publisher_builder()
+    ///                        .write_combining(3, Duration::from_millis(1))
+    ///                        .create()?;
+    ///
+    /// assert!(publisher.send_combined(1)?.is_none());
+    /// assert!(publisher.send_combined(2)?.is_none());
+    /// // the third value completes the batch and delivers it
+    /// assert!(publisher.send_combined(3)?.is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_combined(&self, value: Payload) -> Result<Option<usize>, SendError> {
+        // required since Rust does not support generic specializations or negative traits
+        debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
+
+        let msg = "Unable to send write-combined value";
+        let pending_batch = unsafe { &mut *self.backend.pending_batch.get() };
+        if pending_batch.is_none() {
+            *pending_batch = Some(fail!(from self, when self.loan_batch(),
+                "{} since the loan of a sample failed.", msg));
+        }
+
+        let is_complete = match pending_batch {
+            Some(batch) => {
+                unsafe { (batch.payload as *mut Payload).add(batch.len).write(value) };
+                batch.len += 1;
+                batch.len == batch.capacity || self.backend.is_batch_window_elapsed(batch)
+            }
+            None => false,
+        };
+
+        if is_complete {
+            Ok(Some(self.backend.flush_pending_batch()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn loan_batch(&self) -> Result<PendingBatch, LoanError> {
+        let (capacity, start) = match self.backend.config.write_combining {
+            Some(write_combining) => (
+                write_combining.max_batch_len,
+                Time::now_with_clock(ClockType::Monotonic).ok(),
+            ),
+            None => (1, None),
+        };
+
+        let sample_layout = self.backend.subscriber_connections.sample_layout(capacity);
+        let chunk = self.backend.allocate(sample_layout)?;
+        unsafe { (chunk.user_header as *mut UserHeader).write(UserHeader::default()) };
+
+        Ok(PendingBatch {
+            offset: chunk.offset,
+            sample_size: chunk.size,
+            header: chunk.header as *mut Header,
+            payload: chunk.payload,
+            len: 0,
+            capacity,
+            start,
+        })
+    }
+}
+
 impl<Service: service::Service, UserHeader: Debug>
     Publisher<Service, [CustomPayloadMarker], UserHeader>
 {
@@ -790,6 +1533,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Upda
     for Publisher<Service, Payload, UserHeader>
 {
     fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.backend.update_resource_usage();
         self.backend.update_connections()
     }
 }
@@ -804,15 +1548,56 @@ pub(crate) unsafe fn remove_data_segment_of_publisher<Service: service::Service>
         port_id
     );
 
+    let segment_name = data_segment_name(port_id.value());
     fail!(from origin, when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
-            &data_segment_name(port_id.value()),
+            &segment_name,
             &data_segment_config::<Service>(config),
         ), "Unable to remove the publishers data segment."
     );
 
+    for size_class in 0..MAX_NUMBER_OF_SIZE_CLASSES {
+        fail!(from origin, when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
+                &size_class_data_segment_name(&segment_name, size_class as u8),
+                &data_segment_config::<Service>(config),
+            ), "Unable to remove the data segment of the size class {} of the publisher.", size_class
+        );
+    }
+
     Ok(())
 }
 
+// Orders the configured size classes by their slice length and adds the largest size class
+// of `initial_max_slice_len` that contains the remaining samples, but at least one.
+fn effective_size_classes(
+    config: &LocalPublisherConfig,
+    number_of_samples: usize,
+) -> Vec<SizeClass> {
+    let mut size_classes: Vec<SizeClass> = config
+        .size_classes
+        .iter()
+        .filter(|size_class| {
+            size_class.max_slice_len < config.initial_max_slice_len
+                && size_class.number_of_samples > 0
+        })
+        .copied()
+        .collect();
+    size_classes.sort_by_key(|size_class| size_class.max_slice_len);
+    size_classes.dedup_by_key(|size_class| size_class.max_slice_len);
+
+    let number_of_samples_in_smaller_size_classes: usize = size_classes
+        .iter()
+        .map(|size_class| size_class.number_of_samples)
+        .sum();
+    size_classes.push(SizeClass {
+        max_slice_len: config.initial_max_slice_len,
+        number_of_samples: number_of_samples
+            .saturating_sub(number_of_samples_in_smaller_size_classes)
+            .max(1),
+    });
+
+    size_classes
+}
+
 fn connections<Service: service::Service>(
     origin: &str,
     msg: &str,
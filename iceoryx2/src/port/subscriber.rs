@@ -13,6 +13,7 @@
 //! # Example
 //!
 //! ```
+//! use core::time::Duration;
 //! use iceoryx2::prelude::*;
 //!
 //! # fn main() -> Result<(), Box<dyn core::error::Error>> {
@@ -27,6 +28,11 @@
 //!     println!("received: {:?}", *sample);
 //! }
 //!
+//! // waits at most one millisecond for the next sample
+//! if let Some(sample) = subscriber.timed_receive(Duration::from_millis(1))? {
+//!     println!("received: {:?}", *sample);
+//! }
+//!
 //! # Ok(())
 //! # }
 //! ```
@@ -36,20 +42,30 @@ use core::cell::UnsafeCell;
 use core::fmt::Debug;
 use core::marker::PhantomData;
 use core::sync::atomic::Ordering;
+use core::time::Duration;
+use std::time::Instant;
 
 extern crate alloc;
+use alloc::vec::Vec;
 
 use iceoryx2_bb_container::queue::Queue;
 use iceoryx2_bb_elementary::visitor::Visitor;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
-use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::event::{Event, Listener, ListenerBuilder, ListenerWaitError, TriggerId};
+use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptMgmt, NamedConceptRemoveError};
+use iceoryx2_cal::zero_copy_connection::{ZeroCopyReceiver, ZeroCopyReleaseError};
 
+use crate::config::Config;
+use crate::node::quota::QuotaReservation;
 use crate::service::builder::publish_subscribe::CustomPayloadMarker;
+use crate::service::config_scheme::event_config;
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
 use crate::service::header::publish_subscribe::Header;
+use crate::service::naming_scheme::receiver_event_concept_name;
 use crate::service::port_factory::subscriber::SubscriberConfig;
 use crate::service::static_config::publish_subscribe::StaticConfig;
 use crate::{raw_sample::RawSample, sample::Sample, service};
@@ -57,10 +73,17 @@ use crate::{raw_sample::RawSample, sample::Sample, service};
 use super::details::chunk::Chunk;
 use super::details::chunk_details::ChunkDetails;
 use super::details::incoming_connections::*;
-use super::port_identifiers::UniqueSubscriberId;
+use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
 use super::update_connections::{ConnectionFailure, UpdateConnections};
+use super::wait_strategy::WaitStrategy;
 use super::ReceiveError;
 
+// Publishers read the waiting subscribers without a fence, a sample that is delivered while
+// the subscriber registers as waiting may therefore be delivered without a notification.
+// The first wait after the registration is bounded by this period so that such a sample is
+// received nevertheless.
+const WAIT_REGISTRATION_GRACE_PERIOD: Duration = Duration::from_millis(1);
+
 /// Describes the failures when a new [`Subscriber`] is created via the
 /// [`crate::service::port_factory::subscriber::PortFactorySubscriber`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -73,6 +96,9 @@ pub enum SubscriberCreateError {
     /// When the [`Subscriber`] requires a larger buffer size than the
     /// [`Service`](crate::service::Service) offers the creation will fail.
     BufferSizeExceedsMaxSupportedBufferSizeOfService,
+    /// The [`Subscriber`] cannot be created since it would exceed the
+    /// [`NodeQuota`](crate::node::quota::NodeQuota) of the [`Node`](crate::node::Node).
+    ExceedsNodeQuota,
 }
 
 impl core::fmt::Display for SubscriberCreateError {
@@ -94,6 +120,11 @@ pub struct Subscriber<
     publisher_connections: IncomingConnections<Service>,
 
     publisher_list_state: UnsafeCell<ContainerState<PublisherDetails>>,
+    wait_strategy: WaitStrategy,
+    listener: UnsafeCell<Option<<Service::Event as Event>::Listener>>,
+    receive_by_priority: bool,
+    pending_samples: UnsafeCell<Vec<(ChunkDetails<Service>, Chunk)>>,
+    _quota_reservation: QuotaReservation,
     _payload: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
@@ -102,6 +133,13 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Drop
     for Subscriber<Service, Payload, UserHeader>
 {
     fn drop(&mut self) {
+        let _log_scope = self
+            .publisher_connections
+            .service_state
+            .shared_node
+            .log_scope();
+        self.release_pending_samples();
+
         if let Some(handle) = self.dynamic_subscriber_handle {
             self.publisher_connections
                 .service_state
@@ -121,6 +159,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         static_config: &StaticConfig,
         config: SubscriberConfig,
     ) -> Result<Self, SubscriberCreateError> {
+        let _log_scope = service.__internal_state().shared_node.log_scope();
         let msg = "Failed to create Subscriber port";
         let origin = "Subscriber::new()";
         let subscriber_id = UniqueSubscriberId::new();
@@ -144,6 +183,19 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             None => static_config.subscriber_max_buffer_size,
         };
 
+        let quota_reservation = match service
+            .__internal_state()
+            .shared_node
+            .quota()
+            .acquire_port(0)
+        {
+            Ok(reservation) => reservation,
+            Err(e) => {
+                fail!(from origin, with SubscriberCreateError::ExceedsNodeQuota,
+                    "{} since it would exceed the quota of the node ({:?}).", msg, e);
+            }
+        };
+
         let publisher_connections = IncomingConnections {
             connections: (0..publisher_list.capacity())
                 .map(|_| UnsafeCell::new(None))
@@ -171,12 +223,18 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             publisher_connections,
             publisher_list_state: UnsafeCell::new(unsafe { publisher_list.get_state() }),
             dynamic_subscriber_handle: None,
+            wait_strategy: config.wait_strategy,
+            listener: UnsafeCell::new(None),
+            receive_by_priority: config.receive_by_priority,
+            pending_samples: UnsafeCell::new(Vec::new()),
+            _quota_reservation: quota_reservation,
             _payload: PhantomData,
             _user_header: PhantomData,
         };
 
         if let Err(e) = new_self.force_update_connections() {
-            warn!(from new_self, "The new subscriber is unable to connect to every publisher, caused by {:?}.", e);
+            warn!(from new_self, fields [error = e],
+                "The new subscriber is unable to connect to every publisher.");
         }
 
         core::sync::atomic::compiler_fence(Ordering::SeqCst);
@@ -190,7 +248,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             .publish_subscribe()
             .add_subscriber_id(SubscriberDetails {
                 subscriber_id,
-                buffer_size,
+                buffer_size: buffer_size as u64,
                 node_id: *service.__internal_state().shared_node.id(),
             }) {
             Some(unique_index) => unique_index,
@@ -216,7 +274,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                     h.index() as usize,
                     SenderDetails {
                         port_id: details.publisher_id.value(),
-                        number_of_samples: details.number_of_samples,
+                        number_of_samples: details.number_of_samples as usize,
                         max_number_of_segments: details.max_number_of_segments,
                         data_segment_type: details.data_segment_type,
                     },
@@ -246,21 +304,234 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         self.publisher_connections.buffer_size
     }
 
+    /// Returns the number of [`Sample`]s the [`Subscriber`] missed since its buffer was full,
+    /// accumulated over all currently connected
+    /// [`Publisher`](crate::port::publisher::Publisher)s.
+    pub fn number_of_missed_samples(&self) -> u64 {
+        let mut number_of_missed_samples = 0;
+        self.list_missed_samples(|_, n| {
+            number_of_missed_samples += n;
+            CallbackProgression::Continue
+        });
+        number_of_missed_samples
+    }
+
+    /// Iterates over all connected [`Publisher`](crate::port::publisher::Publisher)s and
+    /// provides the number of [`Sample`]s that the [`Subscriber`] missed from the
+    /// corresponding [`Publisher`](crate::port::publisher::Publisher) to the callback.
+    pub fn list_missed_samples<F: FnMut(UniquePublisherId, u64) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        self.publisher_connections
+            .list_missed_samples(|port_id, number_of_missed_samples| {
+                callback(
+                    UniquePublisherId(UniqueSystemId::from(port_id)),
+                    number_of_missed_samples,
+                )
+            });
+    }
+
     /// Returns true if the [`Subscriber`] has samples in the buffer that can be received with [`Subscriber::receive`].
     pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
+        let _log_scope = self
+            .publisher_connections
+            .service_state
+            .shared_node
+            .log_scope();
         fail!(from self, when self.update_connections(),
                 "Some samples are not being received since not all connections to publishers could be established.");
+        if unsafe { !(*self.pending_samples.get()).is_empty() } {
+            return Ok(true);
+        }
+
         self.publisher_connections.has_samples()
     }
 
     fn receive_impl(&self) -> Result<Option<(ChunkDetails<Service>, Chunk)>, ReceiveError> {
+        let _log_scope = self
+            .publisher_connections
+            .service_state
+            .shared_node
+            .log_scope();
         if let Err(e) = self.update_connections() {
             fail!(from self,
                 with ReceiveError::ConnectionFailure(e),
                 "Some samples are not being received since not all connections to publishers could be established.");
         }
 
-        self.publisher_connections.receive()
+        if self.receive_by_priority {
+            self.receive_by_priority_impl()
+        } else {
+            self.publisher_connections.receive()
+        }
+    }
+
+    // moves all samples that can be borrowed into the pending samples and returns the oldest
+    // sample with the highest priority
+    fn receive_by_priority_impl(
+        &self,
+    ) -> Result<Option<(ChunkDetails<Service>, Chunk)>, ReceiveError> {
+        let _log_scope = self
+            .publisher_connections
+            .service_state
+            .shared_node
+            .log_scope();
+        let pending_samples = unsafe { &mut *self.pending_samples.get() };
+
+        loop {
+            match self.publisher_connections.receive() {
+                Ok(Some(chunk)) => pending_samples.push(chunk),
+                Ok(None) => break,
+                Err(ReceiveError::ExceedsMaxBorrowedSamples) if !pending_samples.is_empty() => {
+                    break
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        let mut selected: Option<(usize, u8)> = None;
+        for (n, (_, chunk)) in pending_samples.iter().enumerate() {
+            let priority = unsafe { (*(chunk.header as *const Header)).priority() };
+            match selected {
+                Some((_, selected_priority)) if selected_priority >= priority => (),
+                _ => selected = Some((n, priority)),
+            }
+        }
+
+        Ok(selected.map(|(n, _)| pending_samples.remove(n)))
+    }
+
+    fn release_pending_samples(&mut self) {
+        for (details, _) in self.pending_samples.get_mut().drain(..) {
+            unsafe {
+                details
+                    .connection
+                    .data_segment
+                    .unregister_offset(details.offset)
+            };
+
+            match details.connection.receiver.release(details.offset) {
+                Ok(()) => (),
+                Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                    warn!(from self.publisher_connections, fields [publisher_id = details.origin],
+                        "Unable to return a pending sample to publisher since its retrieve channel is full.");
+                }
+            }
+        }
+    }
+
+    // waits according to the configured wait strategy, the blocking part waits on the event
+    // listener of the subscriber until a publisher delivered a sample or the timeout has passed
+    fn blocking_receive_impl(
+        &self,
+        timeout: Option<Duration>,
+    ) -> Result<Option<(ChunkDetails<Service>, Chunk)>, ReceiveError> {
+        let _log_scope = self
+            .publisher_connections
+            .service_state
+            .shared_node
+            .log_scope();
+        let start = Instant::now();
+
+        if let Some(chunk) = self.wait_strategy.poll(timeout, || self.receive_impl())? {
+            return Ok(Some(chunk));
+        }
+
+        if !self.wait_strategy.blocks() {
+            return Ok(None);
+        }
+
+        let listener = self.listener()?;
+        let index = match self.dynamic_subscriber_handle {
+            Some(handle) => handle.index() as usize,
+            None => {
+                fatal_panic!(from self, "This should never happen! The subscriber waits without being registered in the service.")
+            }
+        };
+        let dynamic_config = self
+            .publisher_connections
+            .service_state
+            .dynamic_storage
+            .get()
+            .publish_subscribe();
+
+        dynamic_config.set_subscriber_waiting(index, true);
+        let result = self.wait_on_listener(listener, start, timeout);
+        dynamic_config.set_subscriber_waiting(index, false);
+
+        result
+    }
+
+    fn wait_on_listener(
+        &self,
+        listener: &<Service::Event as Event>::Listener,
+        start: Instant,
+        timeout: Option<Duration>,
+    ) -> Result<Option<(ChunkDetails<Service>, Chunk)>, ReceiveError> {
+        let msg = "Unable to wait for samples";
+        let mut grace_period = Some(WAIT_REGISTRATION_GRACE_PERIOD);
+
+        loop {
+            // the subscriber is marked as waiting, a sample that is delivered from now on
+            // wakes up the listener
+            if let Some(chunk) = self.receive_impl()? {
+                return Ok(Some(chunk));
+            }
+
+            let remaining = match timeout {
+                Some(timeout) => match timeout.checked_sub(start.elapsed()) {
+                    Some(remaining) => Some(remaining),
+                    None => return self.receive_impl(),
+                },
+                None => None,
+            };
+
+            let result = match (remaining, grace_period.take()) {
+                (Some(remaining), Some(grace_period)) => {
+                    listener.timed_wait_all(|_| {}, remaining.min(grace_period))
+                }
+                (Some(wait_time), None) | (None, Some(wait_time)) => {
+                    listener.timed_wait_all(|_| {}, wait_time)
+                }
+                (None, None) => listener.blocking_wait_all(|_| {}),
+            };
+
+            match result {
+                Ok(()) | Err(ListenerWaitError::InterruptSignal) => (),
+                Err(e) => {
+                    fail!(from self, with ReceiveError::InternalFailure,
+                        "{} since the underlying event listener failed to wait ({:?}).", msg, e);
+                }
+            }
+        }
+    }
+
+    // the listener is created with the first blocking wait, publishers open a notifier only
+    // for subscribers that wait
+    fn listener(&self) -> Result<&<Service::Event as Event>::Listener, ReceiveError> {
+        let listener = unsafe { &mut *self.listener.get() };
+        if listener.is_none() {
+            let event_name =
+                receiver_event_concept_name(self.publisher_connections.receiver_port_id());
+            *listener = Some(fail!(from self,
+                when <Service::Event as Event>::ListenerBuilder::new(&event_name)
+                    .config(&event_config::<Service>(
+                        self.publisher_connections.service_state.shared_node.config(),
+                    ))
+                    .trigger_id_max(TriggerId::new(0))
+                    .create(),
+                with ReceiveError::InternalFailure,
+                "Unable to wait for samples since the underlying event concept \"{}\" could not be created.",
+                event_name));
+        }
+
+        match listener {
+            Some(listener) => Ok(listener),
+            None => {
+                fatal_panic!(from self, "This should never happen! The listener of the subscriber is not initialized.")
+            }
+        }
     }
 }
 
@@ -291,7 +562,56 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
     /// Receives a [`crate::sample::Sample`] from [`crate::port::publisher::Publisher`]. If no sample could be
     /// received [`None`] is returned. If a failure occurs [`ReceiveError`] is returned.
     pub fn receive(&self) -> Result<Option<Sample<Service, Payload, UserHeader>>, ReceiveError> {
-        Ok(self.receive_impl()?.map(|(details, chunk)| Sample {
+        Ok(self.receive_impl()?.map(Self::to_sample))
+    }
+
+    /// Returns a [`Future`](core::future::Future) that is ready as soon as a
+    /// [`crate::sample::Sample`] was received. The pending [`Future`](core::future::Future)
+    /// is woken up by the [`default_reactor()`](crate::future::default_reactor()).
+    #[cfg(feature = "async")]
+    pub fn receive_async(&self) -> crate::future::ReceiveFuture<'_, Service, Payload, UserHeader> {
+        self.receive_async_with(crate::future::default_reactor())
+    }
+
+    /// Like [`Subscriber::receive_async()`] but the pending [`Future`](core::future::Future)
+    /// is woken up by the provided [`AsyncReactor`](crate::future::AsyncReactor).
+    #[cfg(feature = "async")]
+    pub fn receive_async_with<'a>(
+        &'a self,
+        reactor: &'a dyn crate::future::AsyncReactor,
+    ) -> crate::future::ReceiveFuture<'a, Service, Payload, UserHeader> {
+        crate::future::ReceiveFuture::new(self, reactor)
+    }
+
+    /// Blocks until a [`crate::sample::Sample`] from a [`crate::port::publisher::Publisher`]
+    /// was received. If a failure occurs [`ReceiveError`] is returned.
+    /// See [`PortFactorySubscriber::wait_strategy()`](crate::service::port_factory::subscriber::PortFactorySubscriber::wait_strategy())
+    /// for the waiting strategy.
+    pub fn blocking_receive(&self) -> Result<Sample<Service, Payload, UserHeader>, ReceiveError> {
+        match self.blocking_receive_impl(None)? {
+            Some(v) => Ok(Self::to_sample(v)),
+            None => {
+                fatal_panic!(from self, "This should never happen! The blocking receive returned without a sample.")
+            }
+        }
+    }
+
+    /// Blocks until a [`crate::sample::Sample`] from a [`crate::port::publisher::Publisher`]
+    /// was received or the timeout has passed. If no sample could be received until the timeout
+    /// has passed [`None`] is returned. If a failure occurs [`ReceiveError`] is returned.
+    pub fn timed_receive(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Sample<Service, Payload, UserHeader>>, ReceiveError> {
+        Ok(self
+            .blocking_receive_impl(Some(timeout))?
+            .map(Self::to_sample))
+    }
+
+    fn to_sample(
+        (details, chunk): (ChunkDetails<Service>, Chunk),
+    ) -> Sample<Service, Payload, UserHeader> {
+        Sample {
             details,
             ptr: unsafe {
                 RawSample::new_unchecked(
@@ -300,7 +620,7 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
                     chunk.payload.cast(),
                 )
             },
-        }))
+        }
     }
 }
 
@@ -312,21 +632,54 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
     pub fn receive(&self) -> Result<Option<Sample<Service, [Payload], UserHeader>>, ReceiveError> {
         debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
 
-        Ok(self.receive_impl()?.map(|(details, chunk)| {
-            let header_ptr = chunk.header as *const Header;
-            let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
+        Ok(self.receive_impl()?.map(Self::to_sample))
+    }
 
-            Sample {
-                details,
-                ptr: unsafe {
-                    RawSample::<Header, UserHeader, [Payload]>::new_slice_unchecked(
-                        header_ptr,
-                        chunk.user_header.cast(),
-                        core::slice::from_raw_parts(chunk.payload.cast(), number_of_elements as _),
-                    )
-                },
+    /// Blocks until a [`crate::sample::Sample`] from a [`crate::port::publisher::Publisher`]
+    /// was received. If a failure occurs [`ReceiveError`] is returned.
+    /// See [`PortFactorySubscriber::wait_strategy()`](crate::service::port_factory::subscriber::PortFactorySubscriber::wait_strategy())
+    /// for the waiting strategy.
+    pub fn blocking_receive(&self) -> Result<Sample<Service, [Payload], UserHeader>, ReceiveError> {
+        debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
+
+        match self.blocking_receive_impl(None)? {
+            Some(v) => Ok(Self::to_sample(v)),
+            None => {
+                fatal_panic!(from self, "This should never happen! The blocking receive returned without a sample.")
             }
-        }))
+        }
+    }
+
+    /// Blocks until a [`crate::sample::Sample`] from a [`crate::port::publisher::Publisher`]
+    /// was received or the timeout has passed. If no sample could be received until the timeout
+    /// has passed [`None`] is returned. If a failure occurs [`ReceiveError`] is returned.
+    pub fn timed_receive(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Sample<Service, [Payload], UserHeader>>, ReceiveError> {
+        debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
+
+        Ok(self
+            .blocking_receive_impl(Some(timeout))?
+            .map(Self::to_sample))
+    }
+
+    fn to_sample(
+        (details, chunk): (ChunkDetails<Service>, Chunk),
+    ) -> Sample<Service, [Payload], UserHeader> {
+        let header_ptr = chunk.header as *const Header;
+        let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
+
+        Sample {
+            details,
+            ptr: unsafe {
+                RawSample::<Header, UserHeader, [Payload]>::new_slice_unchecked(
+                    header_ptr,
+                    chunk.user_header.cast(),
+                    core::slice::from_raw_parts(chunk.payload.cast(), number_of_elements as _),
+                )
+            },
+        }
     }
 }
 
@@ -365,3 +718,22 @@ impl<Service: service::Service, UserHeader: Debug>
         }))
     }
 }
+
+pub(crate) unsafe fn remove_listener_of_subscriber<Service: service::Service>(
+    subscriber_id: &UniqueSubscriberId,
+    config: &Config,
+) -> Result<(), NamedConceptRemoveError> {
+    let origin = format!(
+        "remove_listener_of_subscriber::<{}>({:?})",
+        core::any::type_name::<Service>(),
+        subscriber_id
+    );
+    let msg = "Unable to remove the subscriber listener";
+    let event_name = receiver_event_concept_name(subscriber_id.value());
+    let event_config = event_config::<Service>(config);
+
+    fail!(from origin,
+            when <Service::Event as NamedConceptMgmt>::remove_cfg(&event_name, &event_config),
+            "{} since the underlying concept could not be removed.", msg);
+    Ok(())
+}
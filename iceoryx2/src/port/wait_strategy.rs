@@ -0,0 +1,103 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use std::time::Instant;
+
+/// Defines how a port waits in its blocking calls, like
+/// [`Listener::blocking_wait_one()`](crate::port::listener::Listener::blocking_wait_one())
+/// or [`Subscriber::blocking_receive()`](crate::port::subscriber::Subscriber::blocking_receive()).
+/// It is defined per port, for instance with
+/// [`PortFactoryListener::wait_strategy()`](crate::service::port_factory::listener::PortFactoryListener::wait_strategy()).
+///
+/// Spinning avoids the wake-up latency of the operating system at the cost of CPU time and
+/// is meant for threads that run on isolated cores.
+#[derive(Debug, Default, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum WaitStrategy {
+    /// Waits in the underlying mechanism of the port right away.
+    #[default]
+    Block,
+    /// Busy spins until the call succeeds or the timeout has passed. It never hands the CPU
+    /// back to the operating system.
+    Spin,
+    /// Busy spins `spin_repetitions` times and yields the CPU between all further attempts.
+    SpinThenYield {
+        /// The number of attempts before the CPU is yielded.
+        spin_repetitions: u64,
+    },
+    /// Busy spins `spin_repetitions` times and waits in the underlying mechanism of the port
+    /// afterwards.
+    SpinThenBlock {
+        /// The number of attempts before the port waits in the underlying mechanism.
+        spin_repetitions: u64,
+    },
+}
+
+impl WaitStrategy {
+    /// Returns true when the strategy ends in the underlying mechanism of the port.
+    pub(crate) fn blocks(&self) -> bool {
+        matches!(
+            self,
+            WaitStrategy::Block | WaitStrategy::SpinThenBlock { .. }
+        )
+    }
+
+    // Calls `try_call` according to the strategy until it provides a value. Returns `None`
+    // when the strategy continues in the underlying mechanism of the port or when the
+    // timeout has passed in a strategy that never blocks.
+    pub(crate) fn poll<T, E, F: FnMut() -> Result<Option<T>, E>>(
+        &self,
+        timeout: Option<Duration>,
+        mut try_call: F,
+    ) -> Result<Option<T>, E> {
+        let start = Instant::now();
+        let has_timed_out = || match timeout {
+            Some(timeout) => start.elapsed() >= timeout,
+            None => false,
+        };
+
+        let spin_repetitions = match *self {
+            WaitStrategy::Block => return Ok(None),
+            WaitStrategy::Spin => u64::MAX,
+            WaitStrategy::SpinThenYield { spin_repetitions }
+            | WaitStrategy::SpinThenBlock { spin_repetitions } => spin_repetitions,
+        };
+
+        for _ in 0..spin_repetitions {
+            if let Some(value) = try_call()? {
+                return Ok(Some(value));
+            }
+
+            // the spinning phase before a blocking wait is bounded, the timeout is applied
+            // by the blocking wait
+            if !self.blocks() && has_timed_out() {
+                return Ok(None);
+            }
+            core::hint::spin_loop();
+        }
+
+        if let WaitStrategy::SpinThenYield { .. } = self {
+            loop {
+                if let Some(value) = try_call()? {
+                    return Ok(Some(value));
+                }
+
+                if has_timed_out() {
+                    return Ok(None);
+                }
+                std::thread::yield_now();
+            }
+        }
+
+        Ok(None)
+    }
+}
@@ -12,19 +12,25 @@
 
 pub use crate::config::Config;
 pub use crate::node::{node_name::NodeName, Node, NodeBuilder, NodeState};
-pub use crate::port::{event_id::EventId, unable_to_deliver_strategy::UnableToDeliverStrategy};
+pub use crate::port::{
+    event_id::EventId, out_of_memory_strategy::OutOfMemoryStrategy,
+    unable_to_deliver_strategy::UnableToDeliverStrategy, wait_strategy::WaitStrategy,
+};
 pub use crate::service::messaging_pattern::MessagingPattern;
 pub use crate::service::{
     attribute::AttributeSet, attribute::AttributeSpecifier, attribute::AttributeVerifier, ipc,
-    local, port_factory::PortFactory, service_name::ServiceName, Service, ServiceDetails,
+    local, port_factory::PortFactory, service_name::ServiceName, static_memory, Service,
+    ServiceDetails,
 };
 pub use crate::signal_handling_mode::SignalHandlingMode;
 pub use crate::waitset::{WaitSet, WaitSetAttachmentId, WaitSetBuilder, WaitSetGuard};
-pub use iceoryx2_bb_derive_macros::PlacementDefault;
+pub use iceoryx2_bb_derive_macros::{PlacementDefault, StableTypeId};
 pub use iceoryx2_bb_elementary::alignment::Alignment;
 pub use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+pub use iceoryx2_bb_elementary::stable_type_id::StableTypeId;
 pub use iceoryx2_bb_elementary::CallbackProgression;
 pub use iceoryx2_bb_log::set_log_level;
+pub use iceoryx2_bb_log::set_log_level_for;
 pub use iceoryx2_bb_log::set_log_level_from_env_or;
 pub use iceoryx2_bb_log::set_log_level_from_env_or_default;
 pub use iceoryx2_bb_log::LogLevel;
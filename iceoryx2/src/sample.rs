@@ -38,6 +38,7 @@ use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::zero_copy_connection::{ZeroCopyReceiver, ZeroCopyReleaseError};
 
+use crate::payload_view::{PayloadView, PayloadViewError};
 use crate::port::details::chunk_details::ChunkDetails;
 use crate::port::port_identifiers::UniquePublisherId;
 use crate::raw_sample::RawSample;
@@ -123,3 +124,14 @@ impl<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader>
         UniquePublisherId(UniqueSystemId::from(self.details.origin))
     }
 }
+
+impl<Service: crate::service::Service, UserHeader> Sample<Service, [u8], UserHeader> {
+    /// Validates the payload and returns a typed [`PayloadView`] on it. The schema of the
+    /// [`Service`](crate::service::Service) can be verified once with
+    /// [`verify_schema()`](crate::payload_view::verify_schema()).
+    pub fn view<'payload, View: PayloadView<'payload>>(
+        &'payload self,
+    ) -> Result<View, PayloadViewError> {
+        View::view(self.payload()).ok_or(PayloadViewError::InvalidPayload)
+    }
+}
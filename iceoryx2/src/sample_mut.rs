@@ -64,8 +64,10 @@
 //! ```
 
 use crate::{
-    port::publisher::PublisherBackend, port::SendError, raw_sample::RawSampleMut,
-    service::header::publish_subscribe::Header,
+    port::publisher::PublisherBackend,
+    port::SendError,
+    raw_sample::RawSampleMut,
+    service::header::publish_subscribe::{Header, ProvenanceError},
 };
 use iceoryx2_cal::shared_memory::*;
 
@@ -115,7 +117,6 @@ impl<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader> Drop
 {
     fn drop(&mut self) {
         self.publisher_backend
-            .subscriber_connections
             .return_loaned_sample(self.offset_to_chunk);
     }
 }
@@ -151,6 +152,67 @@ impl<
         self.ptr.as_header_ref()
     }
 
+    /// Sets the priority of the sample. A higher value means a higher priority, the default is
+    /// `0`. A [`Subscriber`](crate::port::subscriber::Subscriber) that was created with
+    /// [`PortFactorySubscriber::receive_by_priority()`](crate::service::port_factory::subscriber::PortFactorySubscriber::receive_by_priority())
+    /// receives pending samples with a higher priority first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// sample.set_priority(10);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_priority(&mut self, priority: u8) {
+        self.ptr.as_header_mut().set_priority(priority)
+    }
+
+    /// Appends the origin of the received `source` sample to the provenance chain of this
+    /// sample, see [`Header::provenance()`]. The chain of the `source` sample is copied and
+    /// extended by the [`UniquePublisherId`](crate::port::port_identifiers::UniquePublisherId)
+    /// and the send timestamp of the `source` sample. Fails when the chain of the `source` sample
+    /// already contains [`MAX_PROVENANCE_DEPTH`](crate::service::header::publish_subscribe::MAX_PROVENANCE_DEPTH)
+    /// records, the header stays unchanged then.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    /// # let subscriber = service.subscriber_builder().create()?;
+    /// # publisher.send_copy(1234)?;
+    ///
+    /// let received_sample = subscriber.receive()?.unwrap();
+    /// let mut sample = publisher.loan()?;
+    /// sample.append_provenance(received_sample.header())?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn append_provenance(&mut self, source: &Header) -> Result<(), ProvenanceError> {
+        self.ptr.as_header_mut().append_provenance(source)
+    }
+
     /// Returns a reference to the user_header of the sample.
     ///
     /// # Example
@@ -291,8 +353,11 @@ impl<
     /// # Ok(())
     /// # }
     /// ```
-    pub fn send(self) -> Result<usize, SendError> {
-        self.publisher_backend
-            .send_sample(self.offset_to_chunk, self.sample_size)
+    pub fn send(mut self) -> Result<usize, SendError> {
+        self.publisher_backend.send_sample(
+            self.ptr.as_header_mut(),
+            self.offset_to_chunk,
+            self.sample_size,
+        )
     }
 }
@@ -89,17 +89,74 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Slice API with dynamically sized contents
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_bb_container::byte_string::RelocatableByteString;
+//! use iceoryx2_bb_container::vec::RelocatableVec;
+//! use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! #[derive(Debug)]
+//! #[repr(C)]
+//! struct Message {
+//!     name: RelocatableByteString,
+//!     values: RelocatableVec<u64>,
+//! }
+//!
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<[u8]>()
+//!     // the message is placed at the beginning of the payload
+//!     .payload_alignment(Alignment::new(core::mem::align_of::<Message>()).unwrap())
+//!     .create()?;
+//!
+//! let publisher = service.publisher_builder().initial_max_slice_len(1024).create()?;
+//! let subscriber = service.subscriber_builder().create()?;
+//!
+//! let mut sample = publisher.loan_slice_uninit(1024)?;
+//! let allocator = sample.payload_allocator();
+//! let message = allocator.place(Message {
+//!     name: unsafe { RelocatableByteString::new_uninit(32) },
+//!     values: unsafe { RelocatableVec::new_uninit(8) },
+//! })?;
+//! unsafe {
+//!     message.name.init(&allocator)?;
+//!     message.values.init(&allocator)?;
+//!     message.name.push_bytes(b"hypnotoad")?;
+//!     message.values.push(42);
+//! }
+//! // the payload was zeroed when the allocator was acquired
+//! let sample = unsafe { sample.assume_init() };
+//! sample.send()?;
+//!
+//! if let Some(sample) = subscriber.receive()? {
+//!     let message = unsafe { &*sample.payload().as_ptr().cast::<Message>() };
+//!     assert_eq!(unsafe { message.name.as_bytes() }, b"hypnotoad");
+//!     assert_eq!(unsafe { message.values.as_slice() }, [42]);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
 
-use core::{fmt::Debug, mem::MaybeUninit};
+use core::{fmt::Debug, marker::PhantomData, mem::MaybeUninit, ptr::NonNull};
 
 extern crate alloc;
 use alloc::sync::Arc;
 
+use iceoryx2_bb_elementary::allocator::{AllocationError, BaseAllocator};
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
 use iceoryx2_cal::shm_allocator::PointerOffset;
 
 use crate::{
-    port::publisher::PublisherBackend, raw_sample::RawSampleMut, sample_mut::SampleMut,
-    service::header::publish_subscribe::Header,
+    port::publisher::PublisherBackend,
+    raw_sample::RawSampleMut,
+    sample_mut::SampleMut,
+    service::header::publish_subscribe::{Header, ProvenanceError},
 };
 
 /// Acquired by a [`crate::port::publisher::Publisher`] via
@@ -148,6 +205,67 @@ impl<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader>
         self.sample.header()
     }
 
+    /// Sets the priority of the sample. A higher value means a higher priority, the default is
+    /// `0`. A [`Subscriber`](crate::port::subscriber::Subscriber) that was created with
+    /// [`PortFactorySubscriber::receive_by_priority()`](crate::service::port_factory::subscriber::PortFactorySubscriber::receive_by_priority())
+    /// receives pending samples with a higher priority first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan_uninit()?;
+    /// sample.set_priority(10);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_priority(&mut self, priority: u8) {
+        self.sample.set_priority(priority)
+    }
+
+    /// Appends the origin of the received `source` sample to the provenance chain of this
+    /// sample, see [`Header::provenance()`]. The chain of the `source` sample is copied and
+    /// extended by the [`UniquePublisherId`](crate::port::port_identifiers::UniquePublisherId)
+    /// and the send timestamp of the `source` sample. Fails when the chain of the `source` sample
+    /// already contains [`MAX_PROVENANCE_DEPTH`](crate::service::header::publish_subscribe::MAX_PROVENANCE_DEPTH)
+    /// records, the header stays unchanged then.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    /// # let subscriber = service.subscriber_builder().create()?;
+    /// # publisher.send_copy(1234)?;
+    ///
+    /// let received_sample = subscriber.receive()?.unwrap();
+    /// let mut sample = publisher.loan_uninit()?;
+    /// sample.append_provenance(received_sample.header())?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn append_provenance(&mut self, source: &Header) -> Result<(), ProvenanceError> {
+        self.sample.append_provenance(source)
+    }
+
     /// Returns a reference to the user_header of the sample.
     ///
     /// # Example
@@ -476,3 +594,108 @@ impl<Service: crate::service::Service, Payload: Debug + Copy, UserHeader>
         unsafe { self.assume_init() }
     }
 }
+
+impl<Service: crate::service::Service, UserHeader>
+    SampleMutUninit<Service, [MaybeUninit<u8>], UserHeader>
+{
+    /// Returns a [`PayloadAllocator`] that hands out the memory of the payload. It can be used
+    /// to initialize relocatable containers, like
+    /// [`RelocatableVec`](iceoryx2_bb_container::vec::RelocatableVec) or
+    /// [`RelocatableByteString`](iceoryx2_bb_container::byte_string::RelocatableByteString),
+    /// so that data structures of variable length can be constructed inside a single sample
+    /// without a compile time fixed worst case capacity.
+    ///
+    /// The payload is zeroed when the allocator is acquired, therefore
+    /// [`SampleMutUninit::assume_init()`] can be called as soon as all constructed objects
+    /// are initialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2_bb_container::vec::RelocatableVec;
+    /// use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u8]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().initial_max_slice_len(256).create()?;
+    ///
+    /// let mut sample = publisher.loan_slice_uninit(256)?;
+    /// let allocator = sample.payload_allocator();
+    /// let values = allocator.place(unsafe { RelocatableVec::<u32>::new_uninit(16) })?;
+    /// unsafe { values.init(&allocator)? };
+    /// println!("bytes in use: {}", allocator.used_space());
+    ///
+    /// let sample = unsafe { sample.assume_init() };
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn payload_allocator(&mut self) -> PayloadAllocator<'_> {
+        let payload = self.payload_mut();
+        let size = payload.len();
+        let start = NonNull::from(payload).cast::<u8>();
+        unsafe { core::ptr::write_bytes(start.as_ptr(), 0, size) };
+
+        PayloadAllocator {
+            allocator: BumpAllocator::new(start, size),
+            _payload: PhantomData,
+        }
+    }
+}
+
+/// Acquired via [`SampleMutUninit::payload_allocator()`]. A bump allocator that hands out the
+/// payload memory of the [`SampleMutUninit`] it was created from. Deallocation has no effect,
+/// the memory is released together with the sample.
+///
+/// Since the relocatable containers store only offsets to their data, objects that are
+/// constructed with this allocator can be accessed by every
+/// [`crate::port::subscriber::Subscriber`] that receives the sample.
+#[derive(Debug)]
+pub struct PayloadAllocator<'payload> {
+    allocator: BumpAllocator,
+    _payload: PhantomData<&'payload mut [MaybeUninit<u8>]>,
+}
+
+impl BaseAllocator for PayloadAllocator<'_> {
+    fn allocate(&self, layout: core::alloc::Layout) -> Result<NonNull<[u8]>, AllocationError> {
+        self.allocator.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: core::alloc::Layout) {}
+}
+
+impl<'payload> PayloadAllocator<'payload> {
+    /// Returns the number of bytes of the payload that are already in use, including the
+    /// padding required for alignment.
+    pub fn used_space(&self) -> usize {
+        self.allocator.used_space()
+    }
+
+    /// Returns the number of bytes of the payload that are still available.
+    pub fn free_space(&self) -> usize {
+        self.allocator.free_space()
+    }
+
+    /// Moves the value into the payload and returns a mutable reference to it. The value is
+    /// never dropped. If the payload has not enough space left or `T` is zero sized, it
+    /// fails.
+    pub fn place<T>(&self, value: T) -> Result<&'payload mut T, AllocationError> {
+        let memory = fail!(from self, when self.allocator.allocate(core::alloc::Layout::new::<T>()),
+            "Unable to place value of type {} into the payload.", core::any::type_name::<T>());
+        let ptr = memory.cast::<T>().as_ptr();
+
+        // SAFETY: the memory is aligned for T, is part of the payload that is exclusively
+        // borrowed for 'payload and is handed out only once by the bump allocator
+        unsafe {
+            ptr.write(value);
+            Ok(&mut *ptr)
+        }
+    }
+}
@@ -83,6 +83,44 @@
 //! # }
 //! ```
 //!
+//! ## Dynamic Attributes
+//!
+//! Besides the attributes that are fixed for the lifetime of a service, the creator can declare
+//! dynamic attributes. They are stored in the dynamic config of the service and their value
+//! can be updated at runtime. The [`DynamicAttributePermission`] of an attribute defines
+//! whether only the [`Node`](crate::node::Node) that created the service or every participant
+//! can update it.
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::attribute::DynamicAttributePermission;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/DynamicServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .create_with_attributes(
+//!         &AttributeSpecifier::new()
+//!             .define("sensor", "lidar")
+//!             .define_dynamic(
+//!                 "calibration_revision",
+//!                 "1",
+//!                 DynamicAttributePermission::AllParticipants,
+//!             )?
+//!     )?;
+//!
+//! let revision = service.dynamic_attributes_revision();
+//! service.update_dynamic_attribute("calibration_revision", "2")?;
+//!
+//! assert!(service.dynamic_attributes_revision() != revision);
+//! assert_eq!(
+//!     service.dynamic_attributes().get_key_value_at("calibration_revision", 0),
+//!     Some("2")
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## List Attributes Of All Services In Discovery
 //!
 //! ```
@@ -99,8 +137,73 @@
 
 use core::ops::Deref;
 use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::fail;
 use serde::{Deserialize, Serialize};
 
+/// The maximum number of dynamic [`Attribute`]s a [`crate::service::Service`] can declare.
+pub const MAX_NUMBER_OF_DYNAMIC_ATTRIBUTES: usize = capacity!(small: 2, default: 8, large: 32);
+
+/// The maximum length of the key of a dynamic [`Attribute`].
+pub const MAX_DYNAMIC_ATTRIBUTE_KEY_LENGTH: usize = capacity!(small: 32, default: 64, large: 256);
+
+/// The maximum length of the value of a dynamic [`Attribute`].
+pub const MAX_DYNAMIC_ATTRIBUTE_VALUE_LENGTH: usize = capacity!(small: 32, default: 64, large: 256);
+
+/// Defines the failures that can occur when a dynamic [`Attribute`] is updated with
+/// [`PortFactory::update_dynamic_attribute()`](crate::service::port_factory::PortFactory::update_dynamic_attribute).
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum DynamicAttributeUpdateError {
+    /// The key was not declared as dynamic attribute when the service was created.
+    UnknownKey,
+    /// The value exceeds [`MAX_DYNAMIC_ATTRIBUTE_VALUE_LENGTH`].
+    ValueTooLong,
+    /// The [`Node`](crate::node::Node) of the caller is not registered in the
+    /// [`crate::service::Service`] anymore which indicates a corrupted service state.
+    ServiceInCorruptedState,
+    /// The attribute can only be updated by the [`Node`](crate::node::Node) that created the
+    /// [`crate::service::Service`], see [`DynamicAttributePermission::OwnerOnly`].
+    InsufficientPermissions,
+}
+
+impl core::fmt::Display for DynamicAttributeUpdateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "DynamicAttributeUpdateError::{:?}", self)
+    }
+}
+
+impl core::error::Error for DynamicAttributeUpdateError {}
+
+/// Defines the failures that can occur when a dynamic [`Attribute`] is declared with
+/// [`AttributeSpecifier::define_dynamic()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum DynamicAttributeDefinitionError {
+    /// The key exceeds [`MAX_DYNAMIC_ATTRIBUTE_KEY_LENGTH`].
+    KeyTooLong,
+    /// The initial value exceeds [`MAX_DYNAMIC_ATTRIBUTE_VALUE_LENGTH`].
+    ValueTooLong,
+    /// The declaration would exceed [`MAX_NUMBER_OF_DYNAMIC_ATTRIBUTES`].
+    ExceedsMaxNumberOfDynamicAttributes,
+}
+
+impl core::fmt::Display for DynamicAttributeDefinitionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "DynamicAttributeDefinitionError::{:?}", self)
+    }
+}
+
+impl core::error::Error for DynamicAttributeDefinitionError {}
+
+/// Defines who is allowed to update a dynamic [`Attribute`].
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum DynamicAttributePermission {
+    /// Only the [`Node`](crate::node::Node) that created the [`crate::service::Service`] can
+    /// update the value.
+    OwnerOnly,
+    /// Every participant that opened the [`crate::service::Service`] can update the value.
+    AllParticipants,
+}
+
 /// Represents a single service attribute (key-value) pair that can be defined when the service
 /// is being created.
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, PartialOrd, Ord)]
@@ -123,11 +226,19 @@ impl Attribute {
 
 /// Represents the set of [`Attribute`]s that are defined when the [`crate::service::Service`]
 /// is created.
-pub struct AttributeSpecifier(pub(crate) AttributeSet);
+pub struct AttributeSpecifier {
+    pub(crate) attributes: AttributeSet,
+    pub(crate) dynamic_attributes: AttributeSet,
+    dynamic_attribute_permissions: Vec<(String, DynamicAttributePermission)>,
+}
 
 impl Default for AttributeSpecifier {
     fn default() -> Self {
-        Self(AttributeSet::new())
+        Self {
+            attributes: AttributeSet::new(),
+            dynamic_attributes: AttributeSet::new(),
+            dynamic_attribute_permissions: Vec::new(),
+        }
     }
 }
 
@@ -137,15 +248,79 @@ impl AttributeSpecifier {
         Self::default()
     }
 
+    pub(crate) fn from_attributes(attributes: &AttributeSet) -> Self {
+        Self {
+            attributes: attributes.clone(),
+            ..Self::default()
+        }
+    }
+
     /// Defines a value for a specific key. A key is allowed to have multiple values.
     pub fn define(mut self, key: &str, value: &str) -> Self {
-        self.0.add(key, value);
+        self.attributes.add(key, value);
         self
     }
 
+    /// Declares a dynamic attribute with an initial value. In contrast to [`Self::define()`],
+    /// a dynamic attribute has exactly one value that can be updated at runtime, the
+    /// [`DynamicAttributePermission`] defines by whom. Defining the same key again replaces
+    /// the initial value and the permission.
+    ///
+    /// At most [`MAX_NUMBER_OF_DYNAMIC_ATTRIBUTES`] can be declared and the key and value
+    /// lengths are limited by [`MAX_DYNAMIC_ATTRIBUTE_KEY_LENGTH`] and
+    /// [`MAX_DYNAMIC_ATTRIBUTE_VALUE_LENGTH`].
+    pub fn define_dynamic(
+        mut self,
+        key: &str,
+        initial_value: &str,
+        permission: DynamicAttributePermission,
+    ) -> Result<Self, DynamicAttributeDefinitionError> {
+        let origin = "AttributeSpecifier::define_dynamic()";
+        let msg = "Unable to declare dynamic attribute";
+        if MAX_DYNAMIC_ATTRIBUTE_KEY_LENGTH < key.len() {
+            fail!(from origin, with DynamicAttributeDefinitionError::KeyTooLong,
+                "{} \"{}\" since the key exceeds the maximum supported length of {}.",
+                msg, key, MAX_DYNAMIC_ATTRIBUTE_KEY_LENGTH);
+        }
+
+        if MAX_DYNAMIC_ATTRIBUTE_VALUE_LENGTH < initial_value.len() {
+            fail!(from origin, with DynamicAttributeDefinitionError::ValueTooLong,
+                "{} \"{}\" since the value exceeds the maximum supported length of {}.",
+                msg, key, MAX_DYNAMIC_ATTRIBUTE_VALUE_LENGTH);
+        }
+
+        if self.dynamic_attributes.get_key_value_len(key) == 0
+            && self.dynamic_attributes.len() == MAX_NUMBER_OF_DYNAMIC_ATTRIBUTES
+        {
+            fail!(from origin, with DynamicAttributeDefinitionError::ExceedsMaxNumberOfDynamicAttributes,
+                "{} \"{}\" since it would exceed the maximum supported number of dynamic attributes of {}.",
+                msg, key, MAX_NUMBER_OF_DYNAMIC_ATTRIBUTES);
+        }
+
+        self.dynamic_attributes.set(key, initial_value);
+        self.dynamic_attribute_permissions.retain(|(k, _)| k != key);
+        self.dynamic_attribute_permissions
+            .push((key.to_string(), permission));
+        Ok(self)
+    }
+
     /// Returns the underlying [`AttributeSet`]
     pub fn attributes(&self) -> &AttributeSet {
-        &self.0
+        &self.attributes
+    }
+
+    /// Returns the declared dynamic attributes with their initial values
+    pub fn dynamic_attributes(&self) -> &AttributeSet {
+        &self.dynamic_attributes
+    }
+
+    /// Returns the [`DynamicAttributePermission`] of a declared dynamic attribute or [`None`]
+    /// when the key was not declared with [`Self::define_dynamic()`].
+    pub fn dynamic_attribute_permission(&self, key: &str) -> Option<DynamicAttributePermission> {
+        self.dynamic_attribute_permissions
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, permission)| *permission)
     }
 }
 
@@ -242,6 +417,11 @@ impl AttributeSet {
         self.0.sort();
     }
 
+    pub(crate) fn set(&mut self, key: &str, value: &str) {
+        self.0.retain(|p| p.key != key);
+        self.add(key, value);
+    }
+
     fn get_vec(&self, key: &str) -> Vec<&str> {
         self.0
             .iter()
@@ -14,6 +14,7 @@
 //!
 //! See [`crate::service`]
 //!
+use crate::node::service_dependencies::notify_service_created;
 pub use crate::port::event_id::EventId;
 use crate::service::builder::OpenDynamicStorageFailure;
 use crate::service::port_factory::event;
@@ -57,6 +58,9 @@ pub enum EventOpenError {
     /// The event id that is emitted if a [`Notifier`](crate::port::notifier::Notifier) is
     /// identified as dead does not fit the required event id.
     IncompatibleNotifierDeadEvent,
+    /// The event id that is emitted if a dynamic attribute of the [`Service`] is updated does not
+    /// fit the required event id.
+    IncompatibleDynamicAttributeChangedEvent,
     /// The [`Service`]s creation timeout has passed and it is still not initialized. Can be caused
     /// by a process that crashed during [`Service`] creation.
     HangsInCreation,
@@ -74,6 +78,23 @@ pub enum EventOpenError {
     /// When the call creation call is repeated with a little delay the [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`Service`] has a lower version than required with
+    /// [`Builder::require_version_at_least()`].
+    IncompatibleVersion,
+    /// The [`Service`] was created in another domain, see
+    /// [`Config::set_domain()`](crate::config::Config::set_domain()).
+    IncompatibleDomain,
+    /// The [`Service`] was created by an iceoryx2 version that is incompatible to the version
+    /// of this process, see
+    /// [`Iceoryx2Version::is_compatible_to()`](crate::service::static_config::iceoryx2_version::Iceoryx2Version::is_compatible_to()).
+    IncompatibleIceoryx2Version,
+    /// The [`Service`] was created by a process with a different memory layout of the shared
+    /// data, see [`ABI_VERSION`](crate::service::static_config::abi_version::ABI_VERSION).
+    IncompatibleAbiVersion,
+    /// A different [`Service`] is mapped onto the same underlying resources, since the hashes
+    /// of their [`ServiceName`](crate::service::service_name::ServiceName)s collide, see
+    /// [`ServiceNameHashing`](crate::service::service_id::ServiceNameHashing).
+    ServiceNameHashCollision,
 }
 
 impl core::fmt::Display for EventOpenError {
@@ -90,6 +111,12 @@ impl From<ServiceState> for EventOpenError {
             ServiceState::IncompatibleMessagingPattern => {
                 EventOpenError::IncompatibleMessagingPattern
             }
+            ServiceState::IncompatibleDomain => EventOpenError::IncompatibleDomain,
+            ServiceState::IncompatibleIceoryx2Version => {
+                EventOpenError::IncompatibleIceoryx2Version
+            }
+            ServiceState::IncompatibleAbiVersion => EventOpenError::IncompatibleAbiVersion,
+            ServiceState::ServiceNameHashCollision => EventOpenError::ServiceNameHashCollision,
             ServiceState::InsufficientPermissions => EventOpenError::InsufficientPermissions,
             ServiceState::HangsInCreation => EventOpenError::HangsInCreation,
             ServiceState::Corrupted => EventOpenError::ServiceInCorruptedState,
@@ -113,6 +140,9 @@ pub enum EventCreateError {
     HangsInCreation,
     /// The process has insufficient permissions to create the [`Service`].
     InsufficientPermissions,
+    /// The [`Service`] cannot be created since it would exceed the
+    /// [`NodeQuota`](crate::node::quota::NodeQuota) of the [`Node`](crate::node::Node).
+    ExceedsNodeQuota,
 }
 
 impl core::fmt::Display for EventCreateError {
@@ -126,7 +156,11 @@ impl core::error::Error for EventCreateError {}
 impl From<ServiceState> for EventCreateError {
     fn from(value: ServiceState) -> Self {
         match value {
-            ServiceState::IncompatibleMessagingPattern => EventCreateError::AlreadyExists,
+            ServiceState::IncompatibleMessagingPattern
+            | ServiceState::IncompatibleDomain
+            | ServiceState::IncompatibleIceoryx2Version
+            | ServiceState::IncompatibleAbiVersion
+            | ServiceState::ServiceNameHashCollision => EventCreateError::AlreadyExists,
             ServiceState::InsufficientPermissions => EventCreateError::InsufficientPermissions,
             ServiceState::HangsInCreation => EventCreateError::HangsInCreation,
             ServiceState::Corrupted => EventCreateError::ServiceInCorruptedState,
@@ -189,6 +223,8 @@ pub struct Builder<ServiceType: service::Service> {
     verify_notifier_created_event: bool,
     verify_notifier_dropped_event: bool,
     verify_notifier_dead_event: bool,
+    verify_dynamic_attribute_changed_event: bool,
+    required_min_version: Option<u32>,
 }
 
 impl<ServiceType: service::Service> Builder<ServiceType> {
@@ -203,6 +239,8 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             verify_notifier_dead_event: false,
             verify_notifier_created_event: false,
             verify_notifier_dropped_event: false,
+            verify_dynamic_attribute_changed_event: false,
+            required_min_version: None,
         };
 
         new_self.base.service_config.messaging_pattern = MessagingPattern::Event(
@@ -212,6 +250,12 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         new_self
     }
 
+    // Checks the existence without opening the service, so that a missing service is not
+    // reported as failure.
+    pub(crate) fn does_exist(&self) -> bool {
+        self.base.does_exist()
+    }
+
     fn config_details(&mut self) -> &mut static_config::event::StaticConfig {
         match self.base.service_config.messaging_pattern {
             MessagingPattern::Event(ref mut v) => v,
@@ -240,6 +284,21 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self
     }
 
+    /// If the [`Service`] is created, defines the version of the service. The version is
+    /// ignored when an existing [`Service`] is opened, use
+    /// [`Builder::require_version_at_least()`] to define a requirement.
+    pub fn version(mut self, value: u32) -> Self {
+        self.config_details().version = value;
+        self
+    }
+
+    /// If an existing [`Service`] is opened it requires the service to have at least the
+    /// provided version, otherwise [`EventOpenError::IncompatibleVersion`] is returned.
+    pub fn require_version_at_least(mut self, value: u32) -> Self {
+        self.required_min_version = Some(value);
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`Node`](crate::node::Node)s shall
     /// be able to open it in parallel. If an existing [`Service`] is opened it defines how many
     /// [`Node`](crate::node::Node)s must be at least supported.
@@ -324,6 +383,22 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self
     }
 
+    /// If the [`Service`] is created it defines the event that shall be emitted whenever a
+    /// dynamic attribute of the [`Service`] is updated.
+    pub fn dynamic_attribute_changed_event(mut self, value: EventId) -> Self {
+        self.config_details().dynamic_attribute_changed_event = Some(value.as_value());
+        self.verify_dynamic_attribute_changed_event = true;
+        self
+    }
+
+    /// If the [`Service`] is created it disables the event that shall be emitted whenever a
+    /// dynamic attribute of the [`Service`] is updated.
+    pub fn disable_dynamic_attribute_changed_event(mut self) -> Self {
+        self.config_details().dynamic_attribute_changed_event = None;
+        self.verify_dynamic_attribute_changed_event = true;
+        self
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created.
     pub fn open_or_create(self) -> Result<event::PortFactory<ServiceType>, EventOpenOrCreateError> {
@@ -338,6 +413,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         mut self,
         required_attributes: &AttributeVerifier,
     ) -> Result<event::PortFactory<ServiceType>, EventOpenOrCreateError> {
+        let _log_scope = self.base.shared_node.log_scope();
         let msg = "Unable to open or create event service";
 
         let mut retry_count = 0;
@@ -353,8 +429,8 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             match self.base.is_service_available(msg)? {
                 Some(_) => return Ok(self.open_with_attributes(required_attributes)?),
                 None => {
-                    match self.create_impl(&AttributeSpecifier(
-                        required_attributes.attributes().clone(),
+                    match self.create_impl(&AttributeSpecifier::from_attributes(
+                        required_attributes.attributes(),
                     )) {
                         Ok(factory) => return Ok(factory),
                         Err(EventCreateError::AlreadyExists)
@@ -379,6 +455,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         mut self,
         required_attributes: &AttributeVerifier,
     ) -> Result<event::PortFactory<ServiceType>, EventOpenError> {
+        let _log_scope = self.base.shared_node.log_scope();
         let msg = "Unable to open event service";
 
         let mut service_open_retry_count = 0;
@@ -443,6 +520,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                             self.base.shared_node,
                             dynamic_config,
                             static_storage,
+                            None,
                         ),
                     )));
                 }
@@ -467,12 +545,17 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         &mut self,
         attributes: &AttributeSpecifier,
     ) -> Result<event::PortFactory<ServiceType>, EventCreateError> {
+        let _log_scope = self.base.shared_node.log_scope();
         self.adjust_attributes_to_meaningful_values();
 
         let msg = "Unable to create event service";
 
         match self.base.is_service_available(msg)? {
             None => {
+                let quota_reservation = self
+                    .base
+                    .acquire_service_quota(msg, EventCreateError::ExceedsNodeQuota)?;
+
                 let service_tag = self
                     .base
                     .create_node_service_tag(msg, EventCreateError::InternalFailure)?;
@@ -518,6 +601,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                     ),
                     dynamic_config::event::DynamicConfig::memory_size(&dynamic_config_setting),
                     event_config.max_nodes,
+                    attributes,
                 ) {
                     Ok(dynamic_config) => dynamic_config,
                     Err(DynamicStorageCreateError::AlreadyExists) => {
@@ -530,7 +614,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                     }
                 };
 
-                self.base.service_config.attributes = attributes.0.clone();
+                self.base.service_config.attributes = attributes.attributes.clone();
 
                 let service_config = fail!(from self, when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
                                             with EventCreateError::ServiceInCorruptedState,
@@ -546,12 +630,15 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                     service_tag.release_ownership();
                 }
 
+                notify_service_created(&self.base.shared_node, self.base.service_config.name());
+
                 Ok(event::PortFactory::new(ServiceType::__internal_from_state(
                     service::ServiceState::new(
                         self.base.service_config.clone(),
                         self.base.shared_node.clone(),
                         dynamic_config,
                         unlocked_static_details,
+                        Some(quota_reservation),
                     ),
                 )))
             }
@@ -564,20 +651,34 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
 
     fn adjust_attributes_to_meaningful_values(&mut self) {
         let origin = format!("{:?}", self);
+        let is_event_id_max_value_required = self.verify_event_id_max_value;
         let settings = self.base.service_config.event_mut();
 
+        // the resources of some events grow with the max event id, therefore the default
+        // of the config is reduced to a value they can provide
+        let default_event_id_max_value =
+            <ServiceType::Event as iceoryx2_cal::event::Event>::default_trigger_id_max().as_value();
+        if !is_event_id_max_value_required
+            && default_event_id_max_value < settings.event_id_max_value
+        {
+            settings.event_id_max_value = default_event_id_max_value;
+        }
+
         if settings.max_notifiers == 0 {
-            warn!(from origin, "Setting the maximum amount of notifiers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            warn!(from origin, fields [setting = "max_notifiers", adjusted_value = 1],
+                "Setting the maximum amount of notifiers to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_notifiers = 1;
         }
 
         if settings.max_listeners == 0 {
-            warn!(from origin, "Setting the maximum amount of listeners to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            warn!(from origin, fields [setting = "max_listeners", adjusted_value = 1],
+                "Setting the maximum amount of listeners to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_listeners = 1;
         }
 
         if settings.max_nodes == 0 {
-            warn!(from origin, "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            warn!(from origin, fields [setting = "max_nodes", adjusted_value = 1],
+                "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_nodes = 1;
         }
     }
@@ -606,6 +707,14 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             }
         };
 
+        if let Some(required_min_version) = self.required_min_version {
+            if existing_settings.version < required_min_version {
+                fail!(from self, with EventOpenError::IncompatibleVersion,
+                    "{} since the event has the version {} but at least version {} is required.",
+                    msg, existing_settings.version, required_min_version);
+            }
+        }
+
         if self.verify_max_notifiers
             && existing_settings.max_notifiers < required_settings.max_notifiers
         {
@@ -660,6 +769,15 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 msg, existing_settings.notifier_dead_event, required_settings.notifier_dead_event);
         }
 
+        if self.verify_dynamic_attribute_changed_event
+            && existing_settings.dynamic_attribute_changed_event
+                != required_settings.dynamic_attribute_changed_event
+        {
+            fail!(from self, with EventOpenError::IncompatibleDynamicAttributeChangedEvent,
+                "{} since the dynamic_attribute_changed_event id is {:?} but the value {:?} is required.",
+                msg, existing_settings.dynamic_attribute_changed_event, required_settings.dynamic_attribute_changed_event);
+        }
+
         if self.verify_deadline
             && existing_settings.deadline.map(|v| v.value)
                 != required_settings.deadline.map(|v| v.value)
@@ -23,10 +23,13 @@ pub mod publish_subscribe;
 /// Builder for [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
 pub mod request_response;
 
+use crate::node::quota::QuotaReservation;
 use crate::node::SharedNode;
 use crate::service;
 use crate::service::dynamic_config::DynamicConfig;
 use crate::service::dynamic_config::RegisterNodeResult;
+use crate::service::static_config::abi_version::{ABI_VERSION, ABI_VERSION_INTRODUCED_IN};
+use crate::service::static_config::iceoryx2_version::{Iceoryx2Version, MIN_COMPATIBLE_VERSION};
 use crate::service::static_config::*;
 use core::fmt::Debug;
 use core::marker::PhantomData;
@@ -40,12 +43,14 @@ use iceoryx2_cal::dynamic_storage::{DynamicStorage, DynamicStorageBuilder};
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
 use iceoryx2_cal::named_concept::NamedConceptDoesExistError;
 use iceoryx2_cal::named_concept::NamedConceptMgmt;
+use iceoryx2_cal::named_concept::{AccessControlConfiguration, AccessControlList};
 use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::static_storage::*;
 
 extern crate alloc;
 use alloc::sync::Arc;
 
+use super::attribute::AttributeSpecifier;
 use super::config_scheme::dynamic_config_storage_config;
 use super::config_scheme::service_tag_config;
 use super::config_scheme::static_config_storage_config;
@@ -57,6 +62,10 @@ const RETRY_LIMIT: usize = 5;
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 enum ServiceState {
     IncompatibleMessagingPattern,
+    IncompatibleDomain,
+    IncompatibleIceoryx2Version,
+    IncompatibleAbiVersion,
+    ServiceNameHashCollision,
     InsufficientPermissions,
     HangsInCreation,
     Corrupted,
@@ -105,27 +114,47 @@ impl core::error::Error for ReadStaticStorageFailure {}
 pub struct Builder<S: Service> {
     name: ServiceName,
     shared_node: Arc<SharedNode<S>>,
+    access_control_list: Option<AccessControlList>,
     _phantom_s: PhantomData<S>,
 }
 
 impl<S: Service> Builder<S> {
     pub(crate) fn new(name: &ServiceName, shared_node: Arc<SharedNode<S>>) -> Self {
+        let access_control_list = shared_node.config().global.service.access_control_list;
         Self {
             name: name.clone(),
             shared_node,
+            access_control_list,
             _phantom_s: PhantomData,
         }
     }
 
+    /// Defines the [`AccessControlList`] that is applied to the shared memory and the static
+    /// storage of the [`Service`] when it is created. It overrides
+    /// [`Service::access_control_list`](crate::config::Service::access_control_list) of the
+    /// [`Config`](crate::config::Config). When the [`Service`] already exists, the
+    /// [`AccessControlList`] of the creator is used.
+    pub fn access_control_list(mut self, value: AccessControlList) -> Self {
+        self.access_control_list = Some(value);
+        self
+    }
+
+    fn with_access_control_list(&self, mut static_config: StaticConfig) -> StaticConfig {
+        static_config.set_access_control_list(self.access_control_list);
+        static_config
+    }
+
     /// Create a new builder to create a
     /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) [`Service`].
     pub fn request_response<RequestPayload: Debug, ResponsePayload: Debug>(
         self,
     ) -> request_response::Builder<RequestPayload, (), ResponsePayload, (), S> {
         BuilderWithServiceType::new(
-            StaticConfig::new_request_response::<S::ServiceNameHasher>(
-                &self.name,
-                self.shared_node.config(),
+            self.with_access_control_list(
+                StaticConfig::new_request_response::<S::ServiceNameHasher>(
+                    &self.name,
+                    self.shared_node.config(),
+                ),
             ),
             self.shared_node,
         )
@@ -138,10 +167,9 @@ impl<S: Service> Builder<S> {
         self,
     ) -> publish_subscribe::Builder<PayloadType, (), S> {
         BuilderWithServiceType::new(
-            StaticConfig::new_publish_subscribe::<S::ServiceNameHasher>(
-                &self.name,
-                self.shared_node.config(),
-            ),
+            self.with_access_control_list(StaticConfig::new_publish_subscribe::<
+                S::ServiceNameHasher,
+            >(&self.name, self.shared_node.config())),
             self.shared_node,
         )
         .publish_subscribe()
@@ -151,7 +179,10 @@ impl<S: Service> Builder<S> {
     /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) [`Service`].
     pub fn event(self) -> event::Builder<S> {
         BuilderWithServiceType::new(
-            StaticConfig::new_event::<S::ServiceNameHasher>(&self.name, self.shared_node.config()),
+            self.with_access_control_list(StaticConfig::new_event::<S::ServiceNameHasher>(
+                &self.name,
+                self.shared_node.config(),
+            )),
             self.shared_node,
         )
         .event()
@@ -191,6 +222,16 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         event::Builder::new(self)
     }
 
+    fn does_exist(&self) -> bool {
+        matches!(
+            <ServiceType::StaticStorage as NamedConceptMgmt>::does_exist_cfg(
+                &self.service_config.service_id().0.into(),
+                &static_config_storage_config::<ServiceType>(self.shared_node.config()),
+            ),
+            Ok(true)
+        )
+    }
+
     fn is_service_available(
         &self,
         msg: &str,
@@ -229,12 +270,16 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
 
                 let mut read_content =
                     String::from_utf8(vec![b' '; storage.len() as usize]).expect("");
-                if storage
-                    .read(unsafe { read_content.as_mut_vec() }.as_mut_slice())
-                    .is_err()
-                {
-                    fail!(from self, with ServiceState::InsufficientPermissions,
+                match storage.read(unsafe { read_content.as_mut_vec() }.as_mut_slice()) {
+                    Ok(()) => (),
+                    Err(StaticStorageReadError::StaticStorageWasModified) => {
+                        fail!(from self, with ServiceState::Corrupted,
+                            "{} since the services underlying static details were modified or could not be authenticated.", msg);
+                    }
+                    Err(_) => {
+                        fail!(from self, with ServiceState::InsufficientPermissions,
                             "{} since it is not possible to read the services underlying static details. Is the service accessible?", msg);
+                    }
                 }
 
                 let service_config = fail!(from self, when ServiceType::ConfigSerializer::deserialize::<StaticConfig>(unsafe {
@@ -246,6 +291,12 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
                         "{} a service with that name exist but different ServiceId.", msg);
                 }
 
+                if service_config.name() != self.service_config.name() {
+                    fail!(from self, with ServiceState::ServiceNameHashCollision,
+                        "{} since the service \"{}\" is mapped onto the same ServiceId \"{}\" as the requested service \"{}\". The service name hashes collide, consider a different name hashing in the config.",
+                        msg, service_config.name(), self.service_config.service_id().as_str(), self.service_config.name());
+                }
+
                 let msg = "Service exist but is not compatible";
                 if !service_config.has_same_messaging_pattern(&self.service_config) {
                     fail!(from self, with ServiceState::IncompatibleMessagingPattern,
@@ -253,6 +304,43 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
                         msg, service_config.messaging_pattern(), self.service_config.messaging_pattern());
                 }
 
+                match service_config.abi_version() {
+                    Some(abi_version) if abi_version == ABI_VERSION => (),
+                    Some(abi_version) => {
+                        fail!(from self, with ServiceState::IncompatibleAbiVersion,
+                            "{} since the service was created with the ABI version {} but the ABI version {} is required.",
+                            msg, abi_version, ABI_VERSION);
+                    }
+                    // services of releases before the ABI version was introduced do not
+                    // record it, their layout is verified by the messaging pattern specific
+                    // type details and the version of the dynamic storage
+                    None => match service_config.iceoryx2_version() {
+                        Some(version) if ABI_VERSION_INTRODUCED_IN <= *version => {
+                            fail!(from self, with ServiceState::IncompatibleAbiVersion,
+                                "{} since the service was created with iceoryx2 {} without an ABI version but the ABI version {} is required.",
+                                msg, version, ABI_VERSION);
+                        }
+                        _ => (),
+                    },
+                }
+
+                // the matching ABI version guarantees the layout, services that do not record
+                // the iceoryx2 version are therefore compatible
+                if let Some(version) = service_config.iceoryx2_version() {
+                    let current_version = Iceoryx2Version::current();
+                    if !version.is_compatible_to(&current_version) {
+                        fail!(from self, with ServiceState::IncompatibleIceoryx2Version,
+                            "{} since the service was created with iceoryx2 {} but iceoryx2 {} can only open services created with versions from {} to {}.{}.x.",
+                            msg, version, current_version, MIN_COMPATIBLE_VERSION, current_version.major(), current_version.minor());
+                    }
+                }
+
+                if service_config.domain() != self.service_config.domain() {
+                    fail!(from self, with ServiceState::IncompatibleDomain,
+                        "{} since the service belongs to the domain {:?} but the domain {:?} is required.",
+                        msg, service_config.domain(), self.service_config.domain());
+                }
+
                 Ok(Some((service_config, storage)))
             }
             Err(v) => {
@@ -262,34 +350,40 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         }
     }
 
-    fn config_init_call(config: &mut DynamicConfig, allocator: &mut BumpAllocator) -> bool {
-        unsafe { config.init(allocator) };
-        true
-    }
-
     fn create_dynamic_config_storage(
         &self,
         messaging_pattern: super::dynamic_config::MessagingPattern,
         additional_size: usize,
         max_number_of_nodes: usize,
+        attributes: &AttributeSpecifier,
     ) -> Result<ServiceType::DynamicStorage, DynamicStorageCreateError> {
         let msg = "Failed to create dynamic storage for service";
         let required_memory_size = DynamicConfig::memory_size(max_number_of_nodes);
+        let node_id = *self.shared_node.id();
+        let mut node_handle = None;
+        // The creator registers itself while the storage is initialized. Otherwise another
+        // instance could open the storage before, become its last owner and remove it again.
+        let initializer = |config: &mut DynamicConfig, allocator: &mut BumpAllocator| {
+            unsafe { config.init(allocator) };
+            node_handle = config.register_node_id(node_id).ok();
+            node_handle.is_some()
+        };
+
         match <<ServiceType::DynamicStorage as DynamicStorage<
             DynamicConfig,
         >>::Builder<'_> as NamedConceptBuilder<
             ServiceType::DynamicStorage,
         >>::new(&self.service_config.service_id().0.into())
-            .config(&dynamic_config_storage_config::<ServiceType>(self.shared_node.config()))
+            .config(&dynamic_config_storage_config::<ServiceType>(self.shared_node.config())
+                .access_control_list(self.service_config.access_control_list().copied()))
             .supplementary_size(additional_size + required_memory_size)
             .has_ownership(false)
-            .initializer(Self::config_init_call)
-            .create(DynamicConfig::new_uninit(messaging_pattern, max_number_of_nodes) ) {
+            .initializer(initializer)
+            .create(DynamicConfig::new_uninit(messaging_pattern, max_number_of_nodes, attributes, node_id) ) {
                 Ok(dynamic_storage) => {
-                    let node_id = self.shared_node.id();
                     let node_handle = fatal_panic!(from self,
-                            when dynamic_storage.get().register_node_id(*node_id),
-                            "{} since event the first NodeId could not be registered.", msg);
+                            when node_handle.ok_or(()),
+                            "{} since even the first NodeId could not be registered.", msg);
                     self.shared_node.registered_services().add(self.service_config.service_id(), node_handle);
                     Ok(dynamic_storage)
                 },
@@ -335,6 +429,24 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         Ok(storage)
     }
 
+    fn acquire_service_quota<ErrorType>(
+        &self,
+        error_msg: &str,
+        error_value: ErrorType,
+    ) -> Result<QuotaReservation, ErrorType> {
+        if self.service_config.name().is_builtin() {
+            return Ok(self.shared_node.quota().acquire_builtin_service());
+        }
+
+        match self.shared_node.quota().acquire_service() {
+            Ok(reservation) => Ok(reservation),
+            Err(e) => {
+                fail!(from self, with error_value,
+                    "{} since it would exceed the quota of the node ({:?}).", error_msg, e);
+            }
+        }
+    }
+
     fn create_node_service_tag<ErrorType>(
         &self,
         error_msg: &str,
@@ -369,7 +481,7 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
                     >>::new(&self.service_config.service_id().0.into())
                     .config(&static_config_storage_config::<ServiceType>(
                         self.shared_node.config(),
-                    ))
+                    ).access_control_list(self.service_config.access_control_list().copied()))
                     .has_ownership(true)
                     .create_locked(),
                     "Failed to create static service information since the underlying static storage could not be created."),
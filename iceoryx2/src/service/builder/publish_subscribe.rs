@@ -16,14 +16,18 @@
 //!
 use core::marker::PhantomData;
 
+use crate::node::service_dependencies::notify_service_created;
+use crate::port::out_of_memory_strategy::OutOfMemoryStrategy;
 use crate::service;
 use crate::service::dynamic_config::publish_subscribe::DynamicConfigSettings;
 use crate::service::header::publish_subscribe::Header;
 use crate::service::port_factory::publish_subscribe;
 use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::*;
+use crate::tagged_union::TaggedUnion;
 use builder::RETRY_LIMIT;
 use iceoryx2_bb_elementary::alignment::Alignment;
+use iceoryx2_bb_elementary::stable_type_id::StableTypeId;
 use iceoryx2_bb_log::{fail, fatal_panic, warn};
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use iceoryx2_cal::serialize::Serialize;
@@ -86,6 +90,26 @@ pub enum PublishSubscribeOpenError {
     /// When the call creation call is repeated with a little delay the [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`Service`] has a lower version than required with
+    /// [`Builder::require_version_at_least()`].
+    IncompatibleVersion,
+    /// The [`Service`] was created in another domain, see
+    /// [`Config::set_domain()`](crate::config::Config::set_domain()).
+    IncompatibleDomain,
+    /// The [`Service`] was created by an iceoryx2 version that is incompatible to the version
+    /// of this process, see
+    /// [`Iceoryx2Version::is_compatible_to()`](crate::service::static_config::iceoryx2_version::Iceoryx2Version::is_compatible_to()).
+    IncompatibleIceoryx2Version,
+    /// The [`Service`] was created by a process with a different memory layout of the shared
+    /// data, see [`ABI_VERSION`](crate::service::static_config::abi_version::ABI_VERSION).
+    IncompatibleAbiVersion,
+    /// A different [`Service`] is mapped onto the same underlying resources, since the hashes
+    /// of their [`ServiceName`](crate::service::service_name::ServiceName)s collide, see
+    /// [`ServiceNameHashing`](crate::service::service_id::ServiceNameHashing).
+    ServiceNameHashCollision,
+    /// The [`Service`] was created by a process with a different pointer width and its types
+    /// have a different layout in this process, for instance since they contain a [`usize`].
+    IncompatibleBitness,
 }
 
 impl core::fmt::Display for PublishSubscribeOpenError {
@@ -102,9 +126,27 @@ impl From<ServiceAvailabilityState> for PublishSubscribeOpenError {
             ServiceAvailabilityState::IncompatibleTypes => {
                 PublishSubscribeOpenError::IncompatibleTypes
             }
+            ServiceAvailabilityState::IncompatibleVersion => {
+                PublishSubscribeOpenError::IncompatibleVersion
+            }
             ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern) => {
                 PublishSubscribeOpenError::IncompatibleMessagingPattern
             }
+            ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleDomain) => {
+                PublishSubscribeOpenError::IncompatibleDomain
+            }
+            ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleIceoryx2Version) => {
+                PublishSubscribeOpenError::IncompatibleIceoryx2Version
+            }
+            ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleAbiVersion) => {
+                PublishSubscribeOpenError::IncompatibleAbiVersion
+            }
+            ServiceAvailabilityState::ServiceState(ServiceState::ServiceNameHashCollision) => {
+                PublishSubscribeOpenError::ServiceNameHashCollision
+            }
+            ServiceAvailabilityState::IncompatibleBitness => {
+                PublishSubscribeOpenError::IncompatibleBitness
+            }
             ServiceAvailabilityState::ServiceState(ServiceState::InsufficientPermissions) => {
                 PublishSubscribeOpenError::InsufficientPermissions
             }
@@ -138,6 +180,9 @@ pub enum PublishSubscribeCreateError {
     /// The [`Service`]s creation timeout has passed and it is still not initialized. Can be caused
     /// by a process that crashed during [`Service`] creation.
     HangsInCreation,
+    /// The [`Service`] cannot be created since it would exceed the
+    /// [`NodeQuota`](crate::node::quota::NodeQuota) of the [`Node`](crate::node::Node).
+    ExceedsNodeQuota,
 }
 
 impl core::fmt::Display for PublishSubscribeCreateError {
@@ -152,7 +197,13 @@ impl From<ServiceAvailabilityState> for PublishSubscribeCreateError {
     fn from(value: ServiceAvailabilityState) -> Self {
         match value {
             ServiceAvailabilityState::IncompatibleTypes
-            | ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern) => {
+            | ServiceAvailabilityState::IncompatibleVersion
+            | ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern)
+            | ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleDomain)
+            | ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleIceoryx2Version)
+            | ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleAbiVersion)
+            | ServiceAvailabilityState::ServiceState(ServiceState::ServiceNameHashCollision)
+            | ServiceAvailabilityState::IncompatibleBitness => {
                 PublishSubscribeCreateError::AlreadyExists
             }
             ServiceAvailabilityState::ServiceState(ServiceState::InsufficientPermissions) => {
@@ -172,6 +223,8 @@ impl From<ServiceAvailabilityState> for PublishSubscribeCreateError {
 enum ServiceAvailabilityState {
     ServiceState(ServiceState),
     IncompatibleTypes,
+    IncompatibleVersion,
+    IncompatibleBitness,
 }
 
 /// Errors that can occur when a [`MessagingPattern::PublishSubscribe`] [`Service`] shall be
@@ -224,6 +277,10 @@ pub struct Builder<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: serv
     override_alignment: Option<usize>,
     override_payload_type: Option<TypeDetail>,
     override_user_header_type: Option<TypeDetail>,
+    payload_stable_type_id: Option<u128>,
+    user_header_stable_type_id: Option<u128>,
+    payload_variants: Vec<TypeDetail>,
+    allow_smaller_payload_type: bool,
     verify_number_of_subscribers: bool,
     verify_number_of_publishers: bool,
     verify_subscriber_max_buffer_size: bool,
@@ -231,6 +288,7 @@ pub struct Builder<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: serv
     verify_publisher_history_size: bool,
     verify_enable_safe_overflow: bool,
     verify_max_nodes: bool,
+    required_min_version: Option<u32>,
     _data: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
@@ -248,9 +306,14 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
             verify_subscriber_max_borrowed_samples: false,
             verify_enable_safe_overflow: false,
             verify_max_nodes: false,
+            required_min_version: None,
             override_alignment: None,
             override_payload_type: None,
             override_user_header_type: None,
+            payload_stable_type_id: None,
+            user_header_stable_type_id: None,
+            payload_variants: vec![],
+            allow_smaller_payload_type: false,
             _data: PhantomData,
             _user_header: PhantomData,
         };
@@ -287,11 +350,36 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
     ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
         match self.base.is_service_available(error_msg) {
             Ok(Some((config, storage))) => {
-                if !self
-                    .config_details()
-                    .message_type_details
-                    .is_compatible_to(&config.publish_subscribe().message_type_details)
+                // a changed payload type is expected for an outdated service version,
+                // therefore the version is verified first
+                if let Some(required_min_version) = self.required_min_version {
+                    let version = config.publish_subscribe().version;
+                    if version < required_min_version {
+                        fail!(from self, with ServiceAvailabilityState::IncompatibleVersion,
+                            "{} since the service has the version {} but at least version {} is required.",
+                            error_msg, version, required_min_version);
+                    }
+                }
+
+                let own_details = &self.config_details().message_type_details;
+                let service_details = &config.publish_subscribe().message_type_details;
+
+                if config.has_different_pointer_width()
+                    && own_details.has_different_layout_than(service_details)
                 {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleBitness,
+                        "{} since the service was created by a {}-bit process and offers the type \"{:?}\" which has a different layout than the requested type \"{:?}\" in this {}-bit process.",
+                        error_msg, config.pointer_width().unwrap_or_default(), &config.publish_subscribe().message_type_details,
+                        self.config_details().message_type_details, usize::BITS);
+                }
+
+                let is_compatible = if self.allow_smaller_payload_type {
+                    own_details.is_prefix_compatible_to(service_details)
+                } else {
+                    own_details.is_compatible_to(service_details)
+                };
+
+                if !is_compatible {
                     fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
                         "{} since the service offers the type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
                         error_msg, &config.publish_subscribe().message_type_details , self.config_details().message_type_details);
@@ -306,7 +394,10 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
 
     /// Sets the user header type of the [`Service`].
     pub fn user_header<M: Debug>(self) -> Builder<Payload, M, ServiceType> {
-        unsafe { core::mem::transmute::<Self, Builder<Payload, M, ServiceType>>(self) }
+        let mut builder =
+            unsafe { core::mem::transmute::<Self, Builder<Payload, M, ServiceType>>(self) };
+        builder.user_header_stable_type_id = None;
+        builder
     }
 
     /// If the [`Service`] is created, it defines the [`Alignment`] of the payload for the service. If
@@ -318,6 +409,35 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         self
     }
 
+    /// If an existing [`Service`] is opened, it accepts a service whose fixed size payload type
+    /// is larger than the requested payload type as long as both share the same type name
+    /// and a compatible alignment. It allows to evolve a payload type by appending fields
+    /// without changing the [`ServiceName`](crate::service::service_name::ServiceName).
+    ///
+    /// Only [`Subscriber`](crate::port::subscriber::Subscriber)s can be created from a
+    /// [`Service`] that was opened with a smaller payload type, they see the prefix of the
+    /// payload that corresponds to their type.
+    pub fn allow_smaller_payload_type(mut self, value: bool) -> Self {
+        self.allow_smaller_payload_type = value;
+        self
+    }
+
+    /// If the [`Service`] is created, defines the version of the service. The version is
+    /// ignored when an existing [`Service`] is opened, use
+    /// [`Builder::require_version_at_least()`] to define a requirement.
+    pub fn version(mut self, value: u32) -> Self {
+        self.config_details_mut().version = value;
+        self
+    }
+
+    /// If an existing [`Service`] is opened it requires the service to have at least the
+    /// provided version, otherwise [`PublishSubscribeOpenError::IncompatibleVersion`] is
+    /// returned.
+    pub fn require_version_at_least(mut self, value: u32) -> Self {
+        self.required_min_version = Some(value);
+        self
+    }
+
     /// If the [`Service`] is created, defines the overflow behavior of the service. If an existing
     /// [`Service`] is opened it requires the service to have the defined overflow behavior.
     pub fn enable_safe_overflow(mut self, value: bool) -> Self {
@@ -326,6 +446,24 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         self
     }
 
+    /// If the [`Service`] is created, defines how the [`crate::port::publisher::Publisher`]s
+    /// degrade when their data segment is exhausted. The strategy is ignored when an existing
+    /// [`Service`] is opened.
+    pub fn out_of_memory_strategy(mut self, value: OutOfMemoryStrategy) -> Self {
+        self.config_details_mut().out_of_memory_strategy = value;
+        self
+    }
+
+    /// If the [`Service`] is created, defines if the [`crate::port::publisher::Publisher`]s
+    /// zero the memory of every [`crate::sample_mut::SampleMut`] on loan. It prevents that
+    /// the content of a previously sent [`crate::sample::Sample`] is leaked to a
+    /// [`crate::port::subscriber::Subscriber`] when the new payload is only partially written.
+    /// The setting is ignored when an existing [`Service`] is opened.
+    pub fn zero_payload_on_loan(mut self, value: bool) -> Self {
+        self.config_details_mut().zero_payload_on_loan = value;
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`crate::sample::Sample`] a
     /// [`crate::port::subscriber::Subscriber`] can borrow at most in parallel. If an existing
     /// [`Service`] is opened it defines the minimum required.
@@ -386,31 +524,31 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         let settings = self.base.service_config.publish_subscribe_mut();
 
         if settings.subscriber_max_borrowed_samples == 0 {
-            warn!(from origin,
+            warn!(from origin, fields [setting = "subscriber_max_borrowed_samples", adjusted_value = 1],
                 "Setting the subscribers max borrowed samples to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.subscriber_max_borrowed_samples = 1;
         }
 
         if settings.subscriber_max_buffer_size == 0 {
-            warn!(from origin,
+            warn!(from origin, fields [setting = "subscriber_max_buffer_size", adjusted_value = 1],
                 "Setting the subscribers buffer size to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.subscriber_max_buffer_size = 1;
         }
 
         if settings.max_subscribers == 0 {
-            warn!(from origin,
+            warn!(from origin, fields [setting = "max_subscribers", adjusted_value = 1],
                 "Setting the maximum amount of subscribers to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_subscribers = 1;
         }
 
         if settings.max_publishers == 0 {
-            warn!(from origin,
+            warn!(from origin, fields [setting = "max_publishers", adjusted_value = 1],
                 "Setting the maximum amount of publishers to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_publishers = 1;
         }
 
         if settings.max_nodes == 0 {
-            warn!(from origin,
+            warn!(from origin, fields [setting = "max_nodes", adjusted_value = 1],
                 "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_nodes = 1;
         }
@@ -506,6 +644,7 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
         PublishSubscribeCreateError,
     > {
+        let _log_scope = self.base.shared_node.log_scope();
         self.adjust_configuration_to_meaningful_values();
 
         let msg = "Unable to create publish subscribe service";
@@ -520,6 +659,10 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
 
         match self.is_service_available(msg)? {
             None => {
+                let quota_reservation = self
+                    .base
+                    .acquire_service_quota(msg, PublishSubscribeCreateError::ExceedsNodeQuota)?;
+
                 let service_tag = self
                     .base
                     .create_node_service_tag(msg, PublishSubscribeCreateError::InternalFailure)?;
@@ -563,6 +706,7 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                         &dynamic_config_setting,
                     ),
                     pubsub_config.max_nodes,
+                    attributes,
                 ) {
                     Ok(dynamic_config) => dynamic_config,
                     Err(DynamicStorageCreateError::AlreadyExists) => {
@@ -575,7 +719,7 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                     }
                 };
 
-                self.base.service_config.attributes = attributes.0.clone();
+                self.base.service_config.attributes = attributes.attributes.clone();
                 let service_config = fail!(from self,
                             when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
                             with PublishSubscribeCreateError::ServiceInCorruptedState,
@@ -591,12 +735,15 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                     service_tag.release_ownership();
                 }
 
+                notify_service_created(&self.base.shared_node, self.base.service_config.name());
+
                 Ok(publish_subscribe::PortFactory::new(
                     ServiceType::__internal_from_state(service::ServiceState::new(
                         self.base.service_config.clone(),
                         self.base.shared_node.clone(),
                         dynamic_config,
                         unlocked_static_details,
+                        Some(quota_reservation),
                     )),
                 ))
             }
@@ -614,6 +761,7 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
         PublishSubscribeOpenError,
     > {
+        let _log_scope = self.base.shared_node.log_scope();
         let msg = "Unable to open publish subscribe service";
 
         let mut service_open_retry_count = 0;
@@ -665,6 +813,10 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                         }
                     };
 
+                    let has_smaller_payload_type =
+                        self.config_details().message_type_details.payload.size
+                            < pub_sub_static_config.message_type_details.payload.size;
+
                     self.base.service_config.messaging_pattern =
                         MessagingPattern::PublishSubscribe(pub_sub_static_config.clone());
 
@@ -672,14 +824,18 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                         service_tag.release_ownership();
                     }
 
-                    return Ok(publish_subscribe::PortFactory::new(
+                    let mut port_factory = publish_subscribe::PortFactory::new(
                         ServiceType::__internal_from_state(service::ServiceState::new(
                             static_config,
                             self.base.shared_node.clone(),
                             dynamic_config,
                             static_storage,
+                            None,
                         )),
-                    ));
+                    );
+                    port_factory.has_smaller_payload_type = has_smaller_payload_type;
+
+                    return Ok(port_factory);
                 }
             }
         }
@@ -692,6 +848,7 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
         PublishSubscribeOpenOrCreateError,
     > {
+        let _log_scope = self.base.shared_node.log_scope();
         let msg = "Unable to open or create publish subscribe service";
 
         let mut retry_count = 0;
@@ -711,7 +868,9 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                     Err(e) => return Err(e.into()),
                 },
                 None => {
-                    match self.create_impl(&AttributeSpecifier(attributes.attributes().clone())) {
+                    match self.create_impl(&AttributeSpecifier::from_attributes(
+                        attributes.attributes(),
+                    )) {
                         Ok(factory) => return Ok(factory),
                         Err(PublishSubscribeCreateError::AlreadyExists)
                         | Err(PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance) => {
@@ -724,6 +883,31 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         }
     }
 
+    fn apply_stable_type_ids(&mut self) {
+        if let Some(id) = self.payload_stable_type_id {
+            self.config_details_mut()
+                .message_type_details
+                .payload
+                .stable_type_id = Some(id);
+        }
+
+        if let Some(id) = self.user_header_stable_type_id {
+            self.config_details_mut()
+                .message_type_details
+                .user_header
+                .stable_type_id = Some(id);
+        }
+    }
+
+    fn apply_payload_variants(&mut self) {
+        if !self.payload_variants.is_empty() {
+            self.config_details_mut()
+                .message_type_details
+                .payload
+                .variants = self.payload_variants.clone();
+        }
+    }
+
     fn adjust_payload_alignment(&mut self) {
         if let Some(alignment) = self.override_alignment {
             self.config_details_mut()
@@ -739,6 +923,46 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
     }
 }
 
+impl<Payload: Debug + ?Sized + StableTypeId, UserHeader: Debug, ServiceType: service::Service>
+    Builder<Payload, UserHeader, ServiceType>
+{
+    /// Uses the [`StableTypeId`] of the payload instead of its Rust type name to verify the
+    /// type compatibility when a [`Service`] is opened. The payload type is only considered
+    /// compatible when both sides provide the same [`StableTypeId`], the size, alignment
+    /// and type variant are still verified. This allows to connect to [`Service`]s that were
+    /// created with a type from another crate, language or software version.
+    pub fn use_stable_payload_type_id(mut self) -> Self {
+        self.payload_stable_type_id = Some(Payload::STABLE_TYPE_ID);
+        self
+    }
+}
+
+impl<Payload: Debug + TaggedUnion, UserHeader: Debug, ServiceType: service::Service>
+    Builder<Payload, UserHeader, ServiceType>
+{
+    /// Stores the [`TypeDetail`] of every variant of the [`TaggedUnion`] payload in the
+    /// static config of the [`Service`]. When both sides provide the variants, the
+    /// [`Service`] can only be opened when all variants have the same type, size,
+    /// alignment and order.
+    pub fn use_tagged_union_payload_variants(mut self) -> Self {
+        self.payload_variants = Payload::variant_type_details();
+        self
+    }
+}
+
+impl<Payload: Debug + ?Sized, UserHeader: Debug + StableTypeId, ServiceType: service::Service>
+    Builder<Payload, UserHeader, ServiceType>
+{
+    /// Uses the [`StableTypeId`] of the user header instead of its Rust type name to verify
+    /// the type compatibility when a [`Service`] is opened. Must be called after
+    /// [`Builder::user_header()`].
+    /// See [`Builder::use_stable_payload_type_id()`] for details.
+    pub fn use_stable_user_header_type_id(mut self) -> Self {
+        self.user_header_stable_type_id = Some(UserHeader::STABLE_TYPE_ID);
+        self
+    }
+}
+
 impl<UserHeader: Debug, ServiceType: service::Service>
     Builder<[CustomPayloadMarker], UserHeader, ServiceType>
 {
@@ -774,6 +998,8 @@ impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
             self.config_details_mut().message_type_details.user_header = details.clone();
         }
 
+        self.apply_stable_type_ids();
+        self.apply_payload_variants();
         self.adjust_payload_alignment();
     }
 
@@ -866,6 +1092,8 @@ impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
             self.config_details_mut().message_type_details.user_header = details.clone();
         }
 
+        self.apply_stable_type_ids();
+        self.apply_payload_variants();
         self.adjust_payload_alignment();
     }
 
@@ -13,6 +13,7 @@
 use core::fmt::Debug;
 use core::marker::PhantomData;
 
+use crate::node::service_dependencies::notify_service_created;
 use crate::prelude::{AttributeSpecifier, AttributeVerifier};
 use crate::service::builder::OpenDynamicStorageFailure;
 use crate::service::dynamic_config::request_response::DynamicConfigSettings;
@@ -80,6 +81,23 @@ pub enum RequestResponseOpenError {
     IsMarkedForDestruction,
     /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
     ServiceInCorruptedState,
+    /// The [`Service`] was created in another domain, see
+    /// [`Config::set_domain()`](crate::config::Config::set_domain()).
+    IncompatibleDomain,
+    /// The [`Service`] was created by an iceoryx2 version that is incompatible to the version
+    /// of this process, see
+    /// [`Iceoryx2Version::is_compatible_to()`](crate::service::static_config::iceoryx2_version::Iceoryx2Version::is_compatible_to()).
+    IncompatibleIceoryx2Version,
+    /// The [`Service`] was created by a process with a different memory layout of the shared
+    /// data, see [`ABI_VERSION`](crate::service::static_config::abi_version::ABI_VERSION).
+    IncompatibleAbiVersion,
+    /// A different [`Service`] is mapped onto the same underlying resources, since the hashes
+    /// of their [`ServiceName`](crate::service::service_name::ServiceName)s collide, see
+    /// [`ServiceNameHashing`](crate::service::service_id::ServiceNameHashing).
+    ServiceNameHashCollision,
+    /// The [`Service`] was created by a process with a different pointer width and its types
+    /// have a different layout in this process, for instance since they contain a [`usize`].
+    IncompatibleBitness,
 }
 
 impl core::fmt::Display for RequestResponseOpenError {
@@ -102,6 +120,21 @@ impl From<ServiceAvailabilityState> for RequestResponseOpenError {
             ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern) => {
                 RequestResponseOpenError::IncompatibleMessagingPattern
             }
+            ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleDomain) => {
+                RequestResponseOpenError::IncompatibleDomain
+            }
+            ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleIceoryx2Version) => {
+                RequestResponseOpenError::IncompatibleIceoryx2Version
+            }
+            ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleAbiVersion) => {
+                RequestResponseOpenError::IncompatibleAbiVersion
+            }
+            ServiceAvailabilityState::ServiceState(ServiceState::ServiceNameHashCollision) => {
+                RequestResponseOpenError::ServiceNameHashCollision
+            }
+            ServiceAvailabilityState::IncompatibleBitness => {
+                RequestResponseOpenError::IncompatibleBitness
+            }
             ServiceAvailabilityState::ServiceState(ServiceState::InsufficientPermissions) => {
                 RequestResponseOpenError::InsufficientPermissions
             }
@@ -131,6 +164,9 @@ pub enum RequestResponseCreateError {
     HangsInCreation,
     /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
     ServiceInCorruptedState,
+    /// The [`Service`] cannot be created since it would exceed the
+    /// [`NodeQuota`](crate::node::quota::NodeQuota) of the [`Node`](crate::node::Node).
+    ExceedsNodeQuota,
 }
 
 impl core::fmt::Display for RequestResponseCreateError {
@@ -146,7 +182,12 @@ impl From<ServiceAvailabilityState> for RequestResponseCreateError {
         match value {
             ServiceAvailabilityState::IncompatibleRequestType
             | ServiceAvailabilityState::IncompatibleResponseType
-            | ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern) => {
+            | ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern)
+            | ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleDomain)
+            | ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleIceoryx2Version)
+            | ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleAbiVersion)
+            | ServiceAvailabilityState::ServiceState(ServiceState::ServiceNameHashCollision)
+            | ServiceAvailabilityState::IncompatibleBitness => {
                 RequestResponseCreateError::AlreadyExists
             }
             ServiceAvailabilityState::ServiceState(ServiceState::InsufficientPermissions) => {
@@ -206,6 +247,7 @@ enum ServiceAvailabilityState {
     ServiceState(ServiceState),
     IncompatibleRequestType,
     IncompatibleResponseType,
+    IncompatibleBitness,
 }
 
 /// Builder to create new [`MessagingPattern::RequestResponse`] based [`Service`]s
@@ -437,55 +479,55 @@ impl<
         let settings = self.base.service_config.request_response_mut();
 
         if settings.max_request_buffer_size == 0 {
-            warn!(from origin,
+            warn!(from origin, fields [setting = "max_request_buffer_size", adjusted_value = 1],
                 "Setting the maximum size of the request buffer to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_request_buffer_size = 1;
         }
 
         if settings.max_response_buffer_size == 0 {
-            warn!(from origin,
+            warn!(from origin, fields [setting = "max_response_buffer_size", adjusted_value = 1],
                 "Setting the maximum size of the response buffer to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_response_buffer_size = 1;
         }
 
         if settings.max_active_requests == 0 {
-            warn!(from origin,
+            warn!(from origin, fields [setting = "max_active_requests", adjusted_value = 1],
                 "Setting the maximum number of active requests to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_active_requests = 1;
         }
 
         if settings.max_active_responses == 0 {
-            warn!(from origin,
+            warn!(from origin, fields [setting = "max_active_responses", adjusted_value = 1],
                 "Setting the maximum number of active responses to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_active_responses = 1;
         }
 
         if settings.max_borrowed_responses == 0 {
-            warn!(from origin,
+            warn!(from origin, fields [setting = "max_borrowed_responses", adjusted_value = 1],
                 "Setting the maximum number of borrowed responses to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_borrowed_responses = 1;
         }
 
         if settings.max_borrowed_requests == 0 {
-            warn!(from origin,
+            warn!(from origin, fields [setting = "max_borrowed_requests", adjusted_value = 1],
                 "Setting the maximum number of borrowed requests to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_borrowed_requests = 1;
         }
 
         if settings.max_servers == 0 {
-            warn!(from origin,
+            warn!(from origin, fields [setting = "max_servers", adjusted_value = 1],
                 "Setting the maximum number of servers to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_servers = 1;
         }
 
         if settings.max_clients == 0 {
-            warn!(from origin,
+            warn!(from origin, fields [setting = "max_clients", adjusted_value = 1],
                 "Setting the maximum number of clients to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_clients = 1;
         }
 
         if settings.max_nodes == 0 {
-            warn!(from origin,
+            warn!(from origin, fields [setting = "max_nodes", adjusted_value = 1],
                 "Setting the maximum number of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_nodes = 1;
         }
@@ -624,10 +666,31 @@ impl<
     > {
         match self.base.is_service_available(error_msg) {
             Ok(Some((config, storage))) => {
-                if !self
-                    .config_details()
+                let own_details = self.config_details();
+                let service_details = config.request_response();
+
+                if config.has_different_pointer_width()
+                    && (own_details
+                        .request_message_type_details
+                        .has_different_layout_than(&service_details.request_message_type_details)
+                        || own_details
+                            .response_message_type_details
+                            .has_different_layout_than(
+                                &service_details.response_message_type_details,
+                            ))
+                {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleBitness,
+                        "{} since the service was created by a {}-bit process and uses the request type \"{:?}\" and response type \"{:?}\" which have a different layout than the requested types \"{:?}\" and \"{:?}\" in this {}-bit process.",
+                        error_msg, config.pointer_width().unwrap_or_default(),
+                        &config.request_response().request_message_type_details,
+                        &config.request_response().response_message_type_details,
+                        self.config_details().request_message_type_details,
+                        self.config_details().response_message_type_details, usize::BITS);
+                }
+
+                if !own_details
                     .request_message_type_details
-                    .is_compatible_to(&config.request_response().request_message_type_details)
+                    .is_compatible_to(&service_details.request_message_type_details)
                 {
                     fail!(from self, with ServiceAvailabilityState::IncompatibleRequestType,
                         "{} since the services uses the request type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
@@ -635,10 +698,9 @@ impl<
                         self.config_details().request_message_type_details);
                 }
 
-                if !self
-                    .config_details()
+                if !own_details
                     .response_message_type_details
-                    .is_compatible_to(&config.request_response().response_message_type_details)
+                    .is_compatible_to(&service_details.response_message_type_details)
                 {
                     fail!(from self, with ServiceAvailabilityState::IncompatibleResponseType,
                         "{} since the services uses the response type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
@@ -666,6 +728,7 @@ impl<
         >,
         RequestResponseCreateError,
     > {
+        let _log_scope = self.base.shared_node.log_scope();
         let msg = "Unable to create request response service";
         self.adjust_configuration_to_meaningful_values();
 
@@ -676,6 +739,10 @@ impl<
                     msg);
             }
             None => {
+                let quota_reservation = self
+                    .base
+                    .acquire_service_quota(msg, RequestResponseCreateError::ExceedsNodeQuota)?;
+
                 let service_tag = self
                     .base
                     .create_node_service_tag(msg, RequestResponseCreateError::InternalFailure)?;
@@ -718,6 +785,7 @@ impl<
                         &dynamic_config_setting,
                     ),
                     request_response_config.max_nodes,
+                    attributes,
                 ) {
                     Ok(dynamic_config) => dynamic_config,
                     Err(DynamicStorageCreateError::AlreadyExists) => {
@@ -732,7 +800,7 @@ impl<
                     }
                 };
 
-                self.base.service_config.attributes = attributes.0.clone();
+                self.base.service_config.attributes = attributes.attributes.clone();
                 let serialized_service_config = fail!(from self,
                           when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
                           with RequestResponseCreateError::ServiceInCorruptedState,
@@ -750,12 +818,15 @@ impl<
                     service_tag.release_ownership();
                 }
 
+                notify_service_created(&self.base.shared_node, self.base.service_config.name());
+
                 Ok(request_response::PortFactory::new(
                     ServiceType::__internal_from_state(service::ServiceState::new(
                         self.base.service_config.clone(),
                         self.base.shared_node.clone(),
                         dynamic_config,
                         unlocked_static_details,
+                        Some(quota_reservation),
                     )),
                 ))
             }
@@ -775,6 +846,7 @@ impl<
         >,
         RequestResponseOpenError,
     > {
+        let _log_scope = self.base.shared_node.log_scope();
         const OPEN_RETRY_LIMIT: usize = 5;
         let msg = "Unable to open request response service";
 
@@ -844,6 +916,7 @@ impl<
                             self.base.shared_node.clone(),
                             dynamic_config,
                             static_storage,
+                            None,
                         )),
                     ));
                 }
@@ -864,6 +937,7 @@ impl<
         >,
         RequestResponseOpenOrCreateError,
     > {
+        let _log_scope = self.base.shared_node.log_scope();
         let msg = "Unable to open or create request response service";
 
         let mut retry_count = 0;
@@ -883,7 +957,9 @@ impl<
                     Err(e) => return Err(e.into()),
                 },
                 None => {
-                    match self.create_impl(&AttributeSpecifier(attributes.attributes().clone())) {
+                    match self.create_impl(&AttributeSpecifier::from_attributes(
+                        attributes.attributes(),
+                    )) {
                         Ok(factory) => return Ok(factory),
                         Err(RequestResponseCreateError::AlreadyExists)
                         | Err(RequestResponseCreateError::IsBeingCreatedByAnotherInstance) => {
@@ -13,6 +13,8 @@
 use crate::{config, node::NodeId};
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_cal::named_concept::{NamedConceptConfiguration, NamedConceptMgmt};
+use iceoryx2_cal::shared_memory::HugePageConfiguration;
+use iceoryx2_cal::static_storage::StaticStorageConfiguration;
 
 pub(crate) fn dynamic_config_storage_config<Service: crate::service::Service>(
     global_config: &config::Config,
@@ -28,7 +30,7 @@ pub(crate) fn static_config_storage_config<Service: crate::service::Service>(
 ) -> <Service::StaticStorage as NamedConceptMgmt>::Configuration {
     let origin = "static_config_storage_config";
     let msg = "Unable to generate static config storage directory";
-    let mut path_hint = *global_config.global.root_path();
+    let mut path_hint = *global_config.global.static_storage_root_path();
     fatal_panic!(from origin, when path_hint.add_path_entry(&global_config.global.service.directory),
             "{} since the combination of root directory and service directory entry result in an invalid directory \"{}{}\".",
             msg, path_hint, global_config.global.service.directory);
@@ -37,6 +39,11 @@ pub(crate) fn static_config_storage_config<Service: crate::service::Service>(
         .prefix(&global_config.global.prefix)
         .suffix(&global_config.global.service.static_config_storage_suffix)
         .path_hint(&path_hint)
+        .encryption_key_provider(
+            global_config
+                .global
+                .static_storage_encryption_key_provider(),
+        )
 }
 
 pub(crate) fn connection_config<Service: crate::service::Service>(
@@ -63,7 +70,8 @@ pub(crate) fn data_segment_config<Service: crate::service::Service>(
     <<Service::SharedMemory as NamedConceptMgmt>::Configuration>::default()
         .prefix(&global_config.global.prefix)
         .suffix(&global_config.global.service.publisher_data_segment_suffix)
-        .path_hint(global_config.global.root_path())
+        .path_hint(global_config.global.data_segment_root_path())
+        .huge_pages(global_config.global.service.data_segment_huge_pages())
 }
 
 pub(crate) fn resizable_data_segment_config<Service: crate::service::Service>(
@@ -72,7 +80,8 @@ pub(crate) fn resizable_data_segment_config<Service: crate::service::Service>(
     <<Service::ResizableSharedMemory as NamedConceptMgmt>::Configuration>::default()
         .prefix(&global_config.global.prefix)
         .suffix(&global_config.global.service.publisher_data_segment_suffix)
-        .path_hint(global_config.global.root_path())
+        .path_hint(global_config.global.data_segment_root_path())
+        .huge_pages(global_config.global.service.data_segment_huge_pages())
 }
 
 pub(crate) fn node_monitoring_config<Service: crate::service::Service>(
@@ -81,7 +90,7 @@ pub(crate) fn node_monitoring_config<Service: crate::service::Service>(
     <<Service::Monitoring as NamedConceptMgmt>::Configuration>::default()
         .prefix(&global_config.global.prefix)
         .suffix(&global_config.global.node.monitor_suffix)
-        .path_hint(&global_config.global.node_dir())
+        .path_hint(&global_config.global.node_monitoring_dir())
 }
 
 pub(crate) fn node_details_path(
@@ -103,6 +112,11 @@ pub(crate) fn node_details_config<Service: crate::service::Service>(
         .prefix(&global_config.global.prefix)
         .suffix(&global_config.global.node.static_config_suffix)
         .path_hint(&node_details_path(global_config, node_id))
+        .encryption_key_provider(
+            global_config
+                .global
+                .static_storage_encryption_key_provider(),
+        )
 }
 
 pub(crate) fn service_tag_config<Service: crate::service::Service>(
@@ -113,4 +127,9 @@ pub(crate) fn service_tag_config<Service: crate::service::Service>(
         .prefix(&global_config.global.prefix)
         .suffix(&global_config.global.node.service_tag_suffix)
         .path_hint(&node_details_path(global_config, node_id))
+        .encryption_key_provider(
+            global_config
+                .global
+                .static_storage_encryption_key_provider(),
+        )
 }
@@ -105,6 +105,21 @@ impl DynamicConfig {
         self.notifiers.len()
     }
 
+    pub(crate) fn list_ports<F: FnMut(UniquePortId, &NodeId)>(&self, mut callback: F) {
+        self.__internal_list_listeners(|details| {
+            callback(
+                UniquePortId::Listener(details.listener_id),
+                &details.node_id,
+            )
+        });
+        self.__internal_list_notifiers(|details| {
+            callback(
+                UniquePortId::Notifier(details.notifier_id),
+                &details.node_id,
+            )
+        });
+    }
+
     #[doc(hidden)]
     pub fn __internal_list_listeners<F: FnMut(&ListenerDetails)>(&self, mut callback: F) {
         let state = unsafe { self.listeners.get_state() };
@@ -26,17 +26,28 @@ pub mod publish_subscribe;
 pub mod request_response;
 
 use core::fmt::Display;
+use core::sync::atomic::Ordering;
+use iceoryx2_bb_container::byte_string::FixedSizeByteString;
 use iceoryx2_bb_container::queue::RelocatableContainer;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::{
     container::{Container, ContainerAddFailure, ContainerHandle},
     unique_index_set::{ReleaseMode, ReleaseState},
 };
-use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::UnrestrictedAtomic;
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::scheduler::yield_now;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU32, IoxAtomicU64};
 
 use crate::{node::NodeId, port::port_identifiers::UniquePortId};
 
+use super::attribute::{
+    AttributeSet, AttributeSpecifier, DynamicAttributePermission, DynamicAttributeUpdateError,
+    MAX_DYNAMIC_ATTRIBUTE_KEY_LENGTH, MAX_DYNAMIC_ATTRIBUTE_VALUE_LENGTH,
+    MAX_NUMBER_OF_DYNAMIC_ATTRIBUTES,
+};
+
 #[derive(Debug, PartialEq, Eq)]
 pub(crate) enum PortCleanupAction {
     RemovePort,
@@ -66,11 +77,96 @@ pub(crate) enum MessagingPattern {
     Event(event::DynamicConfig),
 }
 
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct DynamicAttribute {
+    key: FixedSizeByteString<MAX_DYNAMIC_ATTRIBUTE_KEY_LENGTH>,
+    value: FixedSizeByteString<MAX_DYNAMIC_ATTRIBUTE_VALUE_LENGTH>,
+    permission: DynamicAttributePermission,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct DynamicAttributes {
+    len: u64,
+    entries: [DynamicAttribute; MAX_NUMBER_OF_DYNAMIC_ATTRIBUTES],
+}
+
+// The attributes are shared between processes of different bitness, the layout must be
+// identical in 32-bit and 64-bit processes.
+const _: () = assert!(
+    core::mem::size_of::<DynamicAttribute>()
+        == (MAX_DYNAMIC_ATTRIBUTE_KEY_LENGTH + 16) / 8 * 8
+            + (MAX_DYNAMIC_ATTRIBUTE_VALUE_LENGTH + 16) / 8 * 8
+            + 8
+);
+const _: () = assert!(core::mem::offset_of!(DynamicAttributes, entries) == 8);
+
+impl DynamicAttributes {
+    fn new(attributes: &AttributeSpecifier) -> Self {
+        let mut new_self = Self {
+            len: 0,
+            entries: [DynamicAttribute {
+                key: FixedSizeByteString::new(),
+                value: FixedSizeByteString::new(),
+                permission: DynamicAttributePermission::OwnerOnly,
+            }; MAX_NUMBER_OF_DYNAMIC_ATTRIBUTES],
+        };
+
+        // the limits are already enforced by AttributeSpecifier::define_dynamic()
+        for attribute in attributes
+            .dynamic_attributes()
+            .iter()
+            .take(MAX_NUMBER_OF_DYNAMIC_ATTRIBUTES)
+        {
+            new_self.entries[new_self.len as usize] = DynamicAttribute {
+                key: FixedSizeByteString::from_bytes_truncated(attribute.key().as_bytes()),
+                value: FixedSizeByteString::from_bytes_truncated(attribute.value().as_bytes()),
+                permission: attributes
+                    .dynamic_attribute_permission(attribute.key())
+                    .unwrap_or(DynamicAttributePermission::OwnerOnly),
+            };
+            new_self.len += 1;
+        }
+
+        new_self
+    }
+
+    fn attribute_set(&self) -> AttributeSet {
+        let mut attributes = AttributeSet::new();
+        for entry in &self.entries[..self.len as usize] {
+            attributes.add(
+                &String::from_utf8_lossy(entry.key.as_bytes()),
+                &String::from_utf8_lossy(entry.value.as_bytes()),
+            );
+        }
+        attributes
+    }
+}
+
+const DYNAMIC_ATTRIBUTES_WRITER_LIVENESS_CHECK_INTERVAL: u64 = 1024;
+
 #[doc(hidden)]
 #[derive(Debug)]
 pub struct DynamicConfig {
     messaging_pattern: MessagingPattern,
     nodes: Container<NodeId>,
+    dynamic_attributes: UnrestrictedAtomic<DynamicAttributes>,
+    dynamic_attributes_revision: IoxAtomicU64,
+    dynamic_attributes_owner: NodeId,
+    // index + 1 of the slot in `nodes` of the node that currently updates the dynamic
+    // attributes, 0 when no update is in progress
+    dynamic_attributes_writer: IoxAtomicU32,
+}
+
+struct DynamicAttributesWriterGuard<'a> {
+    writer: &'a IoxAtomicU32,
+}
+
+impl Drop for DynamicAttributesWriterGuard<'_> {
+    fn drop(&mut self) {
+        self.writer.store(0, Ordering::Release);
+    }
 }
 
 impl Display for DynamicConfig {
@@ -87,10 +183,16 @@ impl DynamicConfig {
     pub(crate) fn new_uninit(
         messaging_pattern: MessagingPattern,
         max_number_of_nodes: usize,
+        attributes: &AttributeSpecifier,
+        owner: NodeId,
     ) -> Self {
         Self {
             messaging_pattern,
             nodes: unsafe { Container::new_uninit(max_number_of_nodes) },
+            dynamic_attributes: UnrestrictedAtomic::new(DynamicAttributes::new(attributes)),
+            dynamic_attributes_revision: IoxAtomicU64::new(0),
+            dynamic_attributes_owner: owner,
+            dynamic_attributes_writer: IoxAtomicU32::new(0),
         }
     }
 
@@ -130,6 +232,14 @@ impl DynamicConfig {
             .get_state()
             .for_each(|handle: ContainerHandle, registered_node_id| {
                 if registered_node_id == node_id {
+                    // the writer lock of a dead node must be released before its slot can be
+                    // reused by another node
+                    let _ = self.dynamic_attributes_writer.compare_exchange(
+                        handle.index() + 1,
+                        0,
+                        Ordering::Release,
+                        Ordering::Relaxed,
+                    );
                     ret_val = Ok(self.deregister_node_id(handle));
                     CallbackProgression::Stop
                 } else {
@@ -163,6 +273,21 @@ impl DynamicConfig {
         state.for_each(|_, node_id| callback(node_id));
     }
 
+    pub(crate) fn list_ports<F: FnMut(UniquePortId, &NodeId)>(&self, callback: F) {
+        match self.messaging_pattern {
+            MessagingPattern::PublishSubscribe(ref v) => v.list_ports(callback),
+            MessagingPattern::Event(ref v) => v.list_ports(callback),
+            MessagingPattern::RequestResponse(ref v) => v.list_ports(callback),
+        }
+    }
+
+    pub(crate) fn number_of_discarded_samples(&self) -> u64 {
+        match self.messaging_pattern {
+            MessagingPattern::PublishSubscribe(ref v) => v.number_of_discarded_samples(),
+            MessagingPattern::Event(_) | MessagingPattern::RequestResponse(_) => 0,
+        }
+    }
+
     pub(crate) fn is_marked_for_destruction(&self) -> bool {
         self.nodes.is_locked()
     }
@@ -177,6 +302,179 @@ impl DynamicConfig {
         }
     }
 
+    pub(crate) fn dynamic_attributes(&self) -> AttributeSet {
+        self.dynamic_attributes.load().attribute_set()
+    }
+
+    pub(crate) fn dynamic_attributes_revision(&self) -> u64 {
+        self.dynamic_attributes_revision.load(Ordering::Relaxed)
+    }
+
+    fn node_slot(&self, node_id: &NodeId) -> Option<u32> {
+        let mut slot = None;
+        unsafe { self.nodes.get_state() }.for_each(|handle, registered_node_id| {
+            if registered_node_id == node_id {
+                slot = Some(handle.index());
+                CallbackProgression::Stop
+            } else {
+                CallbackProgression::Continue
+            }
+        });
+        slot
+    }
+
+    fn node_id_in_slot(&self, slot: u32) -> Option<NodeId> {
+        let mut node_id = None;
+        unsafe { self.nodes.get_state() }.for_each(|handle, registered_node_id| {
+            if handle.index() == slot {
+                node_id = Some(*registered_node_id);
+                CallbackProgression::Stop
+            } else {
+                CallbackProgression::Continue
+            }
+        });
+        node_id
+    }
+
+    // The writer lock stores the slot of the node that holds it. The node monitoring decides
+    // whether the holder died during an update, so that its lock can be taken over, and is not
+    // affected by reused process ids. Other threads, also of the same node, wait until the lock
+    // is released.
+    fn acquire_dynamic_attributes_writer_lock<IsDeadNode: Fn(&NodeId) -> bool>(
+        &self,
+        node_id: &NodeId,
+        is_dead_node: IsDeadNode,
+    ) -> Option<DynamicAttributesWriterGuard<'_>> {
+        let writer = self.node_slot(node_id)? + 1;
+        let mut checked_holder = 0;
+        let mut number_of_waits: u64 = 0;
+        loop {
+            match self.dynamic_attributes_writer.compare_exchange(
+                0,
+                writer,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(holder) => {
+                    // the liveness check is expensive, it is repeated only occasionally while
+                    // the same holder keeps the lock
+                    if holder != checked_holder
+                        || number_of_waits % DYNAMIC_ATTRIBUTES_WRITER_LIVENESS_CHECK_INTERVAL == 0
+                    {
+                        checked_holder = holder;
+                        let is_abandoned = match self.node_id_in_slot(holder - 1) {
+                            Some(holder_node_id) => is_dead_node(&holder_node_id),
+                            None => true,
+                        };
+
+                        if is_abandoned
+                            && self
+                                .dynamic_attributes_writer
+                                .compare_exchange(
+                                    holder,
+                                    writer,
+                                    Ordering::Acquire,
+                                    Ordering::Relaxed,
+                                )
+                                .is_ok()
+                        {
+                            warn!(from self, fields [slot = holder - 1],
+                                "The node died while updating the dynamic attributes, taking over its writer lock.");
+                            break;
+                        }
+                    }
+
+                    number_of_waits += 1;
+                    yield_now();
+                }
+            }
+        }
+
+        Some(DynamicAttributesWriterGuard {
+            writer: &self.dynamic_attributes_writer,
+        })
+    }
+
+    pub(crate) fn update_dynamic_attribute<IsDeadNode: Fn(&NodeId) -> bool>(
+        &self,
+        key: &str,
+        value: &str,
+        node_id: &NodeId,
+        is_dead_node: IsDeadNode,
+    ) -> Result<(), DynamicAttributeUpdateError> {
+        let msg = "Unable to update dynamic attribute";
+        let new_value = fail!(from self,
+            when FixedSizeByteString::<MAX_DYNAMIC_ATTRIBUTE_VALUE_LENGTH>::from_bytes(value.as_bytes()),
+            with DynamicAttributeUpdateError::ValueTooLong,
+            "{} \"{}\" since the value exceeds the maximum supported length of {}.",
+            msg, key, MAX_DYNAMIC_ATTRIBUTE_VALUE_LENGTH);
+
+        // holding the writer lock serializes all updates, therefore the read-modify-write
+        // sequence below cannot lose concurrent updates
+        let _writer_lock = match self.acquire_dynamic_attributes_writer_lock(node_id, is_dead_node)
+        {
+            Some(writer_lock) => writer_lock,
+            None => {
+                fail!(from self, with DynamicAttributeUpdateError::ServiceInCorruptedState,
+                    "{} \"{}\" since the node {:?} is not registered in the service.",
+                    msg, key, node_id);
+            }
+        };
+
+        let producer = match self.dynamic_attributes.acquire_producer() {
+            Some(producer) => producer,
+            None => {
+                // only the holder of the writer lock acquires the producer, it can only be
+                // still acquired when the previous holder died during the update
+                unsafe { self.dynamic_attributes.release_abandoned_producer() };
+                fatal_panic!(from self, when self.dynamic_attributes.acquire_producer().ok_or(()),
+                    "This should never happen! {} \"{}\" since the released producer could not be acquired.",
+                    msg, key)
+            }
+        };
+
+        let mut attributes = self.dynamic_attributes.load();
+        let entry = match attributes.entries[..attributes.len as usize]
+            .iter_mut()
+            .find(|entry| entry.key.as_bytes() == key.as_bytes())
+        {
+            Some(entry) => entry,
+            None => {
+                fail!(from self, with DynamicAttributeUpdateError::UnknownKey,
+                    "{} \"{}\" since the key was not declared as dynamic attribute.", msg, key);
+            }
+        };
+
+        if entry.permission == DynamicAttributePermission::OwnerOnly
+            && *node_id != self.dynamic_attributes_owner
+        {
+            fail!(from self, with DynamicAttributeUpdateError::InsufficientPermissions,
+                "{} \"{}\" since it can only be updated by the node {:?} that created the service.",
+                msg, key, self.dynamic_attributes_owner);
+        }
+
+        entry.value = new_value;
+        producer.store(attributes);
+        self.dynamic_attributes_revision
+            .fetch_add(1, Ordering::Release);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "testing")]
+    pub(crate) unsafe fn abandon_dynamic_attributes_writer_lock(&self, node_id: &NodeId) {
+        let writer = match self.node_slot(node_id) {
+            Some(slot) => slot + 1,
+            None => fatal_panic!(from self,
+                "The writer lock can only be abandoned by a node {:?} that is registered in the service.",
+                node_id),
+        };
+        core::mem::forget(self.dynamic_attributes.acquire_producer());
+        self.dynamic_attributes_writer
+            .store(writer, Ordering::Release);
+    }
+
     pub(crate) fn request_response(&self) -> &request_response::DynamicConfig {
         match &self.messaging_pattern {
             MessagingPattern::RequestResponse(ref v) => v,
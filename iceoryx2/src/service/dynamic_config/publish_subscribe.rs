@@ -23,19 +23,25 @@
 //!
 //! println!("number of active publishers:      {:?}", pubsub.dynamic_config().number_of_publishers());
 //! println!("number of active subscribers:     {:?}", pubsub.dynamic_config().number_of_subscribers());
+//! println!("allocated data segment bytes:     {:?}", pubsub.dynamic_config().allocated_data_segment_bytes());
+//! println!("number of loaned samples:         {:?}", pubsub.dynamic_config().number_of_loaned_samples());
 //! # Ok(())
 //! # }
 //! ```
+use core::sync::atomic::Ordering;
+use iceoryx2_bb_container::vec::RelocatableVec;
 use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64};
 
 use crate::{
     node::NodeId,
     port::{
-        details::data_segment::DataSegmentType,
+        details::data_segment::{BucketUsage, DataSegmentType},
         port_identifiers::{UniquePortId, UniquePublisherId, UniqueSubscriberId},
+        publisher::MAX_NUMBER_OF_SIZE_CLASSES,
     },
 };
 
@@ -49,24 +55,146 @@ pub(crate) struct DynamicConfigSettings {
 }
 
 #[doc(hidden)]
-#[repr(C)]
+#[repr(C, align(8))]
 #[derive(Debug, Clone, Copy)]
 pub struct PublisherDetails {
     pub publisher_id: UniquePublisherId,
     pub node_id: NodeId,
-    pub number_of_samples: usize,
-    pub max_slice_len: usize,
+    pub number_of_samples: u64,
+    pub max_slice_len: u64,
     pub data_segment_type: DataSegmentType,
     pub max_number_of_segments: u8,
 }
 
 #[doc(hidden)]
-#[repr(C)]
+#[repr(C, align(8))]
 #[derive(Debug, Copy, Clone)]
 pub struct SubscriberDetails {
     pub subscriber_id: UniqueSubscriberId,
     pub node_id: NodeId,
-    pub buffer_size: usize,
+    pub buffer_size: u64,
+}
+
+// The details and counters are shared between processes of different bitness, the layout must
+// be identical in 32-bit and 64-bit processes.
+const _: () = assert!(core::mem::size_of::<PublisherDetails>() == 56);
+const _: () = assert!(core::mem::offset_of!(PublisherDetails, number_of_samples) == 32);
+const _: () = assert!(core::mem::offset_of!(PublisherDetails, data_segment_type) == 48);
+const _: () = assert!(core::mem::size_of::<SubscriberDetails>() == 40);
+const _: () = assert!(core::mem::offset_of!(SubscriberDetails, buffer_size) == 32);
+
+#[repr(C)]
+#[derive(Debug, Default)]
+pub(crate) struct SizeClassUsageCounters {
+    max_slice_len: IoxAtomicU64,
+    number_of_buckets: IoxAtomicU64,
+    number_of_used_buckets: IoxAtomicU64,
+}
+
+#[repr(C)]
+#[derive(Debug, Default)]
+pub(crate) struct PublisherUsageCounters {
+    number_of_loaned_samples: IoxAtomicU64,
+    allocated_data_segment_bytes: IoxAtomicU64,
+    number_of_discarded_samples: IoxAtomicU64,
+    number_of_reclaimed_abandoned_samples: IoxAtomicU64,
+    number_of_size_classes: IoxAtomicU64,
+    size_classes: [SizeClassUsageCounters; MAX_NUMBER_OF_SIZE_CLASSES],
+}
+
+const _: () = assert!(core::mem::size_of::<SizeClassUsageCounters>() == 24);
+const _: () =
+    assert!(core::mem::size_of::<PublisherUsageCounters>() == 40 + 24 * MAX_NUMBER_OF_SIZE_CLASSES);
+
+/// Snapshot of a size class of the data segment of a [`crate::port::publisher::Publisher`],
+/// see
+/// [`PortFactoryPublisher::size_class()`](crate::service::port_factory::publisher::PortFactoryPublisher::size_class()).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SizeClassUsage {
+    max_slice_len: usize,
+    number_of_buckets: usize,
+    number_of_used_buckets: usize,
+}
+
+impl SizeClassUsage {
+    /// Returns the maximum slice length a bucket of the size class can store.
+    pub fn max_slice_len(&self) -> usize {
+        self.max_slice_len
+    }
+
+    /// Returns the number of buckets of the size class.
+    pub fn number_of_buckets(&self) -> usize {
+        self.number_of_buckets
+    }
+
+    /// Returns the number of buckets of the size class that are currently in use.
+    pub fn number_of_used_buckets(&self) -> usize {
+        self.number_of_used_buckets
+    }
+}
+
+/// Snapshot of the resources a [`crate::port::publisher::Publisher`] currently uses. Can be
+/// acquired with [`DynamicConfig::list_publisher_resource_usage()`].
+///
+/// The [`crate::port::publisher::Publisher`] samples its usage at most every 100ms while it
+/// loans and sends samples, and on every call to
+/// [`UpdateConnections::update_connections()`](crate::port::update_connections::UpdateConnections::update_connections()).
+/// The snapshot can therefore lag behind the actual usage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublisherResourceUsage {
+    publisher_id: UniquePublisherId,
+    node_id: NodeId,
+    number_of_loaned_samples: usize,
+    allocated_data_segment_bytes: usize,
+    number_of_discarded_samples: u64,
+    number_of_reclaimed_abandoned_samples: u64,
+    number_of_size_classes: usize,
+    size_classes: [SizeClassUsage; MAX_NUMBER_OF_SIZE_CLASSES],
+}
+
+impl PublisherResourceUsage {
+    /// Returns the [`UniquePublisherId`] of the [`crate::port::publisher::Publisher`].
+    pub fn publisher_id(&self) -> UniquePublisherId {
+        self.publisher_id
+    }
+
+    /// Returns the [`NodeId`] of the [`crate::node::Node`] that owns the
+    /// [`crate::port::publisher::Publisher`].
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the number of samples the [`crate::port::publisher::Publisher`] has currently
+    /// loaned and not yet sent or released.
+    pub fn number_of_loaned_samples(&self) -> usize {
+        self.number_of_loaned_samples
+    }
+
+    /// Returns the size in bytes of the data segment the
+    /// [`crate::port::publisher::Publisher`] has currently allocated.
+    pub fn allocated_data_segment_bytes(&self) -> usize {
+        self.allocated_data_segment_bytes
+    }
+
+    /// Returns the number of samples the [`crate::port::publisher::Publisher`] could not
+    /// deliver or that were overridden since the buffer of a
+    /// [`crate::port::subscriber::Subscriber`] was full.
+    pub fn number_of_discarded_samples(&self) -> u64 {
+        self.number_of_discarded_samples
+    }
+
+    /// Returns the number of samples the [`crate::port::publisher::Publisher`] reclaimed
+    /// from [`crate::port::subscriber::Subscriber`]s whose [`crate::node::Node`] died.
+    pub fn number_of_reclaimed_abandoned_samples(&self) -> u64 {
+        self.number_of_reclaimed_abandoned_samples
+    }
+
+    /// Returns the [`SizeClassUsage`] of every size class of the data segment, ordered by
+    /// their slice length. A data segment without additional size classes consists of one
+    /// size class. It is empty when the data segment is dynamic.
+    pub fn size_classes(&self) -> &[SizeClassUsage] {
+        &self.size_classes[..self.number_of_size_classes]
+    }
 }
 
 /// The dynamic configuration of an [`crate::service::messaging_pattern::MessagingPattern::Event`]
@@ -76,6 +204,9 @@ pub struct SubscriberDetails {
 pub struct DynamicConfig {
     pub(crate) subscribers: Container<SubscriberDetails>,
     pub(crate) publishers: Container<PublisherDetails>,
+    publisher_usage: RelocatableVec<PublisherUsageCounters>,
+    subscriber_wait_states: RelocatableVec<IoxAtomicBool>,
+    number_of_waiting_subscribers: IoxAtomicU64,
 }
 
 impl DynamicConfig {
@@ -83,6 +214,11 @@ impl DynamicConfig {
         Self {
             subscribers: unsafe { Container::new_uninit(config.number_of_subscribers) },
             publishers: unsafe { Container::new_uninit(config.number_of_publishers) },
+            publisher_usage: unsafe { RelocatableVec::new_uninit(config.number_of_publishers) },
+            subscriber_wait_states: unsafe {
+                RelocatableVec::new_uninit(config.number_of_subscribers)
+            },
+            number_of_waiting_subscribers: IoxAtomicU64::new(0),
         }
     }
 
@@ -93,11 +229,25 @@ impl DynamicConfig {
         fatal_panic!(from self,
             when self.publishers.init(allocator),
             "This should never happen! Unable to initialize publisher port id container.");
+        fatal_panic!(from self,
+            when self.publisher_usage.init(allocator),
+            "This should never happen! Unable to initialize publisher usage counters.");
+        for _ in 0..self.publisher_usage.capacity() {
+            self.publisher_usage.push(PublisherUsageCounters::default());
+        }
+        fatal_panic!(from self,
+            when self.subscriber_wait_states.init(allocator),
+            "This should never happen! Unable to initialize subscriber wait states.");
+        for _ in 0..self.subscriber_wait_states.capacity() {
+            self.subscriber_wait_states.push(IoxAtomicBool::new(false));
+        }
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
         Container::<SubscriberDetails>::memory_size(config.number_of_subscribers)
             + Container::<PublisherDetails>::memory_size(config.number_of_publishers)
+            + RelocatableVec::<PublisherUsageCounters>::memory_size(config.number_of_publishers)
+            + RelocatableVec::<IoxAtomicBool>::memory_size(config.number_of_subscribers)
     }
 
     pub(crate) unsafe fn remove_dead_node_id<
@@ -144,6 +294,100 @@ impl DynamicConfig {
         self.subscribers.len()
     }
 
+    /// Returns the accumulated size in bytes of the data segments of all connected
+    /// [`crate::port::publisher::Publisher`] ports.
+    pub fn allocated_data_segment_bytes(&self) -> usize {
+        let mut bytes = 0;
+        self.list_publisher_resource_usage(|usage| {
+            bytes += usage.allocated_data_segment_bytes();
+            CallbackProgression::Continue
+        });
+        bytes
+    }
+
+    /// Returns the number of samples that are currently loaned by all connected
+    /// [`crate::port::publisher::Publisher`] ports.
+    pub fn number_of_loaned_samples(&self) -> usize {
+        let mut number_of_loaned_samples = 0;
+        self.list_publisher_resource_usage(|usage| {
+            number_of_loaned_samples += usage.number_of_loaned_samples();
+            CallbackProgression::Continue
+        });
+        number_of_loaned_samples
+    }
+
+    /// Returns the number of samples all connected [`crate::port::publisher::Publisher`] ports
+    /// discarded since the buffer of a [`crate::port::subscriber::Subscriber`] was full.
+    pub fn number_of_discarded_samples(&self) -> u64 {
+        let mut number_of_discarded_samples = 0;
+        self.list_publisher_resource_usage(|usage| {
+            number_of_discarded_samples += usage.number_of_discarded_samples();
+            CallbackProgression::Continue
+        });
+        number_of_discarded_samples
+    }
+
+    /// Iterates over all connected [`crate::port::publisher::Publisher`] ports and provides
+    /// the [`PublisherResourceUsage`] of every port to the callback.
+    pub fn list_publisher_resource_usage<
+        F: FnMut(&PublisherResourceUsage) -> CallbackProgression,
+    >(
+        &self,
+        mut callback: F,
+    ) {
+        let state = unsafe { self.publishers.get_state() };
+
+        state.for_each(|handle, details| {
+            let counters = &self.publisher_usage[handle.index() as usize];
+            let mut size_classes = [SizeClassUsage::default(); MAX_NUMBER_OF_SIZE_CLASSES];
+            for (usage, counter) in size_classes.iter_mut().zip(counters.size_classes.iter()) {
+                *usage = SizeClassUsage {
+                    max_slice_len: counter.max_slice_len.load(Ordering::Relaxed) as usize,
+                    number_of_buckets: counter.number_of_buckets.load(Ordering::Relaxed) as usize,
+                    number_of_used_buckets: counter.number_of_used_buckets.load(Ordering::Relaxed)
+                        as usize,
+                };
+            }
+
+            callback(&PublisherResourceUsage {
+                publisher_id: details.publisher_id,
+                node_id: details.node_id,
+                number_of_loaned_samples: counters.number_of_loaned_samples.load(Ordering::Relaxed)
+                    as usize,
+                allocated_data_segment_bytes: counters
+                    .allocated_data_segment_bytes
+                    .load(Ordering::Relaxed) as usize,
+                number_of_discarded_samples: counters
+                    .number_of_discarded_samples
+                    .load(Ordering::Relaxed),
+                number_of_reclaimed_abandoned_samples: counters
+                    .number_of_reclaimed_abandoned_samples
+                    .load(Ordering::Relaxed),
+                number_of_size_classes: counters
+                    .number_of_size_classes
+                    .load(Ordering::Relaxed)
+                    .min(MAX_NUMBER_OF_SIZE_CLASSES as u64)
+                    as usize,
+                size_classes,
+            })
+        });
+    }
+
+    pub(crate) fn list_ports<F: FnMut(UniquePortId, &NodeId)>(&self, mut callback: F) {
+        self.__internal_list_publishers(|details| {
+            callback(
+                UniquePortId::Publisher(details.publisher_id),
+                &details.node_id,
+            )
+        });
+        self.__internal_list_subscribers(|details| {
+            callback(
+                UniquePortId::Subscriber(details.subscriber_id),
+                &details.node_id,
+            )
+        });
+    }
+
     #[doc(hidden)]
     pub fn __internal_list_subscribers<F: FnMut(&SubscriberDetails)>(&self, mut callback: F) {
         let state = unsafe { self.subscribers.get_state() };
@@ -169,14 +413,88 @@ impl DynamicConfig {
     }
 
     pub(crate) fn release_subscriber_handle(&self, handle: ContainerHandle) {
+        self.set_subscriber_waiting(handle.index() as usize, false);
         unsafe { self.subscribers.remove(handle, ReleaseMode::Default) };
     }
 
+    // a subscriber that waits in its blocking receive calls is woken up by the publishers
+    // via its event listener, the publishers notify only the waiting subscribers
+    pub(crate) fn set_subscriber_waiting(&self, index: usize, value: bool) {
+        if value {
+            self.number_of_waiting_subscribers
+                .fetch_add(1, Ordering::SeqCst);
+            self.subscriber_wait_states[index].store(true, Ordering::SeqCst);
+        } else if self.subscriber_wait_states[index].swap(false, Ordering::SeqCst) {
+            self.number_of_waiting_subscribers
+                .fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    // publishers check it on every delivery, the wait states are only inspected when at
+    // least one subscriber waits
+    pub(crate) fn number_of_waiting_subscribers(&self) -> u64 {
+        self.number_of_waiting_subscribers.load(Ordering::Acquire)
+    }
+
+    pub(crate) fn is_subscriber_waiting(&self, index: usize) -> bool {
+        self.subscriber_wait_states[index].load(Ordering::SeqCst)
+    }
+
     pub(crate) fn add_publisher_id(&self, details: PublisherDetails) -> Option<ContainerHandle> {
         unsafe { self.publishers.add(details).ok() }
     }
 
     pub(crate) fn release_publisher_handle(&self, handle: ContainerHandle) {
+        self.update_publisher_resource_usage(handle.index() as usize, 0, 0, 0, 0);
+        self.publisher_usage[handle.index() as usize]
+            .number_of_size_classes
+            .store(0, Ordering::Relaxed);
         unsafe { self.publishers.remove(handle, ReleaseMode::Default) };
     }
+
+    pub(crate) fn update_publisher_resource_usage(
+        &self,
+        index: usize,
+        number_of_loaned_samples: usize,
+        allocated_data_segment_bytes: usize,
+        number_of_discarded_samples: u64,
+        number_of_reclaimed_abandoned_samples: u64,
+    ) {
+        let counters = &self.publisher_usage[index];
+        counters
+            .number_of_loaned_samples
+            .store(number_of_loaned_samples as u64, Ordering::Relaxed);
+        counters
+            .allocated_data_segment_bytes
+            .store(allocated_data_segment_bytes as u64, Ordering::Relaxed);
+        counters
+            .number_of_discarded_samples
+            .store(number_of_discarded_samples, Ordering::Relaxed);
+        counters
+            .number_of_reclaimed_abandoned_samples
+            .store(number_of_reclaimed_abandoned_samples, Ordering::Relaxed);
+    }
+
+    pub(crate) fn update_publisher_size_class_usage(
+        &self,
+        index: usize,
+        size_class: usize,
+        max_slice_len: usize,
+        usage: BucketUsage,
+    ) {
+        let counters = &self.publisher_usage[index];
+        let size_class_counters = &counters.size_classes[size_class];
+        size_class_counters
+            .max_slice_len
+            .store(max_slice_len as u64, Ordering::Relaxed);
+        size_class_counters
+            .number_of_buckets
+            .store(usage.number_of_buckets as u64, Ordering::Relaxed);
+        size_class_counters
+            .number_of_used_buckets
+            .store(usage.number_of_used_buckets as u64, Ordering::Relaxed);
+        counters
+            .number_of_size_classes
+            .fetch_max(size_class as u64 + 1, Ordering::Relaxed);
+    }
 }
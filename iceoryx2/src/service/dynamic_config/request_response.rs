@@ -24,22 +24,30 @@ use crate::{
 use super::PortCleanupAction;
 
 #[doc(hidden)]
-#[repr(C)]
+#[repr(C, align(8))]
 #[derive(Debug, Clone, Copy)]
 pub struct ServerDetails {
     pub server_port_id: UniqueServerId,
-    pub buffer_size: usize,
+    pub node_id: NodeId,
+    pub buffer_size: u64,
 }
 
 #[doc(hidden)]
-#[repr(C)]
+#[repr(C, align(8))]
 #[derive(Debug, Clone, Copy)]
 pub struct ClientDetails {
     pub client_port_id: UniqueClientId,
     pub node_id: NodeId,
-    pub number_of_requests: usize,
+    pub number_of_requests: u64,
 }
 
+// The details are shared between processes of different bitness, the layout must be identical
+// in 32-bit and 64-bit processes.
+const _: () = assert!(core::mem::size_of::<ServerDetails>() == 40);
+const _: () = assert!(core::mem::offset_of!(ServerDetails, buffer_size) == 32);
+const _: () = assert!(core::mem::size_of::<ClientDetails>() == 40);
+const _: () = assert!(core::mem::offset_of!(ClientDetails, number_of_requests) == 32);
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct DynamicConfigSettings {
@@ -107,6 +115,21 @@ impl DynamicConfig {
         unsafe { self.clients.remove(handle, ReleaseMode::Default) };
     }
 
+    pub(crate) fn list_ports<F: FnMut(UniquePortId, &NodeId)>(&self, mut callback: F) {
+        self.__internal_list_servers(|details| {
+            callback(
+                UniquePortId::Server(details.server_port_id),
+                &details.node_id,
+            )
+        });
+        self.__internal_list_publishers(|details| {
+            callback(
+                UniquePortId::Client(details.client_port_id),
+                &details.node_id,
+            )
+        });
+    }
+
     #[doc(hidden)]
     pub fn __internal_list_servers<F: FnMut(&ServerDetails)>(&self, mut callback: F) {
         let state = unsafe { self.servers.get_state() };
@@ -30,24 +30,163 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Provenance
+//!
+//! A forwarding stage of a multi-stage pipeline can append the origin of the received sample to
+//! the sample it forwards, see [`SampleMut::append_provenance()`](crate::sample_mut::SampleMut::append_provenance()).
+//! The end consumer can reconstruct the processing path and the latency of every stage from
+//! [`Header::provenance()`].
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let input = node.service_builder(&"Pipeline/Input".try_into()?)
+//! #     .publish_subscribe::<u64>()
+//! #     .open_or_create()?;
+//! # let output = node.service_builder(&"Pipeline/Output".try_into()?)
+//! #     .publish_subscribe::<u64>()
+//! #     .open_or_create()?;
+//! let subscriber = input.subscriber_builder().create()?;
+//! let publisher = output.publisher_builder().create()?;
+//!
+//! while let Some(input_sample) = subscriber.receive()? {
+//!     let mut sample = publisher.loan_uninit()?;
+//!     sample.append_provenance(input_sample.header())?;
+//!     sample.write_payload(*input_sample * 2).send()?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
 
+use iceoryx2_bb_posix::clock::{ClockType, Time, TimeBuilder};
+
+use crate::node::NodeId;
 use crate::port::port_identifiers::UniquePublisherId;
 
+/// The maximum number of [`ProvenanceRecord`]s a [`Header`] can contain.
+pub const MAX_PROVENANCE_DEPTH: usize = 4;
+
+/// Defines the failures that can occur when a [`ProvenanceRecord`] is appended to a sample with
+/// [`SampleMut::append_provenance()`](crate::sample_mut::SampleMut::append_provenance()).
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ProvenanceError {
+    /// The provenance chain of the source sample already contains [`MAX_PROVENANCE_DEPTH`]
+    /// records.
+    ExceedsMaxDepth,
+}
+
+impl core::fmt::Display for ProvenanceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ProvenanceError::{:?}", self)
+    }
+}
+
+impl core::error::Error for ProvenanceError {}
+
+/// Describes a previous stage of a multi-stage pipeline that a sample passed. Contains the
+/// [`UniquePublisherId`] of the [`Publisher`](crate::port::publisher::Publisher) that sent the
+/// upstream sample and the time it was sent.
+#[derive(Debug, Copy, Clone)]
+#[repr(C, align(8))]
+pub struct ProvenanceRecord {
+    publisher_port_id: UniquePublisherId,
+    timestamp_seconds: u64,
+    timestamp_nanoseconds: u32,
+}
+
+impl ProvenanceRecord {
+    /// Returns the [`UniquePublisherId`] of the [`Publisher`](crate::port::publisher::Publisher)
+    /// that sent the upstream sample.
+    pub fn publisher_id(&self) -> UniquePublisherId {
+        self.publisher_port_id
+    }
+
+    /// Returns the time of the [`ClockType::Monotonic`] when the upstream sample was sent.
+    pub fn timestamp(&self) -> Time {
+        TimeBuilder::new()
+            .clock_type(ClockType::Monotonic)
+            .seconds(self.timestamp_seconds)
+            .nanoseconds(self.timestamp_nanoseconds)
+            .create()
+    }
+}
+
 /// Sample header used by
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 #[derive(Debug, Copy, Clone)]
-#[repr(C)]
+#[repr(C, align(8))]
 pub struct Header {
     publisher_port_id: UniquePublisherId,
+    node_id: NodeId,
     number_of_elements: u64,
+    sequence_number: u64,
+    timestamp_seconds: u64,
+    timestamp_nanoseconds: u32,
+    priority: u8,
+    provenance_depth: u8,
+    provenance: [ProvenanceRecord; MAX_PROVENANCE_DEPTH],
 }
 
+// The header is shared between processes of different bitness, the layout must be identical
+// in 32-bit and 64-bit processes.
+const _: () = assert!(core::mem::size_of::<ProvenanceRecord>() == 32);
+const _: () = assert!(core::mem::offset_of!(ProvenanceRecord, timestamp_seconds) == 16);
+const _: () = assert!(core::mem::size_of::<Header>() == 64 + 32 * MAX_PROVENANCE_DEPTH);
+const _: () = assert!(core::mem::offset_of!(Header, number_of_elements) == 32);
+const _: () = assert!(core::mem::offset_of!(Header, timestamp_nanoseconds) == 56);
+const _: () = assert!(core::mem::offset_of!(Header, provenance) == 64);
+
 impl Header {
-    pub(crate) fn new(publisher_port_id: UniquePublisherId, number_of_elements: u64) -> Self {
+    pub(crate) fn new(
+        publisher_port_id: UniquePublisherId,
+        node_id: NodeId,
+        number_of_elements: u64,
+    ) -> Self {
         Self {
             publisher_port_id,
+            node_id,
             number_of_elements,
+            sequence_number: 0,
+            timestamp_seconds: 0,
+            timestamp_nanoseconds: 0,
+            priority: 0,
+            provenance_depth: 0,
+            provenance: [ProvenanceRecord {
+                publisher_port_id,
+                timestamp_seconds: 0,
+                timestamp_nanoseconds: 0,
+            }; MAX_PROVENANCE_DEPTH],
+        }
+    }
+
+    pub(crate) fn set_send_details(&mut self, sequence_number: u64, timestamp: Time) {
+        self.sequence_number = sequence_number;
+        self.timestamp_seconds = timestamp.seconds();
+        self.timestamp_nanoseconds = timestamp.nanoseconds();
+    }
+
+    pub(crate) fn set_priority(&mut self, priority: u8) {
+        self.priority = priority;
+    }
+
+    pub(crate) fn append_provenance(&mut self, source: &Header) -> Result<(), ProvenanceError> {
+        let depth = source.provenance_depth as usize;
+        if depth == MAX_PROVENANCE_DEPTH {
+            return Err(ProvenanceError::ExceedsMaxDepth);
         }
+
+        self.provenance[..depth].copy_from_slice(&source.provenance[..depth]);
+        self.provenance[depth] = ProvenanceRecord {
+            publisher_port_id: source.publisher_port_id,
+            timestamp_seconds: source.timestamp_seconds,
+            timestamp_nanoseconds: source.timestamp_nanoseconds,
+        };
+        self.provenance_depth = (depth + 1) as u8;
+
+        Ok(())
     }
 
     /// Returns the [`UniquePublisherId`] of the source [`crate::port::publisher::Publisher`].
@@ -55,6 +194,44 @@ impl Header {
         self.publisher_port_id
     }
 
+    /// Returns the [`NodeId`] of the [`Node`](crate::node::Node) that owns the source
+    /// [`crate::port::publisher::Publisher`].
+    pub fn node_id(&self) -> NodeId {
+        self.node_id
+    }
+
+    /// Returns the sequence number of the sample. Every
+    /// [`crate::port::publisher::Publisher`] starts with zero and increments it with every
+    /// sent sample, so that a gap indicates a lost sample. It is set when the sample is sent.
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    /// Returns the time of the [`ClockType::Monotonic`] when the sample was sent.
+    pub fn timestamp(&self) -> Time {
+        TimeBuilder::new()
+            .clock_type(ClockType::Monotonic)
+            .seconds(self.timestamp_seconds)
+            .nanoseconds(self.timestamp_nanoseconds)
+            .create()
+    }
+
+    /// Returns the priority of the sample. A higher value means a higher priority, the default
+    /// is `0`. A [`Subscriber`](crate::port::subscriber::Subscriber) that was created with
+    /// [`PortFactorySubscriber::receive_by_priority()`](crate::service::port_factory::subscriber::PortFactorySubscriber::receive_by_priority())
+    /// receives pending samples with a higher priority first.
+    pub fn priority(&self) -> u8 {
+        self.priority
+    }
+
+    /// Returns the [`ProvenanceRecord`]s of all previous pipeline stages the sample passed,
+    /// starting with the origin. The latency of a stage is the difference between the timestamp
+    /// of its record and the timestamp of the next record or, for the last stage,
+    /// [`Header::timestamp()`]. It is empty when no provenance was appended.
+    pub fn provenance(&self) -> &[ProvenanceRecord] {
+        &self.provenance[..self.provenance_depth as usize]
+    }
+
     /// Returns how many elements are stored inside the sample's payload.
     ///
     /// # Details when using
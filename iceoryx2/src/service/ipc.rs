@@ -56,8 +56,16 @@ impl crate::service::Service for Service {
     type ResizableSharedMemory =
         resizable_shared_memory::dynamic::DynamicMemory<PoolAllocator, Self::SharedMemory>;
     type Connection = zero_copy_connection::posix_shared_memory::Connection;
+    #[cfg(not(feature = "io_uring_event"))]
     type Event = event::unix_datagram_socket::EventImpl;
+    #[cfg(feature = "io_uring_event")]
+    type Event = event::io_uring::EventImpl;
+    #[cfg(not(any(feature = "robust_mutex_monitoring", feature = "pidfd_monitoring")))]
     type Monitoring = monitoring::file_lock::FileLockMonitoring;
+    #[cfg(all(feature = "robust_mutex_monitoring", not(feature = "pidfd_monitoring")))]
+    type Monitoring = monitoring::robust_mutex::RobustMutexMonitoring;
+    #[cfg(feature = "pidfd_monitoring")]
+    type Monitoring = monitoring::pidfd::PidFdMonitoring;
     type Reactor = reactor::posix_select::Reactor;
 }
 
@@ -158,12 +158,19 @@ pub mod static_config;
 /// Represents static features of a service that can be set when a [`Service`] is created.
 pub mod attribute;
 
+/// End-to-end self test of a [`Service`] that can be used as startup health check.
+pub mod self_test;
+
 /// A configuration when communicating within a single process or single address space.
 pub mod local;
 
 /// A configuration when communicating between different processes using posix mechanisms.
 pub mod ipc;
 
+/// A configuration when communicating within a single address space, like on a
+/// microcontroller, using only statically allocated memory.
+pub mod static_memory;
+
 pub(crate) mod config_scheme;
 pub(crate) mod naming_scheme;
 
@@ -174,7 +181,9 @@ extern crate alloc;
 use alloc::sync::Arc;
 
 use crate::config;
+use crate::node::quota::QuotaReservation;
 use crate::node::{NodeId, NodeListFailure, NodeState, SharedNode};
+use crate::port::port_identifiers::UniquePortId;
 use crate::service::config_scheme::dynamic_config_storage_config;
 use crate::service::dynamic_config::DynamicConfig;
 use crate::service::static_config::*;
@@ -216,6 +225,12 @@ pub(crate) enum ServiceRemoveTagError {
     InsufficientPermissions,
 }
 
+impl core::fmt::Display for ServiceRemoveTagError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ServiceRemoveTagError::{:?}", self)
+    }
+}
+
 /// Failure that can be reported when the [`ServiceDetails`] are acquired with [`Service::details()`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ServiceDetailsError {
@@ -261,12 +276,31 @@ impl core::fmt::Display for ServiceListError {
 
 impl core::error::Error for ServiceListError {}
 
+/// Describes a port that is connected to a [`Service`] and the [`Node`](crate::node::Node)
+/// that owns it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ServicePortDetails {
+    /// The [`UniquePortId`] of the port.
+    pub port_id: UniquePortId,
+    /// The [`NodeId`] of the [`Node`](crate::node::Node) that owns the port.
+    pub node_id: NodeId,
+}
+
 /// Represents all the [`Service`] information that one can acquire with [`Service::list()`]
 /// when the [`Service`] is accessible by the current process.
 #[derive(Debug)]
 pub struct ServiceDynamicDetails<S: Service> {
     /// A list of all [`Node`](crate::node::Node)s that a registered at the [`Service`]
     pub nodes: Vec<NodeState<S>>,
+    /// A list of all ports that are currently connected to the [`Service`]
+    pub ports: Vec<ServicePortDetails>,
+    /// The current dynamic [`AttributeSet`](crate::service::attribute::AttributeSet) of the
+    /// [`Service`]
+    pub dynamic_attributes: attribute::AttributeSet,
+    /// The number of samples all publishers of a
+    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+    /// [`Service`] discarded since the buffer of a subscriber was full
+    pub number_of_discarded_samples: u64,
 }
 
 /// Represents all the [`Service`] information that one can acquire with [`Service::list()`].
@@ -286,6 +320,7 @@ pub struct ServiceState<S: Service> {
     pub(crate) shared_node: Arc<SharedNode<S>>,
     pub(crate) dynamic_storage: S::DynamicStorage,
     pub(crate) static_storage: S::StaticStorage,
+    _quota_reservation: Option<QuotaReservation>,
 }
 
 impl<S: Service> ServiceState<S> {
@@ -294,12 +329,14 @@ impl<S: Service> ServiceState<S> {
         shared_node: Arc<SharedNode<S>>,
         dynamic_storage: S::DynamicStorage,
         static_storage: S::StaticStorage,
+        quota_reservation: Option<QuotaReservation>,
     ) -> Self {
         let new_self = Self {
             static_config,
             shared_node,
             dynamic_storage,
             static_storage,
+            _quota_reservation: quota_reservation,
         };
         trace!(from "Service::open()", "open service: {} ({:?})",
             new_self.static_config.name(), new_self.static_config.service_id());
@@ -312,10 +349,11 @@ impl<S: Service> Drop for ServiceState<S> {
         let origin = "ServiceState::drop()";
         let id = self.static_config.service_id();
         self.shared_node.registered_services().remove(id, |handle| {
-            if let Err(e) = remove_service_tag::<S>(self.shared_node.id(), id, self.shared_node.config())
+            if let Err(e) =
+                remove_service_tag::<S>(self.shared_node.id(), id, self.shared_node.config())
             {
-                debug!(from origin, "The service tag could not be removed from the node {:?} ({:?}).",
-                        self.shared_node.id(), e);
+                debug!(from origin, fields [node_id = self.shared_node.id().value(), error = e],
+                        "The service tag could not be removed from the node.");
             }
 
             match self.dynamic_storage.get().deregister_node_id(handle) {
@@ -349,6 +387,7 @@ pub(crate) mod internal {
                 remove_data_segment_of_publisher, remove_publisher_from_all_connections,
                 remove_subscriber_from_all_connections,
             },
+            subscriber::remove_listener_of_subscriber,
         },
         prelude::EventId,
     };
@@ -362,9 +401,8 @@ pub(crate) mod internal {
             Ok(Some(service_details)) => service_details,
             Ok(None) => return,
             Err(e) => {
-                warn!(from origin,
-                    "Unable to acquire service details to emit dead node signal to waiting listeners for the service id {:?} due to ({:?})",
-                    service_id, e);
+                warn!(from origin, fields [service_id = service_id.as_str(), error = e],
+                    "Unable to acquire service details to emit dead node signal to waiting listeners.");
                 return;
             }
         };
@@ -378,9 +416,8 @@ pub(crate) mod internal {
         let node = match NodeBuilder::new().config(&config).create::<S>() {
             Ok(node) => node,
             Err(e) => {
-                warn!(from origin,
-                                "Unable to create node to emit dead node signal to waiting listeners on the service {} due to ({:?}).",
-                                service_name, e);
+                warn!(from origin, fields [service = service_name, error = e],
+                    "Unable to create node to emit dead node signal to waiting listeners.");
                 return;
             }
         };
@@ -389,9 +426,8 @@ pub(crate) mod internal {
             Ok(service) => service,
             Err(EventOpenError::DoesNotExist) => return,
             Err(e) => {
-                warn!(from origin,
-                                "Unable to open event service to emit dead node signal to waiting listeners on the service {} due to ({:?}).",
-                                service_name, e);
+                warn!(from origin, fields [service = service_name, error = e],
+                    "Unable to open event service to emit dead node signal to waiting listeners.");
                 return;
             }
         };
@@ -405,23 +441,19 @@ pub(crate) mod internal {
             None => return,
         };
 
-        let notifier = match Notifier::new_without_auto_event_emission(
-            &service.service,
-            EventId::new(0),
-        ) {
-            Ok(notifier) => notifier,
-            Err(e) => {
-                warn!(from origin,
-                                "Unable to create notifier to send dead node signal to waiting listeners on the service {} due to ({:?})",
-                                service_name, e);
-                return;
-            }
-        };
+        let notifier =
+            match Notifier::new_without_auto_event_emission(&service.service, EventId::new(0)) {
+                Ok(notifier) => notifier,
+                Err(e) => {
+                    warn!(from origin, fields [service = service_name, error = e],
+                    "Unable to create notifier to send dead node signal to waiting listeners.");
+                    return;
+                }
+            };
 
         if let Err(e) = notifier.notify_with_custom_event_id(EventId::new(event_id)) {
-            warn!(from origin,
-                            "Unable to send dead node signal to waiting listeners on service {} due to ({:?})",
-                            service_name, e);
+            warn!(from origin, fields [service = service_name, error = e],
+                "Unable to send dead node signal to waiting listeners.");
         }
 
         trace!(from origin, "Send dead node signal on service {}.", service_name);
@@ -467,13 +499,15 @@ pub(crate) mod internal {
                         if let Err(e) =
                             unsafe { remove_publisher_from_all_connections::<S>(id, config) }
                         {
-                            debug!(from origin, "Failed to remove the publishers ({:?}) from all of its connections ({:?}).", id, e);
+                            debug!(from origin, fields [publisher_id = id.value(), error = e],
+                                "Failed to remove the publisher from all of its connections.");
                             return PortCleanupAction::SkipPort;
                         }
 
                         if let Err(e) = unsafe { remove_data_segment_of_publisher::<S>(id, config) }
                         {
-                            debug!(from origin, "Failed to remove the publishers ({:?}) data segment ({:?}).", id, e);
+                            debug!(from origin, fields [publisher_id = id.value(), error = e],
+                                "Failed to remove the data segment of the publisher.");
                             return PortCleanupAction::SkipPort;
                         }
                     }
@@ -481,7 +515,14 @@ pub(crate) mod internal {
                         if let Err(e) =
                             unsafe { remove_subscriber_from_all_connections::<S>(id, config) }
                         {
-                            debug!(from origin, "Failed to remove the subscriber ({:?}) from all of its connections ({:?}).", id, e);
+                            debug!(from origin, fields [subscriber_id = id.value(), error = e],
+                                "Failed to remove the subscriber from all of its connections.");
+                            return PortCleanupAction::SkipPort;
+                        }
+
+                        if let Err(e) = unsafe { remove_listener_of_subscriber::<S>(id, config) } {
+                            debug!(from origin, fields [subscriber_id = id.value(), error = e],
+                                "Failed to remove the listener of the subscriber.");
                             return PortCleanupAction::SkipPort;
                         }
                     }
@@ -490,7 +531,8 @@ pub(crate) mod internal {
                     }
                     UniquePortId::Listener(ref id) => {
                         if let Err(e) = unsafe { remove_connection_of_listener::<S>(id, config) } {
-                            debug!(from origin, "Failed to remove the listeners ({:?}) connection ({:?}).", id, e);
+                            debug!(from origin, fields [listener_id = id.value(), error = e],
+                                "Failed to remove the connection of the listener.");
                             return PortCleanupAction::SkipPort;
                         }
                     }
@@ -521,8 +563,8 @@ pub(crate) mod internal {
                         dynamic_config.acquire_ownership()
                     }
                     Err(e) => {
-                        warn!(from origin, "Unable to remove static config of unused service ({:?}).",
-                            e);
+                        warn!(from origin, fields [error = e],
+                            "Unable to remove static config of unused service.");
                     }
                 }
             } else if number_of_dead_node_notifications != 0 {
@@ -598,7 +640,10 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
         Ok(Self::details(service_name, config, messaging_pattern)?.is_some())
     }
 
-    /// Acquires the [`ServiceDetails`] of a [`Service`].
+    /// Acquires the [`ServiceDetails`] of a [`Service`]. They contain the full
+    /// [`StaticConfig`] with the [`MessagingPattern`] and the attributes of the [`Service`] and,
+    /// when the [`Service`] is accessible, all [`Node`](crate::node::Node)s and the ports they
+    /// own. Returns [`None`] when the [`Service`] does not exist.
     ///
     /// # Example
     ///
@@ -615,7 +660,14 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
     ///                 MessagingPattern::Event)?;
     ///
     /// if let Some(details) = details {
-    ///     println!("Service details: {:?}", details);
+    ///     println!("messaging pattern: {:?}", details.static_details.messaging_pattern());
+    ///     println!("attributes: {:?}", details.static_details.attributes());
+    ///
+    ///     if let Some(dynamic_details) = details.dynamic_details {
+    ///         for port in dynamic_details.ports {
+    ///             println!("port {:?} is owned by node {:?}", port.port_id, port.node_id);
+    ///         }
+    ///     }
     /// }
     /// # Ok(())
     /// # }
@@ -625,11 +677,18 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
         config: &config::Config,
         messaging_pattern: MessagingPattern,
     ) -> Result<Option<ServiceDetails<Self>>, ServiceDetailsError> {
-        let service_id = ServiceId::new::<Self::ServiceNameHasher>(service_name, messaging_pattern);
+        let service_id = ServiceId::new::<Self::ServiceNameHasher>(
+            service_name,
+            messaging_pattern,
+            &config.global.service,
+        );
         details::<Self>(config, &service_id.0.into())
     }
 
-    /// Returns a list of all services created under a given [`config::Config`].
+    /// Returns a list of all services created under a given [`config::Config`]. The builtin
+    /// services, whose [`ServiceName`] starts with the reserved
+    /// [`BUILTIN_SERVICE_NAME_PREFIX`](crate::service::service_name::BUILTIN_SERVICE_NAME_PREFIX),
+    /// are not listed.
     ///
     /// # Example
     ///
@@ -661,6 +720,10 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
 
         for uuid in &service_uuids {
             if let Ok(Some(service_details)) = details::<Self>(config, uuid) {
+                if service_details.static_details.name().is_builtin() {
+                    continue;
+                }
+
                 if callback(service_details) == CallbackProgression::Stop {
                     break;
                 }
@@ -669,6 +732,25 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
 
         Ok(())
     }
+
+    /// Runs an end-to-end self test under the given [`config::Config`]. It creates temporary
+    /// services, exchanges a sample and an event and reports the duration and outcome of
+    /// every [`SelfTestStage`](self_test::SelfTestStage). Can be used as startup health check.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    ///
+    /// let report = ipc::Service::self_test(Config::global_config());
+    /// if let Some(stage) = report.failed_stage() {
+    ///     println!("{:?} failed: {:?}", stage.stage(), stage.failure());
+    /// }
+    /// ```
+    fn self_test(config: &config::Config) -> self_test::SelfTestReport {
+        self_test::run::<Self>(config)
+    }
 }
 
 pub(crate) unsafe fn remove_static_service_config<S: Service>(
@@ -750,7 +832,21 @@ fn details<S: Service>(
             };
             CallbackProgression::Continue
         });
-        Some(ServiceDynamicDetails { nodes })
+
+        let mut ports = vec![];
+        d.get().list_ports(|port_id, node_id| {
+            ports.push(ServicePortDetails {
+                port_id,
+                node_id: *node_id,
+            })
+        });
+
+        Some(ServiceDynamicDetails {
+            nodes,
+            ports,
+            dynamic_attributes: d.get().dynamic_attributes(),
+            number_of_discarded_samples: d.get().number_of_discarded_samples(),
+        })
     } else {
         None
     };
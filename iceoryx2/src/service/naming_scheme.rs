@@ -23,6 +23,14 @@ pub(crate) fn event_concept_name(listener_id: &UniqueListenerId) -> FileName {
                  "{}", msg)
 }
 
+pub(crate) fn receiver_event_concept_name(receiver_port_id: u128) -> FileName {
+    let msg = "The system does not support the required file name length for the receivers event concept name.";
+    let origin = "receiver_event_concept_name()";
+    fatal_panic!(from origin,
+                 when FileName::new(receiver_port_id.to_string().as_bytes()),
+                 "{}", msg)
+}
+
 pub(crate) fn connection_name(sender_port_id: u128, receiver_port_id: u128) -> FileName {
     let mut file = FileName::new(sender_port_id.to_string().as_bytes()).unwrap();
     file.push(b'_').unwrap();
@@ -55,3 +63,20 @@ pub(crate) fn data_segment_name(port_id_value: u128) -> FileName {
                  when FileName::new(port_id_value.to_string().as_bytes()),
                  "{}", msg)
 }
+
+pub(crate) fn size_class_data_segment_name(
+    data_segment_name: &FileName,
+    size_class: u8,
+) -> FileName {
+    let msg = "The system does not support the required file name length for the data segment of the size class.";
+    let origin = "size_class_data_segment_name()";
+
+    let mut name = *data_segment_name;
+    fatal_panic!(from origin,
+                 when name.push(b'_'),
+                 "{}", msg);
+    fatal_panic!(from origin,
+                 when name.push_bytes(size_class.to_string().as_bytes()),
+                 "{}", msg);
+    name
+}
@@ -46,6 +46,9 @@ pub enum ClientCreateError {
     /// defined in [`crate::config::Config`]. When this is exceeded no more [`Client`]s
     /// can be created for a specific [`Service`](crate::service::Service).
     ExceedsMaxSupportedClients,
+    /// The [`Client`] cannot be created since it would exceed the
+    /// [`NodeQuota`](crate::node::quota::NodeQuota) of the [`Node`](crate::node::Node).
+    ExceedsNodeQuota,
 }
 
 impl core::fmt::Display for ClientCreateError {
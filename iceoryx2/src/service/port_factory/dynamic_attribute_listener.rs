@@ -0,0 +1,229 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::attribute::DynamicAttributePermission;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .create_with_attributes(
+//!         &AttributeSpecifier::new()
+//!             .define_dynamic("calibration_revision", "1", DynamicAttributePermission::OwnerOnly)?
+//!     )?;
+//!
+//! let listener = service.dynamic_attribute_listener()?;
+//!
+//! service.update_dynamic_attribute("calibration_revision", "2")?;
+//!
+//! if listener.try_wait()? {
+//!     println!("new dynamic attributes: {:?}", service.dynamic_attributes());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::time::Duration;
+
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_cal::event::ListenerWaitError;
+
+use crate::port::event_id::EventId;
+use crate::port::listener::{Listener, ListenerCreateError};
+use crate::port::notifier::notify_all_listeners;
+use crate::service::builder::event::EventOpenError;
+use crate::service::builder::Builder;
+use crate::service::service_name::ServiceName;
+use crate::service::{self, ServiceState};
+
+use super::event::PortFactory;
+
+const DYNAMIC_ATTRIBUTE_SERVICE_NAME_PREFIX: &str = "iox2://dynamic_attributes/";
+const DYNAMIC_ATTRIBUTE_CHANGED_EVENT: EventId = EventId::new(0);
+
+/// The maximum amount of [`DynamicAttributeListener`]s of a single
+/// [`Service`](crate::service::Service) that can exist at the same time.
+pub const DYNAMIC_ATTRIBUTE_MAX_LISTENERS: usize = capacity!(small: 8, default: 64, large: 1024);
+
+/// Describes the failures when a [`DynamicAttributeListener`] is created.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DynamicAttributeListenerCreateError {
+    /// The event [`Service`](crate::service::Service) that delivers the change notifications
+    /// could not be opened or created.
+    ServiceUnavailable,
+    /// The [`Service`](crate::service::Service) already has
+    /// [`DYNAMIC_ATTRIBUTE_MAX_LISTENERS`] [`DynamicAttributeListener`]s.
+    ExceedsMaxSupportedListeners,
+    /// The [`DynamicAttributeListener`] cannot be created since it would exceed the
+    /// [`NodeQuota`](crate::node::quota::NodeQuota) of the [`Node`](crate::node::Node).
+    ExceedsNodeQuota,
+    /// An underlying resource of the [`DynamicAttributeListener`] could not be created.
+    InternalFailure,
+}
+
+impl core::fmt::Display for DynamicAttributeListenerCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "DynamicAttributeListenerCreateError::{:?}", self)
+    }
+}
+
+impl core::error::Error for DynamicAttributeListenerCreateError {}
+
+impl From<ListenerCreateError> for DynamicAttributeListenerCreateError {
+    fn from(value: ListenerCreateError) -> Self {
+        match value {
+            ListenerCreateError::ExceedsMaxSupportedListeners => {
+                DynamicAttributeListenerCreateError::ExceedsMaxSupportedListeners
+            }
+            ListenerCreateError::ExceedsNodeQuota => {
+                DynamicAttributeListenerCreateError::ExceedsNodeQuota
+            }
+            ListenerCreateError::ResourceCreationFailed => {
+                DynamicAttributeListenerCreateError::InternalFailure
+            }
+        }
+    }
+}
+
+// The change notifications are delivered via an event service that accompanies the service
+// so that they are available for every messaging pattern and do not occupy any port of the
+// user.
+fn dynamic_attribute_service_builder<Service: service::Service>(
+    service_state: &ServiceState<Service>,
+) -> Option<crate::service::builder::event::Builder<Service>> {
+    let service_name = match ServiceName::new_builtin(
+        &(DYNAMIC_ATTRIBUTE_SERVICE_NAME_PREFIX.to_string()
+            + service_state.static_config.service_id().as_str()),
+    ) {
+        Ok(service_name) => service_name,
+        Err(_) => return None,
+    };
+
+    Some(
+        Builder::new(&service_name, service_state.shared_node.clone())
+            .event()
+            .max_nodes(DYNAMIC_ATTRIBUTE_MAX_LISTENERS)
+            .max_notifiers(1)
+            .max_listeners(DYNAMIC_ATTRIBUTE_MAX_LISTENERS)
+            .event_id_max_value(DYNAMIC_ATTRIBUTE_CHANGED_EVENT.as_value())
+            .disable_deadline()
+            .disable_notifier_created_event()
+            .disable_notifier_dropped_event()
+            .disable_notifier_dead_event()
+            .disable_dynamic_attribute_changed_event(),
+    )
+}
+
+// Wakes up all DynamicAttributeListeners of the service. The notification does not require a
+// notifier port, it is delivered to the listeners directly.
+pub(crate) fn notify_dynamic_attribute_listeners<Service: service::Service>(
+    service_state: &ServiceState<Service>,
+) {
+    let builder = match dynamic_attribute_service_builder(service_state) {
+        Some(builder) => builder,
+        None => {
+            warn!(from service_state.static_config.name(),
+                "This should never happen! Unable to notify the dynamic attribute listeners since the name of their service is invalid.");
+            return;
+        }
+    };
+
+    // no DynamicAttributeListener exists
+    if !builder.does_exist() {
+        return;
+    }
+
+    let service = match builder.open() {
+        Ok(service) => service,
+        Err(EventOpenError::DoesNotExist) => return,
+        Err(e) => {
+            warn!(from service_state.static_config.name(), fields [error = e],
+                "Unable to notify the dynamic attribute listeners since their service could not be opened.");
+            return;
+        }
+    };
+
+    notify_all_listeners(
+        service.service.__internal_state(),
+        DYNAMIC_ATTRIBUTE_CHANGED_EVENT,
+    );
+}
+
+/// Is woken up whenever a dynamic attribute of a [`Service`](crate::service::Service) is
+/// updated with
+/// [`PortFactory::update_dynamic_attribute()`](crate::service::port_factory::PortFactory::update_dynamic_attribute()).
+/// It is available for every messaging pattern and can be created with
+/// [`PortFactory::dynamic_attribute_listener()`](crate::service::port_factory::PortFactory::dynamic_attribute_listener()).
+#[derive(Debug)]
+pub struct DynamicAttributeListener<Service: service::Service> {
+    listener: Listener<Service>,
+    _service: PortFactory<Service>,
+}
+
+impl<Service: service::Service> DynamicAttributeListener<Service> {
+    pub(crate) fn new(
+        service_state: &ServiceState<Service>,
+    ) -> Result<Self, DynamicAttributeListenerCreateError> {
+        let origin = "DynamicAttributeListener::new()";
+        let msg = "Unable to create the dynamic attribute listener";
+        let builder = fail!(from origin, when dynamic_attribute_service_builder(service_state).ok_or(()),
+            with DynamicAttributeListenerCreateError::InternalFailure,
+            "{} since the name of the underlying service is invalid.", msg);
+
+        let service = fail!(from origin, when builder.open_or_create(),
+            with DynamicAttributeListenerCreateError::ServiceUnavailable,
+            "{} since the underlying service could not be opened or created.", msg);
+
+        let listener = fail!(from origin, when service.listener_builder().create(),
+            "{} since the listener could not be created.", msg);
+
+        Ok(Self {
+            listener,
+            _service: service,
+        })
+    }
+
+    /// Returns the underlying [`Listener`] so that it can be attached to a
+    /// [`WaitSet`](crate::waitset::WaitSet).
+    pub fn listener(&self) -> &Listener<Service> {
+        &self.listener
+    }
+
+    /// Non-blocking check for updates of the dynamic attributes. Returns true when at least
+    /// one update happened since the last call.
+    pub fn try_wait(&self) -> Result<bool, ListenerWaitError> {
+        let mut has_changed = false;
+        self.listener.try_wait_all(|_| has_changed = true)?;
+        Ok(has_changed)
+    }
+
+    /// Blocks until at least one update of the dynamic attributes happened or the timeout has
+    /// passed. Returns true when at least one update happened.
+    pub fn timed_wait(&self, timeout: Duration) -> Result<bool, ListenerWaitError> {
+        let mut has_changed = false;
+        self.listener
+            .timed_wait_all(|_| has_changed = true, timeout)?;
+        Ok(has_changed)
+    }
+
+    /// Blocks until at least one update of the dynamic attributes happened. Returns true when
+    /// at least one update happened, false when the wait was interrupted.
+    pub fn blocking_wait(&self) -> Result<bool, ListenerWaitError> {
+        let mut has_changed = false;
+        self.listener.blocking_wait_all(|_| has_changed = true)?;
+        Ok(has_changed)
+    }
+}
@@ -38,14 +38,19 @@ use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
 use crate::node::NodeListFailure;
-use crate::service::attribute::AttributeSet;
+use crate::port::notifier::notify_all_listeners;
+use crate::port::wait_strategy::WaitStrategy;
+use crate::service::attribute::{AttributeSet, DynamicAttributeUpdateError};
 use crate::service::service_id::ServiceId;
 use crate::service::{self, static_config};
 use crate::service::{dynamic_config, ServiceName};
 
+use super::dynamic_attribute_listener::{
+    DynamicAttributeListener, DynamicAttributeListenerCreateError,
+};
 use super::listener::PortFactoryListener;
-use super::nodes;
 use super::notifier::PortFactoryNotifier;
+use super::{nodes, update_dynamic_attribute};
 
 /// The factory for
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event). It can
@@ -76,6 +81,50 @@ impl<Service: service::Service> crate::service::port_factory::PortFactory for Po
         self.service.__internal_state().static_config.attributes()
     }
 
+    fn dynamic_attributes(&self) -> AttributeSet {
+        self.service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .dynamic_attributes()
+    }
+
+    fn dynamic_attributes_revision(&self) -> u64 {
+        self.service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .dynamic_attributes_revision()
+    }
+
+    /// Updates the value of a dynamic attribute that was declared with
+    /// [`AttributeSpecifier::define_dynamic()`](crate::service::attribute::AttributeSpecifier::define_dynamic())
+    /// when the [`crate::service::Service`] was created. If the service defines a
+    /// [`static_config::event::StaticConfig::dynamic_attribute_changed_event()`] it is emitted
+    /// to all [`Listener`](crate::port::listener::Listener)s after the update. The event is
+    /// emitted without a [`Notifier`](crate::port::notifier::Notifier) and does not occupy any
+    /// of the [`static_config::event::StaticConfig::max_notifiers()`].
+    fn update_dynamic_attribute(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> Result<(), DynamicAttributeUpdateError> {
+        let state = self.service.__internal_state();
+        update_dynamic_attribute(state, key, value)?;
+
+        if let Some(event_id) = self.static_config().dynamic_attribute_changed_event() {
+            notify_all_listeners(state, event_id);
+        }
+
+        Ok(())
+    }
+
+    fn dynamic_attribute_listener(
+        &self,
+    ) -> Result<DynamicAttributeListener<Service>, DynamicAttributeListenerCreateError> {
+        DynamicAttributeListener::new(self.service.__internal_state())
+    }
+
     fn static_config(&self) -> &static_config::event::StaticConfig {
         self.service.__internal_state().static_config.event()
     }
@@ -144,6 +193,9 @@ impl<Service: service::Service> PortFactory<Service> {
     /// # }
     /// ```
     pub fn listener_builder(&self) -> PortFactoryListener<Service> {
-        PortFactoryListener { factory: self }
+        PortFactoryListener {
+            factory: self,
+            wait_strategy: WaitStrategy::Block,
+        }
     }
 }
@@ -29,7 +29,7 @@ use core::fmt::Debug;
 
 use iceoryx2_bb_log::fail;
 
-use crate::port::{listener::Listener, listener::ListenerCreateError};
+use crate::port::{listener::Listener, listener::ListenerCreateError, wait_strategy::WaitStrategy};
 use crate::service;
 
 use super::event::PortFactory;
@@ -40,12 +40,22 @@ use super::event::PortFactory;
 #[derive(Debug)]
 pub struct PortFactoryListener<'factory, Service: service::Service> {
     pub(crate) factory: &'factory PortFactory<Service>,
+    pub(crate) wait_strategy: WaitStrategy,
 }
 
 impl<Service: service::Service> PortFactoryListener<'_, Service> {
+    /// Defines the [`WaitStrategy`] of [`Listener::blocking_wait_one()`] and
+    /// [`Listener::blocking_wait_all()`]. Defaults to [`WaitStrategy::Block`].
+    pub fn wait_strategy(mut self, value: WaitStrategy) -> Self {
+        self.wait_strategy = value;
+        self
+    }
+
     /// Creates the [`Listener`] port or returns a [`ListenerCreateError`] on failure.
     pub fn create(self) -> Result<Listener<Service>, ListenerCreateError> {
-        Ok(fail!(from self, when Listener::new(&self.factory.service),
-                    "Failed to create new Listener port."))
+        Ok(
+            fail!(from self, when Listener::new(&self.factory.service, self.wait_strategy),
+                    "Failed to create new Listener port."),
+        )
     }
 }
@@ -11,13 +11,20 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
 use crate::config::Config;
 use crate::node::{NodeListFailure, NodeState};
 
+use self::dynamic_attribute_listener::{
+    notify_dynamic_attribute_listeners, DynamicAttributeListener,
+    DynamicAttributeListenerCreateError,
+};
+use super::attribute::{AttributeSet, DynamicAttributeUpdateError};
 use super::dynamic_config::DynamicConfig;
 use super::service_id::ServiceId;
-use super::{attribute::AttributeSet, service_name::ServiceName};
+use super::service_name::ServiceName;
+use super::ServiceState;
 
 pub mod request_response;
 
@@ -32,6 +39,10 @@ pub mod event;
 /// Factory to create a [`Listener`](crate::port::listener::Listener)
 pub mod listener;
 
+/// Listener that is woken up whenever a dynamic attribute of a
+/// [`Service`](crate::service::Service) is updated
+pub mod dynamic_attribute_listener;
+
 /// Factory to create a [`Notifier`](crate::port::notifier::Notifier)
 pub mod notifier;
 
@@ -69,6 +80,37 @@ pub trait PortFactory {
     /// Returns the attributes defined in the [`crate::service::Service`]
     fn attributes(&self) -> &AttributeSet;
 
+    /// Returns a snapshot of the dynamic attributes of the [`crate::service::Service`]. In
+    /// contrast to [`PortFactory::attributes()`] their values can be updated during the
+    /// lifetime of the [`crate::service::Service`].
+    fn dynamic_attributes(&self) -> AttributeSet;
+
+    /// Returns the revision of the dynamic attributes. It is increased with every successful
+    /// [`PortFactory::update_dynamic_attribute()`] call and can be polled to detect changes.
+    fn dynamic_attributes_revision(&self) -> u64;
+
+    /// Updates the value of a dynamic attribute that was declared with
+    /// [`AttributeSpecifier::define_dynamic()`](crate::service::attribute::AttributeSpecifier::define_dynamic())
+    /// when the [`crate::service::Service`] was created. Attributes with the
+    /// [`DynamicAttributePermission::OwnerOnly`](crate::service::attribute::DynamicAttributePermission::OwnerOnly)
+    /// can only be updated by the [`crate::node::Node`] that created the
+    /// [`crate::service::Service`]. Concurrent updates are serialized. Every
+    /// [`DynamicAttributeListener`] of the [`crate::service::Service`] is woken up after the
+    /// update.
+    fn update_dynamic_attribute(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> Result<(), DynamicAttributeUpdateError>;
+
+    /// Creates a [`DynamicAttributeListener`] that is woken up whenever a dynamic attribute
+    /// of the [`crate::service::Service`] is updated with
+    /// [`PortFactory::update_dynamic_attribute()`]. It does not occupy any port of the
+    /// [`crate::service::Service`].
+    fn dynamic_attribute_listener(
+        &self,
+    ) -> Result<DynamicAttributeListener<Self::Service>, DynamicAttributeListenerCreateError>;
+
     /// Returns the StaticConfig of the [`crate::service::Service`].
     /// Contains all settings that never change during the lifetime of the service.
     fn static_config(&self) -> &Self::StaticConfig;
@@ -109,3 +151,24 @@ pub(crate) fn nodes<
 
     ret_val
 }
+
+pub(crate) fn update_dynamic_attribute<Service: crate::service::Service>(
+    service_state: &ServiceState<Service>,
+    key: &str,
+    value: &str,
+) -> Result<(), DynamicAttributeUpdateError> {
+    let config = service_state.shared_node.config();
+    service_state
+        .dynamic_storage
+        .get()
+        .update_dynamic_attribute(key, value, service_state.shared_node.id(), |node_id| {
+            matches!(
+                NodeState::<Service>::new(node_id, config),
+                Ok(Some(NodeState::Dead(_)) | None)
+            )
+        })?;
+
+    notify_dynamic_attribute_listeners(service_state);
+
+    Ok(())
+}
@@ -46,12 +46,15 @@ use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
 use crate::node::NodeListFailure;
-use crate::service::attribute::AttributeSet;
+use crate::service::attribute::{AttributeSet, DynamicAttributeUpdateError};
 use crate::service::service_id::ServiceId;
 use crate::service::service_name::ServiceName;
 use crate::service::{self, dynamic_config, static_config};
 
-use super::nodes;
+use super::dynamic_attribute_listener::{
+    DynamicAttributeListener, DynamicAttributeListenerCreateError,
+};
+use super::{nodes, update_dynamic_attribute};
 use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
 
 /// The factory for
@@ -62,6 +65,7 @@ use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
 #[derive(Debug)]
 pub struct PortFactory<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> {
     pub(crate) service: Service,
+    pub(crate) has_smaller_payload_type: bool,
     _payload: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
@@ -94,6 +98,36 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         self.service.__internal_state().static_config.attributes()
     }
 
+    fn dynamic_attributes(&self) -> AttributeSet {
+        self.service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .dynamic_attributes()
+    }
+
+    fn dynamic_attributes_revision(&self) -> u64 {
+        self.service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .dynamic_attributes_revision()
+    }
+
+    fn update_dynamic_attribute(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> Result<(), DynamicAttributeUpdateError> {
+        update_dynamic_attribute(self.service.__internal_state(), key, value)
+    }
+
+    fn dynamic_attribute_listener(
+        &self,
+    ) -> Result<DynamicAttributeListener<Service>, DynamicAttributeListenerCreateError> {
+        DynamicAttributeListener::new(self.service.__internal_state())
+    }
+
     fn static_config(&self) -> &static_config::publish_subscribe::StaticConfig {
         self.service
             .__internal_state()
@@ -127,6 +161,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
     pub(crate) fn new(service: Service) -> Self {
         Self {
             service,
+            has_smaller_payload_type: false,
             _payload: PhantomData,
             _user_header: PhantomData,
         }
@@ -53,8 +53,35 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Write-Combining
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use core::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<[u32]>()
+//!     .open_or_create()?;
+//!
+//! let publisher = pubsub.publisher_builder()
+//!                     // combines up to 64 sends within 100us into one sample
+//!                     .write_combining(64, Duration::from_micros(100))
+//!                     .create()?;
+//!
+//! publisher.send_combined(123)?;
+//! publisher.send_combined(456)?;
+//! // delivers all pending values right away
+//! publisher.flush_combined()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
 
 use core::fmt::Debug;
+use core::time::Duration;
 
 use iceoryx2_bb_log::fail;
 use iceoryx2_cal::shm_allocator::AllocationStrategy;
@@ -76,6 +103,21 @@ pub(crate) struct LocalPublisherConfig {
     pub(crate) degration_callback: Option<DegrationCallback<'static>>,
     pub(crate) initial_max_slice_len: usize,
     pub(crate) allocation_strategy: AllocationStrategy,
+    pub(crate) write_combining: Option<WriteCombiningConfig>,
+    pub(crate) data_segment_numa_node: Option<u32>,
+    pub(crate) size_classes: Vec<SizeClass>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SizeClass {
+    pub(crate) max_slice_len: usize,
+    pub(crate) number_of_samples: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WriteCombiningConfig {
+    pub(crate) max_batch_len: usize,
+    pub(crate) window: Duration,
 }
 
 /// Factory to create a new [`Publisher`] port/endpoint for
@@ -101,6 +143,16 @@ impl<'factory, Service: service::Service, Payload: Debug + ?Sized, UserHeader: D
                 allocation_strategy: AllocationStrategy::Static,
                 degration_callback: None,
                 initial_max_slice_len: 1,
+                write_combining: None,
+                size_classes: vec![],
+                data_segment_numa_node: factory
+                    .service
+                    .__internal_state()
+                    .shared_node
+                    .config()
+                    .defaults
+                    .publish_subscribe
+                    .publisher_data_segment_numa_node,
                 max_loaned_samples: factory
                     .service
                     .__internal_state()
@@ -136,6 +188,15 @@ impl<'factory, Service: service::Service, Payload: Debug + ?Sized, UserHeader: D
         self
     }
 
+    /// Binds the memory of the data segment of the [`Publisher`] to the provided NUMA node.
+    /// When the [`Publisher`] and its [`crate::port::subscriber::Subscriber`]s are pinned to
+    /// the same socket, this avoids cross-node memory traffic. When the binding fails, a
+    /// warning is logged and the default memory policy of the system is used.
+    pub fn data_segment_numa_node(mut self, value: u32) -> Self {
+        self.config.data_segment_numa_node = Some(value);
+        self
+    }
+
     /// Sets the [`DegrationCallback`] of the [`Publisher`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this callback
     /// is called and depending on the returned [`DegrationAction`] measures will be taken.
@@ -154,8 +215,22 @@ impl<'factory, Service: service::Service, Payload: Debug + ?Sized, UserHeader: D
     }
 
     /// Creates a new [`Publisher`] or returns a [`PublisherCreateError`] on failure.
-    pub fn create(self) -> Result<Publisher<Service, Payload, UserHeader>, PublisherCreateError> {
+    pub fn create(
+        mut self,
+    ) -> Result<Publisher<Service, Payload, UserHeader>, PublisherCreateError> {
         let origin = format!("{:?}", self);
+        if self.factory.has_smaller_payload_type {
+            fail!(from origin, with PublisherCreateError::IncompatiblePayloadSize,
+                "Failed to create new Publisher port since the service was opened with a smaller payload type.");
+        }
+
+        if let Some(write_combining) = &self.config.write_combining {
+            self.config.initial_max_slice_len = self
+                .config
+                .initial_max_slice_len
+                .max(write_combining.max_batch_len);
+        }
+
         Ok(
             fail!(from origin, when Publisher::new(&self.factory.service, self.factory.service.__internal_state().static_config.publish_subscribe(), self.config),
                 "Failed to create new Publisher port."),
@@ -181,4 +256,46 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
         self.config.allocation_strategy = value;
         self
     }
+
+    /// Adds a size class to the data segment of the [`Publisher`]. Slices with up to
+    /// `max_slice_len` elements are stored in one of the `number_of_samples` buckets of this
+    /// size class instead of a bucket that fits
+    /// [`PortFactoryPublisher::initial_max_slice_len()`] elements. When all buckets of a size
+    /// class are in use, the next larger size class is used.
+    ///
+    /// The largest size class is defined by [`PortFactoryPublisher::initial_max_slice_len()`]
+    /// and contains the remaining samples the [`Publisher`] requires, but at least one. Services
+    /// with highly variable slice lengths do not have to reserve the worst case for every
+    /// sample, at the cost that large slices can run out of memory earlier. Size classes are
+    /// only used with [`AllocationStrategy::Static`] and up to
+    /// [`MAX_NUMBER_OF_SIZE_CLASSES`](crate::port::publisher::MAX_NUMBER_OF_SIZE_CLASSES)
+    /// size classes, including the largest one, are supported.
+    pub fn size_class(mut self, max_slice_len: usize, number_of_samples: usize) -> Self {
+        self.config.size_classes.push(SizeClass {
+            max_slice_len,
+            number_of_samples,
+        });
+        self
+    }
+
+    /// Enables the write-combining mode of the [`Publisher`]. All values that are sent with
+    /// [`Publisher::send_combined()`] are collected in one sample that is delivered as soon as
+    /// it contains `max_batch_len` elements or when the first element is older than `window`.
+    /// The number of combined values is the slice length of the delivered sample.
+    ///
+    /// It trades a bounded latency increase for a much lower per-sample overhead on high
+    /// frequency streams of very small values. The `window` is verified on every loan and send
+    /// of the [`Publisher`] and the pending sample is delivered when the [`Publisher`] is
+    /// dropped. When the [`Publisher`] goes quiet, the pending sample is delivered by
+    /// [`Publisher::flush_combined_if_due()`], which shall be called after
+    /// [`Publisher::combined_flush_timeout()`], or by [`Publisher::flush_combined()`] at any
+    /// time. The pending sample counts as loaned
+    /// sample, see [`PortFactoryPublisher::max_loaned_samples()`].
+    pub fn write_combining(mut self, max_batch_len: usize, window: Duration) -> Self {
+        self.config.write_combining = Some(WriteCombiningConfig {
+            max_batch_len: max_batch_len.max(1),
+            window,
+        });
+        self
+    }
 }
@@ -50,11 +50,17 @@ use crate::{
     node::NodeListFailure,
     prelude::AttributeSet,
     service::{
-        self, dynamic_config, service_id::ServiceId, service_name::ServiceName, static_config,
+        self, attribute::DynamicAttributeUpdateError, dynamic_config, service_id::ServiceId,
+        service_name::ServiceName, static_config,
     },
 };
 
-use super::{client::PortFactoryClient, nodes, server::PortFactoryServer};
+use super::dynamic_attribute_listener::{
+    DynamicAttributeListener, DynamicAttributeListenerCreateError,
+};
+use super::{
+    client::PortFactoryClient, nodes, server::PortFactoryServer, update_dynamic_attribute,
+};
 
 /// The factory for
 /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse).
@@ -122,6 +128,36 @@ impl<
         self.service.__internal_state().static_config.attributes()
     }
 
+    fn dynamic_attributes(&self) -> AttributeSet {
+        self.service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .dynamic_attributes()
+    }
+
+    fn dynamic_attributes_revision(&self) -> u64 {
+        self.service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .dynamic_attributes_revision()
+    }
+
+    fn update_dynamic_attribute(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> Result<(), DynamicAttributeUpdateError> {
+        update_dynamic_attribute(self.service.__internal_state(), key, value)
+    }
+
+    fn dynamic_attribute_listener(
+        &self,
+    ) -> Result<DynamicAttributeListener<Service>, DynamicAttributeListenerCreateError> {
+        DynamicAttributeListener::new(self.service.__internal_state())
+    }
+
     fn static_config(&self) -> &Self::StaticConfig {
         self.service
             .__internal_state()
@@ -35,6 +35,7 @@ use iceoryx2_bb_log::fail;
 use crate::{
     port::{
         subscriber::{Subscriber, SubscriberCreateError},
+        wait_strategy::WaitStrategy,
         DegrationAction, DegrationCallback,
     },
     service,
@@ -46,6 +47,8 @@ use super::publish_subscribe::PortFactory;
 pub(crate) struct SubscriberConfig {
     pub(crate) buffer_size: Option<usize>,
     pub(crate) degration_callback: Option<DegrationCallback<'static>>,
+    pub(crate) wait_strategy: WaitStrategy,
+    pub(crate) receive_by_priority: bool,
 }
 
 /// Factory to create a new [`Subscriber`] port/endpoint for
@@ -70,6 +73,8 @@ impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized, UserHeade
             config: SubscriberConfig {
                 buffer_size: None,
                 degration_callback: None,
+                wait_strategy: WaitStrategy::Block,
+                receive_by_priority: false,
             },
             factory,
         }
@@ -81,6 +86,39 @@ impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized, UserHeade
         self
     }
 
+    /// Defines how often [`Subscriber::blocking_receive()`] and [`Subscriber::timed_receive()`]
+    /// busy spin on the receive buffer before the [`Subscriber`] waits on its event listener
+    /// until a [`Publisher`](crate::port::publisher::Publisher) delivers a sample. A higher value reduces the wake-up latency when samples arrive
+    /// in quick succession at the cost of CPU time. Defaults to `0`.
+    /// It is a shortcut for [`WaitStrategy::SpinThenBlock`], see
+    /// [`PortFactorySubscriber::wait_strategy()`].
+    pub fn blocking_receive_spin_repetitions(mut self, value: u64) -> Self {
+        self.config.wait_strategy = WaitStrategy::SpinThenBlock {
+            spin_repetitions: value,
+        };
+        self
+    }
+
+    /// Defines the [`WaitStrategy`] of [`Subscriber::blocking_receive()`] and
+    /// [`Subscriber::timed_receive()`]. With [`WaitStrategy::Block`] the [`Subscriber`] waits
+    /// on its event listener until a [`Publisher`](crate::port::publisher::Publisher) delivers
+    /// a sample. Defaults to [`WaitStrategy::Block`].
+    pub fn wait_strategy(mut self, value: WaitStrategy) -> Self {
+        self.config.wait_strategy = value;
+        self
+    }
+
+    /// Defines if the [`Subscriber`] receives its pending samples ordered by their
+    /// [`Header::priority()`](crate::service::header::publish_subscribe::Header::priority())
+    /// instead of the order they were sent. Samples with a higher priority are received first,
+    /// samples with the same priority are received in the order they arrived.
+    /// Only the samples that fit into the maximum number of borrowed samples of the
+    /// [`Subscriber`] are taken into account. Defaults to `false`.
+    pub fn receive_by_priority(mut self, value: bool) -> Self {
+        self.config.receive_by_priority = value;
+        self
+    }
+
     /// Sets the [`DegrationCallback`] of the [`Subscriber`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this callback
     /// is called and depending on the returned [`DegrationAction`] measures will be taken.
@@ -0,0 +1,237 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Verifies with [`Service::self_test()`] that a [`Service`] can be used end-to-end under
+//! a given [`Config`]. A temporary publish-subscribe and event service is created, a sample
+//! and an event are exchanged and every [`SelfTestStage`] is reported with its duration and,
+//! when it failed, the reason. The stages depend on each other, therefore the self test stops
+//! at the first failing stage.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::config::Config;
+//!
+//! let report = ipc::Service::self_test(Config::global_config());
+//!
+//! for stage in report.stages() {
+//!     println!("{:?} took {:?}, failure: {:?}", stage.stage(), stage.duration(), stage.failure());
+//! }
+//!
+//! if !report.has_passed() {
+//!     println!("iceoryx2 is not operational");
+//! }
+//! ```
+
+use core::fmt::Debug;
+use core::time::Duration;
+use std::time::Instant;
+
+use iceoryx2_bb_log::warn;
+
+use crate::config::Config;
+use crate::node::NodeBuilder;
+use crate::port::event_id::EventId;
+use crate::service::service_name::ServiceName;
+use crate::service::Service;
+
+const SELF_TEST_PAYLOAD: u64 = 0x1ce0_2c0d;
+const SELF_TEST_EVENT_ID: usize = 1;
+
+/// The stages of the [`Service::self_test()`] in the order in which they are executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SelfTestStage {
+    /// Creates the [`Node`](crate::node::Node) that owns the temporary services.
+    NodeCreation,
+    /// Creates the temporary publish-subscribe service.
+    PublishSubscribeServiceCreation,
+    /// Creates a [`Publisher`](crate::port::publisher::Publisher) and a
+    /// [`Subscriber`](crate::port::subscriber::Subscriber).
+    PublishSubscribePortCreation,
+    /// Sends a sample and verifies that it is received unmodified.
+    SampleExchange,
+    /// Creates the temporary event service.
+    EventServiceCreation,
+    /// Creates a [`Notifier`](crate::port::notifier::Notifier) and a
+    /// [`Listener`](crate::port::listener::Listener).
+    EventPortCreation,
+    /// Sends an event and verifies that it is received.
+    EventExchange,
+}
+
+/// The outcome of a single [`SelfTestStage`].
+#[derive(Debug, Clone)]
+pub struct SelfTestStageReport {
+    stage: SelfTestStage,
+    duration: Duration,
+    failure: Option<String>,
+}
+
+impl SelfTestStageReport {
+    /// Returns the [`SelfTestStage`].
+    pub fn stage(&self) -> SelfTestStage {
+        self.stage
+    }
+
+    /// Returns how long the [`SelfTestStage`] took.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the reason why the [`SelfTestStage`] failed or [`None`] when it succeeded.
+    pub fn failure(&self) -> Option<&str> {
+        self.failure.as_deref()
+    }
+
+    /// Returns true when the [`SelfTestStage`] succeeded.
+    pub fn has_passed(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// The result of [`Service::self_test()`]. Contains a [`SelfTestStageReport`] for every
+/// [`SelfTestStage`] that was executed.
+#[derive(Debug, Clone, Default)]
+pub struct SelfTestReport {
+    stages: Vec<SelfTestStageReport>,
+}
+
+impl SelfTestReport {
+    /// Returns the reports of all executed [`SelfTestStage`]s in the order of their execution.
+    pub fn stages(&self) -> &[SelfTestStageReport] {
+        &self.stages
+    }
+
+    /// Returns true when all [`SelfTestStage`]s were executed successfully.
+    pub fn has_passed(&self) -> bool {
+        self.failed_stage().is_none()
+            && self.stages.last().map(|s| s.stage) == Some(SelfTestStage::EventExchange)
+    }
+
+    /// Returns the report of the [`SelfTestStage`] that failed, if any.
+    pub fn failed_stage(&self) -> Option<&SelfTestStageReport> {
+        self.stages.iter().find(|s| !s.has_passed())
+    }
+
+    /// Returns the accumulated duration of all executed [`SelfTestStage`]s.
+    pub fn duration(&self) -> Duration {
+        self.stages.iter().map(|s| s.duration).sum()
+    }
+
+    fn run_stage<T, F: FnOnce() -> Result<T, String>>(
+        &mut self,
+        stage: SelfTestStage,
+        stage_fn: F,
+    ) -> Option<T> {
+        let start = Instant::now();
+        let result = stage_fn();
+        let duration = start.elapsed();
+
+        let (value, failure) = match result {
+            Ok(value) => (Some(value), None),
+            Err(failure) => (None, Some(failure)),
+        };
+
+        self.stages.push(SelfTestStageReport {
+            stage,
+            duration,
+            failure,
+        });
+
+        value
+    }
+}
+
+fn failure<E: Debug>(error: E) -> String {
+    format!("{:?}", error)
+}
+
+pub(crate) fn run<S: Service>(config: &Config) -> SelfTestReport {
+    let mut report = SelfTestReport::default();
+    run_stages::<S>(config, &mut report);
+
+    if let Some(stage) = report.failed_stage() {
+        warn!(from "Service::self_test()",
+            "The self test failed in stage {:?} ({}).", stage.stage, stage.failure().unwrap_or_default());
+    }
+
+    report
+}
+
+fn run_stages<S: Service>(config: &Config, report: &mut SelfTestReport) -> Option<()> {
+    let node = report.run_stage(SelfTestStage::NodeCreation, || {
+        NodeBuilder::new()
+            .config(config)
+            .create::<S>()
+            .map_err(failure)
+    })?;
+
+    // the node id is unique, so that concurrent self tests do not interfere
+    let service_name = format!("iox2://self_test/{}", node.id().value());
+
+    let service = report.run_stage(SelfTestStage::PublishSubscribeServiceCreation, || {
+        let service_name = ServiceName::new_builtin(&service_name).map_err(failure)?;
+        node.service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .map_err(failure)
+    })?;
+
+    let (publisher, subscriber) =
+        report.run_stage(SelfTestStage::PublishSubscribePortCreation, || {
+            let subscriber = service.subscriber_builder().create().map_err(failure)?;
+            let publisher = service.publisher_builder().create().map_err(failure)?;
+            Ok((publisher, subscriber))
+        })?;
+
+    report.run_stage(SelfTestStage::SampleExchange, || {
+        publisher.send_copy(SELF_TEST_PAYLOAD).map_err(failure)?;
+        match subscriber.receive().map_err(failure)? {
+            Some(sample) if *sample.payload() == SELF_TEST_PAYLOAD => Ok(()),
+            Some(sample) => Err(format!(
+                "The received payload {:#x} does not match the sent payload {:#x}.",
+                *sample.payload(),
+                SELF_TEST_PAYLOAD
+            )),
+            None => Err("The sent sample was not received.".to_string()),
+        }
+    })?;
+
+    let service = report.run_stage(SelfTestStage::EventServiceCreation, || {
+        let service_name = ServiceName::new_builtin(&service_name).map_err(failure)?;
+        node.service_builder(&service_name)
+            .event()
+            .create()
+            .map_err(failure)
+    })?;
+
+    let (notifier, listener) = report.run_stage(SelfTestStage::EventPortCreation, || {
+        let listener = service.listener_builder().create().map_err(failure)?;
+        let notifier = service.notifier_builder().create().map_err(failure)?;
+        Ok((notifier, listener))
+    })?;
+
+    report.run_stage(SelfTestStage::EventExchange, || {
+        notifier
+            .notify_with_custom_event_id(EventId::new(SELF_TEST_EVENT_ID))
+            .map_err(failure)?;
+        match listener.try_wait_one().map_err(failure)? {
+            Some(event_id) if event_id == EventId::new(SELF_TEST_EVENT_ID) => Ok(()),
+            Some(event_id) => Err(format!(
+                "The received event id {:?} does not match the sent event id {}.",
+                event_id, SELF_TEST_EVENT_ID
+            )),
+            None => Err("The sent event was not received.".to_string()),
+        }
+    })
+}
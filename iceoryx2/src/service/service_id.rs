@@ -13,12 +13,81 @@
 use iceoryx2_bb_container::semantic_string::*;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_system_types::file_name::RestrictedFileName;
+use iceoryx2_cal::hash::sip_hash::SipHash;
 use iceoryx2_cal::hash::Hash;
-use serde::{Deserialize, Serialize};
+use serde::{de::Visitor, Deserialize, Serialize};
 
 use super::{messaging_pattern::MessagingPattern, service_name::ServiceName};
+use crate::config;
 
 const SERVICE_ID_CAPACITY: usize = 64;
+const PLAIN_NAME_HASH_SUFFIX_LEN: usize = 16;
+
+/// Defines how the [`ServiceName`] is mapped onto the [`ServiceId`] that is used to name all
+/// underlying resources, like files and shared memory, of a
+/// [`Service`](crate::service::Service). All processes that shall communicate with each other
+/// must use the same setting.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ServiceNameHashing {
+    /// The [`ServiceName`] is hashed with the
+    /// [`Service::ServiceNameHasher`](crate::service::Service::ServiceNameHasher).
+    Hashed,
+    /// The [`ServiceName`] is hashed with a [`SipHash`] that uses the secret key
+    /// [`Service::name_hashing_key`](crate::config::Service::name_hashing_key). Without
+    /// knowing the key, nobody can construct [`ServiceName`]s that are mapped onto the same
+    /// [`ServiceId`] on purpose.
+    KeyedSipHash,
+    /// The [`ServiceName`] is used as human-readable resource name. Every character that is
+    /// not an ASCII alphanumeric, `-` or `_` is replaced with `_`. When the name does not fit
+    /// into the [`ServiceId`] it is truncated and a short hash is appended.
+    ///
+    /// **Note:** Intended for debugging only since different [`ServiceName`]s, like `a/b` and
+    /// `a_b`, can be mapped onto the same [`ServiceId`].
+    PlainName,
+}
+
+impl Serialize for ServiceNameHashing {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&std::format!("{:?}", self))
+    }
+}
+
+struct ServiceNameHashingVisitor;
+
+impl Visitor<'_> for ServiceNameHashingVisitor {
+    type Value = ServiceNameHashing;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a string containing either 'Hashed', 'KeyedSipHash' or 'PlainName'")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "Hashed" => Ok(ServiceNameHashing::Hashed),
+            "KeyedSipHash" => Ok(ServiceNameHashing::KeyedSipHash),
+            "PlainName" => Ok(ServiceNameHashing::PlainName),
+            v => Err(E::custom(format!(
+                "Invalid ServiceNameHashing provided: \"{:?}\".",
+                v
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ServiceNameHashing {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ServiceNameHashingVisitor)
+    }
+}
 
 /// The unique id of a [`Service`](crate::service::Service)
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Hash)]
@@ -28,17 +97,64 @@ impl ServiceId {
     pub(crate) fn new<Hasher: Hash>(
         service_name: &ServiceName,
         messaging_pattern: MessagingPattern,
+        config: &config::Service,
     ) -> Self {
         let pattern_and_service = (messaging_pattern as u32).to_string() + service_name.as_str();
-        let value = *Hasher::new(pattern_and_service.as_bytes())
-            .value()
-            .as_base64url();
+        let hash: String = Hasher::new(pattern_and_service.as_bytes()).value().into();
+
+        let value = match config.name_hashing {
+            ServiceNameHashing::Hashed => hash,
+            ServiceNameHashing::KeyedSipHash => {
+                let key = config.name_hashing_key;
+                let key = [
+                    key[0] as u64 | (key[1] as u64) << 32,
+                    key[2] as u64 | (key[3] as u64) << 32,
+                ];
+                SipHash::new_with_key(&key, pattern_and_service.as_bytes())
+                    .value()
+                    .into()
+            }
+            ServiceNameHashing::PlainName => {
+                Self::plain_name(service_name, messaging_pattern, &hash)
+            }
+        };
 
         Self(fatal_panic!(from "ServiceId::new()",
-                   when RestrictedFileName::new(&value),
+                   when RestrictedFileName::new(value.as_bytes()),
                    "This should never happen! The Hasher used to create the ServiceId created an illegal value ({value}, len = {}).", value.len()))
     }
 
+    fn plain_name(
+        service_name: &ServiceName,
+        messaging_pattern: MessagingPattern,
+        hash: &str,
+    ) -> String {
+        let prefix = match messaging_pattern {
+            MessagingPattern::PublishSubscribe => "ps_",
+            MessagingPattern::Event => "ev_",
+            MessagingPattern::RequestResponse => "rr_",
+        };
+
+        let mut value: String = prefix.to_string()
+            + &service_name
+                .as_str()
+                .chars()
+                .map(|c| match c {
+                    'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' => c,
+                    _ => '_',
+                })
+                .collect::<String>();
+
+        if value.len() > SERVICE_ID_CAPACITY {
+            let hash_suffix = &hash[..PLAIN_NAME_HASH_SUFFIX_LEN.min(hash.len())];
+            value.truncate(SERVICE_ID_CAPACITY - hash_suffix.len() - 1);
+            value.push('_');
+            value.push_str(hash_suffix);
+        }
+
+        value
+    }
+
     /// Returns the maximum string length of a [`ServiceId`]
     pub const fn max_len() -> usize {
         SERVICE_ID_CAPACITY
@@ -21,10 +21,44 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! # Hierarchy and Patterns
+//!
+//! The segments of a [`ServiceName`] are separated by `/` and form a hierarchy of namespaces.
+//! A [`ServiceNamePattern`] selects all [`ServiceName`]s that match a glob, where `*` matches
+//! any number of characters inside a segment and a `**` segment matches any number of segments.
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::service_name::ServiceNamePattern;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//!
+//! assert!(service_name.segments().eq(["My", "Funk", "ServiceName"]));
+//! assert!(service_name.starts_with("My/Funk"));
+//! assert!(!service_name.starts_with("My/Fu"));
+//!
+//! assert!(ServiceNamePattern::new("My/*/Service*")?.matches(&service_name));
+//! assert!(ServiceNamePattern::new("**/ServiceName")?.matches(&service_name));
+//! assert!(!ServiceNamePattern::new("My/*")?.matches(&service_name));
+//!
+//! # Ok(())
+//! # }
+//! ```
 
 use iceoryx2_bb_container::semantic_string::SemanticStringError;
+use iceoryx2_bb_log::fail;
 use serde::{de::Visitor, Deserialize, Serialize};
 
+const SEGMENT_SEPARATOR: char = '/';
+const SEGMENT_WILDCARD: &str = "**";
+
+/// The prefix of the [`ServiceName`]s of the builtin [`Service`](crate::service::Service)s of
+/// iceoryx2, like the system event channel. It is reserved and cannot be used by any other
+/// [`Service`](crate::service::Service).
+pub const BUILTIN_SERVICE_NAME_PREFIX: &str = "iox2://";
+
 /// The name of a [`Service`](crate::service::Service).
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ServiceName {
@@ -32,8 +66,25 @@ pub struct ServiceName {
 }
 
 impl ServiceName {
-    /// Creates a new [`ServiceName`]. The name is not allowed to be empty.
+    /// Creates a new [`ServiceName`]. The name is not allowed to be empty and must not start
+    /// with the reserved [`BUILTIN_SERVICE_NAME_PREFIX`].
     pub fn new(name: &str) -> Result<Self, SemanticStringError> {
+        if name.starts_with(BUILTIN_SERVICE_NAME_PREFIX) {
+            fail!(from "ServiceName::new()", with SemanticStringError::InvalidContent,
+                "Unable to create service name \"{}\" since the prefix \"{}\" is reserved for builtin services.",
+                name, BUILTIN_SERVICE_NAME_PREFIX);
+        }
+
+        Self::new_unreserved(name)
+    }
+
+    // Creates the name of a builtin service, the reserved prefix is part of the name.
+    pub(crate) fn new_builtin(name: &str) -> Result<Self, SemanticStringError> {
+        debug_assert!(name.starts_with(BUILTIN_SERVICE_NAME_PREFIX));
+        Self::new_unreserved(name)
+    }
+
+    fn new_unreserved(name: &str) -> Result<Self, SemanticStringError> {
         if name.is_empty() {
             return Err(SemanticStringError::InvalidContent);
         }
@@ -41,10 +92,139 @@ impl ServiceName {
         Ok(Self { value: name.into() })
     }
 
+    /// Returns true when the [`ServiceName`] belongs to a builtin
+    /// [`Service`](crate::service::Service) of iceoryx2, otherwise false.
+    pub fn is_builtin(&self) -> bool {
+        self.value.starts_with(BUILTIN_SERVICE_NAME_PREFIX)
+    }
+
     /// Returns a str reference to the [`ServiceName`]
     pub fn as_str(&self) -> &str {
         &self.value
     }
+
+    /// Returns an iterator over the `/` separated segments of the [`ServiceName`].
+    pub fn segments(&self) -> core::str::Split<'_, char> {
+        self.value.split(SEGMENT_SEPARATOR)
+    }
+
+    /// Returns true when the [`ServiceName`] is part of the namespace, meaning that the
+    /// leading segments are equal to the segments of the namespace. A trailing `/` of the
+    /// namespace is ignored and the empty namespace contains every [`ServiceName`].
+    pub fn starts_with(&self, namespace: &str) -> bool {
+        let namespace = namespace
+            .strip_suffix(SEGMENT_SEPARATOR)
+            .unwrap_or(namespace);
+        if namespace.is_empty() {
+            return true;
+        }
+
+        let mut segments = self.segments();
+        namespace
+            .split(SEGMENT_SEPARATOR)
+            .all(|namespace_segment| segments.next() == Some(namespace_segment))
+    }
+}
+
+/// A glob pattern that selects [`ServiceName`]s. The segments of the pattern are separated by
+/// `/`. A `*` matches any number of characters inside a segment and a segment consisting
+/// of `**` matches any number of segments, including none.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ServiceNamePattern {
+    value: String,
+}
+
+impl ServiceNamePattern {
+    /// Creates a new [`ServiceNamePattern`]. The pattern is not allowed to be empty and `**`
+    /// must be a segment on its own.
+    pub fn new(pattern: &str) -> Result<Self, SemanticStringError> {
+        if pattern.is_empty() {
+            fail!(from "ServiceNamePattern::new()", with SemanticStringError::InvalidContent,
+                "Unable to create service name pattern since the pattern is empty.");
+        }
+
+        if pattern
+            .split(SEGMENT_SEPARATOR)
+            .any(|segment| segment != SEGMENT_WILDCARD && segment.contains(SEGMENT_WILDCARD))
+        {
+            fail!(from "ServiceNamePattern::new()", with SemanticStringError::InvalidContent,
+                "Unable to create service name pattern \"{}\" since \"{}\" must be a segment on its own.",
+                pattern, SEGMENT_WILDCARD);
+        }
+
+        Ok(Self {
+            value: pattern.into(),
+        })
+    }
+
+    /// Returns a str reference to the [`ServiceNamePattern`]
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns true when the [`ServiceName`] matches the pattern, otherwise false.
+    pub fn matches(&self, service_name: &ServiceName) -> bool {
+        let pattern: Vec<&str> = self.value.split(SEGMENT_SEPARATOR).collect();
+        let segments: Vec<&str> = service_name.segments().collect();
+
+        Self::matches_segments(&pattern, &segments)
+    }
+
+    fn matches_segments(pattern: &[&str], segments: &[&str]) -> bool {
+        match pattern.split_first() {
+            None => segments.is_empty(),
+            Some((&SEGMENT_WILDCARD, pattern)) => {
+                (0..=segments.len()).any(|n| Self::matches_segments(pattern, &segments[n..]))
+            }
+            Some((pattern_segment, pattern)) => match segments.split_first() {
+                Some((segment, segments)) => {
+                    Self::matches_segment(pattern_segment.as_bytes(), segment.as_bytes())
+                        && Self::matches_segments(pattern, segments)
+                }
+                None => false,
+            },
+        }
+    }
+
+    fn matches_segment(pattern: &[u8], segment: &[u8]) -> bool {
+        let mut p = 0;
+        let mut s = 0;
+        // position of the last `*` in the pattern and the position in the segment where
+        // the match was continued after it
+        let mut backtrack = None;
+
+        while s < segment.len() {
+            if p < pattern.len() && pattern[p] == b'*' {
+                backtrack = Some((p, s));
+                p += 1;
+            } else if p < pattern.len() && pattern[p] == segment[s] {
+                p += 1;
+                s += 1;
+            } else if let Some((star, matched)) = backtrack {
+                p = star + 1;
+                s = matched + 1;
+                backtrack = Some((star, s));
+            } else {
+                return false;
+            }
+        }
+
+        pattern[p..].iter().all(|c| *c == b'*')
+    }
+}
+
+impl core::fmt::Display for ServiceNamePattern {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "{}", self.value)
+    }
+}
+
+impl core::str::FromStr for ServiceNamePattern {
+    type Err = SemanticStringError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
 }
 
 impl core::fmt::Display for ServiceName {
@@ -94,7 +274,8 @@ impl Visitor<'_> for ServiceNameVisitor {
     where
         E: serde::de::Error,
     {
-        match ServiceName::new(v) {
+        // the static configs of builtin services contain their reserved names
+        match ServiceName::new_unreserved(v) {
             Ok(v) => Ok(v),
             Err(v) => Err(E::custom(format!("invalid service name provided {:?}.", v))),
         }
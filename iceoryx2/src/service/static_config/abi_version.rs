@@ -0,0 +1,58 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The ABI version describes the layout of all data that iceoryx2 shares between processes,
+//! like the management structures in the dynamic service details or the headers of the
+//! samples. It is stored in the [`StaticConfig`](crate::service::static_config::StaticConfig)
+//! of every [`Service`](crate::service::Service) a [`Node`](crate::node::Node) creates and
+//! verified whenever the [`Service`](crate::service::Service) is opened.
+//!
+//! In contrast to the [`Iceoryx2Version`](crate::service::static_config::iceoryx2_version::Iceoryx2Version),
+//! the ABI version must match exactly. It protects against processes that were linked
+//! against different builds of the library, for instance a C application that loads an
+//! older `libiceoryx2_ffi`, which would otherwise misinterpret the shared memory. Those
+//! processes fail with `IncompatibleAbiVersion` on open. Services that do not record an ABI
+//! version at all were created by a release before [`ABI_VERSION_INTRODUCED_IN`]. They are
+//! opened when their layout is compatible, which is verified by the type details of the
+//! messaging pattern and the version of the dynamic service details.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::static_config::abi_version::ABI_VERSION;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service_name = ServiceName::new("MyEventName")?;
+//! let _event = node.service_builder(&service_name)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! let details = ipc::Service::details(&service_name, node.config(), MessagingPattern::Event)?;
+//! if let Some(abi_version) = details.and_then(|d| d.static_details.abi_version()) {
+//!     assert_eq!(abi_version, ABI_VERSION);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::iceoryx2_version::Iceoryx2Version;
+
+/// The ABI version of the running process. Must be increased whenever the layout of data
+/// that is shared between processes changes, but at most once per release.
+pub const ABI_VERSION: u32 = 1;
+
+/// The iceoryx2 release that introduced the [`ABI_VERSION`]. Must be set to the upcoming
+/// release whenever the [`ABI_VERSION`] is increased, it defines the
+/// [`MIN_COMPATIBLE_VERSION`](crate::service::static_config::iceoryx2_version::MIN_COMPATIBLE_VERSION).
+pub const ABI_VERSION_INTRODUCED_IN: Iceoryx2Version = Iceoryx2Version::new(0, 5, 0);
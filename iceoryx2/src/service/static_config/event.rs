@@ -28,6 +28,8 @@
 //! println!("notifier created event:       {:?}", event.static_config().notifier_created_event());
 //! println!("notifier dropped event:       {:?}", event.static_config().notifier_dropped_event());
 //! println!("notifier dead event:          {:?}", event.static_config().notifier_dead_event());
+//! println!("attribute changed event:      {:?}", event.static_config().dynamic_attribute_changed_event());
+//! println!("version:                      {:?}", event.static_config().version());
 //!
 //! # Ok(())
 //! # }
@@ -57,6 +59,9 @@ pub struct StaticConfig {
     pub(crate) notifier_created_event: Option<usize>,
     pub(crate) notifier_dropped_event: Option<usize>,
     pub(crate) notifier_dead_event: Option<usize>,
+    pub(crate) dynamic_attribute_changed_event: Option<usize>,
+    #[serde(default)]
+    pub(crate) version: u32,
 }
 
 impl StaticConfig {
@@ -73,6 +78,8 @@ impl StaticConfig {
             notifier_created_event: config.defaults.event.notifier_created_event,
             notifier_dropped_event: config.defaults.event.notifier_dropped_event,
             notifier_dead_event: config.defaults.event.notifier_dead_event,
+            dynamic_attribute_changed_event: config.defaults.event.dynamic_attribute_changed_event,
+            version: 0,
         }
     }
 
@@ -120,4 +127,15 @@ impl StaticConfig {
     pub fn notifier_dead_event(&self) -> Option<EventId> {
         self.notifier_dead_event.map(EventId::new)
     }
+
+    /// Returns the emitted [`EventId`] when a dynamic attribute of the service is updated.
+    pub fn dynamic_attribute_changed_event(&self) -> Option<EventId> {
+        self.dynamic_attribute_changed_event.map(EventId::new)
+    }
+
+    /// Returns the user defined version of the [`crate::service::Service`]. Services that
+    /// were created without an explicit version have version `0`.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
 }
@@ -0,0 +1,112 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The version of iceoryx2 that created a [`Service`](crate::service::Service). It is stored
+//! in the [`StaticConfig`](crate::service::static_config::StaticConfig) and verified whenever
+//! the [`Service`](crate::service::Service) is opened, so that processes with incompatible
+//! iceoryx2 versions, for instance during a rolling update, fail with
+//! `IncompatibleIceoryx2Version` instead of an internal failure.
+//!
+//! The accepted range is derived from the
+//! [`ABI_VERSION`](crate::service::static_config::abi_version::ABI_VERSION) which is verified
+//! first. All versions from the release that introduced the current ABI on share the same
+//! layout, therefore services of older releases and of newer patch releases can be opened.
+//! Services that do not record a version at all are accepted when their ABI version matches.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::static_config::iceoryx2_version::Iceoryx2Version;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service_name = ServiceName::new("MyEventName")?;
+//! let _event = node.service_builder(&service_name)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! let details = ipc::Service::details(&service_name, node.config(), MessagingPattern::Event)?;
+//! if let Some(version) = details.and_then(|d| d.static_details.iceoryx2_version().copied()) {
+//!     println!("created by iceoryx2 {}", version);
+//!     assert!(version.is_compatible_to(&Iceoryx2Version::current()));
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2_bb_elementary::package_version::PackageVersion;
+use serde::{Deserialize, Serialize};
+
+use super::abi_version::ABI_VERSION_INTRODUCED_IN;
+
+/// The oldest iceoryx2 version whose [`Service`](crate::service::Service)s can be opened by
+/// the current version. It is the release that introduced the current
+/// [`ABI_VERSION`](crate::service::static_config::abi_version::ABI_VERSION).
+pub const MIN_COMPATIBLE_VERSION: Iceoryx2Version = ABI_VERSION_INTRODUCED_IN;
+
+/// Represents the version of iceoryx2 that created a [`Service`](crate::service::Service).
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Iceoryx2Version {
+    major: u16,
+    minor: u16,
+    patch: u16,
+}
+
+impl core::fmt::Display for Iceoryx2Version {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl Iceoryx2Version {
+    /// Creates a new [`Iceoryx2Version`].
+    pub const fn new(major: u16, minor: u16, patch: u16) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Returns the [`Iceoryx2Version`] of the running process.
+    pub fn current() -> Self {
+        let version = PackageVersion::get();
+        Self::new(version.major(), version.minor(), version.patch())
+    }
+
+    /// Returns the major part of the version
+    pub fn major(&self) -> u16 {
+        self.major
+    }
+
+    /// Returns the minor part of the version
+    pub fn minor(&self) -> u16 {
+        self.minor
+    }
+
+    /// Returns the patch part of the version
+    pub fn patch(&self) -> u16 {
+        self.patch
+    }
+
+    /// Returns true when a [`Service`](crate::service::Service) created by this version can be
+    /// opened by the provided version. This is the case when this version is in the range
+    /// from [`MIN_COMPATIBLE_VERSION`] up to the last patch release of the provided version.
+    /// Patch releases never change the
+    /// [`ABI_VERSION`](crate::service::static_config::abi_version::ABI_VERSION), newer minor
+    /// or major versions are rejected since they may use a layout that is unknown to older
+    /// versions.
+    pub fn is_compatible_to(&self, opener: &Iceoryx2Version) -> bool {
+        MIN_COMPATIBLE_VERSION <= *self && (self.major, self.minor) <= (opener.major, opener.minor)
+    }
+}
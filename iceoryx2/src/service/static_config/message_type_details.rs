@@ -53,6 +53,47 @@ pub struct TypeDetail {
     /// The ABI-required minimum alignment of the underlying type calculated by [`core::mem::align_of`].
     /// It may be set by users with a larger alignment, e.g. the memory provided by allocator used by SIMD.
     pub alignment: usize,
+    /// The optional user defined identifier of the type, see
+    /// [`StableTypeId`](iceoryx2_bb_elementary::stable_type_id::StableTypeId). When both
+    /// sides provide it, it is used for the compatibility check instead of the `type_name`.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "stable_type_id_serialization"
+    )]
+    pub stable_type_id: Option<u128>,
+    /// The [`TypeDetail`]s of all variants when the type is a tagged union, see
+    /// [`TaggedUnion`](crate::tagged_union::TaggedUnion). The position in the list is the tag
+    /// of the variant. Is empty for all other types.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub variants: Vec<TypeDetail>,
+}
+
+// u128 values are not supported by every serialization format, therefore the identifier
+// is stored as hex string.
+mod stable_type_id_serialization {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        value: &Option<u128>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serializer.serialize_str(&format!("{:032x}", value)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u128>, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(value) => u128::from_str_radix(&value, 16)
+                .map(Some)
+                .map_err(D::Error::custom),
+            None => Ok(None),
+        }
+    }
 }
 
 impl TypeDetail {
@@ -63,8 +104,42 @@ impl TypeDetail {
             type_name: core::any::type_name::<T>().to_string(),
             size: core::mem::size_of::<T>(),
             alignment: core::mem::align_of::<T>(),
+            stable_type_id: None,
+            variants: vec![],
         }
     }
+
+    fn is_same_type_as(&self, rhs: &Self) -> bool {
+        let is_same_type = match (self.stable_type_id, rhs.stable_type_id) {
+            (Some(lhs_id), Some(rhs_id)) => lhs_id == rhs_id,
+            _ => self.type_name == rhs.type_name,
+        };
+
+        is_same_type && self.has_same_variants_as(rhs)
+    }
+
+    fn has_same_layout_as(&self, rhs: &Self) -> bool {
+        self.size == rhs.size && self.alignment == rhs.alignment
+    }
+
+    // the variants are only verified when both sides describe them
+    fn has_same_variants_as(&self, rhs: &Self) -> bool {
+        if self.variants.is_empty() || rhs.variants.is_empty() {
+            return true;
+        }
+
+        self.variants.len() == rhs.variants.len()
+            && self
+                .variants
+                .iter()
+                .zip(rhs.variants.iter())
+                .all(|(lhs, rhs)| {
+                    lhs.is_same_type_as(rhs)
+                        && lhs.variant == rhs.variant
+                        && lhs.size == rhs.size
+                        && lhs.alignment == rhs.alignment
+                })
+    }
 }
 
 /// Contains all type information to the header and payload type.
@@ -117,14 +192,45 @@ impl MessageTypeDetails {
     }
 
     pub(crate) fn is_compatible_to(&self, rhs: &Self) -> bool {
+        self.is_compatible_to_impl(rhs, false)
+    }
+
+    /// Like [`MessageTypeDetails::is_compatible_to()`] but accepts a fixed size payload of
+    /// `rhs` that is larger than the own payload. The own payload must be a prefix of the
+    /// payload of `rhs`, e.g. a previous version of the same type where fields were only
+    /// appended.
+    pub(crate) fn is_prefix_compatible_to(&self, rhs: &Self) -> bool {
+        self.is_compatible_to_impl(rhs, true)
+    }
+
+    /// Returns true when both describe the same types but their layout differs, e.g. since
+    /// they were acquired in processes with a different pointer width.
+    pub(crate) fn has_different_layout_than(&self, rhs: &Self) -> bool {
+        let is_same_type = self.user_header.is_same_type_as(&rhs.user_header)
+            && self.payload.is_same_type_as(&rhs.payload);
+        let is_same_layout = self.header.has_same_layout_as(&rhs.header)
+            && self.user_header.has_same_layout_as(&rhs.user_header)
+            && self.payload.has_same_layout_as(&rhs.payload);
+
+        is_same_type && !is_same_layout
+    }
+
+    fn is_compatible_to_impl(&self, rhs: &Self, allow_smaller_payload: bool) -> bool {
+        let is_payload_size_compatible =
+            if allow_smaller_payload && self.payload.variant == TypeVariant::FixedSize {
+                self.payload.size <= rhs.payload.size
+            } else {
+                self.payload.size == rhs.payload.size
+            };
+
         self.header == rhs.header
-            && self.user_header.type_name == rhs.user_header.type_name
+            && self.user_header.is_same_type_as(&rhs.user_header)
             && self.user_header.variant == rhs.user_header.variant
             && self.user_header.size == rhs.user_header.size
             && self.user_header.alignment <= rhs.user_header.alignment
-            && self.payload.type_name == rhs.payload.type_name
+            && self.payload.is_same_type_as(&rhs.payload)
             && self.payload.variant == rhs.payload.variant
-            && self.payload.size == rhs.payload.size
+            && is_payload_size_compatible
             && self.payload.alignment <= rhs.payload.alignment
     }
 }
@@ -155,18 +261,24 @@ mod tests {
                 type_name: "i32".to_string(),
                 size: 4,
                 alignment: 4, // i32 uses 4 bytes, so its aliment is always 4 no matter x32 or x64.
+                stable_type_id: None,
+                variants: vec![],
             },
             user_header: TypeDetail{
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: ALIGNMENT,
+                stable_type_id: None,
+                variants: vec![],
             },
             payload: TypeDetail{
                 variant: TypeVariant::FixedSize,
                 type_name: "iceoryx2::service::static_config::message_type_details::tests::test_from::MyPayload".to_string(),
                 size: 16,
                 alignment: ALIGNMENT,
+                stable_type_id: None,
+                variants: vec![],
             },
         };
         assert_that!(sut, eq expected);
@@ -178,18 +290,24 @@ mod tests {
                 type_name: "i32".to_string(),
                 size: 4,
                 alignment: 4,
+                stable_type_id: None,
+                variants: vec![],
             },
             user_header: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "bool".to_string(),
                 size: 1,
                 alignment: 1,
+                stable_type_id: None,
+                variants: vec![],
             },
             payload: TypeDetail {
                 variant: TypeVariant::Dynamic,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: ALIGNMENT,
+                stable_type_id: None,
+                variants: vec![],
             },
         };
         assert_that!(sut, eq expected);
@@ -335,18 +453,24 @@ mod tests {
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: ALIGNMENT,
+                stable_type_id: None,
+                variants: vec![],
             },
             user_header: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                stable_type_id: None,
+                variants: vec![],
             },
             payload: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                stable_type_id: None,
+                variants: vec![],
             },
         };
         // smaller to bigger is allowed.
@@ -370,22 +494,90 @@ mod tests {
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: ALIGNMENT,
+                stable_type_id: None,
+                variants: vec![],
             },
             user_header: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                stable_type_id: None,
+                variants: vec![],
             },
             payload: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                stable_type_id: None,
+                variants: vec![],
             },
         };
         // bigger to smaller is invalid.
         let sut = right.is_compatible_to(&left);
         assert_that!(sut, eq false);
     }
+
+    #[test]
+    fn test_is_compatible_to_uses_stable_type_id_when_both_sides_provide_it() {
+        let mut left = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::FixedSize);
+        let mut right = MessageTypeDetails::from::<i64, i64, u64>(TypeVariant::FixedSize);
+        assert_that!(left.is_compatible_to(&right), eq false);
+
+        left.payload.stable_type_id = Some(0x1234);
+        assert_that!(left.is_compatible_to(&right), eq false);
+
+        right.payload.stable_type_id = Some(0x1234);
+        assert_that!(left.is_compatible_to(&right), eq true);
+
+        right.payload.stable_type_id = Some(0x4321);
+        assert_that!(left.is_compatible_to(&right), eq false);
+    }
+
+    #[test]
+    fn test_is_compatible_to_with_stable_type_id_still_verifies_the_layout() {
+        let mut left = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::FixedSize);
+        let mut right = MessageTypeDetails::from::<i64, i64, i32>(TypeVariant::FixedSize);
+        left.payload.stable_type_id = Some(0x1234);
+        right.payload.stable_type_id = Some(0x1234);
+
+        assert_that!(left.is_compatible_to(&right), eq false);
+    }
+
+    #[test]
+    fn test_is_prefix_compatible_to_accepts_smaller_fixed_size_payload() {
+        let mut left = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::FixedSize);
+        let mut right = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::FixedSize);
+        right.payload.size = 24;
+
+        assert_that!(left.is_compatible_to(&right), eq false);
+        assert_that!(left.is_prefix_compatible_to(&right), eq true);
+        assert_that!(right.is_prefix_compatible_to(&left), eq false);
+
+        left.payload.variant = TypeVariant::Dynamic;
+        right.payload.variant = TypeVariant::Dynamic;
+        assert_that!(left.is_prefix_compatible_to(&right), eq false);
+    }
+
+    #[test]
+    fn test_is_compatible_to_verifies_tagged_union_variants_when_both_sides_provide_them() {
+        let mut left = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::FixedSize);
+        let mut right = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::FixedSize);
+
+        left.payload.variants = vec![
+            TypeDetail::__internal_new::<u8>(TypeVariant::FixedSize),
+            TypeDetail::__internal_new::<u32>(TypeVariant::FixedSize),
+        ];
+        assert_that!(left.is_compatible_to(&right), eq true);
+
+        right.payload.variants = left.payload.variants.clone();
+        assert_that!(left.is_compatible_to(&right), eq true);
+
+        right.payload.variants.reverse();
+        assert_that!(left.is_compatible_to(&right), eq false);
+
+        right.payload.variants = vec![TypeDetail::__internal_new::<u8>(TypeVariant::FixedSize)];
+        assert_that!(left.is_compatible_to(&right), eq false);
+    }
 }
@@ -25,6 +25,7 @@ use super::request_response;
 #[non_exhaustive]
 #[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "messaging_pattern")]
+#[allow(clippy::large_enum_variant)]
 pub enum MessagingPattern {
     /// Stores the static config of the
     /// [`service::MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
@@ -28,12 +28,22 @@ pub mod request_response;
 
 pub mod messaging_pattern;
 
+/// The version of iceoryx2 that created the service.
+pub mod iceoryx2_version;
+
+/// The version of the layout of the data that is shared between processes.
+pub mod abi_version;
+
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::hash::Hash;
+use iceoryx2_cal::named_concept::AccessControlList;
 use serde::{Deserialize, Serialize};
 
 use crate::config;
 
+use self::abi_version::ABI_VERSION;
+use self::iceoryx2_version::Iceoryx2Version;
 use self::messaging_pattern::MessagingPattern;
 
 use super::{attribute::AttributeSet, service_id::ServiceId, service_name::ServiceName};
@@ -45,6 +55,16 @@ pub struct StaticConfig {
     service_name: ServiceName,
     pub(crate) attributes: AttributeSet,
     pub(crate) messaging_pattern: MessagingPattern,
+    #[serde(default)]
+    domain: Option<FileName>,
+    #[serde(default)]
+    iceoryx2_version: Option<Iceoryx2Version>,
+    #[serde(default)]
+    abi_version: Option<u32>,
+    #[serde(default)]
+    pointer_width: Option<u32>,
+    #[serde(default)]
+    access_control_list: Option<AccessControlList>,
 }
 
 impl StaticConfig {
@@ -58,10 +78,16 @@ impl StaticConfig {
             service_id: ServiceId::new::<Hasher>(
                 service_name,
                 crate::service::messaging_pattern::MessagingPattern::RequestResponse,
+                &config.global.service,
             ),
             service_name: service_name.clone(),
             messaging_pattern,
             attributes: AttributeSet::new(),
+            domain: config.global.domain().copied(),
+            iceoryx2_version: Some(Iceoryx2Version::current()),
+            abi_version: Some(ABI_VERSION),
+            pointer_width: Some(usize::BITS),
+            access_control_list: config.global.service.access_control_list,
         }
     }
 
@@ -74,10 +100,16 @@ impl StaticConfig {
             service_id: ServiceId::new::<Hasher>(
                 service_name,
                 crate::service::messaging_pattern::MessagingPattern::Event,
+                &config.global.service,
             ),
             service_name: service_name.clone(),
             messaging_pattern,
             attributes: AttributeSet::new(),
+            domain: config.global.domain().copied(),
+            iceoryx2_version: Some(Iceoryx2Version::current()),
+            abi_version: Some(ABI_VERSION),
+            pointer_width: Some(usize::BITS),
+            access_control_list: config.global.service.access_control_list,
         }
     }
 
@@ -91,10 +123,16 @@ impl StaticConfig {
             service_id: ServiceId::new::<Hasher>(
                 service_name,
                 crate::service::messaging_pattern::MessagingPattern::PublishSubscribe,
+                &config.global.service,
             ),
             service_name: service_name.clone(),
             messaging_pattern,
             attributes: AttributeSet::new(),
+            domain: config.global.domain().copied(),
+            iceoryx2_version: Some(Iceoryx2Version::current()),
+            abi_version: Some(ABI_VERSION),
+            pointer_width: Some(usize::BITS),
+            access_control_list: config.global.service.access_control_list,
         }
     }
 
@@ -118,6 +156,48 @@ impl StaticConfig {
         &self.messaging_pattern
     }
 
+    /// Returns the domain in which the [`crate::service::Service`] was created, see
+    /// [`Config::set_domain()`](crate::config::Config::set_domain()).
+    pub fn domain(&self) -> Option<&FileName> {
+        self.domain.as_ref()
+    }
+
+    /// Returns the version of iceoryx2 that created the [`crate::service::Service`]. Returns
+    /// [`None`] when the [`crate::service::Service`] was created by a version that did not
+    /// record it.
+    pub fn iceoryx2_version(&self) -> Option<&Iceoryx2Version> {
+        self.iceoryx2_version.as_ref()
+    }
+
+    /// Returns the [`ABI_VERSION`] of the process that created the
+    /// [`crate::service::Service`]. Returns [`None`] when the [`crate::service::Service`] was
+    /// created by a version that did not record it.
+    pub fn abi_version(&self) -> Option<u32> {
+        self.abi_version
+    }
+
+    /// Returns the pointer width in bits of the process that created the
+    /// [`crate::service::Service`]. Returns [`None`] when the [`crate::service::Service`] was
+    /// created by a version that did not record it.
+    pub fn pointer_width(&self) -> Option<u32> {
+        self.pointer_width
+    }
+
+    pub(crate) fn has_different_pointer_width(&self) -> bool {
+        self.pointer_width
+            .is_some_and(|pointer_width| pointer_width != usize::BITS)
+    }
+
+    /// Returns the [`AccessControlList`] that is applied to the resources of the
+    /// [`crate::service::Service`]. When it is [`None`], only the owner can access them.
+    pub fn access_control_list(&self) -> Option<&AccessControlList> {
+        self.access_control_list.as_ref()
+    }
+
+    pub(crate) fn set_access_control_list(&mut self, value: Option<AccessControlList>) {
+        self.access_control_list = value;
+    }
+
     pub(crate) fn has_same_messaging_pattern(&self, rhs: &StaticConfig) -> bool {
         self.messaging_pattern
             .is_same_pattern(&rhs.messaging_pattern)
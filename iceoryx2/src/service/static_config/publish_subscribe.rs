@@ -28,6 +28,9 @@
 //! println!("history size:                     {:?}", pubsub.static_config().history_size());
 //! println!("subscriber max borrowed samples:  {:?}", pubsub.static_config().subscriber_max_borrowed_samples());
 //! println!("safe overflow:                    {:?}", pubsub.static_config().has_safe_overflow());
+//! println!("out of memory strategy:           {:?}", pubsub.static_config().out_of_memory_strategy());
+//! println!("zero payload on loan:             {:?}", pubsub.static_config().zero_payload_on_loan());
+//! println!("version:                          {:?}", pubsub.static_config().version());
 //!
 //! # Ok(())
 //! # }
@@ -35,6 +38,7 @@
 
 use super::message_type_details::MessageTypeDetails;
 use crate::config;
+use crate::port::out_of_memory_strategy::OutOfMemoryStrategy;
 use serde::{Deserialize, Serialize};
 
 /// The static configuration of an
@@ -50,7 +54,13 @@ pub struct StaticConfig {
     pub(crate) subscriber_max_buffer_size: usize,
     pub(crate) subscriber_max_borrowed_samples: usize,
     pub(crate) enable_safe_overflow: bool,
+    #[serde(default)]
+    pub(crate) out_of_memory_strategy: OutOfMemoryStrategy,
+    #[serde(default)]
+    pub(crate) zero_payload_on_loan: bool,
     pub(crate) message_type_details: MessageTypeDetails,
+    #[serde(default)]
+    pub(crate) version: u32,
 }
 
 impl StaticConfig {
@@ -69,7 +79,10 @@ impl StaticConfig {
                 .publish_subscribe
                 .subscriber_max_borrowed_samples,
             enable_safe_overflow: config.defaults.publish_subscribe.enable_safe_overflow,
+            out_of_memory_strategy: config.defaults.publish_subscribe.out_of_memory_strategy,
+            zero_payload_on_loan: config.defaults.publish_subscribe.zero_payload_on_loan,
             message_type_details: MessageTypeDetails::default(),
+            version: 0,
         }
     }
 
@@ -123,8 +136,27 @@ impl StaticConfig {
         self.enable_safe_overflow
     }
 
+    /// Returns the [`OutOfMemoryStrategy`] the [`crate::port::publisher::Publisher`]s of the
+    /// [`crate::service::Service`] pursue when their data segment is exhausted.
+    pub fn out_of_memory_strategy(&self) -> OutOfMemoryStrategy {
+        self.out_of_memory_strategy
+    }
+
+    /// Returns true if the [`crate::port::publisher::Publisher`]s of the
+    /// [`crate::service::Service`] zero the memory of every
+    /// [`crate::sample_mut::SampleMut`] on loan, otherwise false.
+    pub fn zero_payload_on_loan(&self) -> bool {
+        self.zero_payload_on_loan
+    }
+
     /// Returns the type details of the [`crate::service::Service`].
     pub fn message_type_details(&self) -> &MessageTypeDetails {
         &self.message_type_details
     }
+
+    /// Returns the user defined version of the [`crate::service::Service`]. Services that
+    /// were created without an explicit version have version `0`.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
 }
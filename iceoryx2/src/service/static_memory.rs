@@ -0,0 +1,82 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<static_memory::Service>()?;
+//!
+//! // use `static_memory` as communication variant
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher_builder().create()?;
+//! let subscriber = service.subscriber_builder().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! See [`Service`](crate::service) for more detailed examples.
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+
+use crate::service::dynamic_config::DynamicConfig;
+use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::*;
+
+use super::ServiceState;
+
+/// Defines a single address space communication setup, like on a microcontroller, whose
+/// payload memory, connections, events and service configurations are placed into a
+/// statically allocated memory region with a fixed size instead of heap or shared memory. The
+/// size is defined at compile time, see
+/// [`STATIC_MEMORY_SIZE`](iceoryx2_cal::static_memory::STATIC_MEMORY_SIZE).
+///
+/// The listeners are not file descriptor based and cannot be attached to a
+/// [`WaitSet`](crate::waitset::WaitSet).
+#[derive(Debug)]
+pub struct Service {
+    state: Arc<ServiceState<Self>>,
+}
+
+impl crate::service::Service for Service {
+    type StaticStorage = static_storage::static_memory::Storage;
+    type ConfigSerializer = serialize::toml::Toml;
+    type DynamicStorage = dynamic_storage::static_memory::Storage<DynamicConfig>;
+    type ServiceNameHasher = hash::sha1::Sha1;
+    type SharedMemory = shared_memory::static_memory::Memory<PoolAllocator>;
+    type ResizableSharedMemory =
+        resizable_shared_memory::dynamic::DynamicMemory<PoolAllocator, Self::SharedMemory>;
+    type Connection = zero_copy_connection::static_memory::Connection;
+    type Event = event::sem_bitset_static_memory::Event;
+    type Monitoring = monitoring::process_local::ProcessLocalMonitoring;
+    type Reactor = reactor::posix_select::Reactor;
+}
+
+impl crate::service::internal::ServiceInternal<Service> for Service {
+    fn __internal_from_state(state: ServiceState<Self>) -> Self {
+        Self {
+            state: Arc::new(state),
+        }
+    }
+
+    fn __internal_state(&self) -> &Arc<ServiceState<Self>> {
+        &self.state
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Allows a [`Service`](crate::service::Service) to transport one payload out of a closed set of
+//! types, so that a command channel with heterogeneous messages does not require one
+//! [`Service`](crate::service::Service) per message type.
+//!
+//! The [`tagged_union!`](crate::tagged_union!) macro defines a `#[repr(C, u32)]` enum where
+//! every variant wraps exactly one type and the position of the variant is its tag. It
+//! implements [`TaggedUnion`] for the enum and [`TaggedUnionVariant`] for every wrapped type.
+//!
+//! With
+//! [`Builder::use_tagged_union_payload_variants()`](crate::service::builder::publish_subscribe::Builder::use_tagged_union_payload_variants())
+//! the [`TypeDetail`] of every variant is stored in the static config of the
+//! [`Service`](crate::service::Service) and verified when the
+//! [`Service`](crate::service::Service) is opened.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! #[derive(Debug, Clone, Copy)]
+//! #[repr(C)]
+//! pub struct Start {
+//!     speed: u64,
+//! }
+//!
+//! #[derive(Debug, Clone, Copy)]
+//! #[repr(C)]
+//! pub struct Stop;
+//!
+//! iceoryx2::tagged_union! {
+//!     #[derive(Debug)]
+//!     pub enum Command {
+//!         Start(Start),
+//!         Stop(Stop),
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Command/Channel".try_into()?)
+//!     .publish_subscribe::<Command>()
+//!     .use_tagged_union_payload_variants()
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher_builder().create()?;
+//! let subscriber = service.subscriber_builder().create()?;
+//!
+//! publisher.send_variant(Start { speed: 42 })?;
+//! publisher.send_variant(Stop)?;
+//!
+//! while let Some(sample) = subscriber.receive()? {
+//!     match *sample {
+//!         Command::Start(start) => println!("start with {:?}", start),
+//!         Command::Stop(_) => println!("stop"),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+
+use crate::service::static_config::message_type_details::TypeDetail;
+
+/// A `#[repr(C, u32)]` enum where every variant wraps exactly one type. Use the
+/// [`tagged_union!`](crate::tagged_union!) macro to implement it.
+pub trait TaggedUnion: Debug + Sized {
+    /// Returns the [`TypeDetail`] of every variant. The position in the list is the tag of
+    /// the variant.
+    fn variant_type_details() -> Vec<TypeDetail>;
+}
+
+/// Implemented by every type that is wrapped by a variant of the [`TaggedUnion`].
+pub trait TaggedUnionVariant<Union: TaggedUnion> {
+    /// Wraps the value into the corresponding variant of the [`TaggedUnion`].
+    fn into_union(self) -> Union;
+}
+
+/// Defines a `#[repr(C, u32)]` enum where every variant wraps exactly one type and implements
+/// [`TaggedUnion`] for it and [`TaggedUnionVariant`] for every wrapped type. Every type can
+/// only be wrapped by one variant.
+///
+/// ```
+/// #[derive(Debug)]
+/// #[repr(C)]
+/// pub struct Ping(u64);
+///
+/// iceoryx2::tagged_union! {
+///     #[derive(Debug)]
+///     pub enum Message {
+///         Ping(Ping),
+///         Pong(u64),
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! tagged_union {
+    (
+        $(#[$attribute:meta])*
+        $visibility:vis enum $name:ident {
+            $($variant:ident($variant_type:ty)),+ $(,)?
+        }
+    ) => {
+        $(#[$attribute])*
+        #[repr(C, u32)]
+        $visibility enum $name {
+            $($variant($variant_type)),+
+        }
+
+        impl $crate::tagged_union::TaggedUnion for $name {
+            fn variant_type_details(
+            ) -> ::std::vec::Vec<$crate::service::static_config::message_type_details::TypeDetail> {
+                ::std::vec![$(
+                    $crate::service::static_config::message_type_details::TypeDetail::__internal_new::<
+                        $variant_type,
+                    >(
+                        $crate::service::static_config::message_type_details::TypeVariant::FixedSize,
+                    )
+                ),+]
+            }
+        }
+
+        $(
+            impl $crate::tagged_union::TaggedUnionVariant<$name> for $variant_type {
+                fn into_union(self) -> $name {
+                    $name::$variant(self)
+                }
+            }
+        )+
+    };
+}
@@ -61,3 +61,23 @@ pub fn generate_isolated_config() -> Config {
 
     config
 }
+
+/// Leaves the dynamic attributes of the service in the state of a node that died while it
+/// was updating them.
+///
+/// # Safety
+///
+///  * only for internal testing purposes
+///  * the node of the port factory must die before the dynamic attributes are updated again
+#[cfg(feature = "testing")]
+pub unsafe fn __internal_dynamic_attributes_staged_writer_death<S: crate::service::Service>(
+    port_factory: &crate::service::port_factory::event::PortFactory<S>,
+) {
+    use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+    let state = port_factory.service.__internal_state();
+    state
+        .dynamic_storage
+        .get()
+        .abandon_dynamic_attributes_writer_lock(state.shared_node.id());
+}
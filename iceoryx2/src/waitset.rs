@@ -31,13 +31,22 @@
 //!     wakes up and informs the user that the time has passed by.
 //!     This is useful when a [`Publisher`](crate::port::publisher::Publisher) shall send an
 //!     heartbeat every 100ms.
+//!     The intervals are drift-free, a long running callback does not delay the next
+//!     period and the number of lost periods can be acquired with
+//!     [`WaitSetGuard::missed_deadlines()`](crate::waitset::WaitSetGuard::missed_deadlines()).
+//! * **Timeout** - Like an *Interval* but the [`WaitSet`](crate::waitset::WaitSet) informs
+//!     the user only once, until it is rearmed with
+//!     [`WaitSetGuard::reset()`](crate::waitset::WaitSetGuard::reset()).
 //!
 //! The [`WaitSet`](crate::waitset::WaitSet) allows the user to attach multiple
 //! [`Listener`](crate::port::listener::Listener) from multiple [`Node`](crate::node::Node)s,
 //! anything that implements
 //! [`SynchronousMultiplexing`](iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing)
 //! with timeouts (Deadline) or without them (Notification). Additional, an arbitrary amount of
-//! intervals (Ticks) can be attached.
+//! intervals (Ticks) and one-shot timeouts can be attached. Every attachment is identified by
+//! its [`WaitSetAttachmentId`](crate::waitset::WaitSetAttachmentId) which can be used to
+//! associate a user context with it, see the [`HashMap`](std::collections::HashMap) approach
+//! below.
 //!
 //! # Example
 //!
@@ -222,7 +231,10 @@ use std::collections::HashMap;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_posix::{
-    deadline_queue::{DeadlineQueue, DeadlineQueueBuilder, DeadlineQueueGuard, DeadlineQueueIndex},
+    deadline_queue::{
+        DeadlineQueue, DeadlineQueueAddError, DeadlineQueueBuilder, DeadlineQueueGuard,
+        DeadlineQueueIndex,
+    },
     file_descriptor::FileDescriptor,
     file_descriptor_set::SynchronousMultiplexing,
     signal::SignalHandler,
@@ -456,6 +468,36 @@ where
     guard_type: GuardType<'waitset, 'attachment, Service>,
 }
 
+impl<Service: crate::service::Service> WaitSetGuard<'_, '_, Service> {
+    /// Returns the number of deadlines of an interval or deadline attachment that were hit
+    /// but not reported separately, since more than one period passed until the [`WaitSet`]
+    /// handled the attachment. For a cyclic task it is the number of cycles that were lost.
+    /// Is always zero for notifications and timeouts.
+    pub fn missed_deadlines(&self) -> u64 {
+        match &self.guard_type {
+            GuardType::Tick(t) | GuardType::Deadline(_, t) => self
+                .waitset
+                .deadline_queue
+                .number_of_missed_deadlines(t.index()),
+            GuardType::Notification(_) => 0,
+        }
+    }
+
+    /// Restarts the period of an interval, deadline or timeout attachment. A timeout that
+    /// has already fired is rearmed. Does nothing for notifications.
+    pub fn reset(&self) -> Result<(), WaitSetAttachmentError> {
+        match &self.guard_type {
+            GuardType::Tick(t) | GuardType::Deadline(_, t) => {
+                fail!(from self.waitset, when t.reset(),
+                    with WaitSetAttachmentError::InternalError,
+                    "Unable to reset the attachment since the current time could not be acquired.");
+                Ok(())
+            }
+            GuardType::Notification(_) => Ok(()),
+        }
+    }
+}
+
 impl<Service: crate::service::Service> Drop for WaitSetGuard<'_, '_, Service> {
     fn drop(&mut self) {
         if let GuardType::Deadline(r, t) = &self.guard_type {
@@ -697,6 +739,33 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         })
     }
 
+    /// Attaches a one-shot timeout to the [`WaitSet`]. When the timeout is reached the
+    /// [`WaitSet`] informs the user once in [`WaitSet::wait_and_process()`]. It can be rearmed
+    /// with [`WaitSetGuard::reset()`].
+    pub fn attach_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<WaitSetGuard<'_, '_, Service>, WaitSetAttachmentError> {
+        let msg = "Unable to attach timeout to underlying Timer";
+        let deadline_queue_guard = match self.deadline_queue.add_deadline_timeout(timeout) {
+            Ok(guard) => guard,
+            Err(DeadlineQueueAddError::ZeroPeriod) => {
+                fail!(from self, with WaitSetAttachmentError::InternalError,
+                    "{msg} since a timeout of zero is not supported.");
+            }
+            Err(e) => {
+                fail!(from self, with WaitSetAttachmentError::InternalError,
+                    "{msg} since the timeout could not be attached to the underlying deadline_queue due to ({:?}).", e);
+            }
+        };
+        self.attach()?;
+
+        Ok(WaitSetGuard {
+            waitset: self,
+            guard_type: GuardType::Tick(deadline_queue_guard),
+        })
+    }
+
     /// Waits until an event arrives on the [`WaitSet`], then collects all events by calling the
     /// provided `fn_call` callback with the corresponding [`WaitSetAttachmentId`]. In contrast
     /// to [`WaitSet::wait_and_process_once()`] it will never return until the user explicitly
@@ -897,8 +966,7 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         // Collect all triggered file descriptors. We need to collect them first, then reset
         // the deadline and then call the callback, otherwise a long callback may destroy the
         // deadline contract.
-        let reactor_wait_result = if self.deadline_queue.is_empty() || next_timeout == Duration::MAX
-        {
+        let reactor_wait_result = if next_timeout == Duration::MAX {
             self.reactor.blocking_wait(collect_triggered_fds)
         } else {
             self.reactor.timed_wait(collect_triggered_fds, next_timeout)
@@ -972,6 +1040,10 @@ impl<Service: crate::service::Service> WaitSet<Service> {
 
         match self.deadline_queue.add_deadline_interval(timeout) {
             Ok(guard) => Ok(guard),
+            Err(DeadlineQueueAddError::ZeroPeriod) => {
+                fail!(from self, with WaitSetAttachmentError::InternalError,
+                    "{msg} since an interval of zero is not supported.");
+            }
             Err(e) => {
                 fail!(from self, with WaitSetAttachmentError::InternalError,
                     "{msg} since the timeout could not be attached to the underlying deadline_queue due to ({:?}).", e);
@@ -12,7 +12,11 @@
 
 #[cfg(test)]
 mod attribute {
-    use iceoryx2::service::attribute::AttributeVerifier;
+    use iceoryx2::service::attribute::{
+        AttributeSpecifier, AttributeVerifier, DynamicAttributeDefinitionError,
+        DynamicAttributePermission, MAX_DYNAMIC_ATTRIBUTE_KEY_LENGTH,
+        MAX_DYNAMIC_ATTRIBUTE_VALUE_LENGTH, MAX_NUMBER_OF_DYNAMIC_ATTRIBUTES,
+    };
     use iceoryx2_bb_elementary::CallbackProgression;
     use iceoryx2_bb_testing::assert_that;
 
@@ -95,4 +99,61 @@ mod attribute {
 
         assert_that!(counter, eq 0);
     }
+
+    #[test]
+    fn defining_dynamic_attribute_twice_replaces_initial_value() {
+        let sut = AttributeSpecifier::new()
+            .define_dynamic("revision", "1", DynamicAttributePermission::OwnerOnly)
+            .unwrap()
+            .define_dynamic("revision", "2", DynamicAttributePermission::AllParticipants)
+            .unwrap();
+
+        assert_that!(sut.dynamic_attributes().get_key_value_len("revision"), eq 1);
+        assert_that!(sut.dynamic_attributes().get_key_value_at("revision", 0), eq Some("2"));
+        assert_that!(
+            sut.dynamic_attribute_permission("revision"),
+            eq Some(DynamicAttributePermission::AllParticipants)
+        );
+        assert_that!(sut.dynamic_attribute_permission("unknown"), eq None);
+        assert_that!(sut.attributes().iter(), len 0);
+    }
+
+    #[test]
+    fn defining_dynamic_attributes_exceeding_the_limits_fails() {
+        let sut = AttributeSpecifier::new().define_dynamic(
+            &"k".repeat(MAX_DYNAMIC_ATTRIBUTE_KEY_LENGTH + 1),
+            "value",
+            DynamicAttributePermission::OwnerOnly,
+        );
+        assert_that!(sut.err(), eq Some(DynamicAttributeDefinitionError::KeyTooLong));
+
+        let sut = AttributeSpecifier::new().define_dynamic(
+            "key",
+            &"v".repeat(MAX_DYNAMIC_ATTRIBUTE_VALUE_LENGTH + 1),
+            DynamicAttributePermission::OwnerOnly,
+        );
+        assert_that!(sut.err(), eq Some(DynamicAttributeDefinitionError::ValueTooLong));
+
+        let mut sut = AttributeSpecifier::new();
+        for i in 0..MAX_NUMBER_OF_DYNAMIC_ATTRIBUTES {
+            sut = sut
+                .define_dynamic(
+                    &format!("key_{i}"),
+                    "value",
+                    DynamicAttributePermission::OwnerOnly,
+                )
+                .unwrap();
+        }
+        assert_that!(sut.dynamic_attributes().iter(), len MAX_NUMBER_OF_DYNAMIC_ATTRIBUTES);
+
+        let sut = sut.define_dynamic(
+            "one_too_many",
+            "value",
+            DynamicAttributePermission::OwnerOnly,
+        );
+        assert_that!(
+            sut.err(),
+            eq Some(DynamicAttributeDefinitionError::ExceedsMaxNumberOfDynamicAttributes)
+        );
+    }
 }
@@ -11,7 +11,24 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 mod node_name {
+    use core::sync::atomic::Ordering;
+    use core::time::Duration;
+    use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    use iceoryx2::config::{
+        ConfigBuilder, ConfigCreationError, ConfigDomainError, ConfigReloadError,
+        ConfigSerializationError, ConfigValidationSeverity, ConfigWatchError,
+    };
     use iceoryx2::prelude::*;
+    use iceoryx2_bb_log::{get_log_level_for, remove_log_level_for, LogLevel};
+    use iceoryx2_bb_posix::config::test_directory;
+    use iceoryx2_bb_posix::file::*;
+    use iceoryx2_bb_posix::permission::Permission;
+    use iceoryx2_bb_posix::testing::create_test_directory;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_system_types::file_path::*;
     use iceoryx2_bb_system_types::path::*;
     use iceoryx2_bb_testing::{assert_that, test_requires};
@@ -41,4 +58,740 @@ mod node_name {
 
         assert_that!(default_config, eq file_config);
     }
+
+    fn generate_config_file_name(suffix: &str) -> FilePath {
+        create_test_directory();
+        let file = FileName::new(
+            format!(
+                "config_tests_{}_{}.toml",
+                UniqueSystemId::new().unwrap().value(),
+                suffix
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        FilePath::from_path_and_file(&test_directory(), &file).unwrap()
+    }
+
+    fn write_config_file(file_path: &FilePath, contents: &str) {
+        let mut file = FileBuilder::new(file_path)
+            .creation_mode(CreationMode::PurgeAndCreate)
+            .create()
+            .unwrap();
+        file.write(contents.as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn config_from_partial_file_uses_default_values_for_missing_entries() {
+        let file_path = generate_config_file_name("partial");
+        write_config_file(
+            &file_path,
+            "[defaults.publish-subscribe]\nmax-publishers = 123\n",
+        );
+
+        let sut = Config::from_file(&file_path);
+        File::remove(&file_path).unwrap();
+
+        let mut expected_config = Config::default();
+        expected_config.defaults.publish_subscribe.max_publishers = 123;
+        assert_that!(sut, eq Ok(expected_config));
+    }
+
+    #[test]
+    fn config_builder_later_layers_override_earlier_layers() {
+        let file_path = generate_config_file_name("layer");
+        write_config_file(
+            &file_path,
+            "[defaults.event]\nmax-listeners = 11\nmax-notifiers = 12\n",
+        );
+
+        let sut = ConfigBuilder::new()
+            .file(&file_path)
+            .override_with("defaults.event.max-notifiers = 13")
+            .create();
+        File::remove(&file_path).unwrap();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(sut.defaults.event.max_listeners, eq 11);
+        assert_that!(sut.defaults.event.max_notifiers, eq 13);
+    }
+
+    #[test]
+    fn config_builder_applies_included_files_before_including_file() {
+        let include_path = generate_config_file_name("included");
+        write_config_file(
+            &include_path,
+            "[defaults.event]\nmax-listeners = 21\nmax-notifiers = 22\n",
+        );
+
+        let file_path = generate_config_file_name("including");
+        write_config_file(
+            &file_path,
+            &format!(
+                "include = ['{}']\n[defaults.event]\nmax-notifiers = 23\n",
+                include_path.file_name()
+            ),
+        );
+
+        let sut = Config::from_file(&file_path);
+        File::remove(&file_path).unwrap();
+        File::remove(&include_path).unwrap();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(sut.defaults.event.max_listeners, eq 21);
+        assert_that!(sut.defaults.event.max_notifiers, eq 23);
+    }
+
+    #[test]
+    fn config_builder_fails_when_files_include_each_other() {
+        let file_path = generate_config_file_name("recursive");
+        write_config_file(
+            &file_path,
+            &format!("include = ['{}']\n", file_path.file_name()),
+        );
+
+        let sut = Config::from_file(&file_path);
+        File::remove(&file_path).unwrap();
+
+        assert_that!(sut, eq Err(ConfigCreationError::MaxIncludeDepthExceeded));
+    }
+
+    #[test]
+    fn config_builder_fails_with_unknown_entry() {
+        let sut = ConfigBuilder::new()
+            .override_with("defaults.event.max-listenerz = 4")
+            .create();
+
+        assert_that!(sut, eq Err(ConfigCreationError::UnknownConfigEntry));
+
+        let sut = ConfigBuilder::new()
+            .override_with("[global.nodes]\ndirectory = 'nodes'")
+            .create();
+
+        assert_that!(sut, eq Err(ConfigCreationError::UnknownConfigEntry));
+    }
+
+    #[test]
+    fn config_builder_accepts_optional_entries() {
+        let sut = ConfigBuilder::new()
+            .override_with("defaults.event.notifier-dead-event = 7")
+            .create();
+
+        assert_that!(sut, is_ok);
+        assert_that!(sut.unwrap().defaults.event.notifier_dead_event, eq Some(7));
+    }
+
+    #[test]
+    fn config_builder_accepts_access_control_list() {
+        let sut = ConfigBuilder::new()
+            .override_with(
+                "global.service.access-control-list = { group = 1000, permission = 0o060 }",
+            )
+            .create();
+
+        assert_that!(sut, is_ok);
+        let acl = sut.unwrap().global.service.access_control_list.unwrap();
+        assert_that!(acl.owner(), eq None);
+        assert_that!(acl.group(), eq Some(1000));
+        assert_that!(acl.permission(), eq Permission::GROUP_READ | Permission::GROUP_WRITE);
+    }
+
+    #[test]
+    fn separate_root_paths_fall_back_to_root_path() {
+        let mut sut = Config::default();
+
+        assert_that!(sut.global.static_storage_root_path(), eq sut.global.root_path());
+        assert_that!(sut.global.data_segment_root_path(), eq sut.global.root_path());
+        assert_that!(sut.global.monitoring_root_path(), eq sut.global.root_path());
+
+        let static_storage_root_path = Path::new(b"/tmp/static/").unwrap();
+        let data_segment_root_path = Path::new(b"/dev/hugepages/").unwrap();
+        sut.global
+            .set_static_storage_root_path(&static_storage_root_path);
+        sut.global
+            .set_data_segment_root_path(&data_segment_root_path);
+
+        assert_that!(*sut.global.static_storage_root_path(), eq static_storage_root_path);
+        assert_that!(*sut.global.data_segment_root_path(), eq data_segment_root_path);
+        assert_that!(sut.global.monitoring_root_path(), eq sut.global.root_path());
+
+        let mut service_dir = static_storage_root_path;
+        service_dir
+            .add_path_entry(&sut.global.service.directory)
+            .unwrap();
+        assert_that!(sut.global.service_dir(), eq service_dir);
+    }
+
+    #[test]
+    fn config_builder_accepts_separate_root_paths() {
+        let sut = ConfigBuilder::new()
+            .override_with("global.data-segment-root-path = '/dev/hugepages/'")
+            .override_with("global.monitoring-root-path = '/tmp/monitoring/'")
+            .create();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(*sut.global.data_segment_root_path(), eq Path::new(b"/dev/hugepages/").unwrap());
+        assert_that!(*sut.global.monitoring_root_path(), eq Path::new(b"/tmp/monitoring/").unwrap());
+        assert_that!(sut.global.static_storage_root_path(), eq sut.global.root_path());
+    }
+
+    #[test]
+    fn domain_derives_prefix_and_root_path() {
+        let mut sut = Config::default();
+        assert_that!(sut.global.domain(), eq None);
+
+        assert_that!(sut.set_domain("robot_arm"), is_ok);
+
+        let mut root_path = *Config::default().global.root_path();
+        root_path
+            .add_path_entry(&Path::new(b"robot_arm").unwrap())
+            .unwrap();
+        assert_that!(sut.global.domain(), eq Some(&FileName::new(b"robot_arm").unwrap()));
+        assert_that!(sut.global.prefix, eq FileName::new(b"iox2_robot_arm_").unwrap());
+        assert_that!(*sut.global.root_path(), eq root_path);
+
+        assert_that!(sut.set_domain("robot_arm"), is_ok);
+        assert_that!(*sut.global.root_path(), eq root_path);
+    }
+
+    #[test]
+    fn domain_overrides_all_root_paths() {
+        let mut sut = Config::default();
+        let custom_path = Path::new(b"/tmp/custom/").unwrap();
+        sut.global.set_static_storage_root_path(&custom_path);
+        sut.global.set_data_segment_root_path(&custom_path);
+        sut.global.set_monitoring_root_path(&custom_path);
+
+        assert_that!(sut.set_domain("robot_arm"), is_ok);
+
+        assert_that!(sut.global.static_storage_root_path(), eq sut.global.root_path());
+        assert_that!(sut.global.data_segment_root_path(), eq sut.global.root_path());
+        assert_that!(sut.global.monitoring_root_path(), eq sut.global.root_path());
+    }
+
+    #[test]
+    fn different_domains_do_not_share_any_directory() {
+        let mut sut_a = Config::default();
+        let mut sut_b = Config::default();
+        let custom_path = Path::new(b"/tmp/custom/").unwrap();
+        for sut in [&mut sut_a, &mut sut_b] {
+            sut.global.set_static_storage_root_path(&custom_path);
+            sut.global.set_data_segment_root_path(&custom_path);
+            sut.global.set_monitoring_root_path(&custom_path);
+        }
+
+        assert_that!(sut_a.set_domain("robot_arm"), is_ok);
+        assert_that!(sut_b.set_domain("robot_leg"), is_ok);
+
+        assert_that!(sut_a.global.prefix, ne sut_b.global.prefix);
+        assert_that!(sut_a.global.root_path(), ne sut_b.global.root_path());
+        assert_that!(sut_a.global.service_dir(), ne sut_b.global.service_dir());
+        assert_that!(sut_a.global.node_dir(), ne sut_b.global.node_dir());
+        assert_that!(sut_a.global.node_monitoring_dir(), ne sut_b.global.node_monitoring_dir());
+        assert_that!(
+            sut_a.global.data_segment_root_path(),
+            ne sut_b.global.data_segment_root_path()
+        );
+    }
+
+    #[test]
+    fn domain_with_invalid_name_is_rejected() {
+        let mut sut = Config::default();
+
+        assert_that!(sut.set_domain("robot/arm"), eq Err(ConfigDomainError::InvalidDomainName));
+        assert_that!(sut.set_domain(""), eq Err(ConfigDomainError::InvalidDomainName));
+        assert_that!(sut, eq Config::default());
+    }
+
+    #[test]
+    fn config_builder_applies_domain() {
+        let mut expected_config = Config::default();
+        expected_config.set_domain("robot_arm").unwrap();
+
+        let sut = ConfigBuilder::new()
+            .override_with("global.prefix = 'ignored_'")
+            .override_with("global.domain = 'robot_arm'")
+            .create();
+
+        assert_that!(sut, eq Ok(expected_config.clone()));
+
+        let sut = ConfigBuilder::new()
+            .override_with(&expected_config.to_toml_string().unwrap())
+            .create();
+        assert_that!(sut, eq Ok(expected_config));
+    }
+
+    #[test]
+    fn config_builder_fails_with_invalid_domain() {
+        let sut = ConfigBuilder::new()
+            .override_with("global.domain = 'robot/arm'")
+            .create();
+
+        assert_that!(sut.err(), eq Some(ConfigCreationError::UnableToDeserializeContents));
+    }
+
+    #[test]
+    fn config_builder_fails_with_invalid_value() {
+        let sut = ConfigBuilder::new()
+            .override_with("defaults.event.max-listeners = 'many'")
+            .create();
+
+        assert_that!(sut, eq Err(ConfigCreationError::UnableToDeserializeContents));
+    }
+
+    #[test]
+    fn config_builder_fails_with_non_existing_file() {
+        let sut = ConfigBuilder::new()
+            .file(&generate_config_file_name("does_not_exist"))
+            .create();
+
+        assert_that!(sut, eq Err(ConfigCreationError::ConfigFileDoesNotExist));
+    }
+
+    #[test]
+    fn config_watcher_applies_reloadable_changes_and_notifies_callbacks() {
+        let file_path = generate_config_file_name("watch");
+        write_config_file(&file_path, "[defaults.event]\nmax-listeners = 4\n");
+
+        let number_of_notifications = Arc::new(IoxAtomicUsize::new(0));
+        let max_listeners = Arc::new(IoxAtomicUsize::new(0));
+        let number_of_notifications_cb = number_of_notifications.clone();
+        let max_listeners_cb = max_listeners.clone();
+        let sut = Config::watch(&file_path, move |config| {
+            number_of_notifications_cb.fetch_add(1, Ordering::Relaxed);
+            max_listeners_cb.store(config.defaults.event.max_listeners, Ordering::Relaxed);
+        });
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        sut.set_monitoring_interval(None);
+        assert_that!(sut.config().defaults.event.max_listeners, eq 4);
+        assert_that!(*sut.config_file(), eq file_path);
+
+        assert_that!(sut.update(), eq Ok(false));
+        assert_that!(number_of_notifications.load(Ordering::Relaxed), eq 0);
+
+        write_config_file(
+            &file_path,
+            "[defaults.event]\nmax-listeners = 5\n[global.service]\ncreation-timeout = { secs = 2, nanos = 0 }\n",
+        );
+        let result = sut.update();
+        File::remove(&file_path).unwrap();
+
+        assert_that!(result, eq Ok(true));
+        assert_that!(number_of_notifications.load(Ordering::Relaxed), eq 1);
+        assert_that!(max_listeners.load(Ordering::Relaxed), eq 5);
+        assert_that!(sut.config().defaults.event.max_listeners, eq 5);
+        assert_that!(sut.config().global.service.creation_timeout, eq Duration::from_secs(2));
+    }
+
+    #[test]
+    fn config_watcher_notifies_all_registered_callbacks() {
+        let file_path = generate_config_file_name("watch_register");
+        write_config_file(&file_path, "[defaults.event]\nmax-notifiers = 4\n");
+
+        let number_of_notifications = Arc::new(IoxAtomicUsize::new(0));
+        let number_of_notifications_cb = number_of_notifications.clone();
+        let sut = Config::watch(&file_path, move |_| {
+            number_of_notifications_cb.fetch_add(1, Ordering::Relaxed);
+        })
+        .unwrap();
+        sut.set_monitoring_interval(None);
+        let number_of_notifications_cb = number_of_notifications.clone();
+        sut.register(move |_| {
+            number_of_notifications_cb.fetch_add(1, Ordering::Relaxed);
+        });
+
+        write_config_file(&file_path, "[defaults.event]\nmax-notifiers = 5\n");
+        let result = sut.update();
+        File::remove(&file_path).unwrap();
+
+        assert_that!(result, eq Ok(true));
+        assert_that!(number_of_notifications.load(Ordering::Relaxed), eq 2);
+    }
+
+    #[test]
+    fn config_watcher_monitors_the_file_in_the_background() {
+        const TIMEOUT: Duration = Duration::from_secs(10);
+        let file_path = generate_config_file_name("watch_monitor");
+        write_config_file(&file_path, "[defaults.event]\nmax-listeners = 4\n");
+
+        let max_listeners = Arc::new(IoxAtomicUsize::new(0));
+        let max_listeners_cb = max_listeners.clone();
+        let sut = Config::watch(&file_path, move |config| {
+            max_listeners_cb.store(config.defaults.event.max_listeners, Ordering::Relaxed);
+        })
+        .unwrap();
+        sut.set_monitoring_interval(Some(Duration::from_millis(10)));
+
+        write_config_file(&file_path, "[defaults.event]\nmax-listeners = 5\n");
+
+        let start = Instant::now();
+        while max_listeners.load(Ordering::Relaxed) != 5 && start.elapsed() < TIMEOUT {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        drop(sut);
+        File::remove(&file_path).unwrap();
+
+        assert_that!(max_listeners.load(Ordering::Relaxed), eq 5);
+    }
+
+    #[test]
+    fn config_watcher_reloads_the_log_level_filter() {
+        let file_path = generate_config_file_name("watch_log_level");
+        const ORIGIN: &str = "ConfigWatcherLogLevelTest";
+        write_config_file(
+            &file_path,
+            &format!("[global]\nlog-level-filter = '{ORIGIN}=warn'\n"),
+        );
+
+        let sut = Config::watch(&file_path, |_| {}).unwrap();
+        sut.set_monitoring_interval(None);
+
+        write_config_file(
+            &file_path,
+            &format!("[global]\nlog-level-filter = '{ORIGIN}=error'\n"),
+        );
+        let result = sut.update();
+        File::remove(&file_path).unwrap();
+        let log_level = get_log_level_for(ORIGIN);
+        remove_log_level_for(ORIGIN);
+
+        assert_that!(result, eq Ok(true));
+        assert_that!(log_level, eq LogLevel::Error as u8);
+    }
+
+    #[test]
+    fn config_watcher_rejects_non_reloadable_changes() {
+        let file_path = generate_config_file_name("watch_reject");
+        write_config_file(&file_path, "[defaults.event]\nmax-listeners = 4\n");
+
+        let number_of_notifications = Arc::new(IoxAtomicUsize::new(0));
+        let number_of_notifications_cb = number_of_notifications.clone();
+        let sut = Config::watch(&file_path, move |_| {
+            number_of_notifications_cb.fetch_add(1, Ordering::Relaxed);
+        })
+        .unwrap();
+        sut.set_monitoring_interval(None);
+
+        write_config_file(
+            &file_path,
+            "[defaults.event]\nmax-listeners = 5\n[global]\nprefix = 'other_'\n",
+        );
+        let result = sut.update();
+        File::remove(&file_path).unwrap();
+
+        assert_that!(result, eq Err(ConfigReloadError::NonReloadableEntryChanged));
+        assert_that!(number_of_notifications.load(Ordering::Relaxed), eq 0);
+        assert_that!(sut.config().defaults.event.max_listeners, eq 4);
+        assert_that!(sut.config().global.prefix, eq Config::default().global.prefix);
+    }
+
+    #[test]
+    fn config_watcher_keeps_config_when_file_is_invalid() {
+        let file_path = generate_config_file_name("watch_invalid");
+        write_config_file(&file_path, "[defaults.event]\nmax-listeners = 4\n");
+
+        let sut = Config::watch(&file_path, |_| {}).unwrap();
+        sut.set_monitoring_interval(None);
+
+        write_config_file(&file_path, "[defaults.event]\nmax-listeners = 'many'\n");
+        let result = sut.update();
+        File::remove(&file_path).unwrap();
+
+        assert_that!(
+            result,
+            eq Err(ConfigReloadError::ConfigCreationError(
+                ConfigCreationError::UnableToDeserializeContents
+            ))
+        );
+        assert_that!(sut.config().defaults.event.max_listeners, eq 4);
+
+        assert_that!(
+            sut.update(),
+            eq Err(ConfigReloadError::ConfigCreationError(
+                ConfigCreationError::ConfigFileDoesNotExist
+            ))
+        );
+    }
+
+    #[test]
+    fn config_watch_fails_with_non_existing_file() {
+        let sut = Config::watch(&generate_config_file_name("watch_does_not_exist"), |_| {});
+
+        assert_that!(
+            sut.err(),
+            eq Some(ConfigWatchError::ConfigCreationError(
+                ConfigCreationError::ConfigFileDoesNotExist
+            ))
+        );
+    }
+
+    // all environment variables are set in one test since they are shared by all tests that
+    // run concurrently in this process
+    #[test]
+    fn config_builder_applies_environment_variables() {
+        std::env::set_var("IOX2_DEFAULTS_REQUEST_RESPONSE_MAX_CLIENTS", "11");
+        std::env::set_var(
+            "IOX2_DEFAULTS_REQUEST_RESPONSE_ENABLE_SAFE_OVERFLOW_FOR_REQUESTS",
+            "false",
+        );
+        std::env::set_var(
+            "IOX2_DEFAULTS_REQUEST_RESPONSE_CLIENT_UNABLE_TO_DELIVER_STRATEGY",
+            "DiscardSample",
+        );
+        std::env::set_var("IOX2_GLOBAL_PREFIX", "123_");
+        std::env::set_var("IOX2_GLOBAL_SERVICE_CREATION_TIMEOUT_SECS", "3");
+
+        let sut = ConfigBuilder::new()
+            .environment_variables()
+            .override_with("defaults.request-response.max-clients = 12")
+            .create();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(sut.defaults.request_response.max_clients, eq 12);
+        assert_that!(sut.defaults.request_response.enable_safe_overflow_for_requests, eq false);
+        assert_that!(
+            sut.defaults.request_response.client_unable_to_deliver_strategy,
+            eq UnableToDeliverStrategy::DiscardSample
+        );
+        assert_that!(sut.global.prefix, eq FileName::new(b"123_").unwrap());
+        assert_that!(sut.global.service.creation_timeout, eq core::time::Duration::from_millis(3500));
+
+        let sut = ConfigBuilder::new()
+            .override_with("defaults.request-response.max-clients = 12")
+            .environment_variables()
+            .create();
+
+        assert_that!(sut, is_ok);
+        assert_that!(sut.unwrap().defaults.request_response.max_clients, eq 11);
+
+        std::env::set_var("IOX2_DEFAULTS_REQUEST_RESPONSE_MAX_CLIENTS", "many");
+        let sut = ConfigBuilder::new().environment_variables().create();
+
+        std::env::remove_var("IOX2_DEFAULTS_REQUEST_RESPONSE_MAX_CLIENTS");
+        std::env::remove_var("IOX2_DEFAULTS_REQUEST_RESPONSE_ENABLE_SAFE_OVERFLOW_FOR_REQUESTS");
+        std::env::remove_var("IOX2_DEFAULTS_REQUEST_RESPONSE_CLIENT_UNABLE_TO_DELIVER_STRATEGY");
+        std::env::remove_var("IOX2_GLOBAL_PREFIX");
+        std::env::remove_var("IOX2_GLOBAL_SERVICE_CREATION_TIMEOUT_SECS");
+
+        assert_that!(sut, eq Err(ConfigCreationError::UnableToDeserializeContents));
+    }
+
+    #[test]
+    fn config_validation_of_default_config_has_no_errors() {
+        create_test_directory();
+        let mut sut = Config::default();
+        sut.global.set_root_path(&test_directory());
+
+        let report = sut.validate();
+
+        assert_that!(report.has_errors(), eq false);
+        assert_that!(report.issues(), len 0);
+    }
+
+    #[test]
+    fn config_validation_reports_inconsistent_entries() {
+        create_test_directory();
+        let mut sut = Config::default();
+        sut.global.set_root_path(&test_directory());
+        sut.defaults.publish_subscribe.max_publishers = 0;
+        sut.defaults.publish_subscribe.enable_safe_overflow = false;
+        sut.defaults.publish_subscribe.subscriber_max_buffer_size = 2;
+        sut.defaults.publish_subscribe.publisher_history_size = 3;
+        sut.defaults.event.event_id_max_value = 10;
+        sut.defaults.event.notifier_dead_event = Some(11);
+        sut.global.service.creation_timeout = core::time::Duration::ZERO;
+
+        let report = sut.validate();
+
+        assert_that!(report.has_errors(), eq true);
+        let errors = report.errors().map(|e| e.entry()).collect::<Vec<_>>();
+        assert_that!(errors, len 3);
+        assert_that!(errors, contains "defaults.publish-subscribe.max-publishers");
+        assert_that!(errors, contains "defaults.publish-subscribe.publisher-history-size");
+        assert_that!(errors, contains "defaults.event.notifier-dead-event");
+
+        let warnings = report.warnings().collect::<Vec<_>>();
+        assert_that!(warnings, len 1);
+        assert_that!(warnings[0].entry(), eq "global.service.creation-timeout");
+        assert_that!(warnings[0].severity(), eq ConfigValidationSeverity::Warning);
+    }
+
+    #[test]
+    fn config_validation_warns_about_non_existing_root_path() {
+        let mut sut = Config::default();
+        let mut root_path = test_directory();
+        root_path
+            .add_path_entry(
+                &Path::new(
+                    format!(
+                        "config_tests_{}_does_not_exist",
+                        UniqueSystemId::new().unwrap().value()
+                    )
+                    .as_bytes(),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        sut.global.set_root_path(&root_path);
+
+        let report = sut.validate();
+
+        assert_that!(report.has_errors(), eq false);
+        let warnings = report.warnings().collect::<Vec<_>>();
+        assert_that!(warnings, len 1);
+        assert_that!(warnings[0].entry(), eq "global.root-path");
+    }
+
+    #[test]
+    fn config_builder_dry_run_validates_the_resulting_config() {
+        create_test_directory();
+        let sut = ConfigBuilder::new()
+            .override_with(&format!("global.root-path-unix = '{}'", test_directory()))
+            .override_with(&format!(
+                "global.root-path-windows = '{}'",
+                test_directory()
+            ))
+            .override_with("defaults.event.max-listeners = 0")
+            .dry_run();
+
+        assert_that!(sut, is_ok);
+        let report = sut.unwrap();
+        let errors = report.errors().collect::<Vec<_>>();
+        assert_that!(errors, len 1);
+        assert_that!(errors[0].entry(), eq "defaults.event.max-listeners");
+
+        let sut = ConfigBuilder::new()
+            .override_with("defaults.event.max-listeners = 'many'")
+            .dry_run();
+        assert_that!(sut.err(), eq Some(ConfigCreationError::UnableToDeserializeContents));
+    }
+
+    #[test]
+    fn config_serialized_to_toml_can_be_loaded_again() {
+        let mut config = Config::default();
+        config.defaults.event.max_listeners = 91;
+        config.defaults.event.notifier_dead_event = Some(3);
+        config.global.service.creation_timeout = core::time::Duration::from_millis(1234);
+
+        let sut = config.to_toml_string();
+        assert_that!(sut, is_ok);
+
+        let loaded = ConfigBuilder::new().override_with(&sut.unwrap()).create();
+        assert_that!(loaded, eq Ok(config));
+    }
+
+    #[test]
+    fn config_can_be_serialized_to_json() {
+        let mut config = Config::default();
+        config.defaults.publish_subscribe.max_subscribers = 77;
+
+        let sut = config.to_json_string();
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(sut.contains("\"max-subscribers\": 77"), eq true);
+        assert_that!(sut.contains("\"root-path-unix\""), eq true);
+    }
+
+    #[test]
+    fn config_diff_reports_deviations_from_defaults() {
+        let default_config = Config::default();
+        assert_that!(default_config.diff(&Config::default()), is_empty);
+
+        let mut sut = Config::default();
+        sut.defaults.event.max_listeners = 91;
+        sut.defaults.event.notifier_dead_event = Some(3);
+        sut.global.prefix = FileName::new(b"diff_").unwrap();
+
+        let differences = sut.diff(&default_config);
+        let entries = differences.iter().map(|d| d.entry()).collect::<Vec<_>>();
+        assert_that!(entries, eq vec!["defaults.event.max-listeners", "defaults.event.notifier-dead-event", "global.prefix"]);
+
+        assert_that!(differences[0].value(), eq Some("91"));
+        let default_max_listeners = default_config.defaults.event.max_listeners.to_string();
+        assert_that!(differences[0].other_value(), eq Some(default_max_listeners.as_str()));
+        assert_that!(differences[1].value(), eq Some("3"));
+        assert_that!(differences[1].other_value(), eq None);
+        assert_that!(differences[2].value(), eq Some("\"diff_\""));
+    }
+
+    #[test]
+    fn config_serialization_error_display_works() {
+        assert_that!(
+            format!("{}", ConfigSerializationError::UnableToSerializeContents), eq "ConfigSerializationError::UnableToSerializeContents");
+    }
+
+    #[test]
+    fn config_domain_error_display_works() {
+        assert_that!(
+            format!("{}", ConfigDomainError::InvalidDomainName), eq "ConfigDomainError::InvalidDomainName");
+    }
+
+    #[cfg(feature = "static_storage_encryption")]
+    fn encryption_key() -> [u8; 32] {
+        [13; 32]
+    }
+
+    #[cfg(feature = "static_storage_encryption")]
+    fn other_encryption_key() -> [u8; 32] {
+        [14; 32]
+    }
+
+    #[cfg(feature = "static_storage_encryption")]
+    #[test]
+    fn static_storage_encryption_key_provider_can_be_set() {
+        let mut sut = Config::default();
+        assert_that!(sut.global.static_storage_encryption_key_provider(), is_none);
+
+        sut.global
+            .set_static_storage_encryption_key_provider(Some(encryption_key));
+        assert_that!(sut.global.static_storage_encryption_key_provider(), is_some);
+        assert_that!(sut, ne Config::default());
+
+        sut.global.set_static_storage_encryption_key_provider(None);
+        assert_that!(sut, eq Config::default());
+    }
+
+    #[cfg(feature = "static_storage_encryption")]
+    #[test]
+    fn services_with_encrypted_static_storage_require_same_key() {
+        use iceoryx2::service::builder::event::EventOpenError;
+
+        let service_name = ServiceName::new(&format!(
+            "config_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap();
+        let mut config = iceoryx2::testing::generate_isolated_config();
+        config
+            .global
+            .set_static_storage_encryption_key_provider(Some(encryption_key));
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let _service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let sut = node.service_builder(&service_name).event().open();
+        assert_that!(sut, is_ok);
+
+        config
+            .global
+            .set_static_storage_encryption_key_provider(Some(other_encryption_key));
+        let other_node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let sut = other_node.service_builder(&service_name).event().open();
+        assert_that!(sut.err(), eq Some(EventOpenError::ServiceInCorruptedState));
+    }
 }
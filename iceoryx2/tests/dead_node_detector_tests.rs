@@ -0,0 +1,141 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod dead_node_detector {
+    use core::time::Duration;
+    use std::time::Instant;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    #[test]
+    fn dead_node_detector_does_not_watch_nodes_of_the_own_process<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _other_node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let mut sut = node.dead_node_detector();
+
+        let start = Instant::now();
+        let cleanup_state = sut.timed_wait(TIMEOUT).unwrap();
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(cleanup_state.cleanups, eq 0);
+        assert_that!(cleanup_state.failed_cleanups, eq 0);
+        assert_that!(sut.number_of_watched_nodes(), eq 0);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::static_memory::Service>)]
+    mod static_memory {}
+}
+
+// The termination of a node can only be awaited when it lives in another process, therefore
+// the test binary starts itself again and only runs the child test in the new process.
+#[cfg(feature = "pidfd_monitoring")]
+mod dead_node_detector_with_pidfd {
+    use core::time::Duration;
+    use std::io::Read;
+    use std::process::{Command, Stdio};
+    use std::time::Instant;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+
+    const CHILD_PREFIX_ENV: &str = "IOX2_DEAD_NODE_DETECTOR_TEST_PREFIX";
+
+    #[test]
+    fn dead_node_detector_child_process() {
+        let prefix = match std::env::var(CHILD_PREFIX_ENV) {
+            Ok(prefix) => prefix,
+            // only runs when started by the parent test
+            Err(_) => return,
+        };
+
+        let mut config = generate_isolated_config();
+        config.global.prefix = FileName::new(prefix.as_bytes()).unwrap();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+
+        // terminate without removing the node when the parent closes stdin
+        let mut buffer = vec![];
+        let _ = std::io::stdin().read_to_end(&mut buffer);
+        core::mem::forget(node);
+        std::process::exit(0);
+    }
+
+    #[test]
+    fn dead_node_detector_cleans_up_node_of_terminated_process_right_away() {
+        let _watchdog = Watchdog::new();
+        const WAIT_TIMEOUT: Duration = Duration::from_secs(5);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+
+        let mut child = Command::new(std::env::current_exe().unwrap())
+            .args([
+                "dead_node_detector_with_pidfd::dead_node_detector_child_process",
+                "--exact",
+                "--nocapture",
+            ])
+            .env(CHILD_PREFIX_ENV, config.global.prefix.to_string())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .spawn()
+            .unwrap();
+
+        let number_of_nodes = || {
+            let mut number_of_nodes = 0;
+            Node::<ipc::Service>::list(&config, |_| {
+                number_of_nodes += 1;
+                CallbackProgression::Continue
+            })
+            .unwrap();
+            number_of_nodes
+        };
+        while number_of_nodes() != 2 {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        let mut sut = node.dead_node_detector();
+        let cleanup_state = sut.timed_wait(Duration::ZERO).unwrap();
+        assert_that!(cleanup_state.cleanups, eq 0);
+        assert_that!(sut.number_of_watched_nodes(), eq 1);
+
+        drop(child.stdin.take());
+        let start = Instant::now();
+        let cleanup_state = sut.timed_wait(WAIT_TIMEOUT).unwrap();
+        assert_that!(start.elapsed(), lt WAIT_TIMEOUT);
+        assert_that!(cleanup_state.cleanups, eq 1);
+        assert_that!(cleanup_state.failed_cleanups, eq 0);
+        assert_that!(sut.number_of_watched_nodes(), eq 0);
+        assert_that!(number_of_nodes(), eq 1);
+
+        assert_that!(child.wait().unwrap().success(), eq true);
+    }
+}
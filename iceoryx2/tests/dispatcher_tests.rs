@@ -0,0 +1,178 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod dispatcher {
+    use core::time::Duration;
+    use std::time::Instant;
+
+    use iceoryx2::dispatcher::{DispatcherBuilder, DispatcherCreateError, DispatcherRunError};
+    use iceoryx2::port::event_id::EventId;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2::waitset::WaitSetRunResult;
+    use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::event::Event;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "dispatcher_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn create_with_zero_polling_interval_fails<S: Service>() {
+        let sut = DispatcherBuilder::new()
+            .polling_interval(Duration::ZERO)
+            .create::<S>();
+
+        assert_that!(sut.err(), eq Some(DispatcherCreateError::InvalidPollingInterval));
+    }
+
+    #[test]
+    fn run_on_empty_dispatcher_fails<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let mut sut = DispatcherBuilder::new().create::<S>().unwrap();
+
+        assert_that!(sut.number_of_subscribers(), eq 0);
+        assert_that!(sut.number_of_listeners(), eq 0);
+        assert_that!(sut.run_once().err(), eq Some(DispatcherRunError::NoAttachments));
+    }
+
+    #[test]
+    fn samples_are_dispatched_to_the_callback_of_their_subscriber<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service_1 = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let service_2 = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher_1 = service_1.publisher_builder().create().unwrap();
+        let publisher_2 = service_2.publisher_builder().create().unwrap();
+
+        let mut received_1 = vec![];
+        let mut received_2 = vec![];
+        {
+            let mut sut = DispatcherBuilder::new()
+                .polling_interval(TIMEOUT)
+                .create::<S>()
+                .unwrap();
+            sut.add_subscriber(service_1.subscriber_builder().create().unwrap(), |sample| {
+                received_1.push(*sample)
+            });
+            sut.add_subscriber(service_2.subscriber_builder().create().unwrap(), |sample| {
+                received_2.push(*sample)
+            });
+            assert_that!(sut.number_of_subscribers(), eq 2);
+
+            assert_that!(publisher_1.send_copy(12), is_ok);
+            assert_that!(publisher_1.send_copy(34), is_ok);
+            assert_that!(publisher_2.send_copy(56), is_ok);
+
+            assert_that!(sut.run_once(), eq Ok(WaitSetRunResult::AllEventsHandled));
+        }
+
+        assert_that!(received_1, eq vec![12, 34]);
+        assert_that!(received_2, eq vec![56]);
+    }
+
+    #[test]
+    fn events_are_dispatched_to_the_callback_of_their_listener<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service_1 = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let service_2 = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let notifier = service_2.notifier_builder().create().unwrap();
+
+        let mut received_1 = vec![];
+        let mut received_2 = vec![];
+        {
+            let mut sut = DispatcherBuilder::new().create::<S>().unwrap();
+            sut.add_listener(service_1.listener_builder().create().unwrap(), |id| {
+                received_1.push(id)
+            });
+            sut.add_listener(service_2.listener_builder().create().unwrap(), |id| {
+                received_2.push(id)
+            });
+            assert_that!(sut.number_of_listeners(), eq 2);
+
+            assert_that!(notifier.notify_with_custom_event_id(EventId::new(7)), is_ok);
+
+            assert_that!(sut.run_once(), eq Ok(WaitSetRunResult::AllEventsHandled));
+        }
+
+        assert_that!(received_1, len 0);
+        assert_that!(received_2, eq vec![EventId::new(7)]);
+    }
+
+    #[test]
+    fn run_once_with_timeout_returns_when_nothing_was_received<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let mut counter = 0;
+        {
+            let mut sut = DispatcherBuilder::new().create::<S>().unwrap();
+            sut.add_listener(service.listener_builder().create().unwrap(), |_| {
+                counter += 1
+            });
+
+            let start = Instant::now();
+            assert_that!(sut.run_once_with_timeout(TIMEOUT), eq Ok(WaitSetRunResult::AllEventsHandled));
+            assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        }
+
+        assert_that!(counter, eq 0);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -0,0 +1,223 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod event_loop {
+    use core::cell::RefCell;
+    use core::time::Duration;
+    use std::time::Instant;
+
+    use iceoryx2::event_loop::EventLoop;
+    use iceoryx2::port::listener::Listener;
+    use iceoryx2::port::notifier::Notifier;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2::waitset::WaitSetRunResult;
+    use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::event::Event;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "event_loop_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn create_event<S: Service>(node: &Node<S>) -> (Listener<S>, Notifier<S>) {
+        let service = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        (
+            service.listener_builder().create().unwrap(),
+            service.notifier_builder().create().unwrap(),
+        )
+    }
+
+    #[test]
+    fn callbacks_are_called_in_order_of_priority<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let (listener_1, notifier_1) = create_event(&node);
+        let (listener_2, notifier_2) = create_event(&node);
+        let (listener_3, notifier_3) = create_event(&node);
+        let order = RefCell::new(vec![]);
+
+        let waitset = WaitSetBuilder::new().create::<S>().unwrap();
+        let mut sut = EventLoop::new(&waitset);
+        sut.bind_with_priority(
+            waitset.attach_notification(&listener_1).unwrap(),
+            1,
+            |_, _| {
+                order.borrow_mut().push(1);
+                CallbackProgression::Continue
+            },
+        );
+        sut.bind_with_priority(
+            waitset.attach_notification(&listener_2).unwrap(),
+            5,
+            |_, _| {
+                order.borrow_mut().push(2);
+                CallbackProgression::Continue
+            },
+        );
+        sut.bind_with_priority(
+            waitset.attach_notification(&listener_3).unwrap(),
+            3,
+            |_, _| {
+                order.borrow_mut().push(3);
+                CallbackProgression::Continue
+            },
+        );
+        assert_that!(sut.number_of_bindings(), eq 3);
+
+        assert_that!(notifier_1.notify(), is_ok);
+        assert_that!(notifier_2.notify(), is_ok);
+        assert_that!(notifier_3.notify(), is_ok);
+
+        assert_that!(sut.run_once(), eq Ok(WaitSetRunResult::AllEventsHandled));
+        assert_that!(*order.borrow(), eq vec![2, 3, 1]);
+    }
+
+    #[test]
+    fn callbacks_with_same_priority_are_called_in_bind_order<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let (listener_1, notifier_1) = create_event(&node);
+        let (listener_2, notifier_2) = create_event(&node);
+        let order = RefCell::new(vec![]);
+
+        let waitset = WaitSetBuilder::new().create::<S>().unwrap();
+        let mut sut = EventLoop::new(&waitset);
+        sut.bind(waitset.attach_notification(&listener_2).unwrap(), |_, _| {
+            order.borrow_mut().push(2);
+            CallbackProgression::Continue
+        });
+        sut.bind(waitset.attach_notification(&listener_1).unwrap(), |_, _| {
+            order.borrow_mut().push(1);
+            CallbackProgression::Continue
+        });
+
+        assert_that!(notifier_1.notify(), is_ok);
+        assert_that!(notifier_2.notify(), is_ok);
+
+        assert_that!(sut.run_once(), eq Ok(WaitSetRunResult::AllEventsHandled));
+        assert_that!(*order.borrow(), eq vec![2, 1]);
+    }
+
+    #[test]
+    fn stop_in_callback_skips_remaining_callbacks<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let (listener_1, notifier_1) = create_event(&node);
+        let (listener_2, notifier_2) = create_event(&node);
+        let order = RefCell::new(vec![]);
+
+        let waitset = WaitSetBuilder::new().create::<S>().unwrap();
+        let mut sut = EventLoop::new(&waitset);
+        sut.bind_with_priority(
+            waitset.attach_notification(&listener_1).unwrap(),
+            2,
+            |_, _| {
+                order.borrow_mut().push(1);
+                CallbackProgression::Stop
+            },
+        );
+        sut.bind(waitset.attach_notification(&listener_2).unwrap(), |_, _| {
+            order.borrow_mut().push(2);
+            CallbackProgression::Continue
+        });
+
+        assert_that!(notifier_1.notify(), is_ok);
+        assert_that!(notifier_2.notify(), is_ok);
+
+        assert_that!(sut.run(), eq Ok(WaitSetRunResult::StopRequest));
+        assert_that!(*order.borrow(), eq vec![1]);
+    }
+
+    #[test]
+    fn missed_deadline_is_reported_to_callback<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let (listener, _notifier) = create_event(&node);
+        let mut missed_deadline = false;
+
+        {
+            let waitset = WaitSetBuilder::new().create::<S>().unwrap();
+            let mut sut = EventLoop::new(&waitset);
+            sut.bind(
+                waitset.attach_deadline(&listener, TIMEOUT).unwrap(),
+                |guard, attachment_id| {
+                    missed_deadline = attachment_id.has_missed_deadline(guard);
+                    CallbackProgression::Continue
+                },
+            );
+
+            let start = Instant::now();
+            assert_that!(sut.run_once(), eq Ok(WaitSetRunResult::AllEventsHandled));
+            assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        }
+
+        assert_that!(missed_deadline, eq true);
+    }
+
+    #[test]
+    fn unbound_attachments_are_ignored<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let (listener_1, _notifier_1) = create_event(&node);
+        let (listener_2, notifier_2) = create_event(&node);
+        let mut counter = 0;
+
+        {
+            let waitset = WaitSetBuilder::new().create::<S>().unwrap();
+            let _unbound_guard = waitset.attach_notification(&listener_2).unwrap();
+            let mut sut = EventLoop::new(&waitset);
+            sut.bind(waitset.attach_notification(&listener_1).unwrap(), |_, _| {
+                counter += 1;
+                CallbackProgression::Continue
+            });
+
+            assert_that!(notifier_2.notify(), is_ok);
+            assert_that!(sut.run_once(), eq Ok(WaitSetRunResult::AllEventsHandled));
+        }
+
+        assert_that!(counter, eq 0);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -0,0 +1,176 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(feature = "async")]
+#[generic_tests::define]
+mod future {
+    use core::future::Future;
+    use core::task::{Context, Poll, Waker};
+    use core::time::Duration;
+    use std::sync::{Arc, Barrier};
+    use std::task::Wake;
+    use std::thread::{self, Thread};
+
+    use iceoryx2::future::{ImmediateWakeUp, WaitSetReactor};
+    use iceoryx2::port::event_id::EventId;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = core::pin::pin!(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut context = Context::from_waker(&waker);
+
+        loop {
+            match future.as_mut().poll(&mut context) {
+                Poll::Ready(v) => return v,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "future_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn receive_async_is_ready_when_sample_is_available<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(8127), is_ok);
+
+        let sample = block_on(subscriber.receive_async()).unwrap();
+        assert_that!(*sample, eq 8127);
+    }
+
+    #[test]
+    fn receive_async_is_woken_up_when_sample_arrives_later<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let barrier = Barrier::new(2);
+        let reactor = WaitSetReactor::new(Duration::from_millis(1)).unwrap();
+
+        thread::scope(|s| {
+            s.spawn(|| {
+                let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+                let service = node
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .open_or_create()
+                    .unwrap();
+                let subscriber = service.subscriber_builder().create().unwrap();
+                barrier.wait();
+
+                let sample = block_on(subscriber.receive_async_with(&reactor)).unwrap();
+                assert_that!(*sample, eq 42);
+            });
+
+            let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+            let service = node
+                .service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .open_or_create()
+                .unwrap();
+            let publisher = service.publisher_builder().create().unwrap();
+            barrier.wait();
+
+            thread::sleep(TIMEOUT);
+            assert_that!(publisher.send_copy(42), is_ok);
+        });
+    }
+
+    #[test]
+    fn wait_async_is_woken_up_when_event_arrives_later<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let barrier = Barrier::new(2);
+
+        thread::scope(|s| {
+            s.spawn(|| {
+                let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+                let service = node
+                    .service_builder(&service_name)
+                    .event()
+                    .open_or_create()
+                    .unwrap();
+                let listener = service.listener_builder().create().unwrap();
+                barrier.wait();
+
+                let event_id = block_on(listener.wait_async_with(&ImmediateWakeUp)).unwrap();
+                assert_that!(event_id, eq EventId::new(3));
+            });
+
+            let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+            let service = node
+                .service_builder(&service_name)
+                .event()
+                .open_or_create()
+                .unwrap();
+            let notifier = service.notifier_builder().create().unwrap();
+            barrier.wait();
+
+            thread::sleep(TIMEOUT);
+            assert_that!(notifier.notify_with_custom_event_id(EventId::new(3)), is_ok);
+        });
+    }
+
+    #[test]
+    fn wait_async_with_default_reactor_receives_event<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener = service.listener_builder().create().unwrap();
+        let notifier = service.notifier_builder().create().unwrap();
+
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(5)), is_ok);
+
+        let event_id = block_on(listener.wait_async()).unwrap();
+        assert_that!(event_id, eq EventId::new(5));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::static_memory::Service>)]
+    mod static_memory {}
+}
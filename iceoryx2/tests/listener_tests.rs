@@ -12,12 +12,28 @@
 
 #[generic_tests::define]
 mod listener {
+    use core::time::Duration;
     use std::collections::HashSet;
+    use std::sync::Barrier;
 
+    use iceoryx2::port::event_id::EventId;
+    use iceoryx2::port::wait_strategy::WaitStrategy;
     use iceoryx2::testing::*;
     use iceoryx2::{node::NodeBuilder, port::listener::ListenerCreateError, service::Service};
     use iceoryx2_bb_testing::assert_that;
 
+    const TIMEOUT: Duration = Duration::from_millis(50);
+    const WAIT_STRATEGIES: [WaitStrategy; 4] = [
+        WaitStrategy::Block,
+        WaitStrategy::Spin,
+        WaitStrategy::SpinThenYield {
+            spin_repetitions: 100,
+        },
+        WaitStrategy::SpinThenBlock {
+            spin_repetitions: 100,
+        },
+    ];
+
     #[test]
     fn create_error_display_works<S: Service>() {
         assert_that!(
@@ -50,6 +66,86 @@ mod listener {
         }
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn raw_fd_is_the_file_descriptor_of_the_listener<Sut: Service>()
+    where
+        <Sut::Event as iceoryx2_cal::event::Event>::Listener:
+            iceoryx2_bb_posix::file_descriptor::FileDescriptorBased,
+    {
+        use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+        use std::os::fd::{AsFd, AsRawFd};
+
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+
+        let fd = unsafe { listener.file_descriptor().native_handle() };
+        assert_that!(listener.as_raw_fd(), eq fd);
+        assert_that!(listener.as_fd().as_raw_fd(), eq fd);
+    }
+
+    #[test]
+    fn blocking_wait_wakes_up_on_notification_with_every_wait_strategy<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let barrier = Barrier::new(2);
+
+        for wait_strategy in WAIT_STRATEGIES {
+            let sut = service
+                .listener_builder()
+                .wait_strategy(wait_strategy)
+                .create()
+                .unwrap();
+
+            std::thread::scope(|s| {
+                s.spawn(|| {
+                    let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+                    let service = node.service_builder(&service_name).event().open().unwrap();
+                    let notifier = service.notifier_builder().create().unwrap();
+
+                    barrier.wait();
+                    std::thread::sleep(TIMEOUT);
+                    notifier
+                        .notify_with_custom_event_id(EventId::new(7))
+                        .unwrap();
+                    barrier.wait();
+                    std::thread::sleep(TIMEOUT);
+                    notifier
+                        .notify_with_custom_event_id(EventId::new(8))
+                        .unwrap();
+                });
+
+                barrier.wait();
+                let mut id = None;
+                while id.is_none() {
+                    id = sut.blocking_wait_one().unwrap();
+                }
+                assert_that!(id, eq Some(EventId::new(7)));
+
+                barrier.wait();
+                let mut ids = vec![];
+                while ids.is_empty() {
+                    sut.blocking_wait_all(|id| ids.push(id)).unwrap();
+                }
+                assert_that!(ids, eq vec![EventId::new(8)]);
+            });
+        }
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -15,6 +15,7 @@ mod node_death_tests {
     use core::sync::atomic::{AtomicU32, Ordering};
 
     use iceoryx2::config::Config;
+    use iceoryx2::node::crash_journal::CrashRecord;
     use iceoryx2::node::testing::__internal_node_staged_death;
     use iceoryx2::node::{CleanupState, NodeState};
     use iceoryx2::prelude::*;
@@ -36,6 +37,13 @@ mod node_death_tests {
         }
 
         fn create_test_node(config: &Config) -> TestDetails<Self::Service> {
+            Self::create_test_node_with_builder(config, NodeBuilder::new())
+        }
+
+        fn create_test_node_with_builder(
+            config: &Config,
+            builder: NodeBuilder,
+        ) -> TestDetails<Self::Service> {
             static COUNTER: AtomicU32 = AtomicU32::new(0);
             let node_name = Self::generate_node_name(0, "toby or no toby");
             let fake_node_id = ((u32::MAX - COUNTER.fetch_add(1, Ordering::Relaxed)) as u128) << 96;
@@ -43,7 +51,7 @@ mod node_death_tests {
                 unsafe { core::mem::transmute::<u128, UniqueSystemId>(fake_node_id) };
 
             let node = unsafe {
-                NodeBuilder::new()
+                builder
                     .name(&node_name)
                     .config(config)
                     .__internal_create_with_custom_node_id::<Self::Service>(fake_node_id)
@@ -188,6 +196,64 @@ mod node_death_tests {
         }
     }
 
+    #[test]
+    fn samples_of_dead_subscriber_are_reclaimed_by_publisher<S: Test>() {
+        const NUMBER_OF_SAMPLES: usize = 4;
+        const MAX_LOANED_SAMPLES: usize = 2;
+        let service_name = generate_service_name();
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .max_subscribers(1)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .subscriber_max_borrowed_samples(NUMBER_OF_SAMPLES)
+            .create()
+            .unwrap();
+        // all samples except one are single element samples, the size class with two elements
+        // is exhausted as soon as the dead subscriber holds its only sample
+        let publisher = service
+            .publisher_builder()
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
+            .initial_max_slice_len(2)
+            .size_class(1, 2 * NUMBER_OF_SAMPLES + MAX_LOANED_SAMPLES - 1)
+            .create()
+            .unwrap();
+
+        let mut bad_node = S::create_test_node(&config).node;
+        let bad_service = bad_node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .open()
+            .unwrap();
+        let subscriber = bad_service.subscriber_builder().create().unwrap();
+
+        let sample = publisher.loan_slice(2).unwrap();
+        assert_that!(sample.send(), eq Ok(1));
+        core::mem::forget(subscriber.receive().unwrap().unwrap());
+        for _ in 0..NUMBER_OF_SAMPLES {
+            let sample = publisher.loan_slice(1).unwrap();
+            assert_that!(sample.send(), eq Ok(1));
+        }
+
+        S::staged_death(&mut bad_node);
+        core::mem::forget(subscriber);
+        core::mem::forget(bad_service);
+
+        assert_that!(publisher.loan_slice(2), is_ok);
+        assert_that!(service.dynamic_config().number_of_subscribers(), eq 0);
+        assert_that!(
+            publisher.number_of_reclaimed_abandoned_samples(),
+            eq(NUMBER_OF_SAMPLES + 1) as u64
+        );
+    }
+
     #[test]
     fn dead_node_is_removed_from_event_service<S: Test>() {
         let _watchdog = Watchdog::new();
@@ -463,6 +529,94 @@ mod node_death_tests {
         assert_that!(number_of_nodes(), eq 0);
     }
 
+    #[test]
+    fn crashed_node_with_crash_journal_is_cleaned_up<S: Test>() {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let mut crashed_node =
+            S::create_test_node_with_builder(&config, NodeBuilder::new().crash_journal(true)).node;
+        let crashed_node_id = *crashed_node.id();
+        let mut dead_node_without_journal = S::create_test_node(&config).node;
+        let _alive_node = NodeBuilder::new()
+            .config(&config)
+            .crash_journal(true)
+            .create::<S::Service>()
+            .unwrap();
+
+        S::staged_death(&mut crashed_node);
+        core::mem::forget(crashed_node);
+        S::staged_death(&mut dead_node_without_journal);
+        core::mem::forget(dead_node_without_journal);
+
+        assert_that!(Node::<S::Service>::cleanup_crashed_nodes(&config), eq CleanupState { cleanups: 1, failed_cleanups: 0 });
+
+        let mut node_states = vec![];
+        Node::<S::Service>::list(&config, |node_state| {
+            node_states.push((
+                *node_state.node_id(),
+                matches!(node_state, NodeState::Dead(_)),
+            ));
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(node_states, len 2);
+        assert_that!(node_states.iter().any(|(id, _)| *id == crashed_node_id), eq false);
+        assert_that!(node_states.iter().filter(|(_, is_dead)| *is_dead).count(), eq 1);
+
+        let mut records = vec![];
+        CrashRecord::list(&config, |record| {
+            records.push(*record.node_id());
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(records, len 1);
+        assert_that!(records.contains(&crashed_node_id), eq false);
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn dynamic_attributes_can_be_updated_after_node_died_during_update<S: Test>() {
+        use iceoryx2::service::attribute::DynamicAttributePermission;
+        use iceoryx2::testing::__internal_dynamic_attributes_staged_writer_death;
+
+        let _watchdog = Watchdog::new();
+        let service_name = generate_service_name();
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let mut bad_node = S::create_test_node(&config).node;
+        let bad_service = bad_node
+            .service_builder(&service_name)
+            .event()
+            .create_with_attributes(
+                &AttributeSpecifier::new()
+                    .define_dynamic("mode", "idle", DynamicAttributePermission::AllParticipants)
+                    .unwrap(),
+            )
+            .unwrap();
+
+        unsafe { __internal_dynamic_attributes_staged_writer_death(&bad_service) };
+        S::staged_death(&mut bad_node);
+        core::mem::forget(bad_service);
+        core::mem::forget(bad_node);
+
+        let good_node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let service = good_node
+            .service_builder(&service_name)
+            .event()
+            .open()
+            .unwrap();
+
+        // the writer lock of the dead node is taken over
+        assert_that!(service.update_dynamic_attribute("mode", "active"), is_ok);
+        let attributes = service.dynamic_attributes();
+        assert_that!(attributes.get_key_value_at("mode", 0), eq Some("active"));
+    }
+
     #[instantiate_tests(<ZeroCopy>)]
     mod ipc {}
 }
@@ -14,20 +14,37 @@
 mod node {
     use core::time::Duration;
     use std::collections::{HashSet, VecDeque};
-    use std::sync::Barrier;
+    use std::sync::{Barrier, Mutex};
 
     use iceoryx2::config::Config;
+    use iceoryx2::node::crash_journal::CrashRecord;
+    use iceoryx2::node::quota::{NodeQuota, NodeResourceUsage};
+    use iceoryx2::node::service_dependencies::ServiceDependenciesWaitError;
+    use iceoryx2::node::system_event::SystemEvent;
     use iceoryx2::node::{
-        NodeCleanupFailure, NodeCreationFailure, NodeId, NodeListFailure, NodeState, NodeView,
+        CleanupState, NodeCleanupFailure, NodeCreationFailure, NodeId, NodeListFailure, NodeState,
+        NodeView,
     };
+    use iceoryx2::port::listener::ListenerCreateError;
+    use iceoryx2::port::notifier::NotifierCreateError;
+    use iceoryx2::port::publisher::PublisherCreateError;
+    use iceoryx2::port::subscriber::SubscriberCreateError;
+    use iceoryx2::port::LoanError;
     use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::event::EventCreateError;
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
     use iceoryx2::service::Service;
     use iceoryx2::testing::*;
+    use iceoryx2_bb_log::fail::{Failure, FailureHook, FailureKind};
+    use iceoryx2_bb_log::logger::buffer;
+    use iceoryx2_bb_log::{debug, get_log_level_for, set_log_level, LogLevel};
     use iceoryx2_bb_posix::system_configuration::SystemInfo;
     use iceoryx2_bb_system_types::path::*;
     use iceoryx2_bb_testing::watchdog::Watchdog;
     use iceoryx2_bb_testing::{assert_that, test_fail};
 
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
     #[derive(Debug, Eq, PartialEq)]
     struct Details {
         name: NodeName,
@@ -153,6 +170,75 @@ mod node {
         assert_node_presence::<S>(&node_details, &config);
     }
 
+    #[test]
+    fn nodes_with_separate_root_paths_can_be_listed<S: Service>() {
+        const NUMBER_OF_NODES: usize = 4;
+        let mut config = generate_isolated_config();
+
+        let mut static_storage_root_path = *config.global.root_path();
+        static_storage_root_path
+            .add_path_entry(&Path::new(b"static_storage").unwrap())
+            .unwrap();
+        let mut monitoring_root_path = *config.global.root_path();
+        monitoring_root_path
+            .add_path_entry(&Path::new(b"monitoring").unwrap())
+            .unwrap();
+        std::fs::create_dir_all(static_storage_root_path.to_string()).unwrap();
+        std::fs::create_dir_all(monitoring_root_path.to_string()).unwrap();
+
+        config
+            .global
+            .set_static_storage_root_path(&static_storage_root_path);
+        config
+            .global
+            .set_monitoring_root_path(&monitoring_root_path);
+
+        let mut nodes = vec![];
+        let mut node_details = VecDeque::new();
+        for i in 0..NUMBER_OF_NODES {
+            let node_name = generate_node_name(i, "separated");
+            let node = NodeBuilder::new()
+                .config(&config)
+                .name(&node_name)
+                .create::<S>()
+                .unwrap();
+            node_details.push_back(Details::from_node(&node));
+            nodes.push(node);
+        }
+
+        assert_node_presence::<S>(&node_details, &config);
+    }
+
+    #[cfg(feature = "static_storage_encryption")]
+    fn encryption_key() -> [u8; 32] {
+        [7; 32]
+    }
+
+    #[cfg(feature = "static_storage_encryption")]
+    #[test]
+    fn nodes_with_encrypted_static_storage_can_be_listed<S: Service>() {
+        const NUMBER_OF_NODES: usize = 4;
+        let mut config = generate_isolated_config();
+        config
+            .global
+            .set_static_storage_encryption_key_provider(Some(encryption_key));
+
+        let mut nodes = vec![];
+        let mut node_details = VecDeque::new();
+        for i in 0..NUMBER_OF_NODES {
+            let node_name = generate_node_name(i, "encrypted");
+            let node = NodeBuilder::new()
+                .config(&config)
+                .name(&node_name)
+                .create::<S>()
+                .unwrap();
+            node_details.push_back(Details::from_node(&node));
+            nodes.push(node);
+        }
+
+        assert_node_presence::<S>(&node_details, &config);
+    }
+
     #[test]
     fn when_node_goes_out_of_scope_it_cleans_up<S: Service>() {
         const NUMBER_OF_NODES: usize = 16;
@@ -253,6 +339,30 @@ mod node {
             format!("{}", NodeCreationFailure::InsufficientPermissions), eq "NodeCreationFailure::InsufficientPermissions");
         assert_that!(
             format!("{}", NodeCreationFailure::InternalError), eq "NodeCreationFailure::InternalError");
+        assert_that!(
+            format!("{}", NodeCreationFailure::InvalidSecurityDescriptor), eq "NodeCreationFailure::InvalidSecurityDescriptor");
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn node_creation_fails_with_invalid_security_descriptor<S: Service>() {
+        let mut config = generate_isolated_config();
+        config.global.windows_security_descriptor = Some("not a security descriptor".to_string());
+
+        let sut = NodeBuilder::new().config(&config).create::<S>();
+
+        assert_that!(sut.err(), eq Some(NodeCreationFailure::InvalidSecurityDescriptor));
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn security_descriptor_is_ignored_on_non_windows_platforms<S: Service>() {
+        let mut config = generate_isolated_config();
+        config.global.windows_security_descriptor = Some("D:P(A;;GA;;;SY)".to_string());
+
+        let sut = NodeBuilder::new().config(&config).create::<S>();
+
+        assert_that!(sut, is_ok);
     }
 
     #[test]
@@ -395,9 +505,635 @@ mod node {
         assert_that!(node.signal_handling_mode(), eq SignalHandlingMode::HandleTerminationRequests);
     }
 
+    #[test]
+    fn by_default_node_has_no_quota<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(*node.quota(), eq NodeQuota::new());
+        assert_that!(node.quota().ports_limit(), eq None);
+        assert_that!(node.quota().services_limit(), eq None);
+        assert_that!(node.quota().shared_memory_bytes_limit(), eq None);
+        assert_that!(node.resource_usage(), eq NodeResourceUsage::default());
+    }
+
+    #[test]
+    fn node_quota_limits_number_of_created_services<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .quota(NodeQuota::new().max_services(2))
+            .create::<S>()
+            .unwrap();
+
+        let sut_1 = node
+            .service_builder(&"quota_service_1".try_into().unwrap())
+            .event()
+            .create();
+        assert_that!(sut_1, is_ok);
+        let sut_2 = node
+            .service_builder(&"quota_service_2".try_into().unwrap())
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut_2, is_ok);
+        assert_that!(node.resource_usage().services(), eq 2);
+
+        let sut_3 = node
+            .service_builder(&"quota_service_3".try_into().unwrap())
+            .event()
+            .create();
+        assert_that!(sut_3.err().unwrap(), eq EventCreateError::ExceedsNodeQuota);
+        let sut_3 = node
+            .service_builder(&"quota_service_3".try_into().unwrap())
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut_3.err().unwrap(), eq PublishSubscribeCreateError::ExceedsNodeQuota);
+
+        drop(sut_1);
+        assert_that!(node.resource_usage().services(), eq 1);
+
+        let sut_3 = node
+            .service_builder(&"quota_service_3".try_into().unwrap())
+            .event()
+            .create();
+        assert_that!(sut_3, is_ok);
+    }
+
+    #[test]
+    fn node_quota_does_not_restrict_opening_services<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name: ServiceName = "quota_open_service".try_into().unwrap();
+        let creator = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .quota(NodeQuota::new().max_services(0))
+            .create::<S>()
+            .unwrap();
+
+        let _service = creator
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let sut = node.service_builder(&service_name).event().open();
+        assert_that!(sut, is_ok);
+        assert_that!(node.resource_usage().services(), eq 0);
+    }
+
+    #[test]
+    fn node_quota_limits_number_of_ports<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .quota(NodeQuota::new().max_ports(2))
+            .create::<S>()
+            .unwrap();
+
+        let event = node
+            .service_builder(&"quota_event".try_into().unwrap())
+            .event()
+            .create()
+            .unwrap();
+        let pubsub = node
+            .service_builder(&"quota_pubsub".try_into().unwrap())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let notifier = event.notifier_builder().create();
+        assert_that!(notifier, is_ok);
+        let listener = event.listener_builder().create();
+        assert_that!(listener, is_ok);
+        assert_that!(node.resource_usage().ports(), eq 2);
+
+        assert_that!(event.notifier_builder().create().err().unwrap(), eq NotifierCreateError::ExceedsNodeQuota);
+        assert_that!(event.listener_builder().create().err().unwrap(), eq ListenerCreateError::ExceedsNodeQuota);
+        assert_that!(pubsub.publisher_builder().create().err().unwrap(), eq PublisherCreateError::ExceedsNodeQuota);
+        assert_that!(pubsub.subscriber_builder().create().err().unwrap(), eq SubscriberCreateError::ExceedsNodeQuota);
+
+        drop(notifier);
+        drop(listener);
+        assert_that!(node.resource_usage().ports(), eq 0);
+
+        assert_that!(pubsub.publisher_builder().create(), is_ok);
+        assert_that!(pubsub.subscriber_builder().create(), is_ok);
+    }
+
+    #[test]
+    fn node_quota_limits_shared_memory_of_ports<S: Service>() {
+        const MAX_SHARED_MEMORY_BYTES: usize = 64 * 1024;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .quota(NodeQuota::new().max_shared_memory_bytes(MAX_SHARED_MEMORY_BYTES))
+            .create::<S>()
+            .unwrap();
+
+        let service = node
+            .service_builder(&"quota_shm".try_into().unwrap())
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(128)
+            .create();
+        assert_that!(publisher, is_ok);
+        let usage = node.resource_usage().shared_memory_bytes();
+        assert_that!(usage, gt 0);
+        assert_that!(usage, le MAX_SHARED_MEMORY_BYTES);
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(MAX_SHARED_MEMORY_BYTES)
+            .create();
+        assert_that!(sut.err().unwrap(), eq PublisherCreateError::ExceedsNodeQuota);
+        assert_that!(node.resource_usage().shared_memory_bytes(), eq usage);
+        assert_that!(node.resource_usage().ports(), eq 1);
+
+        drop(publisher);
+        assert_that!(node.resource_usage().shared_memory_bytes(), eq 0);
+    }
+
+    #[test]
+    fn node_quota_accounts_every_resize_of_dynamic_data_segments<S: Service>() {
+        const MAX_SHARED_MEMORY_BYTES: usize = 1024 * 1024;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .quota(NodeQuota::new().max_shared_memory_bytes(MAX_SHARED_MEMORY_BYTES))
+            .create::<S>()
+            .unwrap();
+
+        let service = node
+            .service_builder(&"quota_dynamic_shm".try_into().unwrap())
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(128)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+        let initial_usage = node.resource_usage().shared_memory_bytes();
+        assert_that!(initial_usage, gt 0);
+
+        let sample = publisher.loan_slice_uninit(2048);
+        assert_that!(sample, is_ok);
+        let usage = node.resource_usage().shared_memory_bytes();
+        assert_that!(usage, gt initial_usage);
+        assert_that!(usage, le MAX_SHARED_MEMORY_BYTES);
+        drop(sample);
+
+        let sut = publisher.loan_slice_uninit(MAX_SHARED_MEMORY_BYTES);
+        assert_that!(sut.err().unwrap(), eq LoanError::OutOfMemory);
+        assert_that!(node.resource_usage().shared_memory_bytes(), le MAX_SHARED_MEMORY_BYTES);
+        assert_that!(publisher.loan_slice_uninit(2048), is_ok);
+
+        drop(publisher);
+        assert_that!(node.resource_usage().shared_memory_bytes(), eq 0);
+    }
+
+    #[test]
+    fn system_event_is_broadcast_to_all_listeners<S: Service>() {
+        let config = generate_isolated_config();
+        let node_1 = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let node_2 = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let listener_1 = node_1.system_event_listener().unwrap();
+        let listener_2 = node_2.system_event_listener().unwrap();
+        let sut = node_2.system_event_notifier().unwrap();
+
+        assert_that!(sut.emergency_stop(), eq Ok(2));
+        assert_that!(sut.broadcast(SystemEvent::Shutdown), eq Ok(2));
+
+        for listener in [&listener_1, &listener_2] {
+            let mut received = HashSet::new();
+            listener
+                .timed_wait_all(
+                    |event| {
+                        received.insert(event);
+                    },
+                    TIMEOUT,
+                )
+                .unwrap();
+            assert_that!(received, len 2);
+            assert_that!(received.contains(&SystemEvent::EmergencyStop), eq true);
+            assert_that!(received.contains(&SystemEvent::Shutdown), eq true);
+        }
+    }
+
+    #[test]
+    fn system_event_is_not_received_in_other_domain<S: Service>() {
+        let node_1 = NodeBuilder::new()
+            .config(&generate_isolated_config())
+            .create::<S>()
+            .unwrap();
+        let node_2 = NodeBuilder::new()
+            .config(&generate_isolated_config())
+            .create::<S>()
+            .unwrap();
+
+        let listener = node_1.system_event_listener().unwrap();
+        let sut = node_2.system_event_notifier().unwrap();
+
+        assert_that!(sut.emergency_stop(), eq Ok(0));
+
+        let mut received = vec![];
+        listener.try_wait_all(|event| received.push(event)).unwrap();
+        assert_that!(received, is_empty);
+    }
+
+    #[test]
+    fn system_event_service_is_not_accounted_to_node_quota<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .quota(NodeQuota::new().max_services(0))
+            .create::<S>()
+            .unwrap();
+
+        let sut = node.system_event_listener();
+        assert_that!(sut, is_ok);
+        assert_that!(node.resource_usage().services(), eq 0);
+    }
+
+    #[test]
+    fn system_event_service_is_not_listed<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _listener = node.system_event_listener().unwrap();
+
+        let mut number_of_services = 0;
+        S::list(&config, |_| {
+            number_of_services += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(number_of_services, eq 0);
+    }
+
+    #[test]
+    fn service_dependencies_without_dependencies_are_available<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node.service_dependencies();
+        assert_that!(sut.number_of_dependencies(), eq 0);
+        assert_that!(sut.wait_until_available(Duration::ZERO), is_ok);
+    }
+
+    #[test]
+    fn service_dependencies_wait_times_out_when_service_is_missing<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service_name: ServiceName = "node_tests/missing_dependency".try_into().unwrap();
+
+        let sut = node.service_dependencies().require_event(&service_name);
+
+        let start = std::time::Instant::now();
+        assert_that!(sut.wait_until_available(TIMEOUT).err(), eq Some(ServiceDependenciesWaitError::Timeout));
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(sut.unavailable_services(), eq vec![service_name]);
+    }
+
+    #[test]
+    fn service_dependencies_with_incompatible_settings_are_unavailable<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service_name: ServiceName = "node_tests/incompatible_dependency".try_into().unwrap();
+        let _service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_dependencies()
+            .require(&service_name, |builder| {
+                builder.publish_subscribe::<u32>().open()
+            });
+
+        assert_that!(sut.wait_until_available(Duration::ZERO).err(), eq Some(ServiceDependenciesWaitError::Timeout));
+        assert_that!(sut.unavailable_services(), eq vec![service_name]);
+    }
+
+    #[test]
+    fn service_dependencies_are_available_when_created_while_waiting<S: Service>() {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let pubsub_name: ServiceName = "node_tests/pubsub_dependency".try_into().unwrap();
+        let event_name: ServiceName = "node_tests/event_dependency".try_into().unwrap();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+                barrier.wait();
+                std::thread::sleep(TIMEOUT);
+                let _pubsub = node
+                    .service_builder(&pubsub_name)
+                    .publish_subscribe::<u64>()
+                    .create()
+                    .unwrap();
+                let _event = node.service_builder(&event_name).event().create().unwrap();
+                barrier.wait();
+            });
+
+            let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+            let sut = node
+                .service_dependencies()
+                .require(&pubsub_name, |builder| {
+                    builder.publish_subscribe::<u64>().open()
+                })
+                .require_event(&event_name);
+            assert_that!(sut.number_of_dependencies(), eq 2);
+            assert_that!(sut.unavailable_services(), len 2);
+
+            barrier.wait();
+            let result = sut.wait_until_available(Duration::from_secs(10));
+            barrier.wait();
+            assert_that!(result, is_ok);
+        });
+    }
+
+    #[test]
+    fn service_dependencies_keep_waiting_when_unrelated_services_are_created<S: Service>() {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let required_name: ServiceName = "node_tests/required_dependency".try_into().unwrap();
+        let unrelated_name: ServiceName = "node_tests/unrelated_service".try_into().unwrap();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+                barrier.wait();
+                let _unrelated = node
+                    .service_builder(&unrelated_name)
+                    .event()
+                    .create()
+                    .unwrap();
+                barrier.wait();
+            });
+
+            let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+            let sut = node.service_dependencies().require_event(&required_name);
+
+            barrier.wait();
+            let start = std::time::Instant::now();
+            let result = sut.wait_until_available(TIMEOUT);
+            barrier.wait();
+            assert_that!(result.err(), eq Some(ServiceDependenciesWaitError::Timeout));
+            assert_that!(start.elapsed(), time_at_least TIMEOUT);
+            assert_that!(sut.unavailable_services(), eq vec![required_name.clone()]);
+        });
+    }
+
+    fn list_crash_records(config: &Config) -> Vec<CrashRecord> {
+        let mut records = vec![];
+        CrashRecord::list(config, |record| {
+            records.push(record);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        records
+    }
+
+    #[test]
+    fn node_without_crash_journal_has_no_crash_record<S: Service>() {
+        let config = generate_isolated_config();
+        let _sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(list_crash_records(&config), len 0);
+    }
+
+    #[test]
+    fn crash_journal_of_node_exists_until_node_goes_out_of_scope<S: Service>() {
+        let config = generate_isolated_config();
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .crash_journal(true)
+            .create::<S>()
+            .unwrap();
+
+        let records = list_crash_records(&config);
+        assert_that!(records, len 1);
+        assert_that!(*records[0].node_id(), eq * sut.id());
+        assert_that!(records[0].process_id(), eq sut.id().pid());
+        assert_that!(records[0].signal(), eq None);
+
+        drop(sut);
+
+        assert_that!(list_crash_records(&config), len 0);
+    }
+
+    #[test]
+    fn cleanup_crashed_nodes_skips_nodes_that_are_alive<S: Service>() {
+        let config = generate_isolated_config();
+        let _sut = NodeBuilder::new()
+            .config(&config)
+            .crash_journal(true)
+            .create::<S>()
+            .unwrap();
+
+        assert_that!(Node::<S>::cleanup_crashed_nodes(&config), eq CleanupState { cleanups: 0, failed_cleanups: 0 });
+        assert_that!(list_crash_records(&config), len 1);
+    }
+
+    #[test]
+    fn node_logger_receives_messages_of_service_creation<S: Service>() {
+        let logger: &'static buffer::Logger = Box::leak(Box::new(buffer::Logger::new()));
+        set_log_level(LogLevel::Trace);
+
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .logger(logger)
+            .create::<S>()
+            .unwrap();
+
+        let _service = sut.service_builder(&service_name).event().create().unwrap();
+        logger.clear();
+
+        let result = sut.service_builder(&service_name).event().create();
+        assert_that!(result.err(), eq Some(EventCreateError::AlreadyExists));
+        assert_that!(logger.contains(LogLevel::Debug), eq true);
+
+        let number_of_messages = logger.len();
+        debug!("this message is sent to the global logger");
+        assert_that!(logger.len(), eq number_of_messages);
+    }
+
+    #[test]
+    fn node_logger_receives_messages_of_port_operations<S: Service>() {
+        let logger: &'static buffer::Logger = Box::leak(Box::new(buffer::Logger::new()));
+        set_log_level(LogLevel::Trace);
+
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .logger(logger)
+            .create::<S>()
+            .unwrap();
+
+        let service = sut
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .max_loaned_samples(1)
+            .create()
+            .unwrap();
+        let _sample = publisher.loan().unwrap();
+        logger.clear();
+
+        let result = publisher.loan();
+        assert_that!(result.err(), eq Some(LoanError::ExceedsMaxLoanedSamples));
+        assert_that!(logger.is_empty(), eq false);
+
+        let number_of_messages = logger.len();
+        debug!("this message is sent to the global logger");
+        assert_that!(logger.len(), eq number_of_messages);
+    }
+
+    #[test]
+    fn node_logger_receives_structured_fields_of_service_creation<S: Service>() {
+        let logger: &'static buffer::Logger = Box::leak(Box::new(buffer::Logger::new()));
+        set_log_level(LogLevel::Trace);
+
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .logger(logger)
+            .create::<S>()
+            .unwrap();
+
+        let _service = sut
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(0)
+            .create()
+            .unwrap();
+
+        let fields: Vec<_> = logger
+            .content()
+            .into_iter()
+            .filter(|entry| entry.log_level == LogLevel::Warn)
+            .map(|entry| entry.fields)
+            .collect();
+        assert_that!(
+            fields,
+            contains vec![
+                ("setting".to_string(), "max_publishers".to_string()),
+                ("adjusted_value".to_string(), "1".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn node_failure_hook_receives_failures_of_service_creation<S: Service>() {
+        #[derive(Default)]
+        struct Failures(Mutex<Vec<(FailureKind, String)>>);
+
+        impl FailureHook for Failures {
+            fn on_failure(&self, failure: &Failure) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push((failure.kind, format!("{:?}", failure.error)));
+            }
+        }
+
+        let hook: &'static Failures = Box::leak(Box::default());
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .failure_hook(hook)
+            .create::<S>()
+            .unwrap();
+
+        let _service = sut.service_builder(&service_name).event().create().unwrap();
+        assert_that!(hook.0.lock().unwrap(), len 0);
+
+        let result = sut.service_builder(&service_name).event().create();
+        assert_that!(result.err(), eq Some(EventCreateError::AlreadyExists));
+
+        let failures = hook.0.lock().unwrap();
+        assert_that!(
+            failures.contains(&(FailureKind::Error, "Some(AlreadyExists)".to_string())),
+            eq true
+        );
+    }
+
+    #[test]
+    fn log_level_filter_of_config_is_applied_on_node_creation<S: Service>() {
+        let mut config = generate_isolated_config();
+        config.global.log_level_filter = Some("LogLevelFilterTestOrigin=fatal".to_string());
+
+        let _sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(
+            get_log_level_for("LogLevelFilterTestOrigin::create()"),
+            eq LogLevel::Fatal as u8
+        );
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
     #[instantiate_tests(<iceoryx2::service::local::Service>)]
     mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::static_memory::Service>)]
+    mod static_memory {}
+}
+
+mod node_service_independent {
+    use iceoryx2::node::dead_node_detector::DeadNodeDetectorWaitError;
+    use iceoryx2::node::service_dependencies::ServiceDependenciesWaitError;
+    use iceoryx2::node::system_event::{SystemEvent, SystemEventCreateError};
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn system_event_maps_to_event_id_and_back() {
+        for event in [
+            SystemEvent::EmergencyStop,
+            SystemEvent::EmergencyStopReleased,
+            SystemEvent::Shutdown,
+        ] {
+            assert_that!(SystemEvent::from_event_id(event.event_id()), eq Some(event));
+        }
+
+        assert_that!(SystemEvent::from_event_id(EventId::new(0)), eq None);
+    }
+
+    #[test]
+    fn system_event_create_error_display_works() {
+        assert_that!(
+            format!("{}", SystemEventCreateError::ExceedsMaxSupportedPorts), eq "SystemEventCreateError::ExceedsMaxSupportedPorts");
+    }
+
+    #[test]
+    fn service_dependencies_wait_error_display_works() {
+        assert_that!(
+            format!("{}", ServiceDependenciesWaitError::Timeout), eq "ServiceDependenciesWaitError::Timeout");
+    }
+
+    #[test]
+    fn dead_node_detector_wait_error_display_works() {
+        assert_that!(
+            format!("{}", DeadNodeDetectorWaitError::Interrupt), eq "DeadNodeDetectorWaitError::Interrupt");
+    }
 }
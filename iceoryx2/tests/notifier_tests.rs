@@ -63,4 +63,7 @@ mod notifier {
 
     #[instantiate_tests(<iceoryx2::service::local::Service>)]
     mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::static_memory::Service>)]
+    mod static_memory {}
 }
@@ -0,0 +1,165 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod payload_view {
+    use iceoryx2::payload_view::{
+        self, PayloadView, PayloadViewError, PAYLOAD_SCHEMA_ATTRIBUTE_KEY,
+    };
+    use iceoryx2::port::publisher::Publisher;
+    use iceoryx2::port::subscriber::Subscriber;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::port_factory::publish_subscribe;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const MAX_SLICE_LEN: usize = 32;
+
+    // length prefixed utf-8 string
+    struct Text<'payload> {
+        value: &'payload str,
+    }
+
+    impl<'payload> PayloadView<'payload> for Text<'payload> {
+        const SCHEMA: &'static str = "test:text";
+
+        fn view(payload: &'payload [u8]) -> Option<Self> {
+            let (len, text) = payload.split_first()?;
+            let value = core::str::from_utf8(text.get(..*len as usize)?).ok()?;
+            Some(Self { value })
+        }
+    }
+
+    struct Number;
+
+    impl PayloadView<'_> for Number {
+        const SCHEMA: &'static str = "test:number";
+
+        fn view(_payload: &[u8]) -> Option<Self> {
+            Some(Self)
+        }
+    }
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "payload_view_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    struct TestContext<Sut: Service> {
+        _node: Node<Sut>,
+        service: publish_subscribe::PortFactory<Sut, [u8], ()>,
+        publisher: Publisher<Sut, [u8], ()>,
+        subscriber: Subscriber<Sut, [u8], ()>,
+    }
+
+    impl<Sut: Service> TestContext<Sut> {
+        fn new(config: &Config, attributes: &AttributeSpecifier) -> Self {
+            let node = NodeBuilder::new().config(config).create::<Sut>().unwrap();
+            let service = node
+                .service_builder(&generate_name())
+                .publish_subscribe::<[u8]>()
+                .create_with_attributes(attributes)
+                .unwrap();
+            let publisher = service
+                .publisher_builder()
+                .initial_max_slice_len(MAX_SLICE_LEN)
+                .create()
+                .unwrap();
+            let subscriber = service.subscriber_builder().create().unwrap();
+
+            Self {
+                _node: node,
+                service,
+                publisher,
+                subscriber,
+            }
+        }
+
+        fn send(&self, payload: &[u8]) {
+            let sample = self.publisher.loan_slice_uninit(payload.len()).unwrap();
+            assert_that!(sample.write_from_slice(payload).send(), eq Ok(1));
+        }
+    }
+
+    #[test]
+    fn verify_schema_succeeds_when_service_declares_schema_of_view<Sut: Service>() {
+        let config = generate_isolated_config();
+        let sut = TestContext::<Sut>::new(
+            &config,
+            &AttributeSpecifier::new().define(PAYLOAD_SCHEMA_ATTRIBUTE_KEY, Text::SCHEMA),
+        );
+
+        assert_that!(payload_view::verify_schema::<Text>(sut.service.attributes()), eq Ok(()));
+        assert_that!(
+            payload_view::verify_schema::<Number>(sut.service.attributes()),
+            eq Err(PayloadViewError::SchemaMismatch)
+        );
+    }
+
+    #[test]
+    fn verify_schema_fails_when_service_does_not_declare_schema<Sut: Service>() {
+        let config = generate_isolated_config();
+        let sut = TestContext::<Sut>::new(&config, &AttributeSpecifier::new());
+
+        assert_that!(
+            payload_view::verify_schema::<Text>(sut.service.attributes()),
+            eq Err(PayloadViewError::UndeclaredSchema)
+        );
+    }
+
+    #[test]
+    fn view_on_valid_payload_provides_typed_reader<Sut: Service>() {
+        let config = generate_isolated_config();
+        let sut = TestContext::<Sut>::new(
+            &config,
+            &AttributeSpecifier::new().define(PAYLOAD_SCHEMA_ATTRIBUTE_KEY, Text::SCHEMA),
+        );
+
+        sut.send(b"\x05hello");
+        let sample = sut.subscriber.receive().unwrap().unwrap();
+        let view = sample.view::<Text>();
+
+        assert_that!(view, is_ok);
+        assert_that!(view.ok().unwrap().value, eq "hello");
+    }
+
+    #[test]
+    fn view_on_invalid_payload_fails<Sut: Service>() {
+        let config = generate_isolated_config();
+        let sut = TestContext::<Sut>::new(
+            &config,
+            &AttributeSpecifier::new().define(PAYLOAD_SCHEMA_ATTRIBUTE_KEY, Text::SCHEMA),
+        );
+
+        sut.send(b"\x09hello");
+        let sample = sut.subscriber.receive().unwrap().unwrap();
+
+        assert_that!(
+            sample.view::<Text>().err(),
+            eq Some(PayloadViewError::InvalidPayload)
+        );
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::static_memory::Service>)]
+    mod static_memory {}
+}
@@ -17,6 +17,7 @@ mod publisher {
     use std::sync::Mutex;
     use std::time::Instant;
 
+    use iceoryx2::port::update_connections::UpdateConnections;
     use iceoryx2::port::{publisher::PublisherCreateError, LoanError};
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::publish_subscribe::CustomPayloadMarker;
@@ -163,6 +164,87 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn publisher_with_size_classes_delivers_slices_of_every_size_class<Sut: Service>(
+    ) -> TestResult<()> {
+        const MAX_SLICE_LEN: usize = 128;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(MAX_SLICE_LEN)
+            .size_class(4, 2)
+            .size_class(32, 2)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        for slice_len in [1, 4, 5, 32, 33, MAX_SLICE_LEN] {
+            let sample = publisher.loan_slice_uninit(slice_len)?;
+            let sample = sample.write_from_fn(|i| (i * slice_len) as u64);
+            sample.send()?;
+
+            let sample = subscriber.receive()?.unwrap();
+            assert_that!(sample.payload().len(), eq slice_len);
+            for (i, value) in sample.payload().iter().enumerate() {
+                assert_that!(*value, eq(i * slice_len) as u64);
+            }
+        }
+
+        let sut = publisher.loan_slice(MAX_SLICE_LEN + 1);
+        assert_that!(sut.err(), eq Some(LoanError::ExceedsMaxLoanSize));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_size_classes_falls_back_to_larger_size_class<Sut: Service>() -> TestResult<()>
+    {
+        const MAX_SLICE_LEN: usize = 64;
+        const SMALL_SLICE_LEN: usize = 8;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let publisher = service
+            .publisher_builder()
+            .max_loaned_samples(2)
+            .initial_max_slice_len(MAX_SLICE_LEN)
+            .size_class(SMALL_SLICE_LEN, 1)
+            .create()?;
+
+        let _sample_1 = publisher.loan_slice(SMALL_SLICE_LEN)?;
+        let _sample_2 = publisher.loan_slice(SMALL_SLICE_LEN)?;
+        publisher.update_connections()?;
+
+        let mut size_classes = vec![];
+        service
+            .dynamic_config()
+            .list_publisher_resource_usage(|usage| {
+                size_classes.extend_from_slice(usage.size_classes());
+                CallbackProgression::Continue
+            });
+
+        assert_that!(size_classes, len 2);
+        assert_that!(size_classes[0].max_slice_len(), eq SMALL_SLICE_LEN);
+        assert_that!(size_classes[0].number_of_buckets(), eq 1);
+        assert_that!(size_classes[0].number_of_used_buckets(), eq 1);
+        assert_that!(size_classes[1].max_slice_len(), eq MAX_SLICE_LEN);
+        assert_that!(size_classes[1].number_of_buckets(), ge 1);
+        assert_that!(size_classes[1].number_of_used_buckets(), eq 1);
+
+        Ok(())
+    }
+
     #[test]
     fn publisher_loan_unit_and_send_sample_works<Sut: Service>() -> TestResult<()> {
         let service_name = generate_name()?;
@@ -393,6 +475,321 @@ mod publisher {
         }
     }
 
+    #[test]
+    fn publisher_write_combining_delivers_batch_when_full<Sut: Service>() -> TestResult<()> {
+        const BATCH_LEN: usize = 4;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .write_combining(BATCH_LEN, Duration::from_secs(3600))
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        for i in 0..BATCH_LEN - 1 {
+            assert_that!(sut.send_combined(i as u64)?, eq None);
+            assert_that!(sut.number_of_pending_combined_values(), eq i + 1);
+        }
+        assert_that!(subscriber.receive()?, is_none);
+
+        assert_that!(sut.send_combined(BATCH_LEN as u64 - 1)?, eq Some(1));
+        assert_that!(sut.number_of_pending_combined_values(), eq 0);
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(sample.header().number_of_elements(), eq BATCH_LEN as u64);
+        assert_that!(sample.payload(), len BATCH_LEN);
+        for i in 0..BATCH_LEN {
+            assert_that!(sample.payload()[i], eq i as u64);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_write_combining_delivers_batch_when_window_elapsed<Sut: Service>() -> TestResult<()>
+    {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .write_combining(128, TIMEOUT)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.send_combined(12)?, eq None);
+        std::thread::sleep(TIMEOUT);
+        assert_that!(sut.send_combined(34)?, eq Some(1));
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(*sample.payload(), eq [12, 34]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_write_combining_keeps_batch_pending_until_flushed_when_due<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .write_combining(128, TIMEOUT)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.combined_flush_timeout(), eq None);
+        assert_that!(sut.flush_combined_if_due()?, eq None);
+
+        assert_that!(sut.send_combined(78)?, eq None);
+        let timeout = sut.combined_flush_timeout();
+        assert_that!(timeout, is_some);
+        assert_that!(timeout.unwrap(), le TIMEOUT);
+
+        std::thread::sleep(TIMEOUT);
+        // the window elapsed but without another send the batch stays pending
+        assert_that!(subscriber.receive()?, is_none);
+        assert_that!(sut.number_of_pending_combined_values(), eq 1);
+        assert_that!(sut.combined_flush_timeout(), eq Some(Duration::ZERO));
+
+        assert_that!(sut.flush_combined_if_due()?, eq Some(1));
+        assert_that!(sut.number_of_pending_combined_values(), eq 0);
+        assert_that!(sut.combined_flush_timeout(), eq None);
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(*sample.payload(), eq[78]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_flush_combined_if_due_keeps_batch_within_window<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .write_combining(128, Duration::from_secs(3600))
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.send_combined(90)?, eq None);
+        assert_that!(sut.flush_combined_if_due()?, eq None);
+        assert_that!(sut.number_of_pending_combined_values(), eq 1);
+        assert_that!(subscriber.receive()?, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_flush_combined_delivers_pending_values<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .write_combining(128, Duration::from_secs(3600))
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.flush_combined()?, eq 0);
+        assert_that!(subscriber.receive()?, is_none);
+
+        assert_that!(sut.send_combined(56)?, eq None);
+        assert_that!(sut.flush_combined()?, eq 1);
+        assert_that!(sut.number_of_pending_combined_values(), eq 0);
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(*sample.payload(), eq[56]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_write_combining_delivers_pending_values_on_drop<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .write_combining(128, Duration::from_secs(3600))
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.send_combined(78)?, eq None);
+        drop(sut);
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(*sample.payload(), eq[78]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_write_combining_delivers_due_batch_on_loan<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .write_combining(128, TIMEOUT)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.send_combined(12)?, eq None);
+        std::thread::sleep(TIMEOUT);
+
+        let _sample = sut.loan_slice(1)?;
+        assert_that!(sut.number_of_pending_combined_values(), eq 0);
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(*sample.payload(), eq[12]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_write_combining_delivers_due_batch_before_sent_sample<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .write_combining(128, TIMEOUT)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        let mut sample = sut.loan_slice(1)?;
+        sample.payload_mut()[0] = 56;
+        assert_that!(sut.send_combined(34)?, eq None);
+        std::thread::sleep(TIMEOUT);
+
+        assert_that!(sample.send()?, eq 1);
+        assert_that!(sut.number_of_pending_combined_values(), eq 0);
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(*sample.payload(), eq[34]);
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(*sample.payload(), eq[56]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_send_combined_without_write_combining_delivers_immediately<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.send_combined(90)?, eq Some(1));
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(*sample.payload(), eq[90]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_send_combined_default_initializes_user_header<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .user_header::<ComplexType>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .write_combining(2, Duration::from_secs(3600))
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.send_combined(12)?, eq None);
+        assert_that!(sut.send_combined(34)?, eq Some(1));
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(sample.user_header().data, eq COMPLEX_TYPE_DEFAULT_VALUE);
+        assert_that!(*sample.payload(), eq[12, 34]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_unavailable_numa_node_falls_back_to_default_memory_policy<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .data_segment_numa_node(u32::MAX)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        sut.send_copy(1234)?;
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(*sample.payload(), eq 1234);
+
+        Ok(())
+    }
+
     #[test]
     fn publisher_with_custom_payload_details_adjusts_slice_len<Sut: Service>() -> TestResult<()> {
         const TYPE_SIZE_OVERRIDE: usize = 128;
@@ -466,9 +863,179 @@ mod publisher {
         let _sample = unsafe { sut.loan_custom_payload(2) };
     }
 
+    #[test]
+    fn publisher_uses_out_of_memory_strategy_of_service<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .out_of_memory_strategy(OutOfMemoryStrategy::ReclaimHistory)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+
+        assert_that!(service.static_config().out_of_memory_strategy(), eq OutOfMemoryStrategy::ReclaimHistory);
+        assert_that!(sut.out_of_memory_strategy(), eq OutOfMemoryStrategy::ReclaimHistory);
+        assert_that!(sut.number_of_reclaimed_samples(), eq 0);
+        assert_that!(sut.is_degraded(), eq false);
+
+        Ok(())
+    }
+
+    #[test]
+    fn out_of_memory_strategy_is_ignored_when_service_is_opened<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .out_of_memory_strategy(OutOfMemoryStrategy::ReclaimHistory)
+            .open()?;
+
+        let sut = service.publisher_builder().create()?;
+
+        assert_that!(sut.out_of_memory_strategy(), eq OutOfMemoryStrategy::Fail);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_reclaim_history_strategy_does_not_reclaim_samples_when_memory_suffices<
+        Sut: Service,
+    >() -> TestResult<()> {
+        const HISTORY_SIZE: usize = 3;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(HISTORY_SIZE)
+            .subscriber_max_buffer_size(HISTORY_SIZE)
+            .out_of_memory_strategy(OutOfMemoryStrategy::ReclaimHistory)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        for n in 0..(HISTORY_SIZE * 4) as u64 {
+            sut.send_copy(n)?;
+        }
+
+        let mut received_samples = vec![];
+        while let Some(sample) = subscriber.receive()? {
+            received_samples.push(*sample);
+        }
+
+        assert_that!(received_samples, len HISTORY_SIZE);
+        assert_that!(sut.number_of_reclaimed_samples(), eq 0);
+        assert_that!(sut.is_degraded(), eq false);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_zeroes_payload_on_loan_when_enabled<Sut: Service>() -> TestResult<()> {
+        const SLICE_LEN: usize = 16;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .zero_payload_on_loan(true)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(SLICE_LEN)
+            .max_loaned_samples(1)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(service.static_config().zero_payload_on_loan(), eq true);
+        assert_that!(sut.zero_payload_on_loan(), eq true);
+
+        for _ in 0..3 {
+            let sample = sut.loan_slice_uninit(SLICE_LEN)?;
+            let zeroed_bytes = sut.number_of_zeroed_bytes();
+            assert_that!(zeroed_bytes, ge(SLICE_LEN as u64));
+            assert_that!(sample.payload().iter().all(|v| unsafe { v.assume_init() } == 0), eq true);
+
+            let sample = sample.write_from_fn(|_| 0xff);
+            sample.send()?;
+
+            let received = subscriber.receive()?.unwrap();
+            assert_that!(received.payload().iter().all(|v| *v == 0xff), eq true);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_does_not_zero_payload_on_loan_by_default<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        sut.send_copy(42)?;
+
+        assert_that!(service.static_config().zero_payload_on_loan(), eq false);
+        assert_that!(sut.zero_payload_on_loan(), eq false);
+        assert_that!(sut.number_of_zeroed_bytes(), eq 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn zero_payload_on_loan_is_ignored_when_service_is_opened<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .zero_payload_on_loan(true)
+            .create()?;
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .zero_payload_on_loan(false)
+            .open()?;
+
+        let sut = service.publisher_builder().create()?;
+
+        assert_that!(sut.zero_payload_on_loan(), eq true);
+
+        Ok(())
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
     #[instantiate_tests(<iceoryx2::service::local::Service>)]
     mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::static_memory::Service>)]
+    mod static_memory {}
 }
@@ -17,6 +17,7 @@ mod sample_mut {
     use iceoryx2::port::LoanError;
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
+    use iceoryx2::service::header::publish_subscribe::{ProvenanceError, MAX_PROVENANCE_DEPTH};
     use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
     use iceoryx2::service::Service;
     use iceoryx2::testing::*;
@@ -147,6 +148,62 @@ mod sample_mut {
         assert_that!(*received_sample, eq PAYLOAD);
     }
 
+    #[test]
+    fn sample_without_appended_provenance_has_empty_provenance<Sut: Service>() {
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+        let sample = test_context.publisher.loan().unwrap();
+
+        assert_that!(sample.header().provenance(), len 0);
+    }
+
+    #[test]
+    fn append_provenance_records_origin_of_source_sample<Sut: Service>() {
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+        assert_that!(test_context.publisher.send_copy(123), eq Ok(1));
+        let source = test_context.subscriber.receive().unwrap().unwrap();
+
+        let mut sample = test_context.publisher.loan_uninit().unwrap();
+        assert_that!(sample.append_provenance(source.header()), is_ok);
+        assert_that!(sample.write_payload(456).send(), eq Ok(1));
+
+        let received_sample = test_context.subscriber.receive().unwrap().unwrap();
+        let provenance = received_sample.header().provenance();
+        assert_that!(provenance, len 1);
+        assert_that!(provenance[0].publisher_id(), eq source.header().publisher_id());
+        assert_that!(provenance[0].timestamp(), eq source.header().timestamp());
+        assert_that!(
+            provenance[0].timestamp().as_duration(),
+            le received_sample.header().timestamp().as_duration()
+        );
+    }
+
+    #[test]
+    fn append_provenance_fails_when_max_depth_is_exceeded<Sut: Service>() {
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+        assert_that!(test_context.publisher.send_copy(0), eq Ok(1));
+
+        for n in 0..MAX_PROVENANCE_DEPTH {
+            let source = test_context.subscriber.receive().unwrap().unwrap();
+            let mut sample = test_context.publisher.loan().unwrap();
+            assert_that!(sample.append_provenance(source.header()), is_ok);
+            assert_that!(sample.header().provenance(), len n + 1);
+            assert_that!(sample.send(), eq Ok(1));
+        }
+
+        let source = test_context.subscriber.receive().unwrap().unwrap();
+        assert_that!(source.header().provenance(), len MAX_PROVENANCE_DEPTH);
+
+        let mut sample = test_context.publisher.loan().unwrap();
+        assert_that!(
+            sample.append_provenance(source.header()),
+            eq Err(ProvenanceError::ExceedsMaxDepth)
+        );
+        assert_that!(sample.header().provenance(), len 0);
+    }
+
     #[test]
     fn sample_of_dropped_service_does_block_new_service_creation<Sut: Service>() {
         let config = generate_isolated_config();
@@ -184,4 +241,7 @@ mod sample_mut {
 
     #[instantiate_tests(<iceoryx2::service::local::Service>)]
     mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::static_memory::Service>)]
+    mod static_memory {}
 }
@@ -21,6 +21,7 @@ mod service_event {
     use iceoryx2::port::listener::{Listener, ListenerCreateError};
     use iceoryx2::port::notifier::{NotifierCreateError, NotifierNotifyError};
     use iceoryx2::prelude::*;
+    use iceoryx2::service::attribute::DynamicAttributePermission;
     use iceoryx2::service::builder::event::{EventCreateError, EventOpenError};
     use iceoryx2::testing::*;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
@@ -165,6 +166,59 @@ mod service_event {
         assert_that!(sut2, is_ok);
     }
 
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_min_version_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .version(3)
+            .create();
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(sut.static_config().version(), eq 3);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .require_version_at_least(4)
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq EventOpenError::IncompatibleVersion);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .require_version_at_least(3)
+            .open();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().version(), eq 3);
+    }
+
+    #[test]
+    fn open_ignores_version_without_min_version_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .version(1)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .version(5)
+            .open();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().version(), eq 1);
+    }
+
     #[test]
     fn set_max_nodes_to_zero_adjusts_it_to_one<Sut: Service>() {
         let service_name = generate_name();
@@ -396,6 +450,115 @@ mod service_event {
         assert_that!(received_events, eq 1);
     }
 
+    #[test]
+    fn updating_dynamic_attribute_emits_dynamic_attribute_changed_event<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let event_id = EventId::new(11);
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .disable_notifier_created_event()
+            .dynamic_attribute_changed_event(event_id)
+            .create_with_attributes(
+                &AttributeSpecifier::new()
+                    .define_dynamic(
+                        "calibration_revision",
+                        "1",
+                        DynamicAttributePermission::AllParticipants,
+                    )
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let sut2 = node.service_builder(&service_name).event().open().unwrap();
+        assert_that!(sut2.static_config().dynamic_attribute_changed_event(), eq Some(event_id));
+
+        let listener = sut.listener_builder().create().unwrap();
+
+        for revision in ["2", "3"] {
+            assert_that!(
+                sut2.update_dynamic_attribute("calibration_revision", revision),
+                is_ok
+            );
+
+            let mut received_events = 0;
+            for event in listener.try_wait_one().unwrap().iter() {
+                assert_that!(*event, eq event_id);
+                received_events += 1;
+            }
+            assert_that!(received_events, eq 1);
+            let attributes = sut.dynamic_attributes();
+            assert_that!(attributes.get_key_value_at("calibration_revision", 0), eq Some(revision));
+        }
+
+        // the event is emitted without occupying a notifier slot
+        assert_that!(sut.dynamic_config().number_of_notifiers(), eq 0);
+    }
+
+    #[test]
+    fn dynamic_attribute_changed_event_is_emitted_when_all_notifier_slots_are_used<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let event_id = EventId::new(12);
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_notifiers(1)
+            .disable_notifier_created_event()
+            .dynamic_attribute_changed_event(event_id)
+            .create_with_attributes(
+                &AttributeSpecifier::new()
+                    .define_dynamic("mode", "idle", DynamicAttributePermission::OwnerOnly)
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let _notifier = sut.notifier_builder().create().unwrap();
+
+        assert_that!(sut.update_dynamic_attribute("mode", "active"), is_ok);
+
+        let mut received_events = vec![];
+        listener
+            .try_wait_all(|event| received_events.push(event))
+            .unwrap();
+        assert_that!(received_events, eq vec![event_id]);
+        assert_that!(sut.dynamic_config().number_of_notifiers(), eq 1);
+    }
+
+    #[test]
+    fn open_fails_when_dynamic_attribute_changed_event_does_not_match<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .dynamic_attribute_changed_event(EventId::new(5))
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .dynamic_attribute_changed_event(EventId::new(6))
+            .open();
+        assert_that!(sut2.err().unwrap(), eq EventOpenError::IncompatibleDynamicAttributeChangedEvent);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .disable_dynamic_attribute_changed_event()
+            .open();
+        assert_that!(sut2.err().unwrap(), eq EventOpenError::IncompatibleDynamicAttributeChangedEvent);
+    }
+
     #[test]
     fn notifier_emits_create_and_dropped_event_id<Sut: Service>() {
         let service_name = generate_name();
@@ -1407,4 +1570,7 @@ mod service_event {
 
     #[instantiate_tests(<iceoryx2::service::local::Service>)]
     mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::static_memory::Service>)]
+    mod static_memory {}
 }
@@ -0,0 +1,110 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod service_name {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::service_name::{ServiceNamePattern, BUILTIN_SERVICE_NAME_PREFIX};
+    use iceoryx2_bb_container::semantic_string::SemanticStringError;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn empty_service_name_fails() {
+        assert_that!(ServiceName::new(""), eq Err(SemanticStringError::InvalidContent));
+    }
+
+    #[test]
+    fn service_name_with_builtin_prefix_fails() {
+        assert_that!(ServiceName::new(BUILTIN_SERVICE_NAME_PREFIX), eq Err(SemanticStringError::InvalidContent));
+        assert_that!(ServiceName::new("iox2://system_event"), eq Err(SemanticStringError::InvalidContent));
+        assert_that!(TryInto::<ServiceName>::try_into("iox2://my/service"), eq Err(SemanticStringError::InvalidContent));
+
+        let sut = ServiceName::new("iox2:/my/service").unwrap();
+        assert_that!(sut.is_builtin(), eq false);
+    }
+
+    #[test]
+    fn segments_works() {
+        let sut = ServiceName::new("all/glory/to/hypnotoad").unwrap();
+
+        assert_that!(sut.segments().collect::<Vec<_>>(), eq vec!["all", "glory", "to", "hypnotoad"]);
+
+        let sut = ServiceName::new("hypnotoad").unwrap();
+        assert_that!(sut.segments().collect::<Vec<_>>(), eq vec!["hypnotoad"]);
+    }
+
+    #[test]
+    fn starts_with_compares_whole_segments() {
+        let sut = ServiceName::new("all/glory/to/hypnotoad").unwrap();
+
+        assert_that!(sut.starts_with("all"), eq true);
+        assert_that!(sut.starts_with("all/glory"), eq true);
+        assert_that!(sut.starts_with("all/glory/"), eq true);
+        assert_that!(sut.starts_with("all/glory/to/hypnotoad"), eq true);
+        assert_that!(sut.starts_with(""), eq true);
+
+        assert_that!(sut.starts_with("al"), eq false);
+        assert_that!(sut.starts_with("all/glo"), eq false);
+        assert_that!(sut.starts_with("glory"), eq false);
+        assert_that!(sut.starts_with("all/glory/to/hypnotoad/now"), eq false);
+    }
+
+    #[test]
+    fn pattern_with_invalid_content_fails() {
+        assert_that!(ServiceNamePattern::new(""), eq Err(SemanticStringError::InvalidContent));
+        assert_that!(ServiceNamePattern::new("a/**b"), eq Err(SemanticStringError::InvalidContent));
+        assert_that!(ServiceNamePattern::new("a/b***/c"), eq Err(SemanticStringError::InvalidContent));
+    }
+
+    #[test]
+    fn pattern_without_wildcard_matches_only_equal_name() {
+        let sut = ServiceNamePattern::new("all/glory").unwrap();
+
+        assert_that!(sut.matches(&ServiceName::new("all/glory").unwrap()), eq true);
+        assert_that!(sut.matches(&ServiceName::new("all/glory/to").unwrap()), eq false);
+        assert_that!(sut.matches(&ServiceName::new("all").unwrap()), eq false);
+    }
+
+    #[test]
+    fn pattern_with_wildcard_matches_inside_of_segment() {
+        let sut = ServiceNamePattern::new("all/*/to/hypno*").unwrap();
+
+        assert_that!(sut.matches(&ServiceName::new("all/glory/to/hypnotoad").unwrap()), eq true);
+        assert_that!(sut.matches(&ServiceName::new("all/fame/to/hypno").unwrap()), eq true);
+        assert_that!(sut.matches(&ServiceName::new("all/glory/to/toad").unwrap()), eq false);
+        assert_that!(sut.matches(&ServiceName::new("all/glory/fame/to/hypnotoad").unwrap()), eq false);
+
+        let sut = ServiceNamePattern::new("*o*o*").unwrap();
+        assert_that!(sut.matches(&ServiceName::new("hypnotoad").unwrap()), eq true);
+        assert_that!(sut.matches(&ServiceName::new("toad").unwrap()), eq false);
+    }
+
+    #[test]
+    fn pattern_with_segment_wildcard_matches_any_number_of_segments() {
+        let sut = ServiceNamePattern::new("all/**/hypnotoad").unwrap();
+
+        assert_that!(sut.matches(&ServiceName::new("all/hypnotoad").unwrap()), eq true);
+        assert_that!(sut.matches(&ServiceName::new("all/glory/hypnotoad").unwrap()), eq true);
+        assert_that!(sut.matches(&ServiceName::new("all/glory/to/hypnotoad").unwrap()), eq true);
+        assert_that!(sut.matches(&ServiceName::new("all/glory/to/toad").unwrap()), eq false);
+
+        let sut = ServiceNamePattern::new("**").unwrap();
+        assert_that!(sut.matches(&ServiceName::new("all/glory/to/hypnotoad").unwrap()), eq true);
+    }
+
+    #[test]
+    fn pattern_can_be_parsed_from_str() {
+        let sut: ServiceNamePattern = "all/**".parse().unwrap();
+
+        assert_that!(sut.as_str(), eq "all/**");
+        assert_that!(format!("{}", sut), eq "all/**");
+    }
+}
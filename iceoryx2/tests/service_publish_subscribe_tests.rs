@@ -17,6 +17,8 @@ mod service_publish_subscribe {
     use std::thread;
 
     use iceoryx2::config::Config;
+    use iceoryx2::node::NodeCreationFailure;
+    use iceoryx2::port::port_identifiers::UniquePortId;
     use iceoryx2::port::publisher::PublisherCreateError;
     use iceoryx2::port::subscriber::SubscriberCreateError;
     use iceoryx2::port::update_connections::UpdateConnections;
@@ -27,11 +29,13 @@ mod service_publish_subscribe {
     use iceoryx2::service::builder::publish_subscribe::{CustomHeaderMarker, CustomPayloadMarker};
     use iceoryx2::service::messaging_pattern::MessagingPattern;
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
-    use iceoryx2::service::{Service, ServiceDetails};
+    use iceoryx2::service::{Service, ServiceDetails, ServicePortDetails};
     use iceoryx2::testing::*;
     use iceoryx2_bb_elementary::alignment::Alignment;
     use iceoryx2_bb_elementary::CallbackProgression;
     use iceoryx2_bb_log::{set_log_level, LogLevel};
+    use iceoryx2_bb_posix::access_control_list::AccessControlListBuilder;
+    use iceoryx2_bb_posix::permission::Permission;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
@@ -257,6 +261,293 @@ mod service_publish_subscribe {
         assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
     }
 
+    mod version_1 {
+        use iceoryx2::prelude::*;
+
+        #[derive(Debug, StableTypeId)]
+        #[stable_type_id("my_app::Position")]
+        #[repr(C)]
+        pub struct Position {
+            pub x: u64,
+            pub y: u64,
+        }
+
+        #[derive(Debug, StableTypeId)]
+        #[stable_type_id("my_app::Header")]
+        #[repr(C)]
+        pub struct Header {
+            pub id: u32,
+        }
+    }
+
+    mod version_2 {
+        use iceoryx2::prelude::*;
+
+        #[derive(Debug, StableTypeId)]
+        #[stable_type_id("my_app::Position")]
+        #[repr(C)]
+        pub struct RenamedPosition {
+            pub x: u64,
+            pub y: u64,
+        }
+
+        #[derive(Debug, StableTypeId)]
+        #[stable_type_id("my_app::Velocity")]
+        #[repr(C)]
+        pub struct Velocity {
+            pub x: u64,
+            pub y: u64,
+        }
+
+        #[derive(Debug, StableTypeId)]
+        #[stable_type_id("my_app::Header")]
+        #[repr(C)]
+        pub struct RenamedHeader {
+            pub id: u32,
+        }
+    }
+
+    #[test]
+    fn open_succeeds_when_types_differ_in_name_but_have_same_stable_type_id<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<version_1::Position>()
+            .user_header::<version_1::Header>()
+            .use_stable_payload_type_id()
+            .use_stable_user_header_type_id()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<version_2::RenamedPosition>()
+            .user_header::<version_2::RenamedHeader>()
+            .use_stable_payload_type_id()
+            .use_stable_user_header_type_id()
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_succeeds_for_slices_with_same_stable_type_id<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[version_1::Position]>()
+            .use_stable_payload_type_id()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[version_2::RenamedPosition]>()
+            .use_stable_payload_type_id()
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_stable_type_ids_differ<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<version_1::Position>()
+            .use_stable_payload_type_id()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<version_2::Velocity>()
+            .use_stable_payload_type_id()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn open_fails_when_only_one_side_uses_stable_type_id_and_names_differ<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<version_1::Position>()
+            .use_stable_payload_type_id()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<version_2::RenamedPosition>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<version_1::Position>()
+            .open();
+        assert_that!(sut3, is_ok);
+    }
+
+    mod layout_v1 {
+        use iceoryx2::prelude::*;
+
+        #[derive(Debug, StableTypeId)]
+        #[stable_type_id("my_app::Telemetry")]
+        #[repr(C)]
+        pub struct Telemetry {
+            pub value: u64,
+        }
+    }
+
+    mod layout_v2 {
+        use iceoryx2::prelude::*;
+
+        #[derive(Debug, StableTypeId)]
+        #[stable_type_id("my_app::Telemetry")]
+        #[repr(C)]
+        pub struct Telemetry {
+            pub value: u64,
+            pub timestamp: u64,
+        }
+    }
+
+    #[test]
+    fn open_with_smaller_payload_type_fails_by_default<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<layout_v2::Telemetry>()
+            .use_stable_payload_type_id()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<layout_v1::Telemetry>()
+            .use_stable_payload_type_id()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn open_with_smaller_payload_type_works_when_allowed<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<layout_v2::Telemetry>()
+            .use_stable_payload_type_id()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<layout_v1::Telemetry>()
+            .use_stable_payload_type_id()
+            .allow_smaller_payload_type(true)
+            .open()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut2.subscriber_builder().create().unwrap();
+
+        assert_that!(
+            publisher.send_copy(layout_v2::Telemetry {
+                value: 8192,
+                timestamp: 1234
+            }),
+            is_ok
+        );
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload().value, eq 8192);
+    }
+
+    #[test]
+    fn publisher_cannot_be_created_when_opened_with_smaller_payload_type<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<layout_v2::Telemetry>()
+            .use_stable_payload_type_id()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<layout_v1::Telemetry>()
+            .use_stable_payload_type_id()
+            .allow_smaller_payload_type(true)
+            .open()
+            .unwrap();
+
+        let publisher = sut2.publisher_builder().create();
+        assert_that!(publisher, is_err);
+        assert_that!(publisher.err().unwrap(), eq PublisherCreateError::IncompatiblePayloadSize);
+    }
+
+    #[test]
+    fn open_with_larger_payload_type_fails_even_when_smaller_type_is_allowed<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<layout_v1::Telemetry>()
+            .use_stable_payload_type_id()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<layout_v2::Telemetry>()
+            .use_stable_payload_type_id()
+            .allow_smaller_payload_type(true)
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn open_with_smaller_slice_element_type_fails_even_when_smaller_type_is_allowed<
+        Sut: Service,
+    >() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[layout_v2::Telemetry]>()
+            .use_stable_payload_type_id()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[layout_v1::Telemetry]>()
+            .use_stable_payload_type_id()
+            .allow_smaller_payload_type(true)
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
+
     #[test]
     fn open_fails_when_service_is_slice_based_and_typed_is_requested<Sut: Service>() {
         let service_name = generate_name();
@@ -328,6 +619,126 @@ mod service_publish_subscribe {
         assert_that!(sut2, is_ok);
     }
 
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_min_version_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .version(3)
+            .create();
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(sut.static_config().version(), eq 3);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .require_version_at_least(4)
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleVersion);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .require_version_at_least(3)
+            .open();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().version(), eq 3);
+    }
+
+    #[test]
+    fn open_fails_with_incompatible_version_when_version_and_payload_type_differ<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .version(1)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64; 2]>()
+            .require_version_at_least(2)
+            .open();
+
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleVersion));
+    }
+
+    #[test]
+    fn node_of_other_domain_that_shares_the_resources_cannot_be_created<Sut: Service>() {
+        let service_name = generate_name();
+        let isolated_config = generate_isolated_config();
+        let domain_name =
+            |name: &str| format!("{}_{}", name, UniqueSystemId::new().unwrap().value());
+
+        let mut config_a = isolated_config.clone();
+        config_a.set_domain(&domain_name("a")).unwrap();
+        let mut config_b = isolated_config.clone();
+        config_b.set_domain(&domain_name("b")).unwrap();
+        // both domains share the same resources to enforce a collision
+        for config in [&mut config_a, &mut config_b] {
+            config.global.prefix = isolated_config.global.prefix;
+            config
+                .global
+                .set_root_path(isolated_config.global.root_path());
+        }
+
+        let node_a = NodeBuilder::new()
+            .config(&config_a)
+            .create::<Sut>()
+            .unwrap();
+
+        let sut = node_a
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut, is_ok);
+        let _sut = sut.unwrap();
+        let details = Sut::details(&service_name, &config_a, MessagingPattern::PublishSubscribe)
+            .unwrap()
+            .unwrap();
+        assert_that!(details.static_details.domain(), eq config_a.global.domain());
+
+        // the builtin system event service of domain a is incompatible to domain b
+        let node_b = NodeBuilder::new().config(&config_b).create::<Sut>();
+        assert_that!(node_b.err(), eq Some(NodeCreationFailure::InternalError));
+
+        let sut2 = node_a
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_ignores_version_without_min_version_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .version(1)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .version(5)
+            .open();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().version(), eq 1);
+    }
+
     #[test]
     fn open_fails_when_service_does_not_satisfy_max_publishers_requirement<Sut: Service>() {
         let service_name = generate_name();
@@ -806,6 +1217,159 @@ mod service_publish_subscribe {
         }
     }
 
+    #[test]
+    fn number_of_loaned_samples_is_reported_in_dynamic_config<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .max_loaned_samples(4)
+            .create()
+            .unwrap();
+        assert_that!(sut2.dynamic_config().number_of_loaned_samples(), eq 0);
+
+        let sample_1 = publisher.loan().unwrap();
+        let sample_2 = publisher.loan().unwrap();
+        assert_that!(publisher.update_connections(), is_ok);
+        assert_that!(sut2.dynamic_config().number_of_loaned_samples(), eq 2);
+
+        drop(sample_1);
+        assert_that!(publisher.update_connections(), is_ok);
+        assert_that!(sut2.dynamic_config().number_of_loaned_samples(), eq 1);
+
+        sample_2.send().unwrap();
+        assert_that!(publisher.update_connections(), is_ok);
+        assert_that!(sut2.dynamic_config().number_of_loaned_samples(), eq 0);
+    }
+
+    #[test]
+    fn number_of_loaned_samples_is_sampled_on_loan_after_sampling_interval<Sut: Service>() {
+        const SAMPLING_INTERVAL: core::time::Duration = core::time::Duration::from_millis(100);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .max_loaned_samples(4)
+            .create()
+            .unwrap();
+        assert_that!(sut.dynamic_config().number_of_loaned_samples(), eq 0);
+
+        std::thread::sleep(SAMPLING_INTERVAL);
+        let _sample = publisher.loan().unwrap();
+        assert_that!(sut.dynamic_config().number_of_loaned_samples(), eq 1);
+    }
+
+    #[test]
+    fn allocated_data_segment_bytes_are_reported_in_dynamic_config<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.dynamic_config().allocated_data_segment_bytes(), eq 0);
+
+        let publisher_1 = sut.publisher_builder().create().unwrap();
+        let bytes_of_publisher_1 = sut.dynamic_config().allocated_data_segment_bytes();
+        assert_that!(bytes_of_publisher_1, gt 0);
+
+        let publisher_2 = sut.publisher_builder().create().unwrap();
+        let mut usages = vec![];
+        sut.dynamic_config().list_publisher_resource_usage(|usage| {
+            usages.push(*usage);
+            CallbackProgression::Continue
+        });
+
+        assert_that!(usages, len 2);
+        assert_that!(
+            usages,
+            contains_match | u | u.publisher_id() == publisher_1.id()
+        );
+        assert_that!(
+            usages,
+            contains_match | u | u.publisher_id() == publisher_2.id()
+        );
+        for usage in &usages {
+            assert_that!(*usage.node_id(), eq * node.id());
+            assert_that!(usage.allocated_data_segment_bytes(), gt 0);
+            assert_that!(usage.number_of_loaned_samples(), eq 0);
+            assert_that!(usage.size_classes(), len 1);
+            assert_that!(usage.size_classes()[0].number_of_used_buckets(), eq 0);
+        }
+        assert_that!(sut.dynamic_config().allocated_data_segment_bytes(), eq usages
+            .iter()
+            .map(|u| u.allocated_data_segment_bytes())
+            .sum::<usize>());
+
+        drop(publisher_1);
+        drop(publisher_2);
+        assert_that!(sut.dynamic_config().allocated_data_segment_bytes(), eq 0);
+    }
+
+    #[test]
+    fn service_details_contain_ports_and_their_owning_nodes<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node_1 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let node_2 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node_1
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let sut2 = node_2
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut2.subscriber_builder().create().unwrap();
+
+        let details = Sut::details(&service_name, &config, MessagingPattern::PublishSubscribe)
+            .unwrap()
+            .unwrap();
+        let ports = details.dynamic_details.unwrap().ports;
+
+        assert_that!(ports, len 2);
+        assert_that!(ports, contains ServicePortDetails {
+            port_id: UniquePortId::Publisher(publisher.id()),
+            node_id: *node_1.id()
+        });
+        assert_that!(ports, contains ServicePortDetails {
+            port_id: UniquePortId::Subscriber(subscriber.id()),
+            node_id: *node_2.id()
+        });
+    }
+
     #[test]
     fn type_informations_are_correct<Sut: Service>() {
         type Header = iceoryx2::service::header::publish_subscribe::Header;
@@ -1035,6 +1599,176 @@ mod service_publish_subscribe {
         assert_that!(*result.unwrap(), eq 4567);
     }
 
+    #[test]
+    fn tagged_union_variants_are_sent_and_received<Sut: Service>() {
+        #[derive(Debug, PartialEq)]
+        #[repr(C)]
+        struct Start {
+            speed: u64,
+        }
+
+        iceoryx2::tagged_union! {
+            #[derive(Debug, PartialEq)]
+            enum Command {
+                Start(Start),
+                Stop(u32),
+            }
+        }
+
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Command>()
+            .use_tagged_union_payload_variants()
+            .create()
+            .unwrap();
+
+        let variants = &sut.static_config().message_type_details().payload.variants;
+        assert_that!(variants, len 2);
+        assert_that!(variants[0].size, eq core::mem::size_of::<Start>());
+        assert_that!(variants[1].size, eq core::mem::size_of::<u32>());
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_variant(Start { speed: 91 }), eq Ok(1));
+        assert_that!(publisher.send_variant(42u32), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq Command::Start(Start { speed: 91 }));
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq Command::Stop(42));
+    }
+
+    #[test]
+    fn tagged_union_service_can_be_opened_with_and_without_variants<Sut: Service>() {
+        iceoryx2::tagged_union! {
+            #[derive(Debug)]
+            enum Command {
+                Start(u64),
+                Stop(u32),
+            }
+        }
+
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Command>()
+            .use_tagged_union_payload_variants()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Command>()
+            .use_tagged_union_payload_variants()
+            .open();
+        assert_that!(sut2, is_ok);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Command>()
+            .open();
+        assert_that!(sut3, is_ok);
+    }
+
+    #[test]
+    fn discarded_samples_are_counted_per_connection<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        const NUMBER_OF_SAMPLES: u64 = 5;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(false)
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+        let slow_subscriber = sut.subscriber_builder().create().unwrap();
+        let fast_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(publisher.update_connections(), is_ok);
+        assert_that!(slow_subscriber.update_connections(), is_ok);
+        assert_that!(fast_subscriber.update_connections(), is_ok);
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(n), is_ok);
+            while let Some(sample) = fast_subscriber.receive().unwrap() {
+                assert_that!(*sample, eq n);
+            }
+        }
+
+        let number_of_discarded_samples = NUMBER_OF_SAMPLES - BUFFER_SIZE as u64;
+        assert_that!(publisher.number_of_discarded_samples(), eq number_of_discarded_samples);
+        assert_that!(slow_subscriber.number_of_missed_samples(), eq number_of_discarded_samples);
+        assert_that!(fast_subscriber.number_of_missed_samples(), eq 0);
+        assert_that!(publisher.update_connections(), is_ok);
+        assert_that!(sut.dynamic_config().number_of_discarded_samples(), eq number_of_discarded_samples);
+
+        let mut discarded_samples = vec![];
+        publisher.list_discarded_samples(|subscriber_id, n| {
+            discarded_samples.push((subscriber_id, n));
+            CallbackProgression::Continue
+        });
+        assert_that!(discarded_samples, len 2);
+        assert_that!(
+            discarded_samples,
+            contains(slow_subscriber.id(), number_of_discarded_samples)
+        );
+        assert_that!(discarded_samples, contains(fast_subscriber.id(), 0));
+
+        let mut missed_samples = vec![];
+        slow_subscriber.list_missed_samples(|publisher_id, n| {
+            missed_samples.push((publisher_id, n));
+            CallbackProgression::Continue
+        });
+        assert_that!(missed_samples, eq vec![(publisher.id(), number_of_discarded_samples)]);
+    }
+
+    #[test]
+    fn overridden_samples_are_counted_as_discarded_samples<Sut: Service>() {
+        const BUFFER_SIZE: usize = 3;
+        const NUMBER_OF_SAMPLES: u64 = 7;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(true)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(publisher.update_connections(), is_ok);
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(n), is_ok);
+        }
+
+        let number_of_discarded_samples = NUMBER_OF_SAMPLES - BUFFER_SIZE as u64;
+        assert_that!(subscriber.update_connections(), is_ok);
+        assert_that!(publisher.number_of_discarded_samples(), eq number_of_discarded_samples);
+        assert_that!(subscriber.number_of_missed_samples(), eq number_of_discarded_samples);
+    }
+
     #[test]
     fn custom_payload_alignment_cannot_be_smaller_than_payload_type_alignment<Sut: Service>() {
         let service_name = generate_name();
@@ -1131,6 +1865,9 @@ mod service_publish_subscribe {
                 assert_that!(publisher.send_copy(789), eq Ok(0));
             }
         }
+
+        // the subscribers disconnected regularly, their samples were not abandoned
+        assert_that!(publisher.number_of_reclaimed_abandoned_samples(), eq 0);
     }
 
     #[test]
@@ -2976,6 +3713,44 @@ mod service_publish_subscribe {
         assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
     }
 
+    #[test]
+    fn sample_header_contains_node_id_sequence_number_and_timestamp<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 5;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(i), is_ok);
+        }
+
+        let mut last_timestamp = (0, 0);
+        for i in 0..NUMBER_OF_SAMPLES {
+            let sample = subscriber.receive().unwrap().unwrap();
+            let header = sample.header();
+            let timestamp = (
+                header.timestamp().seconds(),
+                header.timestamp().nanoseconds(),
+            );
+
+            assert_that!(header.node_id(), eq * node.id());
+            assert_that!(header.sequence_number(), eq i);
+            assert_that!(timestamp, ge last_timestamp);
+            assert_that!(timestamp, ne(0, 0));
+            last_timestamp = timestamp;
+        }
+    }
+
     #[test]
     fn send_increasing_samples_with_static_allocation_strategy_fails<Sut: Service>() {
         const SLICE_SIZE: usize = 1024;
@@ -3282,9 +4057,44 @@ mod service_publish_subscribe {
         });
     }
 
+    #[test]
+    fn access_control_list_of_service_builder_is_used_by_all_participants<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let acl = AccessControlListBuilder::new()
+            .permission(Permission::GROUP_READ | Permission::GROUP_WRITE)
+            .create();
+
+        let sut = node
+            .service_builder(&service_name)
+            .access_control_list(acl)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let publisher = sut2.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(publisher.send_copy(1234), is_ok);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1234);
+
+        let details = Sut::details(&service_name, &config, MessagingPattern::PublishSubscribe)
+            .unwrap()
+            .unwrap();
+        assert_that!(details.static_details.access_control_list(), eq Some(&acl));
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
     #[instantiate_tests(<iceoryx2::service::local::Service>)]
     mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::static_memory::Service>)]
+    mod static_memory {}
 }
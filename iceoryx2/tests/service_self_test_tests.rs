@@ -0,0 +1,99 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_self_test {
+    use std::sync::Barrier;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::self_test::SelfTestStage;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn self_test_passes_all_stages<S: Service>() {
+        let config = generate_isolated_config();
+
+        let report = S::self_test(&config);
+
+        assert_that!(report.has_passed(), eq true);
+        assert_that!(report.failed_stage().is_none(), eq true);
+
+        let stages = report
+            .stages()
+            .iter()
+            .map(|s| s.stage())
+            .collect::<Vec<_>>();
+        assert_that!(
+            stages,
+            eq vec![
+                SelfTestStage::NodeCreation,
+                SelfTestStage::PublishSubscribeServiceCreation,
+                SelfTestStage::PublishSubscribePortCreation,
+                SelfTestStage::SampleExchange,
+                SelfTestStage::EventServiceCreation,
+                SelfTestStage::EventPortCreation,
+                SelfTestStage::EventExchange,
+            ]
+        );
+
+        for stage in report.stages() {
+            assert_that!(stage.failure(), is_none);
+        }
+        assert_that!(report.duration(), ge report.stages()[0].duration());
+    }
+
+    #[test]
+    fn self_test_removes_temporary_services<S: Service>() {
+        let config = generate_isolated_config();
+
+        assert_that!(S::self_test(&config).has_passed(), eq true);
+
+        let mut number_of_services = 0;
+        S::list(&config, |_| {
+            number_of_services += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(number_of_services, eq 0);
+    }
+
+    #[test]
+    fn concurrent_self_tests_do_not_interfere<S: Service>() {
+        const NUMBER_OF_THREADS: usize = 4;
+        let config = generate_isolated_config();
+        let barrier = Barrier::new(NUMBER_OF_THREADS);
+
+        std::thread::scope(|s| {
+            let mut threads = vec![];
+            for _ in 0..NUMBER_OF_THREADS {
+                threads.push(s.spawn(|| {
+                    barrier.wait();
+                    S::self_test(&config).has_passed()
+                }));
+            }
+
+            for t in threads {
+                assert_that!(t.join().unwrap(), eq true);
+            }
+        });
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::static_memory::Service>)]
+    mod static_memory {}
+}
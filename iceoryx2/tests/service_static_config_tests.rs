@@ -10,6 +10,60 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+#[cfg(test)]
+mod static_config_file {
+    use iceoryx2::config::Config;
+    use iceoryx2::service::service_id::ServiceId;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_posix::file::{AccessMode, CreationMode, FileBuilder, Permission};
+    use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
+    use iceoryx2_bb_system_types::file_path::FilePath;
+
+    // Applies `modify` to the static config file of the service, e.g. to pretend that the
+    // service was created by another iceoryx2 version.
+    pub fn modify_static_config<F: FnOnce(&mut toml::Table)>(
+        config: &Config,
+        service_id: &ServiceId,
+        modify: F,
+    ) {
+        let mut path = *config.global.static_storage_root_path();
+        path.add_path_entry(&config.global.service.directory)
+            .unwrap();
+        let mut file_name = config.global.prefix;
+        file_name
+            .push_bytes(service_id.as_str().as_bytes())
+            .unwrap();
+        file_name
+            .push_bytes(
+                config
+                    .global
+                    .service
+                    .static_config_storage_suffix
+                    .as_bytes(),
+            )
+            .unwrap();
+        let path = FilePath::from_path_and_file(&path, &file_name).unwrap();
+
+        let mut content = String::new();
+        FileBuilder::new(&path)
+            .open_existing(AccessMode::Read)
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+
+        let mut static_config: toml::Table = content.parse().unwrap();
+        modify(&mut static_config);
+
+        let mut file = FileBuilder::new(&path)
+            .creation_mode(CreationMode::PurgeAndCreate)
+            .permission(Permission::OWNER_ALL)
+            .create()
+            .unwrap();
+        file.write(static_config.to_string().as_bytes()).unwrap();
+        file.set_permission(Permission::OWNER_READ).unwrap();
+    }
+}
+
 #[cfg(test)]
 mod service_static_config_message_type_details {
     use core::mem::size_of;
@@ -31,6 +85,8 @@ mod service_static_config_message_type_details {
             type_name: core::any::type_name::<Tmp>().to_string(),
             size: 0,
             alignment: 1,
+            stable_type_id: None,
+            variants: vec![],
         };
         assert_that!(sut, eq expected);
 
@@ -40,6 +96,8 @@ mod service_static_config_message_type_details {
             type_name: core::any::type_name::<i64>().to_string(),
             size: 8,
             alignment: ALIGNMENT,
+            stable_type_id: None,
+            variants: vec![],
         };
 
         assert_that!(sut, eq expected);
@@ -49,9 +107,547 @@ mod service_static_config_message_type_details {
             variant: TypeVariant::FixedSize,
             type_name: core::any::type_name::<TypeDetail>().to_string(),
             size: size_of::<TypeDetail>(),
-            alignment: ALIGNMENT,
+            alignment: align_of::<TypeDetail>(),
+            stable_type_id: None,
+            variants: vec![],
         };
 
         assert_that!(sut, eq expected);
     }
 }
+
+#[cfg(test)]
+mod service_static_config_iceoryx2_version {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::event::EventOpenError;
+    use iceoryx2::service::static_config::abi_version::ABI_VERSION_INTRODUCED_IN;
+    use iceoryx2::service::static_config::iceoryx2_version::{
+        Iceoryx2Version, MIN_COMPATIBLE_VERSION,
+    };
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    use super::static_config_file::modify_static_config;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_static_config_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn current_version_is_compatible_to_itself() {
+        let sut = Iceoryx2Version::current();
+        assert_that!(sut.is_compatible_to(&sut), eq true);
+    }
+
+    #[test]
+    fn version_older_than_min_compatible_version_is_rejected() {
+        let sut = Iceoryx2Version::new(0, 4, 9);
+        assert_that!(sut < MIN_COMPATIBLE_VERSION, eq true);
+        assert_that!(sut.is_compatible_to(&Iceoryx2Version::current()), eq false);
+    }
+
+    #[test]
+    fn version_newer_than_opener_is_rejected() {
+        let current = Iceoryx2Version::current();
+        let sut = Iceoryx2Version::new(current.major(), current.minor() + 1, 0);
+        assert_that!(sut.is_compatible_to(&current), eq false);
+        assert_that!(current.is_compatible_to(&sut), eq true);
+    }
+
+    #[test]
+    fn older_and_newer_patch_versions_are_accepted() {
+        let opener = Iceoryx2Version::new(
+            MIN_COMPATIBLE_VERSION.major(),
+            MIN_COMPATIBLE_VERSION.minor(),
+            MIN_COMPATIBLE_VERSION.patch() + 1,
+        );
+        let older_patch = MIN_COMPATIBLE_VERSION;
+        let newer_patch = Iceoryx2Version::new(opener.major(), opener.minor(), opener.patch() + 1);
+
+        assert_that!(older_patch.is_compatible_to(&opener), eq true);
+        assert_that!(newer_patch.is_compatible_to(&opener), eq true);
+    }
+
+    #[test]
+    fn min_compatible_version_is_the_release_that_introduced_the_abi_version() {
+        assert_that!(MIN_COMPATIBLE_VERSION, eq ABI_VERSION_INTRODUCED_IN);
+        assert_that!(MIN_COMPATIBLE_VERSION <= Iceoryx2Version::current(), eq true);
+    }
+
+    #[test]
+    fn display_prints_version_with_dots() {
+        let sut = Iceoryx2Version::new(1, 22, 333);
+        assert_that!(sut.to_string(), eq "1.22.333");
+    }
+
+    #[test]
+    fn created_service_records_current_version() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let details = ipc::Service::details(&service_name, &config, MessagingPattern::Event)
+            .unwrap()
+            .unwrap();
+        assert_that!(details.static_details.iceoryx2_version(), eq Some(&Iceoryx2Version::current()));
+    }
+
+    #[test]
+    fn open_service_without_version_with_matching_abi_version_works() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        modify_static_config(&config, sut.service_id(), |static_config| {
+            static_config.remove("iceoryx2_version");
+        });
+
+        let result = node.service_builder(&service_name).event().open();
+        assert_that!(result, is_ok);
+    }
+
+    #[test]
+    fn open_service_of_newer_minor_version_fails() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let current = Iceoryx2Version::current();
+        modify_static_config(&config, sut.service_id(), |static_config| {
+            let version = static_config
+                .get_mut("iceoryx2_version")
+                .unwrap()
+                .as_table_mut()
+                .unwrap();
+            version.insert(
+                "minor".to_string(),
+                toml::Value::Integer(current.minor() as i64 + 1),
+            );
+        });
+
+        let result = node.service_builder(&service_name).event().open();
+        assert_that!(result.err(), eq Some(EventOpenError::IncompatibleIceoryx2Version));
+    }
+}
+
+#[cfg(test)]
+mod service_static_config_abi_version {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use iceoryx2::service::static_config::abi_version::ABI_VERSION;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    use super::static_config_file::modify_static_config;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_static_config_abi_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn created_service_records_current_abi_version() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let details =
+            ipc::Service::details(&service_name, &config, MessagingPattern::PublishSubscribe)
+                .unwrap()
+                .unwrap();
+        assert_that!(details.static_details.abi_version(), eq Some(ABI_VERSION));
+    }
+
+    #[test]
+    fn open_service_with_different_abi_version_fails() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        modify_static_config(&config, sut.service_id(), |static_config| {
+            static_config.insert(
+                "abi_version".to_string(),
+                toml::Value::Integer(ABI_VERSION as i64 + 1),
+            );
+        });
+
+        let result = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(result.err(), eq Some(PublishSubscribeOpenError::IncompatibleAbiVersion));
+    }
+
+    #[test]
+    fn open_service_without_abi_version_of_release_with_abi_version_fails() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        modify_static_config(&config, sut.service_id(), |static_config| {
+            static_config.remove("abi_version");
+        });
+
+        let result = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(result.err(), eq Some(PublishSubscribeOpenError::IncompatibleAbiVersion));
+    }
+
+    #[test]
+    fn open_service_with_old_layout_and_compatible_types_works() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        // services of releases before the ABI version was introduced record neither the
+        // iceoryx2 version, the ABI version nor the pointer width
+        modify_static_config(&config, sut.service_id(), |static_config| {
+            static_config.remove("iceoryx2_version");
+            static_config.remove("abi_version");
+            static_config.remove("pointer_width");
+        });
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let opened_service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let subscriber = opened_service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(1234).unwrap();
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 1234);
+    }
+
+    #[test]
+    fn open_service_with_old_layout_and_different_types_fails() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        modify_static_config(&config, sut.service_id(), |static_config| {
+            static_config.remove("iceoryx2_version");
+            static_config.remove("abi_version");
+            static_config.remove("pointer_width");
+        });
+
+        let result = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u32>()
+            .open();
+        assert_that!(result.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+}
+
+#[cfg(test)]
+mod service_static_config_pointer_width {
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use iceoryx2::service::builder::request_response::RequestResponseOpenError;
+    use iceoryx2::service::service_id::ServiceId;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    use super::static_config_file::modify_static_config;
+
+    const OTHER_POINTER_WIDTH: u32 = if usize::BITS == 64 { 32 } else { 64 };
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_static_config_pointer_width_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    // Rewrites the static config of the service as if it was created by a process with the
+    // other pointer width. The payload types given by `payloads` contain a usize and are
+    // adjusted to the layout they have in the other process.
+    fn pretend_creation_by_other_pointer_width(
+        config: &Config,
+        service_id: &ServiceId,
+        payloads: &[&str],
+    ) {
+        modify_static_config(config, service_id, |static_config| {
+            static_config.insert(
+                "pointer_width".to_string(),
+                toml::Value::Integer(OTHER_POINTER_WIDTH as i64),
+            );
+            for details in payloads {
+                let payload = static_config
+                    .get_mut("messaging_pattern")
+                    .and_then(|v| v.get_mut(details))
+                    .and_then(|v| v.get_mut("payload"))
+                    .and_then(|v| v.as_table_mut())
+                    .unwrap();
+                let size_of_usize = toml::Value::Integer(OTHER_POINTER_WIDTH as i64 / 8);
+                payload.insert("size".to_string(), size_of_usize.clone());
+                payload.insert("alignment".to_string(), size_of_usize);
+            }
+        });
+    }
+
+    #[test]
+    fn created_service_records_pointer_width_of_process() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let details =
+            ipc::Service::details(&service_name, &config, MessagingPattern::RequestResponse)
+                .unwrap()
+                .unwrap();
+        assert_that!(details.static_details.pointer_width(), eq Some(usize::BITS));
+    }
+
+    #[test]
+    fn open_pub_sub_service_with_pointer_width_dependent_type_of_other_pointer_width_fails() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .create()
+            .unwrap();
+
+        pretend_creation_by_other_pointer_width(
+            &config,
+            sut.service_id(),
+            &["message_type_details"],
+        );
+
+        let result = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .open();
+        assert_that!(result.err(), eq Some(PublishSubscribeOpenError::IncompatibleBitness));
+    }
+
+    #[test]
+    fn open_pub_sub_service_with_fixed_width_type_of_other_pointer_width_works() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        pretend_creation_by_other_pointer_width(&config, sut.service_id(), &[]);
+
+        let result = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(result, is_ok);
+        let details =
+            ipc::Service::details(&service_name, &config, MessagingPattern::PublishSubscribe)
+                .unwrap()
+                .unwrap();
+        assert_that!(details.static_details.pointer_width(), eq Some(OTHER_POINTER_WIDTH));
+    }
+
+    #[test]
+    fn open_pub_sub_service_with_different_type_of_other_pointer_width_fails_with_incompatible_types(
+    ) {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .create()
+            .unwrap();
+
+        pretend_creation_by_other_pointer_width(
+            &config,
+            sut.service_id(),
+            &["message_type_details"],
+        );
+
+        let result = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u32>()
+            .open();
+        assert_that!(result.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
+    #[test]
+    fn open_req_res_service_with_pointer_width_dependent_type_of_other_pointer_width_fails() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, usize>()
+            .create()
+            .unwrap();
+
+        pretend_creation_by_other_pointer_width(
+            &config,
+            sut.service_id(),
+            &["response_message_type_details"],
+        );
+
+        let result = node
+            .service_builder(&service_name)
+            .request_response::<u64, usize>()
+            .open();
+        assert_that!(result.err(), eq Some(RequestResponseOpenError::IncompatibleBitness));
+    }
+}
+
+#[cfg(test)]
+mod service_static_config_domain {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::publish_subscribe::{
+        PublishSubscribeCreateError, PublishSubscribeOpenError,
+    };
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    use super::static_config_file::modify_static_config;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_static_config_domain_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn open_service_of_other_domain_fails() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        modify_static_config(&config, sut.service_id(), |static_config| {
+            static_config.insert(
+                "domain".to_string(),
+                toml::Value::String("other_domain".to_string()),
+            );
+        });
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleDomain));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeCreateError::AlreadyExists));
+    }
+}
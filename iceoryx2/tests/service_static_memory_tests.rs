@@ -0,0 +1,75 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod service_static_memory {
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn samples_are_placed_in_static_memory() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<static_memory::Service>()
+            .unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let sample = publisher.loan_uninit().unwrap();
+        let payload = sample.payload().as_ptr() as *const u8;
+        assert_that!(iceoryx2_cal::static_memory::contains(payload), eq true);
+        sample.write_payload(8912).send().unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        let payload = sample.payload() as *const u64 as *const u8;
+        assert_that!(iceoryx2_cal::static_memory::contains(payload), eq true);
+        assert_that!(*sample, eq 8912);
+    }
+
+    #[test]
+    fn event_service_reduces_default_max_event_id() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<static_memory::Service>()
+            .unwrap();
+
+        let service = node
+            .service_builder(&generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+        let default_event_id_max_value = config.defaults.event.event_id_max_value;
+        assert_that!(service.static_config().event_id_max_value(), lt default_event_id_max_value);
+
+        let service = node
+            .service_builder(&generate_service_name())
+            .event()
+            .event_id_max_value(1024)
+            .create()
+            .unwrap();
+        assert_that!(service.static_config().event_id_max_value(), eq 1024);
+
+        let notifier = service.notifier_builder().create().unwrap();
+        let listener = service.listener_builder().create().unwrap();
+        notifier
+            .notify_with_custom_event_id(EventId::new(1024))
+            .unwrap();
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(EventId::new(1024)));
+    }
+}
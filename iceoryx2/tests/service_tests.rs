@@ -19,15 +19,21 @@ mod service {
 
     use iceoryx2::node::NodeView;
     use iceoryx2::prelude::*;
+    use iceoryx2::service::attribute::{
+        DynamicAttributePermission, DynamicAttributeUpdateError, MAX_DYNAMIC_ATTRIBUTE_VALUE_LENGTH,
+    };
     use iceoryx2::service::builder::event::{EventCreateError, EventOpenError};
     use iceoryx2::service::builder::publish_subscribe::{
         PublishSubscribeCreateError, PublishSubscribeOpenError,
     };
     use iceoryx2::service::messaging_pattern::MessagingPattern;
     use iceoryx2::service::port_factory::{event, publish_subscribe};
+    use iceoryx2::service::service_id::{ServiceId, ServiceNameHashing};
     use iceoryx2::service::{ServiceDetailsError, ServiceListError};
     use iceoryx2::testing::*;
     use iceoryx2_bb_log::{set_log_level, LogLevel};
+    use iceoryx2_bb_posix::access_control_list::AccessControlListBuilder;
+    use iceoryx2_bb_posix::permission::Permission;
     use iceoryx2_bb_posix::system_configuration::SystemInfo;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
@@ -937,6 +943,394 @@ mod service {
         }
     }
 
+    #[test]
+    fn plain_name_hashing_uses_sanitized_service_name_as_service_id<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let mut config = generate_isolated_config();
+        config.global.service.name_hashing = ServiceNameHashing::PlainName;
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+
+        assert_that!(sut.service_id().as_str().ends_with(service_name.as_str()), eq true);
+
+        let sut2 = test.open(&node, &service_name, &AttributeVerifier::new());
+        assert_that!(sut2, is_ok);
+        let sut2 = sut2.unwrap();
+        assert_that!(sut2.service_id(), eq sut.service_id());
+
+        let details = Sut::details(&service_name, &config, Factory::messaging_pattern());
+        assert_that!(details, is_ok);
+        assert_that!(details.unwrap(), is_some);
+    }
+
+    #[test]
+    fn plain_name_hashing_replaces_invalid_characters_and_truncates_long_names<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = ServiceName::new(&format!(
+            "some/service:with.{}/and/a/really/long/name/that/exceeds/the/service/id/capacity",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap();
+        let mut config = generate_isolated_config();
+        config.global.service.name_hashing = ServiceNameHashing::PlainName;
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+
+        let service_id = sut.service_id().as_str();
+        assert_that!(service_id.len(), le ServiceId::max_len());
+        assert_that!(service_id.contains("some_service_with_"), eq true);
+
+        let sut2 = test.open(&node, &service_name, &AttributeVerifier::new());
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn colliding_service_names_are_detected_instead_of_connected<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let unique_id = UniqueSystemId::new().unwrap().value();
+        let service_name = ServiceName::new(&format!("collision/{}", unique_id)).unwrap();
+        let colliding_service_name = ServiceName::new(&format!("collision_{}", unique_id)).unwrap();
+        let mut config = generate_isolated_config();
+        config.global.service.name_hashing = ServiceNameHashing::PlainName;
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+
+        let sut2 = test.open(&node, &colliding_service_name, &AttributeVerifier::new());
+        assert_that!(sut2, is_err);
+        assert_that!(format!("{:?}", sut2.err().unwrap()).contains("ServiceNameHashCollision"), eq true);
+
+        let sut3 = test.create(&node, &colliding_service_name, &AttributeSpecifier::new());
+        assert_that!(sut3, is_err);
+    }
+
+    #[test]
+    fn keyed_sip_hash_name_hashing_depends_on_the_key<Sut: Service, Factory: SutFactory<Sut>>() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let mut config = generate_isolated_config();
+        config.global.service.name_hashing = ServiceNameHashing::KeyedSipHash;
+        config.global.service.name_hashing_key = [1, 2, 3, 4];
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+
+        let sut2 = test.open(&node, &service_name, &AttributeVerifier::new());
+        assert_that!(sut2, is_ok);
+        let sut2 = sut2.unwrap();
+        assert_that!(sut2.service_id(), eq sut.service_id());
+
+        let mut other_key_config = config.clone();
+        other_key_config.global.service.name_hashing_key = [4, 3, 2, 1];
+        let other_key_node = NodeBuilder::new()
+            .config(&other_key_config)
+            .create::<Sut>()
+            .unwrap();
+        let sut3 = test.open(&other_key_node, &service_name, &AttributeVerifier::new());
+        assert_that!(sut3, is_err);
+    }
+
+    #[test]
+    fn dynamic_attributes_defined_by_creator_can_be_read_in_opener<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let defined_attributes = AttributeSpecifier::new()
+            .define("static", "value")
+            .define_dynamic(
+                "calibration_revision",
+                "1",
+                DynamicAttributePermission::OwnerOnly,
+            )
+            .unwrap();
+
+        let sut = test
+            .create(&node, &service_name, &defined_attributes)
+            .unwrap();
+        let sut2 = test
+            .open(&node, &service_name, &AttributeVerifier::new())
+            .unwrap();
+
+        assert_that!(sut.attributes(), eq defined_attributes.attributes());
+        assert_that!(
+            sut.dynamic_attributes(),
+            eq * defined_attributes.dynamic_attributes()
+        );
+        assert_that!(
+            sut2.dynamic_attributes(),
+            eq * defined_attributes.dynamic_attributes()
+        );
+        assert_that!(sut2.dynamic_attributes_revision(), eq 0);
+    }
+
+    #[test]
+    fn updated_dynamic_attributes_are_visible_to_all_participants<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node_1 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let node_2 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = test
+            .create(
+                &node_1,
+                &service_name,
+                &AttributeSpecifier::new()
+                    .define_dynamic(
+                        "calibration_revision",
+                        "1",
+                        DynamicAttributePermission::AllParticipants,
+                    )
+                    .unwrap()
+                    .define_dynamic("mode", "idle", DynamicAttributePermission::OwnerOnly)
+                    .unwrap(),
+            )
+            .unwrap();
+        let sut2 = test
+            .open(&node_2, &service_name, &AttributeVerifier::new())
+            .unwrap();
+
+        assert_that!(
+            sut2.update_dynamic_attribute("calibration_revision", "2"),
+            is_ok
+        );
+        assert_that!(sut.dynamic_attributes_revision(), eq 1);
+        let attributes = sut.dynamic_attributes();
+        assert_that!(attributes.get_key_value_at("calibration_revision", 0), eq Some("2"));
+        let attributes = sut.dynamic_attributes();
+        assert_that!(attributes.get_key_value_at("mode", 0), eq Some("idle"));
+
+        assert_that!(sut.update_dynamic_attribute("mode", "active"), is_ok);
+        assert_that!(sut2.dynamic_attributes_revision(), eq 2);
+        let attributes = sut2.dynamic_attributes();
+        assert_that!(attributes.get_key_value_at("mode", 0), eq Some("active"));
+        let attributes = sut2.dynamic_attributes();
+        assert_that!(attributes.get_key_value_len("mode"), eq 1);
+    }
+
+    #[test]
+    fn owner_only_dynamic_attributes_can_only_be_updated_by_the_creator<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node_1 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let node_2 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = test
+            .create(
+                &node_1,
+                &service_name,
+                &AttributeSpecifier::new()
+                    .define_dynamic("mode", "idle", DynamicAttributePermission::OwnerOnly)
+                    .unwrap(),
+            )
+            .unwrap();
+        let sut2 = test
+            .open(&node_2, &service_name, &AttributeVerifier::new())
+            .unwrap();
+        let sut3 = test
+            .open(&node_1, &service_name, &AttributeVerifier::new())
+            .unwrap();
+
+        let result = sut2.update_dynamic_attribute("mode", "active");
+        assert_that!(result, eq Err(DynamicAttributeUpdateError::InsufficientPermissions));
+        assert_that!(sut.dynamic_attributes_revision(), eq 0);
+
+        assert_that!(sut3.update_dynamic_attribute("mode", "active"), is_ok);
+        let attributes = sut2.dynamic_attributes();
+        assert_that!(attributes.get_key_value_at("mode", 0), eq Some("active"));
+    }
+
+    #[test]
+    fn dynamic_attribute_listener_is_woken_up_by_updates<Sut: Service, Factory: SutFactory<Sut>>() {
+        const TIMEOUT: Duration = Duration::from_millis(50);
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = test
+            .create(
+                &node,
+                &service_name,
+                &AttributeSpecifier::new()
+                    .define_dynamic("mode", "idle", DynamicAttributePermission::OwnerOnly)
+                    .unwrap(),
+            )
+            .unwrap();
+        let sut2 = test
+            .open(&node, &service_name, &AttributeVerifier::new())
+            .unwrap();
+
+        let listener = sut2.dynamic_attribute_listener().unwrap();
+        assert_that!(listener.try_wait().unwrap(), eq false);
+
+        assert_that!(sut.update_dynamic_attribute("mode", "active"), is_ok);
+        assert_that!(listener.timed_wait(TIMEOUT).unwrap(), eq true);
+        assert_that!(listener.try_wait().unwrap(), eq false);
+        let attributes = sut2.dynamic_attributes();
+        assert_that!(attributes.get_key_value_at("mode", 0), eq Some("active"));
+
+        // an update that fails does not wake up the listener
+        assert_that!(sut.update_dynamic_attribute("unknown", "value"), is_err);
+        assert_that!(listener.timed_wait(TIMEOUT).unwrap(), eq false);
+    }
+
+    #[test]
+    fn concurrent_dynamic_attribute_updates_of_one_process_are_serialized<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let _watch_dog = Watchdog::new();
+        let number_of_threads = (SystemInfo::NumberOfCpuCores.value()).clamp(2, 4);
+        const NUMBER_OF_ITERATIONS: u64 = 50;
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = test
+            .create(
+                &node,
+                &service_name,
+                &AttributeSpecifier::new()
+                    .define_dynamic("counter", "0", DynamicAttributePermission::AllParticipants)
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let barrier = Barrier::new(number_of_threads);
+        std::thread::scope(|s| {
+            let mut threads = vec![];
+            for _ in 0..number_of_threads {
+                threads.push(s.spawn(|| {
+                    let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+                    let sut = test
+                        .open(&node, &service_name, &AttributeVerifier::new())
+                        .unwrap();
+                    barrier.wait();
+
+                    for n in 0..NUMBER_OF_ITERATIONS {
+                        assert_that!(
+                            sut.update_dynamic_attribute("counter", &n.to_string()),
+                            is_ok
+                        );
+                    }
+                }));
+            }
+
+            for thread in threads {
+                thread.join().unwrap();
+            }
+        });
+
+        assert_that!(sut.dynamic_attributes_revision(), eq number_of_threads as u64 * NUMBER_OF_ITERATIONS);
+    }
+
+    #[test]
+    fn details_of_non_existing_service_are_none<Sut: Service, Factory: SutFactory<Sut>>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+
+        let sut = Sut::details(&service_name, &config, Factory::messaging_pattern());
+
+        assert_that!(sut, is_ok);
+        assert_that!(sut.unwrap(), is_none);
+    }
+
+    #[test]
+    fn details_contain_static_config_and_dynamic_attributes<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let defined_attributes = AttributeSpecifier::new()
+            .define("static", "value")
+            .define_dynamic("mode", "idle", DynamicAttributePermission::OwnerOnly)
+            .unwrap();
+
+        let service = test
+            .create(&node, &service_name, &defined_attributes)
+            .unwrap();
+        assert_that!(service.update_dynamic_attribute("mode", "active"), is_ok);
+
+        let sut = Sut::details(&service_name, &config, Factory::messaging_pattern())
+            .unwrap()
+            .unwrap();
+
+        assert_that!(sut.static_details.service_id(), eq service.service_id());
+        assert_that!(sut.static_details.attributes(), eq defined_attributes.attributes());
+
+        let dynamic_details = sut.dynamic_details.unwrap();
+        assert_that!(dynamic_details.ports, is_empty);
+        assert_that!(dynamic_details.dynamic_attributes.get_key_value_at("mode", 0), eq Some("active"));
+    }
+
+    #[test]
+    fn updating_undeclared_dynamic_attribute_fails<Sut: Service, Factory: SutFactory<Sut>>() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = test
+            .create(
+                &node,
+                &service_name,
+                &AttributeSpecifier::new()
+                    .define("static", "value")
+                    .define_dynamic("dynamic", "value", DynamicAttributePermission::OwnerOnly)
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let result = sut.update_dynamic_attribute("static", "another value");
+        assert_that!(result, eq Err(DynamicAttributeUpdateError::UnknownKey));
+
+        let result = sut.update_dynamic_attribute(
+            "dynamic",
+            &"x".repeat(MAX_DYNAMIC_ATTRIBUTE_VALUE_LENGTH + 1),
+        );
+        assert_that!(result, eq Err(DynamicAttributeUpdateError::ValueTooLong));
+
+        assert_that!(sut.dynamic_attributes_revision(), eq 0);
+        let attributes = sut.dynamic_attributes();
+        assert_that!(attributes.get_key_value_at("dynamic", 0), eq Some("value"));
+    }
+
     #[test]
     fn uuid_is_equal_in_within_all_opened_instances<Sut: Service, Factory: SutFactory<Sut>>() {
         let test = Factory::new();
@@ -954,6 +1348,32 @@ mod service {
         assert_that!(sut.service_id(), eq sut2.service_id());
     }
 
+    #[test]
+    fn access_control_list_of_config_is_stored_in_static_config<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let mut config = generate_isolated_config();
+        let acl = AccessControlListBuilder::new()
+            .permission(Permission::GROUP_READ | Permission::OTHERS_READ)
+            .create();
+        config.global.service.access_control_list = Some(acl);
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+        let sut2 = test.open(&node, &service_name, &AttributeVerifier::new());
+        assert_that!(sut2, is_ok);
+
+        let details = Sut::details(&service_name, &config, Factory::messaging_pattern())
+            .unwrap()
+            .unwrap();
+        assert_that!(details.static_details.access_control_list(), eq Some(&acl));
+    }
+
     mod ipc {
         use iceoryx2::service::ipc::Service;
 
@@ -973,4 +1393,14 @@ mod service {
         #[instantiate_tests(<Service, crate::service::PubSubTests::<Service>>)]
         mod publish_subscribe {}
     }
+
+    mod static_memory {
+        use iceoryx2::service::static_memory::Service;
+
+        #[instantiate_tests(<Service, crate::service::EventTests::<Service>>)]
+        mod event {}
+
+        #[instantiate_tests(<Service, crate::service::PubSubTests::<Service>>)]
+        mod publish_subscribe {}
+    }
 }
@@ -12,10 +12,15 @@
 
 #[generic_tests::define]
 mod subscriber {
+    use iceoryx2::port::wait_strategy::WaitStrategy;
     use iceoryx2::port::ReceiveError;
     use iceoryx2::service::builder::publish_subscribe::CustomPayloadMarker;
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use std::collections::HashSet;
+    use std::sync::Barrier;
+    use std::time::Instant;
+
+    use core::time::Duration;
 
     use iceoryx2::{
         node::NodeBuilder,
@@ -25,6 +30,9 @@ mod subscriber {
     };
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
 
     fn generate_name() -> ServiceName {
         ServiceName::new(&format!(
@@ -99,9 +107,407 @@ mod subscriber {
         let _sample = sut.receive();
     }
 
+    #[test]
+    fn timed_receive_returns_none_when_timeout_has_passed<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = service
+            .subscriber_builder()
+            .blocking_receive_spin_repetitions(100)
+            .create()
+            .unwrap();
+
+        let start = Instant::now();
+        let sample = sut.timed_receive(TIMEOUT).unwrap();
+
+        assert_that!(sample, is_none);
+        assert_that!(start.elapsed(), ge TIMEOUT);
+    }
+
+    #[test]
+    fn timed_receive_with_spinning_wait_strategy_returns_none_when_timeout_has_passed<
+        Sut: Service,
+    >() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        for wait_strategy in [
+            WaitStrategy::Spin,
+            WaitStrategy::SpinThenYield {
+                spin_repetitions: 100,
+            },
+        ] {
+            let sut = service
+                .subscriber_builder()
+                .wait_strategy(wait_strategy)
+                .create()
+                .unwrap();
+
+            let start = Instant::now();
+            let sample = sut.timed_receive(TIMEOUT).unwrap();
+
+            assert_that!(sample, is_none);
+            assert_that!(start.elapsed(), ge TIMEOUT);
+        }
+    }
+
+    #[test]
+    fn timed_receive_returns_available_sample_immediately<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(8192).unwrap();
+
+        let sample = sut.timed_receive(Duration::from_secs(10)).unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 8192);
+    }
+
+    #[test]
+    fn blocking_receive_wakes_up_when_sample_is_sent<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+                let service = node
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .open_or_create()
+                    .unwrap();
+                let publisher = service.publisher_builder().create().unwrap();
+
+                barrier.wait();
+                std::thread::sleep(TIMEOUT);
+                publisher.send_copy(1234).unwrap();
+                barrier.wait();
+            });
+
+            let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+            let service = node
+                .service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .open_or_create()
+                .unwrap();
+            let sut = service
+                .subscriber_builder()
+                .blocking_receive_spin_repetitions(1000)
+                .create()
+                .unwrap();
+
+            barrier.wait();
+            let sample = sut.blocking_receive().unwrap();
+            assert_that!(*sample, eq 1234);
+            barrier.wait();
+        });
+    }
+
+    #[test]
+    fn blocking_receive_receives_samples_sent_while_it_starts_to_wait<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 500;
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+                let service = node
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .open_or_create()
+                    .unwrap();
+                let publisher = service.publisher_builder().create().unwrap();
+
+                barrier.wait();
+                for n in 0..NUMBER_OF_SAMPLES {
+                    barrier.wait();
+                    publisher.send_copy(n).unwrap();
+                }
+                barrier.wait();
+            });
+
+            let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+            let service = node
+                .service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .open_or_create()
+                .unwrap();
+            let sut = service
+                .subscriber_builder()
+                .wait_strategy(WaitStrategy::Block)
+                .create()
+                .unwrap();
+
+            barrier.wait();
+            for n in 0..NUMBER_OF_SAMPLES {
+                barrier.wait();
+                let sample = sut.blocking_receive().unwrap();
+                assert_that!(*sample, eq n);
+            }
+            barrier.wait();
+        });
+    }
+
+    #[test]
+    fn timed_receive_wakes_up_when_sample_is_sent<Sut: Service>() {
+        const LONG_TIMEOUT: Duration = Duration::from_secs(60);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+                let service = node
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .open_or_create()
+                    .unwrap();
+                let publisher = service.publisher_builder().create().unwrap();
+
+                barrier.wait();
+                std::thread::sleep(TIMEOUT);
+                publisher.send_copy(5678).unwrap();
+                barrier.wait();
+            });
+
+            let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+            let service = node
+                .service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .open_or_create()
+                .unwrap();
+            let sut = service
+                .subscriber_builder()
+                .wait_strategy(WaitStrategy::Block)
+                .create()
+                .unwrap();
+
+            barrier.wait();
+            let start = Instant::now();
+            let sample = sut.timed_receive(LONG_TIMEOUT).unwrap();
+            assert_that!(start.elapsed(), lt LONG_TIMEOUT);
+            assert_that!(sample, is_some);
+            assert_that!(*sample.unwrap(), eq 5678);
+            barrier.wait();
+        });
+    }
+
+    #[test]
+    fn blocking_receive_with_spin_wait_strategy_wakes_up_when_sample_is_sent<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+                let service = node
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .open_or_create()
+                    .unwrap();
+                let publisher = service.publisher_builder().create().unwrap();
+
+                barrier.wait();
+                std::thread::sleep(TIMEOUT);
+                publisher.send_copy(4321).unwrap();
+                barrier.wait();
+            });
+
+            let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+            let service = node
+                .service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .open_or_create()
+                .unwrap();
+            let sut = service
+                .subscriber_builder()
+                .wait_strategy(WaitStrategy::Spin)
+                .create()
+                .unwrap();
+
+            barrier.wait();
+            let sample = sut.blocking_receive().unwrap();
+            assert_that!(*sample, eq 4321);
+            barrier.wait();
+        });
+    }
+
+    #[test]
+    fn blocking_receive_works_with_slices<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(4)
+            .create()
+            .unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        let sample = publisher.loan_slice_uninit(3).unwrap();
+        sample.write_from_fn(|n| n as u64 * 2).send().unwrap();
+
+        let sample = sut.blocking_receive().unwrap();
+        assert_that!(*sample, eq [0, 2, 4]);
+
+        let sample = sut.timed_receive(TIMEOUT).unwrap();
+        assert_that!(sample, is_none);
+    }
+
+    fn send_with_priorities<Sut: Service>(
+        publisher: &iceoryx2::port::publisher::Publisher<Sut, u64, ()>,
+        samples: &[(u64, u8)],
+    ) {
+        for (value, priority) in samples {
+            let mut sample = publisher.loan_uninit().unwrap();
+            sample.set_priority(*priority);
+            sample.write_payload(*value).send().unwrap();
+        }
+    }
+
+    #[test]
+    fn receive_by_priority_delivers_samples_with_higher_priority_first<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 5;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .subscriber_max_borrowed_samples(NUMBER_OF_SAMPLES)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .receive_by_priority(true)
+            .create()
+            .unwrap();
+
+        send_with_priorities(&publisher, &[(1, 0), (2, 5), (3, 1), (4, 5), (5, 0)]);
+
+        let mut received = vec![];
+        while let Some(sample) = sut.receive().unwrap() {
+            received.push((*sample, sample.header().priority()));
+        }
+
+        assert_that!(received, eq vec![(2, 5), (4, 5), (3, 1), (1, 0), (5, 0)]);
+    }
+
+    #[test]
+    fn receive_by_priority_is_disabled_by_default<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 3;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        send_with_priorities(&publisher, &[(1, 0), (2, 9), (3, 4)]);
+
+        let mut received = vec![];
+        while let Some(sample) = sut.receive().unwrap() {
+            received.push(*sample);
+        }
+
+        assert_that!(received, eq vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn receive_by_priority_releases_pending_samples_on_drop<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 4;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .subscriber_max_borrowed_samples(NUMBER_OF_SAMPLES)
+            .create()
+            .unwrap();
+
+        let publisher = service
+            .publisher_builder()
+            .max_loaned_samples(1)
+            .create()
+            .unwrap();
+        let sut = service
+            .subscriber_builder()
+            .receive_by_priority(true)
+            .create()
+            .unwrap();
+
+        send_with_priorities(&publisher, &[(1, 0), (2, 3), (3, 2), (4, 1)]);
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 2);
+        assert_that!(sut.has_samples().unwrap(), eq true);
+
+        drop(sample);
+        drop(sut);
+
+        for _ in 0..NUMBER_OF_SAMPLES * 2 {
+            assert_that!(publisher.send_copy(0), is_ok);
+        }
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
     #[instantiate_tests(<iceoryx2::service::local::Service>)]
     mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::static_memory::Service>)]
+    mod static_memory {}
 }
@@ -322,6 +322,86 @@ mod waitset {
         assert_that!(start.elapsed(), time_at_least TIMEOUT);
     }
 
+    #[test]
+    fn run_with_timeout_informs_only_once_until_reset<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let timeout_guard = sut.attach_timeout(TIMEOUT).unwrap();
+        let _tick_guard = sut.attach_interval(TIMEOUT * 2).unwrap();
+
+        let mut timeout_counter = 0;
+        let start = Instant::now();
+        for _ in 0..2 {
+            sut.wait_and_process_once(|id| {
+                if id.has_event_from(&timeout_guard) {
+                    timeout_counter += 1;
+                }
+                CallbackProgression::Continue
+            })
+            .unwrap();
+        }
+
+        assert_that!(timeout_counter, eq 1);
+        assert_that!(start.elapsed(), time_at_least TIMEOUT * 2);
+
+        assert_that!(timeout_guard.reset(), is_ok);
+        let start = Instant::now();
+        sut.wait_and_process_once(|id| {
+            if id.has_event_from(&timeout_guard) {
+                timeout_counter += 1;
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(timeout_counter, eq 2);
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+    }
+
+    #[test]
+    fn lost_interval_periods_are_counted_as_missed_deadlines<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let tick_guard = sut.attach_interval(TIMEOUT).unwrap();
+        assert_that!(tick_guard.missed_deadlines(), eq 0);
+
+        std::thread::sleep(TIMEOUT * 3 + TIMEOUT / 2);
+
+        let mut callback_counter = 0;
+        sut.wait_and_process_once(|_| {
+            callback_counter += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(callback_counter, eq 1);
+        assert_that!(tick_guard.missed_deadlines(), ge 2);
+    }
+
+    #[test]
+    fn attaching_zero_interval_or_timeout_fails<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let result = sut.attach_interval(Duration::ZERO);
+        assert_that!(result.err(), eq Some(WaitSetAttachmentError::InternalError));
+
+        let result = sut.attach_timeout(Duration::ZERO);
+        assert_that!(result.err(), eq Some(WaitSetAttachmentError::InternalError));
+
+        assert_that!(sut.is_empty(), eq true);
+    }
+
     #[test]
     fn run_lists_all_deadlines<S: Service>()
     where
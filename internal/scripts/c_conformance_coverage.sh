@@ -0,0 +1,70 @@
+# Copyright (c) 2024 Contributors to the Eclipse Foundation
+#
+# See the NOTICE file(s) distributed with this work for additional
+# information regarding copyright ownership.
+#
+# This program and the accompanying materials are made available under the
+# terms of the Apache Software License 2.0 which is available at
+# https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+# which is available at https://opensource.org/licenses/MIT.
+#
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#!/bin/bash
+
+# Lists all exported 'iox2_*' functions of the C API that are not called by
+# the C conformance tests and fails when at least one function is not covered.
+
+set -e
+
+cd $(git rev-parse --show-toplevel)
+
+FFI_SOURCES=iceoryx2-ffi/ffi/src
+CONFORMANCE_SOURCES=iceoryx2-ffi/c/tests/conformance/src
+
+# functions marked with 'cbindgen:ignore' are not part of the public C header
+EXPORTED_FUNCTIONS=$(find ${FFI_SOURCES} -name "*.rs" -exec awk '
+    /cbindgen:ignore/ { ignore = 1; next }
+    /(unsafe )?extern "C" fn iox2_/ {
+        match($0, /fn iox2_[a-z0-9_]*/)
+        if (ignore == 0) { print substr($0, RSTART + 3, RLENGTH - 3) }
+        ignore = 0
+        next
+    }
+    /^(pub )?(struct|enum|type|impl|fn) / { ignore = 0 }
+' {} \; | sort -u)
+
+NUMBER_OF_FUNCTIONS=0
+NUMBER_OF_COVERED_FUNCTIONS=0
+UNCOVERED_FUNCTIONS=""
+
+for f in ${EXPORTED_FUNCTIONS}
+do
+    let NUMBER_OF_FUNCTIONS=${NUMBER_OF_FUNCTIONS}+1
+    if grep -rqw "${f}" ${CONFORMANCE_SOURCES}
+    then
+        let NUMBER_OF_COVERED_FUNCTIONS=${NUMBER_OF_COVERED_FUNCTIONS}+1
+    else
+        UNCOVERED_FUNCTIONS="${UNCOVERED_FUNCTIONS} ${f}"
+    fi
+done
+
+if [[ -n "${UNCOVERED_FUNCTIONS}" ]]
+then
+    echo "Functions not covered by the C conformance tests:"
+    for f in ${UNCOVERED_FUNCTIONS}
+    do
+        echo "  ${f}"
+    done
+fi
+
+echo
+echo "Report"
+echo "======"
+echo "  Covered functions: ${NUMBER_OF_COVERED_FUNCTIONS}/${NUMBER_OF_FUNCTIONS}"
+echo
+
+if [[ ${NUMBER_OF_COVERED_FUNCTIONS} != ${NUMBER_OF_FUNCTIONS} ]]
+then
+    exit 1
+fi